@@ -0,0 +1,51 @@
+//! Arbitrary sequences of bit code reads over arbitrary data.
+#![no_main]
+
+use _core::lowlevel::{BitReader, Endian};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // The first bytes pick the codepage and the reads; the rest is the stream.
+    let Some((&codepage, rest)) = data.split_first() else {
+        return;
+    };
+    let split = rest.len().min(64);
+    let (ops, stream) = rest.split_at(split);
+    let mut reader = BitReader::new_with_codepage(stream, Some(u16::from(codepage)));
+    if codepage & 1 == 1 {
+        reader.enable_trace();
+    }
+    for (index, op) in ops.iter().enumerate() {
+        let result = match op % 24 {
+            0 => reader.read_b().map(drop),
+            1 => reader.read_bb().map(drop),
+            2 => reader.read_3b().map(drop),
+            3 => reader.read_rc().map(drop),
+            4 => reader.read_rcs(usize::from(*op) * 16).map(drop),
+            5 => reader.read_rs(Endian::Little).map(drop),
+            6 => reader.read_rl(Endian::Big).map(drop),
+            7 => reader.read_rd(Endian::Little).map(drop),
+            8 => reader.read_bd().map(drop),
+            9 => reader.read_3bd().map(drop),
+            10 => reader.read_dd(1.0).map(drop),
+            11 => reader.read_bt().map(drop),
+            12 => reader.read_be().map(drop),
+            13 => reader.read_bs().map(drop),
+            14 => reader.read_bl().map(drop),
+            15 => reader.read_bll().map(drop),
+            16 => reader.read_ms().map(drop),
+            17 => reader.read_mc().map(drop),
+            18 => reader.read_umc().map(drop),
+            19 => reader.read_ot_r2010().map(drop),
+            20 => reader.read_h().map(drop),
+            21 => reader.read_tv().map(drop),
+            22 => reader.read_tu().map(drop),
+            _ => reader.read_bits_msb(op / 3).map(drop),
+        };
+        if result.is_err() {
+            // Keep going from an arbitrary position, including past the end.
+            reader.set_bit_pos((index as u32) * u32::from(*op));
+        }
+    }
+    let _ = reader.take_trace();
+});
@@ -0,0 +1,30 @@
+//! Whole-file pipeline: container parsing, the object index and per-record
+//! header parsing.
+#![no_main]
+
+use _core::lowlevel::{parse_object_header, read_object_type, Decoder, ParseConfig};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let config = ParseConfig {
+        max_objects: 10_000,
+        max_section_bytes: 16 * 1024 * 1024,
+        ..Default::default()
+    };
+    let Ok(decoder) = Decoder::new(data, config) else {
+        return;
+    };
+    let _ = decoder.section_directory();
+    let _ = decoder.dynamic_type_map();
+    let Ok(index) = decoder.build_object_index() else {
+        return;
+    };
+    for object in &index.objects {
+        let Ok(record) = decoder.parse_object_record(object.offset) else {
+            continue;
+        };
+        let _ = parse_object_header(&record, decoder.version());
+        let mut reader = record.bit_reader();
+        let _ = read_object_type(&mut reader, decoder.version());
+    }
+});
@@ -0,0 +1,109 @@
+//! Every entity decoder, in every version variant, fed an arbitrary bit
+//! stream positioned where the object type prefix would end.
+#![no_main]
+
+use _core::entities;
+use _core::lowlevel::BitReader;
+use libfuzzer_sys::fuzz_target;
+
+macro_rules! entity_decoders {
+    ($($base:ident, $r2007:ident, $r2010:ident, $r2013:ident;)*) => {
+        const DECODER_FAMILIES: usize = [$(stringify!($base)),*].len();
+
+        fn decode(family: usize, variant: u8, stream: &[u8], start_bit: u32, end_bit: u32) {
+            let mut reader = BitReader::new(stream);
+            reader.set_bit_pos(start_bit);
+            let mut index = 0;
+            $(
+                if family == index {
+                    let _ = match variant % 4 {
+                        0 => entities::$base(&mut reader).map(drop),
+                        1 => entities::$r2007(&mut reader).map(drop),
+                        2 => entities::$r2010(&mut reader, end_bit, 1).map(drop),
+                        _ => entities::$r2013(&mut reader, end_bit, 1).map(drop),
+                    };
+                    return;
+                }
+                index += 1;
+            )*
+            let _ = index;
+        }
+    };
+}
+
+entity_decoders! {
+    decode_line, decode_line_r2007, decode_line_r2010, decode_line_r2013;
+    decode_point, decode_point_r2007, decode_point_r2010, decode_point_r2013;
+    decode_arc, decode_arc_r2007, decode_arc_r2010, decode_arc_r2013;
+    decode_circle, decode_circle_r2007, decode_circle_r2010, decode_circle_r2013;
+    decode_ellipse, decode_ellipse_r2007, decode_ellipse_r2010, decode_ellipse_r2013;
+    decode_spline, decode_spline_r2007, decode_spline_r2010, decode_spline_r2013;
+    decode_text, decode_text_r2007, decode_text_r2010, decode_text_r2013;
+    decode_attrib, decode_attrib_r2007, decode_attrib_r2010, decode_attrib_r2013;
+    decode_attdef, decode_attdef_r2007, decode_attdef_r2010, decode_attdef_r2013;
+    decode_mtext, decode_mtext_r2007, decode_mtext_r2010, decode_mtext_r2013;
+    decode_insert, decode_insert_r2007, decode_insert_r2010, decode_insert_r2013;
+    decode_minsert, decode_minsert_r2007, decode_minsert_r2010, decode_minsert_r2013;
+    decode_lwpolyline, decode_lwpolyline_r2007, decode_lwpolyline_r2010, decode_lwpolyline_r2013;
+    decode_hatch, decode_hatch_r2007, decode_hatch_r2010, decode_hatch_r2013;
+    decode_leader, decode_leader_r2007, decode_leader_r2010, decode_leader_r2013;
+    decode_tolerance, decode_tolerance_r2007, decode_tolerance_r2010, decode_tolerance_r2013;
+    decode_mline, decode_mline_r2007, decode_mline_r2010, decode_mline_r2013;
+    decode_3dface, decode_3dface_r2007, decode_3dface_r2010, decode_3dface_r2013;
+    decode_solid, decode_solid_r2007, decode_solid_r2010, decode_solid_r2013;
+    decode_trace, decode_trace_r2007, decode_trace_r2010, decode_trace_r2013;
+    decode_shape, decode_shape_r2007, decode_shape_r2010, decode_shape_r2013;
+    decode_viewport, decode_viewport_r2007, decode_viewport_r2010, decode_viewport_r2013;
+    decode_oleframe, decode_oleframe_r2007, decode_oleframe_r2010, decode_oleframe_r2013;
+    decode_ole2frame, decode_ole2frame_r2007, decode_ole2frame_r2010, decode_ole2frame_r2013;
+    decode_long_transaction, decode_long_transaction_r2007, decode_long_transaction_r2010,
+        decode_long_transaction_r2013;
+    decode_region, decode_region_r2007, decode_region_r2010, decode_region_r2013;
+    decode_3dsolid, decode_3dsolid_r2007, decode_3dsolid_r2010, decode_3dsolid_r2013;
+    decode_body, decode_body_r2007, decode_body_r2010, decode_body_r2013;
+    decode_ray, decode_ray_r2007, decode_ray_r2010, decode_ray_r2013;
+    decode_xline, decode_xline_r2007, decode_xline_r2010, decode_xline_r2013;
+    decode_mesh, decode_mesh_r2007, decode_mesh_r2010, decode_mesh_r2013;
+    decode_light, decode_light_r2007, decode_light_r2010, decode_light_r2013;
+    decode_polyline_2d, decode_polyline_2d_r2007, decode_polyline_2d_r2010,
+        decode_polyline_2d_r2013;
+    decode_polyline_3d, decode_polyline_3d_r2007, decode_polyline_3d_r2010,
+        decode_polyline_3d_r2013;
+    decode_polyline_mesh, decode_polyline_mesh_r2007, decode_polyline_mesh_r2010,
+        decode_polyline_mesh_r2013;
+    decode_polyline_pface, decode_polyline_pface_r2007, decode_polyline_pface_r2010,
+        decode_polyline_pface_r2013;
+    decode_vertex_2d, decode_vertex_2d_r2007, decode_vertex_2d_r2010, decode_vertex_2d_r2013;
+    decode_vertex_3d, decode_vertex_3d_r2007, decode_vertex_3d_r2010, decode_vertex_3d_r2013;
+    decode_vertex_pface_face, decode_vertex_pface_face_r2007, decode_vertex_pface_face_r2010,
+        decode_vertex_pface_face_r2013;
+    decode_dim_linear, decode_dim_linear_r2007, decode_dim_linear_r2010, decode_dim_linear_r2013;
+    decode_dim_radius, decode_dim_radius_r2007, decode_dim_radius_r2010, decode_dim_radius_r2013;
+    decode_dim_diameter, decode_dim_diameter_r2007, decode_dim_diameter_r2010,
+        decode_dim_diameter_r2013;
+    decode_dim_ordinate, decode_dim_ordinate_r2007, decode_dim_ordinate_r2010,
+        decode_dim_ordinate_r2013;
+    decode_dim_ang3pt, decode_dim_ang3pt_r2007, decode_dim_ang3pt_r2010, decode_dim_ang3pt_r2013;
+    decode_dim_ang2ln, decode_dim_ang2ln_r2007, decode_dim_ang2ln_r2010, decode_dim_ang2ln_r2013;
+    decode_dim_arc, decode_dim_arc_r2007, decode_dim_arc_r2010, decode_dim_arc_r2013;
+    decode_dim_large_radial, decode_dim_large_radial_r2007, decode_dim_large_radial_r2010,
+        decode_dim_large_radial_r2013;
+}
+
+fuzz_target!(|data: &[u8]| {
+    // Header: decoder family, version variant, start bit and handle stream
+    // end bit (as a fraction of the stream); the rest is the object data.
+    let [family, variant, start, end, stream @ ..] = data else {
+        return;
+    };
+    let bits = (stream.len() * 8) as u32;
+    let start_bit = u32::from(*start) % 17;
+    let end_bit = bits * u32::from(*end) / 255;
+    decode(
+        usize::from(*family) % DECODER_FAMILIES,
+        *variant,
+        stream,
+        start_bit,
+        end_bit,
+    );
+});
@@ -4,8 +4,16 @@ include!("bindings/shared.rs");
 include!("bindings/write.rs");
 include!("bindings/decode.rs");
 include!("bindings/layer.rs");
+include!("bindings/table.rs");
+include!("bindings/appearance.rs");
+include!("bindings/plot_layout.rs");
 include!("bindings/dimension.rs");
 include!("bindings/polyline.rs");
 include!("bindings/block_insert.rs");
+include!("bindings/raster.rs");
+include!("bindings/cache.rs");
+include!("bindings/recovery.rs");
+include!("bindings/stats.rs");
+include!("bindings/drawing_index.rs");
 include!("bindings/utils.rs");
 include!("bindings/register.rs");
@@ -0,0 +1,412 @@
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_materials(path: &str, limit: Option<usize>) -> PyResult<Vec<MaterialRow>> {
+    collect_object_rows(path, limit, "MATERIAL", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut material = objects::decode_material(&mut reader, version, handle)?;
+        if material.name.is_none() {
+            let strings = read_object_string_stream(
+                record,
+                header,
+                version,
+                material.string_stream_count(),
+                0,
+            )
+            .unwrap_or_default();
+            material.apply_string_stream(&strings);
+        }
+        let color_row = |color: &objects::MaterialColor| {
+            (color.method, color.factor, color.true_color)
+        };
+        Ok((
+            material.handle,
+            material.name,
+            material.description,
+            color_row(&material.ambient),
+            color_row(&material.diffuse),
+            material.diffuse_map.file_name.filter(|name| !name.is_empty()),
+        ))
+    })
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_visual_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<VisualStyleRow>> {
+    collect_object_rows(path, limit, "VISUALSTYLE", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut style = objects::decode_visual_style(&mut reader, version, handle)?;
+        if style.description.is_none() {
+            style.description = read_object_string_stream(record, header, version, 1, 0)
+                .and_then(|strings| strings.into_iter().next());
+        }
+        Ok((style.handle, style.description, style.style_type))
+    })
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_scales(path: &str, limit: Option<usize>) -> PyResult<Vec<ScaleRow>> {
+    collect_object_rows(path, limit, "SCALE", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut scale = objects::decode_scale(&mut reader, version, handle)?;
+        if scale.name.is_none() {
+            scale.name = read_object_string_stream(record, header, version, 1, 0)
+                .and_then(|strings| strings.into_iter().next());
+        }
+        Ok((
+            scale.handle,
+            scale.name,
+            scale.paper_units,
+            scale.drawing_units,
+            scale.is_unit_scale,
+        ))
+    })
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_light_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<LightEntityRow>> {
+    collect_object_rows(path, limit, "LIGHT", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut light = decode_light_for_version(&mut reader, version, header, handle)?;
+        if light.name.is_none() {
+            light.name = read_object_string_stream(record, header, version, 1, 0)
+                .and_then(|strings| strings.into_iter().next());
+        }
+        Ok((
+            light.handle,
+            light.name,
+            light.light_type,
+            light.is_on,
+            (light.light_color_index, light.light_true_color),
+            light.intensity,
+            light.position,
+            light.target,
+            light.attenuation_type,
+            light.hotspot_angle,
+            light.falloff_angle,
+            light.cast_shadows,
+        ))
+    })
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_rtext_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<RTextEntityRow>> {
+    collect_object_rows(path, limit, "RTEXT", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut rtext = decode_rtext_for_version(&mut reader, version, header, handle)?;
+        if rtext.contents.is_none() {
+            rtext.contents = read_object_string_stream(record, header, version, 1, 0)
+                .and_then(|strings| strings.into_iter().next());
+        }
+        Ok((
+            rtext.handle,
+            rtext.insertion,
+            rtext.extrusion,
+            rtext.rotation,
+            rtext.height,
+            rtext.flags,
+            rtext.contents,
+            rtext.style_handle,
+        ))
+    })
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_arc_aligned_text_entities(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<ArcAlignedTextEntityRow>> {
+    collect_object_rows(path, limit, "ARCALIGNEDTEXT", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut text = decode_arc_aligned_text_for_version(&mut reader, version, header, handle)?;
+        if text.text.is_none() {
+            let strings = read_object_string_stream(
+                record,
+                header,
+                version,
+                entities::ARC_ALIGNED_TEXT_STRING_COUNT,
+                0,
+            )
+            .unwrap_or_default();
+            text.apply_string_stream(&strings);
+        }
+        Ok((
+            text.handle,
+            text.text,
+            (text.font, text.bigfont, text.style_name),
+            text.center,
+            text.radius,
+            (text.start_angle, text.end_angle),
+            (text.height, text.width_factor, text.char_spacing),
+            (text.offset_from_arc, text.right_offset, text.left_offset),
+            (text.reversed, text.direction, text.alignment, text.side),
+            text.is_shx,
+            text.extrusion,
+            text.arc_handle,
+        ))
+    })
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_entity_materials(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<EntityMaterialRow>> {
+    let known_material_handles = known_material_handles(path)?;
+    collect_entity_common_header_rows(path, limit, |handle, reader, common, header| {
+        // Only an explicit material (flags == 3) carries a handle; BYLAYER and
+        // BYBLOCK are resolved by the caller.
+        let material_handle = if common.material_flags == 3 {
+            recover_entity_material_handle(reader, common, header, &known_material_handles)
+        } else {
+            None
+        };
+        Some((handle, common.material_flags, material_handle))
+    })
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_layer_materials(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<LayerMaterialRow>> {
+    let known_material_handles = known_material_handles(path)?;
+    collect_object_rows(path, limit, "LAYER", |record, header, version, handle| {
+        let material_handle = decode_layer_material_handle(
+            record,
+            header,
+            version,
+            handle,
+            &known_material_handles,
+        )?;
+        Ok((handle, material_handle))
+    })
+}
+
+fn known_material_handles(path: &str) -> PyResult<HashSet<u64>> {
+    Ok(decode_materials(path, None)?
+        .into_iter()
+        .map(|(handle, _, _, _, _, _)| handle)
+        .collect())
+}
+
+/// Runs `decode` over every object named `type_name`, skipping records that
+/// fail to decode.
+fn collect_object_rows<T>(
+    path: &str,
+    limit: Option<usize>,
+    type_name: &str,
+    mut decode: impl FnMut(
+        &objects::ObjectRecord<'_>,
+        &ApiObjectHeader,
+        &version::DwgVersion,
+        u64,
+    ) -> crate::core::result::Result<T>,
+) -> PyResult<Vec<T>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+
+    let mut result = Vec::new();
+    for obj in index.objects.iter() {
+        let Some((record, header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        if resolved_type_name(header.type_code, &dynamic_types) != type_name {
+            continue;
+        }
+        let row = match decode(&record, &header, decoder.version(), obj.handle.0) {
+            Ok(row) => row,
+            Err(_) if best_effort => continue,
+            Err(err) if is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        };
+        result.push(row);
+        if let Some(limit) = limit {
+            if result.len() >= limit {
+                break;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Reads the first `count` strings from the R2007+ string stream of an
+/// object, trying the nearby data end bits used elsewhere for R2010+.
+///
+/// The string at `name_index` is a mandatory name, so an empty one, or any
+/// non-printable string, marks a misplaced stream.
+fn read_object_string_stream(
+    record: &objects::ObjectRecord<'_>,
+    header: &ApiObjectHeader,
+    version: &version::DwgVersion,
+    count: usize,
+    name_index: usize,
+) -> Option<Vec<String>> {
+    let mut base_reader = record.bit_reader();
+    skip_object_type_prefix(&mut base_reader, version).ok()?;
+    let end_bits = match version {
+        version::DwgVersion::R2007 => {
+            let mut size_reader = base_reader.clone();
+            vec![size_reader.read_rl(Endian::Little).ok()?]
+        }
+        version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+            // The data end is counted from after the handle stream size UMC,
+            // so try it shifted by the UMC width before the usual candidates.
+            let mut umc_reader = record.bit_reader();
+            let umc_start = umc_reader.tell_bits();
+            umc_reader.read_umc().ok()?;
+            let umc_bits = (umc_reader.tell_bits() - umc_start) as u32;
+            let canonical = resolve_r2010_object_data_end_bit(header).ok();
+            let mut end_bits: Vec<u32> = canonical
+                .map(|end_bit| end_bit + umc_bits)
+                .into_iter()
+                .chain(canonical)
+                .collect();
+            end_bits.extend(resolve_r2010_object_data_end_bit_candidates(header));
+            end_bits
+        }
+        _ => return None,
+    };
+    for end_bit in end_bits {
+        let mut ranges = resolve_r2010_string_stream_ranges_legacy_compat(&base_reader, end_bit);
+        ranges.extend(resolve_r2010_string_stream_ranges(&base_reader, end_bit));
+        for (start_bit, stream_end_bit) in ranges {
+            let mut reader = base_reader.clone();
+            reader.set_bit_pos(start_bit);
+            let Some(strings) = (0..count)
+                .map(|_| reader.read_tu().ok())
+                .collect::<Option<Vec<String>>>()
+            else {
+                continue;
+            };
+            if reader.tell_bits() > u64::from(stream_end_bit) {
+                continue;
+            }
+            let plausible = strings.get(name_index).is_some_and(|name| !name.is_empty())
+                && strings
+                    .iter()
+                    .all(|text| text.chars().all(|ch| !ch.is_control() && ch != '\u{FFFD}'));
+            if plausible {
+                return Some(strings);
+            }
+        }
+    }
+    None
+}
+
+fn recover_entity_material_handle(
+    reader: &mut BitReader<'_>,
+    common: &entities::common::CommonEntityHeader,
+    header: &ApiObjectHeader,
+    known_material_handles: &HashSet<u64>,
+) -> Option<u64> {
+    let mut end_bits = vec![common.obj_size];
+    end_bits.extend(resolve_r2010_object_data_end_bit_candidates(header));
+    end_bits.dedup();
+    for end_bit in end_bits {
+        reader.set_bit_pos(end_bit);
+        let Ok(handles) = entities::common::parse_common_entity_handles(reader, common) else {
+            continue;
+        };
+        if let Some(material) = handles
+            .material
+            .filter(|material| known_material_handles.contains(material))
+        {
+            return Some(material);
+        }
+    }
+    None
+}
+
+/// LAYER handle stream: control, reactors, xdic, xref block, plotstyle, then
+/// (R2007+) the material.
+fn decode_layer_material_handle(
+    record: &objects::ObjectRecord<'_>,
+    header: &ApiObjectHeader,
+    version: &version::DwgVersion,
+    object_handle: u64,
+    known_material_handles: &HashSet<u64>,
+) -> crate::core::result::Result<Option<u64>> {
+    let r2010_plus = matches!(
+        version,
+        version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018
+    );
+    if !r2010_plus && !matches!(version, version::DwgVersion::R2007) {
+        return Ok(None);
+    }
+    let mut reader = record.bit_reader();
+    skip_object_type_prefix(&mut reader, version)?;
+    let prologue = objects::parse_common_object_prologue(&mut reader, version)?;
+    let base_handle = prologue.handle_or(object_handle);
+    let num_reactors = prologue.num_reactors;
+    let xdic_missing = prologue.xdic_missing;
+
+    let end_bits = match prologue.obj_size {
+        Some(obj_size) => vec![obj_size],
+        None => {
+            let mut end_bits: Vec<u32> = resolve_r2010_object_data_end_bit(header)
+                .ok()
+                .into_iter()
+                .collect();
+            end_bits.extend(resolve_r2010_object_data_end_bit_candidates(header));
+            end_bits
+        }
+    };
+    for end_bit in end_bits {
+        reader.set_bit_pos(end_bit);
+        let handle_refs = (|| -> crate::core::result::Result<u64> {
+            let _control = reader.read_h()?;
+            for _ in 0..num_reactors {
+                let _reactor = reader.read_h()?;
+            }
+            if !xdic_missing {
+                let _xdic = reader.read_h()?;
+            }
+            let _xref_block = reader.read_h()?;
+            let _plotstyle = reader.read_h()?;
+            entities::common::read_handle_reference(&mut reader, base_handle)
+        })();
+        if let Ok(material) = handle_refs {
+            if known_material_handles.contains(&material) {
+                return Ok(Some(material));
+            }
+        }
+    }
+    Ok(None)
+}
+
+impl_version_dispatch! {
+    no_r14;
+    fn decode_light_for_version -> entities::LightEntity;
+    r2010: entities::decode_light_r2010;
+    r2013: entities::decode_light_r2013;
+    r2007: entities::decode_light_r2007;
+    default: entities::decode_light;
+}
+
+impl_version_dispatch! {
+    with_r14;
+    fn decode_rtext_for_version -> entities::RTextEntity;
+    r14: entities::decode_rtext_r14;
+    r2010: entities::decode_rtext_r2010;
+    r2013: entities::decode_rtext_r2013;
+    r2007: entities::decode_rtext_r2007;
+    default: entities::decode_rtext;
+}
+
+impl_version_dispatch! {
+    with_r14;
+    fn decode_arc_aligned_text_for_version -> entities::ArcAlignedTextEntity;
+    r14: entities::decode_arc_aligned_text_r14;
+    r2010: entities::decode_arc_aligned_text_r2010;
+    r2013: entities::decode_arc_aligned_text_r2013;
+    r2007: entities::decode_arc_aligned_text_r2007;
+    default: entities::decode_arc_aligned_text;
+}
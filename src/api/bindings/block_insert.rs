@@ -166,7 +166,7 @@ fn decode_insert_entities_with_state(
             obj.handle.0,
         ) {
             Ok(entity) => entity,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let resolved_block_handle = recover_insert_block_header_handle_r2010_plus(
@@ -322,7 +322,17 @@ fn decode_insert_entities_with_state(
                 handle, block_handle, resolved_name, candidate_debug
             );
         }
-        result.push((handle, px, py, pz, sx, sy, sz, rotation, resolved_name));
+        result.push((
+            handle,
+            px,
+            py,
+            pz,
+            sx,
+            sy,
+            sz,
+            rotation,
+            resolved_name.map(InternedName::from),
+        ));
     }
     Ok(result)
 }
@@ -371,7 +381,7 @@ fn decode_minsert_entities_with_state(
             debug_minsert,
         ) {
             Ok(entity) => entity,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         if let Some(reason) = _minsert_reasonableness_failure(&entity) {
@@ -577,7 +587,7 @@ fn decode_minsert_entities_with_state(
                 num_rows,
                 column_spacing,
                 row_spacing,
-                resolved_name,
+                resolved_name.map(InternedName::from),
             ),
         ));
     }
@@ -635,7 +645,7 @@ fn decode_insert_owner_handles_impl(
             obj.handle.0,
         ) {
             Ok(entity) => entity,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let resolved_block_handle = recover_insert_block_header_handle_r2010_plus(
@@ -792,7 +802,11 @@ pub fn decode_block_header_names(
     let index = decoder.build_object_index().map_err(to_py_err)?;
     let state =
         prepare_insert_name_resolution_state(&decoder, &dynamic_types, &index, best_effort)?;
-    let mut rows: Vec<BlockHeaderNameRow> = state.block_header_names.into_iter().collect();
+    let mut rows: Vec<BlockHeaderNameRow> = state
+        .block_header_names
+        .into_iter()
+        .map(|(handle, name)| (handle, name.into()))
+        .collect();
     rows.sort_by_key(|(handle, _)| *handle);
     if let Some(limit) = limit {
         rows.truncate(limit);
@@ -972,13 +986,13 @@ pub fn decode_block_entity_names(
         }
     }
 
-    let mut rows: Vec<BlockEntityNameRow> = Vec::new();
-    rows.reserve(block_aliases.len().saturating_add(endblk_aliases.len()));
+    let mut rows: Vec<BlockEntityNameRow> =
+        Vec::with_capacity(block_aliases.len().saturating_add(endblk_aliases.len()));
     for (handle, name) in block_aliases {
-        rows.push((handle, "BLOCK".to_string(), name));
+        rows.push((handle, "BLOCK".into(), name.into()));
     }
     for (handle, name) in endblk_aliases {
-        rows.push((handle, "ENDBLK".to_string(), name));
+        rows.push((handle, "ENDBLK".into(), name.into()));
     }
     rows.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
     if let Some(limit) = limit {
@@ -1005,6 +1019,188 @@ pub fn decode_block_entity_name_maps(
     Ok((block_rows, endblk_rows))
 }
 
+/// Entity handles owned by a block record, in stored (draw) order.
+///
+/// R2004+ block records list their entities directly; R13-R2000 records only
+/// hold the first and last entity, so the chain is followed through each
+/// entity's previous/next links (or consecutive handles when an entity omits
+/// them).
+#[pyfunction(signature = (path, block_record_handle))]
+pub fn iter_block_children(path: &str, block_record_handle: u64) -> PyResult<Vec<u64>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+    let block = load_block_record(&decoder, &index, block_record_handle)?;
+    match block.first_entity {
+        Some(first) => {
+            collect_legacy_entity_chain(&decoder, &index, first, block.last_entity)
+                .map_err(to_py_err)
+        }
+        None => Ok(block.entities),
+    }
+}
+
+/// Depth of every block in the graph of `(block, referenced block)` pairs,
+/// or the kind (`"cycle"` or `"depth"`) and blocks of the first problem
+/// found with an empty depth list. Blocks are any integer ids.
+#[pyfunction(signature = (references, max_depth))]
+pub fn check_block_references(
+    references: Vec<(u64, u64)>,
+    max_depth: usize,
+) -> BlockNestingRow {
+    let mut graph = BlockReferenceGraph::new();
+    for (block, referenced) in references {
+        graph.add_reference(block, referenced);
+    }
+    match graph.check(max_depth) {
+        Ok(depths) => (depths.into_iter().collect(), None, Vec::new()),
+        Err(BlockNestingIssue::Cycle(cycle)) => (Vec::new(), Some("cycle"), cycle),
+        Err(BlockNestingIssue::TooDeep(chain)) => (Vec::new(), Some("depth"), chain),
+    }
+}
+
+/// Base point of a block record, in block coordinates. INSERT placement maps
+/// this point onto the insertion point.
+#[pyfunction(signature = (path, block_record_handle))]
+pub fn decode_block_base_point(path: &str, block_record_handle: u64) -> PyResult<Point3> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+    Ok(load_block_record(&decoder, &index, block_record_handle)?.base_point)
+}
+
+/// Xref and overlay block records with the path of the drawing each one
+/// references, as stored in the file.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_xref_blocks(path: &str, limit: Option<usize>) -> PyResult<Vec<XrefBlockRow>> {
+    let blocks = collect_object_rows(path, None, "BLOCK_HEADER", decode_block_record_strings)?;
+    let mut rows: Vec<XrefBlockRow> = blocks
+        .into_iter()
+        .filter(|block| block.is_xref || block.is_overlaid)
+        .map(|block| {
+            (
+                block.handle,
+                block.name.unwrap_or_default(),
+                block.xref_path.unwrap_or_default(),
+                block.is_overlaid,
+            )
+        })
+        .collect();
+    if let Some(limit) = limit {
+        rows.truncate(limit);
+    }
+    Ok(rows)
+}
+
+fn decode_block_record_strings(
+    record: &objects::ObjectRecord<'_>,
+    header: &ApiObjectHeader,
+    version: &version::DwgVersion,
+    handle: u64,
+) -> crate::core::result::Result<objects::BlockRecordObject> {
+    let (mut reader, object_data_end_bit) = object_reader_with_data_end_bit(record, version)?;
+    let mut block =
+        objects::decode_block_record(&mut reader, version, object_data_end_bit, handle)?;
+    if block.name.is_none() {
+        let strings = read_object_string_stream(
+            record,
+            header,
+            version,
+            objects::BlockRecordObject::STRING_STREAM_COUNT,
+            0,
+        )
+        .unwrap_or_default();
+        block.apply_string_stream(&strings);
+    }
+    Ok(block)
+}
+
+fn load_block_record(
+    decoder: &decoder::Decoder<'_>,
+    index: &objects::ObjectIndex,
+    block_record_handle: u64,
+) -> PyResult<objects::BlockRecordObject> {
+    let best_effort = is_best_effort_compat_version(decoder);
+    let dynamic_types = load_dynamic_types(decoder, best_effort)?;
+
+    // Some files map a handle more than once, so look for the entry that is
+    // actually a block record.
+    let mut found = None;
+    for obj in index
+        .objects
+        .iter()
+        .filter(|obj| obj.handle.0 == block_record_handle)
+    {
+        let Some((record, header)) = parse_record_and_header(decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        if matches_type_name(header.type_code, 0x31, "BLOCK_HEADER", &dynamic_types) {
+            found = Some(record);
+            break;
+        }
+    }
+    let record = found.ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "block record not found: {block_record_handle:#X}"
+        ))
+    })?;
+    let (mut reader, object_data_end_bit) =
+        object_reader_with_data_end_bit(&record, decoder.version()).map_err(to_py_err)?;
+    objects::decode_block_record(
+        &mut reader,
+        decoder.version(),
+        object_data_end_bit,
+        block_record_handle,
+    )
+    .map_err(to_py_err)
+}
+
+fn collect_legacy_entity_chain(
+    decoder: &decoder::Decoder<'_>,
+    index: &objects::ObjectIndex,
+    first: u64,
+    last: Option<u64>,
+) -> crate::core::result::Result<Vec<u64>> {
+    let version = decoder.version();
+    let mut handles = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = first;
+    while seen.insert(current) {
+        let Some(obj) = index.get(objects::Handle(current)) else {
+            break;
+        };
+        handles.push(current);
+        if Some(current) == last {
+            break;
+        }
+        let record = decoder.parse_object_record(obj.offset)?;
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut header = match version {
+            version::DwgVersion::R14 => {
+                entities::common::parse_common_entity_header_r14(&mut reader)?
+            }
+            _ => entities::common::parse_common_entity_header_r2000(&mut reader)?,
+        };
+        if !header.has_legacy_entity_links {
+            // Entities without stored links continue with the next handle.
+            current += 1;
+            continue;
+        }
+        if header.handle == 0 {
+            header.handle = current;
+        }
+        reader.set_bit_pos(header.obj_size);
+        let links = entities::common::parse_common_entity_handles(&mut reader, &header)?;
+        match links.next_entity {
+            Some(next) if next != 0 => current = next,
+            _ => break,
+        }
+    }
+    Ok(handles)
+}
+
 impl_version_dispatch! {
     no_r14;
     fn decode_insert_for_version -> entities::InsertEntity;
@@ -1020,66 +1216,70 @@ fn decode_minsert_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::MInsertEntity> {
-    match version {
-        version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            let mut candidate_bits: Vec<u32> = Vec::new();
-            if let Ok(primary) = resolve_r2010_object_data_end_bit(header) {
-                candidate_bits.push(primary);
-            }
-            for candidate in resolve_r2010_object_data_end_bit_candidates(header) {
-                if !candidate_bits.contains(&candidate) {
-                    candidate_bits.push(candidate);
+    time_type_decode(
+        "decode_minsert_for_version",
+        reader,
+        |reader| match version {
+            version::DwgVersion::R2010
+            | version::DwgVersion::R2013
+            | version::DwgVersion::R2018 => {
+                let mut candidate_bits: Vec<u32> = Vec::new();
+                if let Ok(primary) = resolve_r2010_object_data_end_bit(header) {
+                    candidate_bits.push(primary);
                 }
-            }
-            if candidate_bits.is_empty() {
-                return Err(DwgError::new(
-                    ErrorKind::Format,
-                    "no R2010 object data end-bit candidates",
-                ));
-            }
-
-            let mut first_err: Option<DwgError> = None;
-            for object_data_end_bit in candidate_bits {
-                let mut attempt_reader = reader.clone();
-                let result = match version {
-                    version::DwgVersion::R2010 => {
-                        entities::decode_minsert_r2010(
-                            &mut attempt_reader,
-                            object_data_end_bit,
-                            object_handle,
-                        )
+                for candidate in resolve_r2010_object_data_end_bit_candidates(header) {
+                    if !candidate_bits.contains(&candidate) {
+                        candidate_bits.push(candidate);
                     }
-                    version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-                        entities::decode_minsert_r2013(
+                }
+                if candidate_bits.is_empty() {
+                    return Err(DwgError::new(
+                        ErrorKind::Format,
+                        "no R2010 object data end-bit candidates",
+                    ));
+                }
+
+                let mut first_err: Option<DwgError> = None;
+                for object_data_end_bit in candidate_bits {
+                    let mut attempt_reader = reader.clone();
+                    let result = match version {
+                        version::DwgVersion::R2010 => entities::decode_minsert_r2010(
                             &mut attempt_reader,
                             object_data_end_bit,
                             object_handle,
-                        )
-                    }
-                    _ => unreachable!(),
-                };
-                match result {
-                    Ok(entity) => {
-                        *reader = attempt_reader;
-                        return Ok(entity);
-                    }
-                    Err(err) => {
-                        if first_err.is_none() {
-                            first_err = Some(err);
+                        ),
+                        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+                            entities::decode_minsert_r2013(
+                                &mut attempt_reader,
+                                object_data_end_bit,
+                                object_handle,
+                            )
+                        }
+                        _ => unreachable!(),
+                    };
+                    match result {
+                        Ok(entity) => {
+                            *reader = attempt_reader;
+                            return Ok(entity);
+                        }
+                        Err(err) => {
+                            if first_err.is_none() {
+                                first_err = Some(err);
+                            }
                         }
                     }
                 }
+                Err(first_err.unwrap_or_else(|| {
+                    DwgError::new(
+                        ErrorKind::Format,
+                        "failed to decode MINSERT for all end-bit candidates",
+                    )
+                }))
             }
-            Err(first_err.unwrap_or_else(|| {
-                DwgError::new(
-                    ErrorKind::Format,
-                    "failed to decode MINSERT for all end-bit candidates",
-                )
-            }))
-        }
-        version::DwgVersion::R2007 => entities::decode_minsert_r2007(reader),
-        _ => entities::decode_minsert(reader),
-    }
+            version::DwgVersion::R2007 => entities::decode_minsert_r2007(reader),
+            _ => entities::decode_minsert(reader),
+        },
+    )
 }
 
 fn _insert_as_single_minsert(insert: entities::InsertEntity) -> entities::MInsertEntity {
@@ -2215,7 +2415,7 @@ fn collect_block_header_targeted_aliases_in_order(
                     let mut prev_handle = base_handle;
                     for index in 0..256u64 {
                         let candidate = if chained_base {
-                            match read_handle_reference_chained(&mut reader, &mut prev_handle) {
+                            match entities::common::read_chained_handle_reference(&mut reader, &mut prev_handle) {
                                 Ok(value) => value,
                                 Err(_) => break,
                             }
@@ -2347,7 +2547,7 @@ fn collect_insert_block_handle_candidates_r2010_plus(
             }
         }
     }
-    if let Some(expected) = resolve_r2010_object_data_end_bit(api_header).ok() {
+    if let Ok(expected) = resolve_r2010_object_data_end_bit(api_header) {
         for delta in -48i32..=48i32 {
             let candidate_i64 = i64::from(expected) + i64::from(delta);
             if candidate_i64 < 0 {
@@ -2413,7 +2613,7 @@ fn collect_insert_block_handle_candidates_r2010_plus(
                 let mut prev_handle = base_handle;
                 for index in 0..64u64 {
                     let candidate = if chained_base {
-                        match read_handle_reference_chained(&mut reader, &mut prev_handle) {
+                        match entities::common::read_chained_handle_reference(&mut reader, &mut prev_handle) {
                             Ok(value) => value,
                             Err(_) => break,
                         }
@@ -2477,6 +2677,40 @@ fn recover_insert_block_header_handle_r2010_plus(
     parsed_block_handle: Option<u64>,
     known_block_handles: &HashSet<u64>,
     named_block_handles: &HashSet<u64>,
+) -> Option<u64> {
+    let block_handle = time_heuristic("insert_block_handle_scan", record.raw.len(), || {
+        scan_insert_block_header_handle_r2010_plus(
+            record,
+            version,
+            api_header,
+            object_handle,
+            parsed_block_handle,
+            known_block_handles,
+            named_block_handles,
+        )
+    });
+    if let Some(handle) = block_handle.filter(|handle| Some(*handle) != parsed_block_handle) {
+        note_recovered_field(
+            object_handle,
+            "block_handle",
+            RecoveredValue::Handle(handle),
+            parsed_block_handle
+                .filter(|parsed| *parsed != 0)
+                .map(RecoveredValue::Handle),
+            "handle_stream_scan",
+        );
+    }
+    block_handle
+}
+
+fn scan_insert_block_header_handle_r2010_plus(
+    record: &objects::ObjectRecord<'_>,
+    version: &version::DwgVersion,
+    api_header: &ApiObjectHeader,
+    object_handle: u64,
+    parsed_block_handle: Option<u64>,
+    known_block_handles: &HashSet<u64>,
+    named_block_handles: &HashSet<u64>,
 ) -> Option<u64> {
     if !matches!(
         version,
@@ -2613,7 +2847,7 @@ fn recover_insert_block_header_handle_r2010_plus(
                 let mut prev_handle = base_handle;
                 for index in 0..64u64 {
                     let candidate = if chained_base {
-                        match read_handle_reference_chained(&mut reader, &mut prev_handle) {
+                        match entities::common::read_chained_handle_reference(&mut reader, &mut prev_handle) {
                             Ok(value) => value,
                             Err(_) => break,
                         }
@@ -2825,7 +3059,7 @@ fn decode_block_header_name_record(
 ) -> crate::core::result::Result<(u64, String)> {
     let obj_size_bits = reader.read_rl(Endian::Little)?;
     let record_handle = reader.read_h()?.value;
-    skip_eed(reader)?;
+    objects::read_eed(reader)?;
     let _num_reactors = reader.read_bl()?;
     let _xdic_missing_flag = reader.read_b()?;
     if matches!(
@@ -0,0 +1,58 @@
+#[cfg(feature = "cache")]
+type CacheKeyRow = (u64, u128, String);
+
+#[cfg(feature = "cache")]
+fn cache_key_from_row(key: CacheKeyRow) -> PyResult<crate::cache::FileKey> {
+    let (size, modified_ns, hash) = key;
+    let hash = u128::from_str_radix(&hash, 16)
+        .map_err(|_| PyValueError::new_err(format!("invalid cache key hash: {hash:?}")))?;
+    Ok(crate::cache::FileKey {
+        size,
+        modified_ns,
+        hash,
+    })
+}
+
+/// Key of `path` in the result cache under `cache_dir`: its size,
+/// modification time and content hash. The contents are only rehashed when
+/// the size or modification time changed since the last call.
+#[cfg(feature = "cache")]
+#[pyfunction]
+pub fn cache_file_key(cache_dir: &str, path: &str) -> PyResult<CacheKeyRow> {
+    let key = crate::cache::ResultCache::new(cache_dir)
+        .key(path)
+        .map_err(to_py_err)?;
+    Ok((key.size, key.modified_ns, key.hash_hex()))
+}
+
+#[cfg(feature = "cache")]
+#[pyfunction]
+pub fn cache_load(
+    py: Python<'_>,
+    cache_dir: &str,
+    key: CacheKeyRow,
+    table: &str,
+) -> PyResult<Option<Py<PyBytes>>> {
+    let key = cache_key_from_row(key)?;
+    let payload = crate::cache::ResultCache::new(cache_dir)
+        .load(&key, table)
+        .map_err(to_py_err)?;
+    Ok(payload.map(|payload| PyBytes::new_bound(py, &payload).unbind()))
+}
+
+#[cfg(feature = "cache")]
+#[pyfunction]
+pub fn cache_store(cache_dir: &str, key: CacheKeyRow, table: &str, payload: &[u8]) -> PyResult<()> {
+    let key = cache_key_from_row(key)?;
+    crate::cache::ResultCache::new(cache_dir)
+        .store(&key, table, payload)
+        .map_err(to_py_err)
+}
+
+#[cfg(feature = "cache")]
+#[pyfunction]
+pub fn cache_clear(cache_dir: &str) -> PyResult<usize> {
+    crate::cache::ResultCache::new(cache_dir)
+        .clear()
+        .map_err(to_py_err)
+}
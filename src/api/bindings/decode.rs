@@ -21,7 +21,7 @@ macro_rules! impl_version_dispatch {
             header: &ApiObjectHeader,
             object_handle: u64,
         ) -> crate::core::result::Result<$entity_ty> {
-            match version {
+            time_type_decode(stringify!($fn_name), reader, |reader| match version {
                 version::DwgVersion::R14 => $r14_fn(reader, object_handle),
                 version::DwgVersion::R2010 => {
                     let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
@@ -33,7 +33,7 @@ macro_rules! impl_version_dispatch {
                 }
                 version::DwgVersion::R2007 => $r2007_fn(reader),
                 _ => $default_fn(reader),
-            }
+            })
         }
     };
     (
@@ -50,7 +50,7 @@ macro_rules! impl_version_dispatch {
             header: &ApiObjectHeader,
             object_handle: u64,
         ) -> crate::core::result::Result<$entity_ty> {
-            match version {
+            time_type_decode(stringify!($fn_name), reader, |reader| match version {
                 version::DwgVersion::R2010 => {
                     let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
                     $r2010_fn(reader, object_data_end_bit, object_handle)
@@ -61,7 +61,7 @@ macro_rules! impl_version_dispatch {
                 }
                 version::DwgVersion::R2007 => $r2007_fn(reader),
                 _ => $default_fn(reader),
-            }
+            })
         }
     };
 }
@@ -148,35 +148,194 @@ pub fn list_section_locators(path: &str) -> PyResult<Vec<SectionLocatorRow>> {
     Ok(result)
 }
 
+/// `(version, flavor, is_standard, warnings, sections)` of the section
+/// layout, with the sections in normalized order.
+#[pyfunction]
+pub fn describe_file_layout(path: &str) -> PyResult<FileLayoutRow> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let directory = decoder.section_directory().map_err(to_py_err)?;
+    let is_standard = directory.is_standard();
+    let sections = directory
+        .records
+        .into_iter()
+        .map(|record| {
+            let label = record.name.clone().unwrap_or_else(|| record.kind().label());
+            (label, record.offset, record.size)
+        })
+        .collect();
+    Ok((
+        decoder.version().as_str().to_string(),
+        decoder.layout_flavor().to_string(),
+        is_standard,
+        directory.warnings,
+        sections,
+    ))
+}
+
+/// `TDCREATE`, `TDUPDATE`, `TDINDWG` and `TDUSRTIMER` as `(day, millis)`
+/// pairs.
+#[pyfunction]
+pub fn decode_drawing_times(path: &str) -> PyResult<DrawingTimesRow> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let times = decoder.drawing_times().map_err(to_py_err)?;
+    Ok((
+        (times.created.day, times.created.millis),
+        (times.updated.day, times.updated.millis),
+        (times.edit_time.day, times.edit_time.millis),
+        (times.user_timer.day, times.user_timer.millis),
+    ))
+}
+
+/// `LTSCALE` and `CELTSCALE` from the header variables.
+#[pyfunction]
+pub fn decode_linetype_scales(path: &str) -> PyResult<(f64, f64)> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let scales = decoder.linetype_scales().map_err(to_py_err)?;
+    Ok((scales.ltscale, scales.celtscale))
+}
+
 #[pyfunction]
-pub fn read_section_bytes(path: &str, index: usize) -> PyResult<Vec<u8>> {
+pub fn read_section_bytes(py: Python<'_>, path: &str, index: usize) -> PyResult<Py<PyBytes>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
     let decoder = build_decoder(&bytes).map_err(to_py_err)?;
     let directory = decoder.section_directory().map_err(to_py_err)?;
     let section = decoder
         .load_section_by_index(&directory, index)
         .map_err(to_py_err)?;
-    Ok(section.data.as_ref().to_vec())
+    // R14/R2000 sections borrow the file bytes; copy them once, straight
+    // into the Python object.
+    Ok(PyBytes::new_bound(py, section.data.as_ref()).unbind())
 }
 
-#[pyfunction(signature = (path, limit=None))]
-pub fn list_object_map_entries(
+#[pyfunction]
+pub fn read_decompressed_section(
+    py: Python<'_>,
     path: &str,
-    limit: Option<usize>,
-) -> PyResult<Vec<ObjectMapEntryRow>> {
+    name: &str,
+) -> PyResult<Py<PyBytes>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
     let decoder = build_decoder(&bytes).map_err(to_py_err)?;
-    let index = decoder.build_object_index().map_err(to_py_err)?;
-    let mut entries: Vec<ObjectMapEntryRow> = index
-        .objects
+    let data = decoder.load_section_by_name(name).map_err(to_py_err)?;
+    Ok(PyBytes::new_bound(py, &data).unbind())
+}
+
+#[pyfunction]
+pub fn decode_acds_records(py: Python<'_>, path: &str) -> PyResult<Vec<AcDsRecordRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let records = decoder.acds_records().map_err(to_py_err)?;
+    Ok(records
         .iter()
-        .map(|obj| (obj.handle.0, obj.offset))
-        .collect();
-    if let Some(limit) = limit {
-        if entries.len() > limit {
-            entries.truncate(limit);
+        .map(|record| {
+            (
+                record.handle,
+                record.schema_index,
+                record.acis_format().map(str::to_string),
+                PyBytes::new_bound(py, &record.data).unbind(),
+            )
+        })
+        .collect())
+}
+
+/// Runs a Python tessellator over the ACIS solids of a drawing.
+///
+/// `tessellator(handle, format, data)` returns `None` to skip a solid or a
+/// `(vertices, triangles)` pair.
+#[pyfunction]
+pub fn tessellate_solids(
+    py: Python<'_>,
+    path: &str,
+    tessellator: TessellatorCallback,
+) -> PyResult<Vec<SolidMeshRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let error: std::cell::RefCell<Option<PyErr>> = std::cell::RefCell::new(None);
+    let callback = |solid: &entities::AcisSolid<'_>| {
+        let result = tessellator
+            .call1(
+                py,
+                (
+                    solid.handle,
+                    solid.format.as_str(),
+                    PyBytes::new_bound(py, solid.data),
+                ),
+            )
+            .and_then(|mesh| {
+                mesh.extract::<Option<(Vec<Point3>, Vec<(u32, u32, u32)>)>>(py)
+            });
+        match result {
+            Ok(mesh) => Ok(mesh.map(|(vertices, triangles)| entities::TriangleMesh {
+                vertices,
+                triangles: triangles.into_iter().map(|(a, b, c)| [a, b, c]).collect(),
+            })),
+            Err(err) => {
+                *error.borrow_mut() = Some(err);
+                Err(DwgError::new(ErrorKind::Decode, "solid tessellator failed"))
+            }
         }
-    }
+    };
+    let meshes = match decoder.tessellate_solids(&callback) {
+        Ok(meshes) => meshes,
+        Err(err) => return Err(error.take().unwrap_or_else(|| to_py_err(err))),
+    };
+    Ok(meshes
+        .into_iter()
+        .map(|(handle, mesh)| {
+            let triangles = mesh
+                .triangles
+                .into_iter()
+                .map(|[a, b, c]| (a, b, c))
+                .collect();
+            (handle, mesh.vertices, triangles)
+        })
+        .collect())
+}
+
+#[pyfunction(signature = (
+    path,
+    limit=None,
+    start_handle=None,
+    end_handle=None,
+    by_offset=false,
+    progress=None
+))]
+pub fn list_object_map_entries(
+    path: &str,
+    limit: Option<usize>,
+    start_handle: Option<u64>,
+    end_handle: Option<u64>,
+    by_offset: bool,
+    progress: Option<ProgressCallback>,
+) -> PyResult<Vec<ObjectMapEntryRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let progress = PyProgress::new(progress);
+    let index = build_decoder_with_progress(&bytes, &progress)
+        .and_then(|decoder| decoder.build_object_index())
+        .map_err(to_py_err);
+    let index = progress.finish(index)?;
+    let limit = limit.unwrap_or(usize::MAX);
+    let start = start_handle.unwrap_or(0);
+    let end = end_handle.unwrap_or(u64::MAX);
+    let row = |obj: &objects::ObjectRef| (obj.handle.0, obj.offset);
+    let entries: Vec<ObjectMapEntryRow> = if by_offset {
+        index
+            .iter_by_offset()
+            .filter(|obj| (start..=end).contains(&obj.handle.0))
+            .take(limit)
+            .map(row)
+            .collect()
+    } else if start_handle.is_some() || end_handle.is_some() {
+        index
+            .handles_in_range(objects::Handle(start), objects::Handle(end))
+            .take(limit)
+            .map(row)
+            .collect()
+    } else {
+        index.objects.iter().take(limit).map(row).collect()
+    };
     Ok(entries)
 }
 
@@ -190,12 +349,12 @@ pub fn list_object_headers(path: &str, limit: Option<usize>) -> PyResult<Vec<Obj
     for obj in index.objects.iter() {
         let record = match decoder.parse_object_record(obj.offset) {
             Ok(record) => record,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let header = match parse_object_header_for_version(&record, decoder.version()) {
             Ok(header) => header,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         result.push((obj.handle.0, obj.offset, header.data_size, header.type_code));
@@ -208,27 +367,38 @@ pub fn list_object_headers(path: &str, limit: Option<usize>) -> PyResult<Vec<Obj
     Ok(result)
 }
 
-#[pyfunction(signature = (path, limit=None))]
+#[pyfunction(signature = (path, limit=None, progress=None))]
 pub fn list_object_headers_with_type(
     path: &str,
     limit: Option<usize>,
+    progress: Option<ProgressCallback>,
+) -> PyResult<Vec<ObjectHeaderWithTypeRow>> {
+    let progress = PyProgress::new(progress);
+    let result = list_object_headers_with_type_impl(path, limit, &progress);
+    progress.finish(result)
+}
+
+fn list_object_headers_with_type_impl(
+    path: &str,
+    limit: Option<usize>,
+    progress: &PyProgress,
 ) -> PyResult<Vec<ObjectHeaderWithTypeRow>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
-    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let decoder = build_decoder_with_progress(&bytes, progress).map_err(to_py_err)?;
     let best_effort = is_best_effort_compat_version(&decoder);
     let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
     let dynamic_type_classes = load_dynamic_type_classes(&decoder, best_effort)?;
     let index = decoder.build_object_index().map_err(to_py_err)?;
     let mut result = Vec::new();
-    for obj in index.objects.iter() {
-        let record = match decoder.parse_object_record(obj.offset) {
+    for (obj, record) in decoder.parse_object_records(&index) {
+        let record = match record {
             Ok(record) => record,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let header = match parse_object_header_for_version(&record, decoder.version()) {
             Ok(header) => header,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let type_name = resolved_type_name(header.type_code, &dynamic_types);
@@ -239,8 +409,8 @@ pub fn list_object_headers_with_type(
             obj.offset,
             header.data_size,
             header.type_code,
-            type_name,
-            type_class,
+            type_name.into(),
+            type_class.into(),
         ));
         if let Some(limit) = limit {
             if result.len() >= limit {
@@ -271,12 +441,12 @@ pub fn list_object_headers_by_type(
     for obj in index.objects.iter() {
         let record = match decoder.parse_object_record(obj.offset) {
             Ok(record) => record,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let header = match parse_object_header_for_version(&record, decoder.version()) {
             Ok(header) => header,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let type_name = resolved_type_name(header.type_code, &dynamic_types);
@@ -290,8 +460,8 @@ pub fn list_object_headers_by_type(
             obj.offset,
             header.data_size,
             header.type_code,
-            type_name,
-            type_class,
+            type_name.into(),
+            type_class.into(),
         ));
         if let Some(limit) = limit {
             if result.len() >= limit {
@@ -356,7 +526,7 @@ pub fn read_object_records_by_handle(
     let decoder = build_decoder(&bytes).map_err(to_py_err)?;
     let index = decoder.build_object_index().map_err(to_py_err)?;
     let target_handles: HashSet<u64> = handles.iter().copied().collect();
-    let mut object_offsets: HashMap<u64, u32> = HashMap::new();
+    let mut object_offsets: HashMap<u64, u64> = HashMap::new();
     for obj in index.objects.iter() {
         object_offsets
             .entry(obj.handle.0)
@@ -408,7 +578,7 @@ pub fn read_object_records_by_handle(
 #[pyfunction(signature = (path, offsets, limit=None))]
 pub fn read_object_records_by_offset(
     path: &str,
-    offsets: Vec<u32>,
+    offsets: Vec<u64>,
     limit: Option<usize>,
 ) -> PyResult<Vec<ObjectRecordBytesRow>> {
     if offsets.is_empty() {
@@ -417,7 +587,7 @@ pub fn read_object_records_by_offset(
 
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
     let decoder = build_decoder(&bytes).map_err(to_py_err)?;
-    let mut found_rows: HashMap<u32, ObjectRecordBytesRow> = HashMap::new();
+    let mut found_rows: HashMap<u64, ObjectRecordBytesRow> = HashMap::new();
 
     for offset in offsets.iter().copied() {
         let record = decoder.parse_object_record(offset).map_err(to_py_err)?;
@@ -449,6 +619,140 @@ pub fn read_object_records_by_offset(
     Ok(result)
 }
 
+/// Decodes a single object with bit tracing enabled. Every top-level read
+/// made while skipping the type prefix and running the entity decoder is
+/// returned with its bit range relative to the object data, so the raw bytes
+/// can be annotated field by field. Decoders that retry alternative layouts
+/// leave the reads of every attempt in order.
+#[pyfunction(signature = (path, handle))]
+pub fn explain_object(py: Python<'_>, path: &str, handle: u64) -> PyResult<ExplainObjectRow> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+    let offset = index
+        .objects
+        .iter()
+        .filter(|obj| obj.handle.0 == handle)
+        .map(|obj| obj.offset)
+        .max()
+        .ok_or_else(|| PyValueError::new_err(format!("object not found: {handle:#X}")))?;
+    let dynamic_types = load_dynamic_types(&decoder, true)?;
+    let (record, header) = parse_record_and_header(&decoder, offset, false)?
+        .ok_or_else(|| PyValueError::new_err(format!("object not found: {handle:#X}")))?;
+    let type_name = resolved_type_name(header.type_code, &dynamic_types);
+
+    let mut reader = record.bit_reader();
+    reader.enable_trace();
+    let mut trace = Vec::new();
+    let prefix = skip_object_type_prefix(&mut reader, decoder.version());
+    push_trace_rows(&mut trace, "prefix", reader.take_trace());
+    let (decoded, error) = match prefix {
+        Err(err) => (None, Some(err.to_string())),
+        Ok(_) => {
+            let outcome =
+                trace_entity_decode(&mut reader, &type_name, decoder.version(), &header, handle);
+            push_trace_rows(&mut trace, &type_name, reader.take_trace());
+            match outcome {
+                Some(Ok(decoded)) => (Some(decoded), None),
+                Some(Err(err)) => (None, Some(err.to_string())),
+                None => (None, Some(format!("no entity decoder for {type_name}"))),
+            }
+        }
+    };
+
+    Ok((
+        handle,
+        offset,
+        header.type_code,
+        type_name,
+        PyBytes::new_bound(py, record.body.as_ref()).unbind(),
+        trace,
+        decoded,
+        error,
+    ))
+}
+
+fn push_trace_rows(rows: &mut Vec<ObjectTraceRow>, stage: &str, entries: Vec<TraceEntry>) {
+    rows.extend(entries.into_iter().map(|entry| {
+        (
+            stage.to_string(),
+            entry.start_bit,
+            entry.end_bit,
+            entry.code.to_string(),
+            entry.value,
+        )
+    }));
+}
+
+/// Runs the entity decoder for `type_name`, returning its `Debug` output, or
+/// `None` when no decoder handles the type.
+fn trace_entity_decode(
+    reader: &mut BitReader<'_>,
+    type_name: &str,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+    object_handle: u64,
+) -> Option<crate::core::result::Result<String>> {
+    macro_rules! traced_decode {
+        ($decode:ident) => {
+            Some(
+                $decode(reader, version, header, object_handle).map(|entity| format!("{entity:?}")),
+            )
+        };
+    }
+    match type_name {
+        "LINE" => traced_decode!(decode_line_for_version),
+        "POINT" => traced_decode!(decode_point_for_version),
+        "ARC" => traced_decode!(decode_arc_for_version),
+        "CIRCLE" => traced_decode!(decode_circle_for_version),
+        "ELLIPSE" => traced_decode!(decode_ellipse_for_version),
+        "SPLINE" => traced_decode!(decode_spline_for_version),
+        "TEXT" => traced_decode!(decode_text_for_version),
+        "ATTRIB" => traced_decode!(decode_attrib_for_version),
+        "ATTDEF" => traced_decode!(decode_attdef_for_version),
+        "MTEXT" => traced_decode!(decode_mtext_for_version),
+        "INSERT" => traced_decode!(decode_insert_for_version),
+        "MINSERT" => traced_decode!(decode_minsert_for_version),
+        "LWPOLYLINE" => traced_decode!(decode_lwpolyline_for_version),
+        "POLYLINE_2D" => traced_decode!(decode_polyline_2d_for_version),
+        "POLYLINE_3D" => traced_decode!(decode_polyline_3d_for_version),
+        "POLYLINE_MESH" => traced_decode!(decode_polyline_mesh_for_version),
+        "POLYLINE_PFACE" => traced_decode!(decode_polyline_pface_for_version),
+        "VERTEX_2D" => traced_decode!(decode_vertex_2d_for_version),
+        "VERTEX_3D" => traced_decode!(decode_vertex_3d_for_version),
+        "VERTEX_PFACE_FACE" => traced_decode!(decode_vertex_pface_face_for_version),
+        "HATCH" => traced_decode!(decode_hatch_for_version),
+        "LEADER" => traced_decode!(decode_leader_for_version),
+        "MULTILEADER" => traced_decode!(decode_mleader_for_version),
+        "TOLERANCE" => traced_decode!(decode_tolerance_for_version),
+        "MLINE" => traced_decode!(decode_mline_for_version),
+        "3DFACE" => traced_decode!(decode_3dface_for_version),
+        "SOLID" => traced_decode!(decode_solid_for_version),
+        "TRACE" => traced_decode!(decode_trace_for_version),
+        "SHAPE" => traced_decode!(decode_shape_for_version),
+        "VIEWPORT" => traced_decode!(decode_viewport_for_version),
+        "OLEFRAME" => traced_decode!(decode_oleframe_for_version),
+        "OLE2FRAME" => traced_decode!(decode_ole2frame_for_version),
+        "LONG_TRANSACTION" => traced_decode!(decode_long_transaction_for_version),
+        "REGION" => traced_decode!(decode_region_for_version),
+        "3DSOLID" => traced_decode!(decode_3dsolid_for_version),
+        "BODY" => traced_decode!(decode_body_for_version),
+        "RAY" => traced_decode!(decode_ray_for_version),
+        "XLINE" => traced_decode!(decode_xline_for_version),
+        "MESH" => traced_decode!(decode_mesh_for_version),
+        "LIGHT" => traced_decode!(decode_light_for_version),
+        "RTEXT" => traced_decode!(decode_rtext_for_version),
+        "ARCALIGNEDTEXT" => traced_decode!(decode_arc_aligned_text_for_version),
+        _ => DIM_DECODE_SPECS
+            .iter()
+            .find(|spec| spec.type_name == type_name)
+            .map(|spec| {
+                (spec.decode_entity)(reader, version, header, object_handle)
+                    .map(|entity| format!("{entity:?}"))
+            }),
+    }
+}
+
 #[pyfunction(signature = (path, handles, limit=None))]
 pub fn decode_object_entity_layer_handles(
     path: &str,
@@ -469,7 +773,7 @@ pub fn decode_object_entity_layer_handles(
             .into_iter()
             .collect();
     let target_handles: HashSet<u64> = handles.iter().copied().collect();
-    let mut object_offsets: HashMap<u64, u32> = HashMap::new();
+    let mut object_offsets: HashMap<u64, u64> = HashMap::new();
     for obj in index.objects.iter() {
         if !target_handles.contains(&obj.handle.0) {
             continue;
@@ -720,7 +1024,7 @@ fn decode_known_handle_refs_from_object_record(
     }
     let canonical_start = resolve_r2010_object_data_end_bit(header).ok();
     let preferred_ref_types = preferred_ref_type_codes_for_acis_unknown(header.type_code);
-    let mut best: Option<(i64, i64, usize, u32, Vec<u64>)> = None;
+    let mut best: Option<(i64, i64, usize, u32, Vec<u64>, Vec<HandleRefKind>)> = None;
     let mut second_score: Option<i64> = None;
 
     for start_bit in start_candidates {
@@ -731,6 +1035,7 @@ fn decode_known_handle_refs_from_object_record(
         reader.set_bit_pos(start_bit);
 
         let mut refs: Vec<u64> = Vec::new();
+        let mut kinds: Vec<HandleRefKind> = Vec::new();
         let mut seen: HashSet<u64> = HashSet::new();
         let mut quality_score: i64 = 0;
         for _ in 0..128usize {
@@ -738,10 +1043,12 @@ fn decode_known_handle_refs_from_object_record(
                 break;
             }
             let before_bits = reader.tell_bits();
-            let value = match entities::common::read_handle_reference(&mut reader, object_handle) {
-                Ok(value) => value,
-                Err(_) => break,
-            };
+            let (value, kind) =
+                match entities::common::read_resolved_handle_reference(&mut reader, object_handle)
+                {
+                    Ok(handle_ref) => (handle_ref.handle, handle_ref.kind),
+                    Err(_) => break,
+                };
             if reader.tell_bits() <= before_bits {
                 break;
             }
@@ -750,13 +1057,14 @@ fn decode_known_handle_refs_from_object_record(
             }
             if seen.insert(value) {
                 refs.push(value);
+                kinds.push(kind);
                 if let Some(type_codes) = object_type_codes {
                     if let Some(ref_type_code) = type_codes.get(&value) {
                         if preferred_ref_types.contains(ref_type_code) {
                             quality_score += 6;
                         } else if (0x214..=0x225).contains(ref_type_code) {
                             quality_score += 3;
-                        } else if matches!(*ref_type_code, 0x25 | 0x26 | 0x27) {
+                        } else if matches!(*ref_type_code, 0x25..=0x27) {
                             quality_score += 2;
                         } else if *ref_type_code == 0x33 {
                             quality_score -= 2;
@@ -777,39 +1085,40 @@ fn decode_known_handle_refs_from_object_record(
             .saturating_add((refs.len() as i64).saturating_mul(4))
             .saturating_sub(i64::from(delta));
 
-        let should_replace_best = match &best {
-            Some((best_score, _best_quality, best_len, best_delta, _))
+        let should_replace_best = !matches!(
+            &best,
+            Some((best_score, _best_quality, best_len, best_delta, _, _))
                 if score < *best_score
                     || (score == *best_score
                         && (refs.len() < *best_len
-                            || (refs.len() == *best_len && delta >= *best_delta))) =>
-            {
-                false
-            }
-            _ => true,
-        };
+                            || (refs.len() == *best_len && delta >= *best_delta)))
+        );
         if should_replace_best {
-            if let Some((prev_best_score, _, _, _, _)) = &best {
+            if let Some((prev_best_score, _, _, _, _, _)) = &best {
                 second_score = Some(
                     second_score
                         .map(|value| value.max(*prev_best_score))
                         .unwrap_or(*prev_best_score),
                 );
             }
-            best = Some((score, quality_score, refs.len(), delta, refs));
+            best = Some((score, quality_score, refs.len(), delta, refs, kinds));
         } else {
             second_score = Some(second_score.map(|value| value.max(score)).unwrap_or(score));
         }
     }
 
-    if let Some((best_score, quality_score, _len, _delta, refs)) = best {
+    if let Some((best_score, quality_score, _len, _delta, refs, kinds)) = best {
         let confidence = derive_known_handle_refs_confidence(
             refs.len(),
             quality_score,
             best_score,
             second_score,
         );
-        KnownHandleRefsDecode { refs, confidence }
+        KnownHandleRefsDecode {
+            refs,
+            kinds,
+            confidence,
+        }
     } else {
         KnownHandleRefsDecode::default()
     }
@@ -1106,6 +1415,37 @@ pub fn decode_object_handle_stream_refs(
     handles: Vec<u64>,
     limit: Option<usize>,
 ) -> PyResult<Vec<HandleStreamRefsRow>> {
+    Ok(decode_handle_stream_refs_for_handles(path, handles, limit)?
+        .into_iter()
+        .map(|(handle, decoded)| (handle, decoded.refs))
+        .collect())
+}
+
+#[pyfunction(signature = (path, handles, limit=None))]
+pub fn decode_object_handle_stream_ref_kinds(
+    path: &str,
+    handles: Vec<u64>,
+    limit: Option<usize>,
+) -> PyResult<Vec<HandleStreamRefKindsRow>> {
+    Ok(decode_handle_stream_refs_for_handles(path, handles, limit)?
+        .into_iter()
+        .map(|(handle, decoded)| {
+            let refs = decoded
+                .refs
+                .into_iter()
+                .zip(decoded.kinds)
+                .map(|(ref_handle, kind)| (ref_handle, kind.as_str().to_string()))
+                .collect();
+            (handle, refs)
+        })
+        .collect())
+}
+
+fn decode_handle_stream_refs_for_handles(
+    path: &str,
+    handles: Vec<u64>,
+    limit: Option<usize>,
+) -> PyResult<Vec<(u64, KnownHandleRefsDecode)>> {
     if handles.is_empty() {
         return Ok(Vec::new());
     }
@@ -1115,7 +1455,7 @@ pub fn decode_object_handle_stream_refs(
     let best_effort = is_best_effort_compat_version(&decoder);
     let index = decoder.build_object_index().map_err(to_py_err)?;
     let known_handles: HashSet<u64> = index.objects.iter().map(|obj| obj.handle.0).collect();
-    let mut object_offsets: HashMap<u64, u32> = HashMap::new();
+    let mut object_offsets: HashMap<u64, u64> = HashMap::new();
     for obj in index.objects.iter() {
         object_offsets
             .entry(obj.handle.0)
@@ -1144,7 +1484,7 @@ pub fn decode_object_handle_stream_refs(
             None,
             16,
         );
-        result.push((handle, decoded.refs));
+        result.push((handle, decoded));
         if let Some(limit) = limit {
             if result.len() >= limit {
                 break;
@@ -1170,7 +1510,7 @@ pub fn decode_acis_candidate_infos(
     let index = decoder.build_object_index().map_err(to_py_err)?;
     let known_handles: HashSet<u64> = index.objects.iter().map(|obj| obj.handle.0).collect();
     let object_type_codes = collect_object_type_codes(&decoder, &index, best_effort)?;
-    let mut object_offsets: HashMap<u64, u32> = HashMap::new();
+    let mut object_offsets: HashMap<u64, u64> = HashMap::new();
     for obj in index.objects.iter() {
         object_offsets
             .entry(obj.handle.0)
@@ -1381,22 +1721,7 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
     let best_effort = is_best_effort_compat_version(&decoder);
     let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
     let index = decoder.build_object_index().map_err(to_py_err)?;
-    let decoded_layer_rows = decode_layer_colors(path, None)?;
-    let decoded_layer_handles: Vec<u64> = decoded_layer_rows.iter().map(|(h, _, _)| *h).collect();
-    let raw_layer_handles =
-        collect_known_layer_handles_in_order(&decoder, &dynamic_types, &index, best_effort)?;
-    let mut layer_handle_remap = HashMap::new();
-    if raw_layer_handles.len() == decoded_layer_handles.len() {
-        for (raw, decoded) in raw_layer_handles
-            .iter()
-            .copied()
-            .zip(decoded_layer_handles.iter().copied())
-        {
-            layer_handle_remap.insert(raw, decoded);
-        }
-    }
-    let mut known_layer_handles: HashSet<u64> = decoded_layer_handles.into_iter().collect();
-    known_layer_handles.extend(raw_layer_handles.iter().copied());
+    let layers = EntityLayerResolver::load(path, &decoder, &dynamic_types, &index, best_effort)?;
     let mut result = Vec::new();
 
     for obj in index.objects.iter() {
@@ -1423,18 +1748,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1452,18 +1772,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1478,18 +1793,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                     Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                     Err(err) => return Err(to_py_err(err)),
                 };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1507,18 +1817,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1536,18 +1841,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1565,18 +1865,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1594,18 +1889,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1623,18 +1913,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1652,18 +1937,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1681,18 +1961,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1710,18 +1985,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1739,18 +2009,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1768,18 +2033,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1797,18 +2057,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1826,18 +2081,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1855,18 +2105,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1884,18 +2129,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1913,18 +2153,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1942,18 +2177,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -1971,18 +2201,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2000,18 +2225,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2029,18 +2249,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2058,18 +2273,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2087,18 +2297,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2116,18 +2321,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2145,18 +2345,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2174,18 +2369,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2203,18 +2393,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2232,18 +2417,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2258,18 +2438,13 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                     Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                     Err(err) => return Err(to_py_err(err)),
                 };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
@@ -2287,55 +2462,25 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
                 entity.layer_handle,
-                &known_layer_handles,
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 entity.handle,
                 entity.color_index,
                 entity.true_color,
                 layer_handle,
             ));
-        } else if matches_type_name(header.type_code, 0x15, "DIM_LINEAR", &dynamic_types) {
-            let entity = match decode_dim_linear_for_version(
-                &mut reader,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-            ) {
-                Ok(entity) => entity,
-                Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
-                Err(err) => return Err(to_py_err(err)),
-            };
-            let common = &entity.common;
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
-                &record,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-                common.layer_handle,
-                &known_layer_handles,
-            );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
-            result.push((
-                common.handle,
-                common.color_index,
-                common.true_color,
-                layer_handle,
-            ));
-        } else if matches_type_name(header.type_code, 0x14, "DIM_ORDINATE", &dynamic_types) {
+        } else if matches_type_name(header.type_code, 0x15, "DIM_LINEAR", &dynamic_types)
+            || matches_type_name(header.type_code, 0x14, "DIM_ORDINATE", &dynamic_types)
+            || matches_type_name(header.type_code, 0x16, "DIM_ALIGNED", &dynamic_types)
+            || matches_type_name(header.type_code, 0x17, "DIM_ANG3PT", &dynamic_types)
+            || matches_type_name(header.type_code, 0x18, "DIM_ANG2LN", &dynamic_types)
+        {
             let entity = match decode_dim_linear_for_version(
                 &mut reader,
                 decoder.version(),
@@ -2347,26 +2492,21 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) => return Err(to_py_err(err)),
             };
             let common = &entity.common;
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
-                common.layer_handle,
-                &known_layer_handles,
+                common.layer_handle.unwrap_or(0),
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 common.handle,
                 common.color_index,
                 common.true_color,
                 layer_handle,
             ));
-        } else if matches_type_name(header.type_code, 0x16, "DIM_ALIGNED", &dynamic_types) {
-            let entity = match decode_dim_linear_for_version(
+        } else if matches_type_name(header.type_code, 0x1A, "DIM_DIAMETER", &dynamic_types) {
+            let entity = match decode_dim_diameter_for_version(
                 &mut reader,
                 decoder.version(),
                 &header,
@@ -2377,26 +2517,21 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) => return Err(to_py_err(err)),
             };
             let common = &entity.common;
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
-                common.layer_handle,
-                &known_layer_handles,
+                common.layer_handle.unwrap_or(0),
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 common.handle,
                 common.color_index,
                 common.true_color,
                 layer_handle,
             ));
-        } else if matches_type_name(header.type_code, 0x17, "DIM_ANG3PT", &dynamic_types) {
-            let entity = match decode_dim_linear_for_version(
+        } else if matches_type_name(header.type_code, 0x19, "DIM_RADIUS", &dynamic_types) {
+            let entity = match decode_dim_radius_for_version(
                 &mut reader,
                 decoder.version(),
                 &header,
@@ -2407,118 +2542,232 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
                 Err(err) => return Err(to_py_err(err)),
             };
             let common = &entity.common;
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
+            let layer_handle = layers.resolve(
                 &record,
                 decoder.version(),
                 &header,
                 obj.handle.0,
-                common.layer_handle,
-                &known_layer_handles,
+                common.layer_handle.unwrap_or(0),
             );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
             result.push((
                 common.handle,
                 common.color_index,
                 common.true_color,
                 layer_handle,
             ));
-        } else if matches_type_name(header.type_code, 0x18, "DIM_ANG2LN", &dynamic_types) {
-            let entity = match decode_dim_linear_for_version(
-                &mut reader,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-            ) {
-                Ok(entity) => entity,
-                Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
-                Err(err) => return Err(to_py_err(err)),
-            };
-            let common = &entity.common;
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
-                &record,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-                common.layer_handle,
-                &known_layer_handles,
-            );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
-            result.push((
-                common.handle,
-                common.color_index,
-                common.true_color,
+        } else {
+            continue;
+        }
+
+        if let Some(limit) = limit {
+            if result.len() >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_entity_colors(path: &str, limit: Option<usize>) -> PyResult<Vec<EntityColorRow>> {
+    let rows = decode_entity_styles(path, limit)?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(handle, color_index, true_color, layer_handle)| {
+            let color = entities::EntityColor::from_index_and_true_color(color_index, true_color)?;
+            Some((
+                handle,
+                color.kind().into(),
+                color.aci().map(u16::from),
+                color.to_rgb_u32(),
                 layer_handle,
-            ));
-        } else if matches_type_name(header.type_code, 0x1A, "DIM_DIAMETER", &dynamic_types) {
-            let entity = match decode_dim_diameter_for_version(
-                &mut reader,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-            ) {
-                Ok(entity) => entity,
-                Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
-                Err(err) => return Err(to_py_err(err)),
-            };
-            let common = &entity.common;
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
-                &record,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-                common.layer_handle,
-                &known_layer_handles,
-            );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
-            result.push((
-                common.handle,
-                common.color_index,
-                common.true_color,
-                layer_handle,
-            ));
-        } else if matches_type_name(header.type_code, 0x19, "DIM_RADIUS", &dynamic_types) {
-            let entity = match decode_dim_radius_for_version(
-                &mut reader,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-            ) {
-                Ok(entity) => entity,
-                Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
-                Err(err) => return Err(to_py_err(err)),
-            };
-            let common = &entity.common;
-            let layer_handle = recover_entity_layer_handle_r2010_plus(
-                &record,
-                decoder.version(),
-                &header,
-                obj.handle.0,
-                common.layer_handle,
-                &known_layer_handles,
-            );
-            let layer_handle = layer_handle_remap
-                .get(&layer_handle)
-                .copied()
-                .unwrap_or(layer_handle);
-            result.push((
-                common.handle,
-                common.color_index,
-                common.true_color,
-                layer_handle,
-            ));
+            ))
+        })
+        .collect())
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_entity_transparency(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<EntityTransparencyRow>> {
+    collect_entity_common_header_rows(path, limit, |handle, _reader, common, _header| {
+        // Anything without a ByBlock/alpha method byte inherits from the layer.
+        let transparency = common.color.transparency.filter(|raw| {
+            !matches!(
+                entities::Transparency::from_raw(*raw),
+                entities::Transparency::ByLayer
+            )
+        });
+        Some((handle, transparency))
+    })
+}
+
+/// Thickness, elevation and extrusion of the LINE, ARC, CIRCLE, LWPOLYLINE
+/// and POLYLINE_2D entities that have a thickness. Records that fail to
+/// decode are skipped: the geometry decoders report them.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_entity_thickness(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<EntityThicknessRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+    let version = decoder.version();
+    let mut result = Vec::new();
+    for obj in index.objects.iter() {
+        let Some((record, header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        let is_type = |type_code: u16, type_name: &'static str| {
+            matches_type_name(header.type_code, type_code, type_name, &dynamic_types)
+        };
+        let handle = obj.handle.0;
+        let row = if is_type(0x13, "LINE") {
+            decode_line_record(&record, version, &header, handle)
+                .ok()
+                .flatten()
+                .map(|line| (line.handle, line.thickness, 0.0, line.extrusion))
+        } else {
+            let mut reader = record.bit_reader();
+            if skip_object_type_prefix(&mut reader, version).is_err() {
+                continue;
+            }
+            if is_type(0x11, "ARC") {
+                decode_arc_for_version(&mut reader, version, &header, handle)
+                    .ok()
+                    .map(|arc| (arc.handle, arc.thickness, 0.0, arc.extrusion))
+            } else if is_type(0x12, "CIRCLE") {
+                decode_circle_for_version(&mut reader, version, &header, handle)
+                    .ok()
+                    .map(|circle| (circle.handle, circle.thickness, 0.0, circle.extrusion))
+            } else if is_type(0x4D, "LWPOLYLINE") {
+                decode_lwpolyline_for_version(&mut reader, version, &header, handle)
+                    .ok()
+                    .map(|lw| (lw.handle, lw.thickness, lw.elevation, lw.extrusion))
+            } else if is_type(0x0F, "POLYLINE_2D") {
+                decode_polyline_2d_for_version(&mut reader, version, &header, handle)
+                    .ok()
+                    .map(|poly| (poly.handle, poly.thickness, poly.elevation, poly.extrusion))
+            } else {
+                continue;
+            }
+        };
+        let Some(row) = row else {
+            continue;
+        };
+        if row.1 == 0.0 || !row.1.is_finite() {
+            continue;
+        }
+        result.push(row);
+        if let Some(limit) = limit {
+            if result.len() >= limit {
+                break;
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_entity_line_styles(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<EntityLineStyleRow>> {
+    let known_ltype_handles: HashSet<u64> = decode_linetypes(path, None)?
+        .into_iter()
+        .map(|(handle, _, _, _, _)| handle)
+        .collect();
+    collect_entity_common_header_rows(path, limit, |handle, reader, common, header| {
+        // Only an explicit linetype (flags == 3) carries a handle; BYLAYER,
+        // BYBLOCK and CONTINUOUS are resolved by the caller.
+        let ltype_handle = if common.ltype_flags == 3 {
+            recover_entity_ltype_handle(reader, common, header, &known_ltype_handles)
         } else {
+            None
+        };
+        Some((
+            handle,
+            common.ltype_flags,
+            ltype_handle,
+            common.ltype_scale,
+            entities::common::line_weight_from_index(common.line_weight),
+        ))
+    })
+}
+
+/// R2010+ handle streams do not always start at the computed data end, so
+/// nearby end-bit candidates are tried until the linetype reference points
+/// at a known LTYPE entry.
+fn recover_entity_ltype_handle(
+    reader: &mut BitReader<'_>,
+    common: &entities::common::CommonEntityHeader,
+    header: &ApiObjectHeader,
+    known_ltype_handles: &HashSet<u64>,
+) -> Option<u64> {
+    let mut end_bits = vec![common.obj_size];
+    end_bits.extend(resolve_r2010_object_data_end_bit_candidates(header));
+    end_bits.dedup();
+    for end_bit in end_bits {
+        reader.set_bit_pos(end_bit);
+        let Ok(handles) = entities::common::parse_common_entity_handles(reader, common) else {
             continue;
+        };
+        if let Some(ltype) = handles.ltype.filter(|ltype| known_ltype_handles.contains(ltype)) {
+            return Some(ltype);
         }
+    }
+    None
+}
+
+/// Runs `row` over the common header of every entity reported by
+/// `decode_entity_styles`.
+fn collect_entity_common_header_rows<T>(
+    path: &str,
+    limit: Option<usize>,
+    mut row: impl FnMut(
+        u64,
+        &mut BitReader<'_>,
+        &entities::common::CommonEntityHeader,
+        &ApiObjectHeader,
+    ) -> Option<T>,
+) -> PyResult<Vec<T>> {
+    let mut entity_handles: HashSet<u64> = decode_entity_styles(path, None)?
+        .into_iter()
+        .map(|(handle, _, _, _)| handle)
+        .collect();
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+    let mut result = Vec::new();
 
+    for obj in index.objects.iter() {
+        if !entity_handles.remove(&obj.handle.0) {
+            continue;
+        }
+        let Some((record, header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        let mut reader = record.bit_reader();
+        if skip_object_type_prefix(&mut reader, decoder.version()).is_err() {
+            continue;
+        }
+        let Some(common) =
+            parse_entity_common_header_for_version(&mut reader, decoder.version(), &header)
+        else {
+            continue;
+        };
+        let Some(value) = row(obj.handle.0, &mut reader, &common, &header) else {
+            continue;
+        };
+        result.push(value);
         if let Some(limit) = limit {
             if result.len() >= limit {
                 break;
@@ -2529,6 +2778,37 @@ pub fn decode_entity_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<En
     Ok(result)
 }
 
+fn parse_entity_common_header_for_version(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+) -> Option<entities::common::CommonEntityHeader> {
+    match version {
+        version::DwgVersion::R2010 => parse_dim_common_header_r2010_plus_with_candidates(
+            reader,
+            header,
+            entities::common::parse_common_entity_header_r2010,
+        ),
+        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+            parse_dim_common_header_r2010_plus_with_candidates(
+                reader,
+                header,
+                entities::common::parse_common_entity_header_r2013,
+            )
+        }
+        version::DwgVersion::R2007 => {
+            entities::common::parse_common_entity_header_r2007(reader).ok()
+        }
+        version::DwgVersion::R14 => entities::common::parse_common_entity_header_r14(reader).ok(),
+        _ => entities::common::parse_common_entity_header(reader).ok(),
+    }
+}
+
+#[pyfunction]
+pub fn aci_to_rgb(index: u16) -> Option<(u8, u8, u8)> {
+    u8::try_from(index).ok().and_then(entities::aci_to_rgb)
+}
+
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_line_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<LineEntityRow>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
@@ -2545,37 +2825,13 @@ pub fn decode_line_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<Li
         if !matches_type_name(header.type_code, 0x13, "LINE", &dynamic_types) {
             continue;
         }
-        let mut entity: Option<entities::LineEntity> = None;
-        let mut last_err = None;
-        for with_prefix in [true, false] {
-            let mut reader = record.bit_reader();
-            if with_prefix {
-                if let Err(err) = skip_object_type_prefix(&mut reader, decoder.version()) {
-                    last_err = Some(err);
-                    continue;
-                }
-            }
-            match decode_line_for_version(&mut reader, decoder.version(), &header, obj.handle.0) {
-                Ok(decoded) => {
-                    if !is_plausible_line_entity_candidate(&decoded) {
-                        continue;
-                    }
-                    entity = Some(decoded);
-                    break;
-                }
-                Err(err) => last_err = Some(err),
-            }
-        }
-        let entity = match entity {
-            Some(entity) => entity,
-            None if best_effort => continue,
-            None => {
-                if let Some(err) = last_err {
-                    return Err(to_py_err(err));
-                }
-                continue;
-            }
-        };
+        let entity =
+            match decode_line_record(&record, decoder.version(), &header, obj.handle.0) {
+                Ok(Some(entity)) => entity,
+                Ok(None) => continue,
+                Err(_) if best_effort => continue,
+                Err(err) => return Err(to_py_err(err)),
+            };
         result.push((
             entity.handle,
             entity.start.0,
@@ -2594,6 +2850,35 @@ pub fn decode_line_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<Li
     Ok(result)
 }
 
+/// Decodes a LINE record after its type prefix, retrying from the start of
+/// the record when that fails or yields implausible geometry. `Ok(None)`
+/// means both attempts decoded but neither looked like a line.
+fn decode_line_record(
+    record: &objects::ObjectRecord<'_>,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+    handle: u64,
+) -> crate::core::result::Result<Option<entities::LineEntity>> {
+    let mut last_err = None;
+    for with_prefix in [true, false] {
+        let mut reader = record.bit_reader();
+        if with_prefix {
+            if let Err(err) = skip_object_type_prefix(&mut reader, version) {
+                last_err = Some(err);
+                continue;
+            }
+        }
+        match decode_line_for_version(&mut reader, version, header, handle) {
+            Ok(decoded) if is_plausible_line_entity_candidate(&decoded) => {
+                return Ok(Some(decoded))
+            }
+            Ok(_) => {}
+            Err(err) => last_err = Some(err),
+        }
+    }
+    last_err.map_or(Ok(None), Err)
+}
+
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_line_owner_handles(path: &str, limit: Option<usize>) -> PyResult<Vec<InsertOwnerRow>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
@@ -2610,37 +2895,13 @@ pub fn decode_line_owner_handles(path: &str, limit: Option<usize>) -> PyResult<V
         if !matches_type_name(header.type_code, 0x13, "LINE", &dynamic_types) {
             continue;
         }
-        let mut entity: Option<entities::LineEntity> = None;
-        let mut last_err = None;
-        for with_prefix in [true, false] {
-            let mut reader = record.bit_reader();
-            if with_prefix {
-                if let Err(err) = skip_object_type_prefix(&mut reader, decoder.version()) {
-                    last_err = Some(err);
-                    continue;
-                }
-            }
-            match decode_line_for_version(&mut reader, decoder.version(), &header, obj.handle.0) {
-                Ok(decoded) => {
-                    if !is_plausible_line_entity_candidate(&decoded) {
-                        continue;
-                    }
-                    entity = Some(decoded);
-                    break;
-                }
-                Err(err) => last_err = Some(err),
-            }
-        }
-        let entity = match entity {
-            Some(entity) => entity,
-            None if best_effort => continue,
-            None => {
-                if let Some(err) = last_err {
-                    return Err(to_py_err(err));
-                }
-                continue;
-            }
-        };
+        let entity =
+            match decode_line_record(&record, decoder.version(), &header, obj.handle.0) {
+                Ok(Some(entity)) => entity,
+                Ok(None) => continue,
+                Err(_) if best_effort => continue,
+                Err(err) => return Err(to_py_err(err)),
+            };
         result.push((entity.handle, entity.owner_handle));
         if let Some(limit) = limit {
             if result.len() >= limit {
@@ -2843,7 +3104,7 @@ pub fn decode_line_arc_circle_entities(
                 obj.handle.0,
             ) {
                 Ok(entity) => entity,
-                Err(err) if best_effort => continue,
+                Err(_) if best_effort => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
             lines.push((
@@ -2861,7 +3122,7 @@ pub fn decode_line_arc_circle_entities(
                 match decode_arc_for_version(&mut reader, decoder.version(), &header, obj.handle.0)
                 {
                     Ok(entity) => entity,
-                    Err(err) if best_effort => continue,
+                    Err(_) if best_effort => continue,
                     Err(err) => return Err(to_py_err(err)),
                 };
             arcs.push((
@@ -2882,7 +3143,7 @@ pub fn decode_line_arc_circle_entities(
                 obj.handle.0,
             ) {
                 Ok(entity) => entity,
-                Err(err) if best_effort => continue,
+                Err(_) if best_effort => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
             circles.push((
@@ -2905,6 +3166,213 @@ pub fn decode_line_arc_circle_entities(
     Ok((lines, arcs, circles))
 }
 
+/// LINE, ARC, CIRCLE, POINT and LWPOLYLINE geometry, each row paired with
+/// its resolved style, in one pass over the object index.
+///
+/// The geometry rows are those of the per-type decoders. The style is
+/// `(layer_name, rgb, linetype_name, lineweight)`: ByLayer colors take the
+/// RGB of the layer and ByBlock colors are `None`; the linetype is the LTYPE
+/// name, or `ByLayer`, `ByBlock` or `Continuous`; the lineweight is in
+/// 1/100 mm with -1 ByLayer, -2 ByBlock and -3 the default. Names that do not
+/// resolve to a LAYER or LTYPE entry are `None`.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_styled_entities(path: &str, limit: Option<usize>) -> PyResult<StyledEntityRows> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+    let layers = EntityLayerResolver::load(path, &decoder, &dynamic_types, &index, best_effort)?;
+    let styles = ResolvedStyleTables::load(path)?;
+    let mut rows = StyledEntityRows::default();
+    let mut total = 0usize;
+
+    for obj in index.objects.iter() {
+        let Some((record, header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        let Some(kind) = STYLED_ENTITY_TYPES
+            .iter()
+            .find(|(code, name)| matches_type_name(header.type_code, *code, name, &dynamic_types))
+            .map(|(_, name)| *name)
+        else {
+            continue;
+        };
+
+        let mut reader = record.bit_reader();
+        if let Err(err) = skip_object_type_prefix(&mut reader, decoder.version()) {
+            if best_effort {
+                continue;
+            }
+            return Err(to_py_err(err));
+        }
+        let mut style_reader = reader.clone();
+        let line_style =
+            parse_entity_common_header_for_version(&mut style_reader, decoder.version(), &header)
+                .map(|common| {
+                    let ltype_handle = if common.ltype_flags == 3 {
+                        recover_entity_ltype_handle(
+                            &mut style_reader,
+                            &common,
+                            &header,
+                            &styles.linetype_handles,
+                        )
+                    } else {
+                        None
+                    };
+                    (
+                        common.ltype_flags,
+                        ltype_handle,
+                        entities::common::line_weight_from_index(common.line_weight),
+                    )
+                });
+
+        let version = decoder.version();
+        let handle = obj.handle.0;
+        let decoded = match kind {
+            "LINE" => decode_line_record(&record, version, &header, handle).map(|entity| {
+                entity.map(|e| {
+                    let (x1, y1, z1) = e.start;
+                    let (x2, y2, z2) = e.end;
+                    let row = (e.handle, x1, y1, z1, x2, y2, z2);
+                    ((e.color_index, e.true_color, e.layer_handle), StyledGeometry::Line(row))
+                })
+            }),
+            "ARC" => decode_arc_for_version(&mut reader, version, &header, handle).map(|e| {
+                let (x, y, z) = e.center;
+                let row = (e.handle, x, y, z, e.radius, e.angle_start, e.angle_end);
+                Some(((e.color_index, e.true_color, e.layer_handle), StyledGeometry::Arc(row)))
+            }),
+            "CIRCLE" => decode_circle_for_version(&mut reader, version, &header, handle).map(|e| {
+                let (x, y, z) = e.center;
+                let row = (e.handle, x, y, z, e.radius);
+                Some(((e.color_index, e.true_color, e.layer_handle), StyledGeometry::Circle(row)))
+            }),
+            "POINT" => decode_point_for_version(&mut reader, version, &header, handle).map(|e| {
+                let (x, y, z) = e.location;
+                let row = (e.handle, x, y, z, e.x_axis_angle);
+                Some(((e.color_index, e.true_color, e.layer_handle), StyledGeometry::Point(row)))
+            }),
+            _ => decode_lwpolyline_for_version(&mut reader, version, &header, handle).map(|e| {
+                let color = (e.color_index, e.true_color, e.layer_handle);
+                let row = (e.handle, e.flags, e.vertices, e.bulges, e.widths, e.const_width);
+                Some((color, StyledGeometry::LwPolyline(row)))
+            }),
+        };
+        let ((color_index, true_color, layer_handle), geometry) = match decoded {
+            Ok(Some(decoded)) => decoded,
+            Ok(None) => continue,
+            Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        };
+        let layer_handle = layers.resolve(&record, version, &header, handle, layer_handle);
+        let style = styles.resolve(layer_handle, color_index, true_color, line_style);
+        match geometry {
+            StyledGeometry::Line(row) => rows.0.push((row, style)),
+            StyledGeometry::Arc(row) => rows.1.push((row, style)),
+            StyledGeometry::Circle(row) => rows.2.push((row, style)),
+            StyledGeometry::Point(row) => rows.3.push((row, style)),
+            StyledGeometry::LwPolyline(row) => rows.4.push((row, style)),
+        }
+        total += 1;
+        if let Some(limit) = limit {
+            if total >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(rows)
+}
+
+const STYLED_ENTITY_TYPES: [(u16, &str); 5] = [
+    (0x13, "LINE"),
+    (0x11, "ARC"),
+    (0x12, "CIRCLE"),
+    (0x1B, "POINT"),
+    (0x4D, "LWPOLYLINE"),
+];
+
+enum StyledGeometry {
+    Line(LineEntityRow),
+    Arc(ArcEntityRow),
+    Circle(CircleEntityRow),
+    Point(PointEntityRow),
+    LwPolyline(LwPolylineEntityRow),
+}
+
+/// LAYER and LTYPE entries that `decode_styled_entities` resolves against.
+struct ResolvedStyleTables {
+    layer_names: HashMap<u64, String>,
+    layer_rgb: HashMap<u64, u32>,
+    linetype_names: HashMap<u64, String>,
+    linetype_handles: HashSet<u64>,
+}
+
+impl ResolvedStyleTables {
+    fn load(path: &str) -> PyResult<Self> {
+        let layer_names = decode_layer_names(path, None)?.into_iter().collect();
+        let layer_rgb = decode_layer_colors(path, None)?
+            .into_iter()
+            .filter_map(|(handle, color_index, true_color)| {
+                let color =
+                    entities::EntityColor::from_index_and_true_color(Some(color_index), true_color)?;
+                Some((handle, color.to_rgb_u32()?))
+            })
+            .collect();
+        let linetypes = decode_linetypes(path, None)?;
+        let linetype_handles = linetypes.iter().map(|row| row.0).collect();
+        let linetype_names = linetypes
+            .into_iter()
+            .filter_map(|(handle, name, _, _, _)| Some((handle, name?)))
+            .collect();
+        Ok(Self {
+            layer_names,
+            layer_rgb,
+            linetype_names,
+            linetype_handles,
+        })
+    }
+
+    fn resolve(
+        &self,
+        layer_handle: u64,
+        color_index: Option<u16>,
+        true_color: Option<u32>,
+        line_style: Option<(u8, Option<u64>, i16)>,
+    ) -> ResolvedStyleRow {
+        let layer_rgb = self.layer_rgb.get(&layer_handle).copied();
+        let rgb = match entities::EntityColor::from_index_and_true_color(color_index, true_color) {
+            Some(entities::EntityColor::ByLayer) => layer_rgb,
+            Some(color) => color.to_rgb_u32(),
+            None => None,
+        };
+        let (linetype, lineweight) = match line_style {
+            Some((ltype_flags, ltype_handle, lineweight)) => {
+                let linetype = match ltype_flags {
+                    0 => Some("ByLayer".into()),
+                    1 => Some("ByBlock".into()),
+                    2 => Some("Continuous".into()),
+                    _ => ltype_handle
+                        .and_then(|handle| self.linetype_names.get(&handle))
+                        .map(|name| name.as_str().into()),
+                };
+                (linetype, lineweight)
+            }
+            None => (None, entities::common::LINE_WEIGHT_BY_LAYER),
+        };
+        (
+            self.layer_names
+                .get(&layer_handle)
+                .map(|name| name.as_str().into()),
+            rgb,
+            linetype,
+            lineweight,
+        )
+    }
+}
+
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_ellipse_entities(
     path: &str,
@@ -2957,6 +3425,7 @@ pub fn decode_spline_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<
                 entity.control_points,
                 entity.weights,
                 entity.fit_points,
+                (entity.start_tangent, entity.end_tangent),
             )
         },
     )
@@ -2998,7 +3467,7 @@ pub fn decode_text_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<Te
         let mut entity =
             match decode_text_for_version(&mut reader, decoder.version(), &header, obj.handle.0) {
                 Ok(entity) => entity,
-                Err(err) if best_effort => continue,
+                Err(_) if best_effort => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
         if is_r2010_plus_version(decoder.version()) {
@@ -3136,9 +3605,18 @@ pub fn decode_mtext_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<M
             decoder.version(),
             version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018
         ) {
-            if let Some(recovered_text) =
+            if let Some(recovered_text) = time_heuristic("mtext_text_scan", record.raw.len(), || {
                 recover_r2010_mtext_text(&reader_after_prefix, &header, entity.text.as_str())
-            {
+            }) {
+                if recovered_text != entity.text {
+                    note_recovered_field(
+                        entity.handle,
+                        "text",
+                        RecoveredValue::Text(recovered_text.clone()),
+                        Some(RecoveredValue::Text(std::mem::take(&mut entity.text))),
+                        "text_scan",
+                    );
+                }
                 entity.text = recovered_text;
             }
             let (owner_handle, _style_handle) = recover_textish_owner_and_style_handles(
@@ -3200,6 +3678,44 @@ pub fn decode_leader_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<
     )
 }
 
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_mleader_entities(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<MLeaderEntityRow>> {
+    collect_object_rows(path, limit, "MULTILEADER", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut mleader = decode_mleader_for_version(&mut reader, version, header, handle)?;
+        if mleader.has_text && mleader.text.is_none() {
+            mleader.text = read_object_string_stream(record, header, version, 1, 0)
+                .and_then(|strings| strings.into_iter().next());
+        }
+        let roots = mleader
+            .roots
+            .into_iter()
+            .map(|root| {
+                (
+                    root.connection,
+                    root.direction,
+                    root.landing_distance,
+                    root.lines,
+                )
+            })
+            .collect();
+        Ok((
+            mleader.handle,
+            roots,
+            mleader.text,
+            mleader.text_location,
+            mleader.text_direction,
+            mleader.text_height,
+            mleader.text_width,
+            mleader.text_alignment,
+        ))
+    })
+}
+
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_hatch_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<HatchEntityRow>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
@@ -3255,14 +3771,30 @@ pub fn decode_hatch_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<H
             .into_iter()
             .map(|path| (path.closed, path.points))
             .collect();
+        let pattern: Option<HatchPatternRow> = entity.pattern.map(|pattern| {
+            let lines = pattern
+                .lines
+                .into_iter()
+                .map(|line| (line.angle, line.origin, line.offset, line.dashes))
+                .collect();
+            (
+                pattern.style,
+                pattern.pattern_type,
+                pattern.angle,
+                pattern.scale,
+                pattern.double,
+                lines,
+            )
+        });
         result.push((
             entity.handle,
-            entity.name,
+            entity.name.into(),
             entity.solid_fill,
             entity.associative,
             entity.elevation,
             entity.extrusion,
             paths,
+            pattern,
         ));
         if let Some(limit) = limit {
             if result.len() >= limit {
@@ -3418,6 +3950,21 @@ pub fn decode_viewport_entities(
     )
 }
 
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_viewport_frozen_layers(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<ViewportFrozenLayersRow>> {
+    collect_entity_rows(
+        path,
+        limit,
+        0x22,
+        "VIEWPORT",
+        decode_viewport_for_version,
+        |entity| (entity.handle, entity.frozen_layer_handles),
+    )
+}
+
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_oleframe_entities(
     path: &str,
@@ -3509,7 +4056,7 @@ pub fn decode_region_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<
             obj.handle.0,
         ) {
             Ok(entity) => entity,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let layer_handle = entity.layer_handle;
@@ -3564,7 +4111,7 @@ pub fn decode_3dsolid_entities(
             match decode_3dsolid_for_version(&mut reader, decoder.version(), &header, obj.handle.0)
             {
                 Ok(entity) => entity,
-                Err(err) if best_effort => continue,
+                Err(_) if best_effort => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
         let layer_handle = entity.layer_handle;
@@ -3615,7 +4162,7 @@ pub fn decode_body_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<Bo
         let entity =
             match decode_body_for_version(&mut reader, decoder.version(), &header, obj.handle.0) {
                 Ok(entity) => entity,
-                Err(err) if best_effort => continue,
+                Err(_) if best_effort => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
         let layer_handle = entity.layer_handle;
@@ -3660,36 +4207,38 @@ fn decode_line_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::LineEntity> {
-    let start = reader.get_pos();
-    let primary = match version {
-        version::DwgVersion::R14 => entities::decode_line_r14(reader, object_handle),
-        version::DwgVersion::R2010 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            entities::decode_line_r2010(reader, object_data_end_bit, object_handle)
-        }
-        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            entities::decode_line_r2013(reader, object_data_end_bit, object_handle)
-        }
-        version::DwgVersion::R2007 => entities::decode_line_r2007(reader),
-        _ => entities::decode_line(reader),
-    };
-    if let Ok(entity) = primary {
-        return Ok(entity);
-    }
-    let primary_err = primary.unwrap_err();
+    time_type_decode("decode_line_for_version", reader, |reader| {
+        let start = reader.get_pos();
+        let primary = match version {
+            version::DwgVersion::R14 => entities::decode_line_r14(reader, object_handle),
+            version::DwgVersion::R2010 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                entities::decode_line_r2010(reader, object_data_end_bit, object_handle)
+            }
+            version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                entities::decode_line_r2013(reader, object_data_end_bit, object_handle)
+            }
+            version::DwgVersion::R2007 => entities::decode_line_r2007(reader),
+            _ => entities::decode_line(reader),
+        };
+        let primary_err = match primary {
+            Ok(entity) => return Ok(entity),
+            Err(err) => err,
+        };
 
-    reader.set_pos(start.0, start.1);
-    if let Ok(entity) = entities::decode_line(reader) {
-        return Ok(entity);
-    }
+        reader.set_pos(start.0, start.1);
+        if let Ok(entity) = entities::decode_line(reader) {
+            return Ok(entity);
+        }
 
-    reader.set_pos(start.0, start.1);
-    if let Ok(entity) = entities::decode_line_r14(reader, object_handle) {
-        return Ok(entity);
-    }
+        reader.set_pos(start.0, start.1);
+        if let Ok(entity) = entities::decode_line_r14(reader, object_handle) {
+            return Ok(entity);
+        }
 
-    Err(primary_err)
+        Err(primary_err)
+    })
 }
 
 impl_version_dispatch! {
@@ -3747,7 +4296,7 @@ fn decode_text_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::TextEntity> {
-    match version {
+    time_type_decode("decode_text_for_version", reader, |reader| match version {
         version::DwgVersion::R14 => entities::decode_text_r14(reader, object_handle),
         version::DwgVersion::R2010 => decode_r2010_entity_with_end_bit_candidates(
             reader,
@@ -3767,7 +4316,7 @@ fn decode_text_for_version(
         }
         version::DwgVersion::R2007 => entities::decode_text_r2007(reader),
         _ => entities::decode_text(reader),
-    }
+    })
 }
 
 fn decode_attrib_for_version(
@@ -3776,32 +4325,42 @@ fn decode_attrib_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::AttribEntity> {
-    match version {
-        version::DwgVersion::R2010 => decode_r2010_entity_with_start_and_end_bit_candidates_scored(
-            reader,
-            header,
-            |attempt_reader, object_data_end_bit| {
-                entities::decode_attrib_r2010(attempt_reader, object_data_end_bit, object_handle)
-            },
-            score_attrib_entity_candidate,
-        ),
-        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            decode_r2010_entity_with_start_and_end_bit_candidates_scored(
-                reader,
-                header,
-                |attempt_reader, object_data_end_bit| {
-                    entities::decode_attrib_r2013(
-                        attempt_reader,
-                        object_data_end_bit,
-                        object_handle,
-                    )
-                },
-                score_attrib_entity_candidate,
-            )
-        }
-        version::DwgVersion::R2007 => entities::decode_attrib_r2007(reader),
-        _ => entities::decode_attrib(reader),
-    }
+    time_type_decode(
+        "decode_attrib_for_version",
+        reader,
+        |reader| match version {
+            version::DwgVersion::R2010 => {
+                decode_r2010_entity_with_start_and_end_bit_candidates_scored(
+                    reader,
+                    header,
+                    |attempt_reader, object_data_end_bit| {
+                        entities::decode_attrib_r2010(
+                            attempt_reader,
+                            object_data_end_bit,
+                            object_handle,
+                        )
+                    },
+                    score_attrib_entity_candidate,
+                )
+            }
+            version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+                decode_r2010_entity_with_start_and_end_bit_candidates_scored(
+                    reader,
+                    header,
+                    |attempt_reader, object_data_end_bit| {
+                        entities::decode_attrib_r2013(
+                            attempt_reader,
+                            object_data_end_bit,
+                            object_handle,
+                        )
+                    },
+                    score_attrib_entity_candidate,
+                )
+            }
+            version::DwgVersion::R2007 => entities::decode_attrib_r2007(reader),
+            _ => entities::decode_attrib(reader),
+        },
+    )
 }
 
 fn decode_attdef_for_version(
@@ -3810,32 +4369,42 @@ fn decode_attdef_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::AttribEntity> {
-    match version {
-        version::DwgVersion::R2010 => decode_r2010_entity_with_start_and_end_bit_candidates_scored(
-            reader,
-            header,
-            |attempt_reader, object_data_end_bit| {
-                entities::decode_attdef_r2010(attempt_reader, object_data_end_bit, object_handle)
-            },
-            score_attrib_entity_candidate,
-        ),
-        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            decode_r2010_entity_with_start_and_end_bit_candidates_scored(
-                reader,
-                header,
-                |attempt_reader, object_data_end_bit| {
-                    entities::decode_attdef_r2013(
-                        attempt_reader,
-                        object_data_end_bit,
-                        object_handle,
-                    )
-                },
-                score_attrib_entity_candidate,
-            )
-        }
-        version::DwgVersion::R2007 => entities::decode_attdef_r2007(reader),
-        _ => entities::decode_attdef(reader),
-    }
+    time_type_decode(
+        "decode_attdef_for_version",
+        reader,
+        |reader| match version {
+            version::DwgVersion::R2010 => {
+                decode_r2010_entity_with_start_and_end_bit_candidates_scored(
+                    reader,
+                    header,
+                    |attempt_reader, object_data_end_bit| {
+                        entities::decode_attdef_r2010(
+                            attempt_reader,
+                            object_data_end_bit,
+                            object_handle,
+                        )
+                    },
+                    score_attrib_entity_candidate,
+                )
+            }
+            version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+                decode_r2010_entity_with_start_and_end_bit_candidates_scored(
+                    reader,
+                    header,
+                    |attempt_reader, object_data_end_bit| {
+                        entities::decode_attdef_r2013(
+                            attempt_reader,
+                            object_data_end_bit,
+                            object_handle,
+                        )
+                    },
+                    score_attrib_entity_candidate,
+                )
+            }
+            version::DwgVersion::R2007 => entities::decode_attdef_r2007(reader),
+            _ => entities::decode_attdef(reader),
+        },
+    )
 }
 
 fn score_attrib_entity_candidate(entity: &entities::AttribEntity) -> i64 {
@@ -3960,7 +4529,7 @@ fn score_attrib_entity_candidate(entity: &entities::AttribEntity) -> i64 {
     } else {
         -80
     };
-    score += if entity.generation <= 6 && entity.generation % 2 == 0 {
+    score += if entity.generation <= 6 && entity.generation.is_multiple_of(2) {
         8
     } else {
         -24
@@ -4194,7 +4763,7 @@ fn decode_mtext_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::MTextEntity> {
-    match version {
+    time_type_decode("decode_mtext_for_version", reader, |reader| match version {
         version::DwgVersion::R2010 => decode_r2010_entity_with_end_bit_candidates_scored(
             reader,
             header,
@@ -4216,7 +4785,7 @@ fn decode_mtext_for_version(
         version::DwgVersion::R2007 => entities::decode_mtext_r2007(reader),
         version::DwgVersion::R2004 => entities::decode_mtext_r2004(reader),
         _ => entities::decode_mtext(reader),
-    }
+    })
 }
 
 fn score_mtext_entity_candidate(entity: &entities::MTextEntity) -> i64 {
@@ -4300,13 +4869,22 @@ impl_version_dispatch! {
     default: entities::decode_leader;
 }
 
+impl_version_dispatch! {
+    no_r14;
+    fn decode_mleader_for_version -> entities::MLeaderEntity;
+    r2010: entities::decode_mleader_r2010;
+    r2013: entities::decode_mleader_r2013;
+    r2007: entities::decode_mleader_r2007;
+    default: entities::decode_mleader;
+}
+
 fn decode_hatch_for_version(
     reader: &mut BitReader<'_>,
     version: &version::DwgVersion,
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::HatchEntity> {
-    match version {
+    time_type_decode("decode_hatch_for_version", reader, |reader| match version {
         version::DwgVersion::R2010 => {
             let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
             entities::decode_hatch_r2010(reader, object_data_end_bit, object_handle)
@@ -4318,7 +4896,7 @@ fn decode_hatch_for_version(
         version::DwgVersion::R2007 => entities::decode_hatch_r2007(reader),
         version::DwgVersion::R2004 => entities::decode_hatch_r2004(reader),
         _ => entities::decode_hatch(reader),
-    }
+    })
 }
 
 impl_version_dispatch! {
@@ -4465,23 +5043,6 @@ impl_version_dispatch! {
     default: entities::decode_xline;
 }
 
-fn read_handle_reference_chained(
-    reader: &mut BitReader<'_>,
-    prev_handle: &mut u64,
-) -> crate::core::result::Result<u64> {
-    let handle = reader.read_h()?;
-    let absolute = match handle.code {
-        0x06 => prev_handle.saturating_add(1),
-        0x08 => prev_handle.saturating_sub(1),
-        0x0A => prev_handle.saturating_add(handle.value),
-        0x0C => prev_handle.saturating_sub(handle.value),
-        0x02..=0x05 => handle.value,
-        _ => handle.value,
-    };
-    *prev_handle = absolute;
-    Ok(absolute)
-}
-
 fn read_tu(reader: &mut BitReader<'_>) -> crate::core::result::Result<String> {
     reader.read_tu()
 }
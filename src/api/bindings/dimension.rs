@@ -21,7 +21,7 @@ pub fn decode_dim_ordinate_entities(
         0x14,
         "DIM_ORDINATE",
         true,
-        decode_dim_linear_for_version,
+        decode_dim_ordinate_for_version,
     )
 }
 
@@ -63,7 +63,7 @@ pub fn decode_dim_ang3pt_entities(path: &str, limit: Option<usize>) -> PyResult<
         0x17,
         "DIM_ANG3PT",
         true,
-        decode_dim_linear_for_version,
+        decode_dim_ang3pt_for_version,
     )
 }
 
@@ -75,7 +75,7 @@ pub fn decode_dim_ang2ln_entities(path: &str, limit: Option<usize>) -> PyResult<
         0x18,
         "DIM_ANG2LN",
         true,
-        decode_dim_linear_for_version,
+        decode_dim_ang2ln_for_version,
     )
 }
 
@@ -138,7 +138,7 @@ fn decode_dimension_entities_with_state(
             best_effort,
             insert_name_state,
         )? {
-            result.push((dimtype.to_string(), row));
+            result.push((dimtype.into(), row));
             if let Some(limit) = limit {
                 if result.len() >= limit {
                     break;
@@ -160,12 +160,13 @@ fn decode_dimension_typed_row(
     insert_name_state: &InsertNameResolutionState,
 ) -> PyResult<Option<(&'static str, DimEntityRow)>> {
     for spec in DIM_DECODE_SPECS.iter() {
-        if !matches_type_name(
-            header.type_code,
-            spec.type_code,
-            spec.type_name,
-            dynamic_types,
-        ) {
+        let matches = match spec.type_code {
+            Some(type_code) => {
+                matches_type_name(header.type_code, type_code, spec.type_name, dynamic_types)
+            }
+            None => resolved_type_name(header.type_code, dynamic_types) == spec.type_name,
+        };
+        if !matches {
             continue;
         }
 
@@ -178,7 +179,7 @@ fn decode_dimension_typed_row(
             spec.decode_entity,
         ) {
             Ok(entity) => entity,
-            Err(err) if best_effort => return Ok(None),
+            Err(_) if best_effort => return Ok(None),
             Err(err) => return Err(to_py_err(err)),
         };
         entity.common.anonymous_block_handle = recover_dimension_anonymous_block_handle_r2010_plus(
@@ -205,7 +206,7 @@ fn build_ultra_minimal_dim_linear_entity(object_handle: u64) -> entities::DimLin
         handle: object_handle,
         color_index: None,
         true_color: None,
-        layer_handle: 0,
+        layer_handle: None,
         extrusion: (0.0, 0.0, 1.0),
         text_midpoint: (0.0, 0.0, 0.0),
         elevation: 0.0,
@@ -230,6 +231,14 @@ fn build_ultra_minimal_dim_linear_entity(object_handle: u64) -> entities::DimLin
         point10: (0.0, 0.0, 0.0),
         ext_line_rotation: 0.0,
         dim_rotation: 0.0,
+        point15: None,
+        point16: None,
+        ordinate_x_type: None,
+        point17: None,
+        is_partial: None,
+        arc_params: None,
+        has_leader: None,
+        leader_length: None,
     }
 }
 
@@ -305,7 +314,7 @@ fn decode_dim_linear_like_entity_minimal_for_version(
     let dimstyle_handle = read_optional_handle(reader);
     let anonymous_block_handle = read_optional_handle(reader);
     let layer_handle =
-        entities::common::parse_common_entity_layer_handle(reader, &common_header).unwrap_or(0);
+        entities::common::parse_common_entity_layer_handle(reader, &common_header).ok();
 
     let common = entities::DimensionCommonData {
         handle: common_header.handle,
@@ -337,6 +346,14 @@ fn decode_dim_linear_like_entity_minimal_for_version(
         point10: (0.0, 0.0, 0.0),
         ext_line_rotation: 0.0,
         dim_rotation: 0.0,
+        point15: None,
+        point16: None,
+        ordinate_x_type: None,
+        point17: None,
+        is_partial: None,
+        arc_params: None,
+        has_leader: None,
+        leader_length: None,
     })
 }
 
@@ -513,6 +530,16 @@ fn dim_entity_row_from_linear_like(entity: &entities::DimLinearEntity) -> DimEnt
             common.insert_rotation,
         ),
         (common.dimstyle_handle, common.anonymous_block_handle),
+        (
+            entity.point15,
+            entity.point16,
+            entity.ordinate_x_type,
+            entity.point17,
+            entity.is_partial,
+            entity.arc_params,
+            entity.has_leader,
+            entity.leader_length,
+        ),
     )
 }
 
@@ -543,6 +570,101 @@ impl_version_dispatch! {
     default: entities::decode_dim_diameter;
 }
 
+impl_version_dispatch! {
+    no_r14;
+    fn decode_dim_ordinate_entity_for_version -> entities::DimOrdinateEntity;
+    r2010: entities::decode_dim_ordinate_r2010;
+    r2013: entities::decode_dim_ordinate_r2013;
+    r2007: entities::decode_dim_ordinate_r2007;
+    default: entities::decode_dim_ordinate;
+}
+
+impl_version_dispatch! {
+    no_r14;
+    fn decode_dim_ang3pt_entity_for_version -> entities::DimAng3PtEntity;
+    r2010: entities::decode_dim_ang3pt_r2010;
+    r2013: entities::decode_dim_ang3pt_r2013;
+    r2007: entities::decode_dim_ang3pt_r2007;
+    default: entities::decode_dim_ang3pt;
+}
+
+impl_version_dispatch! {
+    no_r14;
+    fn decode_dim_ang2ln_entity_for_version -> entities::DimAng2LnEntity;
+    r2010: entities::decode_dim_ang2ln_r2010;
+    r2013: entities::decode_dim_ang2ln_r2013;
+    r2007: entities::decode_dim_ang2ln_r2007;
+    default: entities::decode_dim_ang2ln;
+}
+
+impl_version_dispatch! {
+    no_r14;
+    fn decode_dim_arc_entity_for_version -> entities::DimArcEntity;
+    r2010: entities::decode_dim_arc_r2010;
+    r2013: entities::decode_dim_arc_r2013;
+    r2007: entities::decode_dim_arc_r2007;
+    default: entities::decode_dim_arc;
+}
+
+impl_version_dispatch! {
+    no_r14;
+    fn decode_dim_large_radial_entity_for_version -> entities::DimLargeRadialEntity;
+    r2010: entities::decode_dim_large_radial_r2010;
+    r2013: entities::decode_dim_large_radial_r2013;
+    r2007: entities::decode_dim_large_radial_r2007;
+    default: entities::decode_dim_large_radial;
+}
+
+fn decode_dim_ordinate_for_version(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+    object_handle: u64,
+) -> crate::core::result::Result<entities::DimLinearEntity> {
+    decode_dim_ordinate_entity_for_version(reader, version, header, object_handle)
+        .map(|entity| entity.to_linear_like())
+}
+
+fn decode_dim_ang3pt_for_version(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+    object_handle: u64,
+) -> crate::core::result::Result<entities::DimLinearEntity> {
+    decode_dim_ang3pt_entity_for_version(reader, version, header, object_handle)
+        .map(|entity| entity.to_linear_like())
+}
+
+fn decode_dim_ang2ln_for_version(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+    object_handle: u64,
+) -> crate::core::result::Result<entities::DimLinearEntity> {
+    decode_dim_ang2ln_entity_for_version(reader, version, header, object_handle)
+        .map(|entity| entity.to_linear_like())
+}
+
+fn decode_dim_arc_for_version(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+    object_handle: u64,
+) -> crate::core::result::Result<entities::DimLinearEntity> {
+    decode_dim_arc_entity_for_version(reader, version, header, object_handle)
+        .map(|entity| entity.to_linear_like())
+}
+
+fn decode_dim_large_radial_for_version(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+    header: &ApiObjectHeader,
+    object_handle: u64,
+) -> crate::core::result::Result<entities::DimLinearEntity> {
+    decode_dim_large_radial_entity_for_version(reader, version, header, object_handle)
+        .map(|entity| entity.to_linear_like())
+}
+
 fn recover_dimension_anonymous_block_handle_r2010_plus(
     record: &objects::ObjectRecord<'_>,
     version: &version::DwgVersion,
@@ -702,7 +824,7 @@ fn recover_dimension_anonymous_block_handle_r2010_plus(
                 let mut prev_handle = base_handle;
                 for index in 0..256u64 {
                     let candidate = if chained_base {
-                        match read_handle_reference_chained(&mut reader, &mut prev_handle) {
+                        match entities::common::read_chained_handle_reference(&mut reader, &mut prev_handle) {
                             Ok(value) => value,
                             Err(_) => break,
                         }
@@ -779,10 +901,12 @@ fn dimension_block_name_penalty(
 #[cfg(test)]
 mod dimension_tests {
     use super::{
-        dimension_text_plausibility_penalty, is_implausible_dimension_candidate,
-        IMPLAUSIBLE_DIMENSION_CANDIDATE_SCORE,
+        dim_entity_row_from_linear_like, dimension_text_plausibility_penalty,
+        is_implausible_dimension_candidate, IMPLAUSIBLE_DIMENSION_CANDIDATE_SCORE,
+    };
+    use crate::entities::{
+        DimArcEntity, DimLargeRadialEntity, DimLinearEntity, DimensionCommonData,
     };
-    use crate::entities::{DimLinearEntity, DimensionCommonData};
 
     fn base_entity() -> DimLinearEntity {
         DimLinearEntity {
@@ -790,7 +914,7 @@ mod dimension_tests {
                 handle: 1,
                 color_index: None,
                 true_color: None,
-                layer_handle: 0,
+                layer_handle: None,
                 extrusion: (0.0, 0.0, 1.0),
                 text_midpoint: (10.0, 10.0, 0.0),
                 elevation: 0.0,
@@ -813,6 +937,14 @@ mod dimension_tests {
             point10: (20.0, 5.0, 0.0),
             ext_line_rotation: 0.0,
             dim_rotation: 0.0,
+            point15: None,
+            point16: None,
+            ordinate_x_type: None,
+            point17: None,
+            is_partial: None,
+            arc_params: None,
+            has_leader: None,
+            leader_length: None,
         }
     }
 
@@ -835,4 +967,66 @@ mod dimension_tests {
         entity.common.user_text = "bad\ntext".to_string();
         assert!(is_implausible_dimension_candidate(&entity));
     }
+
+    #[test]
+    fn arc_dimension_rows_keep_the_leader_and_arc_fields() {
+        let entity = DimArcEntity {
+            common: base_entity().common,
+            point10: (0.0, 12.0, 0.0),
+            point13: (10.0, 0.0, 0.0),
+            point14: (0.0, 10.0, 0.0),
+            point15: (0.0, 0.0, 0.0),
+            is_partial: true,
+            arc_start_param: 0.25,
+            arc_end_param: 1.5,
+            has_leader: true,
+            point16: (3.0, 11.0, 0.0),
+            point17: (5.0, 15.0, 0.0),
+        };
+
+        let row = dim_entity_row_from_linear_like(&entity.to_linear_like());
+
+        assert_eq!(
+            row.11,
+            (
+                Some((0.0, 0.0, 0.0)),
+                Some((3.0, 11.0, 0.0)),
+                None,
+                Some((5.0, 15.0, 0.0)),
+                Some(true),
+                Some((0.25, 1.5)),
+                Some(true),
+                None,
+            )
+        );
+    }
+
+    #[test]
+    fn large_radial_dimension_rows_keep_the_leader_length() {
+        let entity = DimLargeRadialEntity {
+            common: base_entity().common,
+            point10: (0.0, 0.0, 0.0),
+            point15: (30.0, 40.0, 0.0),
+            leader_length: 7.5,
+            override_center: (3.0, 4.0, 0.0),
+            jog_point: (12.0, 16.0, 0.0),
+        };
+
+        let row = dim_entity_row_from_linear_like(&entity.to_linear_like());
+
+        assert_eq!((row.3, row.4), ((3.0, 4.0, 0.0), (12.0, 16.0, 0.0)));
+        assert_eq!(
+            row.11,
+            (
+                Some((30.0, 40.0, 0.0)),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(7.5),
+            )
+        );
+    }
 }
@@ -0,0 +1,113 @@
+// Project-wide index of block names, xref paths and text. Each drawing is
+// read on a worker thread with the GIL released, so indexing thousands of
+// files scales with the number of cores instead of running one at a time.
+
+/// Indexes `paths` on `threads` worker threads (default: one per core).
+///
+/// Each row is `(path, version, blocks, xrefs, texts, error)`, in the order
+/// of `paths`. `blocks` holds the sorted named (non-`*`) block names,
+/// `xrefs` the rows of `decode_xref_blocks` and `texts` the distinct
+/// TEXT, MTEXT and ATTRIB strings. A file that fails to read keeps its row
+/// with empty lists and the error message.
+#[pyfunction(signature = (paths, threads=None))]
+pub fn build_drawing_index(
+    py: Python<'_>,
+    paths: Vec<String>,
+    threads: Option<usize>,
+) -> PyResult<Vec<DrawingIndexRow>> {
+    if threads == Some(0) {
+        return Err(PyValueError::new_err("threads must be at least 1"));
+    }
+    let threads = threads
+        .or_else(|| std::thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1)
+        .min(paths.len())
+        .max(1);
+    let results = py.allow_threads(|| {
+        let next = AtomicUsize::new(0);
+        let mut results: Vec<(usize, PyResult<DrawingContents>)> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let position = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(path) = paths.get(position) else {
+                                break;
+                            };
+                            done.push((position, index_drawing(path)));
+                        }
+                        done
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap_or_default())
+                .collect()
+        });
+        results.sort_by_key(|(position, _)| *position);
+        results
+    });
+    if results.len() != paths.len() {
+        return Err(PyRuntimeError::new_err("drawing index worker panicked"));
+    }
+    Ok(paths
+        .into_iter()
+        .zip(results)
+        .map(|(path, (_, contents))| match contents {
+            Ok((version, blocks, xrefs, texts)) => (path, version, blocks, xrefs, texts, None),
+            Err(err) => (
+                path,
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Some(err.to_string()),
+            ),
+        })
+        .collect())
+}
+
+type DrawingContents = (String, Vec<String>, Vec<XrefBlockRow>, Vec<String>);
+
+fn index_drawing(path: &str) -> PyResult<DrawingContents> {
+    let version = detect_version(path)?;
+    let block_names: HashMap<u64, InternedName> =
+        decode_block_header_names(path, None)?.into_iter().collect();
+    let mut blocks: Vec<String> = block_names
+        .values()
+        .filter(|name| !name.is_empty() && !name.starts_with('*'))
+        .map(|name| name.to_string())
+        .collect();
+    blocks.sort();
+    blocks.dedup();
+
+    let mut xrefs = decode_xref_blocks(path, None)?;
+    for (handle, name, _path, _overlaid) in xrefs.iter_mut() {
+        if name.is_empty() {
+            if let Some(known) = block_names.get(handle) {
+                *name = known.to_string();
+            }
+        }
+    }
+
+    let mut texts: Vec<String> = decode_text_entities(path, None)?
+        .into_iter()
+        .map(|row| row.1)
+        .chain(
+            decode_mtext_entities(path, None)?
+                .into_iter()
+                .map(|row| row.1),
+        )
+        .chain(
+            decode_attrib_entities(path, None)?
+                .into_iter()
+                .map(|row| row.1),
+        )
+        .filter(|text| !text.trim().is_empty())
+        .collect();
+    texts.sort();
+    texts.dedup();
+    Ok((version, blocks, xrefs, texts))
+}
@@ -1,5 +1,27 @@
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_layer_colors(path: &str, limit: Option<usize>) -> PyResult<Vec<LayerColorRow>> {
+    Ok(decode_layer_color_records(path, limit)?
+        .into_iter()
+        .map(|record| (record.handle, record.color_index, record.true_color))
+        .collect())
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_layer_states(path: &str, limit: Option<usize>) -> PyResult<Vec<LayerStateRow>> {
+    Ok(decode_layer_color_records(path, None)?
+        .into_iter()
+        .filter_map(|record| {
+            let flags = record.flags?;
+            Some((record.handle, objects::layer_dxf_flags(flags), record.is_off))
+        })
+        .take(limit.unwrap_or(usize::MAX))
+        .collect())
+}
+
+fn decode_layer_color_records(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<LayerColorRecord>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
     let decoder = build_decoder(&bytes).map_err(to_py_err)?;
     let best_effort = is_best_effort_compat_version(&decoder);
@@ -23,13 +45,58 @@ pub fn decode_layer_colors(path: &str, limit: Option<usize>) -> PyResult<Vec<Lay
             }
             return Err(to_py_err(err));
         }
-        let (handle, color_index, true_color) =
+        let record =
             match decode_layer_color_record(&mut reader, decoder.version(), obj.handle.0) {
                 Ok(decoded) => decoded,
                 Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
                 Err(err) => return Err(to_py_err(err)),
             };
-        result.push((handle, color_index, true_color));
+        result.push(record);
+        if let Some(limit) = limit {
+            if result.len() >= limit {
+                break;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_layer_transparency(
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<LayerTransparencyRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+    let mut result = Vec::new();
+
+    for obj in index.objects.iter() {
+        let Some((record, header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        if !matches_type_name(header.type_code, 0x33, "LAYER", &dynamic_types) {
+            continue;
+        }
+
+        let mut reader = record.bit_reader();
+        if let Err(err) = skip_object_type_prefix(&mut reader, decoder.version()) {
+            if best_effort {
+                continue;
+            }
+            return Err(to_py_err(err));
+        }
+        let transparency = match decode_layer_transparency_record(&mut reader, decoder.version())
+        {
+            Ok(transparency) => transparency,
+            Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        };
+        result.push((obj.handle.0, transparency));
         if let Some(limit) = limit {
             if result.len() >= limit {
                 break;
@@ -40,6 +107,22 @@ pub fn decode_layer_colors(path: &str, limit: Option<usize>) -> PyResult<Vec<Lay
     Ok(result)
 }
 
+fn decode_layer_transparency_record(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+) -> crate::core::result::Result<Option<u32>> {
+    // R13/R14 store ObjSize after the extended data, which is all this
+    // reads; R2010+ keep it in the object header.
+    if matches!(
+        version,
+        version::DwgVersion::R2000 | version::DwgVersion::R2004 | version::DwgVersion::R2007
+    ) {
+        let _obj_size = reader.read_rl(Endian::Little)?;
+    }
+    let _record_handle = reader.read_h()?;
+    entities::common::read_eed_transparency(reader)
+}
+
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_layer_names(path: &str, limit: Option<usize>) -> PyResult<Vec<LayerNameRow>> {
     let bytes = file_open::read_file(path).map_err(to_py_err)?;
@@ -99,6 +182,63 @@ pub fn decode_layer_names(path: &str, limit: Option<usize>) -> PyResult<Vec<Laye
     Ok(result)
 }
 
+/// Maps the layer handle read from an entity record to the handle of its
+/// LAYER row. R2010+ layer references are rescanned against the known
+/// layers, and raw LAYER handles are remapped onto those `decode_layer_colors`
+/// reports when both lists line up.
+struct EntityLayerResolver {
+    remap: HashMap<u64, u64>,
+    known: HashSet<u64>,
+}
+
+impl EntityLayerResolver {
+    fn load(
+        path: &str,
+        decoder: &decoder::Decoder<'_>,
+        dynamic_types: &HashMap<u16, String>,
+        index: &objects::ObjectIndex,
+        best_effort: bool,
+    ) -> PyResult<Self> {
+        let decoded_layer_handles: Vec<u64> = decode_layer_colors(path, None)?
+            .iter()
+            .map(|(handle, _, _)| *handle)
+            .collect();
+        let raw_layer_handles =
+            collect_known_layer_handles_in_order(decoder, dynamic_types, index, best_effort)?;
+        let mut remap = HashMap::new();
+        if raw_layer_handles.len() == decoded_layer_handles.len() {
+            remap.extend(
+                raw_layer_handles
+                    .iter()
+                    .copied()
+                    .zip(decoded_layer_handles.iter().copied()),
+            );
+        }
+        let mut known: HashSet<u64> = decoded_layer_handles.into_iter().collect();
+        known.extend(raw_layer_handles);
+        Ok(Self { remap, known })
+    }
+
+    fn resolve(
+        &self,
+        record: &objects::ObjectRecord<'_>,
+        version: &version::DwgVersion,
+        header: &ApiObjectHeader,
+        object_handle: u64,
+        parsed_layer_handle: u64,
+    ) -> u64 {
+        let layer_handle = recover_entity_layer_handle_r2010_plus(
+            record,
+            version,
+            header,
+            object_handle,
+            parsed_layer_handle,
+            &self.known,
+        );
+        self.remap.get(&layer_handle).copied().unwrap_or(layer_handle)
+    }
+}
+
 fn collect_known_layer_handles_in_order(
     decoder: &decoder::Decoder<'_>,
     dynamic_types: &HashMap<u16, String>,
@@ -125,6 +265,36 @@ fn recover_entity_layer_handle_r2010_plus(
     object_handle: u64,
     parsed_layer_handle: u64,
     known_layer_handles: &HashSet<u64>,
+) -> u64 {
+    let layer_handle = time_heuristic("entity_layer_handle_scan", record.raw.len(), || {
+        scan_entity_layer_handle_r2010_plus(
+            record,
+            version,
+            api_header,
+            object_handle,
+            parsed_layer_handle,
+            known_layer_handles,
+        )
+    });
+    if layer_handle != parsed_layer_handle {
+        note_recovered_field(
+            object_handle,
+            "layer_handle",
+            RecoveredValue::Handle(layer_handle),
+            (parsed_layer_handle != 0).then_some(RecoveredValue::Handle(parsed_layer_handle)),
+            "handle_stream_scan",
+        );
+    }
+    layer_handle
+}
+
+fn scan_entity_layer_handle_r2010_plus(
+    record: &objects::ObjectRecord<'_>,
+    version: &version::DwgVersion,
+    api_header: &ApiObjectHeader,
+    object_handle: u64,
+    parsed_layer_handle: u64,
+    known_layer_handles: &HashSet<u64>,
 ) -> u64 {
     if !matches!(
         version,
@@ -255,7 +425,7 @@ fn recover_entity_layer_handle_r2010_plus(
                 let mut handle_index = 0u64;
                 while handle_index < 64 {
                     let layer_handle = if chained_base {
-                        match read_handle_reference_chained(&mut reader, &mut prev_handle) {
+                        match entities::common::read_chained_handle_reference(&mut reader, &mut prev_handle) {
                             Ok(handle) => handle,
                             Err(_) => break,
                         }
@@ -521,40 +691,57 @@ fn layer_handle_candidate_score(
     score
 }
 
+/// Color and state of one LAYER table entry.
+struct LayerColorRecord {
+    handle: u64,
+    color_index: u16,
+    true_color: Option<u32>,
+    /// `objects::layer::LAYER_*` bits; `None` from R2007 on, where the
+    /// entry is only located heuristically.
+    flags: Option<u16>,
+    /// Set by a negative color index or the off bit of `flags`.
+    is_off: bool,
+}
+
 fn decode_layer_color_record(
     reader: &mut BitReader<'_>,
     version: &version::DwgVersion,
     expected_handle: u64,
-) -> crate::core::result::Result<(u64, u16, Option<u32>)> {
-    // R2010+/R2013 objects start with handle directly after OT prefix.
-    // Older versions keep ObjSize (RL) before handle.
+) -> crate::core::result::Result<LayerColorRecord> {
+    let handle = objects::parse_common_object_prologue(reader, version)?.handle_or(expected_handle);
+    // R2010+ stores entry name in string stream. The data stream directly
+    // continues with layer state flags and color data.
     if !matches!(
         version,
         version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018
     ) {
-        let _obj_size = reader.read_rl(Endian::Little)?;
+        let _entry_name = reader.read_tv()?;
     }
-    let record_handle = reader.read_h()?.value;
-    skip_eed(reader)?;
-
-    let _num_reactors = reader.read_bl()?;
-    let _xdic_missing_flag = reader.read_b()?;
     if matches!(
         version,
-        version::DwgVersion::R2013 | version::DwgVersion::R2018
+        version::DwgVersion::R14 | version::DwgVersion::R2000
     ) {
-        let _has_ds_binary_data = reader.read_b()?;
-    }
-    // R2010+ stores entry name in string stream. The data stream directly
-    // continues with layer state flags and color data.
-    if !matches!(
-        version,
-        version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018
-    ) {
-        let _entry_name = reader.read_tv()?;
+        let (flags, color) = decode_layer_color_r2000(reader, version)?;
+        return Ok(LayerColorRecord {
+            handle,
+            color_index: color.unsigned_abs(),
+            true_color: None,
+            flags: Some(flags),
+            is_off: color < 0 || flags & objects::layer::LAYER_OFF != 0,
+        });
     }
 
     let style_start = reader.get_pos();
+    // R2004 stores the flags word right after the common entry fields; the
+    // color parse below tries shifted layouts.
+    let flags = if matches!(version, version::DwgVersion::R2004) {
+        let _flag_64 = reader.read_b()?;
+        let _xref_index_plus_one = reader.read_bs()?;
+        let _xdep = reader.read_b()?;
+        Some(reader.read_bs()?)
+    } else {
+        None
+    };
     let variants = [
         LayerColorParseVariant {
             pre_flag_bits: 0,
@@ -598,6 +785,14 @@ fn decode_layer_color_record(
         },
     ];
 
+    let record = |(color_index, true_color): (u16, Option<u32>)| LayerColorRecord {
+        handle,
+        color_index,
+        true_color,
+        flags,
+        is_off: flags.is_some_and(|flags| flags & objects::layer::LAYER_OFF != 0),
+    };
+
     let mut best: Option<(u64, (u16, Option<u32>))> = None;
     for variant in variants {
         reader.set_pos(style_start.0, style_start.1);
@@ -612,24 +807,14 @@ fn decode_layer_color_record(
         }
     }
 
-    if let Some((_, (color_index, true_color))) = best {
-        let handle = if record_handle != 0 {
-            record_handle
-        } else {
-            expected_handle
-        };
-        return Ok((handle, color_index, true_color));
+    if let Some((_, color)) = best {
+        return Ok(record(color));
     }
 
     // Last resort: parse in the simplest form to keep progress.
     reader.set_pos(style_start.0, style_start.1);
     let (color_index, true_color, _) = decode_layer_color_cmc(reader, variants[0])?;
-    let handle = if record_handle != 0 {
-        record_handle
-    } else {
-        expected_handle
-    };
-    Ok((handle, color_index, true_color))
+    Ok(record((color_index, true_color)))
 }
 
 fn decode_layer_name_record(
@@ -640,23 +825,7 @@ fn decode_layer_name_record(
 ) -> crate::core::result::Result<(u64, String)> {
     let mut reader = record.bit_reader();
     skip_object_type_prefix(&mut reader, version)?;
-    if !matches!(
-        version,
-        version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018
-    ) {
-        let _obj_size = reader.read_rl(Endian::Little)?;
-    }
-    let record_handle = reader.read_h()?.value;
-    skip_eed(&mut reader)?;
-
-    let _num_reactors = reader.read_bl()?;
-    let _xdic_missing_flag = reader.read_b()?;
-    if matches!(
-        version,
-        version::DwgVersion::R2013 | version::DwgVersion::R2018
-    ) {
-        let _has_ds_binary_data = reader.read_b()?;
-    }
+    let handle = objects::parse_common_object_prologue(&mut reader, version)?.handle_or(expected_handle);
 
     let name = if matches!(
         version,
@@ -667,11 +836,6 @@ fn decode_layer_name_record(
         reader.read_tv()?
     };
 
-    let handle = if record_handle != 0 {
-        record_handle
-    } else {
-        expected_handle
-    };
     Ok((handle, name))
 }
 
@@ -839,8 +1003,8 @@ fn scan_shifted_utf16_layer_name_candidates(raw: &[u8], best: &mut Option<(u64,
             while index + 6 <= shifted.len() {
                 let mut cursor = index;
                 let mut units = Vec::new();
-                while cursor + 1 < shifted.len() {
-                    let code = u16::from_le_bytes([shifted[cursor], shifted[cursor + 1]]);
+                while let Some(&[low, high]) = shifted.get(cursor..cursor + 2) {
+                    let code = u16::from_le_bytes([low, high]);
                     if code == 0 {
                         break;
                     }
@@ -881,13 +1045,14 @@ fn shift_bits_bytes(raw: &[u8], shift: u8) -> Vec<u8> {
     if shift == 0 {
         return raw.to_vec();
     }
-    let mut out = vec![0u8; raw.len()];
     let mut carry = 0u8;
-    for (index, value) in raw.iter().copied().enumerate() {
-        out[index] = ((value >> shift) | carry) & 0xFF;
-        carry = value.wrapping_shl((8 - shift) as u32);
-    }
-    out
+    raw.iter()
+        .map(|&value| {
+            let out = (value >> shift) | carry;
+            carry = value.wrapping_shl((8 - shift) as u32);
+            out
+        })
+        .collect()
 }
 
 fn is_plausible_layer_name_fragment_char(ch: char) -> bool {
@@ -959,7 +1124,7 @@ fn shifted_utf16_layer_name_candidate_penalty(name: &str, _shift: u8) -> u64 {
     let mut score = 0u64;
     let char_count = name.chars().count();
     let has_ascii_alpha = name.chars().any(|ch| ch.is_ascii_alphabetic());
-    let has_non_ascii = name.chars().any(|ch| !ch.is_ascii());
+    let has_non_ascii = !name.is_ascii();
     let has_separator = name
         .chars()
         .any(|ch| matches!(ch, '_' | '-' | '.' | '$' | '*' | ' ' | '/' | '(' | ')' | '[' | ']'));
@@ -1198,6 +1363,23 @@ mod layer_name_tests {
     }
 }
 
+/// R13-R2000 layers store the color as a plain index, negated while the
+/// layer is off. Returns the flags and the signed color.
+fn decode_layer_color_r2000(
+    reader: &mut BitReader<'_>,
+    version: &version::DwgVersion,
+) -> crate::core::result::Result<(u16, i16)> {
+    let _flag_64 = reader.read_b()?;
+    let _xref_index_plus_one = reader.read_bs()?;
+    let _xdep = reader.read_b()?;
+    let flags = if matches!(version, version::DwgVersion::R14) {
+        objects::read_r14_layer_flags(reader)?
+    } else {
+        reader.read_bs()?
+    };
+    Ok((flags, reader.read_bs()? as i16))
+}
+
 fn decode_layer_color_cmc(
     reader: &mut BitReader<'_>,
     variant: LayerColorParseVariant,
@@ -0,0 +1,112 @@
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_layouts(path: &str, limit: Option<usize>) -> PyResult<Vec<LayoutRow>> {
+    collect_object_rows(path, limit, "LAYOUT", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mut layout = objects::decode_layout(&mut reader, version, handle)?;
+        if layout.name.is_none() {
+            let strings = read_object_string_stream(
+                record,
+                header,
+                version,
+                objects::LayoutObject::STRING_STREAM_COUNT,
+                objects::LayoutObject::STRING_STREAM_COUNT - 1,
+            )
+            .unwrap_or_default();
+            layout.apply_string_stream(&strings);
+        }
+        Ok((
+            layout.handle,
+            layout.name,
+            layout.tab_order,
+            layout.flags,
+            (layout.limits_min, layout.limits_max),
+            (layout.extents_min, layout.extents_max),
+            plot_settings_row(layout.plot_settings),
+        ))
+    })
+}
+
+fn plot_settings_row(settings: objects::PlotSettings) -> PlotSettingsRow {
+    let [left, bottom, right, top] = settings.margins;
+    (
+        (
+            settings.page_setup_name,
+            settings.printer_name,
+            settings.paper_size,
+            settings.plot_style_table,
+        ),
+        settings.flags,
+        (settings.paper_width, settings.paper_height),
+        (left, bottom, right, top),
+        settings.plot_origin,
+        settings.paper_units,
+        settings.rotation,
+        settings.plot_type,
+        (settings.window_min, settings.window_max),
+        (
+            settings.real_world_units,
+            settings.drawing_units,
+            settings.scale_type,
+            settings.scale_factor,
+        ),
+    )
+}
+
+fn plot_settings_from_row(row: PlotSettingsRow) -> objects::PlotSettings {
+    let (
+        (page_setup_name, printer_name, paper_size, plot_style_table),
+        flags,
+        (paper_width, paper_height),
+        (left, bottom, right, top),
+        plot_origin,
+        paper_units,
+        rotation,
+        plot_type,
+        (window_min, window_max),
+        (real_world_units, drawing_units, scale_type, scale_factor),
+    ) = row;
+    objects::PlotSettings {
+        page_setup_name,
+        printer_name,
+        flags,
+        margins: [left, bottom, right, top],
+        paper_width,
+        paper_height,
+        paper_size,
+        plot_origin,
+        paper_units,
+        rotation,
+        plot_type,
+        window_min,
+        window_max,
+        real_world_units,
+        drawing_units,
+        plot_style_table,
+        scale_type,
+        scale_factor,
+    }
+}
+
+fn writer_layout_from_row(row: LayoutWriteRow) -> writer::LayoutDef {
+    let (name, settings, viewports) = row;
+    let mut layout = writer::LayoutDef::new(name, plot_settings_from_row(settings));
+    for (handle, center, width, height, view_center, view_height) in viewports {
+        layout.viewports.push(writer::ViewportEntity {
+            common: writer::CommonEntityProps {
+                handle: row_handle(handle),
+                layer_name: "0".to_string(),
+                color_index: Some(7),
+                true_color: None,
+                ..writer::CommonEntityProps::default()
+            },
+            center,
+            width,
+            height,
+            view_center,
+            view_height,
+            ..writer::ViewportEntity::default()
+        });
+    }
+    layout
+}
@@ -329,9 +329,7 @@ pub fn decode_polyline_3d_with_vertices(
     let mut result = Vec::with_capacity(decoded_rows.len());
     for row in decoded_rows {
         let mut vertices: Vec<Point3> = row.vertices.iter().map(|vertex| vertex.position).collect();
-        if row.closed && vertices.len() > 1 {
-            let first = vertices[0];
-            let last = *vertices.last().unwrap();
+        if let (true, &[first, .., last]) = (row.closed, vertices.as_slice()) {
             if !points_equal_3d(first, last) {
                 vertices.push(first);
             }
@@ -356,9 +354,9 @@ fn decode_polyline_3d_vertex_rows(
     let vertex_map = build_vertex_3d_map(&decoder, &sorted, &dynamic_types, best_effort)?;
     let mut result = Vec::new();
     let mut i = 0usize;
-    while i < sorted.len() {
+    while let Some(current) = sorted.get(i) {
         let Some((record, header)) =
-            parse_record_and_header(&decoder, sorted[i].offset, best_effort)?
+            parse_record_and_header(&decoder, current.offset, best_effort)?
         else {
             i += 1;
             continue;
@@ -380,10 +378,10 @@ fn decode_polyline_3d_vertex_rows(
             &mut reader,
             decoder.version(),
             &header,
-            sorted[i].handle.0,
+            current.handle.0,
         ) {
             Ok(poly) => poly,
-            Err(err) if best_effort => {
+            Err(_) if best_effort => {
                 i += 1;
                 continue;
             }
@@ -444,7 +442,7 @@ fn build_vertex_3d_map(
             obj.handle.0,
         ) {
             Ok(vertex) => vertex,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         vertex_map.insert(vertex.handle, vertex);
@@ -463,19 +461,17 @@ fn collect_polyline_3d_vertices(
 ) -> PyResult<(Vec<entities::Vertex3dEntity>, usize)> {
     let mut vertices = Vec::new();
 
-    if !poly.owned_handles.is_empty() {
-        for handle in &poly.owned_handles {
-            if let Some(vertex) = vertex_map.get(handle) {
-                vertices.push(vertex.clone());
-            }
-        }
-        return Ok((vertices, start_index + 1));
+    if let Some(owned_vertices) =
+        owned_sequence_children("POLYLINE_3D", poly.handle, &poly.owned_handles, vertex_map)
+    {
+        return Ok((owned_vertices, start_index + 1));
     }
 
     let mut next_i = start_index + 1;
-    while next_i < sorted.len() {
+    let mut found_seqend = false;
+    while let Some(next) = sorted.get(next_i) {
         let Some((next_record, next_header)) =
-            parse_record_and_header(decoder, sorted[next_i].offset, best_effort)?
+            parse_record_and_header(decoder, next.offset, best_effort)?
         else {
             next_i += 1;
             continue;
@@ -493,10 +489,10 @@ fn collect_polyline_3d_vertices(
                 &mut next_reader,
                 decoder.version(),
                 &next_header,
-                sorted[next_i].handle.0,
+                next.handle.0,
             ) {
                 Ok(vertex) => vertex,
-                Err(err) if best_effort => {
+                Err(_) if best_effort => {
                     next_i += 1;
                     continue;
                 }
@@ -519,11 +515,20 @@ fn collect_polyline_3d_vertices(
                     return Err(to_py_err(err));
                 }
             }
+            found_seqend = true;
             next_i += 1;
         }
         break;
     }
 
+    if !found_seqend {
+        let adjacent = collect_handle_adjacent_children(poly.handle, vertex_map);
+        report_sequence_resync("POLYLINE_3D", poly.handle, "SEQEND not reached", adjacent.len());
+        if adjacent.len() > vertices.len() {
+            return Ok((adjacent, start_index + 1));
+        }
+    }
+
     Ok((vertices, next_i))
 }
 
@@ -584,9 +589,7 @@ pub fn decode_polyline_mesh_with_vertices(
     let mut result = Vec::with_capacity(decoded_rows.len());
     for row in decoded_rows {
         let mut vertices: Vec<Point3> = row.vertices.iter().map(|vertex| vertex.position).collect();
-        if row.closed && vertices.len() > 1 {
-            let first = vertices[0];
-            let last = *vertices.last().unwrap();
+        if let (true, &[first, .., last]) = (row.closed, vertices.as_slice()) {
             if !points_equal_3d(first, last) {
                 vertices.push(first);
             }
@@ -618,9 +621,9 @@ fn decode_polyline_mesh_vertex_rows(
     let vertex_map = build_vertex_mesh_map(&decoder, &sorted, &dynamic_types, best_effort)?;
     let mut result = Vec::new();
     let mut i = 0usize;
-    while i < sorted.len() {
+    while let Some(current) = sorted.get(i) {
         let Some((record, header)) =
-            parse_record_and_header(&decoder, sorted[i].offset, best_effort)?
+            parse_record_and_header(&decoder, current.offset, best_effort)?
         else {
             i += 1;
             continue;
@@ -642,10 +645,10 @@ fn decode_polyline_mesh_vertex_rows(
             &mut reader,
             decoder.version(),
             &header,
-            sorted[i].handle.0,
+            current.handle.0,
         ) {
             Ok(poly) => poly,
-            Err(err) if best_effort => {
+            Err(_) if best_effort => {
                 i += 1;
                 continue;
             }
@@ -708,7 +711,7 @@ fn build_vertex_mesh_map(
             obj.handle.0,
         ) {
             Ok(vertex) => vertex,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         vertex_map.insert(vertex.handle, vertex);
@@ -727,19 +730,17 @@ fn collect_polyline_mesh_vertices(
 ) -> PyResult<(Vec<entities::Vertex3dEntity>, usize)> {
     let mut vertices = Vec::new();
 
-    if !poly.owned_handles.is_empty() {
-        for handle in &poly.owned_handles {
-            if let Some(vertex) = vertex_map.get(handle) {
-                vertices.push(vertex.clone());
-            }
-        }
-        return Ok((vertices, start_index + 1));
+    if let Some(owned_vertices) =
+        owned_sequence_children("POLYLINE_MESH", poly.handle, &poly.owned_handles, vertex_map)
+    {
+        return Ok((owned_vertices, start_index + 1));
     }
 
     let mut next_i = start_index + 1;
-    while next_i < sorted.len() {
+    let mut found_seqend = false;
+    while let Some(next) = sorted.get(next_i) {
         let Some((next_record, next_header)) =
-            parse_record_and_header(decoder, sorted[next_i].offset, best_effort)?
+            parse_record_and_header(decoder, next.offset, best_effort)?
         else {
             next_i += 1;
             continue;
@@ -757,10 +758,10 @@ fn collect_polyline_mesh_vertices(
                 &mut next_reader,
                 decoder.version(),
                 &next_header,
-                sorted[next_i].handle.0,
+                next.handle.0,
             ) {
                 Ok(vertex) => vertex,
-                Err(err) if best_effort => {
+                Err(_) if best_effort => {
                     next_i += 1;
                     continue;
                 }
@@ -783,11 +784,20 @@ fn collect_polyline_mesh_vertices(
                     return Err(to_py_err(err));
                 }
             }
+            found_seqend = true;
             next_i += 1;
         }
         break;
     }
 
+    if !found_seqend {
+        let adjacent = collect_handle_adjacent_children(poly.handle, vertex_map);
+        report_sequence_resync("POLYLINE_MESH", poly.handle, "SEQEND not reached", adjacent.len());
+        if adjacent.len() > vertices.len() {
+            return Ok((adjacent, start_index + 1));
+        }
+    }
+
     Ok((vertices, next_i))
 }
 
@@ -866,7 +876,19 @@ pub fn decode_polyline_pface_with_faces(
             .iter()
             .map(|face| (face.index1, face.index2, face.index3, face.index4))
             .collect();
-        result.push((row.handle, row.num_vertices, row.num_faces, vertices, faces));
+        let face_colors: Vec<PFaceFaceColorRow> = row
+            .faces
+            .iter()
+            .map(|face| (face.color_index, face.true_color))
+            .collect();
+        result.push((
+            row.handle,
+            row.num_vertices,
+            row.num_faces,
+            vertices,
+            faces,
+            face_colors,
+        ));
     }
     Ok(result)
 }
@@ -884,9 +906,9 @@ fn decode_polyline_pface_rows(path: &str, limit: Option<usize>) -> PyResult<Vec<
     let face_map = build_vertex_pface_face_map(&decoder, &sorted, &dynamic_types, best_effort)?;
     let mut result = Vec::new();
     let mut i = 0usize;
-    while i < sorted.len() {
+    while let Some(current) = sorted.get(i) {
         let Some((record, header)) =
-            parse_record_and_header(&decoder, sorted[i].offset, best_effort)?
+            parse_record_and_header(&decoder, current.offset, best_effort)?
         else {
             i += 1;
             continue;
@@ -908,10 +930,10 @@ fn decode_polyline_pface_rows(path: &str, limit: Option<usize>) -> PyResult<Vec<
             &mut reader,
             decoder.version(),
             &header,
-            sorted[i].handle.0,
+            current.handle.0,
         ) {
             Ok(poly) => poly,
-            Err(err) if best_effort => {
+            Err(_) if best_effort => {
                 i += 1;
                 continue;
             }
@@ -974,7 +996,7 @@ fn build_vertex_pface_map(
             obj.handle.0,
         ) {
             Ok(vertex) => vertex,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         vertex_map.insert(vertex.handle, vertex);
@@ -1011,7 +1033,7 @@ fn build_vertex_pface_face_map(
             obj.handle.0,
         ) {
             Ok(face) => face,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         face_map.insert(face.handle, face);
@@ -1037,6 +1059,7 @@ fn collect_polyline_pface_data(
     let mut faces = Vec::new();
 
     if !poly.owned_handles.is_empty() {
+        let mut missing = 0usize;
         for handle in &poly.owned_handles {
             if let Some(vertex) = vertex_map.get(handle) {
                 vertices.push(vertex.clone());
@@ -1044,15 +1067,28 @@ fn collect_polyline_pface_data(
             }
             if let Some(face) = face_map.get(handle) {
                 faces.push(face.clone());
+                continue;
             }
+            missing += 1;
+        }
+        if missing > 0 {
+            report_sequence_resync(
+                "POLYLINE_PFACE",
+                poly.handle,
+                "owned handles missing",
+                vertices.len() + faces.len(),
+            );
+        }
+        if !vertices.is_empty() || !faces.is_empty() {
+            return Ok((vertices, faces, start_index + 1));
         }
-        return Ok((vertices, faces, start_index + 1));
     }
 
     let mut next_i = start_index + 1;
-    while next_i < sorted.len() {
+    let mut found_seqend = false;
+    while let Some(next) = sorted.get(next_i) {
         let Some((next_record, next_header)) =
-            parse_record_and_header(decoder, sorted[next_i].offset, best_effort)?
+            parse_record_and_header(decoder, next.offset, best_effort)?
         else {
             next_i += 1;
             continue;
@@ -1070,10 +1106,10 @@ fn collect_polyline_pface_data(
                 &mut next_reader,
                 decoder.version(),
                 &next_header,
-                sorted[next_i].handle.0,
+                next.handle.0,
             ) {
                 Ok(vertex) => vertex,
-                Err(err) if best_effort => {
+                Err(_) if best_effort => {
                     next_i += 1;
                     continue;
                 }
@@ -1100,10 +1136,10 @@ fn collect_polyline_pface_data(
                 &mut next_reader,
                 decoder.version(),
                 &next_header,
-                sorted[next_i].handle.0,
+                next.handle.0,
             ) {
                 Ok(face) => face,
-                Err(err) if best_effort => {
+                Err(_) if best_effort => {
                     next_i += 1;
                     continue;
                 }
@@ -1126,40 +1162,106 @@ fn collect_polyline_pface_data(
                     return Err(to_py_err(err));
                 }
             }
+            found_seqend = true;
             next_i += 1;
         }
         break;
     }
 
+    if !found_seqend {
+        // Vertices and faces share one handle run: POLYLINE -> VERTEX* -> FACE* -> SEQEND.
+        let mut adjacent_vertices = Vec::new();
+        let mut adjacent_faces = Vec::new();
+        let mut handle_cursor = poly.handle.saturating_add(1);
+        loop {
+            if let Some(vertex) = vertex_map.get(&handle_cursor) {
+                adjacent_vertices.push(vertex.clone());
+            } else if let Some(face) = face_map.get(&handle_cursor) {
+                adjacent_faces.push(face.clone());
+            } else {
+                break;
+            }
+            handle_cursor = handle_cursor.saturating_add(1);
+        }
+        report_sequence_resync(
+            "POLYLINE_PFACE",
+            poly.handle,
+            "SEQEND not reached",
+            adjacent_vertices.len() + adjacent_faces.len(),
+        );
+        if adjacent_vertices.len() + adjacent_faces.len() > vertices.len() + faces.len() {
+            return Ok((adjacent_vertices, adjacent_faces, start_index + 1));
+        }
+    }
+
     Ok((vertices, faces, next_i))
 }
 
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_mesh_entities(path: &str, limit: Option<usize>) -> PyResult<Vec<MeshEntityRow>> {
+    collect_object_rows(path, limit, "MESH", |record, header, version, handle| {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        let mesh = decode_mesh_for_version(&mut reader, version, header, handle)?;
+        Ok((
+            mesh.handle,
+            mesh.subdivision_level,
+            mesh.vertices,
+            mesh.faces,
+            mesh.edges,
+            mesh.creases,
+        ))
+    })
+}
+
 #[pyfunction(signature = (path, limit=None))]
 pub fn decode_polyline_2d_with_vertices(
     path: &str,
     limit: Option<usize>,
-) -> PyResult<Vec<PolylineVerticesRow>> {
+) -> PyResult<Vec<Polyline2dVerticesRow>> {
     let decoded_rows = decode_polyline_2d_vertex_rows(path, limit)?;
-    let mut result = Vec::with_capacity(decoded_rows.len());
+    Ok(decoded_rows
+        .iter()
+        .map(polyline_2d_vertices_row)
+        .collect())
+}
 
-    for row in decoded_rows {
-        let use_vertex_z = polyline_uses_vertex_z(row.flags_info);
-        let mut vertices: Vec<Point3> = row
-            .vertices
-            .iter()
-            .map(|vertex| vertex_position_for_polyline(vertex, row.elevation, use_vertex_z))
-            .collect();
-        if row.flags_info.closed && vertices.len() > 1 {
-            let first = vertices[0];
-            let last = *vertices.last().unwrap();
-            if !points_equal_3d(first, last) {
-                vertices.push(first);
-            }
+fn polyline_2d_vertices_row(row: &PolylineVertexRow) -> Polyline2dVerticesRow {
+    let use_vertex_z = polyline_uses_vertex_z(row.flags_info);
+    let closed = row.flags_info.closed;
+    let capacity = row.vertices.len() + 1;
+    let mut points = Vec::with_capacity(capacity);
+    let mut bulges = Vec::with_capacity(capacity);
+    let mut widths = Vec::with_capacity(capacity);
+    let mut tangent_dirs = Vec::with_capacity(capacity);
+    for vertex in row.vertices.iter() {
+        points.push(vertex_position_for_polyline(
+            vertex,
+            row.elevation,
+            use_vertex_z,
+        ));
+        bulges.push(vertex.bulge);
+        widths.push((vertex.start_width, vertex.end_width));
+        tangent_dirs.push(vertex.tangent_dir);
+    }
+    if let (true, &[first, .., last]) = (closed, points.as_slice()) {
+        if !points_equal_3d(first, last) {
+            // Keep the per-vertex arrays aligned with the closing point.
+            points.push(first);
+            bulges.extend(bulges.first().copied());
+            widths.extend(widths.first().copied());
+            tangent_dirs.extend(tangent_dirs.first().copied());
         }
-        result.push((row.handle, row.flags, vertices));
     }
-
-    Ok(result)
+    (
+        row.handle,
+        row.flags,
+        closed,
+        points,
+        bulges,
+        widths,
+        tangent_dirs,
+    )
 }
 
 #[pyfunction(signature = (path, segments_per_span=8, limit=None))]
@@ -1195,9 +1297,7 @@ pub fn decode_polyline_2d_with_vertices_interpolated(
                     .map_err(to_py_err)?;
             vertices = interpolated;
             applied = true;
-        } else if row.flags_info.closed && vertices.len() > 1 {
-            let first = vertices[0];
-            let last = *vertices.last().unwrap();
+        } else if let (true, &[first, .., last]) = (row.flags_info.closed, vertices.as_slice()) {
             if !points_equal_3d(first, last) {
                 vertices.push(first);
             }
@@ -1223,12 +1323,12 @@ pub fn decode_vertex_2d_entities(
     for obj in index.objects.iter() {
         let record = match decoder.parse_object_record(obj.offset) {
             Ok(record) => record,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let header = match parse_object_header_for_version(&record, decoder.version()) {
             Ok(header) => header,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         if !matches_type_name(header.type_code, 0x0A, "VERTEX_2D", &dynamic_types) {
@@ -1302,9 +1402,7 @@ pub fn decode_polyline_2d_with_vertex_data(
             .iter()
             .map(|vertex| vertex_data_for_polyline(vertex, row.elevation, use_vertex_z))
             .collect();
-        if row.flags_info.closed && vertices.len() > 1 {
-            let first = vertices[0];
-            let last = *vertices.last().unwrap();
+        if let (true, &[first, .., last]) = (row.flags_info.closed, vertices.as_slice()) {
             if !points_equal_3d_with_data(first, last) {
                 vertices.push(first);
             }
@@ -1343,11 +1441,10 @@ fn decode_polyline_2d_vertex_rows(
     }
     let mut result = Vec::new();
     let mut i = 0usize;
-    while i < sorted.len() {
-        let obj = sorted[i];
+    while let Some(&obj) = sorted.get(i) {
         let record = match decoder.parse_object_record(obj.offset) {
             Ok(record) => record,
-            Err(err) if best_effort => {
+            Err(_) if best_effort => {
                 i += 1;
                 continue;
             }
@@ -1355,7 +1452,7 @@ fn decode_polyline_2d_vertex_rows(
         };
         let header = match parse_object_header_for_version(&record, decoder.version()) {
             Ok(header) => header,
-            Err(err) if best_effort => {
+            Err(_) if best_effort => {
                 i += 1;
                 continue;
             }
@@ -1478,12 +1575,12 @@ fn build_vertex_2d_map(
     for obj in sorted {
         let record = match decoder.parse_object_record(obj.offset) {
             Ok(record) => record,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         let header = match parse_object_header_for_version(&record, decoder.version()) {
             Ok(header) => header,
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         if !matches_type_name(header.type_code, 0x0A, "VERTEX_2D", dynamic_types) {
@@ -1539,13 +1636,10 @@ fn collect_polyline_vertices(
 ) -> PyResult<(Vec<entities::Vertex2dEntity>, usize)> {
     let mut vertices = Vec::new();
 
-    if !poly.owned_handles.is_empty() {
-        for handle in &poly.owned_handles {
-            if let Some(vertex) = vertex_map.get(handle) {
-                vertices.push(vertex.clone());
-            }
-        }
-        return Ok((vertices, start_index + 1));
+    if let Some(owned_vertices) =
+        owned_sequence_children("POLYLINE_2D", poly.handle, &poly.owned_handles, vertex_map)
+    {
+        return Ok((owned_vertices, start_index + 1));
     }
 
     if let Some(owned_vertices) = vertices_by_owner.get(&poly.handle) {
@@ -1554,21 +1648,17 @@ fn collect_polyline_vertices(
 
     // Legacy POLYLINE_2D often stores VERTEX/SEQEND far from parent in object-offset
     // order, but keeps handle adjacency: POLYLINE -> VERTEX* -> SEQEND.
-    let mut handle_cursor = poly.handle.saturating_add(1);
-    while let Some(vertex) = vertex_map.get(&handle_cursor) {
-        vertices.push(vertex.clone());
-        handle_cursor = handle_cursor.saturating_add(1);
-    }
-    if !vertices.is_empty() {
-        return Ok((vertices, start_index + 1));
+    let adjacent = collect_handle_adjacent_children(poly.handle, vertex_map);
+    if !adjacent.is_empty() {
+        return Ok((adjacent, start_index + 1));
     }
 
     let mut next_i = start_index + 1;
-    while next_i < sorted.len() {
-        let next = sorted[next_i];
+    let mut found_seqend = false;
+    while let Some(&next) = sorted.get(next_i) {
         let next_record = match decoder.parse_object_record(next.offset) {
             Ok(record) => record,
-            Err(err) if best_effort => {
+            Err(_) if best_effort => {
                 next_i += 1;
                 continue;
             }
@@ -1576,7 +1666,7 @@ fn collect_polyline_vertices(
         };
         let next_header = match parse_object_header_for_version(&next_record, decoder.version()) {
             Ok(header) => header,
-            Err(err) if best_effort => {
+            Err(_) if best_effort => {
                 next_i += 1;
                 continue;
             }
@@ -1643,14 +1733,101 @@ fn collect_polyline_vertices(
                 }
                 return Err(to_py_err(err));
             }
+            found_seqend = true;
             next_i += 1;
         }
         break;
     }
 
+    if !found_seqend {
+        report_sequence_resync("POLYLINE_2D", poly.handle, "SEQEND not reached", vertices.len());
+    }
+
     Ok((vertices, next_i))
 }
 
+/// The children a sequence owner lists, or `None` when it lists none that
+/// were decoded and the caller has to walk the records after it instead.
+fn owned_sequence_children<T: Clone>(
+    owner_type: &str,
+    owner_handle: u64,
+    owned_handles: &[u64],
+    child_map: &HashMap<u64, T>,
+) -> Option<Vec<T>> {
+    if owned_handles.is_empty() {
+        return None;
+    }
+    let (children, missing) = lookup_owned_children(owner_handle, owned_handles, child_map);
+    if missing > 0 {
+        report_sequence_resync(
+            owner_type,
+            owner_handle,
+            "owned handles missing",
+            children.len(),
+        );
+    }
+    (!children.is_empty()).then_some(children)
+}
+
+/// Resolves the children a sequence owner lists explicitly, returning them in
+/// owner order together with how many listed handles had no decoded child.
+/// A listed handle without a child points at a damaged owner record, so the
+/// handle run after the owner is merged in and the children are ordered by
+/// handle; otherwise the list alone would drop the vertices it lost.
+fn lookup_owned_children<T: Clone>(
+    owner_handle: u64,
+    owned_handles: &[u64],
+    child_map: &HashMap<u64, T>,
+) -> (Vec<T>, usize) {
+    let mut handles: Vec<u64> = owned_handles
+        .iter()
+        .copied()
+        .filter(|handle| child_map.contains_key(handle))
+        .collect();
+    let missing = owned_handles.len() - handles.len();
+    if missing > 0 {
+        let mut handle_cursor = owner_handle.saturating_add(1);
+        while child_map.contains_key(&handle_cursor) {
+            handles.push(handle_cursor);
+            handle_cursor = handle_cursor.saturating_add(1);
+        }
+        handles.sort_unstable();
+        handles.dedup();
+    }
+    let children = handles
+        .iter()
+        .filter_map(|handle| child_map.get(handle).cloned())
+        .collect();
+    (children, missing)
+}
+
+/// Walks the handle run right after a sequence owner (OWNER -> CHILD* -> SEQEND).
+/// Writers allocate these handles consecutively even when the records end up
+/// interleaved with other objects in offset order.
+fn collect_handle_adjacent_children<T: Clone>(
+    owner_handle: u64,
+    child_map: &HashMap<u64, T>,
+) -> Vec<T> {
+    let mut children = Vec::new();
+    let mut handle_cursor = owner_handle.saturating_add(1);
+    while let Some(child) = child_map.get(&handle_cursor) {
+        children.push(child.clone());
+        handle_cursor = handle_cursor.saturating_add(1);
+    }
+    children
+}
+
+fn report_sequence_resync(owner_type: &str, owner_handle: u64, reason: &str, recovered: usize) {
+    if std::env::var("EZDWG_DEBUG_SEQEND")
+        .ok()
+        .is_some_and(|value| value != "0")
+    {
+        eprintln!(
+            "[seqend] {owner_type} handle={owner_handle} {reason}; recovered {recovered} children"
+        );
+    }
+}
+
 fn sanitize_polyline_2d_vertices(
     vertices: Vec<entities::Vertex2dEntity>,
 ) -> Vec<entities::Vertex2dEntity> {
@@ -1674,26 +1851,14 @@ fn sanitize_polyline_2d_vertices(
         return vertices;
     }
 
-    let mut remove = vec![false; vertices.len()];
-    for index in 0..vertices.len() {
-        if !is_origin_like_polyline_vertex(&vertices[index]) {
-            continue;
-        }
-        if candidate_count >= 2 || has_large_adjacent_jump(&vertices, index) {
-            remove[index] = true;
-        }
-    }
-
     let mut cleaned: Vec<entities::Vertex2dEntity> = vertices
         .iter()
         .enumerate()
-        .filter_map(|(index, vertex)| {
-            if remove[index] {
-                None
-            } else {
-                Some(vertex.clone())
-            }
+        .filter(|(index, vertex)| {
+            !is_origin_like_polyline_vertex(vertex)
+                || (candidate_count < 2 && !has_large_adjacent_jump(&vertices, *index))
         })
+        .map(|(_index, vertex)| vertex.clone())
         .collect();
     if cleaned.len() < 2 {
         if candidate_count >= vertices.len().saturating_sub(1) {
@@ -1828,9 +1993,9 @@ pub fn decode_polyline_sequence_members(
 
     let mut result = Vec::new();
     let mut i = 0usize;
-    while i < sorted.len() {
+    while let Some(current) = sorted.get(i) {
         let Some((record, header)) =
-            parse_record_and_header(&decoder, sorted[i].offset, best_effort)?
+            parse_record_and_header(&decoder, current.offset, best_effort)?
         else {
             i += 1;
             continue;
@@ -1851,7 +2016,7 @@ pub fn decode_polyline_sequence_members(
             continue;
         };
 
-        let polyline_handle = sorted[i].handle.0;
+        let polyline_handle = current.handle.0;
         let mut vertex_handles: Vec<u64> = Vec::new();
         let mut face_handles: Vec<u64> = Vec::new();
         let mut seqend_handle: Option<u64> = None;
@@ -1948,14 +2113,14 @@ pub fn decode_polyline_sequence_members(
                 }
             }
         } else {
-            while next_i < sorted.len() {
+            while let Some(next) = sorted.get(next_i) {
                 let Some((_next_record, next_header)) =
-                    parse_record_and_header(&decoder, sorted[next_i].offset, best_effort)?
+                    parse_record_and_header(&decoder, next.offset, best_effort)?
                 else {
                     next_i += 1;
                     continue;
                 };
-                let next_handle = sorted[next_i].handle.0;
+                let next_handle = next.handle.0;
                 let is_member = match kind {
                     PolylineSequenceKind::Polyline2d => {
                         matches_type_name(next_header.type_code, 0x0A, "VERTEX_2D", &dynamic_types)
@@ -2025,21 +2190,25 @@ fn decode_lwpolyline_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::LwPolylineEntity> {
-    match version {
-        version::DwgVersion::R14 => {
-            entities::decode_lwpolyline_r14(reader, object_handle, header.type_code)
-        }
-        version::DwgVersion::R2010 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            entities::decode_lwpolyline_r2010(reader, object_data_end_bit, object_handle)
-        }
-        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            entities::decode_lwpolyline_r2013(reader, object_data_end_bit, object_handle)
-        }
-        version::DwgVersion::R2007 => entities::decode_lwpolyline_r2007(reader),
-        _ => entities::decode_lwpolyline(reader),
-    }
+    time_type_decode(
+        "decode_lwpolyline_for_version",
+        reader,
+        |reader| match version {
+            version::DwgVersion::R14 => {
+                entities::decode_lwpolyline_r14(reader, object_handle, header.type_code)
+            }
+            version::DwgVersion::R2010 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                entities::decode_lwpolyline_r2010(reader, object_data_end_bit, object_handle)
+            }
+            version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                entities::decode_lwpolyline_r2013(reader, object_data_end_bit, object_handle)
+            }
+            version::DwgVersion::R2007 => entities::decode_lwpolyline_r2007(reader),
+            _ => entities::decode_lwpolyline(reader),
+        },
+    )
 }
 
 fn decode_polyline_2d_for_version(
@@ -2048,38 +2217,42 @@ fn decode_polyline_2d_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::Polyline2dEntity> {
-    let start = reader.get_pos();
-    match version {
-        version::DwgVersion::R14 => entities::decode_polyline_2d_r14(reader, object_handle),
-        version::DwgVersion::R2010 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            match entities::decode_polyline_2d_r2010(reader, object_data_end_bit, object_handle) {
-                Ok(entity) => Ok(entity),
-                Err(primary_err) => {
-                    reader.set_pos(start.0, start.1);
-                    entities::decode_polyline_2d(reader).or(Err(primary_err))
+    time_type_decode("decode_polyline_2d_for_version", reader, |reader| {
+        let start = reader.get_pos();
+        match version {
+            version::DwgVersion::R14 => entities::decode_polyline_2d_r14(reader, object_handle),
+            version::DwgVersion::R2010 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                match entities::decode_polyline_2d_r2010(reader, object_data_end_bit, object_handle)
+                {
+                    Ok(entity) => Ok(entity),
+                    Err(primary_err) => {
+                        reader.set_pos(start.0, start.1);
+                        entities::decode_polyline_2d(reader).or(Err(primary_err))
+                    }
                 }
             }
-        }
-        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            match entities::decode_polyline_2d_r2013(reader, object_data_end_bit, object_handle) {
+            version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                match entities::decode_polyline_2d_r2013(reader, object_data_end_bit, object_handle)
+                {
+                    Ok(entity) => Ok(entity),
+                    Err(primary_err) => {
+                        reader.set_pos(start.0, start.1);
+                        entities::decode_polyline_2d(reader).or(Err(primary_err))
+                    }
+                }
+            }
+            version::DwgVersion::R2007 => match entities::decode_polyline_2d_r2007(reader) {
                 Ok(entity) => Ok(entity),
                 Err(primary_err) => {
                     reader.set_pos(start.0, start.1);
                     entities::decode_polyline_2d(reader).or(Err(primary_err))
                 }
-            }
+            },
+            _ => entities::decode_polyline_2d(reader),
         }
-        version::DwgVersion::R2007 => match entities::decode_polyline_2d_r2007(reader) {
-            Ok(entity) => Ok(entity),
-            Err(primary_err) => {
-                reader.set_pos(start.0, start.1);
-                entities::decode_polyline_2d(reader).or(Err(primary_err))
-            }
-        },
-        _ => entities::decode_polyline_2d(reader),
-    }
+    })
 }
 
 fn is_r14_polyline_2d_speculative_type(version: &version::DwgVersion, type_code: u16) -> bool {
@@ -2123,6 +2296,15 @@ impl_version_dispatch! {
     default: entities::decode_polyline_3d;
 }
 
+impl_version_dispatch! {
+    no_r14;
+    fn decode_mesh_for_version -> entities::MeshEntity;
+    r2010: entities::decode_mesh_r2010;
+    r2013: entities::decode_mesh_r2013;
+    r2007: entities::decode_mesh_r2007;
+    default: entities::decode_mesh;
+}
+
 impl_version_dispatch! {
     no_r14;
     fn decode_vertex_3d_for_version -> entities::Vertex3dEntity;
@@ -2138,44 +2320,46 @@ fn decode_vertex_2d_for_version(
     header: &ApiObjectHeader,
     object_handle: u64,
 ) -> crate::core::result::Result<entities::Vertex2dEntity> {
-    let start = reader.get_pos();
-    let primary = match version {
-        version::DwgVersion::R2010 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            entities::decode_vertex_2d_r2010(reader, object_data_end_bit, object_handle)
-        }
-        version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
-            entities::decode_vertex_2d_r2013(reader, object_data_end_bit, object_handle)
-        }
-        version::DwgVersion::R2007 => entities::decode_vertex_2d_r2007(reader),
-        _ => entities::decode_vertex_2d(reader),
-    };
-    if let Ok(entity) = primary {
-        return Ok(entity);
-    }
-    let primary_err = primary.unwrap_err();
-
-    reader.set_pos(start.0, start.1);
-    if let Ok(entity) = entities::decode_vertex_2d(reader) {
-        return Ok(entity);
-    }
-
-    // Some drawings tag 2D vertices with legacy 3D-like payloads.
-    reader.set_pos(start.0, start.1);
-    if let Ok(vertex3d) = decode_vertex_3d_for_version(reader, version, header, object_handle) {
-        return Ok(entities::Vertex2dEntity {
-            handle: vertex3d.handle,
-            flags: u16::from(vertex3d.flags),
-            position: vertex3d.position,
-            start_width: 0.0,
-            end_width: 0.0,
-            bulge: 0.0,
-            tangent_dir: 0.0,
-            owner_handle: None,
-        });
-    }
-    Err(primary_err)
+    time_type_decode("decode_vertex_2d_for_version", reader, |reader| {
+        let start = reader.get_pos();
+        let primary = match version {
+            version::DwgVersion::R2010 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                entities::decode_vertex_2d_r2010(reader, object_data_end_bit, object_handle)
+            }
+            version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
+                let object_data_end_bit = resolve_r2010_object_data_end_bit(header)?;
+                entities::decode_vertex_2d_r2013(reader, object_data_end_bit, object_handle)
+            }
+            version::DwgVersion::R2007 => entities::decode_vertex_2d_r2007(reader),
+            _ => entities::decode_vertex_2d(reader),
+        };
+        let primary_err = match primary {
+            Ok(entity) => return Ok(entity),
+            Err(err) => err,
+        };
+
+        reader.set_pos(start.0, start.1);
+        if let Ok(entity) = entities::decode_vertex_2d(reader) {
+            return Ok(entity);
+        }
+
+        // Some drawings tag 2D vertices with legacy 3D-like payloads.
+        reader.set_pos(start.0, start.1);
+        if let Ok(vertex3d) = decode_vertex_3d_for_version(reader, version, header, object_handle) {
+            return Ok(entities::Vertex2dEntity {
+                handle: vertex3d.handle,
+                flags: u16::from(vertex3d.flags),
+                position: vertex3d.position,
+                start_width: 0.0,
+                end_width: 0.0,
+                bulge: 0.0,
+                tangent_dir: 0.0,
+                owner_handle: None,
+            });
+        }
+        Err(primary_err)
+    })
 }
 
 impl_version_dispatch! {
@@ -2204,3 +2388,131 @@ impl_version_dispatch! {
     r2007: entities::decode_vertex_pface_face_r2007;
     default: entities::decode_vertex_pface_face;
 }
+
+#[cfg(test)]
+mod polyline_tests {
+    use super::{
+        collect_handle_adjacent_children, lookup_owned_children, owned_sequence_children,
+        polyline_2d_vertices_row, PolylineVertexRow,
+    };
+    use crate::entities::{PolylineCurveType, PolylineFlagsInfo, Polyline2dEntity, Vertex2dEntity};
+    use std::collections::HashMap;
+
+    fn vertex(handle: u64, x: f64, y: f64, bulge: f64) -> Vertex2dEntity {
+        Vertex2dEntity {
+            handle,
+            flags: 0,
+            position: (x, y, 0.0),
+            start_width: 0.1,
+            end_width: 0.2,
+            bulge,
+            tangent_dir: 0.0,
+            owner_handle: Some(0x10),
+        }
+    }
+
+    fn polyline_row(flags: u16, vertices: Vec<Vertex2dEntity>) -> PolylineVertexRow {
+        PolylineVertexRow {
+            handle: 0x10,
+            flags,
+            flags_info: PolylineFlagsInfo::from_flags(flags),
+            curve_type_info: PolylineCurveType::None,
+            elevation: 2.5,
+            vertices,
+        }
+    }
+
+    #[test]
+    fn polyline_2d_vertices_row_closes_and_keeps_arrays_aligned() {
+        let row = polyline_row(
+            0x01,
+            vec![
+                vertex(0x11, 0.0, 0.0, 0.5),
+                vertex(0x12, 1.0, 0.0, 0.0),
+                vertex(0x13, 1.0, 1.0, -1.0),
+            ],
+        );
+
+        let (handle, flags, closed, points, bulges, widths, tangent_dirs) =
+            polyline_2d_vertices_row(&row);
+
+        assert_eq!(handle, 0x10);
+        assert_eq!(flags, 0x01);
+        assert!(closed);
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[0], (0.0, 0.0, 2.5));
+        assert_eq!(points[3], points[0]);
+        assert_eq!(bulges, vec![0.5, 0.0, -1.0, 0.5]);
+        assert_eq!(widths.len(), 4);
+        assert_eq!(tangent_dirs.len(), 4);
+    }
+
+    #[test]
+    fn polyline_2d_vertices_row_leaves_open_polyline_unchanged() {
+        let row = polyline_row(
+            0x00,
+            vec![vertex(0x11, 0.0, 0.0, 0.0), vertex(0x12, 3.0, 4.0, 0.25)],
+        );
+
+        let (_, _, closed, points, bulges, _, _) = polyline_2d_vertices_row(&row);
+
+        assert!(!closed);
+        assert_eq!(points, vec![(0.0, 0.0, 2.5), (3.0, 4.0, 2.5)]);
+        assert_eq!(bulges, vec![0.0, 0.25]);
+    }
+
+    #[test]
+    fn lookup_owned_children_counts_missing_handles() {
+        let children = HashMap::from([(0x11u64, "a"), (0x13u64, "c")]);
+
+        let (found, missing) = lookup_owned_children(0x40, &[0x11, 0x12, 0x13], &children);
+
+        assert_eq!(found, vec!["a", "c"]);
+        assert_eq!(missing, 1);
+    }
+
+    #[test]
+    fn lookup_owned_children_keeps_owner_order_when_complete() {
+        let children = HashMap::from([(0x11u64, "a"), (0x12u64, "b"), (0x13u64, "c")]);
+
+        let (found, missing) = lookup_owned_children(0x10, &[0x13, 0x11], &children);
+
+        assert_eq!(found, vec!["c", "a"]);
+        assert_eq!(missing, 0);
+    }
+
+    #[test]
+    fn damaged_polyline_recovers_vertices_its_owned_handles_lost() {
+        let vertex_map: HashMap<u64, Vertex2dEntity> = [0x11, 0x12, 0x13, 0x14]
+            .into_iter()
+            .map(|handle| (handle, vertex(handle, 0.0, 0.0, 0.0)))
+            .collect();
+        // The second owned handle is corrupt and the last one was cut off.
+        let poly = Polyline2dEntity {
+            handle: 0x10,
+            owned_handles: vec![0x11, 0x9F12, 0x13],
+            ..Default::default()
+        };
+
+        let found = owned_sequence_children(
+            "POLYLINE_2D",
+            poly.handle,
+            &poly.owned_handles,
+            &vertex_map,
+        )
+        .expect("owned vertices");
+
+        let handles: Vec<u64> = found.iter().map(|vertex| vertex.handle).collect();
+        assert_eq!(handles, vec![0x11, 0x12, 0x13, 0x14]);
+        assert!(owned_sequence_children("POLYLINE_2D", 0x10, &[0x9F12], &HashMap::<u64, u8>::new())
+            .is_none());
+    }
+
+    #[test]
+    fn handle_adjacent_children_stop_at_first_gap() {
+        let children = HashMap::from([(0x21u64, 1), (0x22u64, 2), (0x24u64, 4)]);
+
+        assert_eq!(collect_handle_adjacent_children(0x20, &children), vec![1, 2]);
+        assert!(collect_handle_adjacent_children(0x30, &children).is_empty());
+    }
+}
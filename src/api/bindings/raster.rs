@@ -0,0 +1,95 @@
+#[cfg(feature = "raster")]
+#[pyfunction(signature = (
+    output_path,
+    strokes,
+    width=1024,
+    height=None,
+    window=None,
+    background=(255, 255, 255),
+    line_width=1,
+    margin=0.04
+))]
+pub fn write_strokes_png(
+    output_path: &str,
+    strokes: Vec<RasterStrokeRow>,
+    width: u32,
+    height: Option<u32>,
+    window: Option<(f64, f64, f64, f64)>,
+    background: (u8, u8, u8),
+    line_width: u32,
+    margin: f64,
+) -> PyResult<(u32, u32)> {
+    let (bytes, size) = encode_strokes_png(
+        strokes, width, height, window, background, line_width, margin,
+    )?;
+
+    let out_path = Path::new(output_path);
+    if let Some(parent) = out_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).map_err(|err| PyIOError::new_err(err.to_string()))?;
+        }
+    }
+    std::fs::write(out_path, bytes).map_err(|err| PyIOError::new_err(err.to_string()))?;
+    Ok(size)
+}
+
+/// Same as `write_strokes_png`, returning the encoded PNG instead of writing
+/// it, for callers that serve images (such as map tiles) from memory.
+#[cfg(feature = "raster")]
+#[pyfunction(signature = (
+    strokes,
+    width=1024,
+    height=None,
+    window=None,
+    background=(255, 255, 255),
+    line_width=1,
+    margin=0.04
+))]
+pub fn render_strokes_png(
+    py: Python<'_>,
+    strokes: Vec<RasterStrokeRow>,
+    width: u32,
+    height: Option<u32>,
+    window: Option<(f64, f64, f64, f64)>,
+    background: (u8, u8, u8),
+    line_width: u32,
+    margin: f64,
+) -> PyResult<Py<PyBytes>> {
+    let (bytes, _) = encode_strokes_png(
+        strokes, width, height, window, background, line_width, margin,
+    )?;
+    Ok(PyBytes::new_bound(py, &bytes).unbind())
+}
+
+#[cfg(feature = "raster")]
+fn encode_strokes_png(
+    strokes: Vec<RasterStrokeRow>,
+    width: u32,
+    height: Option<u32>,
+    window: Option<(f64, f64, f64, f64)>,
+    background: (u8, u8, u8),
+    line_width: u32,
+    margin: f64,
+) -> PyResult<(Vec<u8>, (u32, u32))> {
+    let strokes: Vec<crate::raster::Stroke> = strokes
+        .into_iter()
+        .map(|(points, color, closed)| crate::raster::Stroke {
+            points,
+            color,
+            closed,
+        })
+        .collect();
+    let options = crate::raster::RasterOptions {
+        width,
+        height,
+        window: window.map(|(min_x, min_y, max_x, max_y)| {
+            crate::raster::Window::new(min_x, min_y, max_x, max_y)
+        }),
+        background,
+        line_width,
+        margin,
+    };
+    let canvas = crate::raster::render_strokes(&strokes, &options).map_err(to_py_err)?;
+    let bytes = canvas.to_png().map_err(to_py_err)?;
+    Ok((bytes, (canvas.width, canvas.height)))
+}
@@ -0,0 +1,111 @@
+// Heuristic recovery (handle stream scans, text rescans) fills in values the
+// normal field parse got wrong or could not read. Those values are
+// indistinguishable from parsed ones in the returned rows, so recovery sites
+// report them here while `decode_recovered_fields` has recording enabled.
+
+#[derive(Debug, Clone, PartialEq)]
+enum RecoveredValue {
+    Handle(u64),
+    Text(String),
+}
+
+impl RecoveredValue {
+    fn into_object(self, py: Python<'_>) -> PyObject {
+        match self {
+            RecoveredValue::Handle(handle) => handle.into_py(py),
+            RecoveredValue::Text(text) => text.into_py(py),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RecoveredField {
+    handle: u64,
+    field: &'static str,
+    value: RecoveredValue,
+    parsed: Option<RecoveredValue>,
+    method: &'static str,
+}
+
+thread_local! {
+    static RECOVERED_FIELDS: std::cell::RefCell<Option<Vec<RecoveredField>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+fn note_recovered_field(
+    handle: u64,
+    field: &'static str,
+    value: RecoveredValue,
+    parsed: Option<RecoveredValue>,
+    method: &'static str,
+) {
+    RECOVERED_FIELDS.with(|fields| {
+        if let Some(fields) = fields.borrow_mut().as_mut() {
+            fields.push(RecoveredField {
+                handle,
+                field,
+                value,
+                parsed,
+                method,
+            });
+        }
+    });
+}
+
+fn with_recovered_fields<T>(decode: impl FnOnce() -> T) -> (T, Vec<RecoveredField>) {
+    let previous = RECOVERED_FIELDS.with(|fields| fields.borrow_mut().replace(Vec::new()));
+    let result = decode();
+    let recorded = RECOVERED_FIELDS.with(|fields| {
+        let mut fields = fields.borrow_mut();
+        let recorded = fields.take().unwrap_or_default();
+        *fields = previous;
+        recorded
+    });
+    (result, recorded)
+}
+
+/// Lists entity fields whose value came from heuristic recovery rather than
+/// from the field itself: layer handles of entities (as returned by
+/// `decode_entity_styles`), block record handles of INSERT/MINSERT and MTEXT
+/// text. Fields that are not listed were parsed directly.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_recovered_fields(
+    py: Python<'_>,
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<RecoveredFieldRow>> {
+    let (styles, mut fields) = with_recovered_fields(|| -> PyResult<Vec<EntityStyleRow>> {
+        let styles = decode_entity_styles(path, limit)?;
+        decode_insert_entities(path, limit)?;
+        decode_minsert_entities(path, limit)?;
+        decode_mtext_entities(path, limit)?;
+        Ok(styles)
+    });
+    // Report the layer handle the style rows carry, after their handle remap.
+    let layer_by_entity: HashMap<u64, u64> = styles?
+        .into_iter()
+        .map(|(handle, _color_index, _true_color, layer_handle)| (handle, layer_handle))
+        .collect();
+    let mut seen = HashSet::new();
+    fields.retain(|field| seen.insert((field.handle, field.field)));
+    fields.sort_by_key(|field| (field.handle, field.field));
+    Ok(fields
+        .into_iter()
+        .filter_map(|mut field| {
+            if field.field == "layer_handle" {
+                let layer_handle = *layer_by_entity.get(&field.handle)?;
+                if field.parsed == Some(RecoveredValue::Handle(layer_handle)) {
+                    return None;
+                }
+                field.value = RecoveredValue::Handle(layer_handle);
+            }
+            Some((
+                field.handle,
+                field.field.to_string(),
+                field.value.into_object(py),
+                field.parsed.map(|parsed| parsed.into_object(py)),
+                field.method.to_string(),
+            ))
+        })
+        .collect())
+}
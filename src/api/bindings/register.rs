@@ -1,24 +1,76 @@
 pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(detect_version, module)?)?;
     module.add_function(wrap_pyfunction!(write_ac1015_dwg, module)?)?;
+    module.add_function(wrap_pyfunction!(apply_incremental_update, module)?)?;
+    module.add_function(wrap_pyfunction!(replace_entity_text, module)?)?;
+    module.add_function(wrap_pyfunction!(redefine_block, module)?)?;
+    module.add_function(wrap_pyfunction!(strip_layers, module)?)?;
     module.add_function(wrap_pyfunction!(write_ac1015_line_dwg, module)?)?;
+    module.add_function(wrap_pyfunction!(list_unknown_objects, module)?)?;
+    module.add_function(wrap_pyfunction!(remap_object_record_handles, module)?)?;
+    #[cfg(feature = "raster")]
+    module.add_function(wrap_pyfunction!(write_strokes_png, module)?)?;
+    #[cfg(feature = "raster")]
+    module.add_function(wrap_pyfunction!(render_strokes_png, module)?)?;
+    #[cfg(feature = "cache")]
+    module.add_function(wrap_pyfunction!(cache_file_key, module)?)?;
+    #[cfg(feature = "cache")]
+    module.add_function(wrap_pyfunction!(cache_load, module)?)?;
+    #[cfg(feature = "cache")]
+    module.add_function(wrap_pyfunction!(cache_store, module)?)?;
+    #[cfg(feature = "cache")]
+    module.add_function(wrap_pyfunction!(cache_clear, module)?)?;
     module.add_function(wrap_pyfunction!(list_section_locators, module)?)?;
+    module.add_function(wrap_pyfunction!(describe_file_layout, module)?)?;
     module.add_function(wrap_pyfunction!(read_section_bytes, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_drawing_times, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_linetype_scales, module)?)?;
+    module.add_function(wrap_pyfunction!(read_decompressed_section, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_acds_records, module)?)?;
+    module.add_function(wrap_pyfunction!(tessellate_solids, module)?)?;
     module.add_function(wrap_pyfunction!(list_object_map_entries, module)?)?;
     module.add_function(wrap_pyfunction!(list_object_headers, module)?)?;
     module.add_function(wrap_pyfunction!(list_object_headers_with_type, module)?)?;
     module.add_function(wrap_pyfunction!(list_object_headers_by_type, module)?)?;
     module.add_function(wrap_pyfunction!(read_object_records_by_type, module)?)?;
     module.add_function(wrap_pyfunction!(read_object_records_by_handle, module)?)?;
+    module.add_function(wrap_pyfunction!(explain_object, module)?)?;
     module.add_function(wrap_pyfunction!(read_object_records_by_offset, module)?)?;
     module.add_function(wrap_pyfunction!(decode_object_entity_layer_handles, module)?)?;
     module.add_function(wrap_pyfunction!(decode_object_handle_stream_refs, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_object_handle_stream_ref_kinds, module)?)?;
     module.add_function(wrap_pyfunction!(decode_acis_candidate_infos, module)?)?;
     module.add_function(wrap_pyfunction!(decode_proxy_graphic_chunk_infos, module)?)?;
     module.add_function(wrap_pyfunction!(decode_proxy_graphic_text_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_entity_styles, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_recovered_fields, module)?)?;
+    module.add_function(wrap_pyfunction!(begin_decode_stats, module)?)?;
+    module.add_function(wrap_pyfunction!(end_decode_stats, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_entity_colors, module)?)?;
+    module.add_function(wrap_pyfunction!(aci_to_rgb, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_entity_transparency, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_entity_thickness, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_entity_line_styles, module)?)?;
     module.add_function(wrap_pyfunction!(decode_layer_colors, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_layer_states, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_layer_transparency, module)?)?;
     module.add_function(wrap_pyfunction!(decode_layer_names, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_table_control, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_table_entry_names, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_dictionaries, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_xrecords, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_xdata, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_table_styles, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_linetypes, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_text_styles, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_dim_styles, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_views, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_materials, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_visual_styles, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_scales, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_entity_materials, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_layer_materials, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_layouts, module)?)?;
     module.add_function(wrap_pyfunction!(decode_line_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_line_owner_handles, module)?)?;
     module.add_function(wrap_pyfunction!(decode_point_entities, module)?)?;
@@ -29,6 +81,7 @@ pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(decode_circle_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_circle_owner_handles, module)?)?;
     module.add_function(wrap_pyfunction!(decode_line_arc_circle_entities, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_styled_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_ellipse_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_spline_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_text_entities, module)?)?;
@@ -36,6 +89,7 @@ pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(decode_attdef_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_mtext_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_leader_entities, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_mleader_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_hatch_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_tolerance_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_mline_entities, module)?)?;
@@ -58,6 +112,11 @@ pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(decode_block_header_names, module)?)?;
     module.add_function(wrap_pyfunction!(decode_block_entity_names, module)?)?;
     module.add_function(wrap_pyfunction!(decode_block_entity_name_maps, module)?)?;
+    module.add_function(wrap_pyfunction!(iter_block_children, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_block_base_point, module)?)?;
+    module.add_function(wrap_pyfunction!(check_block_references, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_xref_blocks, module)?)?;
+    module.add_function(wrap_pyfunction!(build_drawing_index, module)?)?;
     module.add_function(wrap_pyfunction!(decode_polyline_2d_entities, module)?)?;
     module.add_function(wrap_pyfunction!(
         decode_polyline_2d_entities_interpreted,
@@ -74,10 +133,12 @@ pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     )?)?;
     module.add_function(wrap_pyfunction!(decode_polyline_pface_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_polyline_pface_with_faces, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_mesh_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_solid_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_trace_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_shape_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_viewport_entities, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_viewport_frozen_layers, module)?)?;
     module.add_function(wrap_pyfunction!(decode_oleframe_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_ole2frame_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_long_transaction_entities, module)?)?;
@@ -85,6 +146,9 @@ pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(decode_3dsolid_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_body_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_ray_entities, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_light_entities, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_rtext_entities, module)?)?;
+    module.add_function(wrap_pyfunction!(decode_arc_aligned_text_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_xline_entities, module)?)?;
     module.add_function(wrap_pyfunction!(decode_polyline_2d_with_vertices, module)?)?;
     module.add_function(wrap_pyfunction!(
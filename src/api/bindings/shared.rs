@@ -1,43 +1,273 @@
-use pyo3::exceptions::{PyIOError, PyNotImplementedError, PyValueError};
+use pyo3::exceptions::{
+    PyDeprecationWarning, PyIOError, PyNotImplementedError, PyRuntimeError, PyValueError,
+};
 use pyo3::prelude::*;
-use std::collections::{HashMap, HashSet};
+use pyo3::types::{PyBytes, PyString};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
-use crate::bit::{BitReader, Endian};
+use crate::bit::{BitReader, Endian, HandleRefKind, TraceEntry};
 use crate::core::error::{DwgError, ErrorKind};
+use crate::core::progress::ProgressHook;
+use crate::core::stats::{StatKind, StatTotals, StatsCollector};
 use crate::dwg::decoder;
 use crate::dwg::file_open;
+use crate::dwg::header::{DrawingTimes, JulianDate};
 use crate::dwg::version;
 use crate::dwg::version::DwgVersion;
 use crate::entities;
 use crate::objects;
 use crate::writer;
+use crate::writer::object_graph::{BlockNestingIssue, BlockReferenceGraph};
 
 type Point2 = (f64, f64);
 type Point3 = (f64, f64, f64);
 
-type SectionLocatorRow = (String, u32, u32);
-type ObjectMapEntryRow = (u64, u32);
-type ObjectHeaderRow = (u64, u32, u32, u16);
-type ObjectHeaderWithTypeRow = (u64, u32, u32, u16, String, String);
-type ObjectRecordBytesRow = (u64, u32, u32, u16, Vec<u8>);
+// pyi: Callable[[str, int, int | None, int, int | None], object]
+type ProgressCallback = PyObject;
+// pyi: Callable[[int, str, bytes], tuple[list[tuple[float, float, float]], list[tuple[int, int, int]]] | None]
+type TessellatorCallback = PyObject;
+// pyi: int | str
+type RecoveredFieldValue = PyObject;
+
+/// A block, style or type name that repeats across result rows.
+///
+/// Converted through the Python string intern table, so all rows naming the
+/// same block or type share one `str` object instead of each holding a copy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InternedName(String);
+
+impl std::ops::Deref for InternedName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for InternedName {
+    fn from(name: String) -> Self {
+        Self(name)
+    }
+}
+
+impl From<&str> for InternedName {
+    fn from(name: &str) -> Self {
+        Self(name.to_string())
+    }
+}
+
+impl IntoPy<PyObject> for InternedName {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        PyString::intern_bound(py, &self.0).into_any().unbind()
+    }
+}
+
+type SectionLocatorRow = (String, u64, u64);
+type FileLayoutRow = (String, String, bool, Vec<String>, Vec<SectionLocatorRow>);
+type JulianDateRow = (u32, u32);
+type DrawingTimesRow = (JulianDateRow, JulianDateRow, JulianDateRow, JulianDateRow);
+type ObjectMapEntryRow = (u64, u64);
+type DecodeStatRow = (String, u64, u64, f64);
+type DecodeStatsRow = (Vec<DecodeStatRow>, Vec<DecodeStatRow>, Vec<DecodeStatRow>);
+type AcDsRecordRow = (u64, u32, Option<String>, Py<PyBytes>);
+type SolidMeshRow = (u64, Vec<Point3>, Vec<(u32, u32, u32)>);
+type ObjectHeaderRow = (u64, u64, u32, u16);
+type ObjectHeaderWithTypeRow = (u64, u64, u32, u16, InternedName, InternedName);
+type ObjectRecordBytesRow = (u64, u64, u32, u16, Vec<u8>);
+type ObjectTraceRow = (String, u64, u64, String, String);
+type RecoveredFieldRow = (
+    u64,
+    String,
+    RecoveredFieldValue,
+    Option<RecoveredFieldValue>,
+    String,
+);
+type ExplainObjectRow = (
+    u64,
+    u64,
+    u16,
+    String,
+    Py<PyBytes>,
+    Vec<ObjectTraceRow>,
+    Option<String>,
+    Option<String>,
+);
 type HandleStreamRefsRow = (u64, Vec<u64>);
+type HandleStreamRefKindsRow = (u64, Vec<(u64, String)>);
 type AcisCandidateInfoRow = (u64, u16, u32, String, Vec<u64>, u8);
 type ProxyGraphicTextRow = (u64, u16, u32, String, Point3, Point3, f64, f64, f64);
 type ProxyGraphicChunkInfoRow = (u64, u16, u32, u32, u32);
+/// `(handle, color_index, true_color, layer_handle)`. The entity structs
+/// carry `None` for a layer reference that could not be read; this row keeps
+/// the integer it always had and reports 0 for it.
 type EntityStyleRow = (u64, Option<u16>, Option<u32>, u64);
+type EntityColorRow = (u64, InternedName, Option<u16>, Option<u32>, u64);
+type EntityTransparencyRow = (u64, Option<u32>);
+type EntityThicknessRow = (u64, f64, f64, Point3);
+type EntityLineStyleRow = (u64, u8, Option<u64>, f64, i16);
 type ObjectLayerHandleRow = (u64, u64);
 type LayerColorRow = (u64, u16, Option<u32>);
+/// `(handle, flags, is_off)`, flags as the DXF group 70 bits.
+type LayerStateRow = (u64, u16, bool);
+type LayerTransparencyRow = (u64, Option<u32>);
 type LayerNameRow = (u64, String);
+type TableControlRow = (u64, String, u32, Vec<u64>, Vec<(String, u64)>);
+type TableEntryNameRow = (u64, Option<String>);
+type LinetypeDashRow = (f64, u16, u16);
+type LinetypeRow = (
+    u64,
+    Option<String>,
+    Option<String>,
+    f64,
+    Vec<LinetypeDashRow>,
+);
+type TextStyleRow = (
+    u64,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    f64,
+    f64,
+    f64,
+    bool,
+);
+/// `(dimscale, dimasz, dimexo, dimexe, dimdle, dimtxt, dimcen, dimtsz,
+/// dimlfac, dimgap)`.
+type DimStyleSizesRow = (f64, f64, f64, f64, f64, f64, f64, f64, f64, f64);
+/// `(dimse1, dimse2, dimtih, dimtoh)`.
+type DimStyleFlagsRow = (bool, bool, bool, bool);
+type DimStyleEntryRow = (
+    u64,
+    Option<String>,
+    DimStyleSizesRow,
+    u16,
+    u16,
+    DimStyleFlagsRow,
+);
+type ViewRow = (
+    u64,
+    Option<String>,
+    (f64, f64),
+    Point2,
+    Point3,
+    Point3,
+    f64,
+    f64,
+    (f64, f64),
+    u8,
+    u8,
+    (bool, bool),
+);
+type MaterialColorRow = (u8, f64, Option<u32>);
+type MaterialRow = (
+    u64,
+    Option<String>,
+    Option<String>,
+    MaterialColorRow,
+    MaterialColorRow,
+    Option<String>,
+);
+type VisualStyleRow = (u64, Option<String>, u32);
+type ScaleRow = (u64, Option<String>, f64, f64, bool);
+type LightEntityRow = (
+    u64,
+    Option<String>,
+    u32,
+    bool,
+    (u16, Option<u32>),
+    f64,
+    Point3,
+    Point3,
+    u32,
+    f64,
+    f64,
+    bool,
+);
+type RTextEntityRow = (u64, Point3, Point3, f64, f64, u16, Option<String>, Option<u64>);
+type ArcAlignedTextEntityRow = (
+    u64,
+    Option<String>,
+    (Option<String>, Option<String>, Option<String>),
+    Point3,
+    f64,
+    (f64, f64),
+    (f64, f64, f64),
+    (f64, f64, f64),
+    (bool, u16, u16, u16),
+    bool,
+    Point3,
+    Option<u64>,
+);
+type EntityMaterialRow = (u64, u8, Option<u64>);
+type LayerMaterialRow = (u64, Option<u64>);
+type PlotSettingsRow = (
+    (Option<String>, Option<String>, Option<String>, Option<String>),
+    u16,
+    (f64, f64),
+    (f64, f64, f64, f64),
+    (f64, f64),
+    u16,
+    u16,
+    u16,
+    ((f64, f64), (f64, f64)),
+    (f64, f64, u16, f64),
+);
+type LayoutRow = (
+    u64,
+    Option<String>,
+    u32,
+    u16,
+    ((f64, f64), (f64, f64)),
+    (Point3, Point3),
+    PlotSettingsRow,
+);
+type TableStyleColorRow = (u16, Option<u32>);
+type TableStyleBorderRow = (i16, bool, u16, Option<u32>);
+type TableStyleCellRow = (
+    Option<u64>,
+    f64,
+    u16,
+    TableStyleColorRow,
+    TableStyleColorRow,
+    bool,
+    Vec<TableStyleBorderRow>,
+);
+type TableStyleRow = (
+    u64,
+    Option<String>,
+    u16,
+    u16,
+    f64,
+    f64,
+    bool,
+    bool,
+    Vec<TableStyleCellRow>,
+);
+
+#[cfg(feature = "raster")]
+type RasterStrokeRow = (Vec<(f64, f64)>, (u8, u8, u8), bool);
 
 type LineEntityRow = (u64, f64, f64, f64, f64, f64, f64);
 type PointEntityRow = (u64, f64, f64, f64, f64);
 type ArcEntityRow = (u64, f64, f64, f64, f64, f64, f64);
 type CircleEntityRow = (u64, f64, f64, f64, f64);
 type LineArcCircleRows = (Vec<LineEntityRow>, Vec<ArcEntityRow>, Vec<CircleEntityRow>);
+type ResolvedStyleRow = (Option<InternedName>, Option<u32>, Option<InternedName>, i16);
+type StyledEntityRows = (
+    Vec<(LineEntityRow, ResolvedStyleRow)>,
+    Vec<(ArcEntityRow, ResolvedStyleRow)>,
+    Vec<(CircleEntityRow, ResolvedStyleRow)>,
+    Vec<(PointEntityRow, ResolvedStyleRow)>,
+    Vec<(LwPolylineEntityRow, ResolvedStyleRow)>,
+);
 type EllipseEntityRow = (u64, Point3, Point3, Point3, f64, f64, f64);
 type SplineFlagsRow = (u32, u32, bool, bool, bool);
 type SplineToleranceRow = (Option<f64>, Option<f64>, Option<f64>);
+type SplineTangentsRow = (Option<Point3>, Option<Point3>);
 type SplineEntityRow = (
     u64,
     SplineFlagsRow,
@@ -46,6 +276,7 @@ type SplineEntityRow = (
     Vec<Point3>,
     Vec<f64>,
     Vec<Point3>,
+    SplineTangentsRow,
 );
 type TextMetricsRow = (f64, f64, f64, f64, f64);
 type TextAlignmentRow = (u16, u16, u16);
@@ -89,8 +320,21 @@ type MTextEntityRow = (
     Option<u64>,
 );
 type LeaderEntityRow = (u64, u16, u16, Vec<Point3>);
+type MLeaderRootRow = (Point3, Point3, f64, Vec<Vec<Point3>>);
+type MLeaderEntityRow = (u64, Vec<MLeaderRootRow>, Option<String>, Point3, Point3, f64, f64, u16);
 type HatchPathRow = (bool, Vec<Point2>);
-type HatchEntityRow = (u64, String, bool, bool, f64, Point3, Vec<HatchPathRow>);
+type HatchPatternLineRow = (f64, Point2, Point2, Vec<f64>);
+type HatchPatternRow = (u16, u16, f64, f64, bool, Vec<HatchPatternLineRow>);
+type HatchEntityRow = (
+    u64,
+    InternedName,
+    bool,
+    bool,
+    f64,
+    Point3,
+    Vec<HatchPathRow>,
+    Option<HatchPatternRow>,
+);
 type ToleranceEntityRow = (u64, String, Point3, Point3, Point3, f64, f64, Option<u64>);
 type MLineVertexRow = (Point3, Point3, Point3);
 type MLineEntityRow = (
@@ -104,11 +348,23 @@ type MLineEntityRow = (
     Vec<MLineVertexRow>,
     Option<u64>,
 );
-type MInsertArrayRow = (u16, u16, f64, f64, Option<String>);
+type MInsertArrayRow = (u16, u16, f64, f64, Option<InternedName>);
 type DimExtrusionScaleRow = (Point3, Point3);
 type DimAnglesRow = (f64, f64, f64, f64);
 type DimStyleRow = (u8, Option<f64>, Option<u16>, Option<u16>, Option<f64>, f64);
 type DimHandlesRow = (Option<u64>, Option<u64>);
+/// `(point15, point16, is_x_type, point17, is_partial, arc_params,
+/// has_leader, leader_length)`.
+type DimExtraRow = (
+    Option<Point3>,
+    Option<Point3>,
+    Option<bool>,
+    Option<Point3>,
+    Option<bool>,
+    Option<(f64, f64)>,
+    Option<bool>,
+    Option<f64>,
+);
 type DimEntityRow = (
     u64,
     String,
@@ -121,15 +377,16 @@ type DimEntityRow = (
     DimAnglesRow,
     DimStyleRow,
     DimHandlesRow,
+    DimExtraRow,
 );
-type DimTypedEntityRow = (String, DimEntityRow);
+type DimTypedEntityRow = (InternedName, DimEntityRow);
 type DimLinearDecodeFn = for<'a> fn(
     &mut BitReader<'a>,
     &version::DwgVersion,
     &ApiObjectHeader,
     u64,
 ) -> crate::core::result::Result<entities::DimLinearEntity>;
-type InsertEntityRow = (u64, f64, f64, f64, f64, f64, f64, f64, Option<String>);
+type InsertEntityRow = (u64, f64, f64, f64, f64, f64, f64, f64, Option<InternedName>);
 type InsertOwnerRow = (u64, Option<u64>);
 type MInsertEntityRow = (u64, f64, f64, f64, f64, f64, f64, f64, MInsertArrayRow);
 type InsertMInsertRows = (Vec<InsertEntityRow>, Vec<MInsertEntityRow>);
@@ -138,8 +395,18 @@ type InsertMInsertDimensionRows = (
     Vec<MInsertEntityRow>,
     Vec<DimTypedEntityRow>,
 );
-type BlockHeaderNameRow = (u64, String);
-type BlockEntityNameRow = (u64, String, String);
+type BlockHeaderNameRow = (u64, InternedName);
+type BlockEntityNameRow = (u64, InternedName, InternedName);
+type XrefBlockRow = (u64, String, String, bool);
+type BlockNestingRow = (Vec<(u64, usize)>, Option<&'static str>, Vec<u64>);
+type DrawingIndexRow = (
+    String,
+    String,
+    Vec<String>,
+    Vec<XrefBlockRow>,
+    Vec<String>,
+    Option<String>,
+);
 type BlockEntityNameMapsRows = (Vec<BlockHeaderNameRow>, Vec<BlockHeaderNameRow>);
 type Polyline2dEntityRow = (u64, u16, u16, f64, f64, f64, f64);
 type Polyline2dInterpretedRow = (
@@ -165,9 +432,25 @@ type VertexMeshEntityRow = (u64, u8, f64, f64, f64);
 type PolylineMeshVerticesRow = (u64, u16, u16, u16, bool, Vec<Point3>);
 type PolylinePFaceEntityRow = (u64, u16, u16);
 type VertexPFaceEntityRow = (u64, u8, f64, f64, f64);
-type VertexPFaceFaceEntityRow = (u64, u16, u16, u16, u16);
-type PFaceFaceRow = (u16, u16, u16, u16);
-type PolylinePFaceFacesRow = (u64, u16, u16, Vec<Point3>, Vec<PFaceFaceRow>);
+type VertexPFaceFaceEntityRow = (u64, i16, i16, i16, i16);
+type PFaceFaceRow = (i16, i16, i16, i16);
+type PFaceFaceColorRow = (Option<u16>, Option<u32>);
+type PolylinePFaceFacesRow = (
+    u64,
+    u16,
+    u16,
+    Vec<Point3>,
+    Vec<PFaceFaceRow>,
+    Vec<PFaceFaceColorRow>,
+);
+type MeshEntityRow = (
+    u64,
+    u32,
+    Vec<Point3>,
+    Vec<Vec<u32>>,
+    Vec<(u32, u32)>,
+    Vec<f64>,
+);
 type Face3dEntityRow = (u64, Point3, Point3, Point3, Point3, u16);
 type SolidEntityRow = (u64, Point3, Point3, Point3, Point3, f64, Point3);
 type TraceEntityRow = (u64, Point3, Point3, Point3, Point3, f64, Point3);
@@ -184,6 +467,7 @@ type ShapeEntityRow = (
     Option<u64>,
 );
 type ViewportEntityRow = (u64,);
+type ViewportFrozenLayersRow = (u64, Vec<u64>);
 type OleFrameEntityRow = (u64,);
 type LongTransactionEntityRow = (
     u64,
@@ -200,66 +484,153 @@ type Solid3dEntityRow = (u64, Vec<u64>);
 type BodyEntityRow = (u64, Vec<u64>);
 type RayEntityRow = (u64, Point3, Point3);
 type XLineEntityRow = (u64, Point3, Point3);
-type PolylineVerticesRow = (u64, u16, Vec<Point3>);
+type Polyline2dVerticesRow = (u64, u16, bool, Vec<Point3>, Vec<f64>, Vec<Point2>, Vec<f64>);
 type PolylineInterpolatedRow = (u64, u16, bool, Vec<Point3>);
 type Vertex2dEntityRow = (u64, u16, f64, f64, f64, f64, f64, f64, f64);
 type VertexDataRow = (f64, f64, f64, f64, f64, f64, f64, u16);
 type PolylineVertexDataRow = (u64, u16, Vec<VertexDataRow>);
 type PolylineSequenceMembersRow = (u64, String, Vec<u64>, Vec<u64>, Option<u64>);
-type TextWriteRow = (u64, String, Point3, f64, f64);
-type MTextWriteRow = (u64, String, Point3, Point3, f64, f64, u16, u16);
-type PointWriteRow = (u64, f64, f64, f64, f64);
+/// Handle of an entity row to write, `None` to let the writer allocate one.
+/// Handle 0 is deprecated: it allocates like `None`, as it did before handles
+/// were optional, and raises a `DeprecationWarning`.
+// pyi: int | None
+type WriteHandle = RowHandle;
+
+/// The handle item of a write row, extracted from `int | None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RowHandle(Option<u64>);
+
+impl<'py> FromPyObject<'py> for RowHandle {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let handle: Option<u64> = ob.extract()?;
+        if handle != Some(0) {
+            return Ok(Self(handle));
+        }
+        let py = ob.py();
+        PyErr::warn_bound(
+            py,
+            &py.get_type_bound::<PyDeprecationWarning>(),
+            "write row handle 0 is deprecated; use None to let the writer allocate a handle",
+            1,
+        )?;
+        Ok(Self(None))
+    }
+}
+
+type LineWriteRow = (WriteHandle, f64, f64, f64, f64, f64, f64);
+type ArcWriteRow = (WriteHandle, f64, f64, f64, f64, f64, f64);
+type CircleWriteRow = (WriteHandle, f64, f64, f64, f64);
+type LwPolylineWriteRow = (WriteHandle, u16, Vec<Point2>, Vec<f64>, Vec<Point2>, Option<f64>);
+type RayWriteRow = (WriteHandle, Point3, Point3);
+type XLineWriteRow = (WriteHandle, Point3, Point3);
+type TextWriteRow = (WriteHandle, String, Point3, f64, f64);
+/// `(handle, align_point, halign, valign, width_factor, oblique)` for a TEXT row.
+type TextAlignmentWriteRow = (u64, Option<Point3>, u16, u16, f64, f64);
+type MTextWriteRow = (WriteHandle, String, Point3, Point3, f64, f64, u16, u16);
+/// `(handle, text, insertion, x_direction, height, dimgap)`.
+type ToleranceWriteRow = (WriteHandle, String, Point3, Point3, f64, f64);
+/// `(handle, annotation_type, path_type, points)`.
+type LeaderWriteRow = (WriteHandle, u16, u16, Vec<Point3>);
+/// `(handle, style_name, scale, justification, closed, points)`.
+type MLineWriteRow = (WriteHandle, String, f64, u8, bool, Vec<Point3>);
+/// `(handle, vertices, faces)`, faces as 1-based vertex indices with 0 for
+/// the unused corner of a triangle and a negative index for a hidden edge.
+type PolyfaceWriteRow = (WriteHandle, Vec<Point3>, Vec<(i16, i16, i16, i16)>);
+/// `(name, description, [(offset, color_index)])`.
+type MLineStyleWriteRow = (String, String, Vec<(f64, u16)>);
+/// `(handle, type_code, class_dxf_name)` of a record copied without decoding.
+type UnknownObjectRow = (u64, u16, Option<String>);
+type PointWriteRow = (WriteHandle, f64, f64, f64, f64);
+type ViewportWriteRow = (WriteHandle, Point3, f64, f64, (f64, f64), f64);
+type LayoutWriteRow = (String, PlotSettingsRow, Vec<ViewportWriteRow>);
+type EntityStyleWriteRow = (u64, Option<String>, Option<i16>, Option<u32>);
+type LinetypeWriteRow = (String, String, Vec<f64>);
+type LayerWriteRow = (String, u16);
+type EntityLayerWriteRow = (u64, String);
+type LayerLinetypeWriteRow = (String, String);
+/// `(layer_name, flags, is_off)`, flags as the DXF group 70 bits.
+type LayerStateWriteRow = (String, u16, bool);
+type EntityColorWriteRow = (u64, u16);
+/// The first three rows of a row-major 4x4 affine matrix.
+type TransformWriteRow = [[f64; 4]; 3];
+/// `(name, description, selectable, entity_handles)`.
+type GroupWriteRow = (String, String, bool, Vec<u64>);
+/// `(group_code, value)` of extended data or an XRECORD.
+type XDataItemRow = (u16, PyObject);
+/// `(entity_handle, app_name, items)`.
+type EntityXDataWriteRow = (u64, String, Vec<XDataItemRow>);
+/// `(name, [(key, items)])`, a dictionary of XRECORDs.
+type DictionaryWriteRow = (String, Vec<(String, Vec<XDataItemRow>)>);
+/// `(handle, owner_handle, [(name, entry_handle)])`.
+type DictionaryRow = (u64, u64, Vec<(String, u64)>);
+/// `(handle, owner_handle, items)`.
+type XRecordRow = (u64, u64, Vec<XDataItemRow>);
+/// `(handle, app_handle, items)` for one extended data block of an object.
+type XDataRow = (u64, u64, Vec<XDataItemRow>);
 
 struct DimDecodeSpec {
-    type_code: u16,
+    /// Fixed type code; `None` for class entities matched by resolved name.
+    type_code: Option<u16>,
     type_name: &'static str,
     dimtype: &'static str,
     decode_entity: DimLinearDecodeFn,
 }
 
-const DIM_DECODE_SPECS: [DimDecodeSpec; 7] = [
+const DIM_DECODE_SPECS: [DimDecodeSpec; 9] = [
     DimDecodeSpec {
-        type_code: 0x15,
+        type_code: Some(0x15),
         type_name: "DIM_LINEAR",
         dimtype: "LINEAR",
         decode_entity: decode_dim_linear_for_version,
     },
     DimDecodeSpec {
-        type_code: 0x14,
+        type_code: Some(0x14),
         type_name: "DIM_ORDINATE",
         dimtype: "ORDINATE",
-        decode_entity: decode_dim_linear_for_version,
+        decode_entity: decode_dim_ordinate_for_version,
     },
     DimDecodeSpec {
-        type_code: 0x16,
+        type_code: Some(0x16),
         type_name: "DIM_ALIGNED",
         dimtype: "ALIGNED",
         decode_entity: decode_dim_linear_for_version,
     },
     DimDecodeSpec {
-        type_code: 0x17,
+        type_code: Some(0x17),
         type_name: "DIM_ANG3PT",
         dimtype: "ANG3PT",
-        decode_entity: decode_dim_linear_for_version,
+        decode_entity: decode_dim_ang3pt_for_version,
     },
     DimDecodeSpec {
-        type_code: 0x18,
+        type_code: Some(0x18),
         type_name: "DIM_ANG2LN",
         dimtype: "ANG2LN",
-        decode_entity: decode_dim_linear_for_version,
+        decode_entity: decode_dim_ang2ln_for_version,
     },
     DimDecodeSpec {
-        type_code: 0x1A,
+        type_code: Some(0x1A),
         type_name: "DIM_DIAMETER",
         dimtype: "DIAMETER",
         decode_entity: decode_dim_diameter_for_version,
     },
     DimDecodeSpec {
-        type_code: 0x19,
+        type_code: Some(0x19),
         type_name: "DIM_RADIUS",
         dimtype: "RADIUS",
         decode_entity: decode_dim_radius_for_version,
     },
+    DimDecodeSpec {
+        type_code: None,
+        type_name: "ARC_DIMENSION",
+        dimtype: "ARC",
+        decode_entity: decode_dim_arc_for_version,
+    },
+    DimDecodeSpec {
+        type_code: None,
+        type_name: "LARGE_RADIAL_DIMENSION",
+        dimtype: "LARGE_RADIAL",
+        decode_entity: decode_dim_large_radial_for_version,
+    },
 ];
 
 struct InsertNameResolutionState {
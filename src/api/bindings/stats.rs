@@ -0,0 +1,100 @@
+// Decode statistics. While `begin_decode_stats` has a collector active, the
+// decoders built here record section loads into it, the version dispatch
+// functions record per-type decode timings and the recovery scans record
+// heuristic timings. The flag keeps the disabled path to one atomic load.
+
+static DECODE_STATS_ENABLED: AtomicBool = AtomicBool::new(false);
+static ACTIVE_DECODE_STATS: Mutex<Option<StatsCollector>> = Mutex::new(None);
+
+fn active_decode_stats() -> Option<StatsCollector> {
+    if !DECODE_STATS_ENABLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    ACTIVE_DECODE_STATS
+        .lock()
+        .ok()
+        .and_then(|active| active.clone())
+}
+
+/// Runs a `decode_*_for_version` function and records it under its type,
+/// counting the body bytes the reader moved over.
+fn time_type_decode<T>(
+    fn_name: &'static str,
+    reader: &mut BitReader<'_>,
+    decode: impl FnOnce(&mut BitReader<'_>) -> T,
+) -> T {
+    let Some(stats) = active_decode_stats() else {
+        return decode(reader);
+    };
+    let start_bit = reader.tell_bits();
+    let start = Instant::now();
+    let result = decode(reader);
+    let elapsed = start.elapsed();
+    let type_name = fn_name
+        .trim_start_matches("decode_")
+        .trim_end_matches("_for_version")
+        .trim_end_matches("_entity")
+        .to_ascii_uppercase();
+    let bytes = reader.tell_bits().saturating_sub(start_bit) / 8;
+    stats.record(StatKind::Type, &type_name, bytes, elapsed);
+    result
+}
+
+fn time_heuristic<T>(name: &str, record_bytes: usize, heuristic: impl FnOnce() -> T) -> T {
+    match active_decode_stats() {
+        Some(stats) => stats.time(StatKind::Heuristic, name, heuristic, |_| {
+            record_bytes as u64
+        }),
+        None => heuristic(),
+    }
+}
+
+fn stat_rows(table: BTreeMap<String, StatTotals>) -> Vec<DecodeStatRow> {
+    table
+        .into_iter()
+        .map(|(name, totals)| {
+            (
+                name,
+                totals.count,
+                totals.bytes,
+                totals.elapsed.as_secs_f64(),
+            )
+        })
+        .collect()
+}
+
+/// Starts collecting decode statistics for every call made until
+/// `end_decode_stats`, from any thread.
+#[pyfunction]
+pub fn begin_decode_stats() -> PyResult<()> {
+    let mut active = ACTIVE_DECODE_STATS
+        .lock()
+        .map_err(|_| PyRuntimeError::new_err("decode stats lock poisoned"))?;
+    if active.is_some() {
+        return Err(PyRuntimeError::new_err(
+            "decode stats are already being collected",
+        ));
+    }
+    *active = Some(StatsCollector::new());
+    DECODE_STATS_ENABLED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Stops collecting and returns `(sections, types, heuristics)`, each a list
+/// of `(name, count, bytes, seconds)` sorted by name.
+#[pyfunction]
+pub fn end_decode_stats() -> PyResult<DecodeStatsRow> {
+    let mut active = ACTIVE_DECODE_STATS
+        .lock()
+        .map_err(|_| PyRuntimeError::new_err("decode stats lock poisoned"))?;
+    DECODE_STATS_ENABLED.store(false, Ordering::Relaxed);
+    let stats = active
+        .take()
+        .ok_or_else(|| PyRuntimeError::new_err("decode stats are not being collected"))?
+        .snapshot();
+    Ok((
+        stat_rows(stats.sections),
+        stat_rows(stats.types),
+        stat_rows(stats.heuristics),
+    ))
+}
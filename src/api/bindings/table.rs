@@ -0,0 +1,489 @@
+#[pyfunction(signature = (path, table))]
+pub fn decode_table_control(path: &str, table: &str) -> PyResult<TableControlRow> {
+    let kind = objects::TableKind::from_name(table)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown table: {table}")))?;
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+
+    let control = decode_table_control_object(&decoder, &dynamic_types, &index, kind, best_effort)?
+        .ok_or_else(|| {
+            PyValueError::new_err(format!("{} object not found", kind.control_type_name()))
+        })?;
+    let mut special_handles = Vec::new();
+    for (name, handle) in [
+        ("*MODEL_SPACE", control.model_space),
+        ("*PAPER_SPACE", control.paper_space),
+        ("BYBLOCK", control.byblock_ltype),
+        ("BYLAYER", control.bylayer_ltype),
+    ] {
+        if let Some(handle) = handle {
+            special_handles.push((name.to_string(), handle));
+        }
+    }
+    for handle in control.extra_handles.iter().copied() {
+        special_handles.push(("EXTRA".to_string(), handle));
+    }
+    Ok((
+        control.handle,
+        control.kind.control_type_name().to_string(),
+        control.num_entries,
+        control.entries,
+        special_handles,
+    ))
+}
+
+/// Handles and names of the entries of a symbol table, in table order.
+/// Names are `None` from R2007 on, where they live in the string stream.
+/// Files without the control object list the entries in object map order.
+#[pyfunction(signature = (path, table))]
+pub fn decode_table_entry_names(path: &str, table: &str) -> PyResult<Vec<TableEntryNameRow>> {
+    let kind = objects::TableKind::from_name(table)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown table: {table}")))?;
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+
+    let offsets: HashMap<u64, u64> = index
+        .objects
+        .iter()
+        .map(|obj| (obj.handle.0, obj.offset))
+        .collect();
+    // Without a control object, the entries are found by type in object
+    // map order.
+    let control = decode_table_control_object(&decoder, &dynamic_types, &index, kind, best_effort)?;
+    let handles = match &control {
+        Some(control) => control.all_entry_handles(),
+        None => index.objects.iter().map(|obj| obj.handle.0).collect(),
+    };
+    let mut result = Vec::with_capacity(handles.len());
+    for handle in handles {
+        let Some(offset) = offsets.get(&handle).copied() else {
+            continue;
+        };
+        let Some((record, header)) = parse_record_and_header(&decoder, offset, best_effort)? else {
+            continue;
+        };
+        if control.is_none() && header.type_code != kind.entry_type_code() {
+            continue;
+        }
+        let mut reader = record.bit_reader();
+        if let Err(err) = skip_object_type_prefix(&mut reader, decoder.version()) {
+            if best_effort {
+                continue;
+            }
+            return Err(to_py_err(err));
+        }
+        match objects::decode_table_entry_name(&mut reader, decoder.version(), handle) {
+            Ok(row) => result.push(row),
+            Err(_) if best_effort => continue,
+            Err(err) if is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        }
+    }
+    Ok(result)
+}
+
+fn decode_table_control_object(
+    decoder: &decoder::Decoder<'_>,
+    dynamic_types: &HashMap<u16, String>,
+    index: &objects::ObjectIndex,
+    kind: objects::TableKind,
+    best_effort: bool,
+) -> PyResult<Option<objects::TableControlObject>> {
+    let type_code = kind.control_type_code();
+    let type_name = kind.control_type_name();
+    for obj in index.objects.iter() {
+        let Some((record, header)) = parse_record_and_header(decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        if !matches_type_name(header.type_code, type_code, type_name, dynamic_types) {
+            continue;
+        }
+        match decode_table_control_record(&record, decoder.version(), kind, obj.handle.0) {
+            Ok(control) => return Ok(Some(control)),
+            Err(err) if best_effort || is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        }
+    }
+    Ok(None)
+}
+
+fn decode_table_control_record(
+    record: &objects::ObjectRecord<'_>,
+    version: &version::DwgVersion,
+    kind: objects::TableKind,
+    object_handle: u64,
+) -> crate::core::result::Result<objects::TableControlObject> {
+    let (mut reader, object_data_end_bit) = object_reader_with_data_end_bit(record, version)?;
+    objects::decode_table_control(&mut reader, version, kind, object_data_end_bit, object_handle)
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_table_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<TableStyleRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+
+    let mut result = Vec::new();
+    for obj in index.objects.iter() {
+        let Some((record, header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        if resolved_type_name(header.type_code, &dynamic_types) != "TABLESTYLE" {
+            continue;
+        }
+        let mut reader = record.bit_reader();
+        if let Err(err) = skip_object_type_prefix(&mut reader, decoder.version()) {
+            if best_effort {
+                continue;
+            }
+            return Err(to_py_err(err));
+        }
+        let style = match objects::decode_table_style(&mut reader, decoder.version(), obj.handle.0)
+        {
+            Ok(style) => style,
+            Err(err) if err.kind == ErrorKind::Unsupported => return Err(to_py_err(err)),
+            Err(_) if best_effort => continue,
+            Err(err) if is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        };
+        result.push(table_style_row(&style));
+        if let Some(limit) = limit {
+            if result.len() >= limit {
+                break;
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_linetypes(path: &str, limit: Option<usize>) -> PyResult<Vec<LinetypeRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let dynamic_types = load_dynamic_types(&decoder, best_effort)?;
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+
+    let mut result = Vec::new();
+    for obj in index.objects.iter() {
+        let Some((record, header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        if resolved_type_name(header.type_code, &dynamic_types) != "LTYPE" {
+            continue;
+        }
+        let mut reader = record.bit_reader();
+        if let Err(err) = skip_object_type_prefix(&mut reader, decoder.version()) {
+            if best_effort {
+                continue;
+            }
+            return Err(to_py_err(err));
+        }
+        let ltype = match objects::decode_ltype(&mut reader, decoder.version(), obj.handle.0) {
+            Ok(ltype) => ltype,
+            Err(_) if best_effort => continue,
+            Err(err) if is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        };
+        let dashes = ltype
+            .dashes
+            .iter()
+            .map(|dash| (dash.length, dash.shape_code, dash.shape_flag))
+            .collect();
+        result.push((
+            ltype.handle,
+            ltype.name,
+            ltype.description,
+            ltype.pattern_length,
+            dashes,
+        ));
+        if let Some(limit) = limit {
+            if result.len() >= limit {
+                break;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// STYLE table entries with their font files, as
+/// `(handle, name, font_file, bigfont_file, width_factor, oblique_angle,
+/// fixed_height, is_shape_file)`.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_text_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<TextStyleRow>> {
+    collect_object_rows(path, limit, "SHAPEFILE", |record, header, version, handle| {
+        let (mut reader, _) = object_reader_with_data_end_bit(record, version)?;
+        let mut style = objects::decode_text_style(&mut reader, version, handle)?;
+        if style.name.is_none() {
+            let strings = read_object_string_stream(
+                record,
+                header,
+                version,
+                objects::TextStyleObject::STRING_STREAM_COUNT,
+                0,
+            )
+            .unwrap_or_default();
+            style.apply_string_stream(&strings);
+        }
+        Ok((
+            style.handle,
+            style.name,
+            style.font_file,
+            style.bigfont_file,
+            style.width_factor,
+            style.oblique_angle,
+            style.fixed_height,
+            style.is_shape_file,
+        ))
+    })
+}
+
+/// `(handle, name, (dimscale, dimasz, dimexo, dimexe, dimdle, dimtxt, dimcen,
+/// dimtsz, dimlfac, dimgap), dimtad, dimdec, (dimse1, dimse2, dimtih,
+/// dimtoh))` of each DIMSTYLE entry.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_dim_styles(path: &str, limit: Option<usize>) -> PyResult<Vec<DimStyleEntryRow>> {
+    collect_object_rows(path, limit, "DIMSTYLE", |record, header, version, handle| {
+        let (mut reader, _) = object_reader_with_data_end_bit(record, version)?;
+        let mut style = objects::decode_dim_style(&mut reader, version, handle)?;
+        if style.name.is_none() {
+            let strings = read_object_string_stream(
+                record,
+                header,
+                version,
+                objects::DimStyleObject::STRING_STREAM_COUNT,
+                0,
+            )
+            .unwrap_or_default();
+            style.apply_string_stream(&strings);
+        }
+        Ok((
+            style.handle,
+            style.name,
+            (
+                style.dimscale,
+                style.dimasz,
+                style.dimexo,
+                style.dimexe,
+                style.dimdle,
+                style.dimtxt,
+                style.dimcen,
+                style.dimtsz,
+                style.dimlfac,
+                style.dimgap,
+            ),
+            style.dimtad,
+            style.dimdec,
+            (style.dimse1, style.dimse2, style.dimtih, style.dimtoh),
+        ))
+    })
+}
+
+/// Named views of the VIEW table as `(handle, name, (width, height), center,
+/// target, direction, twist_angle, lens_length, (front_clip, back_clip),
+/// view_mode, render_mode, (is_paper_space, is_camera_plottable))`. The camera
+/// sits at `target + direction`.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_views(path: &str, limit: Option<usize>) -> PyResult<Vec<ViewRow>> {
+    collect_object_rows(path, limit, "VIEW", |record, header, version, handle| {
+        let (mut reader, _) = object_reader_with_data_end_bit(record, version)?;
+        let mut view = objects::decode_view(&mut reader, version, handle)?;
+        if view.name.is_none() {
+            let strings = read_object_string_stream(
+                record,
+                header,
+                version,
+                objects::ViewObject::STRING_STREAM_COUNT,
+                0,
+            )
+            .unwrap_or_default();
+            view.apply_string_stream(&strings);
+        }
+        Ok((
+            view.handle,
+            view.name,
+            (view.width, view.height),
+            view.center,
+            view.target,
+            view.direction,
+            view.twist_angle,
+            view.lens_length,
+            (view.front_clip, view.back_clip),
+            view.view_mode,
+            view.render_mode,
+            (view.is_paper_space, view.is_camera_plottable),
+        ))
+    })
+}
+
+fn table_style_row(style: &objects::TableStyleObject) -> TableStyleRow {
+    let rows = style
+        .rows
+        .iter()
+        .map(|row| {
+            let borders = row
+                .borders
+                .iter()
+                .map(|border| {
+                    (
+                        border.lineweight,
+                        border.visible,
+                        border.color.index,
+                        border.color.true_color,
+                    )
+                })
+                .collect();
+            (
+                row.text_style_handle,
+                row.text_height,
+                row.text_alignment,
+                (row.text_color.index, row.text_color.true_color),
+                (row.fill_color.index, row.fill_color.true_color),
+                row.fill_enabled,
+                borders,
+            )
+        })
+        .collect();
+    (
+        style.handle,
+        style.description.clone(),
+        style.flow_direction,
+        style.flags,
+        style.horizontal_cell_margin,
+        style.vertical_cell_margin,
+        style.title_suppressed,
+        style.header_suppressed,
+        rows,
+    )
+}
+
+/// DICTIONARY objects with their entries. Entry names of R2007+ files live
+/// in the string stream, so only R14 to R2004 are decoded.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_dictionaries(path: &str, limit: Option<usize>) -> PyResult<Vec<DictionaryRow>> {
+    collect_object_rows(
+        path,
+        limit,
+        "DICTIONARY",
+        |record, _header, version, handle| {
+            let mut reader = record.bit_reader();
+            skip_object_type_prefix(&mut reader, version)?;
+            let dictionary = objects::decode_dictionary(&mut reader, version, handle)?;
+            Ok((
+                dictionary.handle,
+                dictionary.owner_handle,
+                dictionary.entries,
+            ))
+        },
+    )
+}
+
+/// XRECORD objects with their `(group_code, value)` items. Only R14 to
+/// R2004 are decoded.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_xrecords(
+    py: Python<'_>,
+    path: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<XRecordRow>> {
+    let xrecords = collect_object_rows(
+        path,
+        limit,
+        "XRECORD",
+        |record, _header, version, handle| {
+            let mut reader = record.bit_reader();
+            skip_object_type_prefix(&mut reader, version)?;
+            objects::decode_xrecord(&mut reader, version, handle)
+        },
+    )?;
+    Ok(xrecords
+        .into_iter()
+        .map(|xrecord| {
+            (
+                xrecord.handle,
+                xrecord.owner_handle,
+                xdata_item_rows(py, xrecord.items),
+            )
+        })
+        .collect())
+}
+
+/// Extended data blocks of every object, as the object handle, the APPID
+/// handle the block is registered under and its `(group_code, value)`
+/// items. `limit` caps the number of blocks.
+#[pyfunction(signature = (path, limit=None))]
+pub fn decode_xdata(py: Python<'_>, path: &str, limit: Option<usize>) -> PyResult<Vec<XDataRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let decoder = build_decoder(&bytes).map_err(to_py_err)?;
+    let best_effort = is_best_effort_compat_version(&decoder);
+    let index = decoder.build_object_index().map_err(to_py_err)?;
+
+    let mut result = Vec::new();
+    for obj in index.objects.iter() {
+        let Some((record, _header)) = parse_record_and_header(&decoder, obj.offset, best_effort)?
+        else {
+            continue;
+        };
+        let decoded = (|| {
+            let mut reader = record.bit_reader();
+            skip_object_type_prefix(&mut reader, decoder.version())?;
+            let (record_handle, blocks) = objects::read_object_eed(&mut reader, decoder.version())?;
+            let handle = if record_handle != 0 {
+                record_handle
+            } else {
+                obj.handle.0
+            };
+            blocks
+                .into_iter()
+                .map(|(app_handle, data)| {
+                    let items = objects::decode_xdata_items(&data, decoder.version())?;
+                    Ok((handle, app_handle, items))
+                })
+                .collect::<crate::core::result::Result<Vec<_>>>()
+        })();
+        let blocks = match decoded {
+            Ok(blocks) => blocks,
+            Err(_) if best_effort => continue,
+            Err(err) if is_recoverable_decode_error(&err) => continue,
+            Err(err) => return Err(to_py_err(err)),
+        };
+        for (handle, app_handle, items) in blocks {
+            result.push((handle, app_handle, xdata_item_rows(py, items)));
+            if limit.is_some_and(|limit| result.len() >= limit) {
+                return Ok(result);
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn xdata_item_rows(py: Python<'_>, items: Vec<(u16, objects::XDataValue)>) -> Vec<XDataItemRow> {
+    items
+        .into_iter()
+        .map(|(code, value)| {
+            let value = match value {
+                objects::XDataValue::String(text) => text.into_py(py),
+                objects::XDataValue::Real(value) => value.into_py(py),
+                objects::XDataValue::Point(point) => point.into_py(py),
+                objects::XDataValue::Int8(value) => value.into_py(py),
+                objects::XDataValue::Int16(value) => value.into_py(py),
+                objects::XDataValue::Int32(value) => value.into_py(py),
+                objects::XDataValue::Int64(value) => value.into_py(py),
+                objects::XDataValue::Binary(bytes) => PyBytes::new_bound(py, &bytes).into_py(py),
+                objects::XDataValue::Handle(handle) => handle.into_py(py),
+            };
+            (code, value)
+        })
+        .collect()
+}
@@ -4,7 +4,7 @@ fn start_delta_candidates_for_type(type_code: u16) -> &'static [i32] {
     const PAYLOAD_LIKE: &[i32] = &[-32, -24, -16, -8, -4, 0, 4, 8, 16, 24, 32];
     match type_code {
         0x214 | 0x221 => HEADER_LIKE,
-        0x222 | 0x223 | 0x224 | 0x225 => PAYLOAD_LIKE,
+        0x222..=0x225 => PAYLOAD_LIKE,
         _ => DEFAULT,
     }
 }
@@ -16,7 +16,7 @@ fn preferred_ref_type_codes_for_acis_unknown(type_code: u16) -> &'static [u16] {
         // Link table records are expected to link to header/payload records.
         0x214 => &[0x221, 0x222, 0x223, 0x224, 0x225],
         // Payload chunks often refer to link table/header and sometimes sibling payload chunks.
-        0x222 | 0x223 | 0x224 | 0x225 => &[0x214, 0x221, 0x222, 0x223, 0x224, 0x225],
+        0x222..=0x225 => &[0x214, 0x221, 0x222, 0x223, 0x224, 0x225],
         _ => &[],
     }
 }
@@ -61,6 +61,8 @@ fn resolve_handle_stream_start_candidates(
 #[derive(Default)]
 struct KnownHandleRefsDecode {
     refs: Vec<u64>,
+    /// Reference kind of each entry in `refs`.
+    kinds: Vec<HandleRefKind>,
     confidence: u8,
 }
 
@@ -106,7 +108,7 @@ fn acis_unknown_role_hint_from_type_code(type_code: u16, data_size: u32) -> &'st
         0x214 => "acis-link-table",
         0x221 => "acis-header",
         0x222 => "acis-payload-chunk",
-        0x223 | 0x224 | 0x225 => {
+        0x223..=0x225 => {
             if data_size >= 128 {
                 "acis-payload-main"
             } else {
@@ -313,7 +315,7 @@ where
             &mut decode_entity_row,
         ) {
             Ok(entity) => dim_entity_row_from_linear_like(&entity),
-            Err(err) if best_effort => continue,
+            Err(_) if best_effort => continue,
             Err(err) => return Err(to_py_err(err)),
         };
         result.push(row);
@@ -523,19 +525,16 @@ struct PolylineVertexRow {
 }
 
 fn has_large_adjacent_jump(vertices: &[entities::Vertex2dEntity], index: usize) -> bool {
-    let mut max_jump = 0.0f64;
-    if index > 0 {
-        max_jump = max_jump.max(distance_2d(
-            vertices[index - 1].position,
-            vertices[index].position,
-        ));
-    }
-    if index + 1 < vertices.len() {
-        max_jump = max_jump.max(distance_2d(
-            vertices[index].position,
-            vertices[index + 1].position,
-        ));
-    }
+    let window = vertices
+        .get(index.saturating_sub(1)..vertices.len().min(index + 2))
+        .unwrap_or_default();
+    let max_jump = window
+        .windows(2)
+        .filter_map(|pair| match pair {
+            [a, b] => Some(distance_2d(a.position, b.position)),
+            _ => None,
+        })
+        .fold(0.0f64, f64::max);
     max_jump >= 1000.0
 }
 
@@ -628,69 +627,51 @@ fn skip_object_type_prefix(
     reader: &mut BitReader<'_>,
     version: &version::DwgVersion,
 ) -> crate::core::result::Result<u16> {
-    match version {
-        version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            let _handle_stream_size_bits = reader.read_umc()?;
-            let type_code = reader.read_ot_r2010()?;
-            if type_code == 0 {
-                return Err(DwgError::new(ErrorKind::Format, "object type code is zero"));
-            }
-            Ok(type_code)
-        }
-        _ => {
-            let type_code = reader.read_bs()?;
-            if type_code == 0 {
-                return Err(DwgError::new(ErrorKind::Format, "object type code is zero"));
-            }
-            Ok(type_code)
-        }
-    }
+    objects::read_object_type(reader, version)
 }
 
-#[derive(Debug, Clone, Copy)]
-struct ApiObjectHeader {
-    data_size: u32,
-    type_code: u16,
-    handle_stream_size_bits: Option<u32>,
+/// Reader positioned right after the object type prefix, together with the
+/// handle stream start of R2010+ records. Older versions return `None` and
+/// derive it from the `ObjSize` field stored in the object itself.
+fn object_reader_with_data_end_bit<'r>(
+    record: &'r objects::ObjectRecord<'_>,
+    version: &version::DwgVersion,
+) -> crate::core::result::Result<(BitReader<'r>, Option<u32>)> {
+    if !is_r2010_plus_version(version) {
+        let mut reader = record.bit_reader();
+        skip_object_type_prefix(&mut reader, version)?;
+        return Ok((reader, None));
+    }
+
+    let data = record.data_bits(version)?;
+    let mut reader = record.stream_reader(data);
+    reader.set_pos(0, record.body_bit_pos);
+    skip_object_type_prefix(&mut reader, version)?;
+    Ok((reader, Some(data.end)))
 }
 
+type ApiObjectHeader = objects::ObjectHeader;
+
 fn parse_object_header_for_version(
     record: &objects::ObjectRecord<'_>,
     version: &version::DwgVersion,
 ) -> crate::core::result::Result<ApiObjectHeader> {
-    match version {
-        version::DwgVersion::R2010 | version::DwgVersion::R2013 | version::DwgVersion::R2018 => {
-            let header = objects::object_header_r2010::parse_from_record(record)?;
-            Ok(ApiObjectHeader {
-                data_size: header.data_size,
-                type_code: header.type_code,
-                handle_stream_size_bits: Some(header.handle_stream_size_bits),
-            })
-        }
-        _ => {
-            let header = objects::object_header_r2000::parse_from_record(record)?;
-            Ok(ApiObjectHeader {
-                data_size: header.data_size,
-                type_code: header.type_code,
-                handle_stream_size_bits: None,
-            })
-        }
-    }
+    objects::parse_object_header(record, version)
 }
 
-fn parse_record_and_header<'a>(
-    decoder: &decoder::Decoder<'a>,
-    offset: u32,
+fn parse_record_and_header<'d>(
+    decoder: &'d decoder::Decoder<'_>,
+    offset: u64,
     best_effort: bool,
-) -> PyResult<Option<(objects::ObjectRecord<'a>, ApiObjectHeader)>> {
+) -> PyResult<Option<(objects::ObjectRecord<'d>, ApiObjectHeader)>> {
     let record = match decoder.parse_object_record(offset) {
         Ok(record) => record,
-        Err(err) if best_effort => return Ok(None),
+        Err(_) if best_effort => return Ok(None),
         Err(err) => return Err(to_py_err(err)),
     };
     let header = match parse_object_header_for_version(&record, decoder.version()) {
         Ok(header) => header,
-        Err(err) if best_effort => return Ok(None),
+        Err(_) if best_effort => return Ok(None),
         Err(err) => return Err(to_py_err(err)),
     };
     Ok(Some((record, header)))
@@ -808,7 +789,7 @@ fn resolve_r2010_string_stream_ranges_legacy_compat(
     let Ok(low_size_signed) = size_reader.read_rs(Endian::Little) else {
         return Vec::new();
     };
-    let mut stream_size = u32::from(low_size_signed as u16);
+    let mut stream_size = u32::from(low_size_signed);
     if (stream_size & 0x8000) != 0 {
         if size_field_start < 16 {
             return Vec::new();
@@ -819,7 +800,7 @@ fn resolve_r2010_string_stream_ranges_legacy_compat(
         let Ok(high_size_signed) = hi_reader.read_rs(Endian::Little) else {
             return Vec::new();
         };
-        let high_size = u32::from(high_size_signed as u16);
+        let high_size = u32::from(high_size_signed);
         stream_size = (stream_size & 0x7FFF) | (high_size << 15);
     }
 
@@ -1164,18 +1145,6 @@ struct LayerColorParseVariant {
     pre_values_bits: u8,
 }
 
-fn skip_eed(reader: &mut BitReader<'_>) -> crate::core::result::Result<()> {
-    let mut ext_size = reader.read_bs()?;
-    while ext_size > 0 {
-        let _app_handle = reader.read_h()?;
-        for _ in 0..ext_size {
-            let _ = reader.read_rc()?;
-        }
-        ext_size = reader.read_bs()?;
-    }
-    Ok(())
-}
-
 fn is_recoverable_decode_error(err: &DwgError) -> bool {
     matches!(
         err.kind,
@@ -1184,7 +1153,80 @@ fn is_recoverable_decode_error(err: &DwgError) -> bool {
 }
 
 fn build_decoder(bytes: &[u8]) -> crate::core::result::Result<decoder::Decoder<'_>> {
-    decoder::Decoder::new(bytes, Default::default())
+    decoder::Decoder::new(
+        bytes,
+        crate::core::config::ParseConfig {
+            stats: active_decode_stats(),
+            ..Default::default()
+        },
+    )
+}
+
+fn build_decoder_with_progress<'a>(
+    bytes: &'a [u8],
+    progress: &PyProgress,
+) -> crate::core::result::Result<decoder::Decoder<'a>> {
+    decoder::Decoder::new(
+        bytes,
+        crate::core::config::ParseConfig {
+            progress: progress.hook(),
+            stats: active_decode_stats(),
+            ..Default::default()
+        },
+    )
+}
+
+/// Forwards progress to a Python callable as
+/// `progress(stage, objects, objects_total, bytes, bytes_total)`. The first
+/// exception raised by the callable stops further calls and is returned by
+/// [`PyProgress::finish`].
+struct PyProgress {
+    hook: Option<ProgressHook>,
+    error: Arc<Mutex<Option<PyErr>>>,
+}
+
+impl PyProgress {
+    fn new(callback: Option<PyObject>) -> Self {
+        let error: Arc<Mutex<Option<PyErr>>> = Arc::new(Mutex::new(None));
+        let hook = callback.map(|callback| {
+            let error = Arc::clone(&error);
+            ProgressHook::new(move |progress| {
+                let mut error = error.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                if error.is_some() {
+                    return;
+                }
+                Python::with_gil(|py| {
+                    let args = (
+                        progress.stage.as_str(),
+                        progress.objects,
+                        progress.objects_total,
+                        progress.bytes,
+                        progress.bytes_total,
+                    );
+                    if let Err(err) = callback.call1(py, args) {
+                        *error = Some(err);
+                    }
+                });
+            })
+        });
+        Self { hook, error }
+    }
+
+    fn hook(&self) -> Option<ProgressHook> {
+        self.hook.clone()
+    }
+
+    fn finish<T>(self, result: PyResult<T>) -> PyResult<T> {
+        let error = self
+            .error
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        match error {
+            Some(err) => Err(err),
+            None => result,
+        }
+    }
 }
 
 fn to_py_err(err: DwgError) -> PyErr {
@@ -1204,9 +1246,7 @@ fn points_equal_3d(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
 }
 
 fn strip_closure(mut points: Vec<(f64, f64, f64)>) -> Vec<(f64, f64, f64)> {
-    if points.len() > 1 {
-        let first = points[0];
-        let last = *points.last().unwrap();
+    if let (true, Some(&first), Some(&last)) = (points.len() > 1, points.first(), points.last()) {
         if points_equal_3d(first, last) {
             points.pop();
         }
@@ -1,6 +1,11 @@
 #[pyfunction]
-pub fn write_ac1015_line_dwg(output_path: &str, lines: Vec<LineEntityRow>) -> PyResult<()> {
+pub fn write_ac1015_line_dwg(
+    py: Python<'_>,
+    output_path: &str,
+    lines: Vec<LineWriteRow>,
+) -> PyResult<()> {
     write_ac1015_dwg(
+        py,
         output_path,
         lines,
         Vec::new(),
@@ -11,6 +16,36 @@ pub fn write_ac1015_line_dwg(output_path: &str, lines: Vec<LineEntityRow>) -> Py
         None,
         None,
         None,
+        None,
+        None,
+        None,
+        0,
+        30,
+        None,
+        None,
+        None,
+        None,
+        Some(writer::config::DEFAULT_MAX_LWPOLYLINE_VERTICES),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
     )
 }
 
@@ -24,23 +59,757 @@ pub fn write_ac1015_line_dwg(output_path: &str, lines: Vec<LineEntityRow>) -> Py
     mtexts,
     points=None,
     rays=None,
-    xlines=None
+    xlines=None,
+    layouts=None,
+    application_name=None,
+    application_version=None,
+    maintenance_version=0,
+    codepage=30,
+    drawing_times=None,
+    styles=None,
+    linetypes=None,
+    progress=None,
+    max_lwpolyline_vertices=Some(writer::config::DEFAULT_MAX_LWPOLYLINE_VERTICES),
+    arc_fit_tolerance=None,
+    text_alignments=None,
+    tolerances=None,
+    leaders=None,
+    mlines=None,
+    mline_styles=None,
+    unknown_objects_from=None,
+    header_from=None,
+    layers=None,
+    entity_layers=None,
+    entity_colors=None,
+    transform=None,
+    groups=None,
+    entity_xdata=None,
+    dictionaries=None,
+    layer_linetypes=None,
+    encode_threads=None,
+    handle_namespaces=false,
+    polyfaces=None,
+    layer_states=None
 ))]
 pub fn write_ac1015_dwg(
+    py: Python<'_>,
+    output_path: &str,
+    lines: Vec<LineWriteRow>,
+    arcs: Vec<ArcWriteRow>,
+    circles: Vec<CircleWriteRow>,
+    lwpolylines: Vec<LwPolylineWriteRow>,
+    texts: Vec<TextWriteRow>,
+    mtexts: Vec<MTextWriteRow>,
+    points: Option<Vec<PointWriteRow>>,
+    rays: Option<Vec<RayWriteRow>>,
+    xlines: Option<Vec<XLineWriteRow>>,
+    layouts: Option<Vec<LayoutWriteRow>>,
+    application_name: Option<String>,
+    application_version: Option<String>,
+    maintenance_version: u8,
+    codepage: u16,
+    drawing_times: Option<DrawingTimesRow>,
+    styles: Option<Vec<EntityStyleWriteRow>>,
+    linetypes: Option<Vec<LinetypeWriteRow>>,
+    progress: Option<ProgressCallback>,
+    max_lwpolyline_vertices: Option<usize>,
+    arc_fit_tolerance: Option<f64>,
+    text_alignments: Option<Vec<TextAlignmentWriteRow>>,
+    tolerances: Option<Vec<ToleranceWriteRow>>,
+    leaders: Option<Vec<LeaderWriteRow>>,
+    mlines: Option<Vec<MLineWriteRow>>,
+    mline_styles: Option<Vec<MLineStyleWriteRow>>,
+    unknown_objects_from: Option<&str>,
+    header_from: Option<&str>,
+    layers: Option<Vec<LayerWriteRow>>,
+    entity_layers: Option<Vec<EntityLayerWriteRow>>,
+    entity_colors: Option<Vec<EntityColorWriteRow>>,
+    transform: Option<TransformWriteRow>,
+    groups: Option<Vec<GroupWriteRow>>,
+    entity_xdata: Option<Vec<EntityXDataWriteRow>>,
+    dictionaries: Option<Vec<DictionaryWriteRow>>,
+    layer_linetypes: Option<Vec<LayerLinetypeWriteRow>>,
+    encode_threads: Option<usize>,
+    handle_namespaces: bool,
+    polyfaces: Option<Vec<PolyfaceWriteRow>>,
+    layer_states: Option<Vec<LayerStateWriteRow>>,
+) -> PyResult<()> {
+    if encode_threads == Some(0) {
+        return Err(PyValueError::new_err("encode_threads must be at least 1"));
+    }
+    let mline_styles: Vec<writer::MLineStyleDef> = mline_styles
+        .unwrap_or_default()
+        .into_iter()
+        .map(writer_mline_style_from_row)
+        .collect();
+    let mut modelspace = writer_entities_from_rows(
+        lines,
+        arcs,
+        circles,
+        lwpolylines,
+        texts,
+        mtexts,
+        points,
+        rays,
+        xlines,
+        tolerances,
+        leaders,
+        Some(256),
+    );
+    apply_writer_style_rows(&mut modelspace, styles.unwrap_or_default());
+    apply_text_alignment_rows(&mut modelspace, text_alignments.unwrap_or_default());
+    push_mline_rows(
+        &mut modelspace,
+        mlines.unwrap_or_default(),
+        &mline_styles,
+        Some(256),
+    );
+    push_polyface_rows(&mut modelspace, polyfaces.unwrap_or_default(), Some(256));
+    apply_entity_layer_rows(&mut modelspace, entity_layers.unwrap_or_default());
+    apply_entity_color_rows(&mut modelspace, entity_colors.unwrap_or_default());
+    apply_entity_xdata_rows(&mut modelspace, entity_xdata.unwrap_or_default())?;
+
+    let doc = writer::WriterDocument {
+        version: DwgVersion::R2000,
+        modelspace,
+        layers: writer_layers_from_rows(
+            layers.unwrap_or_default(),
+            layer_linetypes.unwrap_or_default(),
+            layer_states.unwrap_or_default(),
+        ),
+        linetypes: linetypes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, description, dashes)| writer::LinetypeDef {
+                name,
+                description,
+                dashes,
+            })
+            .collect(),
+        mline_styles,
+        layouts: layouts
+            .unwrap_or_default()
+            .into_iter()
+            .map(writer_layout_from_row)
+            .collect(),
+        raw_objects: match unknown_objects_from {
+            Some(path) => Arc::clone(&cached_writer_template(path)?.raw_objects),
+            None => Arc::from([]),
+        },
+        groups: groups
+            .unwrap_or_default()
+            .into_iter()
+            .map(
+                |(name, description, selectable, entities)| writer::GroupDef {
+                    name,
+                    description,
+                    selectable,
+                    entities,
+                },
+            )
+            .collect(),
+        dictionaries: dictionaries
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, records)| {
+                Ok(writer::DictionaryDef {
+                    name,
+                    records: records
+                        .into_iter()
+                        .map(|(key, items)| Ok((key, xdata_items_from_rows(items)?)))
+                        .collect::<PyResult<_>>()?,
+                })
+            })
+            .collect::<PyResult<_>>()?,
+        metadata: writer::WriterMetadata {
+            times: drawing_times.map(writer_drawing_times_from_row),
+            header_template: match header_from {
+                Some(path) => Some(Arc::clone(&cached_writer_template(path)?.header)),
+                None => None,
+            },
+            ..writer::WriterMetadata::default()
+        },
+    };
+    let progress = PyProgress::new(progress);
+    let config = writer::WriterConfig {
+        application_name,
+        application_version,
+        maintenance_version,
+        codepage,
+        progress: progress.hook(),
+        max_lwpolyline_vertices,
+        arc_fit_tolerance,
+        transform: transform
+            .map(writer::DrawingTransform::from_matrix)
+            .transpose()
+            .map_err(to_py_err)?,
+        encode_threads,
+        handle_layout: handle_namespaces.then(writer::HandleLayout::default),
+        ..writer::WriterConfig::default()
+    };
+    // Encoding runs without the GIL so that threads stamping outputs from
+    // one template write in parallel.
+    py.allow_threads(|| {
+        stream_output_file(output_path, |writer| {
+            let result =
+                writer::r2000::write_document_to(writer, &doc, &config).map_err(to_py_err);
+            progress.finish(result).map(|_| ())
+        })
+    })
+}
+
+// Templates read for `unknown_objects_from` and `header_from`, by path. An
+// entry is reused while the file keeps its size and modification time, so
+// stamping many outputs from one template reads and parses it once.
+type TemplateStamp = (u64, Option<std::time::SystemTime>);
+static WRITER_TEMPLATES: Mutex<Vec<(String, TemplateStamp, Arc<writer::WriterTemplate>)>> =
+    Mutex::new(Vec::new());
+const MAX_WRITER_TEMPLATES: usize = 4;
+
+fn cached_writer_template(path: &str) -> PyResult<Arc<writer::WriterTemplate>> {
+    let metadata = std::fs::metadata(path).map_err(|err| PyIOError::new_err(err.to_string()))?;
+    let stamp = (metadata.len(), metadata.modified().ok());
+    let lock = || {
+        WRITER_TEMPLATES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    };
+    if let Some((_, _, template)) = lock()
+        .iter()
+        .find(|(cached, cached_stamp, _)| cached == path && *cached_stamp == stamp)
+    {
+        return Ok(Arc::clone(template));
+    }
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let template = Arc::new(writer::WriterTemplate::from_bytes(&bytes).map_err(to_py_err)?);
+    let mut templates = lock();
+    templates.retain(|(cached, _, _)| cached != path);
+    if templates.len() >= MAX_WRITER_TEMPLATES {
+        templates.remove(0);
+    }
+    templates.push((path.to_string(), stamp, Arc::clone(&template)));
+    Ok(template)
+}
+
+/// Records of the AC1015 file at `path` that ezdwg cannot decode, which
+/// `write_ac1015_dwg(..., unknown_objects_from=path)` copies.
+#[pyfunction]
+pub fn list_unknown_objects(path: &str) -> PyResult<Vec<UnknownObjectRow>> {
+    let bytes = file_open::read_file(path).map_err(to_py_err)?;
+    let objects = writer::r2000::collect_unknown_objects(&bytes).map_err(to_py_err)?;
+    let mut rows = Vec::with_capacity(objects.len());
+    for object in objects {
+        let type_code = BitReader::new(&object.data).read_bs().map_err(to_py_err)?;
+        rows.push((
+            object.handle,
+            type_code,
+            object.class.map(|class| class.dxf_name),
+        ));
+    }
+    Ok(rows)
+}
+
+/// Rewrites the handles in an AC1015 object record, as returned by
+/// `read_object_records_by_handle`, through `remap`.
+#[pyfunction]
+pub fn remap_object_record_handles(
+    py: Python<'_>,
+    record: Vec<u8>,
+    remap: HashMap<u64, u64>,
+) -> PyResult<Py<PyBytes>> {
+    let record = writer::r2000::remap_record_handles(&record, &remap).map_err(to_py_err)?;
+    Ok(PyBytes::new_bound(py, &record).unbind())
+}
+
+/// Replaces existing entities of the AC1015 file at `path` by appending new
+/// records and an object map, and writes the result to `output_path`.
+///
+/// Rows must carry the handle of the entity they replace. Entities keep
+/// their original owner, layer, linetype, lineweight and color.
+#[pyfunction(signature = (
+    path,
+    output_path,
+    lines,
+    arcs,
+    circles,
+    lwpolylines,
+    texts,
+    mtexts,
+    points=None,
+    rays=None,
+    xlines=None,
+    text_alignments=None,
+    tolerances=None,
+    leaders=None
+))]
+pub fn apply_incremental_update(
+    path: &str,
     output_path: &str,
-    lines: Vec<LineEntityRow>,
-    arcs: Vec<ArcEntityRow>,
-    circles: Vec<CircleEntityRow>,
-    lwpolylines: Vec<LwPolylineEntityRow>,
+    lines: Vec<LineWriteRow>,
+    arcs: Vec<ArcWriteRow>,
+    circles: Vec<CircleWriteRow>,
+    lwpolylines: Vec<LwPolylineWriteRow>,
     texts: Vec<TextWriteRow>,
     mtexts: Vec<MTextWriteRow>,
     points: Option<Vec<PointWriteRow>>,
-    rays: Option<Vec<RayEntityRow>>,
-    xlines: Option<Vec<XLineEntityRow>>,
+    rays: Option<Vec<RayWriteRow>>,
+    xlines: Option<Vec<XLineWriteRow>>,
+    text_alignments: Option<Vec<TextAlignmentWriteRow>>,
+    tolerances: Option<Vec<ToleranceWriteRow>>,
+    leaders: Option<Vec<LeaderWriteRow>>,
 ) -> PyResult<()> {
+    let original = file_open::read_file(path).map_err(to_py_err)?;
+    let mut entities = writer_entities_from_rows(
+        lines,
+        arcs,
+        circles,
+        lwpolylines,
+        texts,
+        mtexts,
+        points,
+        rays,
+        xlines,
+        tolerances,
+        leaders,
+        None,
+    );
+    apply_text_alignment_rows(&mut entities, text_alignments.unwrap_or_default());
+    let bytes =
+        writer::r2000::apply_incremental_update(&original, &entities).map_err(to_py_err)?;
+    write_output_file(output_path, &bytes)
+}
+
+/// Replaces the text of TEXT, ATTRIB and MTEXT entities of the AC1015 file
+/// at `path` and writes the result to `output_path`. `edits` pairs entity
+/// handles with their new text.
+#[pyfunction]
+pub fn replace_entity_text(
+    path: &str,
+    output_path: &str,
+    edits: Vec<(u64, String)>,
+) -> PyResult<()> {
+    let original = file_open::read_file(path).map_err(to_py_err)?;
+    let bytes = writer::r2000::replace_entity_text(&original, &edits).map_err(to_py_err)?;
+    write_output_file(output_path, &bytes)
+}
+
+/// Replaces the entities of the block named `block_name` in the AC1015 file
+/// at `path` and writes the result to `output_path`. INSERTs of the block
+/// keep pointing at it.
+///
+/// Row handles only key `styles`, `entity_layers` and `entity_colors`; the
+/// new entities get handles of their own.
+#[pyfunction(signature = (
+    path,
+    output_path,
+    block_name,
+    lines,
+    arcs,
+    circles,
+    lwpolylines,
+    texts,
+    mtexts,
+    points=None,
+    rays=None,
+    xlines=None,
+    text_alignments=None,
+    tolerances=None,
+    leaders=None,
+    styles=None,
+    entity_layers=None,
+    entity_colors=None
+))]
+pub fn redefine_block(
+    path: &str,
+    output_path: &str,
+    block_name: &str,
+    lines: Vec<LineWriteRow>,
+    arcs: Vec<ArcWriteRow>,
+    circles: Vec<CircleWriteRow>,
+    lwpolylines: Vec<LwPolylineWriteRow>,
+    texts: Vec<TextWriteRow>,
+    mtexts: Vec<MTextWriteRow>,
+    points: Option<Vec<PointWriteRow>>,
+    rays: Option<Vec<RayWriteRow>>,
+    xlines: Option<Vec<XLineWriteRow>>,
+    text_alignments: Option<Vec<TextAlignmentWriteRow>>,
+    tolerances: Option<Vec<ToleranceWriteRow>>,
+    leaders: Option<Vec<LeaderWriteRow>>,
+    styles: Option<Vec<EntityStyleWriteRow>>,
+    entity_layers: Option<Vec<EntityLayerWriteRow>>,
+    entity_colors: Option<Vec<EntityColorWriteRow>>,
+) -> PyResult<()> {
+    let original = file_open::read_file(path).map_err(to_py_err)?;
+    let mut entities = writer_entities_from_rows(
+        lines,
+        arcs,
+        circles,
+        lwpolylines,
+        texts,
+        mtexts,
+        points,
+        rays,
+        xlines,
+        tolerances,
+        leaders,
+        Some(256),
+    );
+    apply_writer_style_rows(&mut entities, styles.unwrap_or_default());
+    apply_text_alignment_rows(&mut entities, text_alignments.unwrap_or_default());
+    apply_entity_layer_rows(&mut entities, entity_layers.unwrap_or_default());
+    apply_entity_color_rows(&mut entities, entity_colors.unwrap_or_default());
+    let bytes =
+        writer::r2000::redefine_block(&original, block_name, &entities).map_err(to_py_err)?;
+    write_output_file(output_path, &bytes)
+}
+
+/// Removes the entities on `layers` from the AC1015 file at `path` and
+/// writes the result to `output_path`. Returns the handles of the removed
+/// entities, including the ATTRIBs, VERTEXes and SEQENDs they own.
+#[pyfunction]
+pub fn strip_layers(path: &str, output_path: &str, layers: Vec<String>) -> PyResult<Vec<u64>> {
+    let original = file_open::read_file(path).map_err(to_py_err)?;
+    let names: Vec<&str> = layers.iter().map(String::as_str).collect();
+    let (bytes, removed) = writer::r2000::strip_layers(&original, &names).map_err(to_py_err)?;
+    write_output_file(output_path, &bytes)?;
+    Ok(removed)
+}
+
+fn writer_drawing_times_from_row(row: DrawingTimesRow) -> DrawingTimes {
+    let (created, updated, edit_time, user_timer) = row;
+    DrawingTimes {
+        created: JulianDate::new(created.0, created.1),
+        updated: JulianDate::new(updated.0, updated.1),
+        edit_time: JulianDate::new(edit_time.0, edit_time.1),
+        user_timer: JulianDate::new(user_timer.0, user_timer.1),
+    }
+}
+
+/// Sets the linetype, lineweight and transparency of the entities whose
+/// row handle matches a style row.
+fn apply_writer_style_rows(entities: &mut [writer::WriterEntity], styles: Vec<EntityStyleWriteRow>) {
+    if styles.is_empty() {
+        return;
+    }
+    let styles: HashMap<u64, EntityStyleWriteRow> =
+        styles.into_iter().map(|row| (row.0, row)).collect();
+    for entity in entities {
+        let common = writer::r2000::entity_props_mut(entity);
+        let Some((_, linetype_name, lineweight, transparency)) =
+            common.handle.and_then(|handle| styles.get(&handle))
+        else {
+            continue;
+        };
+        common.linetype_name = linetype_name.clone();
+        common.lineweight = *lineweight;
+        common.transparency = *transparency;
+    }
+}
+
+/// LAYER entries of the `layers` rows; a layer named in `linetypes` or
+/// `states` but missing from `layers` is added with the default color.
+fn writer_layers_from_rows(
+    layers: Vec<LayerWriteRow>,
+    linetypes: Vec<LayerLinetypeWriteRow>,
+    states: Vec<LayerStateWriteRow>,
+) -> Vec<writer::LayerDef> {
+    let mut defs: Vec<writer::LayerDef> = layers
+        .into_iter()
+        .map(|(name, color_index)| writer::LayerDef {
+            name,
+            color_index,
+            ..writer::LayerDef::default()
+        })
+        .collect();
+    for (name, linetype_name) in linetypes {
+        match defs
+            .iter_mut()
+            .find(|def| def.name.eq_ignore_ascii_case(&name))
+        {
+            Some(def) => def.linetype_name = Some(linetype_name),
+            None => defs.push(writer::LayerDef {
+                name,
+                linetype_name: Some(linetype_name),
+                ..writer::LayerDef::default()
+            }),
+        }
+    }
+    for (name, flags, is_off) in states {
+        match defs
+            .iter_mut()
+            .find(|def| def.name.eq_ignore_ascii_case(&name))
+        {
+            Some(def) => {
+                def.flags = flags;
+                def.is_off = is_off;
+            }
+            None => defs.push(writer::LayerDef {
+                name,
+                flags,
+                is_off,
+                ..writer::LayerDef::default()
+            }),
+        }
+    }
+    defs
+}
+
+/// Puts the entities whose row handle matches a layer row on that layer.
+fn apply_entity_layer_rows(
+    entities: &mut [writer::WriterEntity],
+    layers: Vec<EntityLayerWriteRow>,
+) {
+    if layers.is_empty() {
+        return;
+    }
+    let layers: HashMap<u64, String> = layers.into_iter().collect();
+    for entity in entities {
+        let common = writer::r2000::entity_props_mut(entity);
+        if let Some(layer_name) = common.handle.and_then(|handle| layers.get(&handle)) {
+            common.layer_name = layer_name.clone();
+        }
+    }
+}
+
+/// Sets the color index of the entities whose row handle matches a color
+/// row.
+fn apply_entity_color_rows(
+    entities: &mut [writer::WriterEntity],
+    colors: Vec<EntityColorWriteRow>,
+) {
+    if colors.is_empty() {
+        return;
+    }
+    let colors: HashMap<u64, u16> = colors.into_iter().collect();
+    for entity in entities {
+        let common = writer::r2000::entity_props_mut(entity);
+        if let Some(color_index) = common.handle.and_then(|handle| colors.get(&handle)) {
+            common.color_index = Some(*color_index);
+        }
+    }
+}
+
+/// Appends the extended data of each row to the entity whose row handle
+/// matches it.
+fn apply_entity_xdata_rows(
+    entities: &mut [writer::WriterEntity],
+    rows: Vec<EntityXDataWriteRow>,
+) -> PyResult<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+    let mut xdata: HashMap<u64, Vec<(String, Vec<(u16, objects::XDataValue)>)>> = HashMap::new();
+    for (handle, app_name, items) in rows {
+        xdata
+            .entry(handle)
+            .or_default()
+            .push((app_name, xdata_items_from_rows(items)?));
+    }
+    for entity in entities {
+        let common = writer::r2000::entity_props_mut(entity);
+        if let Some(blocks) = common.handle.and_then(|handle| xdata.remove(&handle)) {
+            common.xdata.extend(blocks);
+        }
+    }
+    Ok(())
+}
+
+/// Converts `(group_code, value)` rows to values of the type the group
+/// code holds.
+fn xdata_items_from_rows(rows: Vec<XDataItemRow>) -> PyResult<Vec<(u16, objects::XDataValue)>> {
+    Python::with_gil(|py| {
+        rows.into_iter()
+            .map(|(code, value)| Ok((code, xdata_value_from_py(code, value.bind(py))?)))
+            .collect()
+    })
+}
+
+fn xdata_value_from_py(code: u16, value: &Bound<'_, PyAny>) -> PyResult<objects::XDataValue> {
+    use objects::{XDataKind, XDataValue};
+
+    let kind = objects::group_code_kind(code)
+        .ok_or_else(|| PyValueError::new_err(format!("unsupported group code: {code}")))?;
+    let integer = |value: &Bound<'_, PyAny>| -> PyResult<i64> { value.extract() };
+    let out_of_range =
+        |_| PyValueError::new_err(format!("value out of range for group code {code}"));
+    Ok(match kind {
+        XDataKind::String => XDataValue::String(value.extract()?),
+        XDataKind::Real => XDataValue::Real(value.extract()?),
+        XDataKind::Point => XDataValue::Point(value.extract()?),
+        XDataKind::Int8 => XDataValue::Int8(i8::try_from(integer(value)?).map_err(out_of_range)?),
+        XDataKind::Int16 => {
+            XDataValue::Int16(i16::try_from(integer(value)?).map_err(out_of_range)?)
+        }
+        XDataKind::Int32 => {
+            XDataValue::Int32(i32::try_from(integer(value)?).map_err(out_of_range)?)
+        }
+        XDataKind::Int64 => XDataValue::Int64(integer(value)?),
+        XDataKind::Binary => XDataValue::Binary(value.downcast::<PyBytes>()?.as_bytes().to_vec()),
+        XDataKind::Handle => XDataValue::Handle(value.extract()?),
+    })
+}
+
+/// Sets the alignment, width factor and obliquing of the TEXT entities
+/// whose row handle matches an alignment row.
+fn apply_text_alignment_rows(
+    entities: &mut [writer::WriterEntity],
+    alignments: Vec<TextAlignmentWriteRow>,
+) {
+    if alignments.is_empty() {
+        return;
+    }
+    let alignments: HashMap<u64, TextAlignmentWriteRow> =
+        alignments.into_iter().map(|row| (row.0, row)).collect();
+    for entity in entities {
+        let writer::WriterEntity::Text(text) = entity else {
+            continue;
+        };
+        let Some(&(_, align_point, halign, valign, width_factor, oblique)) =
+            text.common.handle.and_then(|handle| alignments.get(&handle))
+        else {
+            continue;
+        };
+        text.align_point = align_point;
+        text.horizontal_alignment = halign;
+        text.vertical_alignment = valign;
+        text.width_factor = Some(width_factor);
+        text.oblique_rad = oblique;
+    }
+}
+
+fn writer_mline_style_from_row(row: MLineStyleWriteRow) -> writer::MLineStyleDef {
+    let (name, description, elements) = row;
+    writer::MLineStyleDef {
+        name,
+        description,
+        elements: elements
+            .into_iter()
+            .map(|(offset, color_index)| writer::MLineStyleElement {
+                offset,
+                color_index,
+            })
+            .collect(),
+        ..writer::MLineStyleDef::default()
+    }
+}
+
+/// Appends an MLINE for each row, with miters and line offsets taken from
+/// the style of the row in `styles` or the default two-line style.
+fn push_mline_rows(
+    entities: &mut Vec<writer::WriterEntity>,
+    rows: Vec<MLineWriteRow>,
+    styles: &[writer::MLineStyleDef],
+    color_index: Option<u16>,
+) {
+    let standard = writer::MLineStyleDef::default();
+    for (handle, style_name, scale, justification, closed, points) in rows {
+        let style = styles
+            .iter()
+            .find(|style| style.name.eq_ignore_ascii_case(&style_name))
+            .unwrap_or(&standard);
+        let mut mline =
+            writer::MLineEntity::through_points(style, &points, closed, justification, scale);
+        mline.style_name = style_name;
+        mline.common = writer::CommonEntityProps {
+            handle: row_handle(handle),
+            layer_name: "0".to_string(),
+            color_index,
+            true_color: None,
+            ..writer::CommonEntityProps::default()
+        };
+        entities.push(writer::WriterEntity::MLine(mline));
+    }
+}
+
+/// Appends a polyface mesh for each row.
+fn push_polyface_rows(
+    entities: &mut Vec<writer::WriterEntity>,
+    rows: Vec<PolyfaceWriteRow>,
+    color_index: Option<u16>,
+) {
+    for (handle, vertices, faces) in rows {
+        entities.push(writer::WriterEntity::PolyfaceMesh(
+            writer::PolyfaceMeshEntity {
+                common: writer::CommonEntityProps {
+                    handle: row_handle(handle),
+                    layer_name: "0".to_string(),
+                    color_index,
+                    true_color: None,
+                    ..writer::CommonEntityProps::default()
+                },
+                vertices,
+                faces: faces
+                    .into_iter()
+                    .map(|(a, b, c, d)| [a, b, c, d])
+                    .collect(),
+            },
+        ));
+    }
+}
+
+fn write_output_file(output_path: &str, bytes: &[u8]) -> PyResult<()> {
+    stream_output_file(output_path, |writer| {
+        std::io::Write::write_all(writer, bytes).map_err(|err| PyIOError::new_err(err.to_string()))
+    })
+}
+
+/// Runs `write` against a temporary file next to `output_path` and renames
+/// it into place once everything is written, so a failed write leaves any
+/// existing file untouched and never a truncated one.
+fn stream_output_file(
+    output_path: &str,
+    write: impl FnOnce(&mut std::io::BufWriter<std::fs::File>) -> PyResult<()>,
+) -> PyResult<()> {
+    let io_err = |err: std::io::Error| PyIOError::new_err(err.to_string());
+    let out_path = Path::new(output_path);
+    if let Some(parent) = out_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent).map_err(io_err)?;
+        }
+    }
+    let mut temp_name = out_path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(format!(".{}.partial", std::process::id()));
+    let temp_path = out_path.with_file_name(temp_name);
+
+    let result = std::fs::File::create(&temp_path)
+        .map_err(io_err)
+        .and_then(|file| {
+            let mut writer = std::io::BufWriter::new(file);
+            write(&mut writer)?;
+            writer.into_inner().map_err(|err| io_err(err.into_error()))?;
+            std::fs::rename(&temp_path, out_path).map_err(io_err)
+        });
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// The writer handle of an entity row, `None` to allocate one.
+fn row_handle(handle: WriteHandle) -> Option<u64> {
+    handle.0
+}
+
+/// Converts the per-type Python rows into writer entities.
+fn writer_entities_from_rows(
+    lines: Vec<LineWriteRow>,
+    arcs: Vec<ArcWriteRow>,
+    circles: Vec<CircleWriteRow>,
+    lwpolylines: Vec<LwPolylineWriteRow>,
+    texts: Vec<TextWriteRow>,
+    mtexts: Vec<MTextWriteRow>,
+    points: Option<Vec<PointWriteRow>>,
+    rays: Option<Vec<RayWriteRow>>,
+    xlines: Option<Vec<XLineWriteRow>>,
+    tolerances: Option<Vec<ToleranceWriteRow>>,
+    leaders: Option<Vec<LeaderWriteRow>>,
+    color_index: Option<u16>,
+) -> Vec<writer::WriterEntity> {
     let points = points.unwrap_or_default();
     let rays = rays.unwrap_or_default();
     let xlines = xlines.unwrap_or_default();
+    let tolerances = tolerances.unwrap_or_default();
+    let leaders = leaders.unwrap_or_default();
     let mut modelspace = Vec::with_capacity(
         lines.len()
             + arcs.len()
@@ -50,15 +819,18 @@ pub fn write_ac1015_dwg(
             + mtexts.len()
             + points.len()
             + rays.len()
-            + xlines.len(),
+            + xlines.len()
+            + tolerances.len()
+            + leaders.len(),
     );
     for (handle, sx, sy, sz, ex, ey, ez) in lines {
         modelspace.push(writer::WriterEntity::Line(writer::LineEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             start: (sx, sy, sz),
             end: (ex, ey, ez),
@@ -67,10 +839,11 @@ pub fn write_ac1015_dwg(
     for (handle, cx, cy, cz, radius, angle_start, angle_end) in arcs {
         modelspace.push(writer::WriterEntity::Arc(writer::ArcEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             center: (cx, cy, cz),
             radius,
@@ -81,10 +854,11 @@ pub fn write_ac1015_dwg(
     for (handle, cx, cy, cz, radius) in circles {
         modelspace.push(writer::WriterEntity::Circle(writer::CircleEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             center: (cx, cy, cz),
             radius,
@@ -93,10 +867,11 @@ pub fn write_ac1015_dwg(
     for (handle, flags, points, bulges, widths, const_width) in lwpolylines {
         modelspace.push(writer::WriterEntity::LwPolyline(writer::LwPolylineEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             flags,
             vertices: points,
@@ -108,15 +883,17 @@ pub fn write_ac1015_dwg(
     for (handle, text, insertion, height, rotation) in texts {
         modelspace.push(writer::WriterEntity::Text(writer::TextEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             text,
             insert: insertion,
             height,
             rotation_rad: rotation,
+            ..writer::TextEntity::default()
         }));
     }
     for (
@@ -132,10 +909,11 @@ pub fn write_ac1015_dwg(
     {
         modelspace.push(writer::WriterEntity::MText(writer::MTextEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             text,
             insert: insertion,
@@ -149,10 +927,11 @@ pub fn write_ac1015_dwg(
     for (handle, x, y, z, x_axis_angle) in points {
         modelspace.push(writer::WriterEntity::Point(writer::PointEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             location: (x, y, z),
             x_axis_angle,
@@ -161,10 +940,11 @@ pub fn write_ac1015_dwg(
     for (handle, start, unit_vector) in rays {
         modelspace.push(writer::WriterEntity::Ray(writer::RayEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             start,
             unit_vector,
@@ -173,30 +953,46 @@ pub fn write_ac1015_dwg(
     for (handle, start, unit_vector) in xlines {
         modelspace.push(writer::WriterEntity::XLine(writer::XLineEntity {
             common: writer::CommonEntityProps {
-                handle: if handle == 0 { None } else { Some(handle) },
+                handle: row_handle(handle),
                 layer_name: "0".to_string(),
-                color_index: Some(7),
+                color_index,
                 true_color: None,
+                ..writer::CommonEntityProps::default()
             },
             start,
             unit_vector,
         }));
     }
-
-    let doc = writer::WriterDocument {
-        version: DwgVersion::R2000,
-        modelspace,
-        ..writer::WriterDocument::default()
-    };
-    let bytes =
-        writer::r2000::write_document(&doc, &writer::WriterConfig::default()).map_err(to_py_err)?;
-
-    let out_path = Path::new(output_path);
-    if let Some(parent) = out_path.parent() {
-        if !parent.as_os_str().is_empty() {
-            std::fs::create_dir_all(parent).map_err(|err| PyIOError::new_err(err.to_string()))?;
-        }
+    for (handle, text, insertion, x_direction, height, dimgap) in tolerances {
+        modelspace.push(writer::WriterEntity::Tolerance(writer::ToleranceEntity {
+            common: writer::CommonEntityProps {
+                handle: row_handle(handle),
+                layer_name: "0".to_string(),
+                color_index,
+                true_color: None,
+                ..writer::CommonEntityProps::default()
+            },
+            text,
+            insert: insertion,
+            x_direction,
+            height,
+            dimgap,
+        }));
     }
-    std::fs::write(out_path, bytes).map_err(|err| PyIOError::new_err(err.to_string()))?;
-    Ok(())
+    for (handle, annotation_type, path_type, points) in leaders {
+        modelspace.push(writer::WriterEntity::Leader(writer::LeaderEntity {
+            common: writer::CommonEntityProps {
+                handle: row_handle(handle),
+                layer_name: "0".to_string(),
+                color_index,
+                true_color: None,
+                ..writer::CommonEntityProps::default()
+            },
+            annotation_type,
+            path_type,
+            points,
+        }));
+    }
+
+    modelspace
 }
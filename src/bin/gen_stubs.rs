@@ -0,0 +1,541 @@
+//! Generates `src/ezdwg/_core.pyi` from the `#[pyfunction]` signatures in
+//! `src/api/bindings`, so the Python stubs follow the Rust row types. Run
+//! `cargo run --bin gen_stubs` after changing a binding signature; with
+//! `--check` it only reports whether the committed stub is up to date.
+//!
+//! Only the standard library is used: the binding files are scanned for type
+//! aliases, `#[pyfunction]` items and the registration order in
+//! `register.rs`. A `// pyi: <type>` line directly above a type alias
+//! overrides the Python type of that alias (for example callbacks typed as
+//! `PyObject` in Rust).
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+const BINDINGS: &str = "src/api/bindings.rs";
+const STUB: &str = "src/ezdwg/_core.pyi";
+const MAX_LINE: usize = 100;
+
+fn main() -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let check = env::args().skip(1).any(|arg| arg == "--check");
+    let stub = match generate_stub(root) {
+        Ok(stub) => stub,
+        Err(message) => {
+            eprintln!("failed to generate {STUB}: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let path = root.join(STUB);
+    if fs::read_to_string(&path).ok().as_deref() == Some(stub.as_str()) {
+        return ExitCode::SUCCESS;
+    }
+    if check {
+        eprintln!("{STUB} is out of date: run `cargo run --bin gen_stubs`");
+        return ExitCode::FAILURE;
+    }
+    if let Err(err) = fs::write(&path, stub) {
+        eprintln!("could not update {STUB}: {err}");
+        return ExitCode::FAILURE;
+    }
+    println!("updated {STUB}");
+    ExitCode::SUCCESS
+}
+
+struct Function {
+    name: String,
+    params: Vec<Param>,
+    ret: String,
+}
+
+struct Param {
+    name: String,
+    ty: String,
+    has_default: bool,
+    keyword_only: bool,
+}
+
+fn generate_stub(root: &Path) -> Result<String, String> {
+    let bindings = read(&root.join(BINDINGS))?;
+    let mut sources = Vec::new();
+    for line in bindings.lines() {
+        if let Some(rest) = line.trim().strip_prefix("include!(\"") {
+            let file = rest.split('"').next().unwrap_or_default();
+            sources.push((file.to_string(), read(&root.join("src/api").join(file))?));
+        }
+    }
+
+    let mut aliases = HashMap::new();
+    let mut overrides = HashMap::new();
+    for (_, source) in &sources {
+        collect_aliases(source, &mut aliases, &mut overrides);
+    }
+    let types = TypeMapper {
+        aliases: &aliases,
+        overrides: &overrides,
+    };
+
+    let mut functions = HashMap::new();
+    for (file, source) in &sources {
+        for function in parse_functions(source, &types).map_err(|err| format!("{file}: {err}"))? {
+            functions.insert(function.name.clone(), function);
+        }
+    }
+
+    let register = sources
+        .iter()
+        .find(|(file, _)| file.ends_with("register.rs"))
+        .map(|(_, source)| source.as_str())
+        .ok_or("register.rs is not included")?;
+    let mut names = Vec::new();
+    let mut features = HashMap::new();
+    let parts: Vec<&str> = register.split("wrap_pyfunction!(").collect();
+    for pair in parts.windows(2) {
+        let [before, part] = pair else { continue };
+        let name = part.split(',').next().unwrap_or_default().trim();
+        if !functions.contains_key(name) {
+            return Err(format!("registered function {name} has no #[pyfunction]"));
+        }
+        if let Some(feature) = registration_feature(before) {
+            features.insert(name.to_string(), feature);
+        }
+        names.push(name.to_string());
+    }
+
+    let mut body = String::new();
+    for name in &names {
+        if let Some(function) = functions.get(name) {
+            if let Some(feature) = features.get(name) {
+                body.push_str(&format!("# Requires the `{feature}` Cargo feature.\n"));
+            }
+            body.push_str(&render_function(function));
+        }
+    }
+
+    let mut out = String::from(
+        "# Generated by gen_stubs from the #[pyfunction] signatures in src/api/bindings.\n\
+         # Do not edit: change the Rust signature or its `// pyi:` alias instead.\n",
+    );
+    if body.contains("Callable[") {
+        out.push_str("from collections.abc import Callable\n");
+    }
+    if body.contains("Any") {
+        out.push_str("from typing import Any\n");
+    }
+    out.push_str("\n__all__ = [\n");
+    for name in &names {
+        out.push_str(&format!("    \"{name}\",\n"));
+    }
+    out.push_str("]\n\n");
+    out.push_str(&body);
+    Ok(out)
+}
+
+/// Feature named by a `#[cfg(feature = "...")]` line directly above a
+/// `module.add_function(` registration, whose text ends `before`.
+fn registration_feature(before: &str) -> Option<String> {
+    let mut lines = before.lines().rev();
+    lines.next()?;
+    let attribute = lines.next()?.trim();
+    let feature = attribute.strip_prefix("#[cfg(feature = \"")?;
+    Some(feature.split('"').next()?.to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn collect_aliases(
+    source: &str,
+    aliases: &mut HashMap<String, String>,
+    overrides: &mut HashMap<String, String>,
+) {
+    let mut pending_override: Option<String> = None;
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if let Some(python) = trimmed.strip_prefix("// pyi:") {
+            pending_override = Some(python.trim().to_string());
+            continue;
+        }
+        let Some(rest) = trimmed
+            .strip_prefix("pub type ")
+            .or_else(|| trimmed.strip_prefix("type "))
+        else {
+            if !trimmed.starts_with("//") && !trimmed.starts_with("#[") {
+                pending_override = None;
+            }
+            continue;
+        };
+        let mut text = strip_line_comment(rest).to_string();
+        let end = loop {
+            if let Some(end) = top_level_semicolon(&text) {
+                break end;
+            }
+            match lines.next() {
+                Some(next) => {
+                    text.push(' ');
+                    text.push_str(strip_line_comment(next).trim());
+                }
+                None => break text.len(),
+            }
+        };
+        let Some((name, ty)) = text[..end].split_once('=') else {
+            continue;
+        };
+        let name = name.trim().to_string();
+        let ty = ty.trim().to_string();
+        if let Some(python) = pending_override.take() {
+            overrides.insert(name.clone(), python);
+        }
+        aliases.insert(name, ty);
+    }
+}
+
+/// Offset of the `;` ending an alias; array types like `[f64; 4]` nest theirs.
+fn top_level_semicolon(text: &str) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut previous = ' ';
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '<' | '(' | '[' => depth += 1,
+            '>' if previous == '-' => {}
+            '>' | ')' | ']' => depth -= 1,
+            ';' if depth == 0 => return Some(index),
+            _ => {}
+        }
+        previous = ch;
+    }
+    None
+}
+
+fn strip_line_comment(line: &str) -> &str {
+    line.split("//").next().unwrap_or_default()
+}
+
+fn parse_functions(source: &str, types: &TypeMapper<'_>) -> Result<Vec<Function>, String> {
+    let mut functions = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("#[pyfunction") {
+        let after = &rest[start + "#[pyfunction".len()..];
+        let attr_end = matching_close(after, '[', ']', 1).ok_or("unterminated #[pyfunction")?;
+        let attr = &after[..attr_end];
+        let item = &after[attr_end + 1..];
+        let item = &item[fn_keyword_offset(item).ok_or("#[pyfunction] without fn")?..];
+        let open = item.find('(').ok_or("fn without parameters")?;
+        let name = item[..open].trim().to_string();
+        let params_end = open
+            + 1
+            + matching_close(&item[open + 1..], '(', ')', 1).ok_or("unterminated params")?;
+        let params_text = item[open + 1..params_end]
+            .lines()
+            .map(strip_line_comment)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let after_params = &item[params_end + 1..];
+        let body_start = after_params.find('{').ok_or("fn without body")?;
+        let ret = after_params[..body_start]
+            .trim()
+            .strip_prefix("->")
+            .map(str::trim)
+            .unwrap_or("()");
+
+        let signature = parse_signature(attr);
+        let mut params = Vec::new();
+        for param in split_top_level(&params_text) {
+            let Some((param_name, ty)) = param.split_once(':') else {
+                continue;
+            };
+            let param_name = param_name.trim().trim_start_matches("mut ").trim();
+            let ty = ty.trim();
+            if ty.starts_with("Python<") {
+                continue;
+            }
+            let python = types
+                .map(ty, Position::Param)
+                .map_err(|err| format!("{name}({param_name}): {err}"))?;
+            params.push(Param {
+                name: param_name.to_string(),
+                ty: python,
+                has_default: false,
+                keyword_only: false,
+            });
+        }
+        match signature {
+            Some(entries) => {
+                let mut keyword_only = false;
+                for entry in entries {
+                    if entry == "*" {
+                        keyword_only = true;
+                        continue;
+                    }
+                    let (entry_name, has_default) = match entry.split_once('=') {
+                        Some((entry_name, _)) => (entry_name.trim(), true),
+                        None => (entry.as_str(), false),
+                    };
+                    if let Some(param) = params.iter_mut().find(|param| param.name == entry_name) {
+                        param.has_default = has_default;
+                        param.keyword_only = keyword_only;
+                    }
+                }
+            }
+            None => {
+                // PyO3 gives trailing `Option` parameters a `None` default.
+                for param in params.iter_mut().rev() {
+                    if !param.ty.ends_with(" | None") {
+                        break;
+                    }
+                    param.has_default = true;
+                }
+            }
+        }
+
+        let ret = types
+            .map(ret, Position::Return)
+            .map_err(|err| format!("{name} return: {err}"))?;
+        functions.push(Function { name, params, ret });
+        rest = &after_params[body_start..];
+    }
+    Ok(functions)
+}
+
+/// Offset just past `fn ` on the first line that is not an attribute or a
+/// comment.
+fn fn_keyword_offset(item: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in item.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if !trimmed.is_empty() && !trimmed.starts_with("//") && !trimmed.starts_with("#[") {
+            let column = line.find("fn ")?;
+            return Some(offset + column + 3);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Entries of `signature = (...)`, or `None` for a bare `#[pyfunction]`.
+fn parse_signature(attr: &str) -> Option<Vec<String>> {
+    let start = attr.find("signature")?;
+    let open = start + attr[start..].find('(')?;
+    let close = open + 1 + matching_close(&attr[open + 1..], '(', ')', 1)?;
+    Some(split_top_level(&attr[open + 1..close]))
+}
+
+fn matching_close(text: &str, open: char, close: char, mut depth: usize) -> Option<usize> {
+    for (index, ch) in text.char_indices() {
+        if ch == open {
+            depth += 1;
+        } else if ch == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(index);
+            }
+        }
+    }
+    None
+}
+
+fn split_top_level(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+    let mut previous = ' ';
+    for ch in text.chars() {
+        let arrow = ch == '>' && previous == '-';
+        previous = ch;
+        match ch {
+            '<' | '(' | '[' => depth += 1,
+            '>' if arrow => {}
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+    parts.push(current.trim().to_string());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+#[derive(Clone, Copy)]
+enum Position {
+    Param,
+    Return,
+}
+
+struct TypeMapper<'a> {
+    aliases: &'a HashMap<String, String>,
+    overrides: &'a HashMap<String, String>,
+}
+
+impl TypeMapper<'_> {
+    fn map(&self, ty: &str, position: Position) -> Result<String, String> {
+        self.map_depth(ty.trim(), position, 0)
+    }
+
+    fn map_depth(&self, ty: &str, position: Position, depth: usize) -> Result<String, String> {
+        if depth > 32 {
+            return Err(format!("type alias cycle at {ty}"));
+        }
+        let ty = ty.trim();
+        if let Some(inner) = ty.strip_prefix('&') {
+            let inner = inner.trim_start();
+            let inner = match inner.strip_prefix('\'') {
+                Some(lifetime) => lifetime.split_once(' ').map_or("", |(_, rest)| rest),
+                None => inner,
+            };
+            return self.map_depth(inner.trim_start_matches("mut "), position, depth + 1);
+        }
+        if let Some(inner) = ty.strip_prefix('(') {
+            let inner = inner
+                .strip_suffix(')')
+                .ok_or_else(|| format!("bad tuple {ty}"))?;
+            let items = split_top_level(inner);
+            if items.is_empty() {
+                return Ok("None".to_string());
+            }
+            let mapped = items
+                .iter()
+                .map(|item| self.map_depth(item, position, depth + 1))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(format!("tuple[{}]", mapped.join(", ")));
+        }
+        if let Some(inner) = ty.strip_prefix('[') {
+            let inner = inner
+                .strip_suffix(']')
+                .ok_or_else(|| format!("bad slice {ty}"))?;
+            if let Some(split) = top_level_semicolon(inner) {
+                let element = self.map_depth(&inner[..split], position, depth + 1)?;
+                let length: usize = inner[split + 1..]
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("bad array length in {ty}"))?;
+                // Arrays accept any sequence but come back as lists.
+                return Ok(match position {
+                    Position::Param => format!("tuple[{}]", vec![element; length].join(", ")),
+                    Position::Return => format!("list[{element}]"),
+                });
+            }
+            return self.sequence(inner, position, depth);
+        }
+
+        let (path, args) = match ty.find('<') {
+            Some(open) => {
+                let inner = ty[open + 1..]
+                    .strip_suffix('>')
+                    .ok_or_else(|| format!("bad generic {ty}"))?;
+                let args = split_top_level(inner)
+                    .into_iter()
+                    .filter(|arg| !arg.starts_with('\''))
+                    .collect::<Vec<_>>();
+                (&ty[..open], args)
+            }
+            None => (ty, Vec::new()),
+        };
+        let name = path.rsplit("::").next().unwrap_or(path).trim();
+        let arg = |index: usize| -> Result<String, String> {
+            let arg = args
+                .get(index)
+                .ok_or_else(|| format!("{name} needs a type argument"))?;
+            self.map_depth(arg, position, depth + 1)
+        };
+        let python = match name {
+            "str" | "String" | "InternedName" | "char" | "Cow" => "str".to_string(),
+            "bool" => "bool".to_string(),
+            "f32" | "f64" => "float".to_string(),
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+            | "u128" | "usize" => "int".to_string(),
+            "PyObject" | "PyAny" => "object".to_string(),
+            "PyBytes" => "bytes".to_string(),
+            "PyString" => "str".to_string(),
+            "PyResult" | "Result" | "Py" | "Bound" => arg(0)?,
+            "Option" => {
+                let inner = arg(0)?;
+                if inner.ends_with(" | None") {
+                    inner
+                } else {
+                    format!("{inner} | None")
+                }
+            }
+            "Vec" => {
+                let inner = args.first().ok_or("Vec needs a type argument")?;
+                self.sequence(inner, position, depth)?
+            }
+            "HashSet" | "BTreeSet" => format!("set[{}]", arg(0)?),
+            "HashMap" | "BTreeMap" => format!("dict[{}, {}]", arg(0)?, arg(1)?),
+            _ => {
+                if let Some(python) = self.overrides.get(name) {
+                    python.clone()
+                } else if let Some(alias) = self.aliases.get(name) {
+                    self.map_depth(alias, position, depth + 1)?
+                } else {
+                    return Err(format!("no Python type for `{ty}`"));
+                }
+            }
+        };
+        Ok(python)
+    }
+
+    fn sequence(&self, inner: &str, position: Position, depth: usize) -> Result<String, String> {
+        // PyO3 takes `bytes` for a byte vector but returns a list of ints.
+        if inner.trim() == "u8" && matches!(position, Position::Param) {
+            return Ok("bytes".to_string());
+        }
+        Ok(format!(
+            "list[{}]",
+            self.map_depth(inner, position, depth + 1)?
+        ))
+    }
+}
+
+fn render_function(function: &Function) -> String {
+    let mut params = Vec::new();
+    let mut star_written = false;
+    for param in &function.params {
+        if param.keyword_only && !star_written {
+            params.push("*".to_string());
+            star_written = true;
+        }
+        let default = if param.has_default { " = ..." } else { "" };
+        params.push(format!("{}: {}{default}", param.name, param.ty));
+    }
+    let line = format!(
+        "def {}({}) -> {}: ...\n",
+        function.name,
+        params.join(", "),
+        function.ret
+    );
+    if line.len() <= MAX_LINE + 1 || params.is_empty() {
+        return line;
+    }
+    let mut out = format!("def {}(\n", function.name);
+    for param in params {
+        out.push_str(&format!("    {param},\n"));
+    }
+    out.push_str(&format!(") -> {}: ...\n", function.ret));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn committed_stub_is_up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let stub = generate_stub(root).expect("generate stub");
+        let committed = fs::read_to_string(root.join(STUB)).expect("read stub");
+        assert!(
+            committed == stub,
+            "{STUB} is out of date: run `cargo run --bin gen_stubs`"
+        );
+    }
+}
@@ -15,12 +15,91 @@ pub struct HandleRef {
     pub value: u64,
 }
 
+/// Reference semantics carried by a handle code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleRefKind {
+    SoftOwner,
+    HardOwner,
+    SoftPointer,
+    HardPointer,
+    /// Codes 0x6/0x8/0xA/0xC, stored as an offset from the referencing object.
+    Relative,
+    Unspecified,
+}
+
+impl HandleRefKind {
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0x02 => Self::SoftOwner,
+            0x03 => Self::HardOwner,
+            0x04 => Self::SoftPointer,
+            0x05 => Self::HardPointer,
+            0x06 | 0x08 | 0x0A | 0x0C => Self::Relative,
+            _ => Self::Unspecified,
+        }
+    }
+
+    pub fn is_owner(self) -> bool {
+        matches!(self, Self::SoftOwner | Self::HardOwner)
+    }
+
+    pub fn is_hard(self) -> bool {
+        matches!(self, Self::HardOwner | Self::HardPointer)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SoftOwner => "soft_owner",
+            Self::HardOwner => "hard_owner",
+            Self::SoftPointer => "soft_pointer",
+            Self::HardPointer => "hard_pointer",
+            Self::Relative => "relative",
+            Self::Unspecified => "unspecified",
+        }
+    }
+}
+
+impl HandleRef {
+    pub fn kind(&self) -> HandleRefKind {
+        HandleRefKind::from_code(self.code)
+    }
+
+    /// Returns the absolute handle, resolving relative codes against
+    /// `base_handle`.
+    pub fn resolve(&self, base_handle: u64) -> u64 {
+        match self.code {
+            0x06 => base_handle.saturating_add(1),
+            0x08 => base_handle.saturating_sub(1),
+            0x0A => base_handle.saturating_add(self.value),
+            0x0C => base_handle.saturating_sub(self.value),
+            _ => self.value,
+        }
+    }
+}
+
+/// One value read while tracing is enabled, see [`BitReader::enable_trace`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry {
+    pub start_bit: u64,
+    pub end_bit: u64,
+    /// DWG bit code of the read, such as `BS`, `BD` or `H`.
+    pub code: &'static str,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Trace {
+    depth: u32,
+    entries: Vec<TraceEntry>,
+}
+
 #[derive(Debug, Clone)]
 pub struct BitReader<'a> {
     data: &'a [u8],
     byte_pos: usize,
     bit_pos: u8,
     codepage: Option<u16>,
+    trace: Option<Box<Trace>>,
 }
 
 impl<'a> BitReader<'a> {
@@ -36,9 +115,50 @@ impl<'a> BitReader<'a> {
             byte_pos: 0,
             bit_pos: 0,
             codepage,
+            trace: None,
         }
     }
 
+    /// Records every subsequent read as a [`TraceEntry`]. Composite codes
+    /// such as `3BD` are recorded once, not per component.
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(Box::default);
+    }
+
+    /// Returns the reads recorded so far and clears the trace.
+    pub fn take_trace(&mut self) -> Vec<TraceEntry> {
+        self.trace
+            .as_mut()
+            .map(|trace| std::mem::take(&mut trace.entries))
+            .unwrap_or_default()
+    }
+
+    fn traced<T: TraceValue>(
+        &mut self,
+        code: &'static str,
+        read: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let Some(trace) = self.trace.as_mut() else {
+            return read(self);
+        };
+        trace.depth += 1;
+        let start_bit = self.tell_bits();
+        let result = read(self);
+        let end_bit = self.tell_bits();
+        if let Some(trace) = self.trace.as_mut() {
+            trace.depth -= 1;
+            if let (0, Ok(value)) = (trace.depth, &result) {
+                trace.entries.push(TraceEntry {
+                    start_bit,
+                    end_bit,
+                    code,
+                    value: value.trace_value(),
+                });
+            }
+        }
+        result
+    }
+
     pub fn tell_bits(&self) -> u64 {
         (self.byte_pos as u64) * 8 + self.bit_pos as u64
     }
@@ -69,23 +189,23 @@ impl<'a> BitReader<'a> {
     }
 
     pub fn read_b(&mut self) -> Result<u8> {
-        if self.byte_pos >= self.data.len() {
-            return Err(
-                DwgError::new(ErrorKind::Io, "unexpected EOF").with_offset(self.byte_pos as u64)
-            );
-        }
-        let byte = self.data[self.byte_pos];
-        let bit = (byte & (0x80 >> self.bit_pos)) >> (7 - self.bit_pos);
-        self.advance(1);
-        Ok(bit)
+        self.traced("B", |reader| {
+            let Some(&byte) = reader.data.get(reader.byte_pos) else {
+                return Err(DwgError::new(ErrorKind::Io, "unexpected EOF")
+                    .with_offset(reader.byte_pos as u64));
+            };
+            let bit = (byte & (0x80 >> reader.bit_pos)) >> (7 - reader.bit_pos);
+            reader.advance(1);
+            Ok(bit)
+        })
     }
 
     pub fn read_bb(&mut self) -> Result<u8> {
-        Ok(self.read_bits_msb(2)? as u8)
+        self.traced("BB", |reader| Ok(reader.read_bits_msb(2)? as u8))
     }
 
     pub fn read_3b(&mut self) -> Result<u8> {
-        Ok(self.read_bits_msb(3)? as u8)
+        self.traced("3B", |reader| Ok(reader.read_bits_msb(3)? as u8))
     }
 
     pub fn read_bits_msb(&mut self, n: u8) -> Result<u64> {
@@ -103,337 +223,381 @@ impl<'a> BitReader<'a> {
     }
 
     pub fn read_rc(&mut self) -> Result<u8> {
-        if self.byte_pos >= self.data.len() {
-            return Err(
-                DwgError::new(ErrorKind::Io, "unexpected EOF").with_offset(self.byte_pos as u64)
-            );
-        }
-
-        let mut value = self.data[self.byte_pos] as u16;
-        if self.bit_pos != 0 {
-            value <<= self.bit_pos;
-            if self.byte_pos + 1 < self.data.len() {
-                value |= (self.data[self.byte_pos + 1] as u16) >> (8 - self.bit_pos);
+        self.traced("RC", |reader| {
+            let Some(&byte) = reader.data.get(reader.byte_pos) else {
+                return Err(DwgError::new(ErrorKind::Io, "unexpected EOF")
+                    .with_offset(reader.byte_pos as u64));
+            };
+
+            let mut value = byte as u16;
+            if reader.bit_pos != 0 {
+                value <<= reader.bit_pos;
+                if let Some(&next) = reader.data.get(reader.byte_pos + 1) {
+                    value |= (next as u16) >> (8 - reader.bit_pos);
+                }
             }
-        }
-        self.advance(8);
-        Ok((value & 0xFF) as u8)
+            reader.advance(8);
+            Ok((value & 0xFF) as u8)
+        })
     }
 
     pub fn read_rcs(&mut self, count: usize) -> Result<Vec<u8>> {
-        if count == 0 {
-            return Ok(Vec::new());
-        }
-        let remaining = self.data.len().saturating_sub(self.byte_pos);
-        if count > remaining {
-            return Err(DwgError::new(
-                ErrorKind::Format,
-                format!(
-                    "raw byte length {count} exceeds remaining bytes {remaining} at bit offset {}",
-                    self.tell_bits()
-                ),
-            )
-            .with_offset(self.byte_pos as u64));
-        }
-        if self.bit_pos == 0 {
-            let start = self.byte_pos;
-            let end = start + count;
-            self.byte_pos = end;
-            return Ok(self.data[start..end].to_vec());
-        }
+        self.traced("RCS", |reader| {
+            if count == 0 {
+                return Ok(Vec::new());
+            }
+            let remaining = reader.data.len().saturating_sub(reader.byte_pos);
+            if count > remaining {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!(
+                        "raw byte length {count} exceeds remaining bytes {remaining} at bit offset {}",
+                        reader.tell_bits()
+                    ),
+                )
+                .with_offset(reader.byte_pos as u64));
+            }
+            if reader.bit_pos == 0 {
+                let start = reader.byte_pos;
+                let end = start + count;
+                let bytes = reader.data.get(start..end).unwrap_or_default().to_vec();
+                reader.byte_pos = end;
+                return Ok(bytes);
+            }
 
-        let mut out = Vec::with_capacity(count);
-        for _ in 0..count {
-            out.push(self.read_rc()?);
-        }
-        Ok(out)
+            let mut out = Vec::with_capacity(count);
+            for _ in 0..count {
+                out.push(reader.read_rc()?);
+            }
+            Ok(out)
+        })
     }
 
     pub fn read_rs(&mut self, endian: Endian) -> Result<u16> {
-        let byte1 = self.read_rc()? as u16;
-        let byte2 = self.read_rc()? as u16;
-        let value = match endian {
-            Endian::Little => (byte2 << 8) | byte1,
-            Endian::Big => (byte1 << 8) | byte2,
-        };
-        Ok(value)
+        self.traced("RS", |reader| {
+            let byte1 = reader.read_rc()? as u16;
+            let byte2 = reader.read_rc()? as u16;
+            let value = match endian {
+                Endian::Little => (byte2 << 8) | byte1,
+                Endian::Big => (byte1 << 8) | byte2,
+            };
+            Ok(value)
+        })
     }
 
     pub fn read_rl(&mut self, endian: Endian) -> Result<u32> {
-        let short1 = self.read_rs(endian)? as u32;
-        let short2 = self.read_rs(endian)? as u32;
-        let value = match endian {
-            Endian::Little => (short2 << 16) | short1,
-            Endian::Big => (short1 << 16) | short2,
-        };
-        Ok(value)
+        self.traced("RL", |reader| {
+            let short1 = reader.read_rs(endian)? as u32;
+            let short2 = reader.read_rs(endian)? as u32;
+            let value = match endian {
+                Endian::Little => (short2 << 16) | short1,
+                Endian::Big => (short1 << 16) | short2,
+            };
+            Ok(value)
+        })
     }
 
     pub fn read_rd(&mut self, endian: Endian) -> Result<f64> {
-        let mut data = [0u8; 8];
-        for byte in &mut data {
-            *byte = self.read_rc()?;
-        }
-        let value = match endian {
-            Endian::Little => f64::from_le_bytes(data),
-            Endian::Big => f64::from_be_bytes(data),
-        };
-        Ok(value)
+        self.traced("RD", |reader| {
+            let mut data = [0u8; 8];
+            for byte in &mut data {
+                *byte = reader.read_rc()?;
+            }
+            let value = match endian {
+                Endian::Little => f64::from_le_bytes(data),
+                Endian::Big => f64::from_be_bytes(data),
+            };
+            Ok(value)
+        })
     }
 
     pub fn read_bd(&mut self) -> Result<f64> {
-        let what_it_is = self.read_bb()?;
-        let value = match what_it_is {
-            0x00 => self.read_rd(Endian::Little)?,
-            0x01 => 1.0,
-            0x02 => 0.0,
-            0x03 => 0.0,
-            _ => 0.0,
-        };
-        Ok(value)
+        self.traced("BD", |reader| {
+            let what_it_is = reader.read_bb()?;
+            let value = match what_it_is {
+                0x00 => reader.read_rd(Endian::Little)?,
+                0x01 => 1.0,
+                0x02 => 0.0,
+                0x03 => 0.0,
+                _ => 0.0,
+            };
+            Ok(value)
+        })
     }
 
     pub fn read_3bd(&mut self) -> Result<(f64, f64, f64)> {
-        Ok((self.read_bd()?, self.read_bd()?, self.read_bd()?))
+        self.traced("3BD", |reader| {
+            Ok((reader.read_bd()?, reader.read_bd()?, reader.read_bd()?))
+        })
     }
 
     pub fn read_dd(&mut self, default_value: f64) -> Result<f64> {
-        let what_it_is = self.read_bb()?;
-        let value = match what_it_is {
-            0 => default_value,
-            1 => {
-                let mut data = default_value.to_le_bytes();
-                data[0] = self.read_rc()?;
-                data[1] = self.read_rc()?;
-                data[2] = self.read_rc()?;
-                data[3] = self.read_rc()?;
-                f64::from_le_bytes(data)
-            }
-            2 => {
-                let mut data = default_value.to_le_bytes();
-                data[4] = self.read_rc()?;
-                data[5] = self.read_rc()?;
-                data[0] = self.read_rc()?;
-                data[1] = self.read_rc()?;
-                data[2] = self.read_rc()?;
-                data[3] = self.read_rc()?;
-                f64::from_le_bytes(data)
-            }
-            3 => self.read_rd(Endian::Little)?,
-            _ => default_value,
-        };
-        Ok(value)
+        self.traced("DD", |reader| {
+            let what_it_is = reader.read_bb()?;
+            let value = match what_it_is {
+                0 => default_value,
+                1 => {
+                    let mut data = default_value.to_le_bytes();
+                    data[0] = reader.read_rc()?;
+                    data[1] = reader.read_rc()?;
+                    data[2] = reader.read_rc()?;
+                    data[3] = reader.read_rc()?;
+                    f64::from_le_bytes(data)
+                }
+                2 => {
+                    let mut data = default_value.to_le_bytes();
+                    data[4] = reader.read_rc()?;
+                    data[5] = reader.read_rc()?;
+                    data[0] = reader.read_rc()?;
+                    data[1] = reader.read_rc()?;
+                    data[2] = reader.read_rc()?;
+                    data[3] = reader.read_rc()?;
+                    f64::from_le_bytes(data)
+                }
+                3 => reader.read_rd(Endian::Little)?,
+                _ => default_value,
+            };
+            Ok(value)
+        })
     }
 
     pub fn read_bt(&mut self) -> Result<f64> {
-        let what_it_is = self.read_b()?;
-        if what_it_is == 1 {
-            Ok(0.0)
-        } else {
-            self.read_bd()
-        }
+        self.traced("BT", |reader| {
+            let what_it_is = reader.read_b()?;
+            if what_it_is == 1 {
+                Ok(0.0)
+            } else {
+                reader.read_bd()
+            }
+        })
     }
 
     pub fn read_be(&mut self) -> Result<(f64, f64, f64)> {
-        let what_it_is = self.read_b()?;
-        if what_it_is == 1 {
-            Ok((0.0, 0.0, 1.0))
-        } else {
-            Ok((self.read_bd()?, self.read_bd()?, self.read_bd()?))
-        }
+        self.traced("BE", |reader| {
+            let what_it_is = reader.read_b()?;
+            if what_it_is == 1 {
+                Ok((0.0, 0.0, 1.0))
+            } else {
+                Ok((reader.read_bd()?, reader.read_bd()?, reader.read_bd()?))
+            }
+        })
     }
 
     pub fn read_bs(&mut self) -> Result<u16> {
-        let what_it_is = self.read_bb()?;
-        let value = match what_it_is {
-            0x00 => self.read_rs(Endian::Little)?,
-            0x01 => self.read_rc()? as u16,
-            0x02 => 0,
-            0x03 => 256,
-            _ => 0,
-        };
-        Ok(value)
+        self.traced("BS", |reader| {
+            let what_it_is = reader.read_bb()?;
+            let value = match what_it_is {
+                0x00 => reader.read_rs(Endian::Little)?,
+                0x01 => reader.read_rc()? as u16,
+                0x02 => 0,
+                0x03 => 256,
+                _ => 0,
+            };
+            Ok(value)
+        })
     }
 
     pub fn read_bl(&mut self) -> Result<u32> {
-        let what_it_is = self.read_bb()?;
-        let value = match what_it_is {
-            0x00 => self.read_rl(Endian::Little)?,
-            0x01 => self.read_rc()? as u32,
-            0x02 => 0,
-            0x03 => 0,
-            _ => 0,
-        };
-        Ok(value)
+        self.traced("BL", |reader| {
+            let what_it_is = reader.read_bb()?;
+            let value = match what_it_is {
+                0x00 => reader.read_rl(Endian::Little)?,
+                0x01 => reader.read_rc()? as u32,
+                0x02 => 0,
+                0x03 => 0,
+                _ => 0,
+            };
+            Ok(value)
+        })
     }
 
     pub fn read_bll(&mut self) -> Result<u64> {
-        let length = self.read_3b()? as usize;
-        let mut value = 0u64;
-        for _ in 0..length {
-            value = (value << 8) | self.read_rc()? as u64;
-        }
-        Ok(value)
+        self.traced("BLL", |reader| {
+            // The byte count is followed by the value, least significant byte
+            // first.
+            let length = reader.read_3b()? as usize;
+            let mut value = 0u64;
+            for index in 0..length {
+                value |= (reader.read_rc()? as u64) << (index * 8);
+            }
+            Ok(value)
+        })
     }
 
     pub fn read_ms(&mut self) -> Result<u32> {
-        let mut value: u32 = 0;
-        let mut shift = 0;
-
-        for _ in 0..2 {
-            let mut word = self.read_rs(Endian::Little)?;
-            if (word & 0x8000) == 0 {
+        self.traced("MS", |reader| {
+            let mut value: u32 = 0;
+            let mut shift = 0;
+
+            for _ in 0..2 {
+                let mut word = reader.read_rs(Endian::Little)?;
+                if (word & 0x8000) == 0 {
+                    value |= (word as u32) << shift;
+                    return Ok(value);
+                }
+                word &= 0x7FFF;
                 value |= (word as u32) << shift;
-                return Ok(value);
+                shift += 15;
             }
-            word &= 0x7FFF;
-            value |= (word as u32) << shift;
-            shift += 15;
-        }
 
-        Ok(value)
+            Ok(value)
+        })
     }
 
     pub fn read_mc(&mut self) -> Result<i64> {
-        let mut value: i64 = 0;
-        let mut shift = 0;
-
-        for _ in 0..4 {
-            let mut byte = self.read_rc()?;
-            if (byte & 0x80) == 0 {
-                let negative = (byte & 0x40) != 0;
-                if negative {
-                    byte &= 0xBF;
+        self.traced("MC", |reader| {
+            let mut value: i64 = 0;
+            let mut shift = 0;
+
+            for _ in 0..4 {
+                let mut byte = reader.read_rc()?;
+                if (byte & 0x80) == 0 {
+                    let negative = (byte & 0x40) != 0;
+                    if negative {
+                        byte &= 0xBF;
+                    }
+                    value |= (byte as i64) << shift;
+                    if negative {
+                        value = -value;
+                    }
+                    return Ok(value);
                 }
+                byte &= 0x7F;
                 value |= (byte as i64) << shift;
-                if negative {
-                    value = -value;
-                }
-                return Ok(value);
+                shift += 7;
             }
-            byte &= 0x7F;
-            value |= (byte as i64) << shift;
-            shift += 7;
-        }
 
-        Ok(value)
+            Ok(value)
+        })
     }
 
     pub fn read_umc(&mut self) -> Result<u32> {
-        let mut value: u32 = 0;
-        let mut shift = 0u32;
-
-        for _ in 0..5 {
-            let byte = self.read_rc()?;
-            let chunk = (byte & 0x7F) as u32;
-            value |= chunk << shift;
-            if (byte & 0x80) == 0 {
-                return Ok(value);
+        self.traced("UMC", |reader| {
+            let mut value: u32 = 0;
+            let mut shift = 0u32;
+
+            for _ in 0..5 {
+                let byte = reader.read_rc()?;
+                let chunk = (byte & 0x7F) as u32;
+                value |= chunk << shift;
+                if (byte & 0x80) == 0 {
+                    return Ok(value);
+                }
+                shift += 7;
             }
-            shift += 7;
-        }
 
-        Ok(value)
+            Ok(value)
+        })
     }
 
     pub fn read_ot_r2010(&mut self) -> Result<u16> {
-        let opcode = self.read_bb()?;
-        let type_code = match opcode {
-            0 => self.read_rc()? as u16,
-            1 => self.read_rc()? as u16 + 0x01F0,
-            2 | 3 => self.read_rs(Endian::Little)?,
-            _ => 0,
-        };
-        Ok(type_code)
+        self.traced("OT", |reader| {
+            let opcode = reader.read_bb()?;
+            let type_code = match opcode {
+                0 => reader.read_rc()? as u16,
+                1 => reader.read_rc()? as u16 + 0x01F0,
+                2 | 3 => reader.read_rs(Endian::Little)?,
+                _ => 0,
+            };
+            Ok(type_code)
+        })
     }
 
     pub fn read_h(&mut self) -> Result<HandleRef> {
-        let mut code = self.read_rc()?;
-        let counter = code & 0x0F;
-        code = (code & 0xF0) >> 4;
-        if counter > 4 {
-            return Err(DwgError::new(
-                ErrorKind::Format,
-                format!("invalid handle counter {counter}"),
-            )
-            .with_offset(self.byte_pos as u64));
-        }
-        let mut value: u64 = 0;
-        if counter > 0 {
-            for idx in (0..counter).rev() {
-                let byte = self.read_rc()? as u64;
-                value |= byte << (idx * 8);
+        self.traced("H", |reader| {
+            let mut code = reader.read_rc()?;
+            let counter = code & 0x0F;
+            code = (code & 0xF0) >> 4;
+            if counter > 4 {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!("invalid handle counter {counter}"),
+                )
+                .with_offset(reader.byte_pos as u64));
             }
-        }
-        Ok(HandleRef {
-            code,
-            counter,
-            value,
+            let mut value: u64 = 0;
+            if counter > 0 {
+                for idx in (0..counter).rev() {
+                    let byte = reader.read_rc()? as u64;
+                    value |= byte << (idx * 8);
+                }
+            }
+            Ok(HandleRef {
+                code,
+                counter,
+                value,
+            })
         })
     }
 
     pub fn read_tv(&mut self) -> Result<String> {
-        let length = self.read_bs()? as usize;
-        if length > Self::MAX_TEXT_UNITS {
-            return Err(DwgError::new(
-                ErrorKind::Format,
-                format!("text length too large for TV string: {length}"),
-            )
-            .with_offset(self.byte_pos as u64));
-        }
-        let remaining_bytes = self.data.len().saturating_sub(self.byte_pos);
-        if length > remaining_bytes {
-            return Err(DwgError::new(
-                ErrorKind::Format,
-                format!("TV string length {length} exceeds remaining bytes {remaining_bytes}"),
-            )
-            .with_offset(self.byte_pos as u64));
-        }
-        let mut text = Vec::with_capacity(length);
-        for _ in 0..length {
-            let ch = self.read_rc()?;
-            if ch == 0x00 {
-                continue;
+        self.traced("TV", |reader| {
+            let length = reader.read_bs()? as usize;
+            if length > Self::MAX_TEXT_UNITS {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!("text length too large for TV string: {length}"),
+                )
+                .with_offset(reader.byte_pos as u64));
             }
-            text.push(ch);
-        }
-        Ok(decode_tv_bytes(&text, self.codepage))
+            let remaining_bytes = reader.data.len().saturating_sub(reader.byte_pos);
+            if length > remaining_bytes {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!("TV string length {length} exceeds remaining bytes {remaining_bytes}"),
+                )
+                .with_offset(reader.byte_pos as u64));
+            }
+            let mut text = Vec::with_capacity(length);
+            for _ in 0..length {
+                let ch = reader.read_rc()?;
+                if ch == 0x00 {
+                    continue;
+                }
+                text.push(ch);
+            }
+            Ok(decode_tv_bytes(&text, reader.codepage))
+        })
     }
 
     pub fn read_tu(&mut self) -> Result<String> {
-        let length = self.read_bs()? as usize;
-        if length > Self::MAX_TEXT_UNITS {
-            return Err(DwgError::new(
-                ErrorKind::Format,
-                format!("text length too large for TU string: {length}"),
-            )
-            .with_offset(self.byte_pos as u64));
-        }
-        let remaining_bytes = self.data.len().saturating_sub(self.byte_pos);
-        let required_bytes = length.saturating_mul(2);
-        if required_bytes > remaining_bytes {
-            return Err(
-                DwgError::new(
+        self.traced("TU", |reader| {
+            let length = reader.read_bs()? as usize;
+            if length > Self::MAX_TEXT_UNITS {
+                return Err(DwgError::new(
                     ErrorKind::Format,
-                    format!(
-                        "TU string length {length} requires {required_bytes} bytes, but {remaining_bytes} remain"
-                    ),
+                    format!("text length too large for TU string: {length}"),
                 )
-                .with_offset(self.byte_pos as u64),
-            );
-        }
-        let mut units = Vec::with_capacity(length);
-        for _ in 0..length {
-            units.push(self.read_rs(Endian::Little)?);
-        }
-        Ok(String::from_utf16_lossy(&units))
+                .with_offset(reader.byte_pos as u64));
+            }
+            let remaining_bytes = reader.data.len().saturating_sub(reader.byte_pos);
+            let required_bytes = length.saturating_mul(2);
+            if required_bytes > remaining_bytes {
+                return Err(
+                    DwgError::new(
+                        ErrorKind::Format,
+                        format!(
+                            "TU string length {length} requires {required_bytes} bytes, but {remaining_bytes} remain"
+                        ),
+                    )
+                    .with_offset(reader.byte_pos as u64),
+                );
+            }
+            let mut units = Vec::with_capacity(length);
+            for _ in 0..length {
+                units.push(reader.read_rs(Endian::Little)?);
+            }
+            Ok(String::from_utf16_lossy(&units))
+        })
     }
 
     pub fn read_crc(&mut self) -> Result<u16> {
-        if self.bit_pos > 0 {
-            self.set_pos(self.byte_pos + 1, 0);
-        }
-        self.read_rs(Endian::Little)
+        self.traced("CRC", |reader| {
+            if reader.bit_pos > 0 {
+                reader.set_pos(reader.byte_pos + 1, 0);
+            }
+            reader.read_rs(Endian::Little)
+        })
     }
 
     fn advance(&mut self, bits: u8) {
@@ -443,6 +607,49 @@ impl<'a> BitReader<'a> {
     }
 }
 
+trait TraceValue {
+    fn trace_value(&self) -> String;
+}
+
+macro_rules! impl_trace_value_display {
+    ($($ty:ty),*) => {
+        $(impl TraceValue for $ty {
+            fn trace_value(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+impl_trace_value_display!(u8, u16, u32, u64, i64, f64);
+
+impl TraceValue for (f64, f64, f64) {
+    fn trace_value(&self) -> String {
+        format!("({}, {}, {})", self.0, self.1, self.2)
+    }
+}
+
+impl TraceValue for String {
+    fn trace_value(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl TraceValue for Vec<u8> {
+    fn trace_value(&self) -> String {
+        self.iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl TraceValue for HandleRef {
+    fn trace_value(&self) -> String {
+        format!("{}.{}.{:X}", self.code, self.counter, self.value)
+    }
+}
+
 pub(crate) fn decode_tv_bytes(bytes: &[u8], codepage: Option<u16>) -> String {
     if bytes.is_empty() {
         return String::new();
@@ -504,7 +711,7 @@ pub(crate) fn dwg_codepage_encoding(codepage: Option<u16>) -> Option<&'static En
 
 #[cfg(test)]
 mod tests {
-    use super::{BitReader, Endian};
+    use super::{BitReader, Endian, HandleRefKind};
     use crate::bit::BitWriter;
 
     #[test]
@@ -594,4 +801,59 @@ mod tests {
         let mut reader = BitReader::new_with_codepage(&data, Some(38));
         assert_eq!(reader.read_tv().expect("read tv"), "執務室12l1");
     }
+
+    #[test]
+    fn read_h_exposes_kind_and_resolves_relative_codes() {
+        let mut writer = BitWriter::new();
+        writer.write_h(3, 0x1F).expect("write hard owner");
+        writer.write_h(4, 0x20).expect("write soft pointer");
+        writer.write_h(0x0A, 0x05).expect("write relative forward");
+        writer.write_h(0x08, 0).expect("write relative back");
+
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        let owner = reader.read_h().expect("read owner");
+        assert_eq!(owner.kind(), HandleRefKind::HardOwner);
+        assert!(owner.kind().is_owner() && owner.kind().is_hard());
+        assert_eq!(owner.resolve(0x40), 0x1F);
+        let pointer = reader.read_h().expect("read pointer");
+        assert_eq!(pointer.kind(), HandleRefKind::SoftPointer);
+        assert!(!pointer.kind().is_owner());
+        let forward = reader.read_h().expect("read forward");
+        assert_eq!(forward.kind(), HandleRefKind::Relative);
+        assert_eq!(forward.resolve(0x40), 0x45);
+        let back = reader.read_h().expect("read back");
+        assert_eq!(back.resolve(0x40), 0x3F);
+    }
+
+    #[test]
+    fn trace_records_outermost_reads_with_bit_ranges() {
+        let mut writer = BitWriter::new();
+        writer.write_b(1).expect("write bit");
+        writer.write_bs(19).expect("write bs");
+        writer.write_3bd(1.0, 2.5, 0.0).expect("write 3bd");
+        writer.write_h(5, 0x1F).expect("write handle");
+
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        reader.read_b().expect("read untraced bit");
+        reader.enable_trace();
+        reader.read_bs().expect("read bs");
+        let (x, _, _) = reader.read_3bd().expect("read 3bd");
+        assert_eq!(x, 1.0);
+        reader.read_h().expect("read handle");
+
+        let trace = reader.take_trace();
+        let codes: Vec<_> = trace.iter().map(|entry| entry.code).collect();
+        assert_eq!(codes, ["BS", "3BD", "H"]);
+        assert_eq!(trace[0].start_bit, 1);
+        assert_eq!(trace[0].value, "19");
+        assert_eq!(trace[1].value, "(1, 2.5, 0)");
+        assert_eq!(trace[2].value, "5.1.1F");
+        for pair in trace.windows(2) {
+            assert_eq!(pair[0].end_bit, pair[1].start_bit);
+        }
+        assert_eq!(trace[2].end_bit, reader.tell_bits());
+        assert!(reader.take_trace().is_empty());
+    }
 }
@@ -57,10 +57,12 @@ impl BitWriter {
         }
         self.ensure_byte(self.byte_pos);
         let mask = 0x80u8 >> self.bit_pos;
-        if bit == 1 {
-            self.data[self.byte_pos] |= mask;
-        } else {
-            self.data[self.byte_pos] &= !mask;
+        if let Some(byte) = self.data.get_mut(self.byte_pos) {
+            if bit == 1 {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
         }
         self.advance(1);
         self.max_bit_pos = self.max_bit_pos.max(self.tell_bits());
@@ -212,9 +214,12 @@ impl BitWriter {
             self.write_3b(0)?;
             return Ok(());
         }
-        let bytes = value.to_be_bytes();
-        let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
-        let significant = &bytes[start..];
+        let bytes = value.to_le_bytes();
+        let len = bytes
+            .iter()
+            .rposition(|b| *b != 0)
+            .map_or(0, |last| last + 1);
+        let significant = bytes.get(..len).unwrap_or_default();
         if significant.len() > 7 {
             return Err(DwgError::new(
                 ErrorKind::Unsupported,
@@ -283,7 +288,7 @@ impl BitWriter {
         let counter = if value == 0 {
             0
         } else {
-            ((64 - value.leading_zeros() as usize) + 7) / 8
+            (64 - value.leading_zeros() as usize).div_ceil(8)
         };
         if counter > 4 {
             return Err(DwgError::new(
@@ -358,12 +363,12 @@ impl BitWriter {
     }
 
     pub fn into_bytes(self) -> Vec<u8> {
-        let used = ((self.max_bit_pos + 7) / 8) as usize;
+        let used = self.max_bit_pos.div_ceil(8) as usize;
         self.data.into_iter().take(used).collect()
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
-        let used = ((self.max_bit_pos + 7) / 8) as usize;
+        let used = self.max_bit_pos.div_ceil(8) as usize;
         self.data.iter().take(used).copied().collect()
     }
 
@@ -476,6 +481,14 @@ mod tests {
         assert_eq!(reader.read_bll().unwrap(), 0xA1B2C3D4);
     }
 
+    #[test]
+    fn bll_stores_least_significant_byte_first() {
+        let mut writer = BitWriter::new();
+        writer.write_bll(0x2528).unwrap();
+        // 3-bit length 2, then 0x28 0x25.
+        assert_eq!(writer.into_bytes(), vec![0x45, 0x04, 0xA0]);
+    }
+
     #[test]
     fn roundtrip_handle_and_text() {
         let mut writer = BitWriter::new();
@@ -0,0 +1,323 @@
+//! Opt-in on-disk cache of decoded result tables (feature `cache`).
+//!
+//! Entries are keyed by the content hash of the drawing, so a copied or
+//! touched but unchanged file still hits. Hashing a large drawing costs a
+//! full read, so each cache also keeps a stamp per source path: while the
+//! file's size and modification time match the stamp, its recorded hash is
+//! reused without reading the file. A changed size or mtime rehashes the
+//! contents; a changed hash simply misses.
+//!
+//! The payload of an entry is opaque bytes. Every entry starts with a header
+//! naming the crate version and the source size, so tables written by another
+//! ezdwg release are treated as misses instead of being decoded wrongly.
+//!
+//! ```
+//! use _core::cache::ResultCache;
+//!
+//! let root = std::env::temp_dir().join(format!("ezdwg-cache-doc-{}", std::process::id()));
+//! let cache = ResultCache::new(&root);
+//! let key = cache.key("test_dwg/line_2000.dwg")?;
+//! if cache.load(&key, "decode_line_entities")?.is_none() {
+//!     cache.store(&key, "decode_line_entities", b"rows")?;
+//! }
+//! assert_eq!(cache.load(&key, "decode_line_entities")?.as_deref(), Some(&b"rows"[..]));
+//! cache.clear()?;
+//! # Ok::<(), _core::lowlevel::DwgError>(())
+//! ```
+
+use std::fs;
+use std::io::ErrorKind as IoErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::UNIX_EPOCH;
+
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::file_open;
+
+const ENTRY_MAGIC: &[u8; 8] = b"EZDWGC01";
+const ENTRIES_DIR: &str = "entries";
+const STAMPS_DIR: &str = "stamps";
+
+const FNV128_OFFSET: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+const FNV128_PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+const FNV64_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV64_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Identity of one source file as seen by the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileKey {
+    pub size: u64,
+    /// Modification time in nanoseconds since the Unix epoch (0 when the
+    /// platform does not report one).
+    pub modified_ns: u128,
+    /// FNV-1a 128-bit hash of the file contents.
+    pub hash: u128,
+}
+
+impl FileKey {
+    /// Lower-case hex form of [`FileKey::hash`], used as the entry directory.
+    pub fn hash_hex(&self) -> String {
+        format!("{:032x}", self.hash)
+    }
+}
+
+/// Result tables stored under one cache directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultCache {
+    root: PathBuf,
+}
+
+impl ResultCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the key of `path`, rehashing its contents only when the size
+    /// or modification time differ from the stamp left by the previous call.
+    pub fn key(&self, path: impl AsRef<Path>) -> Result<FileKey> {
+        let path = path.as_ref();
+        let (size, modified_ns) = file_stamp(path)?;
+        let stamp_path = self.stamp_path(path);
+        if let Some(hash) = read_stamp(&stamp_path, size, modified_ns) {
+            return Ok(FileKey {
+                size,
+                modified_ns,
+                hash,
+            });
+        }
+        let hash = content_hash(&file_open::read_file(path)?);
+        let stamp = format!("{size} {modified_ns} {hash:032x}\n");
+        // A stamp is only a shortcut; failing to write it costs a rehash.
+        let _ = write_atomic(&stamp_path, stamp.as_bytes());
+        Ok(FileKey {
+            size,
+            modified_ns,
+            hash,
+        })
+    }
+
+    /// Loads the table stored for `key`, or `None` when it is missing or was
+    /// written by a different ezdwg version.
+    pub fn load(&self, key: &FileKey, table: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.entry_path(key, table)?;
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == IoErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let header = entry_header(key.size);
+        Ok(data
+            .strip_prefix(header.as_slice())
+            .map(|payload| payload.to_vec()))
+    }
+
+    /// Stores `payload` as `table` of `key`. The entry is written to a
+    /// temporary file and renamed, so concurrent readers never see a
+    /// partially written table.
+    pub fn store(&self, key: &FileKey, table: &str, payload: &[u8]) -> Result<()> {
+        let path = self.entry_path(key, table)?;
+        let mut data = entry_header(key.size);
+        data.extend_from_slice(payload);
+        write_atomic(&path, &data)
+    }
+
+    /// Removes every entry and stamp, returning the number of files deleted.
+    pub fn clear(&self) -> Result<usize> {
+        let mut removed = 0;
+        for dir in [ENTRIES_DIR, STAMPS_DIR] {
+            removed += remove_tree(&self.root.join(dir))?;
+        }
+        Ok(removed)
+    }
+
+    fn entry_path(&self, key: &FileKey, table: &str) -> Result<PathBuf> {
+        validate_table_name(table)?;
+        Ok(self
+            .root
+            .join(ENTRIES_DIR)
+            .join(key.hash_hex())
+            .join(format!("{table}.bin")))
+    }
+
+    fn stamp_path(&self, path: &Path) -> PathBuf {
+        let absolute = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let name = fnv1a_64(absolute.to_string_lossy().as_bytes());
+        self.root.join(STAMPS_DIR).join(format!("{name:016x}"))
+    }
+}
+
+/// FNV-1a 128-bit hash of `data`.
+pub fn content_hash(data: &[u8]) -> u128 {
+    data.iter().fold(FNV128_OFFSET, |hash, &byte| {
+        (hash ^ u128::from(byte)).wrapping_mul(FNV128_PRIME)
+    })
+}
+
+fn fnv1a_64(data: &[u8]) -> u64 {
+    data.iter().fold(FNV64_OFFSET, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV64_PRIME)
+    })
+}
+
+fn file_stamp(path: &Path) -> Result<(u64, u128)> {
+    let metadata = fs::metadata(path)?;
+    let modified_ns = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    Ok((metadata.len(), modified_ns))
+}
+
+fn read_stamp(path: &Path, size: u64, modified_ns: u128) -> Option<u128> {
+    let text = fs::read_to_string(path).ok()?;
+    let mut fields = text.split_whitespace();
+    let stamped_size: u64 = fields.next()?.parse().ok()?;
+    let stamped_modified: u128 = fields.next()?.parse().ok()?;
+    let hash = u128::from_str_radix(fields.next()?, 16).ok()?;
+    (stamped_size == size && stamped_modified == modified_ns).then_some(hash)
+}
+
+fn entry_header(size: u64) -> Vec<u8> {
+    let version = env!("CARGO_PKG_VERSION").as_bytes();
+    let mut header = Vec::with_capacity(ENTRY_MAGIC.len() + version.len() + 9);
+    header.extend_from_slice(ENTRY_MAGIC);
+    header.push(version.len() as u8);
+    header.extend_from_slice(version);
+    header.extend_from_slice(&size.to_le_bytes());
+    header
+}
+
+fn validate_table_name(table: &str) -> Result<()> {
+    let valid = !table.is_empty()
+        && table.len() <= 128
+        && !table.starts_with('.')
+        && table
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-' | b'.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(DwgError::new(
+            ErrorKind::Format,
+            format!("invalid cache table name: {table:?}"),
+        ))
+    }
+}
+
+fn write_atomic(path: &Path, data: &[u8]) -> Result<()> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| DwgError::new(ErrorKind::Io, "cache path has no parent directory"))?;
+    fs::create_dir_all(parent)?;
+    let temp = parent.join(format!(
+        ".tmp-{}-{}",
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&temp, data)?;
+    if let Err(err) = fs::rename(&temp, path) {
+        let _ = fs::remove_file(&temp);
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+fn remove_tree(dir: &Path) -> Result<usize> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == IoErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err.into()),
+    };
+    let mut removed = 0;
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            removed += remove_tree(&path)?;
+        } else {
+            fs::remove_file(&path)?;
+            removed += 1;
+        }
+    }
+    fs::remove_dir(dir)?;
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content_hash, ResultCache};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("ezdwg-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn stores_tables_by_content_hash() {
+        let root = temp_root("cache-hash");
+        let cache = ResultCache::new(root.join("cache"));
+        fs::create_dir_all(&root).unwrap();
+        let first = root.join("a.dwg");
+        let copy = root.join("b.dwg");
+        fs::write(&first, b"AC1015 drawing").unwrap();
+        fs::write(&copy, b"AC1015 drawing").unwrap();
+
+        let key = cache.key(&first).unwrap();
+        assert_eq!(key.hash, content_hash(b"AC1015 drawing"));
+        assert_eq!(cache.load(&key, "lines").unwrap(), None);
+        cache.store(&key, "lines", b"payload").unwrap();
+
+        let copy_key = cache.key(&copy).unwrap();
+        assert_eq!(
+            cache.load(&copy_key, "lines").unwrap().as_deref(),
+            Some(&b"payload"[..])
+        );
+        assert!(cache.store(&key, "../escape", b"x").is_err());
+
+        fs::write(&first, b"AC1015 drawing, edited").unwrap();
+        let edited = cache.key(&first).unwrap();
+        assert_ne!(edited.hash, key.hash);
+        assert_eq!(cache.load(&edited, "lines").unwrap(), None);
+
+        // One table plus the stamps of both source paths.
+        assert_eq!(cache.clear().unwrap(), 3);
+        assert_eq!(cache.load(&key, "lines").unwrap(), None);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn matching_stamp_skips_rehashing() {
+        let root = temp_root("cache-stamp");
+        let cache = ResultCache::new(root.join("cache"));
+        fs::create_dir_all(&root).unwrap();
+        let drawing = root.join("a.dwg");
+        fs::write(&drawing, b"original").unwrap();
+        let key = cache.key(&drawing).unwrap();
+
+        // Rewrite the stamp with a different hash: while size and mtime
+        // match, the recorded hash wins over the contents.
+        let stamp = fs::read_dir(root.join("cache/stamps"))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let forged = format!("{} {} {:032x}\n", key.size, key.modified_ns, 7u128);
+        fs::write(&stamp, forged).unwrap();
+        assert_eq!(cache.key(&drawing).unwrap().hash, 7);
+
+        fs::write(&stamp, "garbage").unwrap();
+        assert_eq!(cache.key(&drawing).unwrap().hash, key.hash);
+        let _ = fs::remove_dir_all(&root);
+    }
+}
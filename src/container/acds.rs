@@ -0,0 +1,270 @@
+//! AcDs (data storage) section used by R2013+ files.
+//!
+//! The section is a small segmented container: a file header points at a
+//! segment index (`segidx`), which locates the data index (`datidx`) and the
+//! `_data_` segments. Each data index entry names a record header holding the
+//! owning object handle and the offset of its blob. 3DSOLID, REGION and BODY
+//! entities keep their ACIS data here instead of inline.
+
+use std::collections::HashMap;
+
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::io::ByteReader;
+
+/// Section name of the data storage in R2013+ files.
+pub const ACDS_SECTION_NAME: &str = "AcDb:AcDsPrototype_1b";
+
+const FILE_HEADER_SIZE: usize = 56;
+const SEGMENT_SIGNATURE: u16 = 0xD5AC;
+const SEGMENT_HEADER_SIZE: usize = 48;
+const SEGMENT_INDEX_ENTRY_SIZE: usize = 12;
+const BLOB_ALIGNMENT: usize = 16;
+const MAX_SEGMENTS: u32 = 1 << 20;
+const MAX_DATA_ENTRIES: u32 = 1 << 20;
+
+const ACIS_BINARY_SIGNATURES: [&[u8]; 2] = [b"ACIS BinaryFile", b"ASM BinaryFile"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcDsRecord {
+    /// Handle of the object that owns the blob.
+    pub handle: u64,
+    /// Index into the schema table (`schidx`).
+    pub schema_index: u32,
+    pub data: Vec<u8>,
+}
+
+impl AcDsRecord {
+    /// `"sab"` for binary ACIS/ASM data, `"sat"` for text, `None` otherwise.
+    pub fn acis_format(&self) -> Option<&'static str> {
+        if ACIS_BINARY_SIGNATURES
+            .iter()
+            .any(|signature| self.data.starts_with(signature))
+        {
+            return Some("sab");
+        }
+        // SAT text starts with the version number line, e.g. "21800 0 1 0".
+        let head = self.data.get(..16).unwrap_or(&self.data);
+        let digits = head.iter().take_while(|byte| byte.is_ascii_digit()).count();
+        if digits >= 3 && head.get(digits) == Some(&b' ') {
+            return Some("sat");
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SegmentLocation {
+    offset: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DataIndexEntry {
+    segment_index: u32,
+    offset: u32,
+    schema_index: u32,
+}
+
+/// Parses every data record of a decompressed AcDs section.
+pub fn parse_acds_records(bytes: &[u8]) -> Result<Vec<AcDsRecord>> {
+    if bytes.len() < FILE_HEADER_SIZE {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            "AcDs section too small for its file header",
+        ));
+    }
+    let mut reader = ByteReader::new(bytes);
+    reader.seek(24)?;
+    let segidx_offset = reader.read_u32_le()? as usize;
+    let _segidx_unknown = reader.read_u32_le()?;
+    let num_segidx = reader.read_u32_le()?;
+    let _schidx_segidx = reader.read_u32_le()?;
+    let datidx_segidx = reader.read_u32_le()?;
+    if num_segidx > MAX_SEGMENTS {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("AcDs segment count too large: {num_segidx}"),
+        ));
+    }
+
+    let segidx_data = segment_data(bytes, segidx_offset, b"segidx")?;
+    let mut segments = Vec::with_capacity(num_segidx as usize);
+    let mut segidx_reader = ByteReader::new(segidx_data);
+    for _ in 0..num_segidx {
+        if segidx_reader.remaining() < SEGMENT_INDEX_ENTRY_SIZE {
+            break;
+        }
+        let offset = segidx_reader.read_u64_le()?;
+        let _size = segidx_reader.read_u32_le()?;
+        segments.push(SegmentLocation {
+            offset: usize::try_from(offset).unwrap_or(usize::MAX),
+        });
+    }
+
+    let datidx = segments
+        .get(datidx_segidx as usize)
+        .copied()
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "AcDs data index segment missing"))?;
+    let datidx_data = segment_data(bytes, datidx.offset, b"datidx")?;
+    let mut datidx_reader = ByteReader::new(datidx_data);
+    let num_entries = datidx_reader.read_u32_le()?;
+    let _unknown = datidx_reader.read_u32_le()?;
+    if num_entries > MAX_DATA_ENTRIES {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("AcDs data index too large: {num_entries}"),
+        ));
+    }
+    let mut entries = Vec::with_capacity(num_entries as usize);
+    for _ in 0..num_entries {
+        entries.push(DataIndexEntry {
+            segment_index: datidx_reader.read_u32_le()?,
+            offset: datidx_reader.read_u32_le()?,
+            schema_index: datidx_reader.read_u32_le()?,
+        });
+    }
+
+    // Record headers are packed at the start of each `_data_` segment; blob
+    // offsets count from the end of that header table, padded to the next
+    // 16-byte boundary of the section.
+    let mut headers_by_segment: HashMap<u32, Vec<(DataIndexEntry, RecordHeader)>> = HashMap::new();
+    for entry in entries {
+        let location = segments
+            .get(entry.segment_index as usize)
+            .copied()
+            .ok_or_else(|| DwgError::new(ErrorKind::Format, "AcDs data segment missing"))?;
+        let data = segment_data(bytes, location.offset, b"_data_")?;
+        let header = read_record_header(data, entry.offset as usize)?;
+        headers_by_segment
+            .entry(entry.segment_index)
+            .or_default()
+            .push((entry, header));
+    }
+
+    let mut records = Vec::new();
+    let mut segment_order: Vec<_> = headers_by_segment.into_iter().collect();
+    segment_order.sort_unstable_by_key(|(segment_index, _headers)| *segment_index);
+    for (segment_index, headers) in segment_order {
+        let location = segments
+            .get(segment_index as usize)
+            .copied()
+            .ok_or_else(|| DwgError::new(ErrorKind::Format, "AcDs data segment missing"))?;
+        let data = segment_data(bytes, location.offset, b"_data_")?;
+        let table_end = headers
+            .iter()
+            .map(|(entry, header)| entry.offset as usize + header.entry_size as usize)
+            .max()
+            .unwrap_or(0);
+        let payload_start = location.offset + SEGMENT_HEADER_SIZE;
+        let table_end =
+            (payload_start + table_end).next_multiple_of(BLOB_ALIGNMENT) - payload_start;
+        let mut ordered = headers;
+        ordered.sort_by_key(|(entry, _header)| entry.offset);
+        for (entry, header) in ordered {
+            let start = table_end
+                .checked_add(header.data_offset as usize)
+                .ok_or_else(|| DwgError::new(ErrorKind::Format, "AcDs record offset overflow"))?;
+            let mut blob_reader = ByteReader::new(data);
+            blob_reader.seek(start)?;
+            let size = blob_reader.read_u32_le()? as usize;
+            let blob = blob_reader.read_bytes(size)?;
+            records.push(AcDsRecord {
+                handle: header.handle,
+                schema_index: entry.schema_index,
+                data: blob.to_vec(),
+            });
+        }
+    }
+    Ok(records)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RecordHeader {
+    entry_size: u32,
+    handle: u64,
+    data_offset: u32,
+}
+
+fn read_record_header(data: &[u8], offset: usize) -> Result<RecordHeader> {
+    let mut reader = ByteReader::new(data);
+    reader.seek(offset)?;
+    let entry_size = reader.read_u32_le()?;
+    let _unknown = reader.read_u32_le()?;
+    let handle = reader.read_u64_le()?;
+    let data_offset = reader.read_u32_le()?;
+    Ok(RecordHeader {
+        entry_size,
+        handle,
+        data_offset,
+    })
+}
+
+/// Payload of the segment at `offset` after checking its signature and name.
+fn segment_data<'a>(bytes: &'a [u8], offset: usize, name: &[u8; 6]) -> Result<&'a [u8]> {
+    let mut reader = ByteReader::new(bytes);
+    reader.seek(offset)?;
+    let signature = reader.read_u16_le()?;
+    let segment_name = reader.read_bytes(6)?;
+    if signature != SEGMENT_SIGNATURE || segment_name != name {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!(
+                "AcDs segment at 0x{offset:X} is not {}",
+                String::from_utf8_lossy(name)
+            ),
+        ));
+    }
+    let _segment_index = reader.read_u32_le()?;
+    let _is_blob = reader.read_u32_le()?;
+    let segment_size = reader.read_u32_le()? as usize;
+    let end = offset
+        .checked_add(segment_size)
+        .filter(|end| *end <= bytes.len())
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "AcDs segment exceeds section"))?;
+    bytes
+        .get(offset + SEGMENT_HEADER_SIZE..end)
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "AcDs segment smaller than its header"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_acds_records, ACDS_SECTION_NAME};
+    use crate::core::config::ParseConfig;
+    use crate::dwg::r2004::load_section_by_name;
+
+    fn solid_records(path: &str) -> Vec<(u64, Option<&'static str>)> {
+        let bytes = std::fs::read(path).expect("sample file");
+        let section = load_section_by_name(&bytes, &ParseConfig::default(), ACDS_SECTION_NAME)
+            .expect("acds section");
+        let mut records: Vec<_> = parse_acds_records(&section)
+            .expect("acds records")
+            .iter()
+            .filter(|record| record.acis_format().is_some())
+            .map(|record| (record.handle, record.acis_format()))
+            .collect();
+        records.sort_unstable();
+        records
+    }
+
+    #[test]
+    fn parses_acis_records_across_data_segments() {
+        let expected = vec![
+            (3429, Some("sab")),
+            (3433, Some("sab")),
+            (3434, Some("sab")),
+        ];
+        assert_eq!(
+            solid_records("test_dwg/acadsharp/sample_AC1027.dwg"),
+            expected
+        );
+        assert_eq!(
+            solid_records("test_dwg/acadsharp/sample_AC1032.dwg"),
+            expected
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_section() {
+        assert!(parse_acds_records(&[0u8; 16]).is_err());
+    }
+}
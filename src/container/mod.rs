@@ -1,7 +1,12 @@
+pub mod acds;
 pub mod section_directory;
 pub mod section_loader;
 pub mod stream_view;
 
-pub use section_directory::{SectionDirectory, SectionKind, SectionLocatorRecord};
+pub use acds::{parse_acds_records, AcDsRecord, ACDS_SECTION_NAME};
+pub use section_directory::{
+    SectionDirectory, SectionKind, SectionLocatorRecord, SYSTEM_SECTION_MAP,
+    SYSTEM_SECTION_PAGE_MAP,
+};
 pub use section_loader::{load_all_sections, load_section, load_section_by_index, SectionSlice};
 pub use stream_view::StreamView;
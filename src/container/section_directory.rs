@@ -7,6 +7,12 @@ const SECTION_LOCATOR_SENTINEL: [u8; 16] = [
     0x95, 0xA0, 0x4E, 0x28, 0x99, 0x82, 0x1A, 0xE5, 0x5E, 0x41, 0xE0, 0x5F, 0x9D, 0x3A, 0x4D, 0x00,
 ];
 const MAX_SECTION_RECORDS: u32 = 64;
+// Some third-party writers pad the locator table before its sentinel.
+const MAX_SENTINEL_PADDING: usize = 64;
+
+/// Names under which the R2004+ system sections can be loaded by name.
+pub const SYSTEM_SECTION_PAGE_MAP: &str = "AcDb:SectionPageMap";
+pub const SYSTEM_SECTION_MAP: &str = "AcDb:SectionMap";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SectionKind {
@@ -45,8 +51,8 @@ impl SectionKind {
 #[derive(Debug, Clone)]
 pub struct SectionLocatorRecord {
     pub record_no: u8,
-    pub offset: u32,
-    pub size: u32,
+    pub offset: u64,
+    pub size: u64,
     pub name: Option<String>,
 }
 
@@ -62,6 +68,17 @@ pub struct SectionDirectory {
     pub records: Vec<SectionLocatorRecord>,
     pub crc: u16,
     pub sentinel_ok: bool,
+    /// Deviations from the layout AutoCAD writes that were tolerated while
+    /// parsing: padding before the sentinel and records that are repeated or
+    /// out of order. `records` is already normalized.
+    pub warnings: Vec<String>,
+}
+
+impl SectionDirectory {
+    /// Whether the directory was read exactly as AutoCAD writes it.
+    pub fn is_standard(&self) -> bool {
+        self.sentinel_ok && self.warnings.is_empty()
+    }
 }
 
 pub fn parse(bytes: &[u8]) -> Result<SectionDirectory> {
@@ -95,27 +112,158 @@ pub fn parse_with_config(bytes: &[u8], config: &ParseConfig) -> Result<SectionDi
         let size = reader.read_u32_le()?;
         records.push(SectionLocatorRecord {
             record_no,
-            offset,
-            size,
+            offset: u64::from(offset),
+            size: u64::from(size),
             name: None,
         });
     }
 
     let crc = reader.read_u16_le()?;
-    let sentinel = reader.read_bytes(SECTION_LOCATOR_SENTINEL.len())?;
-    let sentinel_ok = sentinel == SECTION_LOCATOR_SENTINEL;
+    let sentinel_offset = reader.tell();
+    let padding = find_sentinel_padding(bytes, sentinel_offset);
+    let sentinel_ok = padding.is_some();
 
-    if config.strict && !sentinel_ok {
+    if config.strict && padding != Some(0) {
         return Err(
             DwgError::new(ErrorKind::Format, "section directory sentinel mismatch")
-                .with_offset(reader.tell()),
+                .with_offset(sentinel_offset),
         );
     }
 
+    let mut warnings = Vec::new();
+    if let Some(padding) = padding.filter(|&padding| padding > 0) {
+        warnings.push(format!(
+            "{padding} bytes of padding before the section locator sentinel"
+        ));
+    }
+    let records = normalize_records(records, bytes.len() as u64, &mut warnings);
+    if config.strict && !warnings.is_empty() {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("nonstandard section directory: {}", warnings.join("; ")),
+        ));
+    }
+
     Ok(SectionDirectory {
         record_count,
         records,
         crc,
         sentinel_ok,
+        warnings,
+    })
+}
+
+fn find_sentinel_padding(bytes: &[u8], offset: u64) -> Option<usize> {
+    let offset = usize::try_from(offset).ok()?;
+    (0..=MAX_SENTINEL_PADDING).find(|padding| {
+        offset
+            .checked_add(*padding)
+            .and_then(|start| bytes.get(start..start.checked_add(SECTION_LOCATOR_SENTINEL.len())?))
+            == Some(SECTION_LOCATOR_SENTINEL.as_slice())
     })
 }
+
+/// Drops repeated records and sorts the rest by record number, the order
+/// AutoCAD writes them in. Empty records are kept: AutoCAD writes record 3
+/// that way when the drawing has no such section.
+fn normalize_records(
+    records: Vec<SectionLocatorRecord>,
+    file_size: u64,
+    warnings: &mut Vec<String>,
+) -> Vec<SectionLocatorRecord> {
+    let mut kept: Vec<SectionLocatorRecord> = Vec::with_capacity(records.len());
+    for record in records {
+        if kept.iter().any(|other| other.record_no == record.record_no) {
+            warnings.push(format!(
+                "skipped repeated section locator record {}",
+                record.record_no
+            ));
+        } else {
+            if record.offset.saturating_add(record.size) > file_size {
+                warnings.push(format!(
+                    "section {} ends past the end of the file",
+                    record.kind().label()
+                ));
+            }
+            kept.push(record);
+        }
+    }
+    if kept
+        .windows(2)
+        .any(|pair| matches!(pair, [a, b] if a.record_no > b.record_no))
+    {
+        warnings.push("section locator records out of order".to_string());
+        kept.sort_by_key(|record| record.record_no);
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_with_config, SECTION_LOCATOR_SENTINEL};
+    use crate::core::config::ParseConfig;
+
+    fn directory_bytes(records: &[(u8, u32, u32)], padding: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; 0x15];
+        bytes.extend_from_slice(&(records.len() as u32).to_le_bytes());
+        for (record_no, offset, size) in records {
+            bytes.push(*record_no);
+            bytes.extend_from_slice(&offset.to_le_bytes());
+            bytes.extend_from_slice(&size.to_le_bytes());
+        }
+        bytes.extend_from_slice(&[0, 0]);
+        bytes.resize(bytes.len() + padding, 0);
+        bytes.extend_from_slice(&SECTION_LOCATOR_SENTINEL);
+        bytes.resize(0x100, 0);
+        bytes
+    }
+
+    #[test]
+    fn standard_directory_has_no_warnings() {
+        let bytes = directory_bytes(&[(0, 0x60, 0x10), (1, 0x70, 0x10), (2, 0x80, 0x10)], 0);
+
+        let directory = parse(&bytes).expect("directory");
+
+        assert!(directory.is_standard());
+        assert_eq!(directory.records.len(), 3);
+    }
+
+    #[test]
+    fn padded_and_reordered_directory_is_normalized() {
+        let bytes = directory_bytes(
+            &[
+                (2, 0x80, 0x10),
+                (0, 0x60, 0x10),
+                (1, 0x70, 0x10),
+                (1, 0x90, 4),
+                (3, 0, 0),
+            ],
+            8,
+        );
+
+        let directory = parse(&bytes).expect("directory");
+
+        assert!(directory.sentinel_ok);
+        assert!(!directory.is_standard());
+        let order: Vec<(u8, u64)> = directory
+            .records
+            .iter()
+            .map(|record| (record.record_no, record.offset))
+            .collect();
+        assert_eq!(order, vec![(0, 0x60), (1, 0x70), (2, 0x80), (3, 0)]);
+        assert_eq!(
+            directory.warnings,
+            vec![
+                "8 bytes of padding before the section locator sentinel",
+                "skipped repeated section locator record 1",
+                "section locator records out of order",
+            ]
+        );
+
+        let strict = ParseConfig {
+            strict: true,
+            ..ParseConfig::default()
+        };
+        assert!(parse_with_config(&bytes, &strict).is_err());
+    }
+}
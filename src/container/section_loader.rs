@@ -2,7 +2,9 @@ use crate::container::section_directory::{SectionDirectory, SectionLocatorRecord
 use crate::core::config::ParseConfig;
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
+use crate::core::stats::StatKind;
 use std::borrow::Cow;
+use std::time::Instant;
 
 #[derive(Debug, Clone)]
 pub struct SectionSlice<'a> {
@@ -15,7 +17,8 @@ pub fn load_section<'a>(
     record: SectionLocatorRecord,
     config: &ParseConfig,
 ) -> Result<SectionSlice<'a>> {
-    let size = record.size as u64;
+    let start = Instant::now();
+    let size = record.size;
     if size > config.max_section_bytes {
         return Err(DwgError::new(
             ErrorKind::Format,
@@ -26,13 +29,18 @@ pub fn load_section<'a>(
         ));
     }
 
-    let offset = record.offset as usize;
-    let size = record.size as usize;
+    let (Ok(offset), Ok(size)) = (usize::try_from(record.offset), usize::try_from(record.size))
+    else {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            "section range exceeds the addressable memory of this platform",
+        ));
+    };
     let end = offset
         .checked_add(size)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "section range overflow"))?;
 
-    if end > bytes.len() {
+    let Some(data) = bytes.get(offset..end) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             format!(
@@ -40,9 +48,19 @@ pub fn load_section<'a>(
                 bytes.len()
             ),
         ));
-    }
+    };
 
-    let data = Cow::Borrowed(&bytes[offset..end]);
+    if let Some(stats) = &config.stats {
+        // R14/R2000 sections are borrowed from the file bytes, so only the
+        // lookup is timed.
+        stats.record(
+            StatKind::Section,
+            &record.kind().label(),
+            size as u64,
+            start.elapsed(),
+        );
+    }
+    let data = Cow::Borrowed(data);
     Ok(SectionSlice { record, data })
 }
 
@@ -17,11 +17,11 @@ impl<'a> StreamView<'a> {
         self.section.record.clone()
     }
 
-    pub fn offset(&self) -> u32 {
+    pub fn offset(&self) -> u64 {
         self.section.record.offset
     }
 
-    pub fn size(&self) -> u32 {
+    pub fn size(&self) -> u64 {
         self.section.record.size
     }
 
@@ -1,9 +1,17 @@
+use crate::core::progress::{Progress, ProgressHook};
+use crate::core::stats::{StatKind, StatsCollector};
+
 #[derive(Debug, Clone)]
 pub struct ParseConfig {
     pub strict: bool,
     pub max_recursion: u32,
     pub max_objects: u32,
     pub max_section_bytes: u64,
+    /// Called while the object index is built and while records are
+    /// parsed through [`Decoder::parse_object_records`](crate::dwg::decoder::Decoder::parse_object_records).
+    pub progress: Option<ProgressHook>,
+    /// Collects section load and heuristic timings when set.
+    pub stats: Option<StatsCollector>,
 }
 
 impl Default for ParseConfig {
@@ -13,6 +21,29 @@ impl Default for ParseConfig {
             max_recursion: 64,
             max_objects: 1_000_000,
             max_section_bytes: 256 * 1024 * 1024,
+            progress: None,
+            stats: None,
+        }
+    }
+}
+
+impl ParseConfig {
+    pub(crate) fn report_progress(&self, progress: Progress) {
+        if let Some(hook) = &self.progress {
+            hook.report(progress);
+        }
+    }
+
+    pub(crate) fn time_stat<T>(
+        &self,
+        kind: StatKind,
+        name: &str,
+        f: impl FnOnce() -> T,
+        bytes: impl FnOnce(&T) -> u64,
+    ) -> T {
+        match &self.stats {
+            Some(stats) => stats.time(kind, name, f, bytes),
+            None => f(),
         }
     }
 }
@@ -1,3 +1,5 @@
 pub mod config;
 pub mod error;
+pub mod progress;
 pub mod result;
+pub mod stats;
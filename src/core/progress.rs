@@ -0,0 +1,68 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// Number of objects between two progress reports inside a loop. The final
+/// state of a stage is always reported.
+pub const PROGRESS_INTERVAL: u64 = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressStage {
+    /// Reading the object map into an [`ObjectIndex`](crate::objects::ObjectIndex).
+    IndexBuild,
+    /// Parsing object records.
+    Decode,
+    /// Encoding object records in the writer.
+    Write,
+}
+
+impl ProgressStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProgressStage::IndexBuild => "index",
+            ProgressStage::Decode => "decode",
+            ProgressStage::Write => "write",
+        }
+    }
+}
+
+/// Snapshot passed to a [`ProgressHook`]. `bytes` counts bytes read while
+/// reading and bytes encoded while writing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub stage: ProgressStage,
+    pub objects: u64,
+    pub objects_total: Option<u64>,
+    pub bytes: u64,
+    pub bytes_total: Option<u64>,
+}
+
+/// Shared progress callback stored on [`ParseConfig`](crate::core::config::ParseConfig)
+/// and [`WriterConfig`](crate::writer::WriterConfig).
+#[derive(Clone)]
+pub struct ProgressHook(Arc<dyn Fn(&Progress) + Send + Sync>);
+
+impl ProgressHook {
+    pub fn new(callback: impl Fn(&Progress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub fn report(&self, progress: Progress) {
+        (self.0)(&progress);
+    }
+
+    /// Reports every [`PROGRESS_INTERVAL`] objects and when `objects`
+    /// reaches `objects_total`.
+    pub fn report_throttled(&self, progress: Progress) {
+        if progress.objects.is_multiple_of(PROGRESS_INTERVAL)
+            || Some(progress.objects) == progress.objects_total
+        {
+            self.report(progress);
+        }
+    }
+}
+
+impl fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHook(..)")
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::core::result::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatKind {
+    /// Loading (and for R2004+ decompressing) a section, keyed by its name.
+    Section,
+    /// Decoding an entity or object body, keyed by its type name.
+    Type,
+    /// A recovery heuristic, keyed by its name.
+    Heuristic,
+}
+
+/// Totals of one key. `bytes` counts section bytes for sections, body bytes
+/// read for types and record bytes inspected for heuristics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatTotals {
+    pub count: u64,
+    pub bytes: u64,
+    pub elapsed: Duration,
+}
+
+/// Snapshot of a [`StatsCollector`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodeStats {
+    pub sections: BTreeMap<String, StatTotals>,
+    pub types: BTreeMap<String, StatTotals>,
+    pub heuristics: BTreeMap<String, StatTotals>,
+}
+
+impl DecodeStats {
+    fn table_mut(&mut self, kind: StatKind) -> &mut BTreeMap<String, StatTotals> {
+        match kind {
+            StatKind::Section => &mut self.sections,
+            StatKind::Type => &mut self.types,
+            StatKind::Heuristic => &mut self.heuristics,
+        }
+    }
+}
+
+/// Shared timing and byte counters stored on
+/// [`ParseConfig`](crate::core::config::ParseConfig). Clones record into the
+/// same totals, so one collector can follow several decoders and threads.
+#[derive(Clone, Default)]
+pub struct StatsCollector(Arc<Mutex<DecodeStats>>);
+
+impl StatsCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, kind: StatKind, name: &str, bytes: u64, elapsed: Duration) {
+        let Ok(mut stats) = self.0.lock() else {
+            return;
+        };
+        let table = stats.table_mut(kind);
+        let totals = match table.get_mut(name) {
+            Some(totals) => totals,
+            None => table.entry(name.to_string()).or_default(),
+        };
+        totals.count += 1;
+        totals.bytes += bytes;
+        totals.elapsed += elapsed;
+    }
+
+    /// Runs `f` and records its duration under `name`, with the byte count
+    /// `bytes` computes from the result.
+    pub fn time<T>(
+        &self,
+        kind: StatKind,
+        name: &str,
+        f: impl FnOnce() -> T,
+        bytes: impl FnOnce(&T) -> u64,
+    ) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.record(kind, name, bytes(&result), start.elapsed());
+        result
+    }
+
+    pub fn snapshot(&self) -> DecodeStats {
+        self.0.lock().map(|stats| stats.clone()).unwrap_or_default()
+    }
+}
+
+/// Byte count of a loaded buffer, or 0 when loading failed.
+pub(crate) fn loaded_len<T: AsRef<[u8]>>(result: &Result<T>) -> u64 {
+    result.as_ref().map_or(0, |data| data.as_ref().len() as u64)
+}
+
+impl fmt::Debug for StatsCollector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StatsCollector(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StatKind, StatsCollector};
+    use std::time::Duration;
+
+    #[test]
+    fn clones_record_into_shared_totals() {
+        let collector = StatsCollector::new();
+        let clone = collector.clone();
+        collector.record(
+            StatKind::Section,
+            "AcDb:Header",
+            100,
+            Duration::from_millis(2),
+        );
+        clone.record(
+            StatKind::Section,
+            "AcDb:Header",
+            50,
+            Duration::from_millis(3),
+        );
+        let decoded = clone.time(StatKind::Type, "LINE", || 7u8, |_| 40);
+
+        let stats = collector.snapshot();
+        let header = stats.sections.get("AcDb:Header").expect("section totals");
+        assert_eq!((header.count, header.bytes), (2, 150));
+        assert_eq!(header.elapsed, Duration::from_millis(5));
+        assert_eq!(decoded, 7);
+        assert_eq!(stats.types.get("LINE").map(|line| line.bytes), Some(40));
+        assert!(stats.heuristics.is_empty());
+    }
+}
@@ -1,15 +1,25 @@
-use crate::container::{SectionDirectory, SectionSlice};
+use crate::container::{
+    parse_acds_records, AcDsRecord, SectionDirectory, SectionKind, SectionSlice, ACDS_SECTION_NAME,
+};
 use crate::core::config::ParseConfig;
 use crate::core::error::{DwgError, ErrorKind};
+use crate::core::progress::{Progress, ProgressStage};
 use crate::core::result::Result;
+use crate::dwg::header::{
+    decode_drawing_times, decode_linetype_scales, DrawingTimes, LinetypeScales,
+};
 use crate::dwg::r2000;
 use crate::dwg::r2004;
 use crate::dwg::r2007;
 use crate::dwg::version::{detect_version, DwgVersion};
-use crate::objects::{ObjectClass, ObjectIndex, ObjectRecord};
+use crate::entities::solid_tessellation::{
+    tessellate_acds_records, SolidTessellator, TriangleMesh,
+};
+use crate::objects::{ObjectClass, ObjectIndex, ObjectRecord, ObjectRef};
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
+const FILE_HEADER_MAINTENANCE_OFFSET: usize = 0x0B;
 const FILE_HEADER_CODEPAGE_OFFSET: usize = 0x13;
 
 #[derive(Debug)]
@@ -73,6 +83,19 @@ impl<'a> Decoder<'a> {
         }
     }
 
+    /// How the sections are laid out: `"locator-table"` for R14/R2000,
+    /// `"paged"` for R2004 and R2010+ and `"paged-r2007"` for R2007.
+    pub fn layout_flavor(&self) -> &'static str {
+        match self.version {
+            DwgVersion::R14 | DwgVersion::R2000 => "locator-table",
+            DwgVersion::R2004 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018 => {
+                "paged"
+            }
+            DwgVersion::R2007 => "paged-r2007",
+            DwgVersion::Unknown(_) => "unknown",
+        }
+    }
+
     pub fn load_section_by_index(
         &self,
         directory: &SectionDirectory,
@@ -95,15 +118,114 @@ impl<'a> Decoder<'a> {
         }
     }
 
+    /// Fully decompressed section bytes by name (R2004+), e.g.
+    /// `AcDb:Header` or `AcDb:AcDbObjects`. The system sections are
+    /// available as `AcDb:SectionPageMap` and `AcDb:SectionMap`.
+    pub fn load_section_by_name(&self, name: &str) -> Result<Vec<u8>> {
+        match self.version {
+            DwgVersion::R14 | DwgVersion::R2000 => Err(DwgError::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "named sections require R2004 or later: {}",
+                    self.version.as_str()
+                ),
+            )),
+            DwgVersion::R2004 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018 => {
+                r2004::load_section_by_name(self.bytes, &self.config, name)
+            }
+            DwgVersion::R2007 => r2007::load_section_by_name(self.bytes, &self.config, name),
+            DwgVersion::Unknown(_) => Err(DwgError::new(
+                ErrorKind::Unsupported,
+                format!("unsupported DWG version: {}", self.version.as_str()),
+            )),
+        }
+    }
+
+    /// Records of the R2013+ data storage section (ACIS data of solids).
+    /// Files without the section yield no records.
+    pub fn acds_records(&self) -> Result<Vec<AcDsRecord>> {
+        if !matches!(self.version, DwgVersion::R2013 | DwgVersion::R2018) {
+            return Ok(Vec::new());
+        }
+        let directory = self.section_directory()?;
+        let has_section = directory
+            .records
+            .iter()
+            .any(|record| record.name.as_deref() == Some(ACDS_SECTION_NAME));
+        if !has_section {
+            return Ok(Vec::new());
+        }
+        let data = self.load_section_by_name(ACDS_SECTION_NAME)?;
+        parse_acds_records(&data)
+    }
+
+    /// Triangulates the ACIS solids of the data storage section with an
+    /// external tessellator. Only R2013+ files keep the solids there; older
+    /// files yield no meshes.
+    pub fn tessellate_solids(
+        &self,
+        tessellator: &dyn SolidTessellator,
+    ) -> Result<Vec<(u64, TriangleMesh)>> {
+        tessellate_acds_records(&self.acds_records()?, tessellator)
+    }
+
+    /// `TDCREATE`/`TDUPDATE` timestamps and edit timers from the header
+    /// variables section.
+    pub fn drawing_times(&self) -> Result<DrawingTimes> {
+        let section = self.header_section()?;
+        let maintenance_version = self
+            .bytes
+            .get(FILE_HEADER_MAINTENANCE_OFFSET)
+            .copied()
+            .unwrap_or(0);
+        decode_drawing_times(&section, &self.version, maintenance_version)
+    }
+
+    /// `LTSCALE` and `CELTSCALE` from the header variables section.
+    pub fn linetype_scales(&self) -> Result<LinetypeScales> {
+        let section = self.header_section()?;
+        let maintenance_version = self
+            .bytes
+            .get(FILE_HEADER_MAINTENANCE_OFFSET)
+            .copied()
+            .unwrap_or(0);
+        decode_linetype_scales(&section, &self.version, maintenance_version)
+    }
+
+    /// The header variables section, starting at its sentinel.
+    pub fn header_section(&self) -> Result<Vec<u8>> {
+        let section = match self.version {
+            DwgVersion::R14 | DwgVersion::R2000 => {
+                let directory = self.section_directory()?;
+                let index = directory
+                    .records
+                    .iter()
+                    .position(|record| record.kind() == SectionKind::HeaderVariables)
+                    .ok_or_else(|| {
+                        DwgError::new(ErrorKind::Format, "header variables section not found")
+                    })?;
+                self.load_section_by_index(&directory, index)?
+                    .data
+                    .into_owned()
+            }
+            _ => self.load_section_by_name("AcDb:Header")?,
+        };
+        Ok(section)
+    }
+
     pub fn build_object_index(&self) -> Result<ObjectIndex> {
         match self.version {
             DwgVersion::R14 | DwgVersion::R2000 => {
                 r2000::build_object_index(self.bytes, &self.config)
             }
             DwgVersion::R2004 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018 => {
-                r2004::build_object_index(self.bytes, &self.config)
+                let data = self.load_objects_section_data()?;
+                r2004::build_object_index_with_objects_data(self.bytes, data, &self.config)
+            }
+            DwgVersion::R2007 => {
+                let data = self.load_objects_section_data()?;
+                r2007::build_object_index_with_objects_data(self.bytes, data, &self.config)
             }
-            DwgVersion::R2007 => r2007::build_object_index(self.bytes, &self.config),
             DwgVersion::Unknown(_) => Err(DwgError::new(
                 ErrorKind::Unsupported,
                 format!("unsupported DWG version: {}", self.version.as_str()),
@@ -111,7 +233,7 @@ impl<'a> Decoder<'a> {
         }
     }
 
-    pub fn parse_object_record(&self, offset: u32) -> Result<ObjectRecord<'a>> {
+    pub fn parse_object_record(&self, offset: u64) -> Result<ObjectRecord<'_>> {
         match self.version {
             DwgVersion::R14 | DwgVersion::R2000 => r2000::parse_object_record(self.bytes, offset)
                 .map(|record| record.with_codepage(self.codepage)),
@@ -132,6 +254,37 @@ impl<'a> Decoder<'a> {
         }
     }
 
+    /// Parses every record of `index` in object map order and reports
+    /// [`ProgressStage::Decode`] progress through the config's hook, counting
+    /// the raw bytes of the parsed records.
+    pub fn parse_object_records<'i>(
+        &'i self,
+        index: &'i ObjectIndex,
+    ) -> impl Iterator<Item = (&'i ObjectRef, Result<ObjectRecord<'i>>)> + 'i {
+        let total = index.len() as u64;
+        let mut bytes_read = 0u64;
+        index
+            .objects
+            .iter()
+            .enumerate()
+            .map(move |(position, obj)| {
+                let record = self.parse_object_record(obj.offset);
+                if let Some(hook) = &self.config.progress {
+                    if let Ok(record) = &record {
+                        bytes_read += record.raw.len() as u64;
+                    }
+                    hook.report_throttled(Progress {
+                        stage: ProgressStage::Decode,
+                        objects: position as u64 + 1,
+                        objects_total: Some(total),
+                        bytes: bytes_read,
+                        bytes_total: None,
+                    });
+                }
+                (obj, record)
+            })
+    }
+
     pub fn dynamic_type_map(&self) -> Result<HashMap<u16, String>> {
         match self.version {
             DwgVersion::R14 | DwgVersion::R2000 => {
@@ -204,5 +357,5 @@ impl<'a> Decoder<'a> {
 
 fn detect_codepage(bytes: &[u8]) -> Option<u16> {
     let slice = bytes.get(FILE_HEADER_CODEPAGE_OFFSET..FILE_HEADER_CODEPAGE_OFFSET + 2)?;
-    Some(u16::from_le_bytes([slice[0], slice[1]]))
+    Some(u16::from_le_bytes(slice.try_into().ok()?))
 }
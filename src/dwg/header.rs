@@ -0,0 +1,381 @@
+//! Drawing header variables (`AcDb:Header`).
+//!
+//! Only the fixed-layout prefix of the header is walked, far enough to reach
+//! the linetype scales, the creation/update timestamps and the edit timers.
+
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+
+pub const HEADER_SENTINEL: [u8; 16] = [
+    0xCF, 0x7B, 0x1F, 0x23, 0xFD, 0xDE, 0x38, 0xA9, 0x5F, 0x7C, 0x68, 0xB8, 0x4E, 0x6D, 0x33, 0x5F,
+];
+pub const HEADER_END_SENTINEL: [u8; 16] = [
+    0x30, 0x84, 0xE0, 0xDC, 0x02, 0x21, 0xC7, 0x56, 0xA0, 0x83, 0x97, 0x47, 0xB1, 0x92, 0xCC, 0xA0,
+];
+
+/// Julian day of 1970-01-01 in the header's day numbering.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+/// A header date or duration stored as a day count and the milliseconds
+/// into that day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JulianDate {
+    pub day: u32,
+    pub millis: u32,
+}
+
+impl JulianDate {
+    pub const MILLIS_PER_DAY: u32 = 86_400_000;
+
+    pub fn new(day: u32, millis: u32) -> Self {
+        Self { day, millis }
+    }
+
+    /// Fractional days, the form used by DXF (`$TDCREATE` etc.).
+    pub fn to_days(self) -> f64 {
+        f64::from(self.day) + f64::from(self.millis) / f64::from(Self::MILLIS_PER_DAY)
+    }
+
+    pub fn from_days(days: f64) -> Self {
+        let days = days.max(0.0);
+        let day = days.floor();
+        let millis = ((days - day) * f64::from(Self::MILLIS_PER_DAY)).round() as u32;
+        Self {
+            day: day as u32,
+            millis: millis.min(Self::MILLIS_PER_DAY - 1),
+        }
+    }
+
+    pub fn from_unix_millis(millis: i64) -> Self {
+        let day = UNIX_EPOCH_JULIAN_DAY + millis.div_euclid(i64::from(Self::MILLIS_PER_DAY));
+        Self {
+            day: day.max(0) as u32,
+            millis: millis.rem_euclid(i64::from(Self::MILLIS_PER_DAY)) as u32,
+        }
+    }
+
+    pub fn now() -> Self {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as i64)
+            .unwrap_or(0);
+        Self::from_unix_millis(millis)
+    }
+}
+
+/// Revision timestamps and timers of a drawing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DrawingTimes {
+    /// `TDCREATE`, local time.
+    pub created: JulianDate,
+    /// `TDUPDATE`, local time.
+    pub updated: JulianDate,
+    /// `TDINDWG`, cumulative editing time.
+    pub edit_time: JulianDate,
+    /// `TDUSRTIMER`, user elapsed timer.
+    pub user_timer: JulianDate,
+}
+
+/// Global and current-entity linetype scales of a drawing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinetypeScales {
+    /// `LTSCALE`, applied to every linetype.
+    pub ltscale: f64,
+    /// `CELTSCALE`, the scale new entities are given.
+    pub celtscale: f64,
+}
+
+/// Decodes `LTSCALE` and `CELTSCALE` from the raw header section, starting
+/// at its sentinel. `maintenance_version` is byte `0x0B` of the file header.
+/// R2018 headers are not walked yet: their variables do not line up with
+/// the R2013 layout.
+pub fn decode_linetype_scales(
+    section: &[u8],
+    version: &DwgVersion,
+    maintenance_version: u8,
+) -> Result<LinetypeScales> {
+    if matches!(version, DwgVersion::R2018) {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            "linetype scales are not decoded from R2018 headers",
+        ));
+    }
+    let data = header_data(section, version, maintenance_version)?;
+    read_to_linetype_scales(&mut BitReader::new(data), version)
+}
+
+/// Decodes the timestamps from the raw header section, starting at its
+/// sentinel. `maintenance_version` is byte `0x0B` of the file header.
+pub fn decode_drawing_times(
+    section: &[u8],
+    version: &DwgVersion,
+    maintenance_version: u8,
+) -> Result<DrawingTimes> {
+    let data = header_data(section, version, maintenance_version)?;
+    let mut reader = BitReader::new(data);
+    read_to_linetype_scales(&mut reader, version)?;
+    if !uses_string_stream(version) {
+        reader.read_tv()?; // MENUNAME
+    }
+
+    let created = read_julian_date(&mut reader)?;
+    let updated = read_julian_date(&mut reader)?;
+    if is_r2004_or_later(version) {
+        for _ in 0..3 {
+            reader.read_bl()?;
+        }
+    }
+    let edit_time = read_julian_date(&mut reader)?;
+    let user_timer = read_julian_date(&mut reader)?;
+    Ok(DrawingTimes {
+        created,
+        updated,
+        edit_time,
+        user_timer,
+    })
+}
+
+/// The header variables behind the sentinel and size fields.
+fn header_data<'a>(
+    section: &'a [u8],
+    version: &DwgVersion,
+    maintenance_version: u8,
+) -> Result<&'a [u8]> {
+    if !section.starts_with(&HEADER_SENTINEL) {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            "header section sentinel mismatch",
+        ));
+    }
+    let data_start = header_data_offset(version, maintenance_version)?;
+    section.get(data_start..).ok_or_else(|| {
+        DwgError::new(
+            ErrorKind::Format,
+            format!("header section too short: {} bytes", section.len()),
+        )
+    })
+}
+
+/// Byte offset of the header variables behind the sentinel and size fields.
+fn header_data_offset(version: &DwgVersion, maintenance_version: u8) -> Result<usize> {
+    match version {
+        DwgVersion::R14 | DwgVersion::R2000 | DwgVersion::R2004 => Ok(20),
+        DwgVersion::R2007 => Ok(24),
+        // R2010+ files written with a maintenance version above 3 store a
+        // 64-bit section size.
+        DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018 => {
+            Ok(if maintenance_version > 3 { 28 } else { 24 })
+        }
+        DwgVersion::Unknown(_) => Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!("unsupported DWG version: {}", version.as_str()),
+        )),
+    }
+}
+
+/// Walks the header variables up to and through the 21 doubles from
+/// `LTSCALE` to `CELTSCALE`, returning the two scales.
+fn read_to_linetype_scales(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+) -> Result<LinetypeScales> {
+    let r14 = matches!(version, DwgVersion::R14);
+    let r2004_plus = is_r2004_or_later(version);
+    let inline_strings = !uses_string_stream(version);
+
+    if matches!(version, DwgVersion::R2013 | DwgVersion::R2018) {
+        reader.read_bll()?; // REQUIREDVERSIONS
+    }
+    for _ in 0..4 {
+        reader.read_bd()?;
+    }
+    if inline_strings {
+        for _ in 0..4 {
+            reader.read_tv()?;
+        }
+    }
+    reader.read_bl()?;
+    reader.read_bl()?;
+    if r14 {
+        reader.read_bs()?;
+    }
+    if !r2004_plus {
+        reader.read_h()?; // current viewport entity header
+    }
+
+    // DIMASO .. PELLIPSE
+    let flag_count = if r14 {
+        26
+    } else if r2004_plus {
+        21
+    } else {
+        20
+    };
+    for _ in 0..flag_count {
+        reader.read_b()?;
+    }
+    // PROXYGRAPHICS .. PDMODE (plus the R13-R14 only DRAGMODE, OSMODE,
+    // COORDS and PICKSTYLE)
+    let short_count = if r14 { 12 } else { 8 };
+    for _ in 0..short_count {
+        reader.read_bs()?;
+    }
+    if r2004_plus {
+        for _ in 0..3 {
+            reader.read_bl()?;
+        }
+    }
+    // USERI1-5, SPLINESEGS .. TEXTQLTY
+    for _ in 0..19 {
+        reader.read_bs()?;
+    }
+    // LTSCALE, then TEXTSIZE .. CMLSCALE, then CELTSCALE
+    let ltscale = reader.read_bd()?;
+    for _ in 0..19 {
+        reader.read_bd()?;
+    }
+    let celtscale = reader.read_bd()?;
+    Ok(LinetypeScales { ltscale, celtscale })
+}
+
+/// R2007+ keeps header strings in a separate string stream.
+fn uses_string_stream(version: &DwgVersion) -> bool {
+    matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    )
+}
+
+fn read_julian_date(reader: &mut BitReader<'_>) -> Result<JulianDate> {
+    let day = reader.read_bl()?;
+    let millis = reader.read_bl()?;
+    Ok(JulianDate { day, millis })
+}
+
+fn is_r2004_or_later(version: &DwgVersion) -> bool {
+    matches!(
+        version,
+        DwgVersion::R2004
+            | DwgVersion::R2007
+            | DwgVersion::R2010
+            | DwgVersion::R2013
+            | DwgVersion::R2018
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_linetype_scales, JulianDate, LinetypeScales, HEADER_SENTINEL};
+    use crate::bit::BitWriter;
+    use crate::core::config::ParseConfig;
+    use crate::dwg::decoder::Decoder;
+    use crate::dwg::version::DwgVersion;
+
+    fn times(path: &str) -> super::DrawingTimes {
+        let bytes = std::fs::read(path).expect("sample");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        decoder.drawing_times().expect("drawing times")
+    }
+
+    #[test]
+    fn decodes_r2000_header_times() {
+        let times = times("test_dwg/text_2000.dwg");
+        assert_eq!(times.created, JulianDate::new(2457479, 77669368));
+        assert_eq!(times.edit_time, JulianDate::new(0, 123502));
+        assert!(times.updated.to_days() >= times.created.to_days());
+    }
+
+    #[test]
+    fn decodes_header_times_across_versions() {
+        for path in [
+            "test_dwg/line_R14.dwg",
+            "test_dwg/line_2004.dwg",
+            "test_dwg/line_2007.dwg",
+            "test_dwg/line_2010.dwg",
+            "test_dwg/line_2013.dwg",
+        ] {
+            let times = times(path);
+            assert!(
+                (2_450_000..2_470_000).contains(&times.created.day),
+                "{path}: {:?}",
+                times.created
+            );
+            assert!(times.created.millis < JulianDate::MILLIS_PER_DAY, "{path}");
+            assert!(times.updated.to_days() >= times.created.to_days(), "{path}");
+        }
+        assert_eq!(
+            times("test_dwg/line_2007.dwg").created,
+            JulianDate::new(2457479, 77669368)
+        );
+    }
+
+    #[test]
+    fn decodes_linetype_scales_across_versions() {
+        for path in [
+            "test_dwg/line_R14.dwg",
+            "test_dwg/line_2000.dwg",
+            "test_dwg/line_2004.dwg",
+            "test_dwg/line_2007.dwg",
+            "test_dwg/line_2010.dwg",
+            "test_dwg/line_2013.dwg",
+        ] {
+            let bytes = std::fs::read(path).expect("sample");
+            let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+            let scales = decoder.linetype_scales().expect("linetype scales");
+            assert_eq!((scales.ltscale, scales.celtscale), (1.0, 1.0), "{path}");
+        }
+    }
+
+    #[test]
+    fn picks_ltscale_and_celtscale_from_their_doubles() {
+        let mut writer = BitWriter::new();
+        for _ in 0..4 {
+            writer.write_bd(0.0).unwrap();
+        }
+        for _ in 0..4 {
+            writer.write_tv("").unwrap();
+        }
+        writer.write_bl(0).unwrap();
+        writer.write_bl(0).unwrap();
+        writer.write_h(5, 0).unwrap();
+        for _ in 0..20 {
+            writer.write_b(0).unwrap();
+        }
+        for _ in 0..27 {
+            writer.write_bs(0).unwrap();
+        }
+        // LTSCALE, 19 other doubles, CELTSCALE.
+        writer.write_bd(10.0).unwrap();
+        for index in 0..19 {
+            writer.write_bd(f64::from(index)).unwrap();
+        }
+        writer.write_bd(0.5).unwrap();
+        let mut section = HEADER_SENTINEL.to_vec();
+        section.extend_from_slice(&[0; 4]);
+        section.extend_from_slice(&writer.into_bytes());
+
+        let scales = decode_linetype_scales(&section, &DwgVersion::R2000, 0).unwrap();
+        assert_eq!(
+            scales,
+            LinetypeScales {
+                ltscale: 10.0,
+                celtscale: 0.5
+            }
+        );
+        assert!(decode_linetype_scales(&section, &DwgVersion::R2018, 0).is_err());
+    }
+
+    #[test]
+    fn converts_julian_dates() {
+        assert_eq!(JulianDate::from_unix_millis(0), JulianDate::new(2440588, 0));
+        assert_eq!(
+            JulianDate::from_unix_millis(-1),
+            JulianDate::new(2440587, JulianDate::MILLIS_PER_DAY - 1)
+        );
+        let date = JulianDate::new(2457479, 43_200_000);
+        assert_eq!(date.to_days(), 2457479.5);
+        assert_eq!(JulianDate::from_days(date.to_days()), date);
+    }
+}
@@ -34,7 +34,7 @@ pub fn build_object_index(bytes: &[u8], config: &ParseConfig) -> Result<ObjectIn
     objects::build_object_index(bytes, config)
 }
 
-pub fn parse_object_record<'a>(bytes: &'a [u8], offset: u32) -> Result<ObjectRecord<'a>> {
+pub fn parse_object_record<'a>(bytes: &'a [u8], offset: u64) -> Result<ObjectRecord<'a>> {
     objects::parse_object_record(bytes, offset)
 }
 
@@ -118,14 +118,39 @@ pub fn load_dynamic_type_class_map(
     Ok(map)
 }
 
-#[derive(Debug, Clone)]
-struct ClassEntry {
-    class_number: u16,
-    item_class_id: u16,
-    dxf_name: String,
+/// Entries of the AcDb:Classes section with the type code objects of each
+/// class carry. Files that do not number their classes get 500, 501, ...
+/// in section order.
+pub fn load_class_definitions(bytes: &[u8], config: &ParseConfig) -> Result<Vec<ClassDefinition>> {
+    let directory = parse_section_directory(bytes, config)?;
+    let classes_index = directory
+        .records
+        .iter()
+        .position(|record| record.kind() == SectionKind::Classes)
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "section not found: AcDb:Classes"))?;
+    let section = load_section_by_index(bytes, &directory, classes_index, config)?;
+    let mut classes = parse_classes_section_r13_r15(&section.data)?;
+    if !classes.iter().any(|entry| entry.class_number >= 500) {
+        for (idx, class) in classes.iter_mut().enumerate() {
+            class.class_number = u16::try_from(500 + idx).unwrap_or(u16::MAX);
+        }
+    }
+    Ok(classes)
 }
 
-fn parse_classes_section_r13_r15(data: &[u8]) -> Result<Vec<ClassEntry>> {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassDefinition {
+    pub class_number: u16,
+    pub proxy_flags: u16,
+    pub app_name: String,
+    pub cpp_name: String,
+    pub dxf_name: String,
+    pub was_a_zombie: bool,
+    /// 0x1F2 for entity classes, 0x1F3 for object classes.
+    pub item_class_id: u16,
+}
+
+fn parse_classes_section_r13_r15(data: &[u8]) -> Result<Vec<ClassDefinition>> {
     let mut reader = BitReader::new(data);
 
     let sentinel_before = reader.read_rcs(SENTINEL_CLASSES_BEFORE.len())?;
@@ -142,18 +167,22 @@ fn parse_classes_section_r13_r15(data: &[u8]) -> Result<Vec<ClassEntry>> {
 
     let mut classes = Vec::new();
     while reader.tell_bits() < class_data_end {
-        let class_entry = (|| -> Result<ClassEntry> {
+        let class_entry = (|| -> Result<ClassDefinition> {
             let class_number = reader.read_bs()?;
-            let _proxy_flags_or_version = reader.read_bs()?;
-            let _app_name = reader.read_tv()?;
-            let _cpp_name = reader.read_tv()?;
+            let proxy_flags = reader.read_bs()?;
+            let app_name = reader.read_tv()?;
+            let cpp_name = reader.read_tv()?;
             let dxf_name = reader.read_tv()?;
-            let _was_a_zombie = reader.read_b()?;
+            let was_a_zombie = reader.read_b()? != 0;
             let item_class_id = reader.read_bs()?;
-            Ok(ClassEntry {
+            Ok(ClassDefinition {
                 class_number,
-                item_class_id,
+                proxy_flags,
+                app_name,
+                cpp_name,
                 dxf_name,
+                was_a_zombie,
+                item_class_id,
             })
         })();
 
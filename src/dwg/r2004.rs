@@ -2,14 +2,19 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 
 use crate::bit::{BitReader, Endian};
-use crate::container::{SectionDirectory, SectionLocatorRecord, SectionSlice};
+use crate::container::{
+    SectionDirectory, SectionLocatorRecord, SectionSlice, SYSTEM_SECTION_MAP,
+    SYSTEM_SECTION_PAGE_MAP,
+};
 use crate::core::config::ParseConfig;
 use crate::core::error::{DwgError, ErrorKind};
+use crate::core::progress::{Progress, ProgressStage};
 use crate::core::result::Result;
+use crate::core::stats::{loaded_len, StatKind};
 use crate::dwg::version::{detect_version, DwgVersion};
 use crate::entities;
 use crate::io::ByteReader;
-use crate::objects::object_record::parse_object_record_owned;
+use crate::objects::object_record::{self, parse_object_record_owned};
 use crate::objects::{Handle, ObjectClass, ObjectIndex, ObjectRecord, ObjectRef};
 
 const HEADER_OFFSET: usize = 0x80;
@@ -17,6 +22,12 @@ const HEADER_SIZE: usize = 0x6c;
 const SECTION_PAGE_MAP_MAGIC: u32 = 0x41630E3B;
 const SECTION_MAP_MAGIC: u32 = 0x4163003B;
 const DATA_SECTION_MAGIC: u32 = 0x4163043B;
+// Sizes below come from the file and are checked before the output buffer is
+// allocated. The page and section maps of real drawings stay far below the
+// system section limit; the page limit matches the default
+// `ParseConfig::max_section_bytes`.
+const MAX_SYSTEM_SECTION_SIZE: u32 = 16 * 1024 * 1024;
+const MAX_DECOMPRESSED_PAGE_SIZE: usize = 256 * 1024 * 1024;
 const SENTINEL_CLASSES_BEFORE: [u8; 16] = [
     0x8D, 0xA1, 0xC4, 0xB8, 0xC4, 0xA9, 0xF8, 0xC5, 0xC0, 0xDC, 0xF4, 0x5F, 0xE7, 0xCF, 0xB6, 0x8A,
 ];
@@ -100,16 +111,12 @@ pub fn parse_section_directory(bytes: &[u8], _config: &ParseConfig) -> Result<Se
     let mut records = Vec::with_capacity(section_map.len());
     for section in section_map {
         let record_no = record_no_for_name(&section.name);
-        let size = section
-            .size
-            .min(u32::MAX as u64)
-            .try_into()
-            .unwrap_or(u32::MAX);
+        let size = section.size;
         let offset = section
             .pages
             .first()
             .and_then(|page| page_lookup.get(&page.page_id))
-            .map(|entry| entry.address as u32)
+            .map(|entry| entry.address)
             .unwrap_or(0);
 
         records.push(SectionLocatorRecord {
@@ -125,6 +132,7 @@ pub fn parse_section_directory(bytes: &[u8], _config: &ParseConfig) -> Result<Se
         records,
         crc: 0,
         sentinel_ok: true,
+        warnings: Vec::new(),
     })
 }
 
@@ -161,13 +169,9 @@ pub fn load_section_by_index<'a>(
                 .pages
                 .first()
                 .and_then(|page| page_lookup.get(&page.page_id))
-                .map(|entry| entry.address as u32)
+                .map(|entry| entry.address)
                 .unwrap_or(0),
-            size: section
-                .size
-                .min(u32::MAX as u64)
-                .try_into()
-                .unwrap_or(u32::MAX),
+            size: section.size,
             name: Some(section.name),
         });
 
@@ -177,16 +181,51 @@ pub fn load_section_by_index<'a>(
     })
 }
 
+/// Decompressed (and decrypted) bytes of a section by name.
+///
+/// Besides the data sections listed in the section map, the two system
+/// sections are available as [`SYSTEM_SECTION_PAGE_MAP`] and
+/// [`SYSTEM_SECTION_MAP`].
+pub fn load_section_by_name(bytes: &[u8], config: &ParseConfig, name: &str) -> Result<Vec<u8>> {
+    match name {
+        SYSTEM_SECTION_PAGE_MAP => read_page_map_data(bytes, &read_header_data(bytes)?),
+        SYSTEM_SECTION_MAP => {
+            let header = read_header_data(bytes)?;
+            let page_map = read_page_map(bytes, &header)?;
+            read_section_map_data(bytes, &header, &page_map)
+        }
+        _ => {
+            let (size, mut data) = load_named_section(bytes, config, name)?;
+            // Pages are decompressed to their full size; drop the padding.
+            if let Ok(size) = usize::try_from(size) {
+                data.truncate(size);
+            }
+            Ok(data)
+        }
+    }
+}
+
 pub fn build_object_index(bytes: &[u8], config: &ParseConfig) -> Result<ObjectIndex> {
-    let handles_data = load_named_section_data(bytes, config, "AcDb:Handles")?;
     let objects_data = load_objects_section_data(bytes, config)?;
+    build_object_index_with_objects_data(bytes, &objects_data, config)
+}
+
+/// Builds the object index against an already decompressed
+/// `AcDb:AcDbObjects` section, so callers that also parse records from it
+/// keep a single copy.
+pub fn build_object_index_with_objects_data(
+    bytes: &[u8],
+    objects_data: &[u8],
+    config: &ParseConfig,
+) -> Result<ObjectIndex> {
+    let handles_data = load_named_section_data(bytes, config, "AcDb:Handles")?;
     let index = parse_object_map_handles(&handles_data, config)?;
     let version = detect_version(bytes)?;
 
     if config.strict {
         let mut valid_objects = Vec::with_capacity(index.objects.len());
         for object in index.objects {
-            if parse_object_record_owned(&objects_data, object.offset).is_ok() {
+            if object_record::parse_object_record(objects_data, object.offset).is_ok() {
                 valid_objects.push(object);
             }
         }
@@ -202,9 +241,12 @@ pub fn build_object_index(bytes: &[u8], config: &ParseConfig) -> Result<ObjectIn
         .filter(|object| (object.offset as usize) < max_offset)
         .collect();
     let objects = match version {
-        DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018 => {
-            select_best_r21_duplicate_handle_candidates(&objects_data, objects, version)
-        }
+        DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018 => config.time_stat(
+            StatKind::Heuristic,
+            "r21_duplicate_handles",
+            || select_best_r21_duplicate_handle_candidates(objects_data, objects, version),
+            |_| 0,
+        ),
         _ => objects,
     };
     Ok(ObjectIndex::from_objects(objects))
@@ -220,7 +262,7 @@ fn select_best_r21_duplicate_handle_candidates(
         grouped.entry(object.handle.0).or_default().push(object);
     }
 
-    let mut candidate_infos: HashMap<(u64, u32), R21DuplicateCandidateInfo> = HashMap::new();
+    let mut candidate_infos: HashMap<(u64, u64), R21DuplicateCandidateInfo> = HashMap::new();
     for candidates in grouped.values() {
         if candidates.len() < 2 {
             continue;
@@ -233,10 +275,10 @@ fn select_best_r21_duplicate_handle_candidates(
         }
     }
 
-    let mut selected_offsets: HashMap<u64, u32> = HashMap::with_capacity(grouped.len());
+    let mut selected_offsets: HashMap<u64, u64> = HashMap::with_capacity(grouped.len());
     for (handle, candidates) in grouped.iter() {
-        if candidates.len() == 1 {
-            selected_offsets.insert(*handle, candidates[0].offset);
+        if let [candidate] = candidates.as_slice() {
+            selected_offsets.insert(*handle, candidate.offset);
             continue;
         }
 
@@ -300,7 +342,7 @@ fn inspect_r21_duplicate_handle_candidate(
     object: ObjectRef,
     version: &DwgVersion,
 ) -> R21DuplicateCandidateInfo {
-    let record = match parse_object_record_owned(objects_data, object.offset) {
+    let record = match object_record::parse_object_record(objects_data, object.offset) {
         Ok(record) => record,
         Err(_) => return R21DuplicateCandidateInfo::default(),
     };
@@ -315,9 +357,7 @@ fn inspect_r21_duplicate_handle_candidate(
         data_size: header.data_size,
         class: crate::objects::object_type_info(header.type_code).class,
         decoded_common_entity_handle: decode_r21_candidate_common_entity_handle(
-            &record,
-            &header,
-            version,
+            &record, &header, version,
         ),
     }
 }
@@ -326,9 +366,12 @@ fn score_r21_duplicate_handle_candidate(
     object: ObjectRef,
     prev_candidates: Option<&Vec<ObjectRef>>,
     next_candidates: Option<&Vec<ObjectRef>>,
-    candidate_infos: &HashMap<(u64, u32), R21DuplicateCandidateInfo>,
+    candidate_infos: &HashMap<(u64, u64), R21DuplicateCandidateInfo>,
 ) -> i32 {
-    let Some(info) = candidate_infos.get(&(object.handle.0, object.offset)).copied() else {
+    let Some(info) = candidate_infos
+        .get(&(object.handle.0, object.offset))
+        .copied()
+    else {
         return i32::MIN / 8;
     };
     if !info.parsed_ok {
@@ -372,7 +415,7 @@ fn score_r21_contiguous_layer_table_bonus(
     type_code: u16,
     prev_candidates: Option<&Vec<ObjectRef>>,
     next_candidates: Option<&Vec<ObjectRef>>,
-    candidate_infos: &HashMap<(u64, u32), R21DuplicateCandidateInfo>,
+    candidate_infos: &HashMap<(u64, u64), R21DuplicateCandidateInfo>,
 ) -> i32 {
     if type_code != 0x33 {
         return 0;
@@ -394,10 +437,10 @@ fn score_r21_contiguous_layer_table_bonus(
 }
 
 fn has_nearby_same_type_candidate(
-    offset: u32,
+    offset: u64,
     type_code: u16,
     candidates: Option<&Vec<ObjectRef>>,
-    candidate_infos: &HashMap<(u64, u32), R21DuplicateCandidateInfo>,
+    candidate_infos: &HashMap<(u64, u64), R21DuplicateCandidateInfo>,
 ) -> bool {
     candidates.is_some_and(|rows| {
         rows.iter().any(|candidate| {
@@ -455,12 +498,11 @@ fn decode_r21_candidate_common_entity_handle(
             }
         } else {
             match version {
-                DwgVersion::R2010 => entities::common::parse_common_entity_header_r2010(
-                    &mut reader,
-                    end_bit,
-                )
-                .ok()
-                .map(|header| header.handle),
+                DwgVersion::R2010 => {
+                    entities::common::parse_common_entity_header_r2010(&mut reader, end_bit)
+                        .ok()
+                        .map(|header| header.handle)
+                }
                 DwgVersion::R2013 | DwgVersion::R2018 => {
                     entities::common::parse_common_entity_header_r2013(&mut reader, end_bit)
                         .ok()
@@ -482,19 +524,15 @@ fn decode_r21_candidate_common_entity_handle(
             return None;
         }
         let fallback = match version {
-            DwgVersion::R2010 => entities::common::parse_common_entity_header_r2010(
-                &mut fallback_reader,
-                end_bit,
-            )
-            .ok()
-            .map(|header| header.handle),
+            DwgVersion::R2010 => {
+                entities::common::parse_common_entity_header_r2010(&mut fallback_reader, end_bit)
+                    .ok()
+                    .map(|header| header.handle)
+            }
             DwgVersion::R2013 | DwgVersion::R2018 => {
-                entities::common::parse_common_entity_header_r2013(
-                    &mut fallback_reader,
-                    end_bit,
-                )
-                .ok()
-                .map(|header| header.handle)
+                entities::common::parse_common_entity_header_r2013(&mut fallback_reader, end_bit)
+                    .ok()
+                    .map(|header| header.handle)
             }
             _ => None,
         };
@@ -507,7 +545,9 @@ fn decode_r21_candidate_common_entity_handle(
 }
 
 fn resolve_r21_object_data_end_bit(data_size: u32, handle_stream_size_bits: u32) -> Option<u32> {
-    data_size.checked_mul(8)?.checked_sub(handle_stream_size_bits)
+    data_size
+        .checked_mul(8)?
+        .checked_sub(handle_stream_size_bits)
 }
 
 fn resolve_r21_object_data_end_bit_candidates(
@@ -555,20 +595,17 @@ pub fn load_objects_section_data(bytes: &[u8], config: &ParseConfig) -> Result<V
     load_named_section_data(bytes, config, "AcDb:AcDbObjects")
 }
 
-pub fn parse_object_record_from_section_data(
-    data: &[u8],
-    offset: u32,
-) -> Result<ObjectRecord<'static>> {
-    parse_object_record_owned(data, offset)
+pub fn parse_object_record_from_section_data(data: &[u8], offset: u64) -> Result<ObjectRecord<'_>> {
+    object_record::parse_object_record(data, offset)
 }
 
 pub fn parse_object_record<'a>(
     bytes: &'a [u8],
-    offset: u32,
+    offset: u64,
     config: &ParseConfig,
 ) -> Result<ObjectRecord<'a>> {
     let data = load_objects_section_data(bytes, config)?;
-    parse_object_record_from_section_data(&data, offset)
+    parse_object_record_owned(&data, offset)
 }
 
 pub fn load_dynamic_type_map(bytes: &[u8], config: &ParseConfig) -> Result<HashMap<u16, String>> {
@@ -662,6 +699,11 @@ fn dynamic_type_class_map_from_classes(classes: &[ClassEntry]) -> HashMap<u16, O
 }
 
 fn load_named_section_data(bytes: &[u8], config: &ParseConfig, name: &str) -> Result<Vec<u8>> {
+    load_named_section(bytes, config, name).map(|(_size, data)| data)
+}
+
+/// Returns the declared section size together with the page-padded data.
+fn load_named_section(bytes: &[u8], config: &ParseConfig, name: &str) -> Result<(u64, Vec<u8>)> {
     let header = read_header_data(bytes)?;
     let page_map = read_page_map(bytes, &header)?;
     let section_map = read_section_map(bytes, &header, &page_map)?;
@@ -678,22 +720,22 @@ fn load_named_section_data(bytes: &[u8], config: &ParseConfig, name: &str) -> Re
         .find(|section| section.name == name)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, format!("section not found: {name}")))?;
 
-    load_section_data(bytes, section, &page_lookup, config)
+    let data = load_section_data(bytes, section, &page_lookup, config)?;
+    Ok((section.size, data))
 }
 
 fn read_header_data(bytes: &[u8]) -> Result<HeaderData> {
-    if bytes.len() < HEADER_OFFSET + HEADER_SIZE {
+    let Some(encrypted) = bytes.get(HEADER_OFFSET..HEADER_OFFSET + HEADER_SIZE) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "file too small for R2004 header data",
         ));
-    }
-    let encrypted = &bytes[HEADER_OFFSET..HEADER_OFFSET + HEADER_SIZE];
-    let magic = magic_sequence();
-    let mut decrypted = vec![0u8; HEADER_SIZE];
-    for (idx, out) in decrypted.iter_mut().enumerate() {
-        *out = encrypted[idx] ^ magic[idx];
-    }
+    };
+    let decrypted: Vec<u8> = encrypted
+        .iter()
+        .zip(magic_sequence())
+        .map(|(byte, magic)| byte ^ magic)
+        .collect();
 
     let mut reader = ByteReader::new(&decrypted);
     reader.seek(0x50)?;
@@ -711,13 +753,13 @@ fn read_header_data(bytes: &[u8]) -> Result<HeaderData> {
 
 fn read_system_section(bytes: &[u8], address: u64, expected_signature: u32) -> Result<Vec<u8>> {
     let offset = address as usize;
-    if offset + 0x14 > bytes.len() {
+    let Some(section) = bytes.get(offset..).filter(|section| section.len() >= 0x14) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "system section header out of range",
         ));
-    }
-    let mut reader = ByteReader::new(&bytes[offset..]);
+    };
+    let mut reader = ByteReader::new(section);
     let header = SystemSectionHeader {
         signature: reader.read_u32_le()?,
         decompressed_size: reader.read_u32_le()?,
@@ -735,16 +777,24 @@ fn read_system_section(bytes: &[u8], address: u64, expected_signature: u32) -> R
     let data_end = data_offset
         .checked_add(header.compressed_size as usize)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "system section size overflow"))?;
-    if data_end > bytes.len() {
+    let Some(data) = bytes.get(data_offset..data_end) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "system section data out of range",
         ));
-    }
-    let data = &bytes[data_offset..data_end];
+    };
     if header.compressed_size == 0 {
         return Ok(Vec::new());
     }
+    if header.decompressed_size > MAX_SYSTEM_SECTION_SIZE {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!(
+                "system section size {} exceeds limit",
+                header.decompressed_size
+            ),
+        ));
+    }
     match header.compressed_type {
         0x02 => decompress_r18(data, header.decompressed_size as usize),
         _ => Err(DwgError::not_implemented(
@@ -753,12 +803,16 @@ fn read_system_section(bytes: &[u8], address: u64, expected_signature: u32) -> R
     }
 }
 
-fn read_page_map(bytes: &[u8], header: &HeaderData) -> Result<Vec<PageMapEntry>> {
+fn read_page_map_data(bytes: &[u8], header: &HeaderData) -> Result<Vec<u8>> {
     let page_map_addr = header
         .section_page_map_address
         .checked_add(0x100)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "section page map address overflow"))?;
-    let data = read_system_section(bytes, page_map_addr, SECTION_PAGE_MAP_MAGIC)?;
+    read_system_section(bytes, page_map_addr, SECTION_PAGE_MAP_MAGIC)
+}
+
+fn read_page_map(bytes: &[u8], header: &HeaderData) -> Result<Vec<PageMapEntry>> {
+    let data = read_page_map_data(bytes, header)?;
     let mut reader = ByteReader::new(&data);
     let mut page_address: u64 = 0x100;
     let mut entries = Vec::new();
@@ -788,18 +842,26 @@ fn read_page_map(bytes: &[u8], header: &HeaderData) -> Result<Vec<PageMapEntry>>
     Ok(entries)
 }
 
-fn read_section_map(
+fn read_section_map_data(
     bytes: &[u8],
     header: &HeaderData,
     page_map: &[PageMapEntry],
-) -> Result<Vec<SectionEntry>> {
+) -> Result<Vec<u8>> {
     let section_map_page = page_map
         .iter()
         .find(|entry| entry.id == header.section_map_id as i32)
         .ok_or_else(|| {
             DwgError::new(ErrorKind::Format, "section map page not found in page map")
         })?;
-    let data = read_system_section(bytes, section_map_page.address, SECTION_MAP_MAGIC)?;
+    read_system_section(bytes, section_map_page.address, SECTION_MAP_MAGIC)
+}
+
+fn read_section_map(
+    bytes: &[u8],
+    header: &HeaderData,
+    page_map: &[PageMapEntry],
+) -> Result<Vec<SectionEntry>> {
+    let data = read_section_map_data(bytes, header, page_map)?;
     let mut reader = ByteReader::new(&data);
     if reader.remaining() < 20 {
         return Err(DwgError::new(
@@ -815,7 +877,9 @@ fn read_section_map(
     let _x00 = reader.read_u32_le()?;
     let _unknown = reader.read_u32_le()?;
 
-    let mut sections = Vec::with_capacity(header.section_entry_count as usize);
+    // Counts come from the file; cap preallocation by the entries that fit.
+    let mut sections =
+        Vec::with_capacity((header.section_entry_count as usize).min(reader.remaining() / 88));
     for _ in 0..header.section_entry_count {
         if reader.remaining() < 88 {
             return Err(DwgError::new(ErrorKind::Format, "section entry truncated"));
@@ -830,7 +894,7 @@ fn read_section_map(
         let name_bytes = reader.read_bytes(64)?;
         let name = read_cstring(name_bytes);
 
-        let mut pages = Vec::with_capacity(page_count as usize);
+        let mut pages = Vec::with_capacity((page_count as usize).min(reader.remaining() / 16));
         for _ in 0..page_count {
             if reader.remaining() < 16 {
                 return Err(DwgError::new(
@@ -862,6 +926,20 @@ fn load_section_data(
     section: &SectionEntry,
     page_map: &HashMap<u32, PageMapEntry>,
     config: &ParseConfig,
+) -> Result<Vec<u8>> {
+    config.time_stat(
+        StatKind::Section,
+        &section.name,
+        || decompress_section_data(bytes, section, page_map, config),
+        loaded_len,
+    )
+}
+
+fn decompress_section_data(
+    bytes: &[u8],
+    section: &SectionEntry,
+    page_map: &HashMap<u32, PageMapEntry>,
+    config: &ParseConfig,
 ) -> Result<Vec<u8>> {
     if section.encrypted == 1 {
         return Err(DwgError::not_implemented(
@@ -891,14 +969,13 @@ fn load_section_data(
             DwgError::new(ErrorKind::Format, "section page not found in page map")
         })?;
         let page_offset = entry.address as usize;
-        if page_offset + 32 > bytes.len() {
+        let Some(encrypted_header) = bytes.get(page_offset..page_offset + 32) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "data section header out of range",
             ));
-        }
-        let header_bytes =
-            decrypt_data_section_header(&bytes[page_offset..page_offset + 32], entry.address)?;
+        };
+        let header_bytes = decrypt_data_section_header(encrypted_header, entry.address)?;
         let header = parse_data_section_header(&header_bytes)?;
         if header.signature != DATA_SECTION_MAGIC {
             return Err(DwgError::new(
@@ -910,13 +987,12 @@ fn load_section_data(
         let data_end = data_offset
             .checked_add(header.compressed_size as usize)
             .ok_or_else(|| DwgError::new(ErrorKind::Format, "data section size overflow"))?;
-        if data_end > bytes.len() {
+        let Some(data) = bytes.get(data_offset..data_end) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "data section data out of range",
             ));
-        }
-        let data = &bytes[data_offset..data_end];
+        };
         let decompressed = if section.compressed == 2 {
             decompress_r18(data, section.max_decompressed_size as usize)?
         } else {
@@ -926,28 +1002,28 @@ fn load_section_data(
         let start = page_idx
             .checked_mul(section.max_decompressed_size as usize)
             .ok_or_else(|| DwgError::new(ErrorKind::Format, "section page offset overflow"))?;
-        if start >= output.len() {
-            continue;
+        if let Some(target) = output.get_mut(start..) {
+            for (slot, byte) in target.iter_mut().zip(&decompressed) {
+                *slot = *byte;
+            }
         }
-        let end = (start + decompressed.len()).min(output.len());
-        output[start..end].copy_from_slice(&decompressed[..end - start]);
     }
 
     Ok(output)
 }
 
 fn decrypt_data_section_header(bytes: &[u8], offset: u64) -> Result<[u8; 32]> {
-    if bytes.len() < 32 {
+    let Some(Ok(mut out)) = bytes.get(..32).map(<[u8; 32]>::try_from) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "data section header truncated",
         ));
-    }
-    let mut out = [0u8; 32];
-    out.copy_from_slice(&bytes[..32]);
+    };
     let mask = 0x4164_536B_u32 ^ (offset as u32);
     for chunk in out.chunks_exact_mut(4) {
-        let value = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) ^ mask;
+        let mut word = [0u8; 4];
+        word.copy_from_slice(chunk);
+        let value = u32::from_le_bytes(word) ^ mask;
         chunk.copy_from_slice(&value.to_le_bytes());
     }
     Ok(out)
@@ -980,8 +1056,8 @@ fn record_no_for_name(name: &str) -> u8 {
 }
 
 fn read_cstring(bytes: &[u8]) -> String {
-    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
-    String::from_utf8_lossy(&bytes[..end]).to_string()
+    let text = bytes.split(|&b| b == 0).next().unwrap_or_default();
+    String::from_utf8_lossy(text).to_string()
 }
 
 fn magic_sequence() -> [u8; HEADER_SIZE] {
@@ -1144,7 +1220,8 @@ fn parse_r21_class_names_best_candidate(
         let mut candidate_reader = numeric_end_reader.clone();
         candidate_reader.set_bit_pos(start_bit);
         let mut candidate_classes = template_classes.to_vec();
-        if fill_r21_class_names_from_reader(&mut candidate_reader, &mut candidate_classes).is_err() {
+        if fill_r21_class_names_from_reader(&mut candidate_reader, &mut candidate_classes).is_err()
+        {
             continue;
         }
         let mut score = score_r21_class_names(&candidate_classes);
@@ -1357,7 +1434,10 @@ fn parse_r21_classes_header_candidates<'a>(
 
     best.ok_or_else(|| {
         first_err.unwrap_or_else(|| {
-            DwgError::new(ErrorKind::Format, "failed to parse R21 classes section header")
+            DwgError::new(
+                ErrorKind::Format,
+                "failed to parse R21 classes section header",
+            )
         })
     })
 }
@@ -1377,7 +1457,10 @@ fn push_r21_class_name_start_candidates(out: &mut Vec<u32>, start_bit: u32) {
     }
 }
 
-fn fill_r21_class_names_from_reader(reader: &mut BitReader<'_>, classes: &mut [ClassEntry]) -> Result<()> {
+fn fill_r21_class_names_from_reader(
+    reader: &mut BitReader<'_>,
+    classes: &mut [ClassEntry],
+) -> Result<()> {
     for class in classes {
         let _app_name = read_tu(reader)?;
         let _cpp_name = read_tu(reader)?;
@@ -1435,14 +1518,14 @@ fn resolve_r21_string_stream_range(
     let mut size_field_start = absolute_end_bit.checked_sub(STRING_STREAM_METADATA_BITS)?;
     let mut size_reader = base_reader.clone();
     size_reader.set_bit_pos(size_field_start);
-    let low_size = u32::from(size_reader.read_rs(Endian::Little).ok()? as u16);
+    let low_size = u32::from(size_reader.read_rs(Endian::Little).ok()?);
 
     let mut stream_size_bits = low_size;
     if (stream_size_bits & 0x8000) != 0 {
         size_field_start = size_field_start.checked_sub(STRING_STREAM_METADATA_BITS)?;
         let mut hi_reader = base_reader.clone();
         hi_reader.set_bit_pos(size_field_start);
-        let high_size = u32::from(hi_reader.read_rs(Endian::Little).ok()? as u16);
+        let high_size = u32::from(hi_reader.read_rs(Endian::Little).ok()?);
         stream_size_bits = (stream_size_bits & 0x7FFF) | (high_size << 15);
     }
 
@@ -1503,8 +1586,16 @@ fn parse_object_map_handles(bytes: &[u8], config: &ParseConfig) -> Result<Object
         while (reader.tell() - start) < (section_size as u64 - 2) {
             let prev_handle = last_handle;
             let prev_offset = last_offset;
-            last_handle += read_modular_char(&mut reader)?;
-            last_offset += read_modular_char(&mut reader)?;
+            let delta_handle = read_modular_char(&mut reader)?;
+            let delta_offset = read_modular_char(&mut reader)?;
+            last_handle = last_handle.checked_add(delta_handle).ok_or_else(|| {
+                DwgError::new(ErrorKind::Format, "object map handle overflow")
+                    .with_offset(reader.tell())
+            })?;
+            last_offset = last_offset.checked_add(delta_offset).ok_or_else(|| {
+                DwgError::new(ErrorKind::Format, "object map offset overflow")
+                    .with_offset(reader.tell())
+            })?;
 
             if last_handle < 0 || last_offset < 0 {
                 if config.strict {
@@ -1520,22 +1611,9 @@ fn parse_object_map_handles(bytes: &[u8], config: &ParseConfig) -> Result<Object
                 last_offset = prev_offset;
                 continue;
             }
-            if last_offset > u32::MAX as i64 {
-                if config.strict {
-                    return Err(DwgError::new(
-                        ErrorKind::Format,
-                        "AcDb:Handles offset exceeds u32 range",
-                    )
-                    .with_offset(reader.tell()));
-                }
-                last_handle = prev_handle;
-                last_offset = prev_offset;
-                continue;
-            }
-
             objects.push(ObjectRef {
                 handle: Handle(last_handle as u64),
-                offset: last_offset as u32,
+                offset: last_offset as u64,
             });
 
             if objects.len() as u32 > config.max_objects {
@@ -1550,6 +1628,13 @@ fn parse_object_map_handles(bytes: &[u8], config: &ParseConfig) -> Result<Object
             break;
         }
         let _crc = read_u16_be(&mut reader)?;
+        config.report_progress(Progress {
+            stage: ProgressStage::IndexBuild,
+            objects: objects.len() as u64,
+            objects_total: None,
+            bytes: reader.tell(),
+            bytes_total: Some(bytes.len() as u64),
+        });
     }
 
     Ok(ObjectIndex::from_objects(objects))
@@ -1565,7 +1650,8 @@ fn read_modular_char(reader: &mut ByteReader<'_>) -> Result<i64> {
     let mut value: i64 = 0;
     let mut shift = 0;
 
-    for _ in 0..4 {
+    // Nine bytes carry 62 bits, enough for object map offsets past 4 GiB.
+    for _ in 0..9 {
         let mut byte = reader.read_u8()?;
         if (byte & 0x80) == 0 {
             let negative = (byte & 0x40) != 0;
@@ -1583,10 +1669,20 @@ fn read_modular_char(reader: &mut ByteReader<'_>) -> Result<i64> {
         shift += 7;
     }
 
-    Ok(value)
+    Err(DwgError::new(
+        ErrorKind::Format,
+        "object map modular char exceeds 64-bit range",
+    )
+    .with_offset(reader.tell()))
 }
 
 fn decompress_r18(src: &[u8], dst_size: usize) -> Result<Vec<u8>> {
+    if dst_size > MAX_DECOMPRESSED_PAGE_SIZE {
+        return Err(DwgError::new(
+            ErrorKind::Decode,
+            format!("decompressed page size {dst_size} exceeds limit"),
+        ));
+    }
     let mut dst = vec![0u8; dst_size];
     let mut dst_idx: usize = 0;
     let mut cursor = Cursor::new(src);
@@ -1688,13 +1784,12 @@ impl<'a> Cursor<'a> {
     }
 
     fn read_u8(&mut self) -> Result<u8> {
-        if self.pos >= self.data.len() {
+        let Some(&value) = self.data.get(self.pos) else {
             return Err(DwgError::new(
                 ErrorKind::Decode,
                 "unexpected end of compressed stream",
             ));
-        }
-        let value = self.data[self.pos];
+        };
         self.pos += 1;
         Ok(value)
     }
@@ -1753,14 +1848,16 @@ fn copy_literal(
         return Ok(dst_idx);
     }
     let end = cursor.pos + length;
-    if end > src.len() {
+    let Some(literal) = src.get(cursor.pos..end) else {
         return Err(DwgError::new(
             ErrorKind::Decode,
             "literal run exceeds compressed data",
         ));
-    }
+    };
     ensure_len(dst, dst_idx + length);
-    dst[dst_idx..dst_idx + length].copy_from_slice(&src[cursor.pos..end]);
+    if let Some(target) = dst.get_mut(dst_idx..dst_idx + length) {
+        target.copy_from_slice(literal);
+    }
     cursor.pos = end;
     Ok(dst_idx + length)
 }
@@ -1782,14 +1879,14 @@ fn copy_decompressed(
     }
 
     ensure_len(dst, dst_idx + length);
-    let mut out = dst_idx;
-    for _ in 0..length {
-        let src_idx = out - offset;
-        let byte = dst[src_idx];
-        dst[out] = byte;
-        out += 1;
+    // Byte by byte: the source run may overlap the bytes being written.
+    for out in dst_idx..dst_idx + length {
+        let byte = dst.get(out - offset).copied().unwrap_or(0);
+        if let Some(slot) = dst.get_mut(out) {
+            *slot = byte;
+        }
     }
-    Ok(out)
+    Ok(dst_idx + length)
 }
 
 fn ensure_len(dst: &mut Vec<u8>, len: usize) {
@@ -1802,6 +1899,21 @@ fn ensure_len(dst: &mut Vec<u8>, len: usize) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn loads_named_and_system_sections_without_padding() {
+        let bytes = std::fs::read("test_dwg/line_2004.dwg").expect("sample file");
+        let config = ParseConfig::default();
+
+        let header = load_section_by_name(&bytes, &config, "AcDb:Header").expect("header");
+        assert_eq!(header.len(), 620);
+        assert_eq!(header[..2], [0xCF, 0x7B]);
+        let section_map =
+            load_section_by_name(&bytes, &config, SYSTEM_SECTION_MAP).expect("section map");
+        assert_eq!(section_map[..4], 14u32.to_le_bytes());
+        assert!(load_section_by_name(&bytes, &config, SYSTEM_SECTION_PAGE_MAP).is_ok());
+        assert!(load_section_by_name(&bytes, &config, "AcDb:Missing").is_err());
+    }
+
     #[test]
     fn parses_section_directory_from_sample() {
         let bytes = std::fs::read("test_dwg/line_2004.dwg").expect("sample file");
@@ -1831,6 +1943,21 @@ mod tests {
         assert!(record.size > 0);
     }
 
+    #[test]
+    fn decoder_records_borrow_the_cached_objects_section() {
+        let bytes = std::fs::read("test_dwg/line_2004.dwg").expect("sample file");
+        let decoder =
+            crate::dwg::decoder::Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert_eq!(index.objects.len(), 199);
+        let object = index.objects.first().expect("object");
+        let record = decoder
+            .parse_object_record(object.offset)
+            .expect("object record");
+        assert!(matches!(record.raw, Cow::Borrowed(_)));
+        assert!(matches!(record.body, Cow::Borrowed(_)));
+    }
+
     #[test]
     fn parses_object_headers_from_records() {
         let bytes = std::fs::read("test_dwg/line_2004.dwg").expect("sample file");
@@ -2109,7 +2236,10 @@ mod tests {
             Some("ACDBDICTIONARYWDFLT")
         );
         assert_eq!(dynamic_map.get(&501).map(String::as_str), Some("MATERIAL"));
-        assert_eq!(dynamic_map.get(&502).map(String::as_str), Some("VISUALSTYLE"));
+        assert_eq!(
+            dynamic_map.get(&502).map(String::as_str),
+            Some("VISUALSTYLE")
+        );
     }
 
     #[test]
@@ -2128,8 +2258,14 @@ mod tests {
         ];
 
         let type_map = dynamic_type_map_from_classes(&classes);
-        assert_eq!(type_map.get(&644).map(String::as_str), Some("CUSTOM_ENTITY"));
-        assert_eq!(type_map.get(&694).map(String::as_str), Some("CUSTOM_OBJECT"));
+        assert_eq!(
+            type_map.get(&644).map(String::as_str),
+            Some("CUSTOM_ENTITY")
+        );
+        assert_eq!(
+            type_map.get(&694).map(String::as_str),
+            Some("CUSTOM_OBJECT")
+        );
 
         let class_map = dynamic_type_class_map_from_classes(&classes);
         assert_eq!(class_map.get(&644), Some(&ObjectClass::Entity));
@@ -2167,14 +2303,40 @@ mod tests {
             0x00, 0x00, // crc
             0x00, 0x02, // terminator section
         ];
-        let mut config = ParseConfig::default();
-        config.strict = true;
+        let config = ParseConfig {
+            strict: true,
+            ..ParseConfig::default()
+        };
         let err = parse_object_map_handles(&bytes, &config).expect_err("strict error");
         assert!(err
             .to_string()
             .contains("AcDb:Handles contains negative handle or offset"));
     }
 
+    #[test]
+    fn parse_object_map_handles_rejects_overflowing_deltas() {
+        // Three handle deltas of 2^62 - 1: the third overflows the i64 sum.
+        let max_delta = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F];
+        let mut bytes = vec![0x00, 0x28]; // 2-byte header + 38-byte payload
+        for _ in 0..2 {
+            bytes.extend_from_slice(&max_delta);
+            bytes.push(0x01);
+        }
+        bytes.extend_from_slice(&max_delta);
+        bytes.extend_from_slice(&max_delta);
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x02]); // crc, terminator
+
+        for strict in [false, true] {
+            let config = ParseConfig {
+                strict,
+                ..ParseConfig::default()
+            };
+            let err = parse_object_map_handles(&bytes, &config).expect_err("overflow");
+            assert_eq!(err.kind, ErrorKind::Format);
+            assert!(err.to_string().contains("object map handle overflow"));
+        }
+    }
+
     #[test]
     fn parse_object_map_handles_keeps_running_deltas_across_blocks() {
         let bytes = vec![
@@ -2188,10 +2350,12 @@ mod tests {
             0x00, 0x00, // crc
             0x00, 0x02, // terminator section
         ];
-        let mut config = ParseConfig::default();
-        config.strict = true;
+        let config = ParseConfig {
+            strict: true,
+            ..ParseConfig::default()
+        };
         let index = parse_object_map_handles(&bytes, &config).expect("index");
-        let refs: Vec<(u64, u32)> = index
+        let refs: Vec<(u64, u64)> = index
             .objects
             .iter()
             .map(|obj| (obj.handle.0, obj.offset))
@@ -2247,13 +2411,7 @@ mod tests {
         ]);
 
         assert_eq!(
-            score_r21_contiguous_layer_table_bonus(
-                current,
-                0x33,
-                Some(&prev),
-                Some(&next),
-                &infos,
-            ),
+            score_r21_contiguous_layer_table_bonus(current, 0x33, Some(&prev), Some(&next), &infos,),
             12_000
         );
     }
@@ -2317,4 +2475,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejects_oversized_system_sections_and_pages() {
+        let mut bytes = Vec::new();
+        for value in [SECTION_MAP_MAGIC, u32::MAX, 1, 2, 0] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.push(0);
+        let err = read_system_section(&bytes, 0, SECTION_MAP_MAGIC).expect_err("size limit");
+        assert!(err.to_string().contains("exceeds limit"));
+        assert!(decompress_r18(&[0], usize::MAX).is_err());
+    }
 }
@@ -1,10 +1,14 @@
 use std::collections::HashMap;
 
 use crate::bit::{BitReader, Endian};
-use crate::container::{SectionDirectory, SectionLocatorRecord, SectionSlice};
+use crate::container::{
+    SectionDirectory, SectionLocatorRecord, SectionSlice, SYSTEM_SECTION_MAP,
+    SYSTEM_SECTION_PAGE_MAP,
+};
 use crate::core::config::ParseConfig;
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
+use crate::core::stats::{loaded_len, StatKind};
 use crate::io::ByteReader;
 use crate::objects::{Handle, ObjectClass, ObjectIndex, ObjectRecord, ObjectRef};
 
@@ -18,6 +22,9 @@ const SYSTEM_PAGE_RS_DATA_SIZE: u64 = 239;
 const SYSTEM_PAGE_RS_CODEWORD_SIZE: u64 = 255;
 const SYSTEM_PAGE_CRC_BLOCK_SIZE: u64 = 8;
 const SYSTEM_PAGE_ALIGN_SIZE: u64 = 0x20;
+// Matches the default `ParseConfig::max_section_bytes`; page sizes come from
+// the file and are checked before the output buffer is allocated.
+const MAX_DECOMPRESSED_PAGE_SIZE: usize = 256 * 1024 * 1024;
 
 const SECTION_ENTRY_SIZE: usize = 8 * 8;
 const SECTION_PAGE_INFO_SIZE: usize = 7 * 8;
@@ -97,8 +104,8 @@ pub fn parse_section_directory(bytes: &[u8], _config: &ParseConfig) -> Result<Se
 
         records.push(SectionLocatorRecord {
             record_no,
-            offset: offset.min(u32::MAX as u64) as u32,
-            size: section.size.min(u32::MAX as u64) as u32,
+            offset,
+            size: section.size,
             name: Some(section.name),
         });
     }
@@ -108,6 +115,7 @@ pub fn parse_section_directory(bytes: &[u8], _config: &ParseConfig) -> Result<Se
         records,
         crc: 0,
         sentinel_ok: true,
+        warnings: Vec::new(),
     })
 }
 
@@ -138,10 +146,10 @@ pub fn load_section_by_index<'a>(
                         .page_map
                         .iter()
                         .find(|entry| entry.id == page.id as i64)
-                        .map(|entry| entry.address as u32)
+                        .map(|entry| entry.address)
                 })
                 .unwrap_or(0),
-            size: section.size.min(u32::MAX as u64) as u32,
+            size: section.size,
             name: Some(section.name.clone()),
         });
 
@@ -151,19 +159,41 @@ pub fn load_section_by_index<'a>(
     })
 }
 
+/// Decompressed bytes of a section by name, including the
+/// [`SYSTEM_SECTION_PAGE_MAP`] and [`SYSTEM_SECTION_MAP`] system pages.
+pub fn load_section_by_name(bytes: &[u8], config: &ParseConfig, name: &str) -> Result<Vec<u8>> {
+    match name {
+        SYSTEM_SECTION_PAGE_MAP => read_page_map_data(bytes, &read_header_data(bytes)?),
+        SYSTEM_SECTION_MAP => {
+            let header = read_header_data(bytes)?;
+            let page_map = read_page_map(bytes, &header)?;
+            read_section_map_data(bytes, &header, &page_map)
+        }
+        _ => load_named_section_data(bytes, config, name),
+    }
+}
+
 pub fn build_object_index(bytes: &[u8], config: &ParseConfig) -> Result<ObjectIndex> {
-    let handles_data = load_named_section_data(bytes, config, "AcDb:Handles")?;
     let objects_data = load_objects_section_data(bytes, config)?;
+    build_object_index_with_objects_data(bytes, &objects_data, config)
+}
+
+/// Builds the object index against an already decompressed
+/// `AcDb:AcDbObjects` section, so callers that also parse records from it
+/// keep a single copy.
+pub fn build_object_index_with_objects_data(
+    bytes: &[u8],
+    objects_data: &[u8],
+    config: &ParseConfig,
+) -> Result<ObjectIndex> {
+    let handles_data = load_named_section_data(bytes, config, "AcDb:Handles")?;
     let index = parse_object_map_handles(&handles_data, config)?;
 
     if config.strict {
         let mut valid_objects = Vec::with_capacity(index.objects.len());
         for object in index.objects {
-            if crate::objects::object_record::parse_object_record_owned(
-                &objects_data,
-                object.offset,
-            )
-            .is_ok()
+            if crate::objects::object_record::parse_object_record(objects_data, object.offset)
+                .is_ok()
             {
                 valid_objects.push(object);
             }
@@ -186,20 +216,17 @@ pub fn load_objects_section_data(bytes: &[u8], config: &ParseConfig) -> Result<V
     load_named_section_data(bytes, config, "AcDb:AcDbObjects")
 }
 
-pub fn parse_object_record_from_section_data(
-    data: &[u8],
-    offset: u32,
-) -> Result<ObjectRecord<'static>> {
-    crate::objects::object_record::parse_object_record_owned(data, offset)
+pub fn parse_object_record_from_section_data(data: &[u8], offset: u64) -> Result<ObjectRecord<'_>> {
+    crate::objects::object_record::parse_object_record(data, offset)
 }
 
 pub fn parse_object_record<'a>(
     bytes: &'a [u8],
-    offset: u32,
+    offset: u64,
     config: &ParseConfig,
 ) -> Result<ObjectRecord<'a>> {
     let data = load_objects_section_data(bytes, config)?;
-    parse_object_record_from_section_data(&data, offset)
+    crate::objects::object_record::parse_object_record_owned(&data, offset)
 }
 
 pub fn load_dynamic_type_map(bytes: &[u8], config: &ParseConfig) -> Result<HashMap<u16, String>> {
@@ -280,6 +307,20 @@ fn load_section_data(
     section: &SectionEntry,
     page_map: &[PageMapEntry],
     config: &ParseConfig,
+) -> Result<Vec<u8>> {
+    config.time_stat(
+        StatKind::Section,
+        &section.name,
+        || decompress_section_data(bytes, section, page_map, config),
+        loaded_len,
+    )
+}
+
+fn decompress_section_data(
+    bytes: &[u8],
+    section: &SectionEntry,
+    page_map: &[PageMapEntry],
+    config: &ParseConfig,
 ) -> Result<Vec<u8>> {
     if section.size > config.max_section_bytes {
         return Err(DwgError::new(
@@ -312,11 +353,11 @@ fn load_section_data(
         )?;
 
         let start = to_usize(page.offset, "R2007 section page offset")?;
-        if start >= output.len() {
-            continue;
+        if let Some(target) = output.get_mut(start..) {
+            for (slot, byte) in target.iter_mut().zip(&page_data) {
+                *slot = *byte;
+            }
         }
-        let end = (start + page_data.len()).min(output.len());
-        output[start..end].copy_from_slice(&page_data[..end - start]);
     }
 
     Ok(output)
@@ -342,14 +383,12 @@ fn read_data_page(
     let end = address
         .checked_add(read_size)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "R2007 data page range overflow"))?;
-    if end > bytes.len() {
+    let Some(page_buf) = bytes.get(address..end) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "R2007 data page out of file range",
         ));
-    }
-
-    let page_buf = &bytes[address..end];
+    };
     let block_count = to_usize(block_count_u64, "R2007 data page RS block count")?;
     let encoded_method = u8::try_from(encoded)
         .map_err(|_| DwgError::new(ErrorKind::Format, "R2007 encoded flag exceeds u8"))?;
@@ -366,22 +405,22 @@ fn read_data_page(
     if size_compressed < size_uncompressed {
         let compressed_size = to_usize(size_compressed, "R2007 compressed data page size")?;
         let uncompressed_size = to_usize(size_uncompressed, "R2007 uncompressed data page size")?;
-        if compressed_size > decoded.len() {
+        let Some(compressed) = decoded.get(..compressed_size) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "R2007 compressed data page exceeds decoded buffer",
             ));
-        }
-        decompress_r21(&decoded[..compressed_size], uncompressed_size)
+        };
+        decompress_r21(compressed, uncompressed_size)
     } else {
         let size = to_usize(size_uncompressed, "R2007 data page size")?;
-        if size > decoded.len() {
+        let Some(data) = decoded.get(..size) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "R2007 data page exceeds decoded buffer",
             ));
-        }
-        Ok(decoded[..size].to_vec())
+        };
+        Ok(data.to_vec())
     }
 }
 
@@ -507,8 +546,16 @@ fn parse_object_map_handles(bytes: &[u8], config: &ParseConfig) -> Result<Object
         while (reader.tell() - start) < (section_size as u64 - 2) {
             let prev_handle = last_handle;
             let prev_offset = last_offset;
-            last_handle += read_modular_char(&mut reader)?;
-            last_offset += read_modular_char(&mut reader)?;
+            let delta_handle = read_modular_char(&mut reader)?;
+            let delta_offset = read_modular_char(&mut reader)?;
+            last_handle = last_handle.checked_add(delta_handle).ok_or_else(|| {
+                DwgError::new(ErrorKind::Format, "object map handle overflow")
+                    .with_offset(reader.tell())
+            })?;
+            last_offset = last_offset.checked_add(delta_offset).ok_or_else(|| {
+                DwgError::new(ErrorKind::Format, "object map offset overflow")
+                    .with_offset(reader.tell())
+            })?;
 
             if last_handle < 0 || last_offset < 0 {
                 if config.strict {
@@ -524,22 +571,9 @@ fn parse_object_map_handles(bytes: &[u8], config: &ParseConfig) -> Result<Object
                 last_offset = prev_offset;
                 continue;
             }
-            if last_offset > u32::MAX as i64 {
-                if config.strict {
-                    return Err(DwgError::new(
-                        ErrorKind::Format,
-                        "AcDb:Handles offset exceeds u32 range",
-                    )
-                    .with_offset(reader.tell()));
-                }
-                last_handle = prev_handle;
-                last_offset = prev_offset;
-                continue;
-            }
-
             objects.push(ObjectRef {
                 handle: Handle(last_handle as u64),
-                offset: last_offset as u32,
+                offset: last_offset as u64,
             });
 
             if objects.len() as u32 > config.max_objects {
@@ -569,7 +603,8 @@ fn read_modular_char(reader: &mut ByteReader<'_>) -> Result<i64> {
     let mut value: i64 = 0;
     let mut shift = 0;
 
-    for _ in 0..4 {
+    // Nine bytes carry 62 bits, enough for object map offsets past 4 GiB.
+    for _ in 0..9 {
         let mut byte = reader.read_u8()?;
         if (byte & 0x80) == 0 {
             let negative = (byte & 0x40) != 0;
@@ -587,18 +622,23 @@ fn read_modular_char(reader: &mut ByteReader<'_>) -> Result<i64> {
         shift += 7;
     }
 
-    Ok(value)
+    Err(DwgError::new(
+        ErrorKind::Format,
+        "object map modular char exceeds 64-bit range",
+    )
+    .with_offset(reader.tell()))
 }
 
 fn read_header_data(bytes: &[u8]) -> Result<HeaderData> {
-    if bytes.len() < SECOND_HEADER_OFFSET + SECOND_HEADER_RS_SIZE {
+    let Some(encoded) =
+        bytes.get(SECOND_HEADER_OFFSET..SECOND_HEADER_OFFSET + SECOND_HEADER_RS_SIZE)
+    else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "file too small for R2007 second header",
         ));
-    }
+    };
 
-    let encoded = &bytes[SECOND_HEADER_OFFSET..SECOND_HEADER_OFFSET + SECOND_HEADER_RS_SIZE];
     let decoded = decode_reed_solomon(encoded, 239, 3, 4)?;
     if decoded.len() < SECOND_HEADER_PAYLOAD_OFFSET {
         return Err(DwgError::new(
@@ -619,28 +659,25 @@ fn read_header_data(bytes: &[u8]) -> Result<HeaderData> {
         let end = SECOND_HEADER_PAYLOAD_OFFSET
             .checked_add(size)
             .ok_or_else(|| DwgError::new(ErrorKind::Format, "second header size overflow"))?;
-        if end > decoded.len() {
+        let Some(data) = decoded.get(SECOND_HEADER_PAYLOAD_OFFSET..end) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "R2007 second header body out of range",
             ));
-        }
-        decoded[SECOND_HEADER_PAYLOAD_OFFSET..end].to_vec()
+        };
+        data.to_vec()
     } else if compressed_size > 0 {
         let compressed_size = compressed_size as usize;
         let end = SECOND_HEADER_PAYLOAD_OFFSET
             .checked_add(compressed_size)
             .ok_or_else(|| DwgError::new(ErrorKind::Format, "second header size overflow"))?;
-        if end > decoded.len() {
+        let Some(compressed) = decoded.get(SECOND_HEADER_PAYLOAD_OFFSET..end) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "R2007 compressed second header body out of range",
             ));
-        }
-        decompress_r21(
-            &decoded[SECOND_HEADER_PAYLOAD_OFFSET..end],
-            SECOND_HEADER_BODY_SIZE,
-        )?
+        };
+        decompress_r21(compressed, SECOND_HEADER_BODY_SIZE)?
     } else {
         return Err(DwgError::new(
             ErrorKind::Format,
@@ -648,17 +685,17 @@ fn read_header_data(bytes: &[u8]) -> Result<HeaderData> {
         ));
     };
 
-    if body.len() < SECOND_HEADER_BODY_SIZE {
+    let Some(body) = body.get(..SECOND_HEADER_BODY_SIZE) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "R2007 second header body is truncated",
         ));
-    }
+    };
 
-    let mut body_reader = ByteReader::new(&body[..SECOND_HEADER_BODY_SIZE]);
-    let mut fields = Vec::with_capacity(34);
-    for _ in 0..34 {
-        fields.push(body_reader.read_u64_le()?);
+    let mut body_reader = ByteReader::new(body);
+    let mut fields = [0u64; 34];
+    for field in &mut fields {
+        *field = body_reader.read_u64_le()?;
     }
 
     Ok(HeaderData {
@@ -674,17 +711,21 @@ fn read_header_data(bytes: &[u8]) -> Result<HeaderData> {
     })
 }
 
-fn read_page_map(bytes: &[u8], header: &HeaderData) -> Result<Vec<PageMapEntry>> {
+fn read_page_map_data(bytes: &[u8], header: &HeaderData) -> Result<Vec<u8>> {
     let address = STREAM_BASE_OFFSET
         .checked_add(header.pages_map_offset)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "R2007 page map address overflow"))?;
-    let data = read_system_page(
+    read_system_page(
         bytes,
         address,
         header.pages_map_size_compressed,
         header.pages_map_size_uncompressed,
         header.pages_map_correction_factor,
-    )?;
+    )
+}
+
+fn read_page_map(bytes: &[u8], header: &HeaderData) -> Result<Vec<PageMapEntry>> {
+    let data = read_page_map_data(bytes, header)?;
 
     let mut reader = ByteReader::new(&data);
     let mut entries = Vec::new();
@@ -721,23 +762,31 @@ fn read_page_map(bytes: &[u8], header: &HeaderData) -> Result<Vec<PageMapEntry>>
     Ok(entries)
 }
 
-fn read_section_map(
+fn read_section_map_data(
     bytes: &[u8],
     header: &HeaderData,
     page_map: &[PageMapEntry],
-) -> Result<Vec<SectionEntry>> {
+) -> Result<Vec<u8>> {
     let section_map_page = page_map
         .iter()
         .find(|entry| entry.id == header.sections_map_id as i64)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "R2007 section map page not found"))?;
 
-    let data = read_system_page(
+    read_system_page(
         bytes,
         section_map_page.address,
         header.sections_map_size_compressed,
         header.sections_map_size_uncompressed,
         header.sections_map_correction_factor,
-    )?;
+    )
+}
+
+fn read_section_map(
+    bytes: &[u8],
+    header: &HeaderData,
+    page_map: &[PageMapEntry],
+) -> Result<Vec<SectionEntry>> {
+    let data = read_section_map_data(bytes, header, page_map)?;
 
     let mut reader = ByteReader::new(&data);
     let mut sections = Vec::new();
@@ -777,7 +826,8 @@ fn read_section_map(
         let name = decode_utf16_string(reader.read_bytes(name_length)?)?;
 
         let page_count = to_usize(page_count, "R2007 section page count")?;
-        let mut pages = Vec::with_capacity(page_count);
+        let mut pages =
+            Vec::with_capacity(page_count.min(reader.remaining() / SECTION_PAGE_INFO_SIZE));
         for _ in 0..page_count {
             if reader.remaining() < SECTION_PAGE_INFO_SIZE {
                 return Err(DwgError::new(
@@ -841,35 +891,35 @@ fn read_system_page(
     let end = address
         .checked_add(page_size)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "R2007 system page range overflow"))?;
-    if end > bytes.len() {
+    let Some(page_buf) = bytes.get(address..end) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "R2007 system page out of file range",
         ));
-    }
+    };
 
     let block_count = to_usize(block_count, "R2007 RS block count")?;
-    let decoded = decode_reed_solomon(&bytes[address..end], 239, block_count, 4)?;
+    let decoded = decode_reed_solomon(page_buf, 239, block_count, 4)?;
 
     if size_compressed < size_uncompressed {
         let compressed_size = to_usize(size_compressed, "R2007 compressed page size")?;
         let uncompressed_size = to_usize(size_uncompressed, "R2007 uncompressed page size")?;
-        if compressed_size > decoded.len() {
+        let Some(compressed) = decoded.get(..compressed_size) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "R2007 compressed system page data out of range",
             ));
-        }
-        decompress_r21(&decoded[..compressed_size], uncompressed_size)
+        };
+        decompress_r21(compressed, uncompressed_size)
     } else {
         let size = to_usize(size_uncompressed, "R2007 page size")?;
-        if size > decoded.len() {
+        let Some(data) = decoded.get(..size) else {
             return Err(DwgError::new(
                 ErrorKind::Format,
                 "R2007 system page data out of range",
             ));
-        }
-        Ok(decoded[..size].to_vec())
+        };
+        Ok(data.to_vec())
     }
 }
 
@@ -880,26 +930,20 @@ fn decode_reed_solomon(src: &[u8], k: usize, block_count: usize, method: u8) ->
     if output_size == 0 {
         return Ok(Vec::new());
     }
-    if src.len() < output_size {
+    let Some(src) = src.get(..output_size) else {
         return Err(DwgError::new(
             ErrorKind::Decode,
             "R2007 RS input is smaller than required output layout",
         ));
-    }
+    };
 
     match method {
-        4 => {
-            let mut out = vec![0u8; output_size];
-            let mut dst = 0usize;
-            for bc in 0..block_count {
-                for idx in 0..k {
-                    out[dst] = src[block_count * idx + bc];
-                    dst += 1;
-                }
-            }
-            Ok(out)
-        }
-        1 => Ok(src[..output_size].to_vec()),
+        // Interleaved: byte `idx` of block `bc` is stored at
+        // `block_count * idx + bc`.
+        4 => Ok((0..block_count)
+            .flat_map(|bc| src.iter().skip(bc).step_by(block_count).copied())
+            .collect()),
+        1 => Ok(src.to_vec()),
         _ => Err(DwgError::not_implemented(
             "unsupported Reed-Solomon method for R2007",
         )),
@@ -917,6 +961,13 @@ fn decompress_r21(src: &[u8], dst_size: usize) -> Result<Vec<u8>> {
         ));
     }
 
+    if dst_size > MAX_DECOMPRESSED_PAGE_SIZE {
+        return Err(DwgError::new(
+            ErrorKind::Decode,
+            format!("R2007 decompressed page size {dst_size} exceeds limit"),
+        ));
+    }
+
     let mut dst = vec![0u8; dst_size];
     let src_size = src.len();
 
@@ -929,13 +980,13 @@ fn decompress_r21(src: &[u8], dst_size: usize) -> Result<Vec<u8>> {
         src_idx = src_idx
             .checked_add(2)
             .ok_or_else(|| DwgError::new(ErrorKind::Decode, "R2007 opcode index overflow"))?;
-        if src_idx >= src_size {
+        let Some(&byte) = src.get(src_idx) else {
             return Err(DwgError::new(
                 ErrorKind::Decode,
                 "R2007 opcode bootstrap exceeds input",
             ));
-        }
-        length = (src[src_idx] & 0x07) as usize;
+        };
+        length = (byte & 0x07) as usize;
         src_idx += 1;
     }
 
@@ -1271,15 +1322,15 @@ fn copy_from_output(dst: &mut [u8], dst_idx: usize, offset: usize, length: usize
         ));
     }
     for i in 0..length {
-        let src_pos = src_idx + i;
-        let dst_pos = dst_idx + i;
-        if src_pos >= dst.len() {
+        let Some(&byte) = dst.get(src_idx + i) else {
             return Err(DwgError::new(
                 ErrorKind::Decode,
                 "R2007 decompressed read exceeds output buffer",
             ));
+        };
+        if let Some(slot) = dst.get_mut(dst_idx + i) {
+            *slot = byte;
         }
-        dst[dst_pos] = dst[src_pos];
     }
     Ok(end)
 }
@@ -1296,13 +1347,13 @@ fn read_u8(src: &[u8], src_idx: &mut usize) -> Result<u8> {
 }
 
 fn copy_1b(src: &[u8], src_idx: usize, dst: &mut [u8], dst_idx: &mut usize) -> Result<()> {
-    if src_idx >= src.len() || *dst_idx >= dst.len() {
+    let (Some(&byte), Some(slot)) = (src.get(src_idx), dst.get_mut(*dst_idx)) else {
         return Err(DwgError::new(
             ErrorKind::Decode,
             "R2007 copy_1b out of range",
         ));
-    }
-    dst[*dst_idx] = src[src_idx];
+    };
+    *slot = byte;
     *dst_idx += 1;
     Ok(())
 }
@@ -1344,13 +1395,14 @@ fn copy_bytes_direct(
     let dst_end = (*dst_idx)
         .checked_add(length)
         .ok_or_else(|| DwgError::new(ErrorKind::Decode, "R2007 destination range overflow"))?;
-    if src_end > src.len() || dst_end > dst.len() {
+    let (Some(source), Some(target)) = (src.get(src_idx..src_end), dst.get_mut(*dst_idx..dst_end))
+    else {
         return Err(DwgError::new(
             ErrorKind::Decode,
             "R2007 direct copy out of range",
         ));
-    }
-    dst[*dst_idx..dst_end].copy_from_slice(&src[src_idx..src_end]);
+    };
+    target.copy_from_slice(source);
     *dst_idx = dst_end;
     Ok(())
 }
@@ -1362,10 +1414,11 @@ fn decode_utf16_string(bytes: &[u8]) -> Result<String> {
             "R2007 UTF-16 section name has odd byte length",
         ));
     }
-    let mut units = Vec::with_capacity(bytes.len() / 2);
-    for chunk in bytes.chunks_exact(2) {
-        units.push(u16::from_le_bytes([chunk[0], chunk[1]]));
-    }
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .filter_map(|chunk| chunk.try_into().ok())
+        .map(u16::from_le_bytes)
+        .collect();
     let decoded = String::from_utf16_lossy(&units);
     Ok(decoded.trim_end_matches('\0').to_string())
 }
@@ -1427,6 +1480,18 @@ mod tests {
             .expect("R2007 should be supported");
     }
 
+    #[test]
+    fn loads_named_and_system_sections_for_ac1021() {
+        let bytes = std::fs::read("test_dwg/line_2007.dwg").expect("sample file");
+        let config = ParseConfig::default();
+
+        let header = load_section_by_name(&bytes, &config, "AcDb:Header").expect("header");
+        assert_eq!(header.len(), 832);
+        assert_eq!(header[..2], [0xCF, 0x7B]);
+        assert!(load_section_by_name(&bytes, &config, SYSTEM_SECTION_MAP).is_ok());
+        assert!(load_section_by_name(&bytes, &config, SYSTEM_SECTION_PAGE_MAP).is_ok());
+    }
+
     #[test]
     fn parses_section_directory_and_core_names_for_ac1021() {
         let bytes = std::fs::read("test_dwg/line_2007.dwg").expect("sample file");
@@ -1631,14 +1696,40 @@ mod tests {
             0x00, 0x00, // crc
             0x00, 0x02, // terminator section
         ];
-        let mut config = ParseConfig::default();
-        config.strict = true;
+        let config = ParseConfig {
+            strict: true,
+            ..ParseConfig::default()
+        };
         let err = parse_object_map_handles(&bytes, &config).expect_err("strict error");
         assert!(err
             .to_string()
             .contains("AcDb:Handles contains negative handle or offset"));
     }
 
+    #[test]
+    fn parse_object_map_handles_rejects_overflowing_deltas() {
+        // Three handle deltas of 2^62 - 1: the third overflows the i64 sum.
+        let max_delta = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x3F];
+        let mut bytes = vec![0x00, 0x28]; // 2-byte header + 38-byte payload
+        for _ in 0..2 {
+            bytes.extend_from_slice(&max_delta);
+            bytes.push(0x01);
+        }
+        bytes.extend_from_slice(&max_delta);
+        bytes.extend_from_slice(&max_delta);
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x02]); // crc, terminator
+
+        for strict in [false, true] {
+            let config = ParseConfig {
+                strict,
+                ..ParseConfig::default()
+            };
+            let err = parse_object_map_handles(&bytes, &config).expect_err("overflow");
+            assert_eq!(err.kind, ErrorKind::Format);
+            assert!(err.to_string().contains("object map handle overflow"));
+        }
+    }
+
     #[test]
     fn parse_object_map_handles_keeps_running_deltas_across_blocks() {
         let bytes = vec![
@@ -1652,10 +1743,12 @@ mod tests {
             0x00, 0x00, // crc
             0x00, 0x02, // terminator section
         ];
-        let mut config = ParseConfig::default();
-        config.strict = true;
+        let config = ParseConfig {
+            strict: true,
+            ..ParseConfig::default()
+        };
         let index = parse_object_map_handles(&bytes, &config).expect("index");
-        let refs: Vec<(u64, u32)> = index
+        let refs: Vec<(u64, u64)> = index
             .objects
             .iter()
             .map(|obj| (obj.handle.0, obj.offset))
@@ -1663,4 +1756,22 @@ mod tests {
         assert_eq!(refs, vec![(1, 10), (3, 14), (10, 22), (12, 25)]);
     }
 
+    #[test]
+    fn rejects_oversized_decompressed_pages() {
+        let err = decompress_r21(&[0x20], usize::MAX).expect_err("size limit");
+        assert!(err.to_string().contains("exceeds limit"));
+    }
+
+    #[test]
+    fn reed_solomon_deinterleaves_blocks_and_rejects_short_input() {
+        // Two blocks of three data bytes, stored column by column.
+        let src = [b'a', b'x', b'b', b'y', b'c', b'z', 0xFF];
+        let decoded = decode_reed_solomon(&src, 3, 2, 4).expect("interleaved");
+        assert_eq!(decoded, b"abcxyz");
+        assert_eq!(
+            decode_reed_solomon(&src, 3, 2, 1).expect("plain"),
+            &src[..6]
+        );
+        assert!(decode_reed_solomon(&src[..5], 3, 2, 4).is_err());
+    }
 }
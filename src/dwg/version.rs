@@ -29,13 +29,13 @@ impl DwgVersion {
 }
 
 pub fn detect_version(bytes: &[u8]) -> Result<DwgVersion> {
-    if bytes.len() < 6 {
+    let Some(tag) = bytes.get(..6) else {
         return Err(DwgError::new(
             ErrorKind::Format,
             "file too small to contain DWG version",
         ));
-    }
-    let tag = std::str::from_utf8(&bytes[..6]).unwrap_or("");
+    };
+    let tag = std::str::from_utf8(tag).unwrap_or("");
     let version = match tag {
         "AC1014" => DwgVersion::R14,
         "AC1015" => DwgVersion::R2000,
@@ -11,6 +11,7 @@ use std::sync::atomic::{AtomicU32, Ordering};
 static R14_ARC_PREFERRED_DELTA: AtomicU32 = AtomicU32::new(64);
 
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ArcEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -21,6 +22,28 @@ pub struct ArcEntity {
     pub radius: f64,
     pub angle_start: f64,
     pub angle_end: f64,
+    /// How far the arc is extruded along `extrusion`; 0 for a flat arc.
+    pub thickness: f64,
+    pub extrusion: (f64, f64, f64),
+}
+
+impl Default for ArcEntity {
+    /// Flat, with the world Z axis as extrusion direction.
+    fn default() -> Self {
+        Self {
+            handle: Default::default(),
+            color_index: Default::default(),
+            true_color: Default::default(),
+            owner_handle: Default::default(),
+            layer_handle: Default::default(),
+            center: Default::default(),
+            radius: Default::default(),
+            angle_start: Default::default(),
+            angle_end: Default::default(),
+            thickness: Default::default(),
+            extrusion: (0.0, 0.0, 1.0),
+        }
+    }
 }
 
 pub fn decode_arc(reader: &mut BitReader<'_>) -> Result<ArcEntity> {
@@ -93,25 +116,24 @@ fn decode_arc_with_header(
 ) -> Result<ArcEntity> {
     let center = reader.read_3bd()?;
     let radius = reader.read_bd()?;
-    let _thickness = reader.read_bt()?;
-    let _extrusion = reader.read_be()?;
+    let thickness = reader.read_bt()?;
+    let extrusion = reader.read_be()?;
     let angle_start = reader.read_bd()?;
     let angle_end = reader.read_bd()?;
-    let (owner_handle, layer_handle) = match parse_common_entity_owner_and_layer_handle(
-        reader,
-        &header,
-        r2007_layer_only,
-    ) {
-        Ok(owner_layer) => owner_layer,
-        Err(err)
-            if allow_handle_decode_failure
-                && matches!(
-                    err.kind,
-                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
-                ) =>
-        { (None, 0) }
-        Err(err) => return Err(err),
-    };
+    let (owner_handle, layer_handle) =
+        match parse_common_entity_owner_and_layer_handle(reader, &header, r2007_layer_only) {
+            Ok(owner_layer) => owner_layer,
+            Err(err)
+                if allow_handle_decode_failure
+                    && matches!(
+                        err.kind,
+                        ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                    ) =>
+            {
+                (None, 0)
+            }
+            Err(err) => return Err(err),
+        };
 
     Ok(ArcEntity {
         handle: header.handle,
@@ -123,6 +145,8 @@ fn decode_arc_with_header(
         radius,
         angle_start,
         angle_end,
+        thickness,
+        extrusion,
     })
 }
 
@@ -234,6 +258,8 @@ fn consider_arc_r14_delta(
             radius,
             angle_start,
             angle_end,
+            thickness: 0.0,
+            extrusion,
         };
 
         if is_high_confidence_arc_candidate(
@@ -342,7 +368,7 @@ fn is_high_confidence_arc_candidate(
     }
 
     let span = (angle_end - angle_start).abs();
-    if span < 1.0e-3 || span > 7.0 {
+    if !(1.0e-3..=7.0).contains(&span) {
         return false;
     }
 
@@ -0,0 +1,190 @@
+use crate::bit::BitReader;
+use crate::core::error::ErrorKind;
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r14,
+    parse_common_entity_header_r2007, parse_common_entity_header_r2010,
+    parse_common_entity_header_r2013, read_handle_reference, CommonEntityHeader,
+};
+
+/// Express Tools text placed along an ARC.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ArcAlignedTextEntity {
+    pub handle: u64,
+    pub color_index: Option<u16>,
+    pub true_color: Option<u32>,
+    /// `None` when the R2007+ handle stream could not be read.
+    pub layer_handle: Option<u64>,
+    /// Text, font, big font and text style names; `None` when they live in
+    /// the R2007+ string stream.
+    pub text: Option<String>,
+    pub font: Option<String>,
+    pub bigfont: Option<String>,
+    pub style_name: Option<String>,
+    pub center: (f64, f64, f64),
+    pub radius: f64,
+    pub width_factor: f64,
+    pub height: f64,
+    pub char_spacing: f64,
+    pub offset_from_arc: f64,
+    pub right_offset: f64,
+    pub left_offset: f64,
+    /// Arc angles in radians.
+    pub start_angle: f64,
+    pub end_angle: f64,
+    pub reversed: bool,
+    /// DXF group 71: 1 outward from the center, 2 inward.
+    pub direction: u16,
+    /// DXF group 72: 1 fit, 2 left, 3 right, 4 center.
+    pub alignment: u16,
+    /// DXF group 73: 1 convex side of the arc, 2 concave side.
+    pub side: u16,
+    pub is_shx: bool,
+    pub extrusion: (f64, f64, f64),
+    pub arc_handle: Option<u64>,
+}
+
+/// Number of strings ARCALIGNEDTEXT keeps in the R2007+ string stream, in
+/// stream order: text, font, big font and style name.
+pub const ARC_ALIGNED_TEXT_STRING_COUNT: usize = 4;
+
+impl ArcAlignedTextEntity {
+    /// Fills the fields returned as `None` from string stream values.
+    pub fn apply_string_stream(&mut self, strings: &[String]) {
+        let mut strings = strings.iter().cloned();
+        self.text = self.text.take().or_else(|| strings.next());
+        self.font = self.font.take().or_else(|| strings.next());
+        self.bigfont = self.bigfont.take().or_else(|| strings.next());
+        self.style_name = self.style_name.take().or_else(|| strings.next());
+    }
+}
+
+pub fn decode_arc_aligned_text(reader: &mut BitReader<'_>) -> Result<ArcAlignedTextEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_arc_aligned_text_with_header(reader, header, false)
+}
+
+pub fn decode_arc_aligned_text_r14(
+    reader: &mut BitReader<'_>,
+    object_handle: u64,
+) -> Result<ArcAlignedTextEntity> {
+    let mut header = parse_common_entity_header_r14(reader)?;
+    if header.handle == 0 {
+        header.handle = object_handle;
+    }
+    decode_arc_aligned_text_with_header(reader, header, false)
+}
+
+pub fn decode_arc_aligned_text_r2007(reader: &mut BitReader<'_>) -> Result<ArcAlignedTextEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_arc_aligned_text_with_header(reader, header, true)
+}
+
+pub fn decode_arc_aligned_text_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<ArcAlignedTextEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_arc_aligned_text_with_header(reader, header, true)
+}
+
+pub fn decode_arc_aligned_text_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<ArcAlignedTextEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_arc_aligned_text_with_header(reader, header, true)
+}
+
+fn decode_arc_aligned_text_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    uses_string_stream: bool,
+) -> Result<ArcAlignedTextEntity> {
+    let read_text = |reader: &mut BitReader<'_>| -> Result<Option<String>> {
+        if uses_string_stream {
+            Ok(None)
+        } else {
+            reader.read_tv().map(Some)
+        }
+    };
+    let text = read_text(reader)?;
+    let font = read_text(reader)?;
+    let bigfont = read_text(reader)?;
+    let style_name = read_text(reader)?;
+    let center = reader.read_3bd()?;
+    let radius = reader.read_bd()?;
+    let width_factor = reader.read_bd()?;
+    let height = reader.read_bd()?;
+    let char_spacing = reader.read_bd()?;
+    let offset_from_arc = reader.read_bd()?;
+    let right_offset = reader.read_bd()?;
+    let left_offset = reader.read_bd()?;
+    let start_angle = reader.read_bd()?;
+    let end_angle = reader.read_bd()?;
+    let reversed = reader.read_bs()? != 0;
+    let direction = reader.read_bs()?;
+    let alignment = reader.read_bs()?;
+    let side = reader.read_bs()?;
+    // Bold, italic, underline, character set and pitch and family.
+    for _ in 0..5 {
+        reader.read_bs()?;
+    }
+    let is_shx = reader.read_bs()? != 0;
+    let _color = reader.read_bl()?;
+    let extrusion = reader.read_3bd()?;
+    let _wizard_flag = reader.read_bs()?;
+
+    reader.set_bit_pos(header.obj_size);
+    let (layer_handle, arc_handle) = match parse_common_entity_handles(reader, &header) {
+        Ok(common_handles) => (
+            Some(common_handles.layer),
+            read_handle_reference(reader, header.handle)
+                .ok()
+                .filter(|handle| *handle != 0),
+        ),
+        Err(err)
+            if uses_string_stream
+                && matches!(
+                    err.kind,
+                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                ) =>
+        {
+            (None, None)
+        }
+        Err(err) => return Err(err),
+    };
+
+    Ok(ArcAlignedTextEntity {
+        handle: header.handle,
+        color_index: header.color.index,
+        true_color: header.color.true_color,
+        layer_handle,
+        text,
+        font,
+        bigfont,
+        style_name,
+        center,
+        radius,
+        width_factor,
+        height,
+        char_spacing,
+        offset_from_arc,
+        right_offset,
+        left_offset,
+        start_angle,
+        end_angle,
+        reversed,
+        direction,
+        alignment,
+        side,
+        is_shx,
+        extrusion,
+        arc_handle,
+    })
+}
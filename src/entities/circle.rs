@@ -11,6 +11,7 @@ use std::sync::atomic::{AtomicU32, Ordering};
 static R14_CIRCLE_PREFERRED_DELTA: AtomicU32 = AtomicU32::new(64);
 
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct CircleEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -19,6 +20,26 @@ pub struct CircleEntity {
     pub layer_handle: u64,
     pub center: (f64, f64, f64),
     pub radius: f64,
+    /// How far the circle is extruded along `extrusion`; 0 for a flat circle.
+    pub thickness: f64,
+    pub extrusion: (f64, f64, f64),
+}
+
+impl Default for CircleEntity {
+    /// Flat, with the world Z axis as extrusion direction.
+    fn default() -> Self {
+        Self {
+            handle: Default::default(),
+            color_index: Default::default(),
+            true_color: Default::default(),
+            owner_handle: Default::default(),
+            layer_handle: Default::default(),
+            center: Default::default(),
+            radius: Default::default(),
+            thickness: Default::default(),
+            extrusion: (0.0, 0.0, 1.0),
+        }
+    }
 }
 
 pub fn decode_circle(reader: &mut BitReader<'_>) -> Result<CircleEntity> {
@@ -91,23 +112,22 @@ fn decode_circle_with_header(
 ) -> Result<CircleEntity> {
     let center = reader.read_3bd()?;
     let radius = reader.read_bd()?;
-    let _thickness = reader.read_bt()?;
-    let _extrusion = reader.read_be()?;
-    let (owner_handle, layer_handle) = match parse_common_entity_owner_and_layer_handle(
-        reader,
-        &header,
-        r2007_layer_only,
-    ) {
-        Ok(owner_layer) => owner_layer,
-        Err(err)
-            if allow_handle_decode_failure
-                && matches!(
-                    err.kind,
-                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
-                ) =>
-        { (None, 0) }
-        Err(err) => return Err(err),
-    };
+    let thickness = reader.read_bt()?;
+    let extrusion = reader.read_be()?;
+    let (owner_handle, layer_handle) =
+        match parse_common_entity_owner_and_layer_handle(reader, &header, r2007_layer_only) {
+            Ok(owner_layer) => owner_layer,
+            Err(err)
+                if allow_handle_decode_failure
+                    && matches!(
+                        err.kind,
+                        ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                    ) =>
+            {
+                (None, 0)
+            }
+            Err(err) => return Err(err),
+        };
 
     Ok(CircleEntity {
         handle: header.handle,
@@ -117,6 +137,8 @@ fn decode_circle_with_header(
         layer_handle,
         center,
         radius,
+        thickness,
+        extrusion,
     })
 }
 
@@ -223,6 +245,8 @@ fn consider_circle_r14_delta(
             layer_handle: 0,
             center,
             radius,
+            thickness: 0.0,
+            extrusion,
         };
 
         if is_high_confidence_circle_candidate(delta, center, radius, extrusion, score) {
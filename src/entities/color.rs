@@ -0,0 +1,288 @@
+/// CMC color method bytes stored in the high byte of the RGB word.
+const COLOR_METHOD_BY_LAYER: u32 = 0xC0;
+const COLOR_METHOD_BY_BLOCK: u32 = 0xC1;
+const COLOR_METHOD_RGB: u32 = 0xC2;
+const COLOR_METHOD_ACI: u32 = 0xC3;
+
+const BY_BLOCK_INDEX: u16 = 0;
+const BY_LAYER_INDEX: u16 = 256;
+/// Written by some exporters as an alias for BYLAYER.
+const BY_ENTITY_INDEX: u16 = 257;
+
+/// Brightness levels of the ten-step ACI hue groups (indices 10..=249).
+const ACI_HUE_LEVELS: [u16; 5] = [255, 204, 153, 127, 76];
+const ACI_GRAYS: [u8; 6] = [51, 80, 105, 130, 190, 255];
+
+/// Transparency method bytes stored in the high byte of the raw word.
+const TRANSPARENCY_METHOD_BY_BLOCK: u32 = 0x01;
+const TRANSPARENCY_METHOD_ALPHA: u32 = 0x02;
+
+/// Entity color after resolving the index/true-color split stored in DWG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntityColor {
+    ByLayer,
+    ByBlock,
+    Aci(u8),
+    TrueColor(u8, u8, u8),
+    ColorBookEntry {
+        book_name: String,
+        color_name: String,
+        rgb: (u8, u8, u8),
+    },
+}
+
+impl EntityColor {
+    /// Builds the color from the decoded `(color_index, true_color)` pair.
+    ///
+    /// A true color wins over the index unless it only carries a CMC method
+    /// marker; bare values in `1..=257` are index fallbacks written by older
+    /// encoders and are read as ACI.
+    pub fn from_index_and_true_color(index: Option<u16>, true_color: Option<u32>) -> Option<Self> {
+        if let Some(raw) = true_color {
+            match raw >> 24 {
+                COLOR_METHOD_BY_LAYER => return Some(Self::ByLayer),
+                COLOR_METHOD_BY_BLOCK => return Some(Self::ByBlock),
+                COLOR_METHOD_ACI => return Self::from_index(raw as u16 & 0x00FF),
+                COLOR_METHOD_RGB => return Some(Self::true_color(raw)),
+                0 if (1..=u32::from(BY_ENTITY_INDEX)).contains(&raw)
+                    && index.is_none_or(is_by_layer_or_block_index) =>
+                {
+                    return Self::from_index(raw as u16);
+                }
+                _ => {
+                    if raw & 0x00FF_FFFF != 0 {
+                        return Some(Self::true_color(raw));
+                    }
+                }
+            }
+        }
+        index.and_then(Self::from_index)
+    }
+
+    /// Builds the color from a CMC record, keeping the color book reference
+    /// when both names are present.
+    pub fn from_cmc(
+        index: u16,
+        rgb: u32,
+        color_name: Option<&str>,
+        book_name: Option<&str>,
+    ) -> Option<Self> {
+        let true_color = (rgb != 0).then_some(rgb);
+        let color = Self::from_index_and_true_color(Some(index), true_color)?;
+        match (color_name, book_name) {
+            (Some(color_name), Some(book_name))
+                if !color_name.is_empty() && !book_name.is_empty() =>
+            {
+                let rgb = color.to_rgb()?;
+                Some(Self::ColorBookEntry {
+                    book_name: book_name.to_string(),
+                    color_name: color_name.to_string(),
+                    rgb,
+                })
+            }
+            _ => Some(color),
+        }
+    }
+
+    pub fn from_index(index: u16) -> Option<Self> {
+        match index {
+            BY_BLOCK_INDEX => Some(Self::ByBlock),
+            BY_LAYER_INDEX | BY_ENTITY_INDEX => Some(Self::ByLayer),
+            1..=255 => Some(Self::Aci(index as u8)),
+            _ => None,
+        }
+    }
+
+    fn true_color(raw: u32) -> Self {
+        Self::TrueColor((raw >> 16) as u8, (raw >> 8) as u8, raw as u8)
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ByLayer => "bylayer",
+            Self::ByBlock => "byblock",
+            Self::Aci(_) => "aci",
+            Self::TrueColor(..) => "true_color",
+            Self::ColorBookEntry { .. } => "color_book",
+        }
+    }
+
+    pub fn aci(&self) -> Option<u8> {
+        match self {
+            Self::Aci(index) => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Display RGB; `None` while the color still depends on the layer or
+    /// the owning block reference.
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        match self {
+            Self::ByLayer | Self::ByBlock => None,
+            Self::Aci(index) => aci_to_rgb(*index),
+            Self::TrueColor(r, g, b) => Some((*r, *g, *b)),
+            Self::ColorBookEntry { rgb, .. } => Some(*rgb),
+        }
+    }
+
+    /// Display RGB packed as `0xRRGGBB`.
+    pub fn to_rgb_u32(&self) -> Option<u32> {
+        self.to_rgb()
+            .map(|(r, g, b)| (u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b))
+    }
+}
+
+/// Entity or layer transparency (R2004+).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transparency {
+    ByLayer,
+    ByBlock,
+    /// Alpha byte: 0 is fully transparent, 255 is opaque.
+    Alpha(u8),
+}
+
+impl Transparency {
+    pub fn from_raw(raw: u32) -> Self {
+        match raw >> 24 {
+            TRANSPARENCY_METHOD_BY_BLOCK => Self::ByBlock,
+            TRANSPARENCY_METHOD_ALPHA => Self::Alpha(raw as u8),
+            _ => Self::ByLayer,
+        }
+    }
+
+    /// Opacity in `0.0..=1.0`; `None` while inherited from the layer or block.
+    pub fn opacity(&self) -> Option<f64> {
+        match self {
+            Self::Alpha(alpha) => Some(f64::from(*alpha) / 255.0),
+            Self::ByLayer | Self::ByBlock => None,
+        }
+    }
+}
+
+fn is_by_layer_or_block_index(index: u16) -> bool {
+    matches!(index, BY_BLOCK_INDEX | BY_LAYER_INDEX | BY_ENTITY_INDEX)
+}
+
+/// Maps an AutoCAD Color Index to the default palette RGB.
+///
+/// ACI 7 is returned as white; callers rendering on light backgrounds pick
+/// their own contrast color.
+pub fn aci_to_rgb(index: u8) -> Option<(u8, u8, u8)> {
+    let rgb = match index {
+        0 => return None,
+        1 => (255, 0, 0),
+        2 => (255, 255, 0),
+        3 => (0, 255, 0),
+        4 => (0, 255, 255),
+        5 => (0, 0, 255),
+        6 => (255, 0, 255),
+        7 => (255, 255, 255),
+        8 => (128, 128, 128),
+        9 => (192, 192, 192),
+        10..=249 => aci_hue_rgb(index - 10)?,
+        250..=255 => {
+            let gray = *ACI_GRAYS.get(usize::from(index - 250))?;
+            (gray, gray, gray)
+        }
+    };
+    Some(rgb)
+}
+
+/// Hue groups advance 15 degrees every ten indices; within a group even
+/// offsets are saturated and odd offsets are the half-saturated tint of the
+/// same brightness.
+fn aci_hue_rgb(step: u8) -> Option<(u8, u8, u8)> {
+    let group = u16::from(step / 10);
+    let offset = usize::from(step % 10);
+    let level = *ACI_HUE_LEVELS.get(offset / 2)?;
+    let pale = offset % 2 == 1;
+
+    let sector = group / 4;
+    let quarter = group % 4;
+    let (r, g, b) = match sector {
+        0 => (4, quarter, 0),
+        1 => (4 - quarter, 4, 0),
+        2 => (0, 4, quarter),
+        3 => (0, 4 - quarter, 4),
+        4 => (quarter, 0, 4),
+        _ => (4, 0, 4 - quarter),
+    };
+    let channel = |quarters: u16| -> u8 {
+        let full = level * quarters / 4;
+        let value = if pale {
+            full + (level - full) / 2
+        } else {
+            full
+        };
+        value as u8
+    };
+    Some((channel(r), channel(g), channel(b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aci_to_rgb, EntityColor, Transparency};
+
+    #[test]
+    fn aci_to_rgb_matches_default_palette() {
+        assert_eq!(aci_to_rgb(0), None);
+        assert_eq!(aci_to_rgb(1), Some((255, 0, 0)));
+        assert_eq!(aci_to_rgb(10), Some((255, 0, 0)));
+        assert_eq!(aci_to_rgb(11), Some((255, 127, 127)));
+        assert_eq!(aci_to_rgb(13), Some((204, 102, 102)));
+        assert_eq!(aci_to_rgb(22), Some((204, 51, 0)));
+        assert_eq!(aci_to_rgb(61), Some((223, 255, 127)));
+        assert_eq!(aci_to_rgb(150), Some((0, 127, 255)));
+        assert_eq!(aci_to_rgb(249), Some((76, 38, 47)));
+        assert_eq!(aci_to_rgb(250), Some((51, 51, 51)));
+        assert_eq!(aci_to_rgb(255), Some((255, 255, 255)));
+    }
+
+    #[test]
+    fn from_index_and_true_color_prefers_true_color() {
+        assert_eq!(
+            EntityColor::from_index_and_true_color(Some(1), Some(0xC212_3456)),
+            Some(EntityColor::TrueColor(0x12, 0x34, 0x56))
+        );
+        assert_eq!(
+            EntityColor::from_index_and_true_color(Some(256), None),
+            Some(EntityColor::ByLayer)
+        );
+        assert_eq!(
+            EntityColor::from_index_and_true_color(Some(0), None),
+            Some(EntityColor::ByBlock)
+        );
+        assert_eq!(
+            EntityColor::from_index_and_true_color(Some(256), Some(5)),
+            Some(EntityColor::Aci(5))
+        );
+        assert_eq!(
+            EntityColor::from_index_and_true_color(Some(3), Some(0xC300_0007)),
+            Some(EntityColor::Aci(7))
+        );
+        assert_eq!(EntityColor::from_index_and_true_color(None, None), None);
+    }
+
+    #[test]
+    fn from_cmc_keeps_color_book_reference() {
+        let color = EntityColor::from_cmc(5, 0xC200_80FF, Some("PANTONE 279 C"), Some("PANTONE+"))
+            .expect("book color");
+
+        assert_eq!(color.kind(), "color_book");
+        assert_eq!(color.to_rgb_u32(), Some(0x0080FF));
+        assert_eq!(
+            EntityColor::from_cmc(5, 0, None, None),
+            Some(EntityColor::Aci(5))
+        );
+    }
+
+    #[test]
+    fn transparency_from_raw_reads_method_byte() {
+        assert_eq!(Transparency::from_raw(0), Transparency::ByLayer);
+        assert_eq!(Transparency::from_raw(0x0100_0000), Transparency::ByBlock);
+        let half = Transparency::from_raw(0x0200_007F);
+        assert_eq!(half, Transparency::Alpha(0x7F));
+        assert_eq!(half.opacity(), Some(127.0 / 255.0));
+        assert_eq!(Transparency::ByLayer.opacity(), None);
+    }
+}
@@ -1,14 +1,59 @@
-use crate::bit::HandleRef;
-use crate::bit::{BitReader, Endian};
+use crate::bit::{BitReader, Endian, HandleRefKind};
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
+use crate::entities::color::EntityColor;
 
 const MAX_COMMON_ENTITY_REACTORS: u32 = 1 << 20;
 
+/// Lineweights in 1/100 mm for the stored enum indices `0..=23`.
+const LINE_WEIGHT_VALUES: [i16; 24] = [
+    0, 5, 9, 13, 15, 18, 20, 25, 30, 35, 40, 50, 53, 60, 70, 80, 90, 100, 106, 120, 140, 158, 200,
+    211,
+];
+pub const LINE_WEIGHT_BY_LAYER_INDEX: u8 = 29;
+pub const LINE_WEIGHT_BY_LAYER: i16 = -1;
+pub const LINE_WEIGHT_BY_BLOCK: i16 = -2;
+pub const LINE_WEIGHT_DEFAULT: i16 = -3;
+
+/// Maps the stored lineweight enum index to the DXF group 370 value
+/// (1/100 mm, or one of the negative BYLAYER/BYBLOCK/DEFAULT markers).
+pub fn line_weight_from_index(index: u8) -> i16 {
+    match index {
+        LINE_WEIGHT_BY_LAYER_INDEX => LINE_WEIGHT_BY_LAYER,
+        30 => LINE_WEIGHT_BY_BLOCK,
+        _ => LINE_WEIGHT_VALUES
+            .get(usize::from(index))
+            .copied()
+            .unwrap_or(LINE_WEIGHT_DEFAULT),
+    }
+}
+
+/// Maps a DXF group 370 lineweight back to its stored enum index. Only the
+/// standard lineweights and the BYLAYER/BYBLOCK/DEFAULT markers are valid.
+pub fn line_weight_to_index(value: i16) -> Option<u8> {
+    match value {
+        LINE_WEIGHT_BY_LAYER => Some(LINE_WEIGHT_BY_LAYER_INDEX),
+        LINE_WEIGHT_BY_BLOCK => Some(30),
+        LINE_WEIGHT_DEFAULT => Some(31),
+        _ => LINE_WEIGHT_VALUES
+            .iter()
+            .position(|weight| *weight == value)
+            .map(|index| index as u8),
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct CommonEntityColor {
     pub index: Option<u16>,
     pub true_color: Option<u32>,
+    /// Raw transparency word (R2004+); see [`EntityColor`] for color data.
+    pub transparency: Option<u32>,
+}
+
+impl CommonEntityColor {
+    pub fn to_entity_color(&self) -> Option<EntityColor> {
+        EntityColor::from_index_and_true_color(self.index, self.true_color)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +65,7 @@ pub struct CommonEntityHeader {
     pub num_of_reactors: u32,
     pub xdic_missing_flag: u8,
     pub has_ds_binary_data: bool,
+    pub ltype_scale: f64,
     pub ltype_flags: u8,
     pub plotstyle_flags: u8,
     pub material_flags: u8,
@@ -27,6 +73,8 @@ pub struct CommonEntityHeader {
     pub has_face_visual_style: bool,
     pub has_edge_visual_style: bool,
     pub has_legacy_entity_links: bool,
+    /// Raw lineweight enum index; see [`line_weight_from_index`].
+    pub line_weight: u8,
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +82,9 @@ pub struct CommonEntityHandles {
     pub owner_ref: Option<u64>,
     pub reactors: Vec<u64>,
     pub xdic_obj: Option<u64>,
+    /// Entity chain links of R13-R2000 entities that store them.
+    pub previous_entity: Option<u64>,
+    pub next_entity: Option<u64>,
     pub layer: u64,
     pub ltype: Option<u64>,
     pub plotstyle: Option<u64>,
@@ -44,6 +95,15 @@ pub fn parse_common_entity_header(reader: &mut BitReader<'_>) -> Result<CommonEn
     parse_common_entity_header_impl(reader, false, false, false, None)
 }
 
+/// R13-R2000 entities have no xdic-missing flag: the bit read in its place is
+/// `nolinks`, and the extension dictionary handle is always present.
+pub fn parse_common_entity_header_r2000(reader: &mut BitReader<'_>) -> Result<CommonEntityHeader> {
+    let mut header = parse_common_entity_header(reader)?;
+    header.has_legacy_entity_links = header.xdic_missing_flag == 0;
+    header.xdic_missing_flag = 0;
+    Ok(header)
+}
+
 pub fn parse_common_entity_header_r14(reader: &mut BitReader<'_>) -> Result<CommonEntityHeader> {
     let start = reader.get_pos();
     match parse_common_entity_header_r14_impl(reader, false) {
@@ -266,10 +326,10 @@ fn parse_common_entity_header_impl_with_proxy_graphics(
     r2013_plus: bool,
     object_data_end_bit: Option<u32>,
 ) -> Result<(CommonEntityHeader, Option<Vec<u8>>)> {
-    let (obj_size, handle, proxy_graphics) =
+    let (obj_size, handle, eed_transparency, proxy_graphics) =
         read_common_entity_header_preamble(reader, r2010_plus, object_data_end_bit)?;
 
-    let header = parse_common_entity_header_fields_from_entmode(
+    let mut header = parse_common_entity_header_fields_from_entmode(
         reader,
         obj_size,
         handle,
@@ -279,6 +339,8 @@ fn parse_common_entity_header_impl_with_proxy_graphics(
         false,
     )?;
 
+    header.color.transparency = header.color.transparency.or(eed_transparency);
+
     Ok((header, proxy_graphics))
 }
 
@@ -286,25 +348,13 @@ fn read_common_entity_header_preamble(
     reader: &mut BitReader<'_>,
     r2010_plus: bool,
     object_data_end_bit: Option<u32>,
-) -> Result<(u32, u64, Option<Vec<u8>>)> {
+) -> Result<(u32, u64, Option<u32>, Option<Vec<u8>>)> {
     let obj_size = match object_data_end_bit {
         Some(bits) => bits,
         None => reader.read_rl(Endian::Little)?,
     };
     let handle = reader.read_h()?.value;
-
-    let mut ext_size = reader.read_bs()?;
-    if ext_size > 0 {
-        let mut size = ext_size;
-        while size > 0 {
-            let _app_handle = reader.read_h()?;
-            for _ in 0..size {
-                let _ = reader.read_rc()?;
-            }
-            ext_size = reader.read_bs()?;
-            size = ext_size;
-        }
-    }
+    let eed_transparency = read_eed_transparency(reader)?;
 
     let graphic_present_flag = reader.read_b()?;
     let proxy_graphics = if graphic_present_flag == 1 {
@@ -318,7 +368,7 @@ fn read_common_entity_header_preamble(
         None
     };
 
-    Ok((obj_size, handle, proxy_graphics))
+    Ok((obj_size, handle, eed_transparency, proxy_graphics))
 }
 
 fn parse_common_entity_header_fields_from_entmode(
@@ -361,14 +411,14 @@ fn parse_common_entity_header_fields_from_entmode(
                 let _name = reader.read_tv()?;
             }
             if flags & 0x2000 != 0 {
-                let _transparency = reader.read_bl()?;
+                color.transparency = Some(reader.read_bl()?);
             }
         }
     } else {
         let _color_unknown = reader.read_b()?;
     }
 
-    let _ltype_scale = reader.read_bd()?;
+    let ltype_scale = reader.read_bd()?;
     let ltype_flags = reader.read_bb()?;
     let plotstyle_flags = reader.read_bb()?;
     let material_flags = if with_material_and_shadow {
@@ -389,7 +439,7 @@ fn parse_common_entity_header_fields_from_entmode(
     };
 
     let _invisibility = reader.read_bs()?;
-    let _line_weight = reader.read_rc()?;
+    let line_weight = reader.read_rc()?;
 
     Ok(CommonEntityHeader {
         obj_size,
@@ -399,6 +449,7 @@ fn parse_common_entity_header_fields_from_entmode(
         num_of_reactors,
         xdic_missing_flag,
         has_ds_binary_data,
+        ltype_scale,
         ltype_flags,
         plotstyle_flags,
         material_flags,
@@ -406,6 +457,7 @@ fn parse_common_entity_header_fields_from_entmode(
         has_face_visual_style,
         has_edge_visual_style,
         has_legacy_entity_links,
+        line_weight,
     })
 }
 
@@ -414,7 +466,7 @@ fn parse_common_entity_header_r14_impl(
     with_ds_binary_flag: bool,
 ) -> Result<CommonEntityHeader> {
     let handle = reader.read_h()?.value;
-    skip_eed(reader)?;
+    let eed_transparency = read_eed_transparency(reader)?;
 
     let graphic_present_flag = reader.read_b()?;
     if graphic_present_flag == 1 {
@@ -442,10 +494,11 @@ fn parse_common_entity_header_r14_impl(
 
     let is_bylayer_ltype = reader.read_b()? != 0;
     let no_links = reader.read_b()?;
-    let color = read_common_entity_color_cmc(reader)?;
-    let _ltype_scale = reader.read_bd()?;
+    let mut color = read_common_entity_color_cmc(reader)?;
+    color.transparency = color.transparency.or(eed_transparency);
+    let ltype_scale = reader.read_bd()?;
     let _invisibility = reader.read_bs()?;
-    let _line_weight = reader.read_rc()?;
+    let line_weight = reader.read_rc()?;
 
     let ltype_flags = if is_bylayer_ltype { 0 } else { 3 };
 
@@ -457,6 +510,7 @@ fn parse_common_entity_header_r14_impl(
         num_of_reactors,
         xdic_missing_flag,
         has_ds_binary_data,
+        ltype_scale,
         ltype_flags,
         plotstyle_flags: 0,
         material_flags: 0,
@@ -464,6 +518,7 @@ fn parse_common_entity_header_r14_impl(
         has_face_visual_style: false,
         has_edge_visual_style: false,
         has_legacy_entity_links: no_links == 0,
+        line_weight,
     })
 }
 
@@ -492,128 +547,31 @@ fn read_common_entity_color_cmc(reader: &mut BitReader<'_>) -> Result<CommonEnti
     Ok(CommonEntityColor {
         index: Some(color_index),
         true_color,
+        transparency: None,
     })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{parse_common_entity_header_r2010, parse_common_entity_header_r2013};
-    use crate::bit::{BitReader, BitWriter};
-
-    fn build_minimal_common_header_bytes(r2013_plus: bool) -> Vec<u8> {
-        let mut writer = BitWriter::new();
-        writer.write_h(4, 0).expect("write handle");
-        writer.write_bs(0).expect("write ext size");
-        writer.write_b(0).expect("write graphic flag");
-        writer.write_bb(0).expect("write entity mode");
-        writer.write_bl(0).expect("write reactors");
-        writer.write_b(1).expect("write xdic missing flag");
-        if r2013_plus {
-            writer.write_b(0).expect("write ds binary flag");
-        }
-        writer.write_b(1).expect("write no links");
-        writer.write_b(0).expect("write color unknown");
-        writer.write_bd(1.0).expect("write ltype scale");
-        writer.write_bb(0).expect("write ltype flags");
-        writer.write_bb(0).expect("write plotstyle flags");
-        writer.write_bb(0).expect("write material flags");
-        writer.write_rc(0).expect("write shadow flags");
-        writer.write_b(0).expect("write full visual style flag");
-        writer.write_b(0).expect("write face visual style flag");
-        writer.write_b(0).expect("write edge visual style flag");
-        writer.write_bs(0).expect("write invisibility");
-        writer.write_rc(0).expect("write line weight");
-        writer.into_bytes()
-    }
-
-    fn build_prefixed_r2010_entity_bytes(type_code: u16, r2013_plus: bool) -> Vec<u8> {
-        let mut writer = BitWriter::new();
-        writer.write_umc(0).expect("write handle stream size");
-        writer.write_ot_r2010(type_code).expect("write type code");
-        writer.align_byte();
-        writer
-            .write_rcs(&build_minimal_common_header_bytes(r2013_plus))
-            .expect("write header body");
-        writer.into_bytes()
-    }
-
-    fn build_shifted_r2010_entity_bytes(
-        type_code: u16,
-        r2013_plus: bool,
-        padding_bits: u8,
-    ) -> Vec<u8> {
-        let mut writer = BitWriter::new();
-        writer.write_umc(0).expect("write handle stream size");
-        writer.write_ot_r2010(type_code).expect("write type code");
-        for _ in 0..padding_bits {
-            writer.write_b(0).expect("write padding bit");
-        }
-        writer
-            .write_rcs(&build_minimal_common_header_bytes(r2013_plus))
-            .expect("write shifted header body");
-        writer.into_bytes()
-    }
-
-    #[test]
-    fn parse_common_entity_header_r2010_recovers_from_byte_aligned_body() {
-        let bytes = build_prefixed_r2010_entity_bytes(0x02, false);
-        let mut reader = BitReader::new(&bytes);
-        let _ = reader.read_umc().expect("read handle stream size");
-        let _ = reader.read_ot_r2010().expect("read type code");
-        assert_eq!(reader.get_pos(), (2, 2));
-
-        let header =
-            parse_common_entity_header_r2010(&mut reader, 64).expect("parse common header");
-
-        assert_eq!(header.handle, 0);
-        assert_eq!(header.obj_size, 64);
-        assert!(reader.tell_bits() >= 24);
-    }
-
-    #[test]
-    fn parse_common_entity_header_r2013_recovers_from_byte_aligned_body() {
-        let bytes = build_prefixed_r2010_entity_bytes(0x03, true);
-        let mut reader = BitReader::new(&bytes);
-        let _ = reader.read_umc().expect("read handle stream size");
-        let _ = reader.read_ot_r2010().expect("read type code");
-        assert_eq!(reader.get_pos(), (2, 2));
-
-        let header =
-            parse_common_entity_header_r2013(&mut reader, 72).expect("parse common header");
-
-        assert_eq!(header.handle, 0);
-        assert_eq!(header.obj_size, 72);
-        assert!(!header.has_ds_binary_data);
-        assert!(reader.tell_bits() >= 24);
-    }
-
-    #[test]
-    fn parse_common_entity_header_r2010_recovers_from_shifted_body() {
-        let bytes = build_shifted_r2010_entity_bytes(0x02, false, 3);
-        let mut reader = BitReader::new(&bytes);
-        let _ = reader.read_umc().expect("read handle stream size");
-        let _ = reader.read_ot_r2010().expect("read type code");
-        assert_eq!(reader.get_pos(), (2, 2));
-
-        let header =
-            parse_common_entity_header_r2010(&mut reader, 64).expect("parse common header");
-
-        assert_eq!(header.handle, 0);
-        assert_eq!(header.obj_size, 64);
-        assert!(reader.tell_bits() >= 24);
-    }
-}
-
-fn skip_eed(reader: &mut BitReader<'_>) -> Result<()> {
+/// Skips extended entity data, returning the transparency stored under the
+/// `AcCmTransparency` application: a single `1071` long (EED code 0x47)
+/// carrying the alpha method byte. Files before R2004 have no other place
+/// for it.
+pub fn read_eed_transparency(reader: &mut BitReader<'_>) -> Result<Option<u32>> {
+    let mut transparency = None;
     let mut ext_size = reader.read_bs()?;
     while ext_size > 0 {
         let _app_handle = reader.read_h()?;
-        for _ in 0..ext_size {
-            let _ = reader.read_rc()?;
+        let data = reader.read_rcs(ext_size as usize)?;
+        if let (None, [0x47, value @ ..]) = (transparency, data.as_slice()) {
+            if let Ok(value) = <[u8; 4]>::try_from(value) {
+                let value = u32::from_le_bytes(value);
+                if (value >> 24) == 0x02 {
+                    transparency = Some(value);
+                }
+            }
         }
         ext_size = reader.read_bs()?;
     }
-    Ok(())
+    Ok(transparency)
 }
 
 pub fn parse_common_entity_handles(
@@ -637,10 +595,14 @@ pub fn parse_common_entity_handles(
         None
     };
 
-    if header.has_legacy_entity_links {
-        let _previous = read_handle_reference(reader, header.handle)?;
-        let _next = read_handle_reference(reader, header.handle)?;
-    }
+    let (previous_entity, next_entity) = if header.has_legacy_entity_links {
+        (
+            Some(read_handle_reference(reader, header.handle)?),
+            Some(read_handle_reference(reader, header.handle)?),
+        )
+    } else {
+        (None, None)
+    };
 
     let layer = read_handle_reference(reader, header.handle)?;
 
@@ -676,6 +638,8 @@ pub fn parse_common_entity_handles(
         owner_ref,
         reactors,
         xdic_obj,
+        previous_entity,
+        next_entity,
         layer,
         ltype,
         plotstyle,
@@ -715,7 +679,7 @@ pub fn parse_common_entity_owner_and_layer_handle(
     reader.set_bit_pos(header.obj_size);
     match parse_common_entity_handles(reader, header) {
         Ok(common_handles) => Ok((common_handles.owner_ref, common_handles.layer)),
-        Err(err) if allow_layer_only_fallback => {
+        Err(_) if allow_layer_only_fallback => {
             reader.set_bit_pos(header.obj_size);
             let layer_handle = parse_common_entity_layer_handle(reader, header)?;
             Ok((None, layer_handle))
@@ -724,17 +688,54 @@ pub fn parse_common_entity_owner_and_layer_handle(
     }
 }
 
+/// A handle reference resolved to an absolute handle, keeping the
+/// ownership/pointer semantics of its code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedHandleRef {
+    pub handle: u64,
+    pub kind: HandleRefKind,
+}
+
+pub fn read_resolved_handle_reference(
+    reader: &mut BitReader<'_>,
+    base_handle: u64,
+) -> Result<ResolvedHandleRef> {
+    let handle_ref = reader.read_h()?;
+    Ok(ResolvedHandleRef {
+        handle: handle_ref.resolve(base_handle),
+        kind: handle_ref.kind(),
+    })
+}
+
 pub fn read_handle_reference(reader: &mut BitReader<'_>, base_handle: u64) -> Result<u64> {
-    let HandleRef { code, value, .. } = reader.read_h()?;
-    let absolute = match code {
-        0x06 => base_handle + 1,
-        0x08 => base_handle.saturating_sub(1),
-        0x0A => base_handle.saturating_add(value),
-        0x0C => base_handle.saturating_sub(value),
-        0x02..=0x05 => value,
-        _ => value,
-    };
-    Ok(absolute)
+    read_resolved_handle_reference(reader, base_handle).map(|handle_ref| handle_ref.handle)
+}
+
+/// Reads `count` handle references. The count comes from the file, so the
+/// preallocation is capped by the bytes left in the reader; each reference
+/// takes at least one.
+pub fn read_handle_references(
+    reader: &mut BitReader<'_>,
+    base_handle: u64,
+    count: usize,
+) -> Result<Vec<u64>> {
+    let remaining_bytes = reader.total_bits().saturating_sub(reader.tell_bits()) / 8;
+    let mut handles = Vec::with_capacity(count.min(remaining_bytes as usize));
+    for _ in 0..count {
+        handles.push(read_handle_reference(reader, base_handle)?);
+    }
+    Ok(handles)
+}
+
+/// Like [`read_handle_reference`], but resolves relative codes against the
+/// previously read handle and advances `prev_handle` to the result.
+pub fn read_chained_handle_reference(
+    reader: &mut BitReader<'_>,
+    prev_handle: &mut u64,
+) -> Result<u64> {
+    let handle = read_handle_reference(reader, *prev_handle)?;
+    *prev_handle = handle;
+    Ok(handle)
 }
 
 pub fn read_additional_entity_handles(
@@ -755,3 +756,237 @@ pub fn read_additional_entity_handles(
     }
     handles
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        line_weight_from_index, line_weight_to_index, parse_common_entity_header_r2010,
+        parse_common_entity_header_r2013, read_additional_entity_handles,
+        read_chained_handle_reference, read_handle_reference, read_handle_references,
+        read_resolved_handle_reference,
+    };
+    use crate::bit::{BitReader, BitWriter, HandleRefKind};
+
+    fn build_minimal_common_header_bytes(r2013_plus: bool) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        writer.write_h(4, 0).expect("write handle");
+        writer.write_bs(0).expect("write ext size");
+        writer.write_b(0).expect("write graphic flag");
+        writer.write_bb(0).expect("write entity mode");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing flag");
+        if r2013_plus {
+            writer.write_b(0).expect("write ds binary flag");
+        }
+        writer.write_b(1).expect("write no links");
+        writer.write_b(0).expect("write color unknown");
+        writer.write_bd(1.0).expect("write ltype scale");
+        writer.write_bb(0).expect("write ltype flags");
+        writer.write_bb(0).expect("write plotstyle flags");
+        writer.write_bb(0).expect("write material flags");
+        writer.write_rc(0).expect("write shadow flags");
+        writer.write_b(0).expect("write full visual style flag");
+        writer.write_b(0).expect("write face visual style flag");
+        writer.write_b(0).expect("write edge visual style flag");
+        writer.write_bs(0).expect("write invisibility");
+        writer.write_rc(0).expect("write line weight");
+        writer.into_bytes()
+    }
+
+    fn build_prefixed_r2010_entity_bytes(type_code: u16, r2013_plus: bool) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        writer.write_umc(0).expect("write handle stream size");
+        writer.write_ot_r2010(type_code).expect("write type code");
+        writer.align_byte();
+        writer
+            .write_rcs(&build_minimal_common_header_bytes(r2013_plus))
+            .expect("write header body");
+        writer.into_bytes()
+    }
+
+    fn build_shifted_r2010_entity_bytes(
+        type_code: u16,
+        r2013_plus: bool,
+        padding_bits: u8,
+    ) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        writer.write_umc(0).expect("write handle stream size");
+        writer.write_ot_r2010(type_code).expect("write type code");
+        for _ in 0..padding_bits {
+            writer.write_b(0).expect("write padding bit");
+        }
+        writer
+            .write_rcs(&build_minimal_common_header_bytes(r2013_plus))
+            .expect("write shifted header body");
+        writer.into_bytes()
+    }
+
+    #[test]
+    fn parse_common_entity_header_r2010_recovers_from_byte_aligned_body() {
+        let bytes = build_prefixed_r2010_entity_bytes(0x02, false);
+        let mut reader = BitReader::new(&bytes);
+        let _ = reader.read_umc().expect("read handle stream size");
+        let _ = reader.read_ot_r2010().expect("read type code");
+        assert_eq!(reader.get_pos(), (2, 2));
+
+        let header =
+            parse_common_entity_header_r2010(&mut reader, 64).expect("parse common header");
+
+        assert_eq!(header.handle, 0);
+        assert_eq!(header.obj_size, 64);
+        assert_eq!(header.ltype_scale, 1.0);
+        assert!(reader.tell_bits() >= 24);
+    }
+
+    #[test]
+    fn line_weight_from_index_maps_enum_and_markers() {
+        assert_eq!(line_weight_from_index(0), 0);
+        assert_eq!(line_weight_from_index(7), 25);
+        assert_eq!(line_weight_from_index(23), 211);
+        assert_eq!(line_weight_from_index(29), -1);
+        assert_eq!(line_weight_from_index(30), -2);
+        assert_eq!(line_weight_from_index(31), -3);
+    }
+
+    #[test]
+    fn line_weight_to_index_inverts_enum_and_markers() {
+        for index in (0..=23).chain(29..=31) {
+            assert_eq!(
+                line_weight_to_index(line_weight_from_index(index)),
+                Some(index)
+            );
+        }
+        assert_eq!(line_weight_to_index(17), None);
+        assert_eq!(line_weight_to_index(-4), None);
+    }
+
+    #[test]
+    fn parse_common_entity_header_r2013_recovers_from_byte_aligned_body() {
+        let bytes = build_prefixed_r2010_entity_bytes(0x03, true);
+        let mut reader = BitReader::new(&bytes);
+        let _ = reader.read_umc().expect("read handle stream size");
+        let _ = reader.read_ot_r2010().expect("read type code");
+        assert_eq!(reader.get_pos(), (2, 2));
+
+        let header =
+            parse_common_entity_header_r2013(&mut reader, 72).expect("parse common header");
+
+        assert_eq!(header.handle, 0);
+        assert_eq!(header.obj_size, 72);
+        assert!(!header.has_ds_binary_data);
+        assert!(reader.tell_bits() >= 24);
+    }
+
+    #[test]
+    fn parse_common_entity_header_r2010_recovers_from_shifted_body() {
+        let bytes = build_shifted_r2010_entity_bytes(0x02, false, 3);
+        let mut reader = BitReader::new(&bytes);
+        let _ = reader.read_umc().expect("read handle stream size");
+        let _ = reader.read_ot_r2010().expect("read type code");
+        assert_eq!(reader.get_pos(), (2, 2));
+
+        let header =
+            parse_common_entity_header_r2010(&mut reader, 64).expect("parse common header");
+
+        assert_eq!(header.handle, 0);
+        assert_eq!(header.obj_size, 64);
+        assert!(reader.tell_bits() >= 24);
+    }
+
+    fn build_handle_stream(refs: &[(u8, u64)]) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        for (code, value) in refs {
+            writer.write_h(*code, *value).expect("write handle ref");
+        }
+        writer.into_bytes()
+    }
+
+    #[test]
+    fn read_handle_reference_resolves_relative_codes() {
+        let bytes = build_handle_stream(&[
+            (0x05, 0x1F),
+            (0x06, 0),
+            (0x08, 0),
+            (0x0A, 0x10),
+            (0x0C, 0x10),
+        ]);
+        let mut reader = BitReader::new(&bytes);
+
+        let resolved: Vec<u64> = (0..5)
+            .map(|_| read_handle_reference(&mut reader, 0x80).expect("read handle ref"))
+            .collect();
+
+        assert_eq!(resolved, vec![0x1F, 0x81, 0x7F, 0x90, 0x70]);
+    }
+
+    #[test]
+    fn read_handle_reference_saturates_at_handle_bounds() {
+        let bytes = build_handle_stream(&[(0x08, 0), (0x0C, 0x10), (0x06, 0)]);
+        let mut reader = BitReader::new(&bytes);
+
+        assert_eq!(read_handle_reference(&mut reader, 0).expect("minus one"), 0);
+        assert_eq!(
+            read_handle_reference(&mut reader, 4).expect("minus offset"),
+            0
+        );
+        assert_eq!(
+            read_handle_reference(&mut reader, u64::MAX).expect("plus one"),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn read_resolved_handle_reference_keeps_reference_kind() {
+        let bytes = build_handle_stream(&[(0x03, 0x20), (0x0A, 0x02)]);
+        let mut reader = BitReader::new(&bytes);
+
+        let owner = read_resolved_handle_reference(&mut reader, 0x40).expect("owner");
+        assert_eq!((owner.handle, owner.kind), (0x20, HandleRefKind::HardOwner));
+        let relative = read_resolved_handle_reference(&mut reader, 0x40).expect("relative");
+        assert_eq!(
+            (relative.handle, relative.kind),
+            (0x42, HandleRefKind::Relative)
+        );
+    }
+
+    #[test]
+    fn read_chained_handle_reference_advances_base() {
+        let bytes = build_handle_stream(&[(0x0A, 0x02), (0x06, 0), (0x05, 0x30), (0x08, 0)]);
+        let mut reader = BitReader::new(&bytes);
+        let mut prev_handle = 0x40;
+
+        let resolved: Vec<u64> = (0..4)
+            .map(|_| {
+                read_chained_handle_reference(&mut reader, &mut prev_handle).expect("chained ref")
+            })
+            .collect();
+
+        assert_eq!(resolved, vec![0x42, 0x43, 0x30, 0x2F]);
+        assert_eq!(prev_handle, 0x2F);
+    }
+
+    #[test]
+    fn read_additional_entity_handles_skips_null_and_duplicate_refs() {
+        let bytes = build_handle_stream(&[(0x05, 0x21), (0x05, 0), (0x0A, 0x01), (0x04, 0x22)]);
+        let mut reader = BitReader::new(&bytes);
+
+        let handles = read_additional_entity_handles(&mut reader, 0x20, 8);
+
+        assert_eq!(handles, vec![0x21, 0x22]);
+    }
+
+    #[test]
+    fn read_handle_references_rejects_counts_beyond_data() {
+        let mut writer = BitWriter::new();
+        writer.write_h(3, 0x1F).expect("write handle");
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(
+            read_handle_references(&mut reader, 0x40, 1).expect("one handle"),
+            [0x1F]
+        );
+        let mut reader = BitReader::new(&bytes);
+        assert!(read_handle_references(&mut reader, 0x40, u32::MAX as usize).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::bit::{BitReader, Endian};
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_header, parse_common_entity_header_r2007, parse_common_entity_header_r2010,
+    parse_common_entity_header_r2013, CommonEntityHeader,
+};
+use crate::entities::dim_common::{
+    decode_best_dimension_variant, plausibility_score, read_legacy_dimension_body,
+    read_legacy_dimension_handles, read_r2010_plus_dimension_body,
+    read_r2010_plus_dimension_handles, LEGACY_DIMENSION_VARIANTS, R2010_PLUS_VARIANTS,
+};
+use crate::entities::dim_linear::{DimLinearEntity, DimensionCommonData};
+
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct DimAng2LnEntity {
+    pub common: DimensionCommonData,
+    /// Point on the dimension arc, stored as 2D at the dimension elevation.
+    pub point16: (f64, f64, f64),
+    /// Start point of the first line.
+    pub point13: (f64, f64, f64),
+    /// End point of the first line.
+    pub point14: (f64, f64, f64),
+    /// Start point of the second line.
+    pub point15: (f64, f64, f64),
+    /// End point of the second line.
+    pub point10: (f64, f64, f64),
+}
+
+impl DimAng2LnEntity {
+    /// Projects the line points onto the shared dimension row layout,
+    /// carrying the second line start as 15-pt and the arc point as 16-pt.
+    pub fn to_linear_like(&self) -> DimLinearEntity {
+        DimLinearEntity {
+            common: self.common.clone(),
+            point13: self.point13,
+            point14: self.point14,
+            point10: self.point10,
+            ext_line_rotation: 0.0,
+            dim_rotation: 0.0,
+            point15: Some(self.point15),
+            point16: Some(self.point16),
+            ordinate_x_type: None,
+            point17: None,
+            is_partial: None,
+            arc_params: None,
+            has_leader: None,
+            leader_length: None,
+        }
+    }
+}
+
+pub fn decode_dim_ang2ln(reader: &mut BitReader<'_>) -> Result<DimAng2LnEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_dim_ang2ln_with_header(reader, header, false)
+}
+
+pub fn decode_dim_ang2ln_r2007(reader: &mut BitReader<'_>) -> Result<DimAng2LnEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_dim_ang2ln_with_header(reader, header, true)
+}
+
+pub fn decode_dim_ang2ln_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimAng2LnEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_ang2ln_r2010_plus_with_header(reader, header, true)
+}
+
+pub fn decode_dim_ang2ln_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimAng2LnEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_ang2ln_r2010_plus_with_header(reader, header, true)
+}
+
+fn decode_dim_ang2ln_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimAng2LnEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &LEGACY_DIMENSION_VARIANTS,
+        "DIM_ANG2LN",
+        |reader, parse_variant| {
+            let body = read_legacy_dimension_body(reader, parse_variant)?;
+            let points = read_ang2ln_points(reader, body.elevation)?;
+            let handles = read_legacy_dimension_handles(
+                reader,
+                &header,
+                parse_variant,
+                allow_handle_decode_failure,
+            )?;
+            Ok(DimAng2LnEntity {
+                common: body.into_common(&header, handles),
+                point16: points.0,
+                point13: points.1,
+                point14: points.2,
+                point15: points.3,
+                point10: points.4,
+            })
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+fn decode_dim_ang2ln_r2010_plus_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimAng2LnEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &R2010_PLUS_VARIANTS,
+        "R2010+ DIM_ANG2LN",
+        |reader, parse_variant| {
+            let body = read_r2010_plus_dimension_body(reader, parse_variant)?;
+            let points = read_ang2ln_points(reader, body.elevation)?;
+            let handles = read_r2010_plus_dimension_handles(
+                reader,
+                &header,
+                allow_handle_decode_failure,
+                "DIM_ANG2LN",
+            )?;
+            Ok(DimAng2LnEntity {
+                common: body.into_common(&header, handles),
+                point16: points.0,
+                point13: points.1,
+                point14: points.2,
+                point15: points.3,
+                point10: points.4,
+            })
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+type Ang2LnPoints = (
+    (f64, f64, f64),
+    (f64, f64, f64),
+    (f64, f64, f64),
+    (f64, f64, f64),
+    (f64, f64, f64),
+);
+
+/// Reads the 16, 13, 14, 15 and 10 points in file order.
+fn read_ang2ln_points(reader: &mut BitReader<'_>, elevation: f64) -> Result<Ang2LnPoints> {
+    let point16_x = reader.read_rd(Endian::Little)?;
+    let point16_y = reader.read_rd(Endian::Little)?;
+    let point13 = reader.read_3bd()?;
+    let point14 = reader.read_3bd()?;
+    let point15 = reader.read_3bd()?;
+    let point10 = reader.read_3bd()?;
+    Ok((
+        (point16_x, point16_y, elevation),
+        point13,
+        point14,
+        point15,
+        point10,
+    ))
+}
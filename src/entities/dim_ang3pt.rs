@@ -0,0 +1,154 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_header, parse_common_entity_header_r2007, parse_common_entity_header_r2010,
+    parse_common_entity_header_r2013, CommonEntityHeader,
+};
+use crate::entities::dim_common::{
+    decode_best_dimension_variant, plausibility_score, read_legacy_dimension_body,
+    read_legacy_dimension_handles, read_r2010_plus_dimension_body,
+    read_r2010_plus_dimension_handles, LEGACY_DIMENSION_VARIANTS, R2010_PLUS_VARIANTS,
+};
+use crate::entities::dim_linear::{DimLinearEntity, DimensionCommonData};
+
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct DimAng3PtEntity {
+    pub common: DimensionCommonData,
+    /// Point on the dimension arc.
+    pub point10: (f64, f64, f64),
+    /// End point of the first extension line.
+    pub point13: (f64, f64, f64),
+    /// End point of the second extension line.
+    pub point14: (f64, f64, f64),
+    /// Angle vertex.
+    pub point15: (f64, f64, f64),
+}
+
+impl DimAng3PtEntity {
+    /// Projects the angular points onto the shared dimension row layout,
+    /// carrying the vertex as the 15-pt.
+    pub fn to_linear_like(&self) -> DimLinearEntity {
+        DimLinearEntity {
+            common: self.common.clone(),
+            point13: self.point13,
+            point14: self.point14,
+            point10: self.point10,
+            ext_line_rotation: 0.0,
+            dim_rotation: 0.0,
+            point15: Some(self.point15),
+            point16: None,
+            ordinate_x_type: None,
+            point17: None,
+            is_partial: None,
+            arc_params: None,
+            has_leader: None,
+            leader_length: None,
+        }
+    }
+}
+
+pub fn decode_dim_ang3pt(reader: &mut BitReader<'_>) -> Result<DimAng3PtEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_dim_ang3pt_with_header(reader, header, false)
+}
+
+pub fn decode_dim_ang3pt_r2007(reader: &mut BitReader<'_>) -> Result<DimAng3PtEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_dim_ang3pt_with_header(reader, header, true)
+}
+
+pub fn decode_dim_ang3pt_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimAng3PtEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_ang3pt_r2010_plus_with_header(reader, header, true)
+}
+
+pub fn decode_dim_ang3pt_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimAng3PtEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_ang3pt_r2010_plus_with_header(reader, header, true)
+}
+
+fn decode_dim_ang3pt_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimAng3PtEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &LEGACY_DIMENSION_VARIANTS,
+        "DIM_ANG3PT",
+        |reader, parse_variant| {
+            let body = read_legacy_dimension_body(reader, parse_variant)?;
+            let (point10, point13, point14, point15) = read_ang3pt_points(reader)?;
+            let handles = read_legacy_dimension_handles(
+                reader,
+                &header,
+                parse_variant,
+                allow_handle_decode_failure,
+            )?;
+            Ok(DimAng3PtEntity {
+                common: body.into_common(&header, handles),
+                point10,
+                point13,
+                point14,
+                point15,
+            })
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+fn decode_dim_ang3pt_r2010_plus_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimAng3PtEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &R2010_PLUS_VARIANTS,
+        "R2010+ DIM_ANG3PT",
+        |reader, parse_variant| {
+            let body = read_r2010_plus_dimension_body(reader, parse_variant)?;
+            let (point10, point13, point14, point15) = read_ang3pt_points(reader)?;
+            let handles = read_r2010_plus_dimension_handles(
+                reader,
+                &header,
+                allow_handle_decode_failure,
+                "DIM_ANG3PT",
+            )?;
+            Ok(DimAng3PtEntity {
+                common: body.into_common(&header, handles),
+                point10,
+                point13,
+                point14,
+                point15,
+            })
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+type Ang3PtPoints = (
+    (f64, f64, f64),
+    (f64, f64, f64),
+    (f64, f64, f64),
+    (f64, f64, f64),
+);
+
+fn read_ang3pt_points(reader: &mut BitReader<'_>) -> Result<Ang3PtPoints> {
+    let point10 = reader.read_3bd()?;
+    let point13 = reader.read_3bd()?;
+    let point14 = reader.read_3bd()?;
+    let point15 = reader.read_3bd()?;
+    Ok((point10, point13, point14, point15))
+}
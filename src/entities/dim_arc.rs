@@ -0,0 +1,240 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_header, parse_common_entity_header_r2007, parse_common_entity_header_r2010,
+    parse_common_entity_header_r2013, CommonEntityHeader,
+};
+use crate::entities::dim_common::{
+    decode_best_dimension_variant, plausibility_score, read_legacy_dimension_body,
+    read_legacy_dimension_handles, read_r2010_plus_dimension_body,
+    read_r2010_plus_dimension_handles, DimensionBody, DimensionHandles, LEGACY_DIMENSION_VARIANTS,
+    R2010_PLUS_VARIANTS,
+};
+use crate::entities::dim_linear::{DimLinearEntity, DimensionCommonData};
+
+/// ARC_DIMENSION (arc length dimension) class entity.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct DimArcEntity {
+    pub common: DimensionCommonData,
+    /// Point on the dimension arc.
+    pub point10: (f64, f64, f64),
+    /// First extension line origin.
+    pub point13: (f64, f64, f64),
+    /// Second extension line origin.
+    pub point14: (f64, f64, f64),
+    /// Arc center.
+    pub point15: (f64, f64, f64),
+    pub is_partial: bool,
+    pub arc_start_param: f64,
+    pub arc_end_param: f64,
+    pub has_leader: bool,
+    /// Leader start point.
+    pub point16: (f64, f64, f64),
+    /// Leader end point.
+    pub point17: (f64, f64, f64),
+}
+
+impl DimArcEntity {
+    /// Projects the arc points onto the shared dimension row layout, carrying
+    /// the arc center as 15-pt, the leader start as 16-pt and the leader end
+    /// as 17-pt.
+    pub fn to_linear_like(&self) -> DimLinearEntity {
+        DimLinearEntity {
+            common: self.common.clone(),
+            point13: self.point13,
+            point14: self.point14,
+            point10: self.point10,
+            ext_line_rotation: 0.0,
+            dim_rotation: 0.0,
+            point15: Some(self.point15),
+            point16: Some(self.point16),
+            ordinate_x_type: None,
+            point17: Some(self.point17),
+            is_partial: Some(self.is_partial),
+            arc_params: Some((self.arc_start_param, self.arc_end_param)),
+            has_leader: Some(self.has_leader),
+            leader_length: None,
+        }
+    }
+}
+
+struct ArcDimensionFields {
+    point10: (f64, f64, f64),
+    point13: (f64, f64, f64),
+    point14: (f64, f64, f64),
+    point15: (f64, f64, f64),
+    is_partial: bool,
+    arc_start_param: f64,
+    arc_end_param: f64,
+    has_leader: bool,
+    point16: (f64, f64, f64),
+    point17: (f64, f64, f64),
+}
+
+pub fn decode_dim_arc(reader: &mut BitReader<'_>) -> Result<DimArcEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_dim_arc_with_header(reader, header, false)
+}
+
+pub fn decode_dim_arc_r2007(reader: &mut BitReader<'_>) -> Result<DimArcEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_dim_arc_with_header(reader, header, true)
+}
+
+pub fn decode_dim_arc_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimArcEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_arc_r2010_plus_with_header(reader, header, true)
+}
+
+pub fn decode_dim_arc_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimArcEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_arc_r2010_plus_with_header(reader, header, true)
+}
+
+fn decode_dim_arc_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimArcEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &LEGACY_DIMENSION_VARIANTS,
+        "ARC_DIMENSION",
+        |reader, parse_variant| {
+            let body = read_legacy_dimension_body(reader, parse_variant)?;
+            let fields = read_arc_dimension_fields(reader)?;
+            let handles = read_legacy_dimension_handles(
+                reader,
+                &header,
+                parse_variant,
+                allow_handle_decode_failure,
+            )?;
+            Ok(build_entity(&header, body, handles, fields))
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+fn decode_dim_arc_r2010_plus_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimArcEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &R2010_PLUS_VARIANTS,
+        "R2010+ ARC_DIMENSION",
+        |reader, parse_variant| {
+            let body = read_r2010_plus_dimension_body(reader, parse_variant)?;
+            let fields = read_arc_dimension_fields(reader)?;
+            let handles = read_r2010_plus_dimension_handles(
+                reader,
+                &header,
+                allow_handle_decode_failure,
+                "ARC_DIMENSION",
+            )?;
+            Ok(build_entity(&header, body, handles, fields))
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+fn read_arc_dimension_fields(reader: &mut BitReader<'_>) -> Result<ArcDimensionFields> {
+    let point10 = reader.read_3bd()?;
+    let point13 = reader.read_3bd()?;
+    let point14 = reader.read_3bd()?;
+    let point15 = reader.read_3bd()?;
+    let is_partial = reader.read_b()? != 0;
+    let arc_start_param = reader.read_bd()?;
+    let arc_end_param = reader.read_bd()?;
+    let has_leader = reader.read_b()? != 0;
+    let point16 = reader.read_3bd()?;
+    let point17 = reader.read_3bd()?;
+    Ok(ArcDimensionFields {
+        point10,
+        point13,
+        point14,
+        point15,
+        is_partial,
+        arc_start_param,
+        arc_end_param,
+        has_leader,
+        point16,
+        point17,
+    })
+}
+
+fn build_entity(
+    header: &CommonEntityHeader,
+    body: DimensionBody,
+    handles: DimensionHandles,
+    fields: ArcDimensionFields,
+) -> DimArcEntity {
+    DimArcEntity {
+        common: body.into_common(header, handles),
+        point10: fields.point10,
+        point13: fields.point13,
+        point14: fields.point14,
+        point15: fields.point15,
+        is_partial: fields.is_partial,
+        arc_start_param: fields.arc_start_param,
+        arc_end_param: fields.arc_end_param,
+        has_leader: fields.has_leader,
+        point16: fields.point16,
+        point17: fields.point17,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_dim_arc_r2013;
+    use crate::bit::{BitReader, BitWriter};
+    use crate::entities::dim_common::test_support::write_r2013_dimension_prefix;
+
+    #[test]
+    fn decode_dim_arc_r2013_reads_arc_fields() {
+        let mut writer = BitWriter::new();
+        write_r2013_dimension_prefix(&mut writer);
+        writer.write_3bd(10.0, 12.0, 0.0).expect("write 10-pt");
+        writer.write_3bd(20.0, 0.0, 0.0).expect("write 13-pt");
+        writer.write_3bd(0.0, 20.0, 0.0).expect("write 14-pt");
+        writer.write_3bd(0.0, 0.0, 0.0).expect("write 15-pt");
+        writer.write_b(1).expect("write partial flag");
+        writer.write_bd(0.25).expect("write arc start");
+        writer.write_bd(1.5).expect("write arc end");
+        writer.write_b(1).expect("write leader flag");
+        writer.write_3bd(14.0, 14.0, 0.0).expect("write 16-pt");
+        writer.write_3bd(18.0, 18.0, 0.0).expect("write 17-pt");
+        let end_bit = writer.tell_bits() as u32;
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        let entity = decode_dim_arc_r2013(&mut reader, end_bit, 0x2A).expect("decode arc dim");
+
+        assert_eq!(entity.common.handle, 0x2A);
+        assert_eq!(entity.common.actual_measurement, Some(12.5));
+        assert_eq!(entity.point10, (10.0, 12.0, 0.0));
+        assert_eq!(entity.point13, (20.0, 0.0, 0.0));
+        assert_eq!(entity.point14, (0.0, 20.0, 0.0));
+        assert_eq!(entity.point15, (0.0, 0.0, 0.0));
+        assert!(entity.is_partial);
+        assert_eq!(entity.arc_start_param, 0.25);
+        assert_eq!(entity.arc_end_param, 1.5);
+        assert!(entity.has_leader);
+        assert_eq!(entity.point17, (18.0, 18.0, 0.0));
+        let linear_like = entity.to_linear_like();
+        assert_eq!(linear_like.point15, Some((0.0, 0.0, 0.0)));
+        assert_eq!(linear_like.point16, Some((14.0, 14.0, 0.0)));
+    }
+}
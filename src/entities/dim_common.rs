@@ -2,9 +2,17 @@
 //!
 //! The R2010+ dimension variants and plausibility scoring heuristics are
 //! identical across `dim_linear`, `dim_diameter`, and `dim_radius`, so they
-//! live here to avoid drift between copies.
+//! live here to avoid drift between copies. The ordinate and angular decoders
+//! also share the common dimension body readers below.
 
-use crate::entities::dim_linear::DimLinearEntity;
+use crate::bit::{BitReader, Endian};
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_handles, parse_common_entity_layer_handle, read_handle_reference,
+    CommonEntityHeader,
+};
+use crate::entities::dim_linear::{DimLinearEntity, DimensionCommonData};
 
 #[derive(Clone, Copy)]
 pub(crate) struct R2010PlusVariant {
@@ -56,6 +64,340 @@ pub(crate) const R2010_PLUS_VARIANTS: [R2010PlusVariant; 8] = [
     },
 ];
 
+/// Optional fields in the R2000-R2007 common dimension body. Files in the
+/// wild disagree on which of them are present, so decoders try each layout.
+#[derive(Clone, Copy)]
+pub(crate) struct LegacyDimensionVariant {
+    pub(crate) has_attachment: bool,
+    pub(crate) has_unknown_flag: bool,
+    pub(crate) has_flip_arrow1: bool,
+    pub(crate) has_flip_arrow2: bool,
+    pub(crate) has_point12: bool,
+    pub(crate) style_before_common: bool,
+}
+
+pub(crate) const LEGACY_DIMENSION_VARIANTS: [LegacyDimensionVariant; 12] = [
+    legacy_variant(true, true, true, true, true, true),
+    legacy_variant(true, true, true, false, true, true),
+    legacy_variant(true, true, false, false, true, true),
+    legacy_variant(true, false, false, false, true, true),
+    legacy_variant(true, false, false, false, false, true),
+    legacy_variant(false, false, false, false, false, true),
+    legacy_variant(true, true, true, true, true, false),
+    legacy_variant(true, true, true, false, true, false),
+    legacy_variant(true, true, false, false, true, false),
+    legacy_variant(true, false, false, false, true, false),
+    legacy_variant(true, false, false, false, false, false),
+    legacy_variant(false, false, false, false, false, false),
+];
+
+const fn legacy_variant(
+    has_attachment: bool,
+    has_unknown_flag: bool,
+    has_flip_arrow1: bool,
+    has_flip_arrow2: bool,
+    has_point12: bool,
+    style_before_common: bool,
+) -> LegacyDimensionVariant {
+    LegacyDimensionVariant {
+        has_attachment,
+        has_unknown_flag,
+        has_flip_arrow1,
+        has_flip_arrow2,
+        has_point12,
+        style_before_common,
+    }
+}
+
+/// Common dimension body fields that precede the subtype-specific points.
+pub(crate) struct DimensionBody {
+    extrusion: (f64, f64, f64),
+    text_midpoint: (f64, f64, f64),
+    pub(crate) elevation: f64,
+    dim_flags: u8,
+    user_text: String,
+    text_rotation: f64,
+    horizontal_direction: f64,
+    insert_scale: (f64, f64, f64),
+    insert_rotation: f64,
+    attachment_point: Option<u16>,
+    line_spacing_style: Option<u16>,
+    line_spacing_factor: Option<f64>,
+    actual_measurement: Option<f64>,
+    insert_point: Option<(f64, f64, f64)>,
+}
+
+impl DimensionBody {
+    pub(crate) fn into_common(
+        self,
+        header: &CommonEntityHeader,
+        handles: DimensionHandles,
+    ) -> DimensionCommonData {
+        DimensionCommonData {
+            handle: header.handle,
+            color_index: header.color.index,
+            true_color: header.color.true_color,
+            layer_handle: handles.layer,
+            extrusion: self.extrusion,
+            text_midpoint: self.text_midpoint,
+            elevation: self.elevation,
+            dim_flags: self.dim_flags,
+            user_text: self.user_text,
+            text_rotation: self.text_rotation,
+            horizontal_direction: self.horizontal_direction,
+            insert_scale: self.insert_scale,
+            insert_rotation: self.insert_rotation,
+            attachment_point: self.attachment_point,
+            line_spacing_style: self.line_spacing_style,
+            line_spacing_factor: self.line_spacing_factor,
+            actual_measurement: self.actual_measurement,
+            insert_point: self.insert_point,
+            dimstyle_handle: handles.dimstyle,
+            anonymous_block_handle: handles.block,
+        }
+    }
+}
+
+pub(crate) struct DimensionHandles {
+    dimstyle: Option<u64>,
+    block: Option<u64>,
+    layer: Option<u64>,
+}
+
+pub(crate) fn read_legacy_dimension_body(
+    reader: &mut BitReader<'_>,
+    parse_variant: LegacyDimensionVariant,
+) -> Result<DimensionBody> {
+    let extrusion = reader.read_3bd()?;
+    let text_mid_x = reader.read_rd(Endian::Little)?;
+    let text_mid_y = reader.read_rd(Endian::Little)?;
+    let elevation = reader.read_bd()?;
+    let dim_flags = reader.read_rc()?;
+    let user_text = reader.read_tv()?;
+    let text_rotation = reader.read_bd()?;
+    let horizontal_direction = reader.read_bd()?;
+    let insert_scale = (reader.read_bd()?, reader.read_bd()?, reader.read_bd()?);
+    let insert_rotation = reader.read_bd()?;
+
+    let (attachment_point, line_spacing_style, line_spacing_factor, actual_measurement) =
+        if parse_variant.has_attachment {
+            (
+                Some(reader.read_bs()?),
+                Some(reader.read_bs()?),
+                Some(reader.read_bd()?),
+                Some(reader.read_bd()?),
+            )
+        } else {
+            (None, None, None, None)
+        };
+    if parse_variant.has_unknown_flag {
+        let _unknown = reader.read_b()?;
+    }
+    if parse_variant.has_flip_arrow1 {
+        let _flip_arrow1 = reader.read_b()?;
+    }
+    if parse_variant.has_flip_arrow2 {
+        let _flip_arrow2 = reader.read_b()?;
+    }
+    let insert_point = if parse_variant.has_point12 {
+        let x = reader.read_rd(Endian::Little)?;
+        let y = reader.read_rd(Endian::Little)?;
+        Some((x, y, elevation))
+    } else {
+        None
+    };
+
+    Ok(DimensionBody {
+        extrusion,
+        text_midpoint: (text_mid_x, text_mid_y, elevation),
+        elevation,
+        dim_flags,
+        user_text,
+        text_rotation,
+        horizontal_direction,
+        insert_scale,
+        insert_rotation,
+        attachment_point,
+        line_spacing_style,
+        line_spacing_factor,
+        actual_measurement,
+        insert_point,
+    })
+}
+
+pub(crate) fn read_r2010_plus_dimension_body(
+    reader: &mut BitReader<'_>,
+    parse_variant: R2010PlusVariant,
+) -> Result<DimensionBody> {
+    if parse_variant.has_dimension_version {
+        let _dimension_version = reader.read_rc()?;
+    }
+    let extrusion = if parse_variant.extrusion_is_be {
+        reader.read_be()?
+    } else {
+        reader.read_3bd()?
+    };
+    let text_mid_x = reader.read_rd(Endian::Little)?;
+    let text_mid_y = reader.read_rd(Endian::Little)?;
+    let elevation = reader.read_bd()?;
+    let dim_flags = reader.read_rc()?;
+    let user_text = if parse_variant.has_user_text {
+        reader.read_tv()?
+    } else {
+        String::new()
+    };
+    let text_rotation = reader.read_bd()?;
+    let horizontal_direction = reader.read_bd()?;
+    let insert_scale = (reader.read_bd()?, reader.read_bd()?, reader.read_bd()?);
+    let insert_rotation = reader.read_bd()?;
+    let attachment_point = Some(reader.read_bs()?);
+    let line_spacing_style = Some(reader.read_bs()?);
+    let line_spacing_factor = Some(reader.read_bd()?);
+    let actual_measurement = Some(reader.read_bd()?);
+    let _unknown = reader.read_b()?;
+    let _flip_arrow1 = reader.read_b()?;
+    let _flip_arrow2 = reader.read_b()?;
+    let point12_x = reader.read_rd(Endian::Little)?;
+    let point12_y = reader.read_rd(Endian::Little)?;
+
+    Ok(DimensionBody {
+        extrusion,
+        text_midpoint: (text_mid_x, text_mid_y, elevation),
+        elevation,
+        dim_flags,
+        user_text,
+        text_rotation,
+        horizontal_direction,
+        insert_scale,
+        insert_rotation,
+        attachment_point,
+        line_spacing_style,
+        line_spacing_factor,
+        actual_measurement,
+        insert_point: Some((point12_x, point12_y, elevation)),
+    })
+}
+
+/// Reads the dimension handles of a pre-R2010 record, mirroring the
+/// fallbacks used by `dim_linear`.
+pub(crate) fn read_legacy_dimension_handles(
+    reader: &mut BitReader<'_>,
+    header: &CommonEntityHeader,
+    parse_variant: LegacyDimensionVariant,
+    allow_handle_decode_failure: bool,
+) -> Result<DimensionHandles> {
+    reader.set_bit_pos(header.obj_size);
+    let handles_pos = reader.get_pos();
+    if allow_handle_decode_failure {
+        let layer = parse_common_entity_layer_handle(reader, header).ok();
+        return Ok(DimensionHandles {
+            dimstyle: None,
+            block: None,
+            layer,
+        });
+    }
+    if parse_variant.style_before_common {
+        let dimstyle = Some(read_handle_reference(reader, header.handle)?);
+        let block = Some(read_handle_reference(reader, header.handle)?);
+        let common_handles = parse_common_entity_handles(reader, header)?;
+        return Ok(DimensionHandles {
+            dimstyle,
+            block,
+            layer: Some(common_handles.layer),
+        });
+    }
+    match parse_common_entity_handles(reader, header) {
+        Ok(common_handles) => Ok(DimensionHandles {
+            dimstyle: read_handle_reference(reader, header.handle).ok(),
+            block: read_handle_reference(reader, header.handle).ok(),
+            layer: Some(common_handles.layer),
+        }),
+        Err(err) => {
+            reader.set_pos(handles_pos.0, handles_pos.1);
+            Err(err)
+        }
+    }
+}
+
+/// Reads the dimension handles of an R2010+ record: DIMSTYLE and anonymous
+/// block first, then the common entity handles.
+pub(crate) fn read_r2010_plus_dimension_handles(
+    reader: &mut BitReader<'_>,
+    header: &CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+    type_name: &str,
+) -> Result<DimensionHandles> {
+    reader.set_bit_pos(header.obj_size);
+    let handles_pos = reader.get_pos();
+    match (
+        read_handle_reference(reader, header.handle),
+        read_handle_reference(reader, header.handle),
+        parse_common_entity_handles(reader, header),
+    ) {
+        (Ok(dimstyle), Ok(block), Ok(common_handles)) => Ok(DimensionHandles {
+            dimstyle: Some(dimstyle),
+            block: Some(block),
+            layer: Some(common_handles.layer),
+        }),
+        _ if allow_handle_decode_failure => {
+            reader.set_pos(handles_pos.0, handles_pos.1);
+            let layer = parse_common_entity_layer_handle(reader, header).ok();
+            Ok(DimensionHandles {
+                dimstyle: None,
+                block: None,
+                layer,
+            })
+        }
+        _ => {
+            reader.set_pos(handles_pos.0, handles_pos.1);
+            Err(DwgError::new(
+                ErrorKind::Decode,
+                format!("failed to decode {type_name} handles"),
+            ))
+        }
+    }
+}
+
+/// Runs `decode_variant` for every candidate layout and keeps the result with
+/// the lowest `score`.
+pub(crate) fn decode_best_dimension_variant<V, T>(
+    reader: &mut BitReader<'_>,
+    variants: &[V],
+    type_name: &str,
+    mut decode_variant: impl FnMut(&mut BitReader<'_>, V) -> Result<T>,
+    score: impl Fn(&T) -> u64,
+) -> Result<T>
+where
+    V: Copy,
+{
+    let data_pos = reader.get_pos();
+    let mut best: Option<(u64, T)> = None;
+    let mut last_error: Option<DwgError> = None;
+    for parse_variant in variants.iter().copied() {
+        reader.set_pos(data_pos.0, data_pos.1);
+        match decode_variant(reader, parse_variant) {
+            Ok(entity) => {
+                let entity_score = score(&entity);
+                match &best {
+                    Some((best_score, _)) if entity_score >= *best_score => {}
+                    _ => best = Some((entity_score, entity)),
+                }
+            }
+            Err(err) => last_error = Some(err),
+        }
+    }
+
+    if let Some((_, entity)) = best {
+        return Ok(entity);
+    }
+    Err(last_error.unwrap_or_else(|| {
+        DwgError::new(
+            ErrorKind::Decode,
+            format!("failed to decode {type_name} with all variants"),
+        )
+    }))
+}
+
 pub(crate) fn plausibility_score(entity: &DimLinearEntity) -> u64 {
     let mut score = 0u64;
     let common = &entity.common;
@@ -190,3 +532,58 @@ pub(crate) fn value_score(value: f64) -> u64 {
         1_000_000
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::bit::{BitWriter, Endian};
+
+    /// Writes an R2013 common entity header followed by the common dimension
+    /// body of the first R2010+ variant, leaving the writer at the subtype
+    /// specific fields.
+    pub(crate) fn write_r2013_dimension_prefix(writer: &mut BitWriter) {
+        writer.write_h(4, 0).expect("write handle");
+        writer.write_bs(0).expect("write ext size");
+        writer.write_b(0).expect("write graphic flag");
+        writer.write_bb(0).expect("write entity mode");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing flag");
+        writer.write_b(0).expect("write ds binary flag");
+        writer.write_b(1).expect("write no links");
+        writer.write_b(0).expect("write color unknown");
+        writer.write_bd(1.0).expect("write ltype scale");
+        writer.write_bb(0).expect("write ltype flags");
+        writer.write_bb(0).expect("write plotstyle flags");
+        writer.write_bb(0).expect("write material flags");
+        writer.write_rc(0).expect("write shadow flags");
+        writer.write_b(0).expect("write full visual style flag");
+        writer.write_b(0).expect("write face visual style flag");
+        writer.write_b(0).expect("write edge visual style flag");
+        writer.write_bs(0).expect("write invisibility");
+        writer.write_rc(0).expect("write line weight");
+
+        writer.write_rc(0).expect("write dimension version");
+        writer.write_3bd(0.0, 0.0, 1.0).expect("write extrusion");
+        writer
+            .write_rd(Endian::Little, 5.0)
+            .expect("write text mid x");
+        writer
+            .write_rd(Endian::Little, 6.0)
+            .expect("write text mid y");
+        writer.write_bd(0.0).expect("write elevation");
+        writer.write_rc(0).expect("write dim flags");
+        writer.write_tv("").expect("write user text");
+        writer.write_bd(0.0).expect("write text rotation");
+        writer.write_bd(0.0).expect("write horizontal direction");
+        writer.write_3bd(1.0, 1.0, 1.0).expect("write insert scale");
+        writer.write_bd(0.0).expect("write insert rotation");
+        writer.write_bs(5).expect("write attachment point");
+        writer.write_bs(1).expect("write line spacing style");
+        writer.write_bd(1.0).expect("write line spacing factor");
+        writer.write_bd(12.5).expect("write actual measurement");
+        writer.write_b(0).expect("write unknown flag");
+        writer.write_b(0).expect("write flip arrow 1");
+        writer.write_b(0).expect("write flip arrow 2");
+        writer.write_rd(Endian::Little, 0.0).expect("write 12-pt x");
+        writer.write_rd(Endian::Little, 0.0).expect("write 12-pt y");
+    }
+}
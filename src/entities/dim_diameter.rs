@@ -132,11 +132,11 @@ fn decode_r2010_plus_variant(
         parse_common_entity_handles(reader, header),
     ) {
         (Ok(dimstyle), Ok(block), Ok(common_handles)) => {
-            (Some(dimstyle), Some(block), common_handles.layer)
+            (Some(dimstyle), Some(block), Some(common_handles.layer))
         }
         _ if allow_handle_decode_failure => {
             reader.set_pos(handles_pos.0, handles_pos.1);
-            let layer = parse_common_entity_layer_handle(reader, header).unwrap_or(0);
+            let layer = parse_common_entity_layer_handle(reader, header).ok();
             (None, None, layer)
         }
         _ => {
@@ -178,5 +178,13 @@ fn decode_r2010_plus_variant(
         point10,
         ext_line_rotation: 0.0,
         dim_rotation: 0.0,
+        point15: None,
+        point16: None,
+        ordinate_x_type: None,
+        point17: None,
+        is_partial: None,
+        arc_params: None,
+        has_leader: None,
+        leader_length: None,
     })
 }
@@ -0,0 +1,205 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_header, parse_common_entity_header_r2007, parse_common_entity_header_r2010,
+    parse_common_entity_header_r2013, CommonEntityHeader,
+};
+use crate::entities::dim_common::{
+    decode_best_dimension_variant, plausibility_score, read_legacy_dimension_body,
+    read_legacy_dimension_handles, read_r2010_plus_dimension_body,
+    read_r2010_plus_dimension_handles, DimensionBody, DimensionHandles, LEGACY_DIMENSION_VARIANTS,
+    R2010_PLUS_VARIANTS,
+};
+use crate::entities::dim_linear::{DimLinearEntity, DimensionCommonData};
+
+/// LARGE_RADIAL_DIMENSION (jogged radius dimension) class entity.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct DimLargeRadialEntity {
+    pub common: DimensionCommonData,
+    /// Arc center.
+    pub point10: (f64, f64, f64),
+    /// Chord point on the arc.
+    pub point15: (f64, f64, f64),
+    pub leader_length: f64,
+    /// Override center the dimension line starts from.
+    pub override_center: (f64, f64, f64),
+    /// Jog point on the dimension line.
+    pub jog_point: (f64, f64, f64),
+}
+
+impl DimLargeRadialEntity {
+    /// Projects the jogged radius points onto the shared dimension row layout:
+    /// center as 10-pt, override center as 13-pt, jog point as 14-pt, and the
+    /// chord point as 15-pt.
+    pub fn to_linear_like(&self) -> DimLinearEntity {
+        DimLinearEntity {
+            common: self.common.clone(),
+            point13: self.override_center,
+            point14: self.jog_point,
+            point10: self.point10,
+            ext_line_rotation: 0.0,
+            dim_rotation: 0.0,
+            point15: Some(self.point15),
+            point16: None,
+            ordinate_x_type: None,
+            point17: None,
+            is_partial: None,
+            arc_params: None,
+            has_leader: None,
+            leader_length: Some(self.leader_length),
+        }
+    }
+}
+
+struct LargeRadialFields {
+    point10: (f64, f64, f64),
+    point15: (f64, f64, f64),
+    leader_length: f64,
+    override_center: (f64, f64, f64),
+    jog_point: (f64, f64, f64),
+}
+
+pub fn decode_dim_large_radial(reader: &mut BitReader<'_>) -> Result<DimLargeRadialEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_dim_large_radial_with_header(reader, header, false)
+}
+
+pub fn decode_dim_large_radial_r2007(reader: &mut BitReader<'_>) -> Result<DimLargeRadialEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_dim_large_radial_with_header(reader, header, true)
+}
+
+pub fn decode_dim_large_radial_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimLargeRadialEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_large_radial_r2010_plus_with_header(reader, header, true)
+}
+
+pub fn decode_dim_large_radial_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimLargeRadialEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_large_radial_r2010_plus_with_header(reader, header, true)
+}
+
+fn decode_dim_large_radial_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimLargeRadialEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &LEGACY_DIMENSION_VARIANTS,
+        "LARGE_RADIAL_DIMENSION",
+        |reader, parse_variant| {
+            let body = read_legacy_dimension_body(reader, parse_variant)?;
+            let fields = read_large_radial_fields(reader)?;
+            let handles = read_legacy_dimension_handles(
+                reader,
+                &header,
+                parse_variant,
+                allow_handle_decode_failure,
+            )?;
+            Ok(build_entity(&header, body, handles, fields))
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+fn decode_dim_large_radial_r2010_plus_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimLargeRadialEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &R2010_PLUS_VARIANTS,
+        "R2010+ LARGE_RADIAL_DIMENSION",
+        |reader, parse_variant| {
+            let body = read_r2010_plus_dimension_body(reader, parse_variant)?;
+            let fields = read_large_radial_fields(reader)?;
+            let handles = read_r2010_plus_dimension_handles(
+                reader,
+                &header,
+                allow_handle_decode_failure,
+                "LARGE_RADIAL_DIMENSION",
+            )?;
+            Ok(build_entity(&header, body, handles, fields))
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+fn read_large_radial_fields(reader: &mut BitReader<'_>) -> Result<LargeRadialFields> {
+    let point10 = reader.read_3bd()?;
+    let point15 = reader.read_3bd()?;
+    let leader_length = reader.read_bd()?;
+    let override_center = reader.read_3bd()?;
+    let jog_point = reader.read_3bd()?;
+    Ok(LargeRadialFields {
+        point10,
+        point15,
+        leader_length,
+        override_center,
+        jog_point,
+    })
+}
+
+fn build_entity(
+    header: &CommonEntityHeader,
+    body: DimensionBody,
+    handles: DimensionHandles,
+    fields: LargeRadialFields,
+) -> DimLargeRadialEntity {
+    DimLargeRadialEntity {
+        common: body.into_common(header, handles),
+        point10: fields.point10,
+        point15: fields.point15,
+        leader_length: fields.leader_length,
+        override_center: fields.override_center,
+        jog_point: fields.jog_point,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_dim_large_radial_r2013;
+    use crate::bit::{BitReader, BitWriter};
+    use crate::entities::dim_common::test_support::write_r2013_dimension_prefix;
+
+    #[test]
+    fn decode_dim_large_radial_r2013_reads_jog_points() {
+        let mut writer = BitWriter::new();
+        write_r2013_dimension_prefix(&mut writer);
+        writer.write_3bd(100.0, 50.0, 0.0).expect("write 10-pt");
+        writer.write_3bd(130.0, 90.0, 0.0).expect("write 15-pt");
+        writer.write_bd(0.0).expect("write leader length");
+        writer
+            .write_3bd(110.0, 60.0, 0.0)
+            .expect("write override center");
+        writer.write_3bd(118.0, 70.0, 0.0).expect("write jog point");
+        let end_bit = writer.tell_bits() as u32;
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        let entity =
+            decode_dim_large_radial_r2013(&mut reader, end_bit, 0x2B).expect("decode jogged dim");
+
+        assert_eq!(entity.point10, (100.0, 50.0, 0.0));
+        assert_eq!(entity.point15, (130.0, 90.0, 0.0));
+        assert_eq!(entity.override_center, (110.0, 60.0, 0.0));
+        assert_eq!(entity.jog_point, (118.0, 70.0, 0.0));
+        let linear_like = entity.to_linear_like();
+        assert_eq!(linear_like.point13, entity.override_center);
+        assert_eq!(linear_like.point14, entity.jog_point);
+        assert_eq!(linear_like.point15, Some(entity.point15));
+    }
+}
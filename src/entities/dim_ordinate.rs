@@ -0,0 +1,155 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_header, parse_common_entity_header_r2007, parse_common_entity_header_r2010,
+    parse_common_entity_header_r2013, CommonEntityHeader,
+};
+use crate::entities::dim_common::{
+    decode_best_dimension_variant, plausibility_score, read_legacy_dimension_body,
+    read_legacy_dimension_handles, read_r2010_plus_dimension_body,
+    read_r2010_plus_dimension_handles, LEGACY_DIMENSION_VARIANTS, R2010_PLUS_VARIANTS,
+};
+use crate::entities::dim_linear::{DimLinearEntity, DimensionCommonData};
+
+/// Set in `flags2` when the dimension measures along the X axis.
+const ORDINATE_X_TYPE_FLAG: u8 = 0x01;
+
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct DimOrdinateEntity {
+    pub common: DimensionCommonData,
+    /// UCS origin the ordinate is measured from.
+    pub point10: (f64, f64, f64),
+    /// Feature location.
+    pub point13: (f64, f64, f64),
+    /// Leader end point.
+    pub point14: (f64, f64, f64),
+    pub flags2: u8,
+}
+
+impl DimOrdinateEntity {
+    pub fn is_x_type(&self) -> bool {
+        (self.flags2 & ORDINATE_X_TYPE_FLAG) != 0
+    }
+
+    /// Projects the ordinate points onto the shared dimension row layout:
+    /// origin as 10-pt, feature as 13-pt, leader end as 14-pt.
+    pub fn to_linear_like(&self) -> DimLinearEntity {
+        DimLinearEntity {
+            common: self.common.clone(),
+            point13: self.point13,
+            point14: self.point14,
+            point10: self.point10,
+            ext_line_rotation: 0.0,
+            dim_rotation: 0.0,
+            point15: None,
+            point16: None,
+            ordinate_x_type: Some(self.is_x_type()),
+            point17: None,
+            is_partial: None,
+            arc_params: None,
+            has_leader: None,
+            leader_length: None,
+        }
+    }
+}
+
+pub fn decode_dim_ordinate(reader: &mut BitReader<'_>) -> Result<DimOrdinateEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_dim_ordinate_with_header(reader, header, false)
+}
+
+pub fn decode_dim_ordinate_r2007(reader: &mut BitReader<'_>) -> Result<DimOrdinateEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_dim_ordinate_with_header(reader, header, true)
+}
+
+pub fn decode_dim_ordinate_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimOrdinateEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_ordinate_r2010_plus_with_header(reader, header, true)
+}
+
+pub fn decode_dim_ordinate_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<DimOrdinateEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_dim_ordinate_r2010_plus_with_header(reader, header, true)
+}
+
+fn decode_dim_ordinate_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimOrdinateEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &LEGACY_DIMENSION_VARIANTS,
+        "DIM_ORDINATE",
+        |reader, parse_variant| {
+            let body = read_legacy_dimension_body(reader, parse_variant)?;
+            let (point10, point13, point14, flags2) = read_ordinate_points(reader)?;
+            let handles = read_legacy_dimension_handles(
+                reader,
+                &header,
+                parse_variant,
+                allow_handle_decode_failure,
+            )?;
+            Ok(DimOrdinateEntity {
+                common: body.into_common(&header, handles),
+                point10,
+                point13,
+                point14,
+                flags2,
+            })
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+fn decode_dim_ordinate_r2010_plus_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+) -> Result<DimOrdinateEntity> {
+    decode_best_dimension_variant(
+        reader,
+        &R2010_PLUS_VARIANTS,
+        "R2010+ DIM_ORDINATE",
+        |reader, parse_variant| {
+            let body = read_r2010_plus_dimension_body(reader, parse_variant)?;
+            let (point10, point13, point14, flags2) = read_ordinate_points(reader)?;
+            let handles = read_r2010_plus_dimension_handles(
+                reader,
+                &header,
+                allow_handle_decode_failure,
+                "DIM_ORDINATE",
+            )?;
+            Ok(DimOrdinateEntity {
+                common: body.into_common(&header, handles),
+                point10,
+                point13,
+                point14,
+                flags2,
+            })
+        },
+        |entity| plausibility_score(&entity.to_linear_like()),
+    )
+}
+
+type OrdinatePoints = ((f64, f64, f64), (f64, f64, f64), (f64, f64, f64), u8);
+
+fn read_ordinate_points(reader: &mut BitReader<'_>) -> Result<OrdinatePoints> {
+    let point10 = reader.read_3bd()?;
+    let point13 = reader.read_3bd()?;
+    let point14 = reader.read_3bd()?;
+    let flags2 = reader.read_rc()?;
+    Ok((point10, point13, point14, flags2))
+}
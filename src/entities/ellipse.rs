@@ -10,7 +10,8 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 static R14_ELLIPSE_PREFERRED_DELTA: AtomicU32 = AtomicU32::new(64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct EllipseEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -259,7 +260,7 @@ fn consider_ellipse_r14_delta(
     if probe.tell_bits() != target_end {
         return None;
     }
-    let Some(score) = score_ellipse_candidate(
+    let score = score_ellipse_candidate(
         delta,
         center,
         major_axis,
@@ -267,9 +268,7 @@ fn consider_ellipse_r14_delta(
         axis_ratio,
         start_angle,
         end_angle,
-    ) else {
-        return None;
-    };
+    )?;
     if !is_high_confidence_ellipse_candidate(
         center,
         major_axis,
@@ -283,10 +282,8 @@ fn consider_ellipse_r14_delta(
     }
 
     let mut layer_reader = reader.clone();
-    let layer_handle = match decode_ellipse_layer_handle(&mut layer_reader, header, true, false) {
-        Ok(layer) => layer,
-        Err(_) => 0,
-    };
+    let layer_handle =
+        decode_ellipse_layer_handle(&mut layer_reader, header, true, false).unwrap_or_default();
     Some(EllipseEntity {
         handle: header.handle,
         color_index: header.color.index,
@@ -339,10 +336,8 @@ fn update_best_ellipse_candidate(
     };
 
     let mut layer_reader = reader.clone();
-    let layer_handle = match decode_ellipse_layer_handle(&mut layer_reader, header, true, false) {
-        Ok(layer) => layer,
-        Err(_) => 0,
-    };
+    let layer_handle =
+        decode_ellipse_layer_handle(&mut layer_reader, header, true, false).unwrap_or_default();
     let candidate = EllipseEntity {
         handle: header.handle,
         color_index: header.color.index,
@@ -398,7 +393,7 @@ fn score_ellipse_candidate(
     }
     let extrusion_norm =
         (extrusion.0 * extrusion.0 + extrusion.1 * extrusion.1 + extrusion.2 * extrusion.2).sqrt();
-    if !extrusion_norm.is_finite() || extrusion_norm < 1.0e-9 || extrusion_norm > 1.0e3 {
+    if !extrusion_norm.is_finite() || !(1.0e-9..=1.0e3).contains(&extrusion_norm) {
         return None;
     }
     if axis_ratio <= 0.0 {
@@ -7,13 +7,37 @@ use crate::entities::common::{
     parse_common_entity_layer_handle, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct HatchPath {
     pub closed: bool,
     pub points: Vec<(f64, f64)>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HatchPatternLine {
+    pub angle: f64,
+    pub origin: (f64, f64),
+    pub offset: (f64, f64),
+    pub dashes: Vec<f64>,
+}
+
+/// Pattern definition as stored on the entity: the lines are already scaled
+/// and rotated by `angle` and `scale`, so they can be filled directly.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HatchPattern {
+    pub style: u16,
+    pub pattern_type: u16,
+    pub angle: f64,
+    pub scale: f64,
+    pub double: bool,
+    pub lines: Vec<HatchPatternLine>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct HatchEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -25,6 +49,7 @@ pub struct HatchEntity {
     pub elevation: f64,
     pub extrusion: (f64, f64, f64),
     pub paths: Vec<HatchPath>,
+    pub pattern: Option<HatchPattern>,
 }
 
 pub fn decode_hatch(reader: &mut BitReader<'_>) -> Result<HatchEntity> {
@@ -298,6 +323,7 @@ fn decode_hatch_with_polyline_path_scan<'a>(
             elevation: 0.0,
             extrusion: (0.0, 0.0, 1.0),
             paths,
+            pattern: None,
         };
 
         let start_penalty =
@@ -434,14 +460,19 @@ fn decode_hatch_body(
         paths.push(HatchPath { closed, points });
     }
 
-    if let Err(err) = skip_hatch_definition_payload(reader, solid_fill, any_path_uses_pixel_size) {
-        if !matches!(
-            err.kind,
-            ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
-        ) {
-            return Err(err);
+    let pattern = match read_hatch_definition_payload(reader, solid_fill, any_path_uses_pixel_size)
+    {
+        Ok(pattern) => Some(pattern),
+        Err(err)
+            if matches!(
+                err.kind,
+                ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+            ) =>
+        {
+            None
         }
-    }
+        Err(err) => return Err(err),
+    };
 
     // Handles are stored in the handle stream at obj_size bit offset.
     reader.set_bit_pos(header.obj_size);
@@ -474,6 +505,7 @@ fn decode_hatch_body(
         elevation,
         extrusion,
         paths,
+        pattern,
     })
 }
 
@@ -630,28 +662,44 @@ fn skip_gradient_payload(reader: &mut BitReader<'_>, use_unicode_text: bool) ->
     Ok(())
 }
 
-fn skip_hatch_definition_payload(
+fn read_hatch_definition_payload(
     reader: &mut BitReader<'_>,
     solid_fill: bool,
     any_path_uses_pixel_size: bool,
-) -> Result<()> {
-    let _style = reader.read_bs()?;
-    let _pattern_type = reader.read_bs()?;
-
+) -> Result<HatchPattern> {
+    let style = reader.read_bs()?;
+    let pattern_type = reader.read_bs()?;
+
+    let mut pattern = HatchPattern {
+        style,
+        pattern_type,
+        angle: 0.0,
+        scale: 1.0,
+        double: false,
+        lines: Vec::new(),
+    };
     if !solid_fill {
-        let _pattern_angle = reader.read_bd()?;
-        let _pattern_scale = reader.read_bd()?;
-        let _double_hatch = reader.read_b()?;
+        pattern.angle = reader.read_bd()?;
+        pattern.scale = reader.read_bd()?;
+        pattern.double = reader.read_b()? != 0;
         let num_def_lines =
             bounded_count(reader.read_bs()? as u32, "hatch pattern definition lines")?;
+        pattern.lines.reserve(num_def_lines);
         for _ in 0..num_def_lines {
-            let _line_angle = reader.read_bd()?;
-            let _line_origin = (reader.read_bd()?, reader.read_bd()?);
-            let _line_offset = (reader.read_bd()?, reader.read_bd()?);
+            let angle = reader.read_bd()?;
+            let origin = (reader.read_bd()?, reader.read_bd()?);
+            let offset = (reader.read_bd()?, reader.read_bd()?);
             let num_dashes = bounded_count(reader.read_bs()? as u32, "hatch pattern dashes")?;
+            let mut dashes = Vec::with_capacity(num_dashes);
             for _ in 0..num_dashes {
-                let _dash_length = reader.read_bd()?;
+                dashes.push(reader.read_bd()?);
             }
+            pattern.lines.push(HatchPatternLine {
+                angle,
+                origin,
+                offset,
+                dashes,
+            });
         }
     }
 
@@ -664,7 +712,7 @@ fn skip_hatch_definition_payload(
     for _ in 0..num_seed_points {
         let _seed = read_point2rd(reader)?;
     }
-    Ok(())
+    Ok(pattern)
 }
 
 fn read_point2rd(reader: &mut BitReader<'_>) -> Result<(f64, f64)> {
@@ -675,26 +723,22 @@ fn read_point2rd(reader: &mut BitReader<'_>) -> Result<(f64, f64)> {
 }
 
 fn append_segment_points(points: &mut Vec<(f64, f64)>, segment: &[(f64, f64)]) {
-    if segment.is_empty() {
+    let Some((&first, rest)) = segment.split_first() else {
         return;
+    };
+    match points.last() {
+        Some(&last) if points_equal_2d(last, first) => points.extend_from_slice(rest),
+        _ => points.extend_from_slice(segment),
     }
-    if points.is_empty() {
-        points.extend_from_slice(segment);
-        return;
-    }
-    let mut start = 0usize;
-    if points_equal_2d(*points.last().unwrap(), segment[0]) {
-        start = 1;
-    }
-    points.extend_from_slice(&segment[start..]);
 }
 
 fn close_path_if_needed(points: &mut Vec<(f64, f64)>) {
     if points.len() <= 1 {
         return;
     }
-    let first = points[0];
-    let last = *points.last().unwrap();
+    let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+        return;
+    };
     if !points_equal_2d(first, last) {
         points.push(first);
     }
@@ -765,21 +809,15 @@ fn polyline_with_bulges_points(
     if points.len() <= 1 {
         return points.to_vec();
     }
-    let mut bulge_values = vec![0.0f64; points.len()];
-    for (idx, bulge) in bulges.iter().enumerate().take(points.len()) {
-        bulge_values[idx] = *bulge;
-    }
-
     let seg_count = if closed {
         points.len()
     } else {
         points.len().saturating_sub(1)
     };
+    let ends = points.iter().cycle().skip(1);
+    let bulge_values = bulges.iter().copied().chain(std::iter::repeat(0.0));
     let mut out: Vec<(f64, f64)> = Vec::new();
-    for idx in 0..seg_count {
-        let start = points[idx];
-        let end = points[(idx + 1) % points.len()];
-        let bulge = bulge_values[idx];
+    for ((&start, &end), bulge) in points.iter().zip(ends).zip(bulge_values).take(seg_count) {
         let segment = bulge_segment_points(start, end, bulge, arc_segments);
         append_segment_points(&mut out, &segment);
     }
@@ -867,3 +905,44 @@ fn bounded_count(raw: u32, label: &str) -> Result<usize> {
     }
     Ok(count)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{read_hatch_definition_payload, HatchPatternLine};
+    use crate::bit::{BitReader, BitWriter};
+
+    #[test]
+    fn read_hatch_definition_payload_keeps_pattern_lines() {
+        let mut writer = BitWriter::new();
+        writer.write_bs(0).expect("write style");
+        writer.write_bs(1).expect("write pattern type");
+        writer.write_bd(0.0).expect("write angle");
+        writer.write_bd(2.0).expect("write scale");
+        writer.write_b(0).expect("write double flag");
+        writer.write_bs(1).expect("write line count");
+        // ANSI31 at scale 2: 45 degree lines, 6.35 apart.
+        for value in [std::f64::consts::FRAC_PI_4, 0.0, 0.0, -4.49, 4.49] {
+            writer.write_bd(value).expect("write line");
+        }
+        writer.write_bs(2).expect("write dash count");
+        writer.write_bd(3.0).expect("write dash");
+        writer.write_bd(-1.0).expect("write gap");
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        let pattern =
+            read_hatch_definition_payload(&mut reader, false, false).expect("pattern payload");
+
+        assert_eq!(pattern.pattern_type, 1);
+        assert_eq!(pattern.scale, 2.0);
+        assert_eq!(
+            pattern.lines,
+            vec![HatchPatternLine {
+                angle: std::f64::consts::FRAC_PI_4,
+                origin: (0.0, 0.0),
+                offset: (-4.49, 4.49),
+                dashes: vec![3.0, -1.0],
+            }]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::bit::BitReader;
+use crate::core::error::ErrorKind;
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r2007,
+    parse_common_entity_header_r2010, parse_common_entity_header_r2013,
+    parse_common_entity_layer_handle, CommonEntityHeader,
+};
+
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct LightEntity {
+    pub handle: u64,
+    pub color_index: Option<u16>,
+    pub true_color: Option<u32>,
+    pub layer_handle: u64,
+    /// `None` when the name lives in the R2007+ string stream.
+    pub name: Option<String>,
+    /// 1 distant, 2 point, 3 spot.
+    pub light_type: u32,
+    pub is_on: bool,
+    pub light_color_index: u16,
+    pub light_true_color: Option<u32>,
+    pub plot_glyph: bool,
+    pub intensity: f64,
+    pub position: (f64, f64, f64),
+    pub target: (f64, f64, f64),
+    /// 0 none, 1 inverse linear, 2 inverse square.
+    pub attenuation_type: u32,
+    pub use_attenuation_limits: bool,
+    pub attenuation_start_limit: f64,
+    pub attenuation_end_limit: f64,
+    /// Spot cone angles in radians.
+    pub hotspot_angle: f64,
+    pub falloff_angle: f64,
+    pub cast_shadows: bool,
+}
+
+/// LIGHT first appeared in R2007; the default branch reads the R2004 layout
+/// used by files saved down from it.
+pub fn decode_light(reader: &mut BitReader<'_>) -> Result<LightEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_light_with_header(reader, header, false, false)
+}
+
+pub fn decode_light_r2007(reader: &mut BitReader<'_>) -> Result<LightEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_light_with_header(reader, header, true, true)
+}
+
+pub fn decode_light_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<LightEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_light_with_header(reader, header, true, true)
+}
+
+pub fn decode_light_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<LightEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_light_with_header(reader, header, true, true)
+}
+
+fn decode_light_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+    uses_string_stream: bool,
+) -> Result<LightEntity> {
+    let _class_version = reader.read_bl()?;
+    let name = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let light_type = reader.read_bl()?;
+    let is_on = reader.read_b()? != 0;
+    let light_color_index = reader.read_bs()?;
+    let color_rgb = reader.read_bl()?;
+    let color_byte = reader.read_rc()?;
+    if !uses_string_stream {
+        if (color_byte & 0x01) != 0 {
+            let _color_name = reader.read_tv()?;
+        }
+        if (color_byte & 0x02) != 0 {
+            let _book_name = reader.read_tv()?;
+        }
+    }
+    let light_true_color = ((color_rgb >> 24) == 0xC2).then_some(color_rgb & 0x00FF_FFFF);
+    let plot_glyph = reader.read_b()? != 0;
+    let intensity = reader.read_bd()?;
+    let position = reader.read_3bd()?;
+    let target = reader.read_3bd()?;
+    let attenuation_type = reader.read_bl()?;
+    let use_attenuation_limits = reader.read_b()? != 0;
+    let attenuation_start_limit = reader.read_bd()?;
+    let attenuation_end_limit = reader.read_bd()?;
+    let hotspot_angle = reader.read_bd()?;
+    let falloff_angle = reader.read_bd()?;
+    let cast_shadows = reader.read_b()? != 0;
+
+    reader.set_bit_pos(header.obj_size);
+    let layer_handle = match if uses_string_stream {
+        parse_common_entity_layer_handle(reader, &header)
+    } else {
+        parse_common_entity_handles(reader, &header).map(|common_handles| common_handles.layer)
+    } {
+        Ok(layer_handle) => layer_handle,
+        Err(err)
+            if allow_handle_decode_failure
+                && matches!(
+                    err.kind,
+                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                ) =>
+        {
+            0
+        }
+        Err(err) => return Err(err),
+    };
+
+    Ok(LightEntity {
+        handle: header.handle,
+        color_index: header.color.index,
+        true_color: header.color.true_color,
+        layer_handle,
+        name,
+        light_type,
+        is_on,
+        light_color_index,
+        light_true_color,
+        plot_glyph,
+        intensity,
+        position,
+        target,
+        attenuation_type,
+        use_attenuation_limits,
+        attenuation_start_limit,
+        attenuation_end_limit,
+        hotspot_angle,
+        falloff_angle,
+        cast_shadows,
+    })
+}
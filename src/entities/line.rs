@@ -8,6 +8,7 @@ use crate::entities::common::{
 };
 
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct LineEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -16,6 +17,26 @@ pub struct LineEntity {
     pub layer_handle: u64,
     pub start: (f64, f64, f64),
     pub end: (f64, f64, f64),
+    /// How far the line is extruded along `extrusion`; 0 for a flat line.
+    pub thickness: f64,
+    pub extrusion: (f64, f64, f64),
+}
+
+impl Default for LineEntity {
+    /// Flat, with the world Z axis as extrusion direction.
+    fn default() -> Self {
+        Self {
+            handle: Default::default(),
+            color_index: Default::default(),
+            true_color: Default::default(),
+            owner_handle: Default::default(),
+            layer_handle: Default::default(),
+            start: Default::default(),
+            end: Default::default(),
+            thickness: Default::default(),
+            extrusion: (0.0, 0.0, 1.0),
+        }
+    }
 }
 
 pub fn decode_line(reader: &mut BitReader<'_>) -> Result<LineEntity> {
@@ -123,6 +144,8 @@ fn decode_line_r14_fallback(reader: &mut BitReader<'_>, object_handle: u64) -> R
                 layer_handle: 0,
                 start,
                 end,
+                thickness: 0.0,
+                extrusion,
             };
 
             match &best {
@@ -252,7 +275,7 @@ fn score_line_candidate(
 
     let ex_norm =
         (extrusion.0 * extrusion.0 + extrusion.1 * extrusion.1 + extrusion.2 * extrusion.2).sqrt();
-    if !ex_norm.is_finite() || ex_norm < 1.0e-9 || ex_norm > 1.0e3 {
+    if !ex_norm.is_finite() || !(1.0e-9..=1.0e3).contains(&ex_norm) {
         return None;
     }
 
@@ -345,8 +368,8 @@ fn decode_line_with_header(
         (0.0, 0.0)
     };
 
-    let _thickness = reader.read_bt()?;
-    let _extrusion = reader.read_be()?;
+    let thickness = reader.read_bt()?;
+    let extrusion = reader.read_be()?;
     let (owner_handle, layer_handle) = decode_owner_and_layer_with_common_header(
         reader,
         &header,
@@ -362,6 +385,8 @@ fn decode_line_with_header(
         layer_handle,
         start: (x_start, y_start, z_start),
         end: (x_end, y_end, z_end),
+        thickness,
+        extrusion,
     })
 }
 
@@ -373,8 +398,8 @@ fn decode_line_with_header_r14(
     // R13/R14 stores explicit 3BD start/end points.
     let start = reader.read_3bd()?;
     let end = reader.read_3bd()?;
-    let _thickness = reader.read_bt()?;
-    let _extrusion = reader.read_be()?;
+    let thickness = reader.read_bt()?;
+    let extrusion = reader.read_be()?;
     let (owner_handle, layer_handle) = decode_owner_and_layer_with_common_header(
         reader,
         &header,
@@ -390,6 +415,8 @@ fn decode_line_with_header_r14(
         layer_handle,
         start,
         end,
+        thickness,
+        extrusion,
     })
 }
 
@@ -399,20 +426,19 @@ fn decode_owner_and_layer_with_common_header(
     allow_handle_decode_failure: bool,
     r2007_layer_only: bool,
 ) -> Result<(Option<u64>, u64)> {
-    let owner_layer = match parse_common_entity_owner_and_layer_handle(
-        reader,
-        header,
-        r2007_layer_only,
-    ) {
-        Ok(owner_layer) => owner_layer,
-        Err(err)
-            if allow_handle_decode_failure
-                && matches!(
-                    err.kind,
-                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
-                ) =>
-        { (None, 0) }
-        Err(err) => return Err(err),
-    };
+    let owner_layer =
+        match parse_common_entity_owner_and_layer_handle(reader, header, r2007_layer_only) {
+            Ok(owner_layer) => owner_layer,
+            Err(err)
+                if allow_handle_decode_failure
+                    && matches!(
+                        err.kind,
+                        ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                    ) =>
+            {
+                (None, 0)
+            }
+            Err(err) => return Err(err),
+        };
     Ok(owner_layer)
 }
@@ -5,9 +5,11 @@ use crate::entities::common::{
     parse_common_entity_header, parse_common_entity_header_r14, parse_common_entity_header_r2007,
     parse_common_entity_header_r2010, parse_common_entity_header_r2013,
     parse_common_entity_owner_and_layer_handle, CommonEntityColor, CommonEntityHeader,
+    LINE_WEIGHT_BY_LAYER_INDEX,
 };
 
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct LwPolylineEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -19,6 +21,32 @@ pub struct LwPolylineEntity {
     pub const_width: Option<f64>,
     pub bulges: Vec<f64>,
     pub widths: Vec<(f64, f64)>,
+    /// Height of the vertices along `extrusion`.
+    pub elevation: f64,
+    /// How far the polyline is extruded along `extrusion`; 0 for a flat one.
+    pub thickness: f64,
+    pub extrusion: (f64, f64, f64),
+}
+
+impl Default for LwPolylineEntity {
+    /// Flat, with the world Z axis as extrusion direction.
+    fn default() -> Self {
+        Self {
+            handle: Default::default(),
+            color_index: Default::default(),
+            true_color: Default::default(),
+            owner_handle: Default::default(),
+            layer_handle: Default::default(),
+            flags: Default::default(),
+            vertices: Default::default(),
+            const_width: Default::default(),
+            bulges: Default::default(),
+            widths: Default::default(),
+            elevation: Default::default(),
+            thickness: Default::default(),
+            extrusion: (0.0, 0.0, 1.0),
+        }
+    }
 }
 
 const MAX_LWPOLYLINE_ITEMS: usize = 1_000_000;
@@ -238,6 +266,9 @@ fn decode_lwpolyline_with_header(
         const_width: body.const_width,
         bulges: body.bulges,
         widths: body.widths,
+        elevation: body.elevation,
+        thickness: body.thickness,
+        extrusion: body.extrusion,
     })
 }
 
@@ -398,6 +429,9 @@ struct LwPolylineBody {
     const_width: Option<f64>,
     bulges: Vec<f64>,
     widths: Vec<(f64, f64)>,
+    elevation: f64,
+    thickness: f64,
+    extrusion: (f64, f64, f64),
 }
 
 fn decode_lwpolyline_body(
@@ -410,15 +444,21 @@ fn decode_lwpolyline_body(
     } else {
         None
     };
-    if (flags & 0x08) != 0 {
-        let _elevation = reader.read_bd()?;
-    }
-    if (flags & 0x02) != 0 {
-        let _thickness = reader.read_bd()?;
-    }
-    if (flags & 0x01) != 0 {
-        let _normal = reader.read_3bd()?;
-    }
+    let elevation = if (flags & 0x08) != 0 {
+        reader.read_bd()?
+    } else {
+        0.0
+    };
+    let thickness = if (flags & 0x02) != 0 {
+        reader.read_bd()?
+    } else {
+        0.0
+    };
+    let extrusion = if (flags & 0x01) != 0 {
+        reader.read_3bd()?
+    } else {
+        (0.0, 0.0, 1.0)
+    };
 
     let num_verts = reader.read_bl()? as usize;
     validate_lwpolyline_count("vertex count", num_verts)?;
@@ -452,11 +492,13 @@ fn decode_lwpolyline_body(
         } else {
             let x0 = reader.read_rd(Endian::Little)?;
             let y0 = reader.read_rd(Endian::Little)?;
-            vertices.push((x0, y0));
+            let mut previous = (x0, y0);
+            vertices.push(previous);
             for _ in 1..num_verts {
-                let x = reader.read_dd(vertices.last().unwrap().0)?;
-                let y = reader.read_dd(vertices.last().unwrap().1)?;
-                vertices.push((x, y));
+                let x = reader.read_dd(previous.0)?;
+                let y = reader.read_dd(previous.1)?;
+                previous = (x, y);
+                vertices.push(previous);
             }
         }
     }
@@ -466,8 +508,8 @@ fn decode_lwpolyline_body(
         let mut normalized = vec![0.0; num_verts];
         for idx in 0..num_bulges {
             let bulge = reader.read_bd()?;
-            if idx < normalized.len() {
-                normalized[idx] = bulge;
+            if let Some(slot) = normalized.get_mut(idx) {
+                *slot = bulge;
             }
         }
         bulges = normalized;
@@ -486,8 +528,8 @@ fn decode_lwpolyline_body(
         for idx in 0..num_widths {
             let start_width = reader.read_bd()?;
             let end_width = reader.read_bd()?;
-            if idx < normalized.len() {
-                normalized[idx] = (start_width, end_width);
+            if let Some(slot) = normalized.get_mut(idx) {
+                *slot = (start_width, end_width);
             }
         }
         widths = normalized;
@@ -499,6 +541,9 @@ fn decode_lwpolyline_body(
         const_width,
         bulges,
         widths,
+        elevation,
+        thickness,
+        extrusion,
     })
 }
 
@@ -508,21 +553,20 @@ fn decode_lwpolyline_owner_and_layer_handle(
     allow_handle_decode_failure: bool,
     r2007_layer_only: bool,
 ) -> Result<(Option<u64>, u64)> {
-    let owner_layer = match parse_common_entity_owner_and_layer_handle(
-        reader,
-        header,
-        r2007_layer_only,
-    ) {
-        Ok(owner_layer) => owner_layer,
-        Err(err)
-            if allow_handle_decode_failure
-                && matches!(
-                    err.kind,
-                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
-                ) =>
-        { (None, 0) }
-        Err(err) => return Err(err),
-    };
+    let owner_layer =
+        match parse_common_entity_owner_and_layer_handle(reader, header, r2007_layer_only) {
+            Ok(owner_layer) => owner_layer,
+            Err(err)
+                if allow_handle_decode_failure
+                    && matches!(
+                        err.kind,
+                        ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                    ) =>
+            {
+                (None, 0)
+            }
+            Err(err) => return Err(err),
+        };
     Ok(owner_layer)
 }
 
@@ -556,7 +600,7 @@ fn parse_r14_lwpolyline_compact_header(
     }
 
     let _color_unknown = reader.read_b()?;
-    let _ltype_scale = reader.read_bd()?;
+    let ltype_scale = reader.read_bd()?;
     let _invisibility = reader.read_bs()?;
 
     Ok(CommonEntityHeader {
@@ -567,6 +611,7 @@ fn parse_r14_lwpolyline_compact_header(
         num_of_reactors,
         xdic_missing_flag,
         has_ds_binary_data: false,
+        ltype_scale,
         ltype_flags: if is_bylayer_ltype { 0 } else { 3 },
         plotstyle_flags: 0,
         material_flags: 0,
@@ -574,6 +619,7 @@ fn parse_r14_lwpolyline_compact_header(
         has_face_visual_style: false,
         has_edge_visual_style: false,
         has_legacy_entity_links: false,
+        line_weight: LINE_WEIGHT_BY_LAYER_INDEX,
     })
 }
 
@@ -659,6 +705,9 @@ fn decode_lwpolyline_r14_scan_by_obj_size(
         const_width: body.const_width,
         bulges: body.bulges,
         widths: body.widths,
+        elevation: body.elevation,
+        thickness: body.thickness,
+        extrusion: body.extrusion,
     })
 }
 
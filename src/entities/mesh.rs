@@ -0,0 +1,156 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r2007,
+    parse_common_entity_header_r2010, parse_common_entity_header_r2013,
+    parse_common_entity_layer_handle, CommonEntityHeader,
+};
+
+const MAX_MESH_ITEMS: u32 = 0x0100_0000;
+
+/// A subdivision mesh (`AcDbSubDMesh`) at its base level.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct MeshEntity {
+    pub handle: u64,
+    pub color_index: Option<u16>,
+    pub true_color: Option<u32>,
+    pub layer_handle: u64,
+    pub subdivision_level: u32,
+    pub vertices: Vec<(f64, f64, f64)>,
+    /// Faces as lists of 0-based vertex indices.
+    pub faces: Vec<Vec<u32>>,
+    pub edges: Vec<(u32, u32)>,
+    pub creases: Vec<f64>,
+}
+
+/// MESH first appeared in R2010; the default branch reads the layout used
+/// by files saved down from it.
+pub fn decode_mesh(reader: &mut BitReader<'_>) -> Result<MeshEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_mesh_with_header(reader, header, false, false)
+}
+
+pub fn decode_mesh_r2007(reader: &mut BitReader<'_>) -> Result<MeshEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_mesh_with_header(reader, header, true, true)
+}
+
+pub fn decode_mesh_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<MeshEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_mesh_with_header(reader, header, true, true)
+}
+
+pub fn decode_mesh_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<MeshEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_mesh_with_header(reader, header, true, true)
+}
+
+fn decode_mesh_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+    r2007_layer_only: bool,
+) -> Result<MeshEntity> {
+    let _version = reader.read_bs()?;
+    let _blend_crease = reader.read_b()?;
+    let subdivision_level = reader.read_bl()?;
+    let vertex_count = read_count(reader, "vertex")?;
+    let mut vertices = Vec::with_capacity(vertex_count as usize);
+    for _ in 0..vertex_count {
+        vertices.push(reader.read_3bd()?);
+    }
+
+    // Faces are stored as one flat list: a corner count followed by that
+    // many vertex indices, repeated.
+    let face_list_len = read_count(reader, "face list")?;
+    let mut faces = Vec::new();
+    let mut remaining = face_list_len;
+    while remaining > 0 {
+        let corners = reader.read_bl()?;
+        remaining -= 1;
+        if corners > remaining {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!("mesh face corner count exceeds face list: {corners}"),
+            ));
+        }
+        let mut face = Vec::with_capacity(corners as usize);
+        for _ in 0..corners {
+            let index = reader.read_bl()?;
+            if index >= vertex_count {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!("mesh face index out of range: {index}"),
+                ));
+            }
+            face.push(index);
+        }
+        remaining -= corners;
+        faces.push(face);
+    }
+
+    let edge_count = read_count(reader, "edge")?;
+    let mut edges = Vec::with_capacity(edge_count as usize);
+    for _ in 0..edge_count {
+        edges.push((reader.read_bl()?, reader.read_bl()?));
+    }
+    let crease_count = read_count(reader, "crease")?;
+    let mut creases = Vec::with_capacity(crease_count as usize);
+    for _ in 0..crease_count {
+        creases.push(reader.read_bd()?);
+    }
+
+    reader.set_bit_pos(header.obj_size);
+    let layer_handle = match if r2007_layer_only {
+        parse_common_entity_layer_handle(reader, &header)
+    } else {
+        parse_common_entity_handles(reader, &header).map(|common_handles| common_handles.layer)
+    } {
+        Ok(layer_handle) => layer_handle,
+        Err(err)
+            if allow_handle_decode_failure
+                && matches!(
+                    err.kind,
+                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                ) =>
+        {
+            0
+        }
+        Err(err) => return Err(err),
+    };
+
+    Ok(MeshEntity {
+        handle: header.handle,
+        color_index: header.color.index,
+        true_color: header.color.true_color,
+        layer_handle,
+        subdivision_level,
+        vertices,
+        faces,
+        edges,
+        creases,
+    })
+}
+
+fn read_count(reader: &mut BitReader<'_>, what: &str) -> Result<u32> {
+    let count = reader.read_bl()?;
+    if count > MAX_MESH_ITEMS {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("mesh {what} count too large: {count}"),
+        ));
+    }
+    Ok(count)
+}
@@ -0,0 +1,246 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r2007,
+    parse_common_entity_header_r2010, parse_common_entity_header_r2013,
+    parse_common_entity_layer_handle, CommonEntityHeader,
+};
+
+const MAX_MLEADER_ITEMS: u32 = 0x0001_0000;
+
+/// One leader of a MULTILEADER: the lines drawn from the arrowheads and the
+/// landing they join.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct MLeaderRoot {
+    /// Where the lines end and the landing starts.
+    pub connection: (f64, f64, f64),
+    /// Direction of the landing from `connection`.
+    pub direction: (f64, f64, f64),
+    /// Length of the landing (dogleg).
+    pub landing_distance: f64,
+    /// Points of each leader line, from the arrowhead towards the landing;
+    /// `connection` is not repeated.
+    pub lines: Vec<Vec<(f64, f64, f64)>>,
+}
+
+/// A multileader (`AcDbMLeader`) with its leaders and text content. Block
+/// content is not decoded.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct MLeaderEntity {
+    pub handle: u64,
+    pub color_index: Option<u16>,
+    pub true_color: Option<u32>,
+    pub layer_handle: u64,
+    pub roots: Vec<MLeaderRoot>,
+    pub scale: f64,
+    pub text_height: f64,
+    pub arrow_size: f64,
+    /// Whether the content is MTEXT; the `text_*` fields are unset otherwise.
+    pub has_text: bool,
+    /// `None` when the text lives in the R2007+ string stream.
+    pub text: Option<String>,
+    /// Top-left corner of the text for left aligned text.
+    pub text_location: (f64, f64, f64),
+    pub text_direction: (f64, f64, f64),
+    pub text_normal: (f64, f64, f64),
+    pub text_rotation: f64,
+    /// Boundary width of the text, 0 for no wrapping.
+    pub text_width: f64,
+    /// 1 left, 2 center, 3 right.
+    pub text_alignment: u16,
+}
+
+/// MULTILEADER first appeared in R2007; the default branch reads the R2004
+/// layout used by files saved down from it.
+pub fn decode_mleader(reader: &mut BitReader<'_>) -> Result<MLeaderEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_mleader_with_header(reader, header, false, false, false)
+}
+
+pub fn decode_mleader_r2007(reader: &mut BitReader<'_>) -> Result<MLeaderEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_mleader_with_header(reader, header, true, true, false)
+}
+
+pub fn decode_mleader_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<MLeaderEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_mleader_with_header(reader, header, true, true, true)
+}
+
+pub fn decode_mleader_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<MLeaderEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_mleader_with_header(reader, header, true, true, true)
+}
+
+fn decode_mleader_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    allow_handle_decode_failure: bool,
+    uses_string_stream: bool,
+    r2010_plus: bool,
+) -> Result<MLeaderEntity> {
+    if r2010_plus {
+        let _class_version = reader.read_bs()?;
+    }
+
+    // Context data: the leaders, then the content they point at. Handle
+    // fields of the context live in the handle stream and are not read.
+    let root_count = read_count(reader, "leader root")?;
+    let mut roots = Vec::with_capacity(root_count as usize);
+    for _ in 0..root_count {
+        roots.push(read_root(reader, uses_string_stream, r2010_plus)?);
+    }
+    let scale = reader.read_bd()?;
+    let _content_base = reader.read_3bd()?;
+    let text_height = reader.read_bd()?;
+    let arrow_size = reader.read_bd()?;
+    let _landing_gap = reader.read_bd()?;
+    let _left_attachment = reader.read_bs()?;
+    let _right_attachment = reader.read_bs()?;
+    let _text_align_type = reader.read_bs()?;
+    let _attachment_type = reader.read_bs()?;
+
+    let has_text = reader.read_b()? != 0;
+    let mut entity = MLeaderEntity {
+        handle: header.handle,
+        color_index: header.color.index,
+        true_color: header.color.true_color,
+        roots,
+        scale,
+        text_height,
+        arrow_size,
+        has_text,
+        text_alignment: 1,
+        ..MLeaderEntity::default()
+    };
+    if has_text {
+        if !uses_string_stream {
+            entity.text = Some(reader.read_tv()?);
+        }
+        entity.text_normal = reader.read_3bd()?;
+        entity.text_location = reader.read_3bd()?;
+        entity.text_direction = reader.read_3bd()?;
+        entity.text_rotation = reader.read_bd()?;
+        entity.text_width = reader.read_bd()?;
+        let _boundary_height = reader.read_bd()?;
+        let _line_spacing_factor = reader.read_bd()?;
+        let _line_spacing_style = reader.read_bs()?;
+        skip_cmc(reader, uses_string_stream)?;
+        entity.text_alignment = reader.read_bs()?;
+    }
+
+    reader.set_bit_pos(header.obj_size);
+    entity.layer_handle = match if uses_string_stream {
+        parse_common_entity_layer_handle(reader, &header)
+    } else {
+        parse_common_entity_handles(reader, &header).map(|common_handles| common_handles.layer)
+    } {
+        Ok(layer_handle) => layer_handle,
+        Err(err)
+            if allow_handle_decode_failure
+                && matches!(
+                    err.kind,
+                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                ) =>
+        {
+            0
+        }
+        Err(err) => return Err(err),
+    };
+    Ok(entity)
+}
+
+fn read_root(
+    reader: &mut BitReader<'_>,
+    uses_string_stream: bool,
+    r2010_plus: bool,
+) -> Result<MLeaderRoot> {
+    let _is_content_valid = reader.read_b()?;
+    let _unknown = reader.read_b()?;
+    let connection = reader.read_3bd()?;
+    let direction = reader.read_3bd()?;
+    let break_count = read_count(reader, "leader root break")?;
+    for _ in 0..break_count {
+        let _start = reader.read_3bd()?;
+        let _end = reader.read_3bd()?;
+    }
+    let _leader_index = reader.read_bl()?;
+    let landing_distance = reader.read_bd()?;
+
+    let line_count = read_count(reader, "leader line")?;
+    let mut lines = Vec::with_capacity(line_count as usize);
+    for _ in 0..line_count {
+        let point_count = read_count(reader, "leader line point")?;
+        let mut points = Vec::with_capacity(point_count as usize);
+        for _ in 0..point_count {
+            points.push(reader.read_3bd()?);
+        }
+        let break_count = read_count(reader, "leader line break")?;
+        if break_count > 0 {
+            let _segment_index = reader.read_bl()?;
+            let pair_count = read_count(reader, "leader line break point")?;
+            for _ in 0..pair_count {
+                let _start = reader.read_3bd()?;
+                let _end = reader.read_3bd()?;
+            }
+        }
+        let _line_index = reader.read_bl()?;
+        if r2010_plus {
+            let _leader_type = reader.read_bs()?;
+            skip_cmc(reader, uses_string_stream)?;
+            let _line_weight = reader.read_bl()?;
+            let _arrow_size = reader.read_bd()?;
+            let _override_flags = reader.read_bl()?;
+        }
+        lines.push(points);
+    }
+    if r2010_plus {
+        let _attachment_direction = reader.read_bs()?;
+    }
+    Ok(MLeaderRoot {
+        connection,
+        direction,
+        landing_distance,
+        lines,
+    })
+}
+
+/// Skips an R2004+ CMC color; its names are in the string stream from R2007.
+fn skip_cmc(reader: &mut BitReader<'_>, uses_string_stream: bool) -> Result<()> {
+    let _index = reader.read_bs()?;
+    let _rgb = reader.read_bl()?;
+    let color_byte = reader.read_rc()?;
+    if !uses_string_stream {
+        if color_byte & 0x01 != 0 {
+            let _color_name = reader.read_tv()?;
+        }
+        if color_byte & 0x02 != 0 {
+            let _book_name = reader.read_tv()?;
+        }
+    }
+    Ok(())
+}
+
+fn read_count(reader: &mut BitReader<'_>, what: &str) -> Result<u32> {
+    let count = reader.read_bl()?;
+    if count > MAX_MLEADER_ITEMS {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("multileader {what} count too large: {count}"),
+        ));
+    }
+    Ok(count)
+}
@@ -7,14 +7,16 @@ use crate::entities::common::{
     parse_common_entity_layer_handle, read_handle_reference, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct MLineVertex {
     pub position: (f64, f64, f64),
     pub vertex_direction: (f64, f64, f64),
     pub miter_direction: (f64, f64, f64),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct MLineEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
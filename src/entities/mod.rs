@@ -1,21 +1,31 @@
 pub mod arc;
+pub mod arc_aligned_text;
 pub mod attrib;
 pub mod body;
 pub mod circle;
+pub mod color;
 pub mod common;
+pub mod dim_ang2ln;
+pub mod dim_ang3pt;
+pub mod dim_arc;
 pub mod dim_common;
 pub mod dim_diameter;
+pub mod dim_large_radial;
 pub mod dim_linear;
+pub mod dim_ordinate;
 pub mod dim_radius;
 pub mod ellipse;
 pub mod face3d;
 pub mod hatch;
 pub mod insert;
 pub mod leader;
+pub mod light;
 pub mod line;
 pub mod long_transaction;
 pub mod lwpolyline;
+pub mod mesh;
 pub mod minsert;
+pub mod mleader;
 pub mod mline;
 pub mod mtext;
 pub mod oleframe;
@@ -26,10 +36,13 @@ pub mod polyline_mesh;
 pub mod polyline_pface;
 pub mod ray;
 pub mod region;
+pub mod rtext;
 pub mod seqend;
 pub mod shape;
+pub mod snapshot;
 pub mod solid;
 pub mod solid3d;
+pub mod solid_tessellation;
 pub mod spline;
 pub mod text;
 pub mod tolerance;
@@ -43,6 +56,11 @@ pub mod xline;
 pub use arc::{
     decode_arc, decode_arc_r14, decode_arc_r2007, decode_arc_r2010, decode_arc_r2013, ArcEntity,
 };
+pub use arc_aligned_text::{
+    decode_arc_aligned_text, decode_arc_aligned_text_r14, decode_arc_aligned_text_r2007,
+    decode_arc_aligned_text_r2010, decode_arc_aligned_text_r2013, ArcAlignedTextEntity,
+    ARC_ALIGNED_TEXT_STRING_COUNT,
+};
 pub use attrib::{
     decode_attdef, decode_attdef_r2007, decode_attdef_r2010, decode_attdef_r2013, decode_attrib,
     decode_attrib_r2007, decode_attrib_r2010, decode_attrib_r2013, AttribEntity,
@@ -55,14 +73,34 @@ pub use circle::{
     decode_circle, decode_circle_r14, decode_circle_r2007, decode_circle_r2010,
     decode_circle_r2013, CircleEntity,
 };
+pub use color::{aci_to_rgb, EntityColor, Transparency};
+pub use dim_ang2ln::{
+    decode_dim_ang2ln, decode_dim_ang2ln_r2007, decode_dim_ang2ln_r2010, decode_dim_ang2ln_r2013,
+    DimAng2LnEntity,
+};
+pub use dim_ang3pt::{
+    decode_dim_ang3pt, decode_dim_ang3pt_r2007, decode_dim_ang3pt_r2010, decode_dim_ang3pt_r2013,
+    DimAng3PtEntity,
+};
+pub use dim_arc::{
+    decode_dim_arc, decode_dim_arc_r2007, decode_dim_arc_r2010, decode_dim_arc_r2013, DimArcEntity,
+};
 pub use dim_diameter::{
     decode_dim_diameter, decode_dim_diameter_r2007, decode_dim_diameter_r2010,
     decode_dim_diameter_r2013, DimDiameterEntity,
 };
+pub use dim_large_radial::{
+    decode_dim_large_radial, decode_dim_large_radial_r2007, decode_dim_large_radial_r2010,
+    decode_dim_large_radial_r2013, DimLargeRadialEntity,
+};
 pub use dim_linear::{
     decode_dim_linear, decode_dim_linear_r2007, decode_dim_linear_r2010, decode_dim_linear_r2013,
     DimLinearEntity, DimensionCommonData,
 };
+pub use dim_ordinate::{
+    decode_dim_ordinate, decode_dim_ordinate_r2007, decode_dim_ordinate_r2010,
+    decode_dim_ordinate_r2013, DimOrdinateEntity,
+};
 pub use dim_radius::{
     decode_dim_radius, decode_dim_radius_r2007, decode_dim_radius_r2010, decode_dim_radius_r2013,
     DimRadiusEntity,
@@ -76,7 +114,7 @@ pub use face3d::{
 };
 pub use hatch::{
     decode_hatch, decode_hatch_r2004, decode_hatch_r2007, decode_hatch_r2010, decode_hatch_r2013,
-    HatchEntity, HatchPath,
+    HatchEntity, HatchPath, HatchPattern, HatchPatternLine,
 };
 pub use insert::{
     decode_insert, decode_insert_r2007, decode_insert_r2010, decode_insert_r2013, InsertEntity,
@@ -84,6 +122,9 @@ pub use insert::{
 pub use leader::{
     decode_leader, decode_leader_r2007, decode_leader_r2010, decode_leader_r2013, LeaderEntity,
 };
+pub use light::{
+    decode_light, decode_light_r2007, decode_light_r2010, decode_light_r2013, LightEntity,
+};
 pub use line::{
     decode_line, decode_line_r14, decode_line_r2007, decode_line_r2010, decode_line_r2013,
     LineEntity,
@@ -96,9 +137,14 @@ pub use lwpolyline::{
     decode_lwpolyline, decode_lwpolyline_r14, decode_lwpolyline_r2007, decode_lwpolyline_r2010,
     decode_lwpolyline_r2013, LwPolylineEntity,
 };
+pub use mesh::{decode_mesh, decode_mesh_r2007, decode_mesh_r2010, decode_mesh_r2013, MeshEntity};
 pub use minsert::{
     decode_minsert, decode_minsert_r2007, decode_minsert_r2010, decode_minsert_r2013, MInsertEntity,
 };
+pub use mleader::{
+    decode_mleader, decode_mleader_r2007, decode_mleader_r2010, decode_mleader_r2013,
+    MLeaderEntity, MLeaderRoot,
+};
 pub use mline::{
     decode_mline, decode_mline_r2007, decode_mline_r2010, decode_mline_r2013, MLineEntity,
     MLineVertex,
@@ -139,10 +185,15 @@ pub use region::{
     decode_region, decode_region_r14, decode_region_r2007, decode_region_r2010,
     decode_region_r2013, RegionEntity,
 };
+pub use rtext::{
+    decode_rtext, decode_rtext_r14, decode_rtext_r2007, decode_rtext_r2010, decode_rtext_r2013,
+    RTextEntity,
+};
 pub use seqend::{decode_seqend, SeqendEntity};
 pub use shape::{
     decode_shape, decode_shape_r2007, decode_shape_r2010, decode_shape_r2013, ShapeEntity,
 };
+pub use snapshot::EntitySnapshot;
 pub use solid::{
     decode_solid, decode_solid_r2007, decode_solid_r2010, decode_solid_r2013, SolidEntity,
 };
@@ -150,6 +201,9 @@ pub use solid3d::{
     decode_3dsolid, decode_3dsolid_r14, decode_3dsolid_r2007, decode_3dsolid_r2010,
     decode_3dsolid_r2013, Solid3dEntity,
 };
+pub use solid_tessellation::{
+    tessellate_acds_records, AcisFormat, AcisSolid, SolidTessellator, TriangleMesh,
+};
 pub use spline::{
     catmull_rom_spline, decode_spline, decode_spline_r2007, decode_spline_r2010,
     decode_spline_r2013, SplineEntity,
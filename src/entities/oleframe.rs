@@ -6,7 +6,8 @@ use crate::entities::common::{
     parse_common_entity_header_r2013, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct OleFrameEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
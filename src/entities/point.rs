@@ -10,7 +10,8 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 static R14_POINT_PREFERRED_DELTA: AtomicU32 = AtomicU32::new(64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct PointEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -175,21 +176,20 @@ fn decode_point_with_header(
     let _thickness = reader.read_bt()?;
     let _extrusion = reader.read_be()?;
     let x_axis_angle = reader.read_bd()?;
-    let (owner_handle, layer_handle) = match parse_common_entity_owner_and_layer_handle(
-        reader,
-        &header,
-        r2007_layer_only,
-    ) {
-        Ok(owner_layer) => owner_layer,
-        Err(err)
-            if allow_handle_decode_failure
-                && matches!(
-                    err.kind,
-                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
-                ) =>
-        { (None, 0) }
-        Err(err) => return Err(err),
-    };
+    let (owner_handle, layer_handle) =
+        match parse_common_entity_owner_and_layer_handle(reader, &header, r2007_layer_only) {
+            Ok(owner_layer) => owner_layer,
+            Err(err)
+                if allow_handle_decode_failure
+                    && matches!(
+                        err.kind,
+                        ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                    ) =>
+            {
+                (None, 0)
+            }
+            Err(err) => return Err(err),
+        };
 
     Ok(PointEntity {
         handle: header.handle,
@@ -229,21 +229,19 @@ fn consider_point_r14_delta(
     let Ok((location, extrusion, x_axis_angle)) = parse_point_body_no_common(&mut probe) else {
         return None;
     };
-    let Some(score) = score_point_candidate(delta, location, extrusion, x_axis_angle) else {
-        return None;
-    };
+    let score = score_point_candidate(delta, location, extrusion, x_axis_angle)?;
     if let Some(list) = debug_candidates {
         list.push((score, delta, location, extrusion, x_axis_angle));
     }
 
-            let candidate = PointEntity {
-                handle: object_handle,
-                color_index: None,
-                true_color: None,
-                owner_handle: None,
-                layer_handle: 0,
-                location,
-                x_axis_angle,
+    let candidate = PointEntity {
+        handle: object_handle,
+        color_index: None,
+        true_color: None,
+        owner_handle: None,
+        layer_handle: 0,
+        location,
+        x_axis_angle,
     };
 
     if is_high_confidence_point_candidate(delta, location, extrusion, x_axis_angle, score) {
@@ -286,7 +284,7 @@ fn score_point_candidate(
 
     let ex_norm =
         (extrusion.0 * extrusion.0 + extrusion.1 * extrusion.1 + extrusion.2 * extrusion.2).sqrt();
-    if !ex_norm.is_finite() || ex_norm < 1.0e-9 || ex_norm > 1.0e3 {
+    if !ex_norm.is_finite() || !(1.0e-9..=1.0e3).contains(&ex_norm) {
         return None;
     }
 
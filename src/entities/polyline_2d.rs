@@ -4,11 +4,11 @@ use crate::core::result::Result;
 use crate::entities::common::{
     parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r14,
     parse_common_entity_header_r2007, parse_common_entity_header_r2010,
-    parse_common_entity_header_r2013, parse_common_entity_layer_handle, read_handle_reference,
+    parse_common_entity_header_r2013, parse_common_entity_layer_handle, read_handle_references,
     CommonEntityHeader,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct PolylineFlagsInfo {
     pub closed: bool,
     pub curve_fit: bool,
@@ -35,8 +35,9 @@ impl PolylineFlagsInfo {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum PolylineCurveType {
+    #[default]
     None,
     QuadraticBSpline,
     CubicBSpline,
@@ -67,6 +68,7 @@ impl PolylineCurveType {
 }
 
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Polyline2dEntity {
     pub handle: u64,
     pub flags: u16,
@@ -77,9 +79,29 @@ pub struct Polyline2dEntity {
     pub width_end: f64,
     pub thickness: f64,
     pub elevation: f64,
+    pub extrusion: (f64, f64, f64),
     pub owned_handles: Vec<u64>,
 }
 
+impl Default for Polyline2dEntity {
+    /// Flat, with the world Z axis as extrusion direction.
+    fn default() -> Self {
+        Self {
+            handle: Default::default(),
+            flags: Default::default(),
+            curve_type: Default::default(),
+            flags_info: Default::default(),
+            curve_type_info: Default::default(),
+            width_start: Default::default(),
+            width_end: Default::default(),
+            thickness: Default::default(),
+            elevation: Default::default(),
+            owned_handles: Default::default(),
+            extrusion: (0.0, 0.0, 1.0),
+        }
+    }
+}
+
 pub fn decode_polyline_2d(reader: &mut BitReader<'_>) -> Result<Polyline2dEntity> {
     let header = parse_common_entity_header(reader)?;
     decode_polyline_2d_with_header(reader, header, false)
@@ -134,18 +156,14 @@ fn decode_polyline_2d_with_header(
     let width_end = reader.read_bd()?;
     let thickness = reader.read_bt()?;
     let elevation = reader.read_bd()?;
-    let _extrusion = reader.read_be()?;
+    let extrusion = reader.read_be()?;
     let owned_obj_count = reader.read_bl()? as usize;
     // Handles are stored in the handle stream at obj_size bit offset.
     reader.set_bit_pos(header.obj_size);
     let handles_pos = reader.get_pos();
     let owned_handles = match (|| -> Result<Vec<u64>> {
         let _common_handles = parse_common_entity_handles(reader, &header)?;
-        let mut owned_handles = Vec::with_capacity(owned_obj_count);
-        for _ in 0..owned_obj_count {
-            owned_handles.push(read_handle_reference(reader, header.handle)?);
-        }
-        Ok(owned_handles)
+        read_handle_references(reader, header.handle, owned_obj_count)
     })() {
         Ok(owned_handles) => owned_handles,
         Err(err)
@@ -172,6 +190,7 @@ fn decode_polyline_2d_with_header(
         width_end,
         thickness,
         elevation,
+        extrusion,
         owned_handles,
     })
 }
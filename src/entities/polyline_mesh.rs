@@ -4,10 +4,11 @@ use crate::core::result::Result;
 use crate::entities::common::{
     parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r2007,
     parse_common_entity_header_r2010, parse_common_entity_header_r2013,
-    parse_common_entity_layer_handle, read_handle_reference, CommonEntityHeader,
+    parse_common_entity_layer_handle, read_handle_references, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct PolylineMeshEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -70,10 +71,7 @@ fn decode_polyline_mesh_with_header(
     let handles_pos = reader.get_pos();
     let (layer_handle, owned_handles) = match parse_common_entity_handles(reader, &header) {
         Ok(common_handles) => {
-            let mut owned_handles = Vec::with_capacity(owned_obj_count);
-            for _ in 0..owned_obj_count {
-                owned_handles.push(read_handle_reference(reader, header.handle)?);
-            }
+            let owned_handles = read_handle_references(reader, header.handle, owned_obj_count)?;
             (common_handles.layer, owned_handles)
         }
         Err(err)
@@ -4,10 +4,12 @@ use crate::core::result::Result;
 use crate::entities::common::{
     parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r2007,
     parse_common_entity_header_r2010, parse_common_entity_header_r2013,
-    parse_common_entity_layer_handle, read_handle_reference, CommonEntityHeader,
+    parse_common_entity_layer_handle, read_handle_reference, read_handle_references,
+    CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct PolylinePFaceEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -20,12 +22,12 @@ pub struct PolylinePFaceEntity {
 
 pub fn decode_polyline_pface(reader: &mut BitReader<'_>) -> Result<PolylinePFaceEntity> {
     let header = parse_common_entity_header(reader)?;
-    decode_polyline_pface_with_header(reader, header, false)
+    decode_polyline_pface_with_header(reader, header, false, false)
 }
 
 pub fn decode_polyline_pface_r2007(reader: &mut BitReader<'_>) -> Result<PolylinePFaceEntity> {
     let header = parse_common_entity_header_r2007(reader)?;
-    decode_polyline_pface_with_header(reader, header, true)
+    decode_polyline_pface_with_header(reader, header, true, true)
 }
 
 pub fn decode_polyline_pface_r2010(
@@ -35,7 +37,7 @@ pub fn decode_polyline_pface_r2010(
 ) -> Result<PolylinePFaceEntity> {
     let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
     header.handle = object_handle;
-    decode_polyline_pface_with_header(reader, header, true)
+    decode_polyline_pface_with_header(reader, header, true, true)
 }
 
 pub fn decode_polyline_pface_r2013(
@@ -45,27 +47,35 @@ pub fn decode_polyline_pface_r2013(
 ) -> Result<PolylinePFaceEntity> {
     let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
     header.handle = object_handle;
-    decode_polyline_pface_with_header(reader, header, true)
+    decode_polyline_pface_with_header(reader, header, true, true)
 }
 
 fn decode_polyline_pface_with_header(
     reader: &mut BitReader<'_>,
     header: CommonEntityHeader,
     allow_handle_decode_failure: bool,
+    r2007_plus: bool,
 ) -> Result<PolylinePFaceEntity> {
     let num_vertices = reader.read_bs()?;
     let num_faces = reader.read_bs()?;
-    let owned_obj_count = reader.read_bl()? as usize;
+    // R2004 added the owned object count; up to R2000 the object data ends
+    // here and the handle stream links the first and last vertex instead.
+    // The default decoder reads both and tells them apart by the data size.
+    let owned_obj_count = if r2007_plus || reader.tell_bits() < u64::from(header.obj_size) {
+        Some(reader.read_bl()? as usize)
+    } else {
+        None
+    };
 
     // Handles are stored in the handle stream at obj_size bit offset.
     reader.set_bit_pos(header.obj_size);
     let handles_pos = reader.get_pos();
     let (layer_handle, owned_handles) = match parse_common_entity_handles(reader, &header) {
         Ok(common_handles) => {
-            let mut owned_handles = Vec::with_capacity(owned_obj_count);
-            for _ in 0..owned_obj_count {
-                owned_handles.push(read_handle_reference(reader, header.handle)?);
-            }
+            let owned_handles = match owned_obj_count {
+                Some(count) => read_handle_references(reader, header.handle, count)?,
+                None => linked_vertex_handles(reader, header.handle, num_vertices, num_faces)?,
+            };
             (common_handles.layer, owned_handles)
         }
         Err(err)
@@ -94,3 +104,92 @@ fn decode_polyline_pface_with_header(
         owned_handles,
     })
 }
+
+/// The vertex and face handles of an R2000 polyface mesh, the run from its
+/// first vertex to its last face record which are stored on consecutive
+/// handles, followed by its SEQEND.
+/// Empty when the links do not span the counted records, leaving readers to
+/// walk the records up to the SEQEND.
+fn linked_vertex_handles(
+    reader: &mut BitReader<'_>,
+    base_handle: u64,
+    num_vertices: u16,
+    num_faces: u16,
+) -> Result<Vec<u64>> {
+    let first = read_handle_reference(reader, base_handle)?;
+    let last = read_handle_reference(reader, base_handle)?;
+    let seqend = read_handle_reference(reader, base_handle)?;
+    let expected = u64::from(num_vertices) + u64::from(num_faces);
+    if first == 0 || last < first || last - first + 1 != expected {
+        return Ok(Vec::new());
+    }
+    Ok((first..=last)
+        .chain((seqend != 0).then_some(seqend))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_polyline_pface;
+    use crate::bit::BitReader;
+    use crate::writer::r2000::entities::common::{
+        encode_entity_payload_with_handles, CommonEntityEncodeInput, EntityStyleEncodeInput,
+    };
+
+    fn encode_pface(owned_count: Option<u32>) -> Vec<u8> {
+        encode_entity_payload_with_handles(
+            0x1D,
+            CommonEntityEncodeInput {
+                handle: 0x50,
+                owner_handle: 0x1F,
+                layer_handle: 0x10,
+                color_index: 256,
+                style: EntityStyleEncodeInput::default(),
+            },
+            |writer| {
+                writer.write_bs(3)?;
+                writer.write_bs(1)?;
+                if let Some(count) = owned_count {
+                    writer.write_bl(count)?;
+                }
+                Ok(())
+            },
+            |writer| match owned_count {
+                Some(_) => {
+                    for handle in 0x51..=0x55 {
+                        writer.write_h(0x03, handle)?;
+                    }
+                    Ok(())
+                }
+                None => {
+                    writer.write_h(0x04, 0x51)?;
+                    writer.write_h(0x04, 0x54)?;
+                    writer.write_h(0x03, 0x55)
+                }
+            },
+        )
+        .expect("encode polyface")
+    }
+
+    #[test]
+    fn decode_polyline_pface_follows_r2000_vertex_links() {
+        let bytes = encode_pface(None);
+        let mut reader = BitReader::new(&bytes);
+        reader.read_bs().expect("type prefix");
+        let entity = decode_polyline_pface(&mut reader).expect("decode polyface");
+
+        assert_eq!((entity.num_vertices, entity.num_faces), (3, 1));
+        assert_eq!(entity.layer_handle, 0x10);
+        assert_eq!(entity.owned_handles, [0x51, 0x52, 0x53, 0x54, 0x55]);
+    }
+
+    #[test]
+    fn decode_polyline_pface_reads_the_r2004_owned_count() {
+        let bytes = encode_pface(Some(5));
+        let mut reader = BitReader::new(&bytes);
+        reader.read_bs().expect("type prefix");
+        let entity = decode_polyline_pface(&mut reader).expect("decode polyface");
+
+        assert_eq!(entity.owned_handles, [0x51, 0x52, 0x53, 0x54, 0x55]);
+    }
+}
@@ -7,7 +7,8 @@ use crate::entities::common::{
     parse_common_entity_header_r2013, parse_common_entity_layer_handle, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct RayEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
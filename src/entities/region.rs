@@ -8,7 +8,8 @@ use crate::entities::common::{
     read_additional_entity_handles, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct RegionEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
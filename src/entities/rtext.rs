@@ -0,0 +1,180 @@
+use crate::bit::BitReader;
+use crate::core::error::ErrorKind;
+use crate::core::result::Result;
+use crate::entities::common::{
+    parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r14,
+    parse_common_entity_header_r2007, parse_common_entity_header_r2010,
+    parse_common_entity_header_r2013, read_handle_reference, CommonEntityHeader,
+};
+
+/// Express Tools remote text: text read from a file or evaluated from a
+/// DIESEL expression whenever the drawing is displayed.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct RTextEntity {
+    pub handle: u64,
+    pub color_index: Option<u16>,
+    pub true_color: Option<u32>,
+    /// `None` when the R2007+ handle stream could not be read.
+    pub layer_handle: Option<u64>,
+    pub insertion: (f64, f64, f64),
+    pub extrusion: (f64, f64, f64),
+    pub rotation: f64,
+    pub height: f64,
+    /// DXF group 70: 1 `contents` is a DIESEL expression rather than a file
+    /// path, 2 inline MTEXT codes are interpreted.
+    pub flags: u16,
+    /// File path or DIESEL expression; `None` when it lives in the R2007+
+    /// string stream.
+    pub contents: Option<String>,
+    pub style_handle: Option<u64>,
+}
+
+impl RTextEntity {
+    pub fn is_expression(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+}
+
+pub fn decode_rtext(reader: &mut BitReader<'_>) -> Result<RTextEntity> {
+    let header = parse_common_entity_header(reader)?;
+    decode_rtext_with_header(reader, header, false, false)
+}
+
+pub fn decode_rtext_r14(reader: &mut BitReader<'_>, object_handle: u64) -> Result<RTextEntity> {
+    let mut header = parse_common_entity_header_r14(reader)?;
+    if header.handle == 0 {
+        header.handle = object_handle;
+    }
+    decode_rtext_with_header(reader, header, true, false)
+}
+
+pub fn decode_rtext_r2007(reader: &mut BitReader<'_>) -> Result<RTextEntity> {
+    let header = parse_common_entity_header_r2007(reader)?;
+    decode_rtext_with_header(reader, header, false, true)
+}
+
+pub fn decode_rtext_r2010(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<RTextEntity> {
+    let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_rtext_with_header(reader, header, false, true)
+}
+
+pub fn decode_rtext_r2013(
+    reader: &mut BitReader<'_>,
+    object_data_end_bit: u32,
+    object_handle: u64,
+) -> Result<RTextEntity> {
+    let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
+    header.handle = object_handle;
+    decode_rtext_with_header(reader, header, false, true)
+}
+
+fn decode_rtext_with_header(
+    reader: &mut BitReader<'_>,
+    header: CommonEntityHeader,
+    r14: bool,
+    uses_string_stream: bool,
+) -> Result<RTextEntity> {
+    let insertion = reader.read_3bd()?;
+    let extrusion = if r14 {
+        reader.read_3bd()?
+    } else {
+        reader.read_be()?
+    };
+    let rotation = reader.read_bd()?;
+    let height = reader.read_bd()?;
+    let flags = reader.read_bs()?;
+    let contents = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+
+    reader.set_bit_pos(header.obj_size);
+    let (layer_handle, style_handle) = match parse_common_entity_handles(reader, &header) {
+        Ok(common_handles) => (
+            Some(common_handles.layer),
+            read_handle_reference(reader, header.handle).ok(),
+        ),
+        Err(err)
+            if uses_string_stream
+                && matches!(
+                    err.kind,
+                    ErrorKind::Format | ErrorKind::Decode | ErrorKind::Io
+                ) =>
+        {
+            (None, None)
+        }
+        Err(err) => return Err(err),
+    };
+
+    Ok(RTextEntity {
+        handle: header.handle,
+        color_index: header.color.index,
+        true_color: header.color.true_color,
+        layer_handle,
+        insertion,
+        extrusion,
+        rotation,
+        height,
+        flags,
+        contents,
+        style_handle,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_rtext_r2013;
+    use crate::bit::{BitReader, BitWriter};
+
+    #[test]
+    fn decode_rtext_r2013_reads_placement_and_flags() {
+        let mut writer = BitWriter::new();
+        writer.write_h(4, 0).expect("write handle");
+        writer.write_bs(0).expect("write ext size");
+        writer.write_b(0).expect("write graphic flag");
+        writer.write_bb(0).expect("write entity mode");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing flag");
+        writer.write_b(0).expect("write ds binary flag");
+        writer.write_b(1).expect("write no links");
+        writer.write_b(0).expect("write color unknown");
+        writer.write_bd(1.0).expect("write ltype scale");
+        writer.write_bb(0).expect("write ltype flags");
+        writer.write_bb(0).expect("write plotstyle flags");
+        writer.write_bb(0).expect("write material flags");
+        writer.write_rc(0).expect("write shadow flags");
+        writer.write_b(0).expect("write full visual style flag");
+        writer.write_b(0).expect("write face visual style flag");
+        writer.write_b(0).expect("write edge visual style flag");
+        writer.write_bs(0).expect("write invisibility");
+        writer.write_rc(0).expect("write line weight");
+
+        writer.write_3bd(3.0, 4.0, 0.0).expect("write insertion");
+        writer.write_be(0.0, 0.0, 1.0).expect("write extrusion");
+        writer.write_bd(0.5).expect("write rotation");
+        writer.write_bd(2.5).expect("write height");
+        writer.write_bs(1).expect("write flags");
+        let end_bit = writer.tell_bits() as u32;
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        let entity = decode_rtext_r2013(&mut reader, end_bit, 0x3C).expect("decode rtext");
+
+        assert_eq!(entity.handle, 0x3C);
+        assert_eq!(entity.insertion, (3.0, 4.0, 0.0));
+        assert_eq!(entity.extrusion, (0.0, 0.0, 1.0));
+        assert_eq!(entity.rotation, 0.5);
+        assert_eq!(entity.height, 2.5);
+        assert!(entity.is_expression());
+        assert_eq!(entity.contents, None);
+        // No handle stream follows the data, so the layer stays unread.
+        assert_eq!(entity.layer_handle, None);
+    }
+}
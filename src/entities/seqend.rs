@@ -2,7 +2,8 @@ use crate::bit::BitReader;
 use crate::core::result::Result;
 use crate::entities::common::parse_common_entity_header;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct SeqendEntity {
     pub handle: u64,
 }
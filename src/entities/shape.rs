@@ -7,7 +7,8 @@ use crate::entities::common::{
     parse_common_entity_layer_handle, read_handle_reference, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct ShapeEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
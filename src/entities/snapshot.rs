@@ -0,0 +1,115 @@
+//! Versioning for the decoded entity structs.
+//!
+//! Entity structs are snapshots of what a decoder read from one record. They
+//! are `#[non_exhaustive]`, so new fields can be added in a minor release:
+//! outside this crate they cannot be built with a struct literal or matched
+//! without `..`. Start from [`Default`] and assign the fields you need
+//! instead:
+//!
+//! ```
+//! use _core::entities::{EntitySnapshot, LineEntity};
+//!
+//! let mut line = LineEntity::default();
+//! line.start = (0.0, 0.0, 0.0);
+//! line.end = (10.0, 0.0, 0.0);
+//! assert_eq!(line.extrusion, (0.0, 0.0, 1.0));
+//! let LineEntity { start, end, .. } = line;
+//! assert_eq!(end.0 - start.0, 10.0);
+//! assert_eq!(LineEntity::SNAPSHOT_VERSION, 2);
+//! ```
+//!
+//! [`EntitySnapshot::SNAPSHOT_VERSION`] is bumped whenever a struct's fields
+//! change, including additions, so code that persists snapshots can tell
+//! layouts apart. A field is never removed or retyped in place: the old field
+//! stays, marked `#[deprecated]`, for at least one minor release next to
+//! its replacement, and the decoder fills both.
+
+use super::{
+    arc, arc_aligned_text, attrib, body, circle, dim_ang2ln, dim_ang3pt, dim_arc, dim_large_radial,
+    dim_linear, dim_ordinate, ellipse, face3d, hatch, insert, leader, light, line,
+    long_transaction, lwpolyline, mesh, minsert, mleader, mline, mtext, oleframe, point,
+    polyline_2d, polyline_3d, polyline_mesh, polyline_pface, ray, region, rtext, seqend, shape,
+    solid, solid3d, spline, text, tolerance, trace, vertex_2d, vertex_3d, vertex_pface_face,
+    viewport, xline,
+};
+
+/// Layout version of a decoded entity struct.
+pub trait EntitySnapshot {
+    /// Starts at 1 and increases each time the struct's fields change.
+    const SNAPSHOT_VERSION: u32;
+}
+
+macro_rules! entity_snapshots {
+    ($($ty:ty => $version:expr,)*) => {
+        $(
+            impl EntitySnapshot for $ty {
+                const SNAPSHOT_VERSION: u32 = $version;
+            }
+        )*
+    };
+}
+
+// Versions past 1, with what changed in each. Version 1 is the layout a
+// struct had in the last release before it changed. `HatchEntity` changed
+// before this trait existed, so 2 is the first version it ever reported.
+//
+// - `HatchEntity` 2: added `pattern`, the pattern definition and its lines.
+// - `ArcEntity`, `CircleEntity` and `LineEntity` 2: added `thickness` and
+//   `extrusion`.
+// - `LwPolylineEntity` 2: added `elevation`, `thickness` and `extrusion`.
+// - `Polyline2dEntity` 2: added `extrusion`.
+entity_snapshots! {
+    arc::ArcEntity => 2,
+    arc_aligned_text::ArcAlignedTextEntity => 1,
+    attrib::AttribEntity => 1,
+    body::BodyEntity => 1,
+    circle::CircleEntity => 2,
+    dim_ang2ln::DimAng2LnEntity => 1,
+    dim_ang3pt::DimAng3PtEntity => 1,
+    dim_arc::DimArcEntity => 1,
+    dim_large_radial::DimLargeRadialEntity => 1,
+    dim_linear::DimensionCommonData => 1,
+    dim_linear::DimLinearEntity => 1,
+    dim_ordinate::DimOrdinateEntity => 1,
+    ellipse::EllipseEntity => 1,
+    face3d::Face3dEntity => 1,
+    hatch::HatchPath => 1,
+    hatch::HatchPatternLine => 1,
+    hatch::HatchPattern => 1,
+    hatch::HatchEntity => 2,
+    insert::InsertEntity => 1,
+    leader::LeaderEntity => 1,
+    light::LightEntity => 1,
+    line::LineEntity => 2,
+    long_transaction::LongTransactionEntity => 1,
+    lwpolyline::LwPolylineEntity => 2,
+    mesh::MeshEntity => 1,
+    minsert::MInsertEntity => 1,
+    mleader::MLeaderRoot => 1,
+    mleader::MLeaderEntity => 1,
+    mline::MLineVertex => 1,
+    mline::MLineEntity => 1,
+    mtext::MTextEntity => 1,
+    oleframe::OleFrameEntity => 1,
+    point::PointEntity => 1,
+    polyline_2d::Polyline2dEntity => 2,
+    polyline_3d::Polyline3dEntity => 1,
+    polyline_mesh::PolylineMeshEntity => 1,
+    polyline_pface::PolylinePFaceEntity => 1,
+    ray::RayEntity => 1,
+    region::RegionEntity => 1,
+    rtext::RTextEntity => 1,
+    seqend::SeqendEntity => 1,
+    shape::ShapeEntity => 1,
+    solid::SolidEntity => 1,
+    solid3d::Solid3dEntity => 1,
+    spline::SplineEntity => 1,
+    text::TextEntity => 1,
+    tolerance::ToleranceEntity => 1,
+    trace::TraceEntity => 1,
+    vertex_2d::Vertex2dEntity => 1,
+    vertex_3d::Vertex3dEntity => 1,
+    vertex_pface_face::VertexPFaceFaceEntity => 1,
+    viewport::ViewportEntity => 1,
+    xline::XLineEntity => 1,
+}
@@ -0,0 +1,154 @@
+//! Pluggable triangulation of ACIS solids.
+//!
+//! ezdwg does not evaluate ACIS geometry itself. A [`SolidTessellator`]
+//! backed by an external modeler (ACIS, Open CASCADE, ...) receives the raw
+//! SAT/SAB data of each 3DSOLID, REGION or BODY and returns a triangle mesh.
+
+use crate::container::AcDsRecord;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcisFormat {
+    /// Text `.sat` data.
+    Sat,
+    /// Binary `.sab` data.
+    Sab,
+}
+
+impl AcisFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AcisFormat::Sat => "sat",
+            AcisFormat::Sab => "sab",
+        }
+    }
+}
+
+/// Modeler data of one solid, as stored in the drawing.
+#[derive(Debug, Clone, Copy)]
+pub struct AcisSolid<'a> {
+    /// Handle of the owning 3DSOLID, REGION or BODY entity.
+    pub handle: u64,
+    pub format: AcisFormat,
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriangleMesh {
+    pub vertices: Vec<(f64, f64, f64)>,
+    /// 0-based indices into `vertices`.
+    pub triangles: Vec<[u32; 3]>,
+}
+
+impl TriangleMesh {
+    pub fn validate(&self) -> Result<()> {
+        let count = self.vertices.len();
+        if let Some(index) = self
+            .triangles
+            .iter()
+            .flatten()
+            .find(|index| **index as usize >= count)
+        {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!("triangle index {index} out of range for {count} vertices"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub trait SolidTessellator {
+    /// Triangulates one solid. `Ok(None)` skips solids the tessellator
+    /// cannot handle; errors abort the whole run.
+    fn tessellate(&self, solid: &AcisSolid<'_>) -> Result<Option<TriangleMesh>>;
+}
+
+impl<F> SolidTessellator for F
+where
+    F: Fn(&AcisSolid<'_>) -> Result<Option<TriangleMesh>>,
+{
+    fn tessellate(&self, solid: &AcisSolid<'_>) -> Result<Option<TriangleMesh>> {
+        self(solid)
+    }
+}
+
+/// Feeds every ACIS record to `tessellator` and collects the meshes it
+/// returns, keyed by the owning entity handle.
+pub fn tessellate_acds_records(
+    records: &[AcDsRecord],
+    tessellator: &dyn SolidTessellator,
+) -> Result<Vec<(u64, TriangleMesh)>> {
+    let mut meshes = Vec::new();
+    for record in records {
+        let format = match record.acis_format() {
+            Some("sat") => AcisFormat::Sat,
+            Some("sab") => AcisFormat::Sab,
+            _ => continue,
+        };
+        let solid = AcisSolid {
+            handle: record.handle,
+            format,
+            data: &record.data,
+        };
+        if let Some(mesh) = tessellator.tessellate(&solid)? {
+            mesh.validate()?;
+            meshes.push((record.handle, mesh));
+        }
+    }
+    Ok(meshes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tessellate_acds_records, AcisFormat, AcisSolid, TriangleMesh};
+    use crate::container::AcDsRecord;
+    use crate::core::result::Result;
+
+    fn record(handle: u64, data: &[u8]) -> AcDsRecord {
+        AcDsRecord {
+            handle,
+            schema_index: 0,
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn passes_acis_records_to_the_tessellator() {
+        let records = [
+            record(0x40, b"ACIS BinaryFile...."),
+            record(0x41, b"not modeler data"),
+            record(0x42, b"21800 0 1 0\n"),
+        ];
+        let tessellator = |solid: &AcisSolid<'_>| -> Result<Option<TriangleMesh>> {
+            if solid.format == AcisFormat::Sat {
+                return Ok(None);
+            }
+            Ok(Some(TriangleMesh {
+                vertices: vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+                triangles: vec![[0, 1, 2]],
+            }))
+        };
+
+        let meshes = tessellate_acds_records(&records, &tessellator).expect("tessellate");
+
+        assert_eq!(meshes.len(), 1);
+        assert_eq!(meshes[0].0, 0x40);
+        assert_eq!(meshes[0].1.triangles, vec![[0, 1, 2]]);
+    }
+
+    #[test]
+    fn rejects_out_of_range_triangles() {
+        let records = [record(0x40, b"ACIS BinaryFile")];
+        let tessellator = |_: &AcisSolid<'_>| -> Result<Option<TriangleMesh>> {
+            Ok(Some(TriangleMesh {
+                vertices: vec![(0.0, 0.0, 0.0)],
+                triangles: vec![[0, 0, 3]],
+            }))
+        };
+
+        let err = tessellate_acds_records(&records, &tessellator).expect_err("invalid mesh");
+        assert!(err.to_string().contains("out of range"), "{err}");
+    }
+}
@@ -10,7 +10,8 @@ use crate::entities::common::{
 type Point3 = (f64, f64, f64);
 type Knots = (f64, f64, f64, f64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct SplineEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -149,12 +150,27 @@ fn decode_spline_with_header(
         }
     }
 
-    let Some(data) = parsed else {
+    let Some(mut data) = parsed else {
         reader.set_pos(data_start.0, data_start.1);
         return Err(last_error
             .unwrap_or_else(|| DwgError::new(ErrorKind::Decode, "failed to decode SPLINE")));
     };
 
+    // Fit-point-only records have no control frame; derive the interpolating
+    // B-spline so callers always get evaluable knots and control points.
+    if data.control_points.is_empty() && data.fit_points.len() >= 2 {
+        if let Ok((degree, knots, control_points)) = interpolate_fit_points(
+            &data.fit_points,
+            data.degree,
+            data.start_tangent,
+            data.end_tangent,
+        ) {
+            data.degree = degree;
+            data.knots = knots;
+            data.control_points = control_points;
+        }
+    }
+
     // Handles are stored in the handle stream at obj_size bit offset.
     reader.set_bit_pos(header.obj_size);
     let layer_handle = match if r2007_layer_only {
@@ -319,6 +335,222 @@ fn bounded_count(raw: u32, label: &str) -> Result<usize> {
     Ok(count)
 }
 
+/// Builds a clamped, non-rational B-spline that passes through `fit_points`.
+///
+/// Parameters follow chord length and knots use averaging. Non-zero end
+/// tangents add one control point each and are scaled to the total chord
+/// length, since DWG stores them as directions. The degree is raised to 1
+/// and lowered to what the control points allow. Returns
+/// `(degree, knots, control_points)` with the degree actually used.
+pub fn interpolate_fit_points(
+    fit_points: &[Point3],
+    degree: u32,
+    start_tangent: Option<Point3>,
+    end_tangent: Option<Point3>,
+) -> Result<(u32, Vec<f64>, Vec<Point3>)> {
+    let [first_point, .., last_point] = fit_points else {
+        return Err(DwgError::new(
+            ErrorKind::Decode,
+            "spline interpolation needs at least two fit points",
+        ));
+    };
+    let last = fit_points.len() - 1;
+    let chord_lengths: Vec<f64> = fit_points
+        .iter()
+        .zip(fit_points.iter().skip(1))
+        .map(|(start, end)| distance(*start, *end))
+        .collect();
+    let total_length: f64 = chord_lengths.iter().sum();
+    if !total_length.is_finite() || total_length < 1e-12 {
+        return Err(DwgError::new(
+            ErrorKind::Decode,
+            "spline fit points are degenerate",
+        ));
+    }
+    let mut params = Vec::with_capacity(fit_points.len());
+    params.push(0.0);
+    let mut accumulated = 0.0;
+    for length in chord_lengths.iter().take(last - 1) {
+        accumulated += length;
+        params.push(accumulated / total_length);
+    }
+    params.push(1.0);
+
+    let start_tangent = start_tangent.and_then(|tangent| scaled_tangent(tangent, total_length));
+    let end_tangent = end_tangent.and_then(|tangent| scaled_tangent(tangent, total_length));
+    let num_ctrl = fit_points.len()
+        + usize::from(start_tangent.is_some())
+        + usize::from(end_tangent.is_some());
+    let degree = (degree.max(1) as usize).min(num_ctrl - 1);
+
+    let mut averaged_params = Vec::with_capacity(num_ctrl);
+    if start_tangent.is_some() {
+        averaged_params.push(0.0);
+    }
+    averaged_params.extend_from_slice(&params);
+    if end_tangent.is_some() {
+        averaged_params.push(1.0);
+    }
+    let mut knots = vec![0.0; degree + 1];
+    for j in 1..num_ctrl - degree {
+        let sum: f64 = averaged_params.iter().skip(j).take(degree).sum();
+        knots.push(sum / degree as f64);
+    }
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+
+    let scale = degree as f64;
+    let mut rows: Vec<(usize, Vec<f64>)> = Vec::with_capacity(num_ctrl);
+    let mut rhs: Vec<Point3> = Vec::with_capacity(num_ctrl);
+    rows.push((0, vec![1.0]));
+    rhs.push(*first_point);
+    if let Some(tangent) = start_tangent {
+        let factor = scale / at(&knots, degree + 1)?;
+        rows.push((0, vec![-factor, factor]));
+        rhs.push(tangent);
+    }
+    for (point, &param) in fit_points.iter().zip(&params).take(last).skip(1) {
+        let span = find_knot_span(&knots, num_ctrl, degree, param)?;
+        rows.push((span - degree, basis_functions(&knots, span, degree, param)?));
+        rhs.push(*point);
+    }
+    if let Some(tangent) = end_tangent {
+        let factor = scale / (1.0 - at(&knots, num_ctrl - 1)?);
+        rows.push((num_ctrl - 2, vec![-factor, factor]));
+        rhs.push(tangent);
+    }
+    rows.push((num_ctrl - 1, vec![1.0]));
+    rhs.push(*last_point);
+
+    let control_points = solve_banded(&rows, rhs)?;
+    Ok((degree as u32, knots, control_points))
+}
+
+fn scaled_tangent(tangent: Point3, length: f64) -> Option<Point3> {
+    let norm = distance(tangent, (0.0, 0.0, 0.0));
+    if !norm.is_finite() || norm < 1e-12 {
+        return None;
+    }
+    let factor = length / norm;
+    Some((tangent.0 * factor, tangent.1 * factor, tangent.2 * factor))
+}
+
+fn find_knot_span(knots: &[f64], num_ctrl: usize, degree: usize, param: f64) -> Result<usize> {
+    if param >= at(knots, num_ctrl)? {
+        return Ok(num_ctrl - 1);
+    }
+    let mut low = degree;
+    let mut high = num_ctrl;
+    while high - low > 1 {
+        let mid = (low + high) / 2;
+        if param < at(knots, mid)? {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    Ok(low)
+}
+
+fn basis_functions(knots: &[f64], span: usize, degree: usize, param: f64) -> Result<Vec<f64>> {
+    let mut values = vec![0.0; degree + 1];
+    let mut left = vec![0.0; degree + 1];
+    let mut right = vec![0.0; degree + 1];
+    *at_mut(&mut values, 0)? = 1.0;
+    for j in 1..=degree {
+        *at_mut(&mut left, j)? = param - at(knots, span + 1 - j)?;
+        *at_mut(&mut right, j)? = at(knots, span + j)? - param;
+        let mut saved = 0.0;
+        for r in 0..j {
+            let denom = at(&right, r + 1)? + at(&left, j - r)?;
+            let value = at_mut(&mut values, r)?;
+            let temp = if denom.abs() < 1e-300 {
+                0.0
+            } else {
+                *value / denom
+            };
+            *value = saved + at(&right, r + 1)? * temp;
+            saved = at(&left, j - r)? * temp;
+        }
+        *at_mut(&mut values, j)? = saved;
+    }
+    Ok(values)
+}
+
+/// Gaussian elimination without pivoting, restricted to the band spanned by
+/// `rows`. Collocation matrices of B-splines are totally positive, so the
+/// pivots stay well away from zero for valid parameterizations.
+fn solve_banded(rows: &[(usize, Vec<f64>)], mut rhs: Vec<Point3>) -> Result<Vec<Point3>> {
+    let size = rows.len();
+    let mut lower = 0usize;
+    let mut upper = 0usize;
+    for (row, (first, coefficients)) in rows.iter().enumerate() {
+        lower = lower.max(row.saturating_sub(*first));
+        upper = upper.max((first + coefficients.len() - 1).saturating_sub(row));
+    }
+    // Without row exchanges the factors stay inside the original band.
+    let width = lower + upper + 1;
+    let mut band = vec![0.0; size * width];
+    let index = |row: usize, col: usize| row * width + col + lower - row;
+    for (row, (first, coefficients)) in rows.iter().enumerate() {
+        for (offset, value) in coefficients.iter().enumerate() {
+            *at_mut(&mut band, index(row, first + offset))? = *value;
+        }
+    }
+
+    for pivot_row in 0..size {
+        let pivot = at(&band, index(pivot_row, pivot_row))?;
+        if !pivot.is_finite() || pivot.abs() < 1e-14 {
+            return Err(DwgError::new(
+                ErrorKind::Decode,
+                "spline interpolation matrix is singular",
+            ));
+        }
+        let last_col = (pivot_row + upper).min(size - 1);
+        for row in pivot_row + 1..=(pivot_row + lower).min(size - 1) {
+            let factor = at(&band, index(row, pivot_row))? / pivot;
+            if factor == 0.0 {
+                continue;
+            }
+            for col in pivot_row..=last_col {
+                let update = factor * at(&band, index(pivot_row, col))?;
+                *at_mut(&mut band, index(row, col))? -= update;
+            }
+            let pivot_rhs = at(&rhs, pivot_row)?;
+            let target = at_mut(&mut rhs, row)?;
+            target.0 -= factor * pivot_rhs.0;
+            target.1 -= factor * pivot_rhs.1;
+            target.2 -= factor * pivot_rhs.2;
+        }
+    }
+
+    let mut solution = vec![(0.0, 0.0, 0.0); size];
+    for row in (0..size).rev() {
+        let mut value = at(&rhs, row)?;
+        for col in row + 1..=(row + upper).min(size - 1) {
+            let coefficient = at(&band, index(row, col))?;
+            let known = at(&solution, col)?;
+            value.0 -= coefficient * known.0;
+            value.1 -= coefficient * known.1;
+            value.2 -= coefficient * known.2;
+        }
+        let pivot = at(&band, index(row, row))?;
+        *at_mut(&mut solution, row)? = (value.0 / pivot, value.1 / pivot, value.2 / pivot);
+    }
+    Ok(solution)
+}
+
+fn at<T: Copy>(values: &[T], index: usize) -> Result<T> {
+    values.get(index).copied().ok_or_else(index_out_of_range)
+}
+
+fn at_mut<T>(values: &mut [T], index: usize) -> Result<&mut T> {
+    values.get_mut(index).ok_or_else(index_out_of_range)
+}
+
+fn index_out_of_range() -> DwgError {
+    DwgError::new(ErrorKind::Decode, "spline index out of range")
+}
+
 pub fn catmull_rom_spline(
     points: &[Point3],
     closed: bool,
@@ -337,20 +569,16 @@ pub fn catmull_rom_spline(
 
     for i in 0..segment_count {
         let p0 = if closed {
-            points[(i + n - 1) % n]
-        } else if i == 0 {
-            points[0]
+            at(points, (i + n - 1) % n)?
         } else {
-            points[i - 1]
+            at(points, i.saturating_sub(1))?
         };
-        let p1 = points[i % n];
-        let p2 = points[(i + 1) % n];
+        let p1 = at(points, i % n)?;
+        let p2 = at(points, (i + 1) % n)?;
         let p3 = if closed {
-            points[(i + 2) % n]
-        } else if i + 2 < n {
-            points[i + 2]
+            at(points, (i + 2) % n)?
         } else {
-            points[n - 1]
+            at(points, (i + 2).min(n - 1))?
         };
 
         let t0 = 0.0;
@@ -370,9 +598,7 @@ pub fn catmull_rom_spline(
         }
     }
 
-    if closed && !out.is_empty() {
-        let first = out[0];
-        let last = *out.last().unwrap();
+    if let (true, Some(&first), Some(&last)) = (closed, out.first(), out.last()) {
         if !points_equal(first, last) {
             out.push(first);
         }
@@ -423,3 +649,163 @@ fn points_equal(a: Point3, b: Point3) -> bool {
     const EPS: f64 = 1e-9;
     (a.0 - b.0).abs() < EPS && (a.1 - b.1).abs() < EPS && (a.2 - b.2).abs() < EPS
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{basis_functions, decode_spline, find_knot_span, interpolate_fit_points, Point3};
+    use crate::bit::BitReader;
+    use crate::writer::r2000::entities::common::{
+        encode_entity_payload, CommonEntityEncodeInput, EntityStyleEncodeInput,
+    };
+
+    fn evaluate(knots: &[f64], control_points: &[Point3], degree: usize, param: f64) -> Point3 {
+        let span = find_knot_span(knots, control_points.len(), degree, param).unwrap();
+        let basis = basis_functions(knots, span, degree, param).unwrap();
+        let mut point = (0.0, 0.0, 0.0);
+        for (offset, weight) in basis.iter().enumerate() {
+            let ctrl = control_points[span - degree + offset];
+            point.0 += weight * ctrl.0;
+            point.1 += weight * ctrl.1;
+            point.2 += weight * ctrl.2;
+        }
+        point
+    }
+
+    fn assert_close(actual: Point3, expected: Point3) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-9,
+            "{actual:?} != {expected:?}"
+        );
+        assert!(
+            (actual.1 - expected.1).abs() < 1e-9,
+            "{actual:?} != {expected:?}"
+        );
+        assert!(
+            (actual.2 - expected.2).abs() < 1e-9,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn chord_params(points: &[Point3]) -> Vec<f64> {
+        let lengths: Vec<f64> = points
+            .windows(2)
+            .map(|pair| super::distance(pair[0], pair[1]))
+            .collect();
+        let total: f64 = lengths.iter().sum();
+        let mut params = vec![0.0];
+        let mut accumulated = 0.0;
+        for length in lengths {
+            accumulated += length;
+            params.push(accumulated / total);
+        }
+        params
+    }
+
+    #[test]
+    fn interpolate_fit_points_passes_through_every_fit_point() {
+        let fit_points = [
+            (0.0, 0.0, 0.0),
+            (1.0, 2.0, 0.0),
+            (3.0, 3.0, 0.0),
+            (4.0, 1.0, 0.0),
+            (6.0, 0.0, 0.0),
+        ];
+
+        let (degree, knots, control_points) =
+            interpolate_fit_points(&fit_points, 3, None, None).expect("interpolate");
+
+        assert_eq!(degree, 3);
+        assert_eq!(control_points.len(), fit_points.len());
+        assert_eq!(knots.len(), control_points.len() + 4);
+        for (point, param) in fit_points.iter().zip(chord_params(&fit_points)) {
+            assert_close(evaluate(&knots, &control_points, 3, param), *point);
+        }
+    }
+
+    #[test]
+    fn interpolate_fit_points_honors_end_tangents() {
+        let fit_points = [(0.0, 0.0, 0.0), (2.0, 1.0, 0.0), (4.0, 0.0, 0.0)];
+
+        let (_degree, knots, control_points) = interpolate_fit_points(
+            &fit_points,
+            3,
+            Some((0.0, 1.0, 0.0)),
+            Some((0.0, -1.0, 0.0)),
+        )
+        .expect("interpolate");
+
+        assert_eq!(control_points.len(), 5);
+        for (point, param) in fit_points.iter().zip(chord_params(&fit_points)) {
+            assert_close(evaluate(&knots, &control_points, 3, param), *point);
+        }
+        // The first and last control legs follow the requested directions.
+        let start_leg = (
+            control_points[1].0 - control_points[0].0,
+            control_points[1].1 - control_points[0].1,
+        );
+        let end_leg = (
+            control_points[4].0 - control_points[3].0,
+            control_points[4].1 - control_points[3].1,
+        );
+        assert!(start_leg.0.abs() < 1e-9 && start_leg.1 > 0.0);
+        assert!(end_leg.0.abs() < 1e-9 && end_leg.1 < 0.0);
+    }
+
+    #[test]
+    fn interpolate_fit_points_lowers_degree_for_short_fit_lists() {
+        let fit_points = [(0.0, 0.0, 0.0), (1.0, 1.0, 0.0)];
+
+        let (degree, knots, control_points) =
+            interpolate_fit_points(&fit_points, 3, None, None).expect("interpolate");
+
+        assert_eq!(degree, 1);
+        assert_eq!(knots, vec![0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(control_points, fit_points.to_vec());
+    }
+
+    #[test]
+    fn decode_spline_reports_the_degree_used_for_fit_points() {
+        let fit_points = [(0.0, 0.0, 0.0), (2.0, 1.0, 0.0), (4.0, 0.0, 0.0)];
+        let bytes = encode_entity_payload(
+            0x24,
+            CommonEntityEncodeInput {
+                handle: 0x40,
+                owner_handle: 0x1F,
+                layer_handle: 0x10,
+                color_index: 256,
+                style: EntityStyleEncodeInput::default(),
+            },
+            |writer| {
+                writer.write_bl(2)?;
+                writer.write_bl(3)?;
+                writer.write_bd(0.0)?;
+                writer.write_3bd(0.0, 0.0, 0.0)?;
+                writer.write_3bd(0.0, 0.0, 0.0)?;
+                writer.write_bl(fit_points.len() as u32)?;
+                for (x, y, z) in fit_points {
+                    writer.write_3bd(x, y, z)?;
+                }
+                Ok(())
+            },
+        )
+        .expect("encode spline");
+        let mut reader = BitReader::new(&bytes);
+        reader.read_bs().expect("type prefix");
+
+        let spline = decode_spline(&mut reader).expect("decode spline");
+
+        // Three fit points hold a quadratic at most.
+        assert_eq!(spline.degree, 2);
+        assert_eq!(spline.control_points.len(), 3);
+        assert_eq!(
+            spline.knots.len(),
+            spline.control_points.len() + spline.degree as usize + 1
+        );
+        for (point, param) in fit_points.iter().zip(chord_params(&fit_points)) {
+            assert_close(
+                evaluate(&spline.knots, &spline.control_points, 2, param),
+                *point,
+            );
+        }
+    }
+}
@@ -8,7 +8,8 @@ use crate::entities::common::{
     CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct TextEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
@@ -203,7 +204,7 @@ pub(crate) fn decode_r21_text_tail(
                 let mut vertical_alignment = 0u16;
 
                 let mut parse_ok = true;
-                for field in &order[..split_index] {
+                for field in order.iter().take(split_index) {
                     let value = match candidate_reader.read_bs() {
                         Ok(value) => value,
                         Err(_) => {
@@ -228,7 +229,7 @@ pub(crate) fn decode_r21_text_tail(
                     Err(_) => continue,
                 };
 
-                for field in &order[split_index..] {
+                for field in order.iter().skip(split_index) {
                     let value = match candidate_reader.read_bs() {
                         Ok(value) => value,
                         Err(_) => {
@@ -7,13 +7,41 @@ use crate::entities::common::{
     parse_common_entity_layer_handle, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+/// A face record of a polyface mesh.
+///
+/// Indices are 1-based vertex numbers; a negative index hides the edge that
+/// starts at that vertex and `0` marks an unused corner. The face color is
+/// the record's own entity color.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct VertexPFaceFaceEntity {
     pub handle: u64,
-    pub index1: u16,
-    pub index2: u16,
-    pub index3: u16,
-    pub index4: u16,
+    pub index1: i16,
+    pub index2: i16,
+    pub index3: i16,
+    pub index4: i16,
+    pub color_index: Option<u16>,
+    pub true_color: Option<u32>,
+}
+
+impl VertexPFaceFaceEntity {
+    pub fn indices(&self) -> [i16; 4] {
+        [self.index1, self.index2, self.index3, self.index4]
+    }
+
+    /// Vertex numbers with the visibility sign removed.
+    pub fn vertex_indices(&self) -> [u16; 4] {
+        self.indices().map(i16::unsigned_abs)
+    }
+
+    /// Bit `n` is set when edge `n + 1` is invisible, as in 3DFACE flags.
+    pub fn invisible_edge_flags(&self) -> u16 {
+        self.indices()
+            .iter()
+            .enumerate()
+            .filter(|(_, index)| **index < 0)
+            .fold(0, |flags, (edge, _)| flags | (1 << edge))
+    }
 }
 
 pub fn decode_vertex_pface_face(reader: &mut BitReader<'_>) -> Result<VertexPFaceFaceEntity> {
@@ -52,10 +80,10 @@ fn decode_vertex_pface_face_with_header(
     allow_handle_decode_failure: bool,
     r2007_layer_only: bool,
 ) -> Result<VertexPFaceFaceEntity> {
-    let index1 = reader.read_bs()?;
-    let index2 = reader.read_bs()?;
-    let index3 = reader.read_bs()?;
-    let index4 = reader.read_bs()?;
+    let index1 = reader.read_bs()? as i16;
+    let index2 = reader.read_bs()? as i16;
+    let index3 = reader.read_bs()? as i16;
+    let index4 = reader.read_bs()? as i16;
 
     // Handles are stored in the handle stream at obj_size bit offset.
     reader.set_bit_pos(header.obj_size);
@@ -80,5 +108,28 @@ fn decode_vertex_pface_face_with_header(
         index2,
         index3,
         index4,
+        color_index: header.color.index,
+        true_color: header.color.true_color,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::VertexPFaceFaceEntity;
+
+    #[test]
+    fn negative_indices_mark_invisible_edges() {
+        let face = VertexPFaceFaceEntity {
+            handle: 0x40,
+            index1: 1,
+            index2: -2,
+            index3: 3,
+            index4: -4,
+            color_index: Some(1),
+            true_color: None,
+        };
+
+        assert_eq!(face.vertex_indices(), [1, 2, 3, 4]);
+        assert_eq!(face.invisible_edge_flags(), 0b1010);
+    }
+}
@@ -1,22 +1,36 @@
-use crate::bit::BitReader;
+use crate::bit::{BitReader, Endian};
 use crate::core::result::Result;
 use crate::entities::common::{
     parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r14,
     parse_common_entity_header_r2007, parse_common_entity_header_r2010,
-    parse_common_entity_header_r2013, CommonEntityHeader,
+    parse_common_entity_header_r2013, read_handle_reference, CommonEntityHeader,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct ViewportEntity {
     pub handle: u64,
     pub color_index: Option<u16>,
     pub true_color: Option<u32>,
     pub layer_handle: u64,
+    /// Layers frozen in this viewport only. Always empty before R2000.
+    pub frozen_layer_handles: Vec<u64>,
 }
 
+/// Layout of the VIEWPORT body fields in front of the frozen layer count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViewportBody {
+    R14,
+    R2000,
+    /// R2007 and later add the grid major line count.
+    R2007,
+}
+
+const MAX_FROZEN_LAYERS: u32 = 1 << 16;
+
 pub fn decode_viewport(reader: &mut BitReader<'_>) -> Result<ViewportEntity> {
     let header = parse_common_entity_header(reader)?;
-    decode_viewport_with_header(reader, header)
+    decode_viewport_with_header(reader, header, ViewportBody::R2000)
 }
 
 pub fn decode_viewport_r14(
@@ -27,12 +41,12 @@ pub fn decode_viewport_r14(
     if header.handle == 0 {
         header.handle = object_handle;
     }
-    decode_viewport_with_header(reader, header)
+    decode_viewport_with_header(reader, header, ViewportBody::R14)
 }
 
 pub fn decode_viewport_r2007(reader: &mut BitReader<'_>) -> Result<ViewportEntity> {
     let header = parse_common_entity_header_r2007(reader)?;
-    decode_viewport_with_header(reader, header)
+    decode_viewport_with_header(reader, header, ViewportBody::R2007)
 }
 
 pub fn decode_viewport_r2010(
@@ -42,7 +56,7 @@ pub fn decode_viewport_r2010(
 ) -> Result<ViewportEntity> {
     let mut header = parse_common_entity_header_r2010(reader, object_data_end_bit)?;
     header.handle = object_handle;
-    decode_viewport_with_header(reader, header)
+    decode_viewport_with_header(reader, header, ViewportBody::R2007)
 }
 
 pub fn decode_viewport_r2013(
@@ -52,22 +66,63 @@ pub fn decode_viewport_r2013(
 ) -> Result<ViewportEntity> {
     let mut header = parse_common_entity_header_r2013(reader, object_data_end_bit)?;
     header.handle = object_handle;
-    decode_viewport_with_header(reader, header)
+    decode_viewport_with_header(reader, header, ViewportBody::R2007)
 }
 
 fn decode_viewport_with_header(
     reader: &mut BitReader<'_>,
     header: CommonEntityHeader,
+    body: ViewportBody,
 ) -> Result<ViewportEntity> {
-    // VIEWPORT body payload is still TODO. We can still expose style/layer metadata by
-    // jumping to the handle stream and decoding common handles.
+    // Only the body fields up to the frozen layer count are decoded; the
+    // rest of the view settings are skipped by jumping to the handle stream.
+    let frozen_layer_count = match body {
+        ViewportBody::R14 => 0,
+        _ => read_frozen_layer_count(reader, body)
+            .ok()
+            .filter(|count| *count <= MAX_FROZEN_LAYERS)
+            .unwrap_or(0),
+    };
     reader.set_bit_pos(header.obj_size);
     let common_handles = parse_common_entity_handles(reader, &header)?;
+    let mut frozen_layer_handles = Vec::with_capacity(frozen_layer_count as usize);
+    for _ in 0..frozen_layer_count {
+        match read_handle_reference(reader, header.handle) {
+            Ok(handle) => frozen_layer_handles.push(handle),
+            Err(_) => {
+                frozen_layer_handles.clear();
+                break;
+            }
+        }
+    }
 
     Ok(ViewportEntity {
         handle: header.handle,
         color_index: header.color.index,
         true_color: header.color.true_color,
         layer_handle: common_handles.layer,
+        frozen_layer_handles,
     })
 }
+
+fn read_frozen_layer_count(reader: &mut BitReader<'_>, body: ViewportBody) -> Result<u32> {
+    let _center = reader.read_3bd()?;
+    let _width = reader.read_bd()?;
+    let _height = reader.read_bd()?;
+    let _view_target = reader.read_3bd()?;
+    let _view_direction = reader.read_3bd()?;
+    // Twist angle, view height, lens length, front and back clip z and
+    // snap angle.
+    for _ in 0..6 {
+        reader.read_bd()?;
+    }
+    // View center, snap base, snap spacing and grid spacing.
+    for _ in 0..8 {
+        reader.read_rd(Endian::Little)?;
+    }
+    let _circle_zoom = reader.read_bs()?;
+    if body == ViewportBody::R2007 {
+        let _grid_major = reader.read_bs()?;
+    }
+    reader.read_bl()
+}
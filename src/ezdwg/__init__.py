@@ -1,21 +1,197 @@
 from typing import Sequence
 
-from .convert import ConvertResult, WriteResult, to_dwg, to_dxf
-from .document import Document, Layout, read
+from .block_nesting import BlockReferenceError, check_block_references
+from .clip import clip_entities
+from .colors import AciPalette
+from .convert import (
+    ConvertResult,
+    EntityUpdateResult,
+    PurgeResult,
+    WriteResult,
+    apply_incremental_update,
+    move_entities_to_layer,
+    purge,
+    rename_layer,
+    redefine_block,
+    strip_layers,
+    replace_text,
+    set_layer_color,
+    to_dwg,
+    transform_drawing,
+    update_entities,
+    to_dxf,
+)
+from .display_list import DisplayList, DrawCommand, build_display_list
+from .document import (
+    Document,
+    DrawingTimes,
+    Layout,
+    LayoutInfo,
+    PlotSettings,
+    ScaleInfo,
+    ViewInfo,
+    ViewportLayerOverrides,
+    read,
+)
+from .dimension_render import DimStyle, DimensionGraphics, regenerate_dimension
+from .hatch_fill import HatchFill, HatchPatternLine, fill_hatch
+from .drawing_index import DrawingIndex, build_index
 from .entity import Entity
-from . import raw
-from .render import plot
+from .layer_map import LayerMap, LayerRule
+from .explain import ObjectExplanation, explain_object
+from .fonts import FontMap, FontRule, FontSubstitutionWarning
+from .geometry_import import csv_to_dwg, geojson_to_dwg
+from .idioms import Arrow, WallSegment, find_arrows, solid_outlines, wall_centerlines
+from .identity import (
+    EntityMatch,
+    GuidStampResult,
+    entity_guids,
+    match_entities,
+    stamp_entity_guids,
+)
+from .mesh_export import (
+    entity_mesh,
+    register_solid_tessellator,
+    thickness_mesh,
+    to_gltf,
+    to_obj,
+    to_stl,
+)
+from .mesh_topology import MeshCheck, check_mesh, face_normals, vertex_normals, weld_vertices
+from .overlay import OverlayDiff, overlay_diff
+from .profiling import (
+    DecodeStats,
+    FileProfile,
+    StatTotals,
+    UnsupportedType,
+    decode_stats,
+    list_unsupported_objects,
+    profile,
+)
+from .recovery import FileLayout, RecoveredField, describe_file_layout, recovered_fields
+from . import batch, cache, raw
+from .render import plot, to_png
+from .similarity import DrawingSimilarity, drawing_similarity
+from .simplify import simplify_points
+from .template import DwgTemplate
+from .text_extents import (
+    TextExtents,
+    estimate_text_extents,
+    hit_test_text,
+    text_bounds,
+    text_outline,
+)
+from .tiles import TileIndex, render_tile
+from .topology import ConnectivityGraph, GraphEdge, Loop, connectivity_graph, find_loops
+from .version_convert import VersionConversion, convert_version
 
 __all__ = [
     "read",
     "Document",
+    "DrawingTimes",
     "Layout",
+    "LayoutInfo",
+    "PlotSettings",
+    "ScaleInfo",
+    "ViewInfo",
+    "ViewportLayerOverrides",
     "Entity",
     "plot",
+    "to_png",
+    "render_tile",
+    "TileIndex",
+    "AciPalette",
+    "FontMap",
+    "FontRule",
+    "FontSubstitutionWarning",
+    "build_display_list",
+    "DisplayList",
+    "DrawCommand",
+    "check_block_references",
+    "BlockReferenceError",
+    "overlay_diff",
+    "OverlayDiff",
+    "drawing_similarity",
+    "DrawingSimilarity",
+    "match_entities",
+    "EntityMatch",
+    "entity_guids",
+    "stamp_entity_guids",
+    "GuidStampResult",
     "to_dxf",
     "to_dwg",
+    "DwgTemplate",
+    "csv_to_dwg",
+    "geojson_to_dwg",
+    "clip_entities",
+    "connectivity_graph",
+    "find_loops",
+    "ConnectivityGraph",
+    "GraphEdge",
+    "Loop",
+    "find_arrows",
+    "wall_centerlines",
+    "solid_outlines",
+    "Arrow",
+    "WallSegment",
+    "simplify_points",
+    "estimate_text_extents",
+    "TextExtents",
+    "text_outline",
+    "text_bounds",
+    "hit_test_text",
+    "regenerate_dimension",
+    "DimStyle",
+    "DimensionGraphics",
+    "fill_hatch",
+    "HatchFill",
+    "HatchPatternLine",
+    "to_obj",
+    "to_stl",
+    "to_gltf",
+    "register_solid_tessellator",
+    "entity_mesh",
+    "thickness_mesh",
+    "weld_vertices",
+    "face_normals",
+    "vertex_normals",
+    "check_mesh",
+    "MeshCheck",
+    "profile",
+    "FileProfile",
+    "LayerMap",
+    "LayerRule",
+    "build_index",
+    "DrawingIndex",
+    "decode_stats",
+    "DecodeStats",
+    "StatTotals",
+    "list_unsupported_objects",
+    "UnsupportedType",
+    "explain_object",
+    "ObjectExplanation",
+    "recovered_fields",
+    "RecoveredField",
+    "describe_file_layout",
+    "FileLayout",
+    "apply_incremental_update",
+    "rename_layer",
+    "redefine_block",
+    "strip_layers",
+    "replace_text",
+    "set_layer_color",
+    "move_entities_to_layer",
+    "update_entities",
+    "transform_drawing",
+    "purge",
+    "convert_version",
+    "VersionConversion",
     "ConvertResult",
+    "EntityUpdateResult",
+    "PurgeResult",
     "WriteResult",
+    "batch",
+    "cache",
     "raw",
 ]
 
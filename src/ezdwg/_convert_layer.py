@@ -4,6 +4,7 @@ from typing import Any
 
 from . import raw
 from ._convert_utils import _to_valid_aci, _to_valid_true_color
+from .layer_map import LayerMap
 
 _INVALID_DXF_LAYER_NAME_CHARS = frozenset('<>/\\":;?*|=')
 
@@ -33,6 +34,17 @@ def _layer_styles_by_handle(decode_path: str | None) -> dict[int, tuple[int, int
     return styles
 
 
+def _layer_states_by_handle(decode_path: str | None) -> dict[int, tuple[int, bool]]:
+    """``(flags, is_off)`` of each layer whose state could be read."""
+    if not decode_path:
+        return {}
+    try:
+        rows = raw.decode_layer_states(decode_path)
+    except Exception:
+        return {}
+    return {int(handle): (int(flags), bool(is_off)) for handle, flags, is_off in rows}
+
+
 def _layer_names_by_handle(decode_path: str | None) -> dict[int, str]:
     if not decode_path:
         return {}
@@ -68,6 +80,8 @@ def _prepare_dxf_layers(
     dxf_doc: Any,
     layer_styles_by_handle: dict[int, tuple[int, int | None]],
     layer_names_by_handle: dict[int, str] | None = None,
+    layer_map: LayerMap | None = None,
+    decode_path: str | None = None,
 ) -> dict[int, str]:
     mapping: dict[int, str] = {0: "0"}
     for handle in sorted(layer_styles_by_handle):
@@ -79,6 +93,11 @@ def _prepare_dxf_layers(
             candidate = layer_names_by_handle.get(handle)
             if isinstance(candidate, str) and _is_valid_dxf_layer_name(candidate):
                 name = candidate.strip()
+        mapped = layer_map.resolve(name) if layer_map is not None else None
+        if mapped is not None:
+            if not _is_valid_dxf_layer_name(mapped.name):
+                raise ValueError(f"invalid layer name: {mapped.name!r}")
+            name = mapped.name.strip()
         dxfattribs: dict[str, Any] = {}
         style = layer_styles_by_handle.get(handle)
         if style is not None:
@@ -89,6 +108,10 @@ def _prepare_dxf_layers(
             resolved_true = _to_valid_true_color(true_color)
             if resolved_true is not None:
                 dxfattribs["true_color"] = resolved_true
+        if mapped is not None:
+            _apply_mapped_layer_style(dxf_doc, name, dxfattribs, mapped, decode_path)
+            mapping[handle] = name
+            continue
         if name not in dxf_doc.layers:
             try:
                 dxf_doc.layers.new(name=name, dxfattribs=dxfattribs or None)
@@ -101,3 +124,36 @@ def _prepare_dxf_layers(
                         continue
         mapping[handle] = name
     return mapping
+
+
+def _apply_mapped_layer_style(
+    dxf_doc: Any,
+    name: str,
+    dxfattribs: dict[str, Any],
+    mapped: Any,
+    decode_path: str | None,
+) -> None:
+    # Several source layers may map onto one target: the first creates it
+    # with its own style, and the rule's color and linetype always apply.
+    if name not in dxf_doc.layers:
+        dxf_doc.layers.new(name=name, dxfattribs=dxfattribs or None)
+    layer = dxf_doc.layers.get(name)
+    if mapped.color is not None:
+        layer.dxf.color = mapped.color
+        if layer.dxf.hasattr("true_color"):
+            layer.dxf.discard("true_color")
+    if mapped.linetype is not None:
+        _ensure_dxf_linetype(dxf_doc, mapped.linetype, decode_path)
+        layer.dxf.linetype = mapped.linetype
+
+
+def _ensure_dxf_linetype(dxf_doc: Any, name: str, decode_path: str | None) -> None:
+    if name in dxf_doc.linetypes:
+        return
+    rows = raw.decode_linetypes(decode_path) if decode_path else []
+    for _handle, row_name, description, pattern_length, dashes in rows:
+        if row_name and row_name.upper() == name.upper():
+            pattern = [float(pattern_length), *(float(length) for length, _, _ in dashes)]
+            dxf_doc.linetypes.add(name, pattern=pattern, description=description or "")
+            return
+    raise ValueError(f"linetype not found: {name}")
@@ -5,7 +5,14 @@ import math
 from .entity import Entity
 
 
-def _as_line_row(entity: Entity) -> tuple[int, float, float, float, float, float, float] | None:
+def _row_handle(entity: Entity) -> int | None:
+    """The handle of a write row; ``None`` lets the writer allocate one."""
+    return int(entity.handle) or None
+
+
+def _as_line_row(
+    entity: Entity,
+) -> tuple[int | None, float, float, float, float, float, float] | None:
     start = entity.dxf.get("start")
     end = entity.dxf.get("end")
     if not (
@@ -16,7 +23,7 @@ def _as_line_row(entity: Entity) -> tuple[int, float, float, float, float, float
     ):
         return None
     return (
-        int(entity.handle),
+        _row_handle(entity),
         float(start[0]),
         float(start[1]),
         float(start[2]),
@@ -26,7 +33,7 @@ def _as_line_row(entity: Entity) -> tuple[int, float, float, float, float, float
     )
 
 
-def _as_point_row(entity: Entity) -> tuple[int, float, float, float, float] | None:
+def _as_point_row(entity: Entity) -> tuple[int | None, float, float, float, float] | None:
     location = entity.dxf.get("location")
     x_axis_angle = entity.dxf.get("x_axis_angle", 0.0)
     if not (
@@ -36,7 +43,7 @@ def _as_point_row(entity: Entity) -> tuple[int, float, float, float, float] | No
     ):
         return None
     return (
-        int(entity.handle),
+        _row_handle(entity),
         float(location[0]),
         float(location[1]),
         float(location[2]),
@@ -44,7 +51,9 @@ def _as_point_row(entity: Entity) -> tuple[int, float, float, float, float] | No
     )
 
 
-def _as_ray_row(entity: Entity) -> tuple[int, tuple[float, float, float], tuple[float, float, float]] | None:
+def _as_ray_row(
+    entity: Entity,
+) -> tuple[int | None, tuple[float, float, float], tuple[float, float, float]] | None:
     start = entity.dxf.get("start")
     unit_vector = entity.dxf.get("unit_vector")
     if not (
@@ -55,17 +64,21 @@ def _as_ray_row(entity: Entity) -> tuple[int, tuple[float, float, float], tuple[
     ):
         return None
     return (
-        int(entity.handle),
+        _row_handle(entity),
         (float(start[0]), float(start[1]), float(start[2])),
         (float(unit_vector[0]), float(unit_vector[1]), float(unit_vector[2])),
     )
 
 
-def _as_xline_row(entity: Entity) -> tuple[int, tuple[float, float, float], tuple[float, float, float]] | None:
+def _as_xline_row(
+    entity: Entity,
+) -> tuple[int | None, tuple[float, float, float], tuple[float, float, float]] | None:
     return _as_ray_row(entity)
 
 
-def _as_arc_row(entity: Entity) -> tuple[int, float, float, float, float, float, float] | None:
+def _as_arc_row(
+    entity: Entity,
+) -> tuple[int | None, float, float, float, float, float, float] | None:
     center = entity.dxf.get("center")
     radius = entity.dxf.get("radius")
     start_angle = entity.dxf.get("start_angle")
@@ -79,7 +92,7 @@ def _as_arc_row(entity: Entity) -> tuple[int, float, float, float, float, float,
     ):
         return None
     return (
-        int(entity.handle),
+        _row_handle(entity),
         float(center[0]),
         float(center[1]),
         float(center[2]),
@@ -89,13 +102,13 @@ def _as_arc_row(entity: Entity) -> tuple[int, float, float, float, float, float,
     )
 
 
-def _as_circle_row(entity: Entity) -> tuple[int, float, float, float, float] | None:
+def _as_circle_row(entity: Entity) -> tuple[int | None, float, float, float, float] | None:
     center = entity.dxf.get("center")
     radius = entity.dxf.get("radius")
     if not (isinstance(center, tuple) and len(center) == 3 and isinstance(radius, (int, float))):
         return None
     return (
-        int(entity.handle),
+        _row_handle(entity),
         float(center[0]),
         float(center[1]),
         float(center[2]),
@@ -105,7 +118,7 @@ def _as_circle_row(entity: Entity) -> tuple[int, float, float, float, float] | N
 
 def _as_lwpolyline_row(
     entity: Entity,
-) -> tuple[int, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None] | None:
+) -> tuple[int | None, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None] | None:
     points = entity.dxf.get("points")
     flags = entity.dxf.get("flags", 0)
     bulges = entity.dxf.get("bulges") or []
@@ -126,7 +139,7 @@ def _as_lwpolyline_row(
         out_widths.append((float(width[0]), float(width[1])))
     out_const_width = float(const_width) if isinstance(const_width, (int, float)) else None
     return (
-        int(entity.handle),
+        _row_handle(entity),
         int(flags),
         points2d,
         out_bulges,
@@ -135,7 +148,9 @@ def _as_lwpolyline_row(
     )
 
 
-def _as_text_row(entity: Entity) -> tuple[int, str, tuple[float, float, float], float, float] | None:
+def _as_text_row(
+    entity: Entity,
+) -> tuple[int | None, str, tuple[float, float, float], float, float] | None:
     text = entity.dxf.get("text")
     insert = entity.dxf.get("insert")
     height = entity.dxf.get("height")
@@ -149,7 +164,7 @@ def _as_text_row(entity: Entity) -> tuple[int, str, tuple[float, float, float],
     ):
         return None
     return (
-        int(entity.handle),
+        _row_handle(entity),
         text,
         (float(insert[0]), float(insert[1]), float(insert[2])),
         float(height),
@@ -157,9 +172,29 @@ def _as_text_row(entity: Entity) -> tuple[int, str, tuple[float, float, float],
     )
 
 
+def _as_text_alignment_row(
+    entity: Entity,
+) -> tuple[int, tuple[float, float, float] | None, int, int, float, float] | None:
+    """Alignment, width factor and obliquing of a TEXT entity, or ``None``
+    when they are all defaults."""
+    dxf = entity.dxf
+    halign = int(dxf.get("halign") or 0)
+    valign = int(dxf.get("valign") or 0)
+    width = float(dxf.get("width", 1.0) or 1.0)
+    oblique = float(dxf.get("oblique", 0.0) or 0.0)
+    align_point = dxf.get("align_point")
+    if isinstance(align_point, (tuple, list)) and len(align_point) == 3:
+        align_point = (float(align_point[0]), float(align_point[1]), float(align_point[2]))
+    else:
+        align_point = None
+    if halign == 0 and valign == 0 and width == 1.0 and oblique == 0.0:
+        return None
+    return (int(entity.handle), align_point, halign, valign, width, math.radians(oblique))
+
+
 def _as_mtext_row(
     entity: Entity,
-) -> tuple[int, str, tuple[float, float, float], tuple[float, float, float], float, float, int, int] | None:
+) -> tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float, int, int] | None:
     text = entity.dxf.get("raw_text")
     if not isinstance(text, str):
         text = entity.dxf.get("text")
@@ -189,7 +224,7 @@ def _as_mtext_row(
     drawing_direction = int(entity.dxf.get("drawing_direction", 1))
 
     return (
-        int(entity.handle),
+        _row_handle(entity),
         text,
         (float(insert[0]), float(insert[1]), float(insert[2])),
         direction,
@@ -198,3 +233,92 @@ def _as_mtext_row(
         attachment_point,
         drawing_direction,
     )
+
+
+def _as_tolerance_row(
+    entity: Entity,
+) -> tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float] | None:
+    text = entity.dxf.get("text")
+    insert = entity.dxf.get("insert")
+    x_direction = entity.dxf.get("x_direction")
+    if not (
+        isinstance(text, str)
+        and isinstance(insert, tuple)
+        and len(insert) == 3
+        and isinstance(x_direction, tuple)
+        and len(x_direction) == 3
+    ):
+        return None
+    return (
+        _row_handle(entity),
+        text,
+        (float(insert[0]), float(insert[1]), float(insert[2])),
+        (float(x_direction[0]), float(x_direction[1]), float(x_direction[2])),
+        float(entity.dxf.get("height", 0.0) or 0.0),
+        float(entity.dxf.get("dimgap", 0.0) or 0.0),
+    )
+
+
+def _as_leader_row(
+    entity: Entity,
+) -> tuple[int | None, int, int, list[tuple[float, float, float]]] | None:
+    points = entity.dxf.get("points")
+    if not isinstance(points, list) or len(points) < 2:
+        return None
+    out_points = []
+    for point in points:
+        if not isinstance(point, (tuple, list)) or len(point) < 2:
+            return None
+        z = point[2] if len(point) > 2 else 0.0
+        out_points.append((float(point[0]), float(point[1]), float(z)))
+    return (
+        _row_handle(entity),
+        int(entity.dxf.get("annotation_type", 3)),
+        int(entity.dxf.get("path_type", 0)),
+        out_points,
+    )
+
+
+def _as_polyface_row(
+    entity: Entity,
+) -> tuple[int | None, list[tuple[float, float, float]], list[tuple[int, int, int, int]]] | None:
+    """A polyface mesh row for a MESH: its vertices with the faces as 1-based
+    indices. Faces of more than four corners are split into a fan of
+    triangles whose inner edges are hidden by negative indices."""
+    vertices = entity.dxf.get("vertices")
+    faces = entity.dxf.get("faces")
+    if not isinstance(vertices, list) or not isinstance(faces, list):
+        return None
+    # Face indices are signed 16-bit in a polyface mesh.
+    if not vertices or len(vertices) > 0x7FFF:
+        return None
+    out_vertices = []
+    for vertex in vertices:
+        if not isinstance(vertex, (tuple, list)) or len(vertex) != 3:
+            return None
+        out_vertices.append((float(vertex[0]), float(vertex[1]), float(vertex[2])))
+    out_faces: list[tuple[int, int, int, int]] = []
+    for face in faces:
+        if not isinstance(face, (tuple, list)) or len(face) < 3:
+            return None
+        corners = [int(index) + 1 for index in face]
+        if any(not 1 <= index <= len(out_vertices) for index in corners):
+            return None
+        if len(corners) == 3:
+            out_faces.append((corners[0], corners[1], corners[2], 0))
+        elif len(corners) == 4:
+            out_faces.append((corners[0], corners[1], corners[2], corners[3]))
+        else:
+            last = len(corners) - 2
+            for i in range(1, last + 1):
+                out_faces.append(
+                    (
+                        corners[0] if i == 1 else -corners[0],
+                        corners[i],
+                        corners[i + 1] if i == last else -corners[i + 1],
+                        0,
+                    )
+                )
+    if not out_faces or len(out_faces) > 0xFFFF:
+        return None
+    return (_row_handle(entity), out_vertices, out_faces)
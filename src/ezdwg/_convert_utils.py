@@ -155,7 +155,11 @@ def _signed_line_distance_2d(
 def _ordinate_dim_type(
     feature: tuple[float, float],
     offset: tuple[float, float],
+    is_x_type: bool | None = None,
 ) -> int:
+    if is_x_type is not None:
+        return 0 if is_x_type else 1
+    # Without the decoded flag, guess the axis from the leader direction.
     dx = abs(offset[0] - feature[0])
     dy = abs(offset[1] - feature[1])
     return 0 if dx >= dy else 1
@@ -1,91 +1,728 @@
+# Generated by gen_stubs from the #[pyfunction] signatures in src/api/bindings.
+# Do not edit: change the Rust signature or its `// pyi:` alias instead.
+from collections.abc import Callable
+
+__all__ = [
+    "detect_version",
+    "write_ac1015_dwg",
+    "apply_incremental_update",
+    "replace_entity_text",
+    "redefine_block",
+    "strip_layers",
+    "write_ac1015_line_dwg",
+    "list_unknown_objects",
+    "remap_object_record_handles",
+    "write_strokes_png",
+    "render_strokes_png",
+    "cache_file_key",
+    "cache_load",
+    "cache_store",
+    "cache_clear",
+    "list_section_locators",
+    "describe_file_layout",
+    "read_section_bytes",
+    "decode_drawing_times",
+    "decode_linetype_scales",
+    "read_decompressed_section",
+    "decode_acds_records",
+    "tessellate_solids",
+    "list_object_map_entries",
+    "list_object_headers",
+    "list_object_headers_with_type",
+    "list_object_headers_by_type",
+    "read_object_records_by_type",
+    "read_object_records_by_handle",
+    "explain_object",
+    "read_object_records_by_offset",
+    "decode_object_entity_layer_handles",
+    "decode_object_handle_stream_refs",
+    "decode_object_handle_stream_ref_kinds",
+    "decode_acis_candidate_infos",
+    "decode_proxy_graphic_chunk_infos",
+    "decode_proxy_graphic_text_entities",
+    "decode_entity_styles",
+    "decode_recovered_fields",
+    "begin_decode_stats",
+    "end_decode_stats",
+    "decode_entity_colors",
+    "aci_to_rgb",
+    "decode_entity_transparency",
+    "decode_entity_thickness",
+    "decode_entity_line_styles",
+    "decode_layer_colors",
+    "decode_layer_states",
+    "decode_layer_transparency",
+    "decode_layer_names",
+    "decode_table_control",
+    "decode_table_entry_names",
+    "decode_dictionaries",
+    "decode_xrecords",
+    "decode_xdata",
+    "decode_table_styles",
+    "decode_linetypes",
+    "decode_text_styles",
+    "decode_dim_styles",
+    "decode_views",
+    "decode_materials",
+    "decode_visual_styles",
+    "decode_scales",
+    "decode_entity_materials",
+    "decode_layer_materials",
+    "decode_layouts",
+    "decode_line_entities",
+    "decode_line_owner_handles",
+    "decode_point_entities",
+    "decode_point_owner_handles",
+    "decode_3dface_entities",
+    "decode_arc_entities",
+    "decode_arc_owner_handles",
+    "decode_circle_entities",
+    "decode_circle_owner_handles",
+    "decode_line_arc_circle_entities",
+    "decode_styled_entities",
+    "decode_ellipse_entities",
+    "decode_spline_entities",
+    "decode_text_entities",
+    "decode_attrib_entities",
+    "decode_attdef_entities",
+    "decode_mtext_entities",
+    "decode_leader_entities",
+    "decode_mleader_entities",
+    "decode_hatch_entities",
+    "decode_tolerance_entities",
+    "decode_mline_entities",
+    "decode_dimension_entities",
+    "decode_dim_linear_entities",
+    "decode_dim_ordinate_entities",
+    "decode_dim_aligned_entities",
+    "decode_dim_ang3pt_entities",
+    "decode_dim_ang2ln_entities",
+    "decode_dim_diameter_entities",
+    "decode_dim_radius_entities",
+    "decode_insert_entities",
+    "decode_insert_owner_handles",
+    "decode_minsert_entities",
+    "decode_insert_minsert_entities",
+    "decode_insert_minsert_dimension_entities",
+    "decode_block_header_names",
+    "decode_block_entity_names",
+    "decode_block_entity_name_maps",
+    "iter_block_children",
+    "decode_block_base_point",
+    "check_block_references",
+    "decode_xref_blocks",
+    "build_drawing_index",
+    "decode_polyline_2d_entities",
+    "decode_polyline_2d_entities_interpreted",
+    "decode_lwpolyline_entities",
+    "decode_lwpolyline_owner_handles",
+    "decode_polyline_3d_entities",
+    "decode_polyline_3d_with_vertices",
+    "decode_polyline_mesh_entities",
+    "decode_polyline_mesh_with_vertices",
+    "decode_polyline_pface_entities",
+    "decode_polyline_pface_with_faces",
+    "decode_mesh_entities",
+    "decode_solid_entities",
+    "decode_trace_entities",
+    "decode_shape_entities",
+    "decode_viewport_entities",
+    "decode_viewport_frozen_layers",
+    "decode_oleframe_entities",
+    "decode_ole2frame_entities",
+    "decode_long_transaction_entities",
+    "decode_region_entities",
+    "decode_3dsolid_entities",
+    "decode_body_entities",
+    "decode_ray_entities",
+    "decode_light_entities",
+    "decode_rtext_entities",
+    "decode_arc_aligned_text_entities",
+    "decode_xline_entities",
+    "decode_polyline_2d_with_vertices",
+    "decode_polyline_2d_with_vertices_interpolated",
+    "decode_vertex_2d_entities",
+    "decode_vertex_3d_entities",
+    "decode_vertex_mesh_entities",
+    "decode_vertex_pface_entities",
+    "decode_vertex_pface_face_entities",
+    "decode_polyline_2d_with_vertex_data",
+    "decode_polyline_sequence_members",
+]
+
 def detect_version(path: str) -> str: ...
 def write_ac1015_dwg(
     output_path: str,
-    lines: list[tuple[int, float, float, float, float, float, float]],
-    arcs: list[tuple[int, float, float, float, float, float, float]],
-    circles: list[tuple[int, float, float, float, float]],
-    lwpolylines: list[tuple[int, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None]],
-    texts: list[tuple[int, str, tuple[float, float, float], float, float]],
-    mtexts: list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], float, float, int, int]],
-    points: list[tuple[int, float, float, float, float]] | None = ...,
-    rays: list[tuple[int, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
-    xlines: list[tuple[int, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
+    lines: list[tuple[int | None, float, float, float, float, float, float]],
+    arcs: list[tuple[int | None, float, float, float, float, float, float]],
+    circles: list[tuple[int | None, float, float, float, float]],
+    lwpolylines: list[tuple[int | None, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None]],
+    texts: list[tuple[int | None, str, tuple[float, float, float], float, float]],
+    mtexts: list[tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float, int, int]],
+    points: list[tuple[int | None, float, float, float, float]] | None = ...,
+    rays: list[tuple[int | None, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
+    xlines: list[tuple[int | None, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
+    layouts: list[tuple[str, tuple[tuple[str | None, str | None, str | None, str | None], int, tuple[float, float], tuple[float, float, float, float], tuple[float, float], int, int, int, tuple[tuple[float, float], tuple[float, float]], tuple[float, float, int, float]], list[tuple[int | None, tuple[float, float, float], float, float, tuple[float, float], float]]]] | None = ...,
+    application_name: str | None = ...,
+    application_version: str | None = ...,
+    maintenance_version: int = ...,
+    codepage: int = ...,
+    drawing_times: tuple[tuple[int, int], tuple[int, int], tuple[int, int], tuple[int, int]] | None = ...,
+    styles: list[tuple[int, str | None, int | None, int | None]] | None = ...,
+    linetypes: list[tuple[str, str, list[float]]] | None = ...,
+    progress: Callable[[str, int, int | None, int, int | None], object] | None = ...,
+    max_lwpolyline_vertices: int | None = ...,
+    arc_fit_tolerance: float | None = ...,
+    text_alignments: list[tuple[int, tuple[float, float, float] | None, int, int, float, float]] | None = ...,
+    tolerances: list[tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float]] | None = ...,
+    leaders: list[tuple[int | None, int, int, list[tuple[float, float, float]]]] | None = ...,
+    mlines: list[tuple[int | None, str, float, int, bool, list[tuple[float, float, float]]]] | None = ...,
+    mline_styles: list[tuple[str, str, list[tuple[float, int]]]] | None = ...,
+    unknown_objects_from: str | None = ...,
+    header_from: str | None = ...,
+    layers: list[tuple[str, int]] | None = ...,
+    entity_layers: list[tuple[int, str]] | None = ...,
+    entity_colors: list[tuple[int, int]] | None = ...,
+    transform: tuple[tuple[float, float, float, float], tuple[float, float, float, float], tuple[float, float, float, float]] | None = ...,
+    groups: list[tuple[str, str, bool, list[int]]] | None = ...,
+    entity_xdata: list[tuple[int, str, list[tuple[int, object]]]] | None = ...,
+    dictionaries: list[tuple[str, list[tuple[str, list[tuple[int, object]]]]]] | None = ...,
+    layer_linetypes: list[tuple[str, str]] | None = ...,
+    encode_threads: int | None = ...,
+    handle_namespaces: bool = ...,
+    polyfaces: list[tuple[int | None, list[tuple[float, float, float]], list[tuple[int, int, int, int]]]] | None = ...,
+    layer_states: list[tuple[str, int, bool]] | None = ...,
 ) -> None: ...
-def write_ac1015_line_dwg(output_path: str, lines: list[tuple[int, float, float, float, float, float, float]]) -> None: ...
+def apply_incremental_update(
+    path: str,
+    output_path: str,
+    lines: list[tuple[int | None, float, float, float, float, float, float]],
+    arcs: list[tuple[int | None, float, float, float, float, float, float]],
+    circles: list[tuple[int | None, float, float, float, float]],
+    lwpolylines: list[tuple[int | None, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None]],
+    texts: list[tuple[int | None, str, tuple[float, float, float], float, float]],
+    mtexts: list[tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float, int, int]],
+    points: list[tuple[int | None, float, float, float, float]] | None = ...,
+    rays: list[tuple[int | None, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
+    xlines: list[tuple[int | None, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
+    text_alignments: list[tuple[int, tuple[float, float, float] | None, int, int, float, float]] | None = ...,
+    tolerances: list[tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float]] | None = ...,
+    leaders: list[tuple[int | None, int, int, list[tuple[float, float, float]]]] | None = ...,
+) -> None: ...
+def replace_entity_text(path: str, output_path: str, edits: list[tuple[int, str]]) -> None: ...
+def redefine_block(
+    path: str,
+    output_path: str,
+    block_name: str,
+    lines: list[tuple[int | None, float, float, float, float, float, float]],
+    arcs: list[tuple[int | None, float, float, float, float, float, float]],
+    circles: list[tuple[int | None, float, float, float, float]],
+    lwpolylines: list[tuple[int | None, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None]],
+    texts: list[tuple[int | None, str, tuple[float, float, float], float, float]],
+    mtexts: list[tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float, int, int]],
+    points: list[tuple[int | None, float, float, float, float]] | None = ...,
+    rays: list[tuple[int | None, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
+    xlines: list[tuple[int | None, tuple[float, float, float], tuple[float, float, float]]] | None = ...,
+    text_alignments: list[tuple[int, tuple[float, float, float] | None, int, int, float, float]] | None = ...,
+    tolerances: list[tuple[int | None, str, tuple[float, float, float], tuple[float, float, float], float, float]] | None = ...,
+    leaders: list[tuple[int | None, int, int, list[tuple[float, float, float]]]] | None = ...,
+    styles: list[tuple[int, str | None, int | None, int | None]] | None = ...,
+    entity_layers: list[tuple[int, str]] | None = ...,
+    entity_colors: list[tuple[int, int]] | None = ...,
+) -> None: ...
+def strip_layers(path: str, output_path: str, layers: list[str]) -> list[int]: ...
+def write_ac1015_line_dwg(
+    output_path: str,
+    lines: list[tuple[int | None, float, float, float, float, float, float]],
+) -> None: ...
+def list_unknown_objects(path: str) -> list[tuple[int, int, str | None]]: ...
+def remap_object_record_handles(record: bytes, remap: dict[int, int]) -> bytes: ...
+# Requires the `raster` Cargo feature.
+def write_strokes_png(
+    output_path: str,
+    strokes: list[tuple[list[tuple[float, float]], tuple[int, int, int], bool]],
+    width: int = ...,
+    height: int | None = ...,
+    window: tuple[float, float, float, float] | None = ...,
+    background: tuple[int, int, int] = ...,
+    line_width: int = ...,
+    margin: float = ...,
+) -> tuple[int, int]: ...
+# Requires the `raster` Cargo feature.
+def render_strokes_png(
+    strokes: list[tuple[list[tuple[float, float]], tuple[int, int, int], bool]],
+    width: int = ...,
+    height: int | None = ...,
+    window: tuple[float, float, float, float] | None = ...,
+    background: tuple[int, int, int] = ...,
+    line_width: int = ...,
+    margin: float = ...,
+) -> bytes: ...
+# Requires the `cache` Cargo feature.
+def cache_file_key(cache_dir: str, path: str) -> tuple[int, int, str]: ...
+# Requires the `cache` Cargo feature.
+def cache_load(cache_dir: str, key: tuple[int, int, str], table: str) -> bytes | None: ...
+# Requires the `cache` Cargo feature.
+def cache_store(cache_dir: str, key: tuple[int, int, str], table: str, payload: bytes) -> None: ...
+# Requires the `cache` Cargo feature.
+def cache_clear(cache_dir: str) -> int: ...
 def list_section_locators(path: str) -> list[tuple[str, int, int]]: ...
+def describe_file_layout(
+    path: str,
+) -> tuple[str, str, bool, list[str], list[tuple[str, int, int]]]: ...
 def read_section_bytes(path: str, index: int) -> bytes: ...
-def list_object_map_entries(path: str, limit: int | None = ...) -> list[tuple[int, int]]: ...
+def decode_drawing_times(
+    path: str,
+) -> tuple[tuple[int, int], tuple[int, int], tuple[int, int], tuple[int, int]]: ...
+def decode_linetype_scales(path: str) -> tuple[float, float]: ...
+def read_decompressed_section(path: str, name: str) -> bytes: ...
+def decode_acds_records(path: str) -> list[tuple[int, int, str | None, bytes]]: ...
+def tessellate_solids(
+    path: str,
+    tessellator: Callable[[int, str, bytes], tuple[list[tuple[float, float, float]], list[tuple[int, int, int]]] | None],
+) -> list[tuple[int, list[tuple[float, float, float]], list[tuple[int, int, int]]]]: ...
+def list_object_map_entries(
+    path: str,
+    limit: int | None = ...,
+    start_handle: int | None = ...,
+    end_handle: int | None = ...,
+    by_offset: bool = ...,
+    progress: Callable[[str, int, int | None, int, int | None], object] | None = ...,
+) -> list[tuple[int, int]]: ...
 def list_object_headers(path: str, limit: int | None = ...) -> list[tuple[int, int, int, int]]: ...
-def list_object_headers_with_type(path: str, limit: int | None = ...) -> list[tuple[int, int, int, int, str, str]]: ...
-def list_object_headers_by_type(path: str, type_codes: list[int], limit: int | None = ...) -> list[tuple[int, int, int, int, str, str]]: ...
-def read_object_records_by_type(path: str, type_codes: list[int], limit: int | None = ...) -> list[tuple[int, int, int, int, bytes]]: ...
-def read_object_records_by_handle(path: str, handles: list[int], limit: int | None = ...) -> list[tuple[int, int, int, int, bytes]]: ...
-def read_object_records_by_offset(path: str, offsets: list[int], limit: int | None = ...) -> list[tuple[int, int, int, int, bytes]]: ...
-def decode_object_entity_layer_handles(path: str, handles: list[int], limit: int | None = ...) -> list[tuple[int, int]]: ...
-def decode_object_handle_stream_refs(path: str, handles: list[int], limit: int | None = ...) -> list[tuple[int, list[int]]]: ...
-def decode_acis_candidate_infos(path: str, handles: list[int], limit: int | None = ...) -> list[tuple[int, int, int, str, list[int], int]]: ...
-def decode_entity_styles(path: str, limit: int | None = ...) -> list[tuple[int, int | None, int | None, int]]: ...
-def decode_layer_colors(path: str, limit: int | None = ...) -> list[tuple[int, int, int | None]]: ...
+def list_object_headers_with_type(
+    path: str,
+    limit: int | None = ...,
+    progress: Callable[[str, int, int | None, int, int | None], object] | None = ...,
+) -> list[tuple[int, int, int, int, str, str]]: ...
+def list_object_headers_by_type(
+    path: str,
+    type_codes: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, str, str]]: ...
+def read_object_records_by_type(
+    path: str,
+    type_codes: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, list[int]]]: ...
+def read_object_records_by_handle(
+    path: str,
+    handles: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, list[int]]]: ...
+def explain_object(
+    path: str,
+    handle: int,
+) -> tuple[int, int, int, str, bytes, list[tuple[str, int, int, str, str]], str | None, str | None]: ...
+def read_object_records_by_offset(
+    path: str,
+    offsets: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, list[int]]]: ...
+def decode_object_entity_layer_handles(
+    path: str,
+    handles: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, int]]: ...
+def decode_object_handle_stream_refs(
+    path: str,
+    handles: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, list[int]]]: ...
+def decode_object_handle_stream_ref_kinds(
+    path: str,
+    handles: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, list[tuple[int, str]]]]: ...
+def decode_acis_candidate_infos(
+    path: str,
+    handles: list[int],
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, str, list[int], int]]: ...
+def decode_proxy_graphic_chunk_infos(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, int]]: ...
+def decode_proxy_graphic_text_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, str, tuple[float, float, float], tuple[float, float, float], float, float, float]]: ...
+def decode_entity_styles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None, int | None, int]]: ...
+def decode_recovered_fields(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, int | str, int | str | None, str]]: ...
+def begin_decode_stats() -> None: ...
+def end_decode_stats() -> tuple[list[tuple[str, int, int, float]], list[tuple[str, int, int, float]], list[tuple[str, int, int, float]]]: ...
+def decode_entity_colors(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, int | None, int | None, int]]: ...
+def aci_to_rgb(index: int) -> tuple[int, int, int] | None: ...
+def decode_entity_transparency(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
+def decode_entity_thickness(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, float, tuple[float, float, float]]]: ...
+def decode_entity_line_styles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int | None, float, int]]: ...
+def decode_layer_colors(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int | None]]: ...
+def decode_layer_states(path: str, limit: int | None = ...) -> list[tuple[int, int, bool]]: ...
+def decode_layer_transparency(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
 def decode_layer_names(path: str, limit: int | None = ...) -> list[tuple[int, str]]: ...
-def decode_line_entities(path: str, limit: int | None = ...) -> list[tuple[int, float, float, float, float, float, float]]: ...
-def decode_point_entities(path: str, limit: int | None = ...) -> list[tuple[int, float, float, float, float]]: ...
-def decode_3dface_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], int]]: ...
-def decode_arc_entities(path: str, limit: int | None = ...) -> list[tuple[int, float, float, float, float, float, float]]: ...
-def decode_circle_entities(path: str, limit: int | None = ...) -> list[tuple[int, float, float, float, float]]: ...
-def decode_line_arc_circle_entities(path: str, limit: int | None = ...) -> tuple[list[tuple[int, float, float, float, float, float, float]], list[tuple[int, float, float, float, float, float, float]], list[tuple[int, float, float, float, float]]]: ...
-def decode_ellipse_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, float, float]]: ...
-def decode_spline_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[int, int, bool, bool, bool], tuple[float | None, float | None, float | None], list[float], list[tuple[float, float, float]], list[float], list[tuple[float, float, float]]]]: ...
-def decode_dimension_entities(path: str, limit: int | None = ...) -> list[tuple[str, tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]]: ...
-def decode_dim_diameter_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]: ...
-def decode_dim_linear_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]: ...
-def decode_dim_ordinate_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]: ...
-def decode_dim_aligned_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]: ...
-def decode_dim_ang3pt_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]: ...
-def decode_dim_ang2ln_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]: ...
-def decode_dim_radius_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None]]]: ...
-def decode_text_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float] | None, tuple[float, float, float], tuple[float, float, float, float, float], tuple[int, int, int], int | None, int | None]]: ...
-def decode_attrib_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, str | None, str | None, tuple[float, float, float], tuple[float, float, float] | None, tuple[float, float, float], tuple[float, float, float, float, float], tuple[int, int, int], int, bool, tuple[int | None, int | None]]]: ...
-def decode_attdef_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, str | None, str | None, tuple[float, float, float], tuple[float, float, float] | None, tuple[float, float, float], tuple[float, float, float, float, float], tuple[int, int, int], int, bool, tuple[int | None, int | None]]]: ...
-def decode_mtext_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, float, int, int, tuple[int, float | None, int | None, int | None, int | None], int | None]]: ...
-def decode_leader_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, int, list[tuple[float, float, float]]]]: ...
-def decode_hatch_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, bool, bool, float, tuple[float, float, float], list[tuple[bool, list[tuple[float, float]]]]]]: ...
-def decode_tolerance_entities(path: str, limit: int | None = ...) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, float, int | None]]: ...
-def decode_mline_entities(path: str, limit: int | None = ...) -> list[tuple[int, float, int, tuple[float, float, float], tuple[float, float, float], int, int, list[tuple[tuple[float, float, float], tuple[float, float, float], tuple[float, float, float]]], int | None]]: ...
-def decode_insert_entities(path: str, limit: int | None = ...) -> list[tuple[int, float, float, float, float, float, float, float, str | None]]: ...
-def decode_minsert_entities(path: str, limit: int | None = ...) -> list[tuple[int, float, float, float, float, float, float, float, tuple[int, int, float, float, str | None]]]: ...
-def decode_insert_minsert_entities(path: str, limit: int | None = ...) -> tuple[
-    list[tuple[int, float, float, float, float, float, float, float, str | None]],
-    list[tuple[int, float, float, float, float, float, float, float, tuple[int, int, float, float, str | None]]],
-]: ...
+def decode_table_control(
+    path: str,
+    table: str,
+) -> tuple[int, str, int, list[int], list[tuple[str, int]]]: ...
+def decode_table_entry_names(path: str, table: str) -> list[tuple[int, str | None]]: ...
+def decode_dictionaries(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, list[tuple[str, int]]]]: ...
+def decode_xrecords(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, list[tuple[int, object]]]]: ...
+def decode_xdata(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, list[tuple[int, object]]]]: ...
+def decode_table_styles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, int, int, float, float, bool, bool, list[tuple[int | None, float, int, tuple[int, int | None], tuple[int, int | None], bool, list[tuple[int, bool, int, int | None]]]]]]: ...
+def decode_linetypes(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, str | None, float, list[tuple[float, int, int]]]]: ...
+def decode_text_styles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, str | None, str | None, float, float, float, bool]]: ...
+def decode_dim_styles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, tuple[float, float, float, float, float, float, float, float, float, float], int, int, tuple[bool, bool, bool, bool]]]: ...
+def decode_views(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, tuple[float, float], tuple[float, float], tuple[float, float, float], tuple[float, float, float], float, float, tuple[float, float], int, int, tuple[bool, bool]]]: ...
+def decode_materials(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, str | None, tuple[int, float, int | None], tuple[int, float, int | None], str | None]]: ...
+def decode_visual_styles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, int]]: ...
+def decode_scales(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, float, float, bool]]: ...
+def decode_entity_materials(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int | None]]: ...
+def decode_layer_materials(path: str, limit: int | None = ...) -> list[tuple[int, int | None]]: ...
+def decode_layouts(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, int, int, tuple[tuple[float, float], tuple[float, float]], tuple[tuple[float, float, float], tuple[float, float, float]], tuple[tuple[str | None, str | None, str | None, str | None], int, tuple[float, float], tuple[float, float, float, float], tuple[float, float], int, int, int, tuple[tuple[float, float], tuple[float, float]], tuple[float, float, int, float]]]]: ...
+def decode_line_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, float, float, float, float, float]]: ...
+def decode_line_owner_handles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
+def decode_point_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, float, float, float]]: ...
+def decode_point_owner_handles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
+def decode_3dface_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], int]]: ...
+def decode_arc_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, float, float, float, float, float]]: ...
+def decode_arc_owner_handles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
+def decode_circle_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, float, float, float]]: ...
+def decode_circle_owner_handles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
+def decode_line_arc_circle_entities(
+    path: str,
+    limit: int | None = ...,
+) -> tuple[list[tuple[int, float, float, float, float, float, float]], list[tuple[int, float, float, float, float, float, float]], list[tuple[int, float, float, float, float]]]: ...
+def decode_styled_entities(
+    path: str,
+    limit: int | None = ...,
+) -> tuple[list[tuple[tuple[int, float, float, float, float, float, float], tuple[str | None, int | None, str | None, int]]], list[tuple[tuple[int, float, float, float, float, float, float], tuple[str | None, int | None, str | None, int]]], list[tuple[tuple[int, float, float, float, float], tuple[str | None, int | None, str | None, int]]], list[tuple[tuple[int, float, float, float, float], tuple[str | None, int | None, str | None, int]]], list[tuple[tuple[int, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None], tuple[str | None, int | None, str | None, int]]]]: ...
+def decode_ellipse_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, float, float]]: ...
+def decode_spline_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[int, int, bool, bool, bool], tuple[float | None, float | None, float | None], list[float], list[tuple[float, float, float]], list[float], list[tuple[float, float, float]], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None]]]: ...
+def decode_text_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float] | None, tuple[float, float, float], tuple[float, float, float, float, float], tuple[int, int, int], int | None, int | None]]: ...
+def decode_attrib_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, str | None, str | None, tuple[float, float, float], tuple[float, float, float] | None, tuple[float, float, float], tuple[float, float, float, float, float], tuple[int, int, int], int, bool, tuple[int | None, int | None]]]: ...
+def decode_attdef_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, str | None, str | None, tuple[float, float, float], tuple[float, float, float] | None, tuple[float, float, float], tuple[float, float, float, float, float], tuple[int, int, int], int, bool, tuple[int | None, int | None]]]: ...
+def decode_mtext_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, float, int, int, tuple[int, float | None, int | None, int | None, int | None], int | None]]: ...
+def decode_leader_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, list[tuple[float, float, float]]]]: ...
+def decode_mleader_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, list[tuple[tuple[float, float, float], tuple[float, float, float], float, list[list[tuple[float, float, float]]]]], str | None, tuple[float, float, float], tuple[float, float, float], float, float, int]]: ...
+def decode_hatch_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, bool, bool, float, tuple[float, float, float], list[tuple[bool, list[tuple[float, float]]]], tuple[int, int, float, float, bool, list[tuple[float, tuple[float, float], tuple[float, float], list[float]]]] | None]]: ...
+def decode_tolerance_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, float, int | None]]: ...
+def decode_mline_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, int, tuple[float, float, float], tuple[float, float, float], int, int, list[tuple[tuple[float, float, float], tuple[float, float, float], tuple[float, float, float]]], int | None]]: ...
+def decode_dimension_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[str, tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]]: ...
+def decode_dim_linear_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]: ...
+def decode_dim_ordinate_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]: ...
+def decode_dim_aligned_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]: ...
+def decode_dim_ang3pt_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]: ...
+def decode_dim_ang2ln_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]: ...
+def decode_dim_diameter_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]: ...
+def decode_dim_radius_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]: ...
+def decode_insert_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, float, float, float, float, float, float, str | None]]: ...
+def decode_insert_owner_handles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
+def decode_minsert_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, float, float, float, float, float, float, float, tuple[int, int, float, float, str | None]]]: ...
+def decode_insert_minsert_entities(
+    path: str,
+    limit: int | None = ...,
+) -> tuple[list[tuple[int, float, float, float, float, float, float, float, str | None]], list[tuple[int, float, float, float, float, float, float, float, tuple[int, int, float, float, str | None]]]]: ...
+def decode_insert_minsert_dimension_entities(
+    path: str,
+    limit: int | None = ...,
+) -> tuple[list[tuple[int, float, float, float, float, float, float, float, str | None]], list[tuple[int, float, float, float, float, float, float, float, tuple[int, int, float, float, str | None]]], list[tuple[str, tuple[int, str, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float] | None, tuple[tuple[float, float, float], tuple[float, float, float]], tuple[float, float, float, float], tuple[int, float | None, int | None, int | None, float | None, float], tuple[int | None, int | None], tuple[tuple[float, float, float] | None, tuple[float, float, float] | None, bool | None, tuple[float, float, float] | None, bool | None, tuple[float, float] | None, bool | None, float | None]]]]]: ...
 def decode_block_header_names(path: str, limit: int | None = ...) -> list[tuple[int, str]]: ...
 def decode_block_entity_names(path: str, limit: int | None = ...) -> list[tuple[int, str, str]]: ...
-def decode_polyline_3d_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, int]]: ...
-def decode_polyline_3d_with_vertices(path: str, limit: int | None = ...) -> list[tuple[int, int, bool, list[tuple[float, float, float]]]]: ...
-def decode_polyline_mesh_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, int, int, int, int, int]]: ...
-def decode_polyline_mesh_with_vertices(path: str, limit: int | None = ...) -> list[tuple[int, int, int, int, bool, list[tuple[float, float, float]]]]: ...
-def decode_polyline_pface_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, int]]: ...
-def decode_polyline_pface_with_faces(path: str, limit: int | None = ...) -> list[tuple[int, int, int, list[tuple[float, float, float]], list[tuple[int, int, int, int]]]]: ...
-def decode_solid_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, tuple[float, float, float]]]: ...
-def decode_trace_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, tuple[float, float, float]]]: ...
-def decode_shape_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[float, float, float], float, float, float, float, float, int, tuple[float, float, float], int | None]]: ...
+def decode_block_entity_name_maps(
+    path: str,
+    limit: int | None = ...,
+) -> tuple[list[tuple[int, str]], list[tuple[int, str]]]: ...
+def iter_block_children(path: str, block_record_handle: int) -> list[int]: ...
+def decode_block_base_point(path: str, block_record_handle: int) -> tuple[float, float, float]: ...
+def check_block_references(
+    references: list[tuple[int, int]],
+    max_depth: int,
+) -> tuple[list[tuple[int, int]], str | None, list[int]]: ...
+def decode_xref_blocks(path: str, limit: int | None = ...) -> list[tuple[int, str, str, bool]]: ...
+def build_drawing_index(
+    paths: list[str],
+    threads: int | None = ...,
+) -> list[tuple[str, str, list[str], list[tuple[int, str, str, bool]], list[str], str | None]]: ...
+def decode_polyline_2d_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, float, float, float, float]]: ...
+def decode_polyline_2d_entities_interpreted(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, str, bool, bool, bool, bool, bool, bool, bool, bool]]: ...
+def decode_lwpolyline_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None]]: ...
+def decode_lwpolyline_owner_handles(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None]]: ...
+def decode_polyline_3d_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int]]: ...
+def decode_polyline_3d_with_vertices(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, bool, list[tuple[float, float, float]]]]: ...
+def decode_polyline_mesh_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, int, int, int]]: ...
+def decode_polyline_mesh_with_vertices(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, bool, list[tuple[float, float, float]]]]: ...
+def decode_polyline_pface_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int]]: ...
+def decode_polyline_pface_with_faces(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, list[tuple[float, float, float]], list[tuple[int, int, int, int]], list[tuple[int | None, int | None]]]]: ...
+def decode_mesh_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, list[tuple[float, float, float]], list[list[int]], list[tuple[int, int]], list[float]]]: ...
+def decode_solid_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, tuple[float, float, float]]]: ...
+def decode_trace_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], tuple[float, float, float], float, tuple[float, float, float]]]: ...
+def decode_shape_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], float, float, float, float, float, int, tuple[float, float, float], int | None]]: ...
 def decode_viewport_entities(path: str, limit: int | None = ...) -> list[tuple[int]]: ...
+def decode_viewport_frozen_layers(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, list[int]]]: ...
 def decode_oleframe_entities(path: str, limit: int | None = ...) -> list[tuple[int]]: ...
 def decode_ole2frame_entities(path: str, limit: int | None = ...) -> list[tuple[int]]: ...
-def decode_long_transaction_entities(path: str, limit: int | None = ...) -> list[tuple[int, int | None, list[int], int | None, int | None, int | None, int | None, list[int]]]: ...
+def decode_long_transaction_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int | None, list[int], int | None, int | None, int | None, int | None, list[int]]]: ...
 def decode_region_entities(path: str, limit: int | None = ...) -> list[tuple[int, list[int]]]: ...
 def decode_3dsolid_entities(path: str, limit: int | None = ...) -> list[tuple[int, list[int]]]: ...
 def decode_body_entities(path: str, limit: int | None = ...) -> list[tuple[int, list[int]]]: ...
-def decode_ray_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float]]]: ...
-def decode_xline_entities(path: str, limit: int | None = ...) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float]]]: ...
-def decode_polyline_2d_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, int, float, float, float, float]]: ...
-def decode_polyline_2d_entities_interpreted(path: str, limit: int | None = ...) -> list[tuple[int, int, int, str, bool, bool, bool, bool, bool, bool, bool, bool]]: ...
-def decode_lwpolyline_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None]]: ...
-def decode_polyline_2d_with_vertices(path: str, limit: int | None = ...) -> list[tuple[int, int, list[tuple[float, float, float]]]]: ...
-def decode_polyline_2d_with_vertices_interpolated(path: str, segments_per_span: int = ..., limit: int | None = ...) -> list[tuple[int, int, bool, list[tuple[float, float, float]]]]: ...
-def decode_vertex_2d_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, float, float, float, float, float, float, float]]: ...
-def decode_vertex_3d_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, float, float, float]]: ...
-def decode_vertex_mesh_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, float, float, float]]: ...
-def decode_vertex_pface_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, float, float, float]]: ...
-def decode_vertex_pface_face_entities(path: str, limit: int | None = ...) -> list[tuple[int, int, int, int, int]]: ...
-def decode_polyline_2d_with_vertex_data(path: str, limit: int | None = ...) -> list[tuple[int, int, list[tuple[float, float, float, float, float, float, float, int]]]]: ...
-def decode_polyline_sequence_members(path: str, limit: int | None = ...) -> list[tuple[int, str, list[int], list[int], int | None]]: ...
+def decode_ray_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float]]]: ...
+def decode_light_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, int, bool, tuple[int, int | None], float, tuple[float, float, float], tuple[float, float, float], int, float, float, bool]]: ...
+def decode_rtext_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float], float, float, int, str | None, int | None]]: ...
+def decode_arc_aligned_text_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str | None, tuple[str | None, str | None, str | None], tuple[float, float, float], float, tuple[float, float], tuple[float, float, float], tuple[float, float, float], tuple[bool, int, int, int], bool, tuple[float, float, float], int | None]]: ...
+def decode_xline_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, tuple[float, float, float], tuple[float, float, float]]]: ...
+def decode_polyline_2d_with_vertices(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, bool, list[tuple[float, float, float]], list[float], list[tuple[float, float]], list[float]]]: ...
+def decode_polyline_2d_with_vertices_interpolated(
+    path: str,
+    segments_per_span: int = ...,
+    limit: int | None = ...,
+) -> list[tuple[int, int, bool, list[tuple[float, float, float]]]]: ...
+def decode_vertex_2d_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, float, float, float, float, float, float, float]]: ...
+def decode_vertex_3d_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, float, float, float]]: ...
+def decode_vertex_mesh_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, float, float, float]]: ...
+def decode_vertex_pface_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, float, float, float]]: ...
+def decode_vertex_pface_face_entities(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, int, int, int]]: ...
+def decode_polyline_2d_with_vertex_data(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, int, list[tuple[float, float, float, float, float, float, float, int]]]]: ...
+def decode_polyline_sequence_members(
+    path: str,
+    limit: int | None = ...,
+) -> list[tuple[int, str, list[int], list[int], int | None]]: ...
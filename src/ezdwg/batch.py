@@ -0,0 +1,169 @@
+from __future__ import annotations
+
+import time
+from concurrent.futures import ThreadPoolExecutor, as_completed
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Callable, Iterable
+
+from . import raw
+from .convert import to_dxf
+from .document import read
+from .render import to_png
+
+BatchOperation = Callable[[Path, Path], Any]
+
+
+@dataclass(frozen=True)
+class BatchProgress:
+    completed: int
+    total: int
+    path: str
+    ok: bool
+
+
+@dataclass(frozen=True)
+class BatchFileResult:
+    path: str
+    outputs: dict[str, Any] = field(default_factory=dict)
+    errors: dict[str, str] = field(default_factory=dict)
+    elapsed: float = 0.0
+
+    @property
+    def ok(self) -> bool:
+        return not self.errors
+
+
+@dataclass(frozen=True)
+class BatchResult:
+    files: list[BatchFileResult]
+
+    @property
+    def succeeded(self) -> list[BatchFileResult]:
+        return [item for item in self.files if item.ok]
+
+    @property
+    def failed(self) -> list[BatchFileResult]:
+        return [item for item in self.files if not item.ok]
+
+
+def _audit(path: Path, _output_dir: Path) -> dict[str, Any]:
+    doc = read(str(path))
+    counts: dict[str, int] = {}
+    for entity in doc.modelspace().query():
+        counts[entity.dxftype] = counts.get(entity.dxftype, 0) + 1
+    return {
+        "version": doc.version,
+        "objects": len(raw.list_object_map_entries(str(path))),
+        "entities": sum(counts.values()),
+        "entity_counts": dict(sorted(counts.items())),
+    }
+
+
+def _convert(path: Path, output_dir: Path) -> str:
+    output_path = output_dir / f"{path.stem}.dxf"
+    return to_dxf(str(path), str(output_path)).output_path
+
+
+def _extract_text(path: Path, _output_dir: Path) -> list[str]:
+    doc = read(str(path))
+    return [
+        str(entity.dxf.get("text", ""))
+        for entity in doc.modelspace().query("TEXT MTEXT")
+    ]
+
+
+def _thumbnail(path: Path, output_dir: Path) -> str:
+    output_path = output_dir / f"{path.stem}.png"
+    to_png(str(path), str(output_path), width=256)
+    return str(output_path)
+
+
+OPERATIONS: dict[str, BatchOperation] = {
+    "audit": _audit,
+    "convert": _convert,
+    "extract_text": _extract_text,
+    "thumbnail": _thumbnail,
+}
+
+
+def process_directory(
+    directory: str,
+    ops: Iterable[str | tuple[str, BatchOperation]],
+    parallelism: int = 1,
+    *,
+    output_dir: str | None = None,
+    pattern: str = "*.dwg",
+    recursive: bool = False,
+    progress: Callable[[BatchProgress], None] | None = None,
+) -> BatchResult:
+    """Run ``ops`` over every DWG in ``directory``.
+
+    ``ops`` holds names from ``OPERATIONS`` or ``(name, callable)`` pairs,
+    where the callable receives the input path and the output directory.
+    Errors are recorded per file and operation instead of being raised, and
+    ``progress`` is called from the calling thread after each file.
+    """
+    if parallelism < 1:
+        raise ValueError(f"parallelism must be at least 1, got {parallelism}")
+    resolved_ops = [_resolve_operation(op) for op in ops]
+    source_dir = Path(directory)
+    if not source_dir.is_dir():
+        raise ValueError(f"not a directory: {source_dir}")
+    target_dir = Path(output_dir) if output_dir is not None else source_dir
+    target_dir.mkdir(parents=True, exist_ok=True)
+
+    matches = source_dir.rglob(pattern) if recursive else source_dir.glob(pattern)
+    paths = sorted(path for path in matches if path.is_file())
+    results: dict[Path, BatchFileResult] = {}
+    with ThreadPoolExecutor(max_workers=parallelism) as executor:
+        futures = {
+            executor.submit(_process_file, path, resolved_ops, target_dir): path
+            for path in paths
+        }
+        for future in as_completed(futures):
+            path = futures[future]
+            result = future.result()
+            results[path] = result
+            if progress is not None:
+                progress(
+                    BatchProgress(
+                        completed=len(results),
+                        total=len(paths),
+                        path=result.path,
+                        ok=result.ok,
+                    )
+                )
+    return BatchResult(files=[results[path] for path in paths])
+
+
+def _resolve_operation(op: str | tuple[str, BatchOperation]) -> tuple[str, BatchOperation]:
+    if isinstance(op, str):
+        func = OPERATIONS.get(op)
+        if func is None:
+            choices = ", ".join(sorted(OPERATIONS))
+            raise ValueError(f"unknown batch operation: {op} (expected one of {choices})")
+        return op, func
+    name, func = op
+    return str(name), func
+
+
+def _process_file(
+    path: Path,
+    ops: list[tuple[str, BatchOperation]],
+    output_dir: Path,
+) -> BatchFileResult:
+    started = time.perf_counter()
+    outputs: dict[str, Any] = {}
+    errors: dict[str, str] = {}
+    for name, func in ops:
+        try:
+            outputs[name] = func(path, output_dir)
+        except Exception as exc:
+            errors[name] = f"{type(exc).__name__}: {exc}"
+    return BatchFileResult(
+        path=str(path),
+        outputs=outputs,
+        errors=errors,
+        elapsed=time.perf_counter() - started,
+    )
@@ -0,0 +1,90 @@
+from __future__ import annotations
+
+from . import raw
+from .convert import _is_layout_pseudo_block_name, _resolve_layout
+from .display_list import _block_definition, _block_handles_by_name
+from .document import Document, Layout, _block_header_name_rows
+
+# Nesting depth past which blocks are rejected, also when build_display_list
+# expands them or to_dxf flattens them.
+DEFAULT_MAX_BLOCK_DEPTH = 16
+
+
+class BlockReferenceError(ValueError):
+    """Blocks that cannot be expanded.
+
+    ``kind`` is ``"cycle"`` when blocks insert each other in a loop; then
+    ``blocks`` lists the loop with its first block repeated at the end.
+    ``kind`` is ``"depth"`` when an INSERT of ``blocks[0]`` expands more
+    than ``max_depth`` levels deep; then ``blocks`` is the deepest chain.
+    """
+
+    def __init__(self, kind: str, blocks: list[str], max_depth: int) -> None:
+        self.kind = kind
+        self.blocks = blocks
+        self.max_depth = max_depth
+        chain = " -> ".join(blocks[: max_depth + 1])
+        if len(blocks) > max_depth + 1:
+            chain += " -> ..."
+        if kind == "cycle":
+            message = f"circular block reference: {chain}"
+        else:
+            message = f"blocks nest {len(blocks)} levels deep, more than {max_depth}: {chain}"
+        super().__init__(message)
+
+
+def check_block_references(
+    source: str | Document | Layout,
+    *,
+    max_depth: int = DEFAULT_MAX_BLOCK_DEPTH,
+) -> dict[str, int]:
+    """Nesting depth of every block of the drawing, by name.
+
+    The depth counts the block levels an INSERT of the block expands to:
+    1 for a block without nested INSERT or MINSERT references. Raises
+    ``BlockReferenceError`` when blocks reference each other in a loop or
+    nest more than ``max_depth`` levels deep. Model and paper space are
+    not blocks here.
+    """
+    if max_depth < 1:
+        raise ValueError("max_depth must be at least 1")
+    _, layout = _resolve_layout(source)
+    decode_path = layout.doc.decode_path or layout.doc.path
+    names: dict[str, str] = {}
+    for row in _block_header_name_rows(decode_path):
+        if len(row) >= 2 and isinstance(row[1], str) and row[1].strip():
+            names.setdefault(row[1].strip().upper(), row[1].strip())
+    inserted = {
+        entity.handle: entity.dxf.get("name") for entity in layout.query(["INSERT", "MINSERT"])
+    }
+
+    keys: list[str] = []
+    ids: dict[str, int] = {}
+
+    def block_id(name: str) -> int:
+        key = name.strip().upper()
+        if key not in ids:
+            ids[key] = len(keys)
+            keys.append(key)
+        return ids[key]
+
+    defined: list[str] = []
+    references: list[tuple[int, int]] = []
+    for key in _block_handles_by_name(decode_path):
+        block = _block_definition(decode_path, key)
+        if block is None or _is_layout_pseudo_block_name(key):
+            continue
+        defined.append(key)
+        owner = block_id(key)
+        for handle in block[2]:
+            name = inserted.get(handle)
+            if isinstance(name, str) and name.strip():
+                references.append((owner, block_id(name)))
+
+    depths, kind, chain = raw.check_block_references(references, max_depth)
+    if kind is not None:
+        raise BlockReferenceError(
+            kind, [names.get(keys[index], keys[index]) for index in chain], max_depth
+        )
+    by_id = dict(depths)
+    return {names.get(key, key): by_id.get(ids[key], 1) for key in defined}
@@ -0,0 +1,173 @@
+from __future__ import annotations
+
+import hashlib
+import inspect
+import marshal
+import os
+import sys
+import threading
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any, Callable
+
+from . import _core, raw
+
+CACHE_DIR_ENV = "EZDWG_CACHE_DIR"
+
+# Callback arguments cannot be part of a cache key.
+_UNCACHEABLE_PARAMS = frozenset({"progress", "tessellator"})
+_CACHED_PREFIXES = ("decode_", "list_object_")
+
+
+@dataclass(frozen=True)
+class CacheInfo:
+    directory: str | None
+    hits: int
+    misses: int
+    stores: int
+    functions: tuple[str, ...]
+
+
+class _CacheState:
+    def __init__(self) -> None:
+        self.lock = threading.Lock()
+        self.directory: str | None = None
+        self.originals: dict[str, Callable[..., Any]] = {}
+        self.hits = 0
+        self.misses = 0
+        self.stores = 0
+
+
+_state = _CacheState()
+
+
+def is_available() -> bool:
+    """Whether the extension was built with the `cache` Cargo feature."""
+    return hasattr(_core, "cache_file_key")
+
+
+def default_cache_dir() -> Path:
+    configured = os.environ.get(CACHE_DIR_ENV)
+    if configured:
+        return Path(configured)
+    base = os.environ.get("XDG_CACHE_HOME") or Path.home() / ".cache"
+    return Path(base) / "ezdwg"
+
+
+def enable_cache(directory: str | os.PathLike[str] | None = None) -> Path:
+    """Serve the path-based `raw` decode functions from an on-disk cache.
+
+    Tables are keyed by the content hash of the drawing (see `ezdwg.cache`),
+    so repeated runs over unchanged files skip decoding. Calling it again
+    switches the directory.
+    """
+    if not is_available():
+        raise RuntimeError("ezdwg was built without the `cache` feature")
+    root = Path(directory) if directory is not None else default_cache_dir()
+    root.mkdir(parents=True, exist_ok=True)
+    with _state.lock:
+        _state.directory = str(root)
+        if not _state.originals:
+            for name in _cacheable_functions():
+                original = getattr(raw, name)
+                _state.originals[name] = original
+                setattr(raw, name, _cached(name, original))
+    return root
+
+
+def disable_cache() -> None:
+    """Restore the uncached `raw` functions. Stored tables are kept."""
+    with _state.lock:
+        for name, original in _state.originals.items():
+            setattr(raw, name, original)
+        _state.originals.clear()
+        _state.directory = None
+
+
+def clear_cache(directory: str | os.PathLike[str] | None = None) -> int:
+    """Delete every stored table and return the number of files removed."""
+    if not is_available():
+        raise RuntimeError("ezdwg was built without the `cache` feature")
+    if directory is None:
+        directory = _state.directory or default_cache_dir()
+    return _core.cache_clear(str(directory))
+
+
+def cache_info() -> CacheInfo:
+    with _state.lock:
+        return CacheInfo(
+            directory=_state.directory,
+            hits=_state.hits,
+            misses=_state.misses,
+            stores=_state.stores,
+            functions=tuple(_state.originals),
+        )
+
+
+def _cacheable_functions() -> list[str]:
+    names = []
+    for name in raw.__all__:
+        function = getattr(raw, name, None)
+        if function is None or not name.startswith(_CACHED_PREFIXES):
+            continue
+        try:
+            params = list(inspect.signature(function).parameters)
+        except (TypeError, ValueError):
+            continue
+        if params and params[0] == "path" and not _UNCACHEABLE_PARAMS.intersection(params):
+            names.append(name)
+    return names
+
+
+def _cached(name: str, function: Callable[..., Any]) -> Callable[..., Any]:
+    def wrapper(path: Any, *args: Any, **kwargs: Any) -> Any:
+        directory = _state.directory
+        if directory is None:
+            return function(path, *args, **kwargs)
+        try:
+            path = os.fspath(path)
+            table = _table_name(name, args, kwargs)
+            key = _core.cache_file_key(directory, path)
+        except (OSError, TypeError, ValueError):
+            # Missing files and unhashable arguments raise or run uncached.
+            return function(path, *args, **kwargs)
+
+        try:
+            payload = _core.cache_load(directory, key, table)
+        except OSError:
+            payload = None
+        if payload is not None:
+            try:
+                result = marshal.loads(payload)
+            except (EOFError, ValueError, TypeError):
+                result = None
+            else:
+                _count("hits")
+                return result
+
+        _count("misses")
+        result = function(path, *args, **kwargs)
+        try:
+            _core.cache_store(directory, key, table, marshal.dumps(result))
+        except (OSError, ValueError):
+            return result
+        _count("stores")
+        return result
+
+    wrapper.__name__ = name
+    wrapper.__qualname__ = name
+    wrapper.__doc__ = function.__doc__
+    wrapper.__wrapped__ = function  # type: ignore[attr-defined]
+    return wrapper
+
+
+def _table_name(name: str, args: tuple[Any, ...], kwargs: dict[str, Any]) -> str:
+    arguments = marshal.dumps((args, tuple(sorted(kwargs.items()))))
+    digest = hashlib.blake2b(arguments, digest_size=8).hexdigest()
+    # marshal output is only guaranteed to round-trip within one Python version.
+    return f"{name}-py{sys.version_info[0]}{sys.version_info[1]}-{digest}"
+
+
+def _count(field: str) -> None:
+    with _state.lock:
+        setattr(_state, field, getattr(_state, field) + 1)
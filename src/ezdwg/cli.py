@@ -9,6 +9,10 @@ from typing import Sequence
 
 from .convert import to_dwg, to_dxf
 from .document import SUPPORTED_ENTITY_TYPES, read
+from .drawing_index import build_index
+from .explain import explain_object
+from .layer_map import LayerMap
+from .profiling import profile
 from . import raw
 
 _RECORD_DIAGNOSTIC_TYPES: tuple[str, ...] = ("LONG_TRANSACTION", "OLEFRAME", "OLE2FRAME")
@@ -110,6 +114,49 @@ def _build_parser() -> argparse.ArgumentParser:
         help="Show expanded diagnostics (e.g. more unknown handle/type-code entries).",
     )
 
+    profile_parser = subparsers.add_parser(
+        "profile",
+        help="Show object sizes per type and layer and the largest objects.",
+    )
+    profile_parser.add_argument("path", help="Path to DWG file.")
+    profile_parser.add_argument(
+        "--top",
+        type=int,
+        default=10,
+        help="Number of entries to list per section (default: 10).",
+    )
+
+    index_parser = subparsers.add_parser(
+        "index",
+        help="Index block names, xrefs and text of every DWG in a directory.",
+    )
+    index_parser.add_argument("directory", help="Directory to scan recursively.")
+    index_parser.add_argument("-o", "--output", help="Save the index as JSON to this path.")
+    index_parser.add_argument("--block", help="List the files defining this block.")
+    index_parser.add_argument("--text", help="List TEXT/MTEXT/ATTRIB strings containing this.")
+    index_parser.add_argument(
+        "--xrefs",
+        action="store_true",
+        help="List xref links between the indexed files.",
+    )
+    index_parser.add_argument(
+        "--threads",
+        type=int,
+        default=None,
+        help="Number of worker threads (default: one per core).",
+    )
+
+    explain_parser = subparsers.add_parser(
+        "explain",
+        help="Dump one object's bytes annotated with the fields the decoder reads.",
+    )
+    explain_parser.add_argument("path", help="Path to DWG file.")
+    explain_parser.add_argument(
+        "handle",
+        type=lambda value: int(value, 0),
+        help="Object handle, decimal or 0x-prefixed hex.",
+    )
+
     convert_parser = subparsers.add_parser(
         "convert",
         help="Convert DWG to DXF using ezdxf as the writing backend.",
@@ -171,6 +218,12 @@ def _build_parser() -> argparse.ArgumentParser:
             "DIMENSION conversion, legacy restores geometric suppression heuristics."
         ),
     )
+    convert_parser.add_argument(
+        "--layer-map",
+        default=None,
+        metavar="CSV",
+        help="Layer mapping table with source,target,color,linetype,regex columns.",
+    )
 
     write_parser = subparsers.add_parser(
         "write",
@@ -193,6 +246,12 @@ def _build_parser() -> argparse.ArgumentParser:
         action="store_true",
         help="Fail if any entity cannot be written.",
     )
+    write_parser.add_argument(
+        "--layer-map",
+        default=None,
+        metavar="CSV",
+        help="Layer mapping table with source,target,color,linetype,regex columns.",
+    )
     return parser
 
 
@@ -292,6 +351,16 @@ def _run_inspect(path: str, *, verbose: bool = False) -> int:
     print(f"file: {file_path}")
     print(f"version: {doc.version}")
     print(f"decode_version: {doc.decode_version}")
+    try:
+        _version, flavor, _standard, layout_warnings, _sections = raw.describe_file_layout(
+            str(file_path)
+        )
+    except Exception:
+        flavor, layout_warnings = None, []
+    if flavor is not None:
+        print(f"layout: {flavor}")
+    for warning in layout_warnings:
+        print(f"layout_warning: {warning}")
     print(f"total_entities: {total}")
     for dxftype in SUPPORTED_ENTITY_TYPES:
         count = counts.get(dxftype, 0)
@@ -356,6 +425,95 @@ def _run_inspect(path: str, *, verbose: bool = False) -> int:
     return 0
 
 
+def _run_profile(path: str, *, top: int = 10) -> int:
+    file_path = Path(path)
+    if not file_path.exists():
+        print(f"error: file not found: {file_path}", file=sys.stderr)
+        return 2
+
+    try:
+        result = profile(str(file_path), top=top)
+    except Exception as exc:
+        print(f"error: failed to profile DWG: {exc}", file=sys.stderr)
+        return 2
+
+    print(f"file: {result.path}")
+    print(f"file_size: {result.file_size}")
+    print(f"objects: {result.object_count}")
+    print(f"object_bytes: {result.object_bytes}")
+    for bucket in result.by_type[:top]:
+        print(f"type[{bucket.name}]: {bucket.count} objects, {bucket.bytes} bytes")
+    for bucket in result.by_layer[:top]:
+        name = "(unresolved)" if bucket.name is None else bucket.name
+        print(f"layer[{name}]: {bucket.count} entities, {bucket.bytes} bytes")
+    for item in result.heaviest:
+        layer = "" if item.layer is None else f" layer={item.layer}"
+        print(
+            f"heaviest: handle=0x{item.handle:X} type={item.type_name}{layer} "
+            f"offset={item.offset} size={item.size}"
+        )
+    return 0
+
+
+def _run_index(
+    directory: str,
+    *,
+    output: str | None = None,
+    block: str | None = None,
+    text: str | None = None,
+    xrefs: bool = False,
+    threads: int | None = None,
+) -> int:
+    if not Path(directory).is_dir():
+        print(f"error: directory not found: {directory}", file=sys.stderr)
+        return 2
+
+    try:
+        index = build_index(directory, threads=threads)
+    except Exception as exc:
+        print(f"error: failed to index drawings: {exc}", file=sys.stderr)
+        return 2
+
+    print(f"root: {index.root}")
+    print(f"files: {len(index.files)}")
+    print(f"blocks: {len(index.block_index())}")
+    print(f"xrefs: {sum(len(drawing.xrefs) for drawing in index.files)}")
+    print(f"errors: {len(index.errors)}")
+    for path, error in index.errors.items():
+        print(f"error[{path}]: {error}")
+    if block is not None:
+        for path in index.files_with_block(block):
+            print(f"block[{block}]: {path}")
+    if text is not None:
+        for hit in index.search_text(text):
+            print(f"text[{hit.path}]: {hit.text}")
+    if xrefs:
+        for drawing in index.files:
+            for link in drawing.xrefs:
+                target = link.target
+                if target is None:
+                    target = f"(unresolved) {link.stored_path}"
+                print(f"xref: {link.source} -> {target} [{link.block}]")
+    if output is not None:
+        index.save(output)
+        print(f"output: {output}")
+    return 0
+
+
+def _run_explain(path: str, handle: int) -> int:
+    file_path = Path(path)
+    if not file_path.exists():
+        print(f"error: file not found: {file_path}", file=sys.stderr)
+        return 2
+
+    try:
+        explanation = explain_object(str(file_path), handle)
+    except Exception as exc:
+        print(f"error: failed to explain object: {exc}", file=sys.stderr)
+        return 2
+    return 0 if explanation.error is None else 1
+
+
 def _run_convert(
     input_path: str,
     output_path: str,
@@ -369,6 +527,7 @@ def _run_convert(
     explode_dimensions: bool = False,
     flatten_inserts: bool = False,
     dim_block_policy: str = "smart",
+    layer_map: str | None = None,
 ) -> int:
     dwg_path = Path(input_path)
     if not dwg_path.exists():
@@ -388,6 +547,7 @@ def _run_convert(
             explode_dimensions=explode_dimensions,
             flatten_inserts=flatten_inserts,
             dim_block_policy=dim_block_policy,
+            layer_map=LayerMap.from_csv(layer_map) if layer_map else None,
         )
     except Exception as exc:
         print(f"error: failed to convert DWG to DXF: {exc}", file=sys.stderr)
@@ -410,6 +570,7 @@ def _run_write(
     types: str | None = None,
     dwg_version: str = "AC1015",
     strict: bool = False,
+    layer_map: str | None = None,
 ) -> int:
     dwg_path = Path(input_path)
     if not dwg_path.exists():
@@ -423,6 +584,7 @@ def _run_write(
             types=types,
             version=dwg_version,
             strict=strict,
+            layer_map=LayerMap.from_csv(layer_map) if layer_map else None,
         )
     except Exception as exc:
         print(f"error: failed to write DWG: {exc}", file=sys.stderr)
@@ -445,6 +607,19 @@ def main(argv: Sequence[str] | None = None) -> int:
 
     if args.command == "inspect":
         return _run_inspect(args.path, verbose=bool(args.verbose))
+    if args.command == "profile":
+        return _run_profile(args.path, top=int(args.top))
+    if args.command == "index":
+        return _run_index(
+            args.directory,
+            output=args.output,
+            block=args.block,
+            text=args.text,
+            xrefs=bool(args.xrefs),
+            threads=args.threads,
+        )
+    if args.command == "explain":
+        return _run_explain(args.path, int(args.handle))
     if args.command == "convert":
         explode_dimensions = bool(args.explode_dimensions)
         if bool(args.native_dimensions):
@@ -461,6 +636,7 @@ def main(argv: Sequence[str] | None = None) -> int:
             explode_dimensions=explode_dimensions,
             flatten_inserts=bool(args.flatten_inserts),
             dim_block_policy=str(args.dim_block_policy),
+            layer_map=args.layer_map,
         )
     if args.command == "write":
         return _run_write(
@@ -469,6 +645,7 @@ def main(argv: Sequence[str] | None = None) -> int:
             types=args.types,
             dwg_version=args.dwg_version,
             strict=bool(args.strict),
+            layer_map=args.layer_map,
         )
 
     parser.print_help()
@@ -0,0 +1,322 @@
+from __future__ import annotations
+
+import math
+from typing import Iterable
+
+from .convert import _resolve_layout
+from .document import Document, Layout
+from .entity import Entity
+
+CLIP_TYPES = ("LINE", "ARC", "CIRCLE", "LWPOLYLINE")
+
+# Curve parameters closer than this to a segment end count as the end.
+_EPS = 1e-9
+
+Rect = tuple[float, float, float, float]
+
+
+def clip_entities(
+    source: str | Document | Layout | Iterable[Entity],
+    rect: tuple[float, float, float, float],
+    types: str | Iterable[str] | None = None,
+) -> list[Entity]:
+    """Trim modelspace geometry to the window ``(min_x, min_y, max_x, max_y)``.
+
+    LINE, ARC, CIRCLE and LWPOLYLINE entities are cut at the window edges in
+    the XY plane; the parts inside are returned as new entities that keep
+    the handle and the other ``dxf`` attributes of their source, so one
+    entity may yield several pieces. A cut CIRCLE becomes ARC pieces and a
+    cut LWPOLYLINE becomes open LWPOLYLINE pieces. Entities entirely inside
+    are returned unchanged; entities outside are dropped. ``source`` may
+    also be an iterable of already decoded entities.
+    """
+    window = _normalize_rect(rect)
+    selected = _normalize_types(types)
+    if isinstance(source, (str, Document, Layout)):
+        _, layout = _resolve_layout(source)
+        entities: Iterable[Entity] = layout.query(sorted(selected))
+    else:
+        entities = source
+    out: list[Entity] = []
+    for entity in entities:
+        if entity.dxftype in selected:
+            out.extend(_clip_entity(entity, window))
+    return out
+
+
+def _normalize_rect(rect: tuple[float, float, float, float]) -> Rect:
+    min_x, min_y, max_x, max_y = (float(value) for value in rect)
+    if not all(math.isfinite(value) for value in (min_x, min_y, max_x, max_y)):
+        raise ValueError("clip window must be finite")
+    if min_x > max_x or min_y > max_y:
+        raise ValueError("clip window must be (min_x, min_y, max_x, max_y)")
+    return min_x, min_y, max_x, max_y
+
+
+def _normalize_types(types: str | Iterable[str] | None) -> set[str]:
+    if types is None:
+        return set(CLIP_TYPES)
+    if isinstance(types, str):
+        types = types.replace(",", " ").split()
+    selected = {str(name).strip().upper() for name in types if str(name).strip()}
+    unsupported = sorted(selected - set(CLIP_TYPES))
+    if unsupported:
+        raise ValueError(f"cannot clip entity types: {', '.join(unsupported)}")
+    return selected
+
+
+def _clip_entity(entity: Entity, rect: Rect) -> list[Entity]:
+    dxf = entity.dxf
+    try:
+        if entity.dxftype == "LINE":
+            return _clip_line(entity, tuple(dxf["start"]), tuple(dxf["end"]), rect)
+        if entity.dxftype == "CIRCLE":
+            return _clip_circle(entity, rect, 0.0, 360.0)
+        if entity.dxftype == "ARC":
+            start = float(dxf["start_angle"])
+            sweep = (float(dxf["end_angle"]) - start) % 360.0
+            return _clip_circle(entity, rect, start, sweep or 360.0)
+        if entity.dxftype == "LWPOLYLINE":
+            return _clip_lwpolyline(entity, rect)
+    except (KeyError, TypeError, ValueError, IndexError):
+        return []
+    return []
+
+
+def _clip_line(entity: Entity, start: tuple, end: tuple, rect: Rect) -> list[Entity]:
+    span = _line_span(start, end, rect)
+    if span is None:
+        return []
+    t0, t1 = span
+    if t0 <= _EPS and t1 >= 1.0 - _EPS:
+        return [entity]
+    return [
+        Entity(
+            entity.dxftype,
+            entity.handle,
+            {**entity.dxf, "start": _lerp(start, end, t0), "end": _lerp(start, end, t1)},
+        )
+    ]
+
+
+def _line_span(start: tuple, end: tuple, rect: Rect) -> tuple[float, float] | None:
+    """Liang-Barsky: the parameter range of the segment inside ``rect``."""
+    min_x, min_y, max_x, max_y = rect
+    dx = end[0] - start[0]
+    dy = end[1] - start[1]
+    t0, t1 = 0.0, 1.0
+    for p, q in (
+        (-dx, start[0] - min_x),
+        (dx, max_x - start[0]),
+        (-dy, start[1] - min_y),
+        (dy, max_y - start[1]),
+    ):
+        if p == 0.0:
+            if q < 0.0:
+                return None
+            continue
+        t = q / p
+        if p < 0.0:
+            t0 = max(t0, t)
+        else:
+            t1 = min(t1, t)
+        if t0 > t1:
+            return None
+    return t0, t1
+
+
+def _lerp(start: tuple, end: tuple, t: float) -> tuple[float, ...]:
+    return tuple(a + (b - a) * t for a, b in zip(start, end))
+
+
+def _clip_circle(entity: Entity, rect: Rect, start_deg: float, sweep_deg: float) -> list[Entity]:
+    dxf = entity.dxf
+    center = tuple(dxf["center"])
+    radius = float(dxf["radius"])
+    spans = _arc_spans(center, radius, math.radians(start_deg), math.radians(sweep_deg), rect)
+    if not spans:
+        return []
+    if spans == [(0.0, 1.0)]:
+        return [entity]
+    full_circle = sweep_deg >= 360.0
+    if full_circle and len(spans) > 1 and spans[0][0] == 0.0 and spans[-1][1] == 1.0:
+        # Join the piece running through the start angle.
+        spans = [(spans[-1][0], spans[0][1] + 1.0)] + spans[1:-1]
+    pieces = []
+    base = {key: value for key, value in dxf.items() if key not in ("start_angle", "end_angle")}
+    for t0, t1 in spans:
+        pieces.append(
+            Entity(
+                "ARC",
+                entity.handle,
+                {
+                    **base,
+                    "start_angle": (start_deg + t0 * sweep_deg) % 360.0,
+                    "end_angle": (start_deg + t1 * sweep_deg) % 360.0,
+                },
+            )
+        )
+    return pieces
+
+
+def _arc_spans(
+    center: tuple, radius: float, start: float, sweep: float, rect: Rect
+) -> list[tuple[float, float]]:
+    """Parameter ranges of a circular arc inside ``rect``.
+
+    The arc runs from angle ``start`` over ``sweep`` radians (negative for
+    clockwise); parameters are fractions of the sweep.
+    """
+    if radius <= 0.0 or sweep == 0.0:
+        return []
+    min_x, min_y, max_x, max_y = rect
+    cx, cy = center[0], center[1]
+    angles = []
+    for edge_x in (min_x, max_x):
+        ratio = (edge_x - cx) / radius
+        if -1.0 <= ratio <= 1.0:
+            angle = math.acos(ratio)
+            angles.extend((angle, -angle))
+    for edge_y in (min_y, max_y):
+        ratio = (edge_y - cy) / radius
+        if -1.0 <= ratio <= 1.0:
+            angle = math.asin(ratio)
+            angles.extend((angle, math.pi - angle))
+    cuts = [0.0, 1.0]
+    for angle in angles:
+        delta = (angle - start) % math.tau if sweep > 0.0 else (start - angle) % math.tau
+        t = delta / abs(sweep)
+        if _EPS < t < 1.0 - _EPS:
+            cuts.append(t)
+    cuts.sort()
+
+    spans: list[tuple[float, float]] = []
+    for t0, t1 in zip(cuts, cuts[1:]):
+        if t1 - t0 <= _EPS:
+            continue
+        angle = start + (t0 + t1) / 2.0 * sweep
+        x = cx + radius * math.cos(angle)
+        y = cy + radius * math.sin(angle)
+        if not _inside(x, y, rect):
+            continue
+        if spans and abs(spans[-1][1] - t0) <= _EPS:
+            spans[-1] = (spans[-1][0], t1)
+        else:
+            spans.append((t0, t1))
+    return spans
+
+
+def _inside(x: float, y: float, rect: Rect) -> bool:
+    min_x, min_y, max_x, max_y = rect
+    tolerance = _EPS * max(1.0, abs(x), abs(y))
+    return (
+        min_x - tolerance <= x <= max_x + tolerance
+        and min_y - tolerance <= y <= max_y + tolerance
+    )
+
+
+def _clip_lwpolyline(entity: Entity, rect: Rect) -> list[Entity]:
+    dxf = entity.dxf
+    points = [tuple(point) for point in dxf["points"]]
+    count = len(points)
+    if count < 2:
+        return []
+    bulges = list(dxf.get("bulges") or [])
+    widths = list(dxf.get("widths") or [])
+    closed = bool(dxf.get("closed"))
+
+    # Each piece is a list of (point, bulge, (start_width, end_width)) vertices.
+    pieces: list[list[tuple]] = []
+    cut = False
+    continuing = False
+    starts_at_first_vertex = False
+    for index in range(count if closed else count - 1):
+        start = points[index]
+        end = points[(index + 1) % count]
+        bulge = float(bulges[index]) if index < len(bulges) else 0.0
+        start_width, end_width = widths[index] if index < len(widths) else (0.0, 0.0)
+        spans = _segment_spans(start, end, bulge, rect)
+        if spans != [(0.0, 1.0)]:
+            cut = True
+        if not spans:
+            continuing = False
+        for t0, t1 in spans:
+            if not (continuing and t0 <= _EPS):
+                pieces.append([(_segment_point(start, end, bulge, t0), 0.0, (0.0, 0.0))])
+                if index == 0 and t0 <= _EPS:
+                    starts_at_first_vertex = True
+            piece = pieces[-1]
+            piece[-1] = (
+                piece[-1][0],
+                math.tan(math.atan(bulge) * (t1 - t0)),
+                (
+                    start_width + (end_width - start_width) * t0,
+                    start_width + (end_width - start_width) * t1,
+                ),
+            )
+            piece.append((_segment_point(start, end, bulge, t1), 0.0, (0.0, 0.0)))
+            continuing = t1 >= 1.0 - _EPS
+
+    if not cut:
+        return [entity]
+    if closed and continuing and starts_at_first_vertex and len(pieces) > 1:
+        # The last piece runs into the first vertex; join it with the first.
+        pieces[0] = pieces.pop()[:-1] + pieces[0]
+
+    flags = int(dxf.get("flags", 0)) & ~1
+    return [
+        Entity(
+            entity.dxftype,
+            entity.handle,
+            {
+                **dxf,
+                "points": [point for point, _bulge, _width in piece],
+                "bulges": [bulge for _point, bulge, _width in piece],
+                "widths": [width for _point, _bulge, width in piece],
+                "flags": flags,
+                "closed": False,
+            },
+        )
+        for piece in pieces
+    ]
+
+
+def _segment_spans(
+    start: tuple, end: tuple, bulge: float, rect: Rect
+) -> list[tuple[float, float]]:
+    if bulge:
+        center, radius, start_angle, sweep = _bulge_arc(start, end, bulge)
+        return _arc_spans(center, radius, start_angle, sweep, rect)
+    span = _line_span(start, end, rect)
+    if span is None or (span[1] - span[0] <= _EPS and start != end):
+        return []
+    return [span]
+
+
+def _segment_point(start: tuple, end: tuple, bulge: float, t: float) -> tuple[float, ...]:
+    if t <= 0.0:
+        return start
+    if t >= 1.0:
+        return end
+    if not bulge:
+        return _lerp(start, end, t)
+    center, radius, start_angle, sweep = _bulge_arc(start, end, bulge)
+    angle = start_angle + sweep * t
+    point = (center[0] + radius * math.cos(angle), center[1] + radius * math.sin(angle))
+    return point + tuple(start[2:])
+
+
+def _bulge_arc(
+    start: tuple, end: tuple, bulge: float
+) -> tuple[tuple[float, float], float, float, float]:
+    """Center, radius, start angle and signed sweep of a bulged segment."""
+    dx = end[0] - start[0]
+    dy = end[1] - start[1]
+    offset = (1.0 - bulge * bulge) / (4.0 * bulge)
+    center = (
+        (start[0] + end[0]) / 2.0 - offset * dy,
+        (start[1] + end[1]) / 2.0 + offset * dx,
+    )
+    radius = math.hypot(start[0] - center[0], start[1] - center[1])
+    start_angle = math.atan2(start[1] - center[1], start[0] - center[0])
+    return center, radius, start_angle, 4.0 * math.atan(bulge)
@@ -0,0 +1,152 @@
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from functools import lru_cache
+from pathlib import Path
+from typing import Any, Iterable, Mapping
+
+RGB = tuple[int, int, int]
+
+ACI_PALETTE_SIZE = 256
+_PROFILE_FORMAT = 1
+
+
+@dataclass(frozen=True)
+class AciPalette:
+    """ACI index to RGB table used by the image exporters.
+
+    ``entries`` holds 256 colors; entry ``0`` (BYBLOCK) is never used. With
+    ``background_aware``, ACI 7 is drawn black on light backgrounds and white
+    on dark ones, as AutoCAD does, instead of using its entry.
+    """
+
+    entries: tuple[RGB, ...]
+    background_aware: bool = True
+
+    def __post_init__(self) -> None:
+        entries = tuple(_rgb(entry) for entry in self.entries)
+        if len(entries) != ACI_PALETTE_SIZE:
+            raise ValueError(
+                f"an ACI palette needs {ACI_PALETTE_SIZE} entries, got {len(entries)}"
+            )
+        object.__setattr__(self, "entries", entries)
+
+    @classmethod
+    def default(cls) -> AciPalette:
+        """The standard AutoCAD palette."""
+        return cls(_default_entries())
+
+    @classmethod
+    def monochrome(cls, color: Iterable[int] = (0, 0, 0)) -> AciPalette:
+        """Every index drawn in ``color``, like a monochrome plot style."""
+        return cls((_rgb(color),) * ACI_PALETTE_SIZE, background_aware=False)
+
+    @classmethod
+    def grayscale(cls) -> AciPalette:
+        """The standard palette reduced to its luminance."""
+        return cls(tuple(_gray(rgb) for rgb in _default_entries()))
+
+    def with_overrides(self, colors: Mapping[int, Iterable[int]]) -> AciPalette:
+        """Copy with some indices replaced; overriding ACI 7 fixes its color."""
+        entries = list(self.entries)
+        for index, rgb in colors.items():
+            if not 1 <= int(index) <= 255:
+                raise ValueError(f"ACI index must be in 1..255: {index}")
+            entries[int(index)] = _rgb(rgb)
+        return AciPalette(tuple(entries), self.background_aware and 7 not in colors)
+
+    def rgb(self, index: int, background: Iterable[int] | None = None) -> RGB | None:
+        """Color of ACI ``index``, or ``None`` for BYBLOCK, BYLAYER and
+        indices out of range. ACI 7 contrasts with ``background`` when given."""
+        if not 1 <= index <= 255:
+            return None
+        if index == 7 and self.background_aware and background is not None:
+            return _contrast(_rgb(background))
+        return self.entries[index]
+
+    def nearest(self, rgb: Iterable[int]) -> int:
+        """ACI index 1..255 whose entry is closest to ``rgb``, the lowest
+        index on ties, to store a true color in a drawing without them."""
+        r, g, b = _rgb(rgb)
+        return min(
+            range(1, ACI_PALETTE_SIZE),
+            key=lambda index: (
+                (self.entries[index][0] - r) ** 2
+                + (self.entries[index][1] - g) ** 2
+                + (self.entries[index][2] - b) ** 2
+            ),
+        )
+
+    @classmethod
+    def load(cls, path: str) -> AciPalette:
+        """Read a palette saved by ``save``, a JSON list of 256 ``[r, g, b]``
+        entries, or a profile ``{"base": ..., "colors": {...}}`` that overrides
+        some indices of the ``default``, ``monochrome`` or ``grayscale`` base."""
+        payload = json.loads(Path(path).read_text(encoding="utf-8"))
+        if isinstance(payload, list):
+            return cls(tuple(payload))
+        if not isinstance(payload, dict):
+            raise ValueError(f"unsupported palette file: {path}")
+        if "entries" in payload:
+            palette = cls(tuple(payload["entries"]))
+        else:
+            bases = {
+                "default": cls.default,
+                "monochrome": cls.monochrome,
+                "grayscale": cls.grayscale,
+            }
+            base = str(payload.get("base", "default")).lower()
+            if base not in bases:
+                raise ValueError(f"unknown palette base: {base!r}")
+            palette = bases[base]()
+        colors = {int(index): rgb for index, rgb in payload.get("colors", {}).items()}
+        palette = palette.with_overrides(colors)
+        if "background_aware" in payload:
+            palette = AciPalette(palette.entries, bool(payload["background_aware"]))
+        return palette
+
+    def save(self, path: str) -> None:
+        payload = {
+            "format": _PROFILE_FORMAT,
+            "entries": [list(rgb) for rgb in self.entries],
+            "background_aware": self.background_aware,
+        }
+        Path(path).write_text(json.dumps(payload), encoding="utf-8")
+
+
+def as_palette(palette: AciPalette | Mapping[int, Iterable[int]] | None) -> AciPalette | None:
+    """``palette`` as an ``AciPalette``; a mapping overrides the default one."""
+    if palette is None or isinstance(palette, AciPalette):
+        return palette
+    return AciPalette.default().with_overrides(palette)
+
+
+@lru_cache(maxsize=1)
+def _default_entries() -> tuple[RGB, ...]:
+    from .raw import aci_to_rgb
+
+    return ((0, 0, 0),) + tuple(aci_to_rgb(index) or (0, 0, 0) for index in range(1, 256))
+
+
+def _rgb(value: Any) -> RGB:
+    try:
+        r, g, b = (int(channel) for channel in value)
+    except (TypeError, ValueError) as exc:
+        raise ValueError(f"expected an (r, g, b) color: {value!r}") from exc
+    if not all(0 <= channel <= 255 for channel in (r, g, b)):
+        raise ValueError(f"color channels must be in 0..255: {value!r}")
+    return (r, g, b)
+
+
+def _luminance(rgb: RGB) -> float:
+    return 0.299 * rgb[0] + 0.587 * rgb[1] + 0.114 * rgb[2]
+
+
+def _gray(rgb: RGB) -> RGB:
+    level = round(_luminance(rgb))
+    return (level, level, level)
+
+
+def _contrast(background: RGB) -> RGB:
+    return (0, 0, 0) if _luminance(background) >= 128 else (255, 255, 255)
@@ -2,15 +2,18 @@ from __future__ import annotations
 
 import json
 import math
+import re
 import unicodedata
 import weakref
 from dataclasses import dataclass, field
 from pathlib import Path
-from typing import Any, Iterable
+from typing import Any, Callable, Iterable
 
 from . import raw
+from .dimension_render import dimension_block_is_stale
 from .document import (
     Document,
+    DrawingTimes,
     Layout,
     SUPPORTED_ENTITY_TYPES,
     TYPE_ALIASES,
@@ -18,7 +21,7 @@ from .document import (
     _present_supported_types,
     read,
 )
-from .entity import Entity
+from .entity import Entity, round_floats
 from ._convert_utils import (
     _MAX_COORD_ABS,
     _dimension_text,
@@ -41,12 +44,15 @@ from ._convert_utils import (
 from ._convert_row_builders import (
     _as_arc_row,
     _as_circle_row,
+    _as_leader_row,
     _as_line_row,
     _as_lwpolyline_row,
     _as_mtext_row,
     _as_point_row,
     _as_ray_row,
+    _as_text_alignment_row,
     _as_text_row,
+    _as_tolerance_row,
     _as_xline_row,
 )
 from ._convert_polyline2d_spline import (
@@ -56,7 +62,9 @@ from ._convert_polyline2d_spline import (
     _should_write_polyline_2d_as_spline,
 )
 from ._convert_layer import (
+    _is_valid_dxf_layer_name,
     _layer_names_by_handle,
+    _layer_states_by_handle,
     _layer_styles_by_handle,
     _prepare_dxf_layers,
 )
@@ -70,6 +78,7 @@ from ._convert_geometry_filter import (
     _is_plausible_text_content,
     _is_plausible_text_insert,
 )
+from .layer_map import LayerMap
 _BLOCK_EXCLUDED_ENTITY_TYPES = {
     "BLOCK",
     "ENDBLK",
@@ -135,6 +144,8 @@ _DWG_WRITABLE_ENTITY_TYPES = {
     "LWPOLYLINE",
     "TEXT",
     "MTEXT",
+    "TOLERANCE",
+    "LEADER",
 }
 _BLOCK_INSERT_SAFETY_CACHE: weakref.WeakKeyDictionary[Any, dict[str, tuple[bool, bool, float | None]]] = weakref.WeakKeyDictionary()
 _BLOCK_LAYOUT_METRICS_CACHE: weakref.WeakKeyDictionary[Any, dict[str, tuple[int, int, float | None]]] = weakref.WeakKeyDictionary()
@@ -172,6 +183,19 @@ class WriteResult:
     skipped_by_type: dict[str, int]
 
 
+@dataclass(frozen=True)
+class EntityUpdateResult:
+    write: WriteResult
+    updated: list[int]
+    failed: dict[int, str]
+
+
+@dataclass(frozen=True)
+class PurgeResult:
+    write: WriteResult
+    purged: dict[str, list[str]]
+
+
 @dataclass
 class _DimensionWriteContext:
     written_block_refs: set[
@@ -196,115 +220,104 @@ def to_dwg(
     types: str | Iterable[str] | None = None,
     version: str = "AC1015",
     strict: bool = False,
+    application_name: str | None = None,
+    application_version: str | None = None,
+    drawing_times: DrawingTimes | None = None,
+    progress: Callable[[str, int, int | None, int, int | None], object] | None = None,
+    precision: int | None = None,
+    max_lwpolyline_vertices: int | None = 32767,
+    arc_fit_tolerance: float | None = None,
+    preserve_unknown_objects: bool = True,
+    preserve_header_variables: bool = True,
+    layer_renames: dict[str, str] | None = None,
+    layer_colors: dict[str, int] | None = None,
+    layer_map: LayerMap | None = None,
+    entity_layers: dict[int, str] | None = None,
+    entity_colors: dict[int, int] | None = None,
+    entity_linetypes: dict[int, str] | None = None,
+    entity_lineweights: dict[int, int] | None = None,
+    transform: Iterable[Iterable[float]] | None = None,
+    keep_unused_layers: bool = True,
+    keep_unused_linetypes: bool = False,
+    groups: dict[str, Iterable[int]] | None = None,
+    entity_xdata: dict[int, dict[str, list[tuple[int, Any]]]] | None = None,
+    dictionaries: dict[str, dict[str, Any]] | None = None,
+    encode_threads: int | None = None,
+    handle_namespaces: bool = False,
 ) -> WriteResult:
     if version != "AC1015":
         raise ValueError(f"unsupported DWG write version: {version}")
 
     source_path, layout = _resolve_layout(source)
     source_entities = _resolve_dwg_export_entities(layout, types)
+    return _write_dwg_rows(
+        source_path,
+        layout,
+        source_entities,
+        _collect_dwg_write_rows(source_entities, precision),
+        output_path,
+        strict=strict,
+        application_name=application_name,
+        application_version=application_version,
+        drawing_times=drawing_times,
+        progress=progress,
+        max_lwpolyline_vertices=max_lwpolyline_vertices,
+        arc_fit_tolerance=arc_fit_tolerance,
+        preserve_unknown_objects=preserve_unknown_objects,
+        preserve_header_variables=preserve_header_variables,
+        layer_renames=layer_renames,
+        layer_colors=layer_colors,
+        layer_map=layer_map,
+        entity_layers=entity_layers,
+        entity_colors=entity_colors,
+        entity_linetypes=entity_linetypes,
+        entity_lineweights=entity_lineweights,
+        transform=transform,
+        keep_unused_layers=keep_unused_layers,
+        keep_unused_linetypes=keep_unused_linetypes,
+        groups=groups,
+        entity_xdata=entity_xdata,
+        dictionaries=dictionaries,
+        encode_threads=encode_threads,
+        handle_namespaces=handle_namespaces,
+    )
 
-    total = 0
-    written = 0
-    skipped_by_type: dict[str, int] = {}
-    line_rows: list[tuple[int, float, float, float, float, float, float]] = []
-    ray_rows: list[tuple[int, tuple[float, float, float], tuple[float, float, float]]] = []
-    xline_rows: list[tuple[int, tuple[float, float, float], tuple[float, float, float]]] = []
-    point_rows: list[tuple[int, float, float, float, float]] = []
-    arc_rows: list[tuple[int, float, float, float, float, float, float]] = []
-    circle_rows: list[tuple[int, float, float, float, float]] = []
-    lwpolyline_rows: list[
-        tuple[int, int, list[tuple[float, float]], list[float], list[tuple[float, float]], float | None]
-    ] = []
-    text_rows: list[tuple[int, str, tuple[float, float, float], float, float]] = []
-    mtext_rows: list[
-        tuple[
-            int,
-            str,
-            tuple[float, float, float],
-            tuple[float, float, float],
-            float,
-            float,
-            int,
-            int,
-        ]
-    ] = []
-
-    for entity in source_entities:
-        total += 1
-        if entity.dxftype == "LINE":
-            row = _as_line_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            line_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "POINT":
-            row = _as_point_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            point_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "RAY":
-            row = _as_ray_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            ray_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "XLINE":
-            row = _as_xline_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            xline_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "ARC":
-            row = _as_arc_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            arc_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "CIRCLE":
-            row = _as_circle_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            circle_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "LWPOLYLINE":
-            row = _as_lwpolyline_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            lwpolyline_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "TEXT":
-            row = _as_text_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            text_rows.append(row)
-            written += 1
-            continue
-        if entity.dxftype == "MTEXT":
-            row = _as_mtext_row(entity)
-            if row is None:
-                skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
-                continue
-            mtext_rows.append(row)
-            written += 1
-            continue
-        skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
 
+def _write_dwg_rows(
+    source_path: str | None,
+    layout: Layout,
+    source_entities: list[Entity],
+    collected: tuple[dict[str, list[tuple[Any, ...]]], int, int, dict[str, int]],
+    output_path: str,
+    *,
+    strict: bool = False,
+    application_name: str | None = None,
+    application_version: str | None = None,
+    drawing_times: DrawingTimes | None = None,
+    progress: Callable[[str, int, int | None, int, int | None], object] | None = None,
+    max_lwpolyline_vertices: int | None = 32767,
+    arc_fit_tolerance: float | None = None,
+    preserve_unknown_objects: bool = True,
+    preserve_header_variables: bool = True,
+    layer_renames: dict[str, str] | None = None,
+    layer_colors: dict[str, int] | None = None,
+    layer_map: LayerMap | None = None,
+    entity_layers: dict[int, str] | None = None,
+    entity_colors: dict[int, int] | None = None,
+    entity_linetypes: dict[int, str] | None = None,
+    entity_lineweights: dict[int, int] | None = None,
+    transform: Iterable[Iterable[float]] | None = None,
+    keep_unused_layers: bool = True,
+    keep_unused_linetypes: bool = False,
+    groups: dict[str, Iterable[int]] | None = None,
+    entity_xdata: dict[int, dict[str, list[tuple[int, Any]]]] | None = None,
+    dictionaries: dict[str, dict[str, Any]] | None = None,
+    encode_threads: int | None = None,
+    handle_namespaces: bool = False,
+) -> WriteResult:
+    """Write the rows ``_collect_dwg_write_rows`` built for ``source_entities``
+    of ``layout``, with the options of ``to_dwg``."""
+    rows, total, written, skipped_by_type = collected
     skipped = total - written
     if strict and skipped > 0:
         summary = ", ".join(
@@ -312,19 +325,333 @@ def to_dwg(
         )
         raise ValueError(f"failed to write {skipped} entities ({summary})")
 
+    layer_rows, entity_layer_rows, layer_linetype_rows, layer_state_rows = _dwg_write_layer_rows(
+        layout.doc.decode_path,
+        source_entities,
+        rows,
+        layer_renames=layer_renames or {},
+        layer_colors=layer_colors or {},
+        layer_map=layer_map,
+        entity_layers=entity_layers or {},
+        keep_unused=keep_unused_layers,
+    )
+    style_rows, linetype_rows = _dwg_write_style_rows(
+        layout.doc.decode_path,
+        rows,
+        entity_linetypes=entity_linetypes or {},
+        entity_lineweights=entity_lineweights or {},
+        layer_linetypes=[linetype for _name, linetype in layer_linetype_rows],
+        keep_unused_linetypes=keep_unused_linetypes,
+    )
+    color_rows = _dwg_write_color_rows(source_entities, rows, entity_colors or {})
+
     out_path = Path(output_path)
     out_path.parent.mkdir(parents=True, exist_ok=True)
     raw.write_ac1015_dwg(
         str(out_path),
-        line_rows,
-        arc_rows,
-        circle_rows,
-        lwpolyline_rows,
-        text_rows,
-        mtext_rows,
-        point_rows,
-        ray_rows,
-        xline_rows,
+        *_dwg_write_row_args(rows),
+        application_name=application_name,
+        application_version=application_version,
+        drawing_times=drawing_times.to_raw() if drawing_times is not None else None,
+        styles=style_rows,
+        linetypes=linetype_rows,
+        progress=progress,
+        max_lwpolyline_vertices=max_lwpolyline_vertices,
+        arc_fit_tolerance=arc_fit_tolerance,
+        unknown_objects_from=(
+            _ac1015_source(layout.doc.decode_path) if preserve_unknown_objects else None
+        ),
+        header_from=(
+            _ac1015_source(layout.doc.decode_path) if preserve_header_variables else None
+        ),
+        layers=layer_rows,
+        layer_linetypes=layer_linetype_rows,
+        layer_states=layer_state_rows,
+        entity_layers=entity_layer_rows,
+        entity_colors=color_rows,
+        transform=_affine_transform_rows(transform) if transform is not None else None,
+        groups=[
+            (name, "", True, [int(handle) for handle in handles])
+            for name, handles in (groups or {}).items()
+        ],
+        entity_xdata=[
+            (int(handle), app_name, [(int(code), value) for code, value in items])
+            for handle, apps in (entity_xdata or {}).items()
+            for app_name, items in apps.items()
+        ],
+        dictionaries=[
+            (name, [(key, _xrecord_items(value)) for key, value in records.items()])
+            for name, records in (dictionaries or {}).items()
+        ],
+        encode_threads=encode_threads,
+        handle_namespaces=handle_namespaces,
+        polyfaces=rows[_POLYFACE_ROWS],
+        **_dwg_write_row_kwargs(rows),
+    )
+
+    return WriteResult(
+        source_path=source_path,
+        output_path=str(out_path),
+        target_version="AC1015",
+        total_entities=total,
+        written_entities=written,
+        skipped_entities=skipped,
+        skipped_by_type=dict(sorted(skipped_by_type.items())),
+    )
+
+
+def rename_layer(
+    source: str | Document | Layout,
+    output_path: str,
+    old_name: str,
+    new_name: str,
+    **kwargs: Any,
+) -> WriteResult:
+    """Rewrite ``source`` with the layer ``old_name`` renamed; the other
+    keyword arguments are those of ``to_dwg``."""
+    return to_dwg(source, output_path, layer_renames={old_name: new_name}, **kwargs)
+
+
+def set_layer_color(
+    source: str | Document | Layout,
+    output_path: str,
+    name: str,
+    color_index: int,
+    **kwargs: Any,
+) -> WriteResult:
+    """Rewrite ``source`` with the layer ``name`` in ``color_index``,
+    creating the layer when it does not exist."""
+    return to_dwg(source, output_path, layer_colors={name: color_index}, **kwargs)
+
+
+def move_entities_to_layer(
+    source: str | Document | Layout,
+    output_path: str,
+    handles: Iterable[int],
+    layer_name: str,
+    **kwargs: Any,
+) -> WriteResult:
+    """Rewrite ``source`` with the entities of ``handles`` on ``layer_name``,
+    creating the layer when it does not exist."""
+    return to_dwg(
+        source,
+        output_path,
+        entity_layers={int(handle): layer_name for handle in handles},
+        **kwargs,
+    )
+
+
+def transform_drawing(
+    source: str | Document | Layout,
+    matrix: Iterable[Iterable[float]],
+    output_path: str,
+    **kwargs: Any,
+) -> WriteResult:
+    """Rewrite ``source`` with its modelspace moved, rotated about the Z
+    axis and uniformly scaled by the affine ``matrix``; the other keyword
+    arguments are those of ``to_dwg``."""
+    return to_dwg(source, output_path, transform=matrix, **kwargs)
+
+
+_PURGE_TABLES = ("layers", "linetypes")
+# Tables the writer leaves out whole, so purging them cannot keep the used
+# entries.
+_UNWRITTEN_TABLES = {"blocks": "block definitions", "styles": "text styles"}
+
+
+def purge(
+    source: str | Document | Layout,
+    output_path: str,
+    what: Iterable[str] = _PURGE_TABLES,
+    **kwargs: Any,
+) -> PurgeResult:
+    """Rewrite ``source`` without the table entries in ``what`` that nothing
+    written uses, like AutoCAD's PURGE. ``what`` names any of ``layers``
+    and ``linetypes``; the other keyword arguments are those of ``to_dwg``."""
+    tables = [what] if isinstance(what, str) else list(what)
+    for table in tables:
+        if table in _UNWRITTEN_TABLES:
+            raise ValueError(
+                f"cannot purge {table}: the writer writes no {_UNWRITTEN_TABLES[table]}"
+            )
+        if table not in _PURGE_TABLES:
+            raise ValueError(f"unknown table to purge: {table}")
+    _source_path, layout = _resolve_layout(source)
+    result = to_dwg(
+        layout,
+        output_path,
+        keep_unused_layers="layers" not in tables,
+        keep_unused_linetypes="linetypes" not in tables,
+        **kwargs,
+    )
+
+    # The rewrite itself purges; what the output no longer has was purged.
+    decode_path = layout.doc.decode_path
+    purged: dict[str, list[str]] = {}
+    for table in _PURGE_TABLES:
+        if table not in tables:
+            continue
+        kind = "LAYER" if table == "layers" else "LTYPE"
+        kept = {name.upper() for name in _table_entry_names(result.output_path, kind)}
+        purged[table] = [
+            name
+            for name in _table_entry_names(decode_path, kind)
+            if name.upper() not in kept and name.upper() not in {"BYLAYER", "BYBLOCK", "CONTINUOUS"}
+        ]
+    return PurgeResult(write=result, purged=purged)
+
+
+def _table_entry_names(path: str | None, table: str) -> list[str]:
+    if not path:
+        return []
+    try:
+        rows = raw.decode_table_entry_names(path, table)
+    except Exception:
+        return []
+    return [name for _handle, name in rows if name]
+
+
+def _affine_transform_rows(
+    matrix: Iterable[Iterable[float]],
+) -> tuple[tuple[float, float, float, float], ...]:
+    """The first three rows of the 4x4 matrix of ``matrix``, which may be a
+    2D affine matrix (2x3 or 3x3) or a 3D one (3x4 or 4x4)."""
+    rows = [tuple(float(value) for value in row) for row in matrix]
+    shape = (len(rows), *{len(row) for row in rows})
+    if shape in {(2, 3), (3, 3)}:
+        if shape == (3, 3) and rows[2] != (0.0, 0.0, 1.0):
+            raise ValueError("the last row of a 3x3 transform matrix must be (0, 0, 1)")
+        (a, c, tx), (b, d, ty) = rows[:2]
+        return ((a, c, 0.0, tx), (b, d, 0.0, ty), (0.0, 0.0, math.hypot(a, b), 0.0))
+    if shape in {(3, 4), (4, 4)}:
+        if shape == (4, 4) and rows[3] != (0.0, 0.0, 0.0, 1.0):
+            raise ValueError("the last row of a 4x4 transform matrix must be (0, 0, 0, 1)")
+        return tuple(rows[:3])  # type: ignore[return-value]
+    raise ValueError(f"transform matrix must be 2x3, 3x3, 3x4 or 4x4, got {rows!r}")
+
+
+def _xrecord_items(value: Any) -> list[tuple[int, Any]]:
+    """XRECORD ``(group_code, value)`` items for an application data value.
+
+    Lists are taken as items. Bytes are split into binary chunks, strings,
+    integers, floats and 3D points get the matching DXF group code.
+    """
+    if isinstance(value, list):
+        return [(int(code), item) for code, item in value]
+    if isinstance(value, (bytes, bytearray)):
+        data = bytes(value)
+        return [(310, data[start : start + 127]) for start in range(0, len(data), 127)] or [
+            (310, b"")
+        ]
+    if isinstance(value, str):
+        return [(1, value)]
+    if isinstance(value, int):
+        return [(90 if -(2**31) <= value < 2**31 else 160, value)]
+    if isinstance(value, float):
+        return [(40, value)]
+    if isinstance(value, tuple) and len(value) == 3:
+        return [(10, tuple(float(coord) for coord in value))]
+    raise TypeError(f"unsupported application data value: {value!r}")
+
+
+def update_entities(
+    source: str | Document | Layout,
+    output_path: str,
+    *,
+    types: str | Iterable[str] | None = None,
+    layers: str | Iterable[str] | None = None,
+    handles: Iterable[int] | None = None,
+    color: int | None = None,
+    layer: str | None = None,
+    linetype: str | None = None,
+    lineweight: int | None = None,
+    **kwargs: Any,
+) -> EntityUpdateResult:
+    """Rewrite ``source`` with property changes on the entities selected by
+    ``types``, ``layers`` and ``handles``, in one ``to_dwg`` pass.
+
+    The selectors combine; leaving all of them out selects every entity.
+    Entities that cannot be written and handles that are not found are
+    reported in ``failed``. The other keyword arguments are those of
+    ``to_dwg``.
+    """
+    if color is None and layer is None and linetype is None and lineweight is None:
+        raise ValueError("no property changes given")
+    _source_path, layout = _resolve_layout(source)
+    names_by_handle = _layer_names_by_handle(layout.doc.decode_path)
+    layer_keys = None
+    if layers is not None:
+        layer_keys = {name.upper() for name in ([layers] if isinstance(layers, str) else layers)}
+    wanted = None if handles is None else {int(handle) for handle in handles}
+
+    entities: list[Entity] = []
+    for entity in _resolve_dwg_export_entities(layout, types):
+        if wanted is not None and entity.handle not in wanted:
+            continue
+        if layer_keys is not None:
+            name = names_by_handle.get(entity.dxf.get("layer_handle"), "0")
+            if name.upper() not in layer_keys:
+                continue
+        entities.append(entity)
+    rows, _total, _written, _skipped = _collect_dwg_write_rows(entities, kwargs.get("precision"))
+    writable = _dwg_row_handles(rows)
+
+    updated = sorted(entity.handle for entity in entities if entity.handle in writable)
+    failed = {
+        entity.handle: f"{entity.dxftype} entity is not written"
+        for entity in entities
+        if entity.handle not in writable
+    }
+    for handle in sorted((wanted or set()) - {entity.handle for entity in entities}):
+        failed[handle] = "no writable entity with this handle"
+    result = to_dwg(
+        layout,
+        output_path,
+        entity_colors=None if color is None else dict.fromkeys(updated, color),
+        entity_layers=None if layer is None else dict.fromkeys(updated, layer),
+        entity_linetypes=None if linetype is None else dict.fromkeys(updated, linetype),
+        entity_lineweights=None if lineweight is None else dict.fromkeys(updated, lineweight),
+        **kwargs,
+    )
+    return EntityUpdateResult(write=result, updated=updated, failed=dict(sorted(failed.items())))
+
+
+def apply_incremental_update(
+    path: str,
+    modified_entities: Iterable[Entity],
+    *,
+    output_path: str | None = None,
+    strict: bool = False,
+    precision: int | None = None,
+) -> WriteResult:
+    """Replace entities of an AC1015 file without rewriting it.
+
+    The new records and an updated object map are appended to the original
+    bytes, so small edits on large drawings stay cheap. Every entity must
+    keep the handle of the entity it replaces; its owner, layer, linetype,
+    lineweight and color are taken from the original record.
+    ``output_path`` defaults to ``path``. ``precision`` works as in
+    ``to_dwg``.
+    """
+    source_path = str(path)
+    version = raw.detect_version(source_path)
+    if version != "AC1015":
+        raise ValueError(f"incremental update is not supported for {version}")
+
+    rows, total, written, skipped_by_type = _collect_dwg_write_rows(modified_entities, precision)
+    skipped = total - written
+    if strict and skipped > 0:
+        summary = ", ".join(
+            f"{dxftype}:{count}" for dxftype, count in sorted(skipped_by_type.items())
+        )
+        raise ValueError(f"failed to write {skipped} entities ({summary})")
+
+    out_path = Path(output_path) if output_path is not None else Path(source_path)
+    raw.apply_incremental_update(
+        source_path,
+        str(out_path),
+        *_dwg_write_row_args(rows),
+        **_dwg_write_row_kwargs(rows),
     )
 
     return WriteResult(
@@ -338,6 +665,650 @@ def to_dwg(
     )
 
 
+def replace_text(
+    path: str,
+    pattern: str | re.Pattern[str],
+    replacement: str,
+    output_path: str | None = None,
+    *,
+    regex: bool = False,
+) -> WriteResult:
+    """Replace ``pattern`` in the TEXT, MTEXT and ATTRIB strings of an
+    AC1015 file without rewriting it.
+
+    A ``str`` pattern matches literally unless ``regex`` is set; with a
+    regular expression, ``replacement`` may refer to its groups as in
+    ``re.sub``. MTEXT is matched against its displayed text, so formatting
+    codes neither match nor break a match, and they stay in place.
+    ``output_path`` defaults to ``path``. The result counts the searched
+    entities in ``total_entities`` and the changed ones in
+    ``written_entities``.
+    """
+    source_path = str(path)
+    version = raw.detect_version(source_path)
+    if version != "AC1015":
+        raise ValueError(f"text replacement is not supported for {version}")
+    literal = isinstance(pattern, str) and not regex
+    compiled = re.compile(re.escape(pattern) if literal else pattern)
+
+    def substitute(match: re.Match[str]) -> str:
+        return replacement if literal else match.expand(replacement)
+
+    strings = [(row[0], row[1], False) for row in raw.decode_text_entities(source_path)]
+    strings += [(row[0], row[1], False) for row in raw.decode_attrib_entities(source_path)]
+    strings += [(row[0], row[1], True) for row in raw.decode_mtext_entities(source_path)]
+    edits: dict[int, str] = {}
+    for handle, text, is_mtext in strings:
+        if is_mtext:
+            new_text = _replace_mtext_text(text, compiled, substitute)
+        else:
+            new_text = compiled.sub(substitute, text)
+        if new_text != text:
+            edits[int(handle)] = new_text
+
+    out_path = Path(output_path) if output_path is not None else Path(source_path)
+    if edits or out_path != Path(source_path):
+        raw.replace_entity_text(source_path, str(out_path), sorted(edits.items()))
+
+    return WriteResult(
+        source_path=source_path,
+        output_path=str(out_path),
+        target_version=version,
+        total_entities=len(strings),
+        written_entities=len(edits),
+        skipped_entities=0,
+        skipped_by_type={},
+    )
+
+
+def redefine_block(
+    path: str,
+    block_name: str,
+    definition: str | Document | Layout | Iterable[Entity],
+    output_path: str | None = None,
+    *,
+    source_block: str | None = None,
+    strict: bool = False,
+    precision: int | None = None,
+) -> WriteResult:
+    """Replace the contents of the block ``block_name`` of an AC1015 file
+    without rewriting it.
+
+    ``definition`` is either the new entities, in block coordinates, or a
+    drawing whose block ``source_block`` (``block_name`` by default)
+    supplies them. The block keeps its handle and base point, so every
+    INSERT of it shows the new contents. The new entities get fresh
+    handles; their layers and linetypes must exist in ``path``.
+    ``output_path`` defaults to ``path``. ``precision`` works as in
+    ``to_dwg``.
+    """
+    source_path = str(path)
+    version = raw.detect_version(source_path)
+    if version != "AC1015":
+        raise ValueError(f"block redefinition is not supported for {version}")
+    if isinstance(definition, (str, Document, Layout)):
+        decode_path, entities = _block_definition_entities(
+            definition, source_block or block_name
+        )
+    else:
+        decode_path, entities = source_path, list(definition)
+
+    rows, total, written, skipped_by_type = _collect_dwg_write_rows(entities, precision)
+    skipped = total - written
+    if strict and skipped > 0:
+        summary = ", ".join(
+            f"{dxftype}:{count}" for dxftype, count in sorted(skipped_by_type.items())
+        )
+        raise ValueError(f"failed to write {skipped} entities ({summary})")
+    style_rows, _linetype_rows = _dwg_source_style_rows(decode_path, rows)
+    names_by_handle = _layer_names_by_handle(decode_path)
+    handles = _dwg_row_handles(rows)
+    layer_rows = sorted(
+        (entity.handle, names_by_handle[entity.dxf.get("layer_handle")])
+        for entity in entities
+        if entity.handle in handles and entity.dxf.get("layer_handle") in names_by_handle
+    )
+
+    out_path = Path(output_path) if output_path is not None else Path(source_path)
+    raw.redefine_block(
+        source_path,
+        str(out_path),
+        block_name,
+        *_dwg_write_row_args(rows),
+        # Transparency would need an APPID entry the drawing may not have.
+        styles=[row[:3] + (None,) for row in sorted(style_rows.values())],
+        entity_layers=layer_rows,
+        entity_colors=_dwg_write_color_rows(entities, rows, {}),
+        **_dwg_write_row_kwargs(rows),
+    )
+
+    return WriteResult(
+        source_path=source_path,
+        output_path=str(out_path),
+        target_version=version,
+        total_entities=total,
+        written_entities=written,
+        skipped_entities=skipped,
+        skipped_by_type=dict(sorted(skipped_by_type.items())),
+    )
+
+
+def strip_layers(
+    path: str,
+    layers: str | Iterable[str],
+    output_path: str | None = None,
+) -> WriteResult:
+    """Remove every entity on ``layers`` from an AC1015 file without
+    rewriting it, such as the survey layers before handing a plan on.
+
+    Only the object map and the entity chains around the removed entities
+    are rewritten; every other record stays byte for byte as it was, which
+    is much faster than ``to_dwg`` for large drawings. Entities go from
+    model space, paper space and block definitions alike, ATTRIBs and
+    VERTEXes with their parent entity. The layers stay in the drawing.
+    ``output_path`` defaults to ``path``. The result counts the removed
+    entities, including the ones they own, in ``written_entities``.
+    """
+    source_path = str(path)
+    version = raw.detect_version(source_path)
+    if version != "AC1015":
+        raise ValueError(f"layer stripping is not supported for {version}")
+    names = [layers] if isinstance(layers, str) else [str(name) for name in layers]
+
+    out_path = Path(output_path) if output_path is not None else Path(source_path)
+    removed = raw.strip_layers(source_path, str(out_path), names)
+
+    return WriteResult(
+        source_path=source_path,
+        output_path=str(out_path),
+        target_version=version,
+        total_entities=len(removed),
+        written_entities=len(removed),
+        skipped_entities=0,
+        skipped_by_type={},
+    )
+
+
+def _block_definition_entities(
+    source: str | Document | Layout, block_name: str
+) -> tuple[str, list[Entity]]:
+    """Path of the drawing ``source`` and the entities of its block
+    ``block_name``, in draw order."""
+    _source_path, layout = _resolve_layout(source)
+    decode_path = layout.doc.decode_path
+    rows = [row for row in raw.decode_table_entry_names(decode_path, "BLOCK") if row[1]]
+    if not rows:
+        rows = raw.decode_block_header_names(decode_path)
+    handle = next(
+        (row[0] for row in rows if str(row[1]).upper() == block_name.upper()),
+        None,
+    )
+    if handle is None:
+        raise ValueError(f"block not found: {block_name}")
+    children = raw.iter_block_children(decode_path, handle)
+    order = {child: index for index, child in enumerate(children)}
+    entities = [entity for entity in layout.query() if entity.handle in order]
+    return decode_path, sorted(entities, key=lambda entity: order[entity.handle])
+
+
+def _replace_mtext_text(
+    value: str,
+    pattern: re.Pattern[str],
+    substitute: Callable[[re.Match[str]], str],
+) -> str:
+    """Apply ``pattern`` to the displayed text of the MTEXT string
+    ``value``. Matched characters are replaced by the escaped replacement;
+    formatting codes inside a match are kept after it."""
+    tokens = _mtext_tokens(value)
+    plain = "".join(shown for _code, shown in tokens)
+    # Each token shows at most one character; map them back to tokens.
+    owners = [index for index, (_code, shown) in enumerate(tokens) if shown]
+    out: list[str] = []
+    token_index = 0
+    for match in pattern.finditer(plain):
+        if match.start() == match.end():
+            continue
+        first = owners[match.start()]
+        last = owners[match.end() - 1]
+        out.extend(code for code, _shown in tokens[token_index:first])
+        out.append(_escape_mtext(substitute(match)))
+        out.extend(code for code, shown in tokens[first : last + 1] if not shown)
+        token_index = last + 1
+    out.extend(code for code, _shown in tokens[token_index:])
+    return "".join(out)
+
+
+def _mtext_tokens(value: str) -> list[tuple[str, str]]:
+    """Split an MTEXT string into ``(code, shown)`` pairs: the source text
+    of each character or formatting code and the character it displays.
+    Stacked fractions count as formatting codes."""
+    tokens: list[tuple[str, str]] = []
+    i = 0
+    n = len(value)
+    while i < n:
+        ch = value[i]
+        if ch in "{}":
+            tokens.append((ch, ""))
+            i += 1
+            continue
+        if ch != "\\" or i + 1 >= n:
+            tokens.append((ch, ch))
+            i += 1
+            continue
+        code = value[i + 1]
+        end = i + 2
+        shown = code
+        if code in {"P", "X"}:
+            shown = "\n"
+        elif code == "~":
+            shown = "\u00a0"
+        elif code in {"L", "l", "O", "o", "K", "k"}:
+            shown = ""
+        elif code in {"U", "u"} and value[i + 2 : i + 3] == "+":
+            hex_digits = value[i + 3 : i + 7]
+            if len(hex_digits) == 4 and all(c in "0123456789abcdefABCDEF" for c in hex_digits):
+                shown = chr(int(hex_digits, 16))
+                end = i + 7
+        elif code in {"A", "C", "c", "F", "f", "H", "h", "Q", "q", "S", "T", "t", "W", "w", "p"}:
+            semicolon = value.find(";", i + 2)
+            end = n if semicolon < 0 else semicolon + 1
+            shown = ""
+        tokens.append((value[i:end], shown))
+        i = end
+    return tokens
+
+
+def _escape_mtext(text: str) -> str:
+    return (
+        text.replace("\\", "\\\\")
+        .replace("{", "\\{")
+        .replace("}", "\\}")
+        .replace("\n", "\\P")
+    )
+
+
+_DWG_WRITE_ROW_BUILDERS = {
+    "LINE": _as_line_row,
+    "ARC": _as_arc_row,
+    "CIRCLE": _as_circle_row,
+    "LWPOLYLINE": _as_lwpolyline_row,
+    "TEXT": _as_text_row,
+    "MTEXT": _as_mtext_row,
+    "POINT": _as_point_row,
+    "RAY": _as_ray_row,
+    "XLINE": _as_xline_row,
+}
+
+# Types written through keyword rows named after the raw writer arguments.
+_DWG_WRITE_KEYWORD_ROW_BUILDERS = {
+    "TOLERANCE": ("tolerances", _as_tolerance_row),
+    "LEADER": ("leaders", _as_leader_row),
+}
+
+# Rows key of the TEXT alignments passed next to the TEXT rows.
+_TEXT_ALIGNMENT_ROWS = "TEXT_ALIGNMENT"
+# Rows key of the polyface meshes that types R2000 lacks are written as.
+_POLYFACE_ROWS = "POLYFACE"
+
+
+def _collect_dwg_write_rows(
+    entities: Iterable[Entity],
+    precision: int | None = None,
+) -> tuple[dict[str, list[tuple[Any, ...]]], int, int, dict[str, int]]:
+    """Build the writer rows, skipping entities that cannot be written.
+
+    Floats are rounded to ``precision`` decimal places when given and
+    subnormal values are flushed to zero; rows holding NaN or infinite
+    values are skipped.
+    """
+    rows: dict[str, list[tuple[Any, ...]]] = {dxftype: [] for dxftype in _DWG_WRITE_ROW_BUILDERS}
+    rows.update({dxftype: [] for dxftype in _DWG_WRITE_KEYWORD_ROW_BUILDERS})
+    rows[_TEXT_ALIGNMENT_ROWS] = []
+    rows[_POLYFACE_ROWS] = []
+    total = 0
+    written = 0
+    skipped_by_type: dict[str, int] = {}
+    for entity in entities:
+        total += 1
+        builder = _DWG_WRITE_ROW_BUILDERS.get(entity.dxftype)
+        if builder is None and entity.dxftype in _DWG_WRITE_KEYWORD_ROW_BUILDERS:
+            builder = _DWG_WRITE_KEYWORD_ROW_BUILDERS[entity.dxftype][1]
+        row = builder(entity) if builder is not None else None
+        if row is not None:
+            row = round_floats(row, precision)
+            if not _all_finite(row):
+                row = None
+        if row is None:
+            skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
+            continue
+        rows[entity.dxftype].append(row)
+        written += 1
+        if entity.dxftype == "TEXT":
+            alignment = round_floats(_as_text_alignment_row(entity), precision)
+            if alignment is not None and _all_finite(alignment):
+                rows[_TEXT_ALIGNMENT_ROWS].append(alignment)
+    return rows, total, written, skipped_by_type
+
+
+def _all_finite(value: Any) -> bool:
+    if isinstance(value, float):
+        return math.isfinite(value)
+    if isinstance(value, (tuple, list)):
+        return all(_all_finite(item) for item in value)
+    return True
+
+
+def _dwg_row_handles(rows: dict[str, list[tuple[Any, ...]]]) -> set[int]:
+    """Handles of the rows to write; rows left to the writer to number
+    carry ``None`` and are not included."""
+    return {int(row[0]) for type_rows in rows.values() for row in type_rows if row[0] is not None}
+
+
+def _dwg_write_row_args(rows: dict[str, list[tuple[Any, ...]]]) -> tuple[list[tuple[Any, ...]], ...]:
+    """Row lists in the positional order of the raw AC1015 writers."""
+    return tuple(rows[dxftype] for dxftype in _DWG_WRITE_ROW_BUILDERS)
+
+
+def _dwg_write_row_kwargs(rows: dict[str, list[tuple[Any, ...]]]) -> dict[str, Any]:
+    """Keyword row lists of the raw AC1015 writers."""
+    kwargs: dict[str, Any] = {
+        name: rows[dxftype] for dxftype, (name, _builder) in _DWG_WRITE_KEYWORD_ROW_BUILDERS.items()
+    }
+    kwargs["text_alignments"] = rows[_TEXT_ALIGNMENT_ROWS]
+    return kwargs
+
+
+_DWG_LTYPE_FLAG_NAMES = {1: "BYBLOCK", 2: "CONTINUOUS"}
+# DXF group 370 values: BYLAYER, BYBLOCK, DEFAULT and the standard weights.
+_DWG_LINEWEIGHTS = frozenset(
+    {-3, -2, -1, 0, 5, 9, 13, 15, 18, 20, 25, 30, 35, 40, 50, 53, 60, 70, 80, 90, 100}
+    | {106, 120, 140, 158, 200, 211}
+)
+
+
+def _ac1015_source(decode_path: str | None) -> str | None:
+    """The source to copy undecodable records and header variables from;
+    only those of AC1015 files can be copied as they are."""
+    if not decode_path:
+        return None
+    try:
+        return decode_path if raw.detect_version(decode_path) == "AC1015" else None
+    except Exception:
+        return None
+
+
+def _dwg_write_layer_rows(
+    decode_path: str | None,
+    entities: Iterable[Entity],
+    rows: dict[str, list[tuple[Any, ...]]],
+    *,
+    layer_renames: dict[str, str],
+    layer_colors: dict[str, int],
+    entity_layers: dict[int, str],
+    layer_map: LayerMap | None = None,
+    keep_unused: bool = True,
+) -> tuple[
+    list[tuple[str, int]],
+    list[tuple[int, str]],
+    list[tuple[str, str]],
+    list[tuple[str, int, bool]],
+]:
+    """LAYER entries of the source, the layer of each row to write, the
+    layer linetypes and the frozen, locked and off states of the layers,
+    with the layer map, renames, colors and entity moves of ``to_dwg``
+    applied in that order. Without ``keep_unused``, only layer 0, the layers
+    of the rows and those given a color are kept."""
+    layers: dict[str, tuple[str, int]] = {}
+    states: dict[str, tuple[int, bool]] = {}
+    names_by_handle = _layer_names_by_handle(decode_path)
+    colors_by_handle = _layer_styles_by_handle(decode_path)
+    states_by_handle = _layer_states_by_handle(decode_path)
+    for handle, name in names_by_handle.items():
+        color_index = colors_by_handle.get(handle, (7, None))[0]
+        layers.setdefault(name.upper(), (name, color_index if 1 <= color_index <= 255 else 7))
+        if handle in states_by_handle:
+            states.setdefault(name.upper(), states_by_handle[handle])
+
+    handles = _dwg_row_handles(rows)
+    assigned: dict[int, str] = {}
+    for entity in entities:
+        name = names_by_handle.get(entity.dxf.get("layer_handle"))
+        if entity.handle in handles and name is not None:
+            assigned[entity.handle] = name
+
+    linetypes: dict[str, str] = {}
+    if layer_map is not None:
+        layers, assigned, linetypes, states = _apply_layer_map(
+            layer_map, layers, assigned, states
+        )
+
+    for old_name, new_name in layer_renames.items():
+        key = old_name.upper()
+        if key == "0":
+            raise ValueError("layer 0 cannot be renamed")
+        if key not in layers:
+            raise ValueError(f"layer not found: {old_name}")
+        _validate_layer_name(new_name)
+        if new_name.upper() != key and new_name.upper() in layers:
+            raise ValueError(f"layer already exists: {new_name}")
+        _name, color_index = layers.pop(key)
+        layers[new_name.upper()] = (new_name, color_index)
+        if key in linetypes:
+            linetypes[new_name.upper()] = linetypes.pop(key)
+        if key in states:
+            states[new_name.upper()] = states.pop(key)
+        for handle, name in assigned.items():
+            if name.upper() == key:
+                assigned[handle] = new_name
+    for name, color_index in layer_colors.items():
+        if not 1 <= int(color_index) <= 255:
+            raise ValueError(f"layer color must be in 1..255: {color_index}")
+        _validate_layer_name(name)
+        known_name, _color_index = layers.get(name.upper(), (name, 7))
+        layers[name.upper()] = (known_name, int(color_index))
+    for handle, name in entity_layers.items():
+        _validate_layer_name(name)
+        layers.setdefault(name.upper(), (name, 7))
+        assigned[int(handle)] = layers[name.upper()][0]
+    if not keep_unused:
+        used = {"0", *(name.upper() for name in assigned.values())}
+        used.update(name.upper() for name in layer_colors)
+        layers = {key: layer for key, layer in layers.items() if key in used}
+    layer_linetypes = [
+        (layers[key][0], linetype) for key, linetype in linetypes.items() if key in layers
+    ]
+    layer_states = [
+        (layers[key][0], flags, is_off)
+        for key, (flags, is_off) in states.items()
+        if key in layers and (flags or is_off)
+    ]
+    return list(layers.values()), sorted(assigned.items()), layer_linetypes, layer_states
+
+
+def _apply_layer_map(
+    layer_map: LayerMap,
+    layers: dict[str, tuple[str, int]],
+    assigned: dict[int, str],
+    states: dict[str, tuple[int, bool]],
+) -> tuple[
+    dict[str, tuple[str, int]], dict[int, str], dict[str, str], dict[str, tuple[int, bool]]
+]:
+    """Layers and entity layers with ``layer_map`` applied, plus the mapped
+    linetypes and ``states`` keyed by upper-cased layer name. Layers mapped
+    onto the same target are merged; the target keeps the first color and
+    state unless a rule sets the color. Layer 0 always stays, even when its
+    entities move elsewhere."""
+    mapped: dict[str, tuple[str, int]] = {}
+    mapped_states: dict[str, tuple[int, bool]] = {}
+    targets: dict[str, str] = {}
+    linetypes: dict[str, str] = {}
+    for key, (name, color_index) in layers.items():
+        result = layer_map.resolve(name)
+        if result is None:
+            mapped.setdefault(key, (name, color_index))
+            if key in states:
+                mapped_states.setdefault(key, states[key])
+            continue
+        _validate_layer_name(result.name)
+        target_key = result.name.upper()
+        target_name, target_color = mapped.get(target_key, (result.name, color_index))
+        if result.color is not None:
+            target_color = result.color
+        mapped[target_key] = (target_name, target_color)
+        if key in states:
+            mapped_states.setdefault(target_key, states[key])
+        if result.linetype is not None:
+            linetypes[target_key] = result.linetype
+        targets[key] = target_key
+    mapped.setdefault("0", layers.get("0", ("0", 7)))
+    if "0" in states:
+        mapped_states.setdefault("0", states["0"])
+    remapped: dict[int, str] = {}
+    for handle, name in assigned.items():
+        key = targets.get(name.upper(), name.upper())
+        remapped[handle] = mapped[key][0] if key in mapped else name
+    return mapped, remapped, linetypes, mapped_states
+
+
+def _validate_layer_name(name: str) -> None:
+    if not _is_valid_dxf_layer_name(name) or name != name.strip():
+        raise ValueError(f"invalid layer name: {name!r}")
+
+
+def _dwg_write_style_rows(
+    decode_path: str | None,
+    rows: dict[str, list[tuple[Any, ...]]],
+    *,
+    entity_linetypes: dict[int, str],
+    entity_lineweights: dict[int, int],
+    layer_linetypes: Iterable[str] = (),
+    keep_unused_linetypes: bool = False,
+) -> tuple[list[tuple[int, str | None, int | None, int | None]], list[tuple[str, str, list[float]]]]:
+    """Linetype, lineweight and transparency of the rows to write, with the
+    linetypes and lineweights of ``to_dwg`` applied, plus the LTYPE entries
+    they and ``layer_linetypes`` refer to, or all those of the source with
+    ``keep_unused_linetypes``."""
+    styles, linetype_rows = _dwg_source_style_rows(decode_path, rows)
+    source_linetypes: dict[str, tuple[str, str, list[float]]] | None = None
+    if keep_unused_linetypes:
+        source_linetypes = _dwg_source_linetypes(decode_path)
+        for key, linetype_row in source_linetypes.items():
+            if key not in {"BYLAYER", "BYBLOCK", "CONTINUOUS"}:
+                linetype_rows.setdefault(key, linetype_row)
+    for handle, name in entity_linetypes.items():
+        key = str(name).upper()
+        if key not in {"BYLAYER", "BYBLOCK", "CONTINUOUS"} and key not in linetype_rows:
+            if source_linetypes is None:
+                source_linetypes = _dwg_source_linetypes(decode_path)
+            if key not in source_linetypes:
+                raise ValueError(f"linetype not found: {name}")
+            linetype_rows[key] = source_linetypes[key]
+        _handle, _name, lineweight, transparency = styles.get(int(handle), (handle, None, -1, None))
+        styles[int(handle)] = (int(handle), str(name), lineweight, transparency)
+    for name in layer_linetypes:
+        key = str(name).upper()
+        if key != "CONTINUOUS" and key not in linetype_rows:
+            if source_linetypes is None:
+                source_linetypes = _dwg_source_linetypes(decode_path)
+            if key not in source_linetypes:
+                raise ValueError(f"linetype not found: {name}")
+            linetype_rows[key] = source_linetypes[key]
+    for handle, lineweight in entity_lineweights.items():
+        if int(lineweight) not in _DWG_LINEWEIGHTS:
+            raise ValueError(f"invalid lineweight: {lineweight}")
+        _handle, linetype_name, _lineweight, transparency = styles.get(
+            int(handle), (handle, None, -1, None)
+        )
+        styles[int(handle)] = (int(handle), linetype_name, int(lineweight), transparency)
+    return sorted(styles.values()), list(linetype_rows.values())
+
+
+def _dwg_source_style_rows(
+    decode_path: str | None,
+    rows: dict[str, list[tuple[Any, ...]]],
+) -> tuple[
+    dict[int, tuple[int, str | None, int | None, int | None]],
+    dict[str, tuple[str, str, list[float]]],
+]:
+    """Source linetype, lineweight and transparency of the rows to write,
+    plus the LTYPE entries they refer to keyed by upper-cased name."""
+    handles = _dwg_row_handles(rows)
+    if not decode_path or not handles:
+        return {}, {}
+    try:
+        # R14 entities have neither lineweight nor transparency.
+        if raw.detect_version(decode_path) == "AC1014":
+            return {}, {}
+        line_styles = raw.decode_entity_line_styles(decode_path)
+        transparencies = dict(raw.decode_entity_transparency(decode_path))
+        linetypes = {row[0]: row for row in raw.decode_linetypes(decode_path)}
+    except Exception:
+        return {}, {}
+
+    style_rows: dict[int, tuple[int, str | None, int | None, int | None]] = {}
+    linetype_rows: dict[str, tuple[str, str, list[float]]] = {}
+    for handle, ltype_flags, ltype_handle, _ltype_scale, lineweight in line_styles:
+        if handle not in handles:
+            continue
+        linetype_name = _DWG_LTYPE_FLAG_NAMES.get(ltype_flags)
+        linetype = linetypes.get(ltype_handle) if ltype_flags == 3 else None
+        if linetype is not None and linetype[1]:
+            linetype_name, linetype_row = _dwg_linetype_row(linetype)
+            linetype_rows[linetype_name.upper()] = linetype_row
+        transparency = transparencies.get(handle)
+        if transparency is not None and (transparency >> 24) != 0x02:
+            transparency = None
+        if linetype_name is None and lineweight == -1 and transparency is None:
+            continue
+        style_rows[handle] = (handle, linetype_name, lineweight, transparency)
+    return style_rows, linetype_rows
+
+
+def _dwg_source_linetypes(decode_path: str | None) -> dict[str, tuple[str, str, list[float]]]:
+    """LTYPE entries of the source keyed by upper-cased name."""
+    if not decode_path:
+        return {}
+    try:
+        rows = raw.decode_linetypes(decode_path)
+    except Exception:
+        return {}
+    linetypes: dict[str, tuple[str, str, list[float]]] = {}
+    for row in rows:
+        if row[1]:
+            name, linetype_row = _dwg_linetype_row(row)
+            linetypes[name.upper()] = linetype_row
+    return linetypes
+
+
+def _dwg_linetype_row(row: tuple[Any, ...]) -> tuple[str, tuple[str, str, list[float]]]:
+    _handle, name, description, _pattern_length, dashes = row
+    return name, (
+        name,
+        description or "",
+        [float(length) for length, _shape_code, _shape_flag in dashes],
+    )
+
+
+def _dwg_write_color_rows(
+    entities: Iterable[Entity],
+    rows: dict[str, list[tuple[Any, ...]]],
+    entity_colors: dict[int, int],
+) -> list[tuple[int, int]]:
+    """Source ACI color of the rows to write, with the colors of ``to_dwg``
+    applied. Rows without one are written BYLAYER."""
+    handles = _dwg_row_handles(rows)
+    colors: dict[int, int] = {}
+    for entity in entities:
+        color_index = entity.dxf.get("color_index")
+        if entity.handle in handles and isinstance(color_index, int) and 0 <= color_index < 256:
+            colors[entity.handle] = color_index
+    for handle, color_index in entity_colors.items():
+        if not 0 <= int(color_index) <= 256:
+            raise ValueError(f"entity color must be in 0..256: {color_index}")
+        colors[int(handle)] = int(color_index)
+    return sorted(colors.items())
+
+
 def to_dxf(
     source: str | Document | Layout,
     output_path: str,
@@ -351,9 +1322,16 @@ def to_dxf(
     explode_dimensions: bool = True,
     flatten_inserts: bool = False,
     dim_block_policy: str = "smart",
+    layer_map: LayerMap | None = None,
 ) -> ConvertResult:
     ezdxf = _require_ezdxf()
     source_path, layout = _resolve_layout(source)
+    if flatten_inserts:
+        # Flattening explodes one block level per round; blocks that nest in
+        # a loop or too deep are rejected instead of being cut short.
+        from .block_nesting import check_block_references
+
+        check_block_references(layout)
     normalized_dim_block_policy = _normalize_dim_block_policy(dim_block_policy)
     decode_cache = _ConvertDecodeCache()
 
@@ -419,6 +1397,8 @@ def to_dxf(
         dxf_doc,
         layer_styles_by_handle,
         layer_names_by_handle,
+        layer_map=layer_map,
+        decode_path=layout.doc.decode_path or layout.doc.path,
     )
 
     block_reference_entities = [
@@ -509,7 +1489,7 @@ def to_dxf(
     )
 
 
-def _flatten_modelspace_inserts(modelspace: Any, *, max_depth: int = 8) -> None:
+def _flatten_modelspace_inserts(modelspace: Any, *, max_depth: int = 16) -> None:
     # Flatten nested block references for CAD viewers that do not reliably
     # evaluate deep INSERT hierarchies.
     try:
@@ -2371,13 +3351,14 @@ def _resolve_export_entities(
 def _resolve_dwg_export_entities(
     layout: Layout,
     types: str | Iterable[str] | None,
+    writable_types: Iterable[str] = _DWG_WRITABLE_ENTITY_TYPES,
 ) -> list[Entity]:
     query_types: str | Iterable[str] | None
     if types is not None:
         query_types = types
     else:
         present_types = set(_present_supported_types(layout.doc.decode_path))
-        query_types = tuple(sorted(present_types & _DWG_WRITABLE_ENTITY_TYPES))
+        query_types = tuple(sorted(present_types.intersection(writable_types)))
     selected_entities = list(layout.query(query_types))
     return _materialize_export_entities(layout, selected_entities)
 
@@ -4443,6 +5424,20 @@ def _write_entity_to_modelspace(
         return False
 
 
+def _apply_pface_face_color(attribs: dict[str, Any], face_color: tuple[Any, Any]) -> None:
+    """Overrides the polyface colors with those of a face record that does
+    not follow its layer."""
+    index, true_color = face_color
+    if index is None or int(index) == 256:
+        return
+    attribs["color"] = int(index)
+    valid_true_color = _to_valid_true_color(true_color)
+    if valid_true_color is not None:
+        attribs["true_color"] = valid_true_color
+    else:
+        attribs.pop("true_color", None)
+
+
 def _write_entity_to_modelspace_unsafe(
     modelspace: Any,
     entity: Entity,
@@ -4639,25 +5634,36 @@ def _write_entity_to_modelspace_unsafe(
     if dxftype == "POLYLINE_PFACE":
         vertices = [_point3(vertex) for vertex in dxf.get("vertices", [])]
         faces = dxf.get("faces", []) or []
+        face_colors = dxf.get("face_colors") or []
         face_written = False
-        for face in faces:
+        for face_index, face in enumerate(faces):
             if not isinstance(face, (list, tuple)):
                 continue
             points: list[tuple[float, float, float]] = []
+            invisible_edges = 0
             for raw_index in face:
                 try:
-                    idx = abs(int(raw_index))
+                    idx = int(raw_index)
                 except Exception:
                     continue
-                if idx <= 0:
+                if idx == 0 or abs(idx) > len(vertices):
                     continue
-                if idx <= len(vertices):
-                    points.append(vertices[idx - 1])
+                if idx < 0:
+                    invisible_edges |= 1 << len(points)
+                points.append(vertices[abs(idx) - 1])
             if len(points) < 3:
                 continue
+            if len(points) == 3 and invisible_edges & 4:
+                # The closing edge of a triangle becomes the fourth 3DFACE edge.
+                invisible_edges |= 8
             while len(points) < 4:
                 points.append(points[-1])
-            modelspace.add_3dface(points[:4], dxfattribs=dxfattribs)
+            face_attribs = dict(dxfattribs)
+            if invisible_edges:
+                face_attribs["invisible_edges"] = invisible_edges
+            if face_index < len(face_colors):
+                _apply_pface_face_color(face_attribs, face_colors[face_index])
+            modelspace.add_3dface(points[:4], dxfattribs=face_attribs)
             face_written = True
         if face_written:
             return True
@@ -5244,7 +6250,25 @@ def _write_hatch(modelspace: Any, dxf: dict[str, Any], dxfattribs: dict[str, Any
         hatch.set_solid_fill(color=color, rgb=rgb)
     else:
         pattern_name = str(dxf.get("pattern_name") or "ANSI31")
-        hatch.set_pattern_fill(pattern_name, color=color)
+        pattern = dxf.get("pattern") or {}
+        hatch.set_pattern_fill(
+            pattern_name,
+            color=color,
+            angle=float(pattern.get("angle", 0.0) or 0.0),
+            scale=float(pattern.get("scale", 1.0) or 1.0),
+            double=bool(pattern.get("double", False)),
+            style=int(pattern.get("style", 1)),
+        )
+        lines = pattern.get("lines") or []
+        if lines:
+            # Decoded lines are already scaled and rotated, so they replace
+            # ezdxf's library definition as-is.
+            hatch.set_pattern_definition(
+                [
+                    [line["angle"], line["origin"], line["offset"], list(line["dashes"])]
+                    for line in lines
+                ]
+            )
 
     path_written = False
     for path in paths:
@@ -5287,8 +6311,9 @@ def _write_dimension_native(
     anonymous_block_name = _dimension_anonymous_block_name(dxf)
     # Generic "*D" names are frequently reused across unrelated anonymous
     # dimension graphics in best-effort decode paths. Prefer native geometry
-    # generation for those to avoid collapsing many dimensions onto one block.
-    prefer_native_first = anonymous_block_name in {None, "*D"}
+    # generation for those to avoid collapsing many dimensions onto one block,
+    # and for blocks drawn for other definition points than the entity has.
+    prefer_native_first = anonymous_block_name in {None, "*D"} or dimension_block_is_stale(dxf)
     if not prefer_native_first and _write_dimension_block_fallback(
         modelspace,
         dxf,
@@ -5375,7 +6400,7 @@ def _write_dimension_native(
             dim = modelspace.add_ordinate_dim(
                 feature_location=feature,
                 offset=offset,
-                dtype=_ordinate_dim_type(feature, offset),
+                dtype=_ordinate_dim_type(feature, offset, dxf.get("is_x_type")),
                 origin=origin,
                 rotation=float(dxf.get("angle", 0.0)),
                 text=text,
@@ -5612,6 +6637,32 @@ def _entity_dxfattribs(
         true_color = _to_valid_true_color(dxf.get("resolved_true_color"))
     if true_color is not None:
         attribs["true_color"] = true_color
+
+    transparency = dxf.get("transparency")
+    if transparency is None:
+        transparency = dxf.get("resolved_transparency")
+    try:
+        transparency_int = int(transparency) if transparency is not None else None
+    except Exception:
+        transparency_int = None
+    if transparency_int is not None and (transparency_int >> 24) in (0x01, 0x02):
+        attribs["transparency"] = transparency_int
+
+    lineweight = dxf.get("lineweight")
+    try:
+        lineweight_int = int(lineweight) if lineweight is not None else None
+    except Exception:
+        lineweight_int = None
+    if lineweight_int is not None and lineweight_int != -1 and lineweight_int >= -3:
+        attribs["lineweight"] = lineweight_int
+
+    ltype_scale = dxf.get("ltype_scale")
+    try:
+        ltype_scale_float = float(ltype_scale) if ltype_scale is not None else None
+    except Exception:
+        ltype_scale_float = None
+    if ltype_scale_float is not None and ltype_scale_float > 0.0 and ltype_scale_float != 1.0:
+        attribs["ltscale"] = ltype_scale_float
     return attribs
 
 
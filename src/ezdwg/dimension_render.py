@@ -0,0 +1,403 @@
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass, field
+from typing import Any, Callable, Mapping
+
+from . import raw
+from .entity import Entity
+from .text_extents import estimate_text_extents
+
+Point = tuple[float, float]
+
+REGENERATED_DIMENSION_TYPES = ("LINEAR", "ALIGNED", "RADIUS", "DIAMETER")
+
+# Relative difference between the stored and the recomputed measurement above
+# which the anonymous block is considered stale.
+_STALE_TOLERANCE = 1e-6
+# Closed filled arrowheads are three times as long as they are wide.
+_ARROW_HALF_WIDTH = 1.0 / 6.0
+
+
+@dataclass(frozen=True)
+class DimStyle:
+    """Geometry variables of a DIMSTYLE entry used to regenerate dimensions.
+
+    Sizes are in drawing units and are multiplied by ``dimscale`` when
+    drawing; a ``dimscale`` of ``0`` (annotative styles) counts as ``1``.
+    """
+
+    name: str = "Standard"
+    dimscale: float = 1.0
+    dimasz: float = 0.18
+    dimexo: float = 0.0625
+    dimexe: float = 0.18
+    dimdle: float = 0.0
+    dimtxt: float = 0.18
+    dimcen: float = 0.09
+    dimtsz: float = 0.0
+    dimlfac: float = 1.0
+    dimgap: float = 0.09
+    dimtad: int = 0
+    dimdec: int = 4
+    dimse1: bool = False
+    dimse2: bool = False
+    dimtih: bool = True
+    dimtoh: bool = True
+
+    @classmethod
+    def from_row(cls, row: tuple) -> DimStyle:
+        """Style from a ``raw.decode_dim_styles`` row."""
+        _handle, name, sizes, dimtad, dimdec, flags = row
+        dimscale, dimasz, dimexo, dimexe, dimdle, dimtxt, dimcen, dimtsz, dimlfac, dimgap = sizes
+        dimse1, dimse2, dimtih, dimtoh = flags
+        return cls(
+            name=name or "",
+            dimscale=dimscale,
+            dimasz=dimasz,
+            dimexo=dimexo,
+            dimexe=dimexe,
+            dimdle=dimdle,
+            dimtxt=dimtxt,
+            dimcen=dimcen,
+            dimtsz=dimtsz,
+            dimlfac=dimlfac or 1.0,
+            dimgap=dimgap,
+            dimtad=int(dimtad),
+            dimdec=int(dimdec),
+            dimse1=bool(dimse1),
+            dimse2=bool(dimse2),
+            dimtih=bool(dimtih),
+            dimtoh=bool(dimtoh),
+        )
+
+    @property
+    def scale(self) -> float:
+        return self.dimscale if self.dimscale > 0.0 else 1.0
+
+
+@dataclass(frozen=True)
+class DimensionText:
+    """Dimension text starting at ``insert`` on its baseline."""
+
+    insert: Point
+    text: str
+    height: float
+    rotation: float
+
+
+@dataclass(frozen=True)
+class DimensionGraphics:
+    """Dimension graphics regenerated from the definition points.
+
+    ``lines`` are open polylines (extension lines, dimension line, ticks
+    and center marks); ``arrowheads`` are closed, filled triangles.
+    """
+
+    measurement: float
+    lines: list[list[Point]] = field(default_factory=list)
+    arrowheads: list[list[Point]] = field(default_factory=list)
+    text: DimensionText | None = None
+
+
+def dim_styles(path: str) -> dict[int, DimStyle]:
+    """DIMSTYLE entries of ``path`` keyed by handle."""
+    return {int(row[0]): DimStyle.from_row(row) for row in raw.decode_dim_styles(path)}
+
+
+def dimension_style_resolver(path: str | None) -> Callable[[Mapping[str, Any]], DimStyle]:
+    """Callable returning the style of a DIMENSION ``dxf`` dict.
+
+    Styles are decoded on first use. Dimensions whose style handle is not a
+    DIMSTYLE entry use ``Standard``, then the first entry, then the
+    defaults of ``DimStyle``.
+    """
+    cache: dict[int, DimStyle] | None = None
+    fallback = DimStyle()
+
+    def resolve(dxf: Mapping[str, Any]) -> DimStyle:
+        nonlocal cache, fallback
+        if cache is None:
+            try:
+                cache = dim_styles(path) if path else {}
+            except Exception:
+                cache = {}
+            by_name = {style.name.upper(): style for style in cache.values()}
+            fallback = by_name.get("STANDARD") or next(iter(cache.values()), DimStyle())
+        handle = _dxf_value(dxf, "dimstyle_handle")
+        return cache.get(handle, fallback) if handle is not None else fallback
+
+    return resolve
+
+
+def dimension_measurement(dxf: Mapping[str, Any]) -> float | None:
+    """Measurement recomputed from the definition points of a LINEAR,
+    ALIGNED, RADIUS or DIAMETER dimension, before ``dimlfac``."""
+    dimtype = _dimtype(dxf)
+    p13 = _point(dxf.get("defpoint2"))
+    p14 = _point(dxf.get("defpoint3"))
+    if p13 is None or p14 is None:
+        return None
+    if dimtype == "LINEAR":
+        angle = math.radians(float(dxf.get("angle") or 0.0))
+        return abs((p14[0] - p13[0]) * math.cos(angle) + (p14[1] - p13[1]) * math.sin(angle))
+    if dimtype in ("ALIGNED", "RADIUS", "DIAMETER"):
+        return math.hypot(p14[0] - p13[0], p14[1] - p13[1])
+    return None
+
+
+def dimension_block_is_stale(dxf: Mapping[str, Any]) -> bool:
+    """Whether the anonymous block of a dimension is missing or was drawn
+    for other definition points than the entity now has."""
+    if not dxf.get("anonymous_block_name") and _dxf_value(dxf, "anonymous_block_handle") is None:
+        return True
+    stored = _dxf_value(dxf, "actual_measurement")
+    measurement = dimension_measurement(dxf)
+    if stored is None or measurement is None:
+        return False
+    stored = float(stored)
+    return abs(stored - measurement) > _STALE_TOLERANCE * max(1.0, abs(stored))
+
+
+def regenerate_dimension(
+    target: Entity | Mapping[str, Any],
+    style: DimStyle | None = None,
+) -> DimensionGraphics | None:
+    """Regenerate the graphics of a dimension from its definition points.
+
+    Extension lines, the dimension line, arrowheads or ticks, center marks
+    and the text follow the sizes of ``style``. Returns ``None`` for
+    dimension types other than ``REGENERATED_DIMENSION_TYPES`` and for
+    degenerate definition points.
+    """
+    dxf = target.dxf if isinstance(target, Entity) else target
+    style = style or DimStyle()
+    measurement = dimension_measurement(dxf)
+    if measurement is None or measurement <= 1e-12:
+        return None
+    dimtype = _dimtype(dxf)
+    if dimtype in ("LINEAR", "ALIGNED"):
+        return _linear_graphics(dxf, style, measurement)
+    return _radial_graphics(dxf, style, measurement, diameter=dimtype == "DIAMETER")
+
+
+def _linear_graphics(dxf: Mapping[str, Any], style: DimStyle, measurement: float):
+    p13 = _point(dxf.get("defpoint2"))
+    p14 = _point(dxf.get("defpoint3"))
+    p10 = _point(dxf.get("defpoint")) or _mid(p13, p14)
+    if _dimtype(dxf) == "LINEAR":
+        angle = math.radians(float(dxf.get("angle") or 0.0))
+        direction = (math.cos(angle), math.sin(angle))
+    else:
+        direction = _unit((p14[0] - p13[0], p14[1] - p13[1]))
+    normal = (-direction[1], direction[0])
+    oblique = math.radians(float(_dxf_value(dxf, "oblique_angle") or 0.0))
+    ext_dir = _rotate(normal, oblique) if oblique else normal
+    i13 = _intersect(p13, ext_dir, p10, direction)
+    i14 = _intersect(p14, ext_dir, p10, direction)
+    if i13 is None or i14 is None:
+        return None
+
+    scale = style.scale
+    lines: list[list[Point]] = []
+    arrowheads: list[list[Point]] = []
+    for origin, foot, suppressed in ((p13, i13, style.dimse1), (p14, i14, style.dimse2)):
+        if not suppressed:
+            line = _extension_line(origin, foot, style.dimexo * scale, style.dimexe * scale)
+            if line is not None:
+                lines.append(line)
+
+    along = _unit((i14[0] - i13[0], i14[1] - i13[1]))
+    text = _dimension_label(dxf, style, measurement, "")
+    text_at = _point(_dxf_value(dxf, "text_midpoint"))
+    if text_at is None or text_at == (0.0, 0.0):
+        text_at = _mid(i13, i14)
+        if style.dimtad:
+            lift = style.dimgap * scale + style.dimtxt * scale / 2.0
+            up = normal if (normal[1], -normal[0]) > (0.0, 0.0) else _neg(normal)
+            text_at = (text_at[0] + up[0] * lift, text_at[1] + up[1] * lift)
+    rotation = math.degrees(math.atan2(along[1], along[0]))
+    if rotation > 90.0 or rotation <= -90.0:
+        rotation = rotation - 180.0 if rotation > 0.0 else rotation + 180.0
+    if style.dimtih:
+        rotation = 0.0
+    placed = _place_text(text, text_at, rotation, style)
+
+    start, end = i13, i14
+    if style.dimtsz > 0.0:
+        extend = style.dimdle * scale
+        start = (start[0] - along[0] * extend, start[1] - along[1] * extend)
+        end = (end[0] + along[0] * extend, end[1] + along[1] * extend)
+        for tip in (i13, i14):
+            lines.append(_tick(tip, along, style.dimtsz * scale))
+    else:
+        arrow = style.dimasz * scale
+        outside = _distance(i13, i14) < 2.0 * arrow
+        sign = -1.0 if outside else 1.0
+        arrowheads.append(_arrowhead(i13, (along[0] * sign, along[1] * sign), arrow))
+        arrowheads.append(_arrowhead(i14, (-along[0] * sign, -along[1] * sign), arrow))
+        if outside:
+            start = (start[0] - along[0] * 2.0 * arrow, start[1] - along[1] * 2.0 * arrow)
+            end = (end[0] + along[0] * 2.0 * arrow, end[1] + along[1] * 2.0 * arrow)
+    lines.extend(_break_for_text([start, end], placed, style, text_at))
+    return DimensionGraphics(measurement, lines, arrowheads, placed)
+
+
+def _radial_graphics(
+    dxf: Mapping[str, Any], style: DimStyle, measurement: float, *, diameter: bool
+):
+    p13 = _point(dxf.get("defpoint2"))
+    p14 = _point(dxf.get("defpoint3"))
+    scale = style.scale
+    arrow = style.dimasz * scale
+    axis = _unit((p14[0] - p13[0], p14[1] - p13[1]))
+    lines: list[list[Point]] = []
+    arrowheads: list[list[Point]] = []
+    lines.append([p13, p14])
+    # Radius arrows point at the arc; diameter arrows at both ends of the chord.
+    arrowheads.append(_arrowhead(p14, _neg(axis), arrow))
+    center = p13
+    if diameter:
+        arrowheads.append(_arrowhead(p13, axis, arrow))
+        center = _mid(p13, p14)
+    if style.dimcen != 0.0:
+        size = abs(style.dimcen) * scale
+        lines.append([(center[0] - size, center[1]), (center[0] + size, center[1])])
+        lines.append([(center[0], center[1] - size), (center[0], center[1] + size)])
+
+    prefix = "⌀" if diameter else "R"
+    text = _dimension_label(dxf, style, measurement, prefix)
+    text_at = _point(_dxf_value(dxf, "text_midpoint"))
+    if text_at is None or text_at == (0.0, 0.0):
+        offset = style.dimgap * scale + style.dimtxt * scale / 2.0
+        text_at = (p14[0] + axis[0] * offset, p14[1] + axis[1] * offset)
+    rotation = 0.0 if style.dimtoh else math.degrees(math.atan2(axis[1], axis[0]))
+    placed = _place_text(text, text_at, rotation, style)
+    return DimensionGraphics(measurement, lines, arrowheads, placed)
+
+
+def _dimension_label(
+    dxf: Mapping[str, Any], style: DimStyle, measurement: float, prefix: str
+) -> str:
+    value = f"{prefix}{measurement * style.dimlfac:.{max(0, style.dimdec)}f}"
+    override = str(_dxf_value(dxf, "text") or "")
+    if not override:
+        return value
+    # "<>" stands for the measured value; a single space suppresses the text.
+    if override.strip() == "":
+        return ""
+    return override.replace("<>", value)
+
+
+def _place_text(text: str, center: Point, rotation: float, style: DimStyle):
+    if not text:
+        return None
+    height = style.dimtxt * style.scale
+    extents = estimate_text_extents(text, None, height)
+    angle = math.radians(rotation)
+    ux, uy = math.cos(angle), math.sin(angle)
+    half_w, half_h = extents.width / 2.0, height / 2.0
+    insert = (center[0] - ux * half_w + uy * half_h, center[1] - uy * half_w - ux * half_h)
+    return DimensionText(insert, text, height, rotation)
+
+
+def _break_for_text(
+    line: list[Point], text: DimensionText | None, style: DimStyle, center: Point
+) -> list[list[Point]]:
+    """Split the dimension line around text centered on it."""
+    start, end = line
+    if text is None or style.dimtad:
+        return [line]
+    along = _unit((end[0] - start[0], end[1] - start[1]))
+    length = _distance(start, end)
+    offset = (center[0] - start[0]) * along[0] + (center[1] - start[1]) * along[1]
+    across = abs((center[0] - start[0]) * along[1] - (center[1] - start[1]) * along[0])
+    width = estimate_text_extents(text.text, None, text.height).width
+    half = width / 2.0 + style.dimgap * style.scale
+    if across > text.height or offset - half <= 0.0 or offset + half >= length:
+        return [line]
+    return [
+        [start, (start[0] + along[0] * (offset - half), start[1] + along[1] * (offset - half))],
+        [(start[0] + along[0] * (offset + half), start[1] + along[1] * (offset + half)), end],
+    ]
+
+
+def _extension_line(origin: Point, foot: Point, offset: float, extension: float):
+    length = _distance(origin, foot)
+    if length <= 1e-12:
+        return None
+    direction = ((foot[0] - origin[0]) / length, (foot[1] - origin[1]) / length)
+    if offset >= length:
+        return None
+    return [
+        (origin[0] + direction[0] * offset, origin[1] + direction[1] * offset),
+        (foot[0] + direction[0] * extension, foot[1] + direction[1] * extension),
+    ]
+
+
+def _arrowhead(tip: Point, direction: Point, size: float) -> list[Point]:
+    """Triangle with its tip at ``tip`` and its base ``size`` along ``direction``."""
+    bx, by = tip[0] + direction[0] * size, tip[1] + direction[1] * size
+    wx, wy = -direction[1] * size * _ARROW_HALF_WIDTH, direction[0] * size * _ARROW_HALF_WIDTH
+    return [tip, (bx + wx, by + wy), (bx - wx, by - wy)]
+
+
+def _tick(center: Point, along: Point, size: float) -> list[Point]:
+    """Architectural tick: a stroke at 45 degrees to the dimension line."""
+    dx = (along[0] - along[1]) * size / 2.0
+    dy = (along[1] + along[0]) * size / 2.0
+    return [(center[0] - dx, center[1] - dy), (center[0] + dx, center[1] + dy)]
+
+
+def _intersect(point: Point, direction: Point, origin: Point, line_dir: Point) -> Point | None:
+    denom = direction[0] * line_dir[1] - direction[1] * line_dir[0]
+    if abs(denom) <= 1e-12:
+        return None
+    t = ((origin[0] - point[0]) * line_dir[1] - (origin[1] - point[1]) * line_dir[0]) / denom
+    return (point[0] + direction[0] * t, point[1] + direction[1] * t)
+
+
+def _dimtype(dxf: Mapping[str, Any]) -> str:
+    dimtype = str(dxf.get("dimtype") or "").upper()
+    return dimtype[4:] if dimtype.startswith("DIM_") else dimtype
+
+
+def _dxf_value(dxf: Mapping[str, Any], key: str):
+    if key in dxf:
+        return dxf.get(key)
+    common = dxf.get("common")
+    return common.get(key) if isinstance(common, dict) else None
+
+
+def _point(value) -> Point | None:
+    try:
+        x, y = float(value[0]), float(value[1])
+    except (TypeError, ValueError, IndexError):
+        return None
+    if not (math.isfinite(x) and math.isfinite(y)):
+        return None
+    return (x, y)
+
+
+def _unit(vector: Point) -> Point:
+    length = math.hypot(vector[0], vector[1])
+    if length <= 1e-12:
+        return (1.0, 0.0)
+    return (vector[0] / length, vector[1] / length)
+
+
+def _rotate(vector: Point, angle: float) -> Point:
+    c, s = math.cos(angle), math.sin(angle)
+    return (vector[0] * c - vector[1] * s, vector[0] * s + vector[1] * c)
+
+
+def _neg(vector: Point) -> Point:
+    return (-vector[0], -vector[1])
+
+
+def _mid(a: Point, b: Point) -> Point:
+    return ((a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0)
+
+
+def _distance(a: Point, b: Point) -> float:
+    return math.hypot(b[0] - a[0], b[1] - a[1])
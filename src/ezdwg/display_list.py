@@ -0,0 +1,716 @@
+from __future__ import annotations
+
+import json
+import math
+import struct
+from dataclasses import dataclass, field
+from functools import lru_cache
+from typing import Any, Iterable, Mapping
+
+from . import raw
+from .colors import AciPalette, as_palette
+from .convert import _resolve_layout
+from .dimension_render import dimension_style_resolver
+from .document import _layer_names_by_handle
+from .hatch_fill import fill_hatch
+from .entity import Entity
+from .render import (
+    _TEXT_POINTS_PER_UNIT,
+    _apply_dwg_transparency,
+    _build_lwpolyline_path,
+    _draw_layout,
+    _resolve_dwg_color,
+)
+
+DISPLAY_LIST_OPS = ("move", "line", "arc", "text", "fill")
+
+_MAGIC = b"EZDL"
+_FORMAT_VERSION = 2
+_SUPPORTED_VERSIONS = (1, 2)
+_OP_CODES = {op: code for code, op in enumerate(DISPLAY_LIST_OPS)}
+_DEFAULT_COLOR = "#000000"
+_MAX_BLOCK_DEPTH = 16
+_MAX_MINSERT_CELLS = 100_000
+_LINEWEIGHT_BYBLOCK = -2
+
+Point2 = tuple[float, float]
+# Affine 2D transform ``(a, b, c, d, e, f)``: x' = a*x + c*y + e, y' = b*x + d*y + f.
+Matrix = tuple[float, float, float, float, float, float]
+_IDENTITY: Matrix = (1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+
+
+@dataclass(frozen=True)
+class DrawCommand:
+    """One display list primitive in world coordinates.
+
+    ``move`` starts a path at ``point`` and ``line`` extends the current path
+    to ``point``. ``arc`` is a standalone counter-clockwise arc around
+    ``point`` from ``start_angle`` to ``end_angle`` (degrees, ``end_angle``
+    is always greater). ``text`` is anchored at ``point``. ``fill`` paints
+    ``rings`` with the even-odd rule.
+
+    Primitives of expanded blocks record where they came from: ``inserts``
+    holds the handles of the INSERT and MINSERT entities that placed them,
+    outermost first, and ``blocks`` the names of the blocks they expanded.
+    ``handle`` is then the entity inside the innermost block.
+    """
+
+    op: str
+    order: int
+    handle: int
+    layer: str
+    color: str
+    width: float
+    point: Point2 = (0.0, 0.0)
+    radius: float = 0.0
+    start_angle: float = 0.0
+    end_angle: float = 0.0
+    text: str = ""
+    height: float = 0.0
+    rotation: float = 0.0
+    rings: tuple[tuple[Point2, ...], ...] = ()
+    inserts: tuple[int, ...] = ()
+    blocks: tuple[str, ...] = ()
+
+    @property
+    def depth(self) -> int:
+        """Block nesting depth; ``0`` for entities drawn directly in the layout."""
+        return len(self.inserts)
+
+    def to_dict(self) -> dict[str, Any]:
+        out: dict[str, Any] = {
+            "op": self.op,
+            "order": self.order,
+            "handle": self.handle,
+            "layer": self.layer,
+            "color": self.color,
+            "width": self.width,
+        }
+        if self.op in ("move", "line"):
+            out["point"] = list(self.point)
+        elif self.op == "arc":
+            out["center"] = list(self.point)
+            out["radius"] = self.radius
+            out["start_angle"] = self.start_angle
+            out["end_angle"] = self.end_angle
+        elif self.op == "text":
+            out["insert"] = list(self.point)
+            out["text"] = self.text
+            out["height"] = self.height
+            out["rotation"] = self.rotation
+        elif self.op == "fill":
+            out["rings"] = [[list(point) for point in ring] for ring in self.rings]
+        if self.inserts:
+            out["inserts"] = list(self.inserts)
+            out["blocks"] = list(self.blocks)
+        return out
+
+
+@dataclass
+class DisplayList:
+    commands: list[DrawCommand] = field(default_factory=list)
+
+    def to_json(self, *, indent: int | None = None) -> str:
+        return json.dumps(
+            {
+                "version": _FORMAT_VERSION,
+                "commands": [command.to_dict() for command in self.commands],
+            },
+            indent=indent,
+            ensure_ascii=False,
+        )
+
+    def to_bytes(self) -> bytes:
+        """Serialize to the compact little-endian ``EZDL`` binary format.
+
+        Layers and text are stored once in a string table. Each command is
+        ``op u8, order u32, handle u64, layer u32, rgba u32, width u16``
+        (hundredths of a millimetre), then ``depth u8`` and ``depth`` pairs
+        of ``insert u64, block u32`` naming its insert chain, followed by
+        its payload: ``x, y`` for
+        move/line, ``cx, cy, radius, start, end`` for arc, ``x, y, height,
+        rotation`` plus a string index for text, and ``ring count, (point
+        count, points...)...`` for fill. Coordinates are f64.
+        """
+        strings: dict[str, int] = {}
+
+        def intern(value: str) -> int:
+            return strings.setdefault(value, len(strings))
+
+        body = bytearray()
+        for command in self.commands:
+            body += struct.pack(
+                "<BIQIIH",
+                _OP_CODES[command.op],
+                command.order,
+                command.handle,
+                intern(command.layer),
+                _color_to_rgba(command.color),
+                round(command.width * 100.0),
+            )
+            body += struct.pack("<B", len(command.inserts))
+            for insert, block in zip(command.inserts, command.blocks):
+                body += struct.pack("<QI", insert, intern(block))
+            if command.op in ("move", "line"):
+                body += struct.pack("<2d", *command.point)
+            elif command.op == "arc":
+                body += struct.pack(
+                    "<5d",
+                    *command.point,
+                    command.radius,
+                    command.start_angle,
+                    command.end_angle,
+                )
+            elif command.op == "text":
+                body += struct.pack(
+                    "<4dI",
+                    *command.point,
+                    command.height,
+                    command.rotation,
+                    intern(command.text),
+                )
+            elif command.op == "fill":
+                body += struct.pack("<I", len(command.rings))
+                for ring in command.rings:
+                    body += struct.pack("<I", len(ring))
+                    for point in ring:
+                        body += struct.pack("<2d", *point)
+
+        out = bytearray(_MAGIC)
+        out += struct.pack("<HI", _FORMAT_VERSION, len(strings))
+        for value in strings:
+            encoded = value.encode("utf-8")
+            out += struct.pack("<I", len(encoded)) + encoded
+        out += struct.pack("<I", len(self.commands))
+        out += body
+        return bytes(out)
+
+    @classmethod
+    def from_bytes(cls, data: bytes) -> "DisplayList":
+        reader = _ByteReader(data)
+        if reader.take(4) != _MAGIC:
+            raise ValueError("not an ezdwg display list")
+        version, string_count = reader.unpack("<HI")
+        if version not in _SUPPORTED_VERSIONS:
+            raise ValueError(f"unsupported display list version: {version}")
+        strings = [reader.take(reader.unpack("<I")[0]).decode("utf-8") for _ in range(string_count)]
+        (count,) = reader.unpack("<I")
+        commands = []
+        for _ in range(count):
+            code, order, handle, layer, rgba, width = reader.unpack("<BIQIIH")
+            if code >= len(DISPLAY_LIST_OPS):
+                raise ValueError(f"unknown display list op code: {code}")
+            op = DISPLAY_LIST_OPS[code]
+            fields: dict[str, Any] = {}
+            if version >= 2:
+                chain = [reader.unpack("<QI") for _ in range(reader.unpack("<B")[0])]
+                fields["inserts"] = tuple(insert for insert, _block in chain)
+                fields["blocks"] = tuple(strings[block] for _insert, block in chain)
+            if op in ("move", "line"):
+                fields["point"] = reader.unpack("<2d")
+            elif op == "arc":
+                x, y, radius, start, end = reader.unpack("<5d")
+                fields.update(point=(x, y), radius=radius, start_angle=start, end_angle=end)
+            elif op == "text":
+                x, y, height, rotation, text = reader.unpack("<4dI")
+                fields.update(point=(x, y), height=height, rotation=rotation, text=strings[text])
+            else:
+                rings = []
+                for _ in range(reader.unpack("<I")[0]):
+                    size = reader.unpack("<I")[0]
+                    rings.append(tuple(reader.unpack("<2d") for _ in range(size)))
+                fields["rings"] = tuple(rings)
+            commands.append(
+                DrawCommand(
+                    op=op,
+                    order=order,
+                    handle=handle,
+                    layer=strings[layer],
+                    color=_rgba_to_color(rgba),
+                    width=width / 100.0,
+                    **fields,
+                )
+            )
+        return cls(commands)
+
+
+def build_display_list(
+    source,
+    types: str | Iterable[str] | None = None,
+    *,
+    expand_blocks: bool = True,
+    arc_segments: int = 64,
+    palette: AciPalette | Mapping[int, tuple[int, int, int]] | None = None,
+) -> DisplayList:
+    """Flatten modelspace into world-space draw commands in draw order.
+
+    Colors, transparency, layers and lineweights are resolved per command,
+    including BYBLOCK and layer ``0`` inheritance inside blocks. INSERT and
+    MINSERT references are expanded into their block contents unless
+    ``expand_blocks`` is false; ``types`` then filters the expanded
+    primitives. ``width`` is the lineweight in millimetres, or ``0.0`` for
+    the renderer default. Curves other than circular arcs are flattened into
+    ``arc_segments`` line segments. ``palette`` maps ACI colors as in
+    ``to_png``; ACI 7 is black.
+    """
+    _, layout = _resolve_layout(source)
+    decode_path = layout.doc.decode_path or layout.doc.path
+    selected = _normalize_types(types)
+    query_types = None
+    if selected is not None:
+        query_types = sorted(selected | {"INSERT", "MINSERT"}) if expand_blocks else sorted(selected)
+    entities = list(layout.query(query_types))
+
+    builder = _DisplayListBuilder(
+        decode_path,
+        {entity.handle: entity for entity in entities},
+        selected,
+        expand_blocks=expand_blocks,
+        arc_segments=arc_segments,
+        palette=as_palette(palette),
+    )
+    roots = entities
+    modelspace = _modelspace_children(decode_path)
+    if modelspace is not None:
+        members = [builder.entities[handle] for handle in modelspace[1] if handle in builder.entities]
+        if members or not entities:
+            roots = members
+            builder.stack = (modelspace[0],)
+    for entity in roots:
+        builder.emit(entity)
+    return DisplayList(builder.commands)
+
+
+@dataclass(frozen=True)
+class _Inherited:
+    layer: str
+    color: str
+    width: float
+
+
+class _DisplayListBuilder:
+    def __init__(
+        self,
+        decode_path: str,
+        entities: dict[int, Entity],
+        selected: set[str] | None,
+        *,
+        expand_blocks: bool,
+        arc_segments: int,
+        palette: AciPalette | None = None,
+    ) -> None:
+        self.decode_path = decode_path
+        self.entities = entities
+        self.selected = selected
+        self.expand_blocks = expand_blocks
+        self.arc_segments = max(4, int(arc_segments))
+        self.palette = palette
+        self.layer_names = _layer_names_by_handle(decode_path)
+        self.dim_styles = dimension_style_resolver(decode_path)
+        self.commands: list[DrawCommand] = []
+        self.matrix = _IDENTITY
+        self.inherited: _Inherited | None = None
+        self.stack: tuple[int, ...] = ()
+        self.inserts: tuple[int, ...] = ()
+        self.blocks: tuple[str, ...] = ()
+        self._order = 0
+        self._style: tuple[int, str, str, float] = (0, "0", _DEFAULT_COLOR, 0.0)
+
+    def emit(self, entity: Entity) -> None:
+        dxf = entity.dxf
+        layer, color, width = self._resolve_style(dxf)
+        dxftype = entity.dxftype
+        if self.expand_blocks and dxftype in ("INSERT", "MINSERT"):
+            self._expand_insert(entity, _Inherited(layer, color, width))
+            return
+        if self.selected is not None and dxftype not in self.selected:
+            return
+        self._style = (int(entity.handle), layer, color, width)
+        emitted = len(self.commands)
+        self._emit_geometry(entity)
+        if len(self.commands) > emitted:
+            self._order += 1
+
+    def _resolve_style(self, dxf: dict) -> tuple[str, str, float]:
+        layer = self.layer_names.get(dxf.get("layer_handle"), "0")
+        inherited = self.inherited
+        color_index = dxf.get("color_index")
+        by_block = color_index == 0 or (
+            layer == "0" and color_index in (None, 256) and dxf.get("true_color") is None
+        )
+        if inherited is not None and by_block:
+            color = inherited.color
+        else:
+            color = _resolve_dwg_color(dxf, self.palette) or _DEFAULT_COLOR
+            color = _apply_dwg_transparency(color, dxf)
+        try:
+            lineweight = int(dxf.get("lineweight", -1))
+        except (TypeError, ValueError):
+            lineweight = -1
+        if lineweight >= 0:
+            width = lineweight / 100.0
+        elif lineweight == _LINEWEIGHT_BYBLOCK and inherited is not None:
+            width = inherited.width
+        else:
+            width = 0.0
+        if inherited is not None and layer == "0":
+            layer = inherited.layer
+        return layer, color, width
+
+    def _expand_insert(self, entity: Entity, inherited: _Inherited) -> None:
+        dxf = entity.dxf
+        name = dxf.get("name")
+        block = _block_definition(self.decode_path, name) if isinstance(name, str) else None
+        if block is None:
+            return
+        record_handle, base_point, children = block
+        if record_handle in self.stack:
+            # Model space may sit below the blocks on the stack, unnamed.
+            start = self.stack.index(record_handle) - (len(self.stack) - len(self.blocks))
+            loop = self.blocks[start:] if start >= 0 else (name,) + self.blocks
+            raise _block_reference_error("cycle", loop + (name,))
+        if len(self.blocks) >= _MAX_BLOCK_DEPTH:
+            raise _block_reference_error("depth", self.blocks + (name,))
+        saved = (self.matrix, self.inherited, self.stack, self.inserts, self.blocks)
+        try:
+            for matrix in _insert_matrices(dxf, base_point):
+                self.matrix = _compose(saved[0], matrix)
+                self.inherited = inherited
+                self.stack = saved[2] + (record_handle,)
+                self.inserts = saved[3] + (int(entity.handle),)
+                self.blocks = saved[4] + (name,)
+                for handle in children:
+                    child = self.entities.get(handle)
+                    if child is not None:
+                        self.emit(child)
+        finally:
+            self.matrix, self.inherited, self.stack, self.inserts, self.blocks = saved
+
+    def _emit_geometry(self, entity: Entity) -> None:
+        dxf = entity.dxf
+        dxftype = entity.dxftype
+        if dxftype == "LINE":
+            self._path([dxf["start"], dxf["end"]])
+        elif dxftype in ("LWPOLYLINE", "POLYLINE_2D"):
+            points = dxf.get("points", [])
+            bulges = dxf.get("bulges")
+            if dxf.get("interpolation_applied"):
+                points, bulges = dxf.get("interpolated_points", []), None
+            self._polyline(points, bulges, bool(dxf.get("closed", False)))
+        elif dxftype in ("POLYLINE_3D", "SPLINE", "MLINE"):
+            self._polyline(dxf.get("points", []), None, bool(dxf.get("closed", False)))
+        elif dxftype == "LEADER":
+            self._polyline(dxf.get("points", []), None, False)
+        elif dxftype == "ARC":
+            self._arc(dxf["center"], dxf["radius"], dxf["start_angle"], dxf["end_angle"])
+        elif dxftype == "CIRCLE":
+            self._arc(dxf["center"], dxf["radius"], 0.0, 360.0)
+        elif dxftype in ("SOLID", "TRACE"):
+            # Corners are stored in zig-zag order: 1, 2, 4, 3.
+            points = list(dxf.get("points", []))[:4]
+            if len(points) == 4:
+                points = [points[0], points[1], points[3], points[2]]
+            self._fill([points])
+        elif dxftype == "HATCH":
+            paths = [path for path in dxf.get("paths", []) if isinstance(path, dict)]
+            fill = fill_hatch(dxf)
+            if dxf.get("solid_fill"):
+                self._fill(fill.polygons)
+            else:
+                for start, end in fill.lines:
+                    self._path([start, end])
+                for path in paths:
+                    self._polyline(path.get("points", []), None, bool(path.get("closed", False)))
+        elif dxftype in ("TEXT", "ATTRIB", "ATTDEF", "TOLERANCE"):
+            self._text(
+                dxf.get("insert", (0.0, 0.0, 0.0)),
+                dxf.get("text", ""),
+                dxf.get("height", 1.0),
+                dxf.get("rotation", 0.0),
+            )
+        elif dxftype == "MTEXT":
+            self._text(
+                dxf.get("insert", (0.0, 0.0, 0.0)),
+                str(dxf.get("text", "")).replace("\\P", "\n"),
+                dxf.get("char_height", 1.0),
+                dxf.get("rotation", 0.0),
+            )
+        else:
+            # Everything else (ellipses, dimensions, meshes, ...) reuses the
+            # plot() tessellation.
+            collector = _PrimitiveCollector()
+            _draw_layout(
+                collector,
+                _SingleEntityLayout(entity),
+                None,
+                line_width=1.0,
+                arc_segments=self.arc_segments,
+                dimension_color=None,
+                lineweights=False,
+                linetypes=False,
+                ltscale=1.0,
+                celtscale=1.0,
+                color_resolver=lambda _dxf: None,
+                dim_styles=self.dim_styles,
+            )
+            for points in collector.paths:
+                self._path(points)
+            for insert, text, height, rotation in collector.texts:
+                self._text(insert, text, height, rotation)
+
+    def _command(self, op: str, **fields: Any) -> None:
+        handle, layer, color, width = self._style
+        self.commands.append(
+            DrawCommand(
+                op=op,
+                order=self._order,
+                handle=handle,
+                layer=layer,
+                color=color,
+                width=width,
+                inserts=self.inserts,
+                blocks=self.blocks,
+                **fields,
+            )
+        )
+
+    def _polyline(self, points, bulges, closed: bool) -> None:
+        self._path(
+            _build_lwpolyline_path(points, bulges=bulges, closed=closed, arc_segments=self.arc_segments)
+        )
+
+    def _path(self, points) -> None:
+        points = [_xy(point) for point in points]
+        if not points:
+            return
+        if len(points) == 1:
+            # Points become zero-length segments so renderers draw a dot.
+            points.append(points[0])
+        self._command("move", point=_apply(self.matrix, points[0]))
+        for point in points[1:]:
+            self._command("line", point=_apply(self.matrix, point))
+
+    def _arc(self, center, radius: float, start_angle: float, end_angle: float) -> None:
+        sweep = (float(end_angle) - float(start_angle)) % 360.0 or 360.0
+        scale = _similarity_scale(self.matrix)
+        if scale is None:
+            # Non-uniform block scaling turns arcs into ellipses.
+            cx, cy = _xy(center)
+            steps = self.arc_segments
+            self._path(
+                [
+                    (
+                        cx + radius * math.cos(math.radians(start_angle + sweep * i / steps)),
+                        cy + radius * math.sin(math.radians(start_angle + sweep * i / steps)),
+                    )
+                    for i in range(steps + 1)
+                ]
+            )
+            return
+        a, b, c, d, _, _ = self.matrix
+        # A mirroring transform reverses the arc, so it starts at the old end.
+        anchor = math.radians(float(end_angle) if a * d - b * c < 0.0 else float(start_angle))
+        ux, uy = math.cos(anchor), math.sin(anchor)
+        start = math.degrees(math.atan2(b * ux + d * uy, a * ux + c * uy)) % 360.0
+        self._command(
+            "arc",
+            point=_apply(self.matrix, _xy(center)),
+            radius=float(radius) * scale,
+            start_angle=start,
+            end_angle=start + sweep,
+        )
+
+    def _text(self, insert, text: str, height, rotation) -> None:
+        if not text:
+            return
+        a, b, c, d, _, _ = self.matrix
+        angle = math.radians(float(rotation or 0.0))
+        ux, uy = math.cos(angle), math.sin(angle)
+        # Height scales with the text's up vector, rotation follows its baseline.
+        self._command(
+            "text",
+            point=_apply(self.matrix, _xy(insert)),
+            text=str(text),
+            height=abs(float(height or 0.0)) * math.hypot(c * ux - a * uy, d * ux - b * uy),
+            rotation=math.degrees(math.atan2(b * ux + d * uy, a * ux + c * uy)),
+        )
+
+    def _fill(self, rings) -> None:
+        out = []
+        for ring in rings:
+            points = tuple(_apply(self.matrix, _xy(point)) for point in ring)
+            if len(points) >= 3:
+                out.append(points)
+        if out:
+            self._command("fill", rings=tuple(out))
+
+
+class _PrimitiveCollector:
+    """Stands in for a matplotlib ``Axes`` and records polylines and text."""
+
+    def __init__(self) -> None:
+        self.paths: list[list[Point2]] = []
+        self.texts: list[tuple[Point2, str, float, float]] = []
+        self.lines: list = []
+
+    def plot(self, xs, ys, **_kwargs):
+        self.paths.append([(float(x), float(y)) for x, y in zip(xs, ys)])
+        return []
+
+    def text(self, x, y, text, fontsize=0.0, rotation=0.0, **_kwargs):
+        self.texts.append(((float(x), float(y)), text, fontsize / _TEXT_POINTS_PER_UNIT, rotation))
+        return None
+
+    def get_facecolor(self):
+        return "#ffffff"
+
+
+class _SingleEntityLayout:
+    def __init__(self, entity: Entity) -> None:
+        self._entity = entity
+
+    def query(self, _types=None):
+        return [self._entity]
+
+
+class _ByteReader:
+    def __init__(self, data: bytes) -> None:
+        self._data = memoryview(data)
+        self._offset = 0
+
+    def take(self, size: int) -> bytes:
+        end = self._offset + size
+        if end > len(self._data):
+            raise ValueError("truncated display list")
+        chunk = bytes(self._data[self._offset : end])
+        self._offset = end
+        return chunk
+
+    def unpack(self, fmt: str) -> tuple:
+        return struct.unpack(fmt, self.take(struct.calcsize(fmt)))
+
+
+@lru_cache(maxsize=16)
+def _modelspace_children(path: str) -> tuple[int, tuple[int, ...]] | None:
+    try:
+        handle = dict(raw.decode_table_control(path, "BLOCK")[4]).get("*MODEL_SPACE")
+        if handle is None:
+            return None
+        return int(handle), tuple(raw.iter_block_children(path, handle))
+    except Exception:
+        return None
+
+
+def _block_reference_error(kind: str, blocks: tuple[str, ...]) -> ValueError:
+    # block_nesting imports this module, so the error class is looked up late.
+    from .block_nesting import BlockReferenceError
+
+    return BlockReferenceError(kind, list(blocks), _MAX_BLOCK_DEPTH)
+
+
+@lru_cache(maxsize=256)
+def _block_definition(
+    path: str, name: str
+) -> tuple[int, tuple[float, float, float], tuple[int, ...]] | None:
+    # Block header names also report handles of BLOCK/ENDBLK entities, so
+    # take the first candidate that decodes as a block record.
+    key = name.strip().upper()
+    for handle in _block_handles_by_name(path).get(key, ()):
+        try:
+            children = raw.iter_block_children(path, handle)
+            base_point = raw.decode_block_base_point(path, handle)
+        except Exception:
+            continue
+        return handle, tuple(base_point), tuple(children)
+    return None
+
+
+@lru_cache(maxsize=16)
+def _block_handles_by_name(path: str) -> dict[str, tuple[int, ...]]:
+    out: dict[str, list[int]] = {}
+    try:
+        rows = raw.decode_block_header_names(path)
+    except Exception:
+        return {}
+    for handle, name in rows:
+        if isinstance(name, str):
+            out.setdefault(name.strip().upper(), []).append(int(handle))
+    return {name: tuple(handles) for name, handles in out.items()}
+
+
+def _insert_matrices(dxf: dict, base_point) -> Iterable[Matrix]:
+    ix, iy = _xy(dxf.get("insert", (0.0, 0.0, 0.0)))
+    sx = float(dxf.get("xscale", 1.0) or 1.0)
+    sy = float(dxf.get("yscale", 1.0) or 1.0)
+    angle = math.radians(float(dxf.get("rotation", 0.0) or 0.0))
+    cos, sin = math.cos(angle), math.sin(angle)
+    a, b, c, d = cos * sx, sin * sx, -sin * sy, cos * sy
+    bx, by = _xy(base_point)
+    columns = max(1, int(dxf.get("column_count", 1) or 1))
+    rows = max(1, int(dxf.get("row_count", 1) or 1))
+    if columns * rows > _MAX_MINSERT_CELLS:
+        columns = rows = 1
+    column_spacing = float(dxf.get("column_spacing", 0.0) or 0.0)
+    row_spacing = float(dxf.get("row_spacing", 0.0) or 0.0)
+    for row in range(rows):
+        for column in range(columns):
+            # MINSERT spacing runs along the rotated block axes.
+            ox, oy = column * column_spacing, row * row_spacing
+            tx = ix + ox * cos - oy * sin
+            ty = iy + ox * sin + oy * cos
+            yield (a, b, c, d, tx - (a * bx + c * by), ty - (b * bx + d * by))
+
+
+def _compose(outer: Matrix, inner: Matrix) -> Matrix:
+    a1, b1, c1, d1, e1, f1 = outer
+    a2, b2, c2, d2, e2, f2 = inner
+    return (
+        a1 * a2 + c1 * b2,
+        b1 * a2 + d1 * b2,
+        a1 * c2 + c1 * d2,
+        b1 * c2 + d1 * d2,
+        a1 * e2 + c1 * f2 + e1,
+        b1 * e2 + d1 * f2 + f1,
+    )
+
+
+def _apply(matrix: Matrix, point: Point2) -> Point2:
+    a, b, c, d, e, f = matrix
+    x, y = point
+    return (a * x + c * y + e, b * x + d * y + f)
+
+
+def _similarity_scale(matrix: Matrix) -> float | None:
+    a, b, c, d, _, _ = matrix
+    sx = math.hypot(a, b)
+    sy = math.hypot(c, d)
+    tolerance = 1.0e-9 * max(sx, sy, 1.0)
+    if abs(sx - sy) > tolerance or abs(a * c + b * d) > tolerance * max(sx, sy, 1.0):
+        return None
+    return sx
+
+
+def _xy(point) -> Point2:
+    return (float(point[0]), float(point[1]))
+
+
+def _normalize_types(types: str | Iterable[str] | None) -> set[str] | None:
+    if types is None:
+        return None
+    if isinstance(types, str):
+        types = types.replace(",", " ").split()
+    selected = {str(name).strip().upper() for name in types if str(name).strip()}
+    return selected or None
+
+
+def _color_to_rgba(color: str) -> int:
+    value = color.lstrip("#")
+    if len(value) == 6:
+        value += "ff"
+    return int(value, 16)
+
+
+def _rgba_to_color(rgba: int) -> str:
+    if rgba & 0xFF == 0xFF:
+        return f"#{rgba >> 8:06x}"
+    return f"#{rgba:08x}"
@@ -3,8 +3,9 @@ from __future__ import annotations
 import fnmatch
 import math
 import re
+from datetime import datetime, timedelta
 from functools import lru_cache
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from typing import Any, Callable, Iterable, Iterator
 
 from . import raw
@@ -23,6 +24,7 @@ SUPPORTED_ENTITY_TYPES = (
     "POLYLINE_PFACE",
     "VERTEX_PFACE",
     "VERTEX_PFACE_FACE",
+    "MESH",
     "SEQEND",
     "3DFACE",
     "SOLID",
@@ -37,6 +39,9 @@ SUPPORTED_ENTITY_TYPES = (
     "REGION",
     "RAY",
     "XLINE",
+    "LIGHT",
+    "RTEXT",
+    "ARCALIGNEDTEXT",
     "ARC",
     "CIRCLE",
     "ELLIPSE",
@@ -47,6 +52,7 @@ SUPPORTED_ENTITY_TYPES = (
     "ATTDEF",
     "MTEXT",
     "LEADER",
+    "MULTILEADER",
     "HATCH",
     "TOLERANCE",
     "MLINE",
@@ -57,6 +63,67 @@ SUPPORTED_ENTITY_TYPES = (
     "DIMENSION",
 )
 
+@dataclass(frozen=True)
+class DrawingTimes:
+    """Revision timestamps of a drawing.
+
+    ``created`` and ``updated`` are naive local times (``TDCREATE`` and
+    ``TDUPDATE``); ``edit_time`` is the cumulative editing time
+    (``TDINDWG``) and ``user_timer`` the user elapsed timer (``TDUSRTIMER``).
+    """
+
+    created: datetime
+    updated: datetime
+    edit_time: timedelta
+    user_timer: timedelta
+
+    @classmethod
+    def from_raw(cls, row: tuple) -> "DrawingTimes":
+        created, updated, edit_time, user_timer = row
+        return cls(
+            created=_julian_to_datetime(created),
+            updated=_julian_to_datetime(updated),
+            edit_time=_julian_to_timedelta(edit_time),
+            user_timer=_julian_to_timedelta(user_timer),
+        )
+
+    def to_raw(self) -> tuple:
+        """``(day, millis)`` pairs as taken by ``raw.write_ac1015_dwg``."""
+        return (
+            _datetime_to_julian(self.created),
+            _datetime_to_julian(self.updated),
+            _timedelta_to_julian(self.edit_time),
+            _timedelta_to_julian(self.user_timer),
+        )
+
+
+# Header dates are Julian day numbers with the milliseconds since midnight;
+# 1970-01-01 is day 2440588.
+_UNIX_EPOCH = datetime(1970, 1, 1)
+_UNIX_EPOCH_JULIAN_DAY = 2440588
+
+
+def _julian_to_timedelta(pair: tuple[int, int]) -> timedelta:
+    day, millis = pair
+    return timedelta(days=int(day), milliseconds=int(millis))
+
+
+def _julian_to_datetime(pair: tuple[int, int]) -> datetime:
+    day, millis = pair
+    return _UNIX_EPOCH + _julian_to_timedelta((int(day) - _UNIX_EPOCH_JULIAN_DAY, millis))
+
+
+def _timedelta_to_julian(delta: timedelta) -> tuple[int, int]:
+    if delta < timedelta(0):
+        raise ValueError(f"negative drawing time: {delta}")
+    return (delta.days, delta.seconds * 1000 + delta.microseconds // 1000)
+
+
+def _datetime_to_julian(value: datetime) -> tuple[int, int]:
+    delta = value.replace(tzinfo=None) - _UNIX_EPOCH
+    return (delta.days + _UNIX_EPOCH_JULIAN_DAY, delta.seconds * 1000 + delta.microseconds // 1000)
+
+
 TYPE_ALIASES = {
     "DIM_LINEAR": "DIMENSION",
     "DIM_RADIUS": "DIMENSION",
@@ -65,6 +132,51 @@ TYPE_ALIASES = {
     "DIM_ALIGNED": "DIMENSION",
     "DIM_ANG3PT": "DIMENSION",
     "DIM_ANG2LN": "DIMENSION",
+    "ARC_DIMENSION": "DIMENSION",
+    "LARGE_RADIAL_DIMENSION": "DIMENSION",
+}
+
+# Entity types the ``annotations_only`` and ``geometry_only`` presets stand
+# for in a ``types`` filter. INSERT, MINSERT, HATCH, VIEWPORT, OLE frames
+# and other types belong to neither.
+ANNOTATION_TYPES = (
+    "TEXT",
+    "MTEXT",
+    "ATTRIB",
+    "ATTDEF",
+    "RTEXT",
+    "ARCALIGNEDTEXT",
+    "DIMENSION",
+    "LEADER",
+    "MULTILEADER",
+    "TOLERANCE",
+)
+GEOMETRY_TYPES = (
+    "LINE",
+    "LWPOLYLINE",
+    "POLYLINE_2D",
+    "POLYLINE_3D",
+    "POLYLINE_MESH",
+    "POLYLINE_PFACE",
+    "MESH",
+    "ARC",
+    "CIRCLE",
+    "ELLIPSE",
+    "SPLINE",
+    "POINT",
+    "RAY",
+    "XLINE",
+    "MLINE",
+    "3DFACE",
+    "SOLID",
+    "TRACE",
+    "3DSOLID",
+    "BODY",
+    "REGION",
+)
+TYPE_PRESETS = {
+    "ANNOTATIONS_ONLY": ANNOTATION_TYPES,
+    "GEOMETRY_ONLY": GEOMETRY_TYPES,
 }
 
 _BULK_PRIMITIVE_TYPES = {"LINE", "ARC", "CIRCLE"}
@@ -106,6 +218,128 @@ class _SimpleEntitySpec:
     setup: Callable[[str], Any] | None = None
 
 
+@dataclass(frozen=True)
+class PlotSettings:
+    """Page setup of a layout. Paper sizes and margins are in millimeters."""
+
+    page_setup_name: str | None
+    printer_name: str | None
+    paper_size: str | None
+    plot_style_table: str | None
+    flags: int
+    paper_width: float
+    paper_height: float
+    margins: tuple[float, float, float, float]
+    plot_origin: tuple[float, float]
+    paper_units: int
+    rotation: int
+    plot_type: int
+    window: tuple[tuple[float, float], tuple[float, float]]
+    real_world_units: float
+    drawing_units: float
+    scale_type: int
+    scale_factor: float
+
+    @property
+    def page_size(self) -> tuple[float, float]:
+        """Sheet ``(width, height)`` in millimeters after applying ``rotation``."""
+        if self.rotation in (1, 3):
+            return (self.paper_height, self.paper_width)
+        return (self.paper_width, self.paper_height)
+
+    @property
+    def orientation(self) -> str:
+        width, height = self.page_size
+        return "landscape" if width > height else "portrait"
+
+
+@dataclass(frozen=True)
+class LayoutInfo:
+    handle: int
+    name: str | None
+    tab_order: int
+    flags: int
+    limits: tuple[tuple[float, float], tuple[float, float]]
+    extents: tuple[tuple[float, float, float], tuple[float, float, float]]
+    plot_settings: PlotSettings
+
+    @property
+    def is_modelspace(self) -> bool:
+        return self.tab_order == 0
+
+
+@dataclass(frozen=True)
+class ScaleInfo:
+    """A SCALE object of the annotation scale list."""
+
+    handle: int
+    name: str | None
+    paper_units: float
+    drawing_units: float
+    is_unit_scale: bool
+
+    @property
+    def factor(self) -> float | None:
+        """Drawing units per paper unit."""
+        if self.paper_units == 0.0:
+            return None
+        return self.drawing_units / self.paper_units
+
+
+@dataclass(frozen=True)
+class ViewInfo:
+    """A named view of the VIEW table. The camera sits at ``target +
+    direction``; ``center`` pans the view in display coordinates and
+    ``lens_length`` is the focal length in millimeters of a 35 mm camera."""
+
+    handle: int
+    name: str | None
+    width: float
+    height: float
+    center: tuple[float, float]
+    target: tuple[float, float, float]
+    direction: tuple[float, float, float]
+    twist_angle: float
+    lens_length: float
+    front_clip: float
+    back_clip: float
+    view_mode: int
+    render_mode: int
+    is_paper_space: bool
+    is_camera_plottable: bool
+
+    @property
+    def camera(self) -> tuple[float, float, float]:
+        tx, ty, tz = self.target
+        dx, dy, dz = self.direction
+        return (tx + dx, ty + dy, tz + dz)
+
+    @property
+    def is_perspective(self) -> bool:
+        return bool(self.view_mode & 1)
+
+    @property
+    def field_of_view(self) -> float | None:
+        """Horizontal field of view in radians across 36 mm wide film, or
+        ``None`` without a lens length."""
+        if self.lens_length <= 0.0:
+            return None
+        return 2.0 * math.atan(18.0 / self.lens_length)
+
+
+@dataclass(frozen=True)
+class ViewportLayerOverrides:
+    """Layer properties of one paperspace viewport that differ from the
+    layer table, keyed by layer handle. Colors are ``(color_index,
+    true_color)`` pairs and transparencies are raw DXF group 440 values."""
+
+    frozen: frozenset[int] = frozenset()
+    colors: dict[int, tuple[int | None, int | None]] = field(default_factory=dict)
+    linetypes: dict[int, int] = field(default_factory=dict)
+    lineweights: dict[int, int] = field(default_factory=dict)
+    transparencies: dict[int, int] = field(default_factory=dict)
+
+
 def read(path: str) -> "Document":
     version = raw.detect_version(path)
     if version not in SUPPORTED_VERSIONS:
@@ -129,6 +363,87 @@ class Document:
     def modelspace(self) -> "Layout":
         return Layout(self, "MODELSPACE")
 
+    def layouts(self) -> list[LayoutInfo]:
+        rows = sorted(raw.decode_layouts(self.decode_path), key=lambda row: row[2])
+        return [_layout_info(row) for row in rows]
+
+    def drawing_times(self) -> DrawingTimes:
+        return DrawingTimes.from_raw(raw.decode_drawing_times(self.decode_path))
+
+    def app_data(self) -> dict[str, dict[str, list[tuple[int, object]]]]:
+        """XRECORDs of the dictionaries in the named objects dictionary, as
+        ``{dictionary: {key: [(group_code, value), ...]}}``."""
+        dictionaries = raw.decode_dictionaries(self.decode_path)
+        xrecords = {handle: items for handle, _, items in raw.decode_xrecords(self.decode_path)}
+        entries_by_handle = {handle: entries for handle, _, entries in dictionaries}
+        result: dict[str, dict[str, list[tuple[int, object]]]] = {}
+        for _, owner, entries in dictionaries:
+            if owner != 0:
+                continue
+            for name, handle in entries:
+                records = {
+                    key: xrecords[record]
+                    for key, record in entries_by_handle.get(handle, [])
+                    if record in xrecords
+                }
+                if records:
+                    result[name] = records
+        return result
+
+    def scales(self) -> list[ScaleInfo]:
+        """Annotation scales in ``ACAD_SCALELIST`` order. Files whose
+        dictionaries cannot be decoded list them in handle order."""
+        scales = {
+            handle: ScaleInfo(handle, name, paper_units, drawing_units, is_unit_scale)
+            for handle, name, paper_units, drawing_units, is_unit_scale in (
+                raw.decode_scales(self.decode_path)
+            )
+        }
+        try:
+            dictionaries = raw.decode_dictionaries(self.decode_path)
+        except Exception:
+            dictionaries = []
+        entries_by_handle = {handle: entries for handle, _, entries in dictionaries}
+        scale_list = next(
+            (
+                handle
+                for _, owner, entries in dictionaries
+                if owner == 0
+                for name, handle in entries
+                if name == "ACAD_SCALELIST"
+            ),
+            None,
+        )
+        order = [
+            handle for _, handle in entries_by_handle.get(scale_list, []) if handle in scales
+        ]
+        order.extend(sorted(scales.keys() - set(order)))
+        return [scales[handle] for handle in order]
+
+    def views(self) -> list[ViewInfo]:
+        """Named views of the VIEW table in handle order."""
+        return [_view_info(row) for row in raw.decode_views(self.decode_path)]
+
+    def viewport_layer_overrides(self) -> dict[int, ViewportLayerOverrides]:
+        """Per-viewport layer settings by VIEWPORT handle: the layers frozen
+        in each viewport and the VP color, linetype, lineweight and
+        transparency overrides stored in the layer extension dictionaries."""
+        return dict(_viewport_layer_overrides(self.decode_path))
+
+    def xdata(self) -> dict[int, dict[str, list[tuple[int, object]]]]:
+        """Extended data by object handle, as ``{handle: {app_name:
+        [(group_code, value), ...]}}``."""
+        app_names = {
+            handle: name
+            for handle, name in raw.decode_table_entry_names(self.decode_path, "APPID")
+            if name is not None
+        }
+        result: dict[int, dict[str, list[tuple[int, object]]]] = {}
+        for handle, app_handle, items in raw.decode_xdata(self.decode_path):
+            app_name = app_names.get(app_handle, f"{app_handle:X}")
+            result.setdefault(handle, {}).setdefault(app_name, []).extend(items)
+        return result
+
     def plot(self, *args, **kwargs):
         from .render import plot
 
@@ -144,6 +459,11 @@ class Document:
 
         return to_dwg(self, output_path, **kwargs)
 
+    def export_png(self, output_path: str, **kwargs):
+        from .render import to_png
+
+        return to_png(self, output_path, **kwargs)
+
     @property
     def raw(self):
         return raw
@@ -159,15 +479,28 @@ class Layout:
         types: str | Iterable[str] | None = None,
         *,
         include_styles: bool = True,
+        precision: int | None = None,
     ) -> Iterator[Entity]:
-        return self.query(types, include_styles=include_styles)
+        return self.query(types, include_styles=include_styles, precision=precision)
 
     def query(
         self,
         types: str | Iterable[str] | None = None,
         *,
         include_styles: bool = True,
+        precision: int | None = None,
+        viewport: int | None = None,
     ) -> Iterator[Entity]:
+        """Decode the entities of ``types`` (all supported types by default).
+
+        ``precision`` rounds every float of the ``dxf`` mappings to that many
+        decimal places, so floating point noise does not show up when output
+        is diffed or hashed.
+
+        ``viewport`` is the handle of a paperspace VIEWPORT. Entities on
+        layers frozen in it are skipped and its layer overrides replace the
+        ByLayer color, linetype, lineweight and transparency.
+        """
         type_set = _normalize_types(types, self.doc.decode_path)
         bulk_rows = None
         if sum(1 for dxftype in type_set if dxftype in _BULK_PRIMITIVE_TYPES) >= 2:
@@ -197,8 +530,35 @@ class Layout:
             entity_style_map = {}
             layer_color_map = {}
             layer_color_overrides = None
+        if include_styles:
+            entity_transparency_map = _entity_transparency_map(self.doc.decode_path)
+            layer_transparency_map = _layer_transparency_map(self.doc.decode_path)
+        else:
+            entity_transparency_map = {}
+            layer_transparency_map = {}
+        if include_styles:
+            entity_line_style_map = _entity_line_style_map(self.doc.decode_path)
+            linetype_pattern_map = _linetype_pattern_map(self.doc.decode_path)
+        else:
+            entity_line_style_map = {}
+            linetype_pattern_map = {}
+        if include_styles:
+            entity_material_map = _entity_material_map(self.doc.decode_path)
+            layer_material_map = _layer_material_map(self.doc.decode_path)
+            material_name_map = _material_name_map(self.doc.decode_path)
+        else:
+            entity_material_map = {}
+            layer_material_map = {}
+            material_name_map = {}
+        viewport_overrides = None
+        if viewport is not None:
+            viewport_overrides = _viewport_layer_overrides(self.doc.decode_path).get(
+                int(viewport)
+            )
+            if viewport_overrides is None:
+                raise ValueError(f"viewport not found: {int(viewport):X}")
         for dxftype in type_set:
-            yield from self._iter_type(
+            for entity in self._iter_type(
                 dxftype,
                 bulk_rows=bulk_rows,
                 insert_minsert_rows=insert_minsert_rows,
@@ -207,7 +567,33 @@ class Layout:
                 layer_color_map=layer_color_map,
                 layer_color_overrides=layer_color_overrides,
                 dimension_rows=dimension_rows,
-            )
+            ):
+                _attach_entity_transparency(
+                    entity.handle,
+                    entity.dxf,
+                    entity_transparency_map,
+                    layer_transparency_map,
+                )
+                _attach_entity_line_style(
+                    entity.handle,
+                    entity.dxf,
+                    entity_line_style_map,
+                    linetype_pattern_map,
+                )
+                _attach_entity_material(
+                    entity.handle,
+                    entity.dxf,
+                    entity_material_map,
+                    layer_material_map,
+                    material_name_map,
+                )
+                if viewport_overrides is not None:
+                    if entity.dxf.get("layer_handle") in viewport_overrides.frozen:
+                        continue
+                    _apply_viewport_layer_overrides(
+                        entity.dxf, viewport_overrides, linetype_pattern_map
+                    )
+                yield entity if precision is None else entity.rounded(precision)
 
     def plot(self, *args, **kwargs):
         from .render import plot
@@ -224,6 +610,11 @@ class Layout:
 
         return to_dwg(self, output_path, **kwargs)
 
+    def export_png(self, output_path: str, **kwargs):
+        from .render import to_png
+
+        return to_png(self, output_path, **kwargs)
+
     def _yield_simple_entities(
         self,
         dxftype: str,
@@ -678,13 +1069,9 @@ class Layout:
 
         if dxftype == "POLYLINE_PFACE":
             polyline_sequence_map, _, _ = _polyline_sequence_relationships(decode_path)
-            for (
-                handle,
-                num_vertices,
-                num_faces,
-                vertices,
-                faces,
-            ) in raw.decode_polyline_pface_with_faces(decode_path):
+            for row in raw.decode_polyline_pface_with_faces(decode_path):
+                handle, num_vertices, num_faces, vertices, faces = row[:5]
+                face_colors = row[5] if len(row) >= 6 else [(None, None)] * len(faces)
                 sequence_info = polyline_sequence_map.get(int(handle), {})
                 yield Entity(
                     dxftype="POLYLINE_PFACE",
@@ -696,6 +1083,10 @@ class Layout:
                             "num_faces": int(num_faces),
                             "vertices": list(vertices),
                             "faces": list(faces),
+                            "face_invisible_edge_flags": [
+                                _pface_invisible_edge_flags(face) for face in faces
+                            ],
+                            "face_colors": list(face_colors),
                             "vertex_handles": list(sequence_info.get("vertex_handles", [])),
                             "face_handles": list(sequence_info.get("face_handles", [])),
                             "seqend_handle": sequence_info.get("seqend_handle"),
@@ -749,6 +1140,9 @@ class Layout:
                                 int(index3),
                                 int(index4),
                             ),
+                            "invisible_edge_flags": _pface_invisible_edge_flags(
+                                (index1, index2, index3, index4)
+                            ),
                             "owner_handle": owner_handle,
                             "owner_type": owner_type,
                         },
@@ -783,6 +1177,7 @@ class Layout:
                     dxf["acis_handles"] = [h for h in acis_handles if h != layer_handle]
                 candidate_handles = list(acis_candidate_map.get(handle, ()))
                 dxf.update(_build_acis_entity_payload(handle, candidate_handles, acis_record_map))
+                dxf.update(_acds_acis_payload(decode_path, handle))
                 yield Entity(
                     dxftype="3DSOLID",
                     handle=handle,
@@ -813,6 +1208,7 @@ class Layout:
                     dxf["acis_handles"] = [h for h in acis_handles if h != layer_handle]
                 candidate_handles = list(acis_candidate_map.get(handle, ()))
                 dxf.update(_build_acis_entity_payload(handle, candidate_handles, acis_record_map))
+                dxf.update(_acds_acis_payload(decode_path, handle))
                 yield Entity(
                     dxftype="BODY",
                     handle=handle,
@@ -923,6 +1319,7 @@ class Layout:
                     dxf["acis_handles"] = [h for h in acis_handles if h != layer_handle]
                 candidate_handles = list(acis_candidate_map.get(handle, ()))
                 dxf.update(_build_acis_entity_payload(handle, candidate_handles, acis_record_map))
+                dxf.update(_acds_acis_payload(decode_path, handle))
                 yield Entity(
                     dxftype="REGION",
                     handle=handle,
@@ -963,40 +1360,47 @@ class Layout:
             return
 
         if dxftype == "SPLINE":
-            for (
-                handle,
-                flags_data,
-                tolerance_data,
-                knots,
-                control_points,
-                weights,
-                fit_points,
-            ) in raw.decode_spline_entities(decode_path):
+            for row in raw.decode_spline_entities(decode_path):
+                (
+                    handle,
+                    flags_data,
+                    tolerance_data,
+                    knots,
+                    control_points,
+                    weights,
+                    fit_points,
+                ) = row[:7]
+                start_tangent, end_tangent = row[7] if len(row) > 7 else (None, None)
                 scenario, degree, rational, closed, periodic = flags_data
                 fit_tolerance, knot_tolerance, ctrl_tolerance = tolerance_data
                 points = list(fit_points if len(fit_points) >= 2 else control_points)
                 if closed and len(points) > 1 and points[0] != points[-1]:
                     points.append(points[0])
+                spline_dxf = {
+                    "scenario": scenario,
+                    "degree": degree,
+                    "rational": bool(rational),
+                    "closed": bool(closed),
+                    "periodic": bool(periodic),
+                    "fit_tolerance": fit_tolerance,
+                    "knot_tolerance": knot_tolerance,
+                    "ctrl_tolerance": ctrl_tolerance,
+                    "knots": list(knots),
+                    "control_points": list(control_points),
+                    "weights": list(weights),
+                    "fit_points": list(fit_points),
+                    "points": points,
+                    "start_tangent": start_tangent,
+                    "end_tangent": end_tangent,
+                }
+                if _is_nonzero_vector(start_tangent) and _is_nonzero_vector(end_tangent):
+                    spline_dxf["fit_tangents"] = [start_tangent, end_tangent]
                 yield Entity(
                     dxftype="SPLINE",
                     handle=handle,
                     dxf=_attach_entity_color(
                         handle,
-                        {
-                            "scenario": scenario,
-                            "degree": degree,
-                            "rational": bool(rational),
-                            "closed": bool(closed),
-                            "periodic": bool(periodic),
-                            "fit_tolerance": fit_tolerance,
-                            "knot_tolerance": knot_tolerance,
-                            "ctrl_tolerance": ctrl_tolerance,
-                            "knots": list(knots),
-                            "control_points": list(control_points),
-                            "weights": list(weights),
-                            "fit_points": list(fit_points),
-                            "points": points,
-                        },
+                        spline_dxf,
                         entity_style_map,
                         layer_color_map,
                         layer_color_overrides,
@@ -1436,6 +1840,7 @@ class Layout:
                 elevation,
                 extrusion,
                 path_rows,
+                pattern_row,
             ) in raw.decode_hatch_entities(decode_path):
                 paths = []
                 for closed, points in path_rows:
@@ -1460,6 +1865,7 @@ class Layout:
                             "elevation": elevation,
                             "extrusion": extrusion,
                             "paths": paths,
+                            "pattern": _hatch_pattern_dxf(pattern_row),
                         },
                         entity_style_map,
                         layer_color_map,
@@ -1726,7 +2132,18 @@ class Layout:
                     angles,
                     common_data,
                     handle_data,
-                ) = row
+                ) = row[:11]
+                extra = tuple(row[11]) if len(row) > 11 else ()
+                (
+                    point15,
+                    point16,
+                    is_x_type,
+                    point17,
+                    is_partial,
+                    arc_params,
+                    has_leader,
+                    leader_length,
+                ) = (extra + (None,) * 8)[:8]
                 extrusion, insert_scale = transforms
                 text_rotation, horizontal_direction, ext_line_rotation, dim_rotation = angles
                 (
@@ -1763,6 +2180,22 @@ class Layout:
                     "oblique_angle": math.degrees(ext_line_rotation),
                     "angle": math.degrees(dim_rotation),
                 }
+                if point15 is not None:
+                    dim_dxf["defpoint4"] = point15
+                if point16 is not None:
+                    dim_dxf["defpoint5"] = point16
+                if is_x_type is not None:
+                    dim_dxf["is_x_type"] = bool(is_x_type)
+                if point17 is not None:
+                    dim_dxf["leader_end"] = point17
+                if is_partial is not None:
+                    dim_dxf["is_partial"] = bool(is_partial)
+                if arc_params is not None:
+                    dim_dxf["arc_start_param"], dim_dxf["arc_end_param"] = arc_params
+                if has_leader is not None:
+                    dim_dxf["has_leader"] = bool(has_leader)
+                if leader_length is not None:
+                    dim_dxf["leader_length"] = leader_length
                 dim_dxf.update(common_dxf)
                 if anonymous_block_handle is not None:
                     try:
@@ -1790,16 +2223,106 @@ class Layout:
 
         raise ValueError(
             f"unsupported entity type: {dxftype}. "
-            "Supported types: LINE, LWPOLYLINE, POLYLINE_2D, VERTEX_2D, POLYLINE_3D, VERTEX_3D, POLYLINE_MESH, VERTEX_MESH, POLYLINE_PFACE, VERTEX_PFACE, VERTEX_PFACE_FACE, SEQEND, 3DFACE, SOLID, TRACE, SHAPE, 3DSOLID, BODY, VIEWPORT, OLEFRAME, OLE2FRAME, LONG_TRANSACTION, REGION, RAY, XLINE, ARC, CIRCLE, ELLIPSE, SPLINE, POINT, TEXT, ATTRIB, ATTDEF, MTEXT, LEADER, HATCH, TOLERANCE, MLINE, BLOCK, ENDBLK, INSERT, MINSERT, DIMENSION"
+            "Supported types: LINE, LWPOLYLINE, POLYLINE_2D, VERTEX_2D, POLYLINE_3D, VERTEX_3D, POLYLINE_MESH, VERTEX_MESH, POLYLINE_PFACE, VERTEX_PFACE, VERTEX_PFACE_FACE, MESH, SEQEND, 3DFACE, SOLID, TRACE, SHAPE, 3DSOLID, BODY, VIEWPORT, OLEFRAME, OLE2FRAME, LONG_TRANSACTION, REGION, RAY, XLINE, LIGHT, RTEXT, ARCALIGNEDTEXT, ARC, CIRCLE, ELLIPSE, SPLINE, POINT, TEXT, ATTRIB, ATTDEF, MTEXT, LEADER, MULTILEADER, HATCH, TOLERANCE, MLINE, BLOCK, ENDBLK, INSERT, MINSERT, DIMENSION"
         )
 
 
+def _view_info(row: tuple) -> ViewInfo:
+    (
+        handle,
+        name,
+        (width, height),
+        center,
+        target,
+        direction,
+        twist_angle,
+        lens_length,
+        (front_clip, back_clip),
+        view_mode,
+        render_mode,
+        (is_paper_space, is_camera_plottable),
+    ) = row
+    return ViewInfo(
+        handle=int(handle),
+        name=name,
+        width=float(width),
+        height=float(height),
+        center=(float(center[0]), float(center[1])),
+        target=tuple(float(value) for value in target),
+        direction=tuple(float(value) for value in direction),
+        twist_angle=float(twist_angle),
+        lens_length=float(lens_length),
+        front_clip=float(front_clip),
+        back_clip=float(back_clip),
+        view_mode=int(view_mode),
+        render_mode=int(render_mode),
+        is_paper_space=bool(is_paper_space),
+        is_camera_plottable=bool(is_camera_plottable),
+    )
+
+
+def _layout_info(row: tuple) -> LayoutInfo:
+    handle, name, tab_order, flags, limits, extents, settings = row
+    (
+        (page_setup_name, printer_name, paper_size, plot_style_table),
+        plot_flags,
+        (paper_width, paper_height),
+        margins,
+        plot_origin,
+        paper_units,
+        rotation,
+        plot_type,
+        window,
+        (real_world_units, drawing_units, scale_type, scale_factor),
+    ) = settings
+    return LayoutInfo(
+        handle=int(handle),
+        name=name,
+        tab_order=int(tab_order),
+        flags=int(flags),
+        limits=limits,
+        extents=extents,
+        plot_settings=PlotSettings(
+            page_setup_name=page_setup_name,
+            printer_name=printer_name,
+            paper_size=paper_size,
+            plot_style_table=plot_style_table,
+            flags=int(plot_flags),
+            paper_width=paper_width,
+            paper_height=paper_height,
+            margins=tuple(margins),
+            plot_origin=tuple(plot_origin),
+            paper_units=int(paper_units),
+            rotation=int(rotation),
+            plot_type=int(plot_type),
+            window=window,
+            real_world_units=real_world_units,
+            drawing_units=drawing_units,
+            scale_type=int(scale_type),
+            scale_factor=scale_factor,
+        ),
+    )
+
+
 def _seqend_owner_pair(handle: int, seqend_map: dict) -> tuple:
     pair = seqend_map.get(int(handle)) or (None, None)
     return pair[0], pair[1]
 
 
 _SIMPLE_ENTITY_REGISTRY: dict[str, _SimpleEntitySpec] = {
+    "MESH": _SimpleEntitySpec(
+        rows_fn=lambda p: raw.decode_mesh_entities(p),
+        build_dxf=lambda row, _: (
+            row[0],
+            {
+                "subdivision_level": int(row[1]),
+                "vertices": list(row[2]),
+                "faces": [list(face) for face in row[3]],
+                "edges": list(row[4]),
+                "edge_crease_values": list(row[5]),
+            },
+        ),
+    ),
     "3DFACE": _SimpleEntitySpec(
         rows_fn=lambda p: raw.decode_3dface_entities(p),
         build_dxf=lambda row, _: (
@@ -1844,6 +2367,71 @@ _SIMPLE_ENTITY_REGISTRY: dict[str, _SimpleEntitySpec] = {
         rows_fn=lambda p: raw.decode_xline_entities(p),
         build_dxf=lambda row, _: (row[0], {"start": row[1], "unit_vector": row[2]}),
     ),
+    "LIGHT": _SimpleEntitySpec(
+        rows_fn=lambda p: raw.decode_light_entities(p),
+        build_dxf=lambda row, _: (
+            row[0],
+            {
+                "name": row[1],
+                "light_type": int(row[2]),
+                "is_on": bool(row[3]),
+                "light_color_index": int(row[4][0]),
+                "light_true_color": row[4][1],
+                "intensity": row[5],
+                "position": row[6],
+                "target": row[7],
+                "attenuation_type": int(row[8]),
+                "hotspot_angle": row[9],
+                "falloff_angle": row[10],
+                "cast_shadows": bool(row[11]),
+            },
+        ),
+    ),
+    "RTEXT": _SimpleEntitySpec(
+        rows_fn=lambda p: raw.decode_rtext_entities(p),
+        build_dxf=lambda row, _: (
+            row[0],
+            {
+                "insert": row[1],
+                "extrusion": row[2],
+                "rotation": math.degrees(row[3]),
+                "height": row[4],
+                "flags": int(row[5]),
+                "is_expression": bool(int(row[5]) & 0x01),
+                "text": row[6] or "",
+                "style_handle": row[7],
+            },
+        ),
+    ),
+    "ARCALIGNEDTEXT": _SimpleEntitySpec(
+        rows_fn=lambda p: raw.decode_arc_aligned_text_entities(p),
+        build_dxf=lambda row, _: (
+            row[0],
+            {
+                "text": row[1] or "",
+                "font": row[2][0],
+                "bigfont": row[2][1],
+                "style_name": row[2][2],
+                "center": row[3],
+                "radius": row[4],
+                "start_angle": math.degrees(row[5][0]),
+                "end_angle": math.degrees(row[5][1]),
+                "height": row[6][0],
+                "width_factor": row[6][1],
+                "char_spacing": row[6][2],
+                "offset_from_arc": row[7][0],
+                "right_offset": row[7][1],
+                "left_offset": row[7][2],
+                "reversed": bool(row[8][0]),
+                "direction": int(row[8][1]),
+                "alignment": int(row[8][2]),
+                "side": int(row[8][3]),
+                "is_shx": bool(row[9]),
+                "extrusion": row[10],
+                "arc_handle": row[11],
+            },
+        ),
+    ),
     "ELLIPSE": _SimpleEntitySpec(
         rows_fn=lambda p: raw.decode_ellipse_entities(p),
         build_dxf=lambda row, _: (
@@ -1869,6 +2457,29 @@ _SIMPLE_ENTITY_REGISTRY: dict[str, _SimpleEntitySpec] = {
             },
         ),
     ),
+    "MULTILEADER": _SimpleEntitySpec(
+        rows_fn=lambda p: raw.decode_mleader_entities(p),
+        build_dxf=lambda row, _: (
+            row[0],
+            {
+                "leaders": [
+                    {
+                        "connection": connection,
+                        "direction": direction,
+                        "landing_distance": landing_distance,
+                        "lines": [list(points) for points in lines],
+                    }
+                    for connection, direction, landing_distance, lines in row[1]
+                ],
+                "text": row[2],
+                "text_location": row[3],
+                "text_direction": row[4],
+                "text_height": row[5],
+                "text_width": row[6],
+                "text_alignment": int(row[7]),
+            },
+        ),
+    ),
     "SHAPE": _SimpleEntitySpec(
         rows_fn=lambda p: raw.decode_shape_entities(p),
         build_dxf=lambda row, _: (
@@ -2030,6 +2641,32 @@ def _strip_duplicate_closure_point(
     return points
 
 
+def _hatch_pattern_dxf(row) -> dict | None:
+    if row is None:
+        return None
+    style, pattern_type, angle, scale, double, lines = row
+    return {
+        "style": int(style),
+        "pattern_type": int(pattern_type),
+        "angle": math.degrees(angle),
+        "scale": scale,
+        "double": bool(double),
+        "lines": [
+            {
+                "angle": math.degrees(line_angle),
+                "origin": origin,
+                "offset": offset,
+                "dashes": list(dashes),
+            }
+            for line_angle, origin, offset, dashes in lines
+        ],
+    }
+
+
+def _is_nonzero_vector(vector: tuple[float, float, float] | None) -> bool:
+    return vector is not None and any(abs(float(value)) > 1e-12 for value in vector)
+
+
 def _polyline_2d_flags_info(flags: int) -> dict[str, bool]:
     value = int(flags)
     return {
@@ -2234,7 +2871,10 @@ def _normalize_types(types: str | Iterable[str] | None, path: str | None = None)
     if any(token in {"*", "ALL"} for token in normalized):
         return list(_present_supported_types(path))
 
-    needs_glob_match = any(any(ch in token for ch in "*?[]") for token in normalized)
+    # Like patterns, presets only select the types the drawing has.
+    needs_glob_match = any(
+        token in TYPE_PRESETS or any(ch in token for ch in "*?[]") for token in normalized
+    )
     candidate_types = (
         list(_present_supported_types(path, include_explicit_only=True))
         if (needs_glob_match and path is not None)
@@ -2245,6 +2885,13 @@ def _normalize_types(types: str | Iterable[str] | None, path: str | None = None)
     seen = set()
 
     for token in normalized:
+        if token in TYPE_PRESETS:
+            for name in TYPE_PRESETS[token]:
+                if name in candidate_types and name not in seen:
+                    seen.add(name)
+                    selected.append(name)
+            continue
+
         if any(ch in token for ch in "*?[]"):
             matches = [
                 name for name in candidate_types if fnmatch.fnmatchcase(name, token)
@@ -2773,6 +3420,29 @@ def _build_acis_entity_payload(
     }
 
 
+@lru_cache(maxsize=16)
+def _acds_acis_map(path: str) -> dict[int, tuple[str | None, bytes]]:
+    try:
+        rows = raw.decode_acds_records(path)
+    except Exception:
+        return {}
+    out: dict[int, tuple[str | None, bytes]] = {}
+    for handle, _schema_index, acis_format, data in rows:
+        if acis_format is None:
+            continue
+        out[int(handle)] = (acis_format, bytes(data))
+    return out
+
+
+def _acds_acis_payload(path: str, handle: int) -> dict[str, object]:
+    # R2013+ stores the modeler data in the AcDs section keyed by entity handle.
+    entry = _acds_acis_map(path).get(handle)
+    if entry is None:
+        return {}
+    acis_format, data = entry
+    return {"acis_format": acis_format, "acis_data": data}
+
+
 @lru_cache(maxsize=16)
 def _acis_candidate_handles_map(path: str) -> dict[int, tuple[int, ...]]:
     try:
@@ -3382,6 +4052,251 @@ def _layer_color_map(path: str) -> dict[int, tuple[int, int | None]]:
         return {}
 
 
+@lru_cache(maxsize=16)
+def _entity_transparency_map(path: str) -> dict[int, int]:
+    try:
+        return {
+            handle: transparency
+            for handle, transparency in raw.decode_entity_transparency(path)
+            if transparency is not None
+        }
+    except Exception:
+        return {}
+
+
+@lru_cache(maxsize=16)
+def _layer_transparency_map(path: str) -> dict[int, int]:
+    try:
+        return {
+            handle: transparency
+            for handle, transparency in raw.decode_layer_transparency(path)
+            if transparency is not None
+        }
+    except Exception:
+        return {}
+
+
+def _attach_entity_transparency(
+    handle: int,
+    dxf: dict,
+    entity_transparency_map: dict[int, int],
+    layer_transparency_map: dict[int, int],
+) -> None:
+    transparency = entity_transparency_map.get(handle)
+    resolved = transparency
+    if resolved is None or (resolved >> 24) == 0:
+        resolved = layer_transparency_map.get(dxf.get("layer_handle"))
+    elif (resolved >> 24) != 0x02:
+        # ByBlock depends on the referencing INSERT; treat as opaque here.
+        resolved = None
+    if transparency is None and resolved is None:
+        return
+    dxf["transparency"] = transparency
+    dxf["resolved_transparency"] = resolved
+
+
+# Layer extension dictionary entries holding the VP overrides of a layer,
+# and the group code of the overridden value in each. Every value follows a
+# 335 group with the VIEWPORT handle it applies to.
+_VIEWPORT_OVERRIDE_RECORDS = {
+    "ADSK_XREC_LAYER_COLOR_OVR": ("colors", 420),
+    "ADSK_XREC_LAYER_LINETYPE_OVR": ("linetypes", 343),
+    "ADSK_XREC_LAYER_LINEWT_OVR": ("lineweights", 91),
+    "ADSK_XREC_LAYER_ALPHA_OVR": ("transparencies", 440),
+}
+
+
+@lru_cache(maxsize=16)
+def _viewport_layer_overrides(path: str) -> dict[int, ViewportLayerOverrides]:
+    try:
+        frozen = {
+            int(handle): frozenset(int(layer) for layer in layers)
+            for handle, layers in raw.decode_viewport_frozen_layers(path)
+        }
+    except Exception:
+        frozen = {}
+    overrides: dict[int, dict[str, dict[int, Any]]] = {}
+    try:
+        dictionaries = raw.decode_dictionaries(path)
+        xrecords = {handle: items for handle, _, items in raw.decode_xrecords(path)}
+    except Exception:
+        dictionaries, xrecords = [], {}
+    layer_names = _layer_names_by_handle(path)
+    for _, owner, entries in dictionaries:
+        if owner not in layer_names:
+            continue
+        for name, record in entries:
+            kind = _VIEWPORT_OVERRIDE_RECORDS.get(name.upper())
+            if kind is None:
+                continue
+            attribute, value_code = kind
+            viewport = None
+            for code, value in xrecords.get(record, []):
+                if code == 335:
+                    viewport = int(value)
+                elif code == value_code and viewport is not None:
+                    values = overrides.setdefault(viewport, {}).setdefault(attribute, {})
+                    values[owner] = _viewport_override_value(attribute, int(value))
+    return {
+        handle: ViewportLayerOverrides(
+            frozen=frozen.get(handle, frozenset()), **overrides.get(handle, {})
+        )
+        for handle in frozen.keys() | overrides.keys()
+    }
+
+
+def _viewport_override_value(attribute: str, value: int) -> Any:
+    if attribute != "colors":
+        return value
+    # DXF group 420 raw colors: 0xC2 carries RGB, 0xC3 an ACI index.
+    value &= 0xFFFFFFFF
+    if value >> 24 == 0xC2:
+        return (None, value & 0xFFFFFF)
+    if value >> 24 == 0xC3:
+        return (value & 0xFF, None)
+    return (256, None)
+
+
+def _apply_viewport_layer_overrides(
+    dxf: dict,
+    overrides: ViewportLayerOverrides,
+    linetype_pattern_map: dict[int, tuple[float, ...]],
+) -> None:
+    layer_handle = dxf.get("layer_handle")
+    color = overrides.colors.get(layer_handle)
+    if (
+        color is not None
+        and color != (256, None)
+        and dxf.get("color_index") in (None, 256)
+        and dxf.get("true_color") is None
+    ):
+        dxf["resolved_color_index"], dxf["resolved_true_color"] = color
+    ltype_handle = overrides.linetypes.get(layer_handle)
+    if ltype_handle is not None and "ltype_handle" not in dxf:
+        dxf["ltype_handle"] = ltype_handle
+        pattern = linetype_pattern_map.get(ltype_handle)
+        if pattern:
+            dxf["linetype_pattern"] = pattern
+    lineweight = overrides.lineweights.get(layer_handle)
+    if lineweight is not None and dxf.get("lineweight") == -1:
+        dxf["lineweight"] = lineweight
+    transparency = overrides.transparencies.get(layer_handle)
+    entity_transparency = dxf.get("transparency")
+    if transparency is not None and (
+        entity_transparency is None or (entity_transparency >> 24) == 0
+    ):
+        dxf["resolved_transparency"] = transparency
+
+
+@lru_cache(maxsize=16)
+def _entity_line_style_map(path: str) -> dict[int, tuple[int, int | None, float, int]]:
+    try:
+        return {
+            handle: (ltype_flags, ltype_handle, ltype_scale, lineweight)
+            for handle, ltype_flags, ltype_handle, ltype_scale, lineweight in (
+                raw.decode_entity_line_styles(path)
+            )
+        }
+    except Exception:
+        return {}
+
+
+@lru_cache(maxsize=16)
+def _linetype_pattern_map(path: str) -> dict[int, tuple[float, ...]]:
+    try:
+        return {
+            handle: tuple(float(length) for length, _shape_code, _shape_flag in dashes)
+            for handle, _name, _description, _pattern_length, dashes in raw.decode_linetypes(
+                path
+            )
+            if dashes
+        }
+    except Exception:
+        return {}
+
+
+def _attach_entity_line_style(
+    handle: int,
+    dxf: dict,
+    entity_line_style_map: dict[int, tuple[int, int | None, float, int]],
+    linetype_pattern_map: dict[int, tuple[float, ...]],
+) -> None:
+    line_style = entity_line_style_map.get(handle)
+    if line_style is None:
+        return
+    ltype_flags, ltype_handle, ltype_scale, lineweight = line_style
+    dxf["lineweight"] = lineweight
+    # Misaligned R2010+ headers can yield denormal or huge scales; skip them.
+    if math.isfinite(ltype_scale) and 1.0e-6 <= ltype_scale <= 1.0e6:
+        dxf["ltype_scale"] = ltype_scale
+    if ltype_flags == 3 and ltype_handle is not None:
+        dxf["ltype_handle"] = ltype_handle
+        pattern = linetype_pattern_map.get(ltype_handle)
+        if pattern:
+            dxf["linetype_pattern"] = pattern
+
+
+@lru_cache(maxsize=16)
+def _material_name_map(path: str) -> dict[int, str]:
+    try:
+        return {
+            handle: name
+            for handle, name, _description, _ambient, _diffuse, _diffuse_map in (
+                raw.decode_materials(path)
+            )
+            if name
+        }
+    except Exception:
+        return {}
+
+
+@lru_cache(maxsize=16)
+def _entity_material_map(path: str) -> dict[int, tuple[int, int | None]]:
+    try:
+        return {
+            handle: (material_flags, material_handle)
+            for handle, material_flags, material_handle in raw.decode_entity_materials(path)
+        }
+    except Exception:
+        return {}
+
+
+@lru_cache(maxsize=16)
+def _layer_material_map(path: str) -> dict[int, int]:
+    try:
+        return {
+            handle: material_handle
+            for handle, material_handle in raw.decode_layer_materials(path)
+            if material_handle is not None
+        }
+    except Exception:
+        return {}
+
+
+def _attach_entity_material(
+    handle: int,
+    dxf: dict,
+    entity_material_map: dict[int, tuple[int, int | None]],
+    layer_material_map: dict[int, int],
+    material_name_map: dict[int, str],
+) -> None:
+    material = entity_material_map.get(handle)
+    if material is None:
+        return
+    material_flags, material_handle = material
+    if material_flags == 0:
+        material_handle = layer_material_map.get(dxf.get("layer_handle"))
+    elif material_flags != 3:
+        # ByBlock depends on the referencing INSERT; leave it unresolved.
+        material_handle = None
+    if material_handle is None:
+        return
+    dxf["material_handle"] = material_handle
+    name = material_name_map.get(material_handle)
+    if name is not None:
+        dxf["material"] = name
+
+
 def _layer_color_overrides(
     version: str,
     entity_style_map: dict[int, tuple[int | None, int | None, int]],
@@ -3432,6 +4347,16 @@ def _layer_color_overrides(
     }
 
 
+def _pface_invisible_edge_flags(indices) -> int:
+    """3DFACE-style edge flags from polyface face indices; a negative index
+    hides the edge starting at that vertex."""
+    flags = 0
+    for edge, index in enumerate(indices):
+        if int(index) < 0:
+            flags |= 1 << edge
+    return flags
+
+
 def _attach_entity_color(
     handle: int,
     dxf: dict,
@@ -0,0 +1,206 @@
+from __future__ import annotations
+
+import json
+import ntpath
+import posixpath
+from dataclasses import asdict, dataclass, field
+from pathlib import Path
+
+from . import raw
+
+_INDEX_FORMAT = 1
+
+
+@dataclass(frozen=True)
+class XrefLink:
+    """One xref block of ``source``.
+
+    ``stored_path`` is the path saved in the drawing; ``target`` is the
+    indexed file it resolves to, or ``None`` when it points outside the index.
+    """
+
+    source: str
+    block: str
+    stored_path: str
+    target: str | None
+    overlay: bool
+
+
+@dataclass(frozen=True)
+class TextHit:
+    path: str
+    text: str
+
+
+@dataclass(frozen=True)
+class IndexedDrawing:
+    path: str
+    version: str
+    blocks: list[str] = field(default_factory=list)
+    xrefs: list[XrefLink] = field(default_factory=list)
+    texts: list[str] = field(default_factory=list)
+    error: str | None = None
+
+
+@dataclass(frozen=True)
+class DrawingIndex:
+    """Block names, xrefs and text of every drawing under ``root``.
+
+    File paths are relative to ``root`` and use ``/`` separators, so a saved
+    index stays valid when the directory is moved.
+    """
+
+    root: str
+    files: list[IndexedDrawing]
+
+    def files_with_block(self, name: str) -> list[str]:
+        """Files defining a block named ``name``, ignoring case as AutoCAD does."""
+        key = name.casefold()
+        return [
+            drawing.path
+            for drawing in self.files
+            if any(block.casefold() == key for block in drawing.blocks)
+        ]
+
+    def block_index(self) -> dict[str, list[str]]:
+        """Block name to the files defining it, with names in their first spelling."""
+        out: dict[str, list[str]] = {}
+        spelling: dict[str, str] = {}
+        for drawing in self.files:
+            for block in drawing.blocks:
+                name = spelling.setdefault(block.casefold(), block)
+                out.setdefault(name, []).append(drawing.path)
+        return dict(sorted(out.items()))
+
+    def xref_graph(self) -> dict[str, list[str]]:
+        """Each file to the indexed files it attaches as xrefs."""
+        graph: dict[str, list[str]] = {}
+        for drawing in self.files:
+            targets = sorted({link.target for link in drawing.xrefs if link.target is not None})
+            graph[drawing.path] = targets
+        return graph
+
+    def referenced_by(self, path: str) -> list[str]:
+        """Files that attach ``path`` as an xref."""
+        return sorted(
+            {
+                drawing.path
+                for drawing in self.files
+                for link in drawing.xrefs
+                if link.target == path
+            }
+        )
+
+    def unresolved_xrefs(self) -> list[XrefLink]:
+        return [link for drawing in self.files for link in drawing.xrefs if link.target is None]
+
+    def search_text(self, query: str, *, case_sensitive: bool = False) -> list[TextHit]:
+        """TEXT, MTEXT and ATTRIB strings containing ``query``.
+
+        MTEXT strings keep their inline formatting codes.
+        """
+        needle = query if case_sensitive else query.casefold()
+        hits = []
+        for drawing in self.files:
+            for text in drawing.texts:
+                haystack = text if case_sensitive else text.casefold()
+                if needle in haystack:
+                    hits.append(TextHit(drawing.path, text))
+        return hits
+
+    @property
+    def errors(self) -> dict[str, str]:
+        return {drawing.path: drawing.error for drawing in self.files if drawing.error}
+
+    def save(self, path: str) -> None:
+        payload = {"format": _INDEX_FORMAT, **asdict(self)}
+        Path(path).write_text(json.dumps(payload, ensure_ascii=False, indent=1), encoding="utf-8")
+
+    @classmethod
+    def load(cls, path: str) -> DrawingIndex:
+        payload = json.loads(Path(path).read_text(encoding="utf-8"))
+        if payload.get("format") != _INDEX_FORMAT:
+            raise ValueError(f"unsupported drawing index format: {payload.get('format')!r}")
+        files = [
+            IndexedDrawing(
+                path=item["path"],
+                version=item["version"],
+                blocks=list(item["blocks"]),
+                xrefs=[XrefLink(**link) for link in item["xrefs"]],
+                texts=list(item["texts"]),
+                error=item["error"],
+            )
+            for item in payload["files"]
+        ]
+        return cls(root=payload["root"], files=files)
+
+
+def build_index(
+    directory: str,
+    *,
+    pattern: str = "*.dwg",
+    recursive: bool = True,
+    threads: int | None = None,
+) -> DrawingIndex:
+    """Index every DWG in ``directory`` for project-wide lookups.
+
+    Files are decoded in parallel on ``threads`` worker threads (default: one
+    per core). Files that fail to read are kept with their error message.
+    Xref paths are resolved against the referencing file's directory first,
+    then by file name when exactly one indexed file has that name.
+    """
+    root = Path(directory)
+    if not root.is_dir():
+        raise ValueError(f"not a directory: {root}")
+    matches = root.rglob(pattern) if recursive else root.glob(pattern)
+    paths = sorted(path for path in matches if path.is_file())
+    relative = [path.relative_to(root).as_posix() for path in paths]
+    rows = raw.build_drawing_index([str(path) for path in paths], threads)
+
+    known = {name.casefold(): name for name in relative}
+    by_basename: dict[str, list[str]] = {}
+    for name in relative:
+        by_basename.setdefault(posixpath.basename(name).casefold(), []).append(name)
+
+    files = []
+    for source, (_path, version, blocks, xrefs, texts, error) in zip(relative, rows):
+        links = [
+            XrefLink(
+                source=source,
+                block=str(block),
+                stored_path=str(stored_path),
+                target=_resolve_xref(source, str(stored_path), known, by_basename),
+                overlay=bool(overlay),
+            )
+            for _handle, block, stored_path, overlay in xrefs
+        ]
+        files.append(
+            IndexedDrawing(
+                path=source,
+                version=str(version),
+                blocks=list(blocks),
+                xrefs=links,
+                texts=list(texts),
+                error=error,
+            )
+        )
+    return DrawingIndex(root=str(root.resolve()), files=files)
+
+
+def _resolve_xref(
+    source: str,
+    stored_path: str,
+    known: dict[str, str],
+    by_basename: dict[str, list[str]],
+) -> str | None:
+    # Xref paths are usually saved on Windows, so treat both separators alike.
+    normalized = stored_path.replace("\\", "/").strip()
+    if not normalized:
+        return None
+    if not ntpath.isabs(stored_path) and not normalized.startswith("/"):
+        candidate = posixpath.normpath(posixpath.join(posixpath.dirname(source), normalized))
+        match = known.get(candidate.casefold())
+        if match is not None:
+            return match
+    same_name = by_basename.get(posixpath.basename(normalized).casefold(), [])
+    return same_name[0] if len(same_name) == 1 else None
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import math
+import sys
 from dataclasses import dataclass
 from typing import Any
 
@@ -12,6 +14,16 @@ class Entity:
     handle: int
     dxf: dict[str, Any]
 
+    def rounded(self, ndigits: int) -> "Entity":
+        """Return a copy with every float in ``dxf`` rounded to ``ndigits`` decimal places."""
+        return Entity(self.dxftype, self.handle, round_floats(self.dxf, ndigits))
+
+    def simplify(self, tolerance: float) -> "Entity":
+        """Return a copy with polyline vertices within ``tolerance`` of the outline dropped."""
+        from .simplify import simplify_entity
+
+        return simplify_entity(self, tolerance)
+
     def to_points(self) -> list[Point3D]:
         if self.dxftype == "LINE":
             return [self.dxf["start"], self.dxf["end"]]
@@ -42,3 +54,24 @@ class Entity:
                 return points
             return [self.dxf["text_midpoint"]]
         raise NotImplementedError(f"to_points is not supported for {self.dxftype}")
+
+
+def round_floats(value: Any, ndigits: int | None) -> Any:
+    """Round the floats nested in tuples, lists and dicts of ``value``.
+
+    Subnormal values and negative zero become ``0.0`` even when ``ndigits``
+    is ``None``; non-finite values are kept as they are.
+    """
+    if isinstance(value, float):
+        if not math.isfinite(value):
+            return value
+        if ndigits is not None:
+            value = round(value, ndigits)
+        return 0.0 if abs(value) < sys.float_info.min else value
+    if isinstance(value, tuple):
+        return tuple(round_floats(item, ndigits) for item in value)
+    if isinstance(value, list):
+        return [round_floats(item, ndigits) for item in value]
+    if isinstance(value, dict):
+        return {key: round_floats(item, ndigits) for key, item in value.items()}
+    return value
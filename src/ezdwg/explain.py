@@ -0,0 +1,106 @@
+from __future__ import annotations
+
+import sys
+from dataclasses import dataclass
+from typing import TextIO
+
+from . import raw
+
+
+_HEXDUMP_WIDTH = 16
+
+
+@dataclass(frozen=True)
+class TracedField:
+    """One value read by the decoder, located by bit range in the object data."""
+
+    stage: str
+    start_bit: int
+    end_bit: int
+    code: str
+    value: str
+
+    def byte_range(self) -> tuple[int, int]:
+        return self.start_bit // 8, max(self.start_bit, self.end_bit - 1) // 8 + 1
+
+
+@dataclass(frozen=True)
+class ObjectExplanation:
+    handle: int
+    offset: int
+    type_code: int
+    type_name: str
+    data: bytes
+    fields: list[TracedField]
+    decoded: str | None
+    error: str | None
+
+    def format(self) -> str:
+        lines = [
+            f"handle: 0x{self.handle:X}",
+            f"type: {self.type_name} ({self.type_code})",
+            f"offset: {self.offset}",
+            f"data_size: {len(self.data)}",
+            "",
+            *_hexdump(self.data),
+            "",
+        ]
+        stage_width = max((len(field.stage) for field in self.fields), default=0)
+        for field in self.fields:
+            start, end = field.byte_range()
+            covered = self.data[start:end].hex(" ")
+            lines.append(
+                f"{field.stage:<{stage_width}}  "
+                f"bits {field.start_bit:>5}..{field.end_bit:<5} "
+                f"{field.code:<4} {field.value:<24} [{covered}]"
+            )
+        if self.fields:
+            lines.append("")
+        if self.decoded is not None:
+            lines.append(f"decoded: {self.decoded}")
+        if self.error is not None:
+            lines.append(f"error: {self.error}")
+        return "\n".join(lines)
+
+
+def explain_object(path: str, handle: int, *, file: TextIO | None = None) -> ObjectExplanation:
+    """Print the data bytes of one object with a field-by-field annotation.
+
+    The object is decoded with bit tracing enabled, so every value the
+    decoder reads is listed with its bit range (relative to the object data),
+    DWG bit code and decoded value. Objects without an entity decoder only
+    show the type prefix. This is a debugging aid; the annotation is best
+    effort and follows whatever the decoder read, including abandoned
+    attempts of decoders that retry alternative layouts.
+    """
+    (
+        handle,
+        offset,
+        type_code,
+        type_name,
+        data,
+        fields,
+        decoded,
+        error,
+    ) = raw.explain_object(str(path), int(handle))
+    explanation = ObjectExplanation(
+        handle=int(handle),
+        offset=int(offset),
+        type_code=int(type_code),
+        type_name=str(type_name),
+        data=bytes(data),
+        fields=[TracedField(*field) for field in fields],
+        decoded=decoded,
+        error=error,
+    )
+    print(explanation.format(), file=sys.stdout if file is None else file)
+    return explanation
+
+
+def _hexdump(data: bytes) -> list[str]:
+    lines = []
+    for start in range(0, len(data), _HEXDUMP_WIDTH):
+        chunk = data[start : start + _HEXDUMP_WIDTH]
+        text = "".join(chr(byte) if 0x20 <= byte < 0x7F else "." for byte in chunk)
+        lines.append(f"{start:04x}  {chunk.hex(' '):<{_HEXDUMP_WIDTH * 3 - 1}}  {text}")
+    return lines
@@ -0,0 +1,175 @@
+from __future__ import annotations
+
+import json
+import ntpath
+import re
+import warnings
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any, Iterable, Mapping
+
+from . import raw
+
+DEFAULT_FALLBACK_FONT = "DejaVu Sans"
+_FONT_FILE_SUFFIXES = (".ttf", ".otf", ".ttc")
+
+
+class FontSubstitutionWarning(UserWarning):
+    """A drawing font had no rule in the ``FontMap`` and was drawn with the
+    fallback font."""
+
+
+@dataclass(frozen=True)
+class FontRule:
+    """Maps a drawing font file onto a font available to the exporters.
+
+    ``pattern`` is a font file name such as ``romans.shx`` or ``arial.ttf``,
+    compared without its directory and ignoring case; a name without an
+    extension is an SHX font. With ``regex`` it is a regular expression that
+    must match the whole normalized name. ``font`` is an installed family
+    name or the path of a TTF/OTF file. ``width_scale`` is the advance width
+    of ``font`` relative to the source font, used to correct text extents.
+    """
+
+    pattern: str
+    font: str
+    width_scale: float = 1.0
+    regex: bool = False
+
+
+@dataclass(frozen=True)
+class ResolvedFont:
+    source: str
+    font: str
+    width_scale: float
+    fallback: bool
+
+    @property
+    def is_file(self) -> bool:
+        return self.font.lower().endswith(_FONT_FILE_SUFFIXES)
+
+    def matplotlib_kwargs(self) -> dict[str, Any]:
+        """Keyword arguments selecting this font in ``Axes.text``."""
+        if self.is_file:
+            from matplotlib.font_manager import FontProperties
+
+            return {"fontproperties": FontProperties(fname=self.font)}
+        return {"fontfamily": self.font}
+
+
+class FontMap:
+    """Ordered font rules used by ``plot`` and text extent estimation.
+
+    The first rule matching a font file wins. Fonts without a rule use
+    ``fallback`` and raise a ``FontSubstitutionWarning`` once per font, or
+    ``ValueError`` when ``strict``, so labels are never silently drawn with
+    a font of another width.
+    """
+
+    def __init__(
+        self,
+        rules: Iterable[FontRule] = (),
+        *,
+        fallback: str = DEFAULT_FALLBACK_FONT,
+        fallback_width_scale: float = 1.0,
+        strict: bool = False,
+    ) -> None:
+        self.rules = tuple(rules)
+        self.fallback = fallback
+        self.fallback_width_scale = float(fallback_width_scale)
+        self.strict = strict
+        self._compiled: list[tuple[FontRule, re.Pattern[str] | None]] = []
+        for rule in self.rules:
+            if rule.width_scale <= 0.0:
+                raise ValueError(f"font width scale must be positive: {rule.width_scale}")
+            pattern = re.compile(rule.pattern, re.IGNORECASE) if rule.regex else None
+            self._compiled.append((rule, pattern))
+        self._warned: set[str] = set()
+
+    def resolve(self, font_file: str | None) -> ResolvedFont:
+        """Font drawn for the drawing font ``font_file``."""
+        source = normalize_font_name(font_file or "")
+        for rule, pattern in self._compiled:
+            if pattern is None:
+                matched = normalize_font_name(rule.pattern) == source
+            else:
+                matched = pattern.fullmatch(source) is not None
+            if matched:
+                return ResolvedFont(source, rule.font, float(rule.width_scale), False)
+        if self.strict:
+            raise ValueError(f"no font mapping for {source or '(empty font name)'}")
+        if source not in self._warned:
+            self._warned.add(source)
+            warnings.warn(
+                f"font {source or '(empty font name)'} drawn with {self.fallback}",
+                FontSubstitutionWarning,
+                stacklevel=2,
+            )
+        return ResolvedFont(source, self.fallback, self.fallback_width_scale, True)
+
+    @classmethod
+    def from_table(
+        cls,
+        table: Mapping[str, str | Mapping[str, Any]],
+        **kwargs: Any,
+    ) -> FontMap:
+        """Rules from ``{font_file: font}`` or
+        ``{font_file: {"font": ..., "width_scale": ..., "regex": ...}}``."""
+        rules = []
+        for pattern, value in table.items():
+            if isinstance(value, str):
+                rules.append(FontRule(pattern, value))
+            else:
+                rules.append(
+                    FontRule(
+                        pattern,
+                        str(value["font"]),
+                        width_scale=float(value.get("width_scale", 1.0)),
+                        regex=bool(value.get("regex", False)),
+                    )
+                )
+        return cls(rules, **kwargs)
+
+    @classmethod
+    def load(cls, path: str) -> FontMap:
+        """Read ``{"fonts": {...}, "fallback": ..., "fallback_width_scale":
+        ..., "strict": ...}`` from a JSON file; ``fonts`` takes the forms of
+        ``from_table``."""
+        payload = json.loads(Path(path).read_text(encoding="utf-8"))
+        return cls.from_table(
+            payload.get("fonts", {}),
+            fallback=str(payload.get("fallback", DEFAULT_FALLBACK_FONT)),
+            fallback_width_scale=float(payload.get("fallback_width_scale", 1.0)),
+            strict=bool(payload.get("strict", False)),
+        )
+
+
+def normalize_font_name(name: str) -> str:
+    """Lower-cased file name of a font without its directory; names without
+    an extension get ``.shx``, as AutoCAD assumes."""
+    base = ntpath.basename(name.replace("/", "\\")).strip().lower()
+    if base and "." not in base:
+        base += ".shx"
+    return base
+
+
+def text_style_fonts(path: str) -> dict[int, str]:
+    """Primary font file of each STYLE entry of ``path``, keyed by handle.
+
+    Shape file entries used by complex linetypes are left out.
+    """
+    return {
+        handle: font or ""
+        for handle, _name, font, _bigfont, _width, _oblique, _height, is_shape_file in (
+            raw.decode_text_styles(path)
+        )
+        if not is_shape_file
+    }
+
+
+def standard_style_handle(path: str) -> int | None:
+    """Handle of the ``Standard`` text style, used by MTEXT entities."""
+    for handle, name, *_rest in raw.decode_text_styles(path):
+        if (name or "").upper() == "STANDARD":
+            return handle
+    return None
@@ -0,0 +1,340 @@
+from __future__ import annotations
+
+import csv
+import json
+from pathlib import Path
+from typing import Any, Iterable, Iterator, Mapping
+
+from . import raw
+from .convert import (
+    WriteResult,
+    _collect_dwg_write_rows,
+    _dwg_write_row_args,
+    _dwg_write_row_kwargs,
+    _validate_layer_name,
+)
+from .entity import Entity
+from .layer_map import LayerMap
+
+# Meters per unit for the names accepted as ``source_units`` and ``target_units``.
+UNIT_SCALES = {
+    "mm": 0.001,
+    "cm": 0.01,
+    "m": 1.0,
+    "km": 1000.0,
+    "in": 0.0254,
+    "ft": 0.3048,
+    "yd": 0.9144,
+    "mi": 1609.344,
+}
+
+# Handle of the first imported entity; the writer keeps handles above its
+# fixed table handles.
+_FIRST_HANDLE = 0x100
+
+
+def csv_to_dwg(
+    source: str | Iterable[str],
+    output_path: str,
+    *,
+    layer_column: str = "layer",
+    default_layer: str = "0",
+    layer_map: Mapping[str, str] | LayerMap | None = None,
+    layer_colors: dict[str, int] | None = None,
+    scale: float = 1.0,
+    source_units: str | None = None,
+    target_units: str | None = None,
+    precision: int | None = None,
+) -> WriteResult:
+    """Write an AC1015 DWG from a CSV of line segments and points.
+
+    ``source`` is a path or the lines of the CSV. The header names the
+    columns, ignoring case: a row with ``x1``, ``y1``, ``x2`` and ``y2``
+    becomes a LINE and one with ``x`` and ``y`` a POINT; ``z1``, ``z2``
+    and ``z`` are optional. Rows with neither are skipped and a row with
+    a value that is not a number raises ``ValueError``. The layer comes
+    from ``layer_column``, or ``default_layer`` when it is missing or empty.
+    """
+    factor = _unit_factor(scale, source_units, target_units)
+    if isinstance(source, str):
+        with open(source, newline="", encoding="utf-8-sig") as handle:
+            features, skipped = _csv_features(handle, layer_column)
+    else:
+        features, skipped = _csv_features(source, layer_column)
+    return _write_features(
+        features,
+        output_path,
+        source_path=source if isinstance(source, str) else "",
+        factor=factor,
+        default_layer=default_layer,
+        layer_map=layer_map,
+        layer_colors=layer_colors,
+        precision=precision,
+        skipped={"CSV row": skipped} if skipped else {},
+    )
+
+
+def _csv_features(
+    lines: Iterable[str], layer_column: str
+) -> tuple[list[tuple[str, str | None, Any]], int]:
+    """Entities of the CSV rows and the number of rows without geometry."""
+    features: list[tuple[str, str | None, Any]] = []
+    skipped = 0
+    reader = csv.DictReader(lines)
+    for line_no, row in enumerate(reader, start=2):
+        fields = {
+            str(key).strip().lower(): (value or "").strip()
+            for key, value in row.items()
+            if key is not None
+        }
+        layer = fields.get(layer_column.lower()) or None
+        try:
+            if fields.get("x1") and fields.get("x2"):
+                start = _csv_point(fields, "x1", "y1", "z1")
+                end = _csv_point(fields, "x2", "y2", "z2")
+                features.append(("LINE", layer, (start, end)))
+            elif fields.get("x"):
+                features.append(("POINT", layer, _csv_point(fields, "x", "y", "z")))
+            else:
+                skipped += 1
+        except ValueError as exc:
+            raise ValueError(f"CSV line {line_no}: {exc}") from None
+    return features, skipped
+
+
+def geojson_to_dwg(
+    source: str | Mapping[str, Any],
+    output_path: str,
+    *,
+    layer_property: str = "layer",
+    default_layer: str = "0",
+    layer_map: Mapping[str, str] | LayerMap | None = None,
+    layer_colors: dict[str, int] | None = None,
+    scale: float = 1.0,
+    source_units: str | None = None,
+    target_units: str | None = None,
+    precision: int | None = None,
+) -> WriteResult:
+    """Write an AC1015 DWG from GeoJSON geometries.
+
+    ``source`` is a path or a parsed FeatureCollection, Feature or bare
+    geometry. Points become POINT entities, line strings open LWPOLYLINE
+    entities and every polygon ring a closed LWPOLYLINE; collections and
+    multi-geometries are flattened. Z values of line strings and polygons
+    are dropped. The layer of a feature is its ``layer_property``, or
+    ``default_layer`` when the property is missing.
+    """
+    source_path = ""
+    if isinstance(source, str):
+        source_path = source
+        source = json.loads(Path(source).read_text(encoding="utf-8"))
+    factor = _unit_factor(scale, source_units, target_units)
+    features: list[tuple[str, str | None, Any]] = []
+    skipped: dict[str, int] = {}
+    for geometry, properties in _geojson_features(source):
+        value = properties.get(layer_property)
+        layer = str(value) if value not in (None, "") else None
+        for dxftype, payload in _geojson_entities(geometry):
+            if payload is None:
+                kind = str(geometry.get("type"))
+                skipped[kind] = skipped.get(kind, 0) + 1
+                continue
+            features.append((dxftype, layer, payload))
+    return _write_features(
+        features,
+        output_path,
+        source_path=source_path,
+        factor=factor,
+        default_layer=default_layer,
+        layer_map=layer_map,
+        layer_colors=layer_colors,
+        precision=precision,
+        skipped=skipped,
+    )
+
+
+def _unit_factor(scale: float, source_units: str | None, target_units: str | None) -> float:
+    if (source_units is None) != (target_units is None):
+        raise ValueError("source_units and target_units must be given together")
+    factor = float(scale)
+    if source_units is not None and target_units is not None:
+        for units in (source_units, target_units):
+            if units.lower() not in UNIT_SCALES:
+                raise ValueError(
+                    f"unknown units {units!r}; expected one of {', '.join(UNIT_SCALES)}"
+                )
+        factor *= UNIT_SCALES[source_units.lower()] / UNIT_SCALES[target_units.lower()]
+    return factor
+
+
+def _csv_point(fields: dict[str, str], x: str, y: str, z: str) -> tuple[float, float, float]:
+    values = []
+    for column in (x, y, z):
+        text = fields.get(column, "")
+        if not text and column == z:
+            text = "0"
+        try:
+            values.append(float(text))
+        except ValueError:
+            raise ValueError(f"{column} is not a number: {text!r}") from None
+    return values[0], values[1], values[2]
+
+
+def _geojson_features(
+    source: Mapping[str, Any],
+) -> Iterator[tuple[Mapping[str, Any], Mapping[str, Any]]]:
+    kind = source.get("type")
+    if kind == "FeatureCollection":
+        for feature in source.get("features") or []:
+            yield from _geojson_features(feature)
+    elif kind == "Feature":
+        geometry = source.get("geometry")
+        if geometry:
+            yield geometry, source.get("properties") or {}
+    elif kind is not None:
+        yield source, {}
+
+
+def _geojson_entities(geometry: Mapping[str, Any]) -> Iterator[tuple[str, Any]]:
+    """``(dxftype, payload)`` per entity of a geometry; the payload is
+    ``None`` for parts without enough coordinates."""
+    kind = geometry.get("type")
+    coordinates = geometry.get("coordinates") or []
+    if kind == "GeometryCollection":
+        for part in geometry.get("geometries") or []:
+            yield from _geojson_entities(part)
+    elif kind == "Point":
+        yield "POINT", _geojson_position(coordinates)
+    elif kind == "MultiPoint":
+        for position in coordinates:
+            yield "POINT", _geojson_position(position)
+    elif kind == "LineString":
+        yield "LWPOLYLINE", _geojson_path(coordinates, closed=False)
+    elif kind == "MultiLineString":
+        for line in coordinates:
+            yield "LWPOLYLINE", _geojson_path(line, closed=False)
+    elif kind == "Polygon":
+        for ring in coordinates:
+            yield "LWPOLYLINE", _geojson_path(ring, closed=True)
+    elif kind == "MultiPolygon":
+        for polygon in coordinates:
+            for ring in polygon:
+                yield "LWPOLYLINE", _geojson_path(ring, closed=True)
+    else:
+        raise ValueError(f"unsupported GeoJSON geometry type: {kind!r}")
+
+
+def _geojson_position(position: Any) -> tuple[float, float, float] | None:
+    if not isinstance(position, (list, tuple)) or len(position) < 2:
+        return None
+    z = position[2] if len(position) > 2 else 0.0
+    return float(position[0]), float(position[1]), float(z)
+
+
+def _geojson_path(
+    positions: Any, *, closed: bool
+) -> tuple[list[tuple[float, float]], bool] | None:
+    points = []
+    for position in positions or []:
+        point = _geojson_position(position)
+        if point is None:
+            return None
+        points.append((point[0], point[1]))
+    # GeoJSON repeats the first position at the end of a ring.
+    if closed and len(points) > 1 and points[0] == points[-1]:
+        points.pop()
+    if len(points) < (3 if closed else 2):
+        return None
+    return points, closed
+
+
+def _write_features(
+    features: list[tuple[str, str | None, Any]],
+    output_path: str,
+    *,
+    source_path: str,
+    factor: float,
+    default_layer: str,
+    layer_map: Mapping[str, str] | LayerMap | None,
+    layer_colors: dict[str, int] | None,
+    precision: int | None,
+    skipped: dict[str, int],
+) -> WriteResult:
+    entities: list[Entity] = []
+    entity_layers: dict[int, str] = {}
+    for handle, (dxftype, layer, payload) in enumerate(features, start=_FIRST_HANDLE):
+        if dxftype == "LINE":
+            start, end = payload
+            dxf = {"start": _scaled(start, factor), "end": _scaled(end, factor)}
+        elif dxftype == "POINT":
+            dxf = {"location": _scaled(payload, factor)}
+        else:
+            points, closed = payload
+            dxf = {
+                "points": [(x * factor, y * factor) for x, y in points],
+                "flags": 1 if closed else 0,
+            }
+        entities.append(Entity(dxftype, handle, dxf))
+        entity_layers[handle] = layer or default_layer
+
+    layers, entity_layer_rows = _layer_rows(entity_layers, layer_map, layer_colors or {})
+    rows, total, written, skipped_by_type = _collect_dwg_write_rows(entities, precision)
+    for kind, count in skipped.items():
+        skipped_by_type[kind] = skipped_by_type.get(kind, 0) + count
+    total += sum(skipped.values())
+
+    out_path = Path(output_path)
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    raw.write_ac1015_dwg(
+        str(out_path),
+        *_dwg_write_row_args(rows),
+        layers=layers,
+        entity_layers=entity_layer_rows,
+        **_dwg_write_row_kwargs(rows),
+    )
+    return WriteResult(
+        source_path=source_path,
+        output_path=str(out_path),
+        target_version="AC1015",
+        total_entities=total,
+        written_entities=written,
+        skipped_entities=total - written,
+        skipped_by_type=dict(sorted(skipped_by_type.items())),
+    )
+
+
+def _scaled(point: tuple[float, float, float], factor: float) -> tuple[float, float, float]:
+    return point[0] * factor, point[1] * factor, point[2] * factor
+
+
+def _layer_rows(
+    entity_layers: dict[int, str],
+    layer_map: Mapping[str, str] | LayerMap | None,
+    layer_colors: dict[str, int],
+) -> tuple[list[tuple[str, int]], list[tuple[int, str]]]:
+    """LAYER entries and the layer of each entity after ``layer_map``; a
+    ``LayerMap`` sets names and colors, its linetypes are not applied.
+    ``layer_colors`` is keyed by the mapped names and overrides the map."""
+    layers: dict[str, tuple[str, int]] = {"0": ("0", 7)}
+    mapped: dict[str, str] = {}
+    for name in dict.fromkeys(entity_layers.values()):
+        target, color = name, None
+        if isinstance(layer_map, LayerMap):
+            result = layer_map.resolve(name)
+            if result is not None:
+                target, color = result.name, result.color
+        elif layer_map is not None:
+            target = layer_map.get(name, name)
+        _validate_layer_name(target)
+        known_name, known_color = layers.get(target.upper(), (target, 7))
+        layers[target.upper()] = (known_name, known_color if color is None else int(color))
+        mapped[name] = known_name
+    for name, color_index in layer_colors.items():
+        if not 1 <= int(color_index) <= 255:
+            raise ValueError(f"layer color must be in 1..255: {color_index}")
+        _validate_layer_name(name)
+        known_name, _color = layers.get(name.upper(), (name, 7))
+        layers[name.upper()] = (known_name, int(color_index))
+    return list(layers.values()), [
+        (handle, mapped[name]) for handle, name in sorted(entity_layers.items())
+    ]
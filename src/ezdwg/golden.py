@@ -0,0 +1,187 @@
+from __future__ import annotations
+
+import argparse
+import json
+import math
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any, Sequence
+
+from .document import read
+
+
+SNAPSHOT_FORMAT = 1
+# Decimal places kept for floats, so snapshots do not depend on the last
+# bits of platform floating point.
+_FLOAT_DIGITS = 9
+_MAX_DIFFERENCES = 20
+
+
+@dataclass(frozen=True)
+class GoldenMismatch:
+    path: Path
+    snapshot_path: Path
+    differences: list[str]
+
+
+def snapshot_path_for(path: str | Path) -> Path:
+    return Path(path).with_suffix(".json")
+
+
+def snapshot(path: str | Path, types: str | None = None) -> dict[str, Any]:
+    """Decode the modelspace entities of ``path`` into a JSON-ready snapshot."""
+    doc = read(str(path))
+    entities = [
+        {
+            "type": entity.dxftype,
+            "handle": int(entity.handle),
+            "dxf": _normalize(entity.dxf),
+        }
+        for entity in doc.modelspace().query(types)
+    ]
+    out: dict[str, Any] = {"format": SNAPSHOT_FORMAT, "version": doc.version}
+    if types is not None:
+        out["types"] = types
+    out["entities"] = entities
+    return out
+
+
+def corpus_files(directory: str | Path) -> list[Path]:
+    return sorted(
+        path
+        for path in Path(directory).rglob("*")
+        if path.is_file() and path.suffix.lower() == ".dwg"
+    )
+
+
+def check_corpus(directory: str | Path) -> list[GoldenMismatch]:
+    """Compare every DWG in ``directory`` against its snapshot.
+
+    Files without a snapshot are reported as mismatches. A snapshot may carry
+    a ``types`` entry to restrict the comparison to some entity types.
+    """
+    mismatches = []
+    for path in corpus_files(directory):
+        snapshot_path = snapshot_path_for(path)
+        if not snapshot_path.exists():
+            mismatches.append(
+                GoldenMismatch(path, snapshot_path, ["snapshot missing; run regenerate"])
+            )
+            continue
+        expected = json.loads(snapshot_path.read_text(encoding="utf-8"))
+        try:
+            actual = snapshot(path, expected.get("types"))
+        except Exception as exc:
+            mismatches.append(GoldenMismatch(path, snapshot_path, [f"decode failed: {exc}"]))
+            continue
+        differences: list[str] = []
+        _diff(expected, actual, "", differences)
+        if differences:
+            mismatches.append(GoldenMismatch(path, snapshot_path, differences))
+    return mismatches
+
+
+def regenerate_corpus(directory: str | Path, *, missing_only: bool = False) -> list[Path]:
+    """Write snapshots for the DWG files in ``directory``.
+
+    Existing ``types`` restrictions are kept. Returns the snapshot paths
+    written.
+    """
+    written = []
+    for path in corpus_files(directory):
+        snapshot_path = snapshot_path_for(path)
+        types = None
+        if snapshot_path.exists():
+            if missing_only:
+                continue
+            types = json.loads(snapshot_path.read_text(encoding="utf-8")).get("types")
+        data = snapshot(path, types)
+        snapshot_path.write_text(json.dumps(data, indent=2, sort_keys=True) + "\n", encoding="utf-8")
+        written.append(snapshot_path)
+    return written
+
+
+def _normalize(value: Any) -> Any:
+    if isinstance(value, bool) or value is None or isinstance(value, (int, str)):
+        return value
+    if isinstance(value, float):
+        if not math.isfinite(value):
+            return str(value)
+        value = round(value, _FLOAT_DIGITS)
+        return 0.0 if value == 0.0 else value
+    if isinstance(value, dict):
+        return {str(key): _normalize(item) for key, item in value.items()}
+    if isinstance(value, (bytes, bytearray)):
+        return bytes(value).hex()
+    if isinstance(value, (set, frozenset)):
+        return sorted((_normalize(item) for item in value), key=repr)
+    if isinstance(value, (list, tuple)):
+        return [_normalize(item) for item in value]
+    return repr(value)
+
+
+def _diff(expected: Any, actual: Any, where: str, out: list[str]) -> None:
+    if len(out) >= _MAX_DIFFERENCES:
+        return
+    if isinstance(expected, dict) and isinstance(actual, dict):
+        for key in sorted(set(expected) | set(actual)):
+            child = f"{where}.{key}" if where else str(key)
+            if key not in actual:
+                out.append(f"{child}: missing")
+            elif key not in expected:
+                out.append(f"{child}: unexpected {_short(actual[key])}")
+            else:
+                _diff(expected[key], actual[key], child, out)
+            if len(out) >= _MAX_DIFFERENCES:
+                return
+        return
+    if isinstance(expected, list) and isinstance(actual, list):
+        if len(expected) != len(actual):
+            out.append(f"{where}: expected {len(expected)} items, got {len(actual)}")
+        for index, (left, right) in enumerate(zip(expected, actual)):
+            _diff(left, right, f"{where}[{index}]", out)
+            if len(out) >= _MAX_DIFFERENCES:
+                return
+        return
+    if expected != actual:
+        out.append(f"{where}: expected {_short(expected)}, got {_short(actual)}")
+
+
+def _short(value: Any, limit: int = 80) -> str:
+    text = json.dumps(value)
+    return text if len(text) <= limit else text[: limit - 3] + "..."
+
+
+def main(argv: Sequence[str] | None = None) -> int:
+    parser = argparse.ArgumentParser(
+        prog="python -m ezdwg.golden",
+        description="Check or regenerate golden DWG snapshots.",
+    )
+    subparsers = parser.add_subparsers(dest="command", required=True)
+    check_parser = subparsers.add_parser("check", help="Compare DWG files with their snapshots.")
+    check_parser.add_argument("directory", help="Corpus directory.")
+    regenerate_parser = subparsers.add_parser("regenerate", help="Rewrite snapshots.")
+    regenerate_parser.add_argument("directory", help="Corpus directory.")
+    regenerate_parser.add_argument(
+        "--missing-only",
+        action="store_true",
+        help="Only write snapshots for files that have none.",
+    )
+    args = parser.parse_args(argv)
+
+    if args.command == "regenerate":
+        for path in regenerate_corpus(args.directory, missing_only=bool(args.missing_only)):
+            print(f"wrote: {path}")
+        return 0
+
+    mismatches = check_corpus(args.directory)
+    for mismatch in mismatches:
+        print(f"mismatch: {mismatch.path}")
+        for difference in mismatch.differences:
+            print(f"  {difference}")
+    print(f"checked: {len(corpus_files(args.directory))} files, {len(mismatches)} mismatched")
+    return 1 if mismatches else 0
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
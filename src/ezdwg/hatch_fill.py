@@ -0,0 +1,253 @@
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass, field
+from typing import Any, Iterable, Sequence
+
+Point2 = tuple[float, float]
+Segment = tuple[Point2, Point2]
+
+
+@dataclass(frozen=True)
+class HatchPatternLine:
+    """One pattern line family in world units.
+
+    ``angle`` is in degrees. Lines run through ``origin`` and repeat every
+    ``offset``; ``dashes`` alternates dash (positive), gap (negative) and dot
+    (zero) lengths, and an empty tuple draws continuous lines.
+    """
+
+    angle: float
+    origin: Point2 = (0.0, 0.0)
+    offset: Point2 = (0.0, 1.0)
+    dashes: tuple[float, ...] = ()
+
+    @classmethod
+    def from_dxf(cls, line: dict[str, Any]) -> "HatchPatternLine":
+        return cls(
+            angle=float(line.get("angle", 0.0)),
+            origin=_xy(line.get("origin", (0.0, 0.0))),
+            offset=_xy(line.get("offset", (0.0, 1.0))),
+            dashes=tuple(float(dash) for dash in line.get("dashes", ())),
+        )
+
+
+@dataclass
+class HatchFill:
+    """Hatch interior geometry: solid ``polygons`` and pattern ``lines``
+    clipped to the boundary. Outer rings run counter-clockwise and islands
+    clockwise, so even-odd and nonzero fills agree."""
+
+    polygons: list[list[Point2]] = field(default_factory=list)
+    lines: list[Segment] = field(default_factory=list)
+
+
+# Pattern definitions from acad.pat as (angle, origin, offset, dashes), with
+# the offset in the line's own frame; used when a HATCH carries only a name.
+PREDEFINED_PATTERNS: dict[str, tuple[tuple[float, Point2, Point2, tuple[float, ...]], ...]] = {
+    "ANSI31": ((45.0, (0.0, 0.0), (0.0, 0.125), ()),),
+    "ANSI32": (
+        (45.0, (0.0, 0.0), (0.0, 0.375), ()),
+        (45.0, (0.176776695, 0.0), (0.0, 0.375), ()),
+    ),
+    "ANSI33": (
+        (45.0, (0.0, 0.0), (0.0, 0.25), ()),
+        (45.0, (0.176776695, 0.0), (0.0, 0.25), (0.125, -0.0625)),
+    ),
+    "ANSI37": (
+        (45.0, (0.0, 0.0), (0.0, 0.125), ()),
+        (135.0, (0.0, 0.0), (0.0, 0.125), ()),
+    ),
+    "DASH": ((0.0, (0.0, 0.0), (0.125, 0.125), (0.125, -0.125)),),
+    "LINE": ((0.0, (0.0, 0.0), (0.0, 0.125), ()),),
+    "NET": (
+        (0.0, (0.0, 0.0), (0.0, 0.125), ()),
+        (90.0, (0.0, 0.0), (0.0, 0.125), ()),
+    ),
+}
+
+
+def predefined_pattern(name: str, scale: float = 1.0, angle: float = 0.0) -> list[HatchPatternLine]:
+    """Return the named pattern from ``PREDEFINED_PATTERNS`` scaled by
+    ``scale`` and rotated by ``angle`` degrees, or ``[]`` for unknown names."""
+    lines = []
+    rotation = math.radians(angle)
+    for line_angle, origin, (dx, dy), dashes in PREDEFINED_PATTERNS.get(str(name).upper(), ()):
+        direction = math.radians(line_angle) + rotation
+        lines.append(
+            HatchPatternLine(
+                angle=line_angle + angle,
+                origin=_scale(_rotate(origin, rotation), scale),
+                offset=_scale(_rotate((dx, dy), direction), scale),
+                dashes=tuple(dash * scale for dash in dashes),
+            )
+        )
+    return lines
+
+
+def fill_hatch(
+    target: Any,
+    pattern: Sequence[HatchPatternLine] | None = None,
+    *,
+    max_lines: int = 10_000,
+) -> HatchFill:
+    """Generate the interior of a HATCH entity (or its ``dxf`` dict).
+
+    Solid hatches become polygons. Pattern hatches use ``pattern``, then the
+    decoded definition, then a ``PREDEFINED_PATTERNS`` entry for the pattern
+    name. The hatch style picks the filled loops: normal fills alternate
+    islands, outer (1) stops at the first island level and ignore (2) fills
+    only outermost loops. Families needing more than ``max_lines`` lines to
+    cover the boundary are skipped, since they would read as solid anyway.
+    """
+    dxf = getattr(target, "dxf", target)
+    loops = []
+    for path in dxf.get("paths", []) or []:
+        points = path.get("points", []) if isinstance(path, dict) else []
+        loop = [_xy(point) for point in points]
+        if len(loop) > 1 and loop[0] == loop[-1]:
+            loop.pop()
+        if len(loop) >= 3:
+            loops.append(loop)
+    definition = dxf.get("pattern") or {}
+    loops = _select_loops(loops, int(definition.get("style", 0) or 0))
+    if not loops:
+        return HatchFill()
+    if dxf.get("solid_fill"):
+        return HatchFill(polygons=_orient_loops(loops))
+
+    if pattern is None:
+        pattern = [HatchPatternLine.from_dxf(line) for line in definition.get("lines", ())]
+    if not pattern:
+        pattern = predefined_pattern(
+            str(dxf.get("pattern_name") or ""),
+            float(definition.get("scale", 1.0) or 1.0),
+            float(definition.get("angle", 0.0) or 0.0),
+        )
+    return HatchFill(lines=hatch_pattern_lines(loops, pattern, max_lines=max_lines))
+
+
+def hatch_pattern_lines(
+    loops: Iterable[Sequence[Point2]],
+    pattern: Iterable[HatchPatternLine],
+    *,
+    max_lines: int = 10_000,
+) -> list[Segment]:
+    """Clip each pattern line family to ``loops`` with the even-odd rule and
+    return the dash segments; dots are zero-length segments."""
+    edges = []
+    for loop in loops:
+        points = [_xy(point) for point in loop]
+        edges.extend(zip(points, points[1:] + points[:1]))
+    if not edges:
+        return []
+
+    segments: list[Segment] = []
+    for line in pattern:
+        theta = math.radians(line.angle)
+        ux, uy = math.cos(theta), math.sin(theta)
+        nx, ny = -uy, ux
+        spacing = line.offset[0] * nx + line.offset[1] * ny
+        if abs(spacing) <= 1.0e-12:
+            continue
+        base = line.origin[0] * nx + line.origin[1] * ny
+        heights = [x * nx + y * ny for (x, y), _ in edges]
+        first, last = sorted(
+            ((min(heights) - base) / spacing, (max(heights) - base) / spacing)
+        )
+        k_start, k_end = math.ceil(first), math.floor(last)
+        if k_end - k_start + 1 > max_lines:
+            continue
+        period = sum(abs(dash) for dash in line.dashes)
+        for k in range(k_start, k_end + 1):
+            ox = line.origin[0] + k * line.offset[0]
+            oy = line.origin[1] + k * line.offset[1]
+            level = ox * nx + oy * ny
+            crossings = []
+            for (ax, ay), (bx, by) in edges:
+                da = ax * nx + ay * ny - level
+                db = bx * nx + by * ny - level
+                if (da > 0.0) == (db > 0.0):
+                    continue
+                t = da / (da - db)
+                px, py = ax + (bx - ax) * t, ay + (by - ay) * t
+                crossings.append((px - ox) * ux + (py - oy) * uy)
+            crossings.sort()
+            for start, end in zip(crossings[::2], crossings[1::2]):
+                for a, b in _dash_spans(start, end, line.dashes, period):
+                    segments.append(((ox + ux * a, oy + uy * a), (ox + ux * b, oy + uy * b)))
+    return segments
+
+
+def _dash_spans(start: float, end: float, dashes: Sequence[float], period: float):
+    if not dashes or period <= 1.0e-12:
+        yield start, end
+        return
+    # Dash patterns are anchored at the line origin (distance 0).
+    position = math.floor(start / period) * period
+    while position <= end:
+        for dash in dashes:
+            length = abs(dash)
+            if dash >= 0.0:
+                a, b = max(position, start), min(position + length, end)
+                if a < b or (dash == 0.0 and start <= position <= end):
+                    yield a, max(a, b)
+            position += length
+            if position > end:
+                return
+
+
+def _select_loops(loops: list[list[Point2]], style: int) -> list[list[Point2]]:
+    if style not in (1, 2) or len(loops) < 2:
+        return loops
+    max_depth = 1 if style == 1 else 0
+    return [loop for loop, depth in zip(loops, _loop_depths(loops)) if depth <= max_depth]
+
+
+def _orient_loops(loops: list[list[Point2]]) -> list[list[Point2]]:
+    oriented = []
+    for loop, depth in zip(loops, _loop_depths(loops)):
+        if (_signed_area(loop) < 0.0) == (depth % 2 == 0):
+            loop = loop[::-1]
+        oriented.append(loop)
+    return oriented
+
+
+def _loop_depths(loops: list[list[Point2]]) -> list[int]:
+    """Count the loops enclosing each loop's first vertex."""
+    return [
+        sum(
+            1
+            for other_index, other in enumerate(loops)
+            if other_index != index and _point_in_loop(loop[0], other)
+        )
+        for index, loop in enumerate(loops)
+    ]
+
+
+def _signed_area(loop: Sequence[Point2]) -> float:
+    return 0.5 * sum(
+        ax * by - bx * ay for (ax, ay), (bx, by) in zip(loop, list(loop[1:]) + list(loop[:1]))
+    )
+
+
+def _point_in_loop(point: Point2, loop: Sequence[Point2]) -> bool:
+    x, y = point
+    inside = False
+    for (ax, ay), (bx, by) in zip(loop, list(loop[1:]) + list(loop[:1])):
+        if (ay > y) != (by > y) and x < ax + (bx - ax) * (y - ay) / (by - ay):
+            inside = not inside
+    return inside
+
+
+def _rotate(point: Point2, angle: float) -> Point2:
+    c, s = math.cos(angle), math.sin(angle)
+    return (point[0] * c - point[1] * s, point[0] * s + point[1] * c)
+
+
+def _scale(point: Point2, factor: float) -> Point2:
+    return (point[0] * factor, point[1] * factor)
+
+
+def _xy(point: Any) -> Point2:
+    return (float(point[0]), float(point[1]))
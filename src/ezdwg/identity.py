@@ -0,0 +1,177 @@
+from __future__ import annotations
+
+import uuid
+from dataclasses import dataclass
+from typing import Any, Iterable
+
+from .convert import WriteResult, _resolve_layout, to_dwg
+from .document import Document, Layout
+from .entity import Entity, round_floats
+
+# Registered application whose extended data holds the persistent GUID of
+# an entity, as a single 1000 string item.
+GUID_APP_NAME = "EZDWG_GUID"
+
+# dxf keys that do not describe the entity itself and may differ between two
+# saves of the same drawing.
+_IGNORED_KEYS = frozenset({"owner_handle"})
+
+
+@dataclass(frozen=True)
+class GuidStampResult:
+    write: WriteResult
+    guids: dict[int, str]
+    created: list[int]
+
+
+@dataclass(frozen=True)
+class EntityMatch:
+    """Entities of revision ``a`` paired with those of revision ``b``."""
+
+    pairs: dict[int, int]
+    methods: dict[int, str]
+    unmatched_a: list[int]
+    unmatched_b: list[int]
+
+
+def entity_guids(source: str | Document | Layout) -> dict[int, str]:
+    """Persistent GUIDs stored by ``stamp_entity_guids``, keyed by handle."""
+    _, layout = _resolve_layout(source)
+    guids: dict[int, str] = {}
+    for handle, apps in layout.doc.xdata().items():
+        items = next(
+            (items for name, items in apps.items() if name.upper() == GUID_APP_NAME), []
+        )
+        guid = next((value for code, value in items if code == 1000), None)
+        if isinstance(guid, str) and guid:
+            guids[handle] = guid
+    return guids
+
+
+def stamp_entity_guids(
+    source: str | Document | Layout,
+    output_path: str,
+    *,
+    guids: dict[int, str] | None = None,
+    **kwargs: Any,
+) -> GuidStampResult:
+    """Write ``source`` with a persistent GUID in the extended data of every
+    entity.
+
+    Entities keep the GUID they already carry, ``guids`` sets them by
+    handle, and the others get a new random one. Other keyword arguments
+    are passed to ``to_dwg``.
+    """
+    _, layout = _resolve_layout(source)
+    existing = entity_guids(layout)
+    requested = {int(handle): str(guid) for handle, guid in (guids or {}).items()}
+    assigned: dict[int, str] = {}
+    created: list[int] = []
+    for entity in layout.query(kwargs.get("types")):
+        handle = int(entity.handle)
+        guid = requested.get(handle) or existing.get(handle)
+        if guid is None:
+            guid = str(uuid.uuid4())
+            created.append(handle)
+        assigned[handle] = guid
+
+    extra_xdata = kwargs.pop("entity_xdata", None) or {}
+    entity_xdata = {int(handle): dict(apps) for handle, apps in extra_xdata.items()}
+    for handle, guid in assigned.items():
+        entity_xdata.setdefault(handle, {})[GUID_APP_NAME] = [(1000, guid)]
+    write = to_dwg(layout, output_path, entity_xdata=entity_xdata, **kwargs)
+    return GuidStampResult(write=write, guids=assigned, created=sorted(created))
+
+
+def match_entities(
+    a: str | Document | Layout | Iterable[Entity],
+    b: str | Document | Layout | Iterable[Entity],
+    *,
+    types: str | Iterable[str] | None = None,
+    precision: int = 9,
+) -> EntityMatch:
+    """Pair the entities of two revisions, even when handles changed.
+
+    Entities are paired, in order, by persistent GUID, by handle when the
+    type and ``dxf`` values are equal, by equal type and ``dxf`` values, and
+    finally by handle when the type is equal. ``methods`` records which
+    rule paired each entity of ``a``: ``"guid"``, ``"handle"`` or
+    ``"content"``.
+    """
+    entities_a, guids_a = _entities_and_guids(a, types)
+    entities_b, guids_b = _entities_and_guids(b, types)
+    return _match(entities_a, guids_a, entities_b, guids_b, precision)
+
+
+def _match(
+    entities_a: dict[int, Entity],
+    guids_a: dict[int, str],
+    entities_b: dict[int, Entity],
+    guids_b: dict[int, str],
+    precision: int,
+) -> EntityMatch:
+    pairs: dict[int, int] = {}
+    methods: dict[int, str] = {}
+
+    def pair(handle_a: int, handle_b: int, method: str) -> None:
+        pairs[handle_a] = handle_b
+        methods[handle_a] = method
+
+    def unpaired_b() -> set[int]:
+        return entities_b.keys() - set(pairs.values())
+
+    by_guid_b: dict[str, list[int]] = {}
+    for handle in sorted(guids_b):
+        if handle in entities_b:
+            by_guid_b.setdefault(guids_b[handle], []).append(handle)
+    for handle in sorted(guids_a):
+        candidates = by_guid_b.get(guids_a[handle])
+        if handle in entities_a and candidates:
+            pair(handle, candidates.pop(0), "guid")
+
+    signatures_a = {handle: _signature(entity, precision) for handle, entity in entities_a.items()}
+    signatures_b = {handle: _signature(entity, precision) for handle, entity in entities_b.items()}
+    free_b = unpaired_b()
+    for handle in sorted(entities_a.keys() - pairs.keys()):
+        if handle in free_b and signatures_a[handle] == signatures_b[handle]:
+            pair(handle, handle, "handle")
+
+    by_signature_b: dict[Any, list[int]] = {}
+    for handle in sorted(unpaired_b()):
+        by_signature_b.setdefault(_signature_key(signatures_b[handle]), []).append(handle)
+    for handle in sorted(entities_a.keys() - pairs.keys()):
+        candidates = by_signature_b.get(_signature_key(signatures_a[handle]))
+        if candidates:
+            pair(handle, candidates.pop(0), "content")
+
+    free_b = unpaired_b()
+    for handle in sorted(entities_a.keys() - pairs.keys()):
+        if handle in free_b and entities_a[handle].dxftype == entities_b[handle].dxftype:
+            pair(handle, handle, "handle")
+
+    return EntityMatch(
+        pairs=dict(sorted(pairs.items())),
+        methods=dict(sorted(methods.items())),
+        unmatched_a=sorted(entities_a.keys() - pairs.keys()),
+        unmatched_b=sorted(unpaired_b()),
+    )
+
+
+def _entities_and_guids(
+    source: Any, types: str | Iterable[str] | None, *, guids: bool = True
+) -> tuple[dict[int, Entity], dict[int, str]]:
+    if isinstance(source, (str, Document, Layout)):
+        _, layout = _resolve_layout(source)
+        entities = {int(entity.handle): entity for entity in layout.query(types)}
+        return entities, entity_guids(layout) if guids else {}
+    return {int(entity.handle): entity for entity in source}, {}
+
+
+def _signature(entity: Entity, precision: int) -> tuple[str, dict[str, Any]]:
+    dxf = {key: value for key, value in entity.dxf.items() if key not in _IGNORED_KEYS}
+    return entity.dxftype, round_floats(dxf, precision)
+
+
+def _signature_key(signature: tuple[str, dict[str, Any]]) -> str:
+    dxftype, dxf = signature
+    return f"{dxftype}:{sorted(dxf.items(), key=lambda item: item[0])!r}"
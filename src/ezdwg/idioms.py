@@ -0,0 +1,290 @@
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass
+from typing import Iterable
+
+from .convert import _resolve_layout
+from .document import Document, Layout
+from .entity import Entity
+from .topology import ConnectivityGraph, GraphEdge, Loop, Point2, _Snapper, connectivity_graph
+
+_FILL_TYPES = ("SOLID", "TRACE")
+_LINE_TYPES = ("LINE", "LWPOLYLINE", "POLYLINE_2D")
+
+
+@dataclass(frozen=True)
+class Arrow:
+    """An arrowhead drawn as a filled triangle, with the shaft behind it.
+
+    ``base`` is the middle of the short side and ``direction`` the unit
+    vector from it to ``tip``. ``tail`` is the far end of the shaft, or
+    ``base`` when no shaft was found.
+    """
+
+    tip: Point2
+    base: Point2
+    direction: Point2
+    length: float
+    width: float
+    handle: int
+    shaft_handle: int | None
+    tail: Point2
+
+    @property
+    def vector(self) -> Point2:
+        """From ``tail`` to ``tip``."""
+        return (self.tip[0] - self.tail[0], self.tip[1] - self.tail[1])
+
+
+@dataclass(frozen=True)
+class WallSegment:
+    """The centerline of a wall drawn as two parallel lines, or as one
+    TRACE, over the stretch where both faces run side by side."""
+
+    start: Point2
+    end: Point2
+    thickness: float
+    handles: tuple[int, int]
+
+    @property
+    def length(self) -> float:
+        return math.hypot(self.end[0] - self.start[0], self.end[1] - self.start[1])
+
+
+def find_arrows(
+    source: str | Document | Layout | Iterable[Entity],
+    *,
+    tolerance: float = 1e-6,
+    angle_tolerance: float = 1.0,
+) -> list[Arrow]:
+    """Arrowheads drawn as SOLID or TRACE triangles, with their shafts.
+
+    A fill with two of its corners within ``tolerance`` is a triangle; it
+    is an arrowhead when it is at least as long as it is wide, and its tip
+    is the corner opposite the shortest side. The shaft is a LINE, a
+    straight polyline segment or a TRACE that ends at the base or at the
+    tip, points back from it and runs within ``angle_tolerance`` degrees
+    of the arrow; the longest one is taken. Points are in the XY plane.
+    """
+    entities = _entities(source, (*_FILL_TYPES, *_LINE_TYPES))
+    heads: list[tuple[Entity, list[Point2]]] = []
+    shafts: list[tuple[Point2, Point2, int]] = []
+    lines: list[Entity] = []
+    for entity in entities:
+        if entity.dxftype in _FILL_TYPES:
+            corners = _fill_corners(entity, tolerance)
+            if corners is None:
+                continue
+            if len(corners) == 3:
+                heads.append((entity, corners))
+            elif entity.dxftype == "TRACE":
+                shafts.append((*_trace_axis(entity), entity.handle))
+        else:
+            lines.append(entity)
+    graph = connectivity_graph(lines, tolerance=tolerance, types=_LINE_TYPES)
+    shafts.extend(_straight_segments(graph))
+
+    limit = math.sin(math.radians(angle_tolerance))
+    arrows: list[Arrow] = []
+    for entity, corners in heads:
+        sides = sorted(
+            (_distance(corners[index - 1], corners[index - 2]), index) for index in range(3)
+        )
+        width, apex = sides[0]
+        tip = corners[apex]
+        a, b = corners[apex - 1], corners[apex - 2]
+        base = ((a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0)
+        length = _distance(tip, base)
+        if width <= 0.0 or length < width:
+            continue
+        direction = ((tip[0] - base[0]) / length, (tip[1] - base[1]) / length)
+        shaft_handle = None
+        tail = base
+        best = 0.0
+        for start, end, handle in shafts:
+            for near, far in ((start, end), (end, start)):
+                if _distance(near, base) > tolerance and _distance(near, tip) > tolerance:
+                    continue
+                span = _distance(near, far)
+                if span <= best:
+                    continue
+                along = ((near[0] - far[0]) / span, (near[1] - far[1]) / span)
+                if abs(_cross(along, direction)) > limit or _dot(along, direction) <= 0.0:
+                    continue
+                shaft_handle, tail, best = handle, far, span
+        arrows.append(
+            Arrow(tip, base, direction, length, width, entity.handle, shaft_handle, tail)
+        )
+    return arrows
+
+
+def wall_centerlines(
+    source: str | Document | Layout | Iterable[Entity],
+    *,
+    min_thickness: float,
+    max_thickness: float,
+    angle_tolerance: float = 1.0,
+    tolerance: float = 1e-6,
+) -> list[WallSegment]:
+    """Centerlines of walls drawn as pairs of parallel lines.
+
+    Straight segments of LINE, LWPOLYLINE and POLYLINE_2D entities within
+    ``angle_tolerance`` degrees of each other, between ``min_thickness``
+    and ``max_thickness`` apart and overlapping along their length pair
+    up when each is among the nearest such partners of the other, so a
+    long face can pair with several shorter ones across door openings.
+    The centerline covers the overlap. A TRACE is a wall of its own width.
+    """
+    if min_thickness < 0.0 or max_thickness < min_thickness:
+        raise ValueError("thickness range must satisfy 0 <= min_thickness <= max_thickness")
+    entities = _entities(source, ("TRACE", *_LINE_TYPES))
+    walls: list[WallSegment] = []
+    lines: list[Entity] = []
+    for entity in entities:
+        if entity.dxftype != "TRACE":
+            lines.append(entity)
+            continue
+        corners = _fill_corners(entity, tolerance)
+        if corners is None or len(corners) != 4:
+            continue
+        start, end = _trace_axis(entity)
+        width = _distance(*_xy_points(entity)[:2])
+        if min_thickness <= width <= max_thickness:
+            walls.append(WallSegment(start, end, width, (entity.handle, entity.handle)))
+    graph = connectivity_graph(lines, tolerance=tolerance, types=_LINE_TYPES)
+    segments = [
+        segment
+        for segment in _straight_segments(graph)
+        if _distance(segment[0], segment[1]) > 0.0
+    ]
+
+    limit = math.sin(math.radians(angle_tolerance))
+    pairs: list[tuple[float, int, int, Point2, Point2]] = []
+    nearest = [math.inf] * len(segments)
+    for i, (start, end, _handle) in enumerate(segments):
+        span = _distance(start, end)
+        axis = ((end[0] - start[0]) / span, (end[1] - start[1]) / span)
+        for j in range(i + 1, len(segments)):
+            other_start, other_end, _other = segments[j]
+            other_span = _distance(other_start, other_end)
+            other_axis = (
+                (other_end[0] - other_start[0]) / other_span,
+                (other_end[1] - other_start[1]) / other_span,
+            )
+            if abs(_cross(axis, other_axis)) > limit:
+                continue
+            offset = (other_start[0] - start[0], other_start[1] - start[1])
+            gap = _cross(axis, offset)
+            if not min_thickness <= abs(gap) <= max_thickness:
+                continue
+            far_offset = (other_end[0] - start[0], other_end[1] - start[1])
+            t0, t1 = sorted((_dot(axis, offset), _dot(axis, far_offset)))
+            low, high = max(t0, 0.0), min(t1, span)
+            if high - low <= tolerance:
+                continue
+            # Move onto the axis halfway between the faces.
+            x = start[0] - axis[1] * gap / 2.0
+            y = start[1] + axis[0] * gap / 2.0
+            center_start = (x + axis[0] * low, y + axis[1] * low)
+            center_end = (x + axis[0] * high, y + axis[1] * high)
+            pairs.append((abs(gap), i, j, center_start, center_end))
+            nearest[i] = min(nearest[i], abs(gap))
+            nearest[j] = min(nearest[j], abs(gap))
+    for gap, i, j, center_start, center_end in pairs:
+        if gap - nearest[i] <= tolerance and gap - nearest[j] <= tolerance:
+            handles = (segments[i][2], segments[j][2])
+            walls.append(WallSegment(center_start, center_end, gap, handles))
+    return walls
+
+
+def solid_outlines(
+    source: str | Document | Layout | Iterable[Entity],
+    *,
+    tolerance: float = 1e-6,
+) -> list[Loop]:
+    """Outlines of the areas filled by adjacent SOLID and TRACE entities,
+    such as a fill that was exploded or drawn strip by strip, largest first.
+
+    Sides shared by two fills, with corners within ``tolerance``, cancel
+    out and the remaining sides are traced into loops, so each area comes
+    back as one boundary that can be written as a HATCH or polyline.
+    Fills that only touch along part of a side are not merged.
+    """
+    graph = ConnectivityGraph()
+    snapper = _Snapper(graph.nodes, tolerance)
+    sides: dict[tuple[int, int], list[GraphEdge]] = {}
+    for entity in _entities(source, _FILL_TYPES):
+        corners = _fill_corners(entity, tolerance)
+        if corners is None:
+            continue
+        nodes = [snapper.node(corner) for corner in corners]
+        for start, end in zip(nodes, nodes[1:] + nodes[:1]):
+            if start != end:
+                edge = GraphEdge(start, end, 0.0, entity.handle, entity.dxftype)
+                sides.setdefault((min(start, end), max(start, end)), []).append(edge)
+    for edges in sides.values():
+        if len(edges) % 2:
+            graph.edges.append(edges[0])
+    return graph.loops()
+
+
+def _entities(
+    source: str | Document | Layout | Iterable[Entity], types: tuple[str, ...]
+) -> list[Entity]:
+    if isinstance(source, (str, Document, Layout)):
+        _, layout = _resolve_layout(source)
+        return list(layout.query(sorted(types)))
+    return [entity for entity in source if entity.dxftype in types]
+
+
+def _xy_points(entity: Entity) -> list[Point2]:
+    return [(float(point[0]), float(point[1])) for point in entity.dxf.get("points") or []]
+
+
+def _fill_corners(entity: Entity, tolerance: float) -> list[Point2] | None:
+    """Outline of a SOLID or TRACE with coincident corners merged, or None
+    when it is not a triangle or quadrilateral."""
+    points = _xy_points(entity)
+    if len(points) != 4:
+        return None
+    # The third and fourth corners are stored swapped.
+    outline = [points[0], points[1], points[3], points[2]]
+    corners: list[Point2] = []
+    for point in outline:
+        if not corners or _distance(corners[-1], point) > tolerance:
+            corners.append(point)
+    while len(corners) > 1 and _distance(corners[-1], corners[0]) <= tolerance:
+        corners.pop()
+    if len(corners) < 3:
+        return None
+    return corners
+
+
+def _trace_axis(entity: Entity) -> tuple[Point2, Point2]:
+    """Middle of the start side and of the end side of a TRACE."""
+    p0, p1, p2, p3 = _xy_points(entity)
+    return (
+        ((p0[0] + p1[0]) / 2.0, (p0[1] + p1[1]) / 2.0),
+        ((p2[0] + p3[0]) / 2.0, (p2[1] + p3[1]) / 2.0),
+    )
+
+
+def _straight_segments(graph: ConnectivityGraph) -> list[tuple[Point2, Point2, int]]:
+    return [
+        (graph.nodes[edge.start], graph.nodes[edge.end], edge.handle)
+        for edge in graph.edges
+        if not edge.bulge
+    ]
+
+
+def _distance(a: Point2, b: Point2) -> float:
+    return math.hypot(b[0] - a[0], b[1] - a[1])
+
+
+def _dot(a: Point2, b: Point2) -> float:
+    return a[0] * b[0] + a[1] * b[1]
+
+
+def _cross(a: Point2, b: Point2) -> float:
+    return a[0] * b[1] - a[1] * b[0]
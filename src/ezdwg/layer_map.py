@@ -0,0 +1,117 @@
+from __future__ import annotations
+
+import csv
+import re
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Any, Iterable, Mapping
+
+
+@dataclass(frozen=True)
+class LayerRule:
+    """Maps source layers onto a target layer, color and linetype.
+
+    ``pattern`` is a layer name compared ignoring case or, with ``regex``,
+    a regular expression that must match the whole name; ``target`` may then
+    refer to its groups as ``\\1`` or ``\\g<name>``. Fields left ``None``
+    keep the source value, so a rule without ``target`` only restyles.
+    """
+
+    pattern: str
+    target: str | None = None
+    color: int | None = None
+    linetype: str | None = None
+    regex: bool = False
+
+
+@dataclass(frozen=True)
+class MappedLayer:
+    name: str
+    color: int | None
+    linetype: str | None
+
+
+class LayerMap:
+    """Ordered layer rules applied by ``to_dwg`` and ``to_dxf``; the first
+    rule matching a layer name wins."""
+
+    def __init__(self, rules: Iterable[LayerRule]) -> None:
+        self.rules = tuple(rules)
+        self._compiled: list[tuple[LayerRule, re.Pattern[str] | None]] = []
+        for rule in self.rules:
+            if rule.color is not None and not 1 <= int(rule.color) <= 255:
+                raise ValueError(f"layer color must be in 1..255: {rule.color}")
+            if rule.linetype is not None and rule.linetype.upper() in {"BYLAYER", "BYBLOCK"}:
+                raise ValueError(f"invalid layer linetype: {rule.linetype}")
+            pattern = re.compile(rule.pattern, re.IGNORECASE) if rule.regex else None
+            self._compiled.append((rule, pattern))
+
+    def resolve(self, name: str) -> MappedLayer | None:
+        """Target of layer ``name``, or ``None`` when no rule matches."""
+        for rule, pattern in self._compiled:
+            if pattern is None:
+                if rule.pattern.upper() != name.upper():
+                    continue
+                target = rule.target if rule.target is not None else name
+            else:
+                match = pattern.fullmatch(name)
+                if match is None:
+                    continue
+                target = match.expand(rule.target) if rule.target is not None else name
+            return MappedLayer(
+                name=target,
+                color=int(rule.color) if rule.color is not None else None,
+                linetype=rule.linetype,
+            )
+        return None
+
+    @classmethod
+    def from_table(
+        cls,
+        table: Mapping[str, str | Mapping[str, Any]],
+        *,
+        regex: bool = False,
+    ) -> LayerMap:
+        """Rules from ``{pattern: target}`` or
+        ``{pattern: {"target": ..., "color": ..., "linetype": ...}}``."""
+        rules = []
+        for pattern, value in table.items():
+            if isinstance(value, str):
+                rules.append(LayerRule(pattern, target=value, regex=regex))
+            else:
+                rules.append(
+                    LayerRule(
+                        pattern,
+                        target=value.get("target"),
+                        color=value.get("color"),
+                        linetype=value.get("linetype"),
+                        regex=bool(value.get("regex", regex)),
+                    )
+                )
+        return cls(rules)
+
+    @classmethod
+    def from_csv(cls, path: str) -> LayerMap:
+        """Rules from a CSV file with a ``source`` column and optional
+        ``target``, ``color``, ``linetype`` and ``regex`` columns; empty
+        cells keep the source value."""
+        rules = []
+        with Path(path).open(newline="", encoding="utf-8-sig") as handle:
+            for row in csv.DictReader(handle):
+                cells = {
+                    key.strip().lower(): (value or "").strip()
+                    for key, value in row.items()
+                    if key is not None
+                }
+                if not cells.get("source"):
+                    raise ValueError(f"layer map row without source: {row}")
+                rules.append(
+                    LayerRule(
+                        cells["source"],
+                        target=cells.get("target") or None,
+                        color=int(cells["color"]) if cells.get("color") else None,
+                        linetype=cells.get("linetype") or None,
+                        regex=cells.get("regex", "").lower() in {"1", "true", "yes"},
+                    )
+                )
+        return cls(rules)
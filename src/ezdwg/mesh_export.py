@@ -0,0 +1,732 @@
+from __future__ import annotations
+
+import base64
+import json
+import math
+import struct
+from dataclasses import dataclass, field
+from typing import Callable, Iterable
+
+from . import raw
+from .convert import ConvertResult, _resolve_layout
+from .document import ViewInfo, _layer_names_by_handle
+from .entity import Entity
+from .mesh_topology import vertex_normals, weld_vertices
+from .render import _build_lwpolyline_path
+
+MESH_EXPORT_TYPES = ("POLYLINE_MESH", "POLYLINE_PFACE", "MESH", "3DFACE")
+SOLID_EXPORT_TYPES = ("3DSOLID", "REGION", "BODY")
+# 2.5D entities, exported as the faces their thickness sweeps.
+THICKNESS_EXPORT_TYPES = ("LINE", "ARC", "CIRCLE", "LWPOLYLINE", "POLYLINE_2D", "SOLID", "TRACE")
+# Types whose thickness comes from raw.decode_entity_thickness, not from dxf.
+_DECODED_THICKNESS_TYPES = ("LINE", "ARC", "CIRCLE", "LWPOLYLINE", "POLYLINE_2D")
+_THICKNESS_SEGMENTS = 32
+
+_POLYLINE_MESH_CLOSED_M = 0x01
+_POLYLINE_MESH_CLOSED_N = 0x20
+
+Point3 = tuple[float, float, float]
+SolidTessellator = Callable[
+    [int, str, bytes], "tuple[list[Point3], list[tuple[int, int, int]]] | None"
+]
+
+_solid_tessellator: SolidTessellator | None = None
+
+
+def register_solid_tessellator(tessellator: SolidTessellator | None) -> SolidTessellator | None:
+    """Install the tessellator used to export ACIS solids; return the previous one.
+
+    ``tessellator(handle, acis_format, data)`` receives the SAT (``"sat"``)
+    or SAB (``"sab"``) data of a 3DSOLID, REGION or BODY and returns
+    ``(vertices, triangles)`` or ``None``. While one is registered, the mesh
+    exporters include solids by default. Pass ``None`` to unregister.
+    """
+    global _solid_tessellator
+    previous = _solid_tessellator
+    _solid_tessellator = tessellator
+    return previous
+
+
+@dataclass
+class _LayerMesh:
+    vertices: list[Point3] = field(default_factory=list)
+    faces: list[tuple[int, ...]] = field(default_factory=list)
+
+    def add_faces(self, points: list[Point3], faces: Iterable[tuple[int, ...]]) -> None:
+        base = len(self.vertices)
+        self.vertices.extend(points)
+        self.faces.extend(tuple(base + index for index in face) for face in faces)
+
+    def weld(self, tolerance: float) -> None:
+        self.vertices, self.faces = weld_vertices(self.vertices, self.faces, tolerance=tolerance)
+
+    def triangles(self) -> list[tuple[int, int, int]]:
+        out: list[tuple[int, int, int]] = []
+        for face in self.faces:
+            for index in range(1, len(face) - 1):
+                out.append((face[0], face[index], face[index + 1]))
+        return out
+
+
+def to_obj(
+    source,
+    output_path: str,
+    *,
+    types: str | Iterable[str] | None = None,
+    weld_tolerance: float | None = None,
+    normals: bool = False,
+) -> ConvertResult:
+    """Write mesh-like entities to a Wavefront OBJ file.
+
+    Faces are grouped by layer with ``g`` statements; quads and n-gons are
+    kept as polygons. With ``weld_tolerance``, vertices of a layer closer
+    than it are merged first. ``normals`` adds a ``vn`` per vertex.
+    """
+    source_path, groups, counts = _collect_layer_meshes(source, types, weld_tolerance)
+    lines = ["# ezdwg mesh export"]
+    offset = 1
+    for name, mesh in groups.items():
+        if not mesh.faces:
+            continue
+        lines.append(f"g {_obj_group_name(name)}")
+        lines.extend(f"v {_fmt(x)} {_fmt(y)} {_fmt(z)}" for x, y, z in mesh.vertices)
+        if normals:
+            lines.extend(
+                f"vn {_fmt(x)} {_fmt(y)} {_fmt(z)}"
+                for x, y, z in vertex_normals(mesh.vertices, mesh.faces)
+            )
+        lines.extend(
+            "f "
+            + " ".join(
+                f"{offset + index}//{offset + index}" if normals else str(offset + index)
+                for index in face
+            )
+            for face in mesh.faces
+        )
+        offset += len(mesh.vertices)
+    with open(output_path, "w", encoding="utf-8", newline="\n") as handle:
+        handle.write("\n".join(lines) + "\n")
+    return _export_result(source_path, output_path, counts)
+
+
+def to_stl(
+    source,
+    output_path: str,
+    *,
+    types: str | Iterable[str] | None = None,
+    binary: bool = True,
+    weld_tolerance: float | None = None,
+) -> ConvertResult:
+    """Write mesh-like entities to an STL file as triangles.
+
+    STL has no groups, so layers only survive in ASCII output, where each
+    layer becomes its own ``solid``. ``weld_tolerance`` merges close
+    vertices first, dropping the faces that collapse.
+    """
+    source_path, groups, counts = _collect_layer_meshes(source, types, weld_tolerance)
+    if binary:
+        triangles = [
+            (mesh.vertices[a], mesh.vertices[b], mesh.vertices[c])
+            for mesh in groups.values()
+            for a, b, c in mesh.triangles()
+        ]
+        with open(output_path, "wb") as handle:
+            handle.write(b"ezdwg mesh export".ljust(80, b"\0"))
+            handle.write(struct.pack("<I", len(triangles)))
+            for p1, p2, p3 in triangles:
+                handle.write(struct.pack("<3f", *_triangle_normal(p1, p2, p3)))
+                for point in (p1, p2, p3):
+                    handle.write(struct.pack("<3f", *point))
+                handle.write(b"\0\0")
+    else:
+        lines: list[str] = []
+        for name, mesh in groups.items():
+            triangles = mesh.triangles()
+            if not triangles:
+                continue
+            solid = _obj_group_name(name)
+            lines.append(f"solid {solid}")
+            for a, b, c in triangles:
+                p1, p2, p3 = mesh.vertices[a], mesh.vertices[b], mesh.vertices[c]
+                nx, ny, nz = _triangle_normal(p1, p2, p3)
+                lines.append(f"  facet normal {_fmt(nx)} {_fmt(ny)} {_fmt(nz)}")
+                lines.append("    outer loop")
+                for x, y, z in (p1, p2, p3):
+                    lines.append(f"      vertex {_fmt(x)} {_fmt(y)} {_fmt(z)}")
+                lines.append("    endloop")
+                lines.append("  endfacet")
+            lines.append(f"endsolid {solid}")
+        with open(output_path, "w", encoding="utf-8", newline="\n") as handle:
+            handle.write("\n".join(lines) + "\n")
+    return _export_result(source_path, output_path, counts)
+
+
+def to_gltf(
+    source,
+    output_path: str,
+    *,
+    types: str | Iterable[str] | None = None,
+    cameras: bool = False,
+    weld_tolerance: float | None = None,
+    normals: bool = False,
+) -> ConvertResult:
+    """Write mesh-like entities to a self-contained glTF 2.0 file.
+
+    Each layer becomes a named node with one triangle mesh. Geometry is
+    converted from the drawing's Z-up axes to glTF's Y-up axes. With
+    ``cameras=True``, every modelspace named view becomes a camera node
+    named after the view. ``weld_tolerance`` merges close vertices first
+    and ``normals`` adds a NORMAL attribute of smoothed vertex normals.
+    """
+    source_path, groups, counts = _collect_layer_meshes(source, types, weld_tolerance)
+    views = _model_views(source) if cameras else []
+    buffer = bytearray()
+    buffer_views: list[dict] = []
+    accessors: list[dict] = []
+    meshes: list[dict] = []
+    nodes: list[dict] = []
+    for name, mesh in groups.items():
+        triangles = mesh.triangles()
+        if not triangles:
+            continue
+        positions = [(x, z, -y) for x, y, z in mesh.vertices]
+        position_offset = len(buffer)
+        for point in positions:
+            buffer += struct.pack("<3f", *point)
+        normal_offset = len(buffer)
+        if normals:
+            for x, y, z in vertex_normals(mesh.vertices, mesh.faces):
+                buffer += struct.pack("<3f", x, z, -y)
+        index_offset = len(buffer)
+        for triangle in triangles:
+            buffer += struct.pack("<3I", *triangle)
+        buffer_views.append(
+            {
+                "buffer": 0,
+                "byteOffset": position_offset,
+                "byteLength": normal_offset - position_offset,
+                "target": 34962,
+            }
+        )
+        attributes = {"POSITION": len(accessors)}
+        if normals:
+            buffer_views.append(
+                {
+                    "buffer": 0,
+                    "byteOffset": normal_offset,
+                    "byteLength": index_offset - normal_offset,
+                    "target": 34962,
+                }
+            )
+        buffer_views.append(
+            {
+                "buffer": 0,
+                "byteOffset": index_offset,
+                "byteLength": len(buffer) - index_offset,
+                "target": 34963,
+            }
+        )
+        accessors.append(
+            {
+                "bufferView": len(buffer_views) - (3 if normals else 2),
+                "componentType": 5126,
+                "count": len(positions),
+                "type": "VEC3",
+                "min": [_f32(min(point[axis] for point in positions)) for axis in range(3)],
+                "max": [_f32(max(point[axis] for point in positions)) for axis in range(3)],
+            }
+        )
+        if normals:
+            attributes["NORMAL"] = len(accessors)
+            accessors.append(
+                {
+                    "bufferView": len(buffer_views) - 2,
+                    "componentType": 5126,
+                    "count": len(positions),
+                    "type": "VEC3",
+                }
+            )
+        accessors.append(
+            {
+                "bufferView": len(buffer_views) - 1,
+                "componentType": 5125,
+                "count": len(triangles) * 3,
+                "type": "SCALAR",
+            }
+        )
+        meshes.append(
+            {
+                "name": name,
+                "primitives": [{"attributes": attributes, "indices": len(accessors) - 1}],
+            }
+        )
+        nodes.append({"name": name, "mesh": len(meshes) - 1})
+
+    vertices = [point for mesh in groups.values() for point in mesh.vertices]
+    gltf_cameras: list[dict] = []
+    for view in views:
+        camera, node = _view_camera(view, vertices)
+        node["camera"] = len(gltf_cameras)
+        gltf_cameras.append(camera)
+        nodes.append(node)
+
+    gltf: dict = {
+        "asset": {"version": "2.0", "generator": "ezdwg"},
+        "scene": 0,
+        "scenes": [{"nodes": list(range(len(nodes)))}],
+        "nodes": nodes,
+    }
+    if meshes:
+        gltf["meshes"] = meshes
+        gltf["accessors"] = accessors
+        gltf["bufferViews"] = buffer_views
+        gltf["buffers"] = [
+            {
+                "byteLength": len(buffer),
+                "uri": "data:application/octet-stream;base64,"
+                + base64.b64encode(bytes(buffer)).decode("ascii"),
+            }
+        ]
+    if gltf_cameras:
+        gltf["cameras"] = gltf_cameras
+    with open(output_path, "w", encoding="utf-8") as handle:
+        json.dump(gltf, handle)
+    return _export_result(source_path, output_path, counts)
+
+
+def _collect_layer_meshes(
+    source, types: str | Iterable[str] | None, weld_tolerance: float | None = None
+) -> tuple[str, dict[str, _LayerMesh], dict[str, list[int]]]:
+    source_path, layout = _resolve_layout(source)
+    selected = _normalize_types(types)
+    decode_path = layout.doc.decode_path or layout.doc.path
+    layer_names = _layer_names_by_handle(decode_path)
+    groups: dict[str, _LayerMesh] = {}
+    # Per type: [total, written].
+    counts: dict[str, list[int]] = {}
+    solid_meshes = _solid_meshes(decode_path, selected)
+    entities = list(layout.query(" ".join(selected)))
+    thickness = _decoded_thickness(decode_path, entities)
+    for entity in entities:
+        if entity.dxftype in SOLID_EXPORT_TYPES:
+            points, faces = solid_meshes.get(entity.handle, ([], []))
+        elif entity.dxftype in THICKNESS_EXPORT_TYPES:
+            # Flat entities are plain 2D geometry, not part of the export.
+            points, faces = thickness_mesh(
+                entity, *thickness.get(entity.handle, (None, None, None))
+            )
+            if not faces:
+                continue
+        else:
+            points, faces = _entity_faces(entity)
+        entry = counts.setdefault(entity.dxftype, [0, 0])
+        entry[0] += 1
+        if not faces:
+            continue
+        layer_handle = entity.dxf.get("layer_handle")
+        name = layer_names.get(layer_handle, "0") if isinstance(layer_handle, int) else "0"
+        groups.setdefault(name, _LayerMesh()).add_faces(points, faces)
+        entry[1] += 1
+    if weld_tolerance is not None:
+        for mesh in groups.values():
+            mesh.weld(weld_tolerance)
+    return source_path, groups, counts
+
+
+def _model_views(source) -> list[ViewInfo]:
+    _source_path, layout = _resolve_layout(source)
+    return [view for view in layout.doc.views() if not view.is_paper_space]
+
+
+def _view_camera(view: ViewInfo, vertices: list[Point3]) -> tuple[dict, dict]:
+    """glTF camera and node for ``view``. The camera axes follow the display
+    coordinate system: the arbitrary axis algorithm applied to the view
+    direction, rotated by the twist angle."""
+    x_axis, y_axis, backward = _ocs_axes(view.direction)
+    cos_twist, sin_twist = math.cos(view.twist_angle), math.sin(view.twist_angle)
+    right = _combine(cos_twist, x_axis, -sin_twist, y_axis)
+    up = _combine(sin_twist, x_axis, cos_twist, y_axis)
+
+    center_x, center_y = view.center
+    target = _combine(1.0, view.target, center_x, right)
+    target = _combine(1.0, target, center_y, up)
+    distance = math.sqrt(sum(value * value for value in view.direction))
+    reach = max(
+        (math.dist(point, target) for point in vertices),
+        default=max(view.width, view.height),
+    )
+    name = view.name or f"view_{view.handle:X}"
+    if view.is_perspective:
+        aspect = view.width / view.height if view.width > 0.0 and view.height > 0.0 else 1.0
+        x_fov = view.field_of_view or math.radians(40.0)
+        camera = {
+            "name": name,
+            "type": "perspective",
+            "perspective": {
+                "yfov": 2.0 * math.atan(math.tan(x_fov / 2.0) / aspect),
+                "aspectRatio": aspect,
+                "znear": max(distance, reach, 1.0) * 1e-4,
+            },
+        }
+    else:
+        # Parallel views see the whole drawing, so back the camera out of
+        # the geometry and let the far plane reach past it.
+        distance = max(distance, reach, 1.0)
+        camera = {
+            "name": name,
+            "type": "orthographic",
+            "orthographic": {
+                "xmag": view.width / 2.0,
+                "ymag": view.height / 2.0,
+                "znear": 0.0,
+                "zfar": distance + reach + 1.0,
+            },
+        }
+    eye = _combine(1.0, target, distance, backward)
+    node = {
+        "name": name,
+        "translation": list(_to_y_up(eye)),
+        "rotation": _quaternion(_to_y_up(right), _to_y_up(up), _to_y_up(backward)),
+    }
+    return camera, node
+
+
+def _ocs_axes(normal) -> tuple[Point3, Point3, Point3]:
+    """X, Y and Z axes of the object coordinate system of ``normal``, by
+    AutoCAD's arbitrary axis algorithm."""
+    z_axis = _normalized(_point3(normal)) or (0.0, 0.0, 1.0)
+    if abs(z_axis[0]) < 1.0 / 64.0 and abs(z_axis[1]) < 1.0 / 64.0:
+        world_axis = (0.0, 1.0, 0.0)
+    else:
+        world_axis = (0.0, 0.0, 1.0)
+    x_axis = _normalized(_cross(world_axis, z_axis)) or (1.0, 0.0, 0.0)
+    return x_axis, _cross(z_axis, x_axis), z_axis
+
+
+def _to_y_up(point: Point3) -> Point3:
+    x, y, z = point
+    return (x, z, -y)
+
+
+def _normalized(vector: Point3) -> Point3 | None:
+    length = math.sqrt(sum(value * value for value in vector))
+    if length == 0.0:
+        return None
+    return (vector[0] / length, vector[1] / length, vector[2] / length)
+
+
+def _cross(a: Point3, b: Point3) -> Point3:
+    return (a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0])
+
+
+def _combine(a: float, u: Point3, b: float, v: Point3) -> Point3:
+    return (a * u[0] + b * v[0], a * u[1] + b * v[1], a * u[2] + b * v[2])
+
+
+def _quaternion(x_axis: Point3, y_axis: Point3, z_axis: Point3) -> list[float]:
+    """``[x, y, z, w]`` rotation whose matrix has the given axes as columns."""
+    m00, m10, m20 = x_axis
+    m01, m11, m21 = y_axis
+    m02, m12, m22 = z_axis
+    trace = m00 + m11 + m22
+    if trace > 0.0:
+        scale = 2.0 * math.sqrt(trace + 1.0)
+        quat = ((m21 - m12) / scale, (m02 - m20) / scale, (m10 - m01) / scale, scale / 4.0)
+    elif m00 > m11 and m00 > m22:
+        scale = 2.0 * math.sqrt(1.0 + m00 - m11 - m22)
+        quat = (scale / 4.0, (m01 + m10) / scale, (m02 + m20) / scale, (m21 - m12) / scale)
+    elif m11 > m22:
+        scale = 2.0 * math.sqrt(1.0 + m11 - m00 - m22)
+        quat = ((m01 + m10) / scale, scale / 4.0, (m12 + m21) / scale, (m02 - m20) / scale)
+    else:
+        scale = 2.0 * math.sqrt(1.0 + m22 - m00 - m11)
+        quat = ((m02 + m20) / scale, (m12 + m21) / scale, scale / 4.0, (m10 - m01) / scale)
+    return list(quat)
+
+
+def _normalize_types(types: str | Iterable[str] | None) -> list[str]:
+    if types is None:
+        if _solid_tessellator is None:
+            return list(MESH_EXPORT_TYPES + THICKNESS_EXPORT_TYPES)
+        return list(MESH_EXPORT_TYPES + THICKNESS_EXPORT_TYPES + SOLID_EXPORT_TYPES)
+    if isinstance(types, str):
+        types = types.split()
+    selected = [str(name).upper() for name in types]
+    supported = MESH_EXPORT_TYPES + THICKNESS_EXPORT_TYPES + SOLID_EXPORT_TYPES
+    unsupported = [name for name in selected if name not in supported]
+    if unsupported:
+        raise ValueError(
+            f"unsupported mesh export type: {unsupported[0]}. "
+            f"Supported types: {', '.join(supported)}"
+        )
+    if _solid_tessellator is None and any(name in SOLID_EXPORT_TYPES for name in selected):
+        raise ValueError("exporting solids requires register_solid_tessellator()")
+    return selected
+
+
+def _solid_meshes(
+    decode_path: str, selected: list[str]
+) -> dict[int, tuple[list[Point3], list[tuple[int, ...]]]]:
+    if _solid_tessellator is None or not any(name in SOLID_EXPORT_TYPES for name in selected):
+        return {}
+    return {
+        int(handle): ([_point3(point) for point in vertices], [tuple(tri) for tri in triangles])
+        for handle, vertices, triangles in raw.tessellate_solids(decode_path, _solid_tessellator)
+    }
+
+
+def _decoded_thickness(
+    decode_path: str, entities: list[Entity]
+) -> dict[int, tuple[float, Point3, float]]:
+    if not any(entity.dxftype in _DECODED_THICKNESS_TYPES for entity in entities):
+        return {}
+    return {
+        int(handle): (thickness, extrusion, elevation)
+        for handle, thickness, elevation, extrusion in raw.decode_entity_thickness(decode_path)
+    }
+
+
+def thickness_mesh(
+    entity: Entity,
+    thickness: float | None = None,
+    extrusion: Iterable[float] | None = None,
+    elevation: float | None = None,
+    *,
+    segments: int = _THICKNESS_SEGMENTS,
+) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    """Faces swept by the thickness of a 2.5D entity, as the exporters write them.
+
+    A LINE or ARC becomes a wall, a CIRCLE a closed cylinder, an LWPOLYLINE
+    or POLYLINE_2D the walls along its segments and a SOLID or TRACE a
+    closed prism. Arguments left out are read from ``entity.dxf``; the
+    entities built by ``query`` only carry them for SOLID and TRACE, and
+    ``raw.decode_entity_thickness`` has them for the others. ``segments``
+    is the number of sides of a full circle. Empty without thickness.
+    """
+    dxf = entity.dxf
+    thickness = float(dxf.get("thickness") or 0.0) if thickness is None else float(thickness)
+    if thickness == 0.0 or not math.isfinite(thickness):
+        return [], []
+    if extrusion is None:
+        extrusion = dxf.get("extrusion") or (0.0, 0.0, 1.0)
+    axes = _ocs_axes(extrusion)
+    elevation = float(dxf.get("elevation") or 0.0) if elevation is None else float(elevation)
+    offset = (thickness * axes[2][0], thickness * axes[2][1], thickness * axes[2][2])
+    segments = max(3, int(segments))
+
+    if entity.dxftype == "LINE":
+        outline = [_point3(dxf["start"]), _point3(dxf["end"])]
+        return _prism(outline, offset, closed=False)
+    if entity.dxftype == "CIRCLE":
+        cx, cy, cz = _point3(dxf["center"])
+        radius = float(dxf["radius"])
+        outline = [
+            _ocs_point(
+                axes,
+                (
+                    cx + radius * math.cos(2.0 * math.pi * index / segments),
+                    cy + radius * math.sin(2.0 * math.pi * index / segments),
+                    cz,
+                ),
+            )
+            for index in range(segments)
+        ]
+        return _prism(outline, offset, closed=True, capped=True)
+    if entity.dxftype == "ARC":
+        cx, cy, cz = _point3(dxf["center"])
+        radius = float(dxf["radius"])
+        start = math.radians(float(dxf["start_angle"]))
+        sweep = (math.radians(float(dxf["end_angle"])) - start) % (2.0 * math.pi)
+        steps = max(1, math.ceil(segments * sweep / (2.0 * math.pi)))
+        outline = [
+            _ocs_point(
+                axes,
+                (
+                    cx + radius * math.cos(start + sweep * index / steps),
+                    cy + radius * math.sin(start + sweep * index / steps),
+                    cz,
+                ),
+            )
+            for index in range(steps + 1)
+        ]
+        return _prism(outline, offset, closed=False)
+    if entity.dxftype in ("LWPOLYLINE", "POLYLINE_2D"):
+        closed = bool(dxf.get("closed"))
+        if dxf.get("interpolation_applied"):
+            points, bulges = dxf.get("interpolated_points") or [], None
+        else:
+            points, bulges = dxf.get("points") or [], dxf.get("bulges")
+        path = _build_lwpolyline_path(points, bulges, closed, arc_segments=segments)
+        outline = [_ocs_point(axes, (x, y, elevation)) for x, y in path]
+        return _prism(outline, offset, closed=closed)
+    if entity.dxftype in ("SOLID", "TRACE"):
+        # The third and fourth corners are stored in drawing order, swapped.
+        corners = [_point3(point) for point in (dxf.get("points") or [])[:4]]
+        if len(corners) < 4:
+            return [], []
+        outline = [_ocs_point(axes, corners[index]) for index in (0, 1, 3, 2)]
+        return _prism(outline, offset, closed=True, capped=True)
+    return [], []
+
+
+def _ocs_point(axes: tuple[Point3, Point3, Point3], point: Point3) -> Point3:
+    x_axis, y_axis, z_axis = axes
+    x, y, z = point
+    return _combine(1.0, _combine(x, x_axis, y, y_axis), z, z_axis)
+
+
+def _prism(
+    outline: list[Point3], offset: Point3, *, closed: bool, capped: bool = False
+) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    """Walls swept by moving ``outline`` by ``offset``, with a bottom and a
+    top face for ``capped`` outlines."""
+    base: list[Point3] = []
+    for point in outline:
+        if not base or math.dist(point, base[-1]) > 1e-12:
+            base.append(point)
+    if closed and len(base) > 1 and math.dist(base[0], base[-1]) <= 1e-12:
+        base.pop()
+    count = len(base)
+    if count < 2 or (closed and count < 3):
+        return [], []
+    top = [_combine(1.0, point, 1.0, offset) for point in base]
+    faces: list[tuple[int, ...]] = [
+        (index, (index + 1) % count, count + (index + 1) % count, count + index)
+        for index in range(count if closed else count - 1)
+    ]
+    if capped:
+        faces.append(tuple(reversed(range(count))))
+        faces.append(tuple(range(count, 2 * count)))
+    return base + top, faces
+
+
+def entity_mesh(entity: Entity) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    """Vertices and polygon faces of a POLYLINE_MESH, POLYLINE_PFACE, MESH
+    or 3DFACE entity as the exporters see them; empty for other types."""
+    return _entity_faces(entity)
+
+
+def _entity_faces(entity: Entity) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    dxf = entity.dxf
+    if entity.dxftype == "3DFACE":
+        return _face3d_faces(dxf.get("points") or [])
+    if entity.dxftype == "POLYLINE_MESH":
+        return _polyline_mesh_faces(dxf)
+    if entity.dxftype == "POLYLINE_PFACE":
+        return _pface_faces(dxf.get("vertices") or [], dxf.get("faces") or [])
+    if entity.dxftype == "MESH":
+        return _indexed_faces(dxf.get("vertices") or [], dxf.get("faces") or [], base=0)
+    return [], []
+
+
+def _face3d_faces(points: list) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    corners: list[Point3] = []
+    for point in points[:4]:
+        point = _point3(point)
+        if not corners or point != corners[-1]:
+            corners.append(point)
+    if len(corners) > 1 and corners[-1] == corners[0]:
+        corners.pop()
+    if len(corners) < 3:
+        return [], []
+    return corners, [tuple(range(len(corners)))]
+
+
+def _polyline_mesh_faces(dxf: dict) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    points = [_point3(point) for point in dxf.get("points") or []]
+    m_count = int(dxf.get("m_vertex_count") or 0)
+    n_count = int(dxf.get("n_vertex_count") or 0)
+    if m_count < 2 or n_count < 2 or len(points) < m_count * n_count:
+        return [], []
+    flags = int(dxf.get("flags") or 0)
+    closed_m = bool(flags & _POLYLINE_MESH_CLOSED_M)
+    closed_n = bool(flags & _POLYLINE_MESH_CLOSED_N)
+    faces: list[tuple[int, ...]] = []
+    for i in range(m_count if closed_m else m_count - 1):
+        next_i = (i + 1) % m_count
+        for j in range(n_count if closed_n else n_count - 1):
+            next_j = (j + 1) % n_count
+            faces.append(
+                (
+                    i * n_count + j,
+                    i * n_count + next_j,
+                    next_i * n_count + next_j,
+                    next_i * n_count + j,
+                )
+            )
+    return points[: m_count * n_count], faces
+
+
+def _pface_faces(vertices: list, faces: list) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    # Face records use 1-based indices; a negative index only hides an edge
+    # and zero marks an unused corner.
+    return _indexed_faces(
+        vertices,
+        [[abs(int(index)) for index in face if int(index) != 0] for face in faces],
+        base=1,
+    )
+
+
+def _indexed_faces(
+    vertices: list, faces: list, *, base: int
+) -> tuple[list[Point3], list[tuple[int, ...]]]:
+    points = [_point3(point) for point in vertices]
+    out: list[tuple[int, ...]] = []
+    for face in faces:
+        indices: list[int] = []
+        for index in face:
+            index = int(index) - base
+            if not 0 <= index < len(points):
+                indices = []
+                break
+            if not indices or indices[-1] != index:
+                indices.append(index)
+        if len(indices) > 1 and indices[-1] == indices[0]:
+            indices.pop()
+        if len(indices) >= 3:
+            out.append(tuple(indices))
+    return points, out
+
+
+def _point3(point) -> Point3:
+    x, y, *rest = point
+    return (float(x), float(y), float(rest[0]) if rest else 0.0)
+
+
+def _triangle_normal(p1: Point3, p2: Point3, p3: Point3) -> Point3:
+    ux, uy, uz = p2[0] - p1[0], p2[1] - p1[1], p2[2] - p1[2]
+    vx, vy, vz = p3[0] - p1[0], p3[1] - p1[1], p3[2] - p1[2]
+    nx, ny, nz = uy * vz - uz * vy, uz * vx - ux * vz, ux * vy - uy * vx
+    length = math.sqrt(nx * nx + ny * ny + nz * nz)
+    if length == 0.0:
+        return (0.0, 0.0, 0.0)
+    return (nx / length, ny / length, nz / length)
+
+
+def _obj_group_name(name: str) -> str:
+    return "_".join(name.split()) or "0"
+
+
+def _fmt(value: float) -> str:
+    return repr(float(value))
+
+
+def _f32(value: float) -> float:
+    return struct.unpack("<f", struct.pack("<f", value))[0]
+
+
+def _export_result(
+    source_path: str, output_path: str, counts: dict[str, list[int]]
+) -> ConvertResult:
+    total = sum(entry[0] for entry in counts.values())
+    written = sum(entry[1] for entry in counts.values())
+    return ConvertResult(
+        source_path=source_path,
+        output_path=output_path,
+        total_entities=total,
+        written_entities=written,
+        skipped_entities=total - written,
+        skipped_by_type={
+            name: entry[0] - entry[1] for name, entry in counts.items() if entry[0] > entry[1]
+        },
+    )
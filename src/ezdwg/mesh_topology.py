@@ -0,0 +1,178 @@
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass
+from typing import Iterable, Sequence
+
+Point3 = tuple[float, float, float]
+Face = tuple[int, ...]
+Edge = tuple[int, int]
+
+
+@dataclass(frozen=True)
+class MeshCheck:
+    """Topology of a polygon mesh. Edges are ``(low, high)`` vertex index
+    pairs, sorted."""
+
+    vertex_count: int
+    face_count: int
+    # Edges used by one face only: the outline of holes and open sides.
+    boundary_edges: list[Edge]
+    # Edges shared by more than two faces.
+    non_manifold_edges: list[Edge]
+    # Edges two faces run along in the same direction, so one of them faces
+    # the other way.
+    flipped_edges: list[Edge]
+    unused_vertices: int
+    # Faces with zero area.
+    degenerate_faces: int
+
+    @property
+    def is_manifold(self) -> bool:
+        return not self.non_manifold_edges
+
+    @property
+    def is_closed(self) -> bool:
+        return self.is_manifold and not self.boundary_edges
+
+    @property
+    def is_oriented(self) -> bool:
+        return not self.flipped_edges
+
+
+def weld_vertices(
+    vertices: Sequence[Point3],
+    faces: Iterable[Sequence[int]],
+    *,
+    tolerance: float = 1e-6,
+) -> tuple[list[Point3], list[Face]]:
+    """Merge vertices closer than ``tolerance`` and reindex ``faces``.
+
+    Each merged vertex keeps the position of the first one seen. Corners
+    that collapse onto their neighbour are dropped, and so are faces left
+    with fewer than three corners. Vertices no face uses are kept.
+    """
+    if tolerance < 0.0:
+        raise ValueError("tolerance must not be negative")
+    welded: list[Point3] = []
+    remap: list[int] = []
+    if tolerance == 0.0:
+        exact: dict[Point3, int] = {}
+        for point in vertices:
+            remap.append(exact.setdefault(point, len(exact)))
+        welded = list(exact)
+    else:
+        cells: dict[tuple[int, int, int], list[int]] = {}
+        limit = tolerance * tolerance
+        for point in vertices:
+            cell = tuple(math.floor(value / tolerance) for value in point)
+            match = None
+            for neighbour in _neighbour_cells(cell):
+                for index in cells.get(neighbour, ()):
+                    if _distance_squared(welded[index], point) <= limit:
+                        match = index
+                        break
+                if match is not None:
+                    break
+            if match is None:
+                match = len(welded)
+                welded.append(point)
+                cells.setdefault(cell, []).append(match)
+            remap.append(match)
+    out: list[Face] = []
+    for face in faces:
+        corners: list[int] = []
+        for index in face:
+            mapped = remap[index]
+            if not corners or corners[-1] != mapped:
+                corners.append(mapped)
+        while len(corners) > 1 and corners[-1] == corners[0]:
+            corners.pop()
+        if len(corners) >= 3:
+            out.append(tuple(corners))
+    return welded, out
+
+
+def face_normals(vertices: Sequence[Point3], faces: Iterable[Sequence[int]]) -> list[Point3]:
+    """Unit normal of each face by Newell's method, so non-planar polygons
+    get their average normal; ``(0, 0, 0)`` for faces with zero area."""
+    return [_normalized(_newell(vertices, face)) for face in faces]
+
+
+def vertex_normals(vertices: Sequence[Point3], faces: Iterable[Sequence[int]]) -> list[Point3]:
+    """Unit normal of each vertex: the area-weighted sum of the normals of
+    the faces around it. Vertices without a face get ``(0, 0, 0)``."""
+    sums = [[0.0, 0.0, 0.0] for _ in vertices]
+    for face in faces:
+        # Newell's vector is twice the area along the normal.
+        normal = _newell(vertices, face)
+        for index in face:
+            total = sums[index]
+            for axis in range(3):
+                total[axis] += normal[axis]
+    return [_normalized((x, y, z)) for x, y, z in sums]
+
+
+def check_mesh(vertices: Sequence[Point3], faces: Iterable[Sequence[int]]) -> MeshCheck:
+    """Report the boundary, non-manifold and inconsistently oriented edges
+    of a mesh. Weld its vertices first: duplicated vertices split edges and
+    make every face look like an island."""
+    faces = [tuple(face) for face in faces]
+    directed: dict[Edge, int] = {}
+    used: set[int] = set()
+    degenerate = 0
+    for face in faces:
+        used.update(face)
+        if _newell(vertices, face) == (0.0, 0.0, 0.0):
+            degenerate += 1
+        for start, end in zip(face, face[1:] + face[:1]):
+            directed[(start, end)] = directed.get((start, end), 0) + 1
+    undirected: dict[Edge, int] = {}
+    for (start, end), count in directed.items():
+        edge = (min(start, end), max(start, end))
+        undirected[edge] = undirected.get(edge, 0) + count
+    return MeshCheck(
+        vertex_count=len(vertices),
+        face_count=len(faces),
+        boundary_edges=sorted(edge for edge, count in undirected.items() if count == 1),
+        non_manifold_edges=sorted(edge for edge, count in undirected.items() if count > 2),
+        flipped_edges=sorted(
+            {
+                (min(start, end), max(start, end))
+                for (start, end), count in directed.items()
+                if count > 1
+            }
+        ),
+        unused_vertices=len(vertices) - len(used & set(range(len(vertices)))),
+        degenerate_faces=degenerate,
+    )
+
+
+def _neighbour_cells(cell: tuple[int, ...]) -> Iterable[tuple[int, int, int]]:
+    x, y, z = cell
+    for dx in (-1, 0, 1):
+        for dy in (-1, 0, 1):
+            for dz in (-1, 0, 1):
+                yield (x + dx, y + dy, z + dz)
+
+
+def _distance_squared(a: Point3, b: Point3) -> float:
+    return (a[0] - b[0]) ** 2 + (a[1] - b[1]) ** 2 + (a[2] - b[2]) ** 2
+
+
+def _newell(vertices: Sequence[Point3], face: Sequence[int]) -> Point3:
+    nx = ny = nz = 0.0
+    for start, end in zip(face, [*face[1:], face[0]]):
+        x1, y1, z1 = vertices[start]
+        x2, y2, z2 = vertices[end]
+        nx += (y1 - y2) * (z1 + z2)
+        ny += (z1 - z2) * (x1 + x2)
+        nz += (x1 - x2) * (y1 + y2)
+    return (nx, ny, nz)
+
+
+def _normalized(vector: Point3) -> Point3:
+    length = math.sqrt(sum(value * value for value in vector))
+    if length == 0.0:
+        return (0.0, 0.0, 0.0)
+    return (vector[0] / length, vector[1] / length, vector[2] / length)
@@ -0,0 +1,194 @@
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any, Iterable
+
+from .convert import _resolve_layout
+from .document import Document, Layout
+from .entity import Entity
+from .identity import _entities_and_guids, _match, _signature
+from .render import _StrokeCollector, _draw_layout, _parse_rgb, _require_matplotlib
+from .tiles import _hex, _points_bounds, _union
+
+_MARGIN = 0.04
+
+
+@dataclass(frozen=True)
+class OverlayDiff:
+    """Handles that differ between two revisions, and the image written."""
+
+    output_path: str
+    added: list[int]
+    removed: list[int]
+    modified: list[int]
+    unchanged: int
+    matched: dict[int, int] = field(default_factory=dict)
+
+    @property
+    def changed(self) -> bool:
+        return bool(self.added or self.removed or self.modified)
+
+
+def overlay_diff(
+    a: str | Document | Layout | Iterable[Entity],
+    b: str | Document | Layout | Iterable[Entity],
+    output_path: str,
+    *,
+    types: str | Iterable[str] | None = None,
+    precision: int = 9,
+    color_a: str = "#d62728",
+    color_b: str = "#1f77b4",
+    unchanged_color: str = "#b0b0b0",
+    line_width: float = 1.0,
+    highlight_width: float = 3.0,
+    width: int = 1024,
+    arc_segments: int = 64,
+    match: str = "handle",
+) -> OverlayDiff:
+    """Draw two revisions of a drawing on top of each other.
+
+    Entities are matched by handle, or with ``match="identity"`` by
+    ``match_entities``, and compared by type and ``dxf`` values rounded to
+    ``precision`` decimal places. Unchanged entities are drawn
+    once in ``unchanged_color``; removed and modified entities are drawn as
+    they are in ``a`` with ``color_a``, added and modified ones as they are
+    in ``b`` with ``color_b``, both ``highlight_width`` wide. The output is
+    SVG, or PDF through matplotlib when ``output_path`` ends in ``.pdf``.
+    """
+    if match not in {"handle", "identity"}:
+        raise ValueError(f"match must be 'handle' or 'identity', got {match!r}")
+    entities_a, guids_a = _entities_and_guids(a, types, guids=match == "identity")
+    entities_b, guids_b = _entities_and_guids(b, types, guids=match == "identity")
+    if match == "identity":
+        matched = _match(entities_a, guids_a, entities_b, guids_b, precision).pairs
+    else:
+        matched = {handle: handle for handle in sorted(entities_a.keys() & entities_b.keys())}
+    added = sorted(entities_b.keys() - set(matched.values()))
+    removed = sorted(entities_a.keys() - matched.keys())
+    modified = []
+    unchanged = []
+    for handle_a, handle_b in matched.items():
+        signature_a = _signature(entities_a[handle_a], precision)
+        if signature_a == _signature(entities_b[handle_b], precision):
+            unchanged.append(handle_b)
+        else:
+            modified.append((handle_a, handle_b))
+
+    groups = [
+        ("unchanged", [entities_b[handle] for handle in unchanged], unchanged_color, line_width),
+        (
+            "revision-a",
+            [entities_a[handle] for handle in sorted(removed + [pair[0] for pair in modified])],
+            color_a,
+            highlight_width,
+        ),
+        (
+            "revision-b",
+            [entities_b[handle] for handle in sorted(added + [pair[1] for pair in modified])],
+            color_b,
+            highlight_width,
+        ),
+    ]
+    drawn = [
+        (name, _strokes(entities, color, arc_segments), color, stroke_width)
+        for name, entities, color, stroke_width in groups
+    ]
+
+    out_path = Path(output_path)
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    if out_path.suffix.lower() == ".pdf":
+        _write_pdf(out_path, drawn, width)
+    else:
+        out_path.write_text(_overlay_svg(drawn, width), encoding="utf-8")
+    return OverlayDiff(
+        output_path=str(out_path),
+        added=added,
+        removed=removed,
+        modified=sorted(handle_b for _, handle_b in modified),
+        unchanged=len(unchanged),
+        matched=matched,
+    )
+
+
+class _EntityLayout:
+    def __init__(self, entities: list[Entity]) -> None:
+        self._entities = entities
+
+    def query(self, _types=None):
+        return iter(self._entities)
+
+
+def _strokes(entities: list[Entity], color: str, arc_segments: int) -> list[list[tuple[float, float]]]:
+    collector = _StrokeCollector((255, 255, 255))
+    _draw_layout(
+        collector,
+        _EntityLayout(entities),
+        None,
+        line_width=1.0,
+        arc_segments=arc_segments,
+        dimension_color=color,
+        lineweights=False,
+        linetypes=False,
+        ltscale=1.0,
+        celtscale=1.0,
+        color_resolver=lambda _dxf: color,
+    )
+    return [points for points, _rgb, _closed in collector.strokes]
+
+
+def _overlay_window(drawn: list[tuple]) -> tuple[float, float, float, float]:
+    boxes = [_points_bounds(points) for _name, strokes, _color, _width in drawn for points in strokes]
+    min_x, min_y, max_x, max_y = _union(boxes)
+    pad = max(max_x - min_x, max_y - min_y, 1e-9) * _MARGIN
+    return min_x - pad, min_y - pad, max_x + pad, max_y + pad
+
+
+def _overlay_svg(drawn: list[tuple], width: int) -> str:
+    min_x, min_y, max_x, max_y = _overlay_window(drawn)
+    scale = width / (max_x - min_x)
+    height = max(1, round((max_y - min_y) * scale))
+    lines = [
+        f'<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" '
+        f'viewBox="0 0 {width} {height}">',
+        '<rect width="100%" height="100%" fill="#ffffff"/>',
+    ]
+    for name, strokes, color, stroke_width in drawn:
+        rgb = _parse_rgb(color)
+        stroke = _hex(rgb) if rgb is not None else color
+        lines.append(
+            f'<g id="{name}" fill="none" stroke="{stroke}" stroke-width="{stroke_width:g}" '
+            'stroke-linecap="round" stroke-linejoin="round">'
+        )
+        for points in strokes:
+            coords = " ".join(
+                f"{(x - min_x) * scale:.2f},{(max_y - y) * scale:.2f}" for x, y in points
+            )
+            lines.append(f'<polyline points="{coords}"/>')
+        lines.append("</g>")
+    lines.append("</svg>")
+    return "\n".join(lines) + "\n"
+
+
+def _write_pdf(path: Path, drawn: list[tuple], width: int) -> None:
+    plt = _require_matplotlib()
+    min_x, min_y, max_x, max_y = _overlay_window(drawn)
+    aspect = (max_y - min_y) / (max_x - min_x)
+    fig = plt.figure(figsize=(width / 100.0, max(0.01, width * aspect / 100.0)))
+    try:
+        ax = fig.add_axes((0.0, 0.0, 1.0, 1.0))
+        ax.set_axis_off()
+        ax.set_xlim(min_x, max_x)
+        ax.set_ylim(min_y, max_y)
+        for _name, strokes, color, stroke_width in drawn:
+            for points in strokes:
+                ax.plot(
+                    [x for x, _y in points],
+                    [y for _x, y in points],
+                    color=color,
+                    linewidth=stroke_width,
+                    solid_capstyle="round",
+                )
+        fig.savefig(str(path), format="pdf")
+    finally:
+        plt.close(fig)
@@ -0,0 +1,231 @@
+from __future__ import annotations
+
+import os
+from collections.abc import Iterator
+from contextlib import contextmanager
+from dataclasses import dataclass, field
+
+from . import raw
+from .document import _canonical_entity_type_name
+
+# Non-entity object types that some ezdwg API decodes. Entities are covered
+# by ``SUPPORTED_ENTITY_TYPES``.
+DECODED_OBJECT_TYPES = frozenset(
+    {
+        "APPID",
+        "BLOCK_HEADER",
+        "DICTIONARY",
+        "DIMSTYLE",
+        "LAYER",
+        "LAYOUT",
+        "LTYPE",
+        "MATERIAL",
+        "SCALE",
+        "SHAPEFILE",
+        "TABLESTYLE",
+        "UCS",
+        "VIEW",
+        "VISUALSTYLE",
+        "VPORT",
+        "VP_ENT_HDR",
+        "XRECORD",
+    }
+)
+
+
+@dataclass(frozen=True)
+class SizeBucket:
+    """Object count and summed object data size for one type or layer."""
+
+    name: str | None
+    count: int
+    bytes: int
+
+
+@dataclass(frozen=True)
+class ObjectSize:
+    handle: int
+    type_name: str
+    layer: str | None
+    offset: int
+    size: int
+
+
+@dataclass(frozen=True)
+class FileProfile:
+    path: str
+    file_size: int
+    object_count: int
+    object_bytes: int
+    by_type: list[SizeBucket]
+    by_layer: list[SizeBucket]
+    heaviest: list[ObjectSize]
+
+
+def profile(path: str, *, top: int = 20) -> FileProfile:
+    """Summarize where the bytes of a DWG file go without decoding geometry.
+
+    Sizes are the object data sizes recorded in the object map headers.
+    ``by_type`` covers every object, ``by_layer`` only entities; entities
+    whose layer cannot be resolved are grouped under ``None``. Buckets are
+    sorted by size, largest first, and ``heaviest`` lists the ``top``
+    largest objects.
+    """
+    rows = raw.list_object_headers_with_type(path)
+    entity_handles = [int(row[0]) for row in rows if str(row[5]).upper() == "E"]
+    layer_names = _layer_names(path)
+    layer_by_entity = _entity_layer_handles(path, entity_handles, layer_names)
+
+    by_type: dict[str, list[int]] = {}
+    by_layer: dict[str | None, list[int]] = {}
+    objects: list[ObjectSize] = []
+    for handle, offset, size, _type_code, type_name, type_class in rows:
+        handle = int(handle)
+        size = int(size)
+        bucket = by_type.setdefault(str(type_name), [0, 0])
+        bucket[0] += 1
+        bucket[1] += size
+        layer = None
+        if str(type_class).upper() == "E":
+            layer = layer_names.get(layer_by_entity.get(handle))
+            bucket = by_layer.setdefault(layer, [0, 0])
+            bucket[0] += 1
+            bucket[1] += size
+        objects.append(ObjectSize(handle, str(type_name), layer, int(offset), size))
+
+    objects.sort(key=lambda item: (-item.size, item.handle))
+    return FileProfile(
+        path=str(path),
+        file_size=os.path.getsize(path),
+        object_count=len(rows),
+        object_bytes=sum(item.size for item in objects),
+        by_type=_sorted_buckets(by_type),
+        by_layer=_sorted_buckets(by_layer),
+        heaviest=objects[: max(0, int(top))],
+    )
+
+
+@dataclass(frozen=True)
+class UnsupportedType:
+    """Objects of one type that no ezdwg decoder reads.
+
+    ``type_class`` is ``"E"`` for entities and ``"O"`` for other objects.
+    ``bytes`` sums the object data sizes from the object map headers.
+    """
+
+    type_code: int
+    type_name: str
+    type_class: str
+    count: int
+    bytes: int
+
+
+def list_unsupported_objects(path: str) -> list[UnsupportedType]:
+    """Summarize the object types in ``path`` that ezdwg cannot decode.
+
+    Table control objects and the entity and object types listed in
+    ``SUPPORTED_ENTITY_TYPES`` and ``DECODED_OBJECT_TYPES`` count as
+    decoded; every other type code is reported, including class-defined
+    types without a known name. Types are sorted by total size, largest
+    first, so the first entries are the decoders that matter most for the
+    file.
+    """
+    found: dict[int, UnsupportedType] = {}
+    for _handle, _offset, size, type_code, type_name, type_class in (
+        raw.list_object_headers_with_type(path)
+    ):
+        name = str(type_name)
+        if _is_decoded_type(name, str(type_class)):
+            continue
+        code = int(type_code)
+        previous = found.get(code)
+        found[code] = UnsupportedType(
+            type_code=code,
+            type_name=name,
+            type_class=str(type_class).upper(),
+            count=1 + (previous.count if previous else 0),
+            bytes=int(size) + (previous.bytes if previous else 0),
+        )
+    return sorted(found.values(), key=lambda item: (-item.bytes, -item.count, item.type_code))
+
+
+@dataclass(frozen=True)
+class StatTotals:
+    """Calls, bytes and wall time recorded under one name."""
+
+    name: str
+    count: int
+    bytes: int
+    seconds: float
+
+
+@dataclass
+class DecodeStats:
+    sections: list[StatTotals] = field(default_factory=list)
+    types: list[StatTotals] = field(default_factory=list)
+    heuristics: list[StatTotals] = field(default_factory=list)
+
+
+@contextmanager
+def decode_stats() -> Iterator[DecodeStats]:
+    """Collect decoder timings for every read made inside the block.
+
+    The yielded ``DecodeStats`` is filled in when the block exits. Sections
+    are keyed by section name, types by entity type name and heuristics by
+    recovery scan name; ``bytes`` counts section bytes, entity body bytes
+    and inspected record bytes respectively. ``Document`` caches decoded
+    rows, so repeated queries on one document may not decode again.
+    """
+    stats = DecodeStats()
+    raw.begin_decode_stats()
+    try:
+        yield stats
+    finally:
+        sections, types, heuristics = raw.end_decode_stats()
+        stats.sections = [StatTotals(*row) for row in sections]
+        stats.types = [StatTotals(*row) for row in types]
+        stats.heuristics = [StatTotals(*row) for row in heuristics]
+
+
+def _layer_names(path: str) -> dict[int, str]:
+    try:
+        return {int(handle): str(name) for handle, name in raw.decode_layer_names(path)}
+    except Exception:
+        return {}
+
+
+def _entity_layer_handles(
+    path: str, entity_handles: list[int], layer_names: dict[int, str]
+) -> dict[int, int]:
+    try:
+        out = {
+            int(handle): int(layer_handle)
+            for handle, _color, _true_color, layer_handle in raw.decode_entity_styles(path)
+            if int(layer_handle) in layer_names
+        }
+    except Exception:
+        out = {}
+    # Fall back to reading the layer reference from the handle stream of
+    # entities the style decoder could not place.
+    missing = [handle for handle in entity_handles if handle not in out]
+    if missing:
+        try:
+            for handle, layer_handle in raw.decode_object_entity_layer_handles(path, missing):
+                if int(layer_handle) in layer_names:
+                    out[int(handle)] = int(layer_handle)
+        except Exception:
+            pass
+    return out
+
+
+def _sorted_buckets(buckets: dict) -> list[SizeBucket]:
+    items = [SizeBucket(name, count, size) for name, (count, size) in buckets.items()]
+    items.sort(key=lambda item: (-item.bytes, -item.count, str(item.name)))
+    return items
+
+
+def _is_decoded_type(type_name: str, type_class: str) -> bool:
+    name = type_name.strip().upper()
+    if type_class.upper() == "E":
+        return _canonical_entity_type_name(name) is not None
+    return name.endswith("_CONTROL") or name in DECODED_OBJECT_TYPES
@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from pathlib import Path
+
+from . import raw
+
+
+@dataclass(frozen=True)
+class RecoveredField:
+    """An entity field whose value came from heuristic recovery.
+
+    ``parsed`` is what the field itself decoded to, or ``None`` when it could
+    not be read at all. ``method`` names the recovery that chose ``value``.
+    """
+
+    handle: int
+    field: str
+    value: int | str
+    parsed: int | str | None
+    method: str
+
+
+def recovered_fields(path: str | Path) -> dict[int, dict[str, RecoveredField]]:
+    """Map entity handles to the fields whose values were guessed.
+
+    Covers the layer handle of entities (and so the layer name derived from
+    it), the block record handle of INSERT/MINSERT (and so ``name``) and the
+    text of MTEXT. Recovery only runs for R2010 and later files; an entity or
+    field missing from the result was parsed directly.
+    """
+    out: dict[int, dict[str, RecoveredField]] = {}
+    for handle, field, value, parsed, method in raw.decode_recovered_fields(str(path)):
+        recovered = RecoveredField(int(handle), str(field), value, parsed, str(method))
+        out.setdefault(recovered.handle, {})[recovered.field] = recovered
+    return out
+
+
+@dataclass(frozen=True)
+class FileLayout:
+    """Section layout of a DWG file.
+
+    ``flavor`` is ``"locator-table"`` for R14/R2000, ``"paged"`` for R2004
+    and R2010+ and ``"paged-r2007"`` for R2007. ``warnings`` lists the
+    deviations from AutoCAD's layout that were tolerated, and ``sections``
+    holds ``(name, offset, size)`` in normalized order.
+    """
+
+    version: str
+    flavor: str
+    standard: bool
+    warnings: list[str]
+    sections: list[tuple[str, int, int]]
+
+
+def describe_file_layout(path: str | Path) -> FileLayout:
+    """Report how the sections of ``path`` are laid out.
+
+    Files from third-party writers may pad the R14/R2000 section directory
+    or store its records out of order, repeated or empty. Such files are
+    read after normalizing the directory; ``standard`` is false for them.
+    """
+    version, flavor, standard, warnings, sections = raw.describe_file_layout(str(path))
+    return FileLayout(
+        version=str(version),
+        flavor=str(flavor),
+        standard=bool(standard),
+        warnings=[str(warning) for warning in warnings],
+        sections=[(str(name), int(offset), int(size)) for name, offset, size in sections],
+    )
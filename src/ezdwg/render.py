@@ -1,6 +1,20 @@
 from __future__ import annotations
 
-from typing import Iterable, Any
+from typing import Iterable, Any, Mapping
+
+from .colors import AciPalette, as_palette
+from .fonts import FontMap, ResolvedFont, standard_style_handle, text_style_fonts
+from .dimension_render import DimensionGraphics, dimension_style_resolver, regenerate_dimension
+from .hatch_fill import fill_hatch
+from .text_extents import estimate_text_extents
+
+
+_POINTS_PER_MM = 72.0 / 25.4
+_MIN_LINE_WIDTH_POINTS = 0.1
+_TEXT_POINTS_PER_UNIT = 3.0
+_MIN_TEXT_POINTS = 6.0
+# Dots (zero-length dashes) still need a visible mark.
+_MIN_DASH_POINTS = 0.5
 
 
 def plot(
@@ -15,6 +29,13 @@ def plot(
     auto_fit: bool = True,
     fit_margin: float = 0.04,
     dimension_color: Any | None = "black",
+    lineweights: bool = True,
+    linetypes: bool = True,
+    ltscale: float | None = None,
+    celtscale: float | None = None,
+    viewport: int | None = None,
+    palette: AciPalette | Mapping[int, tuple[int, int, int]] | None = None,
+    font_map: FontMap | None = None,
 ):
     layout = _resolve_layout(target)
     return plot_layout(
@@ -29,6 +50,13 @@ def plot(
         auto_fit=auto_fit,
         fit_margin=fit_margin,
         dimension_color=dimension_color,
+        lineweights=lineweights,
+        linetypes=linetypes,
+        ltscale=ltscale,
+        celtscale=celtscale,
+        viewport=viewport,
+        palette=palette,
+        font_map=font_map,
     )
 
 
@@ -44,24 +72,162 @@ def plot_layout(
     auto_fit: bool = True,
     fit_margin: float = 0.04,
     dimension_color: Any | None = "black",
+    lineweights: bool = True,
+    linetypes: bool = True,
+    ltscale: float | None = None,
+    celtscale: float | None = None,
+    viewport: int | None = None,
+    palette: AciPalette | Mapping[int, tuple[int, int, int]] | None = None,
+    font_map: FontMap | None = None,
 ):
     plt = _require_matplotlib()
     if ax is None:
         _, ax = plt.subplots()
+    # ACI 7 follows the axes background, so it stays visible on dark themes.
+    foreground = _contrast_hex(_axes_background(ax))
+    if linetypes and (ltscale is None or celtscale is None):
+        header_ltscale, header_celtscale = _header_linetype_scales(layout)
+        ltscale = header_ltscale if ltscale is None else ltscale
+        celtscale = header_celtscale if celtscale is None else celtscale
+
+    dashed_lines = _draw_layout(
+        ax,
+        layout,
+        types,
+        line_width=line_width,
+        arc_segments=arc_segments,
+        dimension_color=dimension_color,
+        lineweights=lineweights,
+        linetypes=linetypes,
+        ltscale=ltscale,
+        celtscale=celtscale,
+        color_resolver=_plot_color_resolver(palette, foreground),
+        viewport=viewport,
+        text_font=_text_font_resolver(layout, font_map),
+    )
+
+    if title:
+        ax.set_title(title)
+    if auto_fit:
+        _apply_auto_limits(ax, equal=equal, margin=fit_margin)
+    else:
+        ax.autoscale(True)
+        if equal:
+            _apply_equal_limits(ax)
+            ax.set_aspect("equal", adjustable="box")
+    _apply_dwg_dashes(ax, dashed_lines)
+    if show:
+        plt.show()
+    return ax
 
-    for entity in layout.query(types):
-        color = _resolve_dwg_color(entity.dxf)
+
+def to_png(
+    target: Any,
+    output_path: str,
+    types: str | Iterable[str] | None = None,
+    width: int = 1024,
+    height: int | None = None,
+    window: tuple[float, float, float, float] | None = None,
+    background: Any = "white",
+    palette: AciPalette | Mapping[int, tuple[int, int, int]] | None = None,
+    line_width: int = 1,
+    arc_segments: int = 64,
+    margin: float = 0.04,
+    viewport: int | None = None,
+) -> tuple[int, int]:
+    """Rasterize modelspace strokes to a PNG and return ``(width, height)``.
+
+    Text and dash patterns are not drawn. ``palette`` is an ``AciPalette`` or
+    a mapping that overrides ACI colors by index; ACI 7 and unresolved colors
+    contrast with ``background``.
+    ``viewport`` draws modelspace as that paperspace VIEWPORT shows it.
+    """
+    from . import raw
+
+    writer = getattr(raw, "write_strokes_png", None)
+    if writer is None:
+        raise RuntimeError("this ezdwg build was compiled without the `raster` feature")
+
+    layout = _resolve_layout(target)
+    background_rgb = _parse_rgb(background)
+    if background_rgb is None:
+        raise ValueError(f"unsupported background color: {background!r}")
+    collector = _StrokeCollector(background_rgb)
+    foreground = _contrast_hex(background_rgb)
+    _draw_layout(
+        collector,
+        layout,
+        types,
+        line_width=1.0,
+        arc_segments=arc_segments,
+        dimension_color=foreground,
+        lineweights=False,
+        linetypes=False,
+        ltscale=1.0,
+        celtscale=1.0,
+        color_resolver=_raster_color_resolver(palette, foreground),
+        viewport=viewport,
+    )
+    if window is None:
+        window = _auto_raster_window(collector.strokes, margin)
+    else:
+        window = tuple(float(value) for value in window)
+    return writer(
+        str(output_path),
+        collector.strokes,
+        width,
+        height,
+        window,
+        background_rgb,
+        line_width,
+        margin,
+    )
+
+
+def _draw_layout(
+    ax: Any,
+    layout: Any,
+    types: str | Iterable[str] | None,
+    *,
+    line_width: float,
+    arc_segments: int,
+    dimension_color: Any | None,
+    lineweights: bool,
+    linetypes: bool,
+    ltscale: float,
+    celtscale: float,
+    color_resolver=None,
+    viewport: int | None = None,
+    text_font=None,
+    dim_styles=None,
+):
+    if color_resolver is None:
+        color_resolver = _resolve_dwg_color
+    if dim_styles is None:
+        path = _layout_decode_path(layout)
+        dim_styles = dimension_style_resolver(path) if path else None
+    dashed_lines = []
+    if viewport is None:
+        entities = layout.query(types)
+    else:
+        entities = layout.query(types, viewport=viewport)
+    for entity in entities:
+        color = color_resolver(entity.dxf)
         if color is None:
             color = "#000000"
+        color = _apply_dwg_transparency(color, entity.dxf)
+        width = _resolve_dwg_line_width(entity.dxf, line_width) if lineweights else line_width
+        dashes = _resolve_dwg_dashes(entity.dxf, ltscale, celtscale) if linetypes else None
+        first_line = len(ax.lines) if dashes is not None else 0
         dxftype = entity.dxftype
         if dxftype == "LINE":
-            _draw_line(ax, entity.dxf["start"], entity.dxf["end"], line_width, color=color)
+            _draw_line(ax, entity.dxf["start"], entity.dxf["end"], width, color=color)
         elif dxftype == "RAY":
             _draw_ray(
                 ax,
                 entity.dxf.get("start", (0.0, 0.0, 0.0)),
                 entity.dxf.get("unit_vector", (1.0, 0.0, 0.0)),
-                line_width,
+                width,
                 color=color,
             )
         elif dxftype == "XLINE":
@@ -69,16 +235,16 @@ def plot_layout(
                 ax,
                 entity.dxf.get("start", (0.0, 0.0, 0.0)),
                 entity.dxf.get("unit_vector", (1.0, 0.0, 0.0)),
-                line_width,
+                width,
                 color=color,
             )
         elif dxftype == "POINT":
-            _draw_point(ax, entity.dxf["location"], line_width, color=color)
+            _draw_point(ax, entity.dxf["location"], width, color=color)
         elif dxftype == "LWPOLYLINE":
             _draw_polyline(
                 ax,
                 entity.dxf.get("points", []),
-                line_width,
+                width,
                 color=color,
                 bulges=entity.dxf.get("bulges"),
                 closed=bool(entity.dxf.get("closed", False)),
@@ -95,7 +261,7 @@ def plot_layout(
             _draw_polyline(
                 ax,
                 draw_points,
-                line_width,
+                width,
                 color=color,
                 bulges=draw_bulges,
                 closed=bool(entity.dxf.get("closed", False)),
@@ -105,7 +271,7 @@ def plot_layout(
             _draw_polyline(
                 ax,
                 entity.dxf.get("points", []),
-                line_width,
+                width,
                 color=color,
                 closed=bool(entity.dxf.get("closed", False)),
                 arc_segments=arc_segments,
@@ -117,7 +283,7 @@ def plot_layout(
                 entity.dxf.get("m_vertex_count", 0),
                 entity.dxf.get("n_vertex_count", 0),
                 bool(entity.dxf.get("closed", False)),
-                line_width,
+                width,
                 color=color,
                 arc_segments=arc_segments,
             )
@@ -126,7 +292,7 @@ def plot_layout(
                 ax,
                 entity.dxf.get("vertices", []),
                 entity.dxf.get("faces", []),
-                line_width,
+                width,
                 color=color,
                 arc_segments=arc_segments,
             )
@@ -135,20 +301,20 @@ def plot_layout(
                 ax,
                 entity.dxf.get("points", []),
                 int(entity.dxf.get("invisible_edge_flags", 0)),
-                line_width,
+                width,
                 color=color,
             )
         elif dxftype == "SOLID" or dxftype == "TRACE":
             _draw_polyline(
                 ax,
                 entity.dxf.get("points", []),
-                line_width,
+                width,
                 color=color,
                 closed=True,
                 arc_segments=arc_segments,
             )
         elif dxftype == "SHAPE":
-            _draw_point(ax, entity.dxf.get("insert", (0.0, 0.0, 0.0)), line_width, color=color)
+            _draw_point(ax, entity.dxf.get("insert", (0.0, 0.0, 0.0)), width, color=color)
         elif dxftype == "ARC":
             _draw_arc(
                 ax,
@@ -157,7 +323,7 @@ def plot_layout(
                 entity.dxf["start_angle"],
                 entity.dxf["end_angle"],
                 arc_segments,
-                line_width,
+                width,
                 color=color,
             )
         elif dxftype == "CIRCLE":
@@ -166,7 +332,7 @@ def plot_layout(
                 entity.dxf["center"],
                 entity.dxf["radius"],
                 arc_segments,
-                line_width,
+                width,
                 color=color,
             )
         elif dxftype == "ELLIPSE":
@@ -178,14 +344,14 @@ def plot_layout(
                 entity.dxf["start_angle"],
                 entity.dxf["end_angle"],
                 arc_segments,
-                line_width,
+                width,
                 color=color,
             )
         elif dxftype == "SPLINE":
             _draw_polyline(
                 ax,
                 entity.dxf.get("points", []),
-                line_width,
+                width,
                 color=color,
                 closed=bool(entity.dxf.get("closed", False)),
                 arc_segments=arc_segments,
@@ -198,6 +364,7 @@ def plot_layout(
                 entity.dxf.get("height", 1.0),
                 entity.dxf.get("rotation", 0.0),
                 color=color,
+                **_text_font_kwargs(text_font, entity.dxf),
             )
         elif dxftype == "ATTRIB" or dxftype == "ATTDEF":
             _draw_text(
@@ -207,6 +374,7 @@ def plot_layout(
                 entity.dxf.get("height", 1.0),
                 entity.dxf.get("rotation", 0.0),
                 color=color,
+                **_text_font_kwargs(text_font, entity.dxf),
             )
         elif dxftype == "MTEXT":
             _draw_text(
@@ -217,24 +385,26 @@ def plot_layout(
                 entity.dxf.get("rotation", 0.0),
                 color=color,
                 background=_resolve_mtext_background_bbox(ax, entity.dxf),
+                **_text_font_kwargs(text_font, entity.dxf),
             )
         elif dxftype == "LEADER":
             _draw_polyline(
                 ax,
                 entity.dxf.get("points", []),
-                line_width,
+                width,
                 color=color,
                 closed=False,
                 arc_segments=arc_segments,
             )
         elif dxftype == "HATCH":
+            _draw_hatch_fill(ax, entity.dxf, width, color=color)
             for path in entity.dxf.get("paths", []):
                 points = path.get("points", []) if isinstance(path, dict) else []
                 closed = bool(path.get("closed", False)) if isinstance(path, dict) else False
                 _draw_polyline(
                     ax,
                     points,
-                    line_width,
+                    width,
                     color=color,
                     closed=closed,
                     arc_segments=arc_segments,
@@ -259,31 +429,176 @@ def plot_layout(
             _draw_polyline(
                 ax,
                 entity.dxf.get("points", []),
-                line_width,
+                width,
                 color=color,
                 closed=bool(entity.dxf.get("closed", False)),
                 arc_segments=arc_segments,
             )
         elif dxftype == "INSERT":
-            _draw_point(ax, entity.dxf.get("insert", (0.0, 0.0, 0.0)), line_width, color=color)
+            _draw_point(ax, entity.dxf.get("insert", (0.0, 0.0, 0.0)), width, color=color)
         elif dxftype == "MINSERT":
-            _draw_point(ax, entity.dxf.get("insert", (0.0, 0.0, 0.0)), line_width, color=color)
+            _draw_point(ax, entity.dxf.get("insert", (0.0, 0.0, 0.0)), width, color=color)
         elif dxftype == "DIMENSION":
             dim_color = color if dimension_color is None else dimension_color
-            _draw_dimension(ax, entity.dxf, line_width, color=dim_color)
+            if dim_styles is None:
+                _draw_dimension(ax, entity.dxf, width, color=dim_color)
+            else:
+                _draw_dimension(
+                    ax, entity.dxf, width, color=dim_color, style=dim_styles(entity.dxf)
+                )
+        if dashes is not None:
+            dashed_lines.append((ax.lines[first_line:], dashes))
 
-    if title:
-        ax.set_title(title)
-    if auto_fit:
-        _apply_auto_limits(ax, equal=equal, margin=fit_margin)
-    else:
-        ax.autoscale(True)
-        if equal:
-            _apply_equal_limits(ax)
-            ax.set_aspect("equal", adjustable="box")
-    if show:
-        plt.show()
-    return ax
+    return dashed_lines
+
+
+class _StrokeCollector:
+    """Stands in for a matplotlib ``Axes`` and records plotted polylines."""
+
+    def __init__(self, background):
+        self.background = background
+        self.strokes = []
+        self.lines = []
+
+    def plot(self, xs, ys, linewidth=None, color=None, **_kwargs):
+        points = [(float(x), float(y)) for x, y in zip(xs, ys)]
+        if not points:
+            return []
+        rgb = _parse_rgb(color, self.background)
+        if rgb is None:
+            rgb = _parse_rgb(_contrast_hex(self.background))
+        self.strokes.append((points, rgb, False))
+        return []
+
+    def text(self, *_args, **_kwargs):
+        return None
+
+    def get_facecolor(self):
+        return "#{:02x}{:02x}{:02x}".format(*self.background)
+
+
+def _auto_raster_window(strokes, margin: float):
+    """Fit the same robust bounds as ``plot()`` so stray outliers do not
+    shrink the drawing to a few pixels."""
+    import math
+
+    xs = []
+    ys = []
+    for points, _rgb, _closed in strokes:
+        for x, y in points:
+            if math.isfinite(x) and math.isfinite(y):
+                xs.append(x)
+                ys.append(y)
+    full = _bounds_from_xy(xs, ys)
+    chosen = _choose_bounds(full, _robust_bounds(xs, ys, q_low=0.02, q_high=0.98))
+    if chosen is None or chosen[1] <= chosen[0] or chosen[3] <= chosen[2]:
+        return None
+    x0, x1, y0, y1 = _expand_bounds(chosen, margin=margin)
+    return (x0, y0, x1, y1)
+
+
+def _raster_color_resolver(palette, foreground: str):
+    palette = as_palette(palette) or AciPalette.default()
+
+    def resolve(dxf):
+        color = _resolve_dwg_color(dxf, palette, foreground)
+        return foreground if color is None else color
+
+    return resolve
+
+
+def _plot_color_resolver(palette, foreground: str):
+    palette = as_palette(palette)
+
+    def resolve(dxf):
+        return _resolve_dwg_color(dxf, palette, foreground)
+
+    return resolve
+
+
+def _text_font_resolver(layout, font_map: FontMap | None):
+    """Font of each text entity from its STYLE entry; MTEXT, which carries no
+    style handle here, uses the ``Standard`` style."""
+    if font_map is None:
+        return None
+    path = _layout_decode_path(layout)
+    style_fonts = text_style_fonts(path) if path else {}
+    standard = standard_style_handle(path) if path else None
+
+    def resolve(dxf) -> ResolvedFont:
+        handle = dxf.get("style_handle")
+        if handle not in style_fonts:
+            handle = standard
+        return font_map.resolve(style_fonts.get(handle))
+
+    return resolve
+
+
+def _header_linetype_scales(layout) -> tuple[float, float]:
+    """``$LTSCALE`` and ``$CELTSCALE`` of the drawing, 1.0 when they cannot
+    be decoded."""
+    from . import raw
+
+    path = _layout_decode_path(layout)
+    if path:
+        try:
+            return raw.decode_linetype_scales(path)
+        except Exception:
+            pass
+    return 1.0, 1.0
+
+
+def _layout_decode_path(layout) -> str | None:
+    doc = getattr(layout, "doc", None)
+    return getattr(doc, "decode_path", None) or getattr(doc, "path", None)
+
+
+def _text_font_kwargs(text_font, dxf) -> dict[str, Any]:
+    return {} if text_font is None else {"font": text_font(dxf)}
+
+
+def _axes_background(ax) -> tuple[int, int, int]:
+    getter = getattr(ax, "get_facecolor", None)
+    color = getter() if getter is not None else None
+    if isinstance(color, (tuple, list)) and all(isinstance(c, float) for c in color[:3]):
+        color = tuple(round(c * 255.0) for c in color[:3])
+    return _parse_rgb(color) or (255, 255, 255)
+
+
+def _parse_rgb(value, background=None):
+    """Parse ``#rrggbb[aa]``, ``white``/``black`` or an RGB tuple. Alpha is
+    blended over ``background`` when one is given."""
+    if isinstance(value, str):
+        text = value.strip().lower()
+        if text == "white":
+            return (255, 255, 255)
+        if text == "black":
+            return (0, 0, 0)
+        if not text.startswith("#") or len(text) not in (7, 9):
+            return None
+        try:
+            channels = [int(text[i : i + 2], 16) for i in range(1, len(text), 2)]
+        except ValueError:
+            return None
+        rgb = tuple(channels[:3])
+        if len(channels) == 4 and background is not None:
+            alpha = channels[3] / 255.0
+            rgb = tuple(
+                round(c * alpha + b * (1.0 - alpha)) for c, b in zip(rgb, background)
+            )
+        return rgb
+    if isinstance(value, (tuple, list)) and len(value) >= 3:
+        try:
+            return tuple(max(0, min(255, int(c))) for c in value[:3])
+        except (TypeError, ValueError):
+            return None
+    return None
+
+
+def _contrast_hex(background) -> str:
+    r, g, b = background
+    luminance = 0.299 * r + 0.587 * g + 0.114 * b
+    return "#000000" if luminance >= 128 else "#ffffff"
 
 
 def _require_matplotlib():
@@ -309,7 +624,7 @@ def _resolve_layout(target: Any):
     raise TypeError("plot() expects a path, Document, or Layout")
 
 
-def _resolve_dwg_color(dxf):
+def _resolve_dwg_color(dxf, palette: AciPalette | None = None, foreground: str = "#000000"):
     true_color = dxf.get("resolved_true_color")
     if true_color is None:
         true_color = dxf.get("true_color")
@@ -326,13 +641,96 @@ def _resolve_dwg_color(dxf):
         except Exception:
             aci = None
         if aci is not None and aci not in (0, 256, 257):
-            mapped = _aci_to_hex(aci)
+            mapped = _aci_to_hex(aci, palette, foreground)
             if mapped is not None:
                 return mapped
 
     return None
 
 
+def _apply_dwg_transparency(color: str, dxf) -> str:
+    transparency = dxf.get("resolved_transparency")
+    if transparency is None:
+        return color
+    try:
+        raw = int(transparency)
+    except Exception:
+        return color
+    if (raw >> 24) != 0x02 or len(color) != 7:
+        return color
+    alpha = raw & 0xFF
+    if alpha >= 0xFF:
+        return color
+    return f"{color}{alpha:02x}"
+
+
+def _resolve_dwg_line_width(dxf, default: float) -> float:
+    lineweight = dxf.get("lineweight")
+    try:
+        value = int(lineweight) if lineweight is not None else -1
+    except Exception:
+        return default
+    # Negative values are BYLAYER/BYBLOCK/DEFAULT; keep the caller's width.
+    if value < 0:
+        return default
+    return max(value / 100.0 * _POINTS_PER_MM, _MIN_LINE_WIDTH_POINTS)
+
+
+def _resolve_dwg_dashes(dxf, ltscale: float, celtscale: float):
+    pattern = dxf.get("linetype_pattern")
+    if not pattern:
+        return None
+    entity_scale = dxf.get("ltype_scale")
+    try:
+        scale = float(ltscale) * float(celtscale if entity_scale is None else entity_scale)
+    except Exception:
+        return None
+    if scale <= 0.0:
+        return None
+
+    # Merge consecutive dashes/gaps into an on/off sequence starting "on".
+    runs = []
+    for length in pattern:
+        on = length >= 0.0
+        value = abs(float(length)) * scale
+        if runs and runs[-1][0] == on:
+            runs[-1][1] += value
+        else:
+            runs.append([on, value])
+    if len(runs) < 2:
+        return None
+    if not runs[0][0]:
+        runs.append(runs.pop(0))
+        if runs[-1][0] == runs[-2][0]:
+            runs[-2][1] += runs.pop()[1]
+    if len(runs) % 2 == 1:
+        runs[0][1] += runs.pop()[1]
+    return [value for _, value in runs]
+
+
+def _apply_dwg_dashes(ax, dashed_lines) -> None:
+    # Dash lengths are in drawing units; convert them to points once the
+    # limits are final.
+    if not dashed_lines:
+        return
+    import matplotlib
+
+    ax.apply_aspect()
+    x0, _ = ax.transData.transform((0.0, 0.0))
+    x1, _ = ax.transData.transform((1.0, 0.0))
+    points_per_unit = abs(x1 - x0) * 72.0 / ax.figure.dpi
+    if points_per_unit <= 0.0:
+        return
+    scale_dashes = bool(matplotlib.rcParams.get("lines.scale_dashes", True))
+    for lines, dashes in dashed_lines:
+        for line in lines:
+            divisor = max(line.get_linewidth(), _MIN_LINE_WIDTH_POINTS) if scale_dashes else 1.0
+            sequence = [
+                max(value * points_per_unit, _MIN_DASH_POINTS) / divisor for value in dashes
+            ]
+            line.set_linestyle((0.0, sequence))
+
+
 def _true_color_to_hex(value):
     if value is None:
         return None
@@ -343,48 +741,21 @@ def _true_color_to_hex(value):
     return f"#{(raw >> 16) & 0xFF:02x}{(raw >> 8) & 0xFF:02x}{raw & 0xFF:02x}"
 
 
-def _aci_to_hex(index: int):
+def _aci_to_hex(index: int, palette: AciPalette | None = None, foreground: str = "#000000"):
+    from .raw import aci_to_rgb
+
     if index <= 0:
         return None
-    base = {
-        1: (255, 0, 0),
-        2: (255, 255, 0),
-        3: (0, 255, 0),
-        4: (0, 255, 255),
-        5: (0, 0, 255),
-        6: (255, 0, 255),
-        # ACI 7 is white/black depending on background. Use black for
-        # matplotlib's default light background so geometry stays visible.
-        7: (0, 0, 0),
-        8: (128, 128, 128),
-        9: (192, 192, 192),
-    }
-    rgb = base.get(index)
-    if rgb is None:
-        rgb = _aci_approx_rgb(index)
+    if index == 7 and (palette is None or palette.background_aware):
+        # ACI 7 is white/black depending on background. Callers pass the
+        # color contrasting with theirs; black suits matplotlib's default.
+        return foreground
+    rgb = aci_to_rgb(index) if palette is None else palette.rgb(index)
     if rgb is None:
         return None
     return f"#{rgb[0]:02x}{rgb[1]:02x}{rgb[2]:02x}"
 
 
-def _aci_approx_rgb(index: int):
-    import colorsys
-
-    if 10 <= index <= 249:
-        step = index - 10
-        hue = (step % 24) / 24.0
-        band = step // 24
-        sat = 1.0 if band < 5 else 0.7
-        val = max(0.28, 1.0 - band * 0.08)
-        r, g, b = colorsys.hsv_to_rgb(hue, sat, val)
-        return (int(round(r * 255)), int(round(g * 255)), int(round(b * 255)))
-    if 250 <= index <= 255:
-        t = (index - 250) / 5.0
-        gray = int(round(255 * t))
-        return (gray, gray, gray)
-    return None
-
-
 def _draw_line(ax, start, end, line_width: float, color=None):
     ax.plot([start[0], end[0]], [start[1], end[1]], linewidth=line_width, color=color)
 
@@ -424,6 +795,30 @@ def _draw_point(ax, location, line_width: float, color=None):
     ax.plot([location[0]], [location[1]], marker="o", markersize=size, linewidth=0, color=color)
 
 
+def _draw_hatch_fill(ax, dxf, line_width: float, color=None):
+    fill = fill_hatch(dxf)
+    if fill.polygons and hasattr(ax, "add_patch"):
+        from matplotlib.patches import PathPatch
+        from matplotlib.path import Path
+
+        vertices = []
+        codes = []
+        for ring in fill.polygons:
+            vertices.extend(ring + ring[:1])
+            codes.extend([Path.MOVETO] + [Path.LINETO] * (len(ring) - 1) + [Path.CLOSEPOLY])
+        ax.add_patch(PathPatch(Path(vertices, codes), facecolor=color, edgecolor="none"))
+    if not fill.lines:
+        return
+    if hasattr(ax, "add_collection"):
+        # A single collection keeps dense patterns cheap to draw.
+        from matplotlib.collections import LineCollection
+
+        ax.add_collection(LineCollection(fill.lines, linewidths=line_width, colors=color))
+        return
+    for start, end in fill.lines:
+        ax.plot([start[0], end[0]], [start[1], end[1]], linewidth=line_width, color=color)
+
+
 def _draw_polyline(
     ax,
     points,
@@ -521,17 +916,23 @@ def _draw_polyline_pface(
         if not isinstance(face, (tuple, list)) or not face:
             continue
         face_points = []
+        visible_edges = []
         for raw_index in face:
             try:
-                index = abs(int(raw_index))
+                index = int(raw_index)
             except Exception:
                 continue
-            if index <= 0:
+            if index == 0:
                 continue
-            idx0 = index - 1
+            idx0 = abs(index) - 1
             if 0 <= idx0 < len(vertex_points):
                 face_points.append(vertex_points[idx0])
-        if len(face_points) >= 2:
+                # A negative index hides the edge starting at that vertex.
+                visible_edges.append(index > 0)
+        if len(face_points) < 2:
+            continue
+        face_drawn = True
+        if all(visible_edges):
             _draw_polyline(
                 ax,
                 face_points,
@@ -540,7 +941,11 @@ def _draw_polyline_pface(
                 closed=True,
                 arc_segments=arc_segments,
             )
-            face_drawn = True
+            continue
+        for edge, visible in enumerate(visible_edges):
+            end = face_points[(edge + 1) % len(face_points)]
+            if visible and face_points[edge] != end:
+                _draw_line(ax, face_points[edge], end, line_width, color=color)
 
     if not face_drawn:
         _draw_polyline(
@@ -725,14 +1130,17 @@ def _draw_text(
     rotation_deg: float,
     color=None,
     background=None,
+    font: ResolvedFont | None = None,
 ):
     if not text:
         return
     text = text.replace("\\P", "\n")
-    size = max(6.0, abs(height) * 3.0)
+    size = max(_MIN_TEXT_POINTS, abs(height) * _TEXT_POINTS_PER_UNIT)
     kwargs = {}
     if background is not None:
         kwargs["bbox"] = background
+    if font is not None:
+        kwargs.update(font.matplotlib_kwargs())
     ax.text(
         insert[0],
         insert[1],
@@ -790,7 +1198,14 @@ def _resolve_mtext_background_alpha(value):
     return max(0.0, min(1.0, 1.0 - (alpha_code / 255.0)))
 
 
-def _draw_dimension(ax, dxf, line_width: float, color=None):
+def _draw_dimension(ax, dxf, line_width: float, color=None, style=None):
+    if style is not None:
+        # Regenerate from the definition points and DIMSTYLE; the anonymous
+        # block may be missing or drawn for older definition points.
+        graphics = regenerate_dimension(dxf, style)
+        if graphics is not None:
+            _draw_dimension_graphics(ax, graphics, line_width, color=color)
+            return
     dimtype = str(_dimension_value(dxf, "dimtype", "LINEAR")).upper()
     p13 = _safe_point(dxf.get("defpoint2"))
     p14 = _safe_point(dxf.get("defpoint3"))
@@ -881,6 +1296,18 @@ def _draw_dimension(ax, dxf, line_width: float, color=None):
         _draw_text(ax, text_pos, text, height, rotation, color=color)
 
 
+def _draw_dimension_graphics(ax, graphics: DimensionGraphics, line_width: float, color=None):
+    ext_w = max(0.5, line_width * 0.8)
+    for points in graphics.lines:
+        ax.plot([p[0] for p in points], [p[1] for p in points], linewidth=ext_w, color=color)
+    for points in graphics.arrowheads:
+        closed = points + points[:1]
+        ax.plot([p[0] for p in closed], [p[1] for p in closed], linewidth=line_width, color=color)
+    text = graphics.text
+    if text is not None:
+        _draw_text(ax, text.insert, text.text, text.height, text.rotation, color=color)
+
+
 def _draw_dimension_diameter(ax, dxf, p13, p14, text_mid, line_width: float, color=None):
     axis = _normalize2((p14[0] - p13[0], p14[1] - p13[1]))
     if axis is None:
@@ -1026,10 +1453,28 @@ def _collect_axes_points(ax):
         except Exception:
             continue
         if math.isfinite(xf) and math.isfinite(yf):
-            points.append((xf, yf))
+            points.extend(_text_artist_corners(text, xf, yf))
     return points
 
 
+def _text_artist_corners(text, x: float, y: float):
+    """Corners of the estimated box of a text drawn by ``_draw_text``, so
+    auto-fit keeps whole labels in view rather than their insertion points."""
+    import math
+
+    try:
+        height = float(text.get_fontsize()) / _TEXT_POINTS_PER_UNIT
+        angle = math.radians(float(text.get_rotation()))
+    except Exception:
+        return [(x, y)]
+    min_x, min_y, max_x, max_y = estimate_text_extents(text.get_text(), None, height).box()
+    c, s = math.cos(angle), math.sin(angle)
+    return [
+        (x + px * c - py * s, y + px * s + py * c)
+        for px, py in ((min_x, min_y), (max_x, min_y), (max_x, max_y), (min_x, max_y))
+    ]
+
+
 def _bounds_from_xy(xs, ys):
     if not xs or not ys:
         return None
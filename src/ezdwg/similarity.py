@@ -0,0 +1,156 @@
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass
+from typing import Iterable
+
+from .document import Document, Layout
+from .entity import Entity
+from .hatch_fill import _loop_depths, _signed_area, _xy
+from .identity import _entities_and_guids, _match, _signature
+from .overlay import _strokes
+
+# Entity types weighted by the area they cover rather than their outline.
+_AREA_TYPES = frozenset({"SOLID", "TRACE", "3DFACE", "HATCH"})
+
+
+@dataclass(frozen=True)
+class DrawingSimilarity:
+    """How much of the geometry of two drawings is identical.
+
+    Lengths and areas are summed over both drawings, so identical drawings
+    have ``matched_length == total_length``.
+    """
+
+    score: float
+    matched_length: float
+    total_length: float
+    matched_area: float
+    total_area: float
+    matched: dict[int, int]
+
+    @property
+    def length_score(self) -> float | None:
+        if self.total_length <= 0.0:
+            return None
+        return self.matched_length / self.total_length
+
+    @property
+    def area_score(self) -> float | None:
+        if self.total_area <= 0.0:
+            return None
+        return self.matched_area / self.total_area
+
+
+def drawing_similarity(
+    a: str | Document | Layout | Iterable[Entity],
+    b: str | Document | Layout | Iterable[Entity],
+    *,
+    types: str | Iterable[str] | None = None,
+    precision: int = 6,
+    arc_segments: int = 64,
+    match: str = "identity",
+) -> DrawingSimilarity:
+    """Score from 0 to 1 for the share of geometry two drawings have in common.
+
+    Entities are paired as in ``overlay_diff`` and a pair counts as matched
+    when its type and ``dxf`` values agree to ``precision`` decimal places.
+    SOLID, TRACE, 3DFACE and HATCH entities are weighted by their area and
+    the others by their drawn length. ``score`` is the mean of the matched
+    length and matched area shares, leaving out a measure neither drawing
+    has; two drawings without measurable geometry score 1 when every entity
+    matched.
+    """
+    if match not in {"handle", "identity"}:
+        raise ValueError(f"match must be 'handle' or 'identity', got {match!r}")
+    entities_a, guids_a = _entities_and_guids(a, types, guids=match == "identity")
+    entities_b, guids_b = _entities_and_guids(b, types, guids=match == "identity")
+    if match == "identity":
+        matched = _match(entities_a, guids_a, entities_b, guids_b, precision).pairs
+    else:
+        matched = {handle: handle for handle in sorted(entities_a.keys() & entities_b.keys())}
+    unchanged = {
+        handle_a: handle_b
+        for handle_a, handle_b in matched.items()
+        if _signature(entities_a[handle_a], precision)
+        == _signature(entities_b[handle_b], precision)
+    }
+
+    measures_a = {
+        handle: _measure(entity, arc_segments) for handle, entity in entities_a.items()
+    }
+    measures_b = {
+        handle: _measure(entity, arc_segments) for handle, entity in entities_b.items()
+    }
+    total_length = sum(length for length, _area in measures_a.values()) + sum(
+        length for length, _area in measures_b.values()
+    )
+    total_area = sum(area for _length, area in measures_a.values()) + sum(
+        area for _length, area in measures_b.values()
+    )
+    matched_length = sum(
+        measures_a[handle_a][0] + measures_b[handle_b][0]
+        for handle_a, handle_b in unchanged.items()
+    )
+    matched_area = sum(
+        measures_a[handle_a][1] + measures_b[handle_b][1]
+        for handle_a, handle_b in unchanged.items()
+    )
+
+    shares = [
+        matched / total
+        for matched, total in ((matched_length, total_length), (matched_area, total_area))
+        if total > 0.0
+    ]
+    if shares:
+        score = sum(shares) / len(shares)
+    else:
+        count = len(entities_a) + len(entities_b)
+        score = 2.0 * len(unchanged) / count if count else 1.0
+    return DrawingSimilarity(
+        score=min(1.0, score),
+        matched_length=matched_length,
+        total_length=total_length,
+        matched_area=matched_area,
+        total_area=total_area,
+        matched=unchanged,
+    )
+
+
+def _measure(entity: Entity, arc_segments: int) -> tuple[float, float]:
+    """``(length, area)`` of one entity; exactly one of them is used."""
+    if entity.dxftype in _AREA_TYPES:
+        return 0.0, _area(entity)
+    length = 0.0
+    for points in _strokes([entity], "#000000", arc_segments):
+        length += sum(
+            math.hypot(bx - ax, by - ay) for (ax, ay), (bx, by) in zip(points, points[1:])
+        )
+    return length, 0.0
+
+
+def _area(entity: Entity) -> float:
+    if entity.dxftype == "HATCH":
+        loops = []
+        for path in entity.dxf.get("paths", []) or []:
+            points = path.get("points", []) if isinstance(path, dict) else []
+            loop = [_xy(point) for point in points]
+            if len(loop) > 1 and loop[0] == loop[-1]:
+                loop.pop()
+            if len(loop) >= 3:
+                loops.append(loop)
+        # Islands nested at odd depths are holes in the hatch.
+        return max(
+            0.0,
+            sum(
+                abs(_signed_area(loop)) * (-1.0 if depth % 2 else 1.0)
+                for loop, depth in zip(loops, _loop_depths(loops))
+            ),
+        )
+    points = [_xy(point) for point in entity.dxf.get("points", []) or []]
+    if entity.dxftype in {"SOLID", "TRACE"} and len(points) == 4:
+        # The third and fourth corners of SOLID and TRACE are stored swapped.
+        points = [points[0], points[1], points[3], points[2]]
+    if len(points) < 3:
+        return 0.0
+    return abs(_signed_area(points))
@@ -0,0 +1,115 @@
+from __future__ import annotations
+
+import math
+from typing import TYPE_CHECKING, Any, Sequence
+
+if TYPE_CHECKING:
+    from .entity import Entity
+
+SIMPLIFY_TYPES = ("LWPOLYLINE", "POLYLINE_2D", "POLYLINE_3D")
+
+# Per-vertex POLYLINE_2D lists kept in step with the simplified points.
+_VERTEX_KEYS = ("tangent_dirs", "vertex_flags", "vertex_handles")
+
+
+def simplify_points(points: Sequence[Sequence[float]], tolerance: float) -> list[Any]:
+    """Douglas-Peucker: drop points within ``tolerance`` of the simplified line.
+
+    Points may be 2D or 3D; both ends are always kept. Use this for curves
+    tessellated into point lists, such as ``Entity.to_points()`` output.
+    """
+    return [points[index] for index in _douglas_peucker(points, tolerance)]
+
+
+def simplify_entity(entity: Entity, tolerance: float) -> Entity:
+    from .entity import Entity
+
+    if entity.dxftype not in SIMPLIFY_TYPES:
+        raise NotImplementedError(f"simplify is not supported for {entity.dxftype}")
+    dxf = entity.dxf
+    points = list(dxf.get("points") or [])
+    closed = bool(dxf.get("closed")) and len(points) > 2
+    bulges = list(dxf.get("bulges") or [])
+    widths = list(dxf.get("widths") or [])
+    kept = _polyline_kept(points, bulges, closed, tolerance)
+    if len(kept) == len(points):
+        return entity
+
+    out = {**dxf, "points": [points[index] for index in kept]}
+    if bulges:
+        out["bulges"] = [bulges[index] for index in kept if index < len(bulges)]
+    if widths:
+        segments = len(points) if closed else len(points) - 1
+        out["widths"] = []
+        for position, index in enumerate(kept):
+            if index >= len(widths):
+                break
+            following = kept[position + 1] if position + 1 < len(kept) else segments
+            last = following - 1
+            end_width = widths[last][1] if 0 <= last < len(widths) else widths[index][1]
+            out["widths"].append((widths[index][0], end_width))
+    for key in _VERTEX_KEYS:
+        values = dxf.get(key)
+        if isinstance(values, list) and len(values) == len(points):
+            out[key] = [values[index] for index in kept]
+    interpolated = dxf.get("interpolated_points")
+    if interpolated:
+        out["interpolated_points"] = simplify_points(interpolated, tolerance)
+    return Entity(entity.dxftype, entity.handle, out)
+
+
+def _polyline_kept(
+    points: list[Any], bulges: list[float], closed: bool, tolerance: float
+) -> list[int]:
+    outline = points + points[:1] if closed else points
+    segments = len(outline) - 1
+    if segments < 2:
+        return list(range(len(points)))
+    anchors = {0, segments}
+    for index in range(segments):
+        if index < len(bulges) and bulges[index]:
+            anchors.update((index, index + 1))
+    ordered = sorted(anchors)
+    kept: list[int] = []
+    for first, last in zip(ordered, ordered[1:]):
+        run = outline[first : last + 1]
+        kept.extend(first + index for index in _douglas_peucker(run, tolerance)[:-1])
+    if not closed:
+        kept.append(segments)
+    return kept
+
+
+def _douglas_peucker(points: Sequence[Sequence[float]], tolerance: float) -> list[int]:
+    count = len(points)
+    tolerance = float(tolerance)
+    if count <= 2 or math.isnan(tolerance):
+        return list(range(count))
+    keep = [False] * count
+    runs = [(0, count - 1)]
+    while runs:
+        first, last = runs.pop()
+        keep[first] = keep[last] = True
+        start, end = points[first], points[last]
+        farthest = None
+        max_distance = tolerance
+        for index in range(first + 1, last):
+            distance = _segment_distance(points[index], start, end)
+            if distance > max_distance:
+                max_distance = distance
+                farthest = index
+        if farthest is not None:
+            runs.append((first, farthest))
+            runs.append((farthest, last))
+    return [index for index, kept in enumerate(keep) if kept]
+
+
+def _segment_distance(
+    point: Sequence[float], start: Sequence[float], end: Sequence[float]
+) -> float:
+    delta = [b - a for a, b in zip(start, end)]
+    offset = [p - a for a, p in zip(start, point)]
+    length_sq = sum(value * value for value in delta)
+    t = 0.0
+    if length_sq > 0.0:
+        t = min(1.0, max(0.0, sum(d * o for d, o in zip(delta, offset)) / length_sq))
+    return math.sqrt(sum((o - t * d) ** 2 for d, o in zip(delta, offset)))
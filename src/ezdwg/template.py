@@ -0,0 +1,162 @@
+from __future__ import annotations
+
+from concurrent.futures import ThreadPoolExecutor
+from pathlib import Path
+from typing import Any, Iterable, Mapping
+
+from . import raw
+from .convert import (
+    WriteResult,
+    _ac1015_source,
+    _collect_dwg_write_rows,
+    _dwg_row_handles,
+    _dwg_write_color_rows,
+    _dwg_write_layer_rows,
+    _dwg_write_row_args,
+    _dwg_write_row_kwargs,
+    _dwg_write_style_rows,
+    _resolve_dwg_export_entities,
+    _resolve_layout,
+    _validate_layer_name,
+)
+from .document import Document, Layout
+from .entity import Entity
+
+
+class DwgTemplate:
+    """A drawing read once and written to many AC1015 outputs, each with its
+    own entities added.
+
+    The rows of the template entities, its layers and styles are built when
+    the template is created and shared by every output, and the records and
+    header variables copied from the source file are parsed once. Writing
+    releases the GIL, so ``write_many`` stamps outputs in parallel.
+    """
+
+    def __init__(
+        self,
+        source: str | Document | Layout,
+        *,
+        types: str | Iterable[str] | None = None,
+        precision: int | None = None,
+        preserve_unknown_objects: bool = True,
+        preserve_header_variables: bool = True,
+        application_name: str | None = None,
+        application_version: str | None = None,
+    ) -> None:
+        self.source_path, layout = _resolve_layout(source)
+        decode_path = layout.doc.decode_path
+        entities = _resolve_dwg_export_entities(layout, types)
+        self._precision = precision
+        self._rows, self._total, self._written, self._skipped_by_type = (
+            _collect_dwg_write_rows(entities, precision)
+        )
+        (
+            self._layers,
+            self._entity_layers,
+            self._layer_linetypes,
+            self._layer_states,
+        ) = _dwg_write_layer_rows(
+            decode_path,
+            entities,
+            self._rows,
+            layer_renames={},
+            layer_colors={},
+            entity_layers={},
+        )
+        self._styles, self._linetypes = _dwg_write_style_rows(
+            decode_path,
+            self._rows,
+            entity_linetypes={},
+            entity_lineweights={},
+            layer_linetypes=[linetype for _name, linetype in self._layer_linetypes],
+        )
+        self._colors = _dwg_write_color_rows(entities, self._rows, {})
+        source_file = _ac1015_source(decode_path)
+        self._unknown_objects_from = source_file if preserve_unknown_objects else None
+        self._header_from = source_file if preserve_header_variables else None
+        self._application = (application_name, application_version)
+        # Added entities are numbered above every handle of the source, so
+        # they never take the handle of a copied record.
+        used = list(_dwg_row_handles(self._rows))
+        if source_file is not None:
+            used.extend(int(row[0]) for row in raw.list_object_map_entries(source_file))
+        self._first_handle = max(used, default=0xFF) + 1
+
+    def write(self, output_path: str, entities: Iterable[Entity] = ()) -> WriteResult:
+        """Write the template plus ``entities`` to ``output_path``.
+
+        Added entities get new handles. Their layer is the name in
+        ``dxf["layer"]``, created with color 7 when the template lacks it,
+        or layer 0 without one.
+        """
+        added: list[Entity] = []
+        added_layers: dict[int, str] = {}
+        for handle, entity in enumerate(entities, start=self._first_handle):
+            added.append(Entity(entity.dxftype, handle, dict(entity.dxf)))
+            layer = entity.dxf.get("layer")
+            if isinstance(layer, str) and layer:
+                added_layers[handle] = layer
+        rows, total, written, skipped_by_type = _collect_dwg_write_rows(added, self._precision)
+        for dxftype, type_rows in self._rows.items():
+            rows[dxftype] = [*type_rows, *rows[dxftype]]
+        for dxftype, count in self._skipped_by_type.items():
+            skipped_by_type[dxftype] = skipped_by_type.get(dxftype, 0) + count
+        total += self._total
+        written += self._written
+
+        layers = list(self._layers)
+        known = {name.upper(): name for name, _color in layers}
+        entity_layers = list(self._entity_layers)
+        for handle, name in added_layers.items():
+            if name.upper() not in known:
+                _validate_layer_name(name)
+                known[name.upper()] = name
+                layers.append((name, 7))
+            entity_layers.append((handle, known[name.upper()]))
+
+        out_path = Path(output_path)
+        out_path.parent.mkdir(parents=True, exist_ok=True)
+        application_name, application_version = self._application
+        raw.write_ac1015_dwg(
+            str(out_path),
+            *_dwg_write_row_args(rows),
+            application_name=application_name,
+            application_version=application_version,
+            styles=self._styles,
+            linetypes=self._linetypes,
+            unknown_objects_from=self._unknown_objects_from,
+            header_from=self._header_from,
+            layers=layers,
+            layer_linetypes=self._layer_linetypes,
+            layer_states=self._layer_states,
+            entity_layers=entity_layers,
+            entity_colors=[*self._colors, *_dwg_write_color_rows(added, rows, {})],
+            **_dwg_write_row_kwargs(rows),
+        )
+        return WriteResult(
+            source_path=self.source_path,
+            output_path=str(out_path),
+            target_version="AC1015",
+            total_entities=total,
+            written_entities=written,
+            skipped_entities=total - written,
+            skipped_by_type=dict(sorted(skipped_by_type.items())),
+        )
+
+    def write_many(
+        self,
+        outputs: Mapping[str, Iterable[Entity]],
+        *,
+        threads: int | None = None,
+    ) -> list[WriteResult]:
+        """Write one output per ``outputs`` entry on up to ``threads``
+        threads, in the order of ``outputs``. The output paths must differ."""
+        if threads is not None and threads < 1:
+            raise ValueError("threads must be at least 1")
+        paths = [str(Path(path).resolve()) for path in outputs]
+        if len(set(paths)) != len(paths):
+            raise ValueError("output paths must be distinct")
+        jobs: list[tuple[str, Any]] = [(path, list(entities)) for path, entities in outputs.items()]
+        with ThreadPoolExecutor(max_workers=threads) as pool:
+            return list(pool.map(lambda job: self.write(*job), jobs))
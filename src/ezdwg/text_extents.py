@@ -0,0 +1,319 @@
+from __future__ import annotations
+
+import math
+import re
+import unicodedata
+from dataclasses import dataclass
+from typing import Iterable, Mapping
+
+from .entity import Entity
+from .fonts import FontMap, normalize_font_name
+
+TEXT_TYPES = ("TEXT", "ATTRIB", "ATTDEF", "MTEXT")
+
+# Glyph advances in units of the text height (cap height). The SHX table is
+# the Hershey simplex font that romans/simplex derive from (cap height 21);
+# the TrueType table is Arial (units per em 1000, cap height 716).
+_SHX_ADVANCES = dict(
+    zip(
+        " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`"
+        "abcdefghijklmnopqrstuvwxyz{|}~",
+        (
+            16, 10, 16, 21, 20, 24, 26, 10, 14, 14, 16, 26, 10, 26, 10, 22,
+            20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 10, 10, 24, 26, 24, 18, 27,
+            18, 21, 21, 21, 19, 18, 21, 22, 8, 16, 21, 17, 24, 22, 22, 21, 22,
+            21, 20, 16, 22, 18, 24, 20, 18, 20, 14, 14, 14, 16, 16, 10,
+            19, 19, 18, 19, 18, 12, 19, 19, 8, 10, 17, 8, 30, 19, 19, 19, 19,
+            13, 17, 12, 19, 16, 22, 17, 16, 17, 14, 8, 14, 24,
+        ),
+    )
+)
+_SHX_CAP_HEIGHT = 21.0
+_TTF_ADVANCES = dict(
+    zip(
+        " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`"
+        "abcdefghijklmnopqrstuvwxyz{|}~",
+        (
+            278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333,
+            278, 278, 556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278,
+            584, 584, 584, 556, 1015, 667, 667, 722, 722, 667, 611, 778, 722, 278,
+            500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944,
+            667, 667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556,
+            278, 556, 556, 222, 222, 500, 222, 833, 556, 556, 556, 556, 333, 500,
+            278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+        ),
+    )
+)
+_TTF_CAP_HEIGHT = 716.0
+# txt.shx and monotxt.shx glyphs sit on a 6x6 grid with a fixed advance.
+_FIXED_PITCH_FONTS = {"", "txt.shx", "monotxt.shx"}
+_DESCENT = 1.0 / 3.0
+_DESCENDERS = set("gjpqy,;()[]{}|")
+# MTEXT baselines are 5/3 of the text height apart at line spacing 1.0.
+_MTEXT_LINE_PITCH = 5.0 / 3.0
+_CONTROL_CODE = re.compile(r"%%(\d{3}|[cdpuokCDPUOK%])")
+
+Point = tuple[float, float]
+Bounds = tuple[float, float, float, float]
+
+
+@dataclass(frozen=True)
+class TextExtents:
+    """Estimated size of a string in drawing units.
+
+    The box runs from the start of the first baseline: ``width`` to the
+    right, ``ascent`` above it and ``descent`` below it, where ``descent``
+    also covers the lines after the first.
+    """
+
+    width: float
+    ascent: float
+    descent: float
+    line_count: int
+
+    @property
+    def height(self) -> float:
+        return self.ascent + self.descent
+
+    def box(self) -> Bounds:
+        """``(min_x, min_y, max_x, max_y)`` relative to the insertion point."""
+        return (0.0, -self.descent, self.width, self.ascent)
+
+
+def estimate_text_extents(
+    text: str,
+    style: str | None = None,
+    height: float = 1.0,
+    width_factor: float = 1.0,
+    *,
+    font_map: FontMap | None = None,
+    line_spacing: float = 1.0,
+) -> TextExtents:
+    """Estimate the extents of ``text`` drawn with the font file ``style``.
+
+    ``style`` is the font of the text style, such as ``romans.shx`` or
+    ``arial.ttf``; ``None`` uses the metrics of ``txt.shx``. Advances come
+    from a per-glyph table of the SHX simplex or Arial fonts, scaled by
+    ``width_factor`` and by the ``width_scale`` ``font_map`` gives the font.
+    ``%%`` control codes are expanded and ``\\n`` starts a new line, spaced
+    like MTEXT lines.
+    """
+    height = abs(float(height))
+    scale = height * float(width_factor)
+    font = normalize_font_name(style or "")
+    if font_map is not None:
+        scale *= font_map.resolve(style).width_scale
+    lines = _expand_control_codes(str(text)).split("\n")
+    width = max(_line_advance(line, font) for line in lines) * scale
+    descent = height * _DESCENT if _DESCENDERS.intersection(lines[-1]) else 0.0
+    pitch = height * _MTEXT_LINE_PITCH * float(line_spacing)
+    return TextExtents(width, height, descent + pitch * (len(lines) - 1), len(lines))
+
+
+def text_outline(
+    entity: Entity,
+    *,
+    style: str | Mapping[int, str] | None = None,
+    font_map: FontMap | None = None,
+) -> list[Point] | None:
+    """Corners of the estimated text box of a TEXT, ATTRIB, ATTDEF or MTEXT
+    entity in world XY, counter-clockwise from the bottom left.
+
+    ``style`` is one font file for every entity or the ``{style_handle:
+    font}`` mapping of ``fonts.text_style_fonts``; MTEXT, which carries no
+    style handle, uses the ``None`` entry. Returns ``None`` for other entity
+    types and empty text.
+    """
+    dxf = entity.dxf
+    if entity.dxftype == "MTEXT":
+        return _mtext_outline(dxf, _style_font(style, None), font_map)
+    if entity.dxftype in TEXT_TYPES:
+        return _text_outline(dxf, _style_font(style, dxf.get("style_handle")), font_map)
+    return None
+
+
+def text_bounds(
+    entity: Entity,
+    *,
+    style: str | Mapping[int, str] | None = None,
+    font_map: FontMap | None = None,
+) -> Bounds | None:
+    """World ``(min_x, min_y, max_x, max_y)`` of ``text_outline``."""
+    corners = text_outline(entity, style=style, font_map=font_map)
+    if corners is None:
+        return None
+    xs = [x for x, _ in corners]
+    ys = [y for _, y in corners]
+    return (min(xs), min(ys), max(xs), max(ys))
+
+
+def hit_test_text(
+    entities: Iterable[Entity],
+    point: Iterable[float],
+    *,
+    tolerance: float = 0.0,
+    style: str | Mapping[int, str] | None = None,
+    font_map: FontMap | None = None,
+) -> list[Entity]:
+    """Text entities whose estimated box contains ``point`` or lies within
+    ``tolerance`` of it."""
+    x, y = (float(value) for value in list(point)[:2])
+    hits = []
+    for entity in entities:
+        corners = text_outline(entity, style=style, font_map=font_map)
+        if corners is not None and _near_polygon(corners, (x, y), float(tolerance)):
+            hits.append(entity)
+    return hits
+
+
+def _expand_control_codes(text: str) -> str:
+    def expand(match: re.Match[str]) -> str:
+        code = match.group(1)
+        if code.isdigit():
+            return chr(int(code))
+        return {"c": "⌀", "d": "°", "p": "±", "%": "%"}.get(code.lower(), "")
+
+    return _CONTROL_CODE.sub(expand, text)
+
+
+def _line_advance(line: str, font: str) -> float:
+    if font in _FIXED_PITCH_FONTS:
+        return float(len(line))
+    if font.endswith((".ttf", ".otf", ".ttc")):
+        table, unit, wide = _TTF_ADVANCES, _TTF_CAP_HEIGHT, 1000.0
+    else:
+        table, unit, wide = _SHX_ADVANCES, _SHX_CAP_HEIGHT, _SHX_CAP_HEIGHT
+    total = 0.0
+    for ch in line:
+        if _is_wide(ch):
+            total += wide
+            continue
+        advance = table.get(ch)
+        if advance is None:
+            base = unicodedata.normalize("NFKD", ch)[:1]
+            advance = table.get(base, table["n"])
+        total += advance
+    return total / unit
+
+
+def _is_wide(ch: str) -> bool:
+    return unicodedata.east_asian_width(ch) in ("W", "F")
+
+
+def _style_font(style: str | Mapping[int | None, str] | None, handle) -> str | None:
+    if style is None or isinstance(style, str):
+        return style
+    return style.get(handle)
+
+
+def _text_outline(dxf: dict, font: str | None, font_map: FontMap | None) -> list[Point] | None:
+    text = str(dxf.get("text") or "")
+    if not text:
+        return None
+    insert = _xy(dxf.get("insert"))
+    align_point = dxf.get("align_point")
+    height = abs(float(dxf.get("height") or 0.0))
+    rotation = math.radians(float(dxf.get("rotation") or 0.0))
+    halign = int(dxf.get("halign") or 0)
+    valign = int(dxf.get("valign") or 0)
+    extents = estimate_text_extents(
+        text, font, height, float(dxf.get("width") or 1.0), font_map=font_map
+    )
+    width, ascent, descent = extents.width, extents.ascent, extents.descent
+    origin = insert
+    x0 = y0 = 0.0
+    if halign in (3, 5) and align_point is not None:
+        # Aligned and fit text spans the baseline from insert to align_point.
+        end = _xy(align_point)
+        span = math.hypot(end[0] - insert[0], end[1] - insert[1])
+        if span > 0.0:
+            rotation = math.atan2(end[1] - insert[1], end[0] - insert[0])
+            if halign == 3 and width > 0.0:
+                ascent *= span / width
+                descent *= span / width
+            width = span
+    elif (halign or valign) and align_point is not None:
+        origin = _xy(align_point)
+        x0 = -width * {1: 0.5, 2: 1.0, 4: 0.5}.get(halign, 0.0)
+        if valign == 1:
+            y0 = descent
+        elif valign == 2 or (valign == 0 and halign == 4):
+            y0 = -ascent / 2.0
+        elif valign == 3:
+            y0 = -ascent
+    shear = math.tan(math.radians(float(dxf.get("oblique") or 0.0)))
+    local = [
+        (x0, y0 - descent),
+        (x0 + width, y0 - descent),
+        (x0 + width, y0 + ascent),
+        (x0, y0 + ascent),
+    ]
+    return _place([(x + (y - y0) * shear, y) for x, y in local], origin, rotation)
+
+
+def _mtext_outline(dxf: dict, font: str | None, font_map: FontMap | None) -> list[Point] | None:
+    text = str(dxf.get("text") or "")
+    if not text:
+        return None
+    height = abs(float(dxf.get("char_height") or 0.0))
+    rect_width = float(dxf.get("rect_width") or 0.0)
+    lines = text.split("\n")
+    if rect_width > 0.0:
+        # Lines wider than the frame wrap; count the extra lines they need.
+        wrapped = []
+        for line in lines:
+            advance = estimate_text_extents(line, font, height, font_map=font_map).width
+            wrapped.extend([line] * max(1, math.ceil(advance / rect_width)))
+        lines = wrapped
+    extents = estimate_text_extents("\n".join(lines), font, height, font_map=font_map)
+    width = rect_width if rect_width > 0.0 else extents.width
+    total = extents.height
+    attachment = int(dxf.get("attachment_point") or 1)
+    column, row = (attachment - 1) % 3, min(2, max(0, (attachment - 1) // 3))
+    x0 = -width * column / 2.0
+    top = total * row / 2.0
+    direction = dxf.get("text_direction")
+    if direction is not None and math.hypot(direction[0], direction[1]) > 0.0:
+        rotation = math.atan2(direction[1], direction[0])
+    else:
+        rotation = math.radians(float(dxf.get("rotation") or 0.0))
+    local = [(x0, top - total), (x0 + width, top - total), (x0 + width, top), (x0, top)]
+    return _place(local, _xy(dxf.get("insert")), rotation)
+
+
+def _place(local: list[Point], origin: Point, rotation: float) -> list[Point]:
+    c, s = math.cos(rotation), math.sin(rotation)
+    return [(origin[0] + x * c - y * s, origin[1] + x * s + y * c) for x, y in local]
+
+
+def _near_polygon(corners: list[Point], point: Point, tolerance: float) -> bool:
+    inside = False
+    count = len(corners)
+    for index in range(count):
+        (x1, y1), (x2, y2) = corners[index], corners[(index + 1) % count]
+        if (y1 > point[1]) != (y2 > point[1]):
+            cross_x = x1 + (point[1] - y1) * (x2 - x1) / (y2 - y1)
+            if point[0] < cross_x:
+                inside = not inside
+    if inside:
+        return True
+    return tolerance > 0.0 and any(
+        _segment_distance(point, corners[index], corners[(index + 1) % count]) <= tolerance
+        for index in range(count)
+    )
+
+
+def _segment_distance(point: Point, start: Point, end: Point) -> float:
+    dx, dy = end[0] - start[0], end[1] - start[1]
+    length_sq = dx * dx + dy * dy
+    t = 0.0
+    if length_sq > 0.0:
+        t = ((point[0] - start[0]) * dx + (point[1] - start[1]) * dy) / length_sq
+        t = min(1.0, max(0.0, t))
+    return math.hypot(point[0] - start[0] - t * dx, point[1] - start[1] - t * dy)
+
+
+def _xy(point) -> Point:
+    if point is None:
+        return (0.0, 0.0)
+    return (float(point[0]), float(point[1]))
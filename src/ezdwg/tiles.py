@@ -0,0 +1,312 @@
+from __future__ import annotations
+
+import math
+import os
+from functools import lru_cache
+from typing import Any, Iterable, Mapping
+
+from . import raw
+from .clip import _line_span
+from .colors import AciPalette, as_palette
+from .render import (
+    _StrokeCollector,
+    _contrast_hex,
+    _draw_layout,
+    _parse_rgb,
+    _raster_color_resolver,
+    _resolve_layout,
+)
+
+Window = tuple[float, float, float, float]
+Stroke = tuple[list[tuple[float, float]], tuple[int, int, int], bool]
+
+TILE_FORMATS = ("png", "svg")
+
+# Strokes covering more grid cells than this are kept in a separate list
+# and tested against every query instead of being copied into each cell.
+_MAX_STROKE_CELLS = 64
+_MAX_GRID_SIZE = 1024
+_TILE_CACHE_SIZE = 8
+
+
+class TileIndex:
+    """Modelspace strokes of one drawing bucketed into a uniform grid.
+
+    The drawing is decoded and flattened into strokes once; each tile then
+    only looks at the grid cells it overlaps and clips the strokes found
+    there to its window, so serving a tile costs time proportional to the
+    geometry inside it rather than to the whole drawing. Tiles use the XYZ
+    web map scheme over ``bounds`` (the stroke extents made square by
+    default): zoom ``z`` splits the bounds into ``2**z`` by ``2**z`` tiles,
+    ``x`` counts from the left and ``y`` from the top.
+    """
+
+    def __init__(
+        self,
+        source: Any,
+        types: str | Iterable[str] | None = None,
+        *,
+        bounds: Window | None = None,
+        background: Any = "white",
+        palette: AciPalette | Mapping[int, tuple[int, int, int]] | None = None,
+        arc_segments: int = 64,
+    ) -> None:
+        background_rgb = _parse_rgb(background)
+        if background_rgb is None:
+            raise ValueError(f"unsupported background color: {background!r}")
+        self.background = background_rgb
+        collector = _StrokeCollector(background_rgb)
+        foreground = _contrast_hex(background_rgb)
+        _draw_layout(
+            collector,
+            _resolve_layout(source),
+            types,
+            line_width=1.0,
+            arc_segments=arc_segments,
+            dimension_color=foreground,
+            lineweights=False,
+            linetypes=False,
+            ltscale=1.0,
+            celtscale=1.0,
+            color_resolver=_raster_color_resolver(palette, foreground),
+        )
+        self.strokes: list[Stroke] = []
+        self._boxes: list[Window] = []
+        for stroke in collector.strokes:
+            points = [(x, y) for x, y in stroke[0] if math.isfinite(x) and math.isfinite(y)]
+            if points:
+                self.strokes.append((points, stroke[1], stroke[2]))
+                self._boxes.append(_points_bounds(points))
+        self.bounds = _square(bounds if bounds is not None else _union(self._boxes))
+        self._build_grid()
+
+    def tile_window(self, z: int, x: int, y: int) -> Window:
+        """World window ``(min_x, min_y, max_x, max_y)`` of tile ``(z, x, y)``."""
+        count = 1 << int(z)
+        if not (0 <= x < count and 0 <= y < count):
+            raise ValueError(f"tile ({z}, {x}, {y}) is outside the tile grid")
+        min_x, _min_y, _max_x, max_y = self.bounds
+        size = (self.bounds[2] - min_x) / count
+        return (
+            min_x + x * size,
+            max_y - (y + 1) * size,
+            min_x + (x + 1) * size,
+            max_y - y * size,
+        )
+
+    def query(self, window: Window) -> list[Stroke]:
+        """Strokes clipped to ``window``, in draw order."""
+        window = tuple(float(value) for value in window)
+        candidates = set(self._large)
+        first_col, first_row, last_col, last_row = self._cell_range(window)
+        for row in range(first_row, last_row + 1):
+            for col in range(first_col, last_col + 1):
+                candidates.update(self._cells.get((col, row), ()))
+        out: list[Stroke] = []
+        for index in sorted(candidates):
+            if not _overlaps(self._boxes[index], window):
+                continue
+            points, rgb, closed = self.strokes[index]
+            if closed:
+                points = points + points[:1]
+            out.extend((run, rgb, False) for run in _clip_polyline(points, window))
+        return out
+
+    def render_tile(
+        self,
+        z: int,
+        x: int,
+        y: int,
+        *,
+        format: str = "png",
+        tile_size: int = 256,
+        line_width: int = 1,
+    ) -> bytes:
+        """Render tile ``(z, x, y)`` as a ``tile_size`` pixel square image."""
+        return self.render_window(
+            self.tile_window(z, x, y),
+            tile_size,
+            tile_size,
+            format=format,
+            line_width=line_width,
+        )
+
+    def render_window(
+        self,
+        window: Window,
+        width: int,
+        height: int,
+        *,
+        format: str = "png",
+        line_width: int = 1,
+    ) -> bytes:
+        """Render the strokes inside a world window into a PNG or SVG image."""
+        window = tuple(float(value) for value in window)
+        if window[2] <= window[0] or window[3] <= window[1]:
+            raise ValueError("window must be (min_x, min_y, max_x, max_y) with a positive size")
+        strokes = self.query(window)
+        if format == "svg":
+            return _strokes_to_svg(strokes, window, width, height, self.background, line_width)
+        if format != "png":
+            raise ValueError(f"unsupported tile format: {format!r}; expected one of {TILE_FORMATS}")
+        encoder = getattr(raw, "render_strokes_png", None)
+        if encoder is None:
+            raise RuntimeError("this ezdwg build was compiled without the `raster` feature")
+        return encoder(strokes, width, height, window, self.background, line_width, 0.0)
+
+    def _build_grid(self) -> None:
+        size = int(math.ceil(math.sqrt(len(self.strokes) / 4.0))) if self.strokes else 1
+        self._grid_size = max(1, min(_MAX_GRID_SIZE, size))
+        self._cells: dict[tuple[int, int], list[int]] = {}
+        self._large: list[int] = []
+        for index, box in enumerate(self._boxes):
+            first_col, first_row, last_col, last_row = self._cell_range(box)
+            if (last_col - first_col + 1) * (last_row - first_row + 1) > _MAX_STROKE_CELLS:
+                self._large.append(index)
+                continue
+            for row in range(first_row, last_row + 1):
+                for col in range(first_col, last_col + 1):
+                    self._cells.setdefault((col, row), []).append(index)
+
+    def _cell_range(self, box: Window) -> tuple[int, int, int, int]:
+        min_x, min_y, max_x, _max_y = self.bounds
+        cell = (max_x - min_x) / self._grid_size
+        last = self._grid_size - 1
+
+        def clamp(value: float) -> int:
+            return max(0, min(last, int(math.floor(value / cell))))
+
+        return (
+            clamp(box[0] - min_x),
+            clamp(box[1] - min_y),
+            clamp(box[2] - min_x),
+            clamp(box[3] - min_y),
+        )
+
+
+def render_tile(
+    path: str,
+    z: int,
+    x: int,
+    y: int,
+    *,
+    format: str = "png",
+    tile_size: int = 256,
+    types: str | Iterable[str] | None = None,
+    line_width: int = 1,
+    palette: AciPalette | Mapping[int, tuple[int, int, int]] | None = None,
+) -> bytes:
+    """Render one XYZ map tile of the modelspace of ``path``.
+
+    The ``TileIndex`` of the file is kept for the following calls and
+    rebuilt when the file changes on disk.
+    """
+    stat = os.stat(path)
+    if types is not None and not isinstance(types, str):
+        types = " ".join(types)
+    index = _cached_tile_index(
+        str(path), stat.st_mtime_ns, stat.st_size, types, as_palette(palette)
+    )
+    return index.render_tile(z, x, y, format=format, tile_size=tile_size, line_width=line_width)
+
+
+@lru_cache(maxsize=_TILE_CACHE_SIZE)
+def _cached_tile_index(
+    path: str,
+    _mtime_ns: int,
+    _size: int,
+    types: str | None,
+    palette: AciPalette | None,
+) -> TileIndex:
+    return TileIndex(path, types, palette=palette)
+
+
+def _clip_polyline(points: list[tuple[float, float]], window: Window) -> list[list[tuple[float, float]]]:
+    if len(points) == 1:
+        x, y = points[0]
+        inside = window[0] <= x <= window[2] and window[1] <= y <= window[3]
+        return [points] if inside else []
+    runs: list[list[tuple[float, float]]] = []
+    current: list[tuple[float, float]] | None = None
+    for start, end in zip(points, points[1:]):
+        span = _line_span(start, end, window)
+        if span is None or (span[1] <= span[0] and start != end):
+            current = None
+            continue
+        t0, t1 = span
+        first = start if t0 <= 0.0 else _lerp(start, end, t0)
+        last = end if t1 >= 1.0 else _lerp(start, end, t1)
+        if current is None or t0 > 0.0:
+            current = [first]
+            runs.append(current)
+        current.append(last)
+        if t1 < 1.0:
+            current = None
+    return runs
+
+
+def _lerp(start: tuple[float, float], end: tuple[float, float], t: float) -> tuple[float, float]:
+    return (start[0] + (end[0] - start[0]) * t, start[1] + (end[1] - start[1]) * t)
+
+
+def _points_bounds(points: list[tuple[float, float]]) -> Window:
+    xs = [x for x, _y in points]
+    ys = [y for _x, y in points]
+    return (min(xs), min(ys), max(xs), max(ys))
+
+
+def _union(boxes: list[Window]) -> Window:
+    if not boxes:
+        return (0.0, 0.0, 1.0, 1.0)
+    return (
+        min(box[0] for box in boxes),
+        min(box[1] for box in boxes),
+        max(box[2] for box in boxes),
+        max(box[3] for box in boxes),
+    )
+
+
+def _square(bounds: Window) -> Window:
+    min_x, min_y, max_x, max_y = (float(value) for value in bounds)
+    side = max(max_x - min_x, max_y - min_y)
+    if not math.isfinite(side):
+        raise ValueError("tile bounds must be finite")
+    if side <= 0.0:
+        side = 1.0
+    return (min_x, min_y, min_x + side, min_y + side)
+
+
+def _overlaps(box: Window, window: Window) -> bool:
+    return box[0] <= window[2] and box[2] >= window[0] and box[1] <= window[3] and box[3] >= window[1]
+
+
+def _strokes_to_svg(
+    strokes: list[Stroke],
+    window: Window,
+    width: int,
+    height: int,
+    background: tuple[int, int, int],
+    line_width: int,
+) -> bytes:
+    min_x, min_y, max_x, max_y = window
+    scale_x = width / (max_x - min_x)
+    scale_y = height / (max_y - min_y)
+    lines = [
+        f'<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" '
+        f'viewBox="0 0 {width} {height}">',
+        f'<rect width="100%" height="100%" fill="{_hex(background)}"/>',
+    ]
+    for points, rgb, _closed in strokes:
+        coords = " ".join(
+            f"{(x - min_x) * scale_x:.2f},{(max_y - y) * scale_y:.2f}" for x, y in points
+        )
+        lines.append(
+            f'<polyline points="{coords}" fill="none" stroke="{_hex(rgb)}" '
+            f'stroke-width="{line_width}" stroke-linecap="round" stroke-linejoin="round"/>'
+        )
+    lines.append("</svg>")
+    return ("\n".join(lines) + "\n").encode("utf-8")
+
+
+def _hex(rgb: tuple[int, int, int]) -> str:
+    return "#{:02x}{:02x}{:02x}".format(*rgb)
@@ -0,0 +1,336 @@
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass, field
+from typing import Iterable
+
+from .convert import _resolve_layout
+from .document import Document, Layout
+from .entity import Entity
+
+GRAPH_TYPES = ("LINE", "ARC", "CIRCLE", "LWPOLYLINE", "POLYLINE_2D")
+
+Point2 = tuple[float, float]
+
+
+@dataclass(frozen=True)
+class GraphEdge:
+    """A straight or circular segment between two nodes. ``bulge`` is the
+    tangent of a quarter of the arc's sweep, positive counterclockwise from
+    ``start`` to ``end``, and 0 for straight segments."""
+
+    start: int
+    end: int
+    bulge: float
+    handle: int
+    dxftype: str
+
+
+@dataclass(frozen=True)
+class Loop:
+    """A closed boundary, counterclockwise. Segment ``i`` runs from
+    ``points[i]`` to ``points[i + 1]``, wrapping to ``points[0]``, with
+    ``bulges[i]``, and comes from the entity ``handles[i]``."""
+
+    points: list[Point2]
+    bulges: list[float]
+    handles: list[int]
+    area: float
+    perimeter: float
+
+    def to_entity(self) -> Entity:
+        """The loop as a closed LWPOLYLINE without a handle."""
+        return Entity(
+            "LWPOLYLINE",
+            0,
+            {
+                "points": list(self.points),
+                "bulges": list(self.bulges),
+                "flags": 1,
+                "closed": True,
+            },
+        )
+
+
+@dataclass
+class ConnectivityGraph:
+    """Curves snapped into a planar graph.
+
+    ``nodes`` are snapped endpoint positions and ``edges`` are the segments
+    joining them. Curves are only connected at their endpoints: crossings
+    and T-junctions in the middle of a segment are not split.
+    """
+
+    nodes: list[Point2] = field(default_factory=list)
+    edges: list[GraphEdge] = field(default_factory=list)
+
+    def degree(self, node: int) -> int:
+        return sum((edge.start == node) + (edge.end == node) for edge in self.edges)
+
+    def dangling_nodes(self) -> list[int]:
+        """Nodes with a single segment: open ends and gaps wider than the
+        tolerance."""
+        degrees = self._degrees()
+        return [node for node, degree in enumerate(degrees) if degree == 1]
+
+    def components(self) -> list[list[int]]:
+        """Edge indices of each connected part of the graph."""
+        parent = list(range(len(self.nodes)))
+
+        def find(node: int) -> int:
+            while parent[node] != node:
+                parent[node] = parent[parent[node]]
+                node = parent[node]
+            return node
+
+        for edge in self.edges:
+            parent[find(edge.start)] = find(edge.end)
+        groups: dict[int, list[int]] = {}
+        for index, edge in enumerate(self.edges):
+            groups.setdefault(find(edge.start), []).append(index)
+        return list(groups.values())
+
+    def loops(self) -> list[Loop]:
+        """The smallest closed regions of the graph, such as rooms or part
+        profiles, largest first.
+
+        Dangling segments are pruned first, so stubs and open runs never
+        end up in a boundary. Each region is traced once; the outline of a
+        whole connected part is not reported, and islands inside a region
+        are separate loops rather than holes of it.
+        """
+        edges = self._closed_edges()
+        # Half-edge 2 * i runs along edge i, 2 * i + 1 against it.
+        outgoing: dict[int, list[int]] = {}
+        for index in edges:
+            edge = self.edges[index]
+            outgoing.setdefault(edge.start, []).append(2 * index)
+            outgoing.setdefault(edge.end, []).append(2 * index + 1)
+        for half_edges in outgoing.values():
+            half_edges.sort(key=self._leaving_angle)
+
+        visited: set[int] = set()
+        loops: list[Loop] = []
+        for first in sorted(half for index in edges for half in (2 * index, 2 * index + 1)):
+            if first in visited:
+                continue
+            boundary: list[int] = []
+            half = first
+            while half not in visited:
+                visited.add(half)
+                boundary.append(half)
+                half = self._next_half_edge(half, outgoing)
+            loop = self._loop(boundary)
+            if loop.area > 0.0:
+                loops.append(loop)
+        loops.sort(key=lambda loop: -loop.area)
+        return loops
+
+    def _degrees(self) -> list[int]:
+        degrees = [0] * len(self.nodes)
+        for edge in self.edges:
+            degrees[edge.start] += 1
+            degrees[edge.end] += 1
+        return degrees
+
+    def _closed_edges(self) -> list[int]:
+        degrees = self._degrees()
+        alive = set(range(len(self.edges)))
+        by_node: dict[int, list[int]] = {}
+        for index, edge in enumerate(self.edges):
+            by_node.setdefault(edge.start, []).append(index)
+            by_node.setdefault(edge.end, []).append(index)
+        pending = [node for node, degree in enumerate(degrees) if degree == 1]
+        while pending:
+            node = pending.pop()
+            for index in by_node.get(node, ()):
+                if index not in alive:
+                    continue
+                alive.discard(index)
+                edge = self.edges[index]
+                for end in (edge.start, edge.end):
+                    degrees[end] -= 1
+                    if degrees[end] == 1:
+                        pending.append(end)
+        return sorted(alive)
+
+    def _half_edge(self, half: int) -> tuple[int, int, float]:
+        edge = self.edges[half // 2]
+        if half % 2:
+            return edge.end, edge.start, -edge.bulge if edge.bulge else 0.0
+        return edge.start, edge.end, edge.bulge
+
+    def _leaving_angle(self, half: int) -> float:
+        start, end, bulge = self._half_edge(half)
+        (x1, y1), (x2, y2) = self.nodes[start], self.nodes[end]
+        # An arc leaves its start turned away from the chord by half its sweep.
+        angle = math.atan2(y2 - y1, x2 - x1) - 2.0 * math.atan(bulge)
+        return angle % math.tau
+
+    def _next_half_edge(self, half: int, outgoing: dict[int, list[int]]) -> int:
+        # Keep the region on the left: at the end node, take the segment
+        # next clockwise from the one just walked.
+        _start, end, _bulge = self._half_edge(half)
+        around = outgoing[end]
+        position = around.index(half ^ 1)
+        return around[position - 1]
+
+    def _loop(self, boundary: list[int]) -> Loop:
+        points: list[Point2] = []
+        bulges: list[float] = []
+        handles: list[int] = []
+        area = 0.0
+        perimeter = 0.0
+        for half in boundary:
+            start, end, bulge = self._half_edge(half)
+            (x1, y1), (x2, y2) = self.nodes[start], self.nodes[end]
+            points.append((x1, y1))
+            bulges.append(bulge)
+            handles.append(self.edges[half // 2].handle)
+            area += (x1 * y2 - x2 * y1) / 2.0
+            chord = math.hypot(x2 - x1, y2 - y1)
+            if bulge:
+                sweep = 4.0 * math.atan(bulge)
+                radius = chord / (2.0 * abs(math.sin(sweep / 2.0)))
+                area += radius * radius * (sweep - math.sin(sweep)) / 2.0
+                perimeter += radius * abs(sweep)
+            else:
+                perimeter += chord
+        return Loop(points, bulges, handles, area, perimeter)
+
+
+def connectivity_graph(
+    source: str | Document | Layout | Iterable[Entity],
+    *,
+    tolerance: float = 1e-6,
+    types: str | Iterable[str] | None = None,
+) -> ConnectivityGraph:
+    """Snap the endpoints of modelspace curves into a connectivity graph.
+
+    LINE, ARC, CIRCLE, LWPOLYLINE and POLYLINE_2D entities are read in the
+    XY plane. Endpoints closer than ``tolerance`` become one node at the
+    first position seen. Polylines contribute one edge per segment and a
+    circle two half arcs; segments that collapse to a point are dropped.
+    ``source`` may also be an iterable of already decoded entities.
+    """
+    if tolerance < 0.0:
+        raise ValueError("tolerance must not be negative")
+    selected = _normalize_types(types)
+    if isinstance(source, (str, Document, Layout)):
+        _, layout = _resolve_layout(source)
+        entities: Iterable[Entity] = layout.query(sorted(selected))
+    else:
+        entities = source
+    graph = ConnectivityGraph()
+    snapper = _Snapper(graph.nodes, tolerance)
+    for entity in entities:
+        if entity.dxftype not in selected:
+            continue
+        try:
+            segments = _entity_segments(entity)
+        except (KeyError, TypeError, ValueError, IndexError):
+            continue
+        for start, end, bulge in segments:
+            first = snapper.node(start)
+            last = snapper.node(end)
+            if first == last and not bulge:
+                continue
+            graph.edges.append(GraphEdge(first, last, bulge, entity.handle, entity.dxftype))
+    return graph
+
+
+def find_loops(
+    source: str | Document | Layout | Iterable[Entity],
+    *,
+    tolerance: float = 1e-6,
+    types: str | Iterable[str] | None = None,
+) -> list[Loop]:
+    """Closed regions bounded by the curves of ``source``, largest first.
+
+    Shorthand for ``connectivity_graph(...).loops()``.
+    """
+    return connectivity_graph(source, tolerance=tolerance, types=types).loops()
+
+
+class _Snapper:
+    def __init__(self, nodes: list[Point2], tolerance: float) -> None:
+        self._nodes = nodes
+        self._tolerance = tolerance
+        self._cells: dict[tuple[int, int], list[int]] = {}
+        self._exact: dict[Point2, int] = {}
+
+    def node(self, point: Point2) -> int:
+        tolerance = self._tolerance
+        if tolerance == 0.0:
+            index = self._exact.setdefault(point, len(self._nodes))
+            if index == len(self._nodes):
+                self._nodes.append(point)
+            return index
+        cell = (math.floor(point[0] / tolerance), math.floor(point[1] / tolerance))
+        for dx in (-1, 0, 1):
+            for dy in (-1, 0, 1):
+                for index in self._cells.get((cell[0] + dx, cell[1] + dy), ()):
+                    x, y = self._nodes[index]
+                    if math.hypot(point[0] - x, point[1] - y) <= tolerance:
+                        return index
+        self._cells.setdefault(cell, []).append(len(self._nodes))
+        self._nodes.append(point)
+        return len(self._nodes) - 1
+
+
+def _normalize_types(types: str | Iterable[str] | None) -> set[str]:
+    if types is None:
+        return set(GRAPH_TYPES)
+    if isinstance(types, str):
+        types = types.replace(",", " ").split()
+    selected = {str(name).strip().upper() for name in types if str(name).strip()}
+    unsupported = sorted(selected - set(GRAPH_TYPES))
+    if unsupported:
+        raise ValueError(f"cannot build a graph from entity types: {', '.join(unsupported)}")
+    return selected
+
+
+def _entity_segments(entity: Entity) -> list[tuple[Point2, Point2, float]]:
+    dxf = entity.dxf
+    if entity.dxftype == "LINE":
+        return [(_xy(dxf["start"]), _xy(dxf["end"]), 0.0)]
+    if entity.dxftype in ("ARC", "CIRCLE"):
+        center = _xy(dxf["center"])
+        radius = float(dxf["radius"])
+        if entity.dxftype == "CIRCLE":
+            start_deg, sweep_deg = 0.0, 360.0
+        else:
+            start_deg = float(dxf["start_angle"])
+            sweep_deg = (float(dxf["end_angle"]) - start_deg) % 360.0 or 360.0
+        # Arcs of half a turn or more are split so both ends of every edge
+        # are distinct nodes.
+        pieces = 2 if sweep_deg >= 180.0 else 1
+        step = math.radians(sweep_deg) / pieces
+        bulge = math.tan(step / 4.0)
+        angles = [math.radians(start_deg) + step * index for index in range(pieces + 1)]
+        points = [
+            (center[0] + radius * math.cos(angle), center[1] + radius * math.sin(angle))
+            for angle in angles
+        ]
+        return [(points[index], points[index + 1], bulge) for index in range(pieces)]
+    # LWPOLYLINE and POLYLINE_2D.
+    if dxf.get("interpolation_applied") and dxf.get("interpolated_points"):
+        points = [_xy(point) for point in dxf["interpolated_points"]]
+        bulges: list = []
+    else:
+        points = [_xy(point) for point in dxf["points"]]
+        bulges = list(dxf.get("bulges") or [])
+    closed = bool(dxf.get("closed")) and len(points) > 2
+    count = len(points) if closed else len(points) - 1
+    return [
+        (
+            points[index],
+            points[(index + 1) % len(points)],
+            float(bulges[index]) if index < len(bulges) else 0.0,
+        )
+        for index in range(count)
+    ]
+
+
+def _xy(point) -> Point2:
+    return (float(point[0]), float(point[1]))
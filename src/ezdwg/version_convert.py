@@ -0,0 +1,198 @@
+from __future__ import annotations
+
+import math
+from dataclasses import dataclass
+from typing import Any
+
+from ._convert_row_builders import _as_polyface_row
+from ._convert_utils import _to_rgb, _to_valid_true_color
+from .colors import AciPalette
+from .convert import (
+    _DWG_WRITABLE_ENTITY_TYPES,
+    _POLYFACE_ROWS,
+    WriteResult,
+    _all_finite,
+    _collect_dwg_write_rows,
+    _dwg_row_handles,
+    _resolve_dwg_export_entities,
+    _resolve_layout,
+    _write_dwg_rows,
+)
+from .document import Document, Layout
+from .entity import Entity, round_floats
+
+# The DWG versions ezdwg can write, by the names accepted for them.
+_WRITE_VERSIONS = {"AC1015": "AC1015", "R2000": "AC1015"}
+# Types written as older ones they are drawn with.
+_TRANSLATED_TYPES = ("MESH", "MULTILEADER")
+# Properties a MULTILEADER passes on to the LEADER and MTEXT it becomes.
+_MLEADER_SHARED_KEYS = ("layer_handle", "color_index", "true_color")
+
+
+@dataclass(frozen=True)
+class VersionConversion:
+    """A drawing rewritten in another DWG version.
+
+    ``downgrades`` counts the written entities per translation:
+    ``"true_color"`` for true colors replaced by the nearest ACI color,
+    ``"mesh"`` for MESH entities written as polyface meshes and
+    ``"multileader"`` for MULTILEADERs written as LEADERs and an MTEXT.
+    Entities of types the target cannot hold are in ``write.skipped_by_type``.
+    """
+
+    write: WriteResult
+    source_version: str
+    downgrades: dict[str, int]
+
+
+def convert_version(
+    source: str | Document | Layout,
+    target_version: str,
+    output_path: str,
+    **kwargs: Any,
+) -> VersionConversion:
+    """Rewrite ``source``, of any version ezdwg reads, as ``target_version``.
+
+    ezdwg writes AC1015 (R2000) only, so older drawings are upgraded to it
+    and newer ones saved down to it. R2000 has no true colors: they become
+    the nearest color of the standard ACI palette unless ``entity_colors``
+    gives one. Transparencies are kept as the extended data R2000 readers
+    take them from. MESH entities are written as polyface meshes of their
+    base cage and MULTILEADERs as a LEADER per leader line plus an MTEXT for
+    their text; other types newer than R2000 are not written. The other
+    keyword arguments are those of ``to_dwg``.
+    """
+    version = _WRITE_VERSIONS.get(str(target_version).strip().upper())
+    if version is None:
+        supported = ", ".join(_WRITE_VERSIONS)
+        raise ValueError(
+            f"unsupported DWG write version: {target_version} (supported: {supported})"
+        )
+    kwargs.pop("version", None)
+    precision = kwargs.pop("precision", None)
+    source_path, layout = _resolve_layout(source)
+
+    source_entities = _resolve_dwg_export_entities(
+        layout,
+        kwargs.pop("types", None),
+        _DWG_WRITABLE_ENTITY_TYPES.union(_TRANSLATED_TYPES),
+    )
+    translated = [entity for entity in source_entities if entity.dxftype in _TRANSLATED_TYPES]
+    entities = [entity for entity in source_entities if entity.dxftype not in _TRANSLATED_TYPES]
+    rows, total, written, skipped_by_type = _collect_dwg_write_rows(entities, precision)
+
+    downgrades = {"true_color": 0, "mesh": 0, "multileader": 0}
+    next_handle = max((entity.handle for entity in source_entities), default=0) + 1
+    for entity in translated:
+        total += 1
+        if entity.dxftype == "MESH":
+            parts = [entity]
+            row = round_floats(_as_polyface_row(entity), precision)
+            written_parts = row is not None and _all_finite(row)
+            if written_parts:
+                rows[_POLYFACE_ROWS].append(row)
+        else:
+            parts = _multileader_parts(entity, next_handle)
+            next_handle += len(parts)
+            part_rows, _parts_total, parts_written, _parts_skipped = _collect_dwg_write_rows(
+                parts, precision
+            )
+            written_parts = bool(parts) and parts_written == len(parts)
+            if written_parts:
+                for key, type_rows in part_rows.items():
+                    rows[key].extend(type_rows)
+        if not written_parts:
+            skipped_by_type[entity.dxftype] = skipped_by_type.get(entity.dxftype, 0) + 1
+            continue
+        written += 1
+        downgrades["mesh" if entity.dxftype == "MESH" else "multileader"] += 1
+        entities.extend(parts)
+
+    writable = _dwg_row_handles(rows)
+    palette = AciPalette.default()
+    colors: dict[int, int] = {}
+    for entity in entities:
+        if entity.handle not in writable:
+            continue
+        rgb = _to_rgb(_to_valid_true_color(entity.dxf.get("true_color")))
+        if rgb is not None:
+            colors[entity.handle] = palette.nearest(rgb)
+    downgrades["true_color"] = len(colors)
+    colors.update(kwargs.pop("entity_colors", None) or {})
+
+    result = _write_dwg_rows(
+        source_path,
+        layout,
+        entities,
+        (rows, total, written, skipped_by_type),
+        output_path,
+        entity_colors=colors,
+        **kwargs,
+    )
+    return VersionConversion(
+        write=result,
+        source_version=layout.doc.version,
+        downgrades={name: count for name, count in downgrades.items() if count},
+    )
+
+
+def _multileader_parts(entity: Entity, next_handle: int) -> list[Entity]:
+    """The LEADER entities and MTEXT a MULTILEADER is drawn with. The first
+    part keeps the handle of the MULTILEADER; the others are numbered from
+    ``next_handle``. Empty when it has neither leader lines nor text."""
+    shared = {key: entity.dxf[key] for key in _MLEADER_SHARED_KEYS if key in entity.dxf}
+    text = entity.dxf.get("text")
+    has_text = isinstance(text, str) and bool(text)
+    parts: list[Entity] = []
+    for leader in entity.dxf.get("leaders") or []:
+        connection = tuple(leader["connection"])
+        direction = tuple(leader["direction"])
+        landing = float(leader.get("landing_distance") or 0.0)
+        tail = [connection]
+        if landing > 0.0:
+            tail.append(tuple(c + d * landing for c, d in zip(connection, direction)))
+        for points in leader.get("lines") or []:
+            parts.append(
+                Entity(
+                    "LEADER",
+                    0,
+                    {
+                        **shared,
+                        # MTEXT annotation or none.
+                        "annotation_type": 0 if has_text else 3,
+                        "path_type": 0,
+                        "points": [tuple(point) for point in points] + tail,
+                    },
+                )
+            )
+    if has_text:
+        # The location is the top-left corner of the text box; the MTEXT
+        # attaches at the top edge by the alignment of the text.
+        alignment = int(entity.dxf.get("text_alignment") or 1)
+        if alignment not in (1, 2, 3):
+            alignment = 1
+        width = float(entity.dxf.get("text_width") or 0.0)
+        direction = tuple(entity.dxf.get("text_direction") or (1.0, 0.0, 0.0))
+        length = math.hypot(*direction) or 1.0
+        offset = width * (alignment - 1) / 2.0 / length
+        location = tuple(entity.dxf["text_location"])
+        parts.append(
+            Entity(
+                "MTEXT",
+                0,
+                {
+                    **shared,
+                    "text": text,
+                    "insert": tuple(c + d * offset for c, d in zip(location, direction)),
+                    "text_direction": direction,
+                    "rect_width": width,
+                    "char_height": float(entity.dxf.get("text_height") or 0.0),
+                    "attachment_point": alignment,
+                    "drawing_direction": 1,
+                },
+            )
+        )
+    return [
+        Entity(part.dxftype, entity.handle if index == 0 else next_handle + index - 1, part.dxf)
+        for index, part in enumerate(parts)
+    ]
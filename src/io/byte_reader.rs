@@ -45,9 +45,7 @@ impl<'a> ByteReader<'a> {
     }
 
     pub fn read_u8(&mut self) -> Result<u8> {
-        self.require(1)?;
-        let value = self.data[self.pos];
-        self.pos += 1;
+        let [value] = self.read_array()?;
         Ok(value)
     }
 
@@ -56,70 +54,48 @@ impl<'a> ByteReader<'a> {
     }
 
     pub fn read_u16_le(&mut self) -> Result<u16> {
-        let bytes = self.read_bytes(2)?;
-        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+        Ok(u16::from_le_bytes(self.read_array()?))
     }
 
     pub fn read_u32_le(&mut self) -> Result<u32> {
-        let bytes = self.read_bytes(4)?;
-        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        Ok(u32::from_le_bytes(self.read_array()?))
     }
 
     pub fn read_u64_le(&mut self) -> Result<u64> {
-        let bytes = self.read_bytes(8)?;
-        Ok(u64::from_le_bytes([
-            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-        ]))
+        Ok(u64::from_le_bytes(self.read_array()?))
     }
 
     pub fn read_i32_le(&mut self) -> Result<i32> {
-        let bytes = self.read_bytes(4)?;
-        Ok(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        Ok(i32::from_le_bytes(self.read_array()?))
     }
 
     pub fn read_f64_le(&mut self) -> Result<f64> {
-        let bytes = self.read_bytes(8)?;
-        Ok(f64::from_le_bytes([
-            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-        ]))
+        Ok(f64::from_le_bytes(self.read_array()?))
     }
 
     pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
-        self.require(n)?;
-        let start = self.pos;
-        let end = self.pos + n;
-        self.pos = end;
-        Ok(&self.data[start..end])
+        let bytes = self.peek_bytes(n)?;
+        self.pos += n;
+        Ok(bytes)
     }
 
     pub fn peek_bytes(&self, n: usize) -> Result<&'a [u8]> {
-        self.require_peek(n)?;
-        let start = self.pos;
-        let end = self.pos + n;
-        Ok(&self.data[start..end])
-    }
-
-    fn require(&self, n: usize) -> Result<()> {
-        if self.pos + n <= self.data.len() {
-            Ok(())
-        } else {
-            Err(DwgError::new(
-                ErrorKind::Io,
-                format!("unexpected EOF: need {n} bytes, have {}", self.remaining()),
-            )
-            .with_offset(self.pos as u64))
-        }
-    }
-
-    fn require_peek(&self, n: usize) -> Result<()> {
-        if self.pos + n <= self.data.len() {
-            Ok(())
-        } else {
-            Err(DwgError::new(
-                ErrorKind::Io,
-                format!("unexpected EOF: need {n} bytes, have {}", self.remaining()),
-            )
-            .with_offset(self.pos as u64))
-        }
+        self.pos
+            .checked_add(n)
+            .and_then(|end| self.data.get(self.pos..end))
+            .ok_or_else(|| {
+                DwgError::new(
+                    ErrorKind::Io,
+                    format!("unexpected EOF: need {n} bytes, have {}", self.remaining()),
+                )
+                .with_offset(self.pos as u64)
+            })
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self.read_bytes(N)?;
+        bytes
+            .try_into()
+            .map_err(|_| DwgError::new(ErrorKind::Io, format!("expected {N} bytes")))
     }
 }
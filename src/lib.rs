@@ -1,13 +1,31 @@
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
 use pyo3::prelude::*;
 
+// Decode paths run on untrusted files: malformed input must surface as a
+// `DwgError`, never as a panic from slice indexing or `unwrap`.
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 mod api;
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 pub mod bit;
+#[cfg(feature = "cache")]
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
+pub mod cache;
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 pub mod container;
 pub mod core;
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 pub mod dwg;
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 pub mod entities;
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 pub mod io;
+pub mod lowlevel;
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 pub mod objects;
+#[cfg(feature = "raster")]
+pub mod raster;
+#[cfg_attr(not(test), deny(clippy::indexing_slicing, clippy::unwrap_used))]
 pub mod writer;
 
 /// A Python module implemented in Rust. The name of this function must match
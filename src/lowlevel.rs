@@ -0,0 +1,60 @@
+//! Stable low-level API for building custom decoders on top of ezdwg.
+//!
+//! Everything a decode pass needs is re-exported here, so callers do not
+//! depend on the internal module layout:
+//!
+//! - **Files and versions:** [`read_file`], [`detect_version`], [`DwgVersion`].
+//! - **Sections:** [`Decoder::section_directory`] lists the section locators
+//!   and [`Decoder::load_section_by_index`] returns their bytes;
+//!   [`Decoder::load_section_by_name`] returns decompressed R2004+ sections.
+//! - **Records:** [`Decoder::build_object_index`] maps handles to offsets and
+//!   [`Decoder::parse_object_record`] slices one object record.
+//! - **Headers:** [`parse_object_header`] reads the size and type code, and
+//!   [`read_object_type`] positions a [`BitReader`] on the first
+//!   object-specific field.
+//! - **Bit access:** [`BitReader`] implements the DWG bit codes (`B`, `BS`,
+//!   `BL`, `BD`, `H`, `TV`, ...); [`BitWriter`] is its inverse for tests.
+//!   [`ObjectRecord::data_bits`], [`ObjectRecord::string_bits`] and
+//!   [`ObjectRecord::handle_bits`] locate the three streams of an R2007+
+//!   record as [`BitSlice`]s.
+//!
+//! Items outside this module may change between minor releases, except that
+//! the decoded entity structs in [`crate::entities`] only gain fields: they
+//! are `#[non_exhaustive]` and carry an [`EntitySnapshot::SNAPSHOT_VERSION`].
+//!
+//! ```
+//! use _core::lowlevel::{object_type_name, read_file, read_object_type, Decoder};
+//!
+//! let bytes = read_file("test_dwg/line_2000.dwg")?;
+//! let decoder = Decoder::new(&bytes, Default::default())?;
+//! let index = decoder.build_object_index()?;
+//! let mut lines = 0;
+//! for object in &index.objects {
+//!     let record = decoder.parse_object_record(object.offset)?;
+//!     let mut reader = record.bit_reader();
+//!     let type_code = read_object_type(&mut reader, decoder.version())?;
+//!     if object_type_name(type_code) == "LINE" {
+//!         lines += 1;
+//!     }
+//! }
+//! assert!(lines > 0);
+//! # Ok::<(), _core::lowlevel::DwgError>(())
+//! ```
+
+pub use crate::bit::{BitReader, BitWriter, Endian, HandleRef, HandleRefKind, TraceEntry};
+pub use crate::container::{
+    SectionDirectory, SectionKind, SectionLocatorRecord, SectionSlice, SYSTEM_SECTION_MAP,
+    SYSTEM_SECTION_PAGE_MAP,
+};
+pub use crate::core::config::ParseConfig;
+pub use crate::core::error::{DwgError, ErrorKind};
+pub use crate::core::result::Result;
+pub use crate::dwg::decoder::Decoder;
+pub use crate::dwg::file_open::read_file;
+pub use crate::dwg::version::{detect_version, DwgVersion};
+pub use crate::entities::EntitySnapshot;
+pub use crate::objects::{
+    object_type_class, object_type_info, object_type_name, parse_object_header, read_object_type,
+    BitSlice, Handle, ObjectClass, ObjectHeader, ObjectIndex, ObjectRecord, ObjectRef,
+    ObjectTypeInfo,
+};
@@ -0,0 +1,284 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::{parse_common_object_prologue, CommonObjectPrologue};
+
+const MAX_BLOCK_RECORD_OWNED: u32 = 0x00F0_0000;
+
+/// Decoded BLOCK_HEADER (block record) object.
+///
+/// R13-R2000 files link the block's entities as a chain from
+/// `first_entity` to `last_entity`; R2004+ files list them in `entities`
+/// instead. Either way the stored order is the draw order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockRecordObject {
+    pub handle: u64,
+    /// `None` when it lives in the R2007+ string stream.
+    pub name: Option<String>,
+    pub anonymous: bool,
+    pub has_attributes: bool,
+    pub is_xref: bool,
+    pub is_overlaid: bool,
+    /// Path of the referenced drawing for xref blocks, empty otherwise;
+    /// `None` when it lives in the R2007+ string stream.
+    pub xref_path: Option<String>,
+    pub base_point: (f64, f64, f64),
+    pub owner_handle: u64,
+    pub block_entity: u64,
+    pub first_entity: Option<u64>,
+    pub last_entity: Option<u64>,
+    pub entities: Vec<u64>,
+    pub end_block_entity: u64,
+    pub inserts: Vec<u64>,
+    pub layout: Option<u64>,
+}
+
+impl BlockRecordObject {
+    /// Number of strings this object keeps in the R2007+ string stream, in
+    /// stream order: name, xref path and description.
+    pub const STRING_STREAM_COUNT: usize = 3;
+
+    /// Fills the fields returned as `None` from string stream values.
+    pub fn apply_string_stream(&mut self, strings: &[String]) {
+        let mut strings = strings.iter().cloned();
+        self.name = self.name.take().or_else(|| strings.next());
+        self.xref_path = self.xref_path.take().or_else(|| strings.next());
+    }
+}
+
+/// Decode a BLOCK_HEADER object whose reader is positioned right after the
+/// object type prefix.
+///
+/// `object_data_end_bit` is the start of the handle stream for R2010+; older
+/// versions derive it from the `ObjSize` field stored in the object itself.
+pub fn decode_block_record(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_data_end_bit: Option<u32>,
+    object_handle: u64,
+) -> Result<BlockRecordObject> {
+    let r14 = matches!(version, DwgVersion::R14);
+    let r2004_plus = !matches!(version, DwgVersion::R14 | DwgVersion::R2000);
+    let r2007_plus = r2004_plus && !matches!(version, DwgVersion::R2004);
+    let prologue = parse_common_object_prologue(reader, version)?;
+    let handle = prologue.handle_or(object_handle);
+    let CommonObjectPrologue {
+        obj_size,
+        num_reactors,
+        xdic_missing,
+        ..
+    } = prologue;
+
+    let read_text = |reader: &mut BitReader<'_>| -> Result<Option<String>> {
+        if r2007_plus {
+            Ok(None)
+        } else {
+            reader.read_tv().map(Some)
+        }
+    };
+    let name = read_text(reader)?;
+    let _flag_64 = reader.read_b()?;
+    // As with LTYPE, R2007+ files leave the xref index out of the data stream.
+    if !r2007_plus {
+        let _xref_index_plus1 = reader.read_bs()?;
+    }
+    let _xdep = reader.read_b()?;
+    let anonymous = reader.read_b()? != 0;
+    let has_attributes = reader.read_b()? != 0;
+    let is_xref = reader.read_b()? != 0;
+    let is_overlaid = reader.read_b()? != 0;
+    if !r14 {
+        let _loaded = reader.read_b()?;
+    }
+    let owned_count = if r2004_plus && !is_xref && !is_overlaid {
+        let count = reader.read_bl()?;
+        if count >= MAX_BLOCK_RECORD_OWNED {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!("block record owned object count too large: {count}"),
+            ));
+        }
+        count
+    } else {
+        0
+    };
+    let base_point = reader.read_3bd()?;
+    let xref_path = read_text(reader)?;
+    let mut insert_count = 0usize;
+    if !r14 {
+        while reader.read_rc()? != 0 {
+            insert_count += 1;
+        }
+    }
+    let _description = read_text(reader)?;
+    let preview_size = reader.read_bl()?;
+    reader.read_rcs(preview_size as usize)?;
+    if r2007_plus {
+        let _insert_units = reader.read_bs()?;
+        let _explodable = reader.read_b()?;
+        let _block_scaling = reader.read_rc()?;
+    }
+
+    let declared = if matches!(
+        version,
+        DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    ) {
+        object_data_end_bit
+    } else {
+        obj_size
+    };
+    let declared = declared
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "missing block record data size"))?;
+    reader.set_bit_pos(declared);
+
+    let read_handle =
+        |reader: &mut BitReader<'_>| -> Result<u64> { Ok(reader.read_h()?.resolve(handle)) };
+    let owner_handle = read_handle(reader)?;
+    for _ in 0..num_reactors {
+        read_handle(reader)?;
+    }
+    if !xdic_missing {
+        read_handle(reader)?;
+    }
+    let _null = read_handle(reader)?;
+    let block_entity = read_handle(reader)?;
+    let (mut first_entity, mut last_entity) = (None, None);
+    if !r2004_plus && !is_xref && !is_overlaid {
+        first_entity = Some(read_handle(reader)?).filter(|value| *value != 0);
+        last_entity = Some(read_handle(reader)?).filter(|value| *value != 0);
+    }
+    let mut entities = Vec::with_capacity(owned_count as usize);
+    for _ in 0..owned_count {
+        let entity = read_handle(reader)?;
+        if entity != 0 {
+            entities.push(entity);
+        }
+    }
+    let end_block_entity = read_handle(reader)?;
+    let mut inserts = Vec::with_capacity(insert_count);
+    let mut layout = None;
+    if !r14 {
+        for _ in 0..insert_count {
+            inserts.push(read_handle(reader)?);
+        }
+        layout = Some(read_handle(reader)?).filter(|value| *value != 0);
+    }
+
+    Ok(BlockRecordObject {
+        handle,
+        name,
+        anonymous,
+        has_attributes,
+        is_xref,
+        is_overlaid,
+        xref_path,
+        base_point,
+        owner_handle,
+        block_entity,
+        first_entity,
+        last_entity,
+        entities,
+        end_block_entity,
+        inserts,
+        layout,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_block_record;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    fn build_block_record_bytes(version: &DwgVersion, entities: &[u64]) -> Vec<u8> {
+        let r2004 = matches!(version, DwgVersion::R2004);
+        let mut writer = BitWriter::new();
+        writer.write_bs(0x31).expect("write type");
+        let obj_size_pos = writer.tell_bits();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x70).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        if r2004 {
+            writer.write_b(1).expect("write xdic missing");
+        }
+        writer.write_tv("*Model_Space").expect("write name");
+        writer.write_b(1).expect("write flag 64");
+        writer.write_bs(0).expect("write xref index");
+        writer.write_b(0).expect("write xdep");
+        for _ in 0..5 {
+            writer.write_b(0).expect("write block flag");
+        }
+        if r2004 {
+            writer
+                .write_bl(entities.len() as u32)
+                .expect("write owned count");
+        }
+        writer.write_3bd(1.0, 2.0, 0.0).expect("write base point");
+        writer.write_tv("").expect("write xref path");
+        writer.write_rc(0).expect("write insert count end");
+        writer.write_tv("").expect("write description");
+        writer.write_bl(0).expect("write preview size");
+        let obj_size = writer.tell_bits() as u32;
+        writer.write_h(4, 1).expect("write owner");
+        if !r2004 {
+            writer.write_h(3, 0).expect("write xdic");
+        }
+        writer.write_h(5, 0).expect("write null");
+        writer.write_h(3, 0x71).expect("write block entity");
+        if r2004 {
+            for entity in entities {
+                writer.write_h(3, *entity).expect("write owned entity");
+            }
+        } else {
+            writer.write_h(4, entities[0]).expect("write first entity");
+            writer
+                .write_h(4, *entities.last().expect("entities"))
+                .expect("write last entity");
+        }
+        writer.write_h(3, 0x72).expect("write endblk");
+        writer.write_h(5, 0x73).expect("write layout");
+        let end = writer.tell_bits();
+        writer.set_bit_pos(obj_size_pos as u32);
+        writer
+            .write_rl(Endian::Little, obj_size)
+            .expect("patch obj size");
+        writer.set_bit_pos(end as u32);
+        writer.into_bytes()
+    }
+
+    fn decode(version: DwgVersion, entities: &[u64]) -> super::BlockRecordObject {
+        let bytes = build_block_record_bytes(&version, entities);
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read_bs().expect("type"), 0x31);
+        decode_block_record(&mut reader, &version, None, 0x70).expect("decode block record")
+    }
+
+    #[test]
+    fn decode_block_record_reads_r2000_entity_chain_bounds() {
+        let block = decode(DwgVersion::R2000, &[0x83, 0x84, 0x90]);
+
+        assert_eq!(block.handle, 0x70);
+        assert_eq!(block.name.as_deref(), Some("*Model_Space"));
+        assert_eq!(block.base_point, (1.0, 2.0, 0.0));
+        assert_eq!(block.xref_path.as_deref(), Some(""));
+        assert_eq!(block.owner_handle, 1);
+        assert_eq!(block.block_entity, 0x71);
+        assert_eq!(block.first_entity, Some(0x83));
+        assert_eq!(block.last_entity, Some(0x90));
+        assert!(block.entities.is_empty());
+        assert_eq!(block.end_block_entity, 0x72);
+        assert_eq!(block.layout, Some(0x73));
+    }
+
+    #[test]
+    fn decode_block_record_reads_r2004_owned_entities_in_order() {
+        let block = decode(DwgVersion::R2004, &[0x90, 0x83, 0x84]);
+
+        assert_eq!(block.first_entity, None);
+        assert_eq!(block.entities, vec![0x90, 0x83, 0x84]);
+        assert_eq!(block.end_block_entity, 0x72);
+        assert_eq!(block.layout, Some(0x73));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::bit::{BitReader, Endian};
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::xdata::read_eed;
+
+const MAX_OBJECT_REACTORS: u32 = 1 << 16;
+
+/// Fields of the common object header that every non-entity object stores
+/// between its type and its own data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommonObjectPrologue {
+    /// Bit position where the handle stream starts. `None` from R2010 on,
+    /// where the object header carries it instead.
+    pub obj_size: Option<u32>,
+    /// The handle stored in the record; 0 when it was left out.
+    pub handle: u64,
+    pub num_reactors: u32,
+    /// Only stored from R2004 on; earlier objects always have the handle.
+    pub xdic_missing: bool,
+    pub has_ds_binary_data: bool,
+}
+
+impl CommonObjectPrologue {
+    /// The stored handle, or `object_handle` from the object map when the
+    /// record left it out.
+    pub fn handle_or(&self, object_handle: u64) -> u64 {
+        if self.handle != 0 {
+            self.handle
+        } else {
+            object_handle
+        }
+    }
+}
+
+/// Reads the common object header from `reader` positioned right after the
+/// object type: ObjSize, handle, extended data (skipped), reactor count and
+/// the extension dictionary and DS binary data flags. R13/R14 store ObjSize
+/// after the extended data, R2000 to R2007 before the handle.
+pub fn parse_common_object_prologue(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+) -> Result<CommonObjectPrologue> {
+    let mut obj_size = None;
+    if matches!(
+        version,
+        DwgVersion::R2000 | DwgVersion::R2004 | DwgVersion::R2007
+    ) {
+        obj_size = Some(reader.read_rl(Endian::Little)?);
+    }
+    let handle = reader.read_h()?.value;
+    read_eed(reader)?;
+    if matches!(version, DwgVersion::R14) {
+        obj_size = Some(reader.read_rl(Endian::Little)?);
+    }
+    let num_reactors = reader.read_bl()?;
+    if num_reactors > MAX_OBJECT_REACTORS {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("object reactor count too large: {num_reactors}"),
+        ));
+    }
+    let xdic_missing =
+        !matches!(version, DwgVersion::R14 | DwgVersion::R2000) && reader.read_b()? != 0;
+    let has_ds_binary_data =
+        matches!(version, DwgVersion::R2013 | DwgVersion::R2018) && reader.read_b()? != 0;
+    Ok(CommonObjectPrologue {
+        obj_size,
+        handle,
+        num_reactors,
+        xdic_missing,
+        has_ds_binary_data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_common_object_prologue;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    fn write_eed(writer: &mut BitWriter) {
+        writer.write_bs(2).unwrap();
+        writer.write_h(5, 0x12).unwrap();
+        writer.write_rc(0xAA).unwrap();
+        writer.write_rc(0xBB).unwrap();
+        writer.write_bs(0).unwrap();
+    }
+
+    #[test]
+    fn reads_r14_obj_size_after_the_extended_data() {
+        let mut writer = BitWriter::new();
+        writer.write_h(0, 0x2F).unwrap();
+        write_eed(&mut writer);
+        writer.write_rl(Endian::Little, 640).unwrap();
+        writer.write_bl(2).unwrap();
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        let prologue = parse_common_object_prologue(&mut reader, &DwgVersion::R14).unwrap();
+        assert_eq!(prologue.obj_size, Some(640));
+        assert_eq!(prologue.handle_or(0x99), 0x2F);
+        assert_eq!(prologue.num_reactors, 2);
+        assert!(!prologue.xdic_missing);
+    }
+
+    #[test]
+    fn reads_the_flags_of_later_versions() {
+        let mut writer = BitWriter::new();
+        writer.write_h(0, 0).unwrap();
+        write_eed(&mut writer);
+        writer.write_bl(0).unwrap();
+        writer.write_b(1).unwrap();
+        writer.write_b(1).unwrap();
+        writer.write_bs(77).unwrap();
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        let prologue = parse_common_object_prologue(&mut reader, &DwgVersion::R2013).unwrap();
+        assert_eq!(prologue.obj_size, None);
+        assert_eq!(prologue.handle_or(0x99), 0x99);
+        assert!(prologue.xdic_missing);
+        assert!(prologue.has_ds_binary_data);
+        assert_eq!(reader.read_bs().unwrap(), 77);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+use crate::objects::xdata::{decode_xrecord_items, XDataValue};
+
+const MAX_DICTIONARY_ENTRIES: u32 = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryObject {
+    pub handle: u64,
+    /// 0 for the named objects dictionary.
+    pub owner_handle: u64,
+    /// Entry names and the objects they refer to, in stored order.
+    pub entries: Vec<(String, u64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct XRecordObject {
+    pub handle: u64,
+    pub owner_handle: u64,
+    /// Values under their DXF group codes, in stored order.
+    pub items: Vec<(u16, XDataValue)>,
+}
+
+/// Fields of the common object header the dictionary decoders use.
+struct ObjectPrefix {
+    handle: u64,
+    obj_size: u32,
+    num_reactors: u32,
+    xdic_missing: bool,
+}
+
+/// Decodes a DICTIONARY object. Entry names of R2007+ files live in the
+/// string stream, so only R14 to R2004 are supported.
+pub fn decode_dictionary(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<DictionaryObject> {
+    let prefix = read_object_prefix(reader, version, object_handle, "DICTIONARY")?;
+    let num_entries = reader.read_bl()?;
+    if num_entries > MAX_DICTIONARY_ENTRIES {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("dictionary entry count too large: {num_entries}"),
+        ));
+    }
+    if matches!(version, DwgVersion::R14) {
+        let _unknown = reader.read_rc()?;
+    } else {
+        let _cloning = reader.read_bs()?;
+        let _hard_owner = reader.read_rc()?;
+    }
+    let mut names = Vec::with_capacity(num_entries as usize);
+    for _ in 0..num_entries {
+        names.push(reader.read_tv()?);
+    }
+
+    reader.set_bit_pos(prefix.obj_size);
+    let owner_handle = read_common_handles(reader, &prefix)?;
+    let mut entries = Vec::with_capacity(names.len());
+    for name in names {
+        entries.push((name, reader.read_h()?.resolve(prefix.handle)));
+    }
+    Ok(DictionaryObject {
+        handle: prefix.handle,
+        owner_handle,
+        entries,
+    })
+}
+
+/// Decodes an XRECORD object. Only R14 to R2004 are supported.
+pub fn decode_xrecord(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<XRecordObject> {
+    let prefix = read_object_prefix(reader, version, object_handle, "XRECORD")?;
+    let num_bytes = reader.read_bl()?;
+    let available = prefix.obj_size.saturating_sub(reader.tell_bits() as u32) / 8;
+    if num_bytes > available {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("XRECORD data size out of range: {num_bytes} bytes"),
+        ));
+    }
+    let data = reader.read_rcs(num_bytes as usize)?;
+    let items = decode_xrecord_items(&data, version)?;
+
+    reader.set_bit_pos(prefix.obj_size);
+    let owner_handle = read_common_handles(reader, &prefix)?;
+    Ok(XRecordObject {
+        handle: prefix.handle,
+        owner_handle,
+        items,
+    })
+}
+
+fn read_object_prefix(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+    type_name: &str,
+) -> Result<ObjectPrefix> {
+    if !matches!(
+        version,
+        DwgVersion::R14 | DwgVersion::R2000 | DwgVersion::R2004
+    ) {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "{type_name} decoding supports R14 to R2004, got {}",
+                version.as_str()
+            ),
+        ));
+    }
+    let prologue = parse_common_object_prologue(reader, version)?;
+    let obj_size = prologue
+        .obj_size
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "missing object data size"))?;
+    Ok(ObjectPrefix {
+        handle: prologue.handle_or(object_handle),
+        obj_size,
+        num_reactors: prologue.num_reactors,
+        xdic_missing: prologue.xdic_missing,
+    })
+}
+
+/// Reads the owner, reactors and extension dictionary at the start of the
+/// handle stream and returns the owner.
+fn read_common_handles(reader: &mut BitReader<'_>, prefix: &ObjectPrefix) -> Result<u64> {
+    let owner_handle = reader.read_h()?.resolve(prefix.handle);
+    for _ in 0..prefix.num_reactors {
+        reader.read_h()?;
+    }
+    if !prefix.xdic_missing {
+        reader.read_h()?;
+    }
+    Ok(owner_handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_dictionary, decode_xrecord};
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+    use crate::objects::xdata::XDataValue;
+
+    fn object(body: impl FnOnce(&mut BitWriter), handles: impl FnOnce(&mut BitWriter)) -> Vec<u8> {
+        let mut pre_handle = BitWriter::new();
+        pre_handle.write_h(0x00, 0x40).unwrap();
+        pre_handle.write_bs(0).unwrap(); // ext_size
+        pre_handle.write_bl(0).unwrap(); // reactors
+        body(&mut pre_handle);
+        let mut handle_stream = BitWriter::new();
+        handle_stream.write_h(0x04, 0x0C).unwrap();
+        handle_stream.write_h(0x03, 0).unwrap(); // xdic
+        handles(&mut handle_stream);
+
+        let mut out = BitWriter::new();
+        out.write_rl(Endian::Little, 32 + pre_handle.len_bits() as u32)
+            .unwrap();
+        out.write_bits_from_bytes(&pre_handle.to_bytes(), pre_handle.len_bits())
+            .unwrap();
+        out.write_bits_from_bytes(&handle_stream.to_bytes(), handle_stream.len_bits())
+            .unwrap();
+        out.into_bytes()
+    }
+
+    #[test]
+    fn decodes_dictionary_entries() {
+        let data = object(
+            |writer| {
+                writer.write_bl(2).unwrap();
+                writer.write_bs(1).unwrap();
+                writer.write_rc(0).unwrap();
+                writer.write_tv("ALPHA").unwrap();
+                writer.write_tv("BETA").unwrap();
+            },
+            |writer| {
+                writer.write_h(0x02, 0x41).unwrap();
+                writer.write_h(0x06, 0).unwrap(); // 0x40 + 1
+            },
+        );
+        let dictionary =
+            decode_dictionary(&mut BitReader::new(&data), &DwgVersion::R2000, 0).unwrap();
+        assert_eq!(dictionary.handle, 0x40);
+        assert_eq!(dictionary.owner_handle, 0x0C);
+        assert_eq!(
+            dictionary.entries,
+            vec![("ALPHA".to_string(), 0x41), ("BETA".to_string(), 0x41)]
+        );
+    }
+
+    #[test]
+    fn decodes_xrecord_values() {
+        let mut items = BitWriter::new();
+        items.write_rs(Endian::Little, 1).unwrap();
+        items.write_rs(Endian::Little, 3).unwrap();
+        items.write_rc(30).unwrap();
+        items.write_rcs(b"abc").unwrap();
+        items.write_rs(Endian::Little, 90).unwrap();
+        items.write_rl(Endian::Little, 0xFFFF_FFFE).unwrap();
+        items.write_rs(Endian::Little, 310).unwrap();
+        items.write_rc(2).unwrap();
+        items.write_rcs(&[0xDE, 0xAD]).unwrap();
+        let items = items.into_bytes();
+        let data = object(
+            |writer| {
+                writer.write_bl(items.len() as u32).unwrap();
+                writer.write_rcs(&items).unwrap();
+                writer.write_bs(1).unwrap();
+            },
+            |_| {},
+        );
+        let xrecord = decode_xrecord(&mut BitReader::new(&data), &DwgVersion::R2000, 0).unwrap();
+        assert_eq!(xrecord.owner_handle, 0x0C);
+        assert_eq!(
+            xrecord.items,
+            vec![
+                (1, XDataValue::String("abc".to_string())),
+                (90, XDataValue::Int32(-2)),
+                (310, XDataValue::Binary(vec![0xDE, 0xAD])),
+            ]
+        );
+    }
+}
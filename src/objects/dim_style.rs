@@ -0,0 +1,322 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+/// Geometry variables of a DIMSTYLE table entry, enough to regenerate
+/// dimension graphics. Sizes are in drawing units before `dimscale`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimStyleObject {
+    pub handle: u64,
+    /// `None` when the name lives in the R2007+ string stream.
+    pub name: Option<String>,
+    pub dimscale: f64,
+    /// Arrowhead size.
+    pub dimasz: f64,
+    /// Offset of extension lines from the definition points.
+    pub dimexo: f64,
+    /// Extension of extension lines beyond the dimension line.
+    pub dimexe: f64,
+    /// Extension of the dimension line beyond the extension lines.
+    pub dimdle: f64,
+    pub dimtxt: f64,
+    pub dimcen: f64,
+    /// Tick size; architectural ticks replace the arrowheads when non-zero.
+    pub dimtsz: f64,
+    pub dimlfac: f64,
+    /// Gap between the dimension line and its text.
+    pub dimgap: f64,
+    /// Vertical text placement: 0 centered on the dimension line, 1 above.
+    pub dimtad: u16,
+    pub dimse1: bool,
+    pub dimse2: bool,
+    pub dimtih: bool,
+    pub dimtoh: bool,
+    /// Decimal places of linear measurements.
+    pub dimdec: u16,
+}
+
+impl DimStyleObject {
+    /// Strings stored in the R2007+ string stream ahead of the ones this
+    /// decoder ignores: the name.
+    pub const STRING_STREAM_COUNT: usize = 1;
+
+    /// Fills the name when it was returned as `None`.
+    pub fn apply_string_stream(&mut self, strings: &[String]) {
+        self.name = self.name.take().or_else(|| strings.first().cloned());
+    }
+}
+
+/// Decodes a DIMSTYLE table entry whose reader is positioned right after the
+/// object type prefix. Reading stops after the variables the dimension
+/// renderer uses.
+pub fn decode_dim_style(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<DimStyleObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let name = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let _flag_64 = reader.read_b()?;
+    if !uses_string_stream {
+        let _xref_index = reader.read_bs()?;
+    }
+    let _xdep = reader.read_b()?;
+
+    if matches!(version, DwgVersion::R14) {
+        decode_r14_variables(reader, handle, name)
+    } else {
+        decode_r2000_variables(reader, version, handle, name, uses_string_stream)
+    }
+}
+
+fn decode_r14_variables(
+    reader: &mut BitReader<'_>,
+    handle: u64,
+    name: Option<String>,
+) -> Result<DimStyleObject> {
+    let _dimtol = reader.read_b()?;
+    let _dimlim = reader.read_b()?;
+    let dimtih = reader.read_b()? != 0;
+    let dimtoh = reader.read_b()? != 0;
+    let dimse1 = reader.read_b()? != 0;
+    let dimse2 = reader.read_b()? != 0;
+    let _dimalt = reader.read_b()?;
+    let _dimtofl = reader.read_b()?;
+    let _dimsah = reader.read_b()?;
+    let _dimtix = reader.read_b()?;
+    let _dimsoxd = reader.read_b()?;
+    let _dimaltd = reader.read_rc()?;
+    let _dimzin = reader.read_rc()?;
+    let _dimsd1 = reader.read_b()?;
+    let _dimsd2 = reader.read_b()?;
+    let _dimtolj = reader.read_rc()?;
+    let _dimjust = reader.read_rc()?;
+    let _dimfit = reader.read_rc()?;
+    let _dimupt = reader.read_b()?;
+    let _dimtzin = reader.read_rc()?;
+    let _dimaltz = reader.read_rc()?;
+    let _dimalttz = reader.read_rc()?;
+    let dimtad = u16::from(reader.read_rc()?);
+    let _dimunit = reader.read_bs()?;
+    let _dimaunit = reader.read_bs()?;
+    let dimdec = reader.read_bs()?;
+    let _dimtdec = reader.read_bs()?;
+    let _dimaltu = reader.read_bs()?;
+    let _dimalttd = reader.read_bs()?;
+    let dimscale = reader.read_bd()?;
+    let dimasz = reader.read_bd()?;
+    let dimexo = reader.read_bd()?;
+    let _dimdli = reader.read_bd()?;
+    let dimexe = reader.read_bd()?;
+    let _dimrnd = reader.read_bd()?;
+    let dimdle = reader.read_bd()?;
+    let _dimtp = reader.read_bd()?;
+    let _dimtm = reader.read_bd()?;
+    let dimtxt = reader.read_bd()?;
+    let dimcen = reader.read_bd()?;
+    let dimtsz = reader.read_bd()?;
+    let _dimaltf = reader.read_bd()?;
+    let dimlfac = reader.read_bd()?;
+    let _dimtvp = reader.read_bd()?;
+    let _dimtfac = reader.read_bd()?;
+    let dimgap = reader.read_bd()?;
+
+    Ok(DimStyleObject {
+        handle,
+        name,
+        dimscale,
+        dimasz,
+        dimexo,
+        dimexe,
+        dimdle,
+        dimtxt,
+        dimcen,
+        dimtsz,
+        dimlfac,
+        dimgap,
+        dimtad,
+        dimse1,
+        dimse2,
+        dimtih,
+        dimtoh,
+        dimdec,
+    })
+}
+
+fn decode_r2000_variables(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    handle: u64,
+    name: Option<String>,
+    uses_string_stream: bool,
+) -> Result<DimStyleObject> {
+    if !uses_string_stream {
+        let _dimpost = reader.read_tv()?;
+        let _dimapost = reader.read_tv()?;
+    }
+    let dimscale = reader.read_bd()?;
+    let dimasz = reader.read_bd()?;
+    let dimexo = reader.read_bd()?;
+    let _dimdli = reader.read_bd()?;
+    let dimexe = reader.read_bd()?;
+    let _dimrnd = reader.read_bd()?;
+    let dimdle = reader.read_bd()?;
+    let _dimtp = reader.read_bd()?;
+    let _dimtm = reader.read_bd()?;
+    if uses_string_stream {
+        let _dimfxl = reader.read_bd()?;
+        let _dimjogang = reader.read_bd()?;
+        let _dimtfill = reader.read_bs()?;
+        skip_cmc(reader, version, uses_string_stream)?;
+    }
+    let _dimtol = reader.read_b()?;
+    let _dimlim = reader.read_b()?;
+    let dimtih = reader.read_b()? != 0;
+    let dimtoh = reader.read_b()? != 0;
+    let dimse1 = reader.read_b()? != 0;
+    let dimse2 = reader.read_b()? != 0;
+    let dimtad = reader.read_bs()?;
+    let _dimzin = reader.read_bs()?;
+    let _dimazin = reader.read_bs()?;
+    if uses_string_stream {
+        let _dimarcsym = reader.read_bs()?;
+    }
+    let dimtxt = reader.read_bd()?;
+    let dimcen = reader.read_bd()?;
+    let dimtsz = reader.read_bd()?;
+    let _dimaltf = reader.read_bd()?;
+    let dimlfac = reader.read_bd()?;
+    let _dimtvp = reader.read_bd()?;
+    let _dimtfac = reader.read_bd()?;
+    let dimgap = reader.read_bd()?;
+    let _dimaltrnd = reader.read_bd()?;
+    let _dimalt = reader.read_b()?;
+    let _dimaltd = reader.read_bs()?;
+    let _dimtofl = reader.read_b()?;
+    let _dimsah = reader.read_b()?;
+    let _dimtix = reader.read_b()?;
+    let _dimsoxd = reader.read_b()?;
+    skip_cmc(reader, version, uses_string_stream)?;
+    skip_cmc(reader, version, uses_string_stream)?;
+    skip_cmc(reader, version, uses_string_stream)?;
+    let _dimadec = reader.read_bs()?;
+    let dimdec = reader.read_bs()?;
+
+    Ok(DimStyleObject {
+        handle,
+        name,
+        dimscale,
+        dimasz,
+        dimexo,
+        dimexe,
+        dimdle,
+        dimtxt,
+        dimcen,
+        dimtsz,
+        dimlfac,
+        dimgap,
+        dimtad,
+        dimse1,
+        dimse2,
+        dimtih,
+        dimtoh,
+        dimdec,
+    })
+}
+
+/// R2000 colors are a bare index; R2004+ add the RGB value and optional
+/// color/book names, which R2007+ keep in the string stream.
+fn skip_cmc(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    uses_string_stream: bool,
+) -> Result<()> {
+    let _index = reader.read_bs()?;
+    if matches!(version, DwgVersion::R2000) {
+        return Ok(());
+    }
+    let _rgb = reader.read_bl()?;
+    let flags = reader.read_rc()?;
+    if !uses_string_stream {
+        if (flags & 0x01) != 0 {
+            let _color_name = reader.read_tv()?;
+        }
+        if (flags & 0x02) != 0 {
+            let _book_name = reader.read_tv()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_dim_style;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    #[test]
+    fn decode_dim_style_reads_r2000_sizes() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x58).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_tv("ISO-25").expect("write name");
+        writer.write_b(0).expect("write 64-flag");
+        writer.write_bs(0).expect("write xref index");
+        writer.write_b(0).expect("write xdep");
+        writer.write_tv("").expect("write dimpost");
+        writer.write_tv("").expect("write dimapost");
+        // DIMSCALE, DIMASZ, DIMEXO, DIMDLI, DIMEXE, DIMRND, DIMDLE, DIMTP, DIMTM
+        for value in [1.0, 2.5, 0.625, 3.75, 1.25, 0.0, 0.0, 0.0, 0.0] {
+            writer.write_bd(value).expect("write size");
+        }
+        // DIMTOL, DIMLIM, DIMTIH, DIMTOH, DIMSE1, DIMSE2
+        for value in [0, 0, 0, 0, 1, 0] {
+            writer.write_b(value).expect("write flag");
+        }
+        writer.write_bs(1).expect("write dimtad");
+        writer.write_bs(8).expect("write dimzin");
+        writer.write_bs(0).expect("write dimazin");
+        // DIMTXT, DIMCEN, DIMTSZ, DIMALTF, DIMLFAC, DIMTVP, DIMTFAC, DIMGAP, DIMALTRND
+        for value in [2.5, 2.5, 0.0, 0.0394, 1.0, 0.0, 1.0, 0.625, 0.0] {
+            writer.write_bd(value).expect("write size");
+        }
+        writer.write_b(0).expect("write dimalt");
+        writer.write_bs(2).expect("write dimaltd");
+        for _ in 0..4 {
+            writer.write_b(0).expect("write fit flag");
+        }
+        for _ in 0..3 {
+            writer.write_bs(0).expect("write color");
+        }
+        writer.write_bs(0).expect("write dimadec");
+        writer.write_bs(2).expect("write dimdec");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let style = decode_dim_style(&mut reader, &DwgVersion::R2000, 0x58).expect("decode");
+
+        assert_eq!(style.handle, 0x58);
+        assert_eq!(style.name.as_deref(), Some("ISO-25"));
+        assert_eq!(style.dimasz, 2.5);
+        assert_eq!(style.dimexo, 0.625);
+        assert_eq!(style.dimexe, 1.25);
+        assert_eq!(style.dimtxt, 2.5);
+        assert_eq!(style.dimgap, 0.625);
+        assert_eq!(style.dimtad, 1);
+        assert_eq!(style.dimdec, 2);
+        assert!(style.dimse1);
+        assert!(!style.dimtih);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+/// Bits of the LAYER flags word as stored from R2000 on.
+pub const LAYER_FROZEN: u16 = 0x01;
+pub const LAYER_OFF: u16 = 0x02;
+pub const LAYER_FROZEN_IN_NEW_VIEWPORTS: u16 = 0x04;
+pub const LAYER_LOCKED: u16 = 0x08;
+pub const LAYER_PLOTTED: u16 = 0x10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerObject {
+    pub handle: u64,
+    pub name: String,
+    /// `LAYER_*` bits; the lineweight index sits in bits 5-9. R14 layers
+    /// store only the first four bits and report no lineweight.
+    pub flags: u16,
+    /// ACI color; negative when the layer is off.
+    pub color_index: i16,
+    /// LTYPE table entry, or 0 when the reference is null.
+    pub linetype_handle: u64,
+}
+
+impl LayerObject {
+    pub fn is_off(&self) -> bool {
+        self.color_index < 0 || self.flags & LAYER_OFF != 0
+    }
+
+    /// Frozen, frozen in new viewports and locked as the DXF group 70
+    /// bits 1, 2 and 4.
+    pub fn dxf_flags(&self) -> u16 {
+        layer_dxf_flags(self.flags)
+    }
+}
+
+/// DXF group 70 bits of the LAYER flags word `flags`.
+pub fn layer_dxf_flags(flags: u16) -> u16 {
+    let mut dxf_flags = 0;
+    if flags & LAYER_FROZEN != 0 {
+        dxf_flags |= 1;
+    }
+    if flags & LAYER_FROZEN_IN_NEW_VIEWPORTS != 0 {
+        dxf_flags |= 2;
+    }
+    if flags & LAYER_LOCKED != 0 {
+        dxf_flags |= 4;
+    }
+    dxf_flags
+}
+
+/// `LAYER_*` bits of the DXF group 70 bits `dxf_flags` and the off state.
+pub fn layer_flags_from_dxf(dxf_flags: u16, is_off: bool) -> u16 {
+    let mut flags = 0;
+    if dxf_flags & 1 != 0 {
+        flags |= LAYER_FROZEN;
+    }
+    if is_off {
+        flags |= LAYER_OFF;
+    }
+    if dxf_flags & 2 != 0 {
+        flags |= LAYER_FROZEN_IN_NEW_VIEWPORTS;
+    }
+    if dxf_flags & 4 != 0 {
+        flags |= LAYER_LOCKED;
+    }
+    flags
+}
+
+/// Reads the R14 frozen, off, frozen-in-new-viewports and locked bits into
+/// the `LAYER_*` bits later versions store in one word.
+pub fn read_r14_layer_flags(reader: &mut BitReader<'_>) -> Result<u16> {
+    let mut flags = 0;
+    if reader.read_b()? != 0 {
+        flags |= LAYER_FROZEN;
+    }
+    if reader.read_b()? != 0 {
+        flags |= LAYER_OFF;
+    }
+    if reader.read_b()? != 0 {
+        flags |= LAYER_FROZEN_IN_NEW_VIEWPORTS;
+    }
+    if reader.read_b()? != 0 {
+        flags |= LAYER_LOCKED;
+    }
+    Ok(flags)
+}
+
+/// Decodes an R14 or R2000 LAYER table entry whose reader is positioned
+/// right after the object type prefix, including the linetype reference
+/// of its handle stream. Later versions store the color as a CMC with true
+/// color data and are not supported.
+pub fn decode_layer(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<LayerObject> {
+    if !matches!(version, DwgVersion::R14 | DwgVersion::R2000) {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "LAYER decoding supports R14 and R2000, got {}",
+                version.as_str()
+            ),
+        ));
+    }
+    let prologue = parse_common_object_prologue(reader, version)?;
+    let handle = prologue.handle_or(object_handle);
+    let obj_size = prologue
+        .obj_size
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "missing layer data size"))?;
+    let name = reader.read_tv()?;
+    let _flag_64 = reader.read_b()?;
+    let _xref_index = reader.read_bs()?;
+    let _xdep = reader.read_b()?;
+    let flags = if matches!(version, DwgVersion::R14) {
+        read_r14_layer_flags(reader)?
+    } else {
+        reader.read_bs()?
+    };
+    let color_index = reader.read_bs()? as i16;
+
+    reader.set_bit_pos(obj_size);
+    let _owner = reader.read_h()?;
+    for _ in 0..prologue.num_reactors {
+        let _reactor = reader.read_h()?;
+    }
+    let _xdictionary = reader.read_h()?;
+    let _xref_block = reader.read_h()?;
+    if matches!(version, DwgVersion::R2000) {
+        let _plot_style = reader.read_h()?;
+    }
+    let linetype_handle = reader.read_h()?.resolve(handle);
+
+    Ok(LayerObject {
+        handle,
+        name,
+        flags,
+        color_index,
+        linetype_handle,
+    })
+}
@@ -0,0 +1,315 @@
+use crate::bit::{BitReader, Endian};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+/// Page setup stored at the start of every LAYOUT object (DXF `AcDbPlotSettings`).
+///
+/// Strings are `None` when they live in the R2007+ string stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlotSettings {
+    pub page_setup_name: Option<String>,
+    pub printer_name: Option<String>,
+    /// DXF group 70 plot layout flags.
+    pub flags: u16,
+    /// Unprintable margins in millimeters: left, bottom, right, top.
+    pub margins: [f64; 4],
+    /// Paper width and height in millimeters, before `rotation` is applied.
+    pub paper_width: f64,
+    pub paper_height: f64,
+    /// Canonical media name, e.g. `"ISO_A4_(210.00_x_297.00_MM)"`.
+    pub paper_size: Option<String>,
+    pub plot_origin: (f64, f64),
+    /// 0 inches, 1 millimeters, 2 pixels.
+    pub paper_units: u16,
+    /// Counter-clockwise quarter turns: 0 none, 1 90, 2 180, 3 270 degrees.
+    pub rotation: u16,
+    /// 0 display, 1 extents, 2 limits, 3 view, 4 window, 5 layout.
+    pub plot_type: u16,
+    pub window_min: (f64, f64),
+    pub window_max: (f64, f64),
+    /// Custom scale as `real_world_units` paper units per `drawing_units`.
+    pub real_world_units: f64,
+    pub drawing_units: f64,
+    /// Plot style table (`.ctb`/`.stb`) file name.
+    pub plot_style_table: Option<String>,
+    /// DXF group 75 standard scale; 0 is scaled to fit, 16 is 1:1.
+    pub scale_type: u16,
+    pub scale_factor: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutObject {
+    pub handle: u64,
+    pub plot_settings: PlotSettings,
+    /// `None` when it lives in the R2007+ string stream.
+    pub name: Option<String>,
+    /// 0 for the model layout, then the paperspace tabs in order.
+    pub tab_order: u32,
+    pub flags: u16,
+    pub limits_min: (f64, f64),
+    pub limits_max: (f64, f64),
+    pub extents_min: (f64, f64, f64),
+    pub extents_max: (f64, f64, f64),
+}
+
+impl LayoutObject {
+    /// Number of strings this object keeps in the R2007+ string stream, in
+    /// stream order: page setup, printer, paper size, plot style table and
+    /// layout name.
+    pub const STRING_STREAM_COUNT: usize = 5;
+
+    /// Fills the fields returned as `None` from string stream values.
+    pub fn apply_string_stream(&mut self, strings: &[String]) {
+        let mut strings = strings.iter().cloned();
+        let settings = &mut self.plot_settings;
+        for field in [
+            &mut settings.page_setup_name,
+            &mut settings.printer_name,
+            &mut settings.paper_size,
+            &mut settings.plot_style_table,
+            &mut self.name,
+        ] {
+            *field = field.take().or_else(|| strings.next());
+        }
+    }
+}
+
+/// Decodes the plot settings and the layout name, tab order, limits and
+/// extents of a LAYOUT object.
+///
+/// The UCS, viewport and handle data that follow are not read.
+pub fn decode_layout(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<LayoutObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let read_text = |reader: &mut BitReader<'_>| -> Result<Option<String>> {
+        if uses_string_stream {
+            Ok(None)
+        } else {
+            reader.read_tv().map(Some)
+        }
+    };
+
+    let page_setup_name = read_text(reader)?;
+    let printer_name = read_text(reader)?;
+    let flags = reader.read_bs()?;
+    let margins = [
+        reader.read_bd()?,
+        reader.read_bd()?,
+        reader.read_bd()?,
+        reader.read_bd()?,
+    ];
+    let paper_width = reader.read_bd()?;
+    let paper_height = reader.read_bd()?;
+    let paper_size = read_text(reader)?;
+    let plot_origin = (reader.read_bd()?, reader.read_bd()?);
+    let paper_units = reader.read_bs()?;
+    let rotation = reader.read_bs()?;
+    let plot_type = reader.read_bs()?;
+    let window_min = (reader.read_bd()?, reader.read_bd()?);
+    let window_max = (reader.read_bd()?, reader.read_bd()?);
+    if matches!(version, DwgVersion::R14 | DwgVersion::R2000) {
+        // Later versions reference the plot view through a handle instead.
+        let _plot_view_name = reader.read_tv()?;
+    }
+    let real_world_units = reader.read_bd()?;
+    let drawing_units = reader.read_bd()?;
+    let plot_style_table = read_text(reader)?;
+    let scale_type = reader.read_bs()?;
+    let scale_factor = reader.read_bd()?;
+    let _paper_image_origin = (reader.read_bd()?, reader.read_bd()?);
+    if !matches!(version, DwgVersion::R14 | DwgVersion::R2000) {
+        let _shade_plot_mode = reader.read_bs()?;
+        let _shade_plot_resolution = reader.read_bs()?;
+        let _shade_plot_dpi = reader.read_bs()?;
+    }
+
+    let name = read_text(reader)?;
+    let tab_order = reader.read_bl()?;
+    let layout_flags = reader.read_bs()?;
+    let _ucs_origin = reader.read_3bd()?;
+    let limits_min = (
+        reader.read_rd(Endian::Little)?,
+        reader.read_rd(Endian::Little)?,
+    );
+    let limits_max = (
+        reader.read_rd(Endian::Little)?,
+        reader.read_rd(Endian::Little)?,
+    );
+    let _insertion_base = reader.read_3bd()?;
+    let _ucs_x_axis = reader.read_3bd()?;
+    let _ucs_y_axis = reader.read_3bd()?;
+    let _elevation = reader.read_bd()?;
+    let _orthographic_type = reader.read_bs()?;
+    let extents_min = reader.read_3bd()?;
+    let extents_max = reader.read_3bd()?;
+
+    Ok(LayoutObject {
+        handle,
+        plot_settings: PlotSettings {
+            page_setup_name,
+            printer_name,
+            flags,
+            margins,
+            paper_width,
+            paper_height,
+            paper_size,
+            plot_origin,
+            paper_units,
+            rotation,
+            plot_type,
+            window_min,
+            window_max,
+            real_world_units,
+            drawing_units,
+            plot_style_table,
+            scale_type,
+            scale_factor,
+        },
+        name,
+        tab_order,
+        flags: layout_flags,
+        limits_min,
+        limits_max,
+        extents_min,
+        extents_max,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_layout, LayoutObject};
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    fn write_layout_body(writer: &mut BitWriter, strings: Option<[&str; 5]>) {
+        let write_text = |writer: &mut BitWriter, index: usize| {
+            if let Some(strings) = strings {
+                writer.write_tv(strings[index]).expect("write text");
+            }
+        };
+        write_text(writer, 0);
+        write_text(writer, 1);
+        writer.write_bs(688).expect("write plot flags");
+        for margin in [6.35, 19.05, 6.35, 19.05] {
+            writer.write_bd(margin).expect("write margin");
+        }
+        writer.write_bd(210.0).expect("write paper width");
+        writer.write_bd(297.0).expect("write paper height");
+        write_text(writer, 2);
+        writer.write_bd(0.0).expect("write origin x");
+        writer.write_bd(0.0).expect("write origin y");
+        writer.write_bs(1).expect("write paper units");
+        writer.write_bs(1).expect("write rotation");
+        writer.write_bs(5).expect("write plot type");
+        for _ in 0..4 {
+            writer.write_bd(0.0).expect("write window");
+        }
+        writer.write_bd(1.0).expect("write real world units");
+        writer.write_bd(2.0).expect("write drawing units");
+        write_text(writer, 3);
+        writer.write_bs(16).expect("write scale type");
+        writer.write_bd(0.5).expect("write scale factor");
+        writer.write_bd(0.0).expect("write image origin x");
+        writer.write_bd(0.0).expect("write image origin y");
+        for value in [0, 2, 300] {
+            writer.write_bs(value).expect("write shade plot");
+        }
+        write_text(writer, 4);
+        writer.write_bl(1).expect("write tab order");
+        writer.write_bs(1).expect("write layout flags");
+        writer.write_3bd(0.0, 0.0, 0.0).expect("write ucs origin");
+        for value in [0.0, 0.0, 420.0, 297.0] {
+            writer
+                .write_rd(Endian::Little, value)
+                .expect("write limits");
+        }
+        writer
+            .write_3bd(0.0, 0.0, 0.0)
+            .expect("write insertion base");
+        writer.write_3bd(1.0, 0.0, 0.0).expect("write ucs x axis");
+        writer.write_3bd(0.0, 1.0, 0.0).expect("write ucs y axis");
+        writer.write_bd(0.0).expect("write elevation");
+        writer.write_bs(0).expect("write ortho type");
+        writer.write_3bd(1.0, 2.0, 0.0).expect("write extents min");
+        writer.write_3bd(3.0, 4.0, 0.0).expect("write extents max");
+    }
+
+    #[test]
+    fn decode_layout_reads_r2004_plot_settings() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x6F).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(1).expect("write reactors");
+        writer.write_b(0).expect("write xdic missing");
+        write_layout_body(
+            &mut writer,
+            Some([
+                "Setup",
+                "DWG To PDF.pc3",
+                "ISO_A4",
+                "monochrome.ctb",
+                "Layout1",
+            ]),
+        );
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let layout = decode_layout(&mut reader, &DwgVersion::R2004, 0x6F).expect("decode layout");
+        let settings = &layout.plot_settings;
+
+        assert_eq!(layout.name.as_deref(), Some("Layout1"));
+        assert_eq!(layout.tab_order, 1);
+        assert_eq!(layout.limits_max, (420.0, 297.0));
+        assert_eq!(layout.extents_max, (3.0, 4.0, 0.0));
+        assert_eq!(settings.printer_name.as_deref(), Some("DWG To PDF.pc3"));
+        assert_eq!(settings.paper_size.as_deref(), Some("ISO_A4"));
+        assert_eq!(settings.plot_style_table.as_deref(), Some("monochrome.ctb"));
+        assert_eq!(
+            (settings.paper_width, settings.paper_height),
+            (210.0, 297.0)
+        );
+        assert_eq!(settings.margins[1], 19.05);
+        assert_eq!(settings.rotation, 1);
+        assert_eq!(settings.plot_type, 5);
+        assert_eq!(settings.scale_type, 16);
+        assert_eq!(settings.drawing_units, 2.0);
+    }
+
+    #[test]
+    fn apply_string_stream_fills_r2007_strings_in_order() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x6F).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(1).expect("write reactors");
+        writer.write_b(0).expect("write xdic missing");
+        write_layout_body(&mut writer, None);
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let mut layout =
+            decode_layout(&mut reader, &DwgVersion::R2007, 0x6F).expect("decode layout");
+        assert_eq!(layout.name, None);
+        assert_eq!(layout.tab_order, 1);
+        let strings: Vec<String> = ["", "None", "ISO_A4", "", "Layout1"]
+            .iter()
+            .map(|text| text.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(strings.len(), LayoutObject::STRING_STREAM_COUNT);
+        layout.apply_string_stream(&strings);
+
+        assert_eq!(layout.name.as_deref(), Some("Layout1"));
+        assert_eq!(layout.plot_settings.page_setup_name.as_deref(), Some(""));
+        assert_eq!(layout.plot_settings.paper_size.as_deref(), Some("ISO_A4"));
+    }
+}
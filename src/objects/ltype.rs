@@ -0,0 +1,152 @@
+use crate::bit::{BitReader, Endian};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LtypeDash {
+    /// Positive for a dash, negative for a gap, zero for a dot.
+    pub length: f64,
+    pub shape_code: u16,
+    pub offset: (f64, f64),
+    pub scale: f64,
+    pub rotation: f64,
+    pub shape_flag: u16,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LtypeObject {
+    pub handle: u64,
+    /// `None` when the name lives in the R2007+ string stream.
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub flags: u8,
+    pub pattern_length: f64,
+    pub alignment: u8,
+    pub dashes: Vec<LtypeDash>,
+}
+
+impl LtypeObject {
+    /// Continuous linetypes carry no dash elements.
+    pub fn is_continuous(&self) -> bool {
+        self.dashes.is_empty() || self.dashes.iter().all(|dash| dash.length >= 0.0)
+    }
+}
+
+/// Decodes an LTYPE table entry up to and including its dash elements.
+///
+/// Embedded text from complex linetypes and the shape file handles are not
+/// read.
+pub fn decode_ltype(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<LtypeObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    // R2007+ moves every string into the string stream at the end of the data.
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let name = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let flag_64 = reader.read_b()?;
+    // Sample files from R2007 on store only the two flag bits here; the xref
+    // index is not part of the data stream.
+    if !uses_string_stream {
+        let _xref_index = reader.read_bs()?;
+    }
+    let xdep = reader.read_b()?;
+    let description = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let pattern_length = reader.read_bd()?;
+    let alignment = reader.read_rc()?;
+    let num_dashes = reader.read_rc()?;
+
+    let mut dashes = Vec::with_capacity(usize::from(num_dashes));
+    for _ in 0..num_dashes {
+        let length = reader.read_bd()?;
+        let shape_code = reader.read_bs()?;
+        let offset_x = reader.read_rd(Endian::Little)?;
+        let offset_y = reader.read_rd(Endian::Little)?;
+        let scale = reader.read_bd()?;
+        let rotation = reader.read_bd()?;
+        let shape_flag = reader.read_bs()?;
+        dashes.push(LtypeDash {
+            length,
+            shape_code,
+            offset: (offset_x, offset_y),
+            scale,
+            rotation,
+            shape_flag,
+        });
+    }
+
+    Ok(LtypeObject {
+        handle,
+        name,
+        description,
+        flags: (flag_64 << 6) | (xdep << 4),
+        pattern_length,
+        alignment,
+        dashes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_ltype;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    #[test]
+    fn decode_ltype_reads_r2000_dashes() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x16).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_tv("DASHED").expect("write name");
+        writer.write_b(0).expect("write 64-flag");
+        writer.write_bs(0).expect("write xref index");
+        writer.write_b(0).expect("write xdep");
+        writer.write_tv("__ __ __").expect("write description");
+        writer.write_bd(0.75).expect("write pattern length");
+        writer.write_rc(b'A').expect("write alignment");
+        writer.write_rc(2).expect("write dash count");
+        for length in [0.5, -0.25] {
+            writer.write_bd(length).expect("write dash length");
+            writer.write_bs(0).expect("write shape code");
+            writer
+                .write_rd(Endian::Little, 0.0)
+                .expect("write x offset");
+            writer
+                .write_rd(Endian::Little, 0.0)
+                .expect("write y offset");
+            writer.write_bd(1.0).expect("write scale");
+            writer.write_bd(0.0).expect("write rotation");
+            writer.write_bs(0).expect("write shape flag");
+        }
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let ltype = decode_ltype(&mut reader, &DwgVersion::R2000, 0x16).expect("decode ltype");
+
+        assert_eq!(ltype.handle, 0x16);
+        assert_eq!(ltype.name.as_deref(), Some("DASHED"));
+        assert_eq!(ltype.description.as_deref(), Some("__ __ __"));
+        assert_eq!(ltype.pattern_length, 0.75);
+        assert_eq!(ltype.alignment, b'A');
+        assert_eq!(ltype.dashes.len(), 2);
+        assert_eq!(ltype.dashes[0].length, 0.5);
+        assert_eq!(ltype.dashes[1].length, -0.25);
+        assert!(!ltype.is_continuous());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+/// Row-major 4x4 mapper transform.
+const MATERIAL_MAP_MATRIX_SIZE: usize = 16;
+
+/// Color channel of a material: `method` 0 inherits the entity color, 1
+/// overrides it with `true_color`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaterialColor {
+    pub method: u8,
+    pub factor: f64,
+    pub true_color: Option<u32>,
+}
+
+impl MaterialColor {
+    pub fn is_override(&self) -> bool {
+        self.method == 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialMap {
+    pub blend_factor: f64,
+    pub projection: u8,
+    pub tiling: u8,
+    pub auto_transform: u8,
+    pub transform: [f64; MATERIAL_MAP_MATRIX_SIZE],
+    /// 0 uses the current scene, 1 reads `file_name`, 2 is procedural.
+    pub source: u8,
+    /// `None` when the name lives in the R2007+ string stream.
+    pub file_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialObject {
+    pub handle: u64,
+    /// `None` when the name lives in the R2007+ string stream.
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub ambient: MaterialColor,
+    pub diffuse: MaterialColor,
+    pub diffuse_map: MaterialMap,
+}
+
+impl MaterialObject {
+    /// Number of strings this object keeps in the R2007+ string stream, in
+    /// stream order: name, description, then the diffuse map file name.
+    pub fn string_stream_count(&self) -> usize {
+        if self.diffuse_map.source == 1 {
+            3
+        } else {
+            2
+        }
+    }
+
+    /// Fills the fields returned as `None` from string stream values.
+    pub fn apply_string_stream(&mut self, strings: &[String]) {
+        let mut strings = strings.iter().cloned();
+        self.name = self.name.take().or_else(|| strings.next());
+        self.description = self.description.take().or_else(|| strings.next());
+        if self.diffuse_map.source == 1 {
+            self.diffuse_map.file_name =
+                self.diffuse_map.file_name.take().or_else(|| strings.next());
+        }
+    }
+}
+
+/// Decodes a MATERIAL object up to its diffuse map.
+///
+/// Specular, reflection, opacity, bump and refraction channels follow and
+/// are not read.
+pub fn decode_material(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<MaterialObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    // R2007+ moves every string into the string stream at the end of the data.
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let name = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let description = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let ambient = read_material_color(reader)?;
+    let diffuse = read_material_color(reader)?;
+
+    let blend_factor = reader.read_bd()?;
+    let projection = reader.read_rc()?;
+    let tiling = reader.read_rc()?;
+    let auto_transform = reader.read_rc()?;
+    let mut transform = [0.0; MATERIAL_MAP_MATRIX_SIZE];
+    for value in transform.iter_mut() {
+        *value = reader.read_bd()?;
+    }
+    let source = reader.read_rc()?;
+    let file_name = if source == 1 && !uses_string_stream {
+        Some(reader.read_tv()?)
+    } else {
+        None
+    };
+
+    Ok(MaterialObject {
+        handle,
+        name,
+        description,
+        ambient,
+        diffuse,
+        diffuse_map: MaterialMap {
+            blend_factor,
+            projection,
+            tiling,
+            auto_transform,
+            transform,
+            source,
+            file_name,
+        },
+    })
+}
+
+/// Override colors are a bare `BL` with the 0xC2 true color marker, not a
+/// full CMC.
+fn read_material_color(reader: &mut BitReader<'_>) -> Result<MaterialColor> {
+    let method = reader.read_rc()?;
+    let factor = reader.read_bd()?;
+    let true_color = if method == 1 {
+        let raw = reader.read_bl()?;
+        ((raw >> 24) == 0xC2).then_some(raw & 0x00FF_FFFF)
+    } else {
+        None
+    };
+    Ok(MaterialColor {
+        method,
+        factor,
+        true_color,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_material;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    fn write_identity(writer: &mut BitWriter) {
+        for index in 0..16 {
+            let value = if index % 5 == 0 { 1.0 } else { 0.0 };
+            writer.write_bd(value).expect("write matrix value");
+        }
+    }
+
+    #[test]
+    fn decode_material_reads_r2004_colors_and_diffuse_map() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0xDAA).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(1).expect("write reactors");
+        writer.write_b(0).expect("write xdic missing");
+        writer.write_tv("Beech").expect("write name");
+        writer
+            .write_tv("Generic material.")
+            .expect("write description");
+        writer.write_rc(1).expect("write ambient method");
+        writer.write_bd(1.0).expect("write ambient factor");
+        writer.write_bl(0xC2E9_B486).expect("write ambient color");
+        writer.write_rc(0).expect("write diffuse method");
+        writer.write_bd(0.5).expect("write diffuse factor");
+        writer.write_bd(1.0).expect("write blend factor");
+        writer.write_rc(2).expect("write projection");
+        writer.write_rc(1).expect("write tiling");
+        writer.write_rc(1).expect("write auto transform");
+        write_identity(&mut writer);
+        writer.write_rc(1).expect("write source");
+        writer.write_tv("beech.jpg").expect("write file name");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let material =
+            decode_material(&mut reader, &DwgVersion::R2004, 0xDAA).expect("decode material");
+
+        assert_eq!(material.handle, 0xDAA);
+        assert_eq!(material.name.as_deref(), Some("Beech"));
+        assert_eq!(material.description.as_deref(), Some("Generic material."));
+        assert!(material.ambient.is_override());
+        assert_eq!(material.ambient.true_color, Some(0xE9_B486));
+        assert!(!material.diffuse.is_override());
+        assert_eq!(material.diffuse.factor, 0.5);
+        assert_eq!(material.diffuse_map.projection, 2);
+        assert_eq!(material.diffuse_map.transform[0], 1.0);
+        assert_eq!(material.diffuse_map.transform[1], 0.0);
+        assert_eq!(material.diffuse_map.file_name.as_deref(), Some("beech.jpg"));
+    }
+
+    #[test]
+    fn apply_string_stream_fills_r2007_strings_in_order() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x21).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing");
+        for _ in 0..2 {
+            writer.write_rc(0).expect("write color method");
+            writer.write_bd(1.0).expect("write color factor");
+        }
+        writer.write_bd(1.0).expect("write blend factor");
+        writer.write_rc(2).expect("write projection");
+        writer.write_rc(1).expect("write tiling");
+        writer.write_rc(1).expect("write auto transform");
+        write_identity(&mut writer);
+        writer.write_rc(1).expect("write source");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let mut material =
+            decode_material(&mut reader, &DwgVersion::R2007, 0x21).expect("decode material");
+        assert_eq!(material.name, None);
+        assert_eq!(material.string_stream_count(), 3);
+        material.apply_string_stream(&["Global".into(), String::new(), "map.png".into()]);
+
+        assert_eq!(material.name.as_deref(), Some("Global"));
+        assert_eq!(material.description.as_deref(), Some(""));
+        assert_eq!(material.diffuse_map.file_name.as_deref(), Some("map.png"));
+    }
+}
@@ -1,17 +1,58 @@
+pub mod block_record;
+pub mod common;
+pub mod dictionary;
+pub mod dim_style;
 pub mod handle;
+pub mod layer;
+pub mod layout;
+pub mod ltype;
+pub mod material;
+pub mod object_header;
 pub mod object_header_r2000;
 pub mod object_header_r2010;
 pub mod object_locator;
 pub mod object_record;
 pub mod object_ref;
 pub mod object_type;
+pub mod scale;
+pub mod table_control;
+pub mod table_style;
+pub mod text_style;
+pub mod view;
+pub mod visual_style;
+pub mod xdata;
 
+pub use block_record::{decode_block_record, BlockRecordObject};
+pub use common::{parse_common_object_prologue, CommonObjectPrologue};
+pub use dictionary::{decode_dictionary, decode_xrecord, DictionaryObject, XRecordObject};
+pub use dim_style::{decode_dim_style, DimStyleObject};
 pub use handle::Handle;
+pub use layer::{
+    decode_layer, layer_dxf_flags, layer_flags_from_dxf, read_r14_layer_flags, LayerObject,
+};
+pub use layout::{decode_layout, LayoutObject, PlotSettings};
+pub use ltype::{decode_ltype, LtypeDash, LtypeObject};
+pub use material::{decode_material, MaterialColor, MaterialMap, MaterialObject};
+pub use object_header::{parse_object_header, read_object_type, ObjectHeader};
 pub use object_header_r2000::{parse_at as parse_object_header_r2000, ObjectHeaderR2000};
 pub use object_header_r2010::{parse_at as parse_object_header_r2010, ObjectHeaderR2010};
 pub use object_locator::{build_object_index, build_object_index_from_directory, ObjectIndex};
-pub use object_record::{parse_object_record, ObjectRecord};
+pub use object_record::{parse_object_record, BitSlice, ObjectRecord};
 pub use object_ref::ObjectRef;
 pub use object_type::{
     object_type_class, object_type_info, object_type_name, ObjectClass, ObjectTypeInfo,
 };
+pub use scale::{decode_scale, ScaleObject};
+pub use table_control::{
+    decode_table_control, decode_table_entry_name, TableControlObject, TableKind,
+};
+pub use table_style::{
+    decode_table_style, TableStyleBorder, TableStyleColor, TableStyleObject, TableStyleRow,
+};
+pub use text_style::{decode_text_style, TextStyleObject};
+pub use view::{decode_view, ViewObject, VIEW_MODE_PERSPECTIVE};
+pub use visual_style::{decode_visual_style, VisualStyleObject};
+pub use xdata::{
+    decode_xdata_items, decode_xrecord_items, group_code_kind, read_eed, read_object_eed,
+    XDataKind, XDataValue,
+};
@@ -0,0 +1,92 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::object_header_r2000;
+use crate::objects::object_header_r2010;
+use crate::objects::object_record::ObjectRecord;
+
+/// Object header fields shared by every supported version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectHeader {
+    pub data_size: u32,
+    pub type_code: u16,
+    /// Only stored by R2010+ (`MC` before the object type).
+    pub handle_stream_size_bits: Option<u32>,
+}
+
+/// Parses the header of `record` using the layout of `version`.
+pub fn parse_object_header(
+    record: &ObjectRecord<'_>,
+    version: &DwgVersion,
+) -> Result<ObjectHeader> {
+    if uses_r2010_header(version) {
+        let header = object_header_r2010::parse_from_record(record)?;
+        Ok(ObjectHeader {
+            data_size: header.data_size,
+            type_code: header.type_code,
+            handle_stream_size_bits: Some(header.handle_stream_size_bits),
+        })
+    } else {
+        let header = object_header_r2000::parse_from_record(record)?;
+        Ok(ObjectHeader {
+            data_size: header.data_size,
+            type_code: header.type_code,
+            handle_stream_size_bits: None,
+        })
+    }
+}
+
+/// Reads the object type at the start of a record body, leaving `reader` on
+/// the first object-specific field.
+pub fn read_object_type(reader: &mut BitReader<'_>, version: &DwgVersion) -> Result<u16> {
+    let type_code = if uses_r2010_header(version) {
+        let _handle_stream_size_bits = reader.read_umc()?;
+        reader.read_ot_r2010()?
+    } else {
+        reader.read_bs()?
+    };
+    if type_code == 0 {
+        return Err(DwgError::new(ErrorKind::Format, "object type code is zero"));
+    }
+    Ok(type_code)
+}
+
+fn uses_r2010_header(version: &DwgVersion) -> bool {
+    matches!(
+        version,
+        DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_object_type;
+    use crate::bit::{BitReader, BitWriter};
+    use crate::dwg::version::DwgVersion;
+
+    #[test]
+    fn read_object_type_reads_bitshort_before_r2010() {
+        let mut writer = BitWriter::new();
+        writer.write_bs(0x13).expect("write type");
+        writer.write_b(1).expect("write marker");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        assert_eq!(
+            read_object_type(&mut reader, &DwgVersion::R2000).ok(),
+            Some(0x13)
+        );
+        assert_eq!(reader.read_b().ok(), Some(1));
+    }
+
+    #[test]
+    fn read_object_type_rejects_zero() {
+        let mut writer = BitWriter::new();
+        writer.write_bs(0).expect("write type");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        assert!(read_object_type(&mut reader, &DwgVersion::R2004).is_err());
+    }
+}
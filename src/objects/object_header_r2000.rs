@@ -6,7 +6,7 @@ use crate::objects::ObjectRef;
 
 #[derive(Debug, Clone, Copy)]
 pub struct ObjectHeaderR2000 {
-    pub offset: u32,
+    pub offset: u64,
     pub data_size: u32,
     pub body_start: usize,
     pub body_bit_pos: u8,
@@ -15,7 +15,7 @@ pub struct ObjectHeaderR2000 {
 
 impl ObjectHeaderR2000 {
     pub fn total_size(&self) -> u32 {
-        let header_bytes = self.body_start as u32 - self.offset;
+        let header_bytes = (self.body_start as u64).saturating_sub(self.offset) as u32;
         header_bytes + self.data_size + 2
     }
 
@@ -36,7 +36,7 @@ impl ObjectHeaderR2000 {
     }
 }
 
-pub fn parse_at(bytes: &[u8], offset: u32) -> Result<ObjectHeaderR2000> {
+pub fn parse_at(bytes: &[u8], offset: u64) -> Result<ObjectHeaderR2000> {
     let record = parse_object_record(bytes, offset)?;
     parse_from_record(&record)
 }
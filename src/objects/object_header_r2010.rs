@@ -6,7 +6,7 @@ use crate::objects::ObjectRef;
 
 #[derive(Debug, Clone, Copy)]
 pub struct ObjectHeaderR2010 {
-    pub offset: u32,
+    pub offset: u64,
     pub data_size: u32,
     pub body_start: usize,
     pub body_bit_pos: u8,
@@ -20,7 +20,7 @@ impl ObjectHeaderR2010 {
     }
 }
 
-pub fn parse_at(bytes: &[u8], offset: u32) -> Result<ObjectHeaderR2010> {
+pub fn parse_at(bytes: &[u8], offset: u64) -> Result<ObjectHeaderR2010> {
     let record = parse_object_record(bytes, offset)?;
     parse_from_record(&record)
 }
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
 use crate::container::section_directory::{SectionDirectory, SectionKind, SectionLocatorRecord};
 use crate::container::section_loader;
 use crate::core::config::ParseConfig;
 use crate::core::error::{DwgError, ErrorKind};
+use crate::core::progress::{Progress, ProgressStage};
 use crate::core::result::Result;
 use crate::io::ByteReader;
 use crate::objects::{Handle, ObjectRef};
@@ -12,11 +14,53 @@ use crate::objects::{Handle, ObjectRef};
 pub struct ObjectIndex {
     pub objects: Vec<ObjectRef>,
     by_handle: HashMap<Handle, usize>,
+    /// Positions into `objects`, built on first use.
+    sorted_by_handle: OnceLock<Vec<usize>>,
+    sorted_by_offset: OnceLock<Vec<usize>>,
 }
 
 impl ObjectIndex {
     pub fn get(&self, handle: Handle) -> Option<&ObjectRef> {
-        self.by_handle.get(&handle).map(|idx| &self.objects[*idx])
+        self.by_handle
+            .get(&handle)
+            .and_then(|idx| self.objects.get(*idx))
+    }
+
+    /// Entries with `start <= handle <= end` in ascending handle order.
+    ///
+    /// Duplicate handles resolve to the same entry as [`ObjectIndex::get`].
+    pub fn handles_in_range(
+        &self,
+        start: Handle,
+        end: Handle,
+    ) -> impl Iterator<Item = &ObjectRef> + '_ {
+        let sorted = self.sorted_by_handle.get_or_init(|| {
+            let mut positions: Vec<usize> = self.by_handle.values().copied().collect();
+            positions.sort_unstable_by_key(|&idx| self.handle_at(idx));
+            positions
+        });
+        let from = sorted.partition_point(|&idx| self.handle_at(idx) < Some(start.0));
+        let to = sorted.partition_point(|&idx| self.handle_at(idx) <= Some(end.0));
+        sorted
+            .get(from..to.max(from))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(move |&idx| self.objects.get(idx))
+    }
+
+    /// Every entry, duplicates included, in ascending offset order. Reading
+    /// records in this order walks the object stream front to back.
+    pub fn iter_by_offset(&self) -> impl Iterator<Item = &ObjectRef> + '_ {
+        let sorted = self.sorted_by_offset.get_or_init(|| {
+            let mut positions: Vec<usize> = (0..self.objects.len()).collect();
+            positions.sort_by_key(|&idx| self.objects.get(idx).map(|obj| obj.offset));
+            positions
+        });
+        sorted.iter().filter_map(move |&idx| self.objects.get(idx))
+    }
+
+    fn handle_at(&self, idx: usize) -> Option<u64> {
+        self.objects.get(idx).map(|obj| obj.handle.0)
     }
 
     pub fn len(&self) -> usize {
@@ -44,7 +88,12 @@ impl ObjectIndex {
                 by_handle.insert(obj.handle, idx);
             }
         }
-        Self { objects, by_handle }
+        Self {
+            objects,
+            by_handle,
+            sorted_by_handle: OnceLock::new(),
+            sorted_by_offset: OnceLock::new(),
+        }
     }
 }
 
@@ -76,7 +125,7 @@ fn find_object_map_record(directory: &SectionDirectory) -> Option<SectionLocator
         .cloned()
 }
 
-fn parse_object_map(bytes: &[u8], _config: &ParseConfig) -> Result<ObjectIndex> {
+fn parse_object_map(bytes: &[u8], config: &ParseConfig) -> Result<ObjectIndex> {
     let mut reader = ByteReader::new(bytes);
     let mut objects = Vec::new();
 
@@ -105,7 +154,7 @@ fn parse_object_map(bytes: &[u8], _config: &ParseConfig) -> Result<ObjectIndex>
         }
 
         let start = reader.tell();
-        if !_config.strict {
+        if !config.strict {
             last_handle = 0;
             last_offset = 0;
         }
@@ -128,16 +177,9 @@ fn parse_object_map(bytes: &[u8], _config: &ParseConfig) -> Result<ObjectIndex>
                 )
                 .with_offset(reader.tell()));
             }
-            if last_offset > u32::MAX as i64 {
-                return Err(DwgError::new(
-                    ErrorKind::Format,
-                    "object map offset exceeds u32 range",
-                )
-                .with_offset(reader.tell()));
-            }
             objects.push(ObjectRef {
                 handle: Handle(last_handle as u64),
-                offset: last_offset as u32,
+                offset: last_offset as u64,
             });
         }
 
@@ -146,6 +188,13 @@ fn parse_object_map(bytes: &[u8], _config: &ParseConfig) -> Result<ObjectIndex>
             break;
         }
         let _crc = read_u16_be(&mut reader)?;
+        config.report_progress(Progress {
+            stage: ProgressStage::IndexBuild,
+            objects: objects.len() as u64,
+            objects_total: None,
+            bytes: reader.tell(),
+            bytes_total: Some(bytes.len() as u64),
+        });
     }
 
     Ok(ObjectIndex::from_objects(objects))
@@ -161,7 +210,8 @@ fn read_modular_char(reader: &mut ByteReader<'_>) -> Result<i64> {
     let mut value: i64 = 0;
     let mut shift = 0;
 
-    for _ in 0..4 {
+    // Nine bytes carry 62 bits, enough for object map offsets past 4 GiB.
+    for _ in 0..9 {
         let mut byte = reader.read_u8()?;
         if (byte & 0x80) == 0 {
             let negative = (byte & 0x40) != 0;
@@ -178,7 +228,12 @@ fn read_modular_char(reader: &mut ByteReader<'_>) -> Result<i64> {
         value |= (byte as i64) << shift;
         shift += 7;
     }
-    Ok(value)
+
+    Err(DwgError::new(
+        ErrorKind::Format,
+        "object map modular char exceeds 64-bit range",
+    )
+    .with_offset(reader.tell()))
 }
 
 #[cfg(test)]
@@ -208,6 +263,30 @@ mod tests {
         assert_eq!(resolved.offset, 527_255);
     }
 
+    #[test]
+    fn object_index_queries_handle_ranges_and_offset_order() {
+        let index = ObjectIndex::from_objects(
+            [(5, 40), (1, 30), (9, 10), (5, 50), (3, 20)]
+                .into_iter()
+                .map(|(handle, offset)| ObjectRef {
+                    handle: Handle(handle),
+                    offset,
+                })
+                .collect(),
+        );
+
+        let in_range: Vec<(u64, u64)> = index
+            .handles_in_range(Handle(2), Handle(9))
+            .map(|obj| (obj.handle.0, obj.offset))
+            .collect();
+        assert_eq!(in_range, vec![(3, 20), (5, 50), (9, 10)]);
+        assert_eq!(index.handles_in_range(Handle(6), Handle(8)).count(), 0);
+        assert_eq!(index.handles_in_range(Handle(9), Handle(1)).count(), 0);
+
+        let offsets: Vec<u64> = index.iter_by_offset().map(|obj| obj.offset).collect();
+        assert_eq!(offsets, vec![10, 20, 30, 40, 50]);
+    }
+
     #[test]
     fn parse_multiblock_object_map_keeps_running_deltas() {
         let bytes = vec![
@@ -221,14 +300,34 @@ mod tests {
             0x00, 0x00, // crc
             0x00, 0x02, // terminator block
         ];
-        let mut config = ParseConfig::default();
-        config.strict = true;
+        let config = ParseConfig {
+            strict: true,
+            ..ParseConfig::default()
+        };
         let index = parse_object_map(&bytes, &config).expect("index");
-        let refs: Vec<(u64, u32)> = index
+        let refs: Vec<(u64, u64)> = index
             .objects
             .iter()
             .map(|obj| (obj.handle.0, obj.offset))
             .collect();
         assert_eq!(refs, vec![(1, 10), (3, 14), (10, 22), (12, 25)]);
     }
+
+    #[test]
+    fn parse_object_map_keeps_offsets_past_4_gib() {
+        let bytes = vec![
+            0x00, 0x08, // block: 2-byte header + 6-byte payload
+            0x01, // +1
+            0x90, 0x80, 0x80, 0x80, 0x10, // +0x1_0000_0010
+            0x00, 0x00, // crc
+            0x00, 0x02, // terminator block
+        ];
+        let config = ParseConfig {
+            strict: true,
+            ..ParseConfig::default()
+        };
+        let index = parse_object_map(&bytes, &config).expect("index");
+        let offset = index.get(Handle(1)).expect("handle 1").offset;
+        assert_eq!(offset, 0x1_0000_0010);
+    }
 }
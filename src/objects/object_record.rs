@@ -1,11 +1,30 @@
-use crate::bit::BitReader;
+use crate::bit::{BitReader, Endian};
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
 use std::borrow::Cow;
 
+/// Bit range `[start, end)` of one object stream, counted from the first
+/// bit of [`ObjectRecord::stream_reader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitSlice {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl BitSlice {
+    pub fn len(&self) -> u32 {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ObjectRecord<'a> {
-    pub offset: u32,
+    pub offset: u64,
     pub size: u32,
     pub body_start: usize,
     pub body_bit_pos: u8,
@@ -37,14 +56,177 @@ impl<'a> ObjectRecord<'a> {
         self.codepage = codepage;
         self
     }
+
+    /// Reader positioned at `slice.start`. Unlike [`Self::bit_reader`], it
+    /// also covers the trailing CRC bytes, which the handle stream of R2010+
+    /// records runs into.
+    pub fn stream_reader(&self, slice: BitSlice) -> BitReader<'_> {
+        let mut reader = BitReader::new_with_codepage(self.stream_bytes(), self.codepage);
+        reader.set_bit_pos(slice.start);
+        reader
+    }
+
+    /// Data stream of an R2007+ record: the object type, the fixed fields,
+    /// and the string stream at its tail. Its end is where the handle stream
+    /// starts, taken from the sizes stored in the record.
+    pub fn data_bits(&self, version: &DwgVersion) -> Result<BitSlice> {
+        let bounds = self.stream_bounds(version)?;
+        Ok(BitSlice {
+            start: bounds.data_start,
+            end: bounds.data_end,
+        })
+    }
+
+    /// String stream of an R2007+ record, or `None` when the flag in the
+    /// last data bit says the object stores no strings.
+    pub fn string_bits(&self, version: &DwgVersion) -> Result<Option<BitSlice>> {
+        let bounds = self.stream_bounds(version)?;
+        let flag_pos = bounds.data_end.checked_sub(1);
+        let Some(flag_pos) = flag_pos.filter(|pos| *pos >= bounds.data_start) else {
+            return Ok(None);
+        };
+        let mut reader = self.stream_reader(BitSlice {
+            start: flag_pos,
+            end: bounds.data_end,
+        });
+        if reader.read_b()? == 0 {
+            return Ok(None);
+        }
+
+        // The size sits right before the flag; a set high bit means the
+        // upper 15 bits follow in a second size field before the first.
+        let mut size_pos = string_size_field_start(flag_pos, bounds.data_start)?;
+        reader.set_bit_pos(size_pos);
+        let mut size_bits = u32::from(reader.read_rs(Endian::Little)?);
+        if size_bits & 0x8000 != 0 {
+            size_pos = string_size_field_start(size_pos, bounds.data_start)?;
+            reader.set_bit_pos(size_pos);
+            let high = u32::from(reader.read_rs(Endian::Little)?);
+            size_bits = (size_bits & 0x7FFF) | (high << 15);
+        }
+        let start = size_pos
+            .checked_sub(size_bits)
+            .filter(|start| *start >= bounds.data_start)
+            .ok_or_else(|| {
+                DwgError::new(
+                    ErrorKind::Format,
+                    format!("string stream of {size_bits} bits exceeds the data stream"),
+                )
+                .with_offset(self.offset)
+            })?;
+        Ok(Some(BitSlice {
+            start,
+            end: size_pos,
+        }))
+    }
+
+    /// Handle stream of an R2007+ record, from the end of the data stream to
+    /// the size declared in the record.
+    pub fn handle_bits(&self, version: &DwgVersion) -> Result<BitSlice> {
+        let bounds = self.stream_bounds(version)?;
+        Ok(BitSlice {
+            start: bounds.data_end,
+            end: bounds.handle_end,
+        })
+    }
+
+    /// Body bytes followed by the trailing CRC.
+    fn stream_bytes(&self) -> &[u8] {
+        let body_offset = self.body_start.saturating_sub(self.offset as usize);
+        self.raw.get(body_offset..).unwrap_or(self.body.as_ref())
+    }
+
+    fn stream_bounds(&self, version: &DwgVersion) -> Result<StreamBounds> {
+        let mut reader = BitReader::new(self.stream_bytes());
+        reader.set_pos(0, self.body_bit_pos);
+        let body_start = reader.tell_bits() as u32;
+        let body_bits = self
+            .size
+            .checked_mul(8)
+            .ok_or_else(|| DwgError::new(ErrorKind::Format, "object size bits overflow"))?;
+        let bounds = match version {
+            DwgVersion::R2007 => {
+                // The type is followed by ObjSize, the data stream length
+                // counted from the start of the body.
+                let _type_code = reader.read_bs()?;
+                let obj_size = reader.read_rl(Endian::Little)?;
+                StreamBounds {
+                    data_start: body_start,
+                    data_end: body_start.saturating_add(obj_size),
+                    handle_end: body_start.saturating_add(body_bits),
+                }
+            }
+            DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018 => {
+                // The record size leaves out the handle stream size UMC, so
+                // both streams start after it and end past `body`.
+                let handle_stream_bits = reader.read_umc()?;
+                let data_start = reader.tell_bits() as u32;
+                let data_bits = body_bits.checked_sub(handle_stream_bits).ok_or_else(|| {
+                    DwgError::new(
+                        ErrorKind::Format,
+                        "R2010 handle stream exceeds object data size",
+                    )
+                    .with_offset(self.offset)
+                })?;
+                let data_end = data_start.saturating_add(data_bits);
+                StreamBounds {
+                    data_start,
+                    data_end,
+                    handle_end: data_end.saturating_add(handle_stream_bits),
+                }
+            }
+            _ => {
+                return Err(DwgError::new(
+                    ErrorKind::Unsupported,
+                    format!(
+                        "object streams are split only from R2007 on, not {}",
+                        version.as_str()
+                    ),
+                ))
+            }
+        };
+        let total_bits = u32::try_from(reader.total_bits()).unwrap_or(u32::MAX);
+        if bounds.data_end < bounds.data_start
+            || bounds.handle_end < bounds.data_end
+            || bounds.handle_end > total_bits
+        {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!(
+                    "object stream sizes exceed the record: data ends at bit {}, handles at {}",
+                    bounds.data_end, bounds.handle_end
+                ),
+            )
+            .with_offset(self.offset));
+        }
+        Ok(bounds)
+    }
+}
+
+struct StreamBounds {
+    data_start: u32,
+    data_end: u32,
+    handle_end: u32,
+}
+
+fn string_size_field_start(end: u32, data_start: u32) -> Result<u32> {
+    end.checked_sub(16)
+        .filter(|start| *start >= data_start)
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "string stream size field out of range"))
 }
 
-pub fn parse_object_record<'a>(bytes: &'a [u8], offset: u32) -> Result<ObjectRecord<'a>> {
-    let offset_usize = offset as usize;
+pub fn parse_object_record<'a>(bytes: &'a [u8], offset: u64) -> Result<ObjectRecord<'a>> {
+    let offset_usize = usize::try_from(offset).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            "object record offset exceeds the addressable memory of this platform",
+        )
+        .with_offset(offset)
+    })?;
     if offset_usize >= bytes.len() {
         return Err(
             DwgError::new(ErrorKind::Format, "object record offset exceeds file size")
-                .with_offset(offset as u64),
+                .with_offset(offset),
         );
     }
 
@@ -54,8 +236,7 @@ pub fn parse_object_record<'a>(bytes: &'a [u8], offset: u32) -> Result<ObjectRec
     let size = reader.read_ms()?; // size in bytes excluding CRC
     if size == 0 {
         return Err(
-            DwgError::new(ErrorKind::Format, "object record size is zero")
-                .with_offset(offset as u64),
+            DwgError::new(ErrorKind::Format, "object record size is zero").with_offset(offset),
         );
     }
 
@@ -63,17 +244,15 @@ pub fn parse_object_record<'a>(bytes: &'a [u8], offset: u32) -> Result<ObjectRec
     let end = body_start
         .checked_add(size as usize)
         .ok_or_else(|| DwgError::new(ErrorKind::Format, "object size overflow"))?;
-    if end + 2 > bytes.len() {
+    let raw_end = end + 2;
+    let (Some(body), Some(raw)) = (bytes.get(body_start..end), bytes.get(offset_usize..raw_end))
+    else {
         return Err(DwgError::new(
             ErrorKind::Format,
             format!("object record exceeds file size: end {end} + crc"),
         )
-        .with_offset(offset as u64));
-    }
-
-    let raw_end = end + 2;
-    let body = &bytes[body_start..end];
-    let raw = &bytes[offset_usize..raw_end];
+        .with_offset(offset));
+    };
 
     Ok(ObjectRecord {
         offset,
@@ -86,7 +265,7 @@ pub fn parse_object_record<'a>(bytes: &'a [u8], offset: u32) -> Result<ObjectRec
     })
 }
 
-pub fn parse_object_record_owned(bytes: &[u8], offset: u32) -> Result<ObjectRecord<'static>> {
+pub fn parse_object_record_owned(bytes: &[u8], offset: u64) -> Result<ObjectRecord<'static>> {
     let record = parse_object_record(bytes, offset)?;
     Ok(ObjectRecord {
         offset: record.offset,
@@ -98,3 +277,55 @@ pub fn parse_object_record_owned(bytes: &[u8], offset: u32) -> Result<ObjectReco
         codepage: record.codepage,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::dwg::decoder::Decoder;
+    use crate::dwg::version::DwgVersion;
+    use crate::objects::parse_object_header;
+
+    #[test]
+    fn stream_slices_follow_header_sizes_from_r2007() {
+        for path in [
+            "test_dwg/line_2007.dwg",
+            "test_dwg/line_2010.dwg",
+            "test_dwg/acadsharp/sample_AC1032.dwg",
+        ] {
+            let bytes = std::fs::read(path).expect("sample file");
+            let decoder = Decoder::new(&bytes, Default::default()).expect("decoder");
+            let version = decoder.version();
+            let index = decoder.build_object_index().expect("object index");
+            let mut layer_names = Vec::new();
+            for object in &index.objects {
+                let record = decoder.parse_object_record(object.offset).expect("record");
+                let data = record.data_bits(version).expect("data stream");
+                let handles = record.handle_bits(version).expect("handle stream");
+                assert_eq!(data.end, handles.start, "{path} handle {}", object.handle.0);
+
+                let Some(strings) = record.string_bits(version).expect("string stream") else {
+                    continue;
+                };
+                assert!(data.start <= strings.start && strings.end < data.end);
+                let header = parse_object_header(&record, version).expect("header");
+                if header.type_code == 0x33 {
+                    let mut reader = record.stream_reader(strings);
+                    layer_names.push(reader.read_tu().expect("layer name"));
+                }
+            }
+            assert!(layer_names.iter().any(|name| name == "0"), "{path}");
+        }
+    }
+
+    #[test]
+    fn stream_slices_need_r2007_or_later() {
+        let bytes = std::fs::read("test_dwg/line_2004.dwg").expect("sample file");
+        let decoder = Decoder::new(&bytes, Default::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let object = index.objects.first().expect("object");
+        let record = decoder.parse_object_record(object.offset).expect("record");
+
+        assert!(record.data_bits(&DwgVersion::R2004).is_err());
+        assert!(record.handle_bits(&DwgVersion::R2004).is_err());
+        assert!(record.string_bits(&DwgVersion::R2004).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleObject {
+    pub handle: u64,
+    /// Display name such as `"1:50"`; `None` when it lives in the R2007+
+    /// string stream.
+    pub name: Option<String>,
+    /// Paper units matching `drawing_units`; the scale factor is their ratio.
+    pub paper_units: f64,
+    pub drawing_units: f64,
+    /// DXF group 290: set on the 1:1 scale of the list.
+    pub is_unit_scale: bool,
+}
+
+impl ScaleObject {
+    /// Drawing units per paper unit, or `None` for a zero paper unit.
+    pub fn factor(&self) -> Option<f64> {
+        (self.paper_units != 0.0).then(|| self.drawing_units / self.paper_units)
+    }
+}
+
+/// Decodes a SCALE object, one entry of the `ACAD_SCALELIST` dictionary.
+pub fn decode_scale(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<ScaleObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let _flags = reader.read_bs()?;
+    let name = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let paper_units = reader.read_bd()?;
+    let drawing_units = reader.read_bd()?;
+    let is_unit_scale = reader.read_b()? != 0;
+
+    Ok(ScaleObject {
+        handle,
+        name,
+        paper_units,
+        drawing_units,
+        is_unit_scale,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_scale;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    #[test]
+    fn decode_scale_reads_r2000_name_and_units() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x44).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(1).expect("write reactors");
+        writer.write_bs(0).expect("write flags");
+        writer.write_tv("1:50").expect("write name");
+        writer.write_bd(1.0).expect("write paper units");
+        writer.write_bd(50.0).expect("write drawing units");
+        writer.write_b(0).expect("write unit scale flag");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let scale = decode_scale(&mut reader, &DwgVersion::R2000, 0x44).expect("decode scale");
+
+        assert_eq!(scale.handle, 0x44);
+        assert_eq!(scale.name.as_deref(), Some("1:50"));
+        assert_eq!(scale.factor(), Some(50.0));
+        assert!(!scale.is_unit_scale);
+    }
+}
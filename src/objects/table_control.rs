@@ -0,0 +1,471 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::{parse_common_object_prologue, CommonObjectPrologue};
+
+const MAX_TABLE_CONTROL_ENTRIES: u32 = 1 << 20;
+
+/// Symbol tables that are owned by a `*_CONTROL` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableKind {
+    Block,
+    Layer,
+    Style,
+    Ltype,
+    View,
+    Ucs,
+    Vport,
+    AppId,
+    DimStyle,
+    VpEntHdr,
+}
+
+impl TableKind {
+    pub const ALL: [TableKind; 10] = [
+        TableKind::Block,
+        TableKind::Layer,
+        TableKind::Style,
+        TableKind::Ltype,
+        TableKind::View,
+        TableKind::Ucs,
+        TableKind::Vport,
+        TableKind::AppId,
+        TableKind::DimStyle,
+        TableKind::VpEntHdr,
+    ];
+
+    /// Fixed type code of the control object.
+    pub fn control_type_code(self) -> u16 {
+        match self {
+            TableKind::Block => 0x30,
+            TableKind::Layer => 0x32,
+            TableKind::Style => 0x34,
+            TableKind::Ltype => 0x38,
+            TableKind::View => 0x3C,
+            TableKind::Ucs => 0x3E,
+            TableKind::Vport => 0x40,
+            TableKind::AppId => 0x42,
+            TableKind::DimStyle => 0x44,
+            TableKind::VpEntHdr => 0x46,
+        }
+    }
+
+    /// Fixed type code of the entries owned by the control object.
+    pub fn entry_type_code(self) -> u16 {
+        self.control_type_code() + 1
+    }
+
+    /// Type name of the control object as reported by `object_type_name`.
+    pub fn control_type_name(self) -> &'static str {
+        match self {
+            TableKind::Block => "BLOCK_CONTROL",
+            TableKind::Layer => "LAYER_CONTROL",
+            TableKind::Style => "SHAPEFILE_CONTROL",
+            TableKind::Ltype => "LTYPE_CONTROL",
+            TableKind::View => "VIEW_CONTROL",
+            TableKind::Ucs => "UCS_CONTROL",
+            TableKind::Vport => "VPORT_CONTROL",
+            TableKind::AppId => "APPID_CONTROL",
+            TableKind::DimStyle => "DIMSTYLE_CONTROL",
+            TableKind::VpEntHdr => "VP_ENT_HDR_CONTROL",
+        }
+    }
+
+    /// Table name as used by DXF (`BLOCK_RECORD`, `LAYER`, `STYLE`, ...).
+    pub fn table_name(self) -> &'static str {
+        match self {
+            TableKind::Block => "BLOCK_RECORD",
+            TableKind::Layer => "LAYER",
+            TableKind::Style => "STYLE",
+            TableKind::Ltype => "LTYPE",
+            TableKind::View => "VIEW",
+            TableKind::Ucs => "UCS",
+            TableKind::Vport => "VPORT",
+            TableKind::AppId => "APPID",
+            TableKind::DimStyle => "DIMSTYLE",
+            TableKind::VpEntHdr => "VP_ENT_HDR",
+        }
+    }
+
+    pub fn from_control_type_code(code: u16) -> Option<TableKind> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.control_type_code() == code)
+    }
+
+    /// Resolve a user supplied table name. Accepts DXF table names, the
+    /// control object names, and a few common aliases (case-insensitive).
+    pub fn from_name(name: &str) -> Option<TableKind> {
+        let upper = name.trim().to_ascii_uppercase();
+        let base = upper.strip_suffix("_CONTROL").unwrap_or(&upper);
+        match base {
+            "BLOCK" | "BLOCK_RECORD" | "BLOCK_HEADER" | "BLOCKS" => Some(TableKind::Block),
+            "LAYER" | "LAYERS" => Some(TableKind::Layer),
+            "STYLE" | "TEXTSTYLE" | "SHAPEFILE" | "STYLES" => Some(TableKind::Style),
+            "LTYPE" | "LINETYPE" | "LTYPES" => Some(TableKind::Ltype),
+            "VIEW" | "VIEWS" => Some(TableKind::View),
+            "UCS" => Some(TableKind::Ucs),
+            "VPORT" | "VPORTS" => Some(TableKind::Vport),
+            "APPID" | "REGAPP" | "APPIDS" => Some(TableKind::AppId),
+            "DIMSTYLE" | "DIMSTYLES" => Some(TableKind::DimStyle),
+            "VP_ENT_HDR" | "VX" => Some(TableKind::VpEntHdr),
+            _ => None,
+        }
+    }
+}
+
+/// Decoded `*_CONTROL` object.
+///
+/// `entries` lists the non-null entry handles in the order they are stored in
+/// the control object, which is the authoritative table order. `num_entries`
+/// is the raw count and may include null slots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableControlObject {
+    pub kind: TableKind,
+    pub handle: u64,
+    pub num_entries: u32,
+    pub owner_handle: u64,
+    pub reactors: Vec<u64>,
+    pub xdic_handle: Option<u64>,
+    pub entries: Vec<u64>,
+    /// `*MODEL_SPACE` block record (BLOCK_CONTROL only).
+    pub model_space: Option<u64>,
+    /// `*PAPER_SPACE` block record (BLOCK_CONTROL only).
+    pub paper_space: Option<u64>,
+    /// `BYBLOCK` linetype (LTYPE_CONTROL only).
+    pub byblock_ltype: Option<u64>,
+    /// `BYLAYER` linetype (LTYPE_CONTROL only).
+    pub bylayer_ltype: Option<u64>,
+    /// Extra entry handles stored after the regular list (DIMSTYLE_CONTROL).
+    pub extra_handles: Vec<u64>,
+}
+
+impl TableControlObject {
+    /// Entry handles including the special entries that are not part of the
+    /// regular list (`*MODEL_SPACE`, `*PAPER_SPACE`, `BYBLOCK`, `BYLAYER`).
+    pub fn all_entry_handles(&self) -> Vec<u64> {
+        let mut handles = self.entries.clone();
+        for special in [
+            self.model_space,
+            self.paper_space,
+            self.byblock_ltype,
+            self.bylayer_ltype,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !handles.contains(&special) {
+                handles.push(special);
+            }
+        }
+        handles
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TableControlCounts {
+    handle: u64,
+    num_reactors: u32,
+    xdic_missing: bool,
+    num_entries: u32,
+    num_extra: u8,
+}
+
+/// Decode a control object whose reader is positioned right after the
+/// object type prefix.
+///
+/// `object_data_end_bit` is the start of the handle stream for R2010+; older
+/// versions derive it from the `ObjSize` field stored in the object itself.
+/// When the declared position does not yield a plausible handle stream, the
+/// positions right after the data stream are tried as well.
+pub fn decode_table_control(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    kind: TableKind,
+    object_data_end_bit: Option<u32>,
+    object_handle: u64,
+) -> Result<TableControlObject> {
+    let r2010_plus = matches!(
+        version,
+        DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let prologue = parse_common_object_prologue(reader, version)?;
+    let handle = prologue.handle_or(object_handle);
+    let CommonObjectPrologue {
+        obj_size,
+        num_reactors,
+        xdic_missing,
+        ..
+    } = prologue;
+
+    let num_entries = reader.read_bl()?;
+    if num_entries > MAX_TABLE_CONTROL_ENTRIES {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("table control entry count too large: {num_entries}"),
+        ));
+    }
+    let num_extra = if kind == TableKind::DimStyle && !matches!(version, DwgVersion::R14) {
+        reader.read_rc()?
+    } else {
+        0
+    };
+    let counts = TableControlCounts {
+        handle,
+        num_reactors,
+        xdic_missing,
+        num_entries,
+        num_extra,
+    };
+
+    let data_end_bit = reader.tell_bits();
+    let declared = if r2010_plus {
+        object_data_end_bit
+    } else {
+        obj_size
+    };
+    let mut candidates: Vec<u64> = Vec::with_capacity(18);
+    if let Some(declared) = declared {
+        candidates.push(u64::from(declared));
+    }
+    for delta in 0..=16u64 {
+        let candidate = data_end_bit.saturating_add(delta);
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+
+    for candidate in candidates {
+        let Ok(candidate) = u32::try_from(candidate) else {
+            continue;
+        };
+        reader.set_bit_pos(candidate);
+        if let Some(control) = read_table_control_handles(reader, kind, &counts) {
+            return Ok(control);
+        }
+    }
+
+    Err(DwgError::new(
+        ErrorKind::Decode,
+        format!(
+            "failed to locate {} handle stream",
+            kind.control_type_name()
+        ),
+    ))
+}
+
+fn read_table_control_handles(
+    reader: &mut BitReader<'_>,
+    kind: TableKind,
+    counts: &TableControlCounts,
+) -> Option<TableControlObject> {
+    let handle = counts.handle;
+    // The owner of a control object is always a NULL soft pointer.
+    let owner = reader.read_h().ok()?;
+    if owner.code != 0x04 {
+        return None;
+    }
+    let owner_handle = owner.resolve(handle);
+
+    let mut reactors = Vec::with_capacity(counts.num_reactors as usize);
+    for _ in 0..counts.num_reactors {
+        reactors.push(reader.read_h().ok()?.resolve(handle));
+    }
+    let xdic_handle = if counts.xdic_missing {
+        None
+    } else {
+        Some(reader.read_h().ok()?.resolve(handle)).filter(|value| *value != 0)
+    };
+
+    let mut entries = Vec::with_capacity(counts.num_entries as usize);
+    for _ in 0..counts.num_entries {
+        let entry = reader.read_h().ok()?;
+        if !matches!(entry.code, 0x02 | 0x06 | 0x08 | 0x0A | 0x0C) {
+            return None;
+        }
+        let entry = entry.resolve(handle);
+        if entry != 0 {
+            entries.push(entry);
+        }
+    }
+
+    // Special entries trail the regular list. Some writers truncate the
+    // stream here, so a missing value is not treated as a failure.
+    let read_special = |reader: &mut BitReader<'_>| -> Option<u64> {
+        let special = reader.read_h().ok()?;
+        Some(special.resolve(handle)).filter(|value| *value != 0)
+    };
+    let (model_space, paper_space, byblock_ltype, bylayer_ltype) = match kind {
+        TableKind::Block => {
+            let model_space = read_special(reader);
+            let paper_space = read_special(reader);
+            (model_space, paper_space, None, None)
+        }
+        TableKind::Ltype => {
+            let byblock = read_special(reader);
+            let bylayer = read_special(reader);
+            (None, None, byblock, bylayer)
+        }
+        _ => (None, None, None, None),
+    };
+
+    let mut extra_handles = Vec::with_capacity(counts.num_extra as usize);
+    for _ in 0..counts.num_extra {
+        let Some(extra) = read_special(reader) else {
+            break;
+        };
+        extra_handles.push(extra);
+    }
+
+    Some(TableControlObject {
+        kind,
+        handle,
+        num_entries: counts.num_entries,
+        owner_handle,
+        reactors,
+        xdic_handle,
+        entries,
+        model_space,
+        paper_space,
+        byblock_ltype,
+        bylayer_ltype,
+        extra_handles,
+    })
+}
+
+/// Decode the handle and name of a symbol table entry (LAYER, STYLE, LTYPE,
+/// BLOCK_HEADER, ...) whose reader is positioned right after the object type
+/// prefix. The name is `None` from R2007 on, where it lives in the string
+/// stream.
+pub fn decode_table_entry_name(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<(u64, Option<String>)> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+    if matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    ) {
+        return Ok((handle, None));
+    }
+    Ok((handle, Some(reader.read_tv()?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_table_control, decode_table_entry_name, TableKind};
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    fn build_r2000_block_control_bytes() -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        writer.write_bs(0x30).expect("write type");
+        let obj_size_pos = writer.tell_bits();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 1).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_bl(2).expect("write entry count");
+        let obj_size = writer.tell_bits() as u32;
+        writer.write_h(4, 0).expect("write owner");
+        writer.write_h(3, 0).expect("write xdic");
+        writer.write_h(2, 0x74).expect("write entry");
+        writer.write_h(2, 0).expect("write null entry");
+        writer.write_h(3, 0x70).expect("write model space");
+        writer.write_h(3, 0x6C).expect("write paper space");
+        let end = writer.tell_bits();
+        writer.set_bit_pos(obj_size_pos as u32);
+        writer
+            .write_rl(Endian::Little, obj_size)
+            .expect("patch obj size");
+        writer.set_bit_pos(end as u32);
+        writer.into_bytes()
+    }
+
+    #[test]
+    fn decode_table_control_reads_r2000_block_control() {
+        let bytes = build_r2000_block_control_bytes();
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read_bs().expect("type"), 0x30);
+
+        let control =
+            decode_table_control(&mut reader, &DwgVersion::R2000, TableKind::Block, None, 1)
+                .expect("decode control");
+
+        assert_eq!(control.handle, 1);
+        assert_eq!(control.num_entries, 2);
+        assert_eq!(control.entries, vec![0x74]);
+        assert_eq!(control.xdic_handle, None);
+        assert_eq!(control.model_space, Some(0x70));
+        assert_eq!(control.paper_space, Some(0x6C));
+        assert_eq!(control.all_entry_handles(), vec![0x74, 0x70, 0x6C]);
+    }
+
+    #[test]
+    fn decode_table_control_falls_back_to_data_end_for_r2010_handles() {
+        let mut writer = BitWriter::new();
+        writer.write_h(0, 5).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing");
+        writer.write_b(0).expect("write ds binary flag");
+        writer.write_bl(1).expect("write entry count");
+        writer.write_h(4, 0).expect("write owner");
+        writer.write_h(2, 0x10).expect("write entry");
+        writer.write_h(3, 0x14).expect("write byblock");
+        writer.write_h(3, 0x15).expect("write bylayer");
+        let bytes = writer.into_bytes();
+
+        // Declared position points into the data stream; the decoder must
+        // recover by probing the positions after the entry count.
+        let mut reader = BitReader::new(&bytes);
+        let control = decode_table_control(
+            &mut reader,
+            &DwgVersion::R2013,
+            TableKind::Ltype,
+            Some(3),
+            5,
+        )
+        .expect("decode control");
+
+        assert_eq!(control.handle, 5);
+        assert_eq!(control.entries, vec![0x10]);
+        assert_eq!(control.byblock_ltype, Some(0x14));
+        assert_eq!(control.bylayer_ltype, Some(0x15));
+    }
+
+    #[test]
+    fn table_kind_from_name_accepts_aliases() {
+        assert_eq!(TableKind::from_name("layer"), Some(TableKind::Layer));
+        assert_eq!(
+            TableKind::from_name("BLOCK_CONTROL"),
+            Some(TableKind::Block)
+        );
+        assert_eq!(TableKind::from_name("block_record"), Some(TableKind::Block));
+        assert_eq!(TableKind::from_name("textstyle"), Some(TableKind::Style));
+        assert_eq!(TableKind::from_name("unknown"), None);
+    }
+
+    #[test]
+    fn decodes_r2000_table_entry_names() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x11).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_tv("Notes").expect("write name");
+        let bytes = writer.into_bytes();
+
+        let mut reader = BitReader::new(&bytes);
+        let decoded = decode_table_entry_name(&mut reader, &DwgVersion::R2000, 0x99)
+            .expect("decode entry name");
+        assert_eq!(decoded, (0x11, Some("Notes".to_string())));
+
+        let mut reader = BitReader::new(&bytes);
+        let decoded = decode_table_entry_name(&mut reader, &DwgVersion::R2007, 0x99)
+            .expect("decode entry name");
+        assert_eq!(decoded.1, None);
+    }
+}
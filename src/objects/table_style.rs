@@ -0,0 +1,292 @@
+use crate::bit::BitReader;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::{parse_common_object_prologue, CommonObjectPrologue};
+
+/// Data, title and header rows, in file order.
+const TABLE_STYLE_ROW_COUNT: usize = 3;
+/// Top, horizontal inside, bottom, left, vertical inside, right.
+const TABLE_STYLE_BORDER_COUNT: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TableStyleColor {
+    pub index: u16,
+    pub true_color: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableStyleBorder {
+    pub lineweight: i16,
+    pub visible: bool,
+    pub color: TableStyleColor,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableStyleRow {
+    pub text_style_handle: Option<u64>,
+    pub text_height: f64,
+    pub text_alignment: u16,
+    pub text_color: TableStyleColor,
+    pub fill_color: TableStyleColor,
+    pub fill_enabled: bool,
+    pub borders: Vec<TableStyleBorder>,
+    pub data_type: Option<u32>,
+    pub unit_type: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableStyleObject {
+    pub handle: u64,
+    /// `None` when the description lives in the R2007+ string stream.
+    pub description: Option<String>,
+    pub flow_direction: u16,
+    pub flags: u16,
+    pub horizontal_cell_margin: f64,
+    pub vertical_cell_margin: f64,
+    pub title_suppressed: bool,
+    pub header_suppressed: bool,
+    /// Row styles in file order: data, title, header.
+    pub rows: Vec<TableStyleRow>,
+}
+
+impl TableStyleObject {
+    pub fn data_row(&self) -> Option<&TableStyleRow> {
+        self.rows.first()
+    }
+
+    pub fn title_row(&self) -> Option<&TableStyleRow> {
+        self.rows.get(1)
+    }
+
+    pub fn header_row(&self) -> Option<&TableStyleRow> {
+        self.rows.get(2)
+    }
+}
+
+/// Decodes a TABLESTYLE object written in the pre-R2010 layout.
+///
+/// R2010+ replaced the fixed row records with cell style trees; those files
+/// are rejected with `ErrorKind::Unsupported`.
+pub fn decode_table_style(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<TableStyleObject> {
+    if matches!(
+        version,
+        DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    ) {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "TABLESTYLE decoding is not supported for {}",
+                version.as_str()
+            ),
+        ));
+    }
+
+    let prologue = parse_common_object_prologue(reader, version)?;
+    let handle = prologue.handle_or(object_handle);
+    let CommonObjectPrologue {
+        obj_size,
+        num_reactors,
+        xdic_missing,
+        ..
+    } = prologue;
+
+    // R2007 moves every string into the string stream at the end of the data.
+    let uses_string_stream = matches!(version, DwgVersion::R2007);
+    let description = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let flow_direction = reader.read_bs()?;
+    let flags = reader.read_bs()?;
+    let horizontal_cell_margin = reader.read_bd()?;
+    let vertical_cell_margin = reader.read_bd()?;
+    let title_suppressed = reader.read_b()? != 0;
+    let header_suppressed = reader.read_b()? != 0;
+
+    let mut rows = Vec::with_capacity(TABLE_STYLE_ROW_COUNT);
+    for _ in 0..TABLE_STYLE_ROW_COUNT {
+        let text_height = reader.read_bd()?;
+        let text_alignment = reader.read_bs()?;
+        let text_color = read_table_style_color(reader, uses_string_stream)?;
+        let fill_color = read_table_style_color(reader, uses_string_stream)?;
+        let fill_enabled = reader.read_b()? != 0;
+        let mut borders = Vec::with_capacity(TABLE_STYLE_BORDER_COUNT);
+        for _ in 0..TABLE_STYLE_BORDER_COUNT {
+            let lineweight = reader.read_bs()? as i16;
+            let visible = reader.read_b()? != 0;
+            let color = read_table_style_color(reader, uses_string_stream)?;
+            borders.push(TableStyleBorder {
+                lineweight,
+                visible,
+                color,
+            });
+        }
+        let (data_type, unit_type) = if uses_string_stream {
+            (Some(reader.read_bl()?), Some(reader.read_bl()?))
+        } else {
+            (None, None)
+        };
+        rows.push(TableStyleRow {
+            text_style_handle: None,
+            text_height,
+            text_alignment,
+            text_color,
+            fill_color,
+            fill_enabled,
+            borders,
+            data_type,
+            unit_type,
+        });
+    }
+
+    // Handle stream: owner, reactors, xdic, then one text style per row.
+    if let Some(obj_size) = obj_size {
+        reader.set_bit_pos(obj_size);
+    }
+    let _owner = reader.read_h()?;
+    for _ in 0..num_reactors {
+        let _reactor = reader.read_h()?;
+    }
+    if !xdic_missing {
+        let _xdic = reader.read_h()?;
+    }
+    for row in rows.iter_mut() {
+        let Ok(text_style) = reader.read_h() else {
+            break;
+        };
+        row.text_style_handle = Some(text_style.resolve(handle)).filter(|value| *value != 0);
+    }
+
+    Ok(TableStyleObject {
+        handle,
+        description,
+        flow_direction,
+        flags,
+        horizontal_cell_margin,
+        vertical_cell_margin,
+        title_suppressed,
+        header_suppressed,
+        rows,
+    })
+}
+
+/// TABLESTYLE only exists since AC1018, so even files saved down to older
+/// versions carry the full CMC (index, RGB, name flags) for every color.
+fn read_table_style_color(
+    reader: &mut BitReader<'_>,
+    uses_string_stream: bool,
+) -> Result<TableStyleColor> {
+    let index = reader.read_bs()?;
+    let color_rgb = reader.read_bl()?;
+    let color_byte = reader.read_rc()?;
+    if !uses_string_stream {
+        if (color_byte & 0x01) != 0 {
+            let _color_name = reader.read_tv()?;
+        }
+        if (color_byte & 0x02) != 0 {
+            let _book_name = reader.read_tv()?;
+        }
+    }
+    let true_color = if (color_rgb >> 24) == 0xC2 {
+        Some(color_rgb & 0x00FF_FFFF)
+    } else {
+        None
+    };
+    Ok(TableStyleColor { index, true_color })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_table_style;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::core::error::ErrorKind;
+    use crate::dwg::version::DwgVersion;
+
+    fn write_color(writer: &mut BitWriter, index: u16, rgb: u32) {
+        writer.write_bs(index).expect("write color index");
+        writer.write_bl(rgb).expect("write color rgb");
+        writer.write_rc(0).expect("write color flags");
+    }
+
+    fn build_r2004_table_style_bytes() -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x65).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing");
+        writer.write_tv("Standard").expect("write description");
+        writer.write_bs(0).expect("write flow direction");
+        writer.write_bs(0).expect("write flags");
+        writer.write_bd(0.06).expect("write horizontal margin");
+        writer.write_bd(0.06).expect("write vertical margin");
+        writer.write_b(0).expect("write title suppressed");
+        writer.write_b(1).expect("write header suppressed");
+        for (height, alignment) in [(0.18, 2u16), (0.25, 5), (0.18, 5)] {
+            writer.write_bd(height).expect("write text height");
+            writer.write_bs(alignment).expect("write text alignment");
+            write_color(&mut writer, 0, 0);
+            write_color(&mut writer, 256, 0xC2FF_8000);
+            writer.write_b(1).expect("write fill enabled");
+            for _ in 0..6 {
+                writer.write_bs(0xFFFE).expect("write lineweight");
+                writer.write_b(1).expect("write border visible");
+                write_color(&mut writer, 7, 0);
+            }
+        }
+        let obj_size = writer.tell_bits() as u32;
+        writer.write_h(4, 0x61).expect("write owner");
+        for _ in 0..3 {
+            writer.write_h(5, 0x11).expect("write text style");
+        }
+        let end = writer.tell_bits();
+        writer.set_bit_pos(0);
+        writer
+            .write_rl(Endian::Little, obj_size)
+            .expect("patch obj size");
+        writer.set_bit_pos(end as u32);
+        writer.into_bytes()
+    }
+
+    #[test]
+    fn decode_table_style_reads_r2004_rows() {
+        let bytes = build_r2004_table_style_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let style =
+            decode_table_style(&mut reader, &DwgVersion::R2004, 0x65).expect("decode style");
+
+        assert_eq!(style.handle, 0x65);
+        assert_eq!(style.description.as_deref(), Some("Standard"));
+        assert_eq!(style.horizontal_cell_margin, 0.06);
+        assert!(!style.title_suppressed);
+        assert!(style.header_suppressed);
+        assert_eq!(style.rows.len(), 3);
+        let title = style.title_row().expect("title row");
+        assert_eq!(title.text_height, 0.25);
+        assert_eq!(title.text_alignment, 5);
+        assert_eq!(title.text_style_handle, Some(0x11));
+        assert!(title.fill_enabled);
+        assert_eq!(title.fill_color.true_color, Some(0xFF_8000));
+        assert_eq!(title.borders.len(), 6);
+        assert_eq!(title.borders[0].lineweight, -2);
+        assert_eq!(title.borders[0].color.index, 7);
+    }
+
+    #[test]
+    fn decode_table_style_rejects_r2010_layout() {
+        let bytes = [0u8; 16];
+        let mut reader = BitReader::new(&bytes);
+
+        let err = decode_table_style(&mut reader, &DwgVersion::R2010, 1)
+            .expect_err("r2010 table style is unsupported");
+
+        assert_eq!(err.kind, ErrorKind::Unsupported);
+    }
+}
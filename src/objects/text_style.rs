@@ -0,0 +1,127 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextStyleObject {
+    pub handle: u64,
+    /// `None` when the name lives in the R2007+ string stream.
+    pub name: Option<String>,
+    pub is_vertical: bool,
+    /// Entries of shape files referenced by complex linetypes, not text styles.
+    pub is_shape_file: bool,
+    pub fixed_height: f64,
+    pub width_factor: f64,
+    pub oblique_angle: f64,
+    pub generation: u8,
+    pub last_height: f64,
+    /// Primary font file, such as `txt.shx` or `arial.ttf`.
+    pub font_file: Option<String>,
+    pub bigfont_file: Option<String>,
+}
+
+impl TextStyleObject {
+    /// Strings stored in the R2007+ string stream: name, font and bigfont.
+    pub const STRING_STREAM_COUNT: usize = 3;
+
+    /// Fills the fields returned as `None` from string stream values.
+    pub fn apply_string_stream(&mut self, strings: &[String]) {
+        let mut strings = strings.iter().cloned();
+        self.name = self.name.take().or_else(|| strings.next());
+        self.font_file = self.font_file.take().or_else(|| strings.next());
+        self.bigfont_file = self.bigfont_file.take().or_else(|| strings.next());
+    }
+}
+
+/// Decodes a STYLE table entry whose reader is positioned right after the
+/// object type prefix.
+pub fn decode_text_style(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<TextStyleObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let name = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let _flag_64 = reader.read_b()?;
+    if !uses_string_stream {
+        let _xref_index = reader.read_bs()?;
+    }
+    let _xdep = reader.read_b()?;
+    let is_vertical = reader.read_b()? != 0;
+    let is_shape_file = reader.read_b()? != 0;
+    let fixed_height = reader.read_bd()?;
+    let width_factor = reader.read_bd()?;
+    let oblique_angle = reader.read_bd()?;
+    let generation = reader.read_rc()?;
+    let last_height = reader.read_bd()?;
+    let (font_file, bigfont_file) = if uses_string_stream {
+        (None, None)
+    } else {
+        (Some(reader.read_tv()?), Some(reader.read_tv()?))
+    };
+
+    Ok(TextStyleObject {
+        handle,
+        name,
+        is_vertical,
+        is_shape_file,
+        fixed_height,
+        width_factor,
+        oblique_angle,
+        generation,
+        last_height,
+        font_file,
+        bigfont_file,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_text_style;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    #[test]
+    fn decode_text_style_reads_r2000_fonts() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x11).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_tv("Standard").expect("write name");
+        writer.write_b(0).expect("write 64-flag");
+        writer.write_bs(0).expect("write xref index");
+        writer.write_b(0).expect("write xdep");
+        writer.write_b(0).expect("write vertical");
+        writer.write_b(0).expect("write shape file");
+        writer.write_bd(0.0).expect("write fixed height");
+        writer.write_bd(0.8).expect("write width factor");
+        writer.write_bd(0.0).expect("write oblique");
+        writer.write_rc(0).expect("write generation");
+        writer.write_bd(2.5).expect("write last height");
+        writer.write_tv("romans.shx").expect("write font");
+        writer.write_tv("").expect("write bigfont");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let style = decode_text_style(&mut reader, &DwgVersion::R2000, 0x11).expect("decode style");
+
+        assert_eq!(style.handle, 0x11);
+        assert_eq!(style.name.as_deref(), Some("Standard"));
+        assert_eq!(style.width_factor, 0.8);
+        assert_eq!(style.last_height, 2.5);
+        assert_eq!(style.font_file.as_deref(), Some("romans.shx"));
+        assert_eq!(style.bigfont_file.as_deref(), Some(""));
+        assert!(!style.is_shape_file);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::bit::{BitReader, Endian};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+/// `view_mode` bit of perspective views.
+pub const VIEW_MODE_PERSPECTIVE: u8 = 0x01;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewObject {
+    pub handle: u64,
+    /// `None` when the name lives in the R2007+ string stream.
+    pub name: Option<String>,
+    pub flags: u8,
+    pub height: f64,
+    pub width: f64,
+    /// View center in display coordinates, relative to the target.
+    pub center: (f64, f64),
+    pub target: (f64, f64, f64),
+    /// Camera position as an offset from `target`.
+    pub direction: (f64, f64, f64),
+    pub twist_angle: f64,
+    /// Focal length in millimeters of a 35 mm camera.
+    pub lens_length: f64,
+    pub front_clip: f64,
+    pub back_clip: f64,
+    /// VIEWMODE bits: 1 perspective, 2 front clipping, 4 back clipping and
+    /// 8 UCS follow.
+    pub view_mode: u8,
+    /// Shade mode, stored from R2000 on: 0 is 2D wireframe.
+    pub render_mode: u8,
+    pub is_paper_space: bool,
+    /// Stored from R2007 on; earlier views are never plotted as cameras.
+    pub is_camera_plottable: bool,
+}
+
+impl ViewObject {
+    /// Strings stored in the R2007+ string stream, starting with the name.
+    pub const STRING_STREAM_COUNT: usize = 1;
+
+    /// Fills the name returned as `None` from string stream values.
+    pub fn apply_string_stream(&mut self, strings: &[String]) {
+        self.name = self.name.take().or_else(|| strings.first().cloned());
+    }
+
+    pub fn is_perspective(&self) -> bool {
+        self.view_mode & VIEW_MODE_PERSPECTIVE != 0
+    }
+
+    /// Camera position in world coordinates.
+    pub fn camera(&self) -> (f64, f64, f64) {
+        let (tx, ty, tz) = self.target;
+        let (dx, dy, dz) = self.direction;
+        (tx + dx, ty + dy, tz + dz)
+    }
+}
+
+/// Decodes a VIEW table entry whose reader is positioned right after the
+/// object type prefix.
+///
+/// The handles of the associated UCS, background, visual style and sun are
+/// not read.
+pub fn decode_view(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<ViewObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let name = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let flag_64 = reader.read_b()?;
+    if !uses_string_stream {
+        let _xref_index = reader.read_bs()?;
+    }
+    let xdep = reader.read_b()?;
+
+    let height = reader.read_bd()?;
+    let width = reader.read_bd()?;
+    let center_x = reader.read_rd(Endian::Little)?;
+    let center_y = reader.read_rd(Endian::Little)?;
+    let target = reader.read_3bd()?;
+    let direction = reader.read_3bd()?;
+    let twist_angle = reader.read_bd()?;
+    let lens_length = reader.read_bd()?;
+    let front_clip = reader.read_bd()?;
+    let back_clip = reader.read_bd()?;
+    let view_mode = reader.read_bits_msb(4)? as u8;
+    let render_mode = if matches!(version, DwgVersion::R14) {
+        0
+    } else {
+        reader.read_rc()?
+    };
+    if uses_string_stream {
+        let _use_default_lights = reader.read_b()?;
+        let _default_lighting_type = reader.read_rc()?;
+        let _brightness = reader.read_bd()?;
+        let _contrast = reader.read_bd()?;
+        skip_cmc(reader, version, uses_string_stream)?;
+    }
+    let is_paper_space = reader.read_b()? != 0;
+    if !matches!(version, DwgVersion::R14) && reader.read_b()? != 0 {
+        let _ucs_origin = reader.read_3bd()?;
+        let _ucs_x_axis = reader.read_3bd()?;
+        let _ucs_y_axis = reader.read_3bd()?;
+        let _ucs_elevation = reader.read_bd()?;
+        let _ucs_ortho_view = reader.read_bs()?;
+    }
+    let is_camera_plottable = uses_string_stream && reader.read_b()? != 0;
+
+    Ok(ViewObject {
+        handle,
+        name,
+        flags: (flag_64 << 6) | (xdep << 4),
+        height,
+        width,
+        center: (center_x, center_y),
+        target,
+        direction,
+        twist_angle,
+        lens_length,
+        front_clip,
+        back_clip,
+        view_mode,
+        render_mode,
+        is_paper_space,
+        is_camera_plottable,
+    })
+}
+
+/// R2000 colors are a bare index; R2004+ add the RGB value and optional
+/// color/book names, which R2007+ keep in the string stream.
+fn skip_cmc(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    uses_string_stream: bool,
+) -> Result<()> {
+    let _index = reader.read_bs()?;
+    if matches!(version, DwgVersion::R2000) {
+        return Ok(());
+    }
+    let _rgb = reader.read_bl()?;
+    let flags = reader.read_rc()?;
+    if !uses_string_stream {
+        if (flags & 0x01) != 0 {
+            let _color_name = reader.read_tv()?;
+        }
+        if (flags & 0x02) != 0 {
+            let _book_name = reader.read_tv()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_view;
+    use crate::bit::{BitReader, BitWriter, Endian};
+    use crate::dwg::version::DwgVersion;
+
+    fn write_view_geometry(writer: &mut BitWriter) {
+        writer.write_bd(40.0).expect("write height");
+        writer.write_bd(60.0).expect("write width");
+        writer
+            .write_rd(Endian::Little, 1.5)
+            .expect("write center x");
+        writer
+            .write_rd(Endian::Little, -2.0)
+            .expect("write center y");
+        writer.write_3bd(10.0, 20.0, 0.0).expect("write target");
+        writer
+            .write_3bd(-100.0, -100.0, 100.0)
+            .expect("write direction");
+        writer.write_bd(0.0).expect("write twist");
+        writer.write_bd(35.0).expect("write lens length");
+        writer.write_bd(0.0).expect("write front clip");
+        writer.write_bd(0.0).expect("write back clip");
+        writer.write_bits_msb(0b0001, 4).expect("write view mode");
+    }
+
+    #[test]
+    fn decode_view_reads_r2000_perspective_camera() {
+        let mut writer = BitWriter::new();
+        writer.write_rl(Endian::Little, 0).expect("write obj size");
+        writer.write_h(0, 0x2A).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_tv("SW Corner").expect("write name");
+        writer.write_b(0).expect("write 64-flag");
+        writer.write_bs(0).expect("write xref index");
+        writer.write_b(0).expect("write xdep");
+        write_view_geometry(&mut writer);
+        writer.write_rc(3).expect("write render mode");
+        writer.write_b(0).expect("write paper space");
+        writer.write_b(0).expect("write associated ucs");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let view = decode_view(&mut reader, &DwgVersion::R2000, 0x2A).expect("decode view");
+
+        assert_eq!(view.handle, 0x2A);
+        assert_eq!(view.name.as_deref(), Some("SW Corner"));
+        assert_eq!((view.width, view.height), (60.0, 40.0));
+        assert_eq!(view.center, (1.5, -2.0));
+        assert_eq!(view.camera(), (-90.0, -80.0, 100.0));
+        assert_eq!(view.lens_length, 35.0);
+        assert!(view.is_perspective());
+        assert_eq!(view.render_mode, 3);
+        assert!(!view.is_paper_space);
+        assert!(!view.is_camera_plottable);
+    }
+
+    #[test]
+    fn decode_view_reads_r2010_lighting_ucs_and_plottable_flag() {
+        let mut writer = BitWriter::new();
+        writer.write_h(0, 0x2B).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(0).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing");
+        writer.write_b(0).expect("write 64-flag");
+        writer.write_b(0).expect("write xdep");
+        write_view_geometry(&mut writer);
+        writer.write_rc(0).expect("write render mode");
+        writer.write_b(1).expect("write default lights");
+        writer.write_rc(1).expect("write lighting type");
+        writer.write_bd(0.0).expect("write brightness");
+        writer.write_bd(0.0).expect("write contrast");
+        writer.write_bs(250).expect("write ambient color index");
+        writer.write_bl(0).expect("write ambient rgb");
+        writer.write_rc(1).expect("write ambient color flags");
+        writer.write_b(1).expect("write paper space");
+        writer.write_b(1).expect("write associated ucs");
+        writer.write_3bd(0.0, 0.0, 0.0).expect("write ucs origin");
+        writer.write_3bd(1.0, 0.0, 0.0).expect("write ucs x axis");
+        writer.write_3bd(0.0, 1.0, 0.0).expect("write ucs y axis");
+        writer.write_bd(0.0).expect("write ucs elevation");
+        writer.write_bs(0).expect("write ucs ortho view");
+        writer.write_b(1).expect("write camera plottable");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let mut view = decode_view(&mut reader, &DwgVersion::R2010, 0x2B).expect("decode view");
+        assert_eq!(view.name, None);
+        view.apply_string_stream(&["Plan".to_string()]);
+
+        assert_eq!(view.name.as_deref(), Some("Plan"));
+        assert_eq!(view.target, (10.0, 20.0, 0.0));
+        assert!(view.is_paper_space);
+        assert!(view.is_camera_plottable);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::bit::BitReader;
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+use crate::objects::common::parse_common_object_prologue;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisualStyleObject {
+    pub handle: u64,
+    /// Display name such as `"Realistic"`; `None` when it lives in the R2007+
+    /// string stream.
+    pub description: Option<String>,
+    /// DXF group 70: 0 flat, 1 flat with edges, 2 Gouraud, 4 2D wireframe,
+    /// 5 3D wireframe, 6 hidden, 7 basic, 8 realistic, 9 conceptual, ...
+    pub style_type: u32,
+}
+
+/// Decodes the description and type of a VISUALSTYLE object.
+///
+/// The face, edge and display settings that follow changed layout with
+/// R2013 and are not read.
+pub fn decode_visual_style(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+    object_handle: u64,
+) -> Result<VisualStyleObject> {
+    let handle = parse_common_object_prologue(reader, version)?.handle_or(object_handle);
+
+    let uses_string_stream = matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    );
+    let description = if uses_string_stream {
+        None
+    } else {
+        Some(reader.read_tv()?)
+    };
+    let style_type = reader.read_bl()?;
+
+    Ok(VisualStyleObject {
+        handle,
+        description,
+        style_type,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_visual_style;
+    use crate::bit::{BitReader, BitWriter};
+    use crate::dwg::version::DwgVersion;
+
+    #[test]
+    fn decode_visual_style_reads_r2013_type_without_inline_description() {
+        let mut writer = BitWriter::new();
+        writer.write_h(0, 0x33).expect("write handle");
+        writer.write_bs(0).expect("write eed size");
+        writer.write_bl(1).expect("write reactors");
+        writer.write_b(1).expect("write xdic missing");
+        writer.write_b(0).expect("write ds binary flag");
+        writer.write_bl(8).expect("write style type");
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+
+        let style =
+            decode_visual_style(&mut reader, &DwgVersion::R2013, 0x33).expect("decode style");
+
+        assert_eq!(style.handle, 0x33);
+        assert_eq!(style.description, None);
+        assert_eq!(style.style_type, 8);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::bit::bit_reader::decode_tv_bytes;
+use crate::bit::{BitReader, Endian};
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::version::DwgVersion;
+
+/// A value of extended data or of an XRECORD. The DXF group code it is
+/// stored under is kept next to it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum XDataValue {
+    String(String),
+    Real(f64),
+    Point((f64, f64, f64)),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Binary(Vec<u8>),
+    Handle(u64),
+}
+
+/// Value type of a DXF group code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XDataKind {
+    String,
+    Real,
+    Point,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Binary,
+    Handle,
+}
+
+impl XDataValue {
+    pub fn kind(&self) -> XDataKind {
+        match self {
+            XDataValue::String(_) => XDataKind::String,
+            XDataValue::Real(_) => XDataKind::Real,
+            XDataValue::Point(_) => XDataKind::Point,
+            XDataValue::Int8(_) => XDataKind::Int8,
+            XDataValue::Int16(_) => XDataKind::Int16,
+            XDataValue::Int32(_) => XDataKind::Int32,
+            XDataValue::Int64(_) => XDataKind::Int64,
+            XDataValue::Binary(_) => XDataKind::Binary,
+            XDataValue::Handle(_) => XDataKind::Handle,
+        }
+    }
+}
+
+/// Value type of the DXF group `code` in XRECORDs and, for 1000 and up,
+/// in extended data. `None` for codes that hold no application data.
+pub fn group_code_kind(code: u16) -> Option<XDataKind> {
+    Some(match code {
+        0..=4 | 6..=9 | 100..=102 | 300..=309 | 410..=419 | 430..=439 | 470..=479 | 999 => {
+            XDataKind::String
+        }
+        1000..=1003 | 1006..=1009 => XDataKind::String,
+        5 | 105 | 320..=369 | 390..=399 | 480 | 481 | 1005 => XDataKind::Handle,
+        10..=17 | 110..=112 | 210 | 1010..=1013 => XDataKind::Point,
+        18..=59 | 113..=149 | 211..=239 | 460..=469 | 1014..=1059 => XDataKind::Real,
+        60..=79 | 170..=179 | 270..=279 | 370..=389 | 400..=409 | 1060..=1070 => XDataKind::Int16,
+        90..=99 | 420..=429 | 440..=459 | 1071 => XDataKind::Int32,
+        160..=169 => XDataKind::Int64,
+        280..=299 => XDataKind::Int8,
+        310..=319 | 1004 => XDataKind::Binary,
+        _ => return None,
+    })
+}
+
+/// Reads the extended data blocks that follow the object handle, as the
+/// registered application handle and the raw bytes of each.
+pub fn read_eed(reader: &mut BitReader<'_>) -> Result<Vec<(u64, Vec<u8>)>> {
+    let mut blocks = Vec::new();
+    loop {
+        let size = reader.read_bs()?;
+        if size == 0 {
+            return Ok(blocks);
+        }
+        let app_handle = reader.read_h()?.value;
+        blocks.push((app_handle, reader.read_rcs(usize::from(size))?));
+    }
+}
+
+/// Reads the handle and extended data blocks of any object, from `reader`
+/// positioned right after the object type.
+pub fn read_object_eed(
+    reader: &mut BitReader<'_>,
+    version: &DwgVersion,
+) -> Result<(u64, Vec<(u64, Vec<u8>)>)> {
+    if matches!(
+        version,
+        DwgVersion::R2000 | DwgVersion::R2004 | DwgVersion::R2007
+    ) {
+        let _obj_size = reader.read_rl(Endian::Little)?;
+    }
+    let handle = reader.read_h()?.value;
+    Ok((handle, read_eed(reader)?))
+}
+
+/// Decodes the items of one extended data block read by [`read_eed`],
+/// under their DXF group codes (1000 and up). Strings carry their own
+/// codepage before R2007, which stores them as UTF-16.
+pub fn decode_xdata_items(data: &[u8], version: &DwgVersion) -> Result<Vec<(u16, XDataValue)>> {
+    let wide_strings = is_r2007_plus(version);
+    let mut reader = BitReader::new(data);
+    let mut items = Vec::new();
+    while reader.tell_bits() < reader.total_bits() {
+        let code = reader.read_rc()?;
+        let value = match code {
+            0 if wide_strings => {
+                let length = reader.read_rs(Endian::Little)?;
+                XDataValue::String(read_utf16(&mut reader, usize::from(length))?)
+            }
+            0 => {
+                let length = reader.read_rc()?;
+                let item_codepage = reader.read_rs(Endian::Little)?;
+                let bytes = reader.read_rcs(usize::from(length))?;
+                XDataValue::String(decode_tv_bytes(&bytes, Some(item_codepage)))
+            }
+            2 => XDataValue::String(if reader.read_rc()? == 0 { "{" } else { "}" }.to_string()),
+            3 | 5 => XDataValue::Handle(u64::from_be_bytes(read_array(&mut reader)?)),
+            4 => {
+                let length = reader.read_rc()?;
+                XDataValue::Binary(reader.read_rcs(usize::from(length))?)
+            }
+            10..=13 => XDataValue::Point((
+                reader.read_rd(Endian::Little)?,
+                reader.read_rd(Endian::Little)?,
+                reader.read_rd(Endian::Little)?,
+            )),
+            40..=42 => XDataValue::Real(reader.read_rd(Endian::Little)?),
+            70 => XDataValue::Int16(reader.read_rs(Endian::Little)? as i16),
+            71 => XDataValue::Int32(reader.read_rl(Endian::Little)? as i32),
+            other => {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!("unknown extended data item code: {other}"),
+                ))
+            }
+        };
+        items.push((1000 + u16::from(code), value));
+    }
+    Ok(items)
+}
+
+/// Decodes the data bytes of an XRECORD: group codes, each followed by
+/// its value. Strings carry their own codepage before R2007, which stores
+/// them as UTF-16.
+pub fn decode_xrecord_items(data: &[u8], version: &DwgVersion) -> Result<Vec<(u16, XDataValue)>> {
+    let wide_strings = is_r2007_plus(version);
+    let mut reader = BitReader::new(data);
+    let mut items = Vec::new();
+    while reader.tell_bits() < reader.total_bits() {
+        let code = reader.read_rs(Endian::Little)?;
+        let kind = group_code_kind(code).ok_or_else(|| {
+            DwgError::new(
+                ErrorKind::Format,
+                format!("unknown XRECORD group code: {code}"),
+            )
+        })?;
+        let value = match kind {
+            XDataKind::String if wide_strings => {
+                let length = reader.read_rs(Endian::Little)?;
+                XDataValue::String(read_utf16(&mut reader, usize::from(length))?)
+            }
+            XDataKind::String => {
+                let length = reader.read_rs(Endian::Little)?;
+                let item_codepage = reader.read_rc()?;
+                let bytes = reader.read_rcs(usize::from(length))?;
+                XDataValue::String(decode_tv_bytes(&bytes, Some(u16::from(item_codepage))))
+            }
+            XDataKind::Real => XDataValue::Real(reader.read_rd(Endian::Little)?),
+            XDataKind::Point => XDataValue::Point((
+                reader.read_rd(Endian::Little)?,
+                reader.read_rd(Endian::Little)?,
+                reader.read_rd(Endian::Little)?,
+            )),
+            XDataKind::Int8 => XDataValue::Int8(reader.read_rc()? as i8),
+            XDataKind::Int16 => XDataValue::Int16(reader.read_rs(Endian::Little)? as i16),
+            XDataKind::Int32 => XDataValue::Int32(reader.read_rl(Endian::Little)? as i32),
+            XDataKind::Int64 => XDataValue::Int64(i64::from_le_bytes(read_array(&mut reader)?)),
+            XDataKind::Binary => {
+                let length = reader.read_rc()?;
+                XDataValue::Binary(reader.read_rcs(usize::from(length))?)
+            }
+            XDataKind::Handle => XDataValue::Handle(u64::from_le_bytes(read_array(&mut reader)?)),
+        };
+        items.push((code, value));
+    }
+    Ok(items)
+}
+
+fn is_r2007_plus(version: &DwgVersion) -> bool {
+    matches!(
+        version,
+        DwgVersion::R2007 | DwgVersion::R2010 | DwgVersion::R2013 | DwgVersion::R2018
+    )
+}
+
+fn read_array(reader: &mut BitReader<'_>) -> Result<[u8; 8]> {
+    let bytes = reader.read_rcs(8)?;
+    let mut array = [0u8; 8];
+    array.copy_from_slice(&bytes);
+    Ok(array)
+}
+
+fn read_utf16(reader: &mut BitReader<'_>, length: usize) -> Result<String> {
+    let mut units = Vec::with_capacity(length);
+    for _ in 0..length {
+        units.push(reader.read_rs(Endian::Little)?);
+    }
+    Ok(String::from_utf16_lossy(&units))
+}
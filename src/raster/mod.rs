@@ -0,0 +1,386 @@
+//! Minimal rasterizer for quick modelspace previews.
+//!
+//! Geometry comes in as already tessellated polylines in drawing units; the
+//! rasterizer only maps them into a pixel window and strokes them.
+
+pub mod png;
+
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+const MAX_RASTER_SIZE: u32 = 16_384;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub points: Vec<(f64, f64)>,
+    pub color: (u8, u8, u8),
+    pub closed: bool,
+}
+
+/// Drawing-unit rectangle mapped onto the image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Window {
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
+        Self {
+            min_x: min_x.min(max_x),
+            min_y: min_y.min(max_y),
+            max_x: min_x.max(max_x),
+            max_y: min_y.max(max_y),
+        }
+    }
+
+    /// Extents of every finite stroke point, or `None` for empty input.
+    pub fn from_strokes(strokes: &[Stroke]) -> Option<Self> {
+        let mut points = strokes
+            .iter()
+            .flat_map(|stroke| stroke.points.iter().copied())
+            .filter(|(x, y)| x.is_finite() && y.is_finite());
+        let (x, y) = points.next()?;
+        let mut window = Self::new(x, y, x, y);
+        for (x, y) in points {
+            window.min_x = window.min_x.min(x);
+            window.min_y = window.min_y.min(y);
+            window.max_x = window.max_x.max(x);
+            window.max_y = window.max_y.max(y);
+        }
+        Some(window)
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max_y - self.min_y
+    }
+
+    /// Grows the window by `fraction` of its larger side on every edge. A
+    /// degenerate window (single point or axis-aligned segment) gets a unit
+    /// span so it still maps onto the image.
+    pub fn expanded(&self, fraction: f64) -> Self {
+        let span = self.width().max(self.height());
+        let span = if span > 0.0 { span } else { 1.0 };
+        let pad = span * fraction;
+        let pad_x = if self.width() > 0.0 {
+            pad
+        } else {
+            pad + span * 0.5
+        };
+        let pad_y = if self.height() > 0.0 {
+            pad
+        } else {
+            pad + span * 0.5
+        };
+        Self::new(
+            self.min_x - pad_x,
+            self.min_y - pad_y,
+            self.max_x + pad_x,
+            self.max_y + pad_y,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RasterOptions {
+    pub width: u32,
+    /// Derived from the window aspect ratio when `None`.
+    pub height: Option<u32>,
+    /// Defaults to the stroke extents plus `margin`.
+    pub window: Option<Window>,
+    pub background: (u8, u8, u8),
+    /// Stroke width in pixels.
+    pub line_width: u32,
+    /// Fraction of the larger extent added around auto-fitted windows.
+    pub margin: f64,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self {
+            width: 1024,
+            height: None,
+            window: None,
+            background: (255, 255, 255),
+            line_width: 1,
+            margin: 0.04,
+        }
+    }
+}
+
+/// RGBA8 pixel buffer, rows top to bottom.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32, background: (u8, u8, u8)) -> Self {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for _ in 0..width as usize * height as usize {
+            pixels.extend_from_slice(&[background.0, background.1, background.2, 0xFF]);
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Option<(u8, u8, u8)> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        Some((
+            self.pixels[offset],
+            self.pixels[offset + 1],
+            self.pixels[offset + 2],
+        ))
+    }
+
+    fn put(&mut self, x: i64, y: i64, color: (u8, u8, u8)) {
+        if x < 0 || y < 0 || x >= i64::from(self.width) || y >= i64::from(self.height) {
+            return;
+        }
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        self.pixels[offset..offset + 3].copy_from_slice(&[color.0, color.1, color.2]);
+    }
+
+    fn put_brush(&mut self, x: i64, y: i64, line_width: u32, color: (u8, u8, u8)) {
+        let before = i64::from(line_width.saturating_sub(1) / 2);
+        let after = i64::from(line_width / 2);
+        for dy in -before..=after {
+            for dx in -before..=after {
+                self.put(x + dx, y + dy, color);
+            }
+        }
+    }
+
+    /// Strokes a segment in pixel coordinates (Bresenham with a square brush).
+    pub fn draw_line(
+        &mut self,
+        start: (f64, f64),
+        end: (f64, f64),
+        line_width: u32,
+        color: (u8, u8, u8),
+    ) {
+        let pad = f64::from(line_width) + 1.0;
+        let Some((start, end)) = clip_segment(
+            start,
+            end,
+            (-pad, -pad),
+            (f64::from(self.width) + pad, f64::from(self.height) + pad),
+        ) else {
+            return;
+        };
+        let (mut x, mut y) = (start.0.round() as i64, start.1.round() as i64);
+        let (x1, y1) = (end.0.round() as i64, end.1.round() as i64);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let step_x = if x < x1 { 1 } else { -1 };
+        let step_y = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            self.put_brush(x, y, line_width, color);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let doubled = 2 * err;
+            if doubled >= dy {
+                err += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                err += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        png::encode_rgba(self.width, self.height, &self.pixels)
+    }
+}
+
+/// Renders `strokes` into a new canvas per `options`.
+pub fn render_strokes(strokes: &[Stroke], options: &RasterOptions) -> Result<Canvas> {
+    if options.width == 0 || options.width > MAX_RASTER_SIZE {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!(
+                "raster width must be in 1..={MAX_RASTER_SIZE}: {}",
+                options.width
+            ),
+        ));
+    }
+    let window = match options.window {
+        Some(window) => window,
+        None => Window::from_strokes(strokes)
+            .unwrap_or(Window::new(0.0, 0.0, 1.0, 1.0))
+            .expanded(options.margin.max(0.0)),
+    };
+    let has_area = |span: f64| span > 0.0 && span.is_finite();
+    if !has_area(window.width()) || !has_area(window.height()) {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            "raster window must have a positive finite size",
+        ));
+    }
+    let height = match options.height {
+        Some(height) => height,
+        None => {
+            let derived = f64::from(options.width) * window.height() / window.width();
+            derived.round().clamp(1.0, f64::from(MAX_RASTER_SIZE)) as u32
+        }
+    };
+    if height == 0 || height > MAX_RASTER_SIZE {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("raster height must be in 1..={MAX_RASTER_SIZE}: {height}"),
+        ));
+    }
+
+    // Uniform scale onto pixel centers, centered, with the drawing Y axis
+    // pointing up.
+    let span_x = f64::from(options.width - 1).max(1.0);
+    let span_y = f64::from(height - 1).max(1.0);
+    let scale = (span_x / window.width()).min(span_y / window.height());
+    let offset_x = (span_x - window.width() * scale) * 0.5;
+    let offset_y = (span_y - window.height() * scale) * 0.5;
+    let to_pixel = |(x, y): (f64, f64)| {
+        (
+            offset_x + (x - window.min_x) * scale,
+            span_y - (offset_y + (y - window.min_y) * scale),
+        )
+    };
+
+    let mut canvas = Canvas::new(options.width, height, options.background);
+    let line_width = options.line_width.max(1);
+    for stroke in strokes {
+        let points: Vec<(f64, f64)> = stroke
+            .points
+            .iter()
+            .copied()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(to_pixel)
+            .collect();
+        if points.len() == 1 {
+            canvas.draw_line(points[0], points[0], line_width, stroke.color);
+            continue;
+        }
+        for pair in points.windows(2) {
+            canvas.draw_line(pair[0], pair[1], line_width, stroke.color);
+        }
+        if stroke.closed && points.len() > 2 {
+            canvas.draw_line(
+                points[points.len() - 1],
+                points[0],
+                line_width,
+                stroke.color,
+            );
+        }
+    }
+    Ok(canvas)
+}
+
+/// Liang-Barsky clip against an axis-aligned rectangle.
+fn clip_segment(
+    start: (f64, f64),
+    end: (f64, f64),
+    min: (f64, f64),
+    max: (f64, f64),
+) -> Option<((f64, f64), (f64, f64))> {
+    let dx = end.0 - start.0;
+    let dy = end.1 - start.1;
+    let mut t0 = 0.0f64;
+    let mut t1 = 1.0f64;
+    for (p, q) in [
+        (-dx, start.0 - min.0),
+        (dx, max.0 - start.0),
+        (-dy, start.1 - min.1),
+        (dy, max.1 - start.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+            continue;
+        }
+        let t = q / p;
+        if p < 0.0 {
+            t0 = t0.max(t);
+        } else {
+            t1 = t1.min(t);
+        }
+        if t0 > t1 {
+            return None;
+        }
+    }
+    Some((
+        (start.0 + t0 * dx, start.1 + t0 * dy),
+        (start.0 + t1 * dx, start.1 + t1 * dy),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_strokes, RasterOptions, Stroke, Window};
+
+    fn red_diagonal() -> Stroke {
+        Stroke {
+            points: vec![(0.0, 0.0), (10.0, 10.0)],
+            color: (255, 0, 0),
+            closed: false,
+        }
+    }
+
+    #[test]
+    fn render_strokes_fits_extents_and_flips_y() {
+        let options = RasterOptions {
+            width: 21,
+            margin: 0.0,
+            ..RasterOptions::default()
+        };
+
+        let canvas = render_strokes(&[red_diagonal()], &options).expect("render");
+
+        assert_eq!((canvas.width, canvas.height), (21, 21));
+        assert_eq!(canvas.pixel(0, 20), Some((255, 0, 0)));
+        assert_eq!(canvas.pixel(20, 0), Some((255, 0, 0)));
+        assert_eq!(canvas.pixel(0, 0), Some((255, 255, 255)));
+    }
+
+    #[test]
+    fn render_strokes_honors_window_and_background() {
+        let options = RasterOptions {
+            width: 40,
+            height: Some(20),
+            window: Some(Window::new(100.0, 100.0, 140.0, 120.0)),
+            background: (0, 0, 0),
+            ..RasterOptions::default()
+        };
+
+        let canvas = render_strokes(&[red_diagonal()], &options).expect("render");
+
+        assert_eq!((canvas.width, canvas.height), (40, 20));
+        assert!(canvas.pixels.chunks_exact(4).all(|px| px == [0, 0, 0, 255]));
+    }
+
+    #[test]
+    fn render_strokes_rejects_zero_width() {
+        let options = RasterOptions {
+            width: 0,
+            ..RasterOptions::default()
+        };
+        assert!(render_strokes(&[], &options).is_err());
+    }
+}
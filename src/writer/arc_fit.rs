@@ -0,0 +1,312 @@
+use std::borrow::Cow;
+use std::f64::consts::TAU;
+
+use crate::writer::ir::{ArcEntity, LwPolylineEntity, WriterDocument, WriterEntity};
+use crate::writer::lwpolyline::{rewrite_lwpolylines, CLOSED_FLAGS};
+
+/// Fewest vertices a run needs before it is replaced by an arc; any three
+/// points lie on a circle, so shorter runs say nothing about the shape.
+const MIN_ARC_VERTICES: usize = 5;
+
+/// Replaces near-circular vertex runs of the LWPOLYLINEs in `doc` by bulge
+/// arcs, see [`LwPolylineEntity::fit_arcs`]. An open LWPOLYLINE reduced to
+/// a single arc segment without widths is written as an ARC entity with
+/// the same handle. Returns `doc` itself when nothing is fitted.
+pub fn fit_arcs(doc: &WriterDocument, tolerance: f64) -> Cow<'_, WriterDocument> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Cow::Borrowed(doc);
+    }
+    rewrite_lwpolylines(doc, |polyline| {
+        let fitted = fit_lwpolyline_arcs(polyline, tolerance)?;
+        Some(vec![match single_arc(&fitted) {
+            Some(arc) => WriterEntity::Arc(arc),
+            None => WriterEntity::LwPolyline(fitted),
+        }])
+    })
+}
+
+impl LwPolylineEntity {
+    /// Replaces runs of at least five vertices joined by straight segments
+    /// that lie within `tolerance` of one circular arc by a single bulged
+    /// segment. Every vertex must be within `tolerance` of the circle and
+    /// every replaced segment must stay within `tolerance` of the arc, so
+    /// the outline moves by at most `tolerance`. Runs whose segments have
+    /// different widths are left alone.
+    pub fn fit_arcs(&self, tolerance: f64) -> LwPolylineEntity {
+        fit_lwpolyline_arcs(self, tolerance).unwrap_or_else(|| self.clone())
+    }
+}
+
+fn fit_lwpolyline_arcs(polyline: &LwPolylineEntity, tolerance: f64) -> Option<LwPolylineEntity> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return None;
+    }
+    let count = polyline.vertices.len();
+    let closed = polyline.flags & CLOSED_FLAGS != 0 && count > 2;
+    let outline: Vec<(f64, f64)> = polyline
+        .vertices
+        .iter()
+        .copied()
+        .chain(polyline.vertices.first().copied().filter(|_| closed))
+        .collect();
+    let segments = outline.len().saturating_sub(1);
+    let bulge = |index: usize| polyline.bulges.get(index).copied().unwrap_or(0.0);
+    let width = |index: usize| polyline.widths.get(index).copied();
+
+    // Kept vertices with the bulge of the segment leaving them and the
+    // last original segment that segment replaces.
+    let mut kept: Vec<(usize, f64, usize)> = Vec::new();
+    let mut start = 0;
+    while start < segments {
+        let mut fitted = None;
+        let mut end = start + 1;
+        while end <= segments && bulge(end - 1) == 0.0 && width(end - 1) == width(start) {
+            if end + 1 - start >= MIN_ARC_VERTICES {
+                match outline
+                    .get(start..=end)
+                    .and_then(|run| arc_bulge(run, tolerance))
+                {
+                    Some(arc) => fitted = Some((end, arc)),
+                    None => break,
+                }
+            }
+            end += 1;
+        }
+        match fitted {
+            Some((end, arc)) => {
+                kept.push((start, arc, end - 1));
+                start = end;
+            }
+            None => {
+                kept.push((start, bulge(start), start));
+                start += 1;
+            }
+        }
+    }
+    if kept.len() == segments {
+        return None;
+    }
+    if !closed && count > 0 {
+        kept.push((count - 1, bulge(count - 1), count - 1));
+    }
+
+    let mut out = LwPolylineEntity {
+        common: polyline.common.clone(),
+        flags: polyline.flags,
+        vertices: Vec::with_capacity(kept.len()),
+        const_width: polyline.const_width,
+        bulges: Vec::with_capacity(kept.len()),
+        widths: Vec::new(),
+    };
+    for (index, arc, last) in kept {
+        let Some(&point) = polyline.vertices.get(index) else {
+            continue;
+        };
+        out.vertices.push(point);
+        out.bulges.push(arc);
+        if let Some((start_width, _)) = width(index) {
+            let end_width = width(last).map_or(start_width, |width| width.1);
+            out.widths.push((start_width, end_width));
+        }
+    }
+    Some(out)
+}
+
+/// Bulge of the arc through the ends of `points` when all of them, and
+/// the chords between them, stay within `tolerance` of one circle turning
+/// in one direction. Runs that are straight within `tolerance` are not arcs.
+fn arc_bulge(points: &[(f64, f64)], tolerance: f64) -> Option<f64> {
+    let (&first, &last) = (points.first()?, points.last()?);
+    let &middle = points.get(points.len() / 2)?;
+    let (center, radius) = circumcircle(first, middle, last)?;
+    if points
+        .iter()
+        .all(|&point| chord_distance(point, first, last) <= tolerance)
+    {
+        return None;
+    }
+
+    let mut sweep = 0.0_f64;
+    for pair in points.windows(2) {
+        let [from, to] = *pair else {
+            continue;
+        };
+        if (distance(to, center) - radius).abs() > tolerance {
+            return None;
+        }
+        let (ax, ay) = (from.0 - center.0, from.1 - center.1);
+        let (bx, by) = (to.0 - center.0, to.1 - center.1);
+        let step = (ax * by - ay * bx).atan2(ax * bx + ay * by);
+        if step == 0.0 || (sweep != 0.0 && step.signum() != sweep.signum()) {
+            return None;
+        }
+        let half_chord = distance(from, to) / 2.0;
+        let sagitta = radius - (radius * radius - half_chord * half_chord).max(0.0).sqrt();
+        if sagitta > tolerance {
+            return None;
+        }
+        sweep += step;
+    }
+    if sweep.abs() >= TAU - 1e-9 {
+        return None;
+    }
+    Some((sweep / 4.0).tan())
+}
+
+fn circumcircle(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Option<((f64, f64), f64)> {
+    let (bx, by) = (b.0 - a.0, b.1 - a.1);
+    let (cx, cy) = (c.0 - a.0, c.1 - a.1);
+    let det = 2.0 * (bx * cy - by * cx);
+    if det == 0.0 {
+        return None;
+    }
+    let b_sq = bx * bx + by * by;
+    let c_sq = cx * cx + cy * cy;
+    let ux = (cy * b_sq - by * c_sq) / det;
+    let uy = (bx * c_sq - cx * b_sq) / det;
+    let radius = ux.hypot(uy);
+    radius.is_finite().then_some(((a.0 + ux, a.1 + uy), radius))
+}
+
+fn chord_distance(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        return distance(point, start);
+    }
+    ((point.0 - start.0) * dy - (point.1 - start.1) * dx).abs() / length
+}
+
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+/// The ARC drawn by an open two-vertex LWPOLYLINE with a bulge and no width.
+fn single_arc(polyline: &LwPolylineEntity) -> Option<ArcEntity> {
+    let [start, end] = polyline.vertices.as_slice() else {
+        return None;
+    };
+    let bulge = polyline.bulges.first().copied().unwrap_or(0.0);
+    let has_width = polyline.const_width.is_some_and(|width| width != 0.0)
+        || polyline
+            .widths
+            .iter()
+            .any(|&(start_width, end_width)| start_width != 0.0 || end_width != 0.0);
+    if polyline.flags & CLOSED_FLAGS != 0 || bulge == 0.0 || has_width {
+        return None;
+    }
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let offset = (1.0 - bulge * bulge) / (4.0 * bulge);
+    let center = (
+        (start.0 + end.0) / 2.0 - offset * dy,
+        (start.1 + end.1) / 2.0 + offset * dx,
+    );
+    let angle = |point: &(f64, f64)| (point.1 - center.1).atan2(point.0 - center.0);
+    // ARCs run counter-clockwise; a negative bulge turns clockwise.
+    let (from, to) = if bulge > 0.0 {
+        (start, end)
+    } else {
+        (end, start)
+    };
+    Some(ArcEntity {
+        common: polyline.common.clone(),
+        center: (center.0, center.1, 0.0),
+        radius: distance(*start, center),
+        angle_start_rad: angle(from),
+        angle_end_rad: angle(to),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fit_arcs;
+    use crate::writer::ir::{CommonEntityProps, LwPolylineEntity, WriterDocument, WriterEntity};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn circle_points(
+        center: (f64, f64),
+        radius: f64,
+        from: f64,
+        to: f64,
+        count: usize,
+    ) -> Vec<(f64, f64)> {
+        (0..count)
+            .map(|i| {
+                let angle = from + (to - from) * i as f64 / (count - 1) as f64;
+                (
+                    center.0 + radius * angle.cos(),
+                    center.1 + radius * angle.sin(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fits_bulges_into_rounded_outline() {
+        // A slot: two half circles joined by straight edges.
+        let mut vertices = circle_points((0.0, 0.0), 1.0, -FRAC_PI_2, FRAC_PI_2, 33);
+        vertices.extend(circle_points(
+            (-4.0, 0.0),
+            1.0,
+            FRAC_PI_2,
+            3.0 * FRAC_PI_2,
+            33,
+        ));
+        let polyline = LwPolylineEntity {
+            flags: 1,
+            vertices,
+            ..LwPolylineEntity::default()
+        };
+        let fitted = polyline.fit_arcs(0.01);
+        assert_eq!(
+            fitted.vertices,
+            vec![
+                polyline.vertices[0],
+                polyline.vertices[32],
+                polyline.vertices[33],
+                polyline.vertices[65]
+            ]
+        );
+        assert!((fitted.bulges[0] - 1.0).abs() < 1e-9);
+        assert_eq!(fitted.bulges[1], 0.0);
+        assert!((fitted.bulges[2] - 1.0).abs() < 1e-9);
+        assert_eq!(fitted.flags, 1);
+
+        // Too tight a tolerance leaves the polyline alone.
+        assert_eq!(polyline.fit_arcs(1e-9).vertices.len(), 66);
+    }
+
+    #[test]
+    fn writes_single_arc_runs_as_arc_entities() {
+        let doc = WriterDocument {
+            modelspace: vec![
+                WriterEntity::LwPolyline(LwPolylineEntity {
+                    common: CommonEntityProps {
+                        handle: Some(0x30),
+                        ..CommonEntityProps::default()
+                    },
+                    vertices: circle_points((1.0, 2.0), 3.0, PI, 0.0, 17),
+                    ..LwPolylineEntity::default()
+                }),
+                WriterEntity::LwPolyline(LwPolylineEntity {
+                    vertices: vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (4.0, 0.0)],
+                    ..LwPolylineEntity::default()
+                }),
+            ],
+            ..WriterDocument::default()
+        };
+        let out = fit_arcs(&doc, 0.05);
+        let WriterEntity::Arc(arc) = &out.modelspace[0] else {
+            panic!("expected an ARC, got {:?}", out.modelspace[0]);
+        };
+        assert_eq!(arc.common.handle, Some(0x30));
+        assert!((arc.center.0 - 1.0).abs() < 1e-9 && (arc.center.1 - 2.0).abs() < 1e-9);
+        assert!((arc.radius - 3.0).abs() < 1e-9);
+        // The points run clockwise over the top; the ARC runs back.
+        assert!(arc.angle_start_rad.abs() < 1e-9);
+        assert!((arc.angle_end_rad - PI).abs() < 1e-9);
+        assert!(
+            matches!(&out.modelspace[1], WriterEntity::LwPolyline(line) if line.vertices.len() == 5)
+        );
+    }
+}
@@ -1,7 +1,47 @@
+use crate::core::progress::ProgressHook;
+use crate::writer::handle_allocator::HandleLayout;
+use crate::writer::transform::DrawingTransform;
+
+/// Vertex count above which LWPOLYLINEs are split by default. Readers that
+/// index vertices with 16-bit counters reject longer outlines.
+pub const DEFAULT_MAX_LWPOLYLINE_VERTICES: usize = 32_767;
+
 #[derive(Debug, Clone)]
 pub struct WriterConfig {
     pub strict: bool,
     pub preserve_input_handles: bool,
+    /// Registered as an APPID entry so readers can tell which application
+    /// created the file. R2000 has no AppInfo section, so this is the only
+    /// place the name is recorded. `None` writes no APPID.
+    pub application_name: Option<String>,
+    /// Attached to the APPID entry as a string xdata item.
+    pub application_version: Option<String>,
+    /// `ACADMAINTVER` byte at 0x0B of the file header.
+    pub maintenance_version: u8,
+    /// `DWGCODEPAGE` at 0x13 of the file header; 30 is ANSI_1252.
+    pub codepage: u16,
+    /// Called as object records are encoded and once the file is assembled.
+    pub progress: Option<ProgressHook>,
+    /// LWPOLYLINEs with more vertices are written as consecutive open
+    /// pieces, and closed outlines enclosing no area are written open
+    /// without repeated vertices. `None` writes every LWPOLYLINE as given.
+    pub max_lwpolyline_vertices: Option<usize>,
+    /// Near-circular LWPOLYLINE vertex runs are replaced by bulge arcs that
+    /// stay within this distance of the input; see
+    /// [`crate::writer::arc_fit::fit_arcs`]. `None` writes vertices as given.
+    pub arc_fit_tolerance: Option<f64>,
+    /// Moves, rotates and scales modelspace before anything else is done
+    /// to it; see [`crate::writer::transform::transform_document`].
+    pub transform: Option<DrawingTransform>,
+    /// Worker threads that encode entity payloads of large entity lists.
+    /// `None` uses one per core and `Some(1)` encodes on the calling thread;
+    /// the written bytes are the same either way.
+    pub encode_threads: Option<usize>,
+    /// Allocates new handles of table entries, blocks, entities and
+    /// dictionaries from separate ranges. Preserved input handles keep their
+    /// value wherever they fall. `None` numbers all new handles in one
+    /// sequence from 0x10.
+    pub handle_layout: Option<HandleLayout>,
 }
 
 impl Default for WriterConfig {
@@ -9,6 +49,16 @@ impl Default for WriterConfig {
         Self {
             strict: false,
             preserve_input_handles: true,
+            application_name: None,
+            application_version: None,
+            maintenance_version: 0,
+            codepage: 30,
+            progress: None,
+            max_lwpolyline_vertices: Some(DEFAULT_MAX_LWPOLYLINE_VERTICES),
+            arc_fit_tolerance: None,
+            transform: None,
+            encode_threads: None,
+            handle_layout: None,
         }
     }
 }
@@ -3,10 +3,121 @@ use std::collections::HashSet;
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
 
+/// Kind of object a handle is allocated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandleNamespace {
+    /// Symbol table entries: layers, linetypes, APPIDs and MLINESTYLEs.
+    Tables,
+    /// Block records and the layouts that own them.
+    Blocks,
+    /// Graphical entities, including paperspace viewports.
+    Entities,
+    /// Dictionaries and the non-graphical objects they hold, such as
+    /// groups, XRECORDs and copied raw objects.
+    Dictionaries,
+}
+
+impl HandleNamespace {
+    pub const ALL: [HandleNamespace; 4] = [
+        HandleNamespace::Tables,
+        HandleNamespace::Blocks,
+        HandleNamespace::Entities,
+        HandleNamespace::Dictionaries,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HandleNamespace::Tables => "tables",
+            HandleNamespace::Blocks => "blocks",
+            HandleNamespace::Entities => "entities",
+            HandleNamespace::Dictionaries => "dictionaries",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// First handle of each namespace. A namespace runs up to the next higher
+/// start; the one with the highest start is unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandleLayout {
+    pub tables: u64,
+    pub blocks: u64,
+    pub entities: u64,
+    pub dictionaries: u64,
+}
+
+impl Default for HandleLayout {
+    /// Table entries from 0x10, then blocks, dictionaries and entities, so a
+    /// handle read off a hex dump tells what kind of object it belongs to.
+    fn default() -> Self {
+        Self {
+            tables: 0x10,
+            blocks: 0x1000,
+            dictionaries: 0x2000,
+            entities: 0x10000,
+        }
+    }
+}
+
+impl HandleLayout {
+    pub fn start(&self, namespace: HandleNamespace) -> u64 {
+        match namespace {
+            HandleNamespace::Tables => self.tables,
+            HandleNamespace::Blocks => self.blocks,
+            HandleNamespace::Entities => self.entities,
+            HandleNamespace::Dictionaries => self.dictionaries,
+        }
+    }
+
+    /// Exclusive end of `namespace`, or `None` when it is unbounded.
+    pub fn end(&self, namespace: HandleNamespace) -> Option<u64> {
+        let start = self.start(namespace);
+        HandleNamespace::ALL
+            .iter()
+            .map(|&other| self.start(other))
+            .filter(|&other| other > start)
+            .min()
+    }
+
+    /// The namespace whose range holds `handle`, or `None` for handles
+    /// below every range.
+    pub fn namespace_of(&self, handle: u64) -> Option<HandleNamespace> {
+        HandleNamespace::ALL.into_iter().find(|&namespace| {
+            handle >= self.start(namespace) && self.end(namespace).is_none_or(|end| handle < end)
+        })
+    }
+
+    fn validate(&self) -> Result<()> {
+        let mut starts: Vec<u64> = HandleNamespace::ALL
+            .iter()
+            .map(|&namespace| self.start(namespace))
+            .collect();
+        starts.sort_unstable();
+        starts.dedup();
+        if starts.len() != HandleNamespace::ALL.len() || starts.first() == Some(&0) {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!("handle namespaces need distinct non-zero starts: {self:?}"),
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HandleAllocator {
     next: u64,
     used: HashSet<u64>,
+    namespaces: Option<Namespaces>,
+}
+
+#[derive(Debug, Clone)]
+struct Namespaces {
+    layout: HandleLayout,
+    next: [u64; 4],
 }
 
 impl Default for HandleAllocator {
@@ -20,9 +131,34 @@ impl HandleAllocator {
         Self {
             next: start.max(1),
             used: HashSet::new(),
+            namespaces: None,
         }
     }
 
+    /// An allocator that hands out the handles of each namespace from its
+    /// own range of `layout`. Handles below the lowest range are the fixed
+    /// handles of the writer and cannot be reserved.
+    pub fn with_layout(layout: HandleLayout) -> Result<Self> {
+        layout.validate()?;
+        let lowest = HandleNamespace::ALL
+            .iter()
+            .map(|&namespace| layout.start(namespace))
+            .min()
+            .unwrap_or(1);
+        let mut out = Self::new(lowest);
+        out.namespaces = Some(Namespaces {
+            layout,
+            next: HandleNamespace::ALL.map(|namespace| layout.start(namespace)),
+        });
+        Ok(out)
+    }
+
+    pub fn layout(&self) -> Option<&HandleLayout> {
+        self.namespaces
+            .as_ref()
+            .map(|namespaces| &namespaces.layout)
+    }
+
     pub fn with_used(start: u64, used_handles: impl IntoIterator<Item = u64>) -> Self {
         let mut out = Self::new(start);
         for handle in used_handles {
@@ -38,6 +174,14 @@ impl HandleAllocator {
                 "handle 0 is reserved and cannot be allocated",
             ));
         }
+        if let Some(layout) = self.layout() {
+            if layout.namespace_of(handle).is_none() {
+                return Err(DwgError::new(
+                    ErrorKind::Resolve,
+                    format!("handle {handle:#X} collides with the fixed handles of the writer"),
+                ));
+            }
+        }
         if !self.used.insert(handle) {
             return Err(DwgError::new(
                 ErrorKind::Resolve,
@@ -78,6 +222,77 @@ impl HandleAllocator {
         Ok(handle)
     }
 
+    /// Allocates the next free handle of `namespace`, skipping reserved
+    /// handles such as preserved input handles. Without a layout every
+    /// namespace shares the sequence of [`HandleAllocator::allocate`].
+    pub fn allocate_in(&mut self, namespace: HandleNamespace) -> Result<u64> {
+        let Some(namespaces) = &mut self.namespaces else {
+            return self.allocate();
+        };
+        let end = namespaces.layout.end(namespace).unwrap_or(u64::MAX);
+        let Some(next) = namespaces.next.get_mut(namespace.index()) else {
+            return self.allocate();
+        };
+        while *next < end && self.used.contains(next) {
+            *next += 1;
+        }
+        if *next >= end {
+            return Err(DwgError::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "handle namespace {} exhausted at {end:#X}",
+                    namespace.as_str()
+                ),
+            ));
+        }
+        let handle = *next;
+        *next += 1;
+        self.used.insert(handle);
+        Ok(handle)
+    }
+
+    /// Allocates `count` consecutive free handles of `namespace` and
+    /// returns the first, for records that readers find by counting on from
+    /// a handle, such as the vertices of a polyline.
+    pub fn allocate_run_in(&mut self, namespace: HandleNamespace, count: u64) -> Result<u64> {
+        let (mut start, end) = match &self.namespaces {
+            Some(namespaces) => (
+                namespaces
+                    .next
+                    .get(namespace.index())
+                    .copied()
+                    .unwrap_or(self.next),
+                namespaces.layout.end(namespace).unwrap_or(u64::MAX),
+            ),
+            None => (self.next, u64::MAX),
+        };
+        let exhausted = || {
+            DwgError::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "handle namespace {} has no run of {count} free handles",
+                    namespace.as_str()
+                ),
+            )
+        };
+        loop {
+            let run_end = start
+                .checked_add(count)
+                .filter(|run_end| *run_end <= end)
+                .ok_or_else(exhausted)?;
+            match (start..run_end)
+                .rev()
+                .find(|handle| self.used.contains(handle))
+            {
+                Some(used) => start = used + 1,
+                None => {
+                    self.used.extend(start..run_end);
+                    return Ok(start);
+                }
+            }
+        }
+    }
+
     pub fn is_reserved(&self, handle: u64) -> bool {
         self.used.contains(&handle)
     }
@@ -85,7 +300,7 @@ impl HandleAllocator {
 
 #[cfg(test)]
 mod tests {
-    use super::HandleAllocator;
+    use super::{HandleAllocator, HandleLayout, HandleNamespace};
 
     #[test]
     fn allocates_monotonic_handles() {
@@ -96,4 +311,78 @@ mod tests {
         assert_eq!(allocator.allocate().unwrap(), 12);
         assert!(allocator.is_reserved(20));
     }
+
+    #[test]
+    fn allocates_runs_of_consecutive_handles() {
+        let mut allocator = HandleAllocator::new(10);
+        allocator.reserve(12).unwrap();
+        assert_eq!(
+            allocator
+                .allocate_run_in(HandleNamespace::Entities, 3)
+                .unwrap(),
+            13
+        );
+        // Handles skipped by a run are still handed out one at a time.
+        assert_eq!(allocator.allocate().unwrap(), 10);
+        assert_eq!(allocator.allocate().unwrap(), 11);
+        assert_eq!(allocator.allocate().unwrap(), 16);
+    }
+
+    #[test]
+    fn allocates_namespaces_from_their_own_ranges() {
+        let layout = HandleLayout {
+            tables: 0x10,
+            blocks: 0x20,
+            dictionaries: 0x30,
+            entities: 0x40,
+        };
+        let mut allocator = HandleAllocator::with_layout(layout).unwrap();
+        // A preserved input handle inside the tables range is skipped.
+        allocator.reserve(0x11).unwrap();
+        assert_eq!(
+            allocator.allocate_in(HandleNamespace::Tables).unwrap(),
+            0x10
+        );
+        assert_eq!(
+            allocator.allocate_in(HandleNamespace::Tables).unwrap(),
+            0x12
+        );
+        assert_eq!(
+            allocator.allocate_in(HandleNamespace::Entities).unwrap(),
+            0x40
+        );
+        assert_eq!(
+            allocator.allocate_in(HandleNamespace::Blocks).unwrap(),
+            0x20
+        );
+        assert_eq!(
+            layout.namespace_of(0x35),
+            Some(HandleNamespace::Dictionaries)
+        );
+        assert_eq!(
+            layout.namespace_of(u64::MAX),
+            Some(HandleNamespace::Entities)
+        );
+
+        let err = allocator.reserve(0x0C).unwrap_err();
+        assert!(err.to_string().contains("fixed handles"));
+        assert!(allocator.reserve(0x40).is_err());
+
+        for handle in 0x21..0x30 {
+            assert_eq!(
+                allocator.allocate_in(HandleNamespace::Blocks).unwrap(),
+                handle
+            );
+        }
+        let err = allocator.allocate_in(HandleNamespace::Blocks).unwrap_err();
+        assert!(err.to_string().contains("blocks exhausted"));
+        assert!(allocator
+            .allocate_run_in(HandleNamespace::Blocks, 1)
+            .is_err());
+        assert!(HandleAllocator::with_layout(HandleLayout {
+            blocks: 0x10,
+            ..layout
+        })
+        .is_err());
+    }
 }
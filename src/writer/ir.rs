@@ -1,10 +1,34 @@
+use std::sync::Arc;
+
+use crate::dwg::header::DrawingTimes;
+use crate::dwg::r2000::ClassDefinition;
 use crate::dwg::version::DwgVersion;
+use crate::objects::{PlotSettings, XDataValue};
 
 #[derive(Debug, Clone)]
 pub struct WriterDocument {
     pub version: DwgVersion,
     pub modelspace: Vec<WriterEntity>,
     pub layers: Vec<LayerDef>,
+    /// LTYPE entries to write. Entries referenced by an entity but missing
+    /// here are written without a dash pattern.
+    pub linetypes: Vec<LinetypeDef>,
+    /// MLINESTYLE entries to write. Styles referenced by an MLINE but
+    /// missing here are written as the default two-line style.
+    pub mline_styles: Vec<MLineStyleDef>,
+    /// Paperspace layouts in tab order. A model layout is added in front
+    /// when this is non-empty.
+    pub layouts: Vec<LayoutDef>,
+    /// Records copied from a source drawing without being decoded, such as
+    /// proxies and objects of application classes. Shared with the
+    /// [`crate::writer::WriterTemplate`] they came from.
+    pub raw_objects: Arc<[RawObjectDef]>,
+    /// Named selections of entities, written as GROUP objects in the
+    /// `ACAD_GROUP` dictionary.
+    pub groups: Vec<GroupDef>,
+    /// Named dictionaries of XRECORDs holding application data, written as
+    /// entries of the named objects dictionary.
+    pub dictionaries: Vec<DictionaryDef>,
     pub metadata: WriterMetadata,
 }
 
@@ -14,6 +38,12 @@ impl Default for WriterDocument {
             version: DwgVersion::R2000,
             modelspace: Vec::new(),
             layers: vec![LayerDef::default()],
+            linetypes: Vec::new(),
+            mline_styles: Vec::new(),
+            layouts: Vec::new(),
+            raw_objects: Arc::from([]),
+            groups: Vec::new(),
+            dictionaries: Vec::new(),
             metadata: WriterMetadata::default(),
         }
     }
@@ -24,6 +54,12 @@ pub struct WriterMetadata {
     pub insertion_base: (f64, f64, f64),
     pub ext_min: Option<(f64, f64, f64)>,
     pub ext_max: Option<(f64, f64, f64)>,
+    /// `TDCREATE`/`TDUPDATE` and the edit timers. `None` stamps the current
+    /// time as both creation and update time with zeroed timers.
+    pub times: Option<DrawingTimes>,
+    /// Header variable data of a source drawing whose unmodelled variables
+    /// the written header keeps. See [`crate::writer::r2000::header_template`].
+    pub header_template: Option<Arc<[u8]>>,
 }
 
 impl Default for WriterMetadata {
@@ -32,6 +68,8 @@ impl Default for WriterMetadata {
             insertion_base: (0.0, 0.0, 0.0),
             ext_min: None,
             ext_max: None,
+            times: None,
+            header_template: None,
         }
     }
 }
@@ -40,6 +78,11 @@ impl Default for WriterMetadata {
 pub struct LayerDef {
     pub name: String,
     pub color_index: u16,
+    /// `None` or `CONTINUOUS` for solid lines.
+    pub linetype_name: Option<String>,
+    /// DXF group 70 bits: 1 frozen, 2 frozen in new viewports, 4 locked.
+    pub flags: u16,
+    pub is_off: bool,
 }
 
 impl Default for LayerDef {
@@ -47,16 +90,182 @@ impl Default for LayerDef {
         Self {
             name: "0".to_string(),
             color_index: 7,
+            linetype_name: None,
+            flags: 0,
+            is_off: false,
         }
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct LinetypeDef {
+    pub name: String,
+    pub description: String,
+    /// Dash lengths: positive for a dash, negative for a gap, zero for a dot.
+    pub dashes: Vec<f64>,
+}
+
+impl LinetypeDef {
+    pub fn pattern_length(&self) -> f64 {
+        self.dashes.iter().map(|length| length.abs()).sum()
+    }
+}
+
+/// An R2000 object record copied as it is. The writer gives it a new
+/// handle when its own one is taken and rewrites the handle references in
+/// the record; references to objects that are not written are cleared.
+#[derive(Debug, Clone)]
+pub struct RawObjectDef {
+    /// Handle of the object in the source drawing.
+    pub handle: u64,
+    /// Record data from the type code through the handle stream, without
+    /// the size prefix and CRC.
+    pub data: Vec<u8>,
+    /// Class of the record when its type code is a class number. The
+    /// class is added to the classes section and the record renumbered.
+    pub class: Option<ClassDefinition>,
+}
+
+/// A GROUP: a named selection of entities.
+#[derive(Debug, Clone, Default)]
+pub struct GroupDef {
+    /// Names starting with `*` are written as unnamed groups.
+    pub name: String,
+    pub description: String,
+    /// Picking one member selects the whole group.
+    pub selectable: bool,
+    /// Member entities by their [`CommonEntityProps::handle`], in group
+    /// order.
+    pub entities: Vec<u64>,
+}
+
+/// A dictionary of application data in the named objects dictionary.
+#[derive(Debug, Clone, Default)]
+pub struct DictionaryDef {
+    pub name: String,
+    /// XRECORDs by key, each holding values under their DXF group codes.
+    pub records: Vec<(String, Vec<(u16, XDataValue)>)>,
+}
+
+/// An MLINESTYLE entry: the parallel lines an MLINE is drawn with.
+#[derive(Debug, Clone)]
+pub struct MLineStyleDef {
+    pub name: String,
+    pub description: String,
+    /// DXF group 70: 1 fill on, 2 show miters, 16/256 square start/end caps.
+    pub flags: u16,
+    pub fill_color_index: u16,
+    pub start_angle_rad: f64,
+    pub end_angle_rad: f64,
+    pub elements: Vec<MLineStyleElement>,
+}
+
+impl Default for MLineStyleDef {
+    /// The STANDARD style: two BYLAYER lines half a unit either side.
+    fn default() -> Self {
+        Self {
+            name: "STANDARD".to_string(),
+            description: String::new(),
+            flags: 0,
+            fill_color_index: 256,
+            start_angle_rad: std::f64::consts::FRAC_PI_2,
+            end_angle_rad: std::f64::consts::FRAC_PI_2,
+            elements: vec![
+                MLineStyleElement {
+                    offset: 0.5,
+                    color_index: 256,
+                },
+                MLineStyleElement {
+                    offset: -0.5,
+                    color_index: 256,
+                },
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MLineStyleElement {
+    /// Distance to the left of the MLINE path, before scaling.
+    pub offset: f64,
+    pub color_index: u16,
+}
+
+#[derive(Debug, Clone)]
+pub struct LayoutDef {
+    pub name: String,
+    pub plot_settings: PlotSettings,
+    pub limits_min: (f64, f64),
+    pub limits_max: (f64, f64),
+    pub viewports: Vec<ViewportEntity>,
+    pub entities: Vec<WriterEntity>,
+}
+
+impl LayoutDef {
+    /// Creates an empty layout whose limits cover the plotted sheet.
+    pub fn new(name: impl Into<String>, plot_settings: PlotSettings) -> Self {
+        let (width, height) = if plot_settings.rotation % 2 == 1 {
+            (plot_settings.paper_height, plot_settings.paper_width)
+        } else {
+            (plot_settings.paper_width, plot_settings.paper_height)
+        };
+        Self {
+            name: name.into(),
+            plot_settings,
+            limits_min: (0.0, 0.0),
+            limits_max: (width, height),
+            viewports: Vec::new(),
+            entities: Vec::new(),
+        }
+    }
+}
+
+impl Default for LayoutDef {
+    /// `Layout1` on an ISO A4 sheet in landscape, plotted 1:1 in millimeters.
+    fn default() -> Self {
+        Self::new(
+            "Layout1",
+            PlotSettings {
+                page_setup_name: Some(String::new()),
+                printer_name: Some("none_device".to_string()),
+                flags: 688,
+                margins: [7.5, 20.0, 7.5, 20.0],
+                paper_width: 210.0,
+                paper_height: 297.0,
+                paper_size: Some("ISO_A4_(210.00_x_297.00_MM)".to_string()),
+                plot_origin: (0.0, 0.0),
+                paper_units: 1,
+                rotation: 1,
+                plot_type: 5,
+                window_min: (0.0, 0.0),
+                window_max: (0.0, 0.0),
+                real_world_units: 1.0,
+                drawing_units: 1.0,
+                plot_style_table: Some(String::new()),
+                scale_type: 16,
+                scale_factor: 1.0,
+            },
+        )
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct CommonEntityProps {
     pub handle: Option<u64>,
     pub layer_name: String,
     pub color_index: Option<u16>,
     pub true_color: Option<u32>,
+    /// `None` or `BYLAYER` follows the layer. `BYBLOCK` and `CONTINUOUS` are
+    /// stored as flags; any other name refers to an LTYPE entry.
+    pub linetype_name: Option<String>,
+    /// DXF group 370 lineweight: a standard weight in 1/100 mm or -1/-2/-3
+    /// for BYLAYER/BYBLOCK/DEFAULT. `None` is BYLAYER.
+    pub lineweight: Option<i16>,
+    /// Raw transparency (`0x02000000 | alpha`); `None` follows the layer.
+    pub transparency: Option<u32>,
+    /// Extended data by registered application name, as values under
+    /// their DXF group codes (1000 and up).
+    pub xdata: Vec<(String, Vec<(u16, XDataValue)>)>,
 }
 
 #[derive(Debug, Clone)]
@@ -70,6 +279,50 @@ pub enum WriterEntity {
     LwPolyline(LwPolylineEntity),
     Text(TextEntity),
     MText(MTextEntity),
+    Tolerance(ToleranceEntity),
+    Leader(LeaderEntity),
+    MLine(MLineEntity),
+    PolyfaceMesh(PolyfaceMeshEntity),
+}
+
+/// A paperspace viewport showing the model view centered on `view_center`.
+#[derive(Debug, Clone)]
+pub struct ViewportEntity {
+    pub common: CommonEntityProps,
+    /// Center, width and height of the viewport on the sheet.
+    pub center: (f64, f64, f64),
+    pub width: f64,
+    pub height: f64,
+    pub view_center: (f64, f64),
+    pub view_target: (f64, f64, f64),
+    pub view_direction: (f64, f64, f64),
+    /// Model space height shown in the viewport.
+    pub view_height: f64,
+    pub twist_angle_rad: f64,
+    pub lens_length: f64,
+    /// DXF group 90 viewport status flags.
+    pub status_flags: u32,
+    /// Names of the layers frozen in this viewport only.
+    pub frozen_layers: Vec<String>,
+}
+
+impl Default for ViewportEntity {
+    fn default() -> Self {
+        Self {
+            common: CommonEntityProps::default(),
+            center: (0.0, 0.0, 0.0),
+            width: 0.0,
+            height: 0.0,
+            view_center: (0.0, 0.0),
+            view_target: (0.0, 0.0, 0.0),
+            view_direction: (0.0, 0.0, 1.0),
+            view_height: 0.0,
+            twist_angle_rad: 0.0,
+            lens_length: 50.0,
+            status_flags: 0x8060,
+            frozen_layers: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -133,6 +386,17 @@ pub struct TextEntity {
     pub insert: (f64, f64, f64),
     pub height: f64,
     pub rotation_rad: f64,
+    /// DXF group 72: 0 left, 1 center, 2 right, 3 aligned, 4 middle, 5 fit.
+    pub horizontal_alignment: u16,
+    /// DXF group 73: 0 baseline, 1 bottom, 2 middle, 3 top.
+    pub vertical_alignment: u16,
+    /// Second alignment point (DXF group 11): the end of the baseline for
+    /// aligned and fit text, the anchor for the other non-default
+    /// alignments. `None` uses `insert` where an alignment needs a point.
+    pub align_point: Option<(f64, f64, f64)>,
+    /// `None` is the default width factor of 1.
+    pub width_factor: Option<f64>,
+    pub oblique_rad: f64,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -146,3 +410,75 @@ pub struct MTextEntity {
     pub attachment_point: u16,
     pub drawing_direction: u16,
 }
+
+/// A feature control frame. `text` holds the frame contents with the
+/// `%%v` separators and `{\Fgdt;...}` symbol codes of DXF group 1.
+#[derive(Debug, Clone, Default)]
+pub struct ToleranceEntity {
+    pub common: CommonEntityProps,
+    pub text: String,
+    pub insert: (f64, f64, f64),
+    pub x_direction: (f64, f64, f64),
+    pub height: f64,
+    pub dimgap: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LeaderEntity {
+    pub common: CommonEntityProps,
+    /// DXF group 73: 0 MTEXT, 1 TOLERANCE, 2 INSERT, 3 no annotation.
+    pub annotation_type: u16,
+    /// DXF group 72: 0 straight segments, 1 spline.
+    pub path_type: u16,
+    pub points: Vec<(f64, f64, f64)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MLineEntity {
+    pub common: CommonEntityProps,
+    /// Name of an entry of [`WriterDocument::mline_styles`].
+    pub style_name: String,
+    pub scale: f64,
+    /// DXF group 70: 0 top, 1 zero, 2 bottom.
+    pub justification: u8,
+    pub closed: bool,
+    pub vertices: Vec<MLineVertex>,
+}
+
+impl Default for MLineEntity {
+    fn default() -> Self {
+        Self {
+            common: CommonEntityProps::default(),
+            style_name: "STANDARD".to_string(),
+            scale: 1.0,
+            justification: 0,
+            closed: false,
+            vertices: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MLineVertex {
+    pub position: (f64, f64, f64),
+    /// Unit direction of the segment leaving the vertex.
+    pub vertex_direction: (f64, f64, f64),
+    /// Unit direction along which the style lines cross the vertex.
+    pub miter_direction: (f64, f64, f64),
+    /// One list per style element: the distance along the miter from the
+    /// vertex to the element line, then the linetype break distances along
+    /// the element line.
+    pub segment_params: Vec<Vec<f64>>,
+}
+
+/// A polyface mesh, written as a POLYLINE followed by its vertex and face
+/// records and a SEQEND. Only whole drawings can hold one; incremental
+/// updates and block redefinitions reject it.
+#[derive(Debug, Clone, Default)]
+pub struct PolyfaceMeshEntity {
+    pub common: CommonEntityProps,
+    pub vertices: Vec<(f64, f64, f64)>,
+    /// 1-based indices into `vertices`, 0 for the unused fourth corner of a
+    /// triangle. A negative index hides the edge starting at that corner.
+    pub faces: Vec<[i16; 4]>,
+}
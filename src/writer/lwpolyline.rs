@@ -0,0 +1,511 @@
+use std::borrow::Cow;
+
+use crate::writer::ir::{LayoutDef, LwPolylineEntity, WriterDocument, WriterEntity};
+
+/// Closed flag bits: bit 0 is the DXF flag, some files use bit 9 instead.
+pub(crate) const CLOSED_FLAGS: u16 = 0x0001 | 0x0200;
+
+/// Vertices closer to the line than this fraction of the loop extent count
+/// as lying on it.
+const COLLINEAR_TOLERANCE: f64 = 1e-12;
+
+#[derive(Debug, Clone, Copy)]
+struct Vertex {
+    point: (f64, f64),
+    bulge: f64,
+    width: (f64, f64),
+}
+
+/// Rewrites the LWPOLYLINEs of `doc` that some readers reject.
+///
+/// Repeated consecutive vertices are dropped, closed outlines whose
+/// vertices all lie on one line are written open, and outlines with more
+/// than `max_vertices` vertices are cut into open pieces sharing their end
+/// vertices. The first piece keeps the entity handle; the others get new
+/// handles. Returns `doc` itself when nothing needs rewriting.
+pub fn split_lwpolylines(doc: &WriterDocument, max_vertices: usize) -> Cow<'_, WriterDocument> {
+    let max_vertices = max_vertices.max(2);
+    rewrite_lwpolylines(doc, |polyline| {
+        split_lwpolyline(polyline, max_vertices)
+            .map(|pieces| pieces.into_iter().map(WriterEntity::LwPolyline).collect())
+    })
+}
+
+/// Replaces every LWPOLYLINE for which `rewrite` returns entities, in
+/// modelspace and in the layouts. Returns `doc` itself when `rewrite`
+/// returns `None` for all of them.
+pub(crate) fn rewrite_lwpolylines<F>(doc: &WriterDocument, rewrite: F) -> Cow<'_, WriterDocument>
+where
+    F: Fn(&LwPolylineEntity) -> Option<Vec<WriterEntity>>,
+{
+    let modelspace = rewrite_entities(&doc.modelspace, &rewrite);
+    let layouts: Vec<Option<Vec<WriterEntity>>> = doc
+        .layouts
+        .iter()
+        .map(|layout| rewrite_entities(&layout.entities, &rewrite))
+        .collect();
+    if modelspace.is_none() && layouts.iter().all(Option::is_none) {
+        return Cow::Borrowed(doc);
+    }
+    Cow::Owned(WriterDocument {
+        version: doc.version.clone(),
+        modelspace: modelspace.unwrap_or_else(|| doc.modelspace.clone()),
+        layers: doc.layers.clone(),
+        linetypes: doc.linetypes.clone(),
+        mline_styles: doc.mline_styles.clone(),
+        raw_objects: doc.raw_objects.clone(),
+        groups: doc.groups.clone(),
+        dictionaries: doc.dictionaries.clone(),
+        layouts: doc
+            .layouts
+            .iter()
+            .zip(layouts)
+            .map(|(layout, entities)| LayoutDef {
+                name: layout.name.clone(),
+                plot_settings: layout.plot_settings.clone(),
+                limits_min: layout.limits_min,
+                limits_max: layout.limits_max,
+                viewports: layout.viewports.clone(),
+                entities: entities.unwrap_or_else(|| layout.entities.clone()),
+            })
+            .collect(),
+        metadata: doc.metadata.clone(),
+    })
+}
+
+fn rewrite_entities<F>(entities: &[WriterEntity], rewrite: &F) -> Option<Vec<WriterEntity>>
+where
+    F: Fn(&LwPolylineEntity) -> Option<Vec<WriterEntity>>,
+{
+    let mut out: Option<Vec<WriterEntity>> = None;
+    for (index, entity) in entities.iter().enumerate() {
+        let replacement = match entity {
+            WriterEntity::LwPolyline(polyline) => rewrite(polyline),
+            _ => None,
+        };
+        match (replacement, out.as_mut()) {
+            (Some(replacement), Some(out)) => out.extend(replacement),
+            (Some(replacement), None) => {
+                let mut rewritten: Vec<WriterEntity> =
+                    entities.iter().take(index).cloned().collect();
+                rewritten.extend(replacement);
+                out = Some(rewritten);
+            }
+            (None, Some(out)) => out.push(entity.clone()),
+            (None, None) => {}
+        }
+    }
+    out
+}
+
+/// Pieces replacing `polyline`, or `None` when it can be written as is.
+fn split_lwpolyline(
+    polyline: &LwPolylineEntity,
+    max_vertices: usize,
+) -> Option<Vec<LwPolylineEntity>> {
+    let mut closed = polyline.flags & CLOSED_FLAGS != 0;
+    let mut vertices: Vec<Vertex> = Vec::with_capacity(polyline.vertices.len());
+    for (index, point) in polyline.vertices.iter().copied().enumerate() {
+        let vertex = Vertex {
+            point,
+            bulge: polyline.bulges.get(index).copied().unwrap_or(0.0),
+            width: polyline.widths.get(index).copied().unwrap_or((0.0, 0.0)),
+        };
+        match vertices.last_mut() {
+            // The zero-length segment is dropped; the vertex keeps the
+            // bulge and widths of the segment that follows it.
+            Some(last) if last.point == point => {
+                last.bulge = vertex.bulge;
+                last.width = vertex.width;
+            }
+            _ => vertices.push(vertex),
+        }
+    }
+    if closed && vertices.len() > 1 {
+        let first = vertices.first().map(|vertex| vertex.point);
+        if vertices.last().map(|vertex| vertex.point) == first {
+            vertices.pop();
+        }
+    }
+    let deduplicated = vertices.len() != polyline.vertices.len();
+    let degenerate = closed && is_degenerate_loop(&vertices);
+    if degenerate {
+        closed = false;
+    }
+    if !deduplicated && !degenerate && vertices.len() <= max_vertices {
+        return None;
+    }
+
+    let flags = if closed {
+        polyline.flags
+    } else {
+        polyline.flags & !CLOSED_FLAGS
+    };
+    if vertices.len() <= max_vertices {
+        return Some(vec![lwpolyline_piece(polyline, flags, &vertices, true)]);
+    }
+
+    // A closed outline becomes an open run back to its first vertex.
+    if closed {
+        if let Some(&first) = vertices.first() {
+            vertices.push(first);
+        }
+    }
+    let flags = polyline.flags & !CLOSED_FLAGS;
+    let pieces = vertices
+        .windows(max_vertices)
+        .step_by(max_vertices - 1)
+        .map(<[Vertex]>::to_vec)
+        .chain(tail_piece(&vertices, max_vertices))
+        .enumerate()
+        .map(|(index, mut piece)| {
+            if let Some(last) = piece.last_mut() {
+                last.bulge = 0.0;
+            }
+            lwpolyline_piece(polyline, flags, &piece, index == 0)
+        })
+        .collect();
+    Some(pieces)
+}
+
+/// Vertices after the last full window of `max_vertices`, with the shared
+/// vertex in front.
+fn tail_piece(vertices: &[Vertex], max_vertices: usize) -> Option<Vec<Vertex>> {
+    let step = max_vertices - 1;
+    let covered = (vertices.len() - 1) / step * step;
+    if covered + 1 >= vertices.len() {
+        return None;
+    }
+    vertices.get(covered..).map(<[Vertex]>::to_vec)
+}
+
+fn lwpolyline_piece(
+    polyline: &LwPolylineEntity,
+    flags: u16,
+    vertices: &[Vertex],
+    keep_handle: bool,
+) -> LwPolylineEntity {
+    let mut common = polyline.common.clone();
+    if !keep_handle {
+        common.handle = None;
+    }
+    LwPolylineEntity {
+        common,
+        flags,
+        vertices: vertices.iter().map(|vertex| vertex.point).collect(),
+        const_width: polyline.const_width,
+        bulges: if polyline.bulges.is_empty() {
+            Vec::new()
+        } else {
+            vertices.iter().map(|vertex| vertex.bulge).collect()
+        },
+        widths: if polyline.widths.is_empty() {
+            Vec::new()
+        } else {
+            vertices.iter().map(|vertex| vertex.width).collect()
+        },
+    }
+}
+
+/// True when a closed outline encloses no area: it has no arc segments and
+/// all of its vertices lie on one line.
+fn is_degenerate_loop(vertices: &[Vertex]) -> bool {
+    if vertices.iter().any(|vertex| vertex.bulge != 0.0) {
+        return false;
+    }
+    let Some(origin) = vertices.first().map(|vertex| vertex.point) else {
+        return false;
+    };
+    let offset = |point: (f64, f64)| (point.0 - origin.0, point.1 - origin.1);
+    let Some(far) = vertices
+        .iter()
+        .map(|vertex| offset(vertex.point))
+        .max_by(|a, b| a.0.hypot(a.1).total_cmp(&b.0.hypot(b.1)))
+    else {
+        return false;
+    };
+    let extent = far.0.hypot(far.1);
+    if !extent.is_finite() {
+        return false;
+    }
+    vertices.iter().all(|vertex| {
+        let (dx, dy) = offset(vertex.point);
+        (far.0 * dy - far.1 * dx).abs() <= COLLINEAR_TOLERANCE * extent * extent
+    })
+}
+
+impl LwPolylineEntity {
+    /// Drops vertices of straight runs that lie within `tolerance` of the
+    /// simplified outline (Douglas-Peucker). Both ends of every arc segment
+    /// are kept, so bulges stay valid; a kept vertex takes the end width of
+    /// the last segment it replaces.
+    pub fn simplify(&self, tolerance: f64) -> LwPolylineEntity {
+        let count = self.vertices.len();
+        let closed = self.flags & CLOSED_FLAGS != 0 && count > 2;
+        let outline: Vec<(f64, f64)> = self
+            .vertices
+            .iter()
+            .copied()
+            .chain(self.vertices.first().copied().filter(|_| closed))
+            .collect();
+        let segments = outline.len().saturating_sub(1);
+        let mut anchors = vec![0, segments];
+        for index in 0..segments {
+            if self.bulges.get(index).is_some_and(|bulge| *bulge != 0.0) {
+                anchors.extend([index, index + 1]);
+            }
+        }
+        anchors.sort_unstable();
+        anchors.dedup();
+
+        let mut kept = Vec::with_capacity(anchors.len());
+        for pair in anchors.windows(2) {
+            let [first, last] = *pair else {
+                continue;
+            };
+            let Some(run) = outline.get(first..=last) else {
+                continue;
+            };
+            kept.extend(
+                douglas_peucker(run, tolerance)
+                    .into_iter()
+                    .map(|index| index + first)
+                    .filter(|index| *index < last),
+            );
+        }
+        if !closed && count > 0 {
+            kept.push(count - 1);
+        }
+
+        let mut simplified = LwPolylineEntity {
+            common: self.common.clone(),
+            flags: self.flags,
+            vertices: Vec::with_capacity(kept.len()),
+            const_width: self.const_width,
+            bulges: Vec::new(),
+            widths: Vec::new(),
+        };
+        for (position, &index) in kept.iter().enumerate() {
+            let Some(&point) = self.vertices.get(index) else {
+                continue;
+            };
+            simplified.vertices.push(point);
+            if let Some(&bulge) = self.bulges.get(index) {
+                simplified.bulges.push(bulge);
+            }
+            if let Some(&(start_width, _)) = self.widths.get(index) {
+                let next = kept.get(position + 1).copied().unwrap_or(segments);
+                let end_width = next
+                    .checked_sub(1)
+                    .and_then(|last| self.widths.get(last))
+                    .map_or(start_width, |width| width.1);
+                simplified.widths.push((start_width, end_width));
+            }
+        }
+        simplified
+    }
+}
+
+impl WriterDocument {
+    /// Simplifies every LWPOLYLINE in modelspace and the layouts with
+    /// [`LwPolylineEntity::simplify`].
+    pub fn simplify_lwpolylines(&mut self, tolerance: f64) {
+        let entities = self.modelspace.iter_mut().chain(
+            self.layouts
+                .iter_mut()
+                .flat_map(|layout| layout.entities.iter_mut()),
+        );
+        for entity in entities {
+            if let WriterEntity::LwPolyline(polyline) = entity {
+                *polyline = polyline.simplify(tolerance);
+            }
+        }
+    }
+}
+
+/// Indices of the points Douglas-Peucker keeps for `tolerance`: the two
+/// ends, and every point farther than `tolerance` from the chord of the
+/// run it splits. A NaN tolerance keeps every point.
+pub fn douglas_peucker(points: &[(f64, f64)], tolerance: f64) -> Vec<usize> {
+    let count = points.len();
+    if count <= 2 || tolerance.is_nan() {
+        return (0..count).collect();
+    }
+    let mut keep = vec![false; count];
+    let mut runs = vec![(0, count - 1)];
+    while let Some((first, last)) = runs.pop() {
+        for index in [first, last] {
+            if let Some(flag) = keep.get_mut(index) {
+                *flag = true;
+            }
+        }
+        let (Some(&start), Some(&end)) = (points.get(first), points.get(last)) else {
+            continue;
+        };
+        let mut farthest = None;
+        let mut max_distance = tolerance;
+        for (index, &point) in points.iter().enumerate().take(last).skip(first + 1) {
+            let distance = segment_distance(point, start, end);
+            if distance > max_distance {
+                max_distance = distance;
+                farthest = Some(index);
+            }
+        }
+        if let Some(index) = farthest {
+            runs.push((first, index));
+            runs.push((index, last));
+        }
+    }
+    keep.iter()
+        .enumerate()
+        .filter_map(|(index, kept)| kept.then_some(index))
+        .collect()
+}
+
+fn segment_distance(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (point.0 - start.0 - t * dx).hypot(point.1 - start.1 - t * dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{douglas_peucker, split_lwpolylines};
+    use crate::writer::ir::{CommonEntityProps, LwPolylineEntity, WriterDocument, WriterEntity};
+
+    fn document(polylines: Vec<LwPolylineEntity>) -> WriterDocument {
+        WriterDocument {
+            modelspace: polylines
+                .into_iter()
+                .map(WriterEntity::LwPolyline)
+                .collect(),
+            ..WriterDocument::default()
+        }
+    }
+
+    fn polylines(doc: &WriterDocument) -> Vec<&LwPolylineEntity> {
+        doc.modelspace
+            .iter()
+            .filter_map(|entity| match entity {
+                WriterEntity::LwPolyline(polyline) => Some(polyline),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_long_polylines_into_pieces_sharing_end_vertices() {
+        let doc = document(vec![LwPolylineEntity {
+            common: CommonEntityProps {
+                handle: Some(0x40),
+                ..CommonEntityProps::default()
+            },
+            flags: 1,
+            vertices: (0..10).map(|i| (i as f64, (i % 2) as f64)).collect(),
+            bulges: vec![0.5; 10],
+            ..LwPolylineEntity::default()
+        }]);
+        let out = split_lwpolylines(&doc, 4);
+        let pieces = polylines(&out);
+        // 10 vertices plus the closing vertex: 0-3, 3-6, 6-9, 9-0.
+        assert_eq!(pieces.len(), 4);
+        assert_eq!(pieces[0].common.handle, Some(0x40));
+        assert!(pieces[1..]
+            .iter()
+            .all(|piece| piece.common.handle.is_none()));
+        assert!(pieces.iter().all(|piece| piece.flags & 1 == 0));
+        for pair in pieces.windows(2) {
+            assert_eq!(pair[0].vertices.last(), pair[1].vertices.first());
+        }
+        assert_eq!(pieces[3].vertices, vec![(9.0, 1.0), (0.0, 0.0)]);
+        assert_eq!(pieces[3].bulges, vec![0.5, 0.0]);
+        assert!(pieces.iter().all(|piece| piece.vertices.len() <= 4));
+    }
+
+    #[test]
+    fn opens_collinear_closed_loops_and_drops_repeated_vertices() {
+        let doc = document(vec![
+            LwPolylineEntity {
+                flags: 1,
+                vertices: vec![(0.0, 0.0), (2.0, 2.0), (2.0, 2.0), (1.0, 1.0)],
+                ..LwPolylineEntity::default()
+            },
+            LwPolylineEntity {
+                flags: 1,
+                vertices: vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)],
+                ..LwPolylineEntity::default()
+            },
+        ]);
+        let out = split_lwpolylines(&doc, 100);
+        let pieces = polylines(&out);
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces[0].flags, 0);
+        assert_eq!(pieces[0].vertices, vec![(0.0, 0.0), (2.0, 2.0), (1.0, 1.0)]);
+        assert_eq!(pieces[1].flags, 1);
+
+        let untouched = document(vec![LwPolylineEntity {
+            vertices: vec![(0.0, 0.0), (1.0, 0.0)],
+            ..LwPolylineEntity::default()
+        }]);
+        assert!(matches!(
+            split_lwpolylines(&untouched, 100),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn douglas_peucker_keeps_points_beyond_tolerance() {
+        let points = [
+            (0.0, 0.0),
+            (1.0, 0.01),
+            (2.0, -0.01),
+            (3.0, 1.0),
+            (4.0, 0.0),
+        ];
+        assert_eq!(douglas_peucker(&points, 0.1), vec![0, 2, 3, 4]);
+        assert_eq!(douglas_peucker(&points, 2.0), vec![0, 4]);
+        assert_eq!(douglas_peucker(&points, f64::NAN).len(), 5);
+    }
+
+    #[test]
+    fn simplify_keeps_arc_segments_and_closing_vertex() {
+        let polyline = LwPolylineEntity {
+            flags: 1,
+            vertices: vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (3.0, 0.0),
+                (3.0, 1.0),
+                (0.0, 1.0),
+            ],
+            bulges: vec![0.0, 0.0, 0.0, 0.5, 0.0, 0.0],
+            widths: vec![
+                (0.0, 1.0),
+                (1.0, 2.0),
+                (2.0, 3.0),
+                (3.0, 3.0),
+                (0.0, 0.0),
+                (0.0, 0.0),
+            ],
+            ..LwPolylineEntity::default()
+        };
+        let simplified = polyline.simplify(0.001);
+        assert_eq!(
+            simplified.vertices,
+            vec![(0.0, 0.0), (3.0, 0.0), (3.0, 1.0), (0.0, 1.0)]
+        );
+        assert_eq!(simplified.bulges, vec![0.0, 0.5, 0.0, 0.0]);
+        assert_eq!(simplified.widths[0], (0.0, 3.0));
+        assert_eq!(simplified.flags, 1);
+
+        let mut doc = document(vec![polyline]);
+        doc.simplify_lwpolylines(0.001);
+        assert_eq!(polylines(&doc)[0].vertices.len(), 4);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::writer::ir::{MLineEntity, MLineStyleDef, MLineVertex};
+
+impl MLineEntity {
+    /// An MLINE along `points` drawn with the lines of `style`.
+    ///
+    /// `justification` puts the path on the top (0), zero (1) or bottom (2)
+    /// style line and `scale` multiplies the element offsets. Directions and
+    /// miters are computed in the XY plane; each miter bisects the corner,
+    /// so the style lines stay parallel to every segment.
+    pub fn through_points(
+        style: &MLineStyleDef,
+        points: &[(f64, f64, f64)],
+        closed: bool,
+        justification: u8,
+        scale: f64,
+    ) -> MLineEntity {
+        let offsets: Vec<f64> = style
+            .elements
+            .iter()
+            .map(|element| element.offset)
+            .collect();
+        let shift = match justification {
+            0 => offsets.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            2 => offsets.iter().copied().fold(f64::INFINITY, f64::min),
+            _ => 0.0,
+        };
+        let shift = if shift.is_finite() { shift } else { 0.0 };
+        let closed = closed && points.len() > 2;
+        let count = points.len();
+        let direction = |from: usize| -> (f64, f64) {
+            let (Some(start), Some(end)) = (points.get(from), points.get((from + 1) % count))
+            else {
+                return (1.0, 0.0);
+            };
+            unit(end.0 - start.0, end.1 - start.1).unwrap_or((1.0, 0.0))
+        };
+
+        let mut vertices = Vec::with_capacity(count);
+        for (index, &position) in points.iter().enumerate() {
+            let last = index + 1 == count;
+            let outgoing = if last && !closed {
+                direction(index.saturating_sub(1))
+            } else {
+                direction(index)
+            };
+            let incoming = if index == 0 && !closed {
+                outgoing
+            } else {
+                direction((index + count - 1) % count)
+            };
+            let normal = left_normal(outgoing);
+            let incoming_normal = left_normal(incoming);
+            let miter =
+                unit(normal.0 + incoming_normal.0, normal.1 + incoming_normal.1).unwrap_or(normal);
+            // Distance along the miter that moves one unit away from the
+            // outgoing segment; reversals fall back to the plain normal.
+            let cosine = miter.0 * normal.0 + miter.1 * normal.1;
+            let stretch = if cosine > 1e-9 { 1.0 / cosine } else { 1.0 };
+            vertices.push(MLineVertex {
+                position,
+                vertex_direction: (outgoing.0, outgoing.1, 0.0),
+                miter_direction: (miter.0, miter.1, 0.0),
+                segment_params: offsets
+                    .iter()
+                    .map(|offset| vec![(offset - shift) * scale * stretch, 0.0])
+                    .collect(),
+            });
+        }
+        MLineEntity {
+            style_name: style.name.clone(),
+            scale,
+            justification,
+            closed,
+            vertices,
+            ..MLineEntity::default()
+        }
+    }
+}
+
+fn unit(dx: f64, dy: f64) -> Option<(f64, f64)> {
+    let length = dx.hypot(dy);
+    (length > 0.0 && length.is_finite()).then_some((dx / length, dy / length))
+}
+
+fn left_normal(direction: (f64, f64)) -> (f64, f64) {
+    (-direction.1, direction.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::writer::ir::{MLineEntity, MLineStyleDef};
+
+    #[test]
+    fn through_points_offsets_lines_along_corner_miters() {
+        let style = MLineStyleDef::default();
+        let points = [(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (4.0, 3.0, 0.0)];
+        let mline = MLineEntity::through_points(&style, &points, false, 1, 2.0);
+        assert_eq!(mline.style_name, "STANDARD");
+        assert_eq!(mline.vertices.len(), 3);
+
+        let [first, corner, last] = mline.vertices.as_slice() else {
+            panic!("expected three vertices");
+        };
+        assert_eq!(first.vertex_direction, (1.0, 0.0, 0.0));
+        assert_eq!(first.miter_direction, (0.0, 1.0, 0.0));
+        assert_eq!(first.segment_params, vec![vec![1.0, 0.0], vec![-1.0, 0.0]]);
+        // The corner miter bisects the turn, so reaching the offset lines
+        // takes sqrt(2) times their distance.
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert!((corner.miter_direction.0 + half).abs() < 1e-12);
+        assert!((corner.miter_direction.1 - half).abs() < 1e-12);
+        assert!((corner.segment_params[0][0] - std::f64::consts::SQRT_2).abs() < 1e-12);
+        assert_eq!(last.vertex_direction, (0.0, 1.0, 0.0));
+        assert_eq!(last.miter_direction, (-1.0, 0.0, 0.0));
+
+        // Top justification runs the path along the upper line.
+        let top = MLineEntity::through_points(&style, &points, false, 0, 2.0);
+        assert_eq!(
+            top.vertices[0].segment_params,
+            vec![vec![0.0, 0.0], vec![-2.0, 0.0]]
+        );
+    }
+}
@@ -1,14 +1,23 @@
+pub mod arc_fit;
 pub mod config;
 pub mod error;
 pub mod handle_allocator;
 pub mod ir;
+pub mod lwpolyline;
+pub mod mline;
 pub mod object_graph;
 pub mod r2000;
+pub mod template;
+pub mod transform;
 
 pub use config::WriterConfig;
-pub use handle_allocator::HandleAllocator;
+pub use handle_allocator::{HandleAllocator, HandleLayout, HandleNamespace};
 pub use ir::{
-    ArcEntity, CircleEntity, CommonEntityProps, LayerDef, LineEntity, LwPolylineEntity,
-    MTextEntity, PointEntity, RayEntity, TextEntity, WriterDocument, WriterEntity, WriterMetadata,
-    XLineEntity,
+    ArcEntity, CircleEntity, CommonEntityProps, DictionaryDef, GroupDef, LayerDef, LayoutDef,
+    LeaderEntity, LineEntity, LinetypeDef, LwPolylineEntity, MLineEntity, MLineStyleDef,
+    MLineStyleElement, MLineVertex, MTextEntity, PointEntity, PolyfaceMeshEntity, RawObjectDef,
+    RayEntity, TextEntity, ToleranceEntity, ViewportEntity, WriterDocument, WriterEntity,
+    WriterMetadata, XLineEntity,
 };
+pub use template::WriterTemplate;
+pub use transform::DrawingTransform;
@@ -1,3 +1,12 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+/// Nesting depth past which blocks are rejected. Renderers such as the
+/// Python display list stop expanding at the same depth.
+pub const DEFAULT_MAX_BLOCK_DEPTH: usize = 16;
+
 #[derive(Debug, Clone)]
 pub struct WriterObject {
     pub handle: u64,
@@ -8,6 +17,7 @@ pub struct WriterObject {
 #[derive(Debug, Clone, Default)]
 pub struct WriterObjectGraph {
     objects: Vec<WriterObject>,
+    block_references: BlockReferenceGraph,
 }
 
 impl WriterObjectGraph {
@@ -19,12 +29,245 @@ impl WriterObjectGraph {
         self.objects.push(object);
     }
 
+    /// Records that the block record `block` contains an INSERT or MINSERT
+    /// of the block record `referenced`.
+    pub fn add_block_reference(&mut self, block: u64, referenced: u64) {
+        self.block_references.add_reference(block, referenced);
+    }
+
     pub fn objects(&self) -> &[WriterObject] {
         &self.objects
     }
 
+    pub fn block_references(&self) -> &BlockReferenceGraph {
+        &self.block_references
+    }
+
+    /// Fails when the recorded block references form a cycle or nest more
+    /// than `max_depth` blocks deep, see [`BlockReferenceGraph::validate`].
+    pub fn validate(&self, max_depth: usize) -> Result<()> {
+        self.block_references.validate(max_depth).map(|_| ())
+    }
+
     pub fn into_sorted_by_handle(mut self) -> Vec<WriterObject> {
         self.objects.sort_by_key(|obj| obj.handle);
         self.objects
     }
 }
+
+/// Which block records insert which, to catch blocks that would expand
+/// forever or too deep before they are written or expanded.
+#[derive(Debug, Clone, Default)]
+pub struct BlockReferenceGraph {
+    references: BTreeMap<u64, BTreeSet<u64>>,
+    names: BTreeMap<u64, String>,
+}
+
+/// Why a [`BlockReferenceGraph`] cannot be expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockNestingIssue {
+    /// Blocks that insert each other in a loop. The first block is repeated
+    /// at the end; a block inserting itself gives `[block, block]`.
+    Cycle(Vec<u64>),
+    /// The chain of nested blocks from the outermost one whose expansion is
+    /// deeper than the limit.
+    TooDeep(Vec<u64>),
+}
+
+impl BlockReferenceGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_reference(&mut self, block: u64, referenced: u64) {
+        self.references.entry(block).or_default().insert(referenced);
+        self.references.entry(referenced).or_default();
+    }
+
+    /// Names blocks in error messages; unnamed ones show their handle.
+    pub fn set_name(&mut self, block: u64, name: impl Into<String>) {
+        self.names.insert(block, name.into());
+    }
+
+    /// How many block levels an INSERT of each block expands to: 1 for a
+    /// block without nested INSERTs. Returns the first cycle found instead
+    /// when there is one.
+    pub fn depths(&self) -> std::result::Result<BTreeMap<u64, usize>, Vec<u64>> {
+        let mut depths: BTreeMap<u64, usize> = BTreeMap::new();
+        let mut on_path: BTreeSet<u64> = BTreeSet::new();
+        for &root in self.references.keys() {
+            if depths.contains_key(&root) {
+                continue;
+            }
+            // Depth-first without recursion, so corrupt drawings with very
+            // long chains cannot overflow the stack.
+            let mut stack: Vec<(u64, Vec<u64>)> = vec![(root, self.children(root))];
+            on_path.insert(root);
+            while let Some((block, pending)) = stack.last_mut() {
+                let block = *block;
+                let Some(child) = pending.pop() else {
+                    let depth = 1 + self
+                        .children(block)
+                        .iter()
+                        .filter_map(|child| depths.get(child))
+                        .max()
+                        .copied()
+                        .unwrap_or(0);
+                    depths.insert(block, depth);
+                    on_path.remove(&block);
+                    stack.pop();
+                    continue;
+                };
+                if on_path.contains(&child) {
+                    let mut cycle: Vec<u64> = stack
+                        .iter()
+                        .map(|(block, _)| *block)
+                        .skip_while(|block| *block != child)
+                        .collect();
+                    cycle.push(child);
+                    return Err(cycle);
+                }
+                if !depths.contains_key(&child) {
+                    on_path.insert(child);
+                    stack.push((child, self.children(child)));
+                }
+            }
+        }
+        Ok(depths)
+    }
+
+    /// The depth of every block, or the reason the blocks cannot be
+    /// expanded within `max_depth` levels.
+    pub fn check(
+        &self,
+        max_depth: usize,
+    ) -> std::result::Result<BTreeMap<u64, usize>, BlockNestingIssue> {
+        let depths = self.depths().map_err(BlockNestingIssue::Cycle)?;
+        let deepest = depths
+            .iter()
+            .filter(|(_, depth)| **depth > max_depth)
+            .max_by_key(|(block, depth)| (**depth, std::cmp::Reverse(**block)));
+        let Some((&block, _)) = deepest else {
+            return Ok(depths);
+        };
+        let mut chain = vec![block];
+        let mut current = block;
+        // Follow a child one level shallower at each step.
+        while let Some(next) = depths.get(&current).and_then(|depth| {
+            self.children(current)
+                .into_iter()
+                .filter(|child| {
+                    depths
+                        .get(child)
+                        .is_some_and(|child_depth| child_depth + 1 == *depth)
+                })
+                .min()
+        }) {
+            chain.push(next);
+            current = next;
+        }
+        Err(BlockNestingIssue::TooDeep(chain))
+    }
+
+    /// [`BlockReferenceGraph::check`] as a format error naming the blocks.
+    pub fn validate(&self, max_depth: usize) -> Result<BTreeMap<u64, usize>> {
+        self.check(max_depth).map_err(|issue| {
+            let message = match &issue {
+                BlockNestingIssue::Cycle(cycle) => {
+                    format!("circular block reference: {}", self.describe(cycle))
+                }
+                BlockNestingIssue::TooDeep(chain) => {
+                    let shown = chain.get(..max_depth + 1).unwrap_or(chain);
+                    let more = if shown.len() < chain.len() {
+                        " -> ..."
+                    } else {
+                        ""
+                    };
+                    format!(
+                        "blocks nest {} levels deep, more than {max_depth}: {}{more}",
+                        chain.len(),
+                        self.describe(shown)
+                    )
+                }
+            };
+            DwgError::new(ErrorKind::Format, message)
+        })
+    }
+
+    fn children(&self, block: u64) -> Vec<u64> {
+        self.references
+            .get(&block)
+            .map(|children| children.iter().rev().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn describe(&self, blocks: &[u64]) -> String {
+        blocks
+            .iter()
+            .map(|block| match self.names.get(block) {
+                Some(name) => name.clone(),
+                None => format!("{block:#X}"),
+            })
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockNestingIssue, BlockReferenceGraph, WriterObjectGraph};
+
+    #[test]
+    fn depths_count_nested_blocks() {
+        let mut graph = BlockReferenceGraph::new();
+        graph.add_reference(1, 2);
+        graph.add_reference(1, 3);
+        graph.add_reference(2, 3);
+
+        let depths = graph.depths().expect("acyclic");
+
+        assert_eq!(
+            depths.into_iter().collect::<Vec<_>>(),
+            vec![(1, 3), (2, 2), (3, 1)]
+        );
+        assert_eq!(
+            graph.check(2),
+            Err(BlockNestingIssue::TooDeep(vec![1, 2, 3]))
+        );
+        assert!(graph.validate(3).is_ok());
+    }
+
+    #[test]
+    fn cycles_are_reported_with_block_names() {
+        let mut graph = BlockReferenceGraph::new();
+        graph.add_reference(1, 2);
+        graph.add_reference(2, 3);
+        graph.add_reference(3, 2);
+        graph.set_name(2, "DOOR");
+
+        assert_eq!(graph.depths(), Err(vec![2, 3, 2]));
+        let err = graph.validate(16).expect_err("cycle");
+        assert_eq!(err.message, "circular block reference: DOOR -> 0x3 -> DOOR");
+
+        let mut objects = WriterObjectGraph::new();
+        objects.add_block_reference(7, 7);
+        assert!(objects.validate(16).is_err());
+        assert_eq!(objects.block_references().depths(), Err(vec![7, 7]));
+    }
+
+    #[test]
+    fn long_chains_do_not_recurse() {
+        let mut graph = BlockReferenceGraph::new();
+        for block in 0..100_000 {
+            graph.add_reference(block, block + 1);
+        }
+
+        let depths = graph.depths().expect("acyclic");
+
+        assert_eq!(depths[&0], 100_001);
+        let err = graph.validate(16).expect_err("too deep");
+        assert!(err
+            .message
+            .starts_with("blocks nest 100001 levels deep, more than 16: 0x0 -> 0x1"));
+    }
+}
@@ -0,0 +1,349 @@
+//! Redefinition of named blocks in AC1015 files.
+//!
+//! The block record keeps its handle, so INSERTs and every other reference
+//! to it stay valid. The old entities of the block leave the object map,
+//! the new ones are appended under handles from `HANDSEED` upward and the
+//! record's entity chain is relinked to them. `HANDSEED` is raised in an
+//! appended copy of the header section; everything else is appended like
+//! the records of [`super::apply_incremental_update`].
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::header::{header_handle_seed, header_template, reseed_header_section};
+use super::incremental::append_update;
+use super::raw_object::copy_bits;
+use super::{encode_object_record, encode_writer_entity, entity_props, StyleTables};
+use crate::bit::{BitReader, BitWriter, Endian};
+use crate::core::config::ParseConfig;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::decoder::Decoder;
+use crate::dwg::version::DwgVersion;
+use crate::entities::common::{parse_common_entity_handles, parse_common_entity_header_r2000};
+use crate::objects::{decode_block_record, decode_table_entry_name, BlockRecordObject};
+use crate::writer::ir::WriterEntity;
+
+const BLOCK_HEADER_TYPE_CODE: u16 = 0x31;
+const LAYER_TYPE_CODE: u16 = 0x33;
+const LTYPE_TYPE_CODE: u16 = 0x39;
+/// ATTRIB, SEQEND and the VERTEX types, which belong to their parent
+/// entity rather than to the block.
+pub(super) const SUBENTITY_TYPE_CODES: [u16; 7] = [0x02, 0x06, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E];
+
+/// Replaces the entities of the block record named `block_name` (compared
+/// case-insensitively) in an AC1015 file with `entities`, and returns the
+/// updated file bytes, which start with the unchanged original contents.
+///
+/// Entity handles in `entities` are ignored; each entity gets a new one.
+/// Layers and linetypes are looked up by name among the drawing's own
+/// entries, an empty layer name meaning layer `0`. The block keeps its
+/// base point, flags and attribute definitions flag; ATTRIBs of existing
+/// INSERTs are left as they are.
+pub fn redefine_block(
+    original: &[u8],
+    block_name: &str,
+    entities: &[WriterEntity],
+) -> Result<Vec<u8>> {
+    let decoder = Decoder::new(original, ParseConfig::default())?;
+    let version = decoder.version();
+    if *version != DwgVersion::R2000 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "block redefinition only supports AC1015, got {}",
+                version.as_str()
+            ),
+        ));
+    }
+    let index = decoder.build_object_index()?;
+    let offsets: BTreeMap<u64, u64> = index
+        .objects
+        .iter()
+        .map(|obj| (obj.handle.0, obj.offset))
+        .collect();
+
+    let mut block: Option<(BlockRecordObject, Vec<u8>)> = None;
+    let mut tables = StyleTables::default();
+    // (handle, owner) of the entities that belong to another entity.
+    let mut subentities = Vec::new();
+    for obj in &index.objects {
+        let Ok(record) = decoder.parse_object_record(obj.offset) else {
+            continue;
+        };
+        let mut reader = record.bit_reader();
+        let Ok(type_code) = reader.read_bs() else {
+            continue;
+        };
+        match type_code {
+            BLOCK_HEADER_TYPE_CODE => {
+                let Ok(decoded) = decode_block_record(&mut reader, version, None, obj.handle.0)
+                else {
+                    continue;
+                };
+                let matches = decoded
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(block_name));
+                if matches && block.is_none() {
+                    block = Some((decoded, record.body.to_vec()));
+                }
+            }
+            LAYER_TYPE_CODE | LTYPE_TYPE_CODE => {
+                let Ok((handle, Some(name))) =
+                    decode_table_entry_name(&mut reader, version, obj.handle.0)
+                else {
+                    continue;
+                };
+                let table = if type_code == LAYER_TYPE_CODE {
+                    &mut tables.layers
+                } else {
+                    &mut tables.linetypes
+                };
+                table.entry(name.to_ascii_uppercase()).or_insert(handle);
+            }
+            code if SUBENTITY_TYPE_CODES.contains(&code) => {
+                let Ok(header) = parse_common_entity_header_r2000(&mut reader) else {
+                    continue;
+                };
+                reader.set_bit_pos(header.obj_size);
+                if let Ok(handles) = parse_common_entity_handles(&mut reader, &header) {
+                    if let Some(owner) = handles.owner_ref {
+                        subentities.push((obj.handle.0, owner));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    let (block, block_data) = block.ok_or_else(|| {
+        DwgError::new(ErrorKind::Resolve, format!("block not found: {block_name}"))
+    })?;
+    if block.is_xref || block.is_overlaid {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!("cannot redefine the external reference block {block_name}"),
+        ));
+    }
+
+    let owners: HashSet<u64> = match block.first_entity {
+        Some(first) => entity_chain(&decoder, &offsets, first, block.last_entity)?
+            .into_iter()
+            .collect(),
+        None => HashSet::new(),
+    };
+    let mut removed = owners.clone();
+    removed.extend(
+        subentities
+            .iter()
+            .filter(|(_, owner)| owners.contains(owner))
+            .map(|(handle, _)| *handle),
+    );
+
+    let template = header_template(original)?;
+    let next_free = offsets.keys().next_back().map_or(1, |handle| handle + 1);
+    let first_handle = header_handle_seed(&template)?.max(next_free);
+    let mut records = Vec::with_capacity(entities.len() + 1);
+    for (handle, entity) in (first_handle..).zip(entities) {
+        let props = entity_props(entity);
+        let layer_name = match props.layer_name.as_str() {
+            "" => "0",
+            name => name,
+        };
+        let layer_handle = tables
+            .layers
+            .get(&layer_name.to_ascii_uppercase())
+            .copied()
+            .ok_or_else(|| {
+                DwgError::new(ErrorKind::Resolve, format!("layer not found: {layer_name}"))
+            })?;
+        let style = tables.resolve(props)?;
+        let mline_style = tables.mline_style(entity)?;
+        let payload = encode_writer_entity(
+            entity,
+            handle,
+            block.handle,
+            layer_handle,
+            style,
+            mline_style,
+        )?;
+        records.push((handle, encode_object_record(&payload)?));
+    }
+    let handle_seed = first_handle + entities.len() as u64;
+    // The writer stores no entity links, so the chain runs over the
+    // consecutive new handles.
+    let (first, last) = match entities.len() {
+        0 => (0, 0),
+        _ => (first_handle, handle_seed - 1),
+    };
+    let block_record = relink_block_record(&block_data, first, last)?;
+    records.push((block.handle, encode_object_record(&block_record)?));
+
+    let header = reseed_header_section(&template, handle_seed)?;
+    append_update(original, &index.objects, &records, &removed, Some(&header))
+}
+
+/// Entity handles of an R2000 block from `first` to `last`, following the
+/// stored links or consecutive handles for entities without them.
+pub(super) fn entity_chain(
+    decoder: &Decoder<'_>,
+    offsets: &BTreeMap<u64, u64>,
+    first: u64,
+    last: Option<u64>,
+) -> Result<Vec<u64>> {
+    let mut handles = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = first;
+    while seen.insert(current) {
+        let Some(offset) = offsets.get(&current) else {
+            break;
+        };
+        handles.push(current);
+        if Some(current) == last {
+            break;
+        }
+        let record = decoder.parse_object_record(*offset)?;
+        let mut reader = record.bit_reader();
+        reader.read_bs()?;
+        let mut header = parse_common_entity_header_r2000(&mut reader)?;
+        if !header.has_legacy_entity_links {
+            current += 1;
+            continue;
+        }
+        if header.handle == 0 {
+            header.handle = current;
+        }
+        reader.set_bit_pos(header.obj_size);
+        match parse_common_entity_handles(&mut reader, &header)?.next_entity {
+            Some(next) if next != 0 => current = next,
+            _ => break,
+        }
+    }
+    Ok(handles)
+}
+
+/// Re-encodes the R2000 BLOCK_HEADER record `data` with `first` and `last`
+/// as its entity chain. Returns the record data without the size prefix
+/// and CRC.
+pub(super) fn relink_block_record(data: &[u8], first: u64, last: u64) -> Result<Vec<u8>> {
+    let total_bits = (data.len() as u64).saturating_mul(8);
+    let mut reader = BitReader::new(data);
+    reader.read_bs()?;
+    let obj_size = reader.read_rl(Endian::Little)?;
+    reader.read_h()?;
+    loop {
+        let size = reader.read_bs()?;
+        if size == 0 {
+            break;
+        }
+        reader.read_h()?;
+        reader.read_rcs(usize::from(size))?;
+    }
+    let num_reactors = reader.read_bl()?;
+
+    // Owner, reactors, extension dictionary, NULL and BLOCK entity come
+    // before the chain.
+    reader.set_bit_pos(obj_size);
+    for _ in 0..u64::from(num_reactors) + 4 {
+        reader.read_h()?;
+    }
+    let chain_start = reader.tell_bits();
+    reader.read_h()?;
+    reader.read_h()?;
+    let chain_end = reader.tell_bits();
+
+    let mut out = BitWriter::new();
+    let mut reader = BitReader::new(data);
+    copy_bits(&mut reader, &mut out, chain_start)?;
+    out.write_h(0x04, first)?;
+    out.write_h(0x04, last)?;
+    reader.set_bit_pos(chain_end as u32);
+    copy_bits(&mut reader, &mut out, total_bits)?;
+    Ok(out.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::redefine_block;
+    use crate::core::config::ParseConfig;
+    use crate::core::error::ErrorKind;
+    use crate::dwg::decoder::Decoder;
+    use crate::entities::{decode_circle, decode_line};
+    use crate::objects::{decode_block_record, BlockRecordObject};
+    use crate::writer::ir::{CircleEntity, CommonEntityProps, WriterEntity};
+    use crate::writer::r2000::header::{header_handle_seed, header_template};
+
+    fn block_record(bytes: &[u8], name: &str) -> BlockRecordObject {
+        let decoder = Decoder::new(bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        index
+            .objects
+            .iter()
+            .find_map(|obj| {
+                let record = decoder.parse_object_record(obj.offset).ok()?;
+                let mut reader = record.bit_reader();
+                if reader.read_bs().ok()? != 0x31 {
+                    return None;
+                }
+                decode_block_record(&mut reader, decoder.version(), None, obj.handle.0)
+                    .ok()
+                    .filter(|block| block.name.as_deref() == Some(name))
+            })
+            .expect("block record")
+    }
+
+    fn circle(radius: f64) -> WriterEntity {
+        WriterEntity::Circle(CircleEntity {
+            common: CommonEntityProps::default(),
+            center: (1.0, 2.0, 0.0),
+            radius,
+        })
+    }
+
+    #[test]
+    fn replaces_block_entities_and_raises_handle_seed() {
+        let original = std::fs::read("test_dwg/line_2000.dwg").expect("sample");
+        let before = block_record(&original, "*Model_Space");
+        let old_line = before.first_entity.expect("line in model space");
+
+        let updated = redefine_block(&original, "*model_space", &[circle(1.5), circle(3.0)])
+            .expect("redefine block");
+        let after = block_record(&updated, "*Model_Space");
+        assert_eq!(after.handle, before.handle);
+        assert_eq!(after.block_entity, before.block_entity);
+        assert_eq!(after.end_block_entity, before.end_block_entity);
+        let (first, last) = (after.first_entity.unwrap(), after.last_entity.unwrap());
+        assert_eq!(last, first + 1);
+        let seed = header_handle_seed(&header_template(&updated).unwrap()).unwrap();
+        assert_eq!(seed, last + 1);
+        assert!(first >= header_handle_seed(&header_template(&original).unwrap()).unwrap());
+
+        let decoder = Decoder::new(&updated, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert!(!index.objects.iter().any(|obj| obj.handle.0 == old_line));
+        let mut radii = Vec::new();
+        for obj in &index.objects {
+            let record = decoder.parse_object_record(obj.offset).expect("record");
+            let mut reader = record.bit_reader();
+            match reader.read_bs().expect("type") {
+                0x12 => radii.push(decode_circle(&mut reader).expect("circle").radius),
+                0x13 => panic!("line {:?} left behind", decode_line(&mut reader)),
+                _ => {}
+            }
+        }
+        assert_eq!(radii, vec![1.5, 3.0]);
+    }
+
+    #[test]
+    fn rejects_unknown_blocks_and_layers() {
+        let original = std::fs::read("test_dwg/line_2000.dwg").expect("sample");
+        let err = redefine_block(&original, "NO_SUCH_BLOCK", &[]).expect_err("unknown block");
+        assert_eq!(err.kind, ErrorKind::Resolve);
+
+        let mut entity = circle(1.0);
+        if let WriterEntity::Circle(circle) = &mut entity {
+            circle.common.layer_name = "NO_SUCH_LAYER".to_string();
+        }
+        let err = redefine_block(&original, "*Model_Space", &[entity]).expect_err("unknown layer");
+        assert_eq!(err.kind, ErrorKind::Resolve);
+    }
+}
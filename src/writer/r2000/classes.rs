@@ -1,5 +1,7 @@
-use crate::bit::{BitWriter, Endian};
+use super::crc::{crc16, CRC_SEED};
+use crate::bit::BitWriter;
 use crate::core::result::Result;
+use crate::dwg::r2000::ClassDefinition;
 
 const SENTINEL_CLASSES_BEFORE: [u8; 16] = [
     0x8D, 0xA1, 0xC4, 0xB8, 0xC4, 0xA9, 0xF8, 0xC5, 0xC0, 0xDC, 0xF4, 0x5F, 0xE7, 0xCF, 0xB6, 0x8A,
@@ -9,10 +11,33 @@ const SENTINEL_CLASSES_AFTER: [u8; 16] = [
 ];
 
 pub fn encode_minimal_classes_section() -> Result<Vec<u8>> {
+    encode_classes_section(&[])
+}
+
+/// Encodes the AC1015 classes section with `classes` in order, each under
+/// its own `class_number`.
+pub fn encode_classes_section(classes: &[ClassDefinition]) -> Result<Vec<u8>> {
     let mut writer = BitWriter::new();
-    writer.write_rcs(&SENTINEL_CLASSES_BEFORE)?;
-    writer.write_rl(Endian::Little, 0)?; // class data size bytes
-    writer.write_crc_zero()?;
-    writer.write_rcs(&SENTINEL_CLASSES_AFTER)?;
-    Ok(writer.into_bytes())
+    for class in classes {
+        writer.write_bs(class.class_number)?;
+        writer.write_bs(class.proxy_flags)?;
+        writer.write_tv(&class.app_name)?;
+        writer.write_tv(&class.cpp_name)?;
+        writer.write_tv(&class.dxf_name)?;
+        writer.write_b(u8::from(class.was_a_zombie))?;
+        writer.write_bs(class.item_class_id)?;
+    }
+    let data = writer.into_bytes();
+
+    let mut out = Vec::with_capacity(data.len() + 38);
+    out.extend_from_slice(&SENTINEL_CLASSES_BEFORE);
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(&data);
+    let crc = crc16(
+        CRC_SEED,
+        out.get(SENTINEL_CLASSES_BEFORE.len()..).unwrap_or_default(),
+    );
+    out.extend_from_slice(&crc.to_le_bytes());
+    out.extend_from_slice(&SENTINEL_CLASSES_AFTER);
+    Ok(out)
 }
@@ -0,0 +1,33 @@
+/// Seed of the CRC stored after the section locator records and after each
+/// object map chunk.
+pub const CRC_SEED: u16 = 0xC0C1;
+
+/// CRC-16 with the reflected 0xA001 polynomial used by R13-R2000 files.
+pub fn crc16(seed: u16, data: &[u8]) -> u16 {
+    let mut crc = seed;
+    for byte in data {
+        crc ^= u16::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xA001
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc16, CRC_SEED};
+
+    #[test]
+    fn matches_section_locator_crc_of_r2000_sample() {
+        let bytes = std::fs::read("test_dwg/line_2000.dwg").expect("sample file");
+        let count = u32::from_le_bytes(bytes[0x15..0x19].try_into().unwrap()) as usize;
+        let crc_offset = 0x19 + count * 9;
+        let stored = u16::from_le_bytes([bytes[crc_offset], bytes[crc_offset + 1]]);
+        assert_eq!(crc16(CRC_SEED, &bytes[..crc_offset]), stored);
+    }
+}
@@ -1,14 +1,15 @@
 use crate::bit::BitWriter;
 use crate::core::result::Result;
 
-use super::common::{encode_entity_payload, CommonEntityEncodeInput};
+use super::common::{encode_entity_payload, CommonEntityEncodeInput, EntityStyleEncodeInput};
 
 #[derive(Debug, Clone, Copy)]
 pub struct CircleEncodeInput {
     pub handle: u64,
     pub owner_handle: u64,
     pub layer_handle: u64,
-    pub color_index: u8,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
     pub center: (f64, f64, f64),
     pub radius: f64,
 }
@@ -19,6 +20,7 @@ pub fn encode_circle_entity_payload(input: CircleEncodeInput) -> Result<Vec<u8>>
         owner_handle: input.owner_handle,
         layer_handle: input.layer_handle,
         color_index: input.color_index,
+        style: input.style,
     };
     encode_entity_payload(0x12, common, |writer| write_circle_geometry(writer, input))
 }
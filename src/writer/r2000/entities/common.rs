@@ -1,13 +1,41 @@
-use crate::bit::{BitWriter, Endian};
+use crate::bit::{BitReader, BitWriter, Endian};
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
+use crate::entities::common::LINE_WEIGHT_BY_LAYER_INDEX;
+use crate::writer::r2000::raw_object::copy_bits;
 
 #[derive(Debug, Clone, Copy)]
 pub struct CommonEntityEncodeInput {
     pub handle: u64,
     pub owner_handle: u64,
     pub layer_handle: u64,
-    pub color_index: u8,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
+}
+
+/// Linetype, lineweight and transparency of an entity. The default follows
+/// the layer for all three.
+#[derive(Debug, Clone, Copy)]
+pub struct EntityStyleEncodeInput {
+    /// 0 BYLAYER, 1 BYBLOCK, 2 CONTINUOUS, 3 the LTYPE at `ltype_handle`.
+    pub ltype_flags: u8,
+    pub ltype_handle: u64,
+    /// Stored lineweight enum index.
+    pub line_weight: u8,
+    /// `AcCmTransparency` APPID handle and the raw transparency value, stored
+    /// as extended data since R2000 has no transparency field.
+    pub transparency: Option<(u64, u32)>,
+}
+
+impl Default for EntityStyleEncodeInput {
+    fn default() -> Self {
+        Self {
+            ltype_flags: 0,
+            ltype_handle: 0,
+            line_weight: LINE_WEIGHT_BY_LAYER_INDEX,
+            transparency: None,
+        }
+    }
 }
 
 pub fn encode_entity_payload<F>(
@@ -17,6 +45,21 @@ pub fn encode_entity_payload<F>(
 ) -> Result<Vec<u8>>
 where
     F: FnOnce(&mut BitWriter) -> Result<()>,
+{
+    encode_entity_payload_with_handles(type_code, common, write_body, |_| Ok(()))
+}
+
+/// Like [`encode_entity_payload`], with `write_handles` appending the
+/// entity-specific references after the owner and layer handles.
+pub fn encode_entity_payload_with_handles<F, H>(
+    type_code: u16,
+    common: CommonEntityEncodeInput,
+    write_body: F,
+    write_handles: H,
+) -> Result<Vec<u8>>
+where
+    F: FnOnce(&mut BitWriter) -> Result<()>,
+    H: FnOnce(&mut BitWriter) -> Result<()>,
 {
     validate_common_input(common)?;
 
@@ -27,13 +70,18 @@ where
     write_common_header_no_obj_size(
         &mut pre_handle,
         common.handle,
-        u16::from(common.color_index),
+        common.color_index,
+        &common.style,
     )?;
     write_body(&mut pre_handle)?;
 
     let mut handle_stream = BitWriter::new();
     handle_stream.write_h(0x02, common.owner_handle)?;
     handle_stream.write_h(0x02, common.layer_handle)?;
+    if common.style.ltype_flags == 3 {
+        handle_stream.write_h(0x05, common.style.ltype_handle)?;
+    }
+    write_handles(&mut handle_stream)?;
 
     let obj_size_bits = type_prefix
         .len_bits()
@@ -54,6 +102,141 @@ where
     Ok(out.into_bytes())
 }
 
+/// Re-encodes the entity record `data`, as encoded by
+/// [`encode_entity_payload`], with `reactors` added to its persistent
+/// reactors, such as the GROUP objects it belongs to. Returns the record
+/// data without the size prefix and CRC.
+pub fn add_entity_reactors(data: &[u8], reactors: &[u64]) -> Result<Vec<u8>> {
+    let total_bits = (data.len() as u64).saturating_mul(8);
+    let mut reader = BitReader::new(data);
+    reader.read_bs()?;
+    let size_pos = reader.tell_bits();
+    let obj_size = u64::from(reader.read_rl(Endian::Little)?);
+    reader.read_h()?;
+    loop {
+        let size = reader.read_bs()?;
+        if size == 0 {
+            break;
+        }
+        reader.read_h()?;
+        reader.read_rcs(usize::from(size))?;
+    }
+    if reader.read_b()? != 0 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            "cannot add reactors to entities with graphics data",
+        ));
+    }
+    let entity_mode = reader.read_bb()?;
+    let count_start = reader.tell_bits();
+    let count = reader.read_bl()?;
+    let count_end = reader.tell_bits();
+    if obj_size < count_end || obj_size > total_bits {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("object data size out of range: {obj_size} bits"),
+        ));
+    }
+    reader.set_bit_pos(obj_size as u32);
+    if entity_mode == 0 {
+        reader.read_h()?; // owner
+    }
+    for _ in 0..count {
+        reader.read_h()?;
+    }
+    let reactors_end = reader.tell_bits();
+
+    let mut count_bits = BitWriter::new();
+    let total = u32::try_from(reactors.len())
+        .ok()
+        .and_then(|added| count.checked_add(added))
+        .ok_or_else(|| DwgError::new(ErrorKind::Unsupported, "too many entity reactors"))?;
+    count_bits.write_bl(total)?;
+    let obj_size = (obj_size - (count_end - count_start)).saturating_add(count_bits.len_bits());
+    let obj_size = u32::try_from(obj_size).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!("entity object data bits exceed u32: {obj_size}"),
+        )
+    })?;
+
+    let mut out = BitWriter::new();
+    let mut reader = BitReader::new(data);
+    copy_bits(&mut reader, &mut out, size_pos)?;
+    out.write_rl(Endian::Little, obj_size)?;
+    reader.set_bit_pos((size_pos + 32) as u32);
+    copy_bits(&mut reader, &mut out, count_start)?;
+    out.write_bits_from_bytes(&count_bits.to_bytes(), count_bits.len_bits())?;
+    reader.set_bit_pos(count_end as u32);
+    copy_bits(&mut reader, &mut out, reactors_end)?;
+    for reactor in reactors {
+        out.write_h(0x04, *reactor)?;
+    }
+    copy_bits(&mut reader, &mut out, total_bits)?;
+    Ok(out.into_bytes())
+}
+
+/// Re-encodes the entity record `data`, as encoded by
+/// [`encode_entity_payload`], with extended data `blocks` appended to its
+/// extended data, each as the APPID handle and the encoded items. Returns
+/// the record data without the size prefix and CRC.
+pub fn add_entity_xdata(data: &[u8], blocks: &[(u64, Vec<u8>)]) -> Result<Vec<u8>> {
+    let total_bits = (data.len() as u64).saturating_mul(8);
+    let mut reader = BitReader::new(data);
+    reader.read_bs()?;
+    let size_pos = reader.tell_bits();
+    let obj_size = u64::from(reader.read_rl(Endian::Little)?);
+    reader.read_h()?;
+    let mut eed_end = reader.tell_bits();
+    loop {
+        let size = reader.read_bs()?;
+        if size == 0 {
+            break;
+        }
+        reader.read_h()?;
+        reader.read_rcs(usize::from(size))?;
+        eed_end = reader.tell_bits();
+    }
+    if obj_size < reader.tell_bits() || obj_size > total_bits {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("object data size out of range: {obj_size} bits"),
+        ));
+    }
+
+    let mut eed = BitWriter::new();
+    for (app_handle, bytes) in blocks {
+        let size = u16::try_from(bytes.len())
+            .ok()
+            .filter(|size| *size != 0)
+            .ok_or_else(|| {
+                DwgError::new(
+                    ErrorKind::Unsupported,
+                    format!("extended data size out of range: {} bytes", bytes.len()),
+                )
+            })?;
+        eed.write_bs(size)?;
+        eed.write_h(0x05, *app_handle)?;
+        eed.write_rcs(bytes)?;
+    }
+    let obj_size = u32::try_from(obj_size.saturating_add(eed.len_bits())).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!("entity object data bits exceed u32: {obj_size}"),
+        )
+    })?;
+
+    let mut out = BitWriter::new();
+    let mut reader = BitReader::new(data);
+    copy_bits(&mut reader, &mut out, size_pos)?;
+    out.write_rl(Endian::Little, obj_size)?;
+    reader.set_bit_pos((size_pos + 32) as u32);
+    copy_bits(&mut reader, &mut out, eed_end)?;
+    out.write_bits_from_bytes(&eed.to_bytes(), eed.len_bits())?;
+    copy_bits(&mut reader, &mut out, total_bits)?;
+    Ok(out.into_bytes())
+}
+
 fn validate_common_input(input: CommonEntityEncodeInput) -> Result<()> {
     if input.handle == 0 {
         return Err(DwgError::new(
@@ -67,6 +250,14 @@ fn validate_common_input(input: CommonEntityEncodeInput) -> Result<()> {
             "entity owner/layer handles must be non-zero",
         ));
     }
+    if input.style.ltype_flags > 3
+        || (input.style.ltype_flags == 3 && input.style.ltype_handle == 0)
+    {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            "entity linetype must be BYLAYER, BYBLOCK, CONTINUOUS or an LTYPE handle",
+        ));
+    }
     Ok(())
 }
 
@@ -74,20 +265,36 @@ fn write_common_header_no_obj_size(
     writer: &mut BitWriter,
     handle: u64,
     color_index: u16,
+    style: &EntityStyleEncodeInput,
 ) -> Result<()> {
     writer.write_h(0x02, handle)?;
+    if let Some((app_handle, transparency)) = style.transparency {
+        writer.write_bs(5)?; // ext_size
+        writer.write_h(0x05, app_handle)?;
+        writer.write_rc(0x47)?; // 1071 long
+        writer.write_rl(Endian::Little, transparency)?;
+    }
     writer.write_bs(0)?; // ext_size
     writer.write_b(0)?; // graphic_present_flag
     writer.write_bb(0)?; // entity_mode
     writer.write_bl(0)?; // num_of_reactors
     writer.write_b(1)?; // xdic_missing_flag
     writer.write_b(0)?; // no_links == 0 => CMC follows
-    writer.write_b(1)?; // CMC mode 1 => ACI byte
-    writer.write_rc((color_index & 0xFF) as u8)?;
+    match u8::try_from(color_index) {
+        Ok(index) => {
+            writer.write_b(1)?; // CMC mode 1 => ACI byte
+            writer.write_rc(index)?;
+        }
+        Err(_) => {
+            // BYLAYER and other indexes above a byte take the flags form.
+            writer.write_b(0)?;
+            writer.write_rs(Endian::Little, color_index & 0x01FF)?;
+        }
+    }
     writer.write_bd(1.0)?; // ltype scale
-    writer.write_bb(0)?; // ltype_flags
+    writer.write_bb(style.ltype_flags)?;
     writer.write_bb(0)?; // plotstyle_flags
     writer.write_bs(0)?; // invisibility
-    writer.write_rc(0)?; // line weight
+    writer.write_rc(style.line_weight)?;
     Ok(())
 }
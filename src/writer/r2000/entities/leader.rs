@@ -0,0 +1,61 @@
+use crate::bit::BitWriter;
+use crate::core::result::Result;
+
+use super::common::{
+    encode_entity_payload_with_handles, CommonEntityEncodeInput, EntityStyleEncodeInput,
+};
+
+#[derive(Debug, Clone)]
+pub struct LeaderEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub layer_handle: u64,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
+    pub annotation_type: u16,
+    pub path_type: u16,
+    pub points: Vec<(f64, f64, f64)>,
+}
+
+pub fn encode_leader_entity_payload(input: &LeaderEncodeInput) -> Result<Vec<u8>> {
+    let common = CommonEntityEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        layer_handle: input.layer_handle,
+        color_index: input.color_index,
+        style: input.style,
+    };
+    encode_entity_payload_with_handles(
+        0x2D,
+        common,
+        |writer| write_leader_body(writer, input),
+        |writer| {
+            writer.write_h(0x05, 0)?; // associated annotation
+            writer.write_h(0x05, 0) // DIMSTYLE
+        },
+    )
+}
+
+fn write_leader_body(writer: &mut BitWriter, input: &LeaderEncodeInput) -> Result<()> {
+    writer.write_b(0)?; // unknown bit
+    writer.write_bs(input.annotation_type)?;
+    writer.write_bs(input.path_type)?;
+    writer.write_bl(input.points.len() as u32)?;
+    for &(x, y, z) in &input.points {
+        writer.write_3bd(x, y, z)?;
+    }
+    let origin = input.points.first().copied().unwrap_or((0.0, 0.0, 0.0));
+    writer.write_3bd(origin.0, origin.1, origin.2)?;
+    writer.write_3bd(0.0, 0.0, 1.0)?; // extrusion
+    writer.write_3bd(1.0, 0.0, 0.0)?; // x direction
+    writer.write_3bd(0.0, 0.0, 0.0)?; // offset to block insertion point
+    writer.write_3bd(0.0, 0.0, 0.0)?; // endpoint projection
+    writer.write_bd(0.0)?; // box height
+    writer.write_bd(0.0)?; // box width
+    writer.write_b(0)?; // hookline on x direction
+    writer.write_b(1)?; // arrowhead on
+    writer.write_bs(0)?; // unknown short
+    writer.write_b(0)?; // unknown bit
+    writer.write_b(0)?; // unknown bit
+    Ok(())
+}
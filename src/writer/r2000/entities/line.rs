@@ -1,14 +1,15 @@
 use crate::bit::{BitWriter, Endian};
 use crate::core::result::Result;
 
-use super::common::{encode_entity_payload, CommonEntityEncodeInput};
+use super::common::{encode_entity_payload, CommonEntityEncodeInput, EntityStyleEncodeInput};
 
 #[derive(Debug, Clone, Copy)]
 pub struct LineEncodeInput {
     pub handle: u64,
     pub owner_handle: u64,
     pub layer_handle: u64,
-    pub color_index: u8,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
     pub start: (f64, f64, f64),
     pub end: (f64, f64, f64),
 }
@@ -19,6 +20,7 @@ pub fn encode_line_entity_payload(input: LineEncodeInput) -> Result<Vec<u8>> {
         owner_handle: input.owner_handle,
         layer_handle: input.layer_handle,
         color_index: input.color_index,
+        style: input.style,
     };
     encode_entity_payload(0x13, common, |writer| {
         write_line_geometry(writer, input.start, input.end)
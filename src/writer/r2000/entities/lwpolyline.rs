@@ -2,14 +2,15 @@ use crate::bit::{BitWriter, Endian};
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
 
-use super::common::{encode_entity_payload, CommonEntityEncodeInput};
+use super::common::{encode_entity_payload, CommonEntityEncodeInput, EntityStyleEncodeInput};
 
 #[derive(Debug, Clone)]
 pub struct LwPolylineEncodeInput {
     pub handle: u64,
     pub owner_handle: u64,
     pub layer_handle: u64,
-    pub color_index: u8,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
     pub flags: u16,
     pub vertices: Vec<(f64, f64)>,
     pub const_width: Option<f64>,
@@ -29,6 +30,7 @@ pub fn encode_lwpolyline_entity_payload(input: LwPolylineEncodeInput) -> Result<
         owner_handle: input.owner_handle,
         layer_handle: input.layer_handle,
         color_index: input.color_index,
+        style: input.style,
     };
     encode_entity_payload(0x4D, common, |writer| write_lwpolyline_body(writer, &input))
 }
@@ -66,7 +68,12 @@ fn write_lwpolyline_body(writer: &mut BitWriter, input: &LwPolylineEncodeInput)
         writer.write_bl(width_count as u32)?;
     }
 
-    let (x0, y0) = input.vertices[0];
+    let Some(&(x0, y0)) = input.vertices.first() else {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            "LWPOLYLINE requires at least one vertex",
+        ));
+    };
     writer.write_rd(Endian::Little, x0)?;
     writer.write_rd(Endian::Little, y0)?;
     let mut prev_x = x0;
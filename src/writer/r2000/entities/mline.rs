@@ -0,0 +1,119 @@
+use crate::bit::BitWriter;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+use super::common::{
+    encode_entity_payload_with_handles, CommonEntityEncodeInput, EntityStyleEncodeInput,
+};
+
+#[derive(Debug, Clone)]
+pub struct MLineEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub layer_handle: u64,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
+    pub mline_style_handle: u64,
+    /// Number of lines in the referenced style.
+    pub lines_in_style: usize,
+    pub scale: f64,
+    pub justification: u8,
+    pub closed: bool,
+    pub vertices: Vec<MLineVertexEncodeInput>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MLineVertexEncodeInput {
+    pub position: (f64, f64, f64),
+    pub vertex_direction: (f64, f64, f64),
+    pub miter_direction: (f64, f64, f64),
+    /// Segment parameters of each style line; missing lines get none.
+    pub segment_params: Vec<Vec<f64>>,
+}
+
+pub fn encode_mline_entity_payload(input: &MLineEncodeInput) -> Result<Vec<u8>> {
+    if input.lines_in_style > u8::MAX as usize {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!("too many mline style lines: {}", input.lines_in_style),
+        ));
+    }
+    if input.vertices.len() > u16::MAX as usize {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!("too many mline vertices: {}", input.vertices.len()),
+        ));
+    }
+    if let Some(vertex) = input
+        .vertices
+        .iter()
+        .find(|vertex| vertex.segment_params.len() > input.lines_in_style)
+    {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!(
+                "mline vertex has parameters for {} lines, the style has {}",
+                vertex.segment_params.len(),
+                input.lines_in_style
+            ),
+        ));
+    }
+    if input
+        .vertices
+        .iter()
+        .flat_map(|vertex| &vertex.segment_params)
+        .any(|params| params.len() > u16::MAX as usize)
+    {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            "too many mline segment parameters",
+        ));
+    }
+    let common = CommonEntityEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        layer_handle: input.layer_handle,
+        color_index: input.color_index,
+        style: input.style,
+    };
+    encode_entity_payload_with_handles(
+        0x2F,
+        common,
+        |writer| write_mline_body(writer, input),
+        |writer| writer.write_h(0x05, input.mline_style_handle),
+    )
+}
+
+fn write_mline_body(writer: &mut BitWriter, input: &MLineEncodeInput) -> Result<()> {
+    let base_point = input
+        .vertices
+        .first()
+        .map_or((0.0, 0.0, 0.0), |vertex| vertex.position);
+    writer.write_bd(input.scale)?;
+    writer.write_rc(input.justification)?;
+    writer.write_3bd(base_point.0, base_point.1, base_point.2)?;
+    writer.write_3bd(0.0, 0.0, 1.0)?; // extrusion
+    writer.write_bs(if input.closed { 3 } else { 1 })?;
+    writer.write_rc(input.lines_in_style as u8)?;
+    writer.write_bs(input.vertices.len() as u16)?;
+    for vertex in &input.vertices {
+        let (x, y, z) = vertex.position;
+        writer.write_3bd(x, y, z)?;
+        let (x, y, z) = vertex.vertex_direction;
+        writer.write_3bd(x, y, z)?;
+        let (x, y, z) = vertex.miter_direction;
+        writer.write_3bd(x, y, z)?;
+        for line in 0..input.lines_in_style {
+            let params = vertex
+                .segment_params
+                .get(line)
+                .map_or(&[][..], Vec::as_slice);
+            writer.write_bs(params.len() as u16)?;
+            for &param in params {
+                writer.write_bd(param)?;
+            }
+            writer.write_bs(0)?; // area fill parameters
+        }
+    }
+    Ok(())
+}
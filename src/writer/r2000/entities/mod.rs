@@ -1,20 +1,33 @@
 pub mod arc;
 pub mod circle;
 pub mod common;
+pub mod leader;
 pub mod line;
 pub mod lwpolyline;
+pub mod mline;
 pub mod mtext;
 pub mod point;
+pub mod polyface;
 pub mod ray;
 pub mod text;
+pub mod tolerance;
+pub mod viewport;
 pub mod xline;
 
 pub use arc::{encode_arc_entity_payload, ArcEncodeInput};
 pub use circle::{encode_circle_entity_payload, CircleEncodeInput};
+pub use common::{add_entity_reactors, add_entity_xdata, EntityStyleEncodeInput};
+pub use leader::{encode_leader_entity_payload, LeaderEncodeInput};
 pub use line::{encode_line_entity_payload, LineEncodeInput};
 pub use lwpolyline::{encode_lwpolyline_entity_payload, LwPolylineEncodeInput};
+pub use mline::{encode_mline_entity_payload, MLineEncodeInput, MLineVertexEncodeInput};
 pub use mtext::{encode_mtext_entity_payload, MTextEncodeInput};
 pub use point::{encode_point_entity_payload, PointEncodeInput};
+pub use polyface::{
+    encode_polyface_mesh_payloads, polyface_owned_record_count, PolyfaceMeshEncodeInput,
+};
 pub use ray::{encode_ray_entity_payload, RayEncodeInput};
 pub use text::{encode_text_entity_payload, TextEncodeInput};
+pub use tolerance::{encode_tolerance_entity_payload, ToleranceEncodeInput};
+pub use viewport::{encode_viewport_entity_payload, ViewportEncodeInput};
 pub use xline::{encode_xline_entity_payload, XLineEncodeInput};
@@ -1,14 +1,15 @@
 use crate::bit::BitWriter;
 use crate::core::result::Result;
 
-use super::common::{encode_entity_payload, CommonEntityEncodeInput};
+use super::common::{encode_entity_payload, CommonEntityEncodeInput, EntityStyleEncodeInput};
 
 #[derive(Debug, Clone)]
 pub struct MTextEncodeInput {
     pub handle: u64,
     pub owner_handle: u64,
     pub layer_handle: u64,
-    pub color_index: u8,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
     pub text: String,
     pub insertion: (f64, f64, f64),
     pub text_direction: (f64, f64, f64),
@@ -24,6 +25,7 @@ pub fn encode_mtext_entity_payload(input: &MTextEncodeInput) -> Result<Vec<u8>>
         owner_handle: input.owner_handle,
         layer_handle: input.layer_handle,
         color_index: input.color_index,
+        style: input.style,
     };
     encode_entity_payload(0x2C, common, |writer| write_mtext_body(writer, input))
 }
@@ -0,0 +1,143 @@
+use crate::bit::BitWriter;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+use super::common::{
+    encode_entity_payload, encode_entity_payload_with_handles, CommonEntityEncodeInput,
+    EntityStyleEncodeInput,
+};
+
+const POLYLINE_PFACE_TYPE_CODE: u16 = 0x1D;
+const VERTEX_PFACE_TYPE_CODE: u16 = 0x0D;
+const VERTEX_PFACE_FACE_TYPE_CODE: u16 = 0x0E;
+const SEQEND_TYPE_CODE: u16 = 0x06;
+/// DXF group 70 of a polyface vertex: 3D polygon mesh vertex and polyface
+/// mesh vertex.
+const PFACE_VERTEX_FLAGS: u8 = 0xC0;
+
+#[derive(Debug, Clone)]
+pub struct PolyfaceMeshEncodeInput {
+    pub handle: u64,
+    /// First of the consecutive handles given to the vertex, face and
+    /// SEQEND records, in that order.
+    pub first_owned_handle: u64,
+    pub owner_handle: u64,
+    pub layer_handle: u64,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
+    pub vertices: Vec<(f64, f64, f64)>,
+    pub faces: Vec<[i16; 4]>,
+}
+
+/// Records a polyface mesh takes besides its POLYLINE: one per vertex and
+/// face and the SEQEND.
+pub fn polyface_owned_record_count(vertices: usize, faces: usize) -> usize {
+    vertices + faces + 1
+}
+
+/// Encodes the POLYLINE of a polyface mesh followed by its vertex, face and
+/// SEQEND records, as `(handle, payload)` pairs. The owned records carry the
+/// layer and color of the mesh.
+pub fn encode_polyface_mesh_payloads(
+    input: &PolyfaceMeshEncodeInput,
+) -> Result<Vec<(u64, Vec<u8>)>> {
+    let num_vertices = u16::try_from(input.vertices.len()).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "polyface mesh has too many vertices: {}",
+                input.vertices.len()
+            ),
+        )
+    })?;
+    let num_faces = u16::try_from(input.faces.len()).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!("polyface mesh has too many faces: {}", input.faces.len()),
+        )
+    })?;
+    if let Some(face) = input.faces.iter().find(|face| {
+        face.iter().any(|index| index.unsigned_abs() > num_vertices)
+            || face.iter().take(3).any(|index| *index == 0)
+    }) {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("polyface face indices out of range: {face:?}"),
+        ));
+    }
+
+    let owned_count = polyface_owned_record_count(input.vertices.len(), input.faces.len()) as u64;
+    let seqend_handle = input.first_owned_handle + owned_count - 1;
+    // Face records are vertices too: the links span both.
+    let (first_vertex, last_vertex) = match owned_count {
+        1 => (0, 0),
+        _ => (input.first_owned_handle, seqend_handle - 1),
+    };
+    let common = |handle, owner_handle| CommonEntityEncodeInput {
+        handle,
+        owner_handle,
+        layer_handle: input.layer_handle,
+        color_index: input.color_index,
+        style: input.style,
+    };
+
+    let mut payloads = Vec::with_capacity(owned_count as usize + 1);
+    payloads.push((
+        input.handle,
+        encode_entity_payload_with_handles(
+            POLYLINE_PFACE_TYPE_CODE,
+            common(input.handle, input.owner_handle),
+            |writer| {
+                writer.write_bs(num_vertices)?;
+                writer.write_bs(num_faces)
+            },
+            |writer| {
+                writer.write_h(0x04, first_vertex)?;
+                writer.write_h(0x04, last_vertex)?;
+                writer.write_h(0x03, seqend_handle)
+            },
+        )?,
+    ));
+    let mut handle = input.first_owned_handle;
+    for &(x, y, z) in &input.vertices {
+        payloads.push((
+            handle,
+            encode_entity_payload(
+                VERTEX_PFACE_TYPE_CODE,
+                common(handle, input.handle),
+                |writer| write_vertex_body(writer, (x, y, z)),
+            )?,
+        ));
+        handle += 1;
+    }
+    for face in &input.faces {
+        payloads.push((
+            handle,
+            encode_entity_payload(
+                VERTEX_PFACE_FACE_TYPE_CODE,
+                common(handle, input.handle),
+                |writer| {
+                    for index in face {
+                        writer.write_bs(*index as u16)?;
+                    }
+                    Ok(())
+                },
+            )?,
+        ));
+        handle += 1;
+    }
+    payloads.push((
+        seqend_handle,
+        encode_entity_payload(
+            SEQEND_TYPE_CODE,
+            common(seqend_handle, input.handle),
+            |_| Ok(()),
+        )?,
+    ));
+    Ok(payloads)
+}
+
+fn write_vertex_body(writer: &mut BitWriter, position: (f64, f64, f64)) -> Result<()> {
+    writer.write_rc(PFACE_VERTEX_FLAGS)?;
+    writer.write_3bd(position.0, position.1, position.2)
+}
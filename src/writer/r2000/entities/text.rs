@@ -1,18 +1,26 @@
 use crate::bit::{BitWriter, Endian};
 use crate::core::result::Result;
 
-use super::common::{encode_entity_payload, CommonEntityEncodeInput};
+use super::common::{encode_entity_payload, CommonEntityEncodeInput, EntityStyleEncodeInput};
 
 #[derive(Debug, Clone)]
 pub struct TextEncodeInput {
     pub handle: u64,
     pub owner_handle: u64,
     pub layer_handle: u64,
-    pub color_index: u8,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
     pub text: String,
     pub insertion: (f64, f64, f64),
     pub height: f64,
     pub rotation: f64,
+    pub horizontal_alignment: u16,
+    pub vertical_alignment: u16,
+    /// Written when either alignment is not the default; `None` then
+    /// repeats the insertion point.
+    pub alignment: Option<(f64, f64)>,
+    pub width_factor: f64,
+    pub oblique: f64,
 }
 
 pub fn encode_text_entity_payload(input: &TextEncodeInput) -> Result<Vec<u8>> {
@@ -21,6 +29,7 @@ pub fn encode_text_entity_payload(input: &TextEncodeInput) -> Result<Vec<u8>> {
         owner_handle: input.owner_handle,
         layer_handle: input.layer_handle,
         color_index: input.color_index,
+        style: input.style,
     };
     encode_entity_payload(0x01, common, |writer| write_text_body(writer, input))
 }
@@ -28,20 +37,39 @@ pub fn encode_text_entity_payload(input: &TextEncodeInput) -> Result<Vec<u8>> {
 fn write_text_body(writer: &mut BitWriter, input: &TextEncodeInput) -> Result<()> {
     let has_elevation = input.insertion.2 != 0.0;
     let has_rotation = input.rotation != 0.0;
+    let has_oblique = input.oblique != 0.0;
+    let has_width_factor = input.width_factor != 1.0;
+    let has_halign = input.horizontal_alignment != 0;
+    let has_valign = input.vertical_alignment != 0;
+    let alignment = (has_halign || has_valign).then(|| {
+        input
+            .alignment
+            .unwrap_or((input.insertion.0, input.insertion.1))
+    });
     let mut data_flags: u8 = 0;
 
     if !has_elevation {
         data_flags |= 0x01;
     }
-    data_flags |= 0x02; // no alignment point
-    data_flags |= 0x04; // oblique default
+    if alignment.is_none() {
+        data_flags |= 0x02;
+    }
+    if !has_oblique {
+        data_flags |= 0x04;
+    }
     if !has_rotation {
         data_flags |= 0x08;
     }
-    data_flags |= 0x10; // width factor default
+    if !has_width_factor {
+        data_flags |= 0x10;
+    }
     data_flags |= 0x20; // generation default
-    data_flags |= 0x40; // horizontal alignment default
-    data_flags |= 0x80; // vertical alignment default
+    if !has_halign {
+        data_flags |= 0x40;
+    }
+    if !has_valign {
+        data_flags |= 0x80;
+    }
 
     writer.write_rc(data_flags)?;
     if has_elevation {
@@ -49,12 +77,28 @@ fn write_text_body(writer: &mut BitWriter, input: &TextEncodeInput) -> Result<()
     }
     writer.write_rd(Endian::Little, input.insertion.0)?;
     writer.write_rd(Endian::Little, input.insertion.1)?;
+    if let Some((x, y)) = alignment {
+        writer.write_dd(input.insertion.0, x)?;
+        writer.write_dd(input.insertion.1, y)?;
+    }
     writer.write_be(0.0, 0.0, 1.0)?; // extrusion
     writer.write_bt(0.0)?; // thickness
+    if has_oblique {
+        writer.write_rd(Endian::Little, input.oblique)?;
+    }
     if has_rotation {
         writer.write_rd(Endian::Little, input.rotation)?;
     }
     writer.write_rd(Endian::Little, input.height)?;
+    if has_width_factor {
+        writer.write_rd(Endian::Little, input.width_factor)?;
+    }
     writer.write_tv(&input.text)?;
+    if has_halign {
+        writer.write_bs(input.horizontal_alignment)?;
+    }
+    if has_valign {
+        writer.write_bs(input.vertical_alignment)?;
+    }
     Ok(())
 }
@@ -0,0 +1,51 @@
+use crate::bit::BitWriter;
+use crate::core::result::Result;
+
+use super::common::{
+    encode_entity_payload_with_handles, CommonEntityEncodeInput, EntityStyleEncodeInput,
+};
+
+#[derive(Debug, Clone)]
+pub struct ToleranceEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub layer_handle: u64,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
+    pub text: String,
+    pub insertion: (f64, f64, f64),
+    pub x_direction: (f64, f64, f64),
+    pub height: f64,
+    pub dimgap: f64,
+}
+
+pub fn encode_tolerance_entity_payload(input: &ToleranceEncodeInput) -> Result<Vec<u8>> {
+    let common = CommonEntityEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        layer_handle: input.layer_handle,
+        color_index: input.color_index,
+        style: input.style,
+    };
+    encode_entity_payload_with_handles(
+        0x2E,
+        common,
+        |writer| write_tolerance_body(writer, input),
+        |writer| writer.write_h(0x05, 0), // DIMSTYLE
+    )
+}
+
+/// Field order of `entities::decode_tolerance`.
+fn write_tolerance_body(writer: &mut BitWriter, input: &ToleranceEncodeInput) -> Result<()> {
+    writer.write_bd(input.height)?;
+    writer.write_bd(input.dimgap)?;
+    writer.write_3bd(input.insertion.0, input.insertion.1, input.insertion.2)?;
+    writer.write_3bd(
+        input.x_direction.0,
+        input.x_direction.1,
+        input.x_direction.2,
+    )?;
+    writer.write_3bd(0.0, 0.0, 1.0)?; // extrusion
+    writer.write_tv(&input.text)?;
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::bit::{BitWriter, Endian};
+use crate::core::result::Result;
+
+use super::common::{
+    encode_entity_payload_with_handles, CommonEntityEncodeInput, EntityStyleEncodeInput,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct ViewportEncodeInput<'a> {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub layer_handle: u64,
+    pub color_index: u16,
+    pub style: EntityStyleEncodeInput,
+    pub center: (f64, f64, f64),
+    pub width: f64,
+    pub height: f64,
+    pub view_center: (f64, f64),
+    pub view_target: (f64, f64, f64),
+    pub view_direction: (f64, f64, f64),
+    pub view_height: f64,
+    pub twist_angle: f64,
+    pub lens_length: f64,
+    pub status_flags: u32,
+    pub frozen_layer_handles: &'a [u64],
+}
+
+pub fn encode_viewport_entity_payload(input: ViewportEncodeInput) -> Result<Vec<u8>> {
+    let common = CommonEntityEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        layer_handle: input.layer_handle,
+        color_index: input.color_index,
+        style: input.style,
+    };
+    encode_entity_payload_with_handles(
+        0x22,
+        common,
+        |writer| write_viewport_body(writer, input),
+        |writer| write_viewport_handles(writer, input.frozen_layer_handles),
+    )
+}
+
+fn write_viewport_body(writer: &mut BitWriter, input: ViewportEncodeInput) -> Result<()> {
+    writer.write_3bd(input.center.0, input.center.1, input.center.2)?;
+    writer.write_bd(input.width)?;
+    writer.write_bd(input.height)?;
+    writer.write_3bd(
+        input.view_target.0,
+        input.view_target.1,
+        input.view_target.2,
+    )?;
+    writer.write_3bd(
+        input.view_direction.0,
+        input.view_direction.1,
+        input.view_direction.2,
+    )?;
+    writer.write_bd(input.twist_angle)?;
+    writer.write_bd(input.view_height)?;
+    writer.write_bd(input.lens_length)?;
+    writer.write_bd(0.0)?; // front clip z
+    writer.write_bd(0.0)?; // back clip z
+    writer.write_bd(0.0)?; // snap angle
+    writer.write_rd(Endian::Little, input.view_center.0)?;
+    writer.write_rd(Endian::Little, input.view_center.1)?;
+    for value in [0.0, 0.0, 10.0, 10.0, 10.0, 10.0] {
+        writer.write_rd(Endian::Little, value)?; // snap base, snap and grid spacing
+    }
+    writer.write_bs(1000)?; // circle zoom
+    writer.write_bl(input.frozen_layer_handles.len() as u32)?;
+    writer.write_bl(input.status_flags)?;
+    writer.write_tv("")?; // style sheet
+    writer.write_rc(0)?; // render mode
+    writer.write_b(0)?; // ucs at origin
+    writer.write_b(0)?; // ucs per viewport
+    writer.write_3bd(0.0, 0.0, 0.0)?; // ucs origin
+    writer.write_3bd(1.0, 0.0, 0.0)?; // ucs x axis
+    writer.write_3bd(0.0, 1.0, 0.0)?; // ucs y axis
+    writer.write_bd(0.0)?; // ucs elevation
+    writer.write_bs(0)?; // ucs ortho type
+    Ok(())
+}
+
+fn write_viewport_handles(writer: &mut BitWriter, frozen_layer_handles: &[u64]) -> Result<()> {
+    for handle in frozen_layer_handles {
+        writer.write_h(0x05, *handle)?;
+    }
+    writer.write_h(0x05, 0)?; // clip boundary
+    writer.write_h(0x05, 0)?; // viewport entity header
+    writer.write_h(0x05, 0)?; // named ucs
+    writer.write_h(0x05, 0)?; // base ucs
+    Ok(())
+}
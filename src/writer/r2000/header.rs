@@ -0,0 +1,638 @@
+use super::crc::{crc16, CRC_SEED};
+use super::raw_object::copy_bits;
+use crate::bit::{BitReader, BitWriter, Endian};
+use crate::core::config::ParseConfig;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::decoder::Decoder;
+use crate::dwg::header::{DrawingTimes, JulianDate, HEADER_END_SENTINEL, HEADER_SENTINEL};
+use crate::dwg::version::DwgVersion;
+
+const HARD_POINTER: u8 = 5;
+const BYLAYER_COLOR: u16 = 256;
+const LINEWEIGHT_BYLAYER: u16 = 0xFFFE;
+
+/// Values of the header variables the writer controls. Everything else is
+/// copied from `template` when given, and otherwise written with the
+/// defaults of a new imperial drawing.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderEncodeInput<'a> {
+    /// `HANDSEED`, the next free handle.
+    pub handle_seed: u64,
+    pub current_layer: u64,
+    pub layer_control: u64,
+    pub model_space_block_record: u64,
+    pub layout_dictionary: u64,
+    pub linetype_control: u64,
+    pub app_id_control: u64,
+    pub mline_style_dictionary: u64,
+    /// The `ACAD_GROUP` dictionary, 0 when the drawing has no groups.
+    pub group_dictionary: u64,
+    /// The named objects dictionary, 0 when the drawing has none.
+    pub named_objects_dictionary: u64,
+    pub insertion_base: (f64, f64, f64),
+    pub ext_min: (f64, f64, f64),
+    pub ext_max: (f64, f64, f64),
+    pub times: DrawingTimes,
+    /// Header variable data of an AC1015 drawing, as returned by
+    /// [`header_template`]. Variables the writer does not model, such as
+    /// `OSMODE` or `DIMASSOC`, keep their values from it.
+    pub template: Option<&'a [u8]>,
+}
+
+/// Encodes the AC1015 header variables section, framed by its sentinels.
+/// A template that does not parse as AC1015 header variables is ignored.
+pub fn encode_header_section(input: &HeaderEncodeInput<'_>) -> Result<Vec<u8>> {
+    let templated = input.template.and_then(|template| {
+        let mut sink = HeaderSink::new(Some(BitReader::new(template)));
+        write_header_variables(&mut sink, input).ok()?;
+        sink.finish().ok()
+    });
+    let data = match templated {
+        Some(data) => data,
+        None => {
+            let mut sink = HeaderSink::new(None);
+            write_header_variables(&mut sink, input)?;
+            sink.finish()?
+        }
+    };
+    Ok(frame_header_section(&data))
+}
+
+/// Re-encodes the AC1015 header variables `template` with `HANDSEED` set
+/// to `handle_seed` and every other variable copied bit for bit. Returns
+/// the framed section.
+pub fn reseed_header_section(template: &[u8], handle_seed: u64) -> Result<Vec<u8>> {
+    let (data, _) = rewrite_handle_seed(template, handle_seed)?;
+    Ok(frame_header_section(&data))
+}
+
+/// `HANDSEED` of the AC1015 header variables `template`.
+pub fn header_handle_seed(template: &[u8]) -> Result<u64> {
+    rewrite_handle_seed(template, 0).map(|(_, seed)| seed)
+}
+
+fn rewrite_handle_seed(template: &[u8], handle_seed: u64) -> Result<(Vec<u8>, u64)> {
+    let mut sink = HeaderSink::new(Some(BitReader::new(template)));
+    sink.keep_all = true;
+    write_header_variables(
+        &mut sink,
+        &HeaderEncodeInput {
+            handle_seed,
+            ..HeaderEncodeInput::default()
+        },
+    )?;
+    let seed = sink.template_seed.unwrap_or_default();
+    Ok((sink.finish()?, seed))
+}
+
+fn frame_header_section(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + 38);
+    out.extend_from_slice(&HEADER_SENTINEL);
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    let crc = crc16(
+        CRC_SEED,
+        out.get(HEADER_SENTINEL.len()..).unwrap_or_default(),
+    );
+    out.extend_from_slice(&crc.to_le_bytes());
+    out.extend_from_slice(&HEADER_END_SENTINEL);
+    out
+}
+
+/// The header variable data of the AC1015 drawing `bytes`, without the
+/// sentinels, size and CRC, for use as [`HeaderEncodeInput::template`].
+pub fn header_template(bytes: &[u8]) -> Result<Vec<u8>> {
+    let decoder = Decoder::new(bytes, ParseConfig::default())?;
+    if *decoder.version() != DwgVersion::R2000 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "header templates only support AC1015, got {}",
+                decoder.version().as_str()
+            ),
+        ));
+    }
+    let section = decoder.header_section()?;
+    let size = section
+        .get(HEADER_SENTINEL.len()..HEADER_SENTINEL.len() + 4)
+        .and_then(|size| <[u8; 4]>::try_from(size).ok())
+        .map(u32::from_le_bytes)
+        .filter(|_| section.starts_with(&HEADER_SENTINEL))
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "header section sentinel mismatch"))?;
+    let start = HEADER_SENTINEL.len() + 4;
+    section
+        .get(start..start.saturating_add(size as usize))
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| {
+            DwgError::new(
+                ErrorKind::Format,
+                format!("header variables size out of range: {size} bytes"),
+            )
+        })
+}
+
+fn write_header_variables(sink: &mut HeaderSink<'_>, input: &HeaderEncodeInput<'_>) -> Result<()> {
+    sink.bd(412_148_564_080.0)?;
+    sink.bd(1.0)?;
+    sink.bd(1.0)?;
+    sink.bd(1.0)?;
+    sink.tv("m")?;
+    sink.tv("")?;
+    sink.tv("")?;
+    sink.tv("")?;
+    sink.bl(24)?;
+    sink.bl(0)?;
+    sink.h(HARD_POINTER, 0)?; // current viewport entity header
+
+    for flag in [
+        1, // DIMASO
+        1, // DIMSHO
+        0, // PLINEGEN
+        0, // ORTHOMODE
+        1, // REGENMODE
+        1, // FILLMODE
+        0, // QTEXTMODE
+        1, // PSLTSCALE
+        0, // LIMCHECK
+        1, // USRTIMER
+        0, // SKPOLY
+        0, // ANGDIR
+        0, // SPLFRAME
+        0, // MIRRTEXT
+        1, // WORLDVIEW
+        1, // TILEMODE
+        0, // PLIMCHECK
+        1, // VISRETAIN
+        0, // DISPSILH
+        0, // PELLIPSE
+    ] {
+        sink.b(flag)?;
+    }
+    for value in [
+        1,    // PROXYGRAPHICS
+        3020, // TREEDEPTH
+        2,    // LUNITS
+        4,    // LUPREC
+        0,    // AUNITS
+        0,    // AUPREC
+        1,    // ATTMODE
+        0,    // PDMODE
+    ] {
+        sink.bs(value)?;
+    }
+    for value in [
+        0, 0, 0, 0, 0,  // USERI1-5
+        8,  // SPLINESEGS
+        6,  // SURFU
+        6,  // SURFV
+        6,  // SURFTYPE
+        6,  // SURFTAB1
+        6,  // SURFTAB2
+        6,  // SPLINETYPE
+        3,  // SHADEDGE
+        70, // SHADEDIF
+        0,  // UNITMODE
+        64, // MAXACTVP
+        4,  // ISOLINES
+        0,  // CMLJUST
+        50, // TEXTQLTY
+    ] {
+        sink.bs(value)?;
+    }
+    for value in [
+        1.0,  // LTSCALE
+        0.2,  // TEXTSIZE
+        0.05, // TRACEWID
+        0.1,  // SKETCHINC
+        0.0,  // FILLETRAD
+        0.0,  // THICKNESS
+        0.0,  // ANGBASE
+        0.0,  // PDSIZE
+        0.0,  // PLINEWID
+        0.0, 0.0, 0.0, 0.0, 0.0, // USERR1-5
+        0.0, // CHAMFERA
+        0.0, // CHAMFERB
+        0.0, // CHAMFERC
+        0.0, // CHAMFERD
+        0.5, // FACETRES
+        1.0, // CMLSCALE
+        1.0, // CELTSCALE
+    ] {
+        sink.bd(value)?;
+    }
+    sink.tv("acad")?; // MENUNAME
+
+    write_julian_date(sink, input.times.created)?;
+    write_julian_date(sink, input.times.updated)?;
+    write_julian_date(sink, input.times.edit_time)?;
+    write_julian_date(sink, input.times.user_timer)?;
+
+    sink.bs(BYLAYER_COLOR)?; // CECOLOR
+    sink.handle_seed(input.handle_seed)?;
+    sink.h(HARD_POINTER, input.current_layer)?;
+    for _ in 0..4 {
+        sink.h(HARD_POINTER, 0)?; // TEXTSTYLE, CELTYPE, DIMSTYLE, CMLSTYLE
+    }
+    sink.bd(0.0)?; // PSVPSCALE
+
+    // Paper space keeps the template extents; model space gets those of
+    // the written entities.
+    let zero = (0.0, 0.0, 0.0);
+    write_space_variables(
+        sink,
+        zero,
+        (1.0e20, 1.0e20, 1.0e20),
+        (-1.0e20, -1.0e20, -1.0e20),
+        false,
+    )?;
+    write_space_variables(
+        sink,
+        input.insertion_base,
+        input.ext_min,
+        input.ext_max,
+        true,
+    )?;
+
+    write_dimension_variables(sink)?;
+
+    // BLOCK, LAYER, STYLE, LINETYPE, VIEW, UCS, VPORT, APPID, DIMSTYLE
+    // controls, the VIEWPORT ENTITY HEADER control and the ACAD_GROUP,
+    // ACAD_MLINESTYLE and named objects dictionaries.
+    for handle in [
+        0,
+        input.layer_control,
+        0,
+        input.linetype_control,
+        0,
+        0,
+        0,
+        input.app_id_control,
+        0,
+        0,
+        input.group_dictionary,
+        input.mline_style_dictionary,
+        input.named_objects_dictionary,
+    ] {
+        sink.h(HARD_POINTER, handle)?;
+    }
+    sink.bs(1)?; // TSTACKALIGN
+    sink.bs(70)?; // TSTACKSIZE
+    sink.tv("")?; // HYPERLINKBASE
+    sink.tv("")?; // STYLESHEET
+    sink.h(HARD_POINTER, input.layout_dictionary)?;
+    sink.h(HARD_POINTER, 0)?; // ACAD_PLOTSETTINGS
+    sink.h(HARD_POINTER, 0)?; // ACAD_PLOTSTYLENAME
+    sink.bl(0x2A1F)?; // CELWEIGHT, ENDCAPS, JOINSTYLE, LWDISPLAY, ...
+    sink.bs(0)?; // INSUNITS
+    sink.bs(0)?; // CEPSNTYPE
+    sink.tv("")?; // FINGERPRINTGUID
+    sink.tv("")?; // VERSIONGUID
+    sink.h(HARD_POINTER, 0)?; // *PAPER_SPACE block record
+    sink.h(HARD_POINTER, input.model_space_block_record)?;
+    for _ in 0..3 {
+        sink.h(HARD_POINTER, 0)?; // BYLAYER, BYBLOCK, CONTINUOUS
+    }
+    for _ in 0..4 {
+        sink.bs(0)?; // undocumented
+    }
+    Ok(())
+}
+
+/// Paper space and model space variables share this layout. `controlled`
+/// writes the insertion base and extents even over a template.
+fn write_space_variables(
+    sink: &mut HeaderSink<'_>,
+    insertion_base: (f64, f64, f64),
+    ext_min: (f64, f64, f64),
+    ext_max: (f64, f64, f64),
+    controlled: bool,
+) -> Result<()> {
+    for point in [insertion_base, ext_min, ext_max] {
+        if controlled {
+            sink.set_point(point)?;
+        } else {
+            sink.point(point)?;
+        }
+    }
+    sink.rd(0.0)?; // LIMMIN
+    sink.rd(0.0)?;
+    sink.rd(12.0)?; // LIMMAX
+    sink.rd(9.0)?;
+    sink.bd(0.0)?; // ELEVATION
+    sink.point((0.0, 0.0, 0.0))?; // UCSORG
+    sink.point((1.0, 0.0, 0.0))?; // UCSXDIR
+    sink.point((0.0, 1.0, 0.0))?; // UCSYDIR
+    sink.h(HARD_POINTER, 0)?; // UCSNAME
+    sink.h(HARD_POINTER, 0)?; // UCSORTHOREF
+    sink.bs(0)?; // UCSORTHOVIEW
+    sink.h(HARD_POINTER, 0)?; // UCSBASE
+    for _ in 0..6 {
+        sink.point((0.0, 0.0, 0.0))?; // ORGTOP .. ORGBACK
+    }
+    Ok(())
+}
+
+fn write_dimension_variables(sink: &mut HeaderSink<'_>) -> Result<()> {
+    sink.tv("")?; // DIMPOST
+    sink.tv("")?; // DIMAPOST
+    for value in [
+        1.0,    // DIMSCALE
+        0.18,   // DIMASZ
+        0.0625, // DIMEXO
+        0.38,   // DIMDLI
+        0.18,   // DIMEXE
+        0.0,    // DIMRND
+        0.0,    // DIMDLE
+        0.0,    // DIMTP
+        0.0,    // DIMTM
+    ] {
+        sink.bd(value)?;
+    }
+    for flag in [
+        0, // DIMTOL
+        0, // DIMLIM
+        1, // DIMTIH
+        1, // DIMTOH
+        0, // DIMSE1
+        0, // DIMSE2
+    ] {
+        sink.b(flag)?;
+    }
+    sink.bs(0)?; // DIMTAD
+    sink.bs(0)?; // DIMZIN
+    sink.bs(0)?; // DIMAZIN
+    for value in [
+        0.18, // DIMTXT
+        0.09, // DIMCEN
+        0.0,  // DIMTSZ
+        25.4, // DIMALTF
+        1.0,  // DIMLFAC
+        0.0,  // DIMTVP
+        1.0,  // DIMTFAC
+        0.09, // DIMGAP
+        0.0,  // DIMALTRND
+    ] {
+        sink.bd(value)?;
+    }
+    sink.b(0)?; // DIMALT
+    sink.bs(2)?; // DIMALTD
+    sink.b(0)?; // DIMTOFL
+    sink.b(0)?; // DIMSAH
+    sink.b(0)?; // DIMTIX
+    sink.b(0)?; // DIMSOXD
+    sink.bs(0)?; // DIMCLRD
+    sink.bs(0)?; // DIMCLRE
+    sink.bs(0)?; // DIMCLRT
+    for value in [
+        0,  // DIMADEC
+        4,  // DIMDEC
+        4,  // DIMTDEC
+        2,  // DIMALTU
+        2,  // DIMALTTD
+        0,  // DIMAUNIT
+        0,  // DIMFRAC
+        2,  // DIMLUNIT
+        46, // DIMDSEP
+        0,  // DIMTMOVE
+        0,  // DIMJUST
+    ] {
+        sink.bs(value)?;
+    }
+    sink.b(0)?; // DIMSD1
+    sink.b(0)?; // DIMSD2
+    sink.bs(1)?; // DIMTOLJ
+    sink.bs(0)?; // DIMTZIN
+    sink.bs(0)?; // DIMALTZ
+    sink.bs(0)?; // DIMALTTZ
+    sink.b(0)?; // DIMUPT
+    sink.bs(3)?; // DIMATFIT
+    for _ in 0..5 {
+        sink.h(HARD_POINTER, 0)?; // DIMTXSTY, DIMLDRBLK, DIMBLK, DIMBLK1, DIMBLK2
+    }
+    sink.bs(LINEWEIGHT_BYLAYER)?; // DIMLWD
+    sink.bs(LINEWEIGHT_BYLAYER)?; // DIMLWE
+    Ok(())
+}
+
+fn write_julian_date(sink: &mut HeaderSink<'_>, date: JulianDate) -> Result<()> {
+    sink.set(BitReader::read_bl, |writer| writer.write_bl(date.day))?;
+    sink.set(BitReader::read_bl, |writer| writer.write_bl(date.millis))
+}
+
+/// Writes header variables in section order. With a template, the
+/// variables the writer does not control are copied bit for bit from it
+/// and the given defaults are ignored.
+struct HeaderSink<'a> {
+    writer: BitWriter,
+    template: Option<BitReader<'a>>,
+    /// Copies every template variable except `HANDSEED`.
+    keep_all: bool,
+    /// `HANDSEED` as read from the template.
+    template_seed: Option<u64>,
+}
+
+impl<'a> HeaderSink<'a> {
+    fn new(template: Option<BitReader<'a>>) -> Self {
+        Self {
+            writer: BitWriter::new(),
+            template,
+            keep_all: false,
+            template_seed: None,
+        }
+    }
+
+    /// Copies the template variable `read` steps over, or runs `write`
+    /// without a template.
+    fn keep<T>(
+        &mut self,
+        read: impl FnOnce(&mut BitReader<'a>) -> Result<T>,
+        write: impl FnOnce(&mut BitWriter) -> Result<()>,
+    ) -> Result<()> {
+        let Some(template) = &mut self.template else {
+            return write(&mut self.writer);
+        };
+        let mut copy = template.clone();
+        read(template)?;
+        copy_bits(&mut copy, &mut self.writer, template.tell_bits())
+    }
+
+    /// Writes a variable the writer controls, skipping it in the template.
+    fn set<T>(
+        &mut self,
+        read: impl FnOnce(&mut BitReader<'a>) -> Result<T>,
+        write: impl FnOnce(&mut BitWriter) -> Result<()>,
+    ) -> Result<()> {
+        if self.keep_all {
+            return self.keep(read, write);
+        }
+        if let Some(template) = &mut self.template {
+            read(template)?;
+        }
+        write(&mut self.writer)
+    }
+
+    fn b(&mut self, value: u8) -> Result<()> {
+        self.keep(BitReader::read_b, |writer| writer.write_b(value))
+    }
+
+    fn bs(&mut self, value: u16) -> Result<()> {
+        self.keep(BitReader::read_bs, |writer| writer.write_bs(value))
+    }
+
+    fn bl(&mut self, value: u32) -> Result<()> {
+        self.keep(BitReader::read_bl, |writer| writer.write_bl(value))
+    }
+
+    fn bd(&mut self, value: f64) -> Result<()> {
+        self.keep(BitReader::read_bd, |writer| writer.write_bd(value))
+    }
+
+    fn rd(&mut self, value: f64) -> Result<()> {
+        self.keep(
+            |reader| reader.read_rd(Endian::Little),
+            |writer| writer.write_rd(Endian::Little, value),
+        )
+    }
+
+    fn tv(&mut self, value: &str) -> Result<()> {
+        self.keep(BitReader::read_tv, |writer| writer.write_tv(value))
+    }
+
+    fn point(&mut self, (x, y, z): (f64, f64, f64)) -> Result<()> {
+        self.keep(BitReader::read_3bd, |writer| writer.write_3bd(x, y, z))
+    }
+
+    fn set_point(&mut self, (x, y, z): (f64, f64, f64)) -> Result<()> {
+        self.set(BitReader::read_3bd, |writer| writer.write_3bd(x, y, z))
+    }
+
+    /// Handles always come from the writer; those of the template name
+    /// objects that are not written.
+    fn h(&mut self, code: u8, value: u64) -> Result<()> {
+        self.set(BitReader::read_h, |writer| writer.write_h(code, value))
+    }
+
+    /// `HANDSEED` always comes from the writer.
+    fn handle_seed(&mut self, value: u64) -> Result<()> {
+        if let Some(template) = &mut self.template {
+            self.template_seed = Some(template.read_h()?.value);
+        }
+        self.writer.write_h(0, value)
+    }
+
+    /// Copies the variables the template has beyond the ones written here.
+    /// Shorter remainders are padding.
+    fn finish(mut self) -> Result<Vec<u8>> {
+        if let Some(template) = &mut self.template {
+            let end = template.total_bits();
+            if end.saturating_sub(template.tell_bits()) >= 8 {
+                copy_bits(template, &mut self.writer, end)?;
+            }
+        }
+        Ok(self.writer.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        encode_header_section, header_handle_seed, header_template, reseed_header_section,
+        HeaderEncodeInput,
+    };
+    use crate::bit::BitReader;
+    use crate::dwg::header::{decode_drawing_times, DrawingTimes, JulianDate};
+    use crate::dwg::version::DwgVersion;
+
+    /// The variables from `PROXYGRAPHICS` through `CELTSCALE`.
+    fn unit_variables(data: &[u8]) -> (Vec<u16>, Vec<f64>) {
+        let mut reader = BitReader::new(data);
+        for _ in 0..4 {
+            reader.read_bd().unwrap();
+        }
+        for _ in 0..4 {
+            reader.read_tv().unwrap();
+        }
+        reader.read_bl().unwrap();
+        reader.read_bl().unwrap();
+        reader.read_h().unwrap();
+        for _ in 0..20 {
+            reader.read_b().unwrap();
+        }
+        let shorts = (0..27).map(|_| reader.read_bs().unwrap()).collect();
+        let doubles = (0..21).map(|_| reader.read_bd().unwrap()).collect();
+        (shorts, doubles)
+    }
+
+    #[test]
+    fn roundtrips_drawing_times() {
+        let times = DrawingTimes {
+            created: JulianDate::new(2457479, 77669368),
+            updated: JulianDate::new(2461078, 58531605),
+            edit_time: JulianDate::new(1, 123502),
+            user_timer: JulianDate::new(0, 42),
+        };
+        let section = encode_header_section(&HeaderEncodeInput {
+            handle_seed: 0x20,
+            times,
+            ..HeaderEncodeInput::default()
+        })
+        .unwrap();
+        assert_eq!(
+            decode_drawing_times(&section, &DwgVersion::R2000, 0).unwrap(),
+            times
+        );
+    }
+
+    #[test]
+    fn keeps_template_variables_and_writes_controlled_ones() {
+        let bytes = std::fs::read("test_dwg/text_2000.dwg").unwrap();
+        let template = header_template(&bytes).unwrap();
+        let times = DrawingTimes {
+            created: JulianDate::new(2460000, 1),
+            ..DrawingTimes::default()
+        };
+        let input = HeaderEncodeInput {
+            handle_seed: 0x40,
+            times,
+            template: Some(&template),
+            ..HeaderEncodeInput::default()
+        };
+        let section = encode_header_section(&input).unwrap();
+        assert_eq!(
+            decode_drawing_times(&section, &DwgVersion::R2000, 0).unwrap(),
+            times
+        );
+        let untemplated = encode_header_section(&HeaderEncodeInput {
+            template: None,
+            ..input.clone()
+        })
+        .unwrap();
+        assert_ne!(section, untemplated);
+
+        let data = section.get(20..section.len() - 18).unwrap();
+        assert_eq!(unit_variables(data), unit_variables(&template));
+        // Encoding over its own output changes nothing.
+        let again = encode_header_section(&HeaderEncodeInput {
+            template: Some(data),
+            ..input
+        })
+        .unwrap();
+        assert_eq!(again, section);
+    }
+
+    #[test]
+    fn reseeds_header_without_touching_other_variables() {
+        let bytes = std::fs::read("test_dwg/line_2000.dwg").unwrap();
+        let template = header_template(&bytes).unwrap();
+        let seed = header_handle_seed(&template).unwrap();
+        assert!(seed > 0);
+
+        let section = reseed_header_section(&template, seed + 0x100).unwrap();
+        let data = section.get(20..section.len() - 18).unwrap();
+        assert_eq!(header_handle_seed(data).unwrap(), seed + 0x100);
+        assert_eq!(unit_variables(data), unit_variables(&template));
+        let same = reseed_header_section(&template, seed).unwrap();
+        assert_eq!(same.get(20..same.len() - 18).unwrap(), template.as_slice());
+    }
+}
@@ -0,0 +1,370 @@
+//! Append-only updates of existing AC1015 files.
+//!
+//! Modified entities are encoded as new object records at the end of the
+//! file, followed by a rebuilt object map. The original records are never
+//! decoded or re-encoded, and in place only the section locator entry for
+//! the object map and its CRC change. An update still copies the whole
+//! original file and writes an object map covering every handle, so its
+//! cost grows with the size of the drawing, not just with the changes.
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::crc::{crc16, CRC_SEED};
+use super::entities::EntityStyleEncodeInput;
+use super::{
+    copy_section, encode_object_map_section, encode_object_record, encode_writer_entity,
+    entity_props, entity_props_mut,
+};
+use crate::container::{section_directory, SectionKind};
+use crate::core::config::ParseConfig;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::decoder::Decoder;
+use crate::dwg::version::DwgVersion;
+use crate::entities::common::{
+    line_weight_to_index, parse_common_entity_handles, parse_common_entity_header,
+};
+use crate::entities::decode_mline;
+use crate::objects::{decode_table_control, Handle, ObjectRef, TableKind};
+use crate::writer::ir::WriterEntity;
+
+const SECTION_DIRECTORY_OFFSET: usize = 0x15;
+const SECTION_RECORD_SIZE: usize = 9;
+const MLINE_TYPE_CODE: u16 = 0x2F;
+
+/// Replaces existing entities of an AC1015 file and returns the updated
+/// file bytes, which start with the unchanged original contents.
+///
+/// Every entity must carry the handle of an entity already in the file. The
+/// replacement keeps the original owner, layer and linetype, and its color
+/// and lineweight when the entity has none. Transparency is not carried
+/// over since it would need an `AcCmTransparency` APPID entry. Adding
+/// entities is not supported because they would also need to be linked
+/// into their block record and `HANDSEED`.
+pub fn apply_incremental_update(original: &[u8], entities: &[WriterEntity]) -> Result<Vec<u8>> {
+    let decoder = Decoder::new(original, ParseConfig::default())?;
+    if *decoder.version() != DwgVersion::R2000 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "incremental update only supports AC1015, got {}",
+                decoder.version().as_str()
+            ),
+        ));
+    }
+    let index = decoder.build_object_index()?;
+    let offsets: BTreeMap<u64, u64> = index
+        .objects
+        .iter()
+        .map(|obj| (obj.handle.0, obj.offset))
+        .collect();
+
+    let mut records = Vec::with_capacity(entities.len());
+    let mut tables = None;
+    for entity in entities {
+        let handle = entity_props(entity).handle.ok_or_else(|| {
+            DwgError::new(
+                ErrorKind::Unsupported,
+                "incremental update requires entities with existing handles",
+            )
+        })?;
+        let offset = *offsets.get(&handle).ok_or_else(|| {
+            DwgError::new(
+                ErrorKind::Resolve,
+                format!("handle {handle:#X} not found in object map"),
+            )
+        })?;
+
+        let record = decoder.parse_object_record(offset)?;
+        let mut reader = record.bit_reader();
+        let _type_code = reader.read_bs()?;
+        let header = parse_common_entity_header(&mut reader)?;
+        reader.set_bit_pos(header.obj_size);
+        let handles = parse_common_entity_handles(&mut reader, &header)?;
+        let needs_tables = handles.owner_ref.is_none() || handles.layer == 0;
+        if needs_tables && tables.is_none() {
+            tables = Some(find_table_defaults(&decoder, &index.objects)?);
+        }
+        let defaults = tables.unwrap_or_default();
+        let owner_handle = match handles.owner_ref {
+            Some(owner) => Some(owner),
+            None if header.entity_mode == 1 => defaults.paper_space,
+            None => defaults.model_space,
+        }
+        .ok_or_else(|| {
+            DwgError::new(
+                ErrorKind::Resolve,
+                format!("owner block record of handle {handle:#X} not found"),
+            )
+        })?;
+        let layer_handle = match handles.layer {
+            0 => defaults.layer_zero.ok_or_else(|| {
+                DwgError::new(
+                    ErrorKind::Resolve,
+                    format!("layer of handle {handle:#X} not found"),
+                )
+            })?,
+            layer => layer,
+        };
+
+        let mut entity = entity.clone();
+        let common = entity_props_mut(&mut entity);
+        if common.color_index.is_none() {
+            common.color_index = header.color.index;
+        }
+        let style = EntityStyleEncodeInput {
+            ltype_flags: header.ltype_flags,
+            ltype_handle: handles.ltype.unwrap_or(0),
+            line_weight: match common.lineweight {
+                Some(value) => line_weight_to_index(value).ok_or_else(|| {
+                    DwgError::new(ErrorKind::Format, format!("invalid lineweight: {value}"))
+                })?,
+                None => header.line_weight,
+            },
+            transparency: None,
+        };
+        // An MLINE keeps the style of the record it replaces.
+        let mline_style = match &entity {
+            WriterEntity::MLine(_) => {
+                let mut reader = record.bit_reader();
+                if reader.read_bs()? != MLINE_TYPE_CODE {
+                    return Err(DwgError::new(
+                        ErrorKind::Unsupported,
+                        format!("handle {handle:#X} is not an MLINE"),
+                    ));
+                }
+                let original = decode_mline(&mut reader)?;
+                Some((
+                    original.mlinestyle_handle.unwrap_or(0),
+                    usize::from(original.lines_in_style),
+                ))
+            }
+            _ => None,
+        };
+        let payload = encode_writer_entity(
+            &entity,
+            handle,
+            owner_handle,
+            layer_handle,
+            style,
+            mline_style,
+        )?;
+        records.push((handle, encode_object_record(&payload)?));
+    }
+    append_object_records(original, &index.objects, &records)
+}
+
+/// Appends the framed object `records`, each replacing the object of its
+/// handle, to `original` and points a rebuilt object map at them.
+pub(crate) fn append_object_records(
+    original: &[u8],
+    objects: &[ObjectRef],
+    records: &[(u64, Vec<u8>)],
+) -> Result<Vec<u8>> {
+    append_update(original, objects, records, &HashSet::new(), None)
+}
+
+/// Like [`append_object_records`], also leaving the `removed` handles out
+/// of the object map and, with `header`, appending that framed header
+/// section and pointing its section locator entry at it.
+pub(crate) fn append_update(
+    original: &[u8],
+    objects: &[ObjectRef],
+    records: &[(u64, Vec<u8>)],
+    removed: &HashSet<u64>,
+    header: Option<&[u8]>,
+) -> Result<Vec<u8>> {
+    let directory = section_directory::parse(original)?;
+    let slot = |kind: SectionKind, name: &str| {
+        directory
+            .records
+            .iter()
+            .position(|record| record.kind() == kind)
+            .ok_or_else(|| DwgError::new(ErrorKind::Format, format!("{name} section not found")))
+    };
+    let map_slot = slot(SectionKind::ObjectMap, "object map")?;
+    let mut offsets: BTreeMap<u64, u64> = objects
+        .iter()
+        .map(|obj| (obj.handle.0, obj.offset))
+        .filter(|(handle, _)| !removed.contains(handle))
+        .collect();
+
+    let mut bytes = original.to_vec();
+    if let Some(header) = header {
+        let header_slot = slot(SectionKind::HeaderVariables, "header variables")?;
+        let header_offset = u32::try_from(bytes.len()).map_err(|_| {
+            DwgError::new(ErrorKind::Unsupported, "file exceeds 4 GiB object offsets")
+        })?;
+        bytes.extend_from_slice(header);
+        let entry = SECTION_DIRECTORY_OFFSET + 4 + header_slot * SECTION_RECORD_SIZE;
+        copy_section(&mut bytes, entry + 1, &header_offset.to_le_bytes())?;
+        copy_section(&mut bytes, entry + 5, &(header.len() as u32).to_le_bytes())?;
+    }
+    for (handle, record) in records {
+        let record_offset = u32::try_from(bytes.len()).map_err(|_| {
+            DwgError::new(ErrorKind::Unsupported, "file exceeds 4 GiB object offsets")
+        })?;
+        bytes.extend_from_slice(record);
+        offsets.insert(*handle, u64::from(record_offset));
+    }
+
+    let object_refs: Vec<ObjectRef> = offsets
+        .into_iter()
+        .map(|(handle, offset)| ObjectRef {
+            handle: Handle(handle),
+            offset,
+        })
+        .collect();
+    let object_map = encode_object_map_section(&object_refs)?;
+    let map_offset = u32::try_from(bytes.len())
+        .map_err(|_| DwgError::new(ErrorKind::Unsupported, "file exceeds 4 GiB object offsets"))?;
+    bytes.extend_from_slice(&object_map);
+
+    let entry = SECTION_DIRECTORY_OFFSET + 4 + map_slot * SECTION_RECORD_SIZE;
+    copy_section(&mut bytes, entry + 1, &map_offset.to_le_bytes())?;
+    copy_section(
+        &mut bytes,
+        entry + 5,
+        &(object_map.len() as u32).to_le_bytes(),
+    )?;
+    let crc_offset = SECTION_DIRECTORY_OFFSET + 4 + directory.records.len() * SECTION_RECORD_SIZE;
+    let crc = crc16(CRC_SEED, bytes.get(..crc_offset).unwrap_or_default());
+    copy_section(&mut bytes, crc_offset, &crc.to_le_bytes())?;
+    Ok(bytes)
+}
+
+/// Handles an entity falls back to when its own record leaves them out or
+/// they cannot be read back.
+#[derive(Debug, Clone, Copy, Default)]
+struct TableDefaults {
+    model_space: Option<u64>,
+    paper_space: Option<u64>,
+    /// First LAYER_CONTROL entry, which is always layer `0`.
+    layer_zero: Option<u64>,
+}
+
+fn find_table_defaults(decoder: &Decoder<'_>, objects: &[ObjectRef]) -> Result<TableDefaults> {
+    let mut defaults = TableDefaults::default();
+    let mut found = (false, false);
+    for obj in objects {
+        let Ok(record) = decoder.parse_object_record(obj.offset) else {
+            continue;
+        };
+        let mut reader = record.bit_reader();
+        let Some(kind) = TableKind::from_control_type_code(reader.read_bs()?) else {
+            continue;
+        };
+        if !matches!(kind, TableKind::Block | TableKind::Layer) {
+            continue;
+        }
+        let control =
+            decode_table_control(&mut reader, decoder.version(), kind, None, obj.handle.0)?;
+        if kind == TableKind::Block {
+            defaults.model_space = control.model_space;
+            defaults.paper_space = control.paper_space;
+            found.0 = true;
+        } else {
+            defaults.layer_zero = control.entries.first().copied();
+            found.1 = true;
+        }
+        if found == (true, true) {
+            break;
+        }
+    }
+    Ok(defaults)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_incremental_update;
+    use crate::container::section_directory;
+    use crate::core::config::ParseConfig;
+    use crate::core::error::ErrorKind;
+    use crate::dwg::decoder::Decoder;
+    use crate::entities::{decode_line, LineEntity};
+    use crate::objects::object_header_r2000;
+    use crate::writer::ir::{CommonEntityProps, LineEntity as WriterLine, WriterEntity};
+    use crate::writer::r2000::crc::{crc16, CRC_SEED};
+
+    fn read_lines(bytes: &[u8]) -> Vec<LineEntity> {
+        let decoder = Decoder::new(bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut lines = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let header = object_header_r2000::parse_from_record(&record).expect("header");
+            if header.type_code != 0x13 {
+                continue;
+            }
+            let mut reader = record.bit_reader();
+            reader.read_bs().expect("type prefix");
+            lines.push(decode_line(&mut reader).expect("decode line"));
+        }
+        lines
+    }
+
+    #[test]
+    fn appends_replacement_line_and_object_map() {
+        let original = std::fs::read("test_dwg/line_2000.dwg").expect("sample");
+        let before = read_lines(&original);
+        let target = before.first().expect("line in sample").clone();
+
+        let updated = apply_incremental_update(
+            &original,
+            &[WriterEntity::Line(WriterLine {
+                common: CommonEntityProps {
+                    handle: Some(target.handle),
+                    ..CommonEntityProps::default()
+                },
+                start: (1.0, 2.0, 0.0),
+                end: (30.0, 40.0, 0.0),
+            })],
+        )
+        .expect("incremental update");
+
+        assert!(updated.len() > original.len());
+        let directory = section_directory::parse(&updated).expect("directory");
+        let crc_offset = 0x15 + 4 + directory.records.len() * 9;
+        assert_eq!(updated[..0x15], original[..0x15]);
+        assert_eq!(
+            u16::from_le_bytes([updated[crc_offset], updated[crc_offset + 1]]),
+            crc16(CRC_SEED, &updated[..crc_offset])
+        );
+        assert_eq!(
+            updated[crc_offset + 2..original.len()],
+            original[crc_offset + 2..]
+        );
+
+        let after = read_lines(&updated);
+        assert_eq!(after.len(), before.len());
+        let line = after
+            .iter()
+            .find(|line| line.handle == target.handle)
+            .expect("replaced line");
+        assert_eq!(line.start, (1.0, 2.0, 0.0));
+        assert_eq!(line.end, (30.0, 40.0, 0.0));
+        // The sample's own layer reference does not read back, so the
+        // replacement lands on layer `0`.
+        assert_eq!(target.layer_handle, 0);
+        assert_ne!(line.layer_handle, 0);
+    }
+
+    #[test]
+    fn rejects_unknown_handles() {
+        let original = std::fs::read("test_dwg/line_2000.dwg").expect("sample");
+        let err = apply_incremental_update(
+            &original,
+            &[WriterEntity::Line(WriterLine {
+                common: CommonEntityProps {
+                    handle: Some(0x00FF_FFFF),
+                    ..CommonEntityProps::default()
+                },
+                ..WriterLine::default()
+            })],
+        )
+        .expect_err("unknown handle");
+        assert_eq!(err.kind, ErrorKind::Resolve);
+    }
+}
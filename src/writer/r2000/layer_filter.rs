@@ -0,0 +1,466 @@
+//! Removal of the entities of whole layers from AC1015 files.
+//!
+//! The removed entities only leave the object map. Block records and
+//! entities next to a removed one in their block's entity chain are
+//! relinked around the gap; every other record stays byte-identical, so
+//! the cost depends on the number of changed links rather than on the size
+//! of the drawing. The relinked records are appended like those of
+//! [`super::apply_incremental_update`].
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::block_edit::{entity_chain, relink_block_record, SUBENTITY_TYPE_CODES};
+use super::encode_object_record;
+use super::incremental::append_update;
+use super::raw_object::copy_bits;
+use crate::bit::{BitReader, BitWriter, Endian};
+use crate::core::config::ParseConfig;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::decoder::Decoder;
+use crate::dwg::version::DwgVersion;
+use crate::entities::common::{
+    parse_common_entity_handles, parse_common_entity_header, parse_common_entity_header_r2000,
+};
+use crate::objects::{
+    decode_block_record, decode_table_entry_name, object_type_class, BlockRecordObject,
+    ObjectClass, ObjectRef,
+};
+
+const BLOCK_HEADER_TYPE_CODE: u16 = 0x31;
+const LAYER_TYPE_CODE: u16 = 0x33;
+/// BLOCK and ENDBLK, which open and close a block rather than draw in it.
+const BLOCK_BOUNDARY_TYPE_CODES: [u16; 2] = [0x04, 0x05];
+
+/// Removes every entity on the layers `layer_names` (compared
+/// case-insensitively) from the blocks, model space and paper space of an
+/// AC1015 file. Returns the updated file bytes, which start with the
+/// unchanged original contents, and the sorted handles of the removed
+/// entities.
+///
+/// ATTRIBs, VERTEXes and SEQENDs go with their parent entity whatever
+/// their own layer. The layers themselves stay in the LAYER table, and
+/// groups or other objects that refer to a removed entity keep the stale
+/// handle.
+pub fn strip_layers(original: &[u8], layer_names: &[&str]) -> Result<(Vec<u8>, Vec<u64>)> {
+    let decoder = Decoder::new(original, ParseConfig::default())?;
+    if *decoder.version() != DwgVersion::R2000 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "layer stripping only supports AC1015, got {}",
+                decoder.version().as_str()
+            ),
+        ));
+    }
+    let drawing = EntityScan::new(&decoder)?;
+    let mut stripped = HashSet::new();
+    for name in layer_names {
+        let handle = drawing
+            .layers
+            .get(&name.to_ascii_uppercase())
+            .ok_or_else(|| DwgError::new(ErrorKind::Resolve, format!("layer not found: {name}")))?;
+        stripped.insert(*handle);
+    }
+    remove_entities(original, &decoder, &drawing, |entity| {
+        stripped.contains(&entity.layer)
+    })
+}
+
+/// The block records, layers and entities of an AC1015 drawing, read only
+/// as far as their handles.
+struct EntityScan {
+    objects: Vec<ObjectRef>,
+    offsets: BTreeMap<u64, u64>,
+    blocks: Vec<(BlockRecordObject, Vec<u8>)>,
+    /// Layer handles by upper-case name.
+    layers: HashMap<String, u64>,
+    entities: Vec<ScannedEntity>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ScannedEntity {
+    handle: u64,
+    layer: u64,
+    /// The owning entity of ATTRIBs, VERTEXes and SEQENDs.
+    parent: Option<u64>,
+    /// Whether the record leaves out the extension dictionary handle that
+    /// R2000 requires, as the records of the writer do. Its `nolinks` flag
+    /// then reads as a missing dictionary.
+    bare: bool,
+}
+
+impl EntityScan {
+    fn new(decoder: &Decoder<'_>) -> Result<Self> {
+        let version = decoder.version();
+        let index = decoder.build_object_index()?;
+        let classes = decoder.dynamic_type_class_map()?;
+        let mut scan = Self {
+            offsets: index
+                .objects
+                .iter()
+                .map(|obj| (obj.handle.0, obj.offset))
+                .collect(),
+            objects: index.objects.clone(),
+            blocks: Vec::new(),
+            layers: HashMap::new(),
+            entities: Vec::new(),
+        };
+        // Each entity with the owner and layer read both with and without
+        // an extension dictionary handle.
+        let mut candidates = Vec::new();
+        for obj in &index.objects {
+            let Ok(record) = decoder.parse_object_record(obj.offset) else {
+                continue;
+            };
+            let mut reader = record.bit_reader();
+            let Ok(type_code) = reader.read_bs() else {
+                continue;
+            };
+            match type_code {
+                BLOCK_HEADER_TYPE_CODE => {
+                    if let Ok(block) = decode_block_record(&mut reader, version, None, obj.handle.0)
+                    {
+                        scan.blocks.push((block, record.body.to_vec()));
+                    }
+                    continue;
+                }
+                LAYER_TYPE_CODE => {
+                    if let Ok((handle, Some(name))) =
+                        decode_table_entry_name(&mut reader, version, obj.handle.0)
+                    {
+                        scan.layers
+                            .entry(name.to_ascii_uppercase())
+                            .or_insert(handle);
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            let class = match classes.get(&type_code) {
+                Some(class) => *class,
+                None => object_type_class(type_code),
+            };
+            if class != ObjectClass::Entity || BLOCK_BOUNDARY_TYPE_CODES.contains(&type_code) {
+                continue;
+            }
+            let read = |bare: bool| {
+                let mut reader = record.bit_reader();
+                reader.read_bs().ok()?;
+                let header = if bare {
+                    parse_common_entity_header(&mut reader)
+                } else {
+                    parse_common_entity_header_r2000(&mut reader)
+                };
+                let mut header = header.ok()?;
+                if header.handle == 0 {
+                    header.handle = obj.handle.0;
+                }
+                reader.set_bit_pos(header.obj_size);
+                let handles = parse_common_entity_handles(&mut reader, &header).ok()?;
+                Some((handles.layer, handles.owner_ref))
+            };
+            let subentity = SUBENTITY_TYPE_CODES.contains(&type_code);
+            candidates.push((obj.handle.0, subentity, read(false), read(true)));
+        }
+
+        let layer_handles: HashSet<u64> = scan.layers.values().copied().collect();
+        for (handle, subentity, spec, bare) in candidates {
+            let (layer, owner, bare) = match (spec, bare) {
+                (Some((layer, owner)), _) if layer_handles.contains(&layer) => {
+                    (layer, owner, false)
+                }
+                (_, Some((layer, owner))) if layer_handles.contains(&layer) => (layer, owner, true),
+                (Some((layer, owner)), _) => (layer, owner, false),
+                (None, _) => continue,
+            };
+            scan.entities.push(ScannedEntity {
+                handle,
+                layer,
+                parent: owner.filter(|_| subentity),
+                bare,
+            });
+        }
+        Ok(scan)
+    }
+}
+
+/// Removes the entities, other than ATTRIBs, VERTEXes and SEQENDs, for
+/// which `remove` returns true, with the entities they own.
+fn remove_entities(
+    original: &[u8],
+    decoder: &Decoder<'_>,
+    drawing: &EntityScan,
+    remove: impl Fn(&ScannedEntity) -> bool,
+) -> Result<(Vec<u8>, Vec<u64>)> {
+    let owners: HashSet<u64> = drawing
+        .entities
+        .iter()
+        .filter(|entity| entity.parent.is_none() && remove(entity))
+        .map(|entity| entity.handle)
+        .collect();
+    let bare: HashSet<u64> = drawing
+        .entities
+        .iter()
+        .filter(|entity| entity.bare)
+        .map(|entity| entity.handle)
+        .collect();
+
+    let mut records = Vec::new();
+    for (block, data) in &drawing.blocks {
+        let Some(first) = block.first_entity else {
+            continue;
+        };
+        let old = entity_chain(decoder, &drawing.offsets, first, block.last_entity)?;
+        let chain: Vec<u64> = old
+            .iter()
+            .copied()
+            .filter(|handle| !owners.contains(handle))
+            .collect();
+        if chain.len() == old.len() {
+            continue;
+        }
+        if chain.first() != old.first() || chain.last() != old.last() {
+            let (new_first, new_last) = match (chain.first(), chain.last()) {
+                (Some(first), Some(last)) => (*first, *last),
+                _ => (0, 0),
+            };
+            let record = relink_block_record(data, new_first, new_last)?;
+            records.push((block.handle, encode_object_record(&record)?));
+        }
+        records.extend(relink_neighbours(
+            decoder,
+            &drawing.offsets,
+            &old,
+            &chain,
+            &bare,
+        )?);
+    }
+    let mut removed = owners.clone();
+    removed.extend(
+        drawing
+            .entities
+            .iter()
+            .filter(|entity| entity.parent.is_some_and(|parent| owners.contains(&parent)))
+            .map(|entity| entity.handle),
+    );
+    let bytes = append_update(original, &drawing.objects, &records, &removed, None)?;
+    let mut removed: Vec<u64> = removed.into_iter().collect();
+    removed.sort_unstable();
+    Ok((bytes, removed))
+}
+
+/// Framed records of the entities of `chain`, what is left of the block
+/// chain `old`, whose neighbours changed, relinked to their new ones.
+fn relink_neighbours(
+    decoder: &Decoder<'_>,
+    offsets: &BTreeMap<u64, u64>,
+    old: &[u64],
+    chain: &[u64],
+    bare: &HashSet<u64>,
+) -> Result<Vec<(u64, Vec<u8>)>> {
+    let neighbours = |handles: &[u64]| -> HashMap<u64, (u64, u64)> {
+        (0..handles.len())
+            .filter_map(|position| {
+                let handle = *handles.get(position)?;
+                let prev = match position {
+                    0 => 0,
+                    _ => handles.get(position - 1).copied().unwrap_or(0),
+                };
+                let next = handles.get(position + 1).copied().unwrap_or(0);
+                Some((handle, (prev, next)))
+            })
+            .collect()
+    };
+    let before = neighbours(old);
+    let mut records = Vec::new();
+    for (handle, (prev, next)) in neighbours(chain) {
+        if before.get(&handle) == Some(&(prev, next)) {
+            continue;
+        }
+        let Some(offset) = offsets.get(&handle) else {
+            continue;
+        };
+        let record = decoder.parse_object_record(*offset)?;
+        let data = relink_entity_record(&record.body, prev, next, bare.contains(&handle))?;
+        records.push((handle, encode_object_record(&data)?));
+    }
+    records.sort_by_key(|(handle, _)| *handle);
+    Ok(records)
+}
+
+/// Re-encodes the R2000 entity record `data` with explicit links to `prev`
+/// and `next`, clearing its `nolinks` flag when it relied on consecutive
+/// handles. A `bare` record also gets the null extension dictionary handle
+/// it left out. Returns the record data without the size prefix and CRC.
+fn relink_entity_record(data: &[u8], prev: u64, next: u64, bare: bool) -> Result<Vec<u8>> {
+    let total_bits = (data.len() as u64).saturating_mul(8);
+    let mut reader = BitReader::new(data);
+    reader.read_bs()?;
+    let obj_size = reader.read_rl(Endian::Little)?;
+    reader.read_h()?;
+    loop {
+        let size = reader.read_bs()?;
+        if size == 0 {
+            break;
+        }
+        reader.read_h()?;
+        reader.read_rcs(usize::from(size))?;
+    }
+    if reader.read_b()? == 1 {
+        let graphics_size = reader.read_rl(Endian::Little)?;
+        reader.read_rcs(graphics_size as usize)?;
+    }
+    let entity_mode = reader.read_bb()?;
+    let num_reactors = reader.read_bl()?;
+    let no_links_pos = reader.tell_bits();
+    let has_links = reader.read_b()? == 0 && !bare;
+
+    // Owner, reactors and extension dictionary come before the links.
+    reader.set_bit_pos(obj_size);
+    let owner = u64::from(entity_mode == 0);
+    for _ in 0..owner + u64::from(num_reactors) + u64::from(!bare) {
+        reader.read_h()?;
+    }
+    let links_start = reader.tell_bits();
+    if has_links {
+        reader.read_h()?;
+        reader.read_h()?;
+    }
+    let links_end = reader.tell_bits();
+
+    let mut out = BitWriter::new();
+    let mut reader = BitReader::new(data);
+    copy_bits(&mut reader, &mut out, no_links_pos)?;
+    out.write_b(0)?;
+    reader.set_bit_pos((no_links_pos + 1) as u32);
+    copy_bits(&mut reader, &mut out, links_start)?;
+    if bare {
+        out.write_h(0x03, 0)?;
+    }
+    out.write_h(0x04, prev)?;
+    out.write_h(0x04, next)?;
+    reader.set_bit_pos(links_end as u32);
+    copy_bits(&mut reader, &mut out, total_bits)?;
+    Ok(out.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{remove_entities, strip_layers, EntityScan};
+    use crate::container::section_directory;
+    use crate::core::config::ParseConfig;
+    use crate::core::error::ErrorKind;
+    use crate::dwg::decoder::Decoder;
+    use crate::entities::decode_circle;
+    use crate::objects::{decode_block_record, BlockRecordObject};
+    use crate::writer::ir::{CircleEntity, CommonEntityProps, WriterEntity};
+    use crate::writer::r2000::block_edit::entity_chain;
+    use crate::writer::r2000::redefine_block;
+
+    fn model_space(bytes: &[u8]) -> BlockRecordObject {
+        let decoder = Decoder::new(bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        index
+            .objects
+            .iter()
+            .find_map(|obj| {
+                let record = decoder.parse_object_record(obj.offset).ok()?;
+                let mut reader = record.bit_reader();
+                if reader.read_bs().ok()? != 0x31 {
+                    return None;
+                }
+                decode_block_record(&mut reader, decoder.version(), None, obj.handle.0)
+                    .ok()
+                    .filter(|block| block.name.as_deref() == Some("*Model_Space"))
+            })
+            .expect("model space")
+    }
+
+    #[test]
+    fn strips_entities_and_empties_their_chain() {
+        let original = std::fs::read("test_dwg/line_2000.dwg").expect("sample");
+        let line = model_space(&original).first_entity.expect("line");
+
+        let (updated, removed) = strip_layers(&original, &["0"]).expect("strip layers");
+
+        assert_eq!(removed, vec![line]);
+        let directory = section_directory::parse(&updated).expect("directory");
+        let records_end = 0x15 + 4 + directory.records.len() * 9 + 2;
+        assert_eq!(
+            updated[records_end..original.len()],
+            original[records_end..]
+        );
+        let block = model_space(&updated);
+        assert_eq!((block.first_entity, block.last_entity), (None, None));
+        let decoder = Decoder::new(&updated, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert!(!index.objects.iter().any(|obj| obj.handle.0 == line));
+
+        let err = strip_layers(&original, &["SURVEY"]).expect_err("unknown layer");
+        assert_eq!(err.kind, ErrorKind::Resolve);
+    }
+
+    #[test]
+    fn relinks_the_neighbours_of_removed_entities() {
+        let original = std::fs::read("test_dwg/line_2000.dwg").expect("sample");
+        let circles: Vec<WriterEntity> = (1..=4)
+            .map(|radius| {
+                WriterEntity::Circle(CircleEntity {
+                    common: CommonEntityProps::default(),
+                    center: (0.0, 0.0, 0.0),
+                    radius: f64::from(radius),
+                })
+            })
+            .collect();
+        let drawing = redefine_block(&original, "*Model_Space", &circles).expect("redefine");
+        let first = model_space(&drawing).first_entity.expect("first circle");
+        let decoder = Decoder::new(&drawing, ParseConfig::default()).expect("decoder");
+
+        let scan = EntityScan::new(&decoder).expect("scan");
+        assert!(scan.entities.iter().all(|entity| entity.bare));
+        let (updated, removed) = remove_entities(&drawing, &decoder, &scan, |entity| {
+            entity.handle == first + 1 || entity.handle == first + 3
+        })
+        .expect("remove entities");
+
+        assert_eq!(removed, vec![first + 1, first + 3]);
+        let block = model_space(&updated);
+        assert_eq!(block.first_entity, Some(first));
+        assert_eq!(block.last_entity, Some(first + 2));
+        let decoder = Decoder::new(&updated, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let offsets: BTreeMap<u64, u64> = index
+            .objects
+            .iter()
+            .map(|obj| (obj.handle.0, obj.offset))
+            .collect();
+        let handles = entity_chain(&decoder, &offsets, first, block.last_entity).expect("chain");
+        assert_eq!(handles, vec![first, first + 2]);
+        let mut radii = Vec::new();
+        for handle in handles {
+            let record = decoder
+                .parse_object_record(offsets[&handle])
+                .expect("record");
+            let mut reader = record.bit_reader();
+            assert_eq!(reader.read_bs().expect("type"), 0x12);
+            radii.push(decode_circle(&mut reader).expect("circle").radius);
+        }
+        assert_eq!(radii, vec![1.0, 3.0]);
+        // The relinked circles, written without an extension dictionary,
+        // now carry one and still read back on their layer.
+        let scan = EntityScan::new(&decoder).expect("scan");
+        let layer_zero = scan.layers["0"];
+        let circles: Vec<(u64, u64, bool)> = scan
+            .entities
+            .iter()
+            .map(|entity| (entity.handle, entity.layer, entity.bare))
+            .collect();
+        assert_eq!(
+            circles,
+            vec![(first, layer_zero, false), (first + 2, layer_zero, false)]
+        );
+    }
+}
@@ -1,32 +1,109 @@
+pub mod block_edit;
 pub mod classes;
+pub mod crc;
 pub mod entities;
+pub mod header;
+pub mod incremental;
+pub mod layer_filter;
 pub mod object_map;
 pub mod object_record;
+pub mod objects;
+pub mod raw_object;
 pub mod sections;
+pub mod text_edit;
 
-use self::classes::encode_minimal_classes_section;
+use self::classes::encode_classes_section;
+use self::crc::{crc16, CRC_SEED};
 use self::entities::{
-    encode_arc_entity_payload, encode_circle_entity_payload, encode_line_entity_payload,
-    encode_lwpolyline_entity_payload, encode_mtext_entity_payload, encode_point_entity_payload,
-    encode_ray_entity_payload, encode_text_entity_payload, encode_xline_entity_payload,
-    ArcEncodeInput, CircleEncodeInput, LineEncodeInput, LwPolylineEncodeInput, MTextEncodeInput,
-    PointEncodeInput, RayEncodeInput, TextEncodeInput, XLineEncodeInput,
+    add_entity_reactors, add_entity_xdata, encode_arc_entity_payload, encode_circle_entity_payload,
+    encode_leader_entity_payload, encode_line_entity_payload, encode_lwpolyline_entity_payload,
+    encode_mline_entity_payload, encode_mtext_entity_payload, encode_point_entity_payload,
+    encode_polyface_mesh_payloads, encode_ray_entity_payload, encode_text_entity_payload,
+    encode_tolerance_entity_payload, encode_viewport_entity_payload, encode_xline_entity_payload,
+    polyface_owned_record_count, ArcEncodeInput, CircleEncodeInput, EntityStyleEncodeInput,
+    LeaderEncodeInput, LineEncodeInput, LwPolylineEncodeInput, MLineEncodeInput,
+    MLineVertexEncodeInput, MTextEncodeInput, PointEncodeInput, PolyfaceMeshEncodeInput,
+    RayEncodeInput, TextEncodeInput, ToleranceEncodeInput, ViewportEncodeInput, XLineEncodeInput,
 };
+use self::header::{encode_header_section, HeaderEncodeInput};
+use self::objects::{
+    encode_app_id_object_payload, encode_dictionary_object_payload, encode_group_object_payload,
+    encode_layer_object_payload, encode_layout_object_payload, encode_ltype_object_payload,
+    encode_mline_style_object_payload, encode_xdata_items, encode_xrecord_object_payload,
+    AppIdEncodeInput, DictionaryEncodeInput, GroupEncodeInput, LayerEncodeInput, LayoutEncodeInput,
+    LtypeEncodeInput, MLineStyleEncodeInput, XRecordEncodeInput,
+};
+use self::raw_object::remap_raw_object_record;
 use crate::core::error::{DwgError, ErrorKind};
+use crate::core::progress::{Progress, ProgressHook, ProgressStage};
 use crate::core::result::Result;
-use crate::objects::{Handle, ObjectRef};
+use crate::dwg::header::{DrawingTimes, JulianDate};
+use crate::dwg::r2000::ClassDefinition;
+use crate::entities::common::{line_weight_to_index, LINE_WEIGHT_BY_LAYER_INDEX};
+use crate::objects::{parse_object_record, Handle, ObjectRef};
+use crate::writer::arc_fit::fit_arcs;
 use crate::writer::config::WriterConfig;
-use crate::writer::ir::{WriterDocument, WriterEntity};
-use crate::writer::HandleAllocator;
+use crate::writer::ir::{
+    CommonEntityProps, LayerDef, LayoutDef, LinetypeDef, MLineStyleDef, WriterDocument,
+    WriterEntity,
+};
+use crate::writer::lwpolyline::split_lwpolylines;
+use crate::writer::transform::transform_document;
+use crate::writer::{HandleAllocator, HandleNamespace};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{Read, Write};
 
+pub use block_edit::redefine_block;
+pub use header::header_template;
+pub use incremental::apply_incremental_update;
+pub use layer_filter::strip_layers;
 pub use object_map::encode_object_map_section;
 pub use object_record::{encode_ms_value, encode_object_record};
+pub use raw_object::{collect_unknown_objects, remap_record_handles};
+pub use text_edit::replace_entity_text;
+
+const MODELSPACE_BLOCK_RECORD_HANDLE: u64 = 1;
+const LAYOUT_DICTIONARY_HANDLE: u64 = 3;
+const LAYER_CONTROL_HANDLE: u64 = 4;
+const LTYPE_CONTROL_HANDLE: u64 = 5;
+const APPID_CONTROL_HANDLE: u64 = 9;
+const MLINESTYLE_DICTIONARY_HANDLE: u64 = 0x0A;
+const NAMED_OBJECTS_DICTIONARY_HANDLE: u64 = 0x0C;
+const GROUP_DICTIONARY_HANDLE: u64 = 0x0D;
+const DEFAULT_LAYER_HANDLE: u64 = 2;
+/// Handles below this one are the fixed handles above.
+const FIRST_FREE_HANDLE: u64 = 0x10;
+
+/// Entity lists shorter than this are encoded on the calling thread.
+const PARALLEL_ENCODE_MIN_ENTITIES: usize = 4096;
+
+/// Registered application whose EED carries entity transparency.
+const TRANSPARENCY_APP_NAME: &str = "AcCmTransparency";
 
 const SECTION_DIRECTORY_SENTINEL: [u8; 16] = [
     0x95, 0xA0, 0x4E, 0x28, 0x99, 0x82, 0x1A, 0xE5, 0x5E, 0x41, 0xE0, 0x5F, 0x9D, 0x3A, 0x4D, 0x00,
 ];
 
 pub fn write_document(doc: &WriterDocument, config: &WriterConfig) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    write_document_to(&mut bytes, doc, config)?;
+    Ok(bytes)
+}
+
+/// Streams `doc` as an AC1015 file into `writer` and returns the number of
+/// bytes written.
+///
+/// The object records are encoded first and the sections are laid out from
+/// their sizes, so the file is written front to back without seeking and
+/// without assembling it in memory: peak memory is the encoded records
+/// rather than twice the file size. Wrap files in a `BufWriter`. On error
+/// `writer` may hold a partial file.
+pub fn write_document_to(
+    writer: &mut impl Write,
+    doc: &WriterDocument,
+    config: &WriterConfig,
+) -> Result<u64> {
     if !matches!(doc.version, crate::dwg::version::DwgVersion::R2000) {
         return Err(DwgError::new(
             ErrorKind::Unsupported,
@@ -37,210 +114,122 @@ pub fn write_document(doc: &WriterDocument, config: &WriterConfig) -> Result<Vec
         ));
     }
 
-    let classes_section = encode_minimal_classes_section()?;
-    let mut allocator = HandleAllocator::new(0x10);
-    let mut record_rows: Vec<(ObjectRef, Vec<u8>)> = Vec::new();
+    let transformed = match &config.transform {
+        Some(transform) => transform_document(doc, transform)?,
+        None => Cow::Borrowed(doc),
+    };
+    let fitted = match config.arc_fit_tolerance {
+        Some(tolerance) => fit_arcs(transformed.as_ref(), tolerance),
+        None => Cow::Borrowed(transformed.as_ref()),
+    };
+    let doc = match config.max_lwpolyline_vertices {
+        Some(max_vertices) => split_lwpolylines(fitted.as_ref(), max_vertices),
+        None => Cow::Borrowed(fitted.as_ref()),
+    };
+    let doc = doc.as_ref();
 
-    for entity in &doc.modelspace {
-        match entity {
-            WriterEntity::Line(line) => {
-                let handle = resolve_handle(&mut allocator, line.common.handle, config)?;
-                let payload = encode_line_entity_payload(LineEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: line.common.color_index.unwrap_or(7) as u8,
-                    start: line.start,
-                    end: line.end,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::Point(point) => {
-                let handle = resolve_handle(&mut allocator, point.common.handle, config)?;
-                let payload = encode_point_entity_payload(PointEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: point.common.color_index.unwrap_or(7) as u8,
-                    location: point.location,
-                    x_axis_angle: point.x_axis_angle,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::Ray(ray) => {
-                let handle = resolve_handle(&mut allocator, ray.common.handle, config)?;
-                let payload = encode_ray_entity_payload(RayEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: ray.common.color_index.unwrap_or(7) as u8,
-                    start: ray.start,
-                    unit_vector: ray.unit_vector,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::XLine(xline) => {
-                let handle = resolve_handle(&mut allocator, xline.common.handle, config)?;
-                let payload = encode_xline_entity_payload(XLineEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: xline.common.color_index.unwrap_or(7) as u8,
-                    start: xline.start,
-                    unit_vector: xline.unit_vector,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::Arc(arc) => {
-                let handle = resolve_handle(&mut allocator, arc.common.handle, config)?;
-                let payload = encode_arc_entity_payload(ArcEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: arc.common.color_index.unwrap_or(7) as u8,
-                    center: arc.center,
-                    radius: arc.radius,
-                    angle_start: arc.angle_start_rad,
-                    angle_end: arc.angle_end_rad,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::Circle(circle) => {
-                let handle = resolve_handle(&mut allocator, circle.common.handle, config)?;
-                let payload = encode_circle_entity_payload(CircleEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: circle.common.color_index.unwrap_or(7) as u8,
-                    center: circle.center,
-                    radius: circle.radius,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::LwPolyline(poly) => {
-                let handle = resolve_handle(&mut allocator, poly.common.handle, config)?;
-                let payload = encode_lwpolyline_entity_payload(LwPolylineEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: poly.common.color_index.unwrap_or(7) as u8,
-                    flags: poly.flags,
-                    vertices: poly.vertices.clone(),
-                    const_width: poly.const_width,
-                    bulges: poly.bulges.clone(),
-                    widths: poly.widths.clone(),
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::Text(text) => {
-                let handle = resolve_handle(&mut allocator, text.common.handle, config)?;
-                let payload = encode_text_entity_payload(&TextEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: text.common.color_index.unwrap_or(7) as u8,
-                    text: text.text.clone(),
-                    insertion: text.insert,
-                    height: text.height,
-                    rotation: text.rotation_rad,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
-                ));
-            }
-            WriterEntity::MText(mtext) => {
-                let handle = resolve_handle(&mut allocator, mtext.common.handle, config)?;
-                let payload = encode_mtext_entity_payload(&MTextEncodeInput {
-                    handle,
-                    owner_handle: 1,
-                    layer_handle: 2,
-                    color_index: mtext.common.color_index.unwrap_or(7) as u8,
-                    text: mtext.text.clone(),
-                    insertion: mtext.insert,
-                    text_direction: mtext.text_direction,
-                    rect_width: mtext.rect_width,
-                    text_height: mtext.char_height,
-                    attachment: mtext.attachment_point,
-                    drawing_dir: mtext.drawing_direction,
-                })?;
-                let record = encode_object_record(&payload)?;
-                record_rows.push((
-                    ObjectRef {
-                        handle: Handle(handle),
-                        offset: 0,
-                    },
-                    record,
+    let (raw_classes, raw_type_codes) = raw_object_classes(doc)?;
+    let classes_section = encode_classes_section(&raw_classes)?;
+    let mut allocator = match config.handle_layout {
+        Some(layout) => {
+            if HandleNamespace::ALL
+                .iter()
+                .any(|&namespace| layout.start(namespace) < FIRST_FREE_HANDLE)
+            {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!("handle namespaces must start at or above {FIRST_FREE_HANDLE:#X}"),
                 ));
             }
+            HandleAllocator::with_layout(layout)?
         }
+        None => HandleAllocator::new(FIRST_FREE_HANDLE),
+    };
+    if config.preserve_input_handles {
+        reserve_input_handles(&mut allocator, doc)?;
     }
+    let raw_handles = raw_object_handles(&mut allocator, doc, config)?;
+    let (linetypes, needs_transparency_app) = style_table_entries(doc);
+    let mline_styles = mline_style_entries(doc);
+    let layers = layer_entries(doc);
+    let app_ids = app_id_entries(doc, config, needs_transparency_app);
+    let style_records = layers.len() + linetypes.len() + app_ids.len() + mline_styles.len();
+    let mut record_rows = RecordRows::new(expected_record_count(doc, style_records), config);
 
+    let styles = encode_style_tables(
+        &mut allocator,
+        &layers,
+        &linetypes,
+        &app_ids,
+        &mline_styles,
+        config,
+        &mut record_rows,
+    )?;
+    encode_entity_records(
+        &mut allocator,
+        &doc.modelspace,
+        MODELSPACE_BLOCK_RECORD_HANDLE,
+        &styles,
+        config,
+        &mut record_rows,
+    )?;
+    encode_layout_records(&mut allocator, doc, &styles, config, &mut record_rows)?;
+    encode_raw_objects(doc, &raw_handles, &raw_type_codes, config, &mut record_rows)?;
+    encode_group_records(&mut allocator, doc, &mut record_rows)?;
+    encode_dictionary_records(&mut allocator, doc, config, &mut record_rows)?;
+
+    let mut record_rows = record_rows.rows;
     record_rows.sort_by_key(|(obj_ref, _)| obj_ref.handle.0);
 
-    let record_count = 2usize;
+    let handle_seed = record_rows
+        .last()
+        .map(|(obj_ref, _)| obj_ref.handle.0)
+        .unwrap_or(0)
+        .max(APPID_CONTROL_HANDLE)
+        + 1;
+    let header_section = encode_header_section(&HeaderEncodeInput {
+        handle_seed,
+        current_layer: DEFAULT_LAYER_HANDLE,
+        layer_control: LAYER_CONTROL_HANDLE,
+        model_space_block_record: MODELSPACE_BLOCK_RECORD_HANDLE,
+        layout_dictionary: LAYOUT_DICTIONARY_HANDLE,
+        linetype_control: LTYPE_CONTROL_HANDLE,
+        app_id_control: APPID_CONTROL_HANDLE,
+        mline_style_dictionary: MLINESTYLE_DICTIONARY_HANDLE,
+        group_dictionary: if doc.groups.is_empty() {
+            0
+        } else {
+            GROUP_DICTIONARY_HANDLE
+        },
+        named_objects_dictionary: named_objects_dictionary(doc),
+        insertion_base: doc.metadata.insertion_base,
+        ext_min: doc.metadata.ext_min.unwrap_or((0.0, 0.0, 0.0)),
+        ext_max: doc.metadata.ext_max.unwrap_or((0.0, 0.0, 0.0)),
+        times: doc.metadata.times.unwrap_or_else(|| {
+            let now = JulianDate::now();
+            DrawingTimes {
+                created: now,
+                updated: now,
+                ..DrawingTimes::default()
+            }
+        }),
+        template: doc.metadata.header_template.as_deref(),
+    })?;
+
+    let record_count = 3usize;
     let directory_size = 0x15usize + 4 + record_count * 9 + 2 + SECTION_DIRECTORY_SENTINEL.len();
     let mut cursor = align_up(directory_size, 4);
 
+    let header_offset = cursor;
+    cursor = cursor.saturating_add(header_section.len());
+    cursor = align_up(cursor, 4);
+
     let classes_offset = cursor;
     cursor = cursor.saturating_add(classes_section.len());
     cursor = align_up(cursor, 4);
 
     for (obj_ref, record) in record_rows.iter_mut() {
-        obj_ref.offset = cursor as u32;
+        obj_ref.offset = cursor as u64;
         cursor = cursor.saturating_add(record.len());
     }
     cursor = align_up(cursor, 4);
@@ -249,166 +238,1449 @@ pub fn write_document(doc: &WriterDocument, config: &WriterConfig) -> Result<Vec
     let object_map_section = encode_object_map_section(&object_refs)?;
     let object_map_offset = cursor;
     cursor = cursor.saturating_add(object_map_section.len());
+    // AC1015 section records hold 32-bit offsets and sizes.
+    if u32::try_from(cursor).is_err() {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            "file exceeds 4 GiB object offsets",
+        ));
+    }
 
-    let mut bytes = vec![0u8; cursor];
-    bytes[0..6].copy_from_slice(b"AC1015");
-    write_u32_le(&mut bytes, 0x15, record_count as u32);
+    let mut directory = vec![0u8; header_offset];
+    copy_section(&mut directory, 0, b"AC1015")?;
+    copy_section(&mut directory, 0x0B, &[config.maintenance_version])?;
+    copy_section(&mut directory, 0x13, &config.codepage.to_le_bytes())?;
+    copy_section(&mut directory, 0x15, &(record_count as u32).to_le_bytes())?;
     let mut entry_off = 0x15usize + 4;
 
     write_section_record(
-        &mut bytes,
+        &mut directory,
+        entry_off,
+        0,
+        header_offset as u32,
+        header_section.len() as u32,
+    )?;
+    entry_off += 9;
+    write_section_record(
+        &mut directory,
         entry_off,
         1,
         classes_offset as u32,
         classes_section.len() as u32,
-    );
+    )?;
     entry_off += 9;
     write_section_record(
-        &mut bytes,
+        &mut directory,
         entry_off,
         2,
         object_map_offset as u32,
         object_map_section.len() as u32,
-    );
+    )?;
     entry_off += 9;
 
-    write_u16_le(&mut bytes, entry_off, 0);
+    let directory_crc = crc16(CRC_SEED, directory.get(..entry_off).unwrap_or_default());
+    copy_section(&mut directory, entry_off, &directory_crc.to_le_bytes())?;
     entry_off += 2;
-    bytes[entry_off..entry_off + SECTION_DIRECTORY_SENTINEL.len()]
-        .copy_from_slice(&SECTION_DIRECTORY_SENTINEL);
+    copy_section(&mut directory, entry_off, &SECTION_DIRECTORY_SENTINEL)?;
 
-    copy_section(&mut bytes, classes_offset, &classes_section)?;
+    let mut sink = SectionSink::new(writer);
+    sink.write_at(0, &directory)?;
+    sink.write_at(header_offset, &header_section)?;
+    sink.write_at(classes_offset, &classes_section)?;
     for (obj_ref, record) in &record_rows {
-        copy_section(&mut bytes, obj_ref.offset as usize, record)?;
+        sink.write_at(obj_ref.offset as usize, record)?;
     }
-    copy_section(&mut bytes, object_map_offset, &object_map_section)?;
+    sink.write_at(object_map_offset, &object_map_section)?;
+    let written = sink.position as u64;
 
-    Ok(bytes)
+    if let Some(hook) = &config.progress {
+        hook.report(Progress {
+            stage: ProgressStage::Write,
+            objects: record_rows.len() as u64,
+            objects_total: Some(record_rows.len() as u64),
+            bytes: written,
+            bytes_total: Some(written),
+        });
+    }
+    Ok(written)
 }
 
-fn resolve_handle(
-    allocator: &mut HandleAllocator,
-    requested: Option<u64>,
-    config: &WriterConfig,
-) -> Result<u64> {
-    if config.preserve_input_handles {
-        if let Some(handle) = requested {
-            allocator.reserve(handle)?;
-            return Ok(handle);
+/// Writes sections at increasing offsets, filling the alignment gaps
+/// between them with zeros.
+struct SectionSink<'w, W: Write> {
+    writer: &'w mut W,
+    position: usize,
+}
+
+impl<'w, W: Write> SectionSink<'w, W> {
+    fn new(writer: &'w mut W) -> Self {
+        Self {
+            writer,
+            position: 0,
         }
     }
-    allocator.allocate()
+
+    fn write_at(&mut self, offset: usize, data: &[u8]) -> Result<()> {
+        let Some(gap) = offset.checked_sub(self.position) else {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!(
+                    "section at offset {offset} overlaps data written up to {}",
+                    self.position
+                ),
+            ));
+        };
+        std::io::copy(&mut std::io::repeat(0).take(gap as u64), self.writer)?;
+        self.writer.write_all(data)?;
+        self.position = offset + data.len();
+        Ok(())
+    }
 }
 
-fn align_up(value: usize, align: usize) -> usize {
-    if align == 0 {
-        return value;
+/// Encoded object records, reporting [`ProgressStage::Write`] progress as
+/// they are added.
+struct RecordRows<'c> {
+    rows: Vec<(ObjectRef, Vec<u8>)>,
+    /// Indices into `rows` of the entity records, keyed by the handle the
+    /// entities were given in the document.
+    entity_rows: HashMap<u64, usize>,
+    bytes: u64,
+    objects_total: u64,
+    progress: Option<&'c ProgressHook>,
+}
+
+impl<'c> RecordRows<'c> {
+    fn new(objects_total: u64, config: &'c WriterConfig) -> Self {
+        Self {
+            rows: Vec::with_capacity(objects_total as usize),
+            entity_rows: HashMap::new(),
+            bytes: 0,
+            objects_total,
+            progress: config.progress.as_ref(),
+        }
     }
-    let rem = value % align;
-    if rem == 0 {
-        value
+
+    fn push(&mut self, row: (ObjectRef, Vec<u8>)) {
+        self.bytes += row.1.len() as u64;
+        self.rows.push(row);
+        if let Some(hook) = self.progress {
+            hook.report_throttled(Progress {
+                stage: ProgressStage::Write,
+                objects: self.rows.len() as u64,
+                objects_total: Some(self.objects_total),
+                bytes: self.bytes,
+                bytes_total: None,
+            });
+        }
+    }
+}
+
+/// Number of records [`write_document`] emits for `doc`.
+fn expected_record_count(doc: &WriterDocument, style_records: usize) -> u64 {
+    let layouts: usize = doc
+        .layouts
+        .iter()
+        .map(|layout| layout.viewports.len() + layout.entities.len() + 1)
+        .sum();
+    let model_layout = usize::from(!doc.layouts.is_empty());
+    let groups = doc.groups.len() + usize::from(!doc.groups.is_empty());
+    let dictionaries: usize = doc
+        .dictionaries
+        .iter()
+        .map(|dictionary| dictionary.records.len() + 1)
+        .sum::<usize>()
+        + usize::from(!doc.dictionaries.is_empty());
+    let owned: usize = doc
+        .modelspace
+        .iter()
+        .chain(doc.layouts.iter().flat_map(|layout| &layout.entities))
+        .map(owned_record_count)
+        .sum();
+    (doc.modelspace.len()
+        + owned
+        + layouts
+        + model_layout
+        + style_records
+        + doc.raw_objects.len()
+        + groups
+        + dictionaries) as u64
+}
+
+/// The named objects dictionary, written only when `doc` has application
+/// dictionaries.
+fn named_objects_dictionary(doc: &WriterDocument) -> u64 {
+    if doc.dictionaries.is_empty() {
+        0
     } else {
-        value + (align - rem)
+        NAMED_OBJECTS_DICTIONARY_HANDLE
     }
 }
 
-fn copy_section(dst: &mut [u8], offset: usize, src: &[u8]) -> Result<()> {
-    let end = offset
-        .checked_add(src.len())
-        .ok_or_else(|| DwgError::new(ErrorKind::Format, "section offset overflow"))?;
-    if end > dst.len() {
+/// Handles of the table entries that entity styles refer to.
+#[derive(Debug, Default)]
+struct StyleTables {
+    /// LAYER handles keyed by upper-cased name.
+    layers: HashMap<String, u64>,
+    /// LTYPE handles keyed by upper-cased name.
+    linetypes: HashMap<String, u64>,
+    transparency_app: Option<u64>,
+    /// APPID handles keyed by upper-cased name.
+    app_ids: HashMap<String, u64>,
+    /// MLINESTYLE handles and line counts keyed by upper-cased name.
+    mline_styles: HashMap<String, (u64, usize)>,
+}
+
+impl StyleTables {
+    /// The LAYER an entity is on; an empty name means layer `0`.
+    fn layer(&self, props: &CommonEntityProps) -> u64 {
+        self.layers
+            .get(&props.layer_name.to_ascii_uppercase())
+            .copied()
+            .unwrap_or(DEFAULT_LAYER_HANDLE)
+    }
+
+    fn frozen_layer(&self, name: &str) -> Result<u64> {
+        self.layers
+            .get(&name.to_ascii_uppercase())
+            .copied()
+            .ok_or_else(|| DwgError::new(ErrorKind::Resolve, format!("layer not found: {name}")))
+    }
+
+    fn resolve(&self, props: &CommonEntityProps) -> Result<EntityStyleEncodeInput> {
+        let (ltype_flags, ltype_handle) = match props.linetype_name.as_deref() {
+            None => (0, 0),
+            Some(name) => match name.to_ascii_uppercase().as_str() {
+                "BYLAYER" => (0, 0),
+                "BYBLOCK" => (1, 0),
+                "CONTINUOUS" => (2, 0),
+                key => {
+                    let handle = self.linetypes.get(key).copied().ok_or_else(|| {
+                        DwgError::new(ErrorKind::Resolve, format!("linetype not found: {name}"))
+                    })?;
+                    (3, handle)
+                }
+            },
+        };
+        let line_weight = match props.lineweight {
+            None => LINE_WEIGHT_BY_LAYER_INDEX,
+            Some(value) => line_weight_to_index(value).ok_or_else(|| {
+                DwgError::new(ErrorKind::Format, format!("invalid lineweight: {value}"))
+            })?,
+        };
+        let transparency = match (props.transparency, self.transparency_app) {
+            (None, _) => None,
+            (Some(value), Some(app_handle)) => Some((app_handle, value)),
+            (Some(_), None) => {
+                return Err(DwgError::new(
+                    ErrorKind::Resolve,
+                    "transparency application not registered",
+                ))
+            }
+        };
+        Ok(EntityStyleEncodeInput {
+            ltype_flags,
+            ltype_handle,
+            line_weight,
+            transparency,
+        })
+    }
+
+    /// Appends the extended data of `props` to the encoded entity `payload`.
+    fn with_xdata(
+        &self,
+        payload: Vec<u8>,
+        props: &CommonEntityProps,
+        codepage: u16,
+    ) -> Result<Vec<u8>> {
+        if props.xdata.is_empty() {
+            return Ok(payload);
+        }
+        let mut blocks = Vec::with_capacity(props.xdata.len());
+        for (app_name, items) in &props.xdata {
+            let app_handle = self
+                .app_ids
+                .get(&app_name.to_ascii_uppercase())
+                .copied()
+                .ok_or_else(|| {
+                    DwgError::new(
+                        ErrorKind::Resolve,
+                        format!("application not registered: {app_name}"),
+                    )
+                })?;
+            blocks.push((app_handle, encode_xdata_items(items, codepage)?));
+        }
+        add_entity_xdata(&payload, &blocks)
+    }
+
+    /// Handle and line count of the MLINESTYLE an MLINE refers to.
+    fn mline_style(&self, entity: &WriterEntity) -> Result<Option<(u64, usize)>> {
+        let WriterEntity::MLine(mline) = entity else {
+            return Ok(None);
+        };
+        self.mline_styles
+            .get(&mline.style_name.to_ascii_uppercase())
+            .copied()
+            .map(Some)
+            .ok_or_else(|| {
+                DwgError::new(
+                    ErrorKind::Resolve,
+                    format!("mline style not found: {}", mline.style_name),
+                )
+            })
+    }
+}
+
+/// Iterates the common properties of every entity and viewport in `doc`.
+fn all_entity_props(doc: &WriterDocument) -> impl Iterator<Item = &CommonEntityProps> {
+    doc.modelspace
+        .iter()
+        .map(entity_props)
+        .chain(doc.layouts.iter().flat_map(|layout| {
+            layout
+                .viewports
+                .iter()
+                .map(|viewport| &viewport.common)
+                .chain(layout.entities.iter().map(entity_props))
+        }))
+}
+
+/// LTYPE entries to write and whether an `AcCmTransparency` APPID is
+/// needed. Linetypes referenced by entities or layers but missing from
+/// `doc.linetypes` get an entry without dashes.
+fn style_table_entries(doc: &WriterDocument) -> (Vec<LinetypeDef>, bool) {
+    let mut linetypes: Vec<LinetypeDef> = Vec::new();
+    let mut push_unique = |def: LinetypeDef| {
+        let key = def.name.to_ascii_uppercase();
+        let builtin = matches!(key.as_str(), "BYLAYER" | "BYBLOCK" | "CONTINUOUS");
+        if !builtin
+            && !linetypes
+                .iter()
+                .any(|known| known.name.eq_ignore_ascii_case(&key))
+        {
+            linetypes.push(def);
+        }
+    };
+    for def in &doc.linetypes {
+        push_unique(def.clone());
+    }
+    let layer_linetypes = doc
+        .layers
+        .iter()
+        .filter_map(|def| def.linetype_name.as_ref());
+    for name in all_entity_props(doc)
+        .filter_map(|props| props.linetype_name.as_ref())
+        .chain(layer_linetypes)
+    {
+        push_unique(LinetypeDef {
+            name: name.clone(),
+            ..LinetypeDef::default()
+        });
+    }
+    let needs_transparency_app = all_entity_props(doc).any(|props| props.transparency.is_some());
+    (linetypes, needs_transparency_app)
+}
+
+/// APPID entries to write, as names and the strings of their extended
+/// data: `AcCmTransparency` when entities are transparent, the writing
+/// application, then every other application entity extended data is
+/// registered under.
+fn app_id_entries(
+    doc: &WriterDocument,
+    config: &WriterConfig,
+    needs_transparency_app: bool,
+) -> Vec<(String, Vec<String>)> {
+    let mut entries: Vec<(String, Vec<String>)> = Vec::new();
+    let mut push_unique = |name: &str, xdata_strings: Vec<String>| {
+        if !entries
+            .iter()
+            .any(|(known, _)| known.eq_ignore_ascii_case(name))
+        {
+            entries.push((name.to_string(), xdata_strings));
+        }
+    };
+    if needs_transparency_app {
+        push_unique(TRANSPARENCY_APP_NAME, Vec::new());
+    }
+    if let Some(name) = &config.application_name {
+        push_unique(name, config.application_version.iter().cloned().collect());
+    }
+    for (name, _) in all_entity_props(doc).flat_map(|props| &props.xdata) {
+        push_unique(name, Vec::new());
+    }
+    entries
+}
+
+/// LAYER entries to write: layer `0`, the layers of `doc`, then a layer
+/// with the default color for every other name an entity is on.
+fn layer_entries(doc: &WriterDocument) -> Vec<LayerDef> {
+    let mut layers = vec![LayerDef::default()];
+    for def in &doc.layers {
+        match layers
+            .iter_mut()
+            .find(|known| known.name.eq_ignore_ascii_case(&def.name))
+        {
+            Some(known) => {
+                known.color_index = def.color_index;
+                known.linetype_name.clone_from(&def.linetype_name);
+                known.flags = def.flags;
+                known.is_off = def.is_off;
+            }
+            None => layers.push(def.clone()),
+        }
+    }
+    for props in all_entity_props(doc) {
+        if !props.layer_name.is_empty()
+            && !layers
+                .iter()
+                .any(|known| known.name.eq_ignore_ascii_case(&props.layer_name))
+        {
+            layers.push(LayerDef {
+                name: props.layer_name.clone(),
+                ..LayerDef::default()
+            });
+        }
+    }
+    layers
+}
+
+/// MLINESTYLE entries to write: those of `doc`, then a default style for
+/// every other name an MLINE refers to.
+fn mline_style_entries(doc: &WriterDocument) -> Vec<MLineStyleDef> {
+    let mut styles: Vec<MLineStyleDef> = Vec::new();
+    let mut push_unique = |def: MLineStyleDef| {
+        if !styles
+            .iter()
+            .any(|known| known.name.eq_ignore_ascii_case(&def.name))
+        {
+            styles.push(def);
+        }
+    };
+    for def in &doc.mline_styles {
+        push_unique(def.clone());
+    }
+    let entities = doc
+        .modelspace
+        .iter()
+        .chain(doc.layouts.iter().flat_map(|layout| &layout.entities));
+    for entity in entities {
+        if let WriterEntity::MLine(mline) = entity {
+            push_unique(MLineStyleDef {
+                name: mline.style_name.clone(),
+                ..MLineStyleDef::default()
+            });
+        }
+    }
+    styles
+}
+
+fn encode_style_tables(
+    allocator: &mut HandleAllocator,
+    layers: &[LayerDef],
+    linetypes: &[LinetypeDef],
+    app_ids: &[(String, Vec<String>)],
+    mline_styles: &[MLineStyleDef],
+    config: &WriterConfig,
+    record_rows: &mut RecordRows<'_>,
+) -> Result<StyleTables> {
+    let mut tables = StyleTables::default();
+    // Handles are allocated layers first, but layers refer to linetypes, so
+    // both are allocated before either table is encoded.
+    let layer_handles = layers
+        .iter()
+        .map(|def| {
+            if def.name == "0" {
+                Ok(DEFAULT_LAYER_HANDLE)
+            } else {
+                allocator.allocate_in(HandleNamespace::Tables)
+            }
+        })
+        .collect::<Result<Vec<u64>>>()?;
+    let linetype_handles = linetypes
+        .iter()
+        .map(|_| allocator.allocate_in(HandleNamespace::Tables))
+        .collect::<Result<Vec<u64>>>()?;
+    for (def, handle) in linetypes.iter().zip(&linetype_handles) {
+        tables
+            .linetypes
+            .insert(def.name.to_ascii_uppercase(), *handle);
+    }
+    for (def, handle) in layers.iter().zip(layer_handles) {
+        let linetype_handle = match def.linetype_name.as_deref() {
+            None => 0,
+            Some(name) => match name.to_ascii_uppercase().as_str() {
+                "CONTINUOUS" => 0,
+                key => tables.linetypes.get(key).copied().ok_or_else(|| {
+                    DwgError::new(
+                        ErrorKind::Resolve,
+                        format!("invalid linetype for layer {}: {name}", def.name),
+                    )
+                })?,
+            },
+        };
+        let input = LayerEncodeInput {
+            handle,
+            owner_handle: LAYER_CONTROL_HANDLE,
+            name: def.name.clone(),
+            color_index: def.color_index,
+            flags: crate::objects::layer_flags_from_dxf(def.flags, def.is_off),
+            linetype_handle,
+        };
+        record_rows.push(object_row(handle, &encode_layer_object_payload(&input)?)?);
+        tables.layers.insert(def.name.to_ascii_uppercase(), handle);
+    }
+    tables.layers.insert(String::new(), DEFAULT_LAYER_HANDLE);
+    for (def, handle) in linetypes.iter().zip(linetype_handles) {
+        let input = LtypeEncodeInput {
+            handle,
+            owner_handle: LTYPE_CONTROL_HANDLE,
+            name: def.name.clone(),
+            description: def.description.clone(),
+            pattern_length: def.pattern_length(),
+            dashes: def.dashes.clone(),
+        };
+        record_rows.push(object_row(
+            input.handle,
+            &encode_ltype_object_payload(&input)?,
+        )?);
+    }
+    for (name, xdata_strings) in app_ids {
+        let handle = allocator.allocate_in(HandleNamespace::Tables)?;
+        let payload = encode_app_id_object_payload(&AppIdEncodeInput {
+            handle,
+            owner_handle: APPID_CONTROL_HANDLE,
+            name: name.clone(),
+            xdata_strings: xdata_strings.clone(),
+            codepage: config.codepage,
+        })?;
+        record_rows.push(object_row(handle, &payload)?);
+        tables.app_ids.insert(name.to_ascii_uppercase(), handle);
+    }
+    tables.transparency_app = tables
+        .app_ids
+        .get(&TRANSPARENCY_APP_NAME.to_ascii_uppercase())
+        .copied();
+    for def in mline_styles {
+        let input = MLineStyleEncodeInput {
+            handle: allocator.allocate_in(HandleNamespace::Tables)?,
+            owner_handle: MLINESTYLE_DICTIONARY_HANDLE,
+            name: def.name.clone(),
+            description: def.description.clone(),
+            flags: def.flags,
+            fill_color_index: def.fill_color_index,
+            start_angle: def.start_angle_rad,
+            end_angle: def.end_angle_rad,
+            elements: def
+                .elements
+                .iter()
+                .map(|element| (element.offset, element.color_index))
+                .collect(),
+        };
+        record_rows.push(object_row(
+            input.handle,
+            &encode_mline_style_object_payload(&input)?,
+        )?);
+        tables.mline_styles.insert(
+            def.name.to_ascii_uppercase(),
+            (input.handle, def.elements.len()),
+        );
+    }
+    Ok(tables)
+}
+
+/// Encodes `entities` owned by `owner_handle` in order, each followed by
+/// the records it owns.
+///
+/// Handles are allocated up front on the calling thread, so the output does
+/// not depend on the thread count. The payloads are independent of each
+/// other and are then encoded on up to [`WriterConfig::encode_threads`]
+/// workers, each taking a contiguous chunk; rows are pushed in input order
+/// and the first error in input order is returned.
+fn encode_entity_records(
+    allocator: &mut HandleAllocator,
+    entities: &[WriterEntity],
+    owner_handle: u64,
+    styles: &StyleTables,
+    config: &WriterConfig,
+    record_rows: &mut RecordRows<'_>,
+) -> Result<()> {
+    let handles = entities
+        .iter()
+        .map(|entity| {
+            let handle = resolve_handle(
+                allocator,
+                HandleNamespace::Entities,
+                entity_props(entity).handle,
+                config,
+            )?;
+            let first_owned_handle = match owned_record_count(entity) {
+                0 => 0,
+                count => allocator.allocate_run_in(HandleNamespace::Entities, count as u64)?,
+            };
+            Ok((handle, first_owned_handle))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let jobs: Vec<(&WriterEntity, (u64, u64))> = entities.iter().zip(handles).collect();
+    let encode = |(entity, (handle, first_owned_handle)): &(&WriterEntity, (u64, u64))| {
+        encode_entity_rows(
+            entity,
+            *handle,
+            *first_owned_handle,
+            owner_handle,
+            styles,
+            config.codepage,
+        )
+    };
+
+    let threads = encode_thread_count(config, jobs.len());
+    let rows: Vec<Result<Vec<(ObjectRef, Vec<u8>)>>> = if threads <= 1 {
+        jobs.iter().map(encode).collect()
+    } else {
+        let chunk_size = jobs.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = jobs
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || chunk.iter().map(encode).collect::<Vec<_>>()))
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker.join().unwrap_or_else(|_| {
+                        vec![Err(DwgError::new(
+                            ErrorKind::Format,
+                            "entity encode worker panicked",
+                        ))]
+                    })
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .flatten()
+                .collect()
+        })
+    };
+    if rows.len() != jobs.len() {
         return Err(DwgError::new(
             ErrorKind::Format,
-            format!(
-                "section write out of bounds: offset={offset} len={}",
-                src.len()
-            ),
+            "entity encode worker panicked",
         ));
     }
-    dst[offset..end].copy_from_slice(src);
+
+    for ((entity, _), entity_rows) in jobs.iter().zip(rows) {
+        let entity_rows = entity_rows?;
+        if let Some(requested) = entity_props(entity).handle {
+            record_rows
+                .entity_rows
+                .entry(requested)
+                .or_insert(record_rows.rows.len());
+        }
+        for row in entity_rows {
+            record_rows.push(row);
+        }
+    }
     Ok(())
 }
 
-fn write_section_record(
-    bytes: &mut [u8],
-    offset: usize,
-    record_no: u8,
-    section_offset: u32,
-    section_size: u32,
-) {
-    bytes[offset] = record_no;
-    write_u32_le(bytes, offset + 1, section_offset);
-    write_u32_le(bytes, offset + 5, section_size);
+/// Records `entity` owns besides its own, written on consecutive handles
+/// after it.
+fn owned_record_count(entity: &WriterEntity) -> usize {
+    match entity {
+        WriterEntity::PolyfaceMesh(mesh) => {
+            polyface_owned_record_count(mesh.vertices.len(), mesh.faces.len())
+        }
+        _ => 0,
+    }
 }
 
-fn write_u16_le(bytes: &mut [u8], offset: usize, value: u16) {
-    bytes[offset] = (value & 0x00FF) as u8;
-    bytes[offset + 1] = (value >> 8) as u8;
+/// Worker threads used to encode `jobs` entity payloads.
+fn encode_thread_count(config: &WriterConfig, jobs: usize) -> usize {
+    if jobs < PARALLEL_ENCODE_MIN_ENTITIES {
+        return 1;
+    }
+    let threads = config
+        .encode_threads
+        .or_else(|| std::thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1);
+    // Small chunks cost more in thread start-up than they save.
+    threads
+        .min(jobs / (PARALLEL_ENCODE_MIN_ENTITIES / 4))
+        .max(1)
 }
 
-fn write_u32_le(bytes: &mut [u8], offset: usize, value: u32) {
-    bytes[offset] = (value & 0x0000_00FF) as u8;
-    bytes[offset + 1] = ((value >> 8) & 0x0000_00FF) as u8;
-    bytes[offset + 2] = ((value >> 16) & 0x0000_00FF) as u8;
-    bytes[offset + 3] = ((value >> 24) & 0x0000_00FF) as u8;
+/// The record of `entity` followed by the records it owns, which take the
+/// handles from `first_owned_handle` on.
+fn encode_entity_rows(
+    entity: &WriterEntity,
+    handle: u64,
+    first_owned_handle: u64,
+    owner_handle: u64,
+    styles: &StyleTables,
+    codepage: u16,
+) -> Result<Vec<(ObjectRef, Vec<u8>)>> {
+    let props = entity_props(entity);
+    if let WriterEntity::PolyfaceMesh(mesh) = entity {
+        let payloads = encode_polyface_mesh_payloads(&PolyfaceMeshEncodeInput {
+            handle,
+            first_owned_handle,
+            owner_handle,
+            layer_handle: styles.layer(props),
+            color_index: mesh.common.color_index.unwrap_or(7),
+            style: styles.resolve(props)?,
+            vertices: mesh.vertices.clone(),
+            faces: mesh.faces.clone(),
+        })?;
+        return payloads
+            .into_iter()
+            .map(|(record_handle, payload)| {
+                let payload = if record_handle == handle {
+                    styles.with_xdata(payload, props, codepage)?
+                } else {
+                    payload
+                };
+                object_row(record_handle, &payload)
+            })
+            .collect();
+    }
+    let payload = encode_writer_entity(
+        entity,
+        handle,
+        owner_handle,
+        styles.layer(props),
+        styles.resolve(props)?,
+        styles.mline_style(entity)?,
+    )?;
+    let payload = styles.with_xdata(payload, props, codepage)?;
+    Ok(vec![object_row(handle, &payload)?])
 }
 
-#[cfg(test)]
-mod tests {
-    use super::write_document;
-    use crate::core::config::ParseConfig;
-    use crate::dwg::decoder::Decoder;
-    use crate::dwg::version::{detect_version, DwgVersion};
-    use crate::entities::{
-        decode_arc, decode_circle, decode_line, decode_lwpolyline, decode_mtext, decode_point,
-        decode_ray, decode_text, decode_xline,
+/// `mline_style` is the handle and line count of the MLINESTYLE of an
+/// MLINE entity and is ignored for other entities.
+fn encode_writer_entity(
+    entity: &WriterEntity,
+    handle: u64,
+    owner_handle: u64,
+    layer_handle: u64,
+    style: EntityStyleEncodeInput,
+    mline_style: Option<(u64, usize)>,
+) -> Result<Vec<u8>> {
+    let payload = match entity {
+        WriterEntity::Line(line) => encode_line_entity_payload(LineEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: line.common.color_index.unwrap_or(7),
+            style,
+            start: line.start,
+            end: line.end,
+        })?,
+        WriterEntity::Point(point) => encode_point_entity_payload(PointEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: point.common.color_index.unwrap_or(7),
+            style,
+            location: point.location,
+            x_axis_angle: point.x_axis_angle,
+        })?,
+        WriterEntity::Ray(ray) => encode_ray_entity_payload(RayEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: ray.common.color_index.unwrap_or(7),
+            style,
+            start: ray.start,
+            unit_vector: ray.unit_vector,
+        })?,
+        WriterEntity::XLine(xline) => encode_xline_entity_payload(XLineEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: xline.common.color_index.unwrap_or(7),
+            style,
+            start: xline.start,
+            unit_vector: xline.unit_vector,
+        })?,
+        WriterEntity::Arc(arc) => encode_arc_entity_payload(ArcEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: arc.common.color_index.unwrap_or(7),
+            style,
+            center: arc.center,
+            radius: arc.radius,
+            angle_start: arc.angle_start_rad,
+            angle_end: arc.angle_end_rad,
+        })?,
+        WriterEntity::Circle(circle) => encode_circle_entity_payload(CircleEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: circle.common.color_index.unwrap_or(7),
+            style,
+            center: circle.center,
+            radius: circle.radius,
+        })?,
+        WriterEntity::LwPolyline(poly) => {
+            encode_lwpolyline_entity_payload(LwPolylineEncodeInput {
+                handle,
+                owner_handle,
+                layer_handle,
+                color_index: poly.common.color_index.unwrap_or(7),
+                style,
+                flags: poly.flags,
+                vertices: poly.vertices.clone(),
+                const_width: poly.const_width,
+                bulges: poly.bulges.clone(),
+                widths: poly.widths.clone(),
+            })?
+        }
+        WriterEntity::Text(text) => encode_text_entity_payload(&TextEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: text.common.color_index.unwrap_or(7),
+            style,
+            text: text.text.clone(),
+            insertion: text.insert,
+            height: text.height,
+            rotation: text.rotation_rad,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            alignment: text.align_point.map(|(x, y, _)| (x, y)),
+            width_factor: text.width_factor.unwrap_or(1.0),
+            oblique: text.oblique_rad,
+        })?,
+        WriterEntity::MText(mtext) => encode_mtext_entity_payload(&MTextEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: mtext.common.color_index.unwrap_or(7),
+            style,
+            text: mtext.text.clone(),
+            insertion: mtext.insert,
+            text_direction: mtext.text_direction,
+            rect_width: mtext.rect_width,
+            text_height: mtext.char_height,
+            attachment: mtext.attachment_point,
+            drawing_dir: mtext.drawing_direction,
+        })?,
+        WriterEntity::Tolerance(tolerance) => {
+            encode_tolerance_entity_payload(&ToleranceEncodeInput {
+                handle,
+                owner_handle,
+                layer_handle,
+                color_index: tolerance.common.color_index.unwrap_or(7),
+                style,
+                text: tolerance.text.clone(),
+                insertion: tolerance.insert,
+                x_direction: tolerance.x_direction,
+                height: tolerance.height,
+                dimgap: tolerance.dimgap,
+            })?
+        }
+        WriterEntity::Leader(leader) => encode_leader_entity_payload(&LeaderEncodeInput {
+            handle,
+            owner_handle,
+            layer_handle,
+            color_index: leader.common.color_index.unwrap_or(7),
+            style,
+            annotation_type: leader.annotation_type,
+            path_type: leader.path_type,
+            points: leader.points.clone(),
+        })?,
+        WriterEntity::MLine(mline) => {
+            let (mline_style_handle, lines_in_style) = mline_style.ok_or_else(|| {
+                DwgError::new(
+                    ErrorKind::Resolve,
+                    format!("mline style not resolved: {}", mline.style_name),
+                )
+            })?;
+            encode_mline_entity_payload(&MLineEncodeInput {
+                handle,
+                owner_handle,
+                layer_handle,
+                color_index: mline.common.color_index.unwrap_or(7),
+                style,
+                mline_style_handle,
+                lines_in_style,
+                scale: mline.scale,
+                justification: mline.justification,
+                closed: mline.closed,
+                vertices: mline
+                    .vertices
+                    .iter()
+                    .map(|vertex| MLineVertexEncodeInput {
+                        position: vertex.position,
+                        vertex_direction: vertex.vertex_direction,
+                        miter_direction: vertex.miter_direction,
+                        segment_params: vertex.segment_params.clone(),
+                    })
+                    .collect(),
+            })?
+        }
+        WriterEntity::PolyfaceMesh(_) => {
+            return Err(DwgError::new(
+                ErrorKind::Unsupported,
+                "polyface meshes are only written to new drawings",
+            ))
+        }
     };
-    use crate::objects::object_header_r2000;
-    use crate::writer::config::WriterConfig;
-    use crate::writer::ir::{
-        ArcEntity, CircleEntity, CommonEntityProps, LineEntity, LwPolylineEntity, MTextEntity,
-        PointEntity, RayEntity, TextEntity, WriterDocument, WriterEntity, XLineEntity,
+    Ok(payload)
+}
+
+/// Emits a model LAYOUT followed by each paperspace layout, its viewports
+/// and its entities. Nothing is written for documents without layouts.
+fn encode_layout_records(
+    allocator: &mut HandleAllocator,
+    doc: &WriterDocument,
+    styles: &StyleTables,
+    config: &WriterConfig,
+    record_rows: &mut RecordRows<'_>,
+) -> Result<()> {
+    if doc.layouts.is_empty() {
+        return Ok(());
+    }
+
+    let mut model_settings = LayoutDef::default().plot_settings;
+    model_settings.flags |= 1024; // model type
+    model_settings.plot_type = 1;
+    model_settings.scale_type = 0;
+    let model = LayoutEncodeInput {
+        handle: allocator.allocate_in(HandleNamespace::Blocks)?,
+        owner_handle: LAYOUT_DICTIONARY_HANDLE,
+        name: "Model".to_string(),
+        tab_order: 0,
+        flags: 0,
+        plot_settings: model_settings,
+        limits_min: (0.0, 0.0),
+        limits_max: (420.0, 297.0),
+        extents_min: doc.metadata.ext_min.unwrap_or((0.0, 0.0, 0.0)),
+        extents_max: doc.metadata.ext_max.unwrap_or((0.0, 0.0, 0.0)),
+        block_record_handle: MODELSPACE_BLOCK_RECORD_HANDLE,
+        last_viewport_handle: 0,
     };
+    record_rows.push(object_row(
+        model.handle,
+        &encode_layout_object_payload(&model)?,
+    )?);
 
-    #[test]
-    fn writes_minimal_r2000_line_document() {
-        let doc = WriterDocument {
-            version: DwgVersion::R2000,
-            modelspace: vec![WriterEntity::Line(LineEntity {
-                common: CommonEntityProps {
-                    handle: Some(0x30),
-                    layer_name: "0".to_string(),
-                    color_index: Some(7),
-                    true_color: None,
-                },
-                start: (1.0, 2.0, 0.0),
-                end: (4.5, 7.0, 0.0),
-            })],
-            ..WriterDocument::default()
-        };
+    for (index, layout) in doc.layouts.iter().enumerate() {
+        let block_record_handle = allocator.allocate_in(HandleNamespace::Blocks)?;
+        let mut last_viewport_handle = 0;
+        let mut extents: Option<((f64, f64, f64), (f64, f64, f64))> = None;
+        for viewport in &layout.viewports {
+            let handle = resolve_handle(
+                allocator,
+                HandleNamespace::Entities,
+                viewport.common.handle,
+                config,
+            )?;
+            let frozen_layer_handles = viewport
+                .frozen_layers
+                .iter()
+                .map(|name| styles.frozen_layer(name))
+                .collect::<Result<Vec<_>>>()?;
+            let payload = encode_viewport_entity_payload(ViewportEncodeInput {
+                handle,
+                owner_handle: block_record_handle,
+                layer_handle: styles.layer(&viewport.common),
+                color_index: viewport.common.color_index.unwrap_or(7),
+                style: styles.resolve(&viewport.common)?,
+                center: viewport.center,
+                width: viewport.width,
+                height: viewport.height,
+                view_center: viewport.view_center,
+                view_target: viewport.view_target,
+                view_direction: viewport.view_direction,
+                view_height: viewport.view_height,
+                twist_angle: viewport.twist_angle_rad,
+                lens_length: viewport.lens_length,
+                status_flags: viewport.status_flags,
+                frozen_layer_handles: &frozen_layer_handles,
+            })?;
+            let payload = styles.with_xdata(payload, &viewport.common, config.codepage)?;
+            record_rows.push(object_row(handle, &payload)?);
+            last_viewport_handle = handle;
 
-        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
-        assert_eq!(
-            detect_version(&bytes).expect("detect version"),
-            DwgVersion::R2000
-        );
+            let (cx, cy, cz) = viewport.center;
+            let (half_w, half_h) = (viewport.width / 2.0, viewport.height / 2.0);
+            let (lo, hi) = extents.unwrap_or((
+                (f64::INFINITY, f64::INFINITY, cz),
+                (f64::NEG_INFINITY, f64::NEG_INFINITY, cz),
+            ));
+            extents = Some((
+                (lo.0.min(cx - half_w), lo.1.min(cy - half_h), lo.2.min(cz)),
+                (hi.0.max(cx + half_w), hi.1.max(cy + half_h), hi.2.max(cz)),
+            ));
+        }
+        encode_entity_records(
+            allocator,
+            &layout.entities,
+            block_record_handle,
+            styles,
+            config,
+            record_rows,
+        )?;
 
-        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
-        let index = decoder.build_object_index().expect("object index");
-        assert_eq!(index.len(), 1);
-        let obj_ref = index.objects[0];
-        assert_eq!(obj_ref.handle.0, 0x30);
+        let (extents_min, extents_max) = extents.unwrap_or(((0.0, 0.0, 0.0), (0.0, 0.0, 0.0)));
+        let input = LayoutEncodeInput {
+            handle: allocator.allocate_in(HandleNamespace::Blocks)?,
+            owner_handle: LAYOUT_DICTIONARY_HANDLE,
+            name: layout.name.clone(),
+            tab_order: index as u32 + 1,
+            flags: 1, // PSLTSCALE
+            plot_settings: layout.plot_settings.clone(),
+            limits_min: layout.limits_min,
+            limits_max: layout.limits_max,
+            extents_min,
+            extents_max,
+            block_record_handle,
+            last_viewport_handle,
+        };
+        record_rows.push(object_row(
+            input.handle,
+            &encode_layout_object_payload(&input)?,
+        )?);
+    }
+    Ok(())
+}
 
-        let record = decoder
-            .parse_object_record(obj_ref.offset)
-            .expect("parse object record");
-        let header = object_header_r2000::parse_from_record(&record).expect("object header");
-        assert_eq!(header.type_code, 0x13);
+fn object_row(handle: u64, payload: &[u8]) -> Result<(ObjectRef, Vec<u8>)> {
+    let record = encode_object_record(payload)?;
+    Ok((
+        ObjectRef {
+            handle: Handle(handle),
+            offset: 0,
+        },
+        record,
+    ))
+}
 
-        let mut reader = record.bit_reader();
-        let type_code = reader.read_bs().expect("read type prefix");
-        assert_eq!(type_code, 0x13);
-        let line = decode_line(&mut reader).expect("decode line");
+fn entity_props(entity: &WriterEntity) -> &CommonEntityProps {
+    match entity {
+        WriterEntity::Line(line) => &line.common,
+        WriterEntity::Point(point) => &point.common,
+        WriterEntity::Ray(ray) => &ray.common,
+        WriterEntity::XLine(xline) => &xline.common,
+        WriterEntity::Arc(arc) => &arc.common,
+        WriterEntity::Circle(circle) => &circle.common,
+        WriterEntity::LwPolyline(poly) => &poly.common,
+        WriterEntity::Text(text) => &text.common,
+        WriterEntity::MText(mtext) => &mtext.common,
+        WriterEntity::Tolerance(tolerance) => &tolerance.common,
+        WriterEntity::Leader(leader) => &leader.common,
+        WriterEntity::MLine(mline) => &mline.common,
+        WriterEntity::PolyfaceMesh(mesh) => &mesh.common,
+    }
+}
+
+pub(crate) fn entity_props_mut(entity: &mut WriterEntity) -> &mut CommonEntityProps {
+    match entity {
+        WriterEntity::Line(line) => &mut line.common,
+        WriterEntity::Point(point) => &mut point.common,
+        WriterEntity::Ray(ray) => &mut ray.common,
+        WriterEntity::XLine(xline) => &mut xline.common,
+        WriterEntity::Arc(arc) => &mut arc.common,
+        WriterEntity::Circle(circle) => &mut circle.common,
+        WriterEntity::LwPolyline(poly) => &mut poly.common,
+        WriterEntity::Text(text) => &mut text.common,
+        WriterEntity::MText(mtext) => &mut mtext.common,
+        WriterEntity::Tolerance(tolerance) => &mut tolerance.common,
+        WriterEntity::Leader(leader) => &mut leader.common,
+        WriterEntity::MLine(mline) => &mut mline.common,
+        WriterEntity::PolyfaceMesh(mesh) => &mut mesh.common,
+    }
+}
+
+/// Reserves every handle requested by the document up front so handles
+/// allocated for layouts and block records never collide with them. With a
+/// handle layout, a requested handle among the fixed handles is an error.
+fn reserve_input_handles(allocator: &mut HandleAllocator, doc: &WriterDocument) -> Result<()> {
+    for handle in input_handles(doc) {
+        allocator.reserve(handle)?;
+    }
+    Ok(())
+}
+
+/// Handles requested by the entities of the document.
+fn input_handles(doc: &WriterDocument) -> impl Iterator<Item = u64> + '_ {
+    let layout_handles = doc.layouts.iter().flat_map(|layout| {
+        layout
+            .viewports
+            .iter()
+            .map(|viewport| viewport.common.handle)
+            .chain(
+                layout
+                    .entities
+                    .iter()
+                    .map(|entity| entity_props(entity).handle),
+            )
+    });
+    doc.modelspace
+        .iter()
+        .map(|entity| entity_props(entity).handle)
+        .chain(layout_handles)
+        .flatten()
+}
+
+/// The classes of the raw objects, numbered from 500 in order of first
+/// use, and the type code each raw object is written with.
+fn raw_object_classes(doc: &WriterDocument) -> Result<(Vec<ClassDefinition>, Vec<Option<u16>>)> {
+    let mut classes: Vec<ClassDefinition> = Vec::new();
+    let mut type_codes = Vec::with_capacity(doc.raw_objects.len());
+    for raw in doc.raw_objects.iter() {
+        let Some(class) = &raw.class else {
+            type_codes.push(None);
+            continue;
+        };
+        let position = match classes
+            .iter()
+            .position(|known| known.dxf_name.eq_ignore_ascii_case(&class.dxf_name))
+        {
+            Some(position) => position,
+            None => {
+                classes.push(class.clone());
+                classes.len() - 1
+            }
+        };
+        let class_number = u16::try_from(500 + position).map_err(|_| {
+            DwgError::new(ErrorKind::Unsupported, "too many classes for raw objects")
+        })?;
+        if let Some(class) = classes.get_mut(position) {
+            class.class_number = class_number;
+        }
+        type_codes.push(Some(class_number));
+    }
+    Ok((classes, type_codes))
+}
+
+/// Output handles of the raw objects, in document order. A raw object
+/// keeps its source handle when input handles are preserved and the handle
+/// is free and above the fixed handles of the writer.
+fn raw_object_handles(
+    allocator: &mut HandleAllocator,
+    doc: &WriterDocument,
+    config: &WriterConfig,
+) -> Result<Vec<u64>> {
+    let mut handles: Vec<Option<u64>> = Vec::with_capacity(doc.raw_objects.len());
+    for raw in doc.raw_objects.iter() {
+        let keep = config.preserve_input_handles
+            && raw.handle >= FIRST_FREE_HANDLE
+            && allocator.reserve(raw.handle).is_ok();
+        handles.push(keep.then_some(raw.handle));
+    }
+    handles
+        .into_iter()
+        .map(|handle| match handle {
+            Some(handle) => Ok(handle),
+            None => allocator.allocate_in(HandleNamespace::Dictionaries),
+        })
+        .collect()
+}
+
+/// Encodes the raw objects under `handles`. References to other raw
+/// objects follow them to their output handles and references to
+/// preserved entity handles are kept; all other references are cleared.
+fn encode_raw_objects(
+    doc: &WriterDocument,
+    handles: &[u64],
+    type_codes: &[Option<u16>],
+    config: &WriterConfig,
+    record_rows: &mut RecordRows<'_>,
+) -> Result<()> {
+    let mut remapped: HashMap<u64, u64> = HashMap::with_capacity(handles.len());
+    for (raw, &handle) in doc.raw_objects.iter().zip(handles) {
+        remapped.entry(raw.handle).or_insert(handle);
+    }
+    let kept: HashSet<u64> = if config.preserve_input_handles {
+        input_handles(doc).collect()
+    } else {
+        HashSet::new()
+    };
+    let remap = |value: u64| match remapped.get(&value) {
+        Some(&handle) => handle,
+        None if kept.contains(&value) => value,
+        None => 0,
+    };
+    for ((raw, &handle), &type_code) in doc.raw_objects.iter().zip(handles).zip(type_codes) {
+        let payload = remap_raw_object_record(&raw.data, type_code, handle, remap)?;
+        record_rows.push(object_row(handle, &payload)?);
+    }
+    Ok(())
+}
+
+/// Emits the `ACAD_GROUP` dictionary and a GROUP for each group of `doc`,
+/// and adds the groups to the persistent reactors of their members. Must
+/// run after every entity record has been pushed to `record_rows`.
+fn encode_group_records(
+    allocator: &mut HandleAllocator,
+    doc: &WriterDocument,
+    record_rows: &mut RecordRows<'_>,
+) -> Result<()> {
+    if doc.groups.is_empty() {
+        return Ok(());
+    }
+    let mut names = HashSet::with_capacity(doc.groups.len());
+    let mut entries = Vec::with_capacity(doc.groups.len());
+    let mut reactors: BTreeMap<usize, Vec<u64>> = BTreeMap::new();
+    for group in &doc.groups {
+        if group.name.is_empty() || !names.insert(group.name.to_ascii_uppercase()) {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!("group names must be unique and non-empty: {:?}", group.name),
+            ));
+        }
+        let handle = allocator.allocate_in(HandleNamespace::Dictionaries)?;
+        let mut entities = Vec::with_capacity(group.entities.len());
+        for member in &group.entities {
+            let written = record_rows.entity_rows.get(member).and_then(|&row| {
+                let (obj_ref, _) = record_rows.rows.get(row)?;
+                Some((row, obj_ref.handle.0))
+            });
+            let Some((row, written)) = written else {
+                return Err(DwgError::new(
+                    ErrorKind::Resolve,
+                    format!(
+                        "group {:?} member {member:#X} is not an entity of the document",
+                        group.name
+                    ),
+                ));
+            };
+            entities.push(written);
+            let row_reactors = reactors.entry(row).or_default();
+            if !row_reactors.contains(&handle) {
+                row_reactors.push(handle);
+            }
+        }
+        let payload = encode_group_object_payload(&GroupEncodeInput {
+            handle,
+            owner_handle: GROUP_DICTIONARY_HANDLE,
+            description: group.description.clone(),
+            unnamed: group.name.starts_with('*'),
+            selectable: group.selectable,
+            entities,
+        })?;
+        record_rows.push(object_row(handle, &payload)?);
+        entries.push((group.name.clone(), handle));
+    }
+    let payload = encode_dictionary_object_payload(&DictionaryEncodeInput {
+        handle: GROUP_DICTIONARY_HANDLE,
+        owner_handle: named_objects_dictionary(doc),
+        entries,
+    })?;
+    record_rows.push(object_row(GROUP_DICTIONARY_HANDLE, &payload)?);
+
+    for (row, reactors) in reactors {
+        if let Some((_, record)) = record_rows.rows.get_mut(row) {
+            let data = add_entity_reactors(&parse_object_record(record, 0)?.body, &reactors)?;
+            *record = encode_object_record(&data)?;
+        }
+    }
+    Ok(())
+}
+
+/// Emits the named objects dictionary with a dictionary of XRECORDs for
+/// each application dictionary of `doc`, and `ACAD_GROUP` when `doc` has
+/// groups.
+fn encode_dictionary_records(
+    allocator: &mut HandleAllocator,
+    doc: &WriterDocument,
+    config: &WriterConfig,
+    record_rows: &mut RecordRows<'_>,
+) -> Result<()> {
+    if doc.dictionaries.is_empty() {
+        return Ok(());
+    }
+    let mut entries = Vec::with_capacity(doc.dictionaries.len() + 1);
+    if !doc.groups.is_empty() {
+        entries.push(("ACAD_GROUP".to_string(), GROUP_DICTIONARY_HANDLE));
+    }
+    for dictionary in &doc.dictionaries {
+        let unique = !dictionary.name.is_empty()
+            && !entries
+                .iter()
+                .any(|(known, _)| known.eq_ignore_ascii_case(&dictionary.name));
+        if !unique {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!(
+                    "dictionary names must be unique and non-empty: {:?}",
+                    dictionary.name
+                ),
+            ));
+        }
+        let handle = allocator.allocate_in(HandleNamespace::Dictionaries)?;
+        let mut records = Vec::with_capacity(dictionary.records.len());
+        for (key, items) in &dictionary.records {
+            let unique = !key.is_empty()
+                && !records
+                    .iter()
+                    .any(|(known, _): &(String, u64)| known.eq_ignore_ascii_case(key));
+            if !unique {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!(
+                        "dictionary {:?} keys must be unique and non-empty: {key:?}",
+                        dictionary.name
+                    ),
+                ));
+            }
+            let record_handle = allocator.allocate_in(HandleNamespace::Dictionaries)?;
+            let payload = encode_xrecord_object_payload(&XRecordEncodeInput {
+                handle: record_handle,
+                owner_handle: handle,
+                items: items.clone(),
+                codepage: config.codepage,
+            })?;
+            record_rows.push(object_row(record_handle, &payload)?);
+            records.push((key.clone(), record_handle));
+        }
+        let payload = encode_dictionary_object_payload(&DictionaryEncodeInput {
+            handle,
+            owner_handle: NAMED_OBJECTS_DICTIONARY_HANDLE,
+            entries: records,
+        })?;
+        record_rows.push(object_row(handle, &payload)?);
+        entries.push((dictionary.name.clone(), handle));
+    }
+    let payload = encode_dictionary_object_payload(&DictionaryEncodeInput {
+        handle: NAMED_OBJECTS_DICTIONARY_HANDLE,
+        owner_handle: 0,
+        entries,
+    })?;
+    record_rows.push(object_row(NAMED_OBJECTS_DICTIONARY_HANDLE, &payload)?);
+    Ok(())
+}
+
+fn resolve_handle(
+    allocator: &mut HandleAllocator,
+    namespace: HandleNamespace,
+    requested: Option<u64>,
+    config: &WriterConfig,
+) -> Result<u64> {
+    if config.preserve_input_handles {
+        if let Some(handle) = requested {
+            return Ok(handle);
+        }
+    }
+    allocator.allocate_in(namespace)
+}
+
+fn align_up(value: usize, align: usize) -> usize {
+    if align == 0 {
+        return value;
+    }
+    let rem = value % align;
+    if rem == 0 {
+        value
+    } else {
+        value + (align - rem)
+    }
+}
+
+fn copy_section(dst: &mut [u8], offset: usize, src: &[u8]) -> Result<()> {
+    let end = offset
+        .checked_add(src.len())
+        .ok_or_else(|| DwgError::new(ErrorKind::Format, "section offset overflow"))?;
+    let Some(target) = dst.get_mut(offset..end) else {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!(
+                "section write out of bounds: offset={offset} len={}",
+                src.len()
+            ),
+        ));
+    };
+    target.copy_from_slice(src);
+    Ok(())
+}
+
+fn write_section_record(
+    bytes: &mut [u8],
+    offset: usize,
+    record_no: u8,
+    section_offset: u32,
+    section_size: u32,
+) -> Result<()> {
+    copy_section(bytes, offset, &[record_no])?;
+    copy_section(bytes, offset + 1, &section_offset.to_le_bytes())?;
+    copy_section(bytes, offset + 5, &section_size.to_le_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        style_table_entries, write_document, write_document_to, DEFAULT_LAYER_HANDLE,
+        LAYER_CONTROL_HANDLE,
+    };
+    use crate::bit::Endian;
+    use crate::core::config::ParseConfig;
+    use crate::core::error::ErrorKind;
+    use crate::core::progress::{ProgressHook, ProgressStage};
+    use crate::dwg::decoder::Decoder;
+    use crate::dwg::header::{DrawingTimes, JulianDate};
+    use crate::dwg::version::{detect_version, DwgVersion};
+    use crate::entities::common::{
+        line_weight_from_index, parse_common_entity_handles, parse_common_entity_header,
+    };
+    use crate::entities::decode_viewport;
+    use crate::entities::{
+        decode_arc, decode_circle, decode_leader, decode_line, decode_lwpolyline, decode_mline,
+        decode_mtext, decode_point, decode_polyline_pface, decode_ray, decode_seqend, decode_text,
+        decode_tolerance, decode_vertex_3d, decode_vertex_pface_face, decode_xline,
+    };
+    use crate::objects::{
+        decode_dictionary, decode_layout, decode_ltype, decode_xdata_items, decode_xrecord,
+        object_header_r2000, read_object_eed, XDataValue,
+    };
+    use crate::writer::config::WriterConfig;
+    use crate::writer::handle_allocator::{HandleLayout, HandleNamespace};
+    use crate::writer::ir::{
+        ArcEntity, CircleEntity, CommonEntityProps, DictionaryDef, GroupDef, LayerDef, LayoutDef,
+        LeaderEntity, LineEntity, LinetypeDef, LwPolylineEntity, MLineEntity, MLineStyleDef,
+        MLineStyleElement, MTextEntity, PointEntity, PolyfaceMeshEntity, RayEntity, TextEntity,
+        ToleranceEntity, ViewportEntity, WriterDocument, WriterEntity, WriterMetadata, XLineEntity,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn writes_minimal_r2000_line_document() {
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    handle: Some(0x30),
+                    layer_name: "0".to_string(),
+                    color_index: Some(7),
+                    true_color: None,
+                    ..CommonEntityProps::default()
+                },
+                start: (1.0, 2.0, 0.0),
+                end: (4.5, 7.0, 0.0),
+            })],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        assert_eq!(
+            detect_version(&bytes).expect("detect version"),
+            DwgVersion::R2000
+        );
+
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert_eq!(index.len(), 2);
+        let obj_ref = index.objects[1];
+        assert_eq!(obj_ref.handle.0, 0x30);
+
+        let record = decoder
+            .parse_object_record(obj_ref.offset)
+            .expect("parse object record");
+        let header = object_header_r2000::parse_from_record(&record).expect("object header");
+        assert_eq!(header.type_code, 0x13);
+
+        let mut reader = record.bit_reader();
+        let type_code = reader.read_bs().expect("read type prefix");
+        assert_eq!(type_code, 0x13);
+        let line = decode_line(&mut reader).expect("decode line");
 
         assert_eq!(line.handle, 0x30);
         assert_eq!(line.start, (1.0, 2.0, 0.0));
@@ -428,6 +1700,7 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     center: (2.0, 3.0, 0.0),
                     radius: 5.0,
@@ -440,6 +1713,7 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     center: (4.0, 5.0, 0.0),
                     radius: 2.5,
@@ -450,6 +1724,7 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     flags: 1,
                     vertices: vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0)],
@@ -463,11 +1738,13 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     text: "HELLO".to_string(),
                     insert: (1.5, 2.5, 0.0),
                     height: 2.0,
                     rotation_rad: 0.2,
+                    ..TextEntity::default()
                 }),
                 WriterEntity::MText(MTextEntity {
                     common: CommonEntityProps {
@@ -475,6 +1752,7 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     text: "MULTI".to_string(),
                     insert: (3.0, 4.0, 0.0),
@@ -490,6 +1768,7 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     location: (7.0, 8.0, 0.0),
                     x_axis_angle: 0.3,
@@ -500,6 +1779,7 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     start: (9.0, 1.0, 0.0),
                     unit_vector: (1.0, 0.0, 0.0),
@@ -510,6 +1790,7 @@ mod tests {
                         layer_name: "0".to_string(),
                         color_index: Some(7),
                         true_color: None,
+                        ..CommonEntityProps::default()
                     },
                     start: (10.0, 2.0, 0.0),
                     unit_vector: (0.0, 1.0, 0.0),
@@ -521,7 +1802,7 @@ mod tests {
         let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
         let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
         let index = decoder.build_object_index().expect("object index");
-        assert_eq!(index.len(), 8);
+        assert_eq!(index.len(), 9);
 
         let mut seen_arc = false;
         let mut seen_circle = false;
@@ -600,6 +1881,7 @@ mod tests {
                     assert_eq!(xline.unit_vector, (0.0, 1.0, 0.0));
                     seen_xline = true;
                 }
+                0x33 => {} // LAYER
                 other => panic!("unexpected type_code: {other:#X}"),
             }
         }
@@ -613,4 +1895,1306 @@ mod tests {
         assert!(seen_ray);
         assert!(seen_xline);
     }
+
+    #[test]
+    fn writes_paperspace_layout_with_viewport() {
+        let mut layout = LayoutDef::default();
+        layout.viewports.push(ViewportEntity {
+            common: CommonEntityProps {
+                handle: Some(0x60),
+                ..CommonEntityProps::default()
+            },
+            center: (148.5, 105.0, 0.0),
+            width: 257.0,
+            height: 170.0,
+            view_center: (50.0, 40.0),
+            view_height: 170.0,
+            frozen_layers: vec!["0".to_string()],
+            ..ViewportEntity::default()
+        });
+        layout.entities.push(WriterEntity::Line(LineEntity {
+            common: CommonEntityProps {
+                handle: Some(0x10),
+                ..CommonEntityProps::default()
+            },
+            start: (0.0, 0.0, 0.0),
+            end: (297.0, 0.0, 0.0),
+        }));
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            layouts: vec![layout],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert_eq!(index.len(), 5);
+
+        let mut layouts = Vec::new();
+        let mut viewport_owner = None;
+        let mut line_owner = None;
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let header = object_header_r2000::parse_from_record(&record).expect("header");
+            let mut reader = record.bit_reader();
+            reader.read_bs().expect("type prefix");
+            match header.type_code {
+                0x52 => layouts.push(
+                    decode_layout(&mut reader, &DwgVersion::R2000, obj_ref.handle.0)
+                        .expect("decode layout"),
+                ),
+                0x22 => {
+                    let mut handles_reader = reader.clone();
+                    let viewport = decode_viewport(&mut reader).expect("decode viewport");
+                    assert_eq!(viewport.handle, 0x60);
+                    assert_eq!(viewport.frozen_layer_handles, vec![DEFAULT_LAYER_HANDLE]);
+                    let common =
+                        parse_common_entity_header(&mut handles_reader).expect("common header");
+                    assert_eq!(
+                        handles_reader.read_3bd().expect("viewport center"),
+                        (148.5, 105.0, 0.0)
+                    );
+                    handles_reader.set_bit_pos(common.obj_size);
+                    let handles = parse_common_entity_handles(&mut handles_reader, &common)
+                        .expect("common handles");
+                    viewport_owner = handles.owner_ref;
+                }
+                0x13 => {
+                    let line = decode_line(&mut reader).expect("decode line");
+                    assert_eq!(line.handle, 0x10);
+                    line_owner = line.owner_handle;
+                }
+                0x33 => {} // LAYER
+                other => panic!("unexpected type_code: {other:#X}"),
+            }
+        }
+
+        layouts.sort_by_key(|layout| layout.tab_order);
+        let names: Vec<_> = layouts
+            .iter()
+            .map(|layout| layout.name.as_deref())
+            .collect();
+        assert_eq!(names, vec![Some("Model"), Some("Layout1")]);
+        let paper = &layouts[1];
+        assert_eq!(paper.limits_max, (297.0, 210.0));
+        assert_eq!(paper.extents_min, (20.0, 20.0, 0.0));
+        assert_eq!(paper.extents_max, (277.0, 190.0, 0.0));
+        assert_eq!(
+            paper.plot_settings.paper_size.as_deref(),
+            Some("ISO_A4_(210.00_x_297.00_MM)")
+        );
+        assert_eq!(paper.plot_settings.rotation, 1);
+        assert_eq!(paper.plot_settings.scale_type, 16);
+        assert!(viewport_owner.is_some());
+        assert_eq!(viewport_owner, line_owner);
+        assert_ne!(viewport_owner, Some(1));
+    }
+
+    #[test]
+    fn stamps_application_name_maintenance_version_and_codepage() {
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![WriterEntity::Line(LineEntity::default())],
+            ..WriterDocument::default()
+        };
+        let config = WriterConfig {
+            application_name: Some("ACMECAD".to_string()),
+            application_version: Some("4.2.1".to_string()),
+            maintenance_version: 6,
+            codepage: 29,
+            ..WriterConfig::default()
+        };
+
+        let bytes = write_document(&doc, &config).expect("write_document");
+        assert_eq!(bytes[0x0B], 6);
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        assert_eq!(decoder.codepage(), Some(29));
+
+        let index = decoder.build_object_index().expect("object index");
+        assert_eq!(index.len(), 3);
+        let app_id = index
+            .objects
+            .iter()
+            .find_map(|obj_ref| {
+                let record = decoder.parse_object_record(obj_ref.offset).ok()?;
+                let header = object_header_r2000::parse_from_record(&record).ok()?;
+                (header.type_code == 0x43).then_some((obj_ref.handle.0, record))
+            })
+            .expect("APPID record");
+        let (handle, record) = app_id;
+
+        let mut reader = record.bit_reader();
+        assert_eq!(reader.read_bs().expect("type prefix"), 0x43);
+        let _obj_size = reader.read_rl(Endian::Little).expect("obj size");
+        assert_eq!(reader.read_h().expect("handle").value, handle);
+        let ext_size = reader.read_bs().expect("ext size");
+        assert_eq!(ext_size, 9);
+        assert_eq!(reader.read_h().expect("app handle").value, handle);
+        let xdata = reader.read_rcs(ext_size as usize).expect("xdata");
+        assert_eq!(&xdata[..4], &[0, 5, 29, 0]);
+        assert_eq!(&xdata[4..], b"4.2.1");
+        assert_eq!(reader.read_bs().expect("eed end"), 0);
+        assert_eq!(reader.read_bl().expect("reactors"), 0);
+        assert_eq!(reader.read_tv().expect("name"), "ACMECAD");
+    }
+
+    #[test]
+    fn writes_drawing_times_to_header() {
+        let times = DrawingTimes {
+            created: JulianDate::new(2457479, 77669368),
+            updated: JulianDate::new(2461078, 58531605),
+            edit_time: JulianDate::new(0, 123502),
+            user_timer: JulianDate::new(0, 123498),
+        };
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![WriterEntity::Line(LineEntity::default())],
+            metadata: WriterMetadata {
+                times: Some(times),
+                ..WriterMetadata::default()
+            },
+            ..WriterDocument::default()
+        };
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        assert_eq!(decoder.drawing_times().expect("drawing times"), times);
+        assert_eq!(decoder.build_object_index().expect("object index").len(), 2);
+
+        let doc = WriterDocument {
+            metadata: WriterMetadata::default(),
+            ..doc
+        };
+        let before = JulianDate::now().to_days();
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let stamped = decoder.drawing_times().expect("drawing times");
+        assert_eq!(stamped.created, stamped.updated);
+        assert!(stamped.created.to_days() >= before);
+        assert_eq!(stamped.edit_time, JulianDate::default());
+    }
+
+    #[test]
+    fn default_config_writes_no_application_id() {
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![WriterEntity::Line(LineEntity::default())],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        assert_eq!(decoder.codepage(), Some(30));
+        assert_eq!(decoder.build_object_index().expect("object index").len(), 2);
+    }
+
+    #[test]
+    fn reports_write_and_read_progress() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let hook = ProgressHook::new(move |progress| sink.lock().unwrap().push(*progress));
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![WriterEntity::Line(LineEntity::default()); 3],
+            ..WriterDocument::default()
+        };
+        let config = WriterConfig {
+            application_name: Some("ezdwg".to_string()),
+            progress: Some(hook.clone()),
+            ..WriterConfig::default()
+        };
+
+        let bytes = write_document(&doc, &config).expect("write_document");
+        let written = std::mem::take(&mut *events.lock().unwrap());
+        let last = written.last().expect("write progress");
+        assert!(written.iter().all(|p| p.stage == ProgressStage::Write));
+        assert_eq!((last.objects, last.objects_total), (5, Some(5)));
+        assert_eq!(last.bytes_total, Some(bytes.len() as u64));
+
+        let decoder = Decoder::new(
+            &bytes,
+            ParseConfig {
+                progress: Some(hook),
+                ..ParseConfig::default()
+            },
+        )
+        .expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert_eq!(decoder.parse_object_records(&index).count(), 5);
+        let read = events.lock().unwrap();
+        let indexed = read
+            .iter()
+            .rfind(|p| p.stage == ProgressStage::IndexBuild)
+            .expect("index progress");
+        assert_eq!(indexed.objects, 5);
+        let decoded = read.last().expect("decode progress");
+        assert_eq!(decoded.stage, ProgressStage::Decode);
+        assert_eq!((decoded.objects, decoded.objects_total), (5, Some(5)));
+        assert!(decoded.bytes > 0);
+    }
+
+    #[test]
+    fn writes_entity_linetype_lineweight_and_transparency() {
+        let styled = |handle, linetype: &str| CommonEntityProps {
+            handle: Some(handle),
+            linetype_name: Some(linetype.to_string()),
+            lineweight: Some(50),
+            transparency: Some(0x0200_0080),
+            ..CommonEntityProps::default()
+        };
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![
+                WriterEntity::Line(LineEntity {
+                    common: styled(0x30, "dashed"),
+                    ..LineEntity::default()
+                }),
+                WriterEntity::Line(LineEntity {
+                    common: styled(0x31, "ByBlock"),
+                    ..LineEntity::default()
+                }),
+                WriterEntity::Line(LineEntity {
+                    common: styled(0x32, "HIDDEN"),
+                    ..LineEntity::default()
+                }),
+            ],
+            linetypes: vec![LinetypeDef {
+                name: "DASHED".to_string(),
+                description: "__ __ __".to_string(),
+                dashes: vec![0.5, -0.25],
+            }],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert_eq!(index.len(), 7);
+
+        let mut ltypes = Vec::new();
+        let mut app_ids = Vec::new();
+        let mut line_styles = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let header = object_header_r2000::parse_from_record(&record).expect("header");
+            let mut reader = record.bit_reader();
+            reader.read_bs().expect("type prefix");
+            match header.type_code {
+                0x39 => ltypes.push(
+                    decode_ltype(&mut reader, &DwgVersion::R2000, obj_ref.handle.0)
+                        .expect("decode ltype"),
+                ),
+                0x43 => app_ids.push(obj_ref.handle.0),
+                0x13 => {
+                    let common = parse_common_entity_header(&mut reader).expect("common header");
+                    reader.set_bit_pos(common.obj_size);
+                    let handles =
+                        parse_common_entity_handles(&mut reader, &common).expect("handles");
+                    line_styles.push((
+                        common.ltype_flags,
+                        handles.ltype,
+                        line_weight_from_index(common.line_weight),
+                        common.color.transparency,
+                    ));
+                }
+                0x33 => {} // LAYER
+                other => panic!("unexpected type_code: {other:#X}"),
+            }
+        }
+
+        let names: Vec<_> = ltypes.iter().map(|ltype| ltype.name.as_deref()).collect();
+        assert_eq!(names, vec![Some("DASHED"), Some("HIDDEN")]);
+        assert_eq!(ltypes[0].pattern_length, 0.75);
+        assert_eq!(ltypes[0].dashes.len(), 2);
+        assert_eq!(ltypes[0].dashes[1].length, -0.25);
+        assert!(ltypes[1].dashes.is_empty());
+        assert_eq!(app_ids.len(), 1);
+        assert_eq!(
+            line_styles,
+            vec![
+                (3, Some(ltypes[0].handle), 50, Some(0x0200_0080)),
+                (1, None, 50, Some(0x0200_0080)),
+                (3, Some(ltypes[1].handle), 50, Some(0x0200_0080)),
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_layers_and_entity_layer_handles() {
+        let on_layer = |handle, layer: &str| {
+            WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    handle: Some(handle),
+                    layer_name: layer.to_string(),
+                    color_index: Some(256),
+                    ..CommonEntityProps::default()
+                },
+                ..LineEntity::default()
+            })
+        };
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![
+                on_layer(0x30, "walls"),
+                on_layer(0x31, "Doors"),
+                on_layer(0x32, "0"),
+            ],
+            layers: vec![
+                LayerDef {
+                    name: "0".to_string(),
+                    color_index: 3,
+                    ..LayerDef::default()
+                },
+                LayerDef {
+                    name: "WALLS".to_string(),
+                    color_index: 1,
+                    ..LayerDef::default()
+                },
+            ],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut layers = Vec::new();
+        let mut line_layers = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            match reader.read_bs().expect("type prefix") {
+                0x33 => {
+                    reader.read_rl(Endian::Little).expect("obj size");
+                    reader.read_h().expect("handle");
+                    assert_eq!(reader.read_bs().expect("eed size"), 0);
+                    assert_eq!(reader.read_bl().expect("reactors"), 0);
+                    let name = reader.read_tv().expect("name");
+                    reader.read_b().expect("64-flag");
+                    reader.read_bs().expect("xrefindex");
+                    reader.read_b().expect("xdep");
+                    assert_eq!(reader.read_bs().expect("flags") & 0x07, 0);
+                    let color = reader.read_bs().expect("color");
+                    layers.push((obj_ref.handle.0, name, color));
+                }
+                0x13 => {
+                    let line = decode_line(&mut reader).expect("decode line");
+                    assert_eq!(line.color_index, Some(256));
+                    line_layers.push(line.layer_handle);
+                }
+                other => panic!("unexpected type_code: {other:#X}"),
+            }
+        }
+
+        let [(zero, zero_name, 3), (walls, walls_name, 1), (doors, doors_name, 7)] =
+            layers.as_slice()
+        else {
+            panic!("unexpected layers: {layers:?}");
+        };
+        assert_eq!((*zero, zero_name.as_str()), (2, "0"));
+        assert_eq!(walls_name, "WALLS");
+        assert_eq!(doors_name, "Doors");
+        assert_eq!(line_layers, vec![*walls, *doors, *zero]);
+    }
+
+    #[test]
+    fn resolves_layer_linetypes() {
+        let mut doc = WriterDocument {
+            version: DwgVersion::R2000,
+            layers: vec![
+                LayerDef {
+                    name: "HIDDEN_EDGES".to_string(),
+                    linetype_name: Some("dashed".to_string()),
+                    ..LayerDef::default()
+                },
+                LayerDef {
+                    name: "OUTLINE".to_string(),
+                    linetype_name: Some("Continuous".to_string()),
+                    ..LayerDef::default()
+                },
+            ],
+            linetypes: vec![LinetypeDef {
+                name: "DASHED".to_string(),
+                description: "__ __ __".to_string(),
+                dashes: vec![0.5, -0.25],
+            }],
+            ..WriterDocument::default()
+        };
+        write_document(&doc, &WriterConfig::default()).expect("write_document");
+
+        doc.layers[1].linetype_name = Some("HIDDEN".to_string());
+        let (linetypes, _) = style_table_entries(&doc);
+        let names: Vec<&str> = linetypes.iter().map(|def| def.name.as_str()).collect();
+        assert_eq!(names, ["DASHED", "HIDDEN"]);
+        write_document(&doc, &WriterConfig::default()).expect("write_document");
+    }
+
+    #[test]
+    fn writes_groups_with_their_dictionary_and_member_reactors() {
+        let line = |handle| {
+            WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    handle: Some(handle),
+                    ..CommonEntityProps::default()
+                },
+                ..LineEntity::default()
+            })
+        };
+        let mut doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![line(0x100), line(0x101), line(0x102)],
+            groups: vec![
+                GroupDef {
+                    name: "DOORS".to_string(),
+                    description: "door swings".to_string(),
+                    selectable: true,
+                    entities: vec![0x101, 0x100],
+                },
+                GroupDef {
+                    name: "*A1".to_string(),
+                    entities: vec![0x101],
+                    ..GroupDef::default()
+                },
+            ],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut groups = Vec::new();
+        let mut dictionary = None;
+        let mut line_reactors = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            match reader.read_bs().expect("type prefix") {
+                0x13 => {
+                    let common = parse_common_entity_header(&mut reader).expect("common header");
+                    reader.set_bit_pos(common.obj_size);
+                    let handles =
+                        parse_common_entity_handles(&mut reader, &common).expect("handles");
+                    assert_eq!(handles.owner_ref, Some(1));
+                    line_reactors.push((obj_ref.handle.0, handles.reactors));
+                }
+                type_code @ (0x48 | 0x2A) => {
+                    let obj_size = reader.read_rl(Endian::Little).expect("obj size");
+                    reader.read_h().expect("handle");
+                    assert_eq!(reader.read_bs().expect("eed size"), 0);
+                    assert_eq!(reader.read_bl().expect("reactors"), 0);
+                    if type_code == 0x48 {
+                        let description = reader.read_tv().expect("description");
+                        let unnamed = reader.read_bs().expect("unnamed");
+                        let selectable = reader.read_bs().expect("selectable");
+                        let count = reader.read_bl().expect("count");
+                        reader.set_bit_pos(obj_size);
+                        assert_eq!(reader.read_h().expect("owner").value, 0x0D);
+                        reader.read_h().expect("xdic");
+                        let members: Vec<u64> = (0..count)
+                            .map(|_| reader.read_h().expect("member").value)
+                            .collect();
+                        groups.push((obj_ref.handle.0, description, unnamed, selectable, members));
+                    } else {
+                        let count = reader.read_bl().expect("count");
+                        assert_eq!(reader.read_bs().expect("cloning"), 1);
+                        assert_eq!(reader.read_rc().expect("hard owner"), 0);
+                        let names: Vec<String> = (0..count)
+                            .map(|_| reader.read_tv().expect("name"))
+                            .collect();
+                        reader.set_bit_pos(obj_size);
+                        reader.read_h().expect("owner");
+                        reader.read_h().expect("xdic");
+                        let entries: Vec<(String, u64)> = names
+                            .into_iter()
+                            .map(|name| (name, reader.read_h().expect("entry").value))
+                            .collect();
+                        dictionary = Some((obj_ref.handle.0, entries));
+                    }
+                }
+                0x33 => {} // LAYER
+                other => panic!("unexpected type_code: {other:#X}"),
+            }
+        }
+
+        let [(doors, description, 0, 1, members), (anonymous, _, 1, 0, anonymous_members)] =
+            groups.as_slice()
+        else {
+            panic!("unexpected groups: {groups:?}");
+        };
+        assert_eq!(description, "door swings");
+        assert_eq!(members, &vec![0x101, 0x100]);
+        assert_eq!(anonymous_members, &vec![0x101]);
+        assert_eq!(
+            dictionary,
+            Some((
+                0x0D,
+                vec![
+                    ("DOORS".to_string(), *doors),
+                    ("*A1".to_string(), *anonymous)
+                ]
+            ))
+        );
+        assert_eq!(
+            line_reactors,
+            vec![
+                (0x100, vec![*doors]),
+                (0x101, vec![*doors, *anonymous]),
+                (0x102, Vec::new())
+            ]
+        );
+
+        doc.groups[1].entities.push(0x200);
+        let err = write_document(&doc, &WriterConfig::default()).expect_err("unknown member");
+        assert_eq!(err.kind, ErrorKind::Resolve);
+        doc.groups[1].name = "doors".to_string();
+        let err = write_document(&doc, &WriterConfig::default()).expect_err("duplicate name");
+        assert_eq!(err.kind, ErrorKind::Format);
+    }
+
+    #[test]
+    fn writes_application_dictionaries_and_entity_xdata() {
+        let mut doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    handle: Some(0x100),
+                    xdata: vec![(
+                        "PIPELINE".to_string(),
+                        vec![
+                            (1000, XDataValue::String("part-7".to_string())),
+                            (1071, XDataValue::Int32(42)),
+                        ],
+                    )],
+                    ..CommonEntityProps::default()
+                },
+                ..LineEntity::default()
+            })],
+            groups: vec![GroupDef {
+                name: "ALL".to_string(),
+                entities: vec![0x100],
+                ..GroupDef::default()
+            }],
+            dictionaries: vec![DictionaryDef {
+                name: "PIPELINE_DATA".to_string(),
+                records: vec![(
+                    "RUN".to_string(),
+                    vec![
+                        (1, XDataValue::String("run-3".to_string())),
+                        (40, XDataValue::Real(2.5)),
+                        (310, XDataValue::Binary(vec![1, 2, 3])),
+                    ],
+                )],
+            }],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut app_ids = Vec::new();
+        let mut dictionaries = Vec::new();
+        let mut xrecords = Vec::new();
+        let mut line_xdata = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            let handle = obj_ref.handle.0;
+            match reader.read_bs().expect("type prefix") {
+                0x13 => {
+                    let (_, blocks) =
+                        read_object_eed(&mut reader, &DwgVersion::R2000).expect("eed");
+                    for (app_handle, data) in blocks {
+                        let items = decode_xdata_items(&data, &DwgVersion::R2000).expect("items");
+                        line_xdata.push((app_handle, items));
+                    }
+                }
+                0x43 => {
+                    read_object_eed(&mut reader, &DwgVersion::R2000).expect("eed");
+                    assert_eq!(reader.read_bl().expect("reactors"), 0);
+                    app_ids.push((handle, reader.read_tv().expect("name")));
+                }
+                0x2A => dictionaries.push(
+                    decode_dictionary(&mut reader, &DwgVersion::R2000, handle).expect("dictionary"),
+                ),
+                0x4F => xrecords.push(
+                    decode_xrecord(&mut reader, &DwgVersion::R2000, handle).expect("xrecord"),
+                ),
+                _ => {}
+            }
+        }
+
+        let [(app_handle, app_name)] = app_ids.as_slice() else {
+            panic!("unexpected APPIDs: {app_ids:?}");
+        };
+        assert_eq!(app_name, "PIPELINE");
+        assert_eq!(
+            line_xdata,
+            vec![(
+                *app_handle,
+                vec![
+                    (1000, XDataValue::String("part-7".to_string())),
+                    (1071, XDataValue::Int32(42)),
+                ]
+            )]
+        );
+
+        let named_objects = dictionaries
+            .iter()
+            .find(|dictionary| dictionary.handle == 0x0C)
+            .expect("named objects dictionary");
+        assert_eq!(named_objects.owner_handle, 0);
+        let [(group_name, 0x0D), (app_name, app_dictionary)] = named_objects.entries.as_slice()
+        else {
+            panic!("unexpected entries: {:?}", named_objects.entries);
+        };
+        assert_eq!(
+            (group_name.as_str(), app_name.as_str()),
+            ("ACAD_GROUP", "PIPELINE_DATA")
+        );
+        let groups = dictionaries
+            .iter()
+            .find(|dictionary| dictionary.handle == 0x0D)
+            .expect("group dictionary");
+        assert_eq!(groups.owner_handle, 0x0C);
+        let records = dictionaries
+            .iter()
+            .find(|dictionary| dictionary.handle == *app_dictionary)
+            .expect("application dictionary");
+        assert_eq!(records.owner_handle, 0x0C);
+        let [xrecord] = xrecords.as_slice() else {
+            panic!("unexpected XRECORDs: {xrecords:?}");
+        };
+        assert_eq!(records.entries, vec![("RUN".to_string(), xrecord.handle)]);
+        assert_eq!(xrecord.owner_handle, *app_dictionary);
+        assert_eq!(xrecord.items, doc.dictionaries[0].records[0].1);
+
+        doc.dictionaries[0].records.push((
+            "run".to_string(),
+            vec![(1, XDataValue::String("again".to_string()))],
+        ));
+        let err = write_document(&doc, &WriterConfig::default()).expect_err("duplicate key");
+        assert_eq!(err.kind, ErrorKind::Format);
+    }
+
+    #[test]
+    fn rejects_unknown_lineweight() {
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    lineweight: Some(17),
+                    ..CommonEntityProps::default()
+                },
+                ..LineEntity::default()
+            })],
+            ..WriterDocument::default()
+        };
+
+        let err = write_document(&doc, &WriterConfig::default()).expect_err("invalid lineweight");
+        assert_eq!(err.kind, ErrorKind::Format);
+    }
+
+    #[test]
+    fn writes_text_alignment_width_factor_and_oblique() {
+        let aligned = |handle, horizontal_alignment, vertical_alignment, align_point| {
+            WriterEntity::Text(TextEntity {
+                common: CommonEntityProps {
+                    handle: Some(handle),
+                    ..CommonEntityProps::default()
+                },
+                text: "LABEL".to_string(),
+                insert: (1.0, 2.0, 0.5),
+                height: 0.25,
+                horizontal_alignment,
+                vertical_alignment,
+                align_point,
+                width_factor: Some(0.8),
+                oblique_rad: 0.25,
+                ..TextEntity::default()
+            })
+        };
+        let doc = WriterDocument {
+            modelspace: vec![
+                aligned(0x40, 5, 0, Some((6.0, 2.0, 0.5))),
+                aligned(0x41, 4, 0, None),
+                aligned(0x42, 2, 3, Some((3.0, 4.0, 0.5))),
+            ],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut texts = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            if reader.read_bs().expect("type prefix") == 0x01 {
+                texts.push(decode_text(&mut reader).expect("decode text"));
+            }
+        }
+        texts.sort_by_key(|text| text.handle);
+        let summary: Vec<_> = texts
+            .iter()
+            .map(|text| {
+                (
+                    text.horizontal_alignment,
+                    text.vertical_alignment,
+                    text.alignment,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (5, 0, Some((6.0, 2.0, 0.5))),
+                (4, 0, Some((1.0, 2.0, 0.5))),
+                (2, 3, Some((3.0, 4.0, 0.5))),
+            ]
+        );
+        for text in &texts {
+            assert_eq!(text.insertion, (1.0, 2.0, 0.5));
+            assert_eq!(text.text, "LABEL");
+            assert!((text.width_factor - 0.8).abs() < 1.0e-12);
+            assert!((text.oblique_angle - 0.25).abs() < 1.0e-12);
+            assert!((text.height - 0.25).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn writes_tolerance_and_leader_entities() {
+        let points = vec![(0.0, 0.0, 0.0), (2.0, 1.0, 0.0), (3.0, 1.0, 0.0)];
+        let doc = WriterDocument {
+            modelspace: vec![
+                WriterEntity::Tolerance(ToleranceEntity {
+                    common: CommonEntityProps {
+                        handle: Some(0x40),
+                        color_index: Some(1),
+                        ..CommonEntityProps::default()
+                    },
+                    text: "{\\Fgdt;j}%%v0.05%%vA".to_string(),
+                    insert: (3.0, 1.0, 0.0),
+                    x_direction: (1.0, 0.0, 0.0),
+                    height: 0.18,
+                    dimgap: 0.09,
+                }),
+                WriterEntity::Leader(LeaderEntity {
+                    common: CommonEntityProps {
+                        handle: Some(0x41),
+                        ..CommonEntityProps::default()
+                    },
+                    annotation_type: 1,
+                    path_type: 0,
+                    points: points.clone(),
+                }),
+            ],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut tolerances = Vec::new();
+        let mut leaders = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            match reader.read_bs().expect("type prefix") {
+                0x2E => tolerances.push(decode_tolerance(&mut reader).expect("decode tolerance")),
+                0x2D => leaders.push(decode_leader(&mut reader).expect("decode leader")),
+                _ => {}
+            }
+        }
+
+        let [tolerance] = tolerances.as_slice() else {
+            panic!("expected one TOLERANCE, got {tolerances:?}");
+        };
+        assert_eq!(tolerance.handle, 0x40);
+        assert_eq!(tolerance.color_index, Some(1));
+        assert_eq!(tolerance.text, "{\\Fgdt;j}%%v0.05%%vA");
+        assert_eq!(tolerance.insertion, (3.0, 1.0, 0.0));
+        assert_eq!(tolerance.x_direction, (1.0, 0.0, 0.0));
+        assert_eq!(tolerance.extrusion, (0.0, 0.0, 1.0));
+        assert_eq!((tolerance.height, tolerance.dimgap), (0.18, 0.09));
+        assert_eq!(tolerance.dimstyle_handle, Some(0));
+
+        let [leader] = leaders.as_slice() else {
+            panic!("expected one LEADER, got {leaders:?}");
+        };
+        assert_eq!(leader.handle, 0x41);
+        assert_eq!((leader.annotation_type, leader.path_type), (1, 0));
+        assert_eq!(leader.points, points);
+        assert_ne!(leader.layer_handle, 0);
+    }
+
+    #[test]
+    fn writes_mlines_with_their_styles() {
+        let wall = MLineStyleDef {
+            name: "WALL".to_string(),
+            elements: vec![
+                MLineStyleElement {
+                    offset: 0.15,
+                    color_index: 1,
+                },
+                MLineStyleElement {
+                    offset: 0.0,
+                    color_index: 8,
+                },
+                MLineStyleElement {
+                    offset: -0.15,
+                    color_index: 1,
+                },
+            ],
+            ..MLineStyleDef::default()
+        };
+        let points = [(0.0, 0.0, 0.0), (5.0, 0.0, 0.0), (5.0, 4.0, 0.0)];
+        let mut walls = MLineEntity::through_points(&wall, &points, true, 1, 2.0);
+        walls.common.handle = Some(0x40);
+        let mut standard =
+            MLineEntity::through_points(&MLineStyleDef::default(), &points[..2], false, 0, 1.0);
+        standard.common.handle = Some(0x41);
+        let doc = WriterDocument {
+            modelspace: vec![WriterEntity::MLine(walls), WriterEntity::MLine(standard)],
+            mline_styles: vec![wall],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut style_handles = Vec::new();
+        let mut mlines = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            match reader.read_bs().expect("type prefix") {
+                0x49 => style_handles.push(obj_ref.handle.0),
+                0x2F => mlines.push(decode_mline(&mut reader).expect("decode mline")),
+                _ => {}
+            }
+        }
+        mlines.sort_by_key(|mline| mline.handle);
+
+        assert_eq!(style_handles.len(), 2);
+        let [walls, standard] = mlines.as_slice() else {
+            panic!("expected two MLINEs, got {mlines:?}");
+        };
+        assert_eq!((walls.lines_in_style, standard.lines_in_style), (3, 2));
+        assert_eq!((walls.open_closed, standard.open_closed), (3, 1));
+        assert_eq!((walls.justification, walls.scale), (1, 2.0));
+        assert_eq!(walls.base_point, (0.0, 0.0, 0.0));
+        let positions: Vec<_> = walls
+            .vertices
+            .iter()
+            .map(|vertex| vertex.position)
+            .collect();
+        assert_eq!(positions, points);
+        assert_eq!(walls.vertices[1].vertex_direction, (0.0, 1.0, 0.0));
+        let style_of = |mline: &crate::entities::MLineEntity| mline.mlinestyle_handle.unwrap_or(0);
+        assert!(style_handles.contains(&style_of(walls)));
+        assert!(style_handles.contains(&style_of(standard)));
+        assert_ne!(style_of(walls), style_of(standard));
+    }
+
+    #[test]
+    fn writes_polyface_meshes_with_their_vertices_faces_and_seqend() {
+        let vertices = vec![
+            (0.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 0.0),
+            (0.0, 1.0, 1.0),
+        ];
+        let doc = WriterDocument {
+            modelspace: vec![
+                WriterEntity::PolyfaceMesh(PolyfaceMeshEntity {
+                    common: CommonEntityProps {
+                        handle: Some(0x80),
+                        color_index: Some(3),
+                        ..CommonEntityProps::default()
+                    },
+                    vertices: vertices.clone(),
+                    faces: vec![[1, 2, -3, 0], [1, 3, 4, 0]],
+                }),
+                WriterEntity::Line(LineEntity {
+                    end: (1.0, 1.0, 0.0),
+                    ..LineEntity::default()
+                }),
+            ],
+            ..WriterDocument::default()
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut polyfaces = Vec::new();
+        let mut positions = Vec::new();
+        let mut faces = Vec::new();
+        let mut owners = Vec::new();
+        let mut seqends = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            let type_code = reader.read_bs().expect("type prefix");
+            match type_code {
+                0x1D => polyfaces.push(decode_polyline_pface(&mut reader).expect("polyface")),
+                0x0D => positions.push(decode_vertex_3d(&mut reader).expect("vertex").position),
+                0x0E => faces.push(decode_vertex_pface_face(&mut reader).expect("face")),
+                0x06 => seqends.push(decode_seqend(&mut reader).expect("seqend").handle),
+                _ => continue,
+            }
+            if type_code != 0x1D {
+                let mut reader = record.bit_reader();
+                reader.read_bs().expect("type prefix");
+                let header = parse_common_entity_header(&mut reader).expect("header");
+                reader.set_bit_pos(header.obj_size);
+                let handles = parse_common_entity_handles(&mut reader, &header).expect("handles");
+                owners.push(handles.owner_ref);
+            }
+        }
+
+        let [polyface] = polyfaces.as_slice() else {
+            panic!("expected one POLYLINE_PFACE, got {polyfaces:?}");
+        };
+        assert_eq!(polyface.handle, 0x80);
+        assert_eq!(polyface.color_index, Some(3));
+        assert_eq!((polyface.num_vertices, polyface.num_faces), (4, 2));
+        assert_eq!(positions, vertices);
+        let indices: Vec<_> = faces
+            .iter()
+            .map(|face| [face.index1, face.index2, face.index3, face.index4])
+            .collect();
+        assert_eq!(indices, [[1, 2, -3, 0], [1, 3, 4, 0]]);
+        // The vertex links span the vertex and face records, which run on
+        // consecutive handles up to the SEQEND it links after them.
+        let first = polyface
+            .owned_handles
+            .first()
+            .copied()
+            .expect("owned handles");
+        assert_eq!(
+            polyface.owned_handles,
+            (first..=first + 6).collect::<Vec<_>>()
+        );
+        assert_eq!(seqends, [first + 6]);
+        assert!(
+            owners.iter().all(|owner| *owner == Some(0x80)),
+            "{owners:?}"
+        );
+    }
+
+    #[test]
+    fn copies_raw_objects_with_remapped_handles() {
+        use super::objects::common::{encode_object_payload, CommonObjectEncodeInput};
+        use super::raw_object::collect_unknown_objects;
+        use crate::dwg::r2000::ClassDefinition;
+        use crate::writer::ir::RawObjectDef;
+
+        // An application object at a handle the writer uses itself, owned by
+        // a proxy object and pointing at a LINE, at the proxy through a
+        // relative reference and at an object that is not copied.
+        let widget = encode_object_payload(
+            500,
+            CommonObjectEncodeInput {
+                handle: 0x0C,
+                owner_handle: 0x30,
+                eed: None,
+            },
+            |writer| {
+                writer.write_tv("payload")?;
+                writer.write_bd(2.5)
+            },
+            |writer| {
+                writer.write_h(0x04, 0x20)?;
+                writer.write_h(0x0A, 0x30 - 0x0C)?;
+                writer.write_h(0x05, 0x99)
+            },
+        )
+        .unwrap();
+        let proxy = encode_object_payload(
+            0x1F3,
+            CommonObjectEncodeInput {
+                handle: 0x30,
+                owner_handle: 0x99,
+                eed: None,
+            },
+            |writer| writer.write_bl(7),
+            |writer| writer.write_h(0x03, 0x0C),
+        )
+        .unwrap();
+        let doc = WriterDocument {
+            modelspace: vec![WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    handle: Some(0x20),
+                    ..CommonEntityProps::default()
+                },
+                start: (0.0, 0.0, 0.0),
+                end: (1.0, 0.0, 0.0),
+            })],
+            raw_objects: vec![
+                RawObjectDef {
+                    handle: 0x0C,
+                    data: widget,
+                    class: Some(ClassDefinition {
+                        class_number: 612,
+                        proxy_flags: 0,
+                        app_name: "AcmeApp".to_string(),
+                        cpp_name: "AcmeDbWidget".to_string(),
+                        dxf_name: "ACME_WIDGET".to_string(),
+                        was_a_zombie: false,
+                        item_class_id: 0x1F3,
+                    }),
+                },
+                RawObjectDef {
+                    handle: 0x30,
+                    data: proxy,
+                    class: None,
+                },
+            ]
+            .into(),
+            ..WriterDocument::default()
+        };
+
+        let read_handles = |raw: &RawObjectDef| {
+            let mut reader = crate::bit::BitReader::new(&raw.data);
+            let type_code = reader.read_bs().unwrap();
+            let obj_size = reader.read_rl(Endian::Little).unwrap();
+            assert_eq!(reader.read_h().unwrap().value, raw.handle);
+            assert_eq!(reader.read_bs().unwrap(), 0);
+            assert_eq!(reader.read_bl().unwrap(), 0);
+            if type_code >= 500 {
+                assert_eq!(reader.read_tv().unwrap(), "payload");
+                assert_eq!(reader.read_bd().unwrap(), 2.5);
+            } else {
+                assert_eq!(reader.read_bl().unwrap(), 7);
+            }
+            reader.set_bit_pos(obj_size);
+            let mut handles = Vec::new();
+            while reader.total_bits() - reader.tell_bits() >= 8 {
+                handles.push(reader.read_h().unwrap().resolve(raw.handle));
+            }
+            (type_code, handles)
+        };
+
+        let bytes = write_document(&doc, &WriterConfig::default()).expect("write_document");
+        let copied = collect_unknown_objects(&bytes).expect("collect unknown objects");
+        let [widget, proxy] = copied.as_slice() else {
+            panic!("expected two raw objects, got {copied:?}");
+        };
+        let class = widget.class.as_ref().expect("widget class");
+        assert_eq!(
+            (class.class_number, class.dxf_name.as_str()),
+            (500, "ACME_WIDGET")
+        );
+        assert_eq!(class.cpp_name, "AcmeDbWidget");
+        assert!(widget.handle >= 0x10 && widget.handle != 0x20);
+        assert_eq!(proxy.handle, 0x30);
+        assert_eq!(read_handles(widget), (500, vec![0x30, 0, 0x20, 0x30, 0]));
+        assert_eq!(read_handles(proxy), (0x1F3, vec![0, 0, widget.handle]));
+
+        // Without preserved handles every object moves and references to the
+        // LINE are cleared along with the others outside the copied set.
+        let config = WriterConfig {
+            preserve_input_handles: false,
+            ..WriterConfig::default()
+        };
+        let copied_doc = WriterDocument {
+            raw_objects: copied.clone().into(),
+            ..doc.clone()
+        };
+        let bytes = write_document(&copied_doc, &config).expect("write_document");
+        let recopied = collect_unknown_objects(&bytes).expect("collect unknown objects");
+        let [widget, proxy] = recopied.as_slice() else {
+            panic!("expected two raw objects, got {recopied:?}");
+        };
+        assert_ne!(proxy.handle, 0x30);
+        assert_eq!(
+            read_handles(widget),
+            (500, vec![proxy.handle, 0, 0, proxy.handle, 0])
+        );
+        assert_eq!(read_handles(proxy), (0x1F3, vec![0, 0, widget.handle]));
+    }
+
+    #[test]
+    fn parallel_entity_encoding_matches_single_thread_output() {
+        let mut doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: (0..10_000u64)
+                .map(|index| {
+                    let x = index as f64;
+                    WriterEntity::Line(LineEntity {
+                        common: CommonEntityProps {
+                            // Mix requested and allocated handles.
+                            handle: (index % 3 == 0).then_some(0x1000 + index),
+                            color_index: Some((index % 255) as u16 + 1),
+                            ..CommonEntityProps::default()
+                        },
+                        start: (x, 0.0, 0.0),
+                        end: (x, 1.0, 0.0),
+                    })
+                })
+                .collect(),
+            metadata: WriterMetadata {
+                times: Some(DrawingTimes::default()),
+                ..WriterMetadata::default()
+            },
+            ..WriterDocument::default()
+        };
+        let write = |doc: &WriterDocument, threads| {
+            let config = WriterConfig {
+                encode_threads: Some(threads),
+                ..WriterConfig::default()
+            };
+            write_document(doc, &config)
+        };
+
+        let single = write(&doc, 1).expect("single-threaded write");
+        assert_eq!(write(&doc, 4).expect("parallel write"), single);
+        let decoder = Decoder::new(&single, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        assert!(index.objects.len() > 10_000);
+
+        // The first failing entity in input order is reported.
+        if let Some(WriterEntity::Line(line)) = doc.modelspace.get_mut(9_000) {
+            line.common.lineweight = Some(7);
+        }
+        let err = write(&doc, 4).expect_err("invalid lineweight");
+        assert_eq!(err.kind, ErrorKind::Format);
+        assert!(err.message.contains("invalid lineweight: 7"));
+    }
+
+    #[test]
+    fn write_document_to_streams_the_same_bytes() {
+        let doc = WriterDocument {
+            version: DwgVersion::R2000,
+            modelspace: vec![
+                WriterEntity::Line(LineEntity::default()),
+                WriterEntity::Circle(CircleEntity {
+                    radius: 2.0,
+                    ..CircleEntity::default()
+                }),
+            ],
+            metadata: WriterMetadata {
+                times: Some(DrawingTimes::default()),
+                ..WriterMetadata::default()
+            },
+            ..WriterDocument::default()
+        };
+        let config = WriterConfig::default();
+        let expected = write_document(&doc, &config).expect("write_document");
+
+        let mut streamed = std::io::Cursor::new(b"prefix".to_vec());
+        streamed.set_position(6);
+        let written = write_document_to(&mut streamed, &doc, &config).expect("streamed");
+        assert_eq!(written, expected.len() as u64);
+        assert_eq!(&streamed.get_ref()[6..], expected.as_slice());
+
+        // A sink that runs out of space reports an I/O error.
+        let mut short = [0u8; 64];
+        let err = write_document_to(&mut &mut short[..], &doc, &config).expect_err("short sink");
+        assert_eq!(err.kind, ErrorKind::Io);
+    }
+
+    #[test]
+    fn allocates_handles_from_namespaces() {
+        let line = |handle| {
+            WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    handle,
+                    layer_name: "WALLS".to_string(),
+                    ..CommonEntityProps::default()
+                },
+                ..LineEntity::default()
+            })
+        };
+        let mut layout = LayoutDef::default();
+        layout.viewports.push(ViewportEntity::default());
+        let mut doc = WriterDocument {
+            version: DwgVersion::R2000,
+            // 0x11 lies in the tables range and must be skipped there.
+            modelspace: vec![line(Some(0x11)), line(None)],
+            layers: vec![LayerDef {
+                name: "WALLS".to_string(),
+                ..LayerDef::default()
+            }],
+            layouts: vec![layout],
+            groups: vec![GroupDef {
+                name: "ALL".to_string(),
+                entities: vec![0x11],
+                ..GroupDef::default()
+            }],
+            dictionaries: vec![DictionaryDef {
+                name: "APP".to_string(),
+                records: vec![("KEY".to_string(), vec![(70, XDataValue::Int16(1))])],
+            }],
+            ..WriterDocument::default()
+        };
+        let layout = HandleLayout::default();
+        let config = WriterConfig {
+            handle_layout: Some(layout),
+            ..WriterConfig::default()
+        };
+
+        let bytes = write_document(&doc, &config).expect("write_document");
+        let decoder = Decoder::new(&bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut seen = Vec::new();
+        for obj_ref in &index.objects {
+            let handle = obj_ref.handle.0;
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let type_code = object_header_r2000::parse_from_record(&record)
+                .expect("header")
+                .type_code;
+            let expected = match type_code {
+                0x33 | 0x38 | 0x43 => HandleNamespace::Tables,
+                0x52 => HandleNamespace::Blocks,
+                0x13 | 0x22 => HandleNamespace::Entities,
+                0x2A | 0x48 | 0x4F => HandleNamespace::Dictionaries,
+                other => panic!("unexpected type_code: {other:#X}"),
+            };
+            if handle < 0x10 || handle == 0x11 {
+                continue; // fixed handles and the preserved LINE
+            }
+            assert_eq!(layout.namespace_of(handle), Some(expected), "{handle:#X}");
+            seen.push(expected);
+        }
+        for namespace in HandleNamespace::ALL {
+            assert!(seen.contains(&namespace), "no {namespace:?} handle");
+        }
+        assert!(index.get(crate::objects::Handle(0x11)).is_some());
+        assert!(index.get(crate::objects::Handle(0x10)).is_some());
+
+        // A preserved handle may not take one of the fixed handles.
+        if let Some(WriterEntity::Line(line)) = doc.modelspace.first_mut() {
+            line.common.handle = Some(LAYER_CONTROL_HANDLE);
+        }
+        let err = write_document(&doc, &config).expect_err("fixed handle collision");
+        assert!(err.message.contains("fixed handles"), "{err}");
+        let config = WriterConfig {
+            handle_layout: Some(HandleLayout {
+                tables: 0x08,
+                ..layout
+            }),
+            ..WriterConfig::default()
+        };
+        assert!(write_document(&doc, &config).is_err());
+    }
 }
@@ -1,59 +1,82 @@
+use super::crc::{crc16, CRC_SEED};
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
 use crate::objects::ObjectRef;
 
+/// Largest object map block, counting its 2-byte size but not the trailing
+/// CRC. AutoCAD cuts the map at this length and restarts the handle and
+/// offset deltas from zero in every block.
+pub const OBJECT_MAP_BLOCK_LIMIT: usize = 2032;
+
 pub fn encode_object_map_section(objects: &[ObjectRef]) -> Result<Vec<u8>> {
     let mut ordered = objects.to_vec();
     ordered.sort_by_key(|obj| (obj.handle.0, obj.offset));
 
-    let mut payload = Vec::new();
+    let mut out = Vec::new();
+    let mut block = Vec::new();
     let mut prev_handle = 0i64;
     let mut prev_offset = 0i64;
 
     for obj in ordered {
         let handle = obj.handle.0 as i64;
         let offset = obj.offset as i64;
-        let delta_handle = handle - prev_handle;
-        let delta_offset = offset - prev_offset;
-        if delta_handle < 0 || delta_offset < 0 {
-            return Err(DwgError::new(
-                ErrorKind::Format,
-                format!(
-                    "object map entries must be monotonic: handle delta={delta_handle} offset delta={delta_offset}"
-                ),
-            ));
+        let mut entry = encode_entry(handle - prev_handle, offset - prev_offset)?;
+        // An entry never straddles two blocks; the next block starts over
+        // from handle 0 and offset 0.
+        if !block.is_empty() && 2 + block.len() + entry.len() > OBJECT_MAP_BLOCK_LIMIT {
+            push_block(&mut out, &block);
+            block.clear();
+            entry = encode_entry(handle, offset)?;
         }
-        payload.extend_from_slice(&encode_modular_char(delta_handle)?);
-        payload.extend_from_slice(&encode_modular_char(delta_offset)?);
+        block.extend_from_slice(&entry);
         prev_handle = handle;
         prev_offset = offset;
     }
 
-    let section_size = payload
-        .len()
-        .checked_add(2)
-        .ok_or_else(|| DwgError::new(ErrorKind::Unsupported, "object map section size overflow"))?;
-    if section_size > u16::MAX as usize {
+    if !block.is_empty() {
+        push_block(&mut out, &block);
+    }
+    push_block(&mut out, &[]); // terminator block
+    Ok(out)
+}
+
+fn encode_entry(delta_handle: i64, delta_offset: i64) -> Result<Vec<u8>> {
+    // Offsets may step backwards once records are appended out of handle
+    // order; only handles have to be ascending.
+    if delta_handle < 0 {
         return Err(DwgError::new(
-            ErrorKind::Unsupported,
-            format!("object map section too large: {section_size}"),
+            ErrorKind::Format,
+            format!("object map handles must be ascending: handle delta={delta_handle}"),
         ));
     }
+    let mut entry = encode_modular_char(delta_handle)?;
+    entry.extend_from_slice(&encode_modular_char(delta_offset)?);
+    Ok(entry)
+}
 
-    let mut out = Vec::with_capacity(section_size + 6);
-    push_u16_be(&mut out, section_size as u16);
-    out.extend_from_slice(&payload);
-    push_u16_be(&mut out, 0); // CRC placeholder
-    push_u16_be(&mut out, 2); // terminator block
-    Ok(out)
+/// Appends one block: big-endian size (data plus the size field), the data,
+/// and a big-endian CRC over both. The empty block is the terminator.
+fn push_block(out: &mut Vec<u8>, data: &[u8]) {
+    let mut block = Vec::with_capacity(data.len() + 2);
+    // Entries are at most 18 bytes and blocks are cut at
+    // OBJECT_MAP_BLOCK_LIMIT, so a block never outgrows the u16 size.
+    push_u16_be(&mut block, (data.len() + 2) as u16);
+    block.extend_from_slice(data);
+    let crc = crc16(CRC_SEED, &block);
+    out.extend_from_slice(&block);
+    push_u16_be(out, crc);
 }
 
+/// Longest modular char the object map reader accepts: 62 bits, enough for
+/// offsets far past 4 GiB.
+const MODULAR_CHAR_MAX_BYTES: usize = 9;
+
 fn encode_modular_char(value: i64) -> Result<Vec<u8>> {
     let negative = value < 0;
     let mut remaining = value.unsigned_abs();
     let mut out = Vec::with_capacity(4);
 
-    for _ in 0..4 {
+    for _ in 0..MODULAR_CHAR_MAX_BYTES {
         let chunk = (remaining & 0x7F) as u8;
         remaining >>= 7;
         if remaining == 0 && chunk <= 0x3F {
@@ -80,10 +103,61 @@ fn push_u16_be(buf: &mut Vec<u8>, value: u16) {
 
 #[cfg(test)]
 mod tests {
-    use super::encode_object_map_section;
+    use super::{encode_modular_char, encode_object_map_section, OBJECT_MAP_BLOCK_LIMIT};
     use crate::container::{SectionDirectory, SectionLocatorRecord};
     use crate::core::config::ParseConfig;
-    use crate::objects::{build_object_index_from_directory, Handle, ObjectRef};
+    use crate::objects::{build_object_index_from_directory, Handle, ObjectIndex, ObjectRef};
+    use crate::writer::r2000::crc::{crc16, CRC_SEED};
+
+    fn parse(bytes: &[u8]) -> ObjectIndex {
+        let directory = SectionDirectory {
+            record_count: 1,
+            records: vec![SectionLocatorRecord {
+                record_no: 2,
+                offset: 0,
+                size: bytes.len() as u64,
+                name: Some("ObjectMap".to_string()),
+            }],
+            crc: 0,
+            sentinel_ok: true,
+            warnings: Vec::new(),
+        };
+        build_object_index_from_directory(bytes, &directory, &ParseConfig::default())
+            .expect("object map should parse")
+    }
+
+    /// Splits an encoded map into block sizes, checking every block CRC and
+    /// that the terminator block ends the section.
+    fn block_sizes(bytes: &[u8]) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        let mut pos = 0;
+        loop {
+            let size = usize::from(u16::from_be_bytes([bytes[pos], bytes[pos + 1]]));
+            let crc = u16::from_be_bytes([bytes[pos + size], bytes[pos + size + 1]]);
+            assert_eq!(
+                crc16(CRC_SEED, &bytes[pos..pos + size]),
+                crc,
+                "block at {pos}"
+            );
+            pos += size + 2;
+            if size == 2 {
+                assert_eq!(pos, bytes.len());
+                return sizes;
+            }
+            assert!(size <= OBJECT_MAP_BLOCK_LIMIT);
+            sizes.push(size);
+        }
+    }
+
+    /// Consecutive handles with one-byte offset deltas: two bytes per entry.
+    fn dense_refs(count: u64) -> Vec<ObjectRef> {
+        (1..=count)
+            .map(|n| ObjectRef {
+                handle: Handle(n),
+                offset: n * 16,
+            })
+            .collect()
+    }
 
     #[test]
     fn roundtrip_object_map_through_existing_parser() {
@@ -100,25 +174,109 @@ mod tests {
                 handle: Handle(10),
                 offset: 220,
             },
+            ObjectRef {
+                handle: Handle(12),
+                offset: 120,
+            },
         ];
-        let bytes = encode_object_map_section(&refs).unwrap();
-        let directory = SectionDirectory {
-            record_count: 1,
-            records: vec![SectionLocatorRecord {
-                record_no: 2,
-                offset: 0,
-                size: bytes.len() as u32,
-                name: Some("ObjectMap".to_string()),
-            }],
-            crc: 0,
-            sentinel_ok: true,
-        };
-        let index = build_object_index_from_directory(&bytes, &directory, &ParseConfig::default())
-            .expect("object map should parse");
+        let index = parse(&encode_object_map_section(&refs).unwrap());
 
-        assert_eq!(index.len(), 3);
+        assert_eq!(index.len(), 4);
         assert_eq!(index.get(Handle(1)).unwrap().offset, 100);
         assert_eq!(index.get(Handle(3)).unwrap().offset, 140);
         assert_eq!(index.get(Handle(10)).unwrap().offset, 220);
+        assert_eq!(index.get(Handle(12)).unwrap().offset, 120);
+    }
+
+    #[test]
+    fn terminator_block_carries_crc_like_autocad() {
+        let bytes = encode_object_map_section(&[]).unwrap();
+        assert_eq!(bytes, vec![0x00, 0x02, 0x01, 0xD0]);
+    }
+
+    #[test]
+    fn object_map_fills_block_exactly_then_splits() {
+        // 1015 two-byte entries plus the size field fill a block exactly.
+        let full = (OBJECT_MAP_BLOCK_LIMIT as u64 - 2) / 2;
+        let bytes = encode_object_map_section(&dense_refs(full)).unwrap();
+        assert_eq!(block_sizes(&bytes), vec![OBJECT_MAP_BLOCK_LIMIT]);
+        assert_eq!(parse(&bytes).len(), full as usize);
+
+        // One more entry opens a second block whose deltas restart from
+        // zero, so its single entry spells out the absolute handle/offset.
+        let refs = dense_refs(full + 1);
+        let bytes = encode_object_map_section(&refs).unwrap();
+        let sizes = block_sizes(&bytes);
+        assert_eq!(sizes[0], OBJECT_MAP_BLOCK_LIMIT);
+        assert_eq!(sizes.len(), 2);
+        let index = parse(&bytes);
+        assert_eq!(index.len(), refs.len());
+        let last = refs.last().unwrap();
+        assert_eq!(index.get(last.handle).unwrap().offset, last.offset);
+    }
+
+    #[test]
+    fn object_map_never_splits_an_entry() {
+        // Four-byte entries: 507 fill 2028 bytes, leaving two spare bytes
+        // that must not hold half of the next entry.
+        let refs: Vec<ObjectRef> = (1..=600u64)
+            .map(|n| ObjectRef {
+                handle: Handle(n * 100),
+                offset: n * 100,
+            })
+            .collect();
+        let bytes = encode_object_map_section(&refs).unwrap();
+        let sizes = block_sizes(&bytes);
+        assert_eq!(sizes[0], 2 + 507 * 4);
+        assert_eq!(parse(&bytes).len(), refs.len());
+    }
+
+    #[test]
+    fn large_object_map_roundtrips_past_64_kib() {
+        let refs: Vec<ObjectRef> = (1..=40_000u64)
+            .map(|n| ObjectRef {
+                handle: Handle(n * 3),
+                offset: 0x200 + n * 97,
+            })
+            .collect();
+        let bytes = encode_object_map_section(&refs).unwrap();
+        assert!(bytes.len() > usize::from(u16::MAX));
+        assert!(block_sizes(&bytes).len() > 32);
+
+        let index = parse(&bytes);
+        assert_eq!(index.len(), refs.len());
+        for obj in &refs {
+            assert_eq!(index.get(obj.handle).unwrap().offset, obj.offset);
+        }
+    }
+
+    #[test]
+    fn modular_chars_grow_past_four_bytes() {
+        // 27 bits fit in four bytes; one more bit needs a fifth.
+        assert_eq!(encode_modular_char((1 << 27) - 1).unwrap().len(), 4);
+        assert_eq!(encode_modular_char(1 << 27).unwrap().len(), 5);
+        assert_eq!(encode_modular_char(-(1 << 27)).unwrap().len(), 5);
+        assert_eq!(encode_modular_char((1 << 62) - 1).unwrap().len(), 9);
+        assert!(encode_modular_char(1 << 62).is_err());
+    }
+
+    #[test]
+    fn object_map_roundtrips_offsets_past_128_mib() {
+        // Each block starts with an absolute offset, which is past 2^27 here
+        // and past 4 GiB for the last entries.
+        let refs: Vec<ObjectRef> = (1..=3_000u64)
+            .map(|n| ObjectRef {
+                handle: Handle(n),
+                offset: (1 << 27) + n * 0x20_0000,
+            })
+            .collect();
+        let bytes = encode_object_map_section(&refs).unwrap();
+        assert!(block_sizes(&bytes).len() > 1);
+
+        let index = parse(&bytes);
+        assert_eq!(index.len(), refs.len());
+        for obj in &refs {
+            assert_eq!(index.get(obj.handle).unwrap().offset, obj.offset);
+        }
     }
 }
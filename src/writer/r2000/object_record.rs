@@ -1,4 +1,5 @@
-use crate::bit::{BitWriter, Endian};
+use super::crc::{crc16, CRC_SEED};
+use crate::bit::BitWriter;
 use crate::core::error::{DwgError, ErrorKind};
 use crate::core::result::Result;
 
@@ -8,6 +9,8 @@ pub fn encode_ms_value(value: u32) -> Result<Vec<u8>> {
     Ok(writer.into_bytes())
 }
 
+/// Frames `body` as an object record: its MS size, the body and the CRC
+/// of both.
 pub fn encode_object_record(body: &[u8]) -> Result<Vec<u8>> {
     if body.is_empty() {
         return Err(DwgError::new(
@@ -24,9 +27,8 @@ pub fn encode_object_record(body: &[u8]) -> Result<Vec<u8>> {
 
     let mut out = encode_ms_value(body.len() as u32)?;
     out.extend_from_slice(body);
-    let mut tail = BitWriter::new();
-    tail.write_rs(Endian::Little, 0)?;
-    out.extend_from_slice(&tail.into_bytes());
+    let crc = crc16(CRC_SEED, &out);
+    out.extend_from_slice(&crc.to_le_bytes());
     Ok(out)
 }
 
@@ -0,0 +1,118 @@
+use crate::bit::{BitWriter, Endian};
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::objects::XDataValue;
+
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+#[derive(Debug, Clone)]
+pub struct AppIdEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub name: String,
+    /// String xdata items attached to the entry under its own name.
+    pub xdata_strings: Vec<String>,
+    pub codepage: u16,
+}
+
+pub fn encode_app_id_object_payload(input: &AppIdEncodeInput) -> Result<Vec<u8>> {
+    let xdata = encode_xdata_strings(&input.xdata_strings, input.codepage)?;
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: (!xdata.is_empty()).then_some((input.handle, xdata.as_slice())),
+    };
+    encode_object_payload(
+        0x43,
+        common,
+        |writer| {
+            writer.write_tv(&input.name)?;
+            writer.write_b(0)?; // 64-flag
+            writer.write_bs(0)?; // xrefindex + 1
+            writer.write_b(0)?; // xdep
+            writer.write_rc(0)?; // unknown
+            Ok(())
+        },
+        |writer| writer.write_h(0x05, 0), // xref block
+    )
+}
+
+/// Encodes R2000 extended data items given under their DXF group codes:
+/// strings (1000), braces (1002), layer and entity handles (1003, 1005),
+/// binary chunks (1004), points (1010-1013), reals (1040-1042) and
+/// integers (1070, 1071). Strings and chunks hold at most 255 bytes.
+pub fn encode_xdata_items(items: &[(u16, XDataValue)], codepage: u16) -> Result<Vec<u8>> {
+    let mut writer = BitWriter::new();
+    for (code, value) in items {
+        let item_code = code
+            .checked_sub(1000)
+            .and_then(|code| u8::try_from(code).ok());
+        match (item_code, value) {
+            (Some(0), XDataValue::String(text)) => {
+                let bytes = text.as_bytes();
+                writer.write_rc(0)?;
+                writer.write_rc(short_length(bytes.len())?)?;
+                writer.write_rs(Endian::Little, codepage)?;
+                writer.write_rcs(bytes)?;
+            }
+            (Some(2), XDataValue::String(brace)) if brace == "{" || brace == "}" => {
+                writer.write_rc(2)?;
+                writer.write_rc(u8::from(brace == "}"))?;
+            }
+            (Some(code @ (3 | 5)), XDataValue::Handle(handle)) => {
+                writer.write_rc(code)?;
+                writer.write_rcs(&handle.to_be_bytes())?;
+            }
+            (Some(4), XDataValue::Binary(bytes)) => {
+                writer.write_rc(4)?;
+                writer.write_rc(short_length(bytes.len())?)?;
+                writer.write_rcs(bytes)?;
+            }
+            (Some(code @ 10..=13), XDataValue::Point((x, y, z))) => {
+                writer.write_rc(code)?;
+                writer.write_rd(Endian::Little, *x)?;
+                writer.write_rd(Endian::Little, *y)?;
+                writer.write_rd(Endian::Little, *z)?;
+            }
+            (Some(code @ 40..=42), XDataValue::Real(value)) => {
+                writer.write_rc(code)?;
+                writer.write_rd(Endian::Little, *value)?;
+            }
+            (Some(70), XDataValue::Int16(value)) => {
+                writer.write_rc(70)?;
+                writer.write_rs(Endian::Little, *value as u16)?;
+            }
+            (Some(71), XDataValue::Int32(value)) => {
+                writer.write_rc(71)?;
+                writer.write_rl(Endian::Little, *value as u32)?;
+            }
+            _ => {
+                return Err(DwgError::new(
+                    ErrorKind::Format,
+                    format!("invalid extended data item for group code {code}: {value:?}"),
+                ))
+            }
+        }
+    }
+    Ok(writer.into_bytes())
+}
+
+/// Encodes R2000 string xdata items (code 0): an RC length, the RS
+/// codepage, then the bytes.
+fn encode_xdata_strings(strings: &[String], codepage: u16) -> Result<Vec<u8>> {
+    let items: Vec<(u16, XDataValue)> = strings
+        .iter()
+        .map(|text| (1000, XDataValue::String(text.clone())))
+        .collect();
+    encode_xdata_items(&items, codepage)
+}
+
+/// The RC length of a string or binary chunk.
+pub(crate) fn short_length(len: usize) -> Result<u8> {
+    u8::try_from(len).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!("xdata value too long: {len} bytes"),
+        )
+    })
+}
@@ -0,0 +1,75 @@
+use crate::bit::{BitWriter, Endian};
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CommonObjectEncodeInput<'a> {
+    pub handle: u64,
+    pub owner_handle: u64,
+    /// Extended data as the registered application handle and its raw bytes.
+    pub eed: Option<(u64, &'a [u8])>,
+}
+
+/// Encodes a non-entity object: the R2000 object header, the body from
+/// `write_body`, then the owner and extension dictionary handles followed
+/// by whatever `write_handles` appends.
+pub fn encode_object_payload<F, H>(
+    type_code: u16,
+    common: CommonObjectEncodeInput<'_>,
+    write_body: F,
+    write_handles: H,
+) -> Result<Vec<u8>>
+where
+    F: FnOnce(&mut BitWriter) -> Result<()>,
+    H: FnOnce(&mut BitWriter) -> Result<()>,
+{
+    if common.handle == 0 {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            "object handle must be non-zero",
+        ));
+    }
+
+    let mut type_prefix = BitWriter::new();
+    type_prefix.write_bs(type_code)?;
+
+    let mut pre_handle = BitWriter::new();
+    pre_handle.write_h(0x00, common.handle)?;
+    if let Some((app_handle, data)) = common.eed {
+        if data.is_empty() || data.len() > u16::MAX as usize {
+            return Err(DwgError::new(
+                ErrorKind::Unsupported,
+                format!("extended data size out of range: {}", data.len()),
+            ));
+        }
+        pre_handle.write_bs(data.len() as u16)?;
+        pre_handle.write_h(0x05, app_handle)?;
+        pre_handle.write_rcs(data)?;
+    }
+    pre_handle.write_bs(0)?; // ext_size
+    pre_handle.write_bl(0)?; // num_of_reactors
+    write_body(&mut pre_handle)?;
+
+    let mut handle_stream = BitWriter::new();
+    handle_stream.write_h(0x04, common.owner_handle)?;
+    handle_stream.write_h(0x03, 0)?; // xdic_obj
+    write_handles(&mut handle_stream)?;
+
+    let obj_size_bits = type_prefix
+        .len_bits()
+        .saturating_add(32)
+        .saturating_add(pre_handle.len_bits());
+    if obj_size_bits > u32::MAX as u64 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!("object data bits exceed u32: {obj_size_bits}"),
+        ));
+    }
+
+    let mut out = BitWriter::new();
+    out.write_bits_from_bytes(&type_prefix.to_bytes(), type_prefix.len_bits())?;
+    out.write_rl(Endian::Little, obj_size_bits as u32)?;
+    out.write_bits_from_bytes(&pre_handle.to_bytes(), pre_handle.len_bits())?;
+    out.write_bits_from_bytes(&handle_stream.to_bytes(), handle_stream.len_bits())?;
+    Ok(out.into_bytes())
+}
@@ -0,0 +1,42 @@
+use crate::core::result::Result;
+
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+/// Keep existing entries when cloning into another drawing.
+const CLONING_KEEP_EXISTING: u16 = 1;
+
+#[derive(Debug, Clone)]
+pub struct DictionaryEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    /// Entry names and the objects they refer to, in stored order.
+    pub entries: Vec<(String, u64)>,
+}
+
+/// Encodes a DICTIONARY object that soft-owns its entries.
+pub fn encode_dictionary_object_payload(input: &DictionaryEncodeInput) -> Result<Vec<u8>> {
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: None,
+    };
+    encode_object_payload(
+        0x2A,
+        common,
+        |writer| {
+            writer.write_bl(input.entries.len() as u32)?;
+            writer.write_bs(CLONING_KEEP_EXISTING)?;
+            writer.write_rc(0)?; // hard owner flag
+            for (name, _handle) in &input.entries {
+                writer.write_tv(name)?;
+            }
+            Ok(())
+        },
+        |writer| {
+            for (_name, handle) in &input.entries {
+                writer.write_h(0x02, *handle)?;
+            }
+            Ok(())
+        },
+    )
+}
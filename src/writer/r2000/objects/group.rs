@@ -0,0 +1,39 @@
+use crate::core::result::Result;
+
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+#[derive(Debug, Clone)]
+pub struct GroupEncodeInput {
+    pub handle: u64,
+    /// The `ACAD_GROUP` dictionary.
+    pub owner_handle: u64,
+    pub description: String,
+    pub unnamed: bool,
+    pub selectable: bool,
+    pub entities: Vec<u64>,
+}
+
+/// Encodes a GROUP object whose members are hard pointers to `entities`.
+pub fn encode_group_object_payload(input: &GroupEncodeInput) -> Result<Vec<u8>> {
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: None,
+    };
+    encode_object_payload(
+        0x48,
+        common,
+        |writer| {
+            writer.write_tv(&input.description)?;
+            writer.write_bs(u16::from(input.unnamed))?;
+            writer.write_bs(u16::from(input.selectable))?;
+            writer.write_bl(input.entities.len() as u32)
+        },
+        |writer| {
+            for entity in &input.entities {
+                writer.write_h(0x05, *entity)?;
+            }
+            Ok(())
+        },
+    )
+}
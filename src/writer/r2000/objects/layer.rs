@@ -0,0 +1,51 @@
+use crate::core::result::Result;
+use crate::objects::layer::{LAYER_OFF, LAYER_PLOTTED};
+
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+/// Plotted, with the DEFAULT lineweight in bits 5-9.
+const LAYER_FLAGS: u16 = LAYER_PLOTTED | (31 << 5);
+
+#[derive(Debug, Clone)]
+pub struct LayerEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub name: String,
+    pub color_index: u16,
+    /// `objects::layer::LAYER_*` bits; `LAYER_OFF` also negates the color.
+    pub flags: u16,
+    /// LTYPE handle, or 0 for Continuous.
+    pub linetype_handle: u64,
+}
+
+/// Encodes a LAYER table entry that is plotted with the default
+/// lineweight. Its plot style reference is left null.
+pub fn encode_layer_object_payload(input: &LayerEncodeInput) -> Result<Vec<u8>> {
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: None,
+    };
+    encode_object_payload(
+        0x33,
+        common,
+        |writer| {
+            writer.write_tv(&input.name)?;
+            writer.write_b(0)?; // 64-flag
+            writer.write_bs(0)?; // xrefindex + 1
+            writer.write_b(0)?; // xdep
+            writer.write_bs(LAYER_FLAGS | input.flags)?;
+            if input.flags & LAYER_OFF != 0 {
+                writer.write_bs((input.color_index as i16).wrapping_neg() as u16)?;
+            } else {
+                writer.write_bs(input.color_index)?;
+            }
+            Ok(())
+        },
+        |writer| {
+            writer.write_h(0x05, 0)?; // xref block
+            writer.write_h(0x05, 0)?; // plot style
+            writer.write_h(0x05, input.linetype_handle) // linetype
+        },
+    )
+}
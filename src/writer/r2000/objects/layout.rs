@@ -0,0 +1,96 @@
+use crate::bit::{BitWriter, Endian};
+use crate::core::result::Result;
+use crate::objects::PlotSettings;
+
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+#[derive(Debug, Clone)]
+pub struct LayoutEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub name: String,
+    pub tab_order: u32,
+    pub flags: u16,
+    pub plot_settings: PlotSettings,
+    pub limits_min: (f64, f64),
+    pub limits_max: (f64, f64),
+    pub extents_min: (f64, f64, f64),
+    pub extents_max: (f64, f64, f64),
+    pub block_record_handle: u64,
+    pub last_viewport_handle: u64,
+}
+
+pub fn encode_layout_object_payload(input: &LayoutEncodeInput) -> Result<Vec<u8>> {
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: None,
+    };
+    encode_object_payload(
+        0x52,
+        common,
+        |writer| write_layout_body(writer, input),
+        |writer| {
+            writer.write_h(0x04, input.block_record_handle)?;
+            writer.write_h(0x04, input.last_viewport_handle)?;
+            writer.write_h(0x05, 0)?; // base ucs
+            writer.write_h(0x05, 0)?; // named ucs
+            Ok(())
+        },
+    )
+}
+
+fn write_layout_body(writer: &mut BitWriter, input: &LayoutEncodeInput) -> Result<()> {
+    let settings = &input.plot_settings;
+    writer.write_tv(settings.page_setup_name.as_deref().unwrap_or(""))?;
+    writer.write_tv(settings.printer_name.as_deref().unwrap_or(""))?;
+    writer.write_bs(settings.flags)?;
+    for margin in settings.margins {
+        writer.write_bd(margin)?;
+    }
+    writer.write_bd(settings.paper_width)?;
+    writer.write_bd(settings.paper_height)?;
+    writer.write_tv(settings.paper_size.as_deref().unwrap_or(""))?;
+    writer.write_bd(settings.plot_origin.0)?;
+    writer.write_bd(settings.plot_origin.1)?;
+    writer.write_bs(settings.paper_units)?;
+    writer.write_bs(settings.rotation)?;
+    writer.write_bs(settings.plot_type)?;
+    writer.write_bd(settings.window_min.0)?;
+    writer.write_bd(settings.window_min.1)?;
+    writer.write_bd(settings.window_max.0)?;
+    writer.write_bd(settings.window_max.1)?;
+    writer.write_tv("")?; // plot view name
+    writer.write_bd(settings.real_world_units)?;
+    writer.write_bd(settings.drawing_units)?;
+    writer.write_tv(settings.plot_style_table.as_deref().unwrap_or(""))?;
+    writer.write_bs(settings.scale_type)?;
+    writer.write_bd(settings.scale_factor)?;
+    writer.write_bd(0.0)?; // paper image origin x
+    writer.write_bd(0.0)?; // paper image origin y
+
+    writer.write_tv(&input.name)?;
+    writer.write_bl(input.tab_order)?;
+    writer.write_bs(input.flags)?;
+    writer.write_3bd(0.0, 0.0, 0.0)?; // ucs origin
+    writer.write_rd(Endian::Little, input.limits_min.0)?;
+    writer.write_rd(Endian::Little, input.limits_min.1)?;
+    writer.write_rd(Endian::Little, input.limits_max.0)?;
+    writer.write_rd(Endian::Little, input.limits_max.1)?;
+    writer.write_3bd(0.0, 0.0, 0.0)?; // insertion base
+    writer.write_3bd(1.0, 0.0, 0.0)?; // ucs x axis
+    writer.write_3bd(0.0, 1.0, 0.0)?; // ucs y axis
+    writer.write_bd(0.0)?; // elevation
+    writer.write_bs(0)?; // orthographic type
+    writer.write_3bd(
+        input.extents_min.0,
+        input.extents_min.1,
+        input.extents_min.2,
+    )?;
+    writer.write_3bd(
+        input.extents_max.0,
+        input.extents_max.1,
+        input.extents_max.2,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::bit::Endian;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+/// Size of the R13-R2004 text area that follows the dash elements.
+const LTYPE_STRING_AREA_LEN: usize = 256;
+
+#[derive(Debug, Clone)]
+pub struct LtypeEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub name: String,
+    pub description: String,
+    pub pattern_length: f64,
+    /// Dash lengths: positive for a dash, negative for a gap, zero for a dot.
+    pub dashes: Vec<f64>,
+}
+
+/// Encodes a simple LTYPE table entry; dashes carry no shapes or text.
+pub fn encode_ltype_object_payload(input: &LtypeEncodeInput) -> Result<Vec<u8>> {
+    if input.dashes.len() > u8::MAX as usize {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!("too many linetype dashes: {}", input.dashes.len()),
+        ));
+    }
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: None,
+    };
+    encode_object_payload(
+        0x39,
+        common,
+        |writer| {
+            writer.write_tv(&input.name)?;
+            writer.write_b(0)?; // 64-flag
+            writer.write_bs(0)?; // xrefindex + 1
+            writer.write_b(0)?; // xdep
+            writer.write_tv(&input.description)?;
+            writer.write_bd(input.pattern_length)?;
+            writer.write_rc(b'A')?; // alignment
+            writer.write_rc(input.dashes.len() as u8)?;
+            for length in &input.dashes {
+                writer.write_bd(*length)?;
+                writer.write_bs(0)?; // shape code
+                writer.write_rd(Endian::Little, 0.0)?; // x offset
+                writer.write_rd(Endian::Little, 0.0)?; // y offset
+                writer.write_bd(1.0)?; // scale
+                writer.write_bd(0.0)?; // rotation
+                writer.write_bs(0)?; // shape flag
+            }
+            writer.write_rcs(&[0; LTYPE_STRING_AREA_LEN])?;
+            Ok(())
+        },
+        |writer| {
+            writer.write_h(0x05, 0)?; // xref block
+            for _ in &input.dashes {
+                writer.write_h(0x05, 0)?; // shape file
+            }
+            Ok(())
+        },
+    )
+}
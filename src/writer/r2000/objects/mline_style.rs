@@ -0,0 +1,55 @@
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+/// Linetype index of a style line following its layer.
+const BYLAYER_LINETYPE_INDEX: u16 = 0x7FFF;
+
+#[derive(Debug, Clone)]
+pub struct MLineStyleEncodeInput {
+    pub handle: u64,
+    pub owner_handle: u64,
+    pub name: String,
+    pub description: String,
+    pub flags: u16,
+    pub fill_color_index: u16,
+    pub start_angle: f64,
+    pub end_angle: f64,
+    /// `(offset, color_index)` of each line; lines follow the layer linetype.
+    pub elements: Vec<(f64, u16)>,
+}
+
+pub fn encode_mline_style_object_payload(input: &MLineStyleEncodeInput) -> Result<Vec<u8>> {
+    if input.elements.len() > u8::MAX as usize {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!("too many mline style lines: {}", input.elements.len()),
+        ));
+    }
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: None,
+    };
+    encode_object_payload(
+        0x49,
+        common,
+        |writer| {
+            writer.write_tv(&input.name)?;
+            writer.write_tv(&input.description)?;
+            writer.write_bs(input.flags)?;
+            writer.write_bs(input.fill_color_index)?;
+            writer.write_bd(input.start_angle)?;
+            writer.write_bd(input.end_angle)?;
+            writer.write_rc(input.elements.len() as u8)?;
+            for &(offset, color_index) in &input.elements {
+                writer.write_bd(offset)?;
+                writer.write_bs(color_index)?;
+                writer.write_bs(BYLAYER_LINETYPE_INDEX)?;
+            }
+            Ok(())
+        },
+        |_| Ok(()),
+    )
+}
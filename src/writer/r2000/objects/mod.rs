@@ -0,0 +1,18 @@
+pub mod app_id;
+pub mod common;
+pub mod dictionary;
+pub mod group;
+pub mod layer;
+pub mod layout;
+pub mod ltype;
+pub mod mline_style;
+pub mod xrecord;
+
+pub use app_id::{encode_app_id_object_payload, encode_xdata_items, AppIdEncodeInput};
+pub use dictionary::{encode_dictionary_object_payload, DictionaryEncodeInput};
+pub use group::{encode_group_object_payload, GroupEncodeInput};
+pub use layer::{encode_layer_object_payload, LayerEncodeInput};
+pub use layout::{encode_layout_object_payload, LayoutEncodeInput};
+pub use ltype::{encode_ltype_object_payload, LtypeEncodeInput};
+pub use mline_style::{encode_mline_style_object_payload, MLineStyleEncodeInput};
+pub use xrecord::{encode_xrecord_object_payload, XRecordEncodeInput};
@@ -0,0 +1,89 @@
+use crate::bit::{BitWriter, Endian};
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::objects::{group_code_kind, XDataValue};
+
+use super::app_id::short_length;
+use super::common::{encode_object_payload, CommonObjectEncodeInput};
+
+/// Keep existing records when cloning into another drawing.
+const CLONING_KEEP_EXISTING: u16 = 1;
+
+#[derive(Debug, Clone)]
+pub struct XRecordEncodeInput {
+    pub handle: u64,
+    /// The dictionary the record is an entry of.
+    pub owner_handle: u64,
+    /// Values under their DXF group codes.
+    pub items: Vec<(u16, XDataValue)>,
+    pub codepage: u16,
+}
+
+/// Encodes an XRECORD object. Handle values are not supported, since they
+/// also need references in the handle stream.
+pub fn encode_xrecord_object_payload(input: &XRecordEncodeInput) -> Result<Vec<u8>> {
+    let data = encode_xrecord_items(&input.items, input.codepage)?;
+    let common = CommonObjectEncodeInput {
+        handle: input.handle,
+        owner_handle: input.owner_handle,
+        eed: None,
+    };
+    encode_object_payload(
+        0x4F,
+        common,
+        |writer| {
+            writer.write_bl(data.len() as u32)?;
+            writer.write_rcs(&data)?;
+            writer.write_bs(CLONING_KEEP_EXISTING)
+        },
+        |_| Ok(()),
+    )
+}
+
+fn encode_xrecord_items(items: &[(u16, XDataValue)], codepage: u16) -> Result<Vec<u8>> {
+    let mut writer = BitWriter::new();
+    for (code, value) in items {
+        if group_code_kind(*code) != Some(value.kind()) {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                format!("invalid XRECORD value for group code {code}: {value:?}"),
+            ));
+        }
+        writer.write_rs(Endian::Little, *code)?;
+        match value {
+            XDataValue::String(text) => {
+                let bytes = text.as_bytes();
+                let length = u16::try_from(bytes.len()).map_err(|_| {
+                    DwgError::new(
+                        ErrorKind::Unsupported,
+                        format!("XRECORD string too long: {} bytes", bytes.len()),
+                    )
+                })?;
+                writer.write_rs(Endian::Little, length)?;
+                writer.write_rc(codepage as u8)?;
+                writer.write_rcs(bytes)?;
+            }
+            XDataValue::Real(value) => writer.write_rd(Endian::Little, *value)?,
+            XDataValue::Point((x, y, z)) => {
+                writer.write_rd(Endian::Little, *x)?;
+                writer.write_rd(Endian::Little, *y)?;
+                writer.write_rd(Endian::Little, *z)?;
+            }
+            XDataValue::Int8(value) => writer.write_rc(*value as u8)?,
+            XDataValue::Int16(value) => writer.write_rs(Endian::Little, *value as u16)?,
+            XDataValue::Int32(value) => writer.write_rl(Endian::Little, *value as u32)?,
+            XDataValue::Int64(value) => writer.write_rcs(&value.to_le_bytes())?,
+            XDataValue::Binary(bytes) => {
+                writer.write_rc(short_length(bytes.len())?)?;
+                writer.write_rcs(bytes)?;
+            }
+            XDataValue::Handle(_) => {
+                return Err(DwgError::new(
+                    ErrorKind::Unsupported,
+                    format!("XRECORD handle values are not written (group code {code})"),
+                ))
+            }
+        }
+    }
+    Ok(writer.into_bytes())
+}
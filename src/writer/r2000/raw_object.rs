@@ -0,0 +1,323 @@
+//! Copying of object records with rewritten handles.
+//!
+//! Only the parts of a record that hold handles are re-encoded: the object
+//! handle, the EED application handles and the handle stream. Everything
+//! else is copied bit for bit, so records of any type can be moved between
+//! drawings without decoding them. The writer uses this to carry proxies
+//! and objects of application classes over from a source drawing.
+
+use std::collections::HashMap;
+
+use super::object_record::encode_object_record;
+use crate::bit::{BitReader, BitWriter, Endian};
+use crate::core::config::ParseConfig;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::decoder::Decoder;
+use crate::dwg::r2000::load_class_definitions;
+use crate::dwg::version::DwgVersion;
+use crate::objects::parse_object_record;
+use crate::writer::ir::RawObjectDef;
+
+const PROXY_ENTITY_TYPE_CODE: u16 = 0x1F2;
+const PROXY_OBJECT_TYPE_CODE: u16 = 0x1F3;
+
+/// Classes AutoCAD itself registers. ezdwg reads their objects as part of
+/// the drawing structure, and the writer either rebuilds them or leaves
+/// them out on purpose.
+const STANDARD_CLASS_NAMES: &[&str] = &[
+    "ACAD_TABLE",
+    "ACDBDETAILVIEWSTYLE",
+    "ACDBDICTIONARYWDFLT",
+    "ACDBPLACEHOLDER",
+    "ACDBSECTIONVIEWSTYLE",
+    "ARC_DIMENSION",
+    "CELLSTYLEMAP",
+    "DBCOLOR",
+    "DICTIONARYVAR",
+    "DIMASSOC",
+    "FIELD",
+    "FIELDLIST",
+    "GEODATA",
+    "GROUP",
+    "HATCH",
+    "IDBUFFER",
+    "IMAGE",
+    "IMAGEDEF",
+    "IMAGEDEF_REACTOR",
+    "LAYER_FILTER",
+    "LAYER_INDEX",
+    "LAYOUT",
+    "LIGHT",
+    "LWPOLYLINE",
+    "MATERIAL",
+    "MESH",
+    "MLEADERSTYLE",
+    "MULTILEADER",
+    "OLE2FRAME",
+    "PLOTSETTINGS",
+    "RASTERVARIABLES",
+    "SCALE",
+    "SORTENTSTABLE",
+    "SPATIAL_FILTER",
+    "SPATIAL_INDEX",
+    "SUN",
+    "TABLEGEOMETRY",
+    "TABLESTYLE",
+    "VISUALSTYLE",
+    "WIPEOUT",
+    "WIPEOUTVARIABLES",
+    "XRECORD",
+];
+
+/// The records of an AC1015 drawing that ezdwg cannot decode: proxy
+/// entities and objects, and objects whose class is not one of AutoCAD's
+/// own. Records that fail to parse are skipped.
+pub fn collect_unknown_objects(bytes: &[u8]) -> Result<Vec<RawObjectDef>> {
+    let config = ParseConfig::default();
+    let decoder = Decoder::new(bytes, config.clone())?;
+    if *decoder.version() != DwgVersion::R2000 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "copying raw objects only supports AC1015, got {}",
+                decoder.version().as_str()
+            ),
+        ));
+    }
+    let classes: HashMap<u16, _> = load_class_definitions(bytes, &config)?
+        .into_iter()
+        .map(|class| (class.class_number, class))
+        .collect();
+    let mut index = decoder.build_object_index()?.objects;
+    index.sort_by_key(|obj| obj.handle.0);
+
+    let mut objects = Vec::new();
+    for obj in index {
+        let Ok(record) = decoder.parse_object_record(obj.offset) else {
+            continue;
+        };
+        let Ok(type_code) = record.bit_reader().read_bs() else {
+            continue;
+        };
+        let class = match type_code {
+            PROXY_ENTITY_TYPE_CODE | PROXY_OBJECT_TYPE_CODE => None,
+            code if code >= 500 => match classes.get(&code) {
+                Some(class)
+                    if STANDARD_CLASS_NAMES
+                        .contains(&class.dxf_name.to_ascii_uppercase().as_str()) =>
+                {
+                    continue
+                }
+                Some(class) => Some(class.clone()),
+                None => continue,
+            },
+            _ => continue,
+        };
+        objects.push(RawObjectDef {
+            handle: obj.handle.0,
+            data: record.body.to_vec(),
+            class,
+        });
+    }
+    Ok(objects)
+}
+
+/// Rewrites the handles of the AC1015 object record `record`, framed as in
+/// the file by its size and CRC, through `remap`. The object handle, the
+/// EED application handles and every handle stream reference are renamed;
+/// handles missing from `remap` stay as they are. Returns the new record
+/// with its size and CRC updated.
+pub fn remap_record_handles(record: &[u8], remap: &HashMap<u64, u64>) -> Result<Vec<u8>> {
+    let parsed = parse_object_record(record, 0)?;
+    let mut reader = parsed.bit_reader();
+    let _type_code = reader.read_bs()?;
+    let _obj_size = reader.read_rl(Endian::Little)?;
+    let handle = reader.read_h()?.value;
+    let lookup = |value: u64| remap.get(&value).copied().unwrap_or(value);
+    let data = remap_raw_object_record(&parsed.body, None, lookup(handle), lookup)?;
+    encode_object_record(&data)
+}
+
+/// Re-encodes the record `data` of a copied object as `handle`, with
+/// `type_code` when given, passing every non-null handle it refers to
+/// through `remap`. Relative references are re-expressed against the new
+/// handle. Returns the record data without the size prefix and CRC.
+pub(crate) fn remap_raw_object_record(
+    data: &[u8],
+    type_code: Option<u16>,
+    handle: u64,
+    remap: impl Fn(u64) -> u64,
+) -> Result<Vec<u8>> {
+    let total_bits = (data.len() as u64).saturating_mul(8);
+    let mut reader = BitReader::new(data);
+    let source_type = reader.read_bs()?;
+    let obj_size = u64::from(reader.read_rl(Endian::Little)?);
+    let source_handle = reader.read_h()?.value;
+
+    let mut type_prefix = BitWriter::new();
+    type_prefix.write_bs(type_code.unwrap_or(source_type))?;
+
+    let mut pre_handle = BitWriter::new();
+    pre_handle.write_h(0x00, handle)?;
+    loop {
+        let size = reader.read_bs()?;
+        pre_handle.write_bs(size)?;
+        if size == 0 {
+            break;
+        }
+        let app = reader.read_h()?;
+        pre_handle.write_h(app.code, remap_value(app.value, &remap))?;
+        pre_handle.write_rcs(&reader.read_rcs(usize::from(size))?)?;
+    }
+    if obj_size < reader.tell_bits() || obj_size > total_bits {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("object data size out of range: {obj_size} bits"),
+        ));
+    }
+    copy_bits(&mut reader, &mut pre_handle, obj_size)?;
+
+    let mut handle_stream = BitWriter::new();
+    while total_bits.saturating_sub(reader.tell_bits()) >= 8 {
+        let start = reader.tell_bits();
+        let Ok(reference) = reader.read_h() else {
+            reader.set_bit_pos(start as u32);
+            break;
+        };
+        match reference.code {
+            0x06 | 0x08 | 0x0A | 0x0C => {
+                let target = remap_value(reference.resolve(source_handle), &remap);
+                let (code, offset) = match target {
+                    0 => (0x04, 0),
+                    target if target == handle.saturating_add(1) => (0x06, 0),
+                    target if target == handle.saturating_sub(1) => (0x08, 0),
+                    target if target >= handle => (0x0A, target - handle),
+                    target => (0x0C, handle - target),
+                };
+                handle_stream.write_h(code, offset)?;
+            }
+            code => handle_stream.write_h(code, remap_value(reference.value, &remap))?,
+        }
+    }
+    // Padding and whatever does not parse as handles stay unchanged.
+    copy_bits(&mut reader, &mut handle_stream, total_bits)?;
+
+    let obj_size_bits = type_prefix
+        .len_bits()
+        .saturating_add(32)
+        .saturating_add(pre_handle.len_bits());
+    let obj_size_bits = u32::try_from(obj_size_bits).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!("object data bits exceed u32: {obj_size_bits}"),
+        )
+    })?;
+    let mut out = BitWriter::new();
+    out.write_bits_from_bytes(&type_prefix.to_bytes(), type_prefix.len_bits())?;
+    out.write_rl(Endian::Little, obj_size_bits)?;
+    out.write_bits_from_bytes(&pre_handle.to_bytes(), pre_handle.len_bits())?;
+    out.write_bits_from_bytes(&handle_stream.to_bytes(), handle_stream.len_bits())?;
+    Ok(out.into_bytes())
+}
+
+fn remap_value(value: u64, remap: &impl Fn(u64) -> u64) -> u64 {
+    if value == 0 {
+        0
+    } else {
+        remap(value)
+    }
+}
+
+/// Copies the bits of `reader` up to `end_bit` to `writer`.
+pub(crate) fn copy_bits(
+    reader: &mut BitReader<'_>,
+    writer: &mut BitWriter,
+    end_bit: u64,
+) -> Result<()> {
+    while reader.tell_bits() < end_bit {
+        let chunk = (end_bit - reader.tell_bits()).min(64) as u8;
+        writer.write_bits_msb(reader.read_bits_msb(chunk)?, chunk)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remap_record_handles;
+    use crate::bit::Endian;
+    use crate::objects::parse_object_record;
+    use crate::writer::r2000::encode_object_record;
+    use crate::writer::r2000::objects::common::{encode_object_payload, CommonObjectEncodeInput};
+    use std::collections::HashMap;
+
+    fn record(handle: u64) -> Vec<u8> {
+        let eed = [0xAB, 0xCD];
+        let payload = encode_object_payload(
+            0x1F3,
+            CommonObjectEncodeInput {
+                handle,
+                owner_handle: 0x0C,
+                eed: Some((0x12, &eed)),
+            },
+            |writer| {
+                writer.write_tv("kept")?;
+                writer.write_bd(-1.25)
+            },
+            |writer| {
+                writer.write_h(0x05, 0x20)?;
+                writer.write_h(0x06, 0)?;
+                writer.write_h(0x0C, 3)
+            },
+        )
+        .unwrap();
+        encode_object_record(&payload).unwrap()
+    }
+
+    /// `(handle, eed_app, data, references)` of a record from [`record`].
+    fn read(record: &[u8]) -> (u64, u64, (String, f64), Vec<(u8, u64)>) {
+        let parsed = parse_object_record(record, 0).unwrap();
+        let mut reader = parsed.bit_reader();
+        assert_eq!(reader.read_bs().unwrap(), 0x1F3);
+        let obj_size = reader.read_rl(Endian::Little).unwrap();
+        let handle = reader.read_h().unwrap().value;
+        assert_eq!(reader.read_bs().unwrap(), 2);
+        let app = reader.read_h().unwrap().value;
+        assert_eq!(reader.read_rcs(2).unwrap(), vec![0xAB, 0xCD]);
+        assert_eq!(reader.read_bs().unwrap(), 0);
+        assert_eq!(reader.read_bl().unwrap(), 0);
+        let data = (reader.read_tv().unwrap(), reader.read_bd().unwrap());
+        reader.set_bit_pos(obj_size);
+        let mut references = Vec::new();
+        while reader.total_bits() - reader.tell_bits() >= 8 {
+            let reference = reader.read_h().unwrap();
+            references.push((reference.code, reference.resolve(handle)));
+        }
+        (handle, app, data, references)
+    }
+
+    #[test]
+    fn remaps_handles_without_touching_object_data() {
+        let source = record(0x30);
+        assert_eq!(
+            remap_record_handles(&source, &HashMap::new()).unwrap(),
+            source
+        );
+
+        let remap = HashMap::from([(0x30, 0x130), (0x0C, 0x0D), (0x12, 0x99), (0x2D, 0x2E)]);
+        let (handle, app, data, references) = read(&remap_record_handles(&source, &remap).unwrap());
+        assert_eq!((handle, app), (0x130, 0x99));
+        assert_eq!(data, ("kept".to_string(), -1.25));
+        // The relative references to 0x31 and 0x2D now count from 0x130.
+        assert_eq!(
+            references,
+            vec![
+                (0x04, 0x0D),
+                (0x03, 0),
+                (0x05, 0x20),
+                (0x0C, 0x31),
+                (0x0C, 0x2E)
+            ]
+        );
+    }
+}
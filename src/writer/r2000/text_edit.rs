@@ -0,0 +1,305 @@
+//! In-place edits of the strings of text entities in AC1015 files.
+//!
+//! Only the text value of each record is re-encoded; the fields around it,
+//! the EED and the handle stream are copied bit for bit, so styles,
+//! alignment and attribute tags survive the edit. The edited records are
+//! appended like those of [`super::apply_incremental_update`].
+
+use std::collections::BTreeMap;
+
+use super::encode_object_record;
+use super::incremental::append_object_records;
+use super::raw_object::copy_bits;
+use crate::bit::bit_reader::dwg_codepage_encoding;
+use crate::bit::{BitReader, BitWriter, Endian};
+use crate::core::config::ParseConfig;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::decoder::Decoder;
+use crate::dwg::version::DwgVersion;
+use crate::entities::common::parse_common_entity_header_r2000;
+
+const TEXT_TYPE_CODE: u16 = 0x01;
+const ATTRIB_TYPE_CODE: u16 = 0x02;
+const MTEXT_TYPE_CODE: u16 = 0x2C;
+
+/// Replaces the text of TEXT, ATTRIB and MTEXT entities of an AC1015 file
+/// and returns the updated file bytes, which start with the unchanged
+/// original contents.
+///
+/// `edits` pairs entity handles with their new text. MTEXT formatting
+/// codes in the new text are written as given. Characters the drawing's
+/// codepage cannot hold are written as `\U+XXXX` escapes.
+pub fn replace_entity_text(original: &[u8], edits: &[(u64, String)]) -> Result<Vec<u8>> {
+    let decoder = Decoder::new(original, ParseConfig::default())?;
+    if *decoder.version() != DwgVersion::R2000 {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            format!(
+                "text replacement only supports AC1015, got {}",
+                decoder.version().as_str()
+            ),
+        ));
+    }
+    let index = decoder.build_object_index()?;
+    let offsets: BTreeMap<u64, u64> = index
+        .objects
+        .iter()
+        .map(|obj| (obj.handle.0, obj.offset))
+        .collect();
+
+    let mut records = Vec::with_capacity(edits.len());
+    for (handle, text) in edits {
+        let offset = *offsets.get(handle).ok_or_else(|| {
+            DwgError::new(
+                ErrorKind::Resolve,
+                format!("handle {handle:#X} not found in object map"),
+            )
+        })?;
+        let record = decoder.parse_object_record(offset)?;
+        let value = encode_tv_bytes(text, decoder.codepage())?;
+        let data = replace_record_text(&record.body, *handle, &value)?;
+        records.push((*handle, encode_object_record(&data)?));
+    }
+    append_object_records(original, &index.objects, &records)
+}
+
+/// Re-encodes the record `data` of the text entity `handle` with the TV
+/// bytes `value` as its text. Returns the record data without the size
+/// prefix and CRC.
+fn replace_record_text(data: &[u8], handle: u64, value: &[u8]) -> Result<Vec<u8>> {
+    let total_bits = (data.len() as u64).saturating_mul(8);
+    let mut reader = BitReader::new(data);
+    let type_code = reader.read_bs()?;
+    let size_pos = reader.tell_bits();
+    let header = parse_common_entity_header_r2000(&mut reader)?;
+    match type_code {
+        TEXT_TYPE_CODE | ATTRIB_TYPE_CODE => skip_text_fields(&mut reader)?,
+        MTEXT_TYPE_CODE => skip_mtext_fields(&mut reader)?,
+        other => {
+            return Err(DwgError::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "handle {handle:#X} is not a TEXT, ATTRIB or MTEXT entity (type {other:#X})"
+                ),
+            ))
+        }
+    }
+    let text_start = reader.tell_bits();
+    let _text = reader.read_tv()?;
+    let text_end = reader.tell_bits();
+    let obj_size = u64::from(header.obj_size);
+    if obj_size < text_end || obj_size > total_bits {
+        return Err(DwgError::new(
+            ErrorKind::Format,
+            format!("object data size out of range: {obj_size} bits"),
+        ));
+    }
+
+    let mut text = BitWriter::new();
+    let length = u16::try_from(value.len()).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!("TV string too long: {} bytes", value.len()),
+        )
+    })?;
+    text.write_bs(length)?;
+    text.write_rcs(value)?;
+    let obj_size = (obj_size - (text_end - text_start)).saturating_add(text.len_bits());
+    let obj_size = u32::try_from(obj_size).map_err(|_| {
+        DwgError::new(
+            ErrorKind::Unsupported,
+            format!("object data bits exceed u32: {obj_size}"),
+        )
+    })?;
+
+    let mut out = BitWriter::new();
+    let mut reader = BitReader::new(data);
+    copy_bits(&mut reader, &mut out, size_pos)?;
+    out.write_rl(Endian::Little, obj_size)?;
+    reader.set_bit_pos((size_pos + 32) as u32);
+    copy_bits(&mut reader, &mut out, text_start)?;
+    out.write_bits_from_bytes(&text.to_bytes(), text.len_bits())?;
+    reader.set_bit_pos(text_end as u32);
+    copy_bits(&mut reader, &mut out, total_bits)?;
+    Ok(out.into_bytes())
+}
+
+/// Skips the R2000 TEXT and ATTRIB fields that precede the text value.
+fn skip_text_fields(reader: &mut BitReader<'_>) -> Result<()> {
+    let data_flags = reader.read_rc()?;
+    if (data_flags & 0x01) == 0 {
+        reader.read_rd(Endian::Little)?; // elevation
+    }
+    let insertion_x = reader.read_rd(Endian::Little)?;
+    let insertion_y = reader.read_rd(Endian::Little)?;
+    if (data_flags & 0x02) == 0 {
+        reader.read_dd(insertion_x)?;
+        reader.read_dd(insertion_y)?;
+    }
+    reader.read_be()?;
+    reader.read_bt()?;
+    if (data_flags & 0x04) == 0 {
+        reader.read_rd(Endian::Little)?; // oblique angle
+    }
+    if (data_flags & 0x08) == 0 {
+        reader.read_rd(Endian::Little)?; // rotation
+    }
+    reader.read_rd(Endian::Little)?; // height
+    if (data_flags & 0x10) == 0 {
+        reader.read_rd(Endian::Little)?; // width factor
+    }
+    Ok(())
+}
+
+/// Skips the R2000 MTEXT fields that precede the text value.
+fn skip_mtext_fields(reader: &mut BitReader<'_>) -> Result<()> {
+    reader.read_3bd()?; // insertion
+    reader.read_3bd()?; // extrusion
+    reader.read_3bd()?; // x-axis direction
+    reader.read_bd()?; // rect width
+    reader.read_bd()?; // text height
+    reader.read_bs()?; // attachment
+    reader.read_bs()?; // drawing direction
+    reader.read_bd()?; // extents height
+    reader.read_bd()?; // extents width
+    Ok(())
+}
+
+/// Encodes `text` in `codepage`, escaping characters it cannot hold as
+/// `\U+XXXX`.
+fn encode_tv_bytes(text: &str, codepage: Option<u16>) -> Result<Vec<u8>> {
+    let encoding = dwg_codepage_encoding(codepage);
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut units = [0u16; 2];
+    let mut buffer = [0u8; 4];
+    for ch in text.chars() {
+        if ch == '\0' {
+            return Err(DwgError::new(
+                ErrorKind::Format,
+                "text must not contain NUL characters",
+            ));
+        }
+        if ch.is_ascii() {
+            bytes.push(ch as u8);
+            continue;
+        }
+        if let Some(encoding) = encoding {
+            let (encoded, _, had_errors) = encoding.encode(ch.encode_utf8(&mut buffer));
+            if !had_errors {
+                bytes.extend_from_slice(&encoded);
+                continue;
+            }
+        }
+        for unit in ch.encode_utf16(&mut units) {
+            bytes.extend_from_slice(format!("\\U+{unit:04X}").as_bytes());
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_tv_bytes, replace_entity_text};
+    use crate::core::config::ParseConfig;
+    use crate::dwg::decoder::Decoder;
+    use crate::entities::{decode_mtext, decode_text};
+
+    fn texts(bytes: &[u8], type_code: u16) -> Vec<(u64, String)> {
+        let decoder = Decoder::new(bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let mut texts = Vec::new();
+        for obj_ref in index.objects {
+            let record = decoder
+                .parse_object_record(obj_ref.offset)
+                .expect("parse object record");
+            let mut reader = record.bit_reader();
+            if reader.read_bs().expect("type prefix") != type_code {
+                continue;
+            }
+            texts.push(match type_code {
+                0x01 => {
+                    let text = decode_text(&mut reader).expect("decode text");
+                    (text.handle, text.text)
+                }
+                _ => {
+                    let mtext = decode_mtext(&mut reader).expect("decode mtext");
+                    (mtext.handle, mtext.text)
+                }
+            });
+        }
+        texts
+    }
+
+    fn record_body(bytes: &[u8], handle: u64) -> Vec<u8> {
+        let decoder = Decoder::new(bytes, ParseConfig::default()).expect("decoder");
+        let index = decoder.build_object_index().expect("object index");
+        let obj_ref = index
+            .objects
+            .iter()
+            .find(|obj| obj.handle.0 == handle)
+            .expect("record in object map");
+        let record = decoder
+            .parse_object_record(obj_ref.offset)
+            .expect("parse object record");
+        record.body.to_vec()
+    }
+
+    #[test]
+    fn replaces_text_and_keeps_the_rest_of_the_record() {
+        let original = std::fs::read("test_dwg/text_2000.dwg").expect("sample");
+        let before = texts(&original, 0x01);
+        let (handle, text) = before.first().expect("text in sample").clone();
+
+        let updated = replace_entity_text(&original, &[(handle, "PRJ-0042 rev B".to_string())])
+            .expect("replace text");
+        let after = texts(&updated, 0x01);
+        assert_eq!(after.len(), before.len());
+        assert!(after.contains(&(handle, "PRJ-0042 rev B".to_string())));
+
+        // Writing the same text back reproduces the record bit for bit.
+        let same = replace_entity_text(&original, &[(handle, text)]).expect("replace text");
+        assert_eq!(record_body(&same, handle), record_body(&original, handle));
+    }
+
+    #[test]
+    fn replaces_mtext_and_rejects_other_entities() {
+        let original = std::fs::read("test_dwg/mtext_2000.dwg").expect("sample");
+        let (handle, _) = texts(&original, 0x2C)
+            .first()
+            .expect("mtext in sample")
+            .clone();
+        let updated = replace_entity_text(&original, &[(handle, "{\\C1;A}\\PB".to_string())])
+            .expect("replace mtext");
+        assert_eq!(
+            texts(&updated, 0x2C),
+            vec![(handle, "{\\C1;A}\\PB".to_string())]
+        );
+
+        let lines = std::fs::read("test_dwg/line_2000.dwg").expect("sample");
+        let decoder = Decoder::new(&lines, ParseConfig::default()).expect("decoder");
+        let line = decoder
+            .build_object_index()
+            .expect("object index")
+            .objects
+            .into_iter()
+            .find(|obj| {
+                let record = decoder.parse_object_record(obj.offset).expect("record");
+                record.bit_reader().read_bs().expect("type") == 0x13
+            })
+            .expect("line in sample");
+        assert!(replace_entity_text(&lines, &[(line.handle.0, "x".to_string())]).is_err());
+    }
+
+    #[test]
+    fn escapes_characters_outside_the_codepage() {
+        assert_eq!(
+            encode_tv_bytes("\u{00C4}\u{03A9}", Some(30)).expect("encode"),
+            b"\xC4\\U+03A9".to_vec()
+        );
+        assert_eq!(
+            encode_tv_bytes("\u{00C4}", None).expect("encode"),
+            b"\\U+00C4".to_vec()
+        );
+    }
+}
@@ -0,0 +1,243 @@
+//! Template drawings that written documents start from.
+//!
+//! The records copied from a template and its header variable data are
+//! reference counted, so every [`WriterDocument`] built from one template
+//! shares them and can be written on its own thread.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::bit::BitReader;
+use crate::core::config::ParseConfig;
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::dwg::decoder::Decoder;
+use crate::dwg::file_open::read_file;
+use crate::dwg::version::DwgVersion;
+use crate::objects::{decode_layer, decode_ltype, read_object_type};
+use crate::writer::ir::{LayerDef, LinetypeDef, RawObjectDef, WriterDocument, WriterMetadata};
+use crate::writer::r2000::{collect_unknown_objects, header_template};
+
+const LAYER_TYPE_CODE: u16 = 0x33;
+const LTYPE_TYPE_CODE: u16 = 0x39;
+const PROXY_ENTITY_TYPE_CODE: u16 = 0x1F2;
+
+#[derive(Debug, Clone)]
+pub struct WriterTemplate {
+    /// Records of the template that ezdwg cannot decode.
+    pub raw_objects: Arc<[RawObjectDef]>,
+    /// Header variable data, see [`header_template`].
+    pub header: Arc<[u8]>,
+}
+
+impl WriterTemplate {
+    /// Loads the AC1015 drawing `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(Self {
+            raw_objects: collect_unknown_objects(bytes)?.into(),
+            header: header_template(bytes)?.into(),
+        })
+    }
+
+    /// An empty document that copies the records and header variables of
+    /// the template.
+    pub fn document(&self) -> WriterDocument {
+        WriterDocument {
+            raw_objects: Arc::clone(&self.raw_objects),
+            metadata: WriterMetadata {
+                header_template: Some(Arc::clone(&self.header)),
+                ..WriterMetadata::default()
+            },
+            ..WriterDocument::default()
+        }
+    }
+}
+
+impl WriterDocument {
+    /// A document without entities that takes the tables and header
+    /// variables of the AC1015 template at `path`, such as a company
+    /// standard `.dwt`: its layers with their colors and linetypes, its
+    /// linetypes and the objects ezdwg cannot decode. Proxy entities are
+    /// left out with the rest of the model space.
+    ///
+    /// Text styles, dimension styles and block definitions are not copied
+    /// because the writer does not write STYLE, DIMSTYLE or BLOCK tables;
+    /// the header variables that select the current ones are kept.
+    pub fn from_template(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_template_bytes(&read_file(path)?)
+    }
+
+    /// [`WriterDocument::from_template`] for a template already in memory.
+    pub fn from_template_bytes(bytes: &[u8]) -> Result<Self> {
+        let decoder = Decoder::new(bytes, ParseConfig::default())?;
+        let version = decoder.version().clone();
+        if version != DwgVersion::R2000 {
+            return Err(DwgError::new(
+                ErrorKind::Unsupported,
+                format!("templates only support AC1015, got {}", version.as_str()),
+            ));
+        }
+        let mut layers = Vec::new();
+        let mut linetype_names: HashMap<u64, String> = HashMap::new();
+        let mut linetypes = Vec::new();
+        for obj in decoder.build_object_index()?.objects {
+            let Ok(record) = decoder.parse_object_record(obj.offset) else {
+                continue;
+            };
+            let mut reader = record.bit_reader();
+            let Ok(type_code) = read_object_type(&mut reader, &version) else {
+                continue;
+            };
+            match type_code {
+                LAYER_TYPE_CODE => layers.push(decode_layer(&mut reader, &version, obj.handle.0)?),
+                LTYPE_TYPE_CODE => {
+                    let ltype = decode_ltype(&mut reader, &version, obj.handle.0)?;
+                    let name = ltype.name.clone().unwrap_or_default();
+                    linetype_names.insert(ltype.handle, name.clone());
+                    if !matches!(
+                        name.to_ascii_uppercase().as_str(),
+                        "BYLAYER" | "BYBLOCK" | "CONTINUOUS"
+                    ) {
+                        linetypes.push(LinetypeDef {
+                            name,
+                            description: ltype.description.clone().unwrap_or_default(),
+                            dashes: ltype.dashes.iter().map(|dash| dash.length).collect(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let template = WriterTemplate::from_bytes(bytes)?;
+        let raw_objects: Vec<RawObjectDef> = template
+            .raw_objects
+            .iter()
+            .filter(|raw| !is_entity_record(raw))
+            .cloned()
+            .collect();
+        Ok(Self {
+            layers: layers
+                .into_iter()
+                .map(|layer| LayerDef {
+                    flags: layer.dxf_flags(),
+                    is_off: layer.is_off(),
+                    name: layer.name,
+                    color_index: layer.color_index.unsigned_abs(),
+                    linetype_name: linetype_names
+                        .get(&layer.linetype_handle)
+                        .filter(|name| !name.eq_ignore_ascii_case("CONTINUOUS"))
+                        .cloned(),
+                })
+                .collect(),
+            linetypes,
+            raw_objects: raw_objects.into(),
+            ..template.document()
+        })
+    }
+}
+
+/// Whether a copied record is an entity: a proxy entity or an entity of an
+/// application class.
+fn is_entity_record(raw: &RawObjectDef) -> bool {
+    match &raw.class {
+        Some(class) => class.item_class_id == PROXY_ENTITY_TYPE_CODE,
+        None => BitReader::new(&raw.data).read_bs().ok() == Some(PROXY_ENTITY_TYPE_CODE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::WriterTemplate;
+    use crate::writer::ir::{
+        CommonEntityProps, LayerDef, LineEntity, LinetypeDef, WriterDocument, WriterEntity,
+    };
+    use crate::writer::r2000::write_document;
+    use crate::writer::WriterConfig;
+
+    #[test]
+    fn documents_share_the_template_data() {
+        let bytes =
+            write_document(&WriterDocument::default(), &WriterConfig::default()).expect("write");
+        let template = WriterTemplate::from_bytes(&bytes).expect("template");
+
+        let mut first = template.document();
+        first
+            .modelspace
+            .push(WriterEntity::Line(LineEntity::default()));
+        let second = template.document();
+
+        let shared = |doc: &WriterDocument| doc.metadata.header_template.clone().expect("header");
+        assert!(Arc::ptr_eq(&shared(&first), &shared(&second)));
+        assert!(Arc::ptr_eq(&first.raw_objects, &template.raw_objects));
+        assert!(second.modelspace.is_empty());
+        assert!(write_document(&first, &WriterConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn from_template_takes_tables_without_entities() {
+        let source = WriterDocument {
+            modelspace: vec![WriterEntity::Line(LineEntity {
+                common: CommonEntityProps {
+                    layer_name: "A-WALL".to_string(),
+                    ..CommonEntityProps::default()
+                },
+                ..LineEntity::default()
+            })],
+            layers: vec![
+                LayerDef {
+                    name: "A-WALL".to_string(),
+                    color_index: 1,
+                    linetype_name: Some("DASHED".to_string()),
+                    flags: 1 | 4,
+                    ..LayerDef::default()
+                },
+                LayerDef {
+                    name: "A-DOOR".to_string(),
+                    color_index: 3,
+                    is_off: true,
+                    ..LayerDef::default()
+                },
+            ],
+            linetypes: vec![LinetypeDef {
+                name: "DASHED".to_string(),
+                description: "__ __".to_string(),
+                dashes: vec![0.5, -0.25],
+            }],
+            ..WriterDocument::default()
+        };
+        let bytes = write_document(&source, &WriterConfig::default()).expect("write");
+
+        let doc = WriterDocument::from_template_bytes(&bytes).expect("template");
+
+        assert!(doc.modelspace.is_empty());
+        let layers: Vec<(&str, u16, Option<&str>, u16, bool)> = doc
+            .layers
+            .iter()
+            .map(|layer| {
+                (
+                    layer.name.as_str(),
+                    layer.color_index,
+                    layer.linetype_name.as_deref(),
+                    layer.flags,
+                    layer.is_off,
+                )
+            })
+            .collect();
+        assert_eq!(
+            layers,
+            vec![
+                ("0", 7, None, 0, false),
+                ("A-WALL", 1, Some("DASHED"), 1 | 4, false),
+                ("A-DOOR", 3, None, 0, true)
+            ]
+        );
+        assert_eq!(doc.linetypes.len(), 1);
+        assert_eq!(doc.linetypes[0].dashes, vec![0.5, -0.25]);
+        assert!(doc.metadata.header_template.is_some());
+        assert!(write_document(&doc, &WriterConfig::default()).is_ok());
+    }
+}
@@ -0,0 +1,371 @@
+use std::borrow::Cow;
+
+use crate::core::error::{DwgError, ErrorKind};
+use crate::core::result::Result;
+use crate::writer::ir::{WriterDocument, WriterEntity};
+
+/// Relative tolerance of the similarity check on the matrix entries.
+const MATRIX_TOLERANCE: f64 = 1e-9;
+
+/// A rotation about the Z axis with uniform scale, followed by a
+/// translation: the affine transforms under which arcs stay arcs and texts
+/// stay upright, so every writer entity can be transformed exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawingTransform {
+    pub scale: f64,
+    pub rotation_rad: f64,
+    pub translation: (f64, f64, f64),
+}
+
+impl DrawingTransform {
+    /// Reads a transform from the first three rows of a row-major affine
+    /// matrix, `x' = m[0][0] x + m[0][1] y + m[0][2] z + m[0][3]` and so
+    /// on. Skew, non-uniform scale, mirroring and rotations that tilt the
+    /// XY plane are rejected.
+    pub fn from_matrix(matrix: [[f64; 4]; 3]) -> Result<Self> {
+        if matrix.iter().flatten().any(|value| !value.is_finite()) {
+            return Err(invalid("transform matrix must be finite"));
+        }
+        let [[a, c, xz, tx], [b, d, yz, ty], [zx, zy, e, tz]] = matrix;
+        let scale = a.hypot(b);
+        if scale <= f64::EPSILON {
+            return Err(invalid("transform matrix must not collapse the drawing"));
+        }
+        let close =
+            |value: f64, expected: f64| (value - expected).abs() <= MATRIX_TOLERANCE * scale;
+        if !(close(xz, 0.0) && close(yz, 0.0) && close(zx, 0.0) && close(zy, 0.0)) {
+            return Err(invalid("transform must rotate about the Z axis only"));
+        }
+        if close(c, b) && close(d, -a) {
+            return Err(invalid("mirroring transforms are not supported"));
+        }
+        if !(close(c, -b) && close(d, a) && close(e, scale)) {
+            return Err(invalid(
+                "transform must be a rotation with uniform scale; skew and non-uniform scale are not supported",
+            ));
+        }
+        Ok(Self {
+            scale,
+            rotation_rad: b.atan2(a),
+            translation: (tx, ty, tz),
+        })
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.scale == 1.0 && self.rotation_rad == 0.0 && self.translation == (0.0, 0.0, 0.0)
+    }
+
+    pub fn apply_point(&self, point: (f64, f64, f64)) -> (f64, f64, f64) {
+        let (x, y, z) = self.apply_vector(point);
+        (
+            x + self.translation.0,
+            y + self.translation.1,
+            z + self.translation.2,
+        )
+    }
+
+    /// Rotates and scales `vector` without translating it.
+    pub fn apply_vector(&self, vector: (f64, f64, f64)) -> (f64, f64, f64) {
+        let (sin, cos) = self.rotation_rad.sin_cos();
+        (
+            self.scale * (cos * vector.0 - sin * vector.1),
+            self.scale * (sin * vector.0 + cos * vector.1),
+            self.scale * vector.2,
+        )
+    }
+
+    /// Rotates `direction` and keeps its length.
+    pub fn apply_direction(&self, direction: (f64, f64, f64)) -> (f64, f64, f64) {
+        let (x, y, z) = self.apply_vector(direction);
+        (x / self.scale, y / self.scale, z / self.scale)
+    }
+}
+
+/// Applies `transform` to the modelspace entities of `doc`, its extents
+/// and insertion base, and the views of its paperspace viewports, so the
+/// layouts show the same part of the drawing. Paperspace entities stay
+/// where they are. LWPOLYLINEs carry no elevation in the writer, so a Z
+/// translation is rejected when modelspace has any.
+pub fn transform_document<'a>(
+    doc: &'a WriterDocument,
+    transform: &DrawingTransform,
+) -> Result<Cow<'a, WriterDocument>> {
+    if transform.is_identity() {
+        return Ok(Cow::Borrowed(doc));
+    }
+    if transform.translation.2 != 0.0
+        && doc
+            .modelspace
+            .iter()
+            .any(|entity| matches!(entity, WriterEntity::LwPolyline(_)))
+    {
+        return Err(DwgError::new(
+            ErrorKind::Unsupported,
+            "Z translation is not supported for LWPOLYLINE entities",
+        ));
+    }
+    let mut doc = doc.clone();
+    for entity in &mut doc.modelspace {
+        transform_entity(entity, transform);
+    }
+    for viewport in doc
+        .layouts
+        .iter_mut()
+        .flat_map(|layout| layout.viewports.iter_mut())
+    {
+        // The view center is measured from the target in the view plane.
+        let (x, y, _) =
+            transform.apply_vector((viewport.view_center.0, viewport.view_center.1, 0.0));
+        viewport.view_center = (x, y);
+        viewport.view_target = transform.apply_point(viewport.view_target);
+        viewport.view_height *= transform.scale;
+    }
+    let metadata = &mut doc.metadata;
+    metadata.insertion_base = transform.apply_point(metadata.insertion_base);
+    if let (Some(min), Some(max)) = (metadata.ext_min, metadata.ext_max) {
+        let (min, max) = transform_extents(min, max, transform);
+        metadata.ext_min = Some(min);
+        metadata.ext_max = Some(max);
+    }
+    Ok(Cow::Owned(doc))
+}
+
+/// The box around the transformed corners of the box `min`..`max`.
+fn transform_extents(
+    min: (f64, f64, f64),
+    max: (f64, f64, f64),
+    transform: &DrawingTransform,
+) -> ((f64, f64, f64), (f64, f64, f64)) {
+    let corners = [
+        (min.0, min.1, min.2),
+        (max.0, min.1, min.2),
+        (min.0, max.1, max.2),
+        (max.0, max.1, max.2),
+    ]
+    .map(|corner| transform.apply_point(corner));
+    let mut low = corners[0];
+    let mut high = corners[0];
+    for (x, y, z) in corners {
+        low = (low.0.min(x), low.1.min(y), low.2.min(z));
+        high = (high.0.max(x), high.1.max(y), high.2.max(z));
+    }
+    (low, high)
+}
+
+fn transform_entity(entity: &mut WriterEntity, transform: &DrawingTransform) {
+    let point = |point| transform.apply_point(point);
+    let direction = |direction| transform.apply_direction(direction);
+    let length = |length: f64| length * transform.scale;
+    let angle = |angle: f64| angle + transform.rotation_rad;
+    match entity {
+        WriterEntity::Line(line) => {
+            line.start = point(line.start);
+            line.end = point(line.end);
+        }
+        WriterEntity::Point(entity) => {
+            entity.location = point(entity.location);
+            entity.x_axis_angle = angle(entity.x_axis_angle);
+        }
+        WriterEntity::Ray(ray) => {
+            ray.start = point(ray.start);
+            ray.unit_vector = direction(ray.unit_vector);
+        }
+        WriterEntity::XLine(xline) => {
+            xline.start = point(xline.start);
+            xline.unit_vector = direction(xline.unit_vector);
+        }
+        WriterEntity::Arc(arc) => {
+            arc.center = point(arc.center);
+            arc.radius = length(arc.radius);
+            arc.angle_start_rad = angle(arc.angle_start_rad);
+            arc.angle_end_rad = angle(arc.angle_end_rad);
+        }
+        WriterEntity::Circle(circle) => {
+            circle.center = point(circle.center);
+            circle.radius = length(circle.radius);
+        }
+        WriterEntity::LwPolyline(polyline) => {
+            // Bulges are ratios and keep their sign under a rotation.
+            for vertex in &mut polyline.vertices {
+                let (x, y, _) = point((vertex.0, vertex.1, 0.0));
+                *vertex = (x, y);
+            }
+            polyline.const_width = polyline.const_width.map(length);
+            for (start, end) in &mut polyline.widths {
+                *start = length(*start);
+                *end = length(*end);
+            }
+        }
+        WriterEntity::Text(text) => {
+            text.insert = point(text.insert);
+            text.align_point = text.align_point.map(point);
+            text.height = length(text.height);
+            text.rotation_rad = angle(text.rotation_rad);
+        }
+        WriterEntity::MText(mtext) => {
+            mtext.insert = point(mtext.insert);
+            mtext.text_direction = direction(mtext.text_direction);
+            mtext.rect_width = length(mtext.rect_width);
+            mtext.char_height = length(mtext.char_height);
+        }
+        WriterEntity::Tolerance(tolerance) => {
+            tolerance.insert = point(tolerance.insert);
+            tolerance.x_direction = direction(tolerance.x_direction);
+            tolerance.height = length(tolerance.height);
+            tolerance.dimgap = length(tolerance.dimgap);
+        }
+        WriterEntity::Leader(leader) => {
+            for vertex in &mut leader.points {
+                *vertex = point(*vertex);
+            }
+        }
+        WriterEntity::MLine(mline) => {
+            mline.scale = length(mline.scale);
+            for vertex in &mut mline.vertices {
+                vertex.position = point(vertex.position);
+                vertex.vertex_direction = direction(vertex.vertex_direction);
+                vertex.miter_direction = direction(vertex.miter_direction);
+                for value in vertex.segment_params.iter_mut().flatten() {
+                    *value = length(*value);
+                }
+            }
+        }
+        WriterEntity::PolyfaceMesh(mesh) => {
+            for vertex in &mut mesh.vertices {
+                *vertex = point(*vertex);
+            }
+        }
+    }
+}
+
+fn invalid(message: &str) -> DwgError {
+    DwgError::new(ErrorKind::Unsupported, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{transform_document, DrawingTransform};
+    use crate::writer::ir::{
+        ArcEntity, LwPolylineEntity, TextEntity, WriterDocument, WriterEntity,
+    };
+    use std::f64::consts::FRAC_PI_2;
+
+    fn close(a: (f64, f64, f64), b: (f64, f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9 && (a.2 - b.2).abs() < 1e-9
+    }
+
+    #[test]
+    fn reads_similarity_matrices_and_rejects_the_rest() {
+        let transform = DrawingTransform::from_matrix([
+            [0.0, -2.0, 0.0, 10.0],
+            [2.0, 0.0, 0.0, 20.0],
+            [0.0, 0.0, 2.0, 0.0],
+        ])
+        .expect("rotation with scale");
+        assert_eq!(transform.scale, 2.0);
+        assert!((transform.rotation_rad - FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(transform.translation, (10.0, 20.0, 0.0));
+
+        for (matrix, message) in [
+            (
+                [
+                    [1.0, 0.5, 0.0, 0.0],
+                    [0.0, 1.0, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                ],
+                "skew",
+            ),
+            (
+                [
+                    [2.0, 0.0, 0.0, 0.0],
+                    [0.0, 1.0, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                ],
+                "uniform scale",
+            ),
+            (
+                [
+                    [1.0, 0.0, 0.0, 0.0],
+                    [0.0, -1.0, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                ],
+                "mirroring",
+            ),
+            (
+                [
+                    [1.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.0, -1.0, 0.0],
+                    [0.0, 1.0, 0.0, 0.0],
+                ],
+                "Z axis",
+            ),
+        ] {
+            let err = DrawingTransform::from_matrix(matrix).expect_err(message);
+            assert!(err.message.contains(message), "{}", err.message);
+        }
+    }
+
+    #[test]
+    fn transforms_arcs_texts_and_polylines() {
+        let doc = WriterDocument {
+            modelspace: vec![
+                WriterEntity::Arc(ArcEntity {
+                    center: (1.0, 0.0, 0.0),
+                    radius: 1.5,
+                    angle_start_rad: 0.0,
+                    angle_end_rad: 1.0,
+                    ..ArcEntity::default()
+                }),
+                WriterEntity::Text(TextEntity {
+                    insert: (0.0, 1.0, 0.0),
+                    align_point: Some((2.0, 1.0, 0.0)),
+                    height: 0.5,
+                    rotation_rad: 0.25,
+                    ..TextEntity::default()
+                }),
+                WriterEntity::LwPolyline(LwPolylineEntity {
+                    vertices: vec![(0.0, 0.0), (1.0, 0.0)],
+                    bulges: vec![0.5, 0.0],
+                    widths: vec![(0.1, 0.2), (0.0, 0.0)],
+                    ..LwPolylineEntity::default()
+                }),
+            ],
+            ..WriterDocument::default()
+        };
+        let transform = DrawingTransform {
+            scale: 2.0,
+            rotation_rad: FRAC_PI_2,
+            translation: (100.0, 200.0, 0.0),
+        };
+        let out = transform_document(&doc, &transform).expect("transform");
+        let [WriterEntity::Arc(arc), WriterEntity::Text(text), WriterEntity::LwPolyline(polyline)] =
+            out.modelspace.as_slice()
+        else {
+            panic!("unexpected entities: {:?}", out.modelspace);
+        };
+        assert!(close(arc.center, (100.0, 202.0, 0.0)));
+        assert_eq!(arc.radius, 3.0);
+        assert_eq!(
+            (arc.angle_start_rad, arc.angle_end_rad),
+            (FRAC_PI_2, 1.0 + FRAC_PI_2)
+        );
+        assert!(close(text.insert, (98.0, 200.0, 0.0)));
+        assert!(close(
+            text.align_point.expect("align point"),
+            (98.0, 204.0, 0.0)
+        ));
+        assert_eq!((text.height, text.rotation_rad), (1.0, 0.25 + FRAC_PI_2));
+        assert!(close(
+            (polyline.vertices[1].0, polyline.vertices[1].1, 0.0),
+            (100.0, 202.0, 0.0)
+        ));
+        assert_eq!(polyline.bulges, vec![0.5, 0.0]);
+        assert_eq!(polyline.widths, vec![(0.2, 0.4), (0.0, 0.0)]);
+
+        let lifted = DrawingTransform {
+            translation: (0.0, 0.0, 5.0),
+            ..transform
+        };
+        assert!(transform_document(&doc, &lifted).is_err());
+    }
+}
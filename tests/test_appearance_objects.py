@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/acadsharp/sample_AC1027.dwg"
+GLOBAL_MATERIAL_HANDLE = 152
+
+
+@pytest.mark.parametrize(
+    "name",
+    ["line_2000.dwg", "line_2004.dwg", "line_2007.dwg", "line_2010.dwg", "line_2013.dwg"],
+)
+def test_decode_materials_reads_default_material_names(name: str) -> None:
+    rows = raw.decode_materials(str(ROOT / "test_dwg" / name))
+
+    assert [row[1] for row in rows] == ["ByLayer", "ByBlock", "Global"]
+
+
+def test_decode_materials_reads_colors_and_diffuse_map() -> None:
+    rows = {row[1]: row for row in raw.decode_materials(str(SAMPLE))}
+
+    _handle, _name, description, ambient, _diffuse, diffuse_map = rows["Beech"]
+    assert description == "Generic material."
+    assert ambient == (1, pytest.approx(1.0), 0xE9B486)
+    assert diffuse_map is None
+    assert rows["Bamboo"][5].endswith("Finishes.Flooring.Bamboo.jpg")
+
+
+@pytest.mark.parametrize("name", ["line_2004.dwg", "line_2013.dwg"])
+def test_decode_visual_styles_reads_descriptions_and_types(name: str) -> None:
+    styles = {
+        description: style_type
+        for _handle, description, style_type in raw.decode_visual_styles(
+            str(ROOT / "test_dwg" / name)
+        )
+    }
+
+    assert styles["Realistic"] == 8
+    assert styles["Conceptual"] == 9
+    assert styles["2dWireframe"] == 4
+
+
+def test_decode_layer_materials_links_layers_to_global() -> None:
+    rows = raw.decode_layer_materials(str(SAMPLE))
+
+    assert rows
+    assert all(material == GLOBAL_MATERIAL_HANDLE for _layer, material in rows)
+    assert raw.decode_layer_materials(str(ROOT / "test_dwg/line_2004.dwg")) == [(84, None)]
+
+
+def test_query_attaches_layer_material() -> None:
+    doc = ezdwg.read(str(ROOT / "test_dwg/line_2007.dwg"))
+    [line] = list(doc.modelspace().query("LINE"))
+
+    assert line.dxf["material_handle"] == 33
+    assert line.dxf["material"] == "Global"
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import shutil
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+def _populate(tmp_path: Path) -> Path:
+    source_dir = tmp_path / "in"
+    source_dir.mkdir()
+    shutil.copy(SAMPLES / "line_2000.dwg", source_dir / "line_2000.dwg")
+    shutil.copy(SAMPLES / "text_2000.dwg", source_dir / "text_2000.dwg")
+    (source_dir / "broken.dwg").write_bytes(b"not a dwg")
+    return source_dir
+
+
+def test_process_directory_captures_per_file_errors(tmp_path: Path) -> None:
+    source_dir = _populate(tmp_path)
+    events: list[ezdwg.batch.BatchProgress] = []
+
+    result = ezdwg.batch.process_directory(
+        str(source_dir),
+        ["audit", "extract_text"],
+        parallelism=2,
+        progress=events.append,
+    )
+
+    assert [Path(item.path).name for item in result.files] == [
+        "broken.dwg",
+        "line_2000.dwg",
+        "text_2000.dwg",
+    ]
+    assert sorted(event.completed for event in events) == [1, 2, 3]
+    assert all(event.total == 3 for event in events)
+    assert [Path(item.path).name for item in result.failed] == ["broken.dwg"]
+    assert set(result.failed[0].errors) == {"audit", "extract_text"}
+
+    line, text = result.files[1], result.files[2]
+    assert line.outputs["audit"]["version"] == "AC1015"
+    assert line.outputs["audit"]["entity_counts"] == {"LINE": 1}
+    assert line.outputs["extract_text"] == []
+    assert text.outputs["extract_text"]
+
+
+def test_process_directory_runs_thumbnail_and_custom_ops(tmp_path: Path) -> None:
+    source_dir = _populate(tmp_path)
+    output_dir = tmp_path / "out"
+
+    result = ezdwg.batch.process_directory(
+        str(source_dir),
+        ["thumbnail", ("size", lambda path, _out: path.stat().st_size)],
+        output_dir=str(output_dir),
+        pattern="line_*.dwg",
+    )
+
+    assert len(result.files) == 1
+    item = result.files[0]
+    assert item.ok
+    assert Path(item.outputs["thumbnail"]) == output_dir / "line_2000.png"
+    assert (output_dir / "line_2000.png").exists()
+    assert item.outputs["size"] == (SAMPLES / "line_2000.dwg").stat().st_size
+
+
+def test_process_directory_rejects_unknown_operation(tmp_path: Path) -> None:
+    with pytest.raises(ValueError, match="unknown batch operation"):
+        ezdwg.batch.process_directory(str(tmp_path), ["shred"])
@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+def _special_handles(path: Path) -> dict[str, int]:
+    return dict(raw.decode_table_control(str(path), "BLOCK")[4])
+
+
+@pytest.mark.parametrize(
+    "name",
+    [
+        "line_R14.dwg",
+        "line_2000.dwg",
+        "line_2004.dwg",
+        "line_2007.dwg",
+        "line_2010.dwg",
+        "line_2013.dwg",
+    ],
+)
+def test_model_space_children(name: str) -> None:
+    path = SAMPLES / name
+    special = _special_handles(path)
+
+    assert raw.iter_block_children(str(path), special["*MODEL_SPACE"]) == [0x83]
+    assert raw.iter_block_children(str(path), special["*PAPER_SPACE"]) == []
+
+
+def test_block_children_are_kept_out_of_model_space() -> None:
+    path = SAMPLES / "insert_2004.dwg"
+    model_space = _special_handles(path)["*MODEL_SPACE"]
+
+    assert raw.iter_block_children(str(path), model_space) == [0x2C]
+    assert raw.iter_block_children(str(path), 0x29) == [0x2A]
+
+
+def test_iter_block_children_rejects_other_objects() -> None:
+    with pytest.raises(ValueError, match="block record not found"):
+        raw.iter_block_children(str(SAMPLES / "line_2000.dwg"), 0x83)
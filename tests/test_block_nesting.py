@@ -0,0 +1,119 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.block_nesting as block_nesting_module
+from ezdwg.entity import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+INSERT_SAMPLE = ROOT / "test_dwg/insert_2004.dwg"
+
+
+class _FakeDoc:
+    path = "dummy_block_nesting.dwg"
+    decode_path = "dummy_block_nesting.dwg"
+
+
+class _FakeLayout:
+    doc = _FakeDoc()
+
+    def __init__(self, entities: list[Entity]) -> None:
+        self._entities = entities
+
+    def query(self, types):
+        return [entity for entity in self._entities if entity.dxftype in types]
+
+
+def _fake_layout(monkeypatch, blocks: dict[str, list[str]]) -> _FakeLayout:
+    """A drawing whose blocks hold INSERTs of the blocks named in
+    ``blocks``; one MINSERT sits in model space."""
+    entities: list[Entity] = []
+    definitions: dict[str, tuple] = {}
+    for index, (name, inserted) in enumerate(blocks.items()):
+        children = []
+        for target in inserted:
+            handle = 0x100 + len(entities)
+            entities.append(Entity("INSERT", handle, {"name": target.lower()}))
+            children.append(handle)
+        definitions[name.upper()] = (0x20 + index, (0.0, 0.0, 0.0), tuple(children))
+    entities.append(Entity("MINSERT", 0x10, {"name": next(iter(blocks))}))
+    definitions["*MODEL_SPACE"] = (0x1F, (0.0, 0.0, 0.0), (0x10,))
+    monkeypatch.setattr(
+        block_nesting_module, "_resolve_layout", lambda layout: (layout.doc.path, layout)
+    )
+    monkeypatch.setattr(
+        block_nesting_module,
+        "_block_handles_by_name",
+        lambda _path: {key: (handle,) for key, (handle, _base, _children) in definitions.items()},
+    )
+    monkeypatch.setattr(
+        block_nesting_module, "_block_definition", lambda _path, name: definitions.get(name)
+    )
+    monkeypatch.setattr(
+        block_nesting_module,
+        "_block_header_name_rows",
+        lambda _path: [(0x20 + index, name) for index, name in enumerate(blocks)],
+    )
+    return _FakeLayout(entities)
+
+
+def test_check_block_references_reads_sample_depths() -> None:
+    assert ezdwg.check_block_references(str(INSERT_SAMPLE)) == {"BLK1": 1}
+
+
+def test_check_block_references_reports_depths_and_cycles(monkeypatch) -> None:
+    layout = _fake_layout(
+        monkeypatch,
+        {"Site": ["House", "Tree"], "House": ["Door", "Door"], "Door": [], "Tree": []},
+    )
+
+    assert ezdwg.check_block_references(layout) == {"Site": 3, "House": 2, "Door": 1, "Tree": 1}
+
+    with pytest.raises(ezdwg.BlockReferenceError) as too_deep:
+        ezdwg.check_block_references(layout, max_depth=2)
+    assert too_deep.value.kind == "depth"
+    assert too_deep.value.blocks == ["Site", "House", "Door"]
+    assert isinstance(too_deep.value, ValueError)
+
+    layout = _fake_layout(
+        monkeypatch, {"Site": ["House"], "House": ["Door"], "Door": ["House"], "Tree": ["Tree"]}
+    )
+    with pytest.raises(ezdwg.BlockReferenceError, match="circular") as cycle:
+        ezdwg.check_block_references(layout)
+    assert cycle.value.kind == "cycle"
+    assert cycle.value.blocks == ["House", "Door", "House"]
+    assert str(cycle.value) == "circular block reference: House -> Door -> House"
+
+
+def test_check_block_references_handles_long_chains(monkeypatch) -> None:
+    names = [f"B{index}" for index in range(5000)]
+    layout = _fake_layout(
+        monkeypatch, {name: names[index + 1 : index + 2] for index, name in enumerate(names)}
+    )
+
+    depths = ezdwg.check_block_references(layout, max_depth=10_000)
+
+    assert depths["B0"] == 5000
+    with pytest.raises(ezdwg.BlockReferenceError, match=r"5000 levels deep, more than 16"):
+        ezdwg.check_block_references(layout)
+
+
+def test_to_dxf_checks_blocks_before_flattening(monkeypatch, tmp_path: Path) -> None:
+    pytest.importorskip("ezdxf")
+
+    def _reject(_source, **_kwargs):
+        raise ezdwg.BlockReferenceError("cycle", ["BLK1", "BLK1"], 16)
+
+    monkeypatch.setattr(block_nesting_module, "check_block_references", _reject)
+    output = tmp_path / "cycle_flattened_out.dxf"
+
+    with pytest.raises(ezdwg.BlockReferenceError, match="BLK1 -> BLK1"):
+        ezdwg.to_dxf(str(INSERT_SAMPLE), str(output), flatten_inserts=True)
+    assert not output.exists()
+
+    ezdwg.to_dxf(str(INSERT_SAMPLE), str(output))
+    assert output.exists()
@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+import shutil
+from pathlib import Path
+
+import pytest
+
+from ezdwg import cache, raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+pytestmark = pytest.mark.skipif(not cache.is_available(), reason="built without the cache feature")
+
+
+def test_repeated_decodes_are_served_from_disk(tmp_path: Path) -> None:
+    cache_dir = cache.enable_cache(tmp_path / "cache")
+    try:
+        _check_repeated_decodes(cache_dir, tmp_path)
+    finally:
+        cache.disable_cache()
+
+
+def _check_repeated_decodes(cache_dir: Path, tmp_path: Path) -> None:
+    drawing = tmp_path / "line_2000.dwg"
+    shutil.copyfile(SAMPLES / "line_2000.dwg", drawing)
+    before = cache.cache_info()
+
+    first = raw.decode_line_entities(str(drawing))
+    second = raw.decode_line_entities(drawing)
+    limited = raw.decode_line_entities(str(drawing), limit=1)
+
+    info = cache.cache_info()
+    assert info.directory == str(cache_dir)
+    assert "decode_line_entities" in info.functions
+    assert second == first
+    assert limited == first[:1]
+    assert (info.hits - before.hits, info.misses - before.misses) == (1, 2)
+
+    # A copy with the same contents shares the entry; edited contents miss.
+    copy = tmp_path / "copy.dwg"
+    shutil.copyfile(drawing, copy)
+    assert raw.decode_line_entities(str(copy)) == first
+    assert cache.cache_info().hits == info.hits + 1
+    shutil.copyfile(SAMPLES / "line_2004.dwg", drawing)
+    assert raw.decode_line_entities(str(drawing)) == raw.decode_line_entities.__wrapped__(
+        str(SAMPLES / "line_2004.dwg")
+    )
+    assert cache.cache_info().misses == info.misses + 1
+
+
+def test_disable_restores_uncached_functions(tmp_path: Path) -> None:
+    cache_dir = cache.enable_cache(tmp_path / "cache")
+    assert hasattr(raw.decode_line_entities, "__wrapped__")
+    with pytest.raises(OSError):
+        raw.decode_line_entities(str(cache_dir / "missing.dwg"))
+
+    raw.decode_line_entities(str(SAMPLES / "line_2000.dwg"))
+    assert cache.clear_cache() > 0
+    cache.disable_cache()
+    assert not hasattr(raw.decode_line_entities, "__wrapped__")
+    assert cache.cache_info().functions == ()
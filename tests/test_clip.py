@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+def test_clip_entities_trims_sample_line_and_keeps_attributes() -> None:
+    source = ezdwg.read(str(SAMPLES / "line_2000.dwg")).modelspace()
+    line = next(iter(source.query("LINE")))
+
+    (piece,) = ezdwg.clip_entities(source, (60.0, 0.0, 200.0, 80.0))
+
+    assert piece.handle == line.handle
+    assert piece.dxf["start"] == pytest.approx((60.0, 60.0, 0.0))
+    assert piece.dxf["end"] == pytest.approx((80.0, 80.0, 0.0))
+    assert {key: value for key, value in piece.dxf.items() if key not in ("start", "end")} == {
+        key: value for key, value in line.dxf.items() if key not in ("start", "end")
+    }
+    assert ezdwg.clip_entities(source, (0.0, 0.0, 200.0, 200.0)) == [line]
+    assert ezdwg.clip_entities(source, (0.0, 0.0, 10.0, 10.0)) == []
+
+
+def test_clip_entities_cuts_circles_and_arcs_into_arcs() -> None:
+    circle = Entity("CIRCLE", 1, {"center": (0.0, 0.0, 0.0), "radius": 2.0})
+    arc = Entity(
+        "ARC",
+        2,
+        {"center": (0.0, 0.0, 0.0), "radius": 2.0, "start_angle": 300.0, "end_angle": 150.0},
+    )
+
+    pieces = ezdwg.clip_entities([circle, arc], (-1.0, -5.0, 5.0, 5.0))
+
+    assert [piece.dxftype for piece in pieces] == ["ARC", "ARC"]
+    # The circle piece runs through 0 degrees; x = -1 cuts it at 120 and 240.
+    assert pieces[0].dxf["start_angle"] == pytest.approx(240.0)
+    assert pieces[0].dxf["end_angle"] == pytest.approx(120.0)
+    assert (pieces[1].dxf["start_angle"], pieces[1].dxf["end_angle"]) == (300.0, 120.0)
+    assert ezdwg.clip_entities([circle], (-1.0, -1.0, 1.0, 1.0)) == []
+
+
+def test_clip_entities_splits_lwpolylines_at_the_window() -> None:
+    square = Entity(
+        "LWPOLYLINE",
+        3,
+        {
+            "points": [(2.0, 2.0, 0.0), (12.0, 2.0, 0.0), (12.0, 8.0, 0.0), (2.0, 8.0, 0.0)],
+            "bulges": [0.0, 0.0, 0.0, 0.0],
+            "widths": [(0.0, 1.0), (1.0, 1.0), (1.0, 1.0), (1.0, 0.0)],
+            "flags": 1,
+            "closed": True,
+        },
+    )
+    bulged = Entity(
+        "LWPOLYLINE",
+        4,
+        {"points": [(8.0, 5.0, 0.0), (12.0, 5.0, 0.0)], "bulges": [1.0, 0.0], "flags": 0},
+    )
+
+    closed_piece, bulged_piece = ezdwg.clip_entities([square, bulged], (0.0, 0.0, 10.0, 10.0))
+
+    # The cut closed outline becomes one open piece running through the
+    # first vertex.
+    assert closed_piece.dxf["points"] == [
+        (10.0, 8.0, 0.0),
+        (2.0, 8.0, 0.0),
+        (2.0, 2.0, 0.0),
+        (10.0, 2.0, 0.0),
+    ]
+    assert closed_piece.dxf["widths"][-2] == pytest.approx((0.0, 0.8))
+    assert (closed_piece.dxf["flags"], closed_piece.dxf["closed"]) == (0, False)
+    # Half of the clockwise half circle below the chord stays inside.
+    assert bulged_piece.dxf["points"] == pytest.approx([(8.0, 5.0, 0.0), (10.0, 3.0, 0.0)])
+    assert bulged_piece.dxf["bulges"][0] == pytest.approx(0.41421356)
+
+
+def test_clip_entities_rejects_bad_windows_and_types() -> None:
+    with pytest.raises(ValueError, match="min_x"):
+        ezdwg.clip_entities([], (10.0, 0.0, 0.0, 10.0))
+    with pytest.raises(ValueError, match="TEXT"):
+        ezdwg.clip_entities([], (0.0, 0.0, 1.0, 1.0), types="LINE TEXT")
@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import ast
+from pathlib import Path
+
+import ezdwg._core as core
+import ezdwg.raw as raw
+
+STUB_PATH = Path(core.__file__).with_name("_core.pyi")
+
+
+def _stub_module() -> ast.Module:
+    return ast.parse(STUB_PATH.read_text(encoding="utf-8"))
+
+
+def _feature_gated(module: ast.Module) -> set[str]:
+    lines = STUB_PATH.read_text(encoding="utf-8").splitlines()
+    return {
+        node.name
+        for node in module.body
+        if isinstance(node, ast.FunctionDef)
+        and lines[node.lineno - 2].startswith("# Requires the `")
+    }
+
+
+def test_generated_stub_matches_compiled_module() -> None:
+    module = _stub_module()
+    functions = [node.name for node in module.body if isinstance(node, ast.FunctionDef)]
+    declared = next(
+        ast.literal_eval(node.value)
+        for node in module.body
+        if isinstance(node, ast.Assign) and node.targets[0].id == "__all__"
+    )
+
+    assert functions == declared
+    assert set(core.__all__) <= set(functions)
+    assert set(functions) - set(core.__all__) <= _feature_gated(module)
+    for name in core.__all__:
+        assert callable(getattr(core, name))
+
+
+def test_generated_stub_types_progress_callbacks() -> None:
+    module = _stub_module()
+    write = next(
+        node
+        for node in module.body
+        if isinstance(node, ast.FunctionDef) and node.name == "write_ac1015_dwg"
+    )
+
+    params = {arg.arg: arg for arg in [*write.args.args, *write.args.kwonlyargs]}
+    assert ast.unparse(params["progress"].annotation).startswith("Callable[")
+    assert ast.unparse(params["output_path"].annotation) == "str"
+
+def test_raw_all_mirrors_core_all() -> None:
+    assert set(core.__all__) <= set(raw.__all__)
+    assert "decode_unknown_embedded_text_entities" in raw.__all__
+    for name in raw.__all__:
+        assert hasattr(raw, name)
@@ -0,0 +1,145 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import render
+from ezdwg.dimension_render import (
+    DimStyle,
+    dim_styles,
+    dimension_block_is_stale,
+    dimension_style_resolver,
+)
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE_AC1032 = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+ISO_25 = DimStyle(
+    name="ISO-25",
+    dimasz=2.5,
+    dimexo=0.625,
+    dimexe=1.25,
+    dimtxt=2.5,
+    dimcen=2.5,
+    dimgap=0.625,
+    dimtad=1,
+    dimdec=2,
+    dimtih=False,
+    dimtoh=False,
+)
+
+
+def _linear(p1, p2, base, **extra) -> dict:
+    return {
+        "dimtype": "LINEAR",
+        "defpoint": (*base, 0.0),
+        "defpoint2": (*p1, 0.0),
+        "defpoint3": (*p2, 0.0),
+        "angle": 0.0,
+        "text": "",
+        **extra,
+    }
+
+
+@pytest.mark.parametrize("name", ["line_R14", "line_2000", "line_2007", "line_2013"])
+def test_decode_dim_styles_reads_iso_25(name: str) -> None:
+    decoded = dim_styles(str(ROOT / f"test_dwg/{name}.dwg")).values()
+    styles = {style.name.upper(): style for style in decoded}
+
+    assert styles["ISO-25"] == DimStyle(
+        name=styles["ISO-25"].name,
+        dimasz=2.5,
+        dimexo=0.625,
+        dimexe=1.25,
+        dimtxt=2.5,
+        dimcen=2.5,
+        dimgap=0.625,
+        dimtad=1,
+        dimdec=2,
+        dimtih=False,
+        dimtoh=False,
+    )
+    assert styles["ANNOTATIVE"].scale == 1.0
+
+
+def test_regenerate_linear_dimension_follows_dimstyle() -> None:
+    graphics = ezdwg.regenerate_dimension(_linear((0.0, 0.0), (20.0, 0.0), (0.0, 10.0)), ISO_25)
+
+    assert graphics.measurement == 20.0
+    first_ext, second_ext, dim_line = graphics.lines
+    assert first_ext == pytest.approx([(0.0, 0.625), (0.0, 11.25)])
+    assert second_ext == pytest.approx([(20.0, 0.625), (20.0, 11.25)])
+    assert dim_line == pytest.approx([(0.0, 10.0), (20.0, 10.0)])
+    left, right = graphics.arrowheads
+    assert left == pytest.approx([(0.0, 10.0), (2.5, 10.0 + 2.5 / 6.0), (2.5, 10.0 - 2.5 / 6.0)])
+    assert right[0] == pytest.approx((20.0, 10.0))
+    assert graphics.text.text == "20.00"
+    assert graphics.text.height == 2.5
+    # DIMTAD places the text above the line, separated by DIMGAP.
+    assert graphics.text.insert == pytest.approx((10.0 - 5 * 2.5 / 2.0, 10.625))
+
+
+def test_regenerate_dimension_variants() -> None:
+    scaled = DimStyle(dimscale=10.0, dimtsz=0.1, dimdle=0.05, dimse2=True)
+    ticks = ezdwg.regenerate_dimension(_linear((0.0, 0.0), (20.0, 0.0), (0.0, 5.0)), scaled)
+    assert ticks.arrowheads == []
+    assert len(ticks.lines) == 1 + 2 + 2  # one extension line, two ticks, broken dimension line
+    assert ticks.lines[-2][0] == pytest.approx((-0.5, 5.0))
+    assert ticks.text.rotation == 0.0
+
+    short = ezdwg.regenerate_dimension(_linear((0.0, 0.0), (3.0, 0.0), (0.0, 5.0)), ISO_25)
+    assert short.arrowheads[0][1][0] < 0.0
+
+    overridden = _linear((0.0, 0.0), (20.0, 0.0), (0.0, 5.0), text="<> mm")
+    assert ezdwg.regenerate_dimension(overridden, ISO_25).text.text == "20.00 mm"
+    suppressed = _linear((0.0, 0.0), (20.0, 0.0), (0.0, 5.0), text=" ")
+    assert ezdwg.regenerate_dimension(suppressed, ISO_25).text is None
+
+    radius = {"dimtype": "RADIUS", "defpoint2": (0.0, 0.0, 0.0), "defpoint3": (3.0, 4.0, 0.0)}
+    graphics = ezdwg.regenerate_dimension(radius, ISO_25)
+    assert graphics.text.text == "R5.00"
+    assert len(graphics.arrowheads) == 1
+    assert len(graphics.lines) == 3  # radius line and the center mark cross
+
+    diameter = {"dimtype": "DIAMETER", "defpoint2": (0.0, 0.0, 0.0), "defpoint3": (10.0, 0.0, 0.0)}
+    assert ezdwg.regenerate_dimension(diameter, ISO_25).text.text == "⌀10.00"
+    assert ezdwg.regenerate_dimension({"dimtype": "ANG3PT"}, ISO_25) is None
+
+
+def test_dimension_block_staleness() -> None:
+    current = _linear(
+        (0.0, 0.0), (20.0, 0.0), (0.0, 5.0), anonymous_block_name="*D1", actual_measurement=20.0
+    )
+    assert not dimension_block_is_stale(current)
+    assert dimension_block_is_stale({**current, "actual_measurement": 18.0})
+    assert dimension_block_is_stale({**current, "anonymous_block_name": None})
+
+
+def test_renderer_regenerates_sample_dimensions(monkeypatch) -> None:
+    path = str(SAMPLE_AC1032)
+    resolve = dimension_style_resolver(path)
+    dims = {dim.handle: dim for dim in ezdwg.read(path).modelspace().query("DIMENSION")}
+
+    assert resolve(dims[1192].dxf).name == "Standard"
+    graphics = ezdwg.regenerate_dimension(dims[1192], resolve(dims[1192].dxf))
+    assert graphics.measurement == pytest.approx(dims[1192].dxf["actual_measurement"])
+    assert graphics.text.text == "46.7156"
+
+    plotted = []
+    texts = []
+
+    class _RecordingAx:
+        def plot(self, xs, ys, **_kwargs):
+            plotted.append(list(zip(xs, ys)))
+
+    monkeypatch.setattr(
+        render, "_draw_text", lambda _ax, _insert, text, *_args, **_kwargs: texts.append(text)
+    )
+    render._draw_dimension(_RecordingAx(), dims[1192].dxf, 1.0, style=resolve(dims[1192].dxf))
+
+    # Two extension lines, the dimension line and two closed arrowheads.
+    assert len(plotted) == 5
+    assert [len(points) for points in plotted[3:]] == [4, 4]
+    assert texts == ["46.7156"]
@@ -0,0 +1,148 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import raw
+from ezdwg._convert_utils import _ordinate_dim_type
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = [
+    "test_dwg/acadsharp/sample_AC1027.dwg",
+    "test_dwg/acadsharp/sample_AC1032.dwg",
+]
+
+
+def _rows_by_handle(path: Path) -> dict[int, tuple[str, tuple]]:
+    return {row[0]: (dimtype, row) for dimtype, row in raw.decode_dimension_entities(str(path))}
+
+
+def _assert_point(actual, expected) -> None:
+    assert actual is not None
+    assert actual[0] == pytest.approx(expected[0], abs=1e-2)
+    assert actual[1] == pytest.approx(expected[1], abs=1e-2)
+
+
+@pytest.mark.parametrize("relative_path", SAMPLES)
+def test_decode_dimension_entities_reads_ordinate_points(relative_path: str) -> None:
+    rows = _rows_by_handle(ROOT / relative_path)
+
+    dimtype, row = rows[1209]
+    assert dimtype == "ORDINATE"
+    _assert_point(row[2], (313.19, 111.24))
+    _assert_point(row[3], (660.87, 8.64))
+    _assert_point(row[4], (700.77, 14.34))
+    assert row[11] == (None, None, False, None, None, None, None, None)
+
+
+@pytest.mark.parametrize("relative_path", SAMPLES)
+def test_decode_dimension_entities_reads_ordinate_axis(relative_path: str) -> None:
+    rows = _rows_by_handle(ROOT / relative_path)
+
+    # 1209 has a horizontal leader and measures Y; 1210 has a vertical one.
+    assert rows[1209][1][11][2] is False
+    assert rows[1210][1][11][2] is True
+
+    dimensions = {
+        entity.handle: entity
+        for entity in ezdwg.read(str(ROOT / relative_path)).modelspace().query("DIMENSION")
+    }
+    assert dimensions[1209].dxf["is_x_type"] is False
+    assert dimensions[1210].dxf["is_x_type"] is True
+    assert "is_x_type" not in dimensions[1193].dxf
+
+
+def test_ordinate_dim_type_prefers_the_decoded_axis() -> None:
+    feature, offset = (660.87, 8.64), (700.77, 14.34)
+
+    assert _ordinate_dim_type(feature, offset, False) == 1
+    assert _ordinate_dim_type(feature, offset, True) == 0
+    # The leader direction is only a fallback.
+    assert _ordinate_dim_type(feature, offset) == 0
+
+
+@pytest.mark.parametrize("relative_path", SAMPLES)
+def test_decode_dimension_entities_reads_ang3pt_vertex(relative_path: str) -> None:
+    rows = _rows_by_handle(ROOT / relative_path)
+
+    dimtype, row = rows[1193]
+    assert dimtype == "ANG3PT"
+    _assert_point(row[2], (472.36, 22.16))
+    _assert_point(row[3], (455.68, 8.64))
+    _assert_point(row[4], (444.28, 37.14))
+    point15, point16 = row[11][:2]
+    _assert_point(point15, (438.58, 14.34))
+    assert point16 is None
+
+
+@pytest.mark.parametrize("relative_path", SAMPLES)
+def test_decode_dimension_entities_reads_ang2ln_lines(relative_path: str) -> None:
+    rows = _rows_by_handle(ROOT / relative_path)
+
+    dimtype, row = rows[1194]
+    assert dimtype == "ANG2LN"
+    _assert_point(row[3], (495.58, 14.34))
+    _assert_point(row[4], (501.28, 37.14))
+    point15, point16 = row[11][:2]
+    _assert_point(point15, (495.58, 14.34))
+    _assert_point(point16, (528.65, 21.99))
+
+
+def _class_dimension_row(handle: int, extra: tuple) -> tuple:
+    return (
+        handle,
+        "<>",
+        (0.0, 12.0, 0.0),
+        (10.0, 0.0, 0.0),
+        (0.0, 10.0, 0.0),
+        (6.0, 6.0, 0.0),
+        None,
+        ((0.0, 0.0, 1.0), (1.0, 1.0, 1.0)),
+        (0.0, 0.0, 0.0, 0.0),
+        (0, 15.7, None, None, None, 0.0),
+        (None, None),
+        extra,
+    )
+
+
+def test_arc_and_jogged_radius_dimensions_expose_their_class_fields(monkeypatch) -> None:
+    import ezdwg.document as document_module
+
+    monkeypatch.setattr(document_module.raw, "decode_entity_styles", lambda _path: [])
+    monkeypatch.setattr(document_module.raw, "decode_layer_colors", lambda _path: [])
+    document_module._entity_style_map.cache_clear()
+    document_module._layer_color_map.cache_clear()
+    rows = [
+        (
+            "ARC",
+            _class_dimension_row(
+                0x201,
+                ((0.0, 0.0, 0.0), (3.0, 11.0, 0.0), None, (5.0, 15.0, 0.0))
+                + (True, (0.25, 1.5), True, None),
+            ),
+        ),
+        (
+            "LARGE_RADIAL",
+            _class_dimension_row(
+                0x202, ((30.0, 40.0, 0.0), None, None, None, None, None, None, 7.5)
+            ),
+        ),
+    ]
+    monkeypatch.setattr(document_module.raw, "decode_dimension_entities", lambda _path: rows)
+
+    doc = document_module.Document(path="dummy_class_dimensions.dwg", version="AC1027")
+    arc, radial = doc.modelspace().query("DIMENSION")
+
+    assert arc.dxf["dimtype"] == "ARC"
+    assert arc.dxf["defpoint5"] == (3.0, 11.0, 0.0)
+    assert arc.dxf["leader_end"] == (5.0, 15.0, 0.0)
+    assert arc.dxf["is_partial"] is True
+    assert (arc.dxf["arc_start_param"], arc.dxf["arc_end_param"]) == (0.25, 1.5)
+    assert arc.dxf["has_leader"] is True
+    assert "leader_length" not in arc.dxf
+    assert radial.dxf["dimtype"] == "LARGE_RADIAL"
+    assert radial.dxf["leader_length"] == 7.5
+    assert "leader_end" not in radial.dxf
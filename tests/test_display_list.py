@@ -0,0 +1,314 @@
+from __future__ import annotations
+
+import json
+import math
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.display_list as display_list_module
+from ezdwg import raw
+from ezdwg.entity import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+INSERT_SAMPLE = ROOT / "test_dwg/insert_2004.dwg"
+
+
+class _FakeDoc:
+    path = "dummy_display_list.dwg"
+    decode_path = "dummy_display_list.dwg"
+
+
+class _FakeLayout:
+    doc = _FakeDoc()
+
+    def __init__(self, entities: list[Entity]) -> None:
+        self._entities = entities
+
+    def query(self, types=None):
+        if types is None:
+            return list(self._entities)
+        return [entity for entity in self._entities if entity.dxftype in set(types)]
+
+
+def _fake_layout(monkeypatch, entities: list[Entity], modelspace: list[int], blocks) -> _FakeLayout:
+    monkeypatch.setattr(
+        display_list_module,
+        "_resolve_layout",
+        lambda layout: (layout.doc.path, layout),
+    )
+    monkeypatch.setattr(
+        display_list_module,
+        "_layer_names_by_handle",
+        lambda _path: {0x10: "0", 0x11: "Doors"},
+    )
+    monkeypatch.setattr(
+        display_list_module,
+        "_modelspace_children",
+        lambda _path: (0x1F, tuple(modelspace)),
+    )
+    monkeypatch.setattr(
+        display_list_module,
+        "_block_definition",
+        lambda _path, name: blocks.get(name),
+    )
+    return _FakeLayout(entities)
+
+
+def test_build_display_list_expands_sample_insert() -> None:
+    result = ezdwg.build_display_list(str(INSERT_SAMPLE))
+
+    # BLK1 holds a 20 unit LINE; the INSERT places it at (100, 50) with
+    # scale (2, 1.5) and a 15 degree rotation.
+    assert [command.op for command in result.commands] == ["move", "line"]
+    start, end = (command.point for command in result.commands)
+    assert start == pytest.approx((100.0, 50.0))
+    angle = math.radians(15.0)
+    assert end == pytest.approx((100.0 + 40.0 * math.cos(angle), 50.0 + 40.0 * math.sin(angle)))
+    assert {command.handle for command in result.commands} == {0x2A}
+    assert {(command.inserts, command.blocks) for command in result.commands} == {
+        ((0x2C,), ("BLK1",))
+    }
+
+
+def test_decode_block_base_point_reads_block_record() -> None:
+    assert raw.decode_block_base_point(str(INSERT_SAMPLE), 41) == (0.0, 0.0, 0.0)
+    with pytest.raises(ValueError, match="block record not found"):
+        raw.decode_block_base_point(str(INSERT_SAMPLE), 0x2A)
+
+
+def test_block_children_inherit_insert_style(monkeypatch) -> None:
+    layout = _fake_layout(
+        monkeypatch,
+        [
+            Entity(
+                dxftype="INSERT",
+                handle=0x30,
+                dxf={
+                    "insert": (10.0, 0.0, 0.0),
+                    "xscale": -2.0,
+                    "yscale": 2.0,
+                    "rotation": 0.0,
+                    "name": "DOOR",
+                    "layer_handle": 0x11,
+                    "color_index": 1,
+                    "lineweight": 35,
+                },
+            ),
+            Entity(
+                dxftype="ARC",
+                handle=0x40,
+                dxf={
+                    "center": (1.0, 0.0, 0.0),
+                    "radius": 1.0,
+                    "start_angle": 0.0,
+                    "end_angle": 90.0,
+                    "layer_handle": 0x10,
+                    "color_index": 0,
+                    "lineweight": -2,
+                },
+            ),
+            Entity(
+                dxftype="TEXT",
+                handle=0x41,
+                dxf={
+                    "insert": (0.0, 1.0, 0.0),
+                    "text": "D1",
+                    "height": 0.5,
+                    "rotation": 90.0,
+                    "layer_handle": 0x11,
+                    "color_index": 3,
+                },
+            ),
+        ],
+        modelspace=[0x30],
+        blocks={"DOOR": (0x20, (1.0, 0.0, 0.0), (0x40, 0x41))},
+    )
+
+    result = ezdwg.build_display_list(layout)
+
+    arc, text = result.commands
+    assert (arc.op, arc.layer, arc.color, arc.width) == ("arc", "Doors", "#ff0000", 0.35)
+    assert arc.point == pytest.approx((10.0, 0.0))
+    assert arc.radius == pytest.approx(2.0)
+    # Mirroring in X turns the 0..90 arc into 90..180.
+    assert (arc.start_angle, arc.end_angle) == pytest.approx((90.0, 180.0))
+    assert (text.op, text.layer, text.color, text.text) == ("text", "Doors", "#00ff00", "D1")
+    assert text.point == pytest.approx((12.0, 2.0))
+    assert text.height == pytest.approx(1.0)
+    assert text.rotation == pytest.approx(90.0)
+    assert (arc.order, text.order) == (0, 1)
+    assert (arc.inserts, arc.blocks, arc.depth) == ((0x30,), ("DOOR",), 1)
+
+
+def test_minsert_repeats_block_and_rejects_cycles(monkeypatch) -> None:
+    blocks = {"CELL": (0x20, (0.0, 0.0, 0.0), (0x40,))}
+    layout = _fake_layout(
+        monkeypatch,
+        [
+            Entity(
+                dxftype="MINSERT",
+                handle=0x30,
+                dxf={
+                    "insert": (0.0, 0.0, 0.0),
+                    "name": "CELL",
+                    "column_count": 3,
+                    "row_count": 2,
+                    "column_spacing": 10.0,
+                    "row_spacing": 5.0,
+                },
+            ),
+            Entity(
+                dxftype="SOLID",
+                handle=0x40,
+                dxf={"points": [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (1.0, 1.0, 0.0)]},
+            ),
+            Entity(dxftype="INSERT", handle=0x41, dxf={"insert": (0.0, 0.0, 0.0), "name": "CELL"}),
+        ],
+        modelspace=[0x30],
+        blocks=blocks,
+    )
+
+    result = ezdwg.build_display_list(layout)
+
+    assert [command.op for command in result.commands] == ["fill"] * 6
+    assert [command.order for command in result.commands] == list(range(6))
+    assert result.commands[0].rings == (((0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)),)
+    assert result.commands[-1].rings[0][0] == pytest.approx((20.0, 5.0))
+    assert {(command.handle, command.inserts) for command in result.commands} == {
+        (0x40, (0x30,))
+    }
+
+    filtered = ezdwg.build_display_list(layout, types="LINE")
+    assert filtered.commands == []
+
+    # CELL inserting itself is an error rather than a partial drawing.
+    blocks["CELL"] = (0x20, (0.0, 0.0, 0.0), (0x40, 0x41))
+    with pytest.raises(ezdwg.BlockReferenceError, match="circular") as cycle:
+        ezdwg.build_display_list(layout)
+    assert cycle.value.kind == "cycle"
+    assert cycle.value.blocks == ["CELL", "CELL"]
+
+
+def test_build_display_list_rejects_blocks_nested_too_deep(monkeypatch) -> None:
+    names = [f"B{index}" for index in range(17)]
+    entities = [
+        Entity(dxftype="INSERT", handle=0x100 + index, dxf={"name": name})
+        for index, name in enumerate(names)
+    ]
+    # Every block holds the INSERT of the next one; model space inserts B0.
+    blocks = {
+        name: (0x20 + index, (0.0, 0.0, 0.0), (0x101 + index,) if index < 16 else ())
+        for index, name in enumerate(names)
+    }
+    layout = _fake_layout(monkeypatch, entities, modelspace=[0x100], blocks=blocks)
+
+    with pytest.raises(ezdwg.BlockReferenceError) as too_deep:
+        ezdwg.build_display_list(layout)
+    assert too_deep.value.kind == "depth"
+    assert too_deep.value.blocks == names
+
+    # Sixteen levels are still drawn.
+    blocks["B15"] = (0x2F, (0.0, 0.0, 0.0), ())
+    assert ezdwg.build_display_list(layout).commands == []
+
+
+def test_display_list_serializes_to_json_and_bytes(monkeypatch) -> None:
+    layout = _fake_layout(
+        monkeypatch,
+        [
+            Entity(
+                dxftype="LINE",
+                handle=0x40,
+                dxf={
+                    "start": (0.0, 0.0, 0.0),
+                    "end": (3.0, 4.0, 0.0),
+                    "layer_handle": 0x11,
+                    "color_index": 5,
+                    "lineweight": 13,
+                    "resolved_transparency": 0x02000080,
+                },
+            ),
+            Entity(
+                dxftype="CIRCLE",
+                handle=0x41,
+                dxf={"center": (1.0, 1.0, 0.0), "radius": 2.0},
+            ),
+            Entity(
+                dxftype="MTEXT",
+                handle=0x42,
+                dxf={"insert": (0.0, 0.0, 0.0), "text": "a\\Pb", "char_height": 2.5},
+            ),
+        ],
+        modelspace=[0x40, 0x41, 0x42],
+        blocks={},
+    )
+    result = ezdwg.build_display_list(layout)
+
+    payload = json.loads(result.to_json())
+    assert payload["version"] == 2
+    assert payload["commands"][0] == {
+        "op": "move",
+        "order": 0,
+        "handle": 0x40,
+        "layer": "Doors",
+        "color": "#0000ff80",
+        "width": 0.13,
+        "point": [0.0, 0.0],
+    }
+    assert payload["commands"][2]["center"] == [1.0, 1.0]
+    assert payload["commands"][2]["end_angle"] == 360.0
+    assert payload["commands"][3]["text"] == "a\nb"
+
+    data = result.to_bytes()
+    assert data.startswith(b"EZDL")
+    assert ezdwg.DisplayList.from_bytes(data) == result
+    with pytest.raises(ValueError, match="truncated"):
+        ezdwg.DisplayList.from_bytes(data[:-1])
+
+
+def test_nested_block_primitives_trace_back_to_their_inserts(monkeypatch) -> None:
+    layout = _fake_layout(
+        monkeypatch,
+        [
+            Entity(
+                dxftype="INSERT",
+                handle=0x30,
+                dxf={"insert": (100.0, 0.0, 0.0), "name": "ROOM"},
+            ),
+            Entity(
+                dxftype="INSERT",
+                handle=0x40,
+                dxf={"insert": (10.0, 0.0, 0.0), "name": "DOOR"},
+            ),
+            Entity(
+                dxftype="LINE",
+                handle=0x50,
+                dxf={"start": (0.0, 0.0, 0.0), "end": (1.0, 0.0, 0.0)},
+            ),
+        ],
+        modelspace=[0x30],
+        blocks={
+            "ROOM": (0x20, (0.0, 0.0, 0.0), (0x40,)),
+            "DOOR": (0x21, (0.0, 0.0, 0.0), (0x50,)),
+        },
+    )
+
+    result = ezdwg.build_display_list(layout)
+
+    move, line = result.commands
+    assert line.point == pytest.approx((111.0, 0.0))
+    assert (move.handle, move.inserts, move.blocks, move.depth) == (
+        0x50,
+        (0x30, 0x40),
+        ("ROOM", "DOOR"),
+        2,
+    )
+    payload = json.loads(result.to_json())
+    assert (payload["commands"][1]["inserts"], payload["commands"][1]["blocks"]) == (
+        [0x30, 0x40],
+        ["ROOM", "DOOR"],
+    )
+    assert ezdwg.DisplayList.from_bytes(result.to_bytes()) == result
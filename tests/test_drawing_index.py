@@ -0,0 +1,91 @@
+from __future__ import annotations
+
+import shutil
+from pathlib import Path
+
+import ezdwg
+import ezdwg.cli as cli_module
+import ezdwg.drawing_index as drawing_index_module
+
+
+ROOT = Path(__file__).resolve().parents[1]
+
+
+def _project(tmp_path: Path) -> Path:
+    (tmp_path / "sheets").mkdir(parents=True)
+    shutil.copy(ROOT / "test_dwg/insert_2004.dwg", tmp_path / "sheets/plan.dwg")
+    shutil.copy(ROOT / "test_dwg/mtext_2004.dwg", tmp_path / "notes.dwg")
+    return tmp_path
+
+
+def test_build_index_collects_blocks_and_text(tmp_path: Path) -> None:
+    index = ezdwg.build_index(str(_project(tmp_path)), threads=2)
+
+    assert [drawing.path for drawing in index.files] == ["notes.dwg", "sheets/plan.dwg"]
+    assert index.errors == {}
+    plan = index.files[1]
+    assert plan.version == "AC1018"
+    assert plan.blocks
+    assert index.files_with_block(plan.blocks[0].lower()) == ["sheets/plan.dwg"]
+    assert index.search_text("hello mtext") == [
+        drawing_index_module.TextHit("notes.dwg", "Hello MTEXT")
+    ]
+
+    saved = tmp_path / "index.json"
+    index.save(str(saved))
+    assert drawing_index_module.DrawingIndex.load(str(saved)) == index
+
+
+def test_build_index_resolves_xref_paths(tmp_path: Path, monkeypatch) -> None:
+    for name in ("base/site.dwg", "sheets/a101.dwg", "sheets/a102.dwg"):
+        (tmp_path / name).parent.mkdir(exist_ok=True)
+        (tmp_path / name).write_bytes(b"")
+    xrefs = {
+        "sheets/a101.dwg": [(0x20, "SITE", "..\\base\\Site.dwg", False)],
+        "sheets/a102.dwg": [
+            (0x21, "SITE", "C:\\projects\\old\\site.dwg", True),
+            (0x22, "SURVEY", "C:\\projects\\old\\survey.dwg", False),
+        ],
+    }
+
+    def fake_build(paths, _threads):
+        rows = []
+        for path in paths:
+            key = Path(path).relative_to(tmp_path).as_posix()
+            rows.append((path, "AC1032", [], xrefs.get(key, []), [], None))
+        return rows
+
+    monkeypatch.setattr(drawing_index_module.raw, "build_drawing_index", fake_build)
+    index = ezdwg.build_index(str(tmp_path))
+
+    assert index.xref_graph() == {
+        "base/site.dwg": [],
+        "sheets/a101.dwg": ["base/site.dwg"],
+        "sheets/a102.dwg": ["base/site.dwg"],
+    }
+    assert index.referenced_by("base/site.dwg") == ["sheets/a101.dwg", "sheets/a102.dwg"]
+    [unresolved] = index.unresolved_xrefs()
+    assert (unresolved.source, unresolved.block) == ("sheets/a102.dwg", "SURVEY")
+
+
+def test_cli_index_lists_blocks_and_text(tmp_path: Path, capsys) -> None:
+    project = _project(tmp_path / "project")
+    output = tmp_path / "index.json"
+
+    code = cli_module.main(
+        [
+            "index",
+            str(project),
+            "--text",
+            "hello",
+            "-o",
+            str(output),
+        ]
+    )
+
+    lines = capsys.readouterr().out.splitlines()
+    assert code == 0
+    assert "files: 2" in lines
+    assert "errors: 0" in lines
+    assert "text[notes.dwg]: Hello MTEXT" in lines
+    assert output.exists()
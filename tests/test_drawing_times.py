@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+@pytest.mark.parametrize(
+    "name",
+    ["line_2000.dwg", "line_2004.dwg", "line_2007.dwg", "line_2010.dwg", "line_2013.dwg"],
+)
+def test_drawing_times_match_creation_timestamp(name: str) -> None:
+    times = ezdwg.read(str(SAMPLES / name)).drawing_times()
+
+    assert times.created == datetime(2016, 3, 31, 21, 34, 29, 368000)
+    assert times.updated >= times.created
+    assert timedelta(0) < times.edit_time < timedelta(minutes=5)
+
+
+def test_decode_drawing_times_returns_julian_pairs() -> None:
+    created, _, edit_time, _ = ezdwg.raw.decode_drawing_times(str(SAMPLES / "text_2000.dwg"))
+
+    assert created == (2457479, 77669368)
+    assert edit_time == (0, 123502)
+
+
+def test_to_dwg_writes_drawing_times(tmp_path: Path) -> None:
+    source = ezdwg.read(str(SAMPLES / "line_2000.dwg"))
+    output = tmp_path / "times.dwg"
+    times = ezdwg.DrawingTimes(
+        created=datetime(2020, 1, 2, 3, 4, 5, 678000),
+        updated=datetime(2024, 6, 7, 8, 9, 10),
+        edit_time=timedelta(hours=26, milliseconds=5),
+        user_timer=timedelta(seconds=42),
+    )
+
+    ezdwg.to_dwg(source, str(output), drawing_times=times)
+    assert ezdwg.read(str(output)).drawing_times() == times
+
+    ezdwg.to_dwg(source, str(output), drawing_times=source.drawing_times())
+    assert ezdwg.read(str(output)).drawing_times() == source.drawing_times()
+
+
+def test_to_dwg_stamps_current_time_by_default(tmp_path: Path) -> None:
+    output = tmp_path / "now.dwg"
+    before = _utcnow() - timedelta(seconds=1)
+
+    ezdwg.to_dwg(str(SAMPLES / "line_2000.dwg"), str(output))
+
+    times = ezdwg.read(str(output)).drawing_times()
+    assert times.created == times.updated
+    assert before <= times.created <= _utcnow() + timedelta(seconds=1)
+    assert times.edit_time == timedelta(0)
+
+
+def _utcnow() -> datetime:
+    return datetime.now(timezone.utc).replace(tzinfo=None)
@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/line_2000.dwg"
+
+
+def _line(y: float, layer: str | None = None) -> Entity:
+    dxf = {"start": (0.0, y, 0.0), "end": (10.0, y, 0.0)}
+    if layer is not None:
+        dxf["layer"] = layer
+    return Entity("LINE", 1, dxf)
+
+
+def test_dwg_template_stamps_variants_concurrently(tmp_path: Path) -> None:
+    template = ezdwg.DwgTemplate(str(SAMPLE))
+    source_line = next(ezdwg.read(str(SAMPLE)).modelspace().query("LINE"))
+    outputs = {
+        str(tmp_path / f"plan_{index}.dwg"): [_line(float(index), layer=f"PLAN-{index % 2}")]
+        for index in range(6)
+    }
+
+    results = template.write_many(outputs, threads=3)
+
+    assert [result.output_path for result in results] == list(outputs)
+    for index, result in enumerate(results):
+        assert (result.total_entities, result.written_entities) == (2, 2)
+        names = dict(ezdwg.raw.decode_layer_names(result.output_path))
+        lines = list(ezdwg.read(result.output_path).modelspace().query("LINE"))
+        assert lines[0].dxf["start"] == source_line.dxf["start"]
+        assert lines[1].dxf["start"] == (0.0, float(index), 0.0)
+        assert names[lines[1].dxf["layer_handle"]] == f"PLAN-{index % 2}"
+        assert lines[1].handle > source_line.handle
+
+
+def test_dwg_template_write_without_additions_matches_to_dwg(tmp_path: Path) -> None:
+    template = ezdwg.DwgTemplate(str(SAMPLE))
+    expected = ezdwg.to_dwg(str(SAMPLE), str(tmp_path / "to_dwg.dwg"))
+
+    result = template.write(str(tmp_path / "template.dwg"), [_line(5.0)])
+    plain = template.write(str(tmp_path / "plain.dwg"))
+
+    assert plain.written_entities == expected.written_entities
+    assert result.written_entities == expected.written_entities + 1
+    names = dict(ezdwg.raw.decode_layer_names(result.output_path))
+    added = list(ezdwg.read(result.output_path).modelspace().query("LINE"))[-1]
+    assert names[added.dxf["layer_handle"]] == "0"
+
+    with pytest.raises(ValueError, match="distinct"):
+        template.write_many({str(tmp_path / "a.dwg"): [], f"{tmp_path}/./a.dwg": []})
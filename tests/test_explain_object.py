@@ -0,0 +1,56 @@
+from __future__ import annotations
+
+import io
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.cli as cli_module
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/insert_2004.dwg"
+
+
+def test_raw_explain_object_traces_line_fields() -> None:
+    handle, offset, type_code, type_name, data, fields, decoded, error = raw.explain_object(
+        str(SAMPLE), 0x2A
+    )
+
+    assert (handle, type_code, type_name, error) == (0x2A, 19, "LINE", None)
+    assert offset > 0
+    assert fields[0] == ("prefix", 0, 10, "BS", "19")
+    assert all(stage == "LINE" for stage, *_ in fields[1:])
+    # Fields are contiguous and stay inside the object data.
+    for previous, current in zip(fields, fields[1:]):
+        assert previous[2] == current[1]
+    assert fields[-1][2] <= len(data) * 8
+    assert ("H", "4.1.29") in [(code, value) for _, _, _, code, value in fields]
+    assert "end: (20.0, 0.0, 0.0)" in decoded
+
+
+def test_explain_object_prints_hexdump_and_fields() -> None:
+    out = io.StringIO()
+
+    explanation = ezdwg.explain_object(str(SAMPLE), 0x2A, file=out)
+
+    assert explanation.fields[1].code == "RL"
+    text = out.getvalue()
+    assert text.startswith("handle: 0x2A\ntype: LINE (19)\n")
+    assert "\n0000  44 c6 c0 40" in text
+    assert "prefix  bits     0..10    BS   19" in text
+    assert text.rstrip().endswith(
+        "end: (20.0, 0.0, 0.0), thickness: 0.0, extrusion: (0.0, 0.0, 1.0) }"
+    )
+
+
+def test_explain_object_reports_missing_decoder_and_handle(capsys) -> None:
+    assert cli_module.main(["explain", str(SAMPLE), "0x1"]) == 1
+    out = capsys.readouterr().out
+    assert "type: BLOCK_CONTROL (48)" in out
+    assert "error: no entity decoder for BLOCK_CONTROL" in out
+
+    with pytest.raises(ValueError, match="object not found: 0xFFFFF"):
+        raw.explain_object(str(SAMPLE), 0xFFFFF)
@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import ezdwg
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+def test_describe_file_layout_of_standard_files() -> None:
+    r2000 = ezdwg.describe_file_layout(SAMPLES / "line_2000.dwg")
+    assert (r2000.version, r2000.flavor, r2000.standard, r2000.warnings) == (
+        "AC1015",
+        "locator-table",
+        True,
+        [],
+    )
+    assert [name for name, _offset, _size in r2000.sections][:3] == [
+        "HeaderVariables",
+        "Classes",
+        "ObjectMap",
+    ]
+
+    r2004 = ezdwg.describe_file_layout(SAMPLES / "line_2004.dwg")
+    assert (r2004.flavor, r2004.standard) == ("paged", True)
+    assert "AcDb:AcDbObjects" in [name for name, _offset, _size in r2004.sections]
+    assert ezdwg.describe_file_layout(SAMPLES / "line_2007.dwg").flavor == "paged-r2007"
+
+
+def test_reordered_section_directory_is_normalized(tmp_path: Path) -> None:
+    data = bytearray((SAMPLES / "line_2000.dwg").read_bytes())
+    # Swap the first two 9-byte locator records after the record count at 0x15.
+    first, second = data[0x19:0x22], data[0x22:0x2B]
+    data[0x19:0x2B] = second + first
+    reordered = tmp_path / "reordered.dwg"
+    reordered.write_bytes(bytes(data))
+
+    layout = ezdwg.describe_file_layout(reordered)
+
+    assert not layout.standard
+    assert layout.warnings == ["section locator records out of order"]
+    assert layout.sections == ezdwg.describe_file_layout(SAMPLES / "line_2000.dwg").sections
+    lines = [entity.dxf["end"] for entity in ezdwg.read(str(reordered)).modelspace().query("LINE")]
+    expected = ezdwg.read(str(SAMPLES / "line_2000.dwg")).modelspace().query("LINE")
+    assert lines == [entity.dxf["end"] for entity in expected]
@@ -0,0 +1,100 @@
+from __future__ import annotations
+
+import json
+import warnings
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg.fonts import ResolvedFont, normalize_font_name, text_style_fonts
+from ezdwg.render import _text_font_resolver
+
+
+ROOT = Path(__file__).resolve().parents[1]
+TEXT_2000 = ROOT / "test_dwg/text_2000.dwg"
+LINE_2007 = ROOT / "test_dwg/line_2007.dwg"
+SAMPLE_AC1032 = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+
+
+def test_normalize_font_name() -> None:
+    assert normalize_font_name("TXT") == "txt.shx"
+    assert normalize_font_name(r"C:\Fonts\RomanS.SHX") == "romans.shx"
+    assert normalize_font_name("/usr/share/fonts/Arial.ttf") == "arial.ttf"
+    assert normalize_font_name("") == ""
+
+
+def test_font_map_resolves_rules_and_warns_on_fallback() -> None:
+    font_map = ezdwg.FontMap(
+        [
+            ezdwg.FontRule("romans", "Liberation Sans Narrow", width_scale=0.9),
+            ezdwg.FontRule(r"simplex.*\.shx", "DejaVu Sans", regex=True),
+        ],
+        fallback="Noto Sans",
+    )
+
+    assert font_map.resolve("ROMANS.SHX") == ResolvedFont(
+        "romans.shx", "Liberation Sans Narrow", 0.9, False
+    )
+    assert font_map.resolve("simplex8").font == "DejaVu Sans"
+
+    with pytest.warns(ezdwg.FontSubstitutionWarning, match="gdt.shx drawn with Noto Sans"):
+        resolved = font_map.resolve("gdt")
+    assert resolved == ResolvedFont("gdt.shx", "Noto Sans", 1.0, True)
+    with warnings.catch_warnings():
+        warnings.simplefilter("error")
+        font_map.resolve("gdt")
+
+    strict = ezdwg.FontMap([ezdwg.FontRule("txt", "DejaVu Sans")], strict=True)
+    with pytest.raises(ValueError, match="no font mapping for arial.ttf"):
+        strict.resolve("arial.ttf")
+    with pytest.raises(ValueError, match="width scale must be positive"):
+        ezdwg.FontMap([ezdwg.FontRule("txt", "DejaVu Sans", width_scale=0.0)])
+
+
+def test_font_map_loads_json(tmp_path: Path) -> None:
+    path = tmp_path / "fonts.json"
+    path.write_text(
+        json.dumps(
+            {
+                "fonts": {
+                    "txt": "DejaVu Sans Mono",
+                    "arial.ttf": {"font": "/opt/fonts/LiberationSans.ttf", "width_scale": 1.02},
+                },
+                "fallback": "Noto Sans",
+                "strict": True,
+            }
+        ),
+        encoding="utf-8",
+    )
+
+    font_map = ezdwg.FontMap.load(str(path))
+
+    assert font_map.fallback == "Noto Sans"
+    assert font_map.strict
+    assert font_map.resolve("txt.shx").font == "DejaVu Sans Mono"
+    resolved = font_map.resolve("Arial.ttf")
+    assert resolved.width_scale == 1.02
+    assert resolved.is_file
+
+
+def test_decode_text_styles_reads_style_fonts() -> None:
+    for path in (TEXT_2000, LINE_2007):
+        styles = {row[1]: row for row in ezdwg.raw.decode_text_styles(str(path))}
+        assert styles["Standard"][2:5] == ("txt", "", 1.0)
+        assert not styles["Standard"][7]
+
+    fonts = text_style_fonts(str(SAMPLE_AC1032))
+    assert fonts[17] == "arial.ttf"
+
+
+def test_text_entities_resolve_their_style_font() -> None:
+    layout = ezdwg.read(str(TEXT_2000)).modelspace()
+    font_map = ezdwg.FontMap([ezdwg.FontRule("txt", "DejaVu Sans Mono", width_scale=0.9)])
+
+    resolve = _text_font_resolver(layout, font_map)
+    fonts = {resolve(entity.dxf) for entity in layout.query("TEXT")}
+
+    assert fonts == {ResolvedFont("txt.shx", "DejaVu Sans Mono", 0.9, False)}
+    assert _text_font_resolver(layout, None) is None
+    assert fonts.pop().matplotlib_kwargs() == {"fontfamily": "DejaVu Sans Mono"}
@@ -0,0 +1,122 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+
+
+def _layers(path: Path) -> dict[str, int]:
+    names = dict(ezdwg.raw.decode_layer_names(str(path)))
+    return {names[handle]: index for handle, index, _ in ezdwg.raw.decode_layer_colors(str(path))}
+
+
+def _entity_layers(path: Path, dxftype: str) -> list[str]:
+    names = dict(ezdwg.raw.decode_layer_names(str(path)))
+    return [
+        names[entity.dxf["layer_handle"]]
+        for entity in ezdwg.read(str(path)).modelspace().query(dxftype)
+    ]
+
+
+def test_csv_to_dwg_writes_segments_and_points(tmp_path: Path) -> None:
+    source = tmp_path / "survey.csv"
+    source.write_text(
+        "X1,Y1,X2,Y2,x,y,z,Layer\n"
+        "0,0,1.5,0,,,,Kerb\n"
+        ",,,,2,3,0.5,Trees\n"
+        ",,,,,,,\n"
+        "1,1,2,2,,,,\n",
+        encoding="utf-8",
+    )
+    output = tmp_path / "survey.dwg"
+
+    result = ezdwg.csv_to_dwg(
+        str(source),
+        str(output),
+        source_units="m",
+        target_units="mm",
+        layer_map={"Kerb": "KERB"},
+        layer_colors={"Trees": 3},
+    )
+
+    assert (result.total_entities, result.written_entities) == (4, 3)
+    assert result.skipped_by_type == {"CSV row": 1}
+    lines = list(ezdwg.read(str(output)).modelspace().query("LINE"))
+    assert [line.dxf["end"] for line in lines] == [
+        pytest.approx((1500.0, 0.0, 0.0)),
+        pytest.approx((2000.0, 2000.0, 0.0)),
+    ]
+    point = next(ezdwg.read(str(output)).modelspace().query("POINT"))
+    assert point.dxf["location"] == pytest.approx((2000.0, 3000.0, 500.0))
+    assert _entity_layers(output, "LINE") == ["KERB", "0"]
+    assert _layers(output) == {"0": 7, "KERB": 7, "Trees": 3}
+
+    with pytest.raises(ValueError, match="CSV line 2: y is not a number"):
+        ezdwg.csv_to_dwg(["x,y\n", "1,north\n"], str(tmp_path / "bad.dwg"))
+    with pytest.raises(ValueError, match="unknown units"):
+        ezdwg.csv_to_dwg(str(source), str(output), source_units="m", target_units="furlong")
+
+
+def test_geojson_to_dwg_writes_features_by_layer(tmp_path: Path) -> None:
+    collection = {
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": {"zone": "residential"},
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [
+                        [[0, 0], [10, 0], [10, 10], [0, 10], [0, 0]],
+                        [[2, 2], [4, 2], [4, 4], [2, 2]],
+                    ],
+                },
+            },
+            {
+                "type": "Feature",
+                "properties": {"zone": "road"},
+                "geometry": {"type": "LineString", "coordinates": [[0, -2], [10, -2, 1]]},
+            },
+            {
+                "type": "Feature",
+                "properties": {},
+                "geometry": {
+                    "type": "GeometryCollection",
+                    "geometries": [
+                        {"type": "MultiPoint", "coordinates": [[1, 1], [2, 2, 3]]},
+                        {"type": "LineString", "coordinates": [[5, 5]]},
+                    ],
+                },
+            },
+            {"type": "Feature", "properties": {"zone": "road"}, "geometry": None},
+        ],
+    }
+    source = tmp_path / "parcels.geojson"
+    source.write_text(json.dumps(collection), encoding="utf-8")
+    output = tmp_path / "parcels.dwg"
+
+    result = ezdwg.geojson_to_dwg(
+        str(source),
+        str(output),
+        layer_property="zone",
+        layer_map=ezdwg.LayerMap([ezdwg.LayerRule("residential", target="Z-RES", color=3)]),
+        scale=2.0,
+    )
+
+    assert (result.written_entities, result.skipped_by_type) == (5, {"GeometryCollection": 1})
+    polylines = list(ezdwg.read(str(output)).modelspace().query("LWPOLYLINE"))
+    assert [(len(p.dxf["points"]), p.dxf["flags"] & 1) for p in polylines] == [
+        (4, 1),
+        (3, 1),
+        (2, 0),
+    ]
+    assert polylines[0].dxf["points"][2] == pytest.approx((20.0, 20.0, 0.0))
+    assert _entity_layers(output, "LWPOLYLINE") == ["Z-RES", "Z-RES", "road"]
+    assert _entity_layers(output, "POINT") == ["0", "0"]
+    assert _layers(output) == {"0": 7, "Z-RES": 3, "road": 7}
+
+    with pytest.raises(ValueError, match="unsupported GeoJSON geometry type"):
+        ezdwg.geojson_to_dwg({"type": "Circle"}, str(tmp_path / "bad.dwg"))
@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+import json
+import os
+import shutil
+from pathlib import Path
+
+import pytest
+
+from ezdwg import golden
+
+
+ROOT = Path(__file__).resolve().parents[1]
+GOLDEN_DIR = Path(os.environ.get("EZDWG_GOLDEN_DIR", ROOT / "tests/golden"))
+
+
+@pytest.mark.skipif(not os.environ.get("EZDWG_GOLDEN"), reason="set EZDWG_GOLDEN=1 to check the corpus")
+def test_golden_corpus_matches_snapshots() -> None:
+    mismatches = golden.check_corpus(GOLDEN_DIR)
+
+    report = "\n".join(
+        f"{mismatch.path}:\n  " + "\n  ".join(mismatch.differences) for mismatch in mismatches
+    )
+    assert not mismatches, report
+
+
+def test_check_corpus_reports_changed_and_missing_snapshots(tmp_path: Path) -> None:
+    shutil.copy(ROOT / "test_dwg/insert_2004.dwg", tmp_path / "insert.dwg")
+    (tmp_path / "nested").mkdir()
+    shutil.copy(ROOT / "test_dwg/line_2000.dwg", tmp_path / "nested/line.dwg")
+    (tmp_path / "nested/line.json").write_text(json.dumps({"format": 1, "types": "LINE"}))
+
+    written = golden.regenerate_corpus(tmp_path, missing_only=True)
+
+    assert written == [tmp_path / "insert.json"]
+    (partial,) = golden.check_corpus(tmp_path)
+    assert partial.path.name == "line.dwg"
+    assert partial.differences[0].startswith('entities: unexpected [{"type": "LINE"')
+    golden.regenerate_corpus(tmp_path)
+    line_snapshot = json.loads((tmp_path / "nested/line.json").read_text())
+    assert line_snapshot["types"] == "LINE"
+    assert {entity["type"] for entity in line_snapshot["entities"]} == {"LINE"}
+    assert golden.check_corpus(tmp_path) == []
+
+    snapshot_path = tmp_path / "insert.json"
+    data = json.loads(snapshot_path.read_text())
+    data["entities"][0]["dxf"]["end"][0] = 21.0
+    del data["entities"][1]
+    snapshot_path.write_text(json.dumps(data))
+    (tmp_path / "nested/line.json").unlink()
+
+    mismatches = golden.check_corpus(tmp_path)
+
+    assert [mismatch.path.name for mismatch in mismatches] == ["insert.dwg", "line.dwg"]
+    assert mismatches[0].differences == [
+        "entities: expected 1 items, got 2",
+        "entities[0].dxf.end[0]: expected 21.0, got 20.0",
+    ]
+    assert mismatches[1].differences == ["snapshot missing; run regenerate"]
+
+
+def test_snapshot_normalizes_values() -> None:
+    assert golden._normalize(
+        {"a": (0.1 + 0.2, -0.0), "b": b"\x01\xff", "c": float("nan"), "d": {3, 1}}
+    ) == {"a": [0.3, 0.0], "b": "01ff", "c": "nan", "d": [1, 3]}
+
+
+def test_golden_cli_checks_corpus(capsys) -> None:
+    assert golden.main(["check", str(ROOT / "tests/golden")]) == 0
+    assert capsys.readouterr().out.splitlines()[-1] == "checked: 2 files, 0 mismatched"
@@ -0,0 +1,94 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+import ezdwg
+from ezdwg import render
+from ezdwg.entity import Entity
+from ezdwg.hatch_fill import hatch_pattern_lines, predefined_pattern
+
+SQUARE = [(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0), (0.0, 0.0, 0.0)]
+HOLE = [(4.0, 4.0, 0.0), (6.0, 4.0, 0.0), (6.0, 6.0, 0.0), (4.0, 6.0, 0.0), (4.0, 4.0, 0.0)]
+
+
+def _hatch(*loops, solid_fill=False, pattern=None, pattern_name="") -> Entity:
+    return Entity(
+        dxftype="HATCH",
+        handle=1,
+        dxf={
+            "pattern_name": pattern_name,
+            "solid_fill": solid_fill,
+            "paths": [{"closed": True, "points": list(loop)} for loop in loops],
+            "pattern": pattern,
+        },
+    )
+
+
+def test_pattern_lines_are_clipped_around_islands() -> None:
+    horizontal = [ezdwg.HatchPatternLine(0.0, (0.0, 0.5), (0.0, 1.0))]
+
+    fill = ezdwg.fill_hatch(_hatch(SQUARE, HOLE), horizontal)
+
+    assert fill.polygons == []
+    # Rows at y = 0.5 .. 9.5; rows 4.5 and 5.5 are split by the island.
+    assert len(fill.lines) == 12
+    assert ((0.0, 4.5), (4.0, 4.5)) in fill.lines
+    assert ((6.0, 4.5), (10.0, 4.5)) in fill.lines
+
+    outer_only = ezdwg.fill_hatch(_hatch(SQUARE, HOLE, pattern={"style": 2}), horizontal)
+    assert len(outer_only.lines) == 10
+
+
+def test_dashes_dots_and_decoded_definitions() -> None:
+    dashed = ezdwg.HatchPatternLine(0.0, (0.0, 5.0), (0.0, 100.0), (3.0, -1.0, 0.0, -1.0))
+    lines = hatch_pattern_lines([[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]], [dashed])
+    assert lines == [
+        ((0.0, 5.0), (3.0, 5.0)),
+        ((4.0, 5.0), (4.0, 5.0)),
+        ((5.0, 5.0), (8.0, 5.0)),
+        ((9.0, 5.0), (9.0, 5.0)),
+    ]
+
+    # Decoded lines are world-space: ANSI31 at scale 16, so 2 units apart.
+    decoded = {
+        "style": 0,
+        "angle": 0.0,
+        "scale": 16.0,
+        "lines": [
+            {"angle": 45.0, "origin": (0.0, 0.0), "offset": (-(2**0.5), 2**0.5), "dashes": []}
+        ],
+    }
+    fill = ezdwg.fill_hatch(_hatch(SQUARE, pattern=decoded, pattern_name="ANSI31"))
+    assert len(fill.lines) == 7
+    assert fill.lines[3] == pytest.approx(((0.0, 0.0), (10.0, 10.0)))
+    for start, end in fill.lines:
+        assert end[0] - start[0] == pytest.approx(end[1] - start[1])
+    by_name = ezdwg.fill_hatch(_hatch(SQUARE, pattern={"scale": 16.0}, pattern_name="ansi31"))
+    assert [point for line in by_name.lines for point in line] == pytest.approx(
+        [point for line in fill.lines for point in line]
+    )
+
+    net = predefined_pattern("NET", 8.0, 30.0)
+    assert [line.angle for line in net] == [30.0, 120.0]
+    assert math.hypot(*net[1].offset) == pytest.approx(1.0)
+    assert ezdwg.fill_hatch(_hatch(SQUARE, pattern_name="UNKNOWN")).lines == []
+
+
+def test_solid_fill_orients_islands() -> None:
+    fill = ezdwg.fill_hatch(_hatch(list(reversed(SQUARE)), HOLE, solid_fill=True))
+
+    outer, island = fill.polygons
+    assert fill.lines == []
+    assert outer[:2] == [(10.0, 0.0), (10.0, 10.0)]  # reversed to counter-clockwise
+    assert island[:2] == [(4.0, 6.0), (6.0, 6.0)]  # reversed to clockwise
+
+
+def test_renderers_draw_hatch_pattern_lines() -> None:
+    hatch = _hatch(SQUARE, pattern={"scale": 16.0}, pattern_name="ANSI31")
+
+    collector = render._StrokeCollector((255, 255, 255))
+    render._draw_hatch_fill(collector, hatch.dxf, 1.0, color="#ff0000")
+    assert len(collector.strokes) == 7
+    assert collector.strokes[0][1] == (255, 0, 0)
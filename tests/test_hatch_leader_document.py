@@ -1,9 +1,21 @@
 from __future__ import annotations
 
+import math
+from pathlib import Path
+
+import pytest
+
 import ezdwg.document as document_module
 import ezdwg.raw as raw_module
 
 
+ROOT = Path(__file__).resolve().parents[1]
+MLEADER_SAMPLES = [
+    ROOT / "test_dwg" / "acadsharp" / "sample_AC1027.dwg",
+    ROOT / "test_dwg" / "acadsharp" / "sample_AC1032.dwg",
+]
+
+
 def _patch_empty_color_maps(monkeypatch) -> None:
     monkeypatch.setattr(document_module.raw, "decode_entity_styles", lambda _path: [])
     monkeypatch.setattr(document_module.raw, "decode_layer_colors", lambda _path: [])
@@ -33,6 +45,63 @@ def test_query_leader_maps_points(monkeypatch) -> None:
     assert entity.dxf["points"][-1] == (12.0, 3.0, 0.0)
 
 
+def test_query_multileader_maps_leaders_and_text(monkeypatch) -> None:
+    _patch_empty_color_maps(monkeypatch)
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_mleader_entities",
+        lambda _path: [
+            (
+                0x910,
+                [((10.0, 5.0, 0.0), (1.0, 0.0, 0.0), 2.0, [[(0.0, 0.0, 0.0), (4.0, 5.0, 0.0)]])],
+                "Note",
+                (12.5, 5.5, 0.0),
+                (1.0, 0.0, 0.0),
+                0.5,
+                3.0,
+                2,
+            )
+        ],
+    )
+
+    doc = document_module.Document(path="dummy_mleader.dwg", version="AC1027")
+    entities = list(doc.modelspace().query("MULTILEADER"))
+
+    assert len(entities) == 1
+    entity = entities[0]
+    assert entity.handle == 0x910
+    assert entity.dxf["leaders"] == [
+        {
+            "connection": (10.0, 5.0, 0.0),
+            "direction": (1.0, 0.0, 0.0),
+            "landing_distance": 2.0,
+            "lines": [[(0.0, 0.0, 0.0), (4.0, 5.0, 0.0)]],
+        }
+    ]
+    assert entity.dxf["text"] == "Note"
+    assert entity.dxf["text_location"] == (12.5, 5.5, 0.0)
+    assert entity.dxf["text_height"] == 0.5
+    assert entity.dxf["text_width"] == 3.0
+    assert entity.dxf["text_alignment"] == 2
+
+
+@pytest.mark.parametrize("path", MLEADER_SAMPLES, ids=lambda path: path.name)
+def test_decode_mleader_entities_reads_sample_multileaders(path: Path) -> None:
+    rows = raw_module.decode_mleader_entities(str(path))
+
+    assert len(rows) == 15
+    handle, roots, text, location, direction, height, width, alignment = rows[1]
+    assert (handle, text) == (2072, "MULTILEADER TEST")
+    assert len(roots) == 1
+    connection, landing_direction, landing_distance, lines = roots[0]
+    assert connection == (168.99934202472878, -434.376343379853, 0.0)
+    assert landing_direction == (1.0, 0.0, 0.0)
+    assert landing_distance == 8.0
+    assert lines == [[(78.99934202472878, -524.376343379853, 0.0)]]
+    assert location == (178.99934202472878, -432.3422369678475, 0.0)
+    assert (direction, height, width, alignment) == ((1.0, 0.0, 0.0), 4.0, 0.0, 1)
+
+
 def test_query_hatch_maps_paths(monkeypatch) -> None:
     _patch_empty_color_maps(monkeypatch)
     monkeypatch.setattr(
@@ -50,6 +119,7 @@ def test_query_hatch_maps_paths(monkeypatch) -> None:
                     (True, [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0)]),
                     (False, [(1.0, 1.0), (2.0, 1.0)]),
                 ],
+                (0, 1, 0.0, 1.0, False, [(math.pi / 4.0, (0.0, 0.0), (-0.088, 0.088), [])]),
             )
         ],
     )
@@ -68,3 +138,6 @@ def test_query_hatch_maps_paths(monkeypatch) -> None:
     assert first_path["closed"] is True
     assert first_path["points"][0] == first_path["points"][-1]
     assert first_path["points"][0][2] == 2.5
+    assert entity.dxf["pattern"]["lines"] == [
+        {"angle": 45.0, "origin": (0.0, 0.0), "offset": (-0.088, 0.088), "dashes": []}
+    ]
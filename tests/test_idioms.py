@@ -0,0 +1,102 @@
+from __future__ import annotations
+
+import pytest
+
+import ezdwg
+from ezdwg import Entity
+
+
+def _line(handle: int, start, end) -> Entity:
+    return Entity("LINE", handle, {"start": (*start, 0.0), "end": (*end, 0.0)})
+
+
+def _fill(dxftype: str, handle: int, *corners) -> Entity:
+    """A SOLID or TRACE around ``corners`` in outline order; a triangle
+    repeats its last corner."""
+    p0, p1, p2, p3 = [(*corner, 0.0) for corner in corners]
+    # SOLID and TRACE store the third and fourth corners swapped.
+    return Entity(dxftype, handle, {"points": [p0, p1, p3, p2]})
+
+
+def test_find_arrows_reads_heads_and_shafts() -> None:
+    entities = [
+        # A head pointing along +X with a LINE shaft ending at its base.
+        _fill("SOLID", 1, (10, -0.5), (13, 0), (10, 0.5), (10, 0.5)),
+        _line(2, (0, 0), (10, 0)),
+        # A head pointing along -Y with a TRACE shaft running into its tip.
+        _fill("SOLID", 3, (19.5, 3), (20.5, 3), (20, 0), (20, 0)),
+        _fill("TRACE", 4, (19.9, 8), (20.1, 8), (20.1, 0), (19.9, 0)),
+        # Too blunt to be an arrow.
+        _fill("SOLID", 5, (30, 0), (32, 0), (31, 1.5), (31, 1.5)),
+        # Does not touch the second head.
+        _line(6, (20, 10), (20, 20)),
+    ]
+
+    arrows = ezdwg.find_arrows(entities, tolerance=1e-6)
+
+    assert [arrow.handle for arrow in arrows] == [1, 3]
+    first, second = arrows
+    assert (first.tip, first.base, first.direction) == ((13.0, 0.0), (10.0, 0.0), (1.0, 0.0))
+    assert (first.length, first.width) == (3.0, 1.0)
+    assert (first.shaft_handle, first.tail, first.vector) == (2, (0.0, 0.0), (13.0, 0.0))
+    assert second.direction == (0.0, -1.0)
+    assert second.shaft_handle == 4
+    assert second.tail == pytest.approx((20.0, 8.0))
+
+
+def test_wall_centerlines_pairs_parallel_faces() -> None:
+    entities = [
+        # A 10 long wall 0.2 thick, with a door opening in the lower face.
+        _line(1, (0, 0.2), (10, 0.2)),
+        _line(2, (0, 0), (4, 0)),
+        _line(3, (5, 0), (10, 0)),
+        # The far face of the room, too far away to pair with it.
+        Entity(
+            "LWPOLYLINE",
+            4,
+            {"points": [(0.0, 4.0), (10.0, 4.0), (10.0, 4.3)], "bulges": [], "closed": False},
+        ),
+        _line(5, (10, 4.3), (0, 4.3)),
+        # A thick-line wall.
+        _fill("TRACE", 6, (20, -0.1), (20, 0.1), (25, 0.1), (25, -0.1)),
+    ]
+
+    walls = ezdwg.wall_centerlines(entities, min_thickness=0.1, max_thickness=0.5)
+
+    summary = sorted(
+        (
+            tuple(round(value, 6) for value in (*wall.start, *wall.end)),
+            round(wall.thickness, 6),
+            tuple(sorted(wall.handles)),
+        )
+        for wall in walls
+    )
+    assert summary == [
+        ((0.0, 0.1, 4.0, 0.1), 0.2, (1, 2)),
+        ((0.0, 4.15, 10.0, 4.15), 0.3, (4, 5)),
+        ((5.0, 0.1, 10.0, 0.1), 0.2, (1, 3)),
+        ((20.0, 0.0, 25.0, 0.0), 0.2, (6, 6)),
+    ]
+    assert sum(wall.length for wall in walls) == pytest.approx(24.0)
+
+    with pytest.raises(ValueError, match="thickness"):
+        ezdwg.wall_centerlines(entities, min_thickness=1.0, max_thickness=0.5)
+
+
+def test_solid_outlines_merges_adjacent_fills() -> None:
+    entities = [
+        # An L shape filled as three squares, one of them two triangles.
+        _fill("SOLID", 1, (0, 0), (1, 0), (1, 1), (0, 1)),
+        _fill("SOLID", 2, (1, 0), (2, 0), (2, 1), (2, 1)),
+        _fill("SOLID", 3, (1, 0), (2, 1), (1, 1), (1, 1)),
+        _fill("TRACE", 4, (0, 1), (1, 1), (1, 2), (0, 2)),
+        # A separate square.
+        _fill("SOLID", 5, (5, 5), (6, 5), (6, 6), (5, 6)),
+    ]
+
+    loops = ezdwg.solid_outlines(entities)
+
+    assert [loop.area for loop in loops] == pytest.approx([3.0, 1.0])
+    assert loops[0].perimeter == pytest.approx(8.0)
+    assert len(loops[0].points) == 8
+    assert sorted(set(loops[0].handles)) == [1, 2, 3, 4]
@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.cli as cli_module
+from ezdwg.layer_map import MappedLayer
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/line_2000.dwg"
+
+
+def _layers(path: Path) -> dict[str, int]:
+    names = dict(ezdwg.raw.decode_layer_names(str(path)))
+    return {names[handle]: index for handle, index, _ in ezdwg.raw.decode_layer_colors(str(path))}
+
+
+def test_layer_map_resolves_first_matching_rule() -> None:
+    layer_map = ezdwg.LayerMap(
+        [
+            ezdwg.LayerRule(r"A-(?P<part>\w+)-OLD", target=r"A-\g<part>", regex=True),
+            ezdwg.LayerRule(r"A-.*", target="ARCH", color=2, regex=True),
+            ezdwg.LayerRule("defpoints", color=8, linetype="CONTINUOUS"),
+        ]
+    )
+
+    assert layer_map.resolve("a-wall-old") == MappedLayer("A-wall", None, None)
+    assert layer_map.resolve("A-DOOR") == MappedLayer("ARCH", 2, None)
+    assert layer_map.resolve("Defpoints") == MappedLayer("Defpoints", 8, "CONTINUOUS")
+    assert layer_map.resolve("S-COLS") is None
+
+    with pytest.raises(ValueError, match="1..255"):
+        ezdwg.LayerMap([ezdwg.LayerRule("0", color=256)])
+    with pytest.raises(ValueError, match="invalid layer linetype"):
+        ezdwg.LayerMap([ezdwg.LayerRule("0", linetype="ByLayer")])
+
+
+def test_layer_map_reads_tables_and_csv(tmp_path: Path) -> None:
+    table = ezdwg.LayerMap.from_table({"old": "new", "dim.*": {"target": "DIMS", "color": 3}})
+    assert table.resolve("OLD") == MappedLayer("new", None, None)
+    assert table.resolve("dim1") is None
+    assert ezdwg.LayerMap.from_table({"dim.*": "DIMS"}, regex=True).resolve("dim1").name == "DIMS"
+
+    csv_path = tmp_path / "layers.csv"
+    csv_path.write_text(
+        "source,target,color,linetype,regex\n"
+        "E-.*,ELEC,4,,true\n"
+        "Walls,,1,Continuous,\n",
+        encoding="utf-8",
+    )
+    from_csv = ezdwg.LayerMap.from_csv(str(csv_path))
+    assert from_csv.resolve("E-LIGHTING") == MappedLayer("ELEC", 4, None)
+    assert from_csv.resolve("walls") == MappedLayer("walls", 1, "Continuous")
+
+
+def test_to_dwg_applies_layer_map(tmp_path: Path) -> None:
+    source = str(SAMPLE)
+    line = next(ezdwg.read(source).modelspace().query("LINE"))
+    staged = tmp_path / "staged.dwg"
+    ezdwg.move_entities_to_layer(source, str(staged), [line.handle], "E-Power")
+    ezdwg.set_layer_color(str(staged), str(staged), "E-Lighting", 5)
+
+    output = tmp_path / "mapped.dwg"
+    layer_map = ezdwg.LayerMap(
+        [ezdwg.LayerRule(r"E-.*", target="ELEC", color=4, linetype="Continuous", regex=True)]
+    )
+    ezdwg.to_dwg(str(staged), str(output), layer_map=layer_map)
+
+    assert _layers(output) == {"0": 7, "ELEC": 4}
+    names = dict(ezdwg.raw.decode_layer_names(str(output)))
+    written = next(ezdwg.read(str(output)).modelspace().query("LINE"))
+    assert names[written.dxf["layer_handle"]] == "ELEC"
+
+    with pytest.raises(ValueError, match="linetype not found"):
+        ezdwg.to_dwg(
+            str(staged),
+            str(tmp_path / "bad.dwg"),
+            layer_map=ezdwg.LayerMap([ezdwg.LayerRule("E-Power", linetype="HIDDEN")]),
+        )
+
+
+def test_to_dxf_applies_layer_map(tmp_path: Path) -> None:
+    ezdxf = pytest.importorskip("ezdxf")
+    staged = tmp_path / "staged.dwg"
+    line = next(ezdwg.read(str(SAMPLE)).modelspace().query("LINE"))
+    ezdwg.move_entities_to_layer(str(SAMPLE), str(staged), [line.handle], "Walls")
+
+    output = tmp_path / "mapped.dxf"
+    layer_map = ezdwg.LayerMap([ezdwg.LayerRule("walls", target="A-WALL", color=1)])
+    ezdwg.to_dxf(str(staged), str(output), layer_map=layer_map)
+
+    doc = ezdxf.readfile(str(output))
+    assert doc.layers.get("A-WALL").dxf.color == 1
+    assert [entity.dxf.layer for entity in doc.modelspace().query("LINE")] == ["A-WALL"]
+
+
+def test_cli_write_reads_layer_map_csv(tmp_path: Path, capsys) -> None:
+    csv_path = tmp_path / "layers.csv"
+    csv_path.write_text("source,target,color\n0,,3\n", encoding="utf-8")
+    output = tmp_path / "out.dwg"
+
+    code = cli_module.main(["write", str(SAMPLE), str(output), "--layer-map", str(csv_path)])
+
+    assert code == 0
+    assert "written_entities: 1" in capsys.readouterr().out.splitlines()
+    assert _layers(output) == {"0": 3}
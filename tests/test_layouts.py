@@ -0,0 +1,55 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+
+
+@pytest.mark.parametrize(
+    "name",
+    [
+        "line_R14.dwg",
+        "line_2000.dwg",
+        "line_2004.dwg",
+        "line_2007.dwg",
+        "line_2010.dwg",
+        "line_2013.dwg",
+    ],
+)
+def test_decode_layouts_reads_names_and_tab_order(name: str) -> None:
+    rows = raw.decode_layouts(str(ROOT / "test_dwg" / name))
+
+    assert sorted((tab_order, layout_name) for _h, layout_name, tab_order, *_rest in rows) == [
+        (0, "Model"),
+        (1, "Layout1"),
+        (2, "Layout2"),
+    ]
+    printers = {row[6][0][1] for row in rows}
+    assert printers == {"PDFill PDF&Image Writer"}
+
+
+def test_document_layouts_expose_plot_settings() -> None:
+    layouts = ezdwg.read(str(SAMPLE)).layouts()
+
+    assert [layout.name for layout in layouts] == ["Model", "Layout1", "Layout2", "MyLayout"]
+    assert layouts[0].is_modelspace
+    settings = layouts[3].plot_settings
+    assert settings.paper_size == "A4"
+    assert settings.printer_name == "MON-MUR-09-PRINT (HP LaserJet Pro M148fdw)"
+    assert (settings.paper_width, settings.paper_height) == (210.0, 297.0)
+    assert settings.rotation == 1
+    assert settings.page_size == (297.0, 210.0)
+    assert settings.orientation == "landscape"
+
+    letter = layouts[1].plot_settings
+    assert letter.paper_size == "Letter_(8.50_x_11.00_Inches)"
+    assert letter.plot_type == 5
+    assert letter.scale_type == 16
+    assert letter.margins[0] == pytest.approx(6.35)
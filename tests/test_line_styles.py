@@ -0,0 +1,158 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.convert as convert_module
+import ezdwg.render as render_module
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+ISO_DASH_HANDLE = 640
+
+
+def test_decode_linetypes_reads_dash_elements() -> None:
+    rows = {row[0]: row for row in raw.decode_linetypes(str(SAMPLE))}
+
+    _handle, _name, _description, pattern_length, dashes = rows[ISO_DASH_HANDLE]
+    assert pattern_length == pytest.approx(15.0)
+    assert [length for length, _shape, _flag in dashes] == [12.0, -3.0]
+
+
+def test_decode_linetypes_reads_names_before_ac1021() -> None:
+    rows = raw.decode_linetypes(str(ROOT / "test_dwg/line_2004.dwg"))
+
+    assert [name for _handle, name, _description, _length, _dashes in rows] == [
+        "ByBlock",
+        "ByLayer",
+        "Continuous",
+    ]
+
+
+def test_decode_entity_line_styles_resolves_explicit_linetype() -> None:
+    rows = raw.decode_entity_line_styles(str(SAMPLE))
+
+    explicit = [row for row in rows if row[1] == 3 and row[2] is not None]
+    assert explicit
+    assert any(row[2] == ISO_DASH_HANDLE for row in explicit)
+    assert {lineweight for *_rest, lineweight in rows} >= {-1, 13}
+
+
+def test_decode_styled_entities_pairs_geometry_with_resolved_style() -> None:
+    path = str(SAMPLE)
+    lines, arcs, circles, points, lwpolylines = raw.decode_styled_entities(path)
+
+    assert [geometry for geometry, _style in lines] == raw.decode_line_entities(path)
+    assert [geometry for geometry, _style in arcs] == raw.decode_arc_entities(path)
+    assert [geometry for geometry, _style in circles] == raw.decode_circle_entities(path)
+    assert [geometry for geometry, _style in points] == raw.decode_point_entities(path)
+    assert [geometry for geometry, _style in lwpolylines] == raw.decode_lwpolyline_entities(path)
+
+    layer_names = dict(raw.decode_layer_names(path))
+    linetype_names = {row[0]: row[1] for row in raw.decode_linetypes(path)}
+    layer_handles = {row[0]: row[3] for row in raw.decode_entity_styles(path)}
+    line_styles = {row[0]: row for row in raw.decode_entity_line_styles(path)}
+    colors = {row[0]: row for row in raw.decode_entity_colors(path)}
+    layer_rgb = {
+        handle: raw.aci_to_rgb(abs(index))
+        for handle, index, true_color in raw.decode_layer_colors(path)
+        if true_color is None
+    }
+    styled = lines + arcs + circles + points + lwpolylines
+    assert styled
+    for geometry, (layer, rgb, linetype, lineweight) in styled:
+        handle = geometry[0]
+        if handle in layer_handles:
+            assert layer == layer_names.get(layer_handles[handle])
+        if handle in line_styles:
+            _handle, flags, ltype_handle, _scale, expected_weight = line_styles[handle]
+            assert lineweight == expected_weight
+            if flags == 3:
+                assert linetype == linetype_names.get(ltype_handle)
+            else:
+                assert linetype == ("ByLayer", "ByBlock", "Continuous")[flags]
+        if handle not in colors:
+            continue
+        _handle, kind, _aci, color_rgb, layer_handle = colors[handle]
+        if kind == "bylayer" and layer_rgb.get(layer_handle) is not None:
+            red, green, blue = layer_rgb[layer_handle]
+            assert rgb == (red << 16) | (green << 8) | blue
+        elif kind != "bylayer":
+            assert rgb == color_rgb
+    assert any(line_styles.get(geometry[0], (0, 0))[1] == 3 for geometry, _style in styled)
+    assert any(lineweight == 13 for _geometry, (_l, _r, _t, lineweight) in styled)
+
+    limited = raw.decode_styled_entities(path, limit=3)
+    assert sum(len(rows) for rows in limited) == 3
+
+
+def test_query_attaches_lineweight_and_linetype_pattern() -> None:
+    doc = ezdwg.read(str(SAMPLE))
+    entities = list(doc.modelspace().query())
+
+    assert any(entity.dxf.get("lineweight") == 13 for entity in entities)
+    patterns = [
+        entity.dxf["linetype_pattern"]
+        for entity in entities
+        if entity.dxf.get("ltype_handle") == ISO_DASH_HANDLE
+    ]
+    assert patterns
+    assert patterns[0] == (12.0, -3.0)
+
+
+def test_resolve_dwg_line_width_maps_lineweight_to_points() -> None:
+    assert render_module._resolve_dwg_line_width({}, 1.0) == 1.0
+    assert render_module._resolve_dwg_line_width({"lineweight": -1}, 1.5) == 1.5
+    assert render_module._resolve_dwg_line_width({"lineweight": 254}, 1.0) == pytest.approx(7.2)
+    assert render_module._resolve_dwg_line_width({"lineweight": 0}, 1.0) == pytest.approx(0.1)
+
+
+def test_resolve_dwg_dashes_applies_linetype_scales() -> None:
+    dxf = {"linetype_pattern": (12.0, -3.0)}
+    assert render_module._resolve_dwg_dashes(dxf, 1.0, 1.0) == [12.0, 3.0]
+    assert render_module._resolve_dwg_dashes(dxf, 2.0, 0.5) == [12.0, 3.0]
+    assert render_module._resolve_dwg_dashes({**dxf, "ltype_scale": 0.5}, 2.0, 4.0) == [
+        12.0,
+        3.0,
+    ]
+
+    # Leading gaps rotate to the end, and dots stay as zero-length dashes.
+    dotted = {"linetype_pattern": (-0.25, 0.0, -0.25, 0.5)}
+    assert render_module._resolve_dwg_dashes(dotted, 1.0, 1.0) == [0.0, 0.25, 0.5, 0.25]
+    assert render_module._resolve_dwg_dashes({"linetype_pattern": (1.0,)}, 1.0, 1.0) is None
+
+
+def test_entity_dxfattribs_carries_lineweight_and_ltscale() -> None:
+    attribs = convert_module._entity_dxfattribs({"lineweight": 13, "ltype_scale": 2.0})
+    assert attribs["lineweight"] == 13
+    assert attribs["ltscale"] == 2.0
+
+    attribs = convert_module._entity_dxfattribs({"lineweight": -1, "ltype_scale": 1.0})
+    assert "lineweight" not in attribs
+    assert "ltscale" not in attribs
+
+
+def test_plot_defaults_linetype_scales_to_the_header(monkeypatch) -> None:
+    assert raw.decode_linetype_scales(str(ROOT / "test_dwg/line_2000.dwg")) == (1.0, 1.0)
+    # R2018 headers are not decoded, so plotting falls back to 1.0.
+    with pytest.raises(Exception):
+        raw.decode_linetype_scales(str(SAMPLE))
+    layout = ezdwg.read(str(SAMPLE)).modelspace()
+    assert render_module._header_linetype_scales(layout) == (1.0, 1.0)
+
+    pytest.importorskip("matplotlib")
+    monkeypatch.setattr(raw, "decode_linetype_scales", lambda _path: (10.0, 0.5))
+    drawn = []
+    monkeypatch.setattr(
+        render_module, "_draw_layout", lambda *_args, **kwargs: drawn.append(kwargs) or []
+    )
+    render_module.plot_layout(layout, show=False)
+    render_module.plot_layout(layout, show=False, ltscale=2.0)
+    assert [(kwargs["ltscale"], kwargs["celtscale"]) for kwargs in drawn] == [
+        (10.0, 0.5),
+        (2.0, 0.5),
+    ]
@@ -0,0 +1,402 @@
+from __future__ import annotations
+
+import json
+import math
+import struct
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.document as document_module
+import ezdwg.mesh_export as mesh_export_module
+from ezdwg import raw
+from ezdwg.document import ViewInfo
+from ezdwg.entity import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+MESH_SAMPLES = [
+    ROOT / "test_dwg/acadsharp/sample_AC1027.dwg",
+    ROOT / "test_dwg/acadsharp/sample_AC1032.dwg",
+]
+
+
+class _FakeDoc:
+    path = "dummy_mesh_export.dwg"
+    decode_path = "dummy_mesh_export.dwg"
+
+
+class _FakeLayout:
+    doc = _FakeDoc()
+
+    def __init__(self, entities: list[Entity]) -> None:
+        self._entities = entities
+
+    def query(self, types: str):
+        selected = set(types.split())
+        return [entity for entity in self._entities if entity.dxftype in selected]
+
+
+def _fake_layout(monkeypatch) -> _FakeLayout:
+    monkeypatch.setattr(
+        mesh_export_module,
+        "_resolve_layout",
+        lambda layout: (layout.doc.path, layout),
+    )
+    monkeypatch.setattr(
+        mesh_export_module,
+        "_layer_names_by_handle",
+        lambda _path: {0x10: "Walls", 0x11: "Roof Tiles"},
+    )
+    return _FakeLayout(
+        [
+            Entity(
+                dxftype="3DFACE",
+                handle=0x100,
+                dxf={
+                    "points": [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (1.0, 1.0, 0.0)],
+                    "layer_handle": 0x10,
+                },
+            ),
+            Entity(
+                dxftype="POLYLINE_PFACE",
+                handle=0x101,
+                dxf={
+                    "vertices": [
+                        (0.0, 0.0, 1.0),
+                        (1.0, 0.0, 1.0),
+                        (1.0, 1.0, 1.0),
+                        (0.0, 1.0, 1.0),
+                    ],
+                    "faces": [(1, 2, 3, -4)],
+                    "layer_handle": 0x11,
+                },
+            ),
+            Entity(
+                dxftype="POLYLINE_MESH",
+                handle=0x102,
+                dxf={
+                    "points": [
+                        (0.0, 0.0, 2.0),
+                        (1.0, 0.0, 2.0),
+                        (2.0, 0.0, 2.0),
+                        (0.0, 1.0, 2.0),
+                        (1.0, 1.0, 2.0),
+                        (2.0, 1.0, 2.0),
+                    ],
+                    "m_vertex_count": 2,
+                    "n_vertex_count": 3,
+                    "flags": 0x10,
+                    "layer_handle": 0x10,
+                },
+            ),
+            Entity(
+                dxftype="MESH",
+                handle=0x103,
+                dxf={
+                    "vertices": [(0.0, 0.0, 3.0), (1.0, 0.0, 3.0), (0.0, 1.0, 3.0)],
+                    "faces": [[0, 1, 2], [0, 1, 7]],
+                    "layer_handle": None,
+                },
+            ),
+        ]
+    )
+
+
+def test_to_obj_groups_faces_by_layer(tmp_path: Path, monkeypatch) -> None:
+    layout = _fake_layout(monkeypatch)
+    output = tmp_path / "mesh.obj"
+
+    result = ezdwg.to_obj(layout, str(output))
+
+    assert result.total_entities == 4
+    assert result.written_entities == 4
+    lines = output.read_text().splitlines()
+    groups = [line for line in lines if line.startswith("g ")]
+    assert groups == ["g Walls", "g Roof_Tiles", "g 0"]
+    faces = [line for line in lines if line.startswith("f ")]
+    # 3DFACE triangle, two mesh quads, the pface quad and the MESH triangle;
+    # the MESH face with an out-of-range index is dropped.
+    assert faces == ["f 1 2 3", "f 4 5 8 7", "f 5 6 9 8", "f 10 11 12 13", "f 14 15 16"]
+
+
+def test_to_stl_writes_triangles(tmp_path: Path, monkeypatch) -> None:
+    layout = _fake_layout(monkeypatch)
+    binary = tmp_path / "mesh.stl"
+    ascii_output = tmp_path / "mesh_ascii.stl"
+
+    ezdwg.to_stl(layout, str(binary))
+    ezdwg.to_stl(layout, str(ascii_output), binary=False)
+
+    data = binary.read_bytes()
+    (count,) = struct.unpack_from("<I", data, 80)
+    assert count == 8
+    assert len(data) == 84 + 50 * count
+    normal = struct.unpack_from("<3f", data, 84)
+    assert normal == (0.0, 0.0, 1.0)
+
+    text = ascii_output.read_text()
+    assert text.count("facet normal") == 8
+    assert "solid Roof_Tiles" in text
+    assert "endsolid Walls" in text
+
+
+def test_to_gltf_writes_one_node_per_layer(tmp_path: Path, monkeypatch) -> None:
+    layout = _fake_layout(monkeypatch)
+    output = tmp_path / "mesh.gltf"
+
+    ezdwg.to_gltf(layout, str(output))
+
+    gltf = json.loads(output.read_text())
+    assert gltf["asset"]["version"] == "2.0"
+    assert [node["name"] for node in gltf["nodes"]] == ["Walls", "Roof Tiles", "0"]
+    index_counts = [
+        gltf["accessors"][mesh["primitives"][0]["indices"]]["count"] for mesh in gltf["meshes"]
+    ]
+    assert index_counts == [15, 6, 3]
+    roof = gltf["accessors"][gltf["meshes"][1]["primitives"][0]["attributes"]["POSITION"]]
+    # Z-up drawing coordinates become Y-up.
+    assert roof["min"] == [0.0, 1.0, -1.0]
+    assert roof["max"] == [1.0, 1.0, 0.0]
+
+
+def _view(name: str, direction, *, mode: int = 0, paper: bool = False) -> ViewInfo:
+    return ViewInfo(
+        handle=0x40,
+        name=name,
+        width=10.0,
+        height=5.0,
+        center=(0.0, 0.0),
+        target=(1.0, 2.0, 0.0),
+        direction=direction,
+        twist_angle=0.0,
+        lens_length=50.0,
+        front_clip=0.0,
+        back_clip=0.0,
+        view_mode=mode,
+        render_mode=0,
+        is_paper_space=paper,
+        is_camera_plottable=False,
+    )
+
+
+def _rotate(quat: list[float], vector: tuple[float, float, float]) -> tuple[float, ...]:
+    x, y, z, w = quat
+    vx, vy, vz = vector
+    tx, ty, tz = 2 * (y * vz - z * vy), 2 * (z * vx - x * vz), 2 * (x * vy - y * vx)
+    return (
+        vx + w * tx + y * tz - z * ty,
+        vy + w * ty + z * tx - x * tz,
+        vz + w * tz + x * ty - y * tx,
+    )
+
+
+def test_to_gltf_exports_named_views_as_cameras(tmp_path: Path, monkeypatch) -> None:
+    layout = _fake_layout(monkeypatch)
+    views = [
+        _view("Top", (0.0, 0.0, 1.0)),
+        _view("SE Iso", (100.0, -100.0, 100.0), mode=1),
+        _view("Sheet", (0.0, 0.0, 1.0), paper=True),
+    ]
+    layout.doc = type("_ViewDoc", (_FakeDoc,), {"views": lambda _self: views})()
+    output = tmp_path / "cameras.gltf"
+
+    ezdwg.to_gltf(layout, str(output))
+    assert "cameras" not in json.loads(output.read_text())
+    ezdwg.to_gltf(layout, str(output), cameras=True)
+
+    gltf = json.loads(output.read_text())
+    assert [camera["name"] for camera in gltf["cameras"]] == ["Top", "SE Iso"]
+    nodes = {node["name"]: node for node in gltf["nodes"] if "camera" in node}
+    assert set(nodes) <= {gltf["nodes"][index]["name"] for index in gltf["scenes"][0]["nodes"]}
+
+    top = gltf["cameras"][nodes["Top"]["camera"]]
+    assert top["type"] == "orthographic"
+    assert (top["orthographic"]["xmag"], top["orthographic"]["ymag"]) == (5.0, 2.5)
+    # Looking straight down: a -90 degree turn about glTF's X axis.
+    assert nodes["Top"]["rotation"] == pytest.approx([-math.sqrt(0.5), 0.0, 0.0, math.sqrt(0.5)])
+    x, y, z = nodes["Top"]["translation"]
+    assert (x, z) == (1.0, -2.0) and y > 3.0
+
+    iso = gltf["cameras"][nodes["SE Iso"]["camera"]]
+    assert iso["type"] == "perspective"
+    assert iso["perspective"]["aspectRatio"] == 2.0
+    assert 0.0 < iso["perspective"]["yfov"] < math.radians(40.0)
+    node = nodes["SE Iso"]
+    assert node["translation"] == pytest.approx([101.0, 100.0, 98.0])
+    length = math.sqrt(3.0)
+    forward = _rotate(node["rotation"], (0.0, 0.0, -1.0))
+    assert forward == pytest.approx((-1 / length, -1 / length, -1 / length))
+    # The view's up direction keeps a positive world Z (glTF Y) component.
+    assert _rotate(node["rotation"], (0.0, 1.0, 0.0))[1] > 0.0
+
+
+def test_mesh_export_rejects_non_mesh_types(tmp_path: Path, monkeypatch) -> None:
+    layout = _fake_layout(monkeypatch)
+    with pytest.raises(ValueError, match="unsupported mesh export type: TEXT"):
+        ezdwg.to_obj(layout, str(tmp_path / "mesh.obj"), types="TEXT")
+
+
+def test_thickness_mesh_sweeps_entities_along_extrusion() -> None:
+    line = Entity("LINE", 0x200, {"start": (0.0, 0.0, 1.0), "end": (3.0, 0.0, 1.0)})
+    assert ezdwg.thickness_mesh(line) == ([], [])
+
+    points, faces = ezdwg.thickness_mesh(line, 2.0)
+    assert points == [(0.0, 0.0, 1.0), (3.0, 0.0, 1.0), (0.0, 0.0, 3.0), (3.0, 0.0, 3.0)]
+    assert faces == [(0, 1, 3, 2)]
+
+    # OCS X of a -Z extrusion is world -X.
+    circle = Entity("CIRCLE", 0x201, {"center": (5.0, 0.0, 0.0), "radius": 1.0})
+    points, faces = ezdwg.thickness_mesh(circle, 1.0, (0.0, 0.0, -1.0), segments=4)
+    assert points[0] == pytest.approx((-6.0, 0.0, 0.0))
+    assert points[4] == pytest.approx((-6.0, 0.0, -1.0))
+    assert len(faces) == 4 + 2
+
+    solid = Entity(
+        "SOLID",
+        0x202,
+        {
+            "points": [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (1.0, 1.0, 0.0)],
+            "thickness": 0.5,
+            "extrusion": (0.0, 0.0, 1.0),
+        },
+    )
+    points, faces = ezdwg.thickness_mesh(solid)
+    assert points[:4] == [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)]
+    assert faces[-2:] == [(3, 2, 1, 0), (4, 5, 6, 7)]
+
+    polyline = Entity(
+        "LWPOLYLINE",
+        0x203,
+        {"points": [(0.0, 0.0), (2.0, 0.0)], "bulges": [1.0, 0.0], "closed": True},
+    )
+    points, faces = ezdwg.thickness_mesh(polyline, 1.0, elevation=4.0, segments=8)
+    assert {point[2] for point in points} == {4.0, 5.0}
+    # The bulged half circle and the straight closing segment, no caps.
+    assert len(faces) == len(points) // 2
+
+
+def test_mesh_export_writes_entities_with_thickness(tmp_path: Path, monkeypatch) -> None:
+    layout = _fake_layout(monkeypatch)
+    layout._entities = [
+        Entity("LINE", 0x200, {"start": (0.0, 0.0, 0.0), "end": (1.0, 0.0, 0.0)}),
+        Entity("LINE", 0x201, {"start": (0.0, 1.0, 0.0), "end": (1.0, 1.0, 0.0)}),
+        Entity("CIRCLE", 0x202, {"center": (0.0, 0.0, 0.0), "radius": 1.0}),
+    ]
+    monkeypatch.setattr(
+        mesh_export_module.raw,
+        "decode_entity_thickness",
+        lambda _path: [(0x200, 2.0, 0.0, (0.0, 0.0, 1.0))],
+    )
+    output = tmp_path / "thick.obj"
+
+    result = ezdwg.to_obj(layout, str(output))
+
+    # Entities without thickness are left out of the counts.
+    assert result.total_entities == result.written_entities == 1
+    faces = [line for line in output.read_text().splitlines() if line.startswith("f ")]
+    assert faces == ["f 1 2 4 3"]
+
+
+def test_query_mesh_maps_vertices_and_faces(monkeypatch) -> None:
+    monkeypatch.setattr(document_module.raw, "decode_entity_styles", lambda _path: [])
+    monkeypatch.setattr(document_module.raw, "decode_layer_colors", lambda _path: [])
+    document_module._entity_style_map.cache_clear()
+    document_module._layer_color_map.cache_clear()
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_mesh_entities",
+        lambda _path: [
+            (
+                0xC01,
+                0,
+                [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+                [[0, 1, 2]],
+                [(0, 1), (1, 2), (2, 0)],
+                [0.0, 0.0, 0.0],
+            )
+        ],
+    )
+
+    doc = document_module.Document(path="dummy_mesh.dwg", version="AC1027")
+    entities = list(doc.modelspace().query("MESH"))
+
+    assert len(entities) == 1
+    assert entities[0].dxf["faces"] == [[0, 1, 2]]
+    assert entities[0].dxf["edges"] == [(0, 1), (1, 2), (2, 0)]
+    assert entities[0].dxf["subdivision_level"] == 0
+
+
+@pytest.mark.parametrize("path", MESH_SAMPLES, ids=lambda path: path.name)
+def test_decode_mesh_entities_reads_sample_meshes(path: Path) -> None:
+    rows = raw.decode_mesh_entities(str(path))
+
+    assert [(row[0], len(row[2]), len(row[3]), len(row[4])) for row in rows] == [
+        (835, 62, 72, 132),
+        (896, 64, 64, 128),
+    ]
+    for _, _, vertices, faces, _, _ in rows:
+        assert all(0 <= index < len(vertices) for face in faces for index in face)
+
+
+def test_to_obj_exports_sample_meshes(tmp_path: Path) -> None:
+    output = tmp_path / "sample.obj"
+
+    result = ezdwg.to_obj(str(MESH_SAMPLES[1]), str(output))
+
+    assert result.skipped_entities == 0
+    faces = [line for line in output.read_text().splitlines() if line.startswith("f ")]
+    # Two MESH entities, one polyface face and one 3DFACE.
+    assert len(faces) == 72 + 64 + 1 + 1
+
+
+def _unit_triangle(handle: int, acis_format: str, data: bytes):
+    assert acis_format in {"sat", "sab"}
+    assert data
+    return ([(0.0, 0.0, float(handle)), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)], [(0, 1, 2)])
+
+
+def test_tessellate_solids_feeds_acis_data_to_callback() -> None:
+    seen: list[tuple[int, str]] = []
+
+    def tessellator(handle: int, acis_format: str, data: bytes):
+        seen.append((handle, acis_format))
+        return None if handle == 3433 else _unit_triangle(handle, acis_format, data)
+
+    rows = raw.tessellate_solids(str(MESH_SAMPLES[1]), tessellator)
+
+    assert sorted(seen) == [(3429, "sab"), (3433, "sab"), (3434, "sab")]
+    assert sorted(row[0] for row in rows) == [3429, 3434]
+    assert all(row[2] == [(0, 1, 2)] for row in rows)
+
+
+def test_tessellate_solids_propagates_callback_errors() -> None:
+    def tessellator(_handle: int, _acis_format: str, _data: bytes):
+        raise KeyError("modeler unavailable")
+
+    with pytest.raises(KeyError, match="modeler unavailable"):
+        raw.tessellate_solids(str(MESH_SAMPLES[1]), tessellator)
+
+
+def test_tessellate_solids_rejects_out_of_range_triangles() -> None:
+    with pytest.raises(ValueError, match="out of range"):
+        raw.tessellate_solids(
+            str(MESH_SAMPLES[1]), lambda *_args: ([(0.0, 0.0, 0.0)], [(0, 1, 2)])
+        )
+
+
+def test_registered_tessellator_exports_solids(tmp_path: Path) -> None:
+    output = tmp_path / "solids.obj"
+    with pytest.raises(ValueError, match="register_solid_tessellator"):
+        ezdwg.to_obj(str(MESH_SAMPLES[1]), str(output), types="3DSOLID")
+
+    previous = ezdwg.register_solid_tessellator(_unit_triangle)
+    try:
+        result = ezdwg.to_obj(str(MESH_SAMPLES[1]), str(output), types="3DSOLID REGION BODY")
+    finally:
+        ezdwg.register_solid_tessellator(previous)
+
+    assert result.written_entities == result.total_entities == 3
+    faces = [line for line in output.read_text().splitlines() if line.startswith("f ")]
+    assert len(faces) == 3
@@ -0,0 +1,145 @@
+from __future__ import annotations
+
+import json
+import math
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.mesh_export as mesh_export_module
+from ezdwg.entity import Entity
+
+
+class _FakeDoc:
+    path = "dummy_mesh_topology.dwg"
+    decode_path = "dummy_mesh_topology.dwg"
+
+
+class _FakeLayout:
+    doc = _FakeDoc()
+
+    def __init__(self, entities: list[Entity]) -> None:
+        self._entities = entities
+
+    def query(self, types: str):
+        selected = set(types.split())
+        return [entity for entity in self._entities if entity.dxftype in selected]
+
+
+def _cube_quads(size: float = 1.0) -> tuple[list, list]:
+    """A cube as six separate outward-facing quads with 24 vertices."""
+    corners = [
+        (0.0, 0.0, 0.0),
+        (size, 0.0, 0.0),
+        (size, size, 0.0),
+        (0.0, size, 0.0),
+        (0.0, 0.0, size),
+        (size, 0.0, size),
+        (size, size, size),
+        (0.0, size, size),
+    ]
+    quads = [
+        (0, 3, 2, 1),
+        (4, 5, 6, 7),
+        (0, 1, 5, 4),
+        (1, 2, 6, 5),
+        (2, 3, 7, 6),
+        (3, 0, 4, 7),
+    ]
+    vertices = []
+    faces = []
+    for quad in quads:
+        faces.append(tuple(range(len(vertices), len(vertices) + 4)))
+        # Nudge the copies so welding has to rely on the tolerance.
+        vertices.extend(
+            tuple(value + 1e-9 * len(vertices) for value in corners[index]) for index in quad
+        )
+    return vertices, faces
+
+
+def test_weld_vertices_closes_a_cube_of_separate_quads() -> None:
+    vertices, faces = _cube_quads()
+
+    before = ezdwg.check_mesh(vertices, faces)
+    welded, welded_faces = ezdwg.weld_vertices(vertices, faces, tolerance=1e-6)
+    check = ezdwg.check_mesh(welded, welded_faces)
+
+    assert len(before.boundary_edges) == 24
+    assert not before.is_closed
+    assert len(welded) == 8
+    assert len(welded_faces) == 6
+    assert (check.is_manifold, check.is_closed, check.is_oriented) == (True, True, True)
+    assert (check.unused_vertices, check.degenerate_faces) == (0, 0)
+    # Exact matching keeps the nudged copies apart.
+    assert len(ezdwg.weld_vertices(vertices, faces, tolerance=0.0)[0]) == 24
+
+
+def test_weld_vertices_drops_collapsed_faces() -> None:
+    vertices = [(0.0, 0.0, 0.0), (1e-8, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)]
+
+    welded, faces = ezdwg.weld_vertices(vertices, [(0, 1, 2), (0, 2, 3), (1, 0, 2, 3)])
+
+    assert len(welded) == 3
+    assert faces == [(0, 1, 2), (0, 1, 2)]
+    with pytest.raises(ValueError):
+        ezdwg.weld_vertices(vertices, [], tolerance=-1.0)
+
+
+def test_normals_and_orientation_checks() -> None:
+    vertices, faces = ezdwg.weld_vertices(*_cube_quads())
+
+    normals = ezdwg.face_normals(vertices, faces)
+    assert normals[0] == pytest.approx((0.0, 0.0, -1.0), abs=1e-6)
+    assert normals[1] == pytest.approx((0.0, 0.0, 1.0), abs=1e-6)
+    corner = ezdwg.vertex_normals(vertices, faces)[0]
+    assert corner == pytest.approx((-1 / math.sqrt(3),) * 3)
+
+    flipped = [tuple(reversed(faces[0])), *faces[1:]]
+    check = ezdwg.check_mesh(vertices, flipped)
+    assert check.is_closed
+    assert not check.is_oriented
+    assert len(check.flipped_edges) == 4
+
+    fan = [(0, 1, 2), (0, 2, 3), (0, 2, 4)]
+    open_mesh = ezdwg.check_mesh([(0.0, 0.0, 0.0)] * 6, fan)
+    assert open_mesh.non_manifold_edges == [(0, 2)]
+    assert (open_mesh.unused_vertices, open_mesh.degenerate_faces) == (1, 3)
+
+
+def test_exporters_weld_and_write_normals(tmp_path: Path, monkeypatch) -> None:
+    vertices, faces = _cube_quads()
+    layout = _FakeLayout(
+        [
+            Entity(
+                dxftype="3DFACE",
+                handle=0x100 + index,
+                dxf={"points": [vertices[corner] for corner in face], "layer_handle": None},
+            )
+            for index, face in enumerate(faces)
+        ]
+    )
+    monkeypatch.setattr(
+        mesh_export_module, "_resolve_layout", lambda layout: (layout.doc.path, layout)
+    )
+    monkeypatch.setattr(mesh_export_module, "_layer_names_by_handle", lambda _path: {})
+    assert ezdwg.entity_mesh(layout.query("3DFACE")[0]) == (vertices[:4], [(0, 1, 2, 3)])
+
+    plain = tmp_path / "plain.obj"
+    welded = tmp_path / "welded.obj"
+    ezdwg.to_obj(layout, str(plain))
+    ezdwg.to_obj(layout, str(welded), weld_tolerance=1e-6, normals=True)
+
+    def count(path: Path, prefix: str) -> int:
+        return sum(line.startswith(prefix) for line in path.read_text().splitlines())
+
+    assert count(plain, "v ") == 24
+    assert (count(welded, "v "), count(welded, "vn "), count(welded, "f ")) == (8, 8, 6)
+    assert "f 1//1 2//2 3//3 4//4" in welded.read_text()
+
+    gltf_path = tmp_path / "cube.gltf"
+    ezdwg.to_gltf(layout, str(gltf_path), weld_tolerance=1e-6, normals=True)
+    gltf = json.loads(gltf_path.read_text())
+    attributes = gltf["meshes"][0]["primitives"][0]["attributes"]
+    assert gltf["accessors"][attributes["POSITION"]]["count"] == 8
+    assert gltf["accessors"][attributes["NORMAL"]]["count"] == 8
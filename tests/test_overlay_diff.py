@@ -0,0 +1,145 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+LINE_SAMPLE = ROOT / "test_dwg/line_2000.dwg"
+
+
+def _line(handle: int, start: tuple[float, float], end: tuple[float, float]) -> Entity:
+    return Entity(
+        dxftype="LINE",
+        handle=handle,
+        dxf={"start": (*start, 0.0), "end": (*end, 0.0), "owner_handle": 0x1F},
+    )
+
+
+def test_overlay_diff_classifies_and_draws_both_revisions(tmp_path: Path) -> None:
+    before = [
+        _line(0x10, (0.0, 0.0), (10.0, 0.0)),
+        _line(0x11, (0.0, 5.0), (10.0, 5.0)),
+        _line(0x12, (0.0, 10.0), (10.0, 10.0)),
+    ]
+    after = [
+        Entity(
+            "LINE",
+            0x10,
+            {**before[0].dxf, "end": (10.0 + 1e-12, 0.0, 0.0), "owner_handle": 0x20},
+        ),
+        _line(0x11, (0.0, 5.0), (10.0, 8.0)),
+        _line(0x13, (0.0, 20.0), (10.0, 20.0)),
+    ]
+    output = tmp_path / "overlay.svg"
+
+    result = ezdwg.overlay_diff(before, after, str(output), width=200)
+
+    assert (result.added, result.removed, result.modified, result.unchanged) == (
+        [0x13],
+        [0x12],
+        [0x11],
+        1,
+    )
+    assert result.changed
+    svg = output.read_text(encoding="utf-8")
+    assert svg.startswith('<svg xmlns="http://www.w3.org/2000/svg" width="200" height="')
+    groups = svg.split("<g ")[1:]
+    assert [group.split('"')[1] for group in groups] == ["unchanged", "revision-a", "revision-b"]
+    assert [group.count("<polyline") for group in groups] == [1, 2, 2]
+    assert 'stroke="#d62728" stroke-width="3"' in groups[1]
+    assert 'stroke="#1f77b4" stroke-width="3"' in groups[2]
+
+
+def test_overlay_diff_of_sample_and_incremental_update(tmp_path: Path) -> None:
+    updated = tmp_path / "line_moved.dwg"
+    line = next(iter(ezdwg.read(str(LINE_SAMPLE)).modelspace().query("LINE")))
+    moved = Entity("LINE", line.handle, {**line.dxf, "end": (120.0, 80.0, 0.0)})
+    ezdwg.apply_incremental_update(str(LINE_SAMPLE), [moved], output_path=str(updated))
+
+    same = ezdwg.overlay_diff(str(LINE_SAMPLE), str(LINE_SAMPLE), str(tmp_path / "same.svg"))
+    assert not same.changed
+    assert same.unchanged == 1
+
+    result = ezdwg.overlay_diff(str(LINE_SAMPLE), str(updated), str(tmp_path / "moved.svg"))
+    assert result.modified == [line.handle]
+    assert (result.added, result.removed, result.unchanged) == ([], [], 0)
+
+
+def test_overlay_diff_writes_pdf(tmp_path: Path) -> None:
+    pytest.importorskip("matplotlib")
+    output = tmp_path / "overlay.pdf"
+
+    ezdwg.overlay_diff([_line(0x10, (0.0, 0.0), (1.0, 1.0))], [], str(output))
+
+    assert output.read_bytes().startswith(b"%PDF")
+
+
+def test_match_entities_pairs_renumbered_entities_by_content(tmp_path: Path) -> None:
+    before = [
+        _line(0x10, (0.0, 0.0), (10.0, 0.0)),
+        _line(0x11, (0.0, 5.0), (10.0, 5.0)),
+        _line(0x12, (0.0, 10.0), (10.0, 10.0)),
+    ]
+    after = [
+        _line(0x20, (0.0, 0.0), (10.0, 0.0)),
+        _line(0x21, (0.0, 5.0), (10.0, 5.0)),
+        _line(0x12, (0.0, 10.0), (10.0, 12.0)),
+        _line(0x13, (0.0, 20.0), (10.0, 20.0)),
+    ]
+
+    match = ezdwg.match_entities(before, after)
+
+    assert match.pairs == {0x10: 0x20, 0x11: 0x21, 0x12: 0x12}
+    assert match.methods == {0x10: "content", 0x11: "content", 0x12: "handle"}
+    assert (match.unmatched_a, match.unmatched_b) == ([], [0x13])
+
+    by_handle = ezdwg.overlay_diff(before, after, str(tmp_path / "handle.svg"))
+    assert (by_handle.added, by_handle.removed) == ([0x13, 0x20, 0x21], [0x10, 0x11])
+    result = ezdwg.overlay_diff(before, after, str(tmp_path / "identity.svg"), match="identity")
+    assert (result.added, result.removed, result.modified) == ([0x13], [], [0x12])
+    assert result.matched == match.pairs
+
+
+def test_entity_guids_pair_entities_across_renumbered_saves(tmp_path: Path) -> None:
+    first = tmp_path / "first.dwg"
+    second = tmp_path / "second.dwg"
+    guid = [(1000, "7d3c6a52-guid")]
+    for output, handle, end in ((first, 0x200, 10.0), (second, 0x300, 20.0)):
+        ezdwg.raw.write_ac1015_dwg(
+            str(output),
+            [(handle, 0.0, 0.0, 0.0, end, 5.0, 0.0)],
+            [],
+            [],
+            [],
+            [],
+            [],
+            entity_xdata=[(handle, "EZDWG_GUID", guid)],
+        )
+
+    assert ezdwg.entity_guids(str(second)) == {0x300: "7d3c6a52-guid"}
+    match = ezdwg.match_entities(str(first), str(second))
+    assert (match.pairs, match.methods) == ({0x200: 0x300}, {0x200: "guid"})
+    result = ezdwg.overlay_diff(
+        str(first), str(second), str(tmp_path / "guid.svg"), match="identity"
+    )
+    assert (result.added, result.removed, result.modified) == ([], [], [0x300])
+
+
+def test_stamp_entity_guids_keeps_existing_guids(tmp_path: Path) -> None:
+    stamped = tmp_path / "stamped.dwg"
+    restamped = tmp_path / "restamped.dwg"
+    line = next(iter(ezdwg.read(str(LINE_SAMPLE)).modelspace().query("LINE")))
+
+    first = ezdwg.stamp_entity_guids(str(LINE_SAMPLE), str(stamped))
+    assert first.created == [line.handle]
+    assert ezdwg.entity_guids(str(stamped)) == first.guids
+
+    second = ezdwg.stamp_entity_guids(str(stamped), str(restamped))
+    assert second.created == []
+    assert ezdwg.entity_guids(str(restamped)) == first.guids
+    assert second.write.written_entities == 1
@@ -96,6 +96,41 @@ def test_query_polyline_pface_maps_faces(monkeypatch) -> None:
     assert entity.dxf["seqend_handle"] == 0xCFF
 
 
+def test_query_polyline_pface_maps_face_visibility_and_colors(monkeypatch) -> None:
+    _patch_empty_color_maps(monkeypatch)
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_polyline_pface_with_faces",
+        lambda _path: [
+            (
+                0xB03,
+                4,
+                2,
+                [
+                    (0.0, 0.0, 0.0),
+                    (1.0, 0.0, 0.0),
+                    (1.0, 1.0, 0.0),
+                    (0.0, 1.0, 0.0),
+                ],
+                [(1, 2, -3, 0), (-1, 3, 4, 0)],
+                [(256, None), (1, 0xC2FF8000)],
+            )
+        ],
+    )
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_polyline_sequence_members",
+        lambda _path: [],
+    )
+
+    doc = document_module.Document(path="dummy_pface_colors.dwg", version="AC1018")
+    (entity,) = doc.modelspace().query("POLYLINE_PFACE")
+
+    assert entity.dxf["faces"] == [(1, 2, -3, 0), (-1, 3, 4, 0)]
+    assert entity.dxf["face_invisible_edge_flags"] == [0b100, 0b001]
+    assert entity.dxf["face_colors"] == [(256, None), (1, 0xC2FF8000)]
+
+
 def test_query_vertex_pface_entities_include_owner_handles(monkeypatch) -> None:
     _patch_empty_color_maps(monkeypatch)
     monkeypatch.setattr(
@@ -0,0 +1,152 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import ezdwg
+import ezdwg.cli as cli_module
+import ezdwg.profiling as profiling_module
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+
+
+def _fake_raw(monkeypatch) -> None:
+    monkeypatch.setattr(
+        profiling_module.raw,
+        "list_object_headers_with_type",
+        lambda _path: [
+            (0x10, 100, 40, 0x33, "LAYER", "O"),
+            (0x11, 140, 40, 0x33, "LAYER", "O"),
+            (0x20, 180, 30, 0x13, "LINE", "E"),
+            (0x21, 210, 500, 0x4E, "HATCH", "E"),
+            (0x22, 710, 25, 0x13, "LINE", "E"),
+            (0x23, 735, 900, 0x4F, "XRECORD", "O"),
+        ],
+    )
+    monkeypatch.setattr(
+        profiling_module.raw,
+        "decode_layer_names",
+        lambda _path: [(0x10, "0"), (0x11, "Hatching")],
+    )
+    monkeypatch.setattr(
+        profiling_module.raw,
+        "decode_entity_styles",
+        lambda _path: [(0x20, None, None, 0x10), (0x21, None, None, 0), (0x22, None, None, 0)],
+    )
+    monkeypatch.setattr(
+        profiling_module.raw,
+        "decode_object_entity_layer_handles",
+        lambda _path, handles: [(handle, 0x11) for handle in handles if handle == 0x21],
+    )
+
+
+def test_profile_groups_sizes_by_type_and_layer(monkeypatch, tmp_path: Path) -> None:
+    _fake_raw(monkeypatch)
+    path = tmp_path / "dummy.dwg"
+    path.write_bytes(b"\0" * 2048)
+
+    result = ezdwg.profile(str(path), top=2)
+
+    assert (result.file_size, result.object_count, result.object_bytes) == (2048, 6, 1535)
+    assert [(item.name, item.count, item.bytes) for item in result.by_type] == [
+        ("XRECORD", 1, 900),
+        ("HATCH", 1, 500),
+        ("LAYER", 2, 80),
+        ("LINE", 2, 55),
+    ]
+    assert [(item.name, item.count, item.bytes) for item in result.by_layer] == [
+        ("Hatching", 1, 500),
+        ("0", 1, 30),
+        (None, 1, 25),
+    ]
+    assert [(item.handle, item.layer) for item in result.heaviest] == [(0x23, None), (0x21, "Hatching")]
+
+
+def test_profile_reads_sample_object_map() -> None:
+    result = ezdwg.profile(str(SAMPLE), top=3)
+
+    assert result.object_count == sum(item.count for item in result.by_type)
+    assert result.object_bytes == sum(item.bytes for item in result.by_type)
+    assert sum(item.count for item in result.by_layer) == 400
+    sizes = [item.size for item in result.heaviest]
+    assert sizes == sorted(sizes, reverse=True)
+    assert result.heaviest[0].type_name == "XRECORD"
+
+
+def test_list_unsupported_objects_counts_undecoded_types(monkeypatch) -> None:
+    monkeypatch.setattr(
+        profiling_module.raw,
+        "list_object_headers_with_type",
+        lambda _path: [
+            (0x10, 100, 40, 0x33, "LAYER", "O"),
+            (0x11, 140, 20, 0x30, "LAYER_CONTROL", "O"),
+            (0x20, 160, 30, 0x13, "LINE", "E"),
+            (0x21, 190, 60, 0x01F4, "DIM_LINEAR", "E"),
+            (0x22, 250, 300, 0x01F6, "WIPEOUT", "E"),
+            (0x23, 550, 200, 0x01F6, "WIPEOUT", "E"),
+            (0x24, 750, 900, 0x01F7, "CELLSTYLEMAP", "O"),
+        ],
+    )
+
+    result = ezdwg.list_unsupported_objects("dummy.dwg")
+
+    assert [(item.type_name, item.type_class, item.count, item.bytes) for item in result] == [
+        ("CELLSTYLEMAP", "O", 1, 900),
+        ("WIPEOUT", "E", 2, 500),
+    ]
+    assert result[1].type_code == 0x01F6
+
+
+def test_list_unsupported_objects_of_sample() -> None:
+    result = ezdwg.list_unsupported_objects(str(SAMPLE))
+
+    entities = {item.type_name: item.count for item in result if item.type_class == "E"}
+    assert entities["ACAD_TABLE"] == 2
+    assert "LINE" not in entities
+    assert "MULTILEADER" not in entities
+    assert not {"LAYER", "XRECORD", "DICTIONARY"} & {item.type_name for item in result}
+    sizes = [item.bytes for item in result]
+    assert sizes == sorted(sizes, reverse=True)
+
+
+def test_cli_profile_prints_report(monkeypatch, tmp_path: Path, capsys) -> None:
+    _fake_raw(monkeypatch)
+    path = tmp_path / "dummy.dwg"
+    path.write_bytes(b"\0" * 16)
+
+    code = cli_module.main(["profile", str(path), "--top", "1"])
+
+    assert code == 0
+    lines = capsys.readouterr().out.splitlines()
+    assert "type[XRECORD]: 1 objects, 900 bytes" in lines
+    assert "layer[Hatching]: 1 entities, 500 bytes" in lines
+    assert "type[HATCH]: 1 objects, 500 bytes" not in lines
+    assert lines[-1] == "heaviest: handle=0x23 type=XRECORD offset=735 size=900"
+
+
+def test_decode_stats_records_sections_and_types() -> None:
+    path = str(ROOT / "test_dwg/line_2004.dwg")
+    with ezdwg.decode_stats() as stats:
+        assert ezdwg.raw.decode_line_entities(path)
+
+    sections = {row.name: row for row in stats.sections}
+    assert sections["AcDb:AcDbObjects"].bytes > 0
+    line = next(row for row in stats.types if row.name == "LINE")
+    assert line.count >= 1
+    assert line.bytes > 0
+    assert line.seconds >= 0.0
+
+
+def test_decode_stats_does_not_nest() -> None:
+    with ezdwg.decode_stats():
+        try:
+            with ezdwg.decode_stats():
+                pass
+        except RuntimeError:
+            pass
+        else:
+            raise AssertionError("nested decode_stats should raise")
+    with ezdwg.decode_stats() as stats:
+        pass
+    assert stats.sections == []
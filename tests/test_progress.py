@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+def test_list_object_headers_with_type_reports_index_and_decode_progress() -> None:
+    path = str(SAMPLES / "line_2013.dwg")
+    events: list[tuple[str, int, int | None, int, int | None]] = []
+
+    rows = ezdwg.raw.list_object_headers_with_type(path, progress=lambda *args: events.append(args))
+
+    stages = [event[0] for event in events]
+    assert "index" in stages
+    assert stages[-1] == "decode"
+    total = len(ezdwg.raw.list_object_map_entries(path))
+    _, objects, objects_total, read_bytes, _ = events[-1]
+    assert objects == objects_total == total
+    assert read_bytes > 0
+    assert len(rows) <= total
+
+
+def test_list_object_map_entries_reports_index_progress() -> None:
+    path = str(SAMPLES / "line_2000.dwg")
+    events: list[tuple[str, int, int | None, int, int | None]] = []
+
+    entries = ezdwg.raw.list_object_map_entries(path, progress=lambda *args: events.append(args))
+
+    assert events
+    stage, objects, _, read_bytes, bytes_total = events[-1]
+    assert stage == "index"
+    assert objects == len(entries)
+    assert 0 < read_bytes <= bytes_total
+
+
+def test_to_dwg_reports_write_progress(tmp_path: Path) -> None:
+    events: list[tuple[str, int, int | None, int, int | None]] = []
+    output = tmp_path / "progress.dwg"
+
+    ezdwg.to_dwg(
+        str(SAMPLES / "line_2000.dwg"),
+        str(output),
+        progress=lambda *args: events.append(args),
+    )
+
+    # The LINE and the LAYER entry it sits on.
+    assert events[-1] == ("write", 2, 2, output.stat().st_size, output.stat().st_size)
+
+
+def test_progress_callback_errors_are_raised() -> None:
+    def fail(*_args: object) -> None:
+        raise RuntimeError("cancelled")
+
+    with pytest.raises(RuntimeError, match="cancelled"):
+        ezdwg.raw.list_object_headers_with_type(str(SAMPLES / "line_2000.dwg"), progress=fail)
+
+
+def test_cancelled_write_leaves_existing_output_untouched(tmp_path: Path) -> None:
+    output = tmp_path / "kept.dwg"
+    output.write_bytes(b"previous")
+
+    def fail(*_args: object) -> None:
+        raise RuntimeError("cancelled")
+
+    with pytest.raises(RuntimeError, match="cancelled"):
+        ezdwg.to_dwg(str(SAMPLES / "line_2000.dwg"), str(output), progress=fail)
+
+    assert output.read_bytes() == b"previous"
+    assert sorted(path.name for path in tmp_path.iterdir()) == ["kept.dwg"]
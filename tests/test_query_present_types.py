@@ -305,3 +305,33 @@ def test_query_wildcard_can_fetch_explicit_only_vertex_types(monkeypatch) -> Non
     assert len(entities) == 1
     assert entities[0].dxftype == "VERTEX_2D"
     assert entities[0].dxf["position"] == (1.0, 2.0, 0.0)
+
+
+def test_normalize_types_expands_presets_to_present_types(monkeypatch) -> None:
+    document_module._present_supported_types.cache_clear()
+
+    monkeypatch.setattr(
+        document_module.raw,
+        "list_object_headers_with_type",
+        lambda _path: [
+            (1, 0, 0, 0x13, "LINE", "Entity"),
+            (2, 0, 0, 0x01, "TEXT", "Entity"),
+            (3, 0, 0, 0x15, "DIM_LINEAR", "Entity"),
+            (4, 0, 0, 0x07, "INSERT", "Entity"),
+            (5, 0, 0, 0x4E, "HATCH", "Entity"),
+        ],
+    )
+
+    assert document_module._normalize_types("annotations_only", "dummy.dwg") == [
+        "TEXT",
+        "DIMENSION",
+    ]
+    assert document_module._normalize_types(["GEOMETRY_ONLY", "insert"], "dummy.dwg") == [
+        "LINE",
+        "INSERT",
+    ]
+    # Without a drawing the preset selects all of its types.
+    assert document_module._normalize_types("geometry_only") == list(
+        document_module.GEOMETRY_TYPES
+    )
+    assert not set(document_module.ANNOTATION_TYPES) & set(document_module.GEOMETRY_TYPES)
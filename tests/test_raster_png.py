@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import struct
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+import ezdwg.render as render_module
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+pytestmark = pytest.mark.skipif(
+    raw.write_strokes_png is None, reason="built without the raster feature"
+)
+
+
+def _png_size(path: Path) -> tuple[int, int]:
+    data = path.read_bytes()
+    assert data[:8] == b"\x89PNG\r\n\x1a\n"
+    assert data[12:16] == b"IHDR"
+    return struct.unpack(">II", data[16:24])
+
+
+def test_to_png_writes_modelspace_preview(tmp_path: Path) -> None:
+    output = tmp_path / "preview.png"
+
+    size = ezdwg.to_png(str(SAMPLES / "line_2000.dwg"), str(output), width=320)
+
+    assert size[0] == 320
+    assert _png_size(output) == size
+
+
+def test_export_png_honors_window_and_height(tmp_path: Path) -> None:
+    doc = ezdwg.read(str(SAMPLES / "arc_2004.dwg"))
+    output = tmp_path / "nested" / "arc.png"
+
+    size = doc.export_png(str(output), width=200, height=50, window=(0.0, 0.0, 10.0, 10.0))
+
+    assert size == (200, 50)
+    assert _png_size(output) == (200, 50)
+
+
+def test_write_strokes_png_rejects_zero_width(tmp_path: Path) -> None:
+    with pytest.raises(Exception):
+        raw.write_strokes_png(str(tmp_path / "empty.png"), [], width=0)
+
+
+def test_raster_color_resolver_uses_palette_and_background_contrast() -> None:
+    resolve = render_module._raster_color_resolver({1: (1, 2, 3)}, "#ffffff")
+
+    assert resolve({"resolved_color_index": 1}) == "#010203"
+    assert resolve({"resolved_color_index": 7}) == "#ffffff"
+    assert resolve({"resolved_color_index": 3}) == "#00ff00"
+    assert resolve({"resolved_true_color": 0x123456, "resolved_color_index": 1}) == "#123456"
+    assert resolve({}) == "#ffffff"
+
+
+def test_parse_rgb_blends_alpha_over_background() -> None:
+    assert render_module._parse_rgb("black") == (0, 0, 0)
+    assert render_module._parse_rgb((10, 20, 300)) == (10, 20, 255)
+    assert render_module._parse_rgb("#ff000080", (255, 255, 255)) == (255, 127, 127)
+    assert render_module._parse_rgb("red") is None
@@ -181,6 +181,53 @@ def test_decode_object_handle_stream_refs_smoke() -> None:
     assert any(len(refs) > 0 for _handle, refs in rows)
 
 
+def test_decode_object_handle_stream_ref_kinds_smoke() -> None:
+    path = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+    assert path.exists(), f"missing sample: {path}"
+
+    headers = ezdwg.raw.list_object_headers_with_type(str(path))
+    handles = [int(row[0]) for row in headers if int(row[3]) in {0x222, 0x223}][:2]
+    assert handles
+
+    plain = ezdwg.raw.decode_object_handle_stream_refs(str(path), handles)
+    rows = ezdwg.raw.decode_object_handle_stream_ref_kinds(str(path), handles)
+    assert [int(row[0]) for row in rows] == handles
+
+    valid_kinds = {
+        "soft_owner",
+        "hard_owner",
+        "soft_pointer",
+        "hard_pointer",
+        "relative",
+        "unspecified",
+    }
+    for (_handle, refs), (_plain_handle, plain_refs) in zip(rows, plain):
+        assert [int(ref) for ref, _kind in refs] == [int(ref) for ref in plain_refs]
+        assert all(kind in valid_kinds for _ref, kind in refs)
+
+
+def test_decode_entity_colors_matches_entity_styles() -> None:
+    path = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+    assert path.exists(), f"missing sample: {path}"
+
+    styles = {row[0]: row for row in ezdwg.raw.decode_entity_styles(str(path))}
+    rows = ezdwg.raw.decode_entity_colors(str(path))
+    assert rows
+
+    for handle, kind, aci, rgb, layer_handle in rows:
+        assert kind in {"bylayer", "byblock", "aci", "true_color", "color_book"}
+        assert layer_handle == styles[handle][3]
+        if kind == "aci":
+            assert aci is not None
+            assert rgb == _rgb_to_int(ezdwg.raw.aci_to_rgb(aci))
+        if kind in {"bylayer", "byblock"}:
+            assert rgb is None
+
+
+def _rgb_to_int(rgb: tuple[int, int, int]) -> int:
+    return (rgb[0] << 16) | (rgb[1] << 8) | rgb[2]
+
+
 def test_decode_acis_candidate_infos_smoke() -> None:
     path = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
     assert path.exists(), f"missing sample: {path}"
@@ -220,3 +267,46 @@ def test_decode_acis_candidate_infos_expected_chain_sample() -> None:
     assert 3430 in row_map[3431][2]
     assert 3431 in row_map[3432][2]
     assert all(0 <= row_map[handle][3] <= 100 for handle in (3430, 3431, 3432))
+
+
+@pytest.mark.parametrize(
+    "relative_path",
+    [
+        "test_dwg/line_2004.dwg",
+        "test_dwg/line_2007.dwg",
+        "test_dwg/line_2010.dwg",
+        "test_dwg/line_2013.dwg",
+    ],
+)
+def test_read_decompressed_section_returns_sized_sections(relative_path: str) -> None:
+    path = str(ROOT / relative_path)
+    sizes = {name: size for name, _offset, size in ezdwg.raw.list_section_locators(path)}
+
+    header = ezdwg.raw.read_decompressed_section(path, "AcDb:Header")
+    assert isinstance(header, bytes)
+    assert len(header) == sizes["AcDb:Header"]
+    assert header.startswith(bytes.fromhex("cf7b1f23fdde38a95f7c68b84e6d335f"))
+    assert ezdwg.raw.read_decompressed_section(path, "AcDb:SectionMap")
+    assert ezdwg.raw.read_decompressed_section(path, "AcDb:SectionPageMap")
+
+
+def test_read_decompressed_section_rejects_r2000_and_unknown_names() -> None:
+    with pytest.raises(ValueError):
+        ezdwg.raw.read_decompressed_section(str(ROOT / "test_dwg/line_2000.dwg"), "AcDb:Header")
+    with pytest.raises(ValueError):
+        ezdwg.raw.read_decompressed_section(str(ROOT / "test_dwg/line_2004.dwg"), "AcDb:Missing")
+
+
+def test_list_object_map_entries_supports_handle_ranges_and_offset_order() -> None:
+    path = str(ROOT / "test_dwg/line_2004.dwg")
+    entries = ezdwg.raw.list_object_map_entries(path)
+    handles = sorted({handle for handle, _offset in entries})
+    low, high = handles[2], handles[10]
+
+    in_range = ezdwg.raw.list_object_map_entries(path, start_handle=low, end_handle=high)
+    assert [handle for handle, _offset in in_range] == [h for h in handles if low <= h <= high]
+    assert ezdwg.raw.list_object_map_entries(path, start_handle=low, end_handle=high, limit=3) == in_range[:3]
+
+    by_offset = ezdwg.raw.list_object_map_entries(path, by_offset=True)
+    assert sorted(by_offset) == sorted(entries)
+    assert [offset for _handle, offset in by_offset] == sorted(offset for _handle, offset in entries)
@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import ezdwg
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+def test_parsed_fields_of_older_versions_are_not_reported() -> None:
+    assert raw.decode_recovered_fields(str(SAMPLES / "arc_2004.dwg")) == []
+    assert ezdwg.recovered_fields(SAMPLES / "insert_2004.dwg") == {}
+
+
+def test_recovered_layer_handle_matches_style_rows() -> None:
+    path = SAMPLES / "arc_2010.dwg"
+    recovered = ezdwg.recovered_fields(path)
+
+    field = recovered[131]["layer_handle"]
+    assert (field.method, field.parsed) == ("handle_stream_scan", 130)
+    styles = {handle: layer for handle, _index, _true_color, layer in raw.decode_entity_styles(str(path))}
+    assert field.value == styles[131]
+
+
+def test_recovered_mtext_and_block_handles_are_reported() -> None:
+    recovered = ezdwg.recovered_fields(SAMPLES / "acadsharp" / "sample_AC1032.dwg")
+    fields = [field for by_field in recovered.values() for field in by_field.values()]
+
+    texts = [field for field in fields if field.field == "text"]
+    assert texts
+    assert all(field.method == "text_scan" and field.value != field.parsed for field in texts)
+    assert "Sample annotation" in {field.value for field in texts}
+
+    blocks = raw.decode_recovered_fields(str(SAMPLES / "acadsharp" / "BLOCKPOINTPARAMETER.dwg"))
+    assert blocks == [(641, "block_handle", 642, 640, "handle_stream_scan")]
@@ -2,7 +2,10 @@ from __future__ import annotations
 
 from types import SimpleNamespace
 
+import pytest
+
 import ezdwg.render as render_module
+from ezdwg import AciPalette
 
 
 class _FakeLines:
@@ -52,6 +55,12 @@ def test_aci_7_resolves_to_black() -> None:
     assert color == "#000000"
 
 
+def test_aci_uses_default_palette() -> None:
+    assert render_module._resolve_dwg_color({"resolved_color_index": 3}) == "#00ff00"
+    assert render_module._resolve_dwg_color({"resolved_color_index": 11}) == "#ff7f7f"
+    assert render_module._resolve_dwg_color({"resolved_color_index": 250}) == "#333333"
+
+
 def test_plot_layout_falls_back_to_black_for_unresolved_color(monkeypatch) -> None:
     captured: list[str | None] = []
     monkeypatch.setattr(render_module, "_require_matplotlib", lambda: object())
@@ -479,6 +488,45 @@ def test_plot_layout_polyline_pface_uses_face_edges(monkeypatch) -> None:
     ]
 
 
+def test_plot_layout_polyline_pface_skips_invisible_edges(monkeypatch) -> None:
+    captured: list[tuple] = []
+    monkeypatch.setattr(render_module, "_require_matplotlib", lambda: object())
+    monkeypatch.setattr(
+        render_module,
+        "_draw_polyline",
+        lambda *_args, **_kwargs: captured.append(("polyline",)),
+    )
+    monkeypatch.setattr(
+        render_module,
+        "_draw_line",
+        lambda _ax, start, end, _line_width, color=None: captured.append((start, end)),
+    )
+
+    layout = _FakeLayout(
+        [
+            SimpleNamespace(
+                dxftype="POLYLINE_PFACE",
+                dxf={
+                    "vertices": [
+                        (0.0, 0.0, 0.0),
+                        (1.0, 0.0, 0.0),
+                        (1.0, 1.0, 0.0),
+                    ],
+                    "faces": [(1, -2, 3, 0)],
+                },
+            )
+        ]
+    )
+    ax = _FakeAx()
+
+    render_module.plot_layout(layout, ax=ax, show=False, auto_fit=False, equal=False)
+
+    assert captured == [
+        ((0.0, 0.0, 0.0), (1.0, 0.0, 0.0)),
+        ((1.0, 1.0, 0.0), (0.0, 0.0, 0.0)),
+    ]
+
+
 def test_plot_layout_3dface_uses_face_drawer(monkeypatch) -> None:
     captured: list[tuple[list, int]] = []
     monkeypatch.setattr(render_module, "_require_matplotlib", lambda: object())
@@ -628,3 +676,43 @@ def test_plot_layout_mline_uses_polyline_drawer(monkeypatch) -> None:
     render_module.plot_layout(layout, ax=ax, show=False, auto_fit=False, equal=False)
 
     assert captured == [([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0)], True)]
+
+
+def test_aci_palette_profiles_and_overrides(tmp_path) -> None:
+    default = AciPalette.default()
+    assert default.rgb(1) == (255, 0, 0)
+    assert default.rgb(0) is None and default.rgb(256) is None
+    assert default.rgb(7, background=(0, 0, 0)) == (255, 255, 255)
+    assert default.rgb(7, background=(240, 240, 240)) == (0, 0, 0)
+    assert AciPalette.grayscale().rgb(3) == (150, 150, 150)
+    assert AciPalette.monochrome().rgb(7, background=(0, 0, 0)) == (0, 0, 0)
+
+    custom = default.with_overrides({7: (10, 20, 30)})
+    assert not custom.background_aware
+    assert custom.rgb(7, background=(0, 0, 0)) == (10, 20, 30)
+    with pytest.raises(ValueError, match="256 entries"):
+        AciPalette(((0, 0, 0),) * 255)
+
+    saved = tmp_path / "palette.json"
+    custom.save(str(saved))
+    assert AciPalette.load(str(saved)) == custom
+    profile = tmp_path / "profile.json"
+    profile.write_text('{"base": "monochrome", "colors": {"1": [200, 0, 0]}}')
+    loaded = AciPalette.load(str(profile))
+    assert (loaded.rgb(1), loaded.rgb(2)) == ((200, 0, 0), (0, 0, 0))
+
+
+def test_palette_maps_aci_colors_for_exporters() -> None:
+    palette = AciPalette.grayscale()
+    assert render_module._resolve_dwg_color({"resolved_color_index": 1}, palette) == "#4c4c4c"
+    assert render_module._resolve_dwg_color({"resolved_true_color": 0x123456}, palette) == "#123456"
+
+    resolve = render_module._raster_color_resolver(palette, "#ffffff")
+    assert resolve({"resolved_color_index": 7}) == "#ffffff"
+    resolve = render_module._raster_color_resolver(AciPalette.monochrome(), "#ffffff")
+    assert resolve({"resolved_color_index": 7}) == "#000000"
+
+    plot_resolve = render_module._plot_color_resolver(None, "#ffffff")
+    assert plot_resolve({"resolved_color_index": 7}) == "#ffffff"
+    assert render_module._axes_background(_FakeMTextAx()) == (230, 230, 230)
+    assert render_module._axes_background(_FakeAx()) == (255, 255, 255)
@@ -0,0 +1,79 @@
+from __future__ import annotations
+
+import math
+
+import ezdwg.document as document_module
+
+
+def _clear_document_caches() -> None:
+    document_module._present_supported_types.cache_clear()
+    document_module._entity_style_map.cache_clear()
+    document_module._layer_color_map.cache_clear()
+    document_module._object_headers_with_type_map.cache_clear()
+
+
+def _patch_headers(monkeypatch, headers) -> None:
+    monkeypatch.setattr(document_module.raw, "list_object_headers_with_type", lambda _path: headers)
+    monkeypatch.setattr(document_module.raw, "decode_entity_styles", lambda _path: [])
+    monkeypatch.setattr(document_module.raw, "decode_layer_colors", lambda _path: [])
+
+
+def test_query_rtext_entity(monkeypatch) -> None:
+    _clear_document_caches()
+    _patch_headers(monkeypatch, [(300, 0, 0, 0x1F4, "RTEXT", "Entity")])
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_rtext_entities",
+        lambda _path: [
+            (300, (1.0, 2.0, 0.0), (0.0, 0.0, 1.0), math.pi / 2, 2.5, 1, "$(getvar, dwgname)", 17)
+        ],
+    )
+
+    doc = document_module.Document(path="dummy_rtext.dwg", version="AC1015")
+    entity = next(doc.modelspace().query("RTEXT"))
+
+    assert entity.dxftype == "RTEXT"
+    assert entity.dxf["insert"] == (1.0, 2.0, 0.0)
+    assert entity.dxf["rotation"] == 90.0
+    assert entity.dxf["height"] == 2.5
+    assert entity.dxf["is_expression"] is True
+    assert entity.dxf["text"] == "$(getvar, dwgname)"
+    assert entity.dxf["style_handle"] == 17
+
+
+def test_query_arc_aligned_text_entity(monkeypatch) -> None:
+    _clear_document_caches()
+    _patch_headers(monkeypatch, [(301, 0, 0, 0x1F5, "ARCALIGNEDTEXT", "Entity")])
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_arc_aligned_text_entities",
+        lambda _path: [
+            (
+                301,
+                "ALONG THE ARC",
+                ("txt.shx", None, "Standard"),
+                (5.0, 5.0, 0.0),
+                10.0,
+                (0.0, math.pi),
+                (1.5, 1.0, 0.0),
+                (0.25, 0.0, 0.0),
+                (False, 1, 4, 1),
+                True,
+                (0.0, 0.0, 1.0),
+                42,
+            )
+        ],
+    )
+
+    doc = document_module.Document(path="dummy_arc_aligned_text.dwg", version="AC1015")
+    entity = next(doc.modelspace().query("ARCALIGNEDTEXT"))
+
+    assert entity.dxftype == "ARCALIGNEDTEXT"
+    assert entity.dxf["text"] == "ALONG THE ARC"
+    assert entity.dxf["font"] == "txt.shx"
+    assert entity.dxf["center"] == (5.0, 5.0, 0.0)
+    assert entity.dxf["radius"] == 10.0
+    assert entity.dxf["end_angle"] == 180.0
+    assert entity.dxf["height"] == 1.5
+    assert entity.dxf["alignment"] == 4
+    assert entity.dxf["arc_handle"] == 42
@@ -0,0 +1,40 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+
+
+@pytest.mark.parametrize(
+    "name",
+    [
+        "line_R14.dwg",
+        "line_2000.dwg",
+        "line_2004.dwg",
+        "line_2007.dwg",
+        "line_2010.dwg",
+        "line_2013.dwg",
+    ],
+)
+def test_decode_scales_reads_names_and_units(name: str) -> None:
+    rows = raw.decode_scales(str(ROOT / "test_dwg" / name))
+
+    assert len(rows) == 17
+    by_name = {row[1]: row[2:] for row in rows}
+    assert by_name["1:1"] == (1.0, 1.0, True)
+    assert by_name["1:4"] == (1.0, 4.0, False)
+    assert by_name["100:1"] == (100.0, 1.0, False)
+
+
+def test_document_scales_follow_the_scale_list() -> None:
+    scales = ezdwg.read(str(ROOT / "test_dwg" / "line_2000.dwg")).scales()
+
+    assert [scale.name for scale in scales[:3]] == ["1:1", "1:2", "1:4"]
+    assert scales[2].factor == 4.0
+    assert scales[0].is_unit_scale
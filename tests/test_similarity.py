@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+LINE_SAMPLE = ROOT / "test_dwg/line_2000.dwg"
+
+
+def _line(handle: int, start: tuple[float, float], end: tuple[float, float]) -> Entity:
+    return Entity("LINE", handle, {"start": (*start, 0.0), "end": (*end, 0.0)})
+
+
+def _solid(handle: int, size: float) -> Entity:
+    points = [(0.0, 0.0, 0.0), (size, 0.0, 0.0), (0.0, size, 0.0), (size, size, 0.0)]
+    return Entity("SOLID", handle, {"points": points})
+
+
+def test_drawing_similarity_weights_matches_by_length_and_area() -> None:
+    before = [
+        _line(0x10, (0.0, 0.0), (30.0, 0.0)),
+        _line(0x11, (0.0, 5.0), (10.0, 5.0)),
+        _solid(0x12, 2.0),
+        _solid(0x13, 1.0),
+    ]
+    after = [
+        _line(0x20, (0.0, 0.0), (30.0, 0.0)),
+        _line(0x21, (0.0, 5.0), (10.0, 8.0)),
+        _solid(0x22, 2.0),
+    ]
+
+    result = ezdwg.drawing_similarity(before, after)
+
+    assert result.matched == {0x10: 0x20, 0x12: 0x22}
+    assert result.total_length == pytest.approx(70.0 + 109**0.5)
+    assert result.matched_length == pytest.approx(60.0)
+    assert (result.matched_area, result.total_area) == (pytest.approx(8.0), pytest.approx(9.0))
+    assert result.score == pytest.approx((result.length_score + 8.0 / 9.0) / 2.0)
+
+    by_handle = ezdwg.drawing_similarity(before, after, match="handle")
+    assert by_handle.matched == {}
+    assert by_handle.score == 0.0
+
+
+def test_drawing_similarity_subtracts_hatch_islands() -> None:
+    square = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]
+    hole = [(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0)]
+    hatch = Entity(
+        "HATCH",
+        0x30,
+        {"solid_fill": True, "paths": [{"points": square}, {"points": hole}]},
+    )
+
+    result = ezdwg.drawing_similarity([hatch], [])
+
+    assert result.total_area == pytest.approx(15.0)
+    assert (result.score, result.length_score) == (0.0, None)
+    assert ezdwg.drawing_similarity([], []).score == 1.0
+
+
+def test_drawing_similarity_of_sample_and_renumbered_copy(tmp_path: Path) -> None:
+    updated = tmp_path / "line_moved.dwg"
+    line = next(iter(ezdwg.read(str(LINE_SAMPLE)).modelspace().query("LINE")))
+    moved = Entity("LINE", line.handle, {**line.dxf, "end": (120.0, 80.0, 0.0)})
+    ezdwg.apply_incremental_update(str(LINE_SAMPLE), [moved], output_path=str(updated))
+
+    same = ezdwg.drawing_similarity(str(LINE_SAMPLE), str(LINE_SAMPLE))
+    assert same.score == 1.0
+    assert same.matched == {line.handle: line.handle}
+
+    assert ezdwg.drawing_similarity(str(LINE_SAMPLE), str(updated)).score == 0.0
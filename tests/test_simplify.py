@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import pytest
+
+import ezdwg
+from ezdwg.entity import Entity
+
+
+def test_simplify_points_drops_points_within_tolerance() -> None:
+    points = [
+        (0.0, 0.0, 0.0),
+        (1.0, 0.01, 0.0),
+        (2.0, -0.01, 0.0),
+        (3.0, 1.0, 0.0),
+        (4.0, 0.0, 0.0),
+    ]
+    assert ezdwg.simplify_points(points, 0.1) == [points[0], points[2], points[3], points[4]]
+    assert ezdwg.simplify_points(points, 2.0) == [points[0], points[4]]
+    assert ezdwg.simplify_points(points[:2], 10.0) == points[:2]
+
+
+def test_entity_simplify_keeps_arcs_widths_and_closure() -> None:
+    points = [
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (3.0, 0.0, 0.0),
+        (3.0, 1.0, 0.0),
+        (0.0, 1.0, 0.0),
+    ]
+    entity = Entity(
+        "LWPOLYLINE",
+        0x30,
+        {
+            "points": points,
+            "closed": True,
+            "flags": 1,
+            "bulges": [0.0, 0.0, 0.0, 0.5, 0.0, 0.0],
+            "widths": [(0.0, 1.0), (1.0, 2.0), (2.0, 3.0), (3.0, 3.0), (0.0, 0.0), (0.0, 0.0)],
+        },
+    )
+
+    simplified = entity.simplify(0.001)
+    assert simplified.handle == 0x30
+    assert simplified.dxf["points"] == [points[0], points[3], points[4], points[5]]
+    assert simplified.dxf["bulges"] == [0.0, 0.5, 0.0, 0.0]
+    assert simplified.dxf["widths"][0] == (0.0, 3.0)
+    assert simplified.dxf["closed"] is True
+    assert entity.simplify(0.0).dxf["points"] == simplified.dxf["points"]
+
+    line = Entity("POLYLINE_3D", 0x31, {"points": [(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)]})
+    assert line.simplify(1.0) is line
+    with pytest.raises(NotImplementedError):
+        Entity("LINE", 0x32, {"start": (0.0, 0.0, 0.0), "end": (1.0, 0.0, 0.0)}).simplify(1.0)
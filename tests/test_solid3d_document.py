@@ -14,6 +14,7 @@ def _clear_document_caches() -> None:
     document_module._object_headers_with_type_map.cache_clear()
     document_module._acis_candidate_handles_map.cache_clear()
     document_module._acis_candidate_record_map.cache_clear()
+    document_module._acds_acis_map.cache_clear()
 
 
 def test_query_3dsolid_entity(monkeypatch) -> None:
@@ -383,3 +384,16 @@ def test_query_3dsolid_acis_low_confidence_entity_fallback(monkeypatch) -> None:
             "rule": "payload-fallback-entity",
         }
     ]
+
+
+def test_ac1032_3dsolid_attaches_acds_acis_data() -> None:
+    _clear_document_caches()
+    sample = Path(__file__).resolve().parents[1] / "test_dwg/acadsharp/sample_AC1032.dwg"
+
+    entities = list(ezdwg.read(str(sample)).modelspace().query("3DSOLID"))
+
+    with_data = [entity for entity in entities if "acis_data" in entity.dxf]
+    assert with_data
+    for entity in with_data:
+        assert entity.dxf["acis_format"] == "sab"
+        assert entity.dxf["acis_data"].startswith(b"ASM BinaryFile")
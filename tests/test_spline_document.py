@@ -66,3 +66,33 @@ def test_query_spline_uses_control_points_when_fit_absent(monkeypatch) -> None:
     assert dxf["closed"] is True
     assert dxf["points"][0] == dxf["points"][-1]
     assert len(dxf["points"]) == 4
+
+
+def test_query_spline_exposes_fit_tangents(monkeypatch) -> None:
+    _patch_empty_color_maps(monkeypatch)
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_spline_entities",
+        lambda _path: [
+            (
+                0x503,
+                (2, 3, False, False, False),
+                (1.0e-7, None, None),
+                [0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0],
+                [(0.0, 0.0, 0.0), (0.0, 1.0, 0.0), (2.0, 1.0, 0.0), (2.0, 0.0, 0.0)],
+                [],
+                [(0.0, 0.0, 0.0), (2.0, 0.0, 0.0)],
+                ((0.0, 1.0, 0.0), (0.0, -1.0, 0.0)),
+            )
+        ],
+    )
+
+    doc = document_module.Document(path="dummy_spline_tangents.dwg", version="AC1018")
+    entities = list(doc.modelspace().query("SPLINE"))
+
+    assert len(entities) == 1
+    dxf = entities[0].dxf
+    assert dxf["start_tangent"] == (0.0, 1.0, 0.0)
+    assert dxf["end_tangent"] == (0.0, -1.0, 0.0)
+    assert dxf["fit_tangents"] == [(0.0, 1.0, 0.0), (0.0, -1.0, 0.0)]
+    assert len(dxf["control_points"]) == 4
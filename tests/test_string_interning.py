@@ -0,0 +1,27 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+
+
+def test_object_type_names_share_one_string_per_type() -> None:
+    rows = raw.list_object_headers_with_type(str(SAMPLE))
+    by_name: dict[str, str] = {}
+    for row in rows:
+        type_name, type_class = row[4], row[5]
+        assert by_name.setdefault(type_name, type_name) is type_name
+        assert by_name.setdefault(type_class, type_class) is type_class
+
+
+def test_insert_block_names_are_shared_with_block_header_rows() -> None:
+    headers = {name: name for _handle, name in raw.decode_block_header_names(str(SAMPLE))}
+    names = [row[8] for row in raw.decode_insert_entities(str(SAMPLE)) if row[8] is not None]
+
+    assert names.count("*D8") == 2
+    for name in names:
+        assert headers.get(name, name) is name
@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLES = ROOT / "test_dwg"
+
+
+def test_decode_table_control_block_lists_entries_and_layouts() -> None:
+    handle, type_name, num_entries, entries, specials = ezdwg.raw.decode_table_control(
+        str(SAMPLES / "line_2000.dwg"), "BLOCK"
+    )
+    assert handle == 1
+    assert type_name == "BLOCK_CONTROL"
+    assert num_entries == 2
+    assert entries == [116]
+    assert specials == [("*MODEL_SPACE", 112), ("*PAPER_SPACE", 108)]
+
+
+def test_decode_table_control_layer_entries_match_layer_names() -> None:
+    path = str(SAMPLES / "line_2000.dwg")
+    _handle, type_name, _num_entries, entries, _specials = ezdwg.raw.decode_table_control(
+        path, "layer_control"
+    )
+    assert type_name == "LAYER_CONTROL"
+    layer_handles = {handle for handle, _name in ezdwg.raw.decode_layer_names(path)}
+    assert set(entries) <= layer_handles
+
+
+def test_decode_table_control_r2013_layer() -> None:
+    _handle, type_name, _num_entries, entries, _specials = ezdwg.raw.decode_table_control(
+        str(SAMPLES / "line_2013.dwg"), "LAYER"
+    )
+    assert type_name == "LAYER_CONTROL"
+    assert entries == [84]
+
+
+def test_decode_table_control_ltype_reports_byblock_bylayer() -> None:
+    _handle, _type_name, _num_entries, _entries, specials = ezdwg.raw.decode_table_control(
+        str(SAMPLES / "line_2000.dwg"), "LTYPE"
+    )
+    labels = [label for label, _handle in specials]
+    assert labels == ["BYBLOCK", "BYLAYER"]
+
+
+def test_decode_table_entry_names_lists_entries_in_table_order() -> None:
+    path = str(SAMPLES / "insert_2004.dwg")
+    assert ezdwg.raw.decode_table_entry_names(path, "BLOCK") == [
+        (41, "BLK1"),
+        (29, "*Model_Space"),
+        (27, "*Paper_Space"),
+    ]
+    assert ezdwg.raw.decode_table_entry_names(path, "STYLE") == [(17, "Standard")]
+    # R2007+ names live in the string stream.
+    assert ezdwg.raw.decode_table_entry_names(str(SAMPLES / "line_2013.dwg"), "LAYER") == [
+        (84, None)
+    ]
+
+
+def test_decode_table_control_rejects_unknown_table() -> None:
+    with pytest.raises(ValueError, match="unknown table"):
+        ezdwg.raw.decode_table_control(str(SAMPLES / "line_2000.dwg"), "NOPE")
+
+
+def test_decode_table_styles_reads_standard_row_formatting() -> None:
+    rows = ezdwg.raw.decode_table_styles(str(SAMPLES / "insert_2004.dwg"))
+    assert len(rows) == 1
+    handle, description, _flow, _flags, h_margin, v_margin, _title_off, _header_off, cells = rows[0]
+    assert handle == 93
+    assert description == "Standard"
+    assert h_margin == pytest.approx(0.06)
+    assert v_margin == pytest.approx(0.06)
+    assert [cell[1] for cell in cells] == pytest.approx([0.18, 0.25, 0.18])
+    text_style_handle, _height, alignment, _text_color, _fill, _fill_on, borders = cells[1]
+    assert text_style_handle is not None
+    assert alignment == 5
+    assert len(borders) == 6
+
+
+def test_decode_table_styles_r2007_has_no_inline_description() -> None:
+    rows = ezdwg.raw.decode_table_styles(str(SAMPLES / "line_2007.dwg"))
+    assert len(rows) == 1
+    assert rows[0][1] is None
+    assert [cell[1] for cell in rows[0][8]] == pytest.approx([4.5, 6.0, 4.5])
@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg.entity import Entity
+
+
+ROOT = Path(__file__).resolve().parents[1]
+TEXT_2000 = ROOT / "test_dwg/text_2000.dwg"
+MTEXT_2000 = ROOT / "test_dwg/mtext_2000.dwg"
+
+
+def _text(**dxf) -> Entity:
+    return Entity(dxftype="TEXT", handle=1, dxf={"text": "ABC", "height": 2.0, **dxf})
+
+
+def test_estimate_text_extents_uses_font_tables() -> None:
+    assert ezdwg.estimate_text_extents("Hello", None, 5.0) == ezdwg.TextExtents(25.0, 5.0, 0.0, 1)
+
+    romans = ezdwg.estimate_text_extents("HI", "RomanS", 21.0, 0.5)
+    assert romans.width == pytest.approx(15.0)
+    arial = ezdwg.estimate_text_extents("ii", "arial.ttf", 7.16)
+    assert arial.width == pytest.approx(4.44)
+
+    lines = ezdwg.estimate_text_extents("top\nqy", "romans.shx", 3.0)
+    assert lines.line_count == 2
+    assert lines.descent == pytest.approx(1.0 + 5.0)
+    assert lines.box() == (0.0, -lines.descent, lines.width, 3.0)
+
+    assert ezdwg.estimate_text_extents("%%c50", None).width == 3.0
+    assert ezdwg.estimate_text_extents("図面", "romans.shx", 2.0).width == 4.0
+
+
+def test_estimate_text_extents_applies_font_map_width_scale() -> None:
+    font_map = ezdwg.FontMap([ezdwg.FontRule("romans", "DejaVu Sans", width_scale=0.5)])
+
+    base = ezdwg.estimate_text_extents("WALL", "romans.shx", 2.5)
+    mapped = ezdwg.estimate_text_extents("WALL", "romans.shx", 2.5, font_map=font_map)
+
+    assert mapped.width == pytest.approx(base.width * 0.5)
+
+
+def test_text_outline_follows_alignment_and_rotation() -> None:
+    left = ezdwg.text_bounds(_text(insert=(10.0, 20.0, 0.0)))
+    assert left == pytest.approx((10.0, 20.0, 16.0, 22.0))
+
+    centered = _text(insert=(0.0, 0.0, 0.0), align_point=(10.0, 20.0, 0.0), halign=1, valign=3)
+    assert ezdwg.text_bounds(centered) == pytest.approx((7.0, 18.0, 13.0, 20.0))
+
+    rotated = ezdwg.text_outline(_text(insert=(0.0, 0.0, 0.0), rotation=90.0))
+    assert rotated[1] == pytest.approx((0.0, 6.0))
+
+    fit = _text(insert=(0.0, 0.0, 0.0), align_point=(30.0, 0.0, 0.0), halign=5)
+    assert ezdwg.text_bounds(fit) == pytest.approx((0.0, 0.0, 30.0, 2.0))
+
+    assert ezdwg.text_outline(Entity(dxftype="LINE", handle=2, dxf={})) is None
+    assert ezdwg.text_outline(_text(text="")) is None
+
+
+def test_text_bounds_and_hit_testing_on_drawings() -> None:
+    text = next(ezdwg.read(str(TEXT_2000)).modelspace().query("TEXT"))
+    mtext = next(ezdwg.read(str(MTEXT_2000)).modelspace().query("MTEXT"))
+
+    # "Hello TEXT" at height 5 in txt.shx; the MTEXT frame is 100 wide,
+    # attached top left.
+    assert ezdwg.text_bounds(text) == pytest.approx((50.0, 50.0, 100.0, 55.0))
+    assert ezdwg.text_bounds(mtext) == pytest.approx((50.0, 45.0, 150.0, 50.0))
+
+    entities = [text, mtext]
+    assert ezdwg.hit_test_text(entities, (90.0, 52.0)) == [text]
+    assert ezdwg.hit_test_text(entities, (120.0, 47.0)) == [mtext]
+    assert ezdwg.hit_test_text(entities, (101.0, 52.0)) == []
+    assert ezdwg.hit_test_text(entities, (101.0, 52.0), tolerance=1.5) == [text]
+    assert ezdwg.text_bounds(text, style={0: "romans.shx"})[2] < 100.0
@@ -0,0 +1,79 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import Entity
+from ezdwg import tiles as tiles_module
+
+
+ROOT = Path(__file__).resolve().parents[1]
+CIRCLE_SAMPLE = ROOT / "test_dwg/circle_2004.dwg"
+
+
+class _FakeLayout:
+    def __init__(self, entities: list[Entity]) -> None:
+        self._entities = entities
+
+    def query(self, types=None):
+        return list(self._entities)
+
+
+def _line(handle: int, start: tuple[float, float], end: tuple[float, float]) -> Entity:
+    return Entity(
+        dxftype="LINE",
+        handle=handle,
+        dxf={"start": (*start, 0.0), "end": (*end, 0.0), "color_index": 1},
+    )
+
+
+def test_tile_index_uses_xyz_tiles_over_square_bounds() -> None:
+    index = ezdwg.TileIndex(str(CIRCLE_SAMPLE))
+
+    assert index.bounds == pytest.approx((0.0, 0.0, 100.0, 100.0))
+    assert index.tile_window(0, 0, 0) == pytest.approx((0.0, 0.0, 100.0, 100.0))
+    assert index.tile_window(1, 0, 0) == pytest.approx((0.0, 50.0, 50.0, 100.0))
+    assert index.tile_window(2, 3, 3) == pytest.approx((75.0, 0.0, 100.0, 25.0))
+    with pytest.raises(ValueError, match="outside"):
+        index.tile_window(1, 2, 0)
+
+    (run,) = [points for points, _rgb, _closed in index.query((0.0, 0.0, 50.0, 50.0))]
+    assert all(0.0 <= x <= 50.0 and 0.0 <= y <= 50.0 for x, y in run)
+    assert run[0] == pytest.approx((0.0, 50.0)) or run[-1] == pytest.approx((0.0, 50.0))
+
+
+def test_tile_index_only_returns_strokes_near_the_window() -> None:
+    entities = [_line(0x10 + i, (i * 10.0, 0.0), (i * 10.0 + 5.0, 5.0)) for i in range(100)]
+    entities.append(_line(0x200, (0.0, 990.0), (1000.0, 990.0)))
+    index = tiles_module.TileIndex(_FakeLayout(entities), bounds=(0.0, 0.0, 1000.0, 1000.0))
+
+    assert [points for points, _rgb, _closed in index.query((12.0, 0.0, 31.0, 10.0))] == [
+        [(12.0, 2.0), (15.0, 5.0)],
+        [(20.0, 0.0), (25.0, 5.0)],
+        [(30.0, 0.0), (31.0, 1.0)],
+    ]
+    assert index.query((500.0, 500.0, 600.0, 600.0)) == []
+    ((points, rgb, _closed),) = index.query((100.0, 900.0, 200.0, 1000.0))
+    assert points == [(100.0, 990.0), (200.0, 990.0)]
+    assert rgb == (255, 0, 0)
+
+
+def test_render_tile_writes_png_and_svg() -> None:
+    png = ezdwg.render_tile(str(CIRCLE_SAMPLE), 1, 0, 0, tile_size=64)
+    assert png.startswith(b"\x89PNG\r\n\x1a\n")
+
+    svg = ezdwg.render_tile(str(CIRCLE_SAMPLE), 1, 1, 1, format="svg", tile_size=64).decode()
+    assert svg.startswith('<svg xmlns="http://www.w3.org/2000/svg" width="64" height="64"')
+    assert svg.count("<polyline") == 1
+    hits = tiles_module._cached_tile_index.cache_info().hits
+    empty = ezdwg.TileIndex(str(CIRCLE_SAMPLE)).render_window(
+        (200.0, 200.0, 300.0, 300.0), 32, 32, format="svg"
+    )
+    assert b"<polyline" not in empty
+    ezdwg.render_tile(str(CIRCLE_SAMPLE), 0, 0, 0, format="svg")
+    assert tiles_module._cached_tile_index.cache_info().hits == hits + 1
+
+    with pytest.raises(ValueError, match="tile format"):
+        ezdwg.render_tile(str(CIRCLE_SAMPLE), 0, 0, 0, format="gif")
@@ -0,0 +1,102 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+import ezdwg
+from ezdwg import Entity
+
+
+def _line(handle: int, start, end) -> Entity:
+    return Entity("LINE", handle, {"start": (*start, 0.0), "end": (*end, 0.0)})
+
+
+def _plan() -> list[Entity]:
+    """Two rooms side by side sharing a wall, with two wall stubs and a gap.
+
+    The left room is 4 x 3 and the right one 2 x 3; the corner at (6, 3)
+    is drawn 0.001 apart.
+    """
+    return [
+        _line(1, (0, 0), (4, 0)),
+        _line(2, (4, 0), (6, 0)),
+        _line(3, (6, 0), (6, 3.001)),
+        _line(4, (6, 3), (4, 3)),
+        _line(5, (4, 3), (0, 3)),
+        _line(6, (0, 3), (0, 0)),
+        _line(7, (4, 0), (4, 3)),
+        # Stubs into the left room and out of its corner.
+        _line(8, (0, 0), (1, 1)),
+        _line(9, (0, 3), (-1, 4)),
+    ]
+
+
+def test_find_loops_returns_rooms_sharing_walls() -> None:
+    loops = ezdwg.find_loops(_plan(), tolerance=0.01)
+
+    assert [round(loop.area, 2) for loop in loops] == [12.0, 6.0]
+    assert [round(loop.perimeter, 2) for loop in loops] == [14.0, 10.0]
+    assert sorted(loops[1].handles) == [2, 3, 4, 7]
+    assert all(8 not in loop.handles and 9 not in loop.handles for loop in loops)
+    assert (6.0, 3.001) in loops[1].points
+    entity = loops[0].to_entity()
+    assert entity.dxftype == "LWPOLYLINE"
+    assert entity.dxf["closed"] is True
+    assert len(entity.dxf["points"]) == 4
+
+
+def test_connectivity_graph_reports_gaps_and_components() -> None:
+    graph = ezdwg.connectivity_graph(_plan())
+
+    # Without snapping, the corner at (6, 3) stays open and only the left
+    # room closes.
+    assert sorted(graph.nodes[node] for node in graph.dangling_nodes()) == [
+        (-1.0, 4.0),
+        (1.0, 1.0),
+        (6.0, 3.0),
+        (6.0, 3.001),
+    ]
+    assert len(graph.components()) == 1
+    assert graph.degree(graph.nodes.index((4.0, 0.0))) == 3
+    assert [round(loop.area, 6) for loop in graph.loops()] == [12.0]
+
+    with pytest.raises(ValueError, match="TEXT"):
+        ezdwg.connectivity_graph(_plan(), types="LINE TEXT")
+
+
+def test_find_loops_follows_arcs_and_bulges() -> None:
+    # A 2 x 2 slot: a square with a half circle on its right side, drawn as
+    # lines and an ARC, next to a closed LWPOLYLINE with a bulged side and
+    # an unconnected circle.
+    entities = [
+        _line(1, (0, 0), (2, 0)),
+        _line(2, (2, 2), (0, 2)),
+        _line(3, (0, 2), (0, 0)),
+        Entity(
+            "ARC",
+            4,
+            {"center": (2.0, 1.0, 0.0), "radius": 1.0, "start_angle": 270.0, "end_angle": 90.0},
+        ),
+        Entity(
+            "LWPOLYLINE",
+            5,
+            {
+                "points": [(10.0, 0.0), (12.0, 0.0), (12.0, 2.0), (10.0, 2.0)],
+                "bulges": [0.0, 0.0, 0.0, 1.0],
+                "closed": True,
+            },
+        ),
+        Entity("CIRCLE", 6, {"center": (20.0, 0.0, 0.0), "radius": 2.0}),
+    ]
+
+    loops = ezdwg.find_loops(entities)
+
+    assert [loop.handles[0] for loop in loops] == [6, 1, 5]
+    assert loops[0].area == pytest.approx(4.0 * math.pi)
+    assert loops[0].perimeter == pytest.approx(4.0 * math.pi)
+    assert loops[1].area == pytest.approx(4.0 + math.pi / 2.0)
+    assert loops[1].perimeter == pytest.approx(6.0 + math.pi)
+    # The bulge on the left side bows outward, away from the square.
+    assert loops[2].area == pytest.approx(4.0 + math.pi / 2.0)
+    assert sorted(set(loops[1].handles)) == [1, 2, 3, 4]
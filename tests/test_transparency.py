@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import ezdwg
+import ezdwg.convert as convert_module
+import ezdwg.render as render_module
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+SAMPLE = ROOT / "test_dwg/acadsharp/sample_AC1032.dwg"
+R14_SAMPLE = ROOT / "test_dwg/line_R14.dwg"
+
+
+def test_decode_entity_transparency_reads_alpha_words() -> None:
+    rows = raw.decode_entity_transparency(str(SAMPLE))
+    values = {transparency for _handle, transparency in rows if transparency is not None}
+
+    assert 0x02000066 in values
+    assert all((value >> 24) in (0x01, 0x02) for value in values)
+
+
+def test_decode_layer_transparency_reads_eed_value() -> None:
+    rows = dict(raw.decode_layer_transparency(str(SAMPLE)))
+
+    assert rows[642] == 0x0200004C
+    assert sum(1 for value in rows.values() if value is not None) == 1
+
+
+def test_decode_layer_transparency_reads_r14_layers() -> None:
+    # R14 stores ObjSize after the extended data, not before the handle.
+    rows = raw.decode_layer_transparency(str(R14_SAMPLE))
+
+    assert rows == [(84, None)]
+
+
+def test_query_attaches_resolved_transparency() -> None:
+    doc = ezdwg.read(str(SAMPLE))
+    entities = [
+        entity
+        for entity in doc.modelspace().query()
+        if entity.dxf.get("resolved_transparency") is not None
+    ]
+
+    assert entities
+    assert any(entity.dxf["resolved_transparency"] == 0x02000066 for entity in entities)
+
+
+def test_apply_dwg_transparency_appends_alpha() -> None:
+    assert render_module._apply_dwg_transparency("#ff0000", {}) == "#ff0000"
+    assert (
+        render_module._apply_dwg_transparency(
+            "#ff0000", {"resolved_transparency": 0x02000066}
+        )
+        == "#ff000066"
+    )
+    assert (
+        render_module._apply_dwg_transparency(
+            "#ff0000", {"resolved_transparency": 0x01000000}
+        )
+        == "#ff0000"
+    )
+
+
+def test_entity_dxfattribs_carries_transparency() -> None:
+    attribs = convert_module._entity_dxfattribs({"transparency": 0x02000066})
+    assert attribs["transparency"] == 0x02000066
+
+    attribs = convert_module._entity_dxfattribs({"transparency": 0})
+    assert "transparency" not in attribs
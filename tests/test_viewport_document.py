@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import pytest
+
 import ezdwg.document as document_module
 
 
@@ -63,3 +65,71 @@ def test_query_none_can_include_viewport_when_present(monkeypatch) -> None:
     entities = list(doc.modelspace().query())
 
     assert [entity.dxftype for entity in entities] == ["VIEWPORT"]
+
+
+def test_query_applies_viewport_layer_overrides(monkeypatch) -> None:
+    _clear_document_caches()
+    document_module._layer_names_by_handle.cache_clear()
+    document_module._viewport_layer_overrides.cache_clear()
+
+    monkeypatch.setattr(
+        document_module.raw,
+        "list_object_headers_with_type",
+        lambda _path: [
+            (100, 0, 0, 0x22, "VIEWPORT", "Entity"),
+            (101, 0, 0, 0x22, "VIEWPORT", "Entity"),
+        ],
+    )
+    monkeypatch.setattr(
+        document_module.raw, "decode_viewport_entities", lambda _path: [(100,), (101,)]
+    )
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_entity_styles",
+        lambda _path: [(100, 256, None, 7), (101, 256, None, 8)],
+    )
+    monkeypatch.setattr(
+        document_module.raw, "decode_layer_colors", lambda _path: [(7, 5, None), (8, 1, None)]
+    )
+    monkeypatch.setattr(
+        document_module.raw, "decode_layer_names", lambda _path: [(7, "WALLS"), (8, "NOTES")]
+    )
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_viewport_frozen_layers",
+        lambda _path: [(100, [8]), (101, [])],
+    )
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_dictionaries",
+        lambda _path: [(50, 7, [("ADSK_XREC_LAYER_COLOR_OVR", 51)])],
+    )
+    # 0xC3000003 read back as a signed 32-bit value: ACI 3.
+    monkeypatch.setattr(
+        document_module.raw,
+        "decode_xrecords",
+        lambda _path: [
+            (
+                51,
+                50,
+                [(102, "{ADSK_LYR_COLOR_OVERRIDE"), (335, 100), (420, -0x3CFFFFFD), (102, "}")],
+            )
+        ],
+    )
+
+    doc = document_module.Document(path="dummy_viewport_overrides.dwg", version="AC1015")
+    overrides = doc.viewport_layer_overrides()
+    assert overrides[100] == document_module.ViewportLayerOverrides(
+        frozen=frozenset({8}), colors={7: (3, None)}
+    )
+    assert overrides[101] == document_module.ViewportLayerOverrides()
+
+    entities = list(doc.modelspace().query("VIEWPORT", viewport=100))
+    assert [entity.handle for entity in entities] == [100]
+    assert entities[0].dxf["resolved_color_index"] == 3
+
+    entities = list(doc.modelspace().query("VIEWPORT", viewport=101))
+    assert [entity.dxf["resolved_color_index"] for entity in entities] == [5, 1]
+
+    with pytest.raises(ValueError, match="viewport not found"):
+        list(doc.modelspace().query("VIEWPORT", viewport=102))
@@ -0,0 +1,39 @@
+from __future__ import annotations
+
+import math
+from pathlib import Path
+
+import pytest
+
+import ezdwg
+from ezdwg import raw
+
+
+ROOT = Path(__file__).resolve().parents[1]
+
+
+@pytest.mark.parametrize("name", ["sample_AC1027.dwg", "sample_AC1032.dwg"])
+def test_decode_views_reads_named_view(name: str) -> None:
+    rows = raw.decode_views(str(ROOT / "test_dwg/acadsharp" / name))
+
+    assert len(rows) == 1
+    _handle, view_name, (width, height), center, target, direction, *_rest = rows[0]
+    assert view_name == "view_custom"
+    assert (width, height) == pytest.approx((234.1224, 106.9159))
+    assert center == pytest.approx((65.6976, 15.9301))
+    assert (target, direction) == ((0.0, 0.0, 0.0), (0.0, 0.0, 1.0))
+
+
+def test_document_views_wraps_rows() -> None:
+    views = ezdwg.read(str(ROOT / "test_dwg/acadsharp/sample_AC1032.dwg")).views()
+
+    assert [view.name for view in views] == ["view_custom"]
+    view = views[0]
+    assert view.camera == (0.0, 0.0, 1.0)
+    assert view.lens_length == 50.0
+    assert view.field_of_view == pytest.approx(2 * math.atan(18 / 50))
+    assert not view.is_paper_space
+
+
+def test_document_views_are_empty_without_view_table_entries() -> None:
+    assert ezdwg.read(str(ROOT / "test_dwg/line_2000.dwg")).views() == []
@@ -1,11 +1,13 @@
 from __future__ import annotations
 
+import warnings
 from pathlib import Path
 
 import pytest
 
 import ezdwg
 import ezdwg.cli as cli_module
+import ezdwg.convert as convert_module
 import ezdwg.document as document_module
 
 
@@ -60,6 +62,159 @@ def test_raw_write_ac1015_dwg_writes_lwpolyline(tmp_path: Path) -> None:
     assert polys[0].dxf["points"] == [(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 1.0, 0.0)]
 
 
+def test_raw_write_ac1015_dwg_splits_long_lwpolyline(tmp_path: Path) -> None:
+    points = [(float(i), float(i % 2)) for i in range(10)]
+    rows = [(0x42, 1, points, [], [], None)]
+    output = tmp_path / "raw_lwpolyline_split_out.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(output), [], [], [], rows, [], [], [], max_lwpolyline_vertices=4
+    )
+
+    polys = list(ezdwg.read(str(output)).modelspace().query("LWPOLYLINE"))
+    assert len(polys) == 4
+    # The first piece keeps the row handle; the others are allocated in order.
+    polys.sort(key=lambda poly: (poly.handle != 0x42, poly.handle))
+    assert polys[0].handle == 0x42
+    assert all(not poly.dxf["closed"] for poly in polys)
+    assert all(len(poly.dxf["points"]) <= 4 for poly in polys)
+    traced = [point[:2] for point in polys[0].dxf["points"]]
+    for poly in polys[1:]:
+        assert poly.dxf["points"][0][:2] == traced[-1]
+        traced.extend(point[:2] for point in poly.dxf["points"][1:])
+    assert traced == points + points[:1]
+
+    unsplit = tmp_path / "raw_lwpolyline_unsplit_out.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(unsplit), [], [], [], rows, [], [], [], max_lwpolyline_vertices=None
+    )
+    polys = list(ezdwg.read(str(unsplit)).modelspace().query("LWPOLYLINE"))
+    assert len(polys) == 1
+    assert polys[0].dxf["closed"] is True
+
+
+def test_raw_write_ac1015_dwg_fits_arcs_to_dense_lwpolylines(tmp_path: Path) -> None:
+    import math
+
+    half_circle = [
+        (2.0 + math.cos(math.pi * i / 32), 1.0 + math.sin(math.pi * i / 32)) for i in range(33)
+    ]
+    # An upper half circle followed by a lower one turning the other way.
+    wave = half_circle + [(x - 4.0, 2.0 - y) for x, y in half_circle]
+    rows = [(0x42, 0, half_circle, [], [], None), (0x43, 1, wave, [], [], None)]
+    output = tmp_path / "raw_arc_fit_out.dwg"
+    ezdwg.raw.write_ac1015_dwg(str(output), [], [], [], rows, [], [], [], arc_fit_tolerance=0.01)
+
+    msp = ezdwg.read(str(output)).modelspace()
+    arcs = list(msp.query("ARC"))
+    assert [arc.handle for arc in arcs] == [0x42]
+    assert arcs[0].dxf["center"] == pytest.approx((2.0, 1.0, 0.0))
+    assert arcs[0].dxf["radius"] == pytest.approx(1.0)
+    assert arcs[0].dxf["start_angle"] == pytest.approx(0.0, abs=1e-6)
+    assert arcs[0].dxf["end_angle"] == pytest.approx(180.0)
+    polys = list(msp.query("LWPOLYLINE"))
+    assert [poly.handle for poly in polys] == [0x43]
+    assert len(polys[0].dxf["points"]) == 4
+    assert polys[0].dxf["bulges"] == pytest.approx([1.0, 0.0, -1.0, 0.0])
+    assert polys[0].dxf["closed"] is True
+
+
+def test_raw_write_ac1015_dwg_encodes_entities_on_worker_threads(tmp_path: Path) -> None:
+    lines = [(0x100 + i, float(i), 0.0, 0.0, float(i), 1.0, 0.0) for i in range(5000)]
+    times = ((2460000, 0), (2460000, 0), (0, 0), (0, 0))
+    outputs = []
+    for threads in (1, 4):
+        output = tmp_path / f"raw_lines_{threads}.dwg"
+        ezdwg.raw.write_ac1015_dwg(
+            str(output), lines, [], [], [], [], [], drawing_times=times, encode_threads=threads
+        )
+        outputs.append(output.read_bytes())
+
+    assert outputs[0] == outputs[1]
+    written = ezdwg.read(str(tmp_path / "raw_lines_4.dwg")).modelspace().query("LINE")
+    assert len(list(written)) == 5000
+    with pytest.raises(ValueError, match="encode_threads"):
+        ezdwg.raw.write_ac1015_dwg(
+            str(tmp_path / "bad.dwg"), [], [], [], [], [], [], encode_threads=0
+        )
+
+
+def test_raw_write_ac1015_dwg_allocates_handles_by_namespace(tmp_path: Path) -> None:
+    lines = [(0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0), (0x12, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0)]
+    layers = [("WALLS", 3)]
+    output = tmp_path / "raw_namespaces.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(output), lines, [], [], [], [], [], layers=layers, handle_namespaces=True
+    )
+
+    handles = sorted(line.handle for line in ezdwg.read(str(output)).modelspace().query("LINE"))
+    assert handles == [0x12, 0x10000]
+    layer_handles = [row[0] for row in ezdwg.raw.decode_layer_colors(str(output))]
+    assert 0x10 in layer_handles
+
+    with pytest.raises(ValueError, match="fixed handles"):
+        ezdwg.raw.write_ac1015_dwg(
+            str(tmp_path / "bad.dwg"),
+            [(0x05, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0)],
+            [],
+            [],
+            [],
+            [],
+            [],
+            handle_namespaces=True,
+        )
+
+
+def test_raw_write_ac1015_dwg_allocates_handles_for_none(tmp_path: Path) -> None:
+    lines = [(None, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0), (0x40, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0)]
+    texts = [(None, "A", (0.0, 2.0, 0.0), 1.0, 0.0), (None, "B", (0.0, 4.0, 0.0), 1.0, 0.0)]
+    output = tmp_path / "raw_none_handles.dwg"
+    ezdwg.raw.write_ac1015_dwg(str(output), lines, [], [], [], texts, [])
+
+    msp = ezdwg.read(str(output)).modelspace()
+    handles = [entity.handle for entity in msp.query("LINE TEXT")]
+    assert len(handles) == 4
+    assert 0x40 in handles
+    assert 0 not in handles
+    assert len(set(handles)) == 4
+
+
+def test_raw_write_ac1015_dwg_deprecates_handle_zero(tmp_path: Path) -> None:
+    lines = [(0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0), (0x40, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0)]
+    output = tmp_path / "raw_zero_handle.dwg"
+    with pytest.warns(DeprecationWarning, match="handle 0"):
+        ezdwg.raw.write_ac1015_dwg(str(output), lines, [], [], [], [], [])
+
+    handles = [entity.handle for entity in ezdwg.read(str(output)).modelspace().query("LINE")]
+    assert len(handles) == 2
+    assert 0x40 in handles
+    assert 0 not in handles
+
+
+def test_dwg_write_rows_use_none_for_handle_zero_entities(tmp_path: Path) -> None:
+    entities = [
+        ezdwg.Entity("LINE", 0, {"start": (0.0, 0.0, 0.0), "end": (1.0, 0.0, 0.0)}),
+        ezdwg.Entity("CIRCLE", 0, {"center": (0.0, 0.0, 0.0), "radius": 1.0}),
+    ]
+    rows, _total, written, _skipped = convert_module._collect_dwg_write_rows(entities)
+    assert written == 2
+    assert rows["LINE"][0][0] is None
+    assert rows["CIRCLE"][0][0] is None
+    assert convert_module._dwg_row_handles(rows) == set()
+    assert convert_module._dwg_write_color_rows(entities, rows, {}) == []
+
+    output = tmp_path / "zero_handle_entities.dwg"
+    with warnings.catch_warnings():
+        warnings.simplefilter("error", DeprecationWarning)
+        ezdwg.raw.write_ac1015_dwg(
+            str(output),
+            *convert_module._dwg_write_row_args(rows),
+            **convert_module._dwg_write_row_kwargs(rows),
+        )
+    handles = [entity.handle for entity in ezdwg.read(str(output)).modelspace().query()]
+    assert len(handles) == 2
+    assert 0 not in handles
+
+
 def test_raw_write_ac1015_dwg_writes_ray_and_xline(tmp_path: Path) -> None:
     output = tmp_path / "raw_ray_xline_out.dwg"
     ezdwg.raw.write_ac1015_dwg(
@@ -235,6 +390,523 @@ def test_to_dwg_writes_text_from_source_sample(tmp_path: Path) -> None:
     assert abs(float(out_text.dxf["rotation"]) - float(src_text.dxf["rotation"])) < 1.0e-9
 
 
+def test_text_alignment_round_trips_through_raw_write_and_to_dwg(tmp_path: Path) -> None:
+    import math
+
+    texts = [
+        (0x50, "FIT", (1.0, 2.0, 0.0), 0.5, 0.0),
+        (0x51, "MIDDLE", (4.0, 4.0, 0.0), 0.5, 0.0),
+        (0x52, "PLAIN", (0.0, 0.0, 0.0), 0.5, 0.0),
+    ]
+    alignments = [
+        (0x50, (6.0, 2.0, 0.0), 5, 0, 1.0, 0.0),
+        (0x51, None, 4, 0, 0.8, math.radians(15.0)),
+    ]
+    first = tmp_path / "raw_text_alignment_out.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(first), [], [], [], [], texts, [], [], text_alignments=alignments
+    )
+    second = tmp_path / "to_dwg_text_alignment_out.dwg"
+    ezdwg.to_dwg(str(first), str(second))
+
+    for path in (first, second):
+        by_handle = {
+            text.handle: text.dxf for text in ezdwg.read(str(path)).modelspace().query("TEXT")
+        }
+        assert (by_handle[0x50]["halign"], by_handle[0x50]["valign"]) == (5, 0)
+        assert by_handle[0x50]["align_point"] == (6.0, 2.0, 0.0)
+        assert by_handle[0x51]["halign"] == 4
+        assert by_handle[0x51]["align_point"] == (4.0, 4.0, 0.0)
+        assert by_handle[0x51]["width"] == pytest.approx(0.8)
+        assert by_handle[0x51]["oblique"] == pytest.approx(15.0)
+        assert by_handle[0x52]["halign"] == 0
+        assert by_handle[0x52]["align_point"] is None
+
+
+def test_tolerance_and_leader_round_trip_through_raw_write_and_to_dwg(tmp_path: Path) -> None:
+    frame = "{\\Fgdt;j}%%v0.05%%vA"
+    points = [(0.0, 0.0, 0.0), (2.0, 1.0, 0.0), (3.0, 1.0, 0.0)]
+    first = tmp_path / "raw_tolerance_leader_out.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(first),
+        [],
+        [],
+        [],
+        [],
+        [],
+        [],
+        tolerances=[(0x60, frame, (3.0, 1.0, 0.0), (0.0, 1.0, 0.0), 0.18, 0.09)],
+        leaders=[(0x61, 1, 0, points)],
+    )
+    second = tmp_path / "to_dwg_tolerance_leader_out.dwg"
+    result = ezdwg.to_dwg(str(first), str(second), strict=True)
+    assert result.written_entities == 2
+
+    for path in (first, second):
+        layout = ezdwg.read(str(path)).modelspace()
+        (tolerance,) = layout.query("TOLERANCE")
+        assert tolerance.handle == 0x60
+        assert tolerance.dxf["text"] == frame
+        assert tolerance.dxf["insert"] == (3.0, 1.0, 0.0)
+        assert tolerance.dxf["rotation"] == pytest.approx(90.0)
+        assert (tolerance.dxf["height"], tolerance.dxf["dimgap"]) == (0.18, 0.09)
+        (leader,) = layout.query("LEADER")
+        assert leader.handle == 0x61
+        assert (leader.dxf["annotation_type"], leader.dxf["path_type"]) == (1, 0)
+        assert leader.dxf["points"] == points
+
+
+def test_raw_write_ac1015_dwg_writes_mlines(tmp_path: Path) -> None:
+    output = tmp_path / "raw_mline_out.dwg"
+    wall = [(0.0, 0.0, 0.0), (6.0, 0.0, 0.0), (6.0, 4.0, 0.0)]
+    ezdwg.raw.write_ac1015_dwg(
+        str(output),
+        [],
+        [],
+        [],
+        [],
+        [],
+        [],
+        mlines=[
+            (0x70, "WALL", 1.0, 1, True, wall),
+            (0x71, "STANDARD", 2.0, 0, False, wall[:2]),
+        ],
+        mline_styles=[("WALL", "", [(0.2, 1), (0.0, 8), (-0.2, 1)])],
+    )
+
+    by_handle = {
+        entity.handle: entity.dxf
+        for entity in ezdwg.read(str(output)).modelspace().query("MLINE")
+    }
+    assert by_handle[0x70]["points"] == wall
+    assert by_handle[0x70]["closed"] is True
+    assert by_handle[0x70]["line_count"] == 3
+    assert by_handle[0x70]["justification"] == 1
+    assert by_handle[0x70]["vertex_directions"][1] == (0.0, 1.0, 0.0)
+    assert by_handle[0x71]["line_count"] == 2
+    assert by_handle[0x71]["scale"] == 2.0
+    assert by_handle[0x70]["mlinestyle_handle"] != by_handle[0x71]["mlinestyle_handle"]
+
+
+def test_to_dwg_copies_unknown_objects_from_ac1015_sources(monkeypatch, tmp_path: Path) -> None:
+    source = SAMPLES / "line_2000.dwg"
+    # The sample only holds classes AutoCAD registers itself.
+    assert ezdwg.raw.list_unknown_objects(str(source)) == []
+    with pytest.raises(Exception):
+        ezdwg.raw.list_unknown_objects(str(SAMPLES / "line_2004.dwg"))
+
+    sources = []
+    write = ezdwg.raw.write_ac1015_dwg
+
+    def capture(*args, **kwargs):
+        sources.append(kwargs["unknown_objects_from"])
+        return write(*args, **kwargs)
+
+    monkeypatch.setattr(ezdwg.raw, "write_ac1015_dwg", capture)
+    output = tmp_path / "line_2000_unknown.dwg"
+    ezdwg.to_dwg(str(source), str(output))
+    ezdwg.to_dwg(str(source), str(output), preserve_unknown_objects=False)
+    ezdwg.to_dwg(str(SAMPLES / "line_2004.dwg"), str(output))
+    assert sources == [str(source), None, None]
+    assert len(list(ezdwg.read(str(output)).modelspace().query("LINE"))) == 1
+
+
+def test_to_dwg_keeps_header_variables_of_ac1015_sources(tmp_path: Path) -> None:
+    source = str(SAMPLES / "line_2000.dwg")
+    kept = tmp_path / "line_2000_header.dwg"
+    fresh = tmp_path / "line_2000_fresh.dwg"
+    times = ezdwg.read(source).drawing_times()
+    ezdwg.to_dwg(source, str(kept), drawing_times=times)
+    ezdwg.to_dwg(source, str(fresh), drawing_times=times, preserve_header_variables=False)
+
+    kept_header = ezdwg.raw.read_section_bytes(str(kept), 0)
+    fresh_header = ezdwg.raw.read_section_bytes(str(fresh), 0)
+    # The settings of the source replace the defaults of a new drawing.
+    assert len(kept_header) > len(fresh_header)
+    assert ezdwg.read(str(kept)).drawing_times() == times
+    assert len(list(ezdwg.read(str(kept)).modelspace().query("LINE"))) == 1
+
+
+def test_layer_operations_rewrite_layers_and_entity_layers(tmp_path: Path) -> None:
+    source = str(SAMPLES / "line_2000.dwg")
+    line = next(ezdwg.read(source).modelspace().query("LINE"))
+    moved = tmp_path / "line_moved.dwg"
+    colored = tmp_path / "line_colored.dwg"
+    renamed = tmp_path / "line_renamed.dwg"
+
+    ezdwg.move_entities_to_layer(source, str(moved), [line.handle], "Walls")
+    layers = {name: handle for handle, name in ezdwg.raw.decode_layer_names(str(moved))}
+    assert set(layers) == {"0", "Walls"}
+    written = next(ezdwg.read(str(moved)).modelspace().query("LINE"))
+    assert written.dxf["layer_handle"] == layers["Walls"]
+    assert written.dxf["color_index"] == 256
+
+    ezdwg.set_layer_color(str(moved), str(colored), "walls", 3)
+    names = dict(ezdwg.raw.decode_layer_names(str(colored)))
+    colors = {
+        names[handle]: index for handle, index, _ in ezdwg.raw.decode_layer_colors(str(colored))
+    }
+    assert colors == {"0": 7, "Walls": 3}
+
+    ezdwg.rename_layer(str(colored), str(renamed), "Walls", "Partitions")
+    names = dict(ezdwg.raw.decode_layer_names(str(renamed)))
+    assert sorted(names.values()) == ["0", "Partitions"]
+    written = next(ezdwg.read(str(renamed)).modelspace().query("LINE"))
+    assert names[written.dxf["layer_handle"]] == "Partitions"
+
+    with pytest.raises(ValueError, match="cannot be renamed"):
+        ezdwg.rename_layer(source, str(renamed), "0", "Zero")
+    with pytest.raises(ValueError, match="not found"):
+        ezdwg.rename_layer(source, str(renamed), "Missing", "Other")
+    with pytest.raises(ValueError, match="already exists"):
+        ezdwg.rename_layer(str(moved), str(renamed), "Walls", "0")
+    with pytest.raises(ValueError, match="1..255"):
+        ezdwg.set_layer_color(source, str(renamed), "0", 256)
+    with pytest.raises(ValueError, match="invalid layer name"):
+        ezdwg.move_entities_to_layer(source, str(renamed), [line.handle], "a<b")
+
+
+def test_layer_operations_keep_layer_states(tmp_path: Path) -> None:
+    source = tmp_path / "layer_states_source.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(source),
+        [(0x30, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0), (0x31, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0)],
+        [],
+        [],
+        [],
+        [],
+        [],
+        layers=[("Hidden", 2), ("Frozen", 3), ("Locked", 4)],
+        layer_states=[("Hidden", 0, True), ("Frozen", 1, False), ("Locked", 4, False)],
+        entity_layers=[(0x30, "Hidden"), (0x31, "Locked")],
+    )
+
+    def states(path: Path) -> dict[str, tuple[int, int, bool]]:
+        names = dict(ezdwg.raw.decode_layer_names(str(path)))
+        colors = {handle: index for handle, index, _ in ezdwg.raw.decode_layer_colors(str(path))}
+        return {
+            names[handle]: (colors[handle], flags, is_off)
+            for handle, flags, is_off in ezdwg.raw.decode_layer_states(str(path))
+        }
+
+    assert states(source) == {
+        "0": (7, 0, False),
+        "Hidden": (2, 0, True),
+        "Frozen": (3, 1, False),
+        "Locked": (4, 4, False),
+    }
+
+    output = tmp_path / "layer_states_edited.dwg"
+    ezdwg.to_dwg(
+        str(source),
+        str(output),
+        layer_renames={"Hidden": "Off"},
+        layer_colors={"Frozen": 5},
+        entity_layers={0x31: "Frozen"},
+    )
+    assert states(output) == {
+        "0": (7, 0, False),
+        "Off": (2, 0, True),
+        "Frozen": (5, 1, False),
+        "Locked": (4, 4, False),
+    }
+
+
+def test_decode_layer_states_reads_r14_to_r2004_layers() -> None:
+    for version in ("R14", "2000", "2004"):
+        assert ezdwg.raw.decode_layer_states(str(SAMPLES / f"line_{version}.dwg")) == [
+            (84, 0, False)
+        ]
+    assert ezdwg.raw.decode_layer_states(str(SAMPLES / "line_2010.dwg")) == []
+
+
+def test_update_entities_applies_changes_to_selected_entities(tmp_path: Path) -> None:
+    source = str(SAMPLES / "line_2004.dwg")
+    line = next(ezdwg.read(source).modelspace().query("LINE"))
+    output = tmp_path / "line_updated.dwg"
+
+    result = ezdwg.update_entities(
+        source,
+        str(output),
+        types="LINE",
+        layers="0",
+        handles=[line.handle, 0xFFFFF],
+        color=1,
+        layer="Red",
+        linetype="Continuous",
+        lineweight=50,
+    )
+    assert result.updated == [line.handle]
+    assert result.failed == {0xFFFFF: "no writable entity with this handle"}
+    assert result.write.written_entities == 1
+
+    written = next(ezdwg.read(str(output)).modelspace().query("LINE"))
+    names = dict(ezdwg.raw.decode_layer_names(str(output)))
+    assert written.dxf["color_index"] == 1
+    assert names[written.dxf["layer_handle"]] == "Red"
+    assert ezdwg.raw.decode_entity_line_styles(str(output)) == [(line.handle, 2, None, 1.0, 50)]
+
+    unchanged = tmp_path / "line_unchanged.dwg"
+    untouched = ezdwg.update_entities(source, str(unchanged), layers="Other", color=3)
+    assert untouched.updated == []
+    written = next(ezdwg.read(str(unchanged)).modelspace().query("LINE"))
+    assert written.dxf["color_index"] == 256
+
+    with pytest.raises(ValueError, match="no property changes"):
+        ezdwg.update_entities(source, str(output), types="LINE")
+    with pytest.raises(ValueError, match="linetype not found"):
+        ezdwg.update_entities(source, str(output), linetype="DASHED")
+    with pytest.raises(ValueError, match="invalid lineweight"):
+        ezdwg.update_entities(source, str(output), lineweight=17)
+    with pytest.raises(ValueError, match="0..256"):
+        ezdwg.update_entities(source, str(output), color=300)
+
+
+def test_transform_drawing_rotates_scales_and_moves_geometry(tmp_path: Path) -> None:
+    # A quarter turn with scale 2, then a shift to the new origin.
+    matrix = [[0.0, -2.0, 1000.0], [2.0, 0.0, 500.0]]
+    arc_output = tmp_path / "arc_transformed.dwg"
+    result = ezdwg.transform_drawing(str(SAMPLES / "arc_2004.dwg"), matrix, str(arc_output))
+    assert result.written_entities == 1
+
+    arc = next(ezdwg.read(str(arc_output)).modelspace().query("ARC"))
+    assert arc.dxf["center"] == pytest.approx((900.0, 650.0, 0.0))
+    assert arc.dxf["radius"] == pytest.approx(50.0)
+    assert arc.dxf["start_angle"] == pytest.approx(90.0)
+    assert arc.dxf["end_angle"] == pytest.approx(270.0)
+
+    text_output = tmp_path / "text_transformed.dwg"
+    ezdwg.transform_drawing(
+        str(SAMPLES / "text_2000.dwg"),
+        [[0.0, -2.0, 0.0, 1000.0], [2.0, 0.0, 0.0, 500.0], [0.0, 0.0, 2.0, 0.0]],
+        str(text_output),
+    )
+    text = next(ezdwg.read(str(text_output)).modelspace().query("TEXT"))
+    assert text.dxf["insert"] == pytest.approx((900.0, 600.0, 0.0))
+    assert text.dxf["height"] == pytest.approx(10.0)
+    assert text.dxf["rotation"] == pytest.approx(90.0)
+
+    with pytest.raises(ValueError, match="skew"):
+        ezdwg.transform_drawing(
+            str(SAMPLES / "arc_2004.dwg"), [[1.0, 0.5, 0.0], [0.0, 1.0, 0.0]], str(arc_output)
+        )
+    with pytest.raises(ValueError, match="mirroring"):
+        ezdwg.transform_drawing(
+            str(SAMPLES / "arc_2004.dwg"), [[1.0, 0.0, 0.0], [0.0, -1.0, 0.0]], str(arc_output)
+        )
+    with pytest.raises(ValueError, match="2x3, 3x3, 3x4 or 4x4"):
+        ezdwg.transform_drawing(str(SAMPLES / "arc_2004.dwg"), [[1.0, 0.0]], str(arc_output))
+
+
+def test_purge_leaves_out_unused_layers_and_linetypes(tmp_path: Path) -> None:
+    source = tmp_path / "purge_source.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(source),
+        [(0x30, 0.0, 0.0, 0.0, 10.0, 5.0, 0.0)],
+        [],
+        [],
+        [],
+        [],
+        [],
+        layers=[("Walls", 1), ("Spare", 3)],
+        entity_layers=[(0x30, "Walls")],
+        linetypes=[("DASHED", "Dashed __ __", [0.5, -0.25])],
+    )
+
+    purged_output = tmp_path / "purged.dwg"
+    result = ezdwg.purge(str(source), str(purged_output))
+    assert result.purged == {"layers": ["Spare"], "linetypes": ["DASHED"]}
+    assert result.write.written_entities == 1
+    layer_names = [name for _handle, name in ezdwg.raw.decode_layer_names(str(purged_output))]
+    assert layer_names == ["0", "Walls"]
+    assert ezdwg.raw.decode_linetypes(str(purged_output)) == []
+
+    kept_output = tmp_path / "purged_layers_only.dwg"
+    kept = ezdwg.purge(str(source), str(kept_output), what="layers")
+    assert kept.purged == {"layers": ["Spare"]}
+    layer_names = [name for _handle, name in ezdwg.raw.decode_layer_names(str(kept_output))]
+    assert layer_names == ["0", "Walls"]
+    assert [row[1] for row in ezdwg.raw.decode_linetypes(str(kept_output))] == ["DASHED"]
+
+    # Every rewrite drops all blocks and text styles, so they cannot be purged.
+    for table in ("blocks", "styles"):
+        with pytest.raises(ValueError, match=f"cannot purge {table}"):
+            ezdwg.purge(str(source), str(kept_output), what=["layers", table])
+    with pytest.raises(ValueError, match="unknown table to purge"):
+        ezdwg.purge(str(source), str(kept_output), what=["views"])
+
+
+def test_redefine_block_replaces_contents_from_another_drawing(tmp_path: Path) -> None:
+    source = str(SAMPLES / "line_2000.dwg")
+    blocks = dict(
+        (name, handle) for handle, name in ezdwg.raw.decode_table_entry_names(source, "BLOCK")
+    )
+    output = tmp_path / "redefined_paper_space.dwg"
+    result = ezdwg.redefine_block(
+        source,
+        "*Paper_Space",
+        str(SAMPLES / "insert_2004.dwg"),
+        str(output),
+        source_block="BLK1",
+    )
+    assert result.written_entities == 1
+    children = ezdwg.raw.iter_block_children(str(output), blocks["*Paper_Space"])
+    assert len(children) == 1
+    block_line = next(
+        line
+        for line in ezdwg.read(str(SAMPLES / "insert_2004.dwg")).modelspace().query("LINE")
+        if line.handle == 42
+    )
+    lines = {line.handle: line for line in ezdwg.read(str(output)).modelspace().query("LINE")}
+    assert lines[children[0]].dxf["start"] == block_line.dxf["start"]
+    assert lines[children[0]].dxf["end"] == block_line.dxf["end"]
+
+    model_output = tmp_path / "redefined_model_space.dwg"
+    circles = ezdwg.read(str(SAMPLES / "circle_2004.dwg")).modelspace().query("CIRCLE")
+    ezdwg.redefine_block(source, "*Model_Space", circles, str(model_output))
+    doc = ezdwg.read(str(model_output))
+    assert list(doc.modelspace().query("LINE")) == []
+    assert len(list(doc.modelspace().query("CIRCLE"))) == 1
+
+    with pytest.raises(ValueError, match="block not found"):
+        ezdwg.redefine_block(source, "NO_SUCH_BLOCK", [], str(model_output))
+
+
+def test_strip_layers_drops_entities_and_keeps_other_records(tmp_path: Path) -> None:
+    source = tmp_path / "survey_source.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(source),
+        [
+            (0x30, 0.0, 0.0, 0.0, 10.0, 0.0, 0.0),
+            (0x31, 0.0, 1.0, 0.0, 10.0, 1.0, 0.0),
+            (0x32, 0.0, 2.0, 0.0, 10.0, 2.0, 0.0),
+        ],
+        [],
+        [(0x33, 5.0, 5.0, 0.0, 1.0)],
+        [],
+        [],
+        [],
+        layers=[("Walls", 1), ("Survey", 3)],
+        entity_layers=[(0x30, "Walls"), (0x31, "Survey"), (0x33, "SURVEY")],
+    )
+
+    output = tmp_path / "survey_stripped.dwg"
+    result = ezdwg.strip_layers(str(source), "survey", str(output))
+    assert (result.total_entities, result.written_entities) == (2, 2)
+    modelspace = ezdwg.read(str(output)).modelspace()
+    assert sorted(entity.handle for entity in modelspace.query()) == [0x30, 0x32]
+    original = source.read_bytes()
+    stripped = output.read_bytes()
+    # Only the section locators change in place; the rest is appended.
+    assert stripped[: len(original)][0x80:] == original[0x80:]
+    layer_names = [name for _handle, name in ezdwg.raw.decode_layer_names(str(output))]
+    assert "Survey" in layer_names
+
+    model = ezdwg.strip_layers(str(SAMPLES / "line_2000.dwg"), ["0"], str(output))
+    assert model.written_entities == 1
+    assert list(ezdwg.read(str(output)).modelspace().query("LINE")) == []
+
+    with pytest.raises(ValueError, match="layer not found"):
+        ezdwg.strip_layers(str(source), ["Trees"], str(output))
+    with pytest.raises(ValueError, match="not supported"):
+        ezdwg.strip_layers(str(SAMPLES / "line_2004.dwg"), ["0"], str(output))
+
+
+def test_raw_write_ac1015_dwg_writes_polyface_meshes(tmp_path: Path) -> None:
+    output = tmp_path / "raw_polyface_out.dwg"
+    vertices = [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0), (0.5, 0.5, 1.0)]
+    faces = [(1, 2, 5, 0), (2, 3, 5, 0), (3, 4, -5, 1)]
+    ezdwg.raw.write_ac1015_dwg(
+        str(output), [], [], [], [], [], [], polyfaces=[(0x70, vertices, faces)]
+    )
+
+    (pface,) = ezdwg.read(str(output)).modelspace().query("POLYLINE_PFACE")
+    assert pface.handle == 0x70
+    assert (pface.dxf["num_vertices"], pface.dxf["num_faces"]) == (5, 3)
+    assert pface.dxf["vertices"] == vertices
+    assert pface.dxf["faces"] == faces
+    # The hidden edge runs from the third corner.
+    assert pface.dxf["face_invisible_edge_flags"] == [0, 0, 4]
+    assert len(pface.dxf["vertex_handles"]) == 5
+    assert len(pface.dxf["face_handles"]) == 3
+    assert pface.dxf["seqend_handle"] == pface.dxf["face_handles"][-1] + 1
+
+    with pytest.raises(ValueError, match="out of range"):
+        ezdwg.raw.write_ac1015_dwg(
+            str(output), [], [], [], [], [], [], polyfaces=[(0x70, vertices, [(1, 2, 6, 0)])]
+        )
+
+
+def test_convert_version_saves_true_colors_as_nearest_aci(tmp_path: Path) -> None:
+    output = tmp_path / "sample_r2000.dwg"
+    result = ezdwg.convert_version(
+        str(SAMPLES / "acadsharp" / "sample_AC1027.dwg"), "r2000", str(output)
+    )
+
+    assert result.source_version == "AC1027"
+    assert result.write.target_version == "AC1015"
+    assert result.downgrades == {"true_color": 2, "mesh": 2, "multileader": 15}
+    doc = ezdwg.read(str(output))
+    assert doc.version == "AC1015"
+    colors = {entity.handle: entity.dxf["color_index"] for entity in doc.modelspace().query()}
+    # LINE 0xCD7 is true color (8, 0, 160).
+    assert colors[0xCD7] == ezdwg.AciPalette.default().nearest((8, 0, 160)) == 174
+
+    upgraded = ezdwg.convert_version(str(SAMPLES / "line_R14.dwg"), "AC1015", str(output))
+    assert (upgraded.source_version, upgraded.downgrades) == ("AC1014", {})
+    assert len(list(ezdwg.read(str(output)).modelspace().query("LINE"))) == 1
+
+    with pytest.raises(ValueError, match="unsupported DWG write version"):
+        ezdwg.convert_version(str(SAMPLES / "line_2000.dwg"), "AC1018", str(output))
+
+
+def test_convert_version_translates_meshes_and_multileaders(tmp_path: Path) -> None:
+    source = str(SAMPLES / "acadsharp" / "sample_AC1032.dwg")
+    output = tmp_path / "sample_r2000.dwg"
+    result = ezdwg.convert_version(source, "AC1015", str(output))
+
+    assert result.downgrades == {"true_color": 2, "mesh": 2, "multileader": 15}
+    assert result.write.skipped_by_type == {}
+    source_layout = ezdwg.read(source).modelspace()
+    layout = ezdwg.read(str(output)).modelspace()
+    pfaces = {entity.handle: entity for entity in layout.query("POLYLINE_PFACE")}
+    for mesh in source_layout.query("MESH"):
+        pface = pfaces[mesh.handle]
+        assert pface.dxf["vertices"] == mesh.dxf["vertices"]
+        corners = [[abs(index) - 1 for index in face if index] for face in pface.dxf["faces"]]
+        assert corners == [list(face) for face in mesh.dxf["faces"]]
+
+    # MULTILEADER 0x818 has one leader line with an 8 unit landing.
+    (leader,) = [entity for entity in layout.query("LEADER") if entity.handle == 0x818]
+    assert (leader.dxf["annotation_type"], leader.dxf["path_type"]) == (0, 0)
+    assert leader.dxf["points"] == [
+        (78.99934202472878, -524.376343379853, 0.0),
+        (168.99934202472878, -434.376343379853, 0.0),
+        (176.99934202472878, -434.376343379853, 0.0),
+    ]
+    texts = [entity for entity in layout.query("MTEXT") if entity.dxf["text"] == "MULTILEADER TEST"]
+    assert len(texts) == 4
+    assert texts[0].handle not in {entity.handle for entity in source_layout.query()}
+    assert texts[0].dxf["insert"] == (178.99934202472878, -432.3422369678475, 0.0)
+    assert texts[0].dxf["char_height"] == 4.0
+    assert len(list(layout.query("MULTILEADER MESH"))) == 0
+
+
+def test_remap_object_record_handles_rewrites_only_handles() -> None:
+    source = str(SAMPLES / "line_2000.dwg")
+    line = next(ezdwg.read(source).modelspace().query("LINE"))
+    rows = ezdwg.raw.read_object_records_by_handle(source, list(range(1, 0x300)))
+    for _handle, _offset, _size, _type_code, record in rows:
+        assert ezdwg.raw.remap_object_record_handles(bytes(record), {}) == bytes(record)
+
+    record = bytes(ezdwg.raw.read_object_records_by_handle(source, [line.handle])[0][4])
+    remap = {line.handle: 0x4000}
+    moved = ezdwg.raw.remap_object_record_handles(record, remap)
+    assert moved != record
+    back = {new: old for old, new in remap.items()}
+    assert ezdwg.raw.remap_object_record_handles(moved, back) == record
+
+
 def test_to_dwg_writes_mtext_from_source_sample(tmp_path: Path) -> None:
     source = SAMPLES / "mtext_2000.dwg"
     output = tmp_path / "mtext_2000_written.dwg"
@@ -319,6 +991,7 @@ def test_cli_main_dispatches_write_command(monkeypatch) -> None:
         types: str | None = None,
         dwg_version: str = "AC1015",
         strict: bool = False,
+        layer_map: str | None = None,
     ) -> int:
         captured["input_path"] = input_path
         captured["output_path"] = output_path
@@ -350,3 +1023,291 @@ def test_cli_main_dispatches_write_command(monkeypatch) -> None:
         "dwg_version": "AC1015",
         "strict": True,
     }
+
+
+def test_raw_write_ac1015_dwg_writes_paperspace_layout(tmp_path: Path) -> None:
+    output = tmp_path / "raw_layout_out.dwg"
+    plot_settings = (
+        ("", "DWG To PDF.pc3", "ISO_A3_(420.00_x_297.00_MM)", "monochrome.ctb"),
+        688,
+        (297.0, 420.0),
+        (7.5, 20.0, 7.5, 20.0),
+        (0.0, 0.0),
+        1,
+        1,
+        5,
+        ((0.0, 0.0), (0.0, 0.0)),
+        (1.0, 1.0, 16, 1.0),
+    )
+    ezdwg.raw.write_ac1015_dwg(
+        str(output),
+        [(0x30, 1.0, 2.0, 0.0, 4.5, 7.0, 0.0)],
+        [],
+        [],
+        [],
+        [],
+        [],
+        layouts=[
+            ("Sheet1", plot_settings, [(0, (210.0, 148.5, 0.0), 380.0, 257.0, (2.75, 4.5), 10.0)])
+        ],
+    )
+
+    doc = ezdwg.read(str(output))
+    layouts = doc.layouts()
+    assert [layout.name for layout in layouts] == ["Model", "Sheet1"]
+    sheet = layouts[1]
+    assert sheet.limits == ((0.0, 0.0), (420.0, 297.0))
+    assert sheet.extents == ((20.0, 20.0, 0.0), (400.0, 277.0, 0.0))
+    settings = sheet.plot_settings
+    assert settings.printer_name == "DWG To PDF.pc3"
+    assert settings.plot_style_table == "monochrome.ctb"
+    assert settings.page_size == (420.0, 297.0)
+    assert settings.orientation == "landscape"
+    assert len(ezdwg.raw.decode_viewport_entities(str(output))) == 1
+
+
+def test_to_dwg_stamps_application_name(tmp_path: Path) -> None:
+    output = tmp_path / "line_2000_stamped.dwg"
+
+    ezdwg.to_dwg(
+        str(SAMPLES / "line_2000.dwg"),
+        str(output),
+        application_name="ACMECAD",
+        application_version="4.2.1",
+    )
+
+    data = output.read_bytes()
+    assert int.from_bytes(data[0x13:0x15], "little") == 30
+    type_names = [row[4] for row in ezdwg.raw.list_object_headers_with_type(str(output))]
+    assert sorted(type_names) == ["APPID", "LAYER", "LINE"]
+    assert len(list(ezdwg.read(str(output)).modelspace().query("LINE"))) == 1
+
+
+def test_to_dwg_writes_groups(tmp_path: Path) -> None:
+    source = str(SAMPLES / "line_2000.dwg")
+    line = next(ezdwg.read(source).modelspace().query("LINE"))
+    output = tmp_path / "line_2000_grouped.dwg"
+
+    ezdwg.to_dwg(source, str(output), groups={"OUTLINE": [line.handle]})
+
+    headers = ezdwg.raw.list_object_headers_with_type(str(output))
+    type_names = sorted(row[4] for row in headers)
+    assert type_names.count("GROUP") == 1
+    assert type_names.count("DICTIONARY") == 1
+    assert len(list(ezdwg.read(str(output)).modelspace().query("LINE"))) == 1
+
+    with pytest.raises(ValueError, match="not an entity"):
+        ezdwg.to_dwg(source, str(output), groups={"OUTLINE": [0x7FFF]})
+
+
+def test_to_dwg_round_trips_application_data(tmp_path: Path) -> None:
+    source = str(SAMPLES / "line_2000.dwg")
+    line = next(ezdwg.read(source).modelspace().query("LINE"))
+    output = tmp_path / "line_2000_app_data.dwg"
+    payload = bytes(range(200))
+
+    ezdwg.to_dwg(
+        source,
+        str(output),
+        entity_xdata={line.handle: {"PIPELINE": [(1000, "part-7"), (1071, 42)]}},
+        dictionaries={
+            "PIPELINE_DATA": {"RUN": "run-3", "COUNT": 12, "PAYLOAD": payload},
+        },
+    )
+
+    doc = ezdwg.read(str(output))
+    assert doc.xdata()[line.handle] == {"PIPELINE": [(1000, "part-7"), (1071, 42)]}
+    records = doc.app_data()["PIPELINE_DATA"]
+    assert records["RUN"] == [(1, "run-3")]
+    assert records["COUNT"] == [(90, 12)]
+    assert b"".join(value for _, value in records["PAYLOAD"]) == payload
+
+    with pytest.raises(ValueError, match="unique"):
+        ezdwg.to_dwg(source, str(output), dictionaries={"A": {"K": 1}, "a": {"K": 2}})
+
+
+def test_apply_incremental_update_replaces_line(tmp_path: Path) -> None:
+    source = SAMPLES / "line_2000.dwg"
+    output = tmp_path / "line_2000_updated.dwg"
+    original = source.read_bytes()
+    line = next(iter(ezdwg.read(str(source)).modelspace().query("LINE")))
+    moved = ezdwg.Entity(
+        dxftype="LINE",
+        handle=line.handle,
+        dxf={**line.dxf, "start": (1.0, 2.0, 0.0), "end": (30.0, 40.0, 0.0)},
+    )
+
+    result = ezdwg.apply_incremental_update(str(source), [moved], output_path=str(output))
+
+    assert result.written_entities == 1
+    assert result.skipped_entities == 0
+    updated = output.read_bytes()
+    assert len(updated) > len(original)
+    assert updated[:0x15] == original[:0x15]
+    lines = list(ezdwg.read(str(output)).modelspace().query("LINE"))
+    assert len(lines) == 1
+    assert lines[0].handle == line.handle
+    assert lines[0].dxf["start"] == (1.0, 2.0, 0.0)
+    assert lines[0].dxf["end"] == (30.0, 40.0, 0.0)
+
+
+def test_apply_incremental_update_rounds_and_validates_floats(tmp_path: Path) -> None:
+    source = SAMPLES / "line_2000.dwg"
+    output = tmp_path / "line_2000_rounded.dwg"
+    line = next(iter(ezdwg.read(str(source)).modelspace().query("LINE")))
+    noisy = ezdwg.Entity(
+        dxftype="LINE",
+        handle=line.handle,
+        dxf={**line.dxf, "start": (1.0000000000000002, 5e-324, -0.0), "end": (3e-15, 40.0, 0.0)},
+    )
+
+    result = ezdwg.apply_incremental_update(
+        str(source), [noisy], output_path=str(output), precision=6
+    )
+
+    assert result.written_entities == 1
+    written = next(iter(ezdwg.read(str(output)).modelspace().query("LINE")))
+    assert written.dxf["start"] == (1.0, 0.0, 0.0)
+    assert written.dxf["end"] == (0.0, 40.0, 0.0)
+
+    broken = ezdwg.Entity(
+        dxftype="LINE",
+        handle=line.handle,
+        dxf={**line.dxf, "start": (float("nan"), 0.0, 0.0)},
+    )
+    with pytest.raises(ValueError, match="LINE:1"):
+        ezdwg.apply_incremental_update(str(source), [broken], output_path=str(output), strict=True)
+
+
+def test_query_precision_rounds_decoded_floats() -> None:
+    layout = ezdwg.read(str(SAMPLES / "arc_2004.dwg")).modelspace()
+    arc = next(iter(layout.query("ARC")))
+    rounded = next(iter(layout.query("ARC", precision=2)))
+
+    assert rounded.handle == arc.handle
+    assert rounded.dxf["radius"] == round(arc.dxf["radius"], 2)
+    assert rounded.dxf["center"] == tuple(round(value, 2) for value in arc.dxf["center"])
+    assert arc.rounded(2) == rounded
+    assert ezdwg.Entity("POINT", 1, {"location": (-0.0, 1e-310, 0.125)}).rounded(2).dxf == {
+        "location": (0.0, 0.0, 0.12)
+    }
+
+
+def test_apply_incremental_update_rejects_unknown_handle(tmp_path: Path) -> None:
+    stray = ezdwg.Entity(
+        dxftype="LINE",
+        handle=0xFFFFFF,
+        dxf={"start": (0.0, 0.0, 0.0), "end": (1.0, 1.0, 0.0)},
+    )
+    with pytest.raises(Exception, match="not found in object map"):
+        ezdwg.apply_incremental_update(
+            str(SAMPLES / "line_2000.dwg"),
+            [stray],
+            output_path=str(tmp_path / "out.dwg"),
+        )
+
+
+def test_replace_text_updates_text_and_mtext_strings(tmp_path: Path) -> None:
+    text_source = str(SAMPLES / "text_2000.dwg")
+    text_output = tmp_path / "text_replaced.dwg"
+    result = ezdwg.replace_text(text_source, "TEXT", "PRJ-0042", str(text_output))
+    assert (result.total_entities, result.written_entities) == (1, 1)
+    text = next(ezdwg.read(str(text_output)).modelspace().query("TEXT"))
+    source_text = next(ezdwg.read(text_source).modelspace().query("TEXT"))
+    assert text.dxf["text"] == "Hello PRJ-0042"
+    assert text.dxf["insert"] == source_text.dxf["insert"]
+    assert text.dxf["height"] == source_text.dxf["height"]
+
+    mtext_source = SAMPLES / "mtext_2000.dwg"
+    formatted = tmp_path / "mtext_formatted.dwg"
+    mtext = next(ezdwg.read(str(mtext_source)).modelspace().query("MTEXT"))
+    ezdwg.raw.replace_entity_text(
+        str(mtext_source), str(formatted), [(mtext.handle, r"{\C1;PRJ}-0041\PDate 2024-01-31")]
+    )
+    mtext_output = tmp_path / "mtext_replaced.dwg"
+    ezdwg.replace_text(str(formatted), "PRJ-0041", "PRJ-{42}", str(mtext_output))
+    ezdwg.replace_text(str(mtext_output), r"(\d{4})-\d{2}-\d{2}", r"\1-10-15", regex=True)
+    raw_text = ezdwg.raw.decode_mtext_entities(str(mtext_output))[0][1]
+    # The color group around the old number now wraps the new one.
+    assert raw_text == r"{\C1;PRJ-\{42\}}\PDate 2024-10-15"
+
+    unchanged = ezdwg.replace_text(str(mtext_output), "missing", "x", str(tmp_path / "same.dwg"))
+    assert unchanged.written_entities == 0
+    with pytest.raises(ValueError, match="not supported"):
+        ezdwg.replace_text(str(SAMPLES / "line_2007.dwg"), "a", "b", str(tmp_path / "out.dwg"))
+
+
+def test_raw_write_ac1015_dwg_writes_entity_styles(tmp_path: Path) -> None:
+    output = tmp_path / "styled.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(output),
+        [
+            (0x30, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0),
+            (0x31, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0),
+            (0x32, 0.0, 2.0, 0.0, 1.0, 2.0, 0.0),
+        ],
+        [],
+        [],
+        [],
+        [],
+        [],
+        styles=[
+            (0x30, "DASHED", 50, 0x0200_0080),
+            (0x31, "Continuous", -2, None),
+        ],
+        linetypes=[("DASHED", "__ __ __", [0.5, -0.25])],
+    )
+
+    line_styles = {row[0]: row for row in ezdwg.raw.decode_entity_line_styles(str(output))}
+    linetypes = {row[1]: row for row in ezdwg.raw.decode_linetypes(str(output))}
+    transparency = dict(ezdwg.raw.decode_entity_transparency(str(output)))
+
+    dashed = linetypes["DASHED"]
+    assert dashed[2] == "__ __ __"
+    assert dashed[3] == 0.75
+    assert [length for length, _, _ in dashed[4]] == [0.5, -0.25]
+    assert line_styles[0x30][1:3] == (3, dashed[0])
+    assert line_styles[0x30][4] == 50
+    assert line_styles[0x31][1] == 2
+    assert line_styles[0x31][4] == -2
+    assert line_styles[0x32][1] == 0
+    assert line_styles[0x32][4] == -1
+    assert transparency[0x30] == 0x0200_0080
+    assert transparency.get(0x31) is None
+
+
+def test_raw_write_ac1015_dwg_rejects_unknown_lineweight(tmp_path: Path) -> None:
+    with pytest.raises(Exception, match="lineweight"):
+        ezdwg.raw.write_ac1015_dwg(
+            str(tmp_path / "bad.dwg"),
+            [(0x30, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0)],
+            [],
+            [],
+            [],
+            [],
+            [],
+            styles=[(0x30, None, 17, None)],
+        )
+
+
+def test_to_dwg_keeps_source_entity_styles(tmp_path: Path) -> None:
+    source = tmp_path / "styled.dwg"
+    ezdwg.raw.write_ac1015_dwg(
+        str(source),
+        [(0x30, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0)],
+        [],
+        [],
+        [],
+        [],
+        [],
+        styles=[(0x30, "HIDDEN", 35, 0x0200_0040)],
+        linetypes=[("HIDDEN", "_ _ _", [0.25, -0.125])],
+    )
+    output = tmp_path / "copy.dwg"
+
+    ezdwg.to_dwg(str(source), str(output))
+
+    (line,) = ezdwg.read(str(output)).modelspace().query("LINE")
+    assert line.dxf["lineweight"] == 35
+    assert line.dxf["transparency"] == 0x0200_0040
+    assert line.dxf["linetype_pattern"] == (0.25, -0.125)